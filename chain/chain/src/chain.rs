@@ -465,6 +465,7 @@ impl Chain {
             chain_config.save_trie_changes,
             transaction_validity_period,
         );
+        chain_store.set_index_contract_events(chain_config.index_contract_events);
         let state_sync_adapter = ChainStateSyncAdapter::new(
             clock.clone(),
             ChainStoreAdapter::new(chain_store.store()),
@@ -541,6 +542,12 @@ impl Chain {
             &shard_uids_pending_resharding,
             true,
         )?;
+        Self::compile_called_contracts(
+            &chain_store,
+            runtime_adapter.as_ref(),
+            &tip,
+            &tracked_shards,
+        );
 
         info!(target: "chain", "Init: header head @ #{} {}; block head @ #{} {}",
               header_head.height, header_head.last_block_hash,
@@ -594,6 +601,33 @@ impl Chain {
         })
     }
 
+    /// Compiles in the background the contracts recently called on the tracked shards, in
+    /// case the compiled contract cache has been lost or invalidated while the node was down.
+    fn compile_called_contracts(
+        chain_store: &ChainStore,
+        runtime_adapter: &dyn RuntimeAdapter,
+        tip: &Tip,
+        tracked_shards: &[ShardUId],
+    ) {
+        for shard_uid in tracked_shards {
+            let result = chain_store.get_called_contracts(shard_uid).and_then(|called_contracts| {
+                let Some(called_contracts) = called_contracts else {
+                    return Ok(());
+                };
+                let chunk_extra = chain_store.get_chunk_extra(&tip.last_block_hash, shard_uid)?;
+                runtime_adapter.compile_contracts_in_background(
+                    &tip.epoch_id,
+                    *shard_uid,
+                    *chunk_extra.state_root(),
+                    called_contracts.all(),
+                )
+            });
+            if let Err(err) = result {
+                warn!(target: "chain", ?err, %shard_uid, "Failed to compile the called contracts");
+            }
+        }
+    }
+
     #[cfg(feature = "test_features")]
     pub fn adv_disable_doomslug(&mut self) {
         self.doomslug_threshold_mode = DoomslugThresholdMode::NoApprovals
@@ -22,10 +22,12 @@ use near_primitives::hash::CryptoHash;
 use near_primitives::shard_layout::ShardUId;
 use near_primitives::sharding::ShardChunk;
 use near_primitives::state_sync::{ReceiptProofResponse, ShardStateSyncResponseHeader};
+use near_primitives::stateless_validation::contract_distribution::CodeHash;
 use near_primitives::types::chunk_extra::ChunkExtra;
 use near_primitives::types::{BlockHeight, ShardId};
 use near_primitives::views::LightClientBlockView;
 use node_runtime::SignedValidPeriodTransactions;
+use std::collections::HashSet;
 use std::sync::Arc;
 use tracing::{debug, warn};
 
@@ -130,11 +132,19 @@ impl<'a> ChainUpdate<'a> {
                 // Save receipt and transaction results.
                 self.chain_store_update.save_outcomes_with_proofs(
                     block_hash,
+                    height,
                     shard_id,
                     apply_result.outcomes,
                     outcome_paths,
                 );
-                if should_save_state_transition_data {
+                self.save_called_contracts(
+                    block,
+                    shard_uid,
+                    &apply_result.contract_updates.contract_accesses,
+                )?;
+                // Only the deployments of successful receipts are in the contract updates.
+                let deployed_contracts = if should_save_state_transition_data {
+                    let deployed_contracts = apply_result.contract_updates.contract_deploys.clone();
                     self.chain_store_update.save_state_transition_data(
                         *block_hash,
                         shard_id,
@@ -142,7 +152,14 @@ impl<'a> ChainUpdate<'a> {
                         apply_result.applied_receipts_hash,
                         apply_result.contract_updates,
                     );
-                }
+                    deployed_contracts
+                } else {
+                    apply_result.contract_updates.contract_deploys
+                };
+                self.runtime_adapter.compile_deployed_contracts_in_background(
+                    block.header().epoch_id(),
+                    deployed_contracts,
+                )?;
                 self.chain_store_update.save_chunk_apply_stats(
                     *block_hash,
                     shard_id,
@@ -188,6 +205,42 @@ impl<'a> ChainUpdate<'a> {
         Ok(())
     }
 
+    /// Records the contracts called by the chunk of `shard_uid` in `block`. When this is the
+    /// first chunk of the shard in a new epoch, the contracts called in the previous epoch are
+    /// compiled in the background for the configuration of the new epoch.
+    fn save_called_contracts(
+        &mut self,
+        block: &Block,
+        shard_uid: ShardUId,
+        called: &HashSet<CodeHash>,
+    ) -> Result<(), Error> {
+        let epoch_id = block.header().epoch_id();
+        let Some(code_hashes) =
+            self.chain_store_update.save_called_contracts(epoch_id, &shard_uid, called)?
+        else {
+            return Ok(());
+        };
+        if code_hashes.is_empty() {
+            return Ok(());
+        }
+        // The state of this chunk is not committed yet, but the contracts called in the
+        // previous epoch are most likely in the state of the previous block.
+        let prev_hash = block.header().prev_hash();
+        let state_root = match self.chain_store_update.get_chunk_extra(prev_hash, &shard_uid) {
+            Ok(chunk_extra) => *chunk_extra.state_root(),
+            Err(err) => {
+                debug!(target: "chain", ?err, %shard_uid, "Cannot compile called contracts ahead of time");
+                return Ok(());
+            }
+        };
+        self.runtime_adapter.compile_contracts_in_background(
+            epoch_id,
+            shard_uid,
+            state_root,
+            code_hashes,
+        )
+    }
+
     /// Extra sanity check for bandwidth scheduler - the scheduler state should be the same on all shards.
     fn bandwidth_scheduler_state_sanity_check(apply_results: &[ShardUpdateResult]) {
         let state_hashes: Vec<CryptoHash> = apply_results
@@ -526,6 +579,7 @@ impl<'a> ChainUpdate<'a> {
         // Saving transaction results.
         self.chain_store_update.save_outcomes_with_proofs(
             block_header.hash(),
+            block_header.height(),
             shard_id,
             apply_result.outcomes,
             outcome_proofs,
@@ -2,12 +2,12 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::{fmt, io};
 
-use near_chain_configs::GCConfig;
+use near_chain_configs::{GCConfig, GCDataGroup, GCRetention};
 use near_chain_primitives::Error;
 use near_epoch_manager::EpochManagerAdapter;
 use near_epoch_manager::shard_assignment::shard_id_to_uid;
 use near_epoch_manager::shard_tracker::ShardTracker;
-use near_primitives::block::Block;
+use near_primitives::block::{Block, BlockHeader};
 use near_primitives::hash::CryptoHash;
 use near_primitives::shard_layout::get_block_shard_uid;
 use near_primitives::state_sync::{StateHeaderKey, StatePartKey};
@@ -169,13 +169,14 @@ impl ChainStore {
         // blocks.
         let result = self.clear_state_transition_data(epoch_manager.as_ref());
 
-        result.and(self.clear_old_blocks_data(
+        let result = result.and(self.clear_old_blocks_data(
             gc_config,
             runtime_adapter,
-            epoch_manager,
+            epoch_manager.clone(),
             shard_tracker,
             me,
-        ))
+        ));
+        result.and(self.clear_retained_data(gc_config, epoch_manager.as_ref()))
     }
 
     fn clear_old_blocks_data(
@@ -201,6 +202,7 @@ impl ChainStore {
         }
         let prev_epoch_id = *self.get_block_header(&head.prev_block_hash)?.epoch_id();
         let epoch_change = prev_epoch_id != head.epoch_id;
+        let head_header = self.get_block_header(&head.last_block_hash)?;
         let mut fork_tail = self.fork_tail()?;
         metrics::TAIL_HEIGHT.set(tail as i64);
         metrics::FORK_TAIL_HEIGHT.set(fork_tail as i64);
@@ -263,6 +265,14 @@ impl ChainStore {
                 }
                 debug_assert_eq!(blocks_current_height.len(), 1);
 
+                // Canonical Chain Clearing removes the data of the previous block.
+                let retained_groups = retained_gc_groups(
+                    gc_config,
+                    epoch_manager.as_ref(),
+                    &head_header,
+                    &chain_store_update.get_block_header(&prev_hash)?,
+                )?;
+
                 // Do not clean up immediately, as we still need the State in order to run gc for this block.
                 let potential_shards_for_cleanup = get_potential_shards_for_cleanup(
                     &chain_store_update,
@@ -271,10 +281,11 @@ impl ChainStore {
                     block_hash,
                 )?;
 
-                chain_store_update.clear_block_data(
+                chain_store_update.clear_block_data_with_retention(
                     epoch_manager.as_ref(),
                     *block_hash,
                     GCMode::Canonical(tries.clone()),
+                    retained_groups,
                 )?;
                 gc_blocks_remaining -= 1;
 
@@ -296,6 +307,52 @@ impl ChainStore {
         Ok(())
     }
 
+    /// Garbage collects the data which was kept after its block had been
+    /// garbage collected, once its group is no longer retained according to
+    /// `GCConfig::gc_retention`.
+    fn clear_retained_data(
+        &mut self,
+        gc_config: &GCConfig,
+        epoch_manager: &dyn EpochManagerAdapter,
+    ) -> Result<(), Error> {
+        let head = self.head()?;
+        if head.height == self.get_genesis_height() {
+            return Ok(());
+        }
+        let head_header = self.get_block_header(&head.last_block_hash)?;
+        for group in GC_DATA_GROUPS {
+            let entries = self
+                .store()
+                .iter_prefix_ser::<Vec<(u8, Vec<u8>)>>(DBCol::GCRetainedKeys, &[group.id()])
+                .take(gc_config.gc_blocks_limit as usize)
+                .collect::<io::Result<Vec<_>>>()?;
+            if entries.is_empty() {
+                continue;
+            }
+            let columns = gc_data_group_columns(group);
+            let mut chain_store_update = self.store_update();
+            for (entry_key, keys) in entries {
+                let height = BlockHeight::from_be_bytes(entry_key[1..].try_into().unwrap());
+                // Data of groups which are no longer retained at all is removed
+                // right away.
+                if let Some(retention) = gc_config.gc_retention.get(&group) {
+                    let block_hash = chain_store_update.get_block_hash_by_height(height)?;
+                    let header = chain_store_update.get_block_header(&block_hash)?;
+                    if is_retained(epoch_manager, retention, &head_header, &header)? {
+                        break;
+                    }
+                }
+                tracing::debug!(target: "garbage_collection", ?group, height, "GC retained data");
+                for (index, key) in keys {
+                    chain_store_update.gc_col(columns[index as usize], &key);
+                }
+                chain_store_update.gc_col(DBCol::GCRetainedKeys, &entry_key);
+            }
+            chain_store_update.commit()?;
+        }
+        Ok(())
+    }
+
     fn clear_state_transition_data(
         &self,
         epoch_manager: &dyn EpochManagerAdapter,
@@ -546,7 +603,11 @@ impl<'a> ChainStoreUpdate<'a> {
         Ok(())
     }
 
-    fn clear_chunk_data_and_headers(&mut self, min_chunk_height: BlockHeight) -> Result<(), Error> {
+    fn clear_chunk_data_and_headers(
+        &mut self,
+        min_chunk_height: BlockHeight,
+        retained: &mut RetainedKeys,
+    ) -> Result<(), Error> {
         let chunk_tail = self.chunk_tail()?;
         for height in chunk_tail..min_chunk_height {
             let chunk_hashes = self.chain_store().get_all_chunk_hashes_by_height(height)?;
@@ -555,14 +616,22 @@ impl<'a> ChainStoreUpdate<'a> {
                 let chunk = self.get_chunk(&chunk_hash)?.clone();
                 debug_assert_eq!(chunk.cloned_header().height_created(), height);
                 for transaction in chunk.to_transactions() {
-                    self.gc_col(DBCol::Transactions, transaction.get_hash().as_bytes());
+                    self.gc_col_or_retain(
+                        DBCol::Transactions,
+                        transaction.get_hash().as_bytes(),
+                        retained,
+                    );
                 }
 
                 let partial_chunk = self.get_partial_chunk(&chunk_hash);
                 if let Ok(partial_chunk) = partial_chunk {
                     for receipts in partial_chunk.prev_outgoing_receipts() {
                         for receipt in &receipts.0 {
-                            self.gc_col(DBCol::Receipts, receipt.receipt_id().as_bytes());
+                            self.gc_col_or_retain(
+                                DBCol::Receipts,
+                                receipt.receipt_id().as_bytes(),
+                                retained,
+                            );
                         }
                     }
                 }
@@ -659,11 +728,28 @@ impl<'a> ChainStoreUpdate<'a> {
     // Clearing block data of `block_hash`, if on a fork.
     // Clearing block data of `block_hash.prev`, if on the Canonical Chain.
     pub fn clear_block_data(
+        &mut self,
+        epoch_manager: &dyn EpochManagerAdapter,
+        block_hash: CryptoHash,
+        gc_mode: GCMode,
+    ) -> Result<(), Error> {
+        self.clear_block_data_with_retention(epoch_manager, block_hash, gc_mode, vec![])
+    }
+
+    /// Same as `clear_block_data`, but the data of `retained_groups` is kept
+    /// and its keys are saved in `DBCol::GCRetainedKeys` to be garbage collected
+    /// later.  Only Canonical Chain Clearing retains data.
+    pub fn clear_block_data_with_retention(
         &mut self,
         epoch_manager: &dyn EpochManagerAdapter,
         mut block_hash: CryptoHash,
         gc_mode: GCMode,
+        retained_groups: Vec<GCDataGroup>,
     ) -> Result<(), Error> {
+        let mut retained = match gc_mode {
+            GCMode::Canonical(_) => RetainedKeys::new(retained_groups),
+            _ => RetainedKeys::default(),
+        };
         let mut store_update = self.store().trie_store().store_update();
 
         tracing::debug!(target: "garbage_collection", ?gc_mode, ?block_hash, "GC block_hash");
@@ -722,10 +808,10 @@ impl<'a> ChainStoreUpdate<'a> {
             .map(|item| item.map(|(key, _)| key))
             .collect::<io::Result<Vec<_>>>()?;
         for key in stored_state_changes {
-            self.gc_col(DBCol::StateChanges, &key);
+            self.gc_col_or_retain(DBCol::StateChanges, &key, &mut retained);
         }
         self.gc_col(DBCol::BlockRefCount, block_hash.as_bytes());
-        self.gc_outcomes(&block)?;
+        self.gc_outcomes(&block, &mut retained)?;
         match gc_mode {
             GCMode::StateSync { clear_block_info: false } => {}
             _ => self.gc_col(DBCol::BlockInfo, block_hash.as_bytes()),
@@ -749,17 +835,38 @@ impl<'a> ChainStoreUpdate<'a> {
                         min_chunk_height = chunk_header.height_created();
                     }
                 }
-                self.clear_chunk_data_and_headers(min_chunk_height)?;
+                self.clear_chunk_data_and_headers(min_chunk_height, &mut retained)?;
             }
             GCMode::StateSync { .. } => {
                 // 7. State Sync clearing
                 // Chunks deleted separately
             }
         };
+        self.save_retained_keys(height, retained)?;
         self.merge(store_update.into());
         Ok(())
     }
 
+    /// Saves the keys of the data retained while clearing the block at `height`.
+    fn save_retained_keys(
+        &mut self,
+        height: BlockHeight,
+        retained: RetainedKeys,
+    ) -> Result<(), Error> {
+        let mut store_update = self.store().store_update();
+        for (group, mut keys) in retained.keys {
+            let entry_key = retained_keys_key(group, height);
+            if let Some(existing) =
+                self.store().get_ser::<Vec<(u8, Vec<u8>)>>(DBCol::GCRetainedKeys, &entry_key)?
+            {
+                keys.extend(existing);
+            }
+            store_update.set_ser(DBCol::GCRetainedKeys, &entry_key, &keys)?;
+        }
+        self.merge(store_update);
+        Ok(())
+    }
+
     fn gc_trie_changes(
         &mut self,
         epoch_manager: &dyn EpochManagerAdapter,
@@ -860,7 +967,7 @@ impl<'a> ChainStoreUpdate<'a> {
             self.gc_col(DBCol::StateChanges, &key);
         }
         self.gc_col(DBCol::BlockRefCount, block_hash.as_bytes());
-        self.gc_outcomes(&block)?;
+        self.gc_outcomes(&block, &mut RetainedKeys::default())?;
         self.gc_col(DBCol::BlockInfo, block_hash.as_bytes());
         self.gc_col(DBCol::StateDlInfos, block_hash.as_bytes());
         self.gc_col(DBCol::StateSyncNewChunks, block_hash.as_bytes());
@@ -961,7 +1068,7 @@ impl<'a> ChainStoreUpdate<'a> {
         self.merge(store_update);
     }
 
-    fn gc_outcomes(&mut self, block: &Block) -> Result<(), Error> {
+    fn gc_outcomes(&mut self, block: &Block, retained: &mut RetainedKeys) -> Result<(), Error> {
         let block_hash = block.hash();
         let store_update = self.store().store_update();
         for chunk_header in block
@@ -975,17 +1082,40 @@ impl<'a> ChainStoreUpdate<'a> {
             let outcome_ids =
                 self.chain_store().get_outcomes_by_block_hash_and_shard_id(block_hash, shard_id)?;
             for outcome_id in outcome_ids {
-                self.gc_col(
+                self.gc_col_or_retain(
                     DBCol::TransactionResultForBlock,
                     &get_outcome_id_block_hash(&outcome_id, block_hash),
+                    retained,
                 );
             }
-            self.gc_col(DBCol::OutcomeIds, &get_block_shard_id(block_hash, shard_id));
+            let block_shard_id = get_block_shard_id(block_hash, shard_id);
+            self.gc_col_or_retain(DBCol::OutcomeIds, &block_shard_id, retained);
+            // Events are indexed only if `index_contract_events` is set.
+            if let Some(event_keys) =
+                self.store().get_ser::<Vec<Vec<u8>>>(DBCol::ContractEventKeys, &block_shard_id)?
+            {
+                for event_key in event_keys {
+                    self.gc_col_or_retain(DBCol::ContractEvents, &event_key, retained);
+                }
+                self.gc_col_or_retain(DBCol::ContractEventKeys, &block_shard_id, retained);
+            }
         }
         self.merge(store_update);
         Ok(())
     }
 
+    /// Garbage collects the key unless the column belongs to one of the
+    /// retained groups, in which case the key is remembered in `retained`.
+    fn gc_col_or_retain(&mut self, col: DBCol, key: &[u8], retained: &mut RetainedKeys) {
+        for &group in &retained.groups {
+            if let Some(index) = gc_data_group_columns(group).iter().position(|c| *c == col) {
+                retained.keys.entry(group).or_default().push((index as u8, key.to_vec()));
+                return;
+            }
+        }
+        self.gc_col(col, key);
+    }
+
     fn gc_col(&mut self, col: DBCol, key: &[u8]) {
         let mut store_update = self.store().store_update();
         match col {
@@ -1093,6 +1223,15 @@ impl<'a> ChainStoreUpdate<'a> {
             DBCol::ChunkApplyStats => {
                 store_update.delete(col, key);
             }
+            DBCol::GCRetainedKeys => {
+                store_update.delete(col, key);
+            }
+            DBCol::ContractEvents => {
+                store_update.delete(col, key);
+            }
+            DBCol::ContractEventKeys => {
+                store_update.delete(col, key);
+            }
             DBCol::DbVersion
             | DBCol::BlockMisc
             | DBCol::_GCCount
@@ -1121,6 +1260,8 @@ impl<'a> ChainStoreUpdate<'a> {
             | DBCol::FlatStateChanges
             | DBCol::FlatStateDeltaMetadata
             | DBCol::FlatStorageStatus
+            | DBCol::FlatStateHistory
+            | DBCol::FlatStateHistoryKeys
             | DBCol::EpochSyncProof
             | DBCol::Misc
             | DBCol::_ReceiptIdToShardId
@@ -1134,6 +1275,86 @@ impl<'a> ChainStoreUpdate<'a> {
     }
 }
 
+const GC_DATA_GROUPS: [GCDataGroup; 4] = [
+    GCDataGroup::TransactionOutcomes,
+    GCDataGroup::Transactions,
+    GCDataGroup::Receipts,
+    GCDataGroup::StateChanges,
+];
+
+/// Columns of a group of data which can be retained.  The index of a column in
+/// the list is saved in `DBCol::GCRetainedKeys`, so the order must not change.
+fn gc_data_group_columns(group: GCDataGroup) -> &'static [DBCol] {
+    match group {
+        GCDataGroup::TransactionOutcomes => &[
+            DBCol::TransactionResultForBlock,
+            DBCol::OutcomeIds,
+            DBCol::ContractEvents,
+            DBCol::ContractEventKeys,
+        ],
+        GCDataGroup::Transactions => &[DBCol::Transactions],
+        GCDataGroup::Receipts => &[DBCol::Receipts],
+        GCDataGroup::StateChanges => &[DBCol::StateChanges],
+    }
+}
+
+fn retained_keys_key(group: GCDataGroup, height: BlockHeight) -> Vec<u8> {
+    // Big-endian so that the entries of a group are sorted by height.
+    [&[group.id()][..], &height.to_be_bytes()].concat()
+}
+
+/// Keys of the data kept while garbage collecting a block, by group.
+#[derive(Default)]
+struct RetainedKeys {
+    groups: Vec<GCDataGroup>,
+    keys: HashMap<GCDataGroup, Vec<(u8, Vec<u8>)>>,
+}
+
+impl RetainedKeys {
+    fn new(groups: Vec<GCDataGroup>) -> Self {
+        Self { groups, keys: HashMap::new() }
+    }
+}
+
+/// Whether the data of the block with `header` is still kept by `retention`.
+fn is_retained(
+    epoch_manager: &dyn EpochManagerAdapter,
+    retention: &GCRetention,
+    head_header: &BlockHeader,
+    header: &BlockHeader,
+) -> Result<bool, Error> {
+    match retention {
+        GCRetention::Epochs(num_epochs) => {
+            // Unlike block info, epoch info is never garbage collected.
+            let head_epoch_height =
+                epoch_manager.get_epoch_info(head_header.epoch_id())?.epoch_height();
+            let epoch_height = epoch_manager.get_epoch_info(header.epoch_id())?.epoch_height();
+            Ok(head_epoch_height.saturating_sub(epoch_height) < *num_epochs)
+        }
+        GCRetention::Duration(duration) => {
+            let age = head_header.raw_timestamp().saturating_sub(header.raw_timestamp());
+            Ok(i128::from(age) < duration.whole_nanoseconds())
+        }
+    }
+}
+
+/// Returns the groups of data of the block with `header` which should be kept
+/// after the block is garbage collected.
+fn retained_gc_groups(
+    gc_config: &GCConfig,
+    epoch_manager: &dyn EpochManagerAdapter,
+    head_header: &BlockHeader,
+    header: &BlockHeader,
+) -> Result<Vec<GCDataGroup>, Error> {
+    let mut groups = vec![];
+    for (group, retention) in &gc_config.gc_retention {
+        if is_retained(epoch_manager, retention, head_header, header)? {
+            groups.push(*group);
+        }
+    }
+    Ok(groups)
+}
+
 /// Returns shards that we tracked in an epoch, given a hash of the last block in the epoch.
 /// The block has to be available, so this function has to be called before gc is run for the block.
 ///
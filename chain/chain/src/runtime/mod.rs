@@ -24,6 +24,7 @@ use near_primitives::receipt::Receipt;
 use near_primitives::sandbox::state_patch::SandboxStatePatch;
 use near_primitives::shard_layout::{ShardLayout, ShardUId};
 use near_primitives::state_part::PartId;
+use near_primitives::stateless_validation::contract_distribution::CodeHash;
 use near_primitives::transaction::{SignedTransaction, ValidatedTransaction};
 use near_primitives::types::{
     AccountId, Balance, BlockHeight, EpochHeight, EpochId, EpochInfoProvider, Gas, MerkleHash,
@@ -42,7 +43,7 @@ use near_store::{
     TrieConfig, TrieUpdate, WrappedTrieChanges,
 };
 use near_vm_runner::ContractCode;
-use near_vm_runner::{ContractRuntimeCache, precompile_contract};
+use near_vm_runner::{BackgroundCompiler, ContractRuntimeCache, precompile_contract};
 use node_runtime::adapter::ViewRuntimeAdapter;
 use node_runtime::config::tx_cost;
 use node_runtime::state_viewer::{TrieViewer, ViewApplyState};
@@ -63,6 +64,13 @@ pub mod test_utils;
 #[cfg(test)]
 mod tests;
 
+/// Number of threads compiling contracts in the background.
+const BACKGROUND_COMPILATION_THREADS: usize = 2;
+
+/// Number of contracts waiting for background compilation, beyond which newly deployed
+/// contracts are compiled only when they are first called.
+const BACKGROUND_COMPILATION_QUEUE_SIZE: usize = 128;
+
 /// Defines Nightshade state transition and validator rotation.
 /// TODO: this possibly should be merged with the runtime cargo or at least reconciled on the interfaces.
 pub struct NightshadeRuntime {
@@ -70,6 +78,7 @@ pub struct NightshadeRuntime {
     runtime_config_store: RuntimeConfigStore,
     store: Store,
     compiled_contract_cache: Box<dyn ContractRuntimeCache>,
+    background_compiler: BackgroundCompiler,
     tries: ShardTries,
     trie_viewer: TrieViewer,
     pub runtime: Runtime,
@@ -97,7 +106,10 @@ impl NightshadeRuntime {
 
         let runtime = Runtime::new();
         let trie_viewer = TrieViewer::new(trie_viewer_state_size_limit, max_gas_burnt_view);
-        let flat_storage_manager = FlatStorageManager::new(store.flat_store());
+        let flat_storage_manager = FlatStorageManager::new_with_history_window(
+            store.flat_store(),
+            trie_config.flat_state_history_window,
+        );
         let epoch_config = epoch_manager.read().get_epoch_config(genesis_config.protocol_version);
         let shard_uids: Vec<_> = epoch_config.shard_layout.shard_uids().collect();
         let tries = ShardTries::new(
@@ -119,6 +131,10 @@ impl NightshadeRuntime {
         Arc::new(NightshadeRuntime {
             genesis_config: genesis_config.clone(),
             compiled_contract_cache,
+            background_compiler: BackgroundCompiler::new(
+                BACKGROUND_COMPILATION_THREADS,
+                BACKGROUND_COMPILATION_QUEUE_SIZE,
+            ),
             runtime_config_store,
             store,
             tries,
@@ -149,6 +165,33 @@ impl NightshadeRuntime {
         Ok(ShardUId::new(shard_version, shard_id))
     }
 
+    /// Creates a view of the state of `block_hash` at `block_height`.  If the
+    /// block is on the canonical chain, the values may be read from flat
+    /// storage, including its history for heights below flat head.
+    fn new_trie_update_view_at_height(
+        &self,
+        shard_uid: ShardUId,
+        state_root: StateRoot,
+        block_hash: &CryptoHash,
+        block_height: BlockHeight,
+    ) -> TrieUpdate {
+        // History of flat state only describes the canonical chain.
+        let is_canonical = self
+            .store
+            .chain_store()
+            .get_block_hash_by_height(block_height)
+            .is_ok_and(|hash| &hash == block_hash);
+        if !is_canonical {
+            return self.tries.new_trie_update_view(shard_uid, state_root);
+        }
+        TrieUpdate::new(self.tries.get_view_trie_at_height(
+            shard_uid,
+            state_root,
+            block_hash,
+            block_height,
+        ))
+    }
+
     /// Processes state update.
     #[instrument(target = "runtime", level = "debug", "process_state_update", skip_all)]
     fn process_state_update(
@@ -249,6 +292,9 @@ impl NightshadeRuntime {
             current_protocol_version,
             config: self.runtime_config_store.get_config(current_protocol_version).clone(),
             cache: Some(self.compiled_contract_cache.handle()),
+            // The chain compiles the deployed contracts in the background after applying the
+            // chunk, see `ChainUpdate::apply_chunk_postprocessing`.
+            defer_deploy_compilation: apply_reason == ApplyChunkReason::UpdateTrackedShard,
             is_new_chunk,
             congestion_info,
             bandwidth_requests,
@@ -922,6 +968,8 @@ impl RuntimeAdapter for NightshadeRuntime {
                     .view_state(
                         &shard_uid,
                         *state_root,
+                        block_height,
+                        block_hash,
                         account_id,
                         prefix.as_ref(),
                         *include_proof,
@@ -1181,6 +1229,52 @@ impl RuntimeAdapter for NightshadeRuntime {
         });
         Ok(())
     }
+
+    fn compile_contracts_in_background(
+        &self,
+        epoch_id: &EpochId,
+        shard_uid: ShardUId,
+        state_root: StateRoot,
+        code_hashes: Vec<CodeHash>,
+    ) -> Result<(), Error> {
+        if code_hashes.is_empty() {
+            return Ok(());
+        }
+        let protocol_version = self.epoch_manager.get_epoch_protocol_version(epoch_id)?;
+        let config =
+            Arc::clone(&self.runtime_config_store.get_config(protocol_version).wasm_config);
+        let trie = self.tries.get_view_trie_for_shard(shard_uid, state_root);
+        let contract_storage = TrieUpdate::new(trie).contract_storage();
+        debug!(target: "runtime", %shard_uid, num_contracts = code_hashes.len(), "compile_contracts_in_background");
+        // The contracts are read from the storage on the threads of the compiler.
+        self.background_compiler.submit_stored(
+            code_hashes.into_iter().map(|CodeHash(code_hash)| code_hash),
+            move |code_hash| contract_storage.get(code_hash),
+            config,
+            self.compiled_contract_cache.as_ref(),
+        );
+        Ok(())
+    }
+
+    fn compile_deployed_contracts_in_background(
+        &self,
+        epoch_id: &EpochId,
+        contract_codes: Vec<ContractCode>,
+    ) -> Result<(), Error> {
+        if contract_codes.is_empty() {
+            return Ok(());
+        }
+        let protocol_version = self.epoch_manager.get_epoch_protocol_version(epoch_id)?;
+        let config = &self.runtime_config_store.get_config(protocol_version).wasm_config;
+        for code in contract_codes {
+            self.background_compiler.submit(
+                code,
+                Arc::clone(config),
+                self.compiled_contract_cache.as_ref(),
+            );
+        }
+        Ok(())
+    }
 }
 
 /// How much gas of the next chunk we want to spend on converting new
@@ -1268,7 +1362,8 @@ impl node_runtime::adapter::ViewRuntimeAdapter for NightshadeRuntime {
         epoch_info_provider: &dyn EpochInfoProvider,
         current_protocol_version: ProtocolVersion,
     ) -> Result<Vec<u8>, node_runtime::state_viewer::errors::CallFunctionError> {
-        let state_update = self.tries.new_trie_update_view(*shard_uid, state_root);
+        let state_update =
+            self.new_trie_update_view_at_height(*shard_uid, state_root, block_hash, height);
         let view_state = ViewApplyState {
             shard_id: shard_uid.shard_id(),
             block_height: height,
@@ -1317,11 +1412,14 @@ impl node_runtime::adapter::ViewRuntimeAdapter for NightshadeRuntime {
         &self,
         shard_uid: &ShardUId,
         state_root: MerkleHash,
+        height: BlockHeight,
+        block_hash: &CryptoHash,
         account_id: &AccountId,
         prefix: &[u8],
         include_proof: bool,
     ) -> Result<ViewStateResult, node_runtime::state_viewer::errors::ViewStateError> {
-        let state_update = self.tries.new_trie_update_view(*shard_uid, state_root);
+        let state_update =
+            self.new_trie_update_view_at_height(*shard_uid, state_root, block_hash, height);
         self.trie_viewer.view_state(&state_update, account_id, prefix, include_proof)
     }
 }
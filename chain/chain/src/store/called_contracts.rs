@@ -0,0 +1,148 @@
+//! This module keeps track of the contracts called on each shard during the current
+//! and the previous epoch. The node uses it to compile these contracts ahead of time
+//! when it restarts or when a new epoch starts (possibly with a new VM configuration),
+//! so that the first calls do not pay for the compilation.
+
+use std::collections::{BTreeSet, HashSet};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_chain_primitives::error::Error;
+use near_primitives::shard_layout::ShardUId;
+use near_primitives::stateless_validation::contract_distribution::CodeHash;
+use near_primitives::types::EpochId;
+use near_store::DBCol;
+use near_store::db::CALLED_CONTRACTS_KEY;
+
+use crate::ChainStoreAccess;
+
+use super::{ChainStore, ChainStoreUpdate};
+
+/// Maximum number of contracts recorded per shard and epoch.
+const MAX_CALLED_CONTRACTS_PER_EPOCH: usize = 10_000;
+
+/// Contracts called on a shard, stored in `DBCol::Misc`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct CalledContracts {
+    /// Epoch of the latest chunk recorded.
+    pub epoch_id: EpochId,
+    /// Contracts called in `epoch_id`.
+    pub current_epoch: BTreeSet<CodeHash>,
+    /// Contracts called in the epoch before `epoch_id`.
+    pub previous_epoch: BTreeSet<CodeHash>,
+}
+
+impl CalledContracts {
+    /// Records the contracts called by a chunk applied in `epoch_id`.
+    ///
+    /// Returns whether the record has changed, and so needs to be saved.
+    fn record(&mut self, epoch_id: &EpochId, called: &HashSet<CodeHash>) -> bool {
+        let mut changed = false;
+        if &self.epoch_id != epoch_id {
+            self.epoch_id = *epoch_id;
+            self.previous_epoch = std::mem::take(&mut self.current_epoch);
+            changed = true;
+        }
+        for code_hash in called {
+            if self.current_epoch.len() >= MAX_CALLED_CONTRACTS_PER_EPOCH {
+                break;
+            }
+            changed |= self.current_epoch.insert(code_hash.clone());
+        }
+        changed
+    }
+
+    /// Contracts called in either the current or the previous epoch.
+    pub fn all(&self) -> Vec<CodeHash> {
+        self.current_epoch.union(&self.previous_epoch).cloned().collect()
+    }
+}
+
+fn called_contracts_key(shard_uid: &ShardUId) -> Vec<u8> {
+    [CALLED_CONTRACTS_KEY, &shard_uid.to_bytes()].concat()
+}
+
+impl ChainStore {
+    pub fn get_called_contracts(
+        &self,
+        shard_uid: &ShardUId,
+    ) -> Result<Option<CalledContracts>, Error> {
+        Ok(self.store().get_ser(DBCol::Misc, &called_contracts_key(shard_uid))?)
+    }
+}
+
+impl<'a> ChainStoreUpdate<'a> {
+    /// Records the contracts called by a chunk of `shard_uid` applied in `epoch_id`.
+    ///
+    /// The record is kept in memory, and only saved to the database when the first chunk of
+    /// a new epoch is recorded. The contracts called in the current epoch before a restart
+    /// are therefore not remembered, which is fine as the record is only used to warm up the
+    /// compiled contract cache.
+    ///
+    /// If this is the first chunk of the shard recorded in `epoch_id`, returns the contracts
+    /// called in the previous epoch.
+    pub fn save_called_contracts(
+        &mut self,
+        epoch_id: &EpochId,
+        shard_uid: &ShardUId,
+        called: &HashSet<CodeHash>,
+    ) -> Result<Option<Vec<CodeHash>>, Error> {
+        let mut called_contracts = match self.chain_store.called_contracts.remove(shard_uid) {
+            Some(called_contracts) => called_contracts,
+            None => self.chain_store.get_called_contracts(shard_uid)?.unwrap_or_default(),
+        };
+        let is_new_epoch = called_contracts.epoch_id != *epoch_id;
+        called_contracts.record(epoch_id, called);
+        let previous_epoch =
+            is_new_epoch.then(|| called_contracts.previous_epoch.iter().cloned().collect());
+        if is_new_epoch {
+            let mut store_update = self.store().store_update();
+            store_update.set_ser(
+                DBCol::Misc,
+                &called_contracts_key(shard_uid),
+                &called_contracts,
+            )?;
+            self.merge(store_update);
+        }
+        self.chain_store.called_contracts.insert(*shard_uid, called_contracts);
+        Ok(previous_epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use near_primitives::hash::{CryptoHash, hash};
+    use near_primitives::stateless_validation::contract_distribution::CodeHash;
+    use near_primitives::types::EpochId;
+
+    use super::CalledContracts;
+
+    #[test]
+    fn test_record_called_contracts() {
+        let code_hash = |i: u8| CodeHash(hash(&[i]));
+        let epoch1 = EpochId(CryptoHash::hash_bytes(b"epoch1"));
+        let epoch2 = EpochId(CryptoHash::hash_bytes(b"epoch2"));
+        let mut called_contracts = CalledContracts::default();
+
+        assert!(called_contracts.record(&epoch1, &HashSet::from([code_hash(0), code_hash(1)])));
+        assert!(!called_contracts.record(&epoch1, &HashSet::from([code_hash(1)])));
+        assert!(!called_contracts.record(&epoch1, &HashSet::new()));
+        assert!(called_contracts.record(&epoch1, &HashSet::from([code_hash(2)])));
+        assert_eq!(
+            called_contracts.all().into_iter().collect::<BTreeSet<_>>(),
+            BTreeSet::from([code_hash(0), code_hash(1), code_hash(2)])
+        );
+
+        // The contracts of the first epoch are kept for one more epoch.
+        assert!(called_contracts.record(&epoch2, &HashSet::from([code_hash(3)])));
+        assert_eq!(called_contracts.previous_epoch.len(), 3);
+        assert_eq!(called_contracts.current_epoch.len(), 1);
+        assert_eq!(called_contracts.all().len(), 4);
+
+        // Only the last two epochs are kept.
+        assert!(called_contracts.record(&epoch1, &HashSet::new()));
+        assert_eq!(called_contracts.previous_epoch, BTreeSet::from([code_hash(3)]));
+        assert!(called_contracts.current_epoch.is_empty());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::types::{Block, BlockHeader, LatestKnown};
 use borsh::{BorshDeserialize, BorshSerialize};
+pub use called_contracts::CalledContracts;
 use chrono::Utc;
 pub use latest_witnesses::LatestWitnessesInfo;
 pub use merkle_proof::MerkleProofAccess;
@@ -7,6 +8,9 @@ use near_chain_primitives::error::Error;
 use near_epoch_manager::EpochManagerAdapter;
 use near_primitives::block::Tip;
 use near_primitives::chunk_apply_stats::{ChunkApplyStats, ChunkApplyStatsV0};
+use near_primitives::contract_event::{
+    ContractEventKey, StoredContractEvent, parse_contract_events,
+};
 use near_primitives::errors::{EpochError, InvalidTxError};
 use near_primitives::hash::CryptoHash;
 use near_primitives::merkle::{MerklePath, PartialMerkleTree};
@@ -15,7 +19,7 @@ use near_primitives::shard_layout::{ShardLayout, ShardUId, get_block_shard_uid};
 use near_primitives::sharding::{
     ChunkHash, EncodedShardChunk, PartialEncodedChunk, ReceiptProof, ShardChunk, StateSyncInfo,
 };
-use near_primitives::state_sync::StateSyncDumpProgress;
+use near_primitives::state_sync::{StateSyncDumpProgress, StateSyncDumpedParts};
 use near_primitives::stateless_validation::contract_distribution::ContractUpdates;
 use near_primitives::stateless_validation::stored_chunk_state_transition_data::{
     StoredChunkStateTransitionData, StoredChunkStateTransitionDataV1,
@@ -38,7 +42,7 @@ use near_primitives::version::{ProtocolFeature, ProtocolVersion};
 use near_primitives::views::LightClientBlockView;
 use near_store::adapter::chain_store::ChainStoreAdapter;
 use near_store::adapter::{StoreAdapter, StoreUpdateAdapter};
-use near_store::db::{STATE_SYNC_DUMP_KEY, StoreStatistics};
+use near_store::db::{STATE_SYNC_DUMP_KEY, STATE_SYNC_DUMPED_PARTS_KEY, StoreStatistics};
 use near_store::{
     CHUNK_TAIL_KEY, DBCol, FINAL_HEAD_KEY, FORK_TAIL_KEY, HEAD_KEY, HEADER_HEAD_KEY,
     KeyForStateChanges, LARGEST_TARGET_HEIGHT_KEY, LATEST_KNOWN_KEY, PartialStorage, Store,
@@ -51,6 +55,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 use utils::check_transaction_validity_period;
 
+mod called_contracts;
 mod latest_witnesses;
 mod merkle_proof;
 pub mod utils;
@@ -269,8 +274,13 @@ pub struct ChainStore {
     /// - archive is true, cold_store is configured and migration to split_storage is finished - node
     /// working in split storage mode needs trie changes in order to do garbage collection on hot.
     save_trie_changes: bool,
+    /// Whether NEP-297 events in the logs of the outcomes are indexed in
+    /// `DBCol::ContractEvents`.
+    index_contract_events: bool,
     /// The maximum number of blocks for which a transaction is valid since its creation.
     pub(super) transaction_validity_period: BlockHeightDelta,
+    /// Contracts called on each shard, see `ChainStoreUpdate::save_called_contracts`.
+    called_contracts: HashMap<ShardUId, CalledContracts>,
 }
 
 impl Deref for ChainStore {
@@ -302,10 +312,16 @@ impl ChainStore {
             store: store.chain_store(),
             latest_known: std::cell::Cell::new(None),
             save_trie_changes,
+            index_contract_events: false,
             transaction_validity_period,
+            called_contracts: HashMap::new(),
         }
     }
 
+    pub fn set_index_contract_events(&mut self, index_contract_events: bool) {
+        self.index_contract_events = index_contract_events;
+    }
+
     pub fn store_update(&mut self) -> ChainStoreUpdate<'_> {
         ChainStoreUpdate::new(self)
     }
@@ -801,6 +817,39 @@ impl ChainStore {
         store_update.commit().map_err(|err| err.into())
     }
 
+    fn state_sync_dumped_parts_key(shard_id: ShardId) -> Vec<u8> {
+        let mut key = STATE_SYNC_DUMPED_PARTS_KEY.to_vec();
+        key.extend(b":".to_vec());
+        key.extend(shard_id.to_le_bytes());
+        key
+    }
+
+    /// Retrieves the set of state parts already dumped for the given shard, if any.
+    pub fn get_state_sync_dumped_parts(
+        &self,
+        shard_id: ShardId,
+    ) -> Result<Option<StateSyncDumpedParts>, Error> {
+        Ok(self
+            .store
+            .store()
+            .get_ser(DBCol::BlockMisc, &ChainStore::state_sync_dumped_parts_key(shard_id))?)
+    }
+
+    /// Updates the set of state parts already dumped for the given shard.
+    pub fn set_state_sync_dumped_parts(
+        &self,
+        shard_id: ShardId,
+        value: Option<&StateSyncDumpedParts>,
+    ) -> Result<(), Error> {
+        let mut store_update = self.store.store().store_update();
+        let key = ChainStore::state_sync_dumped_parts_key(shard_id);
+        match value {
+            None => store_update.delete(DBCol::BlockMisc, &key),
+            Some(value) => store_update.set_ser(DBCol::BlockMisc, &key, value)?,
+        }
+        store_update.commit().map_err(|err| err.into())
+    }
+
     pub fn prev_block_is_caught_up(
         chain_store: &ChainStoreAdapter,
         prev_prev_hash: &CryptoHash,
@@ -1047,6 +1096,7 @@ pub struct ChainStoreUpdate<'a> {
     add_state_sync_infos: Vec<StateSyncInfo>,
     remove_state_sync_infos: Vec<CryptoHash>,
     chunk_apply_stats: HashMap<(CryptoHash, ShardId), ChunkApplyStats>,
+    contract_events: HashMap<(CryptoHash, ShardId), Vec<(ContractEventKey, StoredContractEvent)>>,
 }
 
 impl<'a> ChainStoreUpdate<'a> {
@@ -1070,6 +1120,7 @@ impl<'a> ChainStoreUpdate<'a> {
             add_state_sync_infos: vec![],
             remove_state_sync_infos: vec![],
             chunk_apply_stats: HashMap::default(),
+            contract_events: HashMap::default(),
         }
     }
 }
@@ -1593,19 +1644,40 @@ impl<'a> ChainStoreUpdate<'a> {
     pub fn save_outcomes_with_proofs(
         &mut self,
         block_hash: &CryptoHash,
+        block_height: BlockHeight,
         shard_id: ShardId,
         outcomes: Vec<ExecutionOutcomeWithId>,
         proofs: Vec<MerklePath>,
     ) {
         let mut outcome_ids = Vec::with_capacity(outcomes.len());
+        let mut contract_events = vec![];
         for (outcome_with_id, proof) in outcomes.into_iter().zip(proofs.into_iter()) {
             outcome_ids.push(outcome_with_id.id);
+            if self.chain_store.index_contract_events {
+                let outcome = &outcome_with_id.outcome;
+                for (log_index, event) in parse_contract_events(&outcome.logs) {
+                    let key = ContractEventKey {
+                        standard: event.standard,
+                        event: event.event,
+                        account_id: outcome.executor_id.clone(),
+                        block_height,
+                        block_hash: *block_hash,
+                        receipt_id: outcome_with_id.id,
+                        log_index,
+                    };
+                    let value = StoredContractEvent { version: event.version, data: event.data };
+                    contract_events.push((key, value));
+                }
+            }
             self.chain_store_cache_update.outcomes.insert(
                 (outcome_with_id.id, *block_hash),
                 ExecutionOutcomeWithProof { outcome: outcome_with_id.outcome, proof },
             );
         }
         self.chain_store_cache_update.outcome_ids.insert((*block_hash, shard_id), outcome_ids);
+        if !contract_events.is_empty() {
+            self.contract_events.insert((*block_hash, shard_id), contract_events);
+        }
     }
 
     pub fn save_trie_changes(&mut self, block_hash: CryptoHash, trie_changes: WrappedTrieChanges) {
@@ -1947,6 +2019,19 @@ impl<'a> ChainStoreUpdate<'a> {
                     &ids,
                 )?;
             }
+            for ((block_hash, shard_id), events) in &self.contract_events {
+                let mut keys = Vec::with_capacity(events.len());
+                for (key, event) in events {
+                    let key = key.to_bytes();
+                    store_update.set_ser(DBCol::ContractEvents, &key, event)?;
+                    keys.push(key);
+                }
+                store_update.set_ser(
+                    DBCol::ContractEventKeys,
+                    &get_block_shard_id(block_hash, *shard_id),
+                    &keys,
+                )?;
+            }
         }
 
         for (block_hash, refcount) in &self.chain_store_cache_update.block_refcounts {
@@ -2103,8 +2188,11 @@ mod tests {
 
     use crate::test_utils::get_chain;
     use near_primitives::errors::InvalidTxError;
+    use near_primitives::hash::CryptoHash;
     use near_primitives::test_utils::TestBlockBuilder;
     use near_primitives::test_utils::create_test_signer;
+    use near_primitives::transaction::{ExecutionOutcome, ExecutionOutcomeWithId};
+    use near_primitives::types::{AccountId, ShardId};
 
     #[test]
     fn test_tx_validity_long_fork() {
@@ -2240,4 +2328,91 @@ mod tests {
             Err(InvalidTxError::Expired)
         );
     }
+
+    #[test]
+    fn test_contract_events() {
+        let mut chain = get_chain(Clock::real());
+        chain.mut_chain_store().set_index_contract_events(true);
+        let genesis = chain.get_block_by_height(0).unwrap();
+        let block =
+            TestBlockBuilder::new(Clock::real(), &genesis, Arc::new(create_test_signer("test1")))
+                .height(1)
+                .build();
+        let fork =
+            TestBlockBuilder::new(Clock::real(), &genesis, Arc::new(create_test_signer("test2")))
+                .height(1)
+                .build();
+        let account_id: AccountId = "token.near".parse().unwrap();
+        let outcome = |id: &[u8]| {
+            ExecutionOutcomeWithId {
+            id: CryptoHash::hash_bytes(id),
+            outcome: ExecutionOutcome {
+                logs: vec![
+                    "transfer".to_string(),
+                    r#"EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"ft_mint","data":[]}"#.to_string(),
+                    r#"EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"ft_burn"}"#.to_string(),
+                ],
+                executor_id: account_id.clone(),
+                ..Default::default()
+            },
+        }
+        };
+        let mut store_update = chain.mut_chain_store().store_update();
+        for (block, id) in [(&block, &b"receipt"[..]), (&fork, &b"fork"[..])] {
+            store_update.save_block_header(block.header().clone()).unwrap();
+            store_update.save_outcomes_with_proofs(
+                block.hash(),
+                1,
+                ShardId::new(0),
+                vec![outcome(id)],
+                vec![vec![]],
+            );
+        }
+        store_update.update_height(1, *block.hash()).unwrap();
+        store_update.commit().unwrap();
+
+        // Events of the fork are not returned.
+        let store = chain.chain_store();
+        let (events, next_cursor) =
+            store.get_contract_events(&account_id, "nep141", None, 0, 1, None, 10).unwrap();
+        assert_eq!(
+            events.iter().map(|(key, _)| (key.event.as_str(), key.log_index)).collect::<Vec<_>>(),
+            [("ft_burn", 2), ("ft_mint", 1)]
+        );
+        assert!(events.iter().all(|(key, _)| key.block_hash == *block.hash()));
+        assert_eq!(next_cursor, None);
+
+        let (events, _) = store
+            .get_contract_events(&account_id, "nep141", Some("ft_mint"), 0, 1, None, 10)
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].1.data.as_deref(), Some("[]"));
+
+        // Paging through the events one by one returns all of them, including
+        // the ones of the same block.
+        let mut paged = vec![];
+        let mut cursor = None;
+        loop {
+            let (events, next_cursor) = store
+                .get_contract_events(&account_id, "nep141", None, 0, 1, cursor.as_ref(), 1)
+                .unwrap();
+            assert!(events.len() <= 1);
+            paged.extend(events.into_iter().map(|(key, _)| (key.event, key.log_index)));
+            match next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+        assert_eq!(paged, [("ft_burn".to_string(), 2), ("ft_mint".to_string(), 1)]);
+
+        for (from, to, limit) in [(0, 1, 0), (2, 5, 10), (1, 0, 10)] {
+            let (events, next_cursor) = store
+                .get_contract_events(&account_id, "nep141", None, from, to, None, limit)
+                .unwrap();
+            assert!(events.is_empty() && next_cursor.is_none());
+        }
+        let (events, _) =
+            store.get_contract_events(&account_id, "nep171", None, 0, 1, None, 10).unwrap();
+        assert!(events.is_empty());
+    }
 }
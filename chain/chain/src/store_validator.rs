@@ -341,6 +341,12 @@ impl StoreValidator {
     }
 
     pub fn validate(&mut self) {
+        self.validate_with_progress(&mut |_| {})
+    }
+
+    /// Same as `validate()`, but calls `on_column` before validating each column,
+    /// so that long running validations can report their progress.
+    pub fn validate_with_progress(&mut self, on_column: &mut dyn FnMut(DBCol)) {
         self.start_time = Clock::real().now();
 
         // Init checks
@@ -351,6 +357,7 @@ impl StoreValidator {
 
         // Main loop
         for col in DBCol::iter() {
+            on_column(col);
             if let Err(e) = self.validate_col(col) {
                 self.process_error(e, col.to_string(), col)
             }
@@ -30,6 +30,7 @@ use near_primitives::receipt::{ActionReceipt, Receipt, ReceiptEnum, ReceiptV0};
 use near_primitives::shard_layout::{ShardLayout, ShardUId};
 use near_primitives::state_part::PartId;
 use near_primitives::stateless_validation::ChunkProductionKey;
+use near_primitives::stateless_validation::contract_distribution::CodeHash;
 use near_primitives::stateless_validation::validator_assignment::ChunkValidatorAssignments;
 use near_primitives::transaction::{
     Action, ExecutionMetadata, ExecutionOutcome, ExecutionOutcomeWithId, ExecutionStatus,
@@ -1396,4 +1397,24 @@ impl RuntimeAdapter for KeyValueRuntime {
         // Note that KeyValueRuntime does not use compiled contract cache, so this is no-op.
         Ok(())
     }
+
+    fn compile_contracts_in_background(
+        &self,
+        _epoch_id: &EpochId,
+        _shard_uid: ShardUId,
+        _state_root: StateRoot,
+        _code_hashes: Vec<CodeHash>,
+    ) -> Result<(), Error> {
+        // Note that KeyValueRuntime does not use compiled contract cache, so this is no-op.
+        Ok(())
+    }
+
+    fn compile_deployed_contracts_in_background(
+        &self,
+        _epoch_id: &EpochId,
+        _contract_codes: Vec<ContractCode>,
+    ) -> Result<(), Error> {
+        // Note that KeyValueRuntime does not use compiled contract cache, so this is no-op.
+        Ok(())
+    }
 }
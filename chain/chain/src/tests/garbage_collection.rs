@@ -1,6 +1,7 @@
-use near_async::time::Clock;
+use near_async::time::{Clock, Duration, FakeClock};
 use near_store::trie::AccessOptions;
 use rand::Rng;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use crate::chain::Chain;
@@ -12,7 +13,9 @@ use crate::test_utils::{
 use crate::types::Tip;
 use crate::{ChainStoreAccess, StoreValidator};
 
-use near_chain_configs::{DEFAULT_GC_NUM_EPOCHS_TO_KEEP, GCConfig, GenesisConfig};
+use near_chain_configs::{
+    DEFAULT_GC_NUM_EPOCHS_TO_KEEP, GCConfig, GCDataGroup, GCRetention, GenesisConfig,
+};
 use near_epoch_manager::EpochManagerAdapter;
 use near_primitives::block::Block;
 use near_primitives::epoch_block_info::BlockInfo;
@@ -22,7 +25,7 @@ use near_primitives::test_utils::{TestBlockBuilder, create_test_signer};
 use near_primitives::types::{BlockHeight, NumBlocks, StateRoot};
 use near_primitives::validator_signer::ValidatorSigner;
 use near_store::test_utils::gen_changes;
-use near_store::{DBCol, ShardTries, Trie, WrappedTrieChanges};
+use near_store::{DBCol, KeyForStateChanges, ShardTries, Trie, WrappedTrieChanges};
 
 // Build a chain of num_blocks on top of prev_block
 fn do_fork(
@@ -721,6 +724,174 @@ fn test_clear_old_data() {
     }
 }
 
+/// Test that the data of the groups with a retention policy outlives its
+/// blocks, and is garbage collected once the policy no longer retains it.
+#[test]
+fn test_clear_old_data_with_retention() {
+    let max_height = 14usize;
+    let mut chain = get_chain_with_epoch_length(Clock::real(), 1);
+    let epoch_manager = chain.epoch_manager.clone();
+    let genesis = chain.get_block_by_height(0).unwrap();
+    let signer = Arc::new(create_test_signer("test1"));
+    let mut prev_block = genesis;
+    let mut blocks = vec![prev_block.clone()];
+    for i in 1..=max_height {
+        add_block(
+            &mut chain,
+            epoch_manager.as_ref(),
+            &mut prev_block,
+            &mut blocks,
+            signer.clone(),
+            i as BlockHeight,
+        );
+    }
+    let state_changes_key =
+        |block: &Block| [KeyForStateChanges::for_block(block.hash()).as_ref(), b"key"].concat();
+    let store = chain.chain_store().store();
+    let mut store_update = store.store_update();
+    for block in &blocks {
+        store_update.set(DBCol::StateChanges, &state_changes_key(block), b"value");
+    }
+    store_update.commit().unwrap();
+
+    let retention =
+        HashMap::from([(GCDataGroup::StateChanges, GCRetention::Duration(Duration::hours(1)))]);
+    chain
+        .clear_data(
+            &GCConfig { gc_blocks_limit: 100, gc_retention: retention, ..GCConfig::default() },
+            None,
+        )
+        .unwrap();
+    for i in 0..=max_height {
+        let expected_removed = i < max_height - DEFAULT_GC_NUM_EPOCHS_TO_KEEP as usize;
+        assert_eq!(chain.get_block(blocks[i].hash()).is_err(), expected_removed);
+        assert!(store.exists(DBCol::StateChanges, &state_changes_key(&blocks[i])).unwrap());
+    }
+    assert!(store.iter(DBCol::GCRetainedKeys).next().is_some());
+
+    // Without the retention policy the retained data is garbage collected.
+    chain.clear_data(&GCConfig { gc_blocks_limit: 100, ..GCConfig::default() }, None).unwrap();
+    for i in 0..=max_height {
+        let expected_removed = i < max_height - DEFAULT_GC_NUM_EPOCHS_TO_KEEP as usize;
+        assert_eq!(
+            store.exists(DBCol::StateChanges, &state_changes_key(&blocks[i])).unwrap(),
+            !expected_removed
+        );
+    }
+    assert!(store.iter(DBCol::GCRetainedKeys).next().is_none());
+}
+
+/// Test that the retained data is garbage collected once it's older than the
+/// retention, measured with the block timestamps.
+#[test]
+fn test_clear_retained_data_after_duration() {
+    let clock = FakeClock::default();
+    let mut chain = get_chain_with_epoch_length(clock.clock(), 1);
+    let epoch_manager = chain.epoch_manager.clone();
+    let signer = Arc::new(create_test_signer("test1"));
+    let mut prev_block = chain.get_block_by_height(0).unwrap();
+    let mut blocks = vec![prev_block.clone()];
+    let mut add_blocks = |chain: &mut Chain, heights: std::ops::RangeInclusive<BlockHeight>| {
+        for height in heights {
+            // Block `height` is produced `height` minutes after genesis.
+            clock.advance(Duration::minutes(1));
+            add_block_with_clock(
+                clock.clock(),
+                chain,
+                epoch_manager.as_ref(),
+                &mut prev_block,
+                &mut blocks,
+                signer.clone(),
+                height,
+            );
+            let mut store_update = chain.chain_store().store().store_update();
+            let key = retained_state_changes_key(blocks.last().unwrap());
+            store_update.set(DBCol::StateChanges, &key, b"value");
+            store_update.commit().unwrap();
+        }
+        blocks.clone()
+    };
+    let retention =
+        HashMap::from([(GCDataGroup::StateChanges, GCRetention::Duration(Duration::minutes(8)))]);
+    let gc_config =
+        GCConfig { gc_blocks_limit: 100, gc_retention: retention, ..GCConfig::default() };
+
+    // The state changes of the blocks produced less than 8 minutes before the
+    // head are retained: with the head at 14, these of the blocks 7 and 8.
+    let blocks = add_blocks(&mut chain, 1..=14);
+    chain.clear_data(&gc_config, None).unwrap();
+    let store = chain.chain_store().store();
+    for (height, block) in blocks.iter().enumerate().skip(1) {
+        let removed = height < 14 - DEFAULT_GC_NUM_EPOCHS_TO_KEEP as usize;
+        assert_eq!(chain.get_block(block.hash()).is_err(), removed);
+        let exists = store.exists(DBCol::StateChanges, &retained_state_changes_key(block)).unwrap();
+        assert_eq!(exists, !removed || height > 6, "height {height}");
+    }
+    assert_eq!(store.iter(DBCol::GCRetainedKeys).count(), 2);
+
+    // With the head at 20, the retention of the blocks 7 and 8 expired while
+    // the data of the newly garbage collected blocks 13 and 14 is retained.
+    let blocks = add_blocks(&mut chain, 15..=20);
+    chain.clear_data(&gc_config, None).unwrap();
+    for (height, block) in blocks.iter().enumerate().skip(1) {
+        let removed = height < 20 - DEFAULT_GC_NUM_EPOCHS_TO_KEEP as usize;
+        let exists = store.exists(DBCol::StateChanges, &retained_state_changes_key(block)).unwrap();
+        assert_eq!(exists, !removed || height > 12, "height {height}");
+    }
+    assert_eq!(store.iter(DBCol::GCRetainedKeys).count(), 2);
+}
+
+/// Test that the retained data is garbage collected once its block is older
+/// than the retained number of epochs.
+#[test]
+fn test_clear_retained_data_after_epochs() {
+    let mut chain = get_chain_with_epoch_length(Clock::real(), 1);
+    let epoch_manager = chain.epoch_manager.clone();
+    let signer = Arc::new(create_test_signer("test1"));
+    let mut prev_block = chain.get_block_by_height(0).unwrap();
+    let mut blocks = vec![prev_block.clone()];
+    let retention = HashMap::from([(GCDataGroup::StateChanges, GCRetention::Epochs(8))]);
+    let gc_config =
+        GCConfig { gc_blocks_limit: 100, gc_retention: retention, ..GCConfig::default() };
+
+    let mut retained = HashSet::new();
+    for height in 1..=30 {
+        add_block(
+            &mut chain,
+            epoch_manager.as_ref(),
+            &mut prev_block,
+            &mut blocks,
+            signer.clone(),
+            height,
+        );
+        let mut store_update = chain.chain_store().store().store_update();
+        store_update.set(DBCol::StateChanges, &retained_state_changes_key(&prev_block), b"value");
+        store_update.commit().unwrap();
+        chain.clear_data(&gc_config, None).unwrap();
+
+        // Every garbage collected block has its state changes retained for a
+        // while, and removed once 8 epochs passed.
+        let store = chain.chain_store().store();
+        for (block_height, block) in blocks.iter().enumerate().skip(1) {
+            let key = retained_state_changes_key(block);
+            if chain.get_block(block.hash()).is_err()
+                && store.exists(DBCol::StateChanges, &key).unwrap()
+            {
+                retained.insert(block_height);
+            }
+            if height - block_height as BlockHeight > 8 + DEFAULT_GC_NUM_EPOCHS_TO_KEEP {
+                assert!(!store.exists(DBCol::StateChanges, &key).unwrap(), "height {block_height}");
+            }
+        }
+    }
+    assert!(retained.len() > 10, "{retained:?}");
+    assert!(chain.chain_store().store().iter(DBCol::GCRetainedKeys).count() <= 8);
+}
+
+fn retained_state_changes_key(block: &Block) -> Vec<u8> {
+    [KeyForStateChanges::for_block(block.hash()).as_ref(), b"key"].concat()
+}
+
 // Adds block to the chain at given height after prev_block.
 fn add_block(
     chain: &mut Chain,
@@ -729,6 +900,20 @@ fn add_block(
     blocks: &mut Vec<Block>,
     signer: Arc<ValidatorSigner>,
     height: u64,
+) {
+    add_block_with_clock(Clock::real(), chain, epoch_manager, prev_block, blocks, signer, height)
+}
+
+// Adds block to the chain at given height after prev_block, with the timestamp
+// taken from `clock`.
+fn add_block_with_clock(
+    clock: Clock,
+    chain: &mut Chain,
+    epoch_manager: &dyn EpochManagerAdapter,
+    prev_block: &mut Block,
+    blocks: &mut Vec<Block>,
+    signer: Arc<ValidatorSigner>,
+    height: u64,
 ) {
     let next_epoch_id = epoch_manager
         .get_next_epoch_id_from_prev_block(prev_block.hash())
@@ -736,11 +921,11 @@ fn add_block(
     let mut store_update = chain.mut_chain_store().store_update();
 
     let block = if next_epoch_id == *prev_block.header().next_epoch_id() {
-        TestBlockBuilder::new(Clock::real(), &prev_block, signer).height(height).build()
+        TestBlockBuilder::new(clock, &prev_block, signer).height(height).build()
     } else {
         let epoch_id = *prev_block.header().next_epoch_id();
         let next_bp_hash = Chain::compute_bp_hash(epoch_manager, next_epoch_id).unwrap();
-        TestBlockBuilder::new(Clock::real(), &prev_block, signer)
+        TestBlockBuilder::new(clock, &prev_block, signer)
             .height(height)
             .epoch_id(epoch_id)
             .next_epoch_id(next_epoch_id)
@@ -24,7 +24,7 @@ use near_primitives::sandbox::state_patch::SandboxStatePatch;
 use near_primitives::shard_layout::ShardLayout;
 use near_primitives::shard_layout::ShardUId;
 use near_primitives::state_part::PartId;
-use near_primitives::stateless_validation::contract_distribution::ContractUpdates;
+use near_primitives::stateless_validation::contract_distribution::{CodeHash, ContractUpdates};
 use near_primitives::transaction::ValidatedTransaction;
 use near_primitives::transaction::{ExecutionOutcomeWithId, SignedTransaction};
 use near_primitives::types::validator_stake::{ValidatorStake, ValidatorStakeIter};
@@ -203,6 +203,8 @@ pub struct ChainConfig {
     pub background_migration_threads: usize,
     /// The resharding configuration.
     pub resharding_config: MutableConfigValue<ReshardingConfig>,
+    /// Whether to index NEP-297 events emitted by contracts.
+    pub index_contract_events: bool,
 }
 
 impl ChainConfig {
@@ -214,6 +216,7 @@ impl ChainConfig {
                 ReshardingConfig::default(),
                 "resharding_config",
             ),
+            index_contract_events: false,
         }
     }
 }
@@ -521,6 +524,26 @@ pub trait RuntimeAdapter: Send + Sync {
         epoch_id: &EpochId,
         contract_codes: Vec<ContractCode>,
     ) -> Result<(), Error>;
+
+    /// Queues the compilation of the given contracts of the shard state in the background,
+    /// so that they are in the compiled contract cache before they are called.
+    ///
+    /// The contracts are compiled for the VM configuration of `epoch_id`. Returns immediately.
+    fn compile_contracts_in_background(
+        &self,
+        epoch_id: &EpochId,
+        shard_uid: ShardUId,
+        state_root: StateRoot,
+        code_hashes: Vec<CodeHash>,
+    ) -> Result<(), Error>;
+
+    /// Queues the compilation of newly deployed contracts in the background, for the VM
+    /// configuration of `epoch_id`. Returns immediately.
+    fn compile_deployed_contracts_in_background(
+        &self,
+        epoch_id: &EpochId,
+        contract_codes: Vec<ContractCode>,
+    ) -> Result<(), Error>;
 }
 
 /// The last known / checked height and time when we have processed it.
@@ -5,7 +5,7 @@ use near_primitives::congestion_info::CongestionInfo;
 use near_primitives::types::{EpochId, ShardId};
 use near_primitives::views::{
     CatchupStatusView, ChainProcessingInfo, EpochValidatorInfo, RequestedStatePartsView,
-    SyncStatusView,
+    StateSyncDumpStatusView, SyncStatusView,
 };
 use near_primitives::{
     block_header::ApprovalInner,
@@ -267,6 +267,8 @@ pub enum DebugStatus {
     ChainProcessingStatus,
     // The state parts already requested.
     RequestedStateParts,
+    // Progress of the state sync dump of each shard.
+    StateSyncDumpStatus,
 }
 
 impl actix::Message for DebugStatus {
@@ -288,4 +290,6 @@ pub enum DebugStatusResponse {
     ChainProcessingStatus(ChainProcessingInfo),
     // The state parts already requested.
     RequestedStateParts(Vec<RequestedStatePartsView>),
+    // Progress of the state sync dump of each shard.
+    StateSyncDumpStatus(Vec<StateSyncDumpStatusView>),
 }
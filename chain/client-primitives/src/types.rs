@@ -1,5 +1,6 @@
 use actix::Message;
 use near_chain_configs::{ClientConfig, ProtocolConfigView};
+use near_primitives::contract_event::ContractEventCursor;
 use near_primitives::hash::CryptoHash;
 use near_primitives::merkle::{MerklePath, PartialMerkleTree};
 use near_primitives::network::PeerId;
@@ -10,9 +11,9 @@ use near_primitives::types::{
 };
 use near_primitives::views::validator_stake_view::ValidatorStakeView;
 use near_primitives::views::{
-    BlockView, ChunkView, EpochValidatorInfo, ExecutionOutcomeWithIdView, GasPriceView,
-    LightClientBlockLiteView, LightClientBlockView, MaintenanceWindowsView, QueryRequest,
-    QueryResponse, ReceiptView, SplitStorageInfoView, StateChangesKindsView,
+    BlockView, ChunkView, ContractEventsView, EpochValidatorInfo, ExecutionOutcomeWithIdView,
+    GasPriceView, LightClientBlockLiteView, LightClientBlockView, MaintenanceWindowsView,
+    QueryRequest, QueryResponse, ReceiptView, SplitStorageInfoView, StateChangesKindsView,
     StateChangesRequestView, StateChangesView, StateSyncStatusView, SyncStatusView, TxStatusView,
 };
 pub use near_primitives::views::{StatusResponse, StatusSyncInfo};
@@ -984,6 +985,47 @@ impl From<near_chain_primitives::Error> for GetMaintenanceWindowsError {
     }
 }
 
+/// Gets the NEP-297 events of a standard emitted by a contract in a range of
+/// block heights.
+#[derive(Debug)]
+pub struct GetContractEvents {
+    pub account_id: AccountId,
+    pub standard: String,
+    /// Only events with this name are returned if set.
+    pub event: Option<String>,
+    pub from_block_height: BlockHeight,
+    /// The height of the head is used if not set.
+    pub to_block_height: Option<BlockHeight>,
+    /// Only the events after this position are returned if set.
+    pub cursor: Option<ContractEventCursor>,
+    pub limit: usize,
+}
+
+impl Message for GetContractEvents {
+    type Result = Result<ContractEventsView, GetContractEventsError>;
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum GetContractEventsError {
+    #[error("IO Error: {0}")]
+    IOError(String),
+    #[error("Contract events are not indexed by this node")]
+    NotIndexed,
+    #[error(
+        "It is a bug if you receive this error type, please, report this incident: https://github.com/near/nearcore/issues/new/choose. Details: {0}"
+    )]
+    Unreachable(String),
+}
+
+impl From<near_chain_primitives::Error> for GetContractEventsError {
+    fn from(error: near_chain_primitives::Error) -> Self {
+        match error {
+            near_chain_primitives::Error::IOErr(error) => Self::IOError(error.to_string()),
+            _ => Self::Unreachable(error.to_string()),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct GetClientConfig {}
 
@@ -233,6 +233,7 @@ impl Client {
             save_trie_changes: config.save_trie_changes,
             background_migration_threads: config.client_background_migration_threads,
             resharding_config: config.resharding_config.clone(),
+            index_contract_events: config.index_contract_events,
         };
         let chain = Chain::new(
             clock.clone(),
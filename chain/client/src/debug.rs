@@ -45,7 +45,8 @@ use near_client_primitives::debug::{DebugBlockStatus, DebugChunkStatus};
 use near_network::types::{ConnectedPeerInfo, NetworkInfo, PeerType};
 use near_primitives::sharding::ChunkHash;
 use near_primitives::views::{
-    AccountDataView, KnownProducerView, NetworkInfoView, PeerInfoView, Tier1ProxyView,
+    AccountDataView, KnownProducerView, NetworkInfoView, PeerInfoView, StateSyncDumpStatusView,
+    Tier1ProxyView,
 };
 
 // Maximum number of blocks to search for the first block to display.
@@ -199,6 +200,9 @@ impl Handler<DebugStatus> for ClientActorInner {
             DebugStatus::ChainProcessingStatus => Ok(DebugStatusResponse::ChainProcessingStatus(
                 self.client.chain.get_chain_processing_info(),
             )),
+            DebugStatus::StateSyncDumpStatus => {
+                Ok(DebugStatusResponse::StateSyncDumpStatus(self.get_state_sync_dump_status()?))
+            }
         }
     }
 }
@@ -476,6 +480,30 @@ impl ClientActorInner {
         })
     }
 
+    /// Reads the progress of the state sync dump from the DB. The dump runs outside the client
+    /// actor, so this reflects what the dumper last persisted rather than its in-memory state.
+    fn get_state_sync_dump_status(
+        &self,
+    ) -> Result<Vec<StateSyncDumpStatusView>, near_chain_primitives::Error> {
+        let chain_store = self.client.chain.chain_store();
+        let mut result = vec![];
+        for item in chain_store.iter_state_sync_dump_progress() {
+            let (shard_id, (epoch_id, done)) = item?;
+            let dumped_parts = chain_store
+                .get_state_sync_dumped_parts(shard_id)?
+                .filter(|parts| parts.epoch_id == epoch_id);
+            result.push(StateSyncDumpStatusView {
+                shard_id,
+                epoch_id,
+                done,
+                num_parts: dumped_parts.as_ref().map(|parts| parts.num_parts),
+                num_parts_dumped: dumped_parts.as_ref().map(|parts| parts.num_dumped()),
+            });
+        }
+        result.sort_by_key(|status| status.shard_id);
+        Ok(result)
+    }
+
     fn get_tracked_shards_view(&self) -> Result<TrackedShardsView, near_chain_primitives::Error> {
         let epoch_id = self.client.chain.header_head()?.epoch_id;
         let fetch_hash = self.client.chain.header_head()?.last_block_hash;
@@ -948,6 +976,7 @@ fn new_peer_info_view(chain: &Chain, connected_peer_info: &ConnectedPeerInfo) ->
             .whole_milliseconds() as u64,
         is_outbound_peer: connected_peer_info.peer_type == PeerType::Outbound,
         nonce: connected_peer_info.nonce,
+        reputation_score: connected_peer_info.reputation_score,
     }
 }
 
@@ -317,11 +317,13 @@ impl HeaderSync {
         peer: &HighestHeightPeerInfo,
     ) -> Result<(), near_chain::Error> {
         let locator = self.get_locator(chain)?;
+        let tip_height = chain.chain_store().header_head()?.height;
         debug!(target: "sync", "Sync: request headers: asking {} for headers, {:?}", peer.peer_info.id, locator);
         self.network_adapter.send(PeerManagerMessageRequest::NetworkRequests(
             NetworkRequests::BlockHeadersRequest {
                 hashes: locator,
                 peer_id: peer.peer_info.id.clone(),
+                tip_height,
             },
         ));
         Ok(())
@@ -539,7 +541,8 @@ mod test {
                     .iter()
                     .map(|i| *chain.get_block_by_height(*i).unwrap().hash())
                     .collect(),
-                peer_id: peer1.peer_info.id
+                peer_id: peer1.peer_info.id,
+                tip_height: 6,
             }
         );
     }
@@ -644,7 +647,8 @@ mod test {
                     .iter()
                     .map(|i| *chain.get_block_by_height(*i).unwrap().hash())
                     .collect(),
-                peer_id: peer1.peer_info.id
+                peer_id: peer1.peer_info.id,
+                tip_height: 7005,
             }
         );
     }
@@ -874,7 +878,7 @@ mod test {
                 }
             };
             match message {
-                NetworkRequests::BlockHeadersRequest { hashes, peer_id } => {
+                NetworkRequests::BlockHeadersRequest { hashes, peer_id, .. } => {
                     assert_eq!(peer_id, peer1.peer_info.id);
                     let headers =
                         retrieve_headers(chain2.chain_store(), hashes, MAX_BLOCK_HEADERS, None)
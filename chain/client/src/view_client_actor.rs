@@ -19,13 +19,13 @@ use near_chain_configs::{ClientConfig, MutableValidatorSigner, ProtocolConfigVie
 use near_chain_primitives::error::EpochErrorResultToChainError;
 use near_client_primitives::types::{
     Error, GetBlock, GetBlockError, GetBlockProof, GetBlockProofError, GetBlockProofResponse,
-    GetBlockWithMerkleTree, GetChunkError, GetExecutionOutcome, GetExecutionOutcomeError,
-    GetExecutionOutcomesForBlock, GetGasPrice, GetGasPriceError, GetMaintenanceWindows,
-    GetMaintenanceWindowsError, GetNextLightClientBlockError, GetProtocolConfig,
-    GetProtocolConfigError, GetReceipt, GetReceiptError, GetSplitStorageInfo,
-    GetSplitStorageInfoError, GetStateChangesError, GetStateChangesWithCauseInBlock,
-    GetStateChangesWithCauseInBlockForTrackedShards, GetValidatorInfoError, Query, QueryError,
-    TxStatus, TxStatusError,
+    GetBlockWithMerkleTree, GetChunkError, GetContractEvents, GetContractEventsError,
+    GetExecutionOutcome, GetExecutionOutcomeError, GetExecutionOutcomesForBlock, GetGasPrice,
+    GetGasPriceError, GetMaintenanceWindows, GetMaintenanceWindowsError,
+    GetNextLightClientBlockError, GetProtocolConfig, GetProtocolConfigError, GetReceipt,
+    GetReceiptError, GetSplitStorageInfo, GetSplitStorageInfoError, GetStateChangesError,
+    GetStateChangesWithCauseInBlock, GetStateChangesWithCauseInBlockForTrackedShards,
+    GetValidatorInfoError, Query, QueryError, TxStatus, TxStatusError,
 };
 use near_epoch_manager::EpochManagerAdapter;
 use near_epoch_manager::shard_assignment::{account_id_to_shard_id, shard_id_to_uid};
@@ -58,11 +58,11 @@ use near_primitives::types::{
 use near_primitives::validator_signer::ValidatorSigner;
 use near_primitives::views::validator_stake_view::ValidatorStakeView;
 use near_primitives::views::{
-    BlockView, ChunkView, EpochValidatorInfo, ExecutionOutcomeWithIdView, ExecutionStatusView,
-    FinalExecutionOutcomeView, FinalExecutionOutcomeViewEnum, FinalExecutionStatus, GasPriceView,
-    LightClientBlockView, MaintenanceWindowsView, QueryRequest, QueryResponse, ReceiptView,
-    SignedTransactionView, SplitStorageInfoView, StateChangesKindsView, StateChangesView,
-    TxExecutionStatus, TxStatusView,
+    BlockView, ChunkView, ContractEventsView, EpochValidatorInfo, ExecutionOutcomeWithIdView,
+    ExecutionStatusView, FinalExecutionOutcomeView, FinalExecutionOutcomeViewEnum,
+    FinalExecutionStatus, GasPriceView, LightClientBlockView, MaintenanceWindowsView, QueryRequest,
+    QueryResponse, ReceiptView, SignedTransactionView, SplitStorageInfoView, StateChangesKindsView,
+    StateChangesView, TxExecutionStatus, TxStatusView,
 };
 use near_store::{COLD_HEAD_KEY, DBCol, FINAL_HEAD_KEY, HEAD_KEY};
 use parking_lot::{Mutex, RwLock};
@@ -1162,6 +1162,36 @@ impl Handler<GetReceipt> for ViewClientActorInner {
     }
 }
 
+impl Handler<GetContractEvents> for ViewClientActorInner {
+    #[perf]
+    fn handle(
+        &mut self,
+        msg: GetContractEvents,
+    ) -> Result<ContractEventsView, GetContractEventsError> {
+        tracing::debug!(target: "client", ?msg);
+        let _timer = metrics::VIEW_CLIENT_MESSAGE_TIME
+            .with_label_values(&["GetContractEvents"])
+            .start_timer();
+        if !self.config.index_contract_events {
+            return Err(GetContractEventsError::NotIndexed);
+        }
+        let to_block_height = match msg.to_block_height {
+            Some(height) => height,
+            None => self.chain.head()?.height,
+        };
+        let (events, next_cursor) = self.chain.chain_store().get_contract_events(
+            &msg.account_id,
+            &msg.standard,
+            msg.event.as_deref(),
+            msg.from_block_height,
+            to_block_height,
+            msg.cursor.as_ref(),
+            msg.limit,
+        )?;
+        Ok(ContractEventsView { events: events.into_iter().map(Into::into).collect(), next_cursor })
+    }
+}
+
 impl Handler<GetBlockProof> for ViewClientActorInner {
     #[perf]
     fn handle(&mut self, msg: GetBlockProof) -> Result<GetBlockProofResponse, GetBlockProofError> {
@@ -0,0 +1,33 @@
+// This file contains structures for the storage backup debug endpoint.
+use crate::errors::RpcError;
+use serde::{Deserialize, Serialize};
+
+/// Summary of a backup created through the `/debug/api/backup` endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BackupView {
+    /// Sequential number of the backup within the backup directory.
+    pub id: u64,
+    /// Time the backup was created at, in seconds since Unix epoch.
+    pub created_at: u64,
+    /// Total size of all files in the backup.
+    pub total_size: u64,
+    /// Number of bytes written by this backup, excluding files shared with
+    /// earlier backups.
+    pub exported_size: u64,
+}
+
+/// We use a trait for this, because jsonrpc does not have access to the node
+/// storage.
+pub trait BackupHandler: Sync + Send {
+    #[allow(clippy::result_large_err)]
+    fn create_backup(&self) -> Result<BackupView, RpcError>;
+}
+
+/// For tests.
+pub struct DummyBackupHandler {}
+
+impl BackupHandler for DummyBackupHandler {
+    fn create_backup(&self) -> Result<BackupView, RpcError> {
+        Err(RpcError::new_internal_error(None, "Not implemented".to_string()))
+    }
+}
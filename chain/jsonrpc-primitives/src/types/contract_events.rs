@@ -0,0 +1,49 @@
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct RpcContractEventsRequest {
+    pub account_id: near_primitives::types::AccountId,
+    pub standard: String,
+    /// Only events with this name are returned if set.
+    #[serde(default)]
+    pub event: Option<String>,
+    pub from_block_height: near_primitives::types::BlockHeight,
+    /// The height of the head is used if not set.
+    #[serde(default)]
+    pub to_block_height: Option<near_primitives::types::BlockHeight>,
+    /// The `next_cursor` of a previous response, to get the events after the
+    /// ones it returned.
+    #[serde(default)]
+    pub cursor: Option<near_primitives::contract_event::ContractEventCursor>,
+    /// Maximal number of events to return, from 1 to 1000.  Defaults to 100.
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct RpcContractEventsResponse {
+    #[serde(flatten)]
+    pub contract_events: near_primitives::views::ContractEventsView,
+}
+
+#[derive(thiserror::Error, Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "name", content = "info", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RpcContractEventsError {
+    #[error("The node reached its limits. Try again later. More details: {error_message}")]
+    InternalError { error_message: String },
+    #[error("Contract events are not indexed by this node")]
+    NotIndexed,
+}
+
+impl From<RpcContractEventsError> for crate::errors::RpcError {
+    fn from(error: RpcContractEventsError) -> Self {
+        let error_data = match serde_json::to_value(error) {
+            Ok(value) => value,
+            Err(err) => {
+                return Self::new_internal_error(
+                    None,
+                    format!("Failed to serialize RpcContractEventsError: {:?}", err),
+                );
+            }
+        };
+        Self::new_internal_or_handler_error(Some(error_data.clone()), error_data)
+    }
+}
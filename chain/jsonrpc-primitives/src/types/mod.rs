@@ -1,9 +1,11 @@
+pub mod backup;
 pub mod blocks;
 pub mod changes;
 pub mod chunks;
 pub mod client_config;
 pub mod config;
 pub mod congestion;
+pub mod contract_events;
 pub mod entity_debug;
 pub mod gas_price;
 pub mod light_client;
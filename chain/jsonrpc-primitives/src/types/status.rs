@@ -6,7 +6,7 @@ use near_client_primitives::debug::{
 use near_primitives::views::{
     CatchupStatusView, ChainProcessingInfo, NetworkGraphView, NetworkRoutesView, PeerStoreView,
     RecentOutboundConnectionsView, RequestedStatePartsView, SnapshotHostsView,
-    SplitStorageInfoView, SyncStatusView,
+    SplitStorageInfoView, StateSyncDumpStatusView, SyncStatusView,
 };
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -31,6 +31,8 @@ pub enum DebugStatusResponse {
     ChainProcessingStatus(ChainProcessingInfo),
     // The state parts already requested.
     RequestedStateParts(Vec<RequestedStatePartsView>),
+    // Progress of the state sync dump of each shard.
+    StateSyncDumpStatus(Vec<StateSyncDumpStatusView>),
     NetworkGraph(NetworkGraphView),
     RecentOutboundConnections(RecentOutboundConnectionsView),
     Routes(NetworkRoutesView),
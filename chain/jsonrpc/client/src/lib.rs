@@ -252,6 +252,15 @@ impl JsonRpcClient {
         call_method(&self.client, &self.server_addr, "EXPERIMENTAL_receipt", request)
     }
 
+    #[allow(non_snake_case)]
+    pub fn EXPERIMENTAL_contract_events(
+        &self,
+        request: near_jsonrpc_primitives::types::contract_events::RpcContractEventsRequest,
+    ) -> RpcRequest<near_jsonrpc_primitives::types::contract_events::RpcContractEventsResponse>
+    {
+        call_method(&self.client, &self.server_addr, "EXPERIMENTAL_contract_events", request)
+    }
+
     #[allow(non_snake_case)]
     pub fn EXPERIMENTAL_protocol_config(
         &self,
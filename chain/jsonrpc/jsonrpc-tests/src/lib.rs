@@ -12,7 +12,7 @@ use near_client::ViewClientActor;
 use near_jsonrpc::{RpcConfig, start_http};
 use near_jsonrpc_primitives::{
     message::{Message, from_slice},
-    types::entity_debug::DummyEntityDebugHandler,
+    types::{backup::DummyBackupHandler, entity_debug::DummyEntityDebugHandler},
 };
 use near_network::tcp;
 use near_primitives::types::NumBlocks;
@@ -66,6 +66,7 @@ pub fn start_all_with_validity_period(
         #[cfg(feature = "test_features")]
         noop().into_multi_sender(),
         Arc::new(DummyEntityDebugHandler {}),
+        Arc::new(DummyBackupHandler {}),
     );
     // setup_no_network_with_validity_period should use runtime_tempdir together with real runtime.
     (actor_handles.view_client_actor, addr, actor_handles.runtime_tempdir.unwrap())
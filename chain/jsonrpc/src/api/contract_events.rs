@@ -0,0 +1,70 @@
+use super::{Params, RpcFrom, RpcRequest};
+use near_async::messaging::AsyncSendError;
+use near_client_primitives::types::{GetContractEvents, GetContractEventsError};
+use near_jsonrpc_primitives::errors::RpcParseError;
+use near_jsonrpc_primitives::types::contract_events::{
+    RpcContractEventsError, RpcContractEventsRequest,
+};
+use near_primitives::contract_event::is_valid_event_name;
+use serde_json::Value;
+
+const DEFAULT_CONTRACT_EVENTS_LIMIT: u32 = 100;
+const MAX_CONTRACT_EVENTS_LIMIT: u32 = 1000;
+
+impl RpcRequest for RpcContractEventsRequest {
+    fn parse(value: Value) -> Result<Self, RpcParseError> {
+        let request: Self = Params::parse(value)?;
+        // Names which can't be indexed would not fit in the keys.
+        let names = std::iter::once(&request.standard)
+            .chain(&request.event)
+            .chain(request.cursor.as_ref().map(|cursor| &cursor.event));
+        for name in names {
+            if !is_valid_event_name(name) {
+                return Err(RpcParseError(format!(
+                    "Invalid event standard or name {name:?}, expected 1 to 255 bytes"
+                )));
+            }
+        }
+        if request.limit == Some(0) {
+            return Err(RpcParseError("Limit must be at least 1".to_string()));
+        }
+        Ok(request)
+    }
+}
+
+impl RpcFrom<AsyncSendError> for RpcContractEventsError {
+    fn rpc_from(error: AsyncSendError) -> Self {
+        Self::InternalError { error_message: error.to_string() }
+    }
+}
+
+impl RpcFrom<RpcContractEventsRequest> for GetContractEvents {
+    fn rpc_from(request: RpcContractEventsRequest) -> Self {
+        let limit = request.limit.unwrap_or(DEFAULT_CONTRACT_EVENTS_LIMIT);
+        Self {
+            account_id: request.account_id,
+            standard: request.standard,
+            event: request.event,
+            from_block_height: request.from_block_height,
+            to_block_height: request.to_block_height,
+            cursor: request.cursor,
+            limit: limit.min(MAX_CONTRACT_EVENTS_LIMIT) as usize,
+        }
+    }
+}
+
+impl RpcFrom<GetContractEventsError> for RpcContractEventsError {
+    fn rpc_from(error: GetContractEventsError) -> Self {
+        match error {
+            GetContractEventsError::IOError(error_message) => Self::InternalError { error_message },
+            GetContractEventsError::NotIndexed => Self::NotIndexed,
+            GetContractEventsError::Unreachable(ref error_message) => {
+                tracing::warn!(target: "jsonrpc", "Unreachable error occurred: {}", error_message);
+                crate::metrics::RPC_UNREACHABLE_ERROR_COUNT
+                    .with_label_values(&["RpcContractEventsError"])
+                    .inc();
+                Self::InternalError { error_message: error.to_string() }
+            }
+        }
+    }
+}
@@ -11,10 +11,11 @@ use near_async::messaging::{
 };
 use near_chain_configs::GenesisConfig;
 use near_client::{
-    DebugStatus, GetBlock, GetBlockProof, GetChunk, GetClientConfig, GetExecutionOutcome,
-    GetGasPrice, GetMaintenanceWindows, GetNetworkInfo, GetNextLightClientBlock, GetProtocolConfig,
-    GetReceipt, GetStateChanges, GetStateChangesInBlock, GetValidatorInfo, GetValidatorOrdered,
-    ProcessTxRequest, ProcessTxResponse, Query, Status, TxStatus,
+    DebugStatus, GetBlock, GetBlockProof, GetChunk, GetClientConfig, GetContractEvents,
+    GetExecutionOutcome, GetGasPrice, GetMaintenanceWindows, GetNetworkInfo,
+    GetNextLightClientBlock, GetProtocolConfig, GetReceipt, GetStateChanges,
+    GetStateChangesInBlock, GetValidatorInfo, GetValidatorOrdered, ProcessTxRequest,
+    ProcessTxResponse, Query, Status, TxStatus,
 };
 use near_client_primitives::debug::{DebugBlockStatusQuery, DebugBlocksStartingMode};
 use near_client_primitives::types::GetSplitStorageInfo;
@@ -22,6 +23,7 @@ pub use near_jsonrpc_client_internal as client;
 pub use near_jsonrpc_primitives as primitives;
 use near_jsonrpc_primitives::errors::{RpcError, RpcErrorKind};
 use near_jsonrpc_primitives::message::{Message, Request};
+use near_jsonrpc_primitives::types::backup::BackupHandler;
 use near_jsonrpc_primitives::types::blocks::RpcBlockRequest;
 use near_jsonrpc_primitives::types::config::{RpcProtocolConfigError, RpcProtocolConfigResponse};
 use near_jsonrpc_primitives::types::entity_debug::{EntityDebugHandler, EntityQueryWithParams};
@@ -253,6 +255,7 @@ pub struct ViewClientSenderForRpc(
     AsyncSender<GetBlock, ActixResult<GetBlock>>,
     AsyncSender<GetBlockProof, ActixResult<GetBlockProof>>,
     AsyncSender<GetChunk, ActixResult<GetChunk>>,
+    AsyncSender<GetContractEvents, ActixResult<GetContractEvents>>,
     AsyncSender<GetExecutionOutcome, ActixResult<GetExecutionOutcome>>,
     AsyncSender<GetGasPrice, ActixResult<GetGasPrice>>,
     AsyncSender<GetMaintenanceWindows, ActixResult<GetMaintenanceWindows>>,
@@ -293,6 +296,7 @@ struct JsonRpcHandler {
     enable_debug_rpc: bool,
     debug_pages_src_path: Option<PathBuf>,
     entity_debug_handler: Arc<dyn EntityDebugHandler>,
+    backup_handler: Arc<dyn BackupHandler>,
 }
 
 impl JsonRpcHandler {
@@ -450,6 +454,9 @@ impl JsonRpcHandler {
             "EXPERIMENTAL_maintenance_windows" => {
                 process_method_call(request, |params| self.maintenance_windows(params)).await
             }
+            "EXPERIMENTAL_contract_events" => {
+                process_method_call(request, |params| self.contract_events(params)).await
+            }
             "EXPERIMENTAL_split_storage_info" => {
                 process_method_call(request, |params| self.split_storage_info(params)).await
             }
@@ -806,6 +813,9 @@ impl JsonRpcHandler {
                     "/debug/api/requested_state_parts" => {
                         self.client_send(DebugStatus::RequestedStateParts).await?.rpc_into()
                     }
+                    "/debug/api/state_sync_dump_status" => {
+                        self.client_send(DebugStatus::StateSyncDumpStatus).await?.rpc_into()
+                    }
                     "/debug/api/peer_store" => self
                         .peer_manager_send(near_network::debug::GetDebugStatus::PeerStore)
                         .await?
@@ -1186,6 +1196,19 @@ impl JsonRpcHandler {
         Ok(windows.iter().map(|r| (r.start, r.end)).collect())
     }
 
+    async fn contract_events(
+        &self,
+        request: near_jsonrpc_primitives::types::contract_events::RpcContractEventsRequest,
+    ) -> Result<
+        near_jsonrpc_primitives::types::contract_events::RpcContractEventsResponse,
+        near_jsonrpc_primitives::types::contract_events::RpcContractEventsError,
+    > {
+        let contract_events = self.view_client_send(GetContractEvents::rpc_from(request)).await?;
+        Ok(near_jsonrpc_primitives::types::contract_events::RpcContractEventsResponse {
+            contract_events,
+        })
+    }
+
     async fn client_config(
         &self,
     ) -> Result<
@@ -1473,6 +1496,20 @@ async fn handle_entity_debug(
     }
 }
 
+async fn handle_backup(handler: web::Data<JsonRpcHandler>) -> Result<HttpResponse, HttpError> {
+    if !handler.enable_debug_rpc {
+        return Ok(HttpResponse::MethodNotAllowed().finish());
+    }
+    // Creating a backup involves copying and hashing files so it must not
+    // block the server's worker thread.
+    let backup_handler = handler.backup_handler.clone();
+    match web::block(move || backup_handler.create_backup()).await {
+        Ok(Ok(value)) => Ok(HttpResponse::Ok().json(&value)),
+        Ok(Err(err)) => Ok(HttpResponse::ServiceUnavailable().body(format!("{:?}", err))),
+        Err(err) => Ok(HttpResponse::InternalServerError().body(format!("{:?}", err))),
+    }
+}
+
 async fn handle_entity_debug_readonly(
     req: web::Json<EntityQueryWithParams>,
     handler: web::Data<Arc<dyn EntityDebugHandler>>,
@@ -1657,6 +1694,7 @@ pub fn start_http(
     peer_manager_sender: PeerManagerSenderForRpc,
     #[cfg(feature = "test_features")] gc_sender: GCSenderForRpc,
     entity_debug_handler: Arc<dyn EntityDebugHandler>,
+    backup_handler: Arc<dyn BackupHandler>,
 ) -> Vec<(&'static str, actix_web::dev::ServerHandle)> {
     let RpcConfig {
         addr,
@@ -1684,6 +1722,7 @@ pub fn start_http(
                 enable_debug_rpc,
                 debug_pages_src_path: debug_pages_src_path.clone().map(Into::into),
                 entity_debug_handler: entity_debug_handler.clone(),
+                backup_handler: backup_handler.clone(),
                 #[cfg(feature = "test_features")]
                 gc_sender: gc_sender.clone(),
             }))
@@ -1708,6 +1747,7 @@ pub fn start_http(
                 .service(
                     web::resource("/debug/api/entity").route(web::post().to(handle_entity_debug)),
                 )
+                .service(web::resource("/debug/api/backup").route(web::post().to(handle_backup)))
                 .service(web::resource("/debug/api/block_status/{starting_height}").route(
                     web::get().to(
                         #[allow(deprecated)]
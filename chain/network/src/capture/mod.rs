@@ -0,0 +1,335 @@
+//! Capture of the network traffic, for reproducing networking issues offline.
+//!
+//! When enabled, every PeerMessage sent to or received from a peer is appended to a capture
+//! file, together with the time, the direction and the id of the peer. Capture files are
+//! rotated once they reach `Config::max_file_size` and only the `Config::max_files` most
+//! recent ones are kept. Files are written by a dedicated thread: if it doesn't keep up,
+//! messages are dropped from the capture rather than slowing down the node.
+//!
+//! Messages are captured exactly as they were sent or received, in the encoding used on the
+//! connection, so capturing doesn't serialize them again.
+//!
+//! A capture file starts with `MAGIC`, followed by a sequence of borsh-encoded `Record`s,
+//! each prefixed with its length (u32 LE). Capture files can be read with `Reader`.
+use crate::network_protocol::{Encoding, ParsePeerMessageError, PeerMessage};
+use crate::stats::metrics;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytesize::MIB;
+use near_async::time;
+use near_primitives::network::PeerId;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read as _, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+#[cfg(test)]
+mod tests;
+
+const MAGIC: &[u8; 8] = b"NEARCAP1";
+const FILE_PREFIX: &str = "capture-";
+const FILE_SUFFIX: &str = ".bin";
+/// Number of records waiting to be written, above which new records are dropped.
+const QUEUE_SIZE: usize = 10_000;
+/// Records larger than this are considered corrupted.
+const MAX_RECORD_SIZE: usize = crate::peer::stream::NETWORK_MESSAGE_MAX_SIZE_BYTES + 1024;
+
+/// Encoding of a captured message.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Proto,
+    Borsh,
+}
+
+impl From<Encoding> for Format {
+    fn from(encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::Proto => Format::Proto,
+            Encoding::Borsh => Format::Borsh,
+        }
+    }
+}
+
+impl From<Format> for Encoding {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Proto => Encoding::Proto,
+            Format::Borsh => Encoding::Borsh,
+        }
+    }
+}
+
+/// Configuration of the network traffic capture.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    /// Directory to write the capture files to.
+    pub dir: PathBuf,
+    /// Size in bytes after which a new capture file is started.
+    pub max_file_size: u64,
+    /// Number of most recent capture files to keep. Older files are removed.
+    pub max_files: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { dir: PathBuf::from("network_capture"), max_file_size: 256 * MIB, max_files: 8 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Received,
+    Sent,
+}
+
+/// A single captured message.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// Unix timestamp in nanoseconds of when the message was sent or received.
+    pub timestamp_nanos: i64,
+    pub direction: Direction,
+    /// The other end of the connection. `None` for messages received on an
+    /// inbound connection before the handshake.
+    pub peer_id: Option<PeerId>,
+    pub format: Format,
+    /// The message, encoded according to `format`.
+    pub message: Vec<u8>,
+}
+
+impl Record {
+    pub fn new(
+        time: time::Utc,
+        direction: Direction,
+        peer_id: Option<PeerId>,
+        format: Format,
+        message: Vec<u8>,
+    ) -> Self {
+        Self {
+            timestamp_nanos: time.unix_timestamp_nanos() as i64,
+            direction,
+            peer_id,
+            format,
+            message,
+        }
+    }
+
+    /// Record of `msg` encoded according to `format`.
+    pub fn from_message(
+        time: time::Utc,
+        direction: Direction,
+        peer_id: Option<PeerId>,
+        format: Format,
+        msg: &PeerMessage,
+    ) -> Self {
+        Self::new(time, direction, peer_id, format, msg.serialize(format.into()))
+    }
+
+    pub(crate) fn decode(&self) -> Result<PeerMessage, ParsePeerMessageError> {
+        PeerMessage::deserialize(self.format.into(), &self.message)
+    }
+
+    pub fn time(&self) -> time::Utc {
+        time::Utc::from_unix_timestamp_nanos(self.timestamp_nanos as i128).unwrap()
+    }
+
+    /// Type of the captured message, or `None` if it cannot be parsed.
+    pub fn message_type(&self) -> Option<&'static str> {
+        self.decode().ok().map(|msg| msg.msg_variant())
+    }
+}
+
+/// Handle to the capture writer thread.
+/// The thread stops once the `Writer` is dropped and all queued records are written.
+pub(crate) struct Writer {
+    sender: mpsc::SyncSender<Record>,
+}
+
+impl Writer {
+    pub fn spawn(cfg: Config) -> io::Result<Self> {
+        let mut files = Files::open(cfg)?;
+        let (sender, receiver) = mpsc::sync_channel::<Record>(QUEUE_SIZE);
+        std::thread::Builder::new().name("network-capture".to_string()).spawn(move || {
+            let result = (|| {
+                while let Ok(record) = receiver.recv() {
+                    files.write(&record)?;
+                    while let Ok(record) = receiver.try_recv() {
+                        files.write(&record)?;
+                    }
+                    files.flush()?;
+                }
+                io::Result::Ok(())
+            })();
+            if let Err(err) = result {
+                tracing::error!(target: "network", ?err, "network capture failed");
+            }
+        })?;
+        Ok(Self { sender })
+    }
+
+    /// Captures a message, encoded as it was sent or received.
+    pub fn record(
+        &self,
+        clock: &time::Clock,
+        direction: Direction,
+        peer_id: Option<&PeerId>,
+        encoding: Encoding,
+        msg: &[u8],
+    ) {
+        let record = Record::new(
+            clock.now_utc(),
+            direction,
+            peer_id.cloned(),
+            encoding.into(),
+            msg.to_vec(),
+        );
+        let result = match self.sender.try_send(record) {
+            Ok(()) => "captured",
+            Err(_) => "dropped",
+        };
+        metrics::PEER_CAPTURE_MESSAGES.with_label_values(&[result]).inc();
+    }
+}
+
+fn file_path(dir: &Path, index: u64) -> PathBuf {
+    dir.join(format!("{FILE_PREFIX}{index:010}{FILE_SUFFIX}"))
+}
+
+/// Lists the capture files in `dir`, ordered from the oldest.
+fn list_files(dir: &Path) -> io::Result<Vec<(u64, PathBuf)>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let index = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix(FILE_PREFIX))
+            .and_then(|name| name.strip_suffix(FILE_SUFFIX))
+            .and_then(|index| index.parse::<u64>().ok());
+        if let Some(index) = index {
+            files.push((index, path));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Rotating capture files.
+struct Files {
+    cfg: Config,
+    index: u64,
+    file: BufWriter<File>,
+    size: u64,
+}
+
+impl Files {
+    fn open(cfg: Config) -> io::Result<Self> {
+        std::fs::create_dir_all(&cfg.dir)?;
+        // Never overwrite the captures of the previous runs.
+        let index = list_files(&cfg.dir)?.last().map_or(0, |(index, _)| index + 1);
+        let (file, size) = Self::create(&cfg, index)?;
+        Ok(Self { cfg, index, file, size })
+    }
+
+    fn create(cfg: &Config, index: u64) -> io::Result<(BufWriter<File>, u64)> {
+        let mut file = BufWriter::new(File::create(file_path(&cfg.dir, index))?);
+        file.write_all(MAGIC)?;
+        let files = list_files(&cfg.dir)?;
+        for (_, path) in &files[..files.len().saturating_sub(cfg.max_files.max(1))] {
+            std::fs::remove_file(path)?;
+        }
+        Ok((file, MAGIC.len() as u64))
+    }
+
+    fn write(&mut self, record: &Record) -> io::Result<()> {
+        let data = borsh::to_vec(record)?;
+        let record_size = (4 + data.len()) as u64;
+        if self.size > MAGIC.len() as u64 && self.size + record_size > self.cfg.max_file_size {
+            self.file.flush()?;
+            self.index += 1;
+            (self.file, self.size) = Self::create(&self.cfg, self.index)?;
+        }
+        self.file.write_all(&(data.len() as u32).to_le_bytes())?;
+        self.file.write_all(&data)?;
+        self.size += record_size;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Reads the records from capture files, in the order they were written.
+pub struct Reader {
+    files: VecDeque<PathBuf>,
+    current: Option<BufReader<File>>,
+}
+
+impl Reader {
+    /// Opens a single capture file, or all the capture files in a directory.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let files = if path.is_dir() {
+            list_files(path)?.into_iter().map(|(_, path)| path).collect()
+        } else {
+            VecDeque::from([path.to_path_buf()])
+        };
+        Ok(Self { files, current: None })
+    }
+
+    fn open_next(&mut self) -> io::Result<bool> {
+        let Some(path) = self.files.pop_front() else {
+            return Ok(false);
+        };
+        let mut file = BufReader::new(File::open(&path)?);
+        let mut magic = [0; MAGIC.len()];
+        file.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is not a capture file", path.display()),
+            ));
+        }
+        self.current = Some(file);
+        Ok(true)
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<Record>> {
+        loop {
+            let Some(file) = &mut self.current else {
+                if !self.open_next()? {
+                    return Ok(None);
+                }
+                continue;
+            };
+            let mut read = || {
+                let mut len = [0; 4];
+                file.read_exact(&mut len)?;
+                let len = u32::from_le_bytes(len) as usize;
+                if len > MAX_RECORD_SIZE {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("record of size {len} is too large"),
+                    ));
+                }
+                let mut data = vec![0; len];
+                file.read_exact(&mut data)?;
+                Record::try_from_slice(&data)
+            };
+            match read() {
+                Ok(record) => return Ok(Some(record)),
+                // The last record may be truncated if the node was killed while writing it.
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => self.current = None,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+impl Iterator for Reader {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
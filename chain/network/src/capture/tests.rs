@@ -0,0 +1,100 @@
+use crate::capture::{Config, Direction, Files, Format, Reader, Record, list_files};
+use crate::network_protocol::PeerMessage;
+use crate::network_protocol::testonly as data;
+use crate::testonly::make_rng;
+use near_async::time;
+use near_primitives::hash::CryptoHash;
+use std::io::Write as _;
+
+fn make_records(clock: &time::Clock, format: Format, n: u64) -> Vec<Record> {
+    let mut rng = make_rng(9827341);
+    (0..n)
+        .map(|i| {
+            let msg = PeerMessage::BlockRequest(CryptoHash::hash_borsh(i));
+            let direction = if i % 2 == 0 { Direction::Received } else { Direction::Sent };
+            let peer_id = Some(data::make_peer_id(&mut rng));
+            Record::from_message(clock.now_utc(), direction, peer_id, format, &msg)
+        })
+        .collect()
+}
+
+#[test]
+fn write_and_read() {
+    let dir = tempfile::tempdir().unwrap();
+    let clock = time::FakeClock::default();
+    for format in [Format::Proto, Format::Borsh] {
+        let cfg = Config {
+            dir: dir.path().join(format!("{format:?}")),
+            max_file_size: 1 << 20,
+            max_files: 2,
+        };
+        let records = make_records(&clock.clock(), format, 100);
+        let mut files = Files::open(cfg.clone()).unwrap();
+        for record in &records {
+            files.write(record).unwrap();
+        }
+        files.flush().unwrap();
+
+        let got: Vec<_> = Reader::open(&cfg.dir).unwrap().map(Result::unwrap).collect();
+        assert_eq!(got, records);
+        assert_eq!(
+            got[0].decode().unwrap(),
+            PeerMessage::BlockRequest(CryptoHash::hash_borsh(0u64))
+        );
+        assert_eq!(got[0].message_type(), Some("BlockRequest"));
+    }
+}
+
+#[test]
+fn rotation() {
+    let dir = tempfile::tempdir().unwrap();
+    let clock = time::FakeClock::default();
+    let records = make_records(&clock.clock(), Format::Proto, 100);
+    let record_size = 4 + borsh::to_vec(&records[0]).unwrap().len() as u64;
+    // Fit 10 records per file.
+    let cfg =
+        Config { dir: dir.path().to_path_buf(), max_file_size: 8 + 10 * record_size, max_files: 3 };
+    let mut files = Files::open(cfg.clone()).unwrap();
+    for record in &records {
+        files.write(record).unwrap();
+    }
+    files.flush().unwrap();
+    let indices: Vec<_> = list_files(&cfg.dir).unwrap().into_iter().map(|(i, _)| i).collect();
+    assert_eq!(indices, vec![7, 8, 9]);
+    let got: Vec<_> = Reader::open(&cfg.dir).unwrap().map(Result::unwrap).collect();
+    assert_eq!(got, records[70..]);
+
+    // Captures of the previous runs are not overwritten.
+    drop(files);
+    let files = Files::open(cfg.clone()).unwrap();
+    assert_eq!(files.index, 10);
+    let indices: Vec<_> = list_files(&cfg.dir).unwrap().into_iter().map(|(i, _)| i).collect();
+    assert_eq!(indices, vec![8, 9, 10]);
+}
+
+#[test]
+fn truncated_record() {
+    let dir = tempfile::tempdir().unwrap();
+    let clock = time::FakeClock::default();
+    let cfg = Config { dir: dir.path().to_path_buf(), ..Config::default() };
+    let records = make_records(&clock.clock(), Format::Proto, 3);
+    let mut files = Files::open(cfg.clone()).unwrap();
+    for record in &records {
+        files.write(record).unwrap();
+    }
+    // Simulate the node being killed in the middle of writing a record.
+    files.file.write_all(&[100, 0, 0, 0, 1, 2]).unwrap();
+    files.flush().unwrap();
+
+    let got: Vec<_> = Reader::open(&cfg.dir).unwrap().map(Result::unwrap).collect();
+    assert_eq!(got, records);
+}
+
+#[test]
+fn not_a_capture_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("capture-0000000000.bin");
+    std::fs::write(&path, b"something else").unwrap();
+    let mut reader = Reader::open(&path).unwrap();
+    assert!(reader.next().unwrap().is_err());
+}
@@ -1,6 +1,6 @@
 use parking_lot::Mutex;
 
-// AtomicCell narrows down a Mutex API to load/store calls.
+// AtomicCell narrows down a Mutex API to load/store/swap calls.
 pub(crate) struct AtomicCell<T>(Mutex<T>);
 
 impl<T: Clone> AtomicCell<T> {
@@ -13,4 +13,7 @@ impl<T: Clone> AtomicCell<T> {
     pub fn store(&self, v: T) {
         *self.0.lock() = v;
     }
+    pub fn swap(&self, v: T) -> T {
+        std::mem::replace(&mut *self.0.lock(), v)
+    }
 }
@@ -1,8 +1,11 @@
 use crate::blacklist;
+use crate::capture;
 use crate::concurrency::rate;
 use crate::network_protocol::PeerAddr;
 use crate::network_protocol::PeerInfo;
+use crate::noise;
 use crate::peer_manager::peer_store;
+use crate::peer_manager::reputation;
 use crate::rate_limits::messages_limits;
 use crate::snapshot_hosts;
 use crate::stun;
@@ -195,6 +198,15 @@ pub struct NetworkConfig {
 
     /// Configuration of rate limits for incoming messages.
     pub received_messages_rate_limits: messages_limits::Config,
+    /// Configuration of the peer reputation scoring.
+    pub peer_reputation: reputation::Config,
+    /// UDP address to accept QUIC connections on. If set, TIER2 connections with peers which
+    /// also support QUIC send consensus, witness and state sync messages over QUIC.
+    pub quic_addr: Option<std::net::SocketAddr>,
+    /// Whether to encrypt the peer connections.
+    pub encryption: noise::Mode,
+    /// If set, all messages sent to and received from peers are written to capture files.
+    pub capture: Option<capture::Config>,
 
     #[cfg(test)]
     pub(crate) event_sink:
@@ -385,6 +397,13 @@ impl NetworkConfig {
             },
             // Use a preset to configure rate limits and override entries with user defined values later.
             received_messages_rate_limits: messages_limits::Config::standard_preset(),
+            peer_reputation: cfg.experimental.peer_reputation,
+            quic_addr: match cfg.experimental.quic_addr.as_str() {
+                "" => None,
+                addr => Some(addr.parse().context("Failed to parse quic_addr")?),
+            },
+            encryption: cfg.experimental.encryption,
+            capture: cfg.experimental.capture,
             #[cfg(test)]
             event_sink: near_async::messaging::IntoSender::into_sender(
                 near_async::messaging::noop(),
@@ -463,6 +482,10 @@ impl NetworkConfig {
             }),
             skip_tombstones: None,
             received_messages_rate_limits: messages_limits::Config::default(),
+            peer_reputation: reputation::Config::default(),
+            quic_addr: None,
+            encryption: noise::Mode::Disabled,
+            capture: None,
             #[cfg(test)]
             event_sink: near_async::messaging::IntoSender::into_sender(
                 near_async::messaging::noop(),
@@ -521,6 +544,7 @@ impl NetworkConfig {
         if let Err(err) = self.received_messages_rate_limits.validate() {
             anyhow::bail!("One or more invalid rate limits: {err:?}");
         }
+        self.peer_reputation.validate().context("peer_reputation")?;
 
         Ok(VerifiedConfig { node_id: self.node_id(), inner: self })
     }
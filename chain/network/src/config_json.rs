@@ -1,4 +1,7 @@
+use crate::capture;
 use crate::network_protocol::PeerAddr;
+use crate::noise;
+use crate::peer_manager::reputation;
 use crate::rate_limits::messages_limits;
 use crate::stun;
 use near_async::time::Duration;
@@ -269,6 +272,27 @@ pub struct ExperimentalConfig {
     /// Fields set here will override the NetworkConfig fields.
     #[serde(default)]
     pub network_config_overrides: NetworkConfigOverrides,
+
+    /// See `near_network::config::NetworkConfig::peer_reputation`.
+    #[serde(default)]
+    pub peer_reputation: reputation::Config,
+
+    /// UDP address to accept QUIC connections on, e.g. "0.0.0.0:24568".
+    /// Empty string disables QUIC. QUIC is used only for TIER2 connections
+    /// with peers which have QUIC enabled as well; TCP is used otherwise.
+    #[serde(default)]
+    pub quic_addr: String,
+
+    /// Whether to encrypt the peer connections with the Noise protocol, authenticated with
+    /// the node key: "disabled", "preferred" (fall back to plaintext with peers which don't
+    /// support encryption) or "required".
+    #[serde(default)]
+    pub encryption: noise::Mode,
+
+    /// If set, all messages sent to and received from peers are written to rotating
+    /// capture files, which can be replayed later with `neard replay-capture`.
+    #[serde(default)]
+    pub capture: Option<capture::Config>,
 }
 
 /// Overrides values from NetworkConfig.
@@ -300,6 +324,10 @@ impl Default for ExperimentalConfig {
             tier1_connect_interval: default_tier1_connect_interval(),
             tier1_new_connections_per_attempt: default_tier1_new_connections_per_attempt(),
             network_config_overrides: Default::default(),
+            peer_reputation: Default::default(),
+            quic_addr: String::new(),
+            encryption: Default::default(),
+            capture: None,
         }
     }
 }
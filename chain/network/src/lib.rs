@@ -1,14 +1,18 @@
 #![cfg_attr(enable_const_type_id, feature(const_type_id))]
 
+pub use crate::noise::Mode as EncryptionMode;
 pub use crate::peer_manager::peer_manager_actor::{Event, PeerManagerActor};
+pub use crate::peer_manager::reputation::Config as PeerReputationConfig;
 pub use crate::rate_limits::messages_limits::OverrideConfig as MessagesLimitsOverrideConfig;
 
 mod accounts_data;
 mod announce_accounts;
 mod network_protocol;
+mod noise;
 mod peer;
 mod peer_manager;
 mod private_actix;
+mod quic;
 mod rate_limits;
 mod snapshot_hosts;
 mod stats;
@@ -17,6 +21,7 @@ mod stun;
 
 pub mod actix;
 pub mod blacklist;
+pub mod capture;
 pub mod client;
 pub mod concurrency;
 pub mod config;
@@ -71,6 +71,8 @@ pub enum ParseHandshakeError {
     TargetPeerId(ParseRequiredError<ParsePublicKeyError>),
     #[error("sender_listen_port {0}")]
     SenderListenPort(std::num::TryFromIntError),
+    #[error("sender_quic_port {0}")]
+    SenderQuicPort(std::num::TryFromIntError),
     #[error("sender_chain_info {0}")]
     SenderChainInfo(ParseRequiredError<ParsePeerChainInfoV2Error>),
     #[error("partial_edge_info {0}")]
@@ -90,6 +92,7 @@ impl From<&Handshake> for proto::Handshake {
             sender_chain_info: MF::some((&x.sender_chain_info).into()),
             partial_edge_info: MF::some((&x.partial_edge_info).into()),
             owned_account: x.owned_account.as_ref().map(Into::into).into(),
+            sender_quic_port: x.sender_quic_port.unwrap_or(0).into(),
             ..Self::default()
         }
     }
@@ -116,6 +119,11 @@ impl TryFrom<&proto::Handshake> for Handshake {
                 .map_err(Self::Error::PartialEdgeInfo)?,
             owned_account: try_from_optional(&p.owned_account)
                 .map_err(Self::Error::OwnedAccount)?,
+            sender_quic_port: {
+                let port =
+                    u16::try_from(p.sender_quic_port).map_err(Self::Error::SenderQuicPort)?;
+                if port == 0 { None } else { Some(port) }
+            },
         })
     }
 }
@@ -0,0 +1,401 @@
+//! Optional encryption of peer connections with the Noise protocol.
+//!
+//! The encryption is negotiated before the `Handshake`, directly on the TCP stream:
+//! * the outbound peer sends a `PREAMBLE` frame.
+//! * the inbound peer reads the beginning of the stream until it knows whether it is the
+//!   `PREAMBLE`. If it is, the peer echoes it back, or responds with `DECLINE` if its
+//!   encryption is disabled. Otherwise the bytes read so far are the beginning of the first
+//!   plaintext frame and are passed on to the receive loop.
+//! * binaries without encryption support fail to parse `PREAMBLE` as a `PeerMessage` and
+//!   don't respond, so if no response arrives within `Setup::negotiation_timeout`, the outbound
+//!   peer continues without encryption (unless plaintext is not allowed). Such peers are
+//!   remembered in `PlaintextPeers`, so that the timeout is paid only once per peer.
+//! * peers run a `Noise_NN` handshake, which establishes the session keys.
+//! * peers exchange `Auth` messages over the encrypted session: a signature of the Noise
+//!   handshake hash with their `node_key`. It proves that the session is established with the
+//!   owner of the `PeerId` and not with an on-path attacker.
+//!
+//! Afterwards every frame is encrypted. Noise messages are limited to 64KiB, so a frame is
+//! split into chunks, which are encrypted separately and sent as a single frame.
+//!
+//! QUIC connections don't go through this negotiation: they are always encrypted with TLS 1.3,
+//! and the `quic::Hello` binds the TLS session to the `PeerId` of both ends, so they provide
+//! the same guarantees as a `Required` Noise session.
+use crate::tcp;
+use near_crypto::{SecretKey, Signature};
+use near_primitives::hash::CryptoHash;
+use near_primitives::network::PeerId;
+use parking_lot::Mutex;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+
+#[cfg(test)]
+mod tests;
+
+const NOISE_PARAMS: &str = "Noise_NN_25519_ChaChaPoly_BLAKE2s";
+/// Frame sent by the outbound peer to request encryption, and echoed by the inbound peer.
+/// The leading 0xff byte makes sure that it doesn't parse as a `PeerMessage`.
+const PREAMBLE: &[u8] = b"\x0e\x00\x00\x00\xffnear-noise/1\x00";
+/// Response of an inbound peer with disabled encryption to `PREAMBLE`.
+const DECLINE: &[u8] = b"\x0e\x00\x00\x00\xffnear-noise/0\x00";
+/// Maximal size of a Noise message.
+const MAX_NOISE_MESSAGE_SIZE: usize = 65535;
+const TAG_SIZE: usize = 16;
+const MAX_CHUNK_SIZE: usize = MAX_NOISE_MESSAGE_SIZE - TAG_SIZE;
+/// Maximal size of the handshake and `Auth` frames.
+const MAX_SETUP_FRAME_SIZE: usize = 1024;
+/// Default time to wait for the inbound peer to respond to `PREAMBLE`.
+pub(crate) const NEGOTIATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+/// Maximal number of peers remembered in `PlaintextPeers`.
+const PLAINTEXT_PEERS_CACHE_SIZE: usize = 1000;
+
+/// Whether to encrypt the peer connections.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+    /// Connections are not encrypted.
+    #[default]
+    Disabled,
+    /// Connections are encrypted if the peer supports encryption.
+    Preferred,
+    /// Connections with peers which don't support encryption are rejected.
+    Required,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum Error {
+    #[error("IO error: {0}")]
+    IO(#[from] std::io::Error),
+    #[error("noise: {0}")]
+    Noise(#[from] snow::Error),
+    #[error("peer doesn't support encryption")]
+    NotSupported,
+    #[error("unexpected response to the encryption request")]
+    UnexpectedResponse,
+    #[error("frame too large: got {got_bytes}B, want <={want_max_bytes}B")]
+    FrameTooLarge { got_bytes: usize, want_max_bytes: usize },
+    #[error("invalid auth message")]
+    InvalidAuth,
+    #[error("expected peer {want}, got {got}")]
+    PeerIdMismatch { want: PeerId, got: PeerId },
+}
+
+/// Peers which didn't respond to `PREAMBLE`, i.e. run a binary without encryption support.
+/// Outbound connections to them don't negotiate encryption again (unless it is required).
+/// A peer is forgotten once it negotiates encryption on an inbound connection.
+pub(crate) struct PlaintextPeers(Mutex<lru::LruCache<PeerId, ()>>);
+
+impl Default for PlaintextPeers {
+    fn default() -> Self {
+        Self(Mutex::new(lru::LruCache::new(NonZeroUsize::new(PLAINTEXT_PEERS_CACHE_SIZE).unwrap())))
+    }
+}
+
+impl PlaintextPeers {
+    pub fn contains(&self, peer_id: &PeerId) -> bool {
+        self.0.lock().contains(peer_id)
+    }
+
+    fn insert(&self, peer_id: PeerId) {
+        self.0.lock().put(peer_id, ());
+    }
+
+    fn remove(&self, peer_id: &PeerId) {
+        self.0.lock().pop(peer_id);
+    }
+}
+
+pub(crate) enum Role {
+    /// Connection initiator, which knows the peer it connects to.
+    Outbound {
+        peer_id: PeerId,
+    },
+    Inbound,
+}
+
+/// Parameters of the encryption setup of a single connection.
+pub(crate) struct Setup {
+    pub node_key: SecretKey,
+    pub role: Role,
+    /// Whether encryption is enabled. Inbound peers with disabled encryption still take part
+    /// in the negotiation, to decline the encryption requests.
+    pub enabled: bool,
+    /// Whether to continue without encryption if the peer doesn't support it.
+    pub allow_plaintext: bool,
+    pub negotiation_timeout: std::time::Duration,
+    pub plaintext_peers: Arc<PlaintextPeers>,
+}
+
+/// Result of a successful encryption setup.
+pub(crate) struct Negotiated {
+    /// `None` if the connection continues unencrypted.
+    pub session: Option<Session>,
+    /// Data consumed from the stream by the inbound peer while looking for `PREAMBLE`.
+    /// It is the beginning of the first frame of an unencrypted connection.
+    pub unread: Vec<u8>,
+}
+
+impl Negotiated {
+    fn plaintext() -> Self {
+        Self { session: None, unread: vec![] }
+    }
+}
+
+impl Setup {
+    /// Returns `None` if there is nothing to negotiate: the connection is outbound and either
+    /// encryption is disabled, or it is preferred and the peer is known not to support it.
+    pub fn new(
+        mode: Mode,
+        node_key: &SecretKey,
+        stream_type: &tcp::StreamType,
+        plaintext_peers: &Arc<PlaintextPeers>,
+    ) -> Option<Self> {
+        let (enabled, allow_plaintext) = match mode {
+            Mode::Disabled => (false, true),
+            Mode::Preferred => (true, true),
+            Mode::Required => (true, false),
+        };
+        let role = match stream_type {
+            tcp::StreamType::Inbound => Role::Inbound,
+            tcp::StreamType::Outbound { peer_id, .. } => {
+                if !enabled || (allow_plaintext && plaintext_peers.contains(peer_id)) {
+                    return None;
+                }
+                Role::Outbound { peer_id: peer_id.clone() }
+            }
+        };
+        Some(Self {
+            node_key: node_key.clone(),
+            role,
+            enabled,
+            allow_plaintext,
+            negotiation_timeout: NEGOTIATION_TIMEOUT,
+            plaintext_peers: plaintext_peers.clone(),
+        })
+    }
+
+    /// Negotiates encryption on a freshly established connection.
+    pub async fn run(&self, stream: &mut tokio::net::TcpStream) -> Result<Negotiated, Error> {
+        let supported = match &self.role {
+            Role::Outbound { peer_id } => {
+                stream.write_all(PREAMBLE).await?;
+                let mut response = [0; PREAMBLE.len()];
+                match tokio::time::timeout(
+                    self.negotiation_timeout,
+                    stream.read_exact(&mut response),
+                )
+                .await
+                {
+                    Ok(res) => {
+                        res?;
+                        if response == PREAMBLE {
+                            true
+                        } else if response == DECLINE {
+                            false
+                        } else {
+                            return Err(Error::UnexpectedResponse);
+                        }
+                    }
+                    // The peer ignored PREAMBLE and is waiting for the Handshake.
+                    Err(_) => {
+                        self.plaintext_peers.insert(peer_id.clone());
+                        false
+                    }
+                }
+            }
+            Role::Inbound => {
+                let unread = read_preamble(stream).await?;
+                if !unread.is_empty() {
+                    return if self.allow_plaintext {
+                        Ok(Negotiated { session: None, unread })
+                    } else {
+                        Err(Error::NotSupported)
+                    };
+                }
+                if !self.enabled {
+                    stream.write_all(DECLINE).await?;
+                    return Ok(Negotiated::plaintext());
+                }
+                stream.write_all(PREAMBLE).await?;
+                true
+            }
+        };
+        if !supported {
+            return if self.allow_plaintext {
+                Ok(Negotiated::plaintext())
+            } else {
+                Err(Error::NotSupported)
+            };
+        }
+        let session = self.handshake(stream).await?;
+        if let Role::Inbound = self.role {
+            self.plaintext_peers.remove(&session.peer_id);
+        }
+        Ok(Negotiated { session: Some(session), unread: vec![] })
+    }
+
+    async fn handshake(&self, stream: &mut tokio::net::TcpStream) -> Result<Session, Error> {
+        let builder = snow::Builder::new(NOISE_PARAMS.parse()?);
+        let mut buf = vec![0; MAX_NOISE_MESSAGE_SIZE];
+        let hs = match &self.role {
+            Role::Outbound { .. } => {
+                let mut hs = builder.build_initiator()?;
+                let n = hs.write_message(&[], &mut buf)?;
+                write_frame(stream, &buf[..n]).await?;
+                hs.read_message(&read_frame(stream, MAX_SETUP_FRAME_SIZE).await?, &mut buf)?;
+                hs
+            }
+            Role::Inbound => {
+                let mut hs = builder.build_responder()?;
+                hs.read_message(&read_frame(stream, MAX_SETUP_FRAME_SIZE).await?, &mut buf)?;
+                let n = hs.write_message(&[], &mut buf)?;
+                write_frame(stream, &buf[..n]).await?;
+                hs
+            }
+        };
+        let is_outbound = matches!(self.role, Role::Outbound { .. });
+        let handshake_hash = CryptoHash::hash_bytes(hs.get_handshake_hash());
+        let transport = Arc::new(hs.into_stateless_transport_mode()?);
+        let (mut encryptor, mut decryptor) = (
+            Encryptor { transport: transport.clone(), nonce: 0 },
+            Decryptor { transport, nonce: 0 },
+        );
+
+        let auth = Auth::new(&self.node_key, &handshake_hash, is_outbound);
+        write_frame(stream, &encryptor.encrypt(&borsh::to_vec(&auth).unwrap())?).await?;
+        let peer_auth = decryptor.decrypt(&read_frame(stream, MAX_SETUP_FRAME_SIZE).await?)?;
+        let peer_auth: Auth = borsh::from_slice(&peer_auth).map_err(|_| Error::InvalidAuth)?;
+        if !peer_auth.verify(&handshake_hash, !is_outbound) {
+            return Err(Error::InvalidAuth);
+        }
+        if let Role::Outbound { peer_id } = &self.role {
+            if peer_id != &peer_auth.peer_id {
+                return Err(Error::PeerIdMismatch {
+                    want: peer_id.clone(),
+                    got: peer_auth.peer_id,
+                });
+            }
+        }
+        Ok(Session { peer_id: peer_auth.peer_id, encryptor, decryptor })
+    }
+}
+
+/// Reads the stream until it is known whether it starts with `PREAMBLE`.
+/// Returns the data read if it doesn't, and an empty vector if the `PREAMBLE` has been consumed.
+/// Reads at most `PREAMBLE.len()` bytes and doesn't wait for more once they differ from it.
+async fn read_preamble(stream: &mut tokio::net::TcpStream) -> std::io::Result<Vec<u8>> {
+    let mut buf = [0; PREAMBLE.len()];
+    let mut n = 0;
+    while n < PREAMBLE.len() {
+        let got = stream.read(&mut buf[n..]).await?;
+        if got == 0 {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        n += got;
+        if buf[..n] != PREAMBLE[..n] {
+            return Ok(buf[..n].to_vec());
+        }
+    }
+    Ok(vec![])
+}
+
+async fn write_frame(stream: &mut tokio::net::TcpStream, frame: &[u8]) -> std::io::Result<()> {
+    stream.write_u32_le(frame.len() as u32).await?;
+    stream.write_all(frame).await
+}
+
+async fn read_frame(stream: &mut tokio::net::TcpStream, max_size: usize) -> Result<Vec<u8>, Error> {
+    let n = stream.read_u32_le().await? as usize;
+    if n > max_size {
+        return Err(Error::FrameTooLarge { got_bytes: n, want_max_bytes: max_size });
+    }
+    let mut buf = vec![0; n];
+    stream.read_exact(&mut buf).await?;
+    Ok(buf)
+}
+
+/// Proof that the Noise session has been established with the owner of `peer_id`.
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+struct Auth {
+    peer_id: PeerId,
+    signature: Signature,
+}
+
+impl Auth {
+    fn hash(handshake_hash: &CryptoHash, is_outbound: bool) -> CryptoHash {
+        CryptoHash::hash_borsh((PREAMBLE, handshake_hash, is_outbound))
+    }
+
+    fn new(node_key: &SecretKey, handshake_hash: &CryptoHash, is_outbound: bool) -> Self {
+        Self {
+            peer_id: PeerId::new(node_key.public_key()),
+            signature: node_key.sign(Self::hash(handshake_hash, is_outbound).as_ref()),
+        }
+    }
+
+    fn verify(&self, handshake_hash: &CryptoHash, is_outbound: bool) -> bool {
+        self.signature
+            .verify(Self::hash(handshake_hash, is_outbound).as_ref(), self.peer_id.public_key())
+    }
+}
+
+/// Established encrypted session.
+pub(crate) struct Session {
+    /// Authenticated id of the peer.
+    pub peer_id: PeerId,
+    pub encryptor: Encryptor,
+    pub decryptor: Decryptor,
+}
+
+/// Maximal size of an encrypted frame with plaintext of size `n`.
+pub(crate) fn max_encrypted_size(n: usize) -> usize {
+    n + n.div_ceil(MAX_CHUNK_SIZE).max(1) * TAG_SIZE
+}
+
+/// Encrypts outgoing frames. Owned by the send loop.
+pub(crate) struct Encryptor {
+    transport: Arc<snow::StatelessTransportState>,
+    nonce: u64,
+}
+
+impl Encryptor {
+    pub fn encrypt(&mut self, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut out = vec![0; max_encrypted_size(msg.len())];
+        let mut n = 0;
+        // An empty frame is encrypted as a single empty chunk.
+        let chunks: Box<dyn Iterator<Item = &[u8]>> = if msg.is_empty() {
+            Box::new(std::iter::once(msg))
+        } else {
+            Box::new(msg.chunks(MAX_CHUNK_SIZE))
+        };
+        for chunk in chunks {
+            n += self.transport.write_message(self.nonce, chunk, &mut out[n..])?;
+            self.nonce += 1;
+        }
+        out.truncate(n);
+        Ok(out)
+    }
+}
+
+/// Decrypts incoming frames. Owned by the receive loop.
+pub(crate) struct Decryptor {
+    transport: Arc<snow::StatelessTransportState>,
+    nonce: u64,
+}
+
+impl Decryptor {
+    pub fn decrypt(&mut self, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        if msg.is_empty() {
+            return Err(Error::Noise(snow::Error::Decrypt));
+        }
+        let mut out = vec![0; msg.len()];
+        let mut n = 0;
+        for chunk in msg.chunks(MAX_NOISE_MESSAGE_SIZE) {
+            n += self.transport.read_message(self.nonce, chunk, &mut out[n..])?;
+            self.nonce += 1;
+        }
+        out.truncate(n);
+        Ok(out)
+    }
+}
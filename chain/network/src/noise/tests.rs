@@ -0,0 +1,215 @@
+use crate::network_protocol::testonly as data;
+use crate::noise::{Error, Mode, Negotiated, PREAMBLE, PlaintextPeers, Setup, max_encrypted_size};
+use crate::tcp;
+use crate::testonly::make_rng;
+use near_crypto::SecretKey;
+use near_primitives::network::PeerId;
+use rand::Rng as _;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+
+const SHORT_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(100);
+
+fn setup(mode: Mode, key: &SecretKey, stream: &tcp::Stream) -> Setup {
+    Setup {
+        negotiation_timeout: SHORT_TIMEOUT,
+        ..Setup::new(mode, key, &stream.type_, &Arc::default()).unwrap()
+    }
+}
+
+/// Runs the encryption setup on both ends of a loopback connection.
+async fn connect(
+    outbound_mode: Mode,
+    inbound_mode: Mode,
+) -> (Result<Negotiated, Error>, Result<Negotiated, Error>, PeerId, PeerId) {
+    let mut rng = make_rng(89712412);
+    let outbound_key = data::make_secret_key(&mut rng);
+    let inbound_key = data::make_secret_key(&mut rng);
+    let inbound_id = PeerId::new(inbound_key.public_key());
+    let (mut outbound, mut inbound) =
+        tcp::Stream::loopback(inbound_id.clone(), tcp::Tier::T2).await;
+    let outbound_setup = setup(outbound_mode, &outbound_key, &outbound);
+    let inbound_setup = setup(inbound_mode, &inbound_key, &inbound);
+    let (o, i) = tokio::join!(
+        outbound_setup.run(&mut outbound.stream),
+        inbound_setup.run(&mut inbound.stream)
+    );
+    (o, i, PeerId::new(outbound_key.public_key()), inbound_id)
+}
+
+#[tokio::test]
+async fn encrypted_session() {
+    let (outbound, inbound, outbound_id, inbound_id) =
+        connect(Mode::Preferred, Mode::Required).await;
+    let mut outbound = outbound.unwrap().session.unwrap();
+    let mut inbound = inbound.unwrap().session.unwrap();
+    assert_eq!(outbound.peer_id, inbound_id);
+    assert_eq!(inbound.peer_id, outbound_id);
+
+    let mut rng = make_rng(4312413);
+    for size in [0, 1, 1000, 65519, 65520, 300000] {
+        let mut msg = vec![0; size];
+        rng.fill(&mut msg[..]);
+        let encrypted = outbound.encryptor.encrypt(&msg).unwrap();
+        assert!(encrypted.len() <= max_encrypted_size(size));
+        assert_eq!(inbound.decryptor.decrypt(&encrypted).unwrap(), msg);
+        let encrypted = inbound.encryptor.encrypt(&msg).unwrap();
+        assert_eq!(outbound.decryptor.decrypt(&encrypted).unwrap(), msg);
+    }
+
+    // Tampered and empty frames are rejected.
+    let mut encrypted = outbound.encryptor.encrypt(b"hello").unwrap();
+    encrypted[0] ^= 1;
+    assert!(inbound.decryptor.decrypt(&encrypted).is_err());
+    assert!(inbound.decryptor.decrypt(&[]).is_err());
+}
+
+#[tokio::test]
+async fn peer_id_mismatch() {
+    let mut rng = make_rng(213124);
+    let key = data::make_secret_key(&mut rng);
+    let (mut outbound, mut inbound) =
+        tcp::Stream::loopback(data::make_peer_id(&mut rng), tcp::Tier::T2).await;
+    let outbound_setup = setup(Mode::Required, &data::make_secret_key(&mut rng), &outbound);
+    let inbound_setup = setup(Mode::Required, &key, &inbound);
+    let (o, _) = tokio::join!(
+        outbound_setup.run(&mut outbound.stream),
+        inbound_setup.run(&mut inbound.stream)
+    );
+    assert!(matches!(o, Err(Error::PeerIdMismatch { .. })));
+}
+
+#[tokio::test]
+async fn plaintext_outbound() {
+    let mut rng = make_rng(98123);
+    let key = data::make_secret_key(&mut rng);
+    let frame = b"\x03\x00\x00\x00abc";
+
+    // Inbound peer falls back to plaintext and returns the data it has consumed.
+    for mode in [Mode::Disabled, Mode::Preferred] {
+        let (mut outbound, mut inbound) =
+            tcp::Stream::loopback(data::make_peer_id(&mut rng), tcp::Tier::T2).await;
+        outbound.stream.write_all(frame).await.unwrap();
+        let res = setup(mode, &key, &inbound).run(&mut inbound.stream).await.unwrap();
+        assert!(res.session.is_none());
+        let mut got = res.unread;
+        assert!(!got.is_empty() && got.len() <= frame.len());
+        let n = got.len();
+        got.resize(frame.len(), 0);
+        inbound.stream.read_exact(&mut got[n..]).await.unwrap();
+        assert_eq!(&got, frame);
+    }
+
+    // A frame which starts like the preamble is returned in full once it diverges.
+    let (mut outbound, mut inbound) =
+        tcp::Stream::loopback(data::make_peer_id(&mut rng), tcp::Tier::T2).await;
+    let prefix = &PREAMBLE[..6];
+    outbound.stream.write_all(prefix).await.unwrap();
+    let (res, _) =
+        tokio::join!(setup(Mode::Preferred, &key, &inbound).run(&mut inbound.stream), async {
+            tokio::time::sleep(SHORT_TIMEOUT).await;
+            outbound.stream.write_all(b"x").await.unwrap();
+        });
+    assert_eq!(res.unwrap().unread, [prefix, b"x"].concat());
+
+    let (mut outbound, mut inbound) =
+        tcp::Stream::loopback(data::make_peer_id(&mut rng), tcp::Tier::T2).await;
+    outbound.stream.write_all(frame).await.unwrap();
+    let res = setup(Mode::Required, &key, &inbound).run(&mut inbound.stream).await;
+    assert!(matches!(res, Err(Error::NotSupported)));
+}
+
+#[tokio::test]
+async fn plaintext_inbound() {
+    let mut rng = make_rng(7612);
+    let key = data::make_secret_key(&mut rng);
+
+    // Inbound peer doesn't support encryption, so it doesn't respond to the preamble.
+    let peer_id = data::make_peer_id(&mut rng);
+    let plaintext_peers = Arc::new(PlaintextPeers::default());
+    let (mut outbound, mut inbound) = tcp::Stream::loopback(peer_id.clone(), tcp::Tier::T2).await;
+    let res = Setup {
+        negotiation_timeout: SHORT_TIMEOUT,
+        ..Setup::new(Mode::Preferred, &key, &outbound.type_, &plaintext_peers).unwrap()
+    }
+    .run(&mut outbound.stream)
+    .await;
+    assert!(res.unwrap().session.is_none());
+    let mut got = [0; PREAMBLE.len()];
+    inbound.stream.read_exact(&mut got).await.unwrap();
+    assert_eq!(&got, PREAMBLE);
+
+    // The peer is remembered, so the encryption is not negotiated with it again,
+    // unless it is required.
+    assert!(plaintext_peers.contains(&peer_id));
+    let (outbound, _inbound) = tcp::Stream::loopback(peer_id.clone(), tcp::Tier::T2).await;
+    assert!(Setup::new(Mode::Preferred, &key, &outbound.type_, &plaintext_peers).is_none());
+    assert!(Setup::new(Mode::Required, &key, &outbound.type_, &plaintext_peers).is_some());
+
+    let (mut outbound, _inbound) =
+        tcp::Stream::loopback(data::make_peer_id(&mut rng), tcp::Tier::T2).await;
+    let res = setup(Mode::Required, &key, &outbound).run(&mut outbound.stream).await;
+    assert!(matches!(res, Err(Error::NotSupported)));
+}
+
+#[tokio::test]
+async fn declined() {
+    let mut rng = make_rng(548121);
+    let outbound_key = data::make_secret_key(&mut rng);
+    let inbound_key = data::make_secret_key(&mut rng);
+    let plaintext_peers = Arc::new(PlaintextPeers::default());
+    for (mode, want_ok) in [(Mode::Preferred, true), (Mode::Required, false)] {
+        let peer_id = data::make_peer_id(&mut rng);
+        let (mut outbound, mut inbound) =
+            tcp::Stream::loopback(peer_id.clone(), tcp::Tier::T2).await;
+        // Inbound peer with disabled encryption responds immediately, without waiting
+        // for the negotiation timeout.
+        let outbound_setup = Setup {
+            negotiation_timeout: std::time::Duration::from_secs(3600),
+            ..Setup::new(mode, &outbound_key, &outbound.type_, &plaintext_peers).unwrap()
+        };
+        let inbound_setup = setup(Mode::Disabled, &inbound_key, &inbound);
+        let (o, i) = tokio::join!(
+            outbound_setup.run(&mut outbound.stream),
+            inbound_setup.run(&mut inbound.stream)
+        );
+        let i = i.unwrap();
+        assert!(i.session.is_none());
+        assert!(i.unread.is_empty());
+        match o {
+            Ok(o) => {
+                assert!(want_ok);
+                assert!(o.session.is_none());
+            }
+            Err(err) => {
+                assert!(!want_ok);
+                assert!(matches!(err, Error::NotSupported));
+            }
+        }
+        // Declining peers support the negotiation, so they are not remembered.
+        assert!(!plaintext_peers.contains(&peer_id));
+    }
+}
+
+#[tokio::test]
+async fn plaintext_peer_forgotten_after_encrypted_inbound() {
+    let mut rng = make_rng(90812);
+    let outbound_key = data::make_secret_key(&mut rng);
+    let inbound_key = data::make_secret_key(&mut rng);
+    let outbound_id = PeerId::new(outbound_key.public_key());
+    let plaintext_peers = Arc::new(PlaintextPeers::default());
+    plaintext_peers.insert(outbound_id.clone());
+
+    let (mut outbound, mut inbound) =
+        tcp::Stream::loopback(PeerId::new(inbound_key.public_key()), tcp::Tier::T2).await;
+    let outbound_setup = setup(Mode::Preferred, &outbound_key, &outbound);
+    let inbound_setup =
+        Setup::new(Mode::Preferred, &inbound_key, &inbound.type_, &plaintext_peers).unwrap();
+    let (o, i) = tokio::join!(
+        outbound_setup.run(&mut outbound.stream),
+        inbound_setup.run(&mut inbound.stream)
+    );
+    assert!(o.unwrap().session.is_some());
+    assert_eq!(i.unwrap().session.unwrap().peer_id, outbound_id);
+    assert!(!plaintext_peers.contains(&outbound_id));
+}
@@ -1,4 +1,5 @@
 use crate::accounts_data::AccountDataError;
+use crate::capture;
 use crate::client::{
     AnnounceAccountRequest, BlockHeadersRequest, BlockHeadersResponse, BlockRequest, BlockResponse,
     EpochSyncRequestMessage, EpochSyncResponseMessage, OptimisticBlockMessage, ProcessTxRequest,
@@ -15,6 +16,7 @@ use crate::network_protocol::{
     RoutedMessageBody, RoutingTableUpdate, SnapshotHostInfoVerificationError, SyncAccountsData,
     SyncSnapshotHosts,
 };
+use crate::noise;
 use crate::peer::stream;
 use crate::peer::tracker::Tracker;
 use crate::peer_manager::connection;
@@ -22,7 +24,9 @@ use crate::peer_manager::network_state::{NetworkState, PRUNE_EDGES_AFTER};
 #[cfg(test)]
 use crate::peer_manager::peer_manager_actor::Event;
 use crate::peer_manager::peer_manager_actor::MAX_TIER2_PEERS;
+use crate::peer_manager::reputation::ReputationEvent;
 use crate::private_actix::{RegisterPeerError, SendMessage};
+use crate::quic;
 use crate::rate_limits::messages_limits;
 #[cfg(feature = "distance_vector_routing")]
 use crate::routing::NetworkTopologyChange;
@@ -97,6 +101,12 @@ pub struct HandshakeCompletedEvent {
     pub(crate) tier: tcp::Tier,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuicAttachedEvent {
+    pub(crate) stream_id: tcp::StreamId,
+    pub(crate) peer_id: PeerId,
+}
+
 #[derive(thiserror::Error, Clone, PartialEq, Eq, Debug)]
 pub(crate) enum ClosingReason {
     #[error("too many inbound connections in connecting state")]
@@ -167,6 +177,9 @@ pub(crate) struct PeerActor {
 
     /// Framed wrapper to send messages through the TCP connection.
     framed: stream::FramedStream<PeerActor>,
+    /// QUIC connection with the peer, if attached. Messages of a `quic::MessageClass`
+    /// are sent over it instead of the TCP connection.
+    quic: Option<quic::Sender>,
 
     /// Tracker for requests and responses.
     tracker: Arc<Mutex<Tracker>>,
@@ -191,6 +204,9 @@ pub(crate) struct PeerActor {
 
     /// Per-message rate limits for incoming messages.
     received_messages_rate_limits: messages_limits::RateLimits,
+    /// Set while `received_messages_rate_limits` are shrunk because of the bad reputation
+    /// of the peer. They are grown back as the reputation recovers.
+    rate_limits_shrunk: bool,
 }
 
 impl Debug for PeerActor {
@@ -340,7 +356,13 @@ impl PeerActor {
                 let peer_addr = stream.peer_addr;
                 let stream_type = stream.type_.clone();
                 let stats = Arc::new(connection::Stats::default());
-                let framed = stream::FramedStream::spawn(ctx, stream, stats.clone());
+                let encryption = noise::Setup::new(
+                    network_state.config.encryption,
+                    &network_state.config.node_key,
+                    &stream.type_,
+                    &network_state.plaintext_peers,
+                );
+                let framed = stream::FramedStream::spawn(ctx, stream, stats.clone(), encryption);
                 Self {
                     closing_reason: None,
                     clock,
@@ -353,6 +375,7 @@ impl PeerActor {
                     },
                     peer_status: PeerStatus::Connecting(send, connecting_status),
                     framed,
+                    quic: None,
                     tracker: Default::default(),
                     stats,
                     routed_message_cache: LruCache::new(
@@ -371,6 +394,7 @@ impl PeerActor {
                     .into(),
                     network_state,
                     received_messages_rate_limits,
+                    rate_limits_shrunk: false,
                 }
             }),
             recv,
@@ -435,7 +459,7 @@ impl PeerActor {
             // peers to update its height at the peer. In the future we will introduce a new
             // peer message type for that and then we can enable this check again.
             //PeerMessage::Block(b) if self.tracker.lock().has_received(b.hash()) => return,
-            PeerMessage::BlockRequest(h) => self.tracker.lock().push_request(*h),
+            PeerMessage::BlockRequest(h) => self.tracker.lock().push_request(&self.clock, *h),
             PeerMessage::SyncAccountsData(d) => metrics::SYNC_ACCOUNTS_DATA
                 .with_label_values(&[
                     "sent",
@@ -454,9 +478,24 @@ impl PeerActor {
 
         let bytes = msg.serialize(enc);
         self.tracker.lock().increment_sent(&self.clock, bytes.len() as u64);
+        // Until the encoding is known, messages are sent in both encodings. Capture only
+        // one copy of them.
+        if let Some(writer) = self.network_state.capture.get() {
+            if self.encoding().is_some() || enc == Encoding::Proto {
+                let peer_id = self.other_peer_id();
+                writer.record(&self.clock, capture::Direction::Sent, peer_id, enc, &bytes);
+            }
+        }
         let bytes_len = bytes.len();
         tracing::trace!(target: "network", msg_len = bytes_len);
-        self.framed.send(stream::Frame(bytes));
+        let frame = match (&self.quic, quic::MessageClass::of(msg)) {
+            // Fall back to TCP if the QUIC connection is broken.
+            (Some(quic), Some(class)) => quic.send(class, stream::Frame(bytes)).err(),
+            _ => Some(stream::Frame(bytes)),
+        };
+        if let Some(frame) = frame {
+            self.framed.send(frame);
+        }
         metrics::PEER_DATA_SENT_BYTES.inc_by(bytes_len as u64);
         let msg_type = msg.msg_variant();
         metrics::PEER_MESSAGE_SENT_BY_TYPE_TOTAL.with_label_values(&[msg_type]).inc();
@@ -494,6 +533,15 @@ impl PeerActor {
                 }
                 .sign(&signer)
             }),
+            sender_quic_port: match spec.tier {
+                tcp::Tier::T2 => self
+                    .network_state
+                    .quic
+                    .get()
+                    .and_then(|endpoint| endpoint.local_addr().ok())
+                    .map(|addr| addr.port()),
+                tcp::Tier::T1 | tcp::Tier::T3 => None,
+            },
         };
         let msg = match spec.tier {
             tcp::Tier::T1 => PeerMessage::Tier1Handshake(handshake),
@@ -521,6 +569,81 @@ impl PeerActor {
         self.peer_info.as_ref().as_ref().map(|peer_info| &peer_info.id)
     }
 
+    /// Updates the reputation of the peer according to `event`.
+    /// Shrinks the rate limits of the peer if its reputation got worse,
+    /// and bans it once its reputation drops too low.
+    fn report_reputation_event(&mut self, ctx: &mut actix::Context<Self>, event: ReputationEvent) {
+        let Some(peer_id) = self.other_peer_id() else {
+            return;
+        };
+        let outcome = self.network_state.peer_reputations.report(&self.clock, peer_id, event);
+        self.set_rate_limit_factor(outcome.rate_limit_factor);
+        if let Some(ban_reason) = outcome.ban {
+            self.stop(ctx, ClosingReason::Ban(ban_reason));
+        }
+    }
+
+    /// Grows the shrunk rate limits of the peer back according to its recovered reputation.
+    fn refresh_rate_limit_factor(&mut self) {
+        if !self.rate_limits_shrunk {
+            return;
+        }
+        let Some(peer_id) = self.other_peer_id() else {
+            return;
+        };
+        let factor = self.network_state.peer_reputations.rate_limit_factor_of(&self.clock, peer_id);
+        self.set_rate_limit_factor(factor);
+    }
+
+    fn set_rate_limit_factor(&mut self, factor: f32) {
+        self.received_messages_rate_limits.set_capacity_factor(factor);
+        self.rate_limits_shrunk = factor < 1.;
+    }
+
+    /// Opens a QUIC connection to the peer, if both this node and the peer support QUIC.
+    fn connect_quic(
+        &self,
+        ctx: &mut actix::Context<Self>,
+        peer_id: PeerId,
+        quic_port: Option<u16>,
+        nonce: u64,
+    ) {
+        let (Some(endpoint), Some(port)) = (self.network_state.quic.get(), quic_port) else {
+            return;
+        };
+        let endpoint = endpoint.clone();
+        let addr = SocketAddr::new(self.peer_addr.ip(), port);
+        let node_key = self.network_state.config.node_key.clone();
+        ctx.spawn(wrap_future(async move { endpoint.connect(addr, &node_key, peer_id, nonce).await }).map(
+            move |res, act: &mut PeerActor, ctx| match res {
+                Ok(conn) => act.attach_quic(ctx, conn),
+                Err(err) => {
+                    tracing::info!(target: "network", %addr, ?err, "failed to open QUIC connection, using TCP only")
+                }
+            },
+        ));
+    }
+
+    /// Starts using `conn` for sending and receiving messages.
+    /// Only a single QUIC connection can be attached to a ready TIER2 connection.
+    fn attach_quic(&mut self, ctx: &mut actix::Context<Self>, conn: quinn::Connection) {
+        let attachable = match &self.peer_status {
+            PeerStatus::Ready(tcp_conn) => tcp_conn.tier == tcp::Tier::T2 && self.quic.is_none(),
+            PeerStatus::Connecting(..) => false,
+        };
+        if !attachable || self.closing_reason.is_some() {
+            conn.close(quinn::VarInt::from_u32(0), b"rejected");
+            return;
+        }
+        tracing::debug!(target: "network", peer = %self.peer_info, remote = %conn.remote_address(), "attached QUIC connection");
+        self.quic = Some(quic::Sender::spawn(ctx, conn, self.stats.clone()));
+        #[cfg(test)]
+        self.network_state.config.event_sink.send(Event::QuicAttached(QuicAttachedEvent {
+            stream_id: self.stream_id,
+            peer_id: self.other_peer_id().unwrap().clone(),
+        }));
+    }
+
     fn process_handshake(
         &mut self,
         ctx: &mut <PeerActor as actix::Actor>::Context,
@@ -532,6 +655,14 @@ impl PeerActor {
             PeerStatus::Connecting(_, it) => it,
             _ => panic!("process_handshake called in non-connecting state"),
         };
+        // An encrypted connection can be used only by the peer which has set up the encryption.
+        if let Some(peer_id) = self.framed.encrypted_peer_id() {
+            if peer_id != &handshake.sender_peer_id {
+                tracing::warn!(target: "network", "Handshake sender doesn't match the encryption peer. Disconnecting peer {}", handshake.sender_peer_id);
+                self.stop(ctx, ClosingReason::HandshakeFailed);
+                return;
+            }
+        }
         match cs {
             ConnectingStatus::Outbound { handshake_spec: spec, .. } => {
                 if handshake.protocol_version != spec.protocol_version {
@@ -683,6 +814,7 @@ impl PeerActor {
                 encoding: self.encoding(),
             }),
             last_time_peer_requested: AtomicCell::new(None),
+            pending_headers_request: AtomicCell::new(None),
             last_time_received_message: AtomicCell::new(now),
             established_time: now,
             send_accounts_data_demux: demux::Demux::new(
@@ -833,6 +965,11 @@ impl PeerActor {
                                     }
                                 }));
                             }
+                            // The outbound side opens the QUIC connection, the inbound side
+                            // attaches it once the peer authenticates itself.
+                            if act.peer_type == PeerType::Outbound {
+                                act.connect_quic(ctx, handshake.sender_peer_id.clone(), handshake.sender_quic_port, nonce);
+                            }
                             // Sync the RoutingTable.
                             act.sync_routing_table();
                             // Sync snapshot hosts
@@ -987,7 +1124,7 @@ impl PeerActor {
     }
 
     fn receive_message(
-        &self,
+        &mut self,
         ctx: &mut actix::Context<Self>,
         conn: &connection::Connection,
         msg: PeerMessage,
@@ -1002,6 +1139,7 @@ impl PeerActor {
                 sink.send(Event::MessageProcessed(tier, msg));
             }
         };
+        let mut reputation_event = None;
         let was_requested = match &msg {
             PeerMessage::Block(block) => {
                 self.network_state.txns_since_last_block.store(0, Ordering::Release);
@@ -1016,10 +1154,28 @@ impl PeerActor {
                 });
                 let mut tracker = self.tracker.lock();
                 tracker.push_received(hash);
+                reputation_event =
+                    tracker.take_response_latency(&self.clock, &hash).map(ReputationEvent::Latency);
                 tracker.has_request(&hash)
             }
+            PeerMessage::BlockHeaders(headers) => {
+                // A synced peer honestly replies with no headers when asked past its head, so
+                // only an empty reply to a request below the head it has told us about is useless.
+                let tip_height = conn.pending_headers_request.swap(None);
+                let last_block = *conn.last_block.load().as_ref();
+                let peer_height = last_block.map(|last_block| last_block.height);
+                if headers.is_empty()
+                    && matches!((tip_height, peer_height), (Some(tip), Some(peer)) if peer > tip)
+                {
+                    reputation_event = Some(ReputationEvent::UselessResponse);
+                }
+                false
+            }
             _ => false,
         };
+        if let Some(event) = reputation_event {
+            self.report_reputation_event(ctx, event);
+        }
         let clock = self.clock.clone();
         let network_state = self.network_state.clone();
         let peer_id = conn.peer_info.id.clone();
@@ -1651,6 +1807,8 @@ impl actix::Handler<stream::Error> for PeerActor {
             }
             // It is expected in a sense that the peer might be just slow.
             stream::Error::Send(stream::SendError::QueueOverflow { .. }) => true,
+            // Peer may not support encryption, or the connection has been tampered with.
+            stream::Error::Recv(stream::RecvError::Encryption(_)) => true,
             stream::Error::Recv(stream::RecvError::IO(err))
             | stream::Error::Send(stream::SendError::IO(err)) => match err.kind() {
                 // Connection has been closed.
@@ -1704,11 +1862,18 @@ impl actix::Handler<stream::Frame> for PeerActor {
             Ok(msg) => msg,
             Err(err) => {
                 tracing::debug!(target: "network", "Received invalid data {} from {}: {}", near_fmt::AbbrBytes(&msg), self.peer_info, err);
+                self.report_reputation_event(ctx, ReputationEvent::InvalidMessage);
                 return;
             }
         };
 
         tracing::trace!(target: "network", "Received message: {}", peer_msg);
+        if let Some(writer) = self.network_state.capture.get() {
+            // `parse_message` falls back to borsh if the encoding is not known yet.
+            let enc = self.encoding().unwrap_or(Encoding::Borsh);
+            let peer_id = self.other_peer_id();
+            writer.record(&self.clock, capture::Direction::Received, peer_id, enc, &msg);
+        }
 
         let now = self.clock.now();
         {
@@ -1717,9 +1882,11 @@ impl actix::Handler<stream::Frame> for PeerActor {
             metrics::PEER_MESSAGE_RECEIVED_BY_TYPE_BYTES
                 .with_label_values(&labels)
                 .inc_by(msg.len() as u64);
+            self.refresh_rate_limit_factor();
             if !self.received_messages_rate_limits.is_allowed(&peer_msg, now) {
                 metrics::PEER_MESSAGE_RATE_LIMITED_BY_TYPE_TOTAL.with_label_values(&labels).inc();
                 tracing::debug!(target: "network", "Peer {} is being rate limited for message {}", self.peer_info, peer_msg.msg_variant());
+                self.report_reputation_event(ctx, ReputationEvent::RateLimited);
                 return;
             }
         }
@@ -1772,6 +1939,15 @@ impl actix::Handler<WithSpanContext<SendMessage>> for PeerActor {
     }
 }
 
+impl actix::Handler<quic::Attach> for PeerActor {
+    type Result = ();
+
+    #[perf]
+    fn handle(&mut self, quic::Attach(conn): quic::Attach, ctx: &mut Self::Context) {
+        self.attach_quic(ctx, conn);
+    }
+}
+
 /// Messages from PeerManager to Peer
 #[derive(actix::Message, Debug)]
 #[rtype(result = "()")]
@@ -1,23 +1,24 @@
+use crate::noise;
 use crate::peer_manager::connection;
 use crate::stats::metrics;
 use crate::tcp;
 use actix::AsyncContext as _;
 use actix::fut::future::wrap_future;
 use bytesize::{GIB, MIB};
+use near_primitives::network::PeerId;
 use std::io;
 use std::net::SocketAddr;
-use std::sync::Arc;
 use std::sync::atomic::Ordering;
+use std::sync::{Arc, OnceLock};
 use tokio::io::AsyncReadExt as _;
 use tokio::io::AsyncWriteExt as _;
 
 /// Maximum size of network message in encoded format.
 /// We encode length as `u32`, and therefore maximum size can't be larger than `u32::MAX`.
-const NETWORK_MESSAGE_MAX_SIZE_BYTES: usize = 512 * MIB as usize;
+pub(crate) const NETWORK_MESSAGE_MAX_SIZE_BYTES: usize = 512 * MIB as usize;
 /// Maximum capacity of write buffer in bytes.
-const MAX_WRITE_BUFFER_CAPACITY_BYTES: usize = GIB as usize;
+pub(crate) const MAX_WRITE_BUFFER_CAPACITY_BYTES: usize = GIB as usize;
 
-type ReadHalf = tokio::io::ReadHalf<tokio::net::TcpStream>;
 type WriteHalf = tokio::io::WriteHalf<tokio::net::TcpStream>;
 
 #[derive(thiserror::Error, Debug)]
@@ -34,6 +35,8 @@ pub(crate) enum RecvError {
     IO(#[source] io::Error),
     #[error("message too large: got {got_bytes}B, want <={want_max_bytes}B")]
     MessageTooLarge { got_bytes: usize, want_max_bytes: usize },
+    #[error("encryption: {0}")]
+    Encryption(#[source] noise::Error),
 }
 
 #[derive(actix::Message, PartialEq, Eq, Clone, Debug)]
@@ -61,6 +64,9 @@ pub(crate) struct FramedStream<Actor: actix::Actor> {
     stats: Arc<connection::Stats>,
     send_buf_size_metric: Arc<metrics::IntGaugeGuard>,
     addr: actix::Addr<Actor>,
+    /// Id of the peer authenticated during the encryption setup.
+    /// Set before any frame is received, if the stream is encrypted.
+    encrypted_peer_id: Arc<OnceLock<PeerId>>,
 }
 
 impl<Actor> FramedStream<Actor>
@@ -69,39 +75,86 @@ where
         + actix::Handler<Error>
         + actix::Handler<Frame>,
 {
+    /// Spawns the send and receive loops. If `encryption` is set, encryption is negotiated
+    /// first; messages sent in the meantime are queued.
     pub fn spawn(
         ctx: &mut actix::Context<Actor>,
         stream: tcp::Stream,
         stats: Arc<connection::Stats>,
+        encryption: Option<noise::Setup>,
     ) -> Self {
-        let (tcp_recv, tcp_send) = tokio::io::split(stream.stream);
         let (queue_send, queue_recv) = tokio::sync::mpsc::unbounded_channel();
         let send_buf_size_metric = Arc::new(metrics::MetricGuard::new(
             &*metrics::PEER_DATA_WRITE_BUFFER_SIZE,
             vec![stream.peer_addr.to_string()],
         ));
+        let encrypted_peer_id = Arc::new(OnceLock::new());
         ctx.spawn(wrap_future({
             let addr = ctx.address();
             let stats = stats.clone();
             let m = send_buf_size_metric.clone();
+            let encrypted_peer_id = encrypted_peer_id.clone();
+            let peer_addr = stream.peer_addr;
+            let mut tcp_stream = stream.stream;
             async move {
-                if let Err(err) = Self::run_send_loop(tcp_send, queue_recv, stats, m).await {
-                    addr.do_send(Error::Send(SendError::IO(err)));
-                }
-            }
-        }));
-        ctx.spawn(wrap_future({
-            let addr = ctx.address();
-            let stats = stats.clone();
-            async move {
-                if let Err(err) =
-                    Self::run_recv_loop(stream.peer_addr, tcp_recv, addr.clone(), stats).await
-                {
-                    addr.do_send(Error::Recv(err));
-                }
+                let negotiated = match encryption {
+                    None => noise::Negotiated { session: None, unread: vec![] },
+                    Some(setup) => match setup.run(&mut tcp_stream).await {
+                        Ok(negotiated) => {
+                            if negotiated.session.is_none() {
+                                metrics::PEER_ENCRYPTION_SETUP
+                                    .with_label_values(&["plaintext"])
+                                    .inc();
+                            }
+                            negotiated
+                        }
+                        Err(err) => {
+                            metrics::PEER_ENCRYPTION_SETUP.with_label_values(&["error"]).inc();
+                            addr.do_send(Error::Recv(RecvError::Encryption(err)));
+                            return;
+                        }
+                    },
+                };
+                let (encryptor, decryptor) = match negotiated.session {
+                    Some(session) => {
+                        metrics::PEER_ENCRYPTION_SETUP.with_label_values(&["encrypted"]).inc();
+                        encrypted_peer_id.set(session.peer_id).unwrap();
+                        (Some(session.encryptor), Some(session.decryptor))
+                    }
+                    None => (None, None),
+                };
+                let (tcp_recv, tcp_send) = tokio::io::split(tcp_stream);
+                // Data consumed by the encryption setup precedes the rest of the stream.
+                let tcp_recv = io::Cursor::new(negotiated.unread).chain(tcp_recv);
+                let send = async {
+                    if let Err(err) =
+                        Self::run_send_loop(tcp_send, queue_recv, encryptor, stats.clone(), m).await
+                    {
+                        addr.do_send(Error::Send(SendError::IO(err)));
+                    }
+                };
+                let recv = async {
+                    if let Err(err) = Self::run_recv_loop(
+                        peer_addr,
+                        tcp_recv,
+                        decryptor,
+                        addr.clone(),
+                        stats.clone(),
+                    )
+                    .await
+                    {
+                        addr.do_send(Error::Recv(err));
+                    }
+                };
+                futures::future::join(send, recv).await;
             }
         }));
-        Self { queue_send, stats, send_buf_size_metric, addr: ctx.address() }
+        Self { queue_send, stats, send_buf_size_metric, addr: ctx.address(), encrypted_peer_id }
+    }
+
+    /// Id of the peer, if the stream is encrypted.
+    pub fn encrypted_peer_id(&self) -> Option<&PeerId> {
+        self.encrypted_peer_id.get()
     }
 
     /// Pushes `msg` to the send queue.
@@ -138,7 +191,8 @@ where
     // directly from the stream.
     async fn run_recv_loop(
         peer_addr: SocketAddr,
-        read: ReadHalf,
+        read: impl tokio::io::AsyncRead + Unpin,
+        mut decryptor: Option<noise::Decryptor>,
         addr: actix::Addr<Actor>,
         stats: Arc<connection::Stats>,
     ) -> Result<(), RecvError> {
@@ -151,13 +205,14 @@ where
             &metrics::PEER_DATA_READ_BUFFER_SIZE,
             vec![peer_addr.to_string()],
         );
+        let max_size = match decryptor {
+            Some(_) => noise::max_encrypted_size(NETWORK_MESSAGE_MAX_SIZE_BYTES),
+            None => NETWORK_MESSAGE_MAX_SIZE_BYTES,
+        };
         loop {
             let n = read.read_u32_le().await.map_err(RecvError::IO)? as usize;
-            if n > NETWORK_MESSAGE_MAX_SIZE_BYTES {
-                return Err(RecvError::MessageTooLarge {
-                    got_bytes: n,
-                    want_max_bytes: NETWORK_MESSAGE_MAX_SIZE_BYTES,
-                });
+            if n > max_size {
+                return Err(RecvError::MessageTooLarge { got_bytes: n, want_max_bytes: max_size });
             }
             msg_size_metric.observe(n as f64);
             buf_size_metric.set(n as i64);
@@ -168,6 +223,9 @@ where
             buf_size_metric.set(0);
             stats.received_messages.fetch_add(1, Ordering::Relaxed);
             stats.received_bytes.fetch_add(n as u64, Ordering::Relaxed);
+            if let Some(decryptor) = &mut decryptor {
+                buf = decryptor.decrypt(&buf).map_err(RecvError::Encryption)?;
+            }
             if let Err(_) = addr.send(Frame(buf)).await {
                 // We got mailbox error, which means that Actor has stopped,
                 // so we should just close the stream.
@@ -178,6 +236,7 @@ where
     async fn run_send_loop(
         tcp_send: WriteHalf,
         mut queue_recv: tokio::sync::mpsc::UnboundedReceiver<Frame>,
+        mut encryptor: Option<noise::Encryptor>,
         stats: Arc<connection::Stats>,
         buf_size_metric: Arc<metrics::IntGaugeGuard>,
     ) -> io::Result<()> {
@@ -190,6 +249,10 @@ where
                 // since dropping messages may lead to hard-to-debug high-level issues.
                 if msg.len() > NETWORK_MESSAGE_MAX_SIZE_BYTES {
                     metrics::MessageDropped::InputTooLong.inc_unknown_msg();
+                } else if let Some(encryptor) = &mut encryptor {
+                    let data = encryptor.encrypt(&msg).map_err(io::Error::other)?;
+                    writer.write_u32_le(data.len() as u32).await?;
+                    writer.write_all(&data[..]).await?;
                 } else {
                     writer.write_u32_le(msg.len() as u32).await?;
                     writer.write_all(&msg[..]).await?;
@@ -202,6 +202,7 @@ async fn test_handshake(outbound_encoding: Option<Encoding>, inbound_encoding: O
         sender_peer_id: outbound_cfg.id(),
         target_peer_id: inbound.cfg.id(),
         sender_listen_port: Some(outbound_port),
+        sender_quic_port: None,
         sender_chain_info: outbound_cfg.chain.get_peer_chain_info(),
         partial_edge_info: outbound_cfg
             .partial_edge_info(&inbound.cfg.id(), Edge::create_fresh_nonce(&clock.clock())),
@@ -1,10 +1,12 @@
 use crate::actix::ActixSystem;
 use crate::network_protocol::testonly as data;
+use crate::noise;
 use crate::peer::stream;
 use crate::tcp;
 use crate::testonly::make_rng;
 use actix::Actor as _;
 use actix::ActorContext as _;
+use near_primitives::network::PeerId;
 use rand::Rng as _;
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -49,13 +51,13 @@ struct Handler {
 }
 
 impl Actor {
-    async fn spawn(s: tcp::Stream) -> Handler {
+    async fn spawn(s: tcp::Stream, encryption: Option<noise::Setup>) -> Handler {
         let (queue_send, queue_recv) = mpsc::unbounded_channel();
         Handler {
             queue_recv,
             system: ActixSystem::spawn(|| {
                 Actor::create(|ctx| {
-                    let stream = stream::FramedStream::spawn(ctx, s, Arc::default());
+                    let stream = stream::FramedStream::spawn(ctx, s, Arc::default(), encryption);
                     Self { stream, queue_send }
                 })
             })
@@ -64,12 +66,15 @@ impl Actor {
     }
 }
 
-#[tokio::test]
-async fn send_recv() {
+async fn send_recv(mode: noise::Mode) {
     let mut rng = make_rng(98324532);
-    let (s1, s2) = tcp::Stream::loopback(data::make_peer_id(&mut rng), tcp::Tier::T2).await;
-    let a1 = Actor::spawn(s1).await;
-    let mut a2 = Actor::spawn(s2).await;
+    let key1 = data::make_secret_key(&mut rng);
+    let key2 = data::make_secret_key(&mut rng);
+    let (s1, s2) = tcp::Stream::loopback(PeerId::new(key2.public_key()), tcp::Tier::T2).await;
+    let e1 = noise::Setup::new(mode, &key1, &s1.type_, &Arc::default());
+    let e2 = noise::Setup::new(mode, &key2, &s2.type_, &Arc::default());
+    let a1 = Actor::spawn(s1, e1).await;
+    let mut a2 = Actor::spawn(s2, e2).await;
 
     for _ in 0..5 {
         let n = rng.gen_range(1..10);
@@ -90,3 +95,13 @@ async fn send_recv() {
         }
     }
 }
+
+#[tokio::test]
+async fn send_recv_plaintext() {
+    send_recv(noise::Mode::Disabled).await;
+}
+
+#[tokio::test]
+async fn send_recv_encrypted() {
+    send_recv(noise::Mode::Required).await;
+}
@@ -1,6 +1,8 @@
 use crate::peer::transfer_stats::TransferStats;
+use lru::LruCache;
 use near_async::time;
 use near_primitives::hash::CryptoHash;
+use std::num::NonZeroUsize;
 
 /// Maximum number of requests and responses to track.
 const MAX_TRACK_SIZE: usize = 30;
@@ -47,6 +49,8 @@ pub(crate) struct Tracker {
     pub(crate) received_bytes: TransferStats,
     /// Sent requests.
     requested: CircularUniqueQueue,
+    /// Time at which the requests still awaiting a response were sent.
+    request_times: LruCache<CryptoHash, time::Instant>,
     /// Received elements.
     received: CircularUniqueQueue,
 }
@@ -57,6 +61,7 @@ impl Default for Tracker {
             sent_bytes: TransferStats::default(),
             received_bytes: TransferStats::default(),
             requested: CircularUniqueQueue::new(MAX_TRACK_SIZE),
+            request_times: LruCache::new(NonZeroUsize::new(MAX_TRACK_SIZE).unwrap()),
             received: CircularUniqueQueue::new(MAX_TRACK_SIZE),
         }
     }
@@ -86,8 +91,19 @@ impl Tracker {
         self.requested.contains(hash)
     }
 
-    pub(crate) fn push_request(&mut self, hash: CryptoHash) {
+    pub(crate) fn push_request(&mut self, clock: &time::Clock, hash: CryptoHash) {
         self.requested.push(hash);
+        self.request_times.put(hash, clock.now());
+    }
+
+    /// Returns the time elapsed since `hash` was requested, if the request is still tracked.
+    /// Each request is accounted for at most once.
+    pub(crate) fn take_response_latency(
+        &mut self,
+        clock: &time::Clock,
+        hash: &CryptoHash,
+    ) -> Option<time::Duration> {
+        self.request_times.pop(hash).map(|requested_at| clock.now() - requested_at)
     }
 }
 
@@ -149,6 +165,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_response_latency() {
+        let clock = time::FakeClock::default();
+        let mut tracker = Tracker::default();
+        tracker.push_request(&clock.clock(), hash(&[1]));
+        clock.advance(time::Duration::seconds(3));
+
+        assert_eq!(tracker.take_response_latency(&clock.clock(), &hash(&[2])), None);
+        assert_eq!(
+            tracker.take_response_latency(&clock.clock(), &hash(&[1])),
+            Some(time::Duration::seconds(3))
+        );
+        assert_eq!(tracker.take_response_latency(&clock.clock(), &hash(&[1])), None);
+        assert!(tracker.has_request(&hash(&[1])));
+    }
+
     #[test]
     fn test_circular_queue_similar_inputs() {
         let mut q = CircularUniqueQueue::new(5);
@@ -17,7 +17,7 @@ use near_crypto::PublicKey;
 use near_o11y::WithSpanContextExt;
 use near_primitives::genesis::GenesisId;
 use near_primitives::network::PeerId;
-use near_primitives::types::ShardId;
+use near_primitives::types::{BlockHeight, ShardId};
 use std::collections::{HashMap, hash_map::Entry};
 use std::fmt;
 use std::future::Future;
@@ -150,6 +150,9 @@ pub(crate) struct Connection {
 
     /// Last time requested peers.
     pub last_time_peer_requested: AtomicCell<Option<time::Instant>>,
+    /// Tip height of the latest block headers request sent to the peer which is still awaiting
+    /// a response.
+    pub pending_headers_request: AtomicCell<Option<BlockHeight>>,
     /// Last time we received a message from this peer.
     pub last_time_received_message: AtomicCell<time::Instant>,
     /// Connection stats
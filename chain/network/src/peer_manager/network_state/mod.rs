@@ -1,5 +1,6 @@
 use crate::accounts_data::{AccountDataCache, AccountDataError};
 use crate::announce_accounts::AnnounceAccountCache;
+use crate::capture;
 use crate::client::{
     BlockApproval, ChunkEndorsementMessage, ClientSenderForNetwork, ProcessTxRequest,
     TxStatusRequest, TxStatusResponse,
@@ -11,12 +12,15 @@ use crate::network_protocol::{
     Edge, EdgeState, PartialEdgeInfo, PeerIdOrHash, PeerInfo, PeerMessage, RawRoutedMessage,
     RoutedMessageBody, RoutedMessageV2, SignedAccountData, SnapshotHostInfo,
 };
+use crate::noise;
 use crate::peer::peer_actor::ClosingReason;
 use crate::peer::peer_actor::PeerActor;
 use crate::peer_manager::connection;
 use crate::peer_manager::connection_store;
 use crate::peer_manager::peer_store;
+use crate::peer_manager::reputation;
 use crate::private_actix::RegisterPeerError;
+use crate::quic;
 #[cfg(feature = "distance_vector_routing")]
 use crate::routing::NetworkTopologyChange;
 use crate::routing::route_back_cache::RouteBackCache;
@@ -132,6 +136,14 @@ pub(crate) struct NetworkState {
     pub snapshot_hosts: Arc<SnapshotHostsCache>,
     /// Connection store that provides read/write access to stored connections.
     pub connection_store: connection_store::ConnectionStore,
+    /// Reputation scores of the peers.
+    pub peer_reputations: reputation::PeerReputations,
+    /// Peers known not to support encryption of the TCP connections.
+    pub plaintext_peers: Arc<noise::PlaintextPeers>,
+    /// QUIC endpoint, set once PeerManagerActor starts if `config.quic_addr` is set.
+    pub quic: std::sync::OnceLock<quic::Endpoint>,
+    /// Network traffic capture, set once PeerManagerActor starts if `config.capture` is set.
+    pub capture: std::sync::OnceLock<capture::Writer>,
     /// List of peers to which we should re-establish a connection
     pub pending_reconnect: Mutex<Vec<PeerInfo>>,
     /// A graph of the whole NEAR network.
@@ -214,6 +226,10 @@ impl NetworkState {
             peer_store,
             snapshot_hosts: Arc::new(SnapshotHostsCache::new(config.snapshot_hosts.clone())),
             connection_store: connection_store::ConnectionStore::new(store.clone()).unwrap(),
+            peer_reputations: reputation::PeerReputations::new(config.peer_reputation.clone()),
+            plaintext_peers: Arc::default(),
+            quic: std::sync::OnceLock::new(),
+            capture: std::sync::OnceLock::new(),
             pending_reconnect: Mutex::new(Vec::<PeerInfo>::new()),
             accounts_data: Arc::new(AccountDataCache::new()),
             account_announcements: Arc::new(AnnounceAccountCache::new(store)),
@@ -497,6 +513,19 @@ impl NetworkState {
         }
     }
 
+    /// Attaches an inbound QUIC connection to the established TIER2 connection with the peer
+    /// which opened it. `hello` has to refer to the edge of that connection.
+    pub fn attach_quic(&self, hello: quic::Hello, conn: quinn::Connection) -> anyhow::Result<()> {
+        anyhow::ensure!(hello.target == self.config.node_id(), "Hello addressed to another node");
+        let Some(tcp_conn) = self.tier2.load().ready.get(&hello.sender).cloned() else {
+            anyhow::bail!("no TIER2 connection with {}", hello.sender);
+        };
+        let nonce = self.graph.load().local_edges.get(&hello.sender).map(|edge| edge.nonce());
+        anyhow::ensure!(nonce == Some(hello.nonce), "Hello nonce doesn't match the edge nonce");
+        tcp_conn.addr.do_send(quic::Attach(conn));
+        Ok(())
+    }
+
     /// Determine if the given target is referring to us.
     pub fn message_for_me(&self, target: &PeerIdOrHash) -> bool {
         let my_peer_id = self.config.node_id();
@@ -1,3 +1,4 @@
+use crate::capture;
 use crate::client::{ClientSenderForNetwork, SetNetworkInfo, StateRequestHeader, StateRequestPart};
 use crate::config;
 use crate::debug::{DebugStatus, GetDebugStatus};
@@ -11,6 +12,7 @@ use crate::peer::peer_actor::PeerActor;
 use crate::peer_manager::connection;
 use crate::peer_manager::network_state::{NetworkState, WhitelistNode};
 use crate::peer_manager::peer_store;
+use crate::quic;
 use crate::shards_manager::ShardsManagerRequestFromNetwork;
 use crate::state_witness::PartialWitnessSenderForNetwork;
 use crate::stats::metrics;
@@ -138,6 +140,8 @@ pub enum Event {
     HandshakeCompleted(crate::peer::peer_actor::HandshakeCompletedEvent),
     // Reported when the TCP connection has been closed.
     ConnectionClosed(crate::peer::peer_actor::ConnectionClosedEvent),
+    // Reported when a QUIC connection has been attached to a TIER2 connection.
+    QuicAttached(crate::peer::peer_actor::QuicAttachedEvent),
 }
 
 impl actix::Actor for PeerManagerActor {
@@ -251,6 +255,19 @@ impl PeerManagerActor {
             partial_witness_adapter,
             whitelist_nodes,
         ));
+        if let Some(cfg) = state.config.capture.clone() {
+            tracing::info!(target: "network", dir = ?cfg.dir, "capturing network traffic");
+            let writer = capture::Writer::spawn(cfg).context("capture::Writer::spawn")?;
+            let _ = state.capture.set(writer);
+        }
+        // Bind the QUIC socket upfront, so that a misconfigured quic_addr is reported to the caller.
+        let quic_socket = match state.config.quic_addr {
+            Some(addr) => Some(
+                std::net::UdpSocket::bind(addr)
+                    .with_context(|| format!("failed to bind QUIC socket to quic_addr={addr}"))?,
+            ),
+            None => None,
+        };
         arbiter.spawn({
             let arbiter = arbiter.clone();
             let state = state.clone();
@@ -289,6 +306,38 @@ impl PeerManagerActor {
                         }
                     });
                 }
+                let quic_endpoint = quic_socket.and_then(|socket| match quic::Endpoint::new(socket) {
+                    Ok(endpoint) => Some(endpoint),
+                    Err(err) => {
+                        tracing::error!(target: "network", ?err, "failed to start QUIC endpoint, using TCP only");
+                        None
+                    }
+                });
+                if let Some(endpoint) = quic_endpoint {
+                    tracing::debug!(target: "network", at = ?endpoint.local_addr(), "started QUIC endpoint");
+                    let _ = state.quic.set(endpoint.clone());
+                    arbiter.spawn({
+                        let state = state.clone();
+                        async move {
+                            while let Some(incoming) = endpoint.accept().await {
+                                let state = state.clone();
+                                tokio::spawn(async move {
+                                    let from = incoming.remote_address();
+                                    let result = async {
+                                        let (hello, conn) =
+                                            quic::accept_hello(incoming, &state.config.node_key)
+                                                .await?;
+                                        state.attach_quic(hello, conn)
+                                    }
+                                    .await;
+                                    if let Err(err) = result {
+                                        tracing::debug!(target: "network", ?from, ?err, "rejected QUIC connection");
+                                    }
+                                });
+                            }
+                        }
+                    });
+                }
                 if let Some(cfg) = state.config.tier1.clone() {
                     // Connect to TIER1 proxies and broadcast the list those connections periodically.
                     arbiter.spawn({
@@ -654,8 +703,12 @@ impl PeerManagerActor {
         self.stop_tier3_idle_connections();
 
         // Find peers that are not reliable (too much behind) - and make sure that we're not routing messages through them.
-        let unreliable_peers = self.unreliable_peers();
+        let mut unreliable_peers = self.unreliable_peers();
         metrics::PEER_UNRELIABLE.set(unreliable_peers.len() as i64);
+        // Peers with a bad reputation are avoided as well.
+        let low_reputation_peers = self.state.peer_reputations.low_reputation_peers(&self.clock);
+        metrics::PEER_LOW_REPUTATION.set(low_reputation_peers.len() as i64);
+        unreliable_peers.extend(low_reputation_peers);
         self.state.set_unreliable_peers(unreliable_peers);
 
         let new_interval = min(max_interval, interval * EXPONENTIAL_BACKOFF_RATIO);
@@ -706,6 +759,11 @@ impl PeerManagerActor {
                 Some(e) => e.nonce(),
                 None => 0,
             },
+            reputation_score: self
+                .state
+                .peer_reputations
+                .score(&self.clock, &cp.peer_info.id)
+                .round() as i64,
         };
         NetworkInfo {
             connected_peers: tier2.ready.values().map(connected_peer).collect(),
@@ -807,7 +865,10 @@ impl PeerManagerActor {
                     NetworkResponses::RouteNotFound
                 }
             }
-            NetworkRequests::BlockHeadersRequest { hashes, peer_id } => {
+            NetworkRequests::BlockHeadersRequest { hashes, peer_id, tip_height } => {
+                if let Some(conn) = self.state.tier2.load().ready.get(&peer_id) {
+                    conn.pending_headers_request.store(Some(tip_height));
+                }
                 if self
                     .state
                     .tier2
@@ -0,0 +1,219 @@
+//! Reputation scoring of the connected peers.
+//!
+//! Every peer starts with a neutral score of 0. Misbehavior (invalid messages, exceeding rate
+//! limits, useless or slow responses) lowers the score, while the score slowly recovers back
+//! towards 0 over time. The score is used to:
+//! * shrink the rate limit buckets of the peer, proportionally to how bad its reputation is,
+//! * avoid routing messages through the peer once its score drops below `deprioritize_threshold`,
+//! * ban the peer once its score drops below `ban_threshold`.
+use crate::stats::metrics;
+use crate::types::ReasonForBan;
+use near_async::time;
+use near_primitives::network::PeerId;
+use parking_lot::Mutex;
+use std::collections::{HashMap, HashSet};
+
+#[cfg(test)]
+mod tests;
+
+/// Events affecting the reputation of a peer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum::IntoStaticStr)]
+pub(crate) enum ReputationEvent {
+    /// Peer sent data which could not be parsed as a `PeerMessage`.
+    InvalidMessage,
+    /// Peer sent a message which exceeded the rate limits.
+    RateLimited,
+    /// Peer sent a response without any useful content.
+    UselessResponse,
+    /// Peer responded to our request after the given time.
+    Latency(time::Duration),
+}
+
+/// Configuration of the peer reputation scoring.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    /// Score penalty for sending data which can't be parsed.
+    pub invalid_message_penalty: f64,
+    /// Score penalty for every message dropped by the rate limiter.
+    pub rate_limited_penalty: f64,
+    /// Score penalty for sending a response without useful content.
+    pub useless_response_penalty: f64,
+    /// Responses slower than this are considered slow.
+    #[serde(with = "near_async::time::serde_duration_as_std")]
+    pub slow_response_threshold: time::Duration,
+    /// Score penalty for a slow response.
+    pub slow_response_penalty: f64,
+    /// How many points per second the score recovers towards 0.
+    pub recovery_per_sec: f64,
+    /// Peers with a score below this threshold are not used to route messages.
+    pub deprioritize_threshold: f64,
+    /// Peers with a score below this threshold are banned.
+    /// Must be lower than `deprioritize_threshold`.
+    pub ban_threshold: f64,
+    /// Rate limit buckets of a peer are scaled linearly from their full size at score 0
+    /// down to this factor at `ban_threshold`.
+    pub min_rate_limit_factor: f32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            invalid_message_penalty: 5.,
+            rate_limited_penalty: 1.,
+            useless_response_penalty: 2.,
+            slow_response_threshold: time::Duration::seconds(5),
+            slow_response_penalty: 1.,
+            recovery_per_sec: 0.1,
+            deprioritize_threshold: -50.,
+            ban_threshold: -100.,
+            min_rate_limit_factor: 0.1,
+        }
+    }
+}
+
+impl Config {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for (name, value) in [
+            ("invalid_message_penalty", self.invalid_message_penalty),
+            ("rate_limited_penalty", self.rate_limited_penalty),
+            ("useless_response_penalty", self.useless_response_penalty),
+            ("slow_response_penalty", self.slow_response_penalty),
+            ("recovery_per_sec", self.recovery_per_sec),
+        ] {
+            if !(value >= 0.) {
+                anyhow::bail!("{name}({value}) must be non-negative");
+            }
+        }
+        if !(self.ban_threshold < self.deprioritize_threshold && self.deprioritize_threshold < 0.) {
+            anyhow::bail!(
+                "expected ban_threshold({}) < deprioritize_threshold({}) < 0",
+                self.ban_threshold,
+                self.deprioritize_threshold
+            );
+        }
+        if !(self.min_rate_limit_factor > 0. && self.min_rate_limit_factor <= 1.) {
+            anyhow::bail!(
+                "min_rate_limit_factor({}) must be in range (0, 1]",
+                self.min_rate_limit_factor
+            );
+        }
+        Ok(())
+    }
+
+    fn penalty(&self, event: ReputationEvent) -> f64 {
+        match event {
+            ReputationEvent::InvalidMessage => self.invalid_message_penalty,
+            ReputationEvent::RateLimited => self.rate_limited_penalty,
+            ReputationEvent::UselessResponse => self.useless_response_penalty,
+            ReputationEvent::Latency(latency) if latency > self.slow_response_threshold => {
+                self.slow_response_penalty
+            }
+            ReputationEvent::Latency(_) => 0.,
+        }
+    }
+}
+
+/// Outcome of reporting an event for a peer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ReportOutcome {
+    /// Factor by which the rate limit buckets of the peer should be scaled.
+    pub rate_limit_factor: f32,
+    /// Set if the peer should be banned.
+    pub ban: Option<ReasonForBan>,
+}
+
+struct Score {
+    value: f64,
+    updated_at: time::Instant,
+}
+
+impl Score {
+    /// Applies the recovery accumulated since the last update.
+    fn recover(&mut self, config: &Config, now: time::Instant) {
+        if now <= self.updated_at {
+            return;
+        }
+        let elapsed = (now - self.updated_at).as_seconds_f64();
+        self.value = (self.value + elapsed * config.recovery_per_sec).min(0.);
+        self.updated_at = now;
+    }
+}
+
+/// Reputation scores of peers, shared between all PeerActors.
+pub(crate) struct PeerReputations {
+    config: Config,
+    scores: Mutex<HashMap<PeerId, Score>>,
+}
+
+impl PeerReputations {
+    pub fn new(config: Config) -> Self {
+        Self { config, scores: Mutex::default() }
+    }
+
+    fn rate_limit_factor(&self, score: f64) -> f32 {
+        let badness = (score / self.config.ban_threshold).clamp(0., 1.) as f32;
+        1. - badness * (1. - self.config.min_rate_limit_factor)
+    }
+
+    /// Updates the score of `peer_id` according to `event`.
+    pub fn report(
+        &self,
+        clock: &time::Clock,
+        peer_id: &PeerId,
+        event: ReputationEvent,
+    ) -> ReportOutcome {
+        let penalty = self.config.penalty(event);
+        let now = clock.now();
+        let mut scores = self.scores.lock();
+        let score = scores.entry(peer_id.clone()).or_insert(Score { value: 0., updated_at: now });
+        score.recover(&self.config, now);
+        if penalty > 0. {
+            metrics::PEER_REPUTATION_EVENTS.with_label_values(&[<&'static str>::from(event)]).inc();
+            score.value -= penalty;
+        }
+        let value = score.value;
+        if value > self.config.ban_threshold {
+            return ReportOutcome { rate_limit_factor: self.rate_limit_factor(value), ban: None };
+        }
+        tracing::info!(target: "network", %peer_id, score = value, "peer reputation dropped below the ban threshold");
+        // The peer gets a fresh start once its ban expires.
+        scores.remove(peer_id);
+        ReportOutcome { rate_limit_factor: 1., ban: Some(ReasonForBan::Abusive) }
+    }
+
+    /// Factor by which the rate limit buckets of `peer_id` should currently be scaled.
+    /// Unlike the factor returned by `report`, it accounts for the recovery of the score
+    /// since the last event.
+    pub fn rate_limit_factor_of(&self, clock: &time::Clock, peer_id: &PeerId) -> f32 {
+        self.rate_limit_factor(self.score(clock, peer_id))
+    }
+
+    /// Current score of `peer_id`.
+    pub fn score(&self, clock: &time::Clock, peer_id: &PeerId) -> f64 {
+        let mut scores = self.scores.lock();
+        match scores.get_mut(peer_id) {
+            Some(score) => {
+                score.recover(&self.config, clock.now());
+                score.value
+            }
+            None => 0.,
+        }
+    }
+
+    /// Returns the peers which should be avoided when routing messages.
+    /// Forgets about the peers whose score has fully recovered.
+    pub fn low_reputation_peers(&self, clock: &time::Clock) -> HashSet<PeerId> {
+        let now = clock.now();
+        let mut scores = self.scores.lock();
+        scores.retain(|_, score| {
+            score.recover(&self.config, now);
+            score.value < 0.
+        });
+        scores
+            .iter()
+            .filter(|(_, score)| score.value < self.config.deprioritize_threshold)
+            .map(|(peer_id, _)| peer_id.clone())
+            .collect()
+    }
+}
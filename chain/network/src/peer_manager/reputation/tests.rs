@@ -0,0 +1,113 @@
+use crate::peer_manager::reputation::{Config, PeerReputations, ReputationEvent};
+use crate::types::ReasonForBan;
+use near_async::time;
+use near_primitives::network::PeerId;
+
+fn test_config() -> Config {
+    Config {
+        invalid_message_penalty: 10.,
+        rate_limited_penalty: 1.,
+        useless_response_penalty: 2.,
+        slow_response_threshold: time::Duration::seconds(1),
+        slow_response_penalty: 5.,
+        recovery_per_sec: 1.,
+        deprioritize_threshold: -20.,
+        ban_threshold: -40.,
+        min_rate_limit_factor: 0.2,
+    }
+}
+
+#[test]
+fn penalties_and_recovery() {
+    let clock = time::FakeClock::default();
+    let reputations = PeerReputations::new(test_config());
+    let peer = PeerId::random();
+
+    reputations.report(&clock.clock(), &peer, ReputationEvent::InvalidMessage);
+    reputations.report(&clock.clock(), &peer, ReputationEvent::UselessResponse);
+    reputations.report(&clock.clock(), &peer, ReputationEvent::RateLimited);
+    assert_eq!(reputations.score(&clock.clock(), &peer), -13.);
+
+    // Fast responses don't affect the score, slow ones do.
+    reputations.report(&clock.clock(), &peer, ReputationEvent::Latency(time::Duration::ZERO));
+    assert_eq!(reputations.score(&clock.clock(), &peer), -13.);
+    reputations.report(&clock.clock(), &peer, ReputationEvent::Latency(time::Duration::seconds(2)));
+    assert_eq!(reputations.score(&clock.clock(), &peer), -18.);
+
+    // The score recovers over time, but never above 0.
+    clock.advance(time::Duration::seconds(8));
+    assert_eq!(reputations.score(&clock.clock(), &peer), -10.);
+    clock.advance(time::Duration::seconds(100));
+    assert_eq!(reputations.score(&clock.clock(), &peer), 0.);
+
+    // Unknown peers have a neutral score.
+    assert_eq!(reputations.score(&clock.clock(), &PeerId::random()), 0.);
+}
+
+#[test]
+fn rate_limit_factor() {
+    let clock = time::FakeClock::default();
+    let reputations = PeerReputations::new(test_config());
+    let peer = PeerId::random();
+
+    let outcome = reputations.report(&clock.clock(), &peer, ReputationEvent::RateLimited);
+    assert!(outcome.rate_limit_factor < 1.);
+    assert!(outcome.ban.is_none());
+
+    for _ in 0..3 {
+        reputations.report(&clock.clock(), &peer, ReputationEvent::InvalidMessage);
+    }
+    // Score is -32, which is past the deprioritize threshold but above the ban threshold.
+    let outcome = reputations.report(&clock.clock(), &peer, ReputationEvent::RateLimited);
+    assert!(outcome.ban.is_none());
+    assert!(outcome.rate_limit_factor > 0.2 && outcome.rate_limit_factor < 0.5);
+    assert_eq!(reputations.rate_limit_factor_of(&clock.clock(), &peer), outcome.rate_limit_factor);
+
+    // The factor grows back as the score recovers, without any further events.
+    clock.advance(time::Duration::seconds(16));
+    let factor = reputations.rate_limit_factor_of(&clock.clock(), &peer);
+    assert!(factor > outcome.rate_limit_factor && factor < 1.);
+    clock.advance(time::Duration::seconds(100));
+    assert_eq!(reputations.rate_limit_factor_of(&clock.clock(), &peer), 1.);
+    assert_eq!(reputations.rate_limit_factor_of(&clock.clock(), &PeerId::random()), 1.);
+}
+
+#[test]
+fn low_reputation_peers_and_ban() {
+    let clock = time::FakeClock::default();
+    let reputations = PeerReputations::new(test_config());
+    let good = PeerId::random();
+    let bad = PeerId::random();
+
+    reputations.report(&clock.clock(), &good, ReputationEvent::RateLimited);
+    for _ in 0..3 {
+        let outcome = reputations.report(&clock.clock(), &bad, ReputationEvent::InvalidMessage);
+        assert_eq!(outcome.ban, None);
+    }
+    assert_eq!(reputations.low_reputation_peers(&clock.clock()), [bad.clone()].into());
+
+    // Once recovered, peers are no longer deprioritized.
+    clock.advance(time::Duration::seconds(15));
+    assert!(reputations.low_reputation_peers(&clock.clock()).is_empty());
+
+    // Crossing the ban threshold bans the peer and resets its score.
+    for _ in 0..2 {
+        reputations.report(&clock.clock(), &bad, ReputationEvent::InvalidMessage);
+    }
+    let outcome = reputations.report(&clock.clock(), &bad, ReputationEvent::InvalidMessage);
+    assert_eq!(outcome.ban, Some(ReasonForBan::Abusive));
+    assert_eq!(reputations.score(&clock.clock(), &bad), 0.);
+}
+
+#[test]
+fn config_validation() {
+    assert!(Config::default().validate().is_ok());
+    assert!(test_config().validate().is_ok());
+    assert!(Config { ban_threshold: -10., ..test_config() }.validate().is_err());
+    assert!(Config { deprioritize_threshold: 5., ..test_config() }.validate().is_err());
+    assert!(Config { rate_limited_penalty: -1., ..test_config() }.validate().is_err());
+    assert!(Config { min_rate_limit_factor: 0., ..test_config() }.validate().is_err());
+    assert!(Config { min_rate_limit_factor: 1.5, ..test_config() }.validate().is_err());
+    let err = Config { recovery_per_sec: -1., ..test_config() }.validate().unwrap_err();
+    assert_eq!(err.to_string(), "recovery_per_sec(-1) must be non-negative");
+}
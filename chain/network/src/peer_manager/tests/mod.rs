@@ -2,6 +2,8 @@ mod accounts_data;
 mod connection_pool;
 mod fuzzers;
 mod nonce;
+mod quic;
+mod reputation;
 mod routing;
 mod snapshot_hosts;
 mod tier1;
@@ -68,6 +68,7 @@ async fn test_nonces() {
             // we have to set this even if we have no intention of listening since otherwise
             // the peer will drop our connection
             sender_listen_port: Some(24567),
+            sender_quic_port: None,
             sender_chain_info: chain.get_peer_chain_info(),
             partial_edge_info: PartialEdgeInfo::new(&peer_id, &pm.cfg.node_id(), test.0, &peer_key),
             owned_account: None,
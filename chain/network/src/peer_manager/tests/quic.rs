@@ -0,0 +1,127 @@
+use crate::broadcast;
+use crate::network_protocol::testonly as data;
+use crate::network_protocol::{PeerIdOrHash, PeerMessage, RawRoutedMessage, RoutedMessageBody};
+use crate::peer_manager;
+use crate::peer_manager::peer_manager_actor::Event as PME;
+use crate::peer_manager::testonly::Event;
+use crate::peer_manager::testonly::start as start_pm;
+use crate::tcp;
+use crate::testonly::{Rng, make_rng};
+use near_async::time;
+use near_o11y::testonly::init_test_logger;
+use near_primitives::block_header::{Approval, ApprovalInner};
+use near_primitives::network::PeerId;
+use near_store::db::TestDB;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+fn make_block_approval(rng: &mut Rng) -> RoutedMessageBody {
+    let signer = data::make_validator_signer(rng);
+    let inner = ApprovalInner::Endorsement(data::make_hash(rng));
+    RoutedMessageBody::BlockApproval(Approval {
+        signature: signer.sign_bytes(&Approval::get_data_for_sig(&inner, 1)),
+        account_id: signer.validator_id().clone(),
+        target_height: 1,
+        inner,
+    })
+}
+
+/// Waits until QUIC connections with all `peers` are attached.
+async fn wait_for_quic(events: &mut broadcast::Receiver<Event>, peers: &[PeerId]) {
+    let mut want: HashSet<_> = peers.iter().cloned().collect();
+    while !want.is_empty() {
+        let peer_id = events
+            .recv_until(|ev| match ev {
+                Event::PeerManager(PME::QuicAttached(ev)) => Some(ev.peer_id),
+                _ => None,
+            })
+            .await;
+        want.remove(&peer_id);
+    }
+}
+
+/// Sends a routed message from `from` to `to` and waits until `to` receives it.
+async fn send_and_recv(
+    rng: &mut Rng,
+    clock: &time::Clock,
+    from: &peer_manager::testonly::ActorHandler,
+    to: &peer_manager::testonly::ActorHandler,
+) {
+    let mut events = to.events.from_now();
+    let want = make_block_approval(rng);
+    let target = PeerIdOrHash::PeerId(to.cfg.node_id());
+    let clock = clock.clone();
+    let body = want.clone();
+    let sent = from
+        .with_state(move |s| async move {
+            let msg = s.sign_message(&clock, RawRoutedMessage { target, body });
+            s.send_message_to_peer(&clock, tcp::Tier::T2, msg)
+        })
+        .await;
+    assert!(sent, "routing info not available");
+    let got = events
+        .recv_until(|ev| match ev {
+            Event::PeerManager(PME::MessageProcessed(tcp::Tier::T2, PeerMessage::Routed(got))) => {
+                Some(got)
+            }
+            _ => None,
+        })
+        .await;
+    assert_eq!(from.cfg.node_id(), got.author);
+    assert_eq!(want, got.body);
+}
+
+/// TIER2 connections between nodes with QUIC enabled get a QUIC connection attached, and the
+/// consensus messages routed over them reach their target, also through nodes without QUIC.
+#[tokio::test]
+async fn multiple_nodes() {
+    init_test_logger();
+    let mut rng = make_rng(921853233);
+    let rng = &mut rng;
+    let mut clock = time::FakeClock::default();
+    let chain = Arc::new(data::Chain::make(&mut clock, rng, 10));
+
+    let mut pms = vec![];
+    for _ in 0..3 {
+        let mut cfg = chain.make_config(rng);
+        cfg.quic_addr = Some("127.0.0.1:0".parse().unwrap());
+        pms.push(start_pm(clock.clock(), TestDB::new(), cfg, chain.clone()).await);
+    }
+    let pm3 = start_pm(clock.clock(), TestDB::new(), chain.make_config(rng), chain.clone()).await;
+    let [pm0, pm1, pm2] = &pms[..] else { unreachable!() };
+    let id0 = pm0.cfg.node_id();
+    let id1 = pm1.cfg.node_id();
+    let id2 = pm2.cfg.node_id();
+    let id3 = pm3.cfg.node_id();
+
+    tracing::info!(target:"test", "connect the nodes in a line: pm3 - pm0 - pm1 - pm2");
+    let mut events: Vec<_> = pms.iter().map(|pm| pm.events.from_now()).collect();
+    pm0.connect_to(&pm1.peer_info(), tcp::Tier::T2).await;
+    pm1.connect_to(&pm2.peer_info(), tcp::Tier::T2).await;
+    pm3.connect_to(&pm0.peer_info(), tcp::Tier::T2).await;
+
+    tracing::info!(target:"test", "wait for the QUIC connections on both sides");
+    wait_for_quic(&mut events[0], &[id1.clone()]).await;
+    wait_for_quic(&mut events[1], &[id0.clone(), id2.clone()]).await;
+    wait_for_quic(&mut events[2], &[id1.clone()]).await;
+
+    tracing::info!(target:"test", "wait for the routing tables");
+    pm3.wait_for_routing_table(&[
+        (id0.clone(), vec![id0.clone()]),
+        (id1.clone(), vec![id0.clone()]),
+        (id2.clone(), vec![id0.clone()]),
+    ])
+    .await;
+    pm2.wait_for_routing_table(&[
+        (id0.clone(), vec![id1.clone()]),
+        (id1.clone(), vec![id1.clone()]),
+        (id3.clone(), vec![id1.clone()]),
+    ])
+    .await;
+
+    tracing::info!(target:"test", "route messages across the whole line, in both directions");
+    send_and_recv(rng, &clock.clock(), pm0, pm2).await;
+    send_and_recv(rng, &clock.clock(), pm2, pm0).await;
+    send_and_recv(rng, &clock.clock(), &pm3, pm2).await;
+    send_and_recv(rng, &clock.clock(), pm2, &pm3).await;
+}
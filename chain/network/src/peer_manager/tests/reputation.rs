@@ -0,0 +1,79 @@
+use crate::network_protocol::testonly as data;
+use crate::peer::testonly::PeerHandle;
+use crate::peer_manager::peer_manager_actor::Event as PME;
+use crate::peer_manager::testonly::{ActorHandler, Event, start as start_pm};
+use crate::testonly::make_rng;
+use crate::types::{NetworkRequests, PeerManagerMessageRequest, PeerMessage};
+use near_async::time;
+use near_o11y::WithSpanContextExt;
+use near_o11y::testonly::init_test_logger;
+use near_primitives::network::PeerId;
+use near_primitives::types::BlockHeight;
+use near_store::db::TestDB;
+use std::sync::Arc;
+
+async fn wait_for_message_processed(pm: &ActorHandler, peer: &PeerHandle, msg: PeerMessage) {
+    let mut events = pm.events.from_now();
+    peer.send(msg.clone()).await;
+    events
+        .recv_until(|ev| match ev {
+            Event::PeerManager(PME::MessageProcessed(_, got)) if got == msg => Some(()),
+            _ => None,
+        })
+        .await;
+}
+
+async fn request_headers(pm: &ActorHandler, peer_id: &PeerId, tip_height: BlockHeight) {
+    let message =
+        PeerManagerMessageRequest::NetworkRequests(NetworkRequests::BlockHeadersRequest {
+            hashes: vec![],
+            peer_id: peer_id.clone(),
+            tip_height,
+        });
+    pm.actix.addr.send(message.with_span_context()).await.unwrap();
+}
+
+async fn score(pm: &ActorHandler, clock: &time::Clock, peer_id: &PeerId) -> f64 {
+    let clock = clock.clone();
+    let peer_id = peer_id.clone();
+    pm.with_state(move |s| async move { s.peer_reputations.score(&clock, &peer_id) }).await
+}
+
+/// An empty headers response is penalized only if the peer has told us about
+/// blocks past the tip we requested the headers from.
+#[tokio::test]
+async fn empty_headers_response() {
+    init_test_logger();
+    let mut rng = make_rng(921853233);
+    let rng = &mut rng;
+    let mut clock = time::FakeClock::default();
+    let chain = Arc::new(data::Chain::make(&mut clock, rng, 10));
+    let clock = clock.clock();
+
+    let pm = start_pm(clock.clone(), TestDB::new(), chain.make_config(rng), chain.clone()).await;
+    let peer =
+        pm.start_inbound(chain.clone(), chain.make_config(rng)).await.handshake(&clock).await;
+    let peer_id = peer.cfg.id();
+
+    tracing::info!(target:"test", "peer announces its head");
+    wait_for_message_processed(
+        &pm,
+        &peer,
+        PeerMessage::Block(chain.blocks.last().unwrap().clone()),
+    )
+    .await;
+
+    tracing::info!(target:"test", "empty response to a request past the peer's head");
+    request_headers(&pm, &peer_id, chain.height()).await;
+    wait_for_message_processed(&pm, &peer, PeerMessage::BlockHeaders(vec![])).await;
+    assert_eq!(score(&pm, &clock, &peer_id).await, 0.);
+
+    tracing::info!(target:"test", "unsolicited empty response");
+    wait_for_message_processed(&pm, &peer, PeerMessage::BlockHeaders(vec![])).await;
+    assert_eq!(score(&pm, &clock, &peer_id).await, 0.);
+
+    tracing::info!(target:"test", "empty response to a request below the peer's head");
+    request_headers(&pm, &peer_id, chain.height() - 1).await;
+    wait_for_message_processed(&pm, &peer, PeerMessage::BlockHeaders(vec![])).await;
+    assert!(score(&pm, &clock, &peer_id).await < 0.);
+}
@@ -0,0 +1,496 @@
+//! Optional QUIC transport for TIER2 connections.
+//!
+//! A TCP connection delivers all messages in order over a single byte stream, so a large
+//! message (e.g. a state witness part) delays every message queued after it. When both peers
+//! advertise a QUIC port in their handshake, the outbound peer additionally opens a QUIC
+//! connection to the inbound peer, and afterwards both sides send the latency sensitive
+//! messages over it, every `MessageClass` over a separate stream.
+//!
+//! The TCP connection remains the primary one: the handshake, the control messages and
+//! everything not covered by `MessageClass` still go over TCP, and closing the TCP connection
+//! closes the QUIC connection as well. If the QUIC connection breaks, messages are sent over
+//! TCP again.
+//!
+//! Certificates are self-signed and are not verified. Instead, right after the TLS handshake
+//! both peers send a `Hello` signed with their node key over keying material exported from the
+//! TLS session, and the QUIC connection is attached only to an already established TCP
+//! connection with the peer which opened it. A man in the middle terminating TLS ends up with a
+//! different session on each side, so it can't relay the `Hello` of either peer.
+use crate::network_protocol::PeerMessage;
+use crate::network_protocol::RoutedMessageBody as R;
+use crate::peer::stream;
+use crate::peer_manager::connection;
+use crate::stats::metrics;
+use actix::AsyncContext as _;
+use actix::fut::future::wrap_future;
+use anyhow::Context as _;
+use bytesize::MIB;
+use enum_map::EnumMap;
+use near_crypto::{SecretKey, Signature};
+use near_primitives::hash::CryptoHash;
+use near_primitives::network::PeerId;
+use rustls::pki_types::{CertificateDer, PrivatePkcs8KeyDer, ServerName, UnixTime};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+#[cfg(test)]
+mod tests;
+
+/// Server name used in TLS. Certificates are not verified, so it is just a constant.
+const SERVER_NAME: &str = "near-network";
+/// ALPN protocol identifier.
+const ALPN: &[u8] = b"near-network/1";
+/// Label of the keying material exported from the TLS session, see `session_id`.
+const HELLO_EXPORTER_LABEL: &[u8] = b"EXPORTER-near-network-hello";
+/// Maximum size of an encoded `Hello`.
+const MAX_HELLO_SIZE: usize = 1024;
+/// Time the peers have to exchange their `Hello`s.
+const HELLO_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+const KEEP_ALIVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+const IDLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+/// Flow control window of a single stream. Large enough to fit a state witness part.
+const STREAM_RECEIVE_WINDOW: u32 = 16 * MIB as u32;
+
+/// Classes of messages sent over QUIC. Every class has a dedicated stream, so that
+/// for example a large state witness part doesn't delay a block approval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, enum_map::Enum, strum::IntoStaticStr)]
+pub(crate) enum MessageClass {
+    /// Blocks, approvals and endorsements.
+    Consensus,
+    /// State witness parts, chunk parts and contract code distribution.
+    Witness,
+    /// State sync and epoch sync.
+    StateSync,
+}
+
+impl MessageClass {
+    /// Class of the message, or `None` if the message should be sent over TCP.
+    pub fn of(msg: &PeerMessage) -> Option<Self> {
+        match msg {
+            PeerMessage::Block(_) | PeerMessage::OptimisticBlock(_) => Some(Self::Consensus),
+            PeerMessage::StateRequestHeader(..)
+            | PeerMessage::StateRequestPart(..)
+            | PeerMessage::VersionedStateResponse(_)
+            | PeerMessage::EpochSyncRequest
+            | PeerMessage::EpochSyncResponse(_) => Some(Self::StateSync),
+            PeerMessage::Routed(msg) => match msg.msg.body {
+                R::BlockApproval(_) | R::VersionedChunkEndorsement(_) => Some(Self::Consensus),
+                R::PartialEncodedStateWitness(_)
+                | R::PartialEncodedStateWitnessForward(_)
+                | R::ChunkStateWitnessAck(_)
+                | R::VersionedPartialEncodedChunk(_)
+                | R::PartialEncodedChunkForward(_)
+                | R::PartialEncodedChunkRequest(_)
+                | R::PartialEncodedChunkResponse(_)
+                | R::ChunkContractAccesses(_)
+                | R::ContractCodeRequest(_)
+                | R::ContractCodeResponse(_)
+                | R::PartialEncodedContractDeploys(_) => Some(Self::Witness),
+                R::StatePartRequest(_) | R::StateHeaderRequest(_) => Some(Self::StateSync),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// First message sent by each peer, on its first unidirectional stream.
+/// Proves that `sender` is the other end of the TLS session the `Hello` has been signed for.
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Hello {
+    pub sender: PeerId,
+    pub target: PeerId,
+    /// Nonce of the edge established by the TCP handshake.
+    pub nonce: u64,
+    /// Signature over the fields above and the `session_id` of the QUIC connection.
+    pub signature: Signature,
+}
+
+impl Hello {
+    pub fn new(node_key: &SecretKey, target: PeerId, nonce: u64, session_id: &SessionId) -> Self {
+        let sender = PeerId::new(node_key.public_key());
+        let signature = node_key.sign(Self::hash(&sender, &target, nonce, session_id).as_ref());
+        Self { sender, target, nonce, signature }
+    }
+
+    fn hash(sender: &PeerId, target: &PeerId, nonce: u64, session_id: &SessionId) -> CryptoHash {
+        CryptoHash::hash_borsh((ALPN, sender, target, nonce, session_id))
+    }
+
+    pub fn verify(&self, session_id: &SessionId) -> bool {
+        self.signature.verify(
+            Self::hash(&self.sender, &self.target, self.nonce, session_id).as_ref(),
+            self.sender.public_key(),
+        )
+    }
+}
+
+/// Keying material exported from the TLS session of a QUIC connection.
+/// Both ends of the connection derive the same value.
+pub(crate) type SessionId = [u8; 32];
+
+pub(crate) fn session_id(conn: &quinn::Connection) -> anyhow::Result<SessionId> {
+    let mut id = SessionId::default();
+    conn.export_keying_material(&mut id, HELLO_EXPORTER_LABEL, &[])
+        .map_err(|_| anyhow::anyhow!("failed to export keying material"))?;
+    Ok(id)
+}
+
+async fn send_hello(conn: &quinn::Connection, hello: &Hello) -> anyhow::Result<()> {
+    let mut stream = conn.open_uni().await?;
+    stream.write_all(&borsh::to_vec(hello)?).await?;
+    stream.finish()?;
+    Ok(())
+}
+
+/// Reads the `Hello` of the other end of `conn` and checks its signature.
+async fn recv_hello(conn: &quinn::Connection, session_id: &SessionId) -> anyhow::Result<Hello> {
+    let mut stream = conn.accept_uni().await?;
+    let hello: Hello = borsh::from_slice(&stream.read_to_end(MAX_HELLO_SIZE).await?)?;
+    anyhow::ensure!(hello.verify(session_id), "invalid Hello signature");
+    Ok(hello)
+}
+
+/// Attaches a QUIC connection to the PeerActor of an established TIER2 connection.
+#[derive(actix::Message)]
+#[rtype(result = "()")]
+pub(crate) struct Attach(pub quinn::Connection);
+
+/// QUIC endpoint of the node, used both to accept and to open connections.
+#[derive(Clone)]
+pub(crate) struct Endpoint(quinn::Endpoint);
+
+impl Endpoint {
+    /// Starts a QUIC endpoint on `socket`. Has to be called within a tokio runtime.
+    pub fn new(socket: std::net::UdpSocket) -> anyhow::Result<Self> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let cert = rcgen::generate_simple_self_signed(vec![SERVER_NAME.to_string()])?;
+        let key = PrivatePkcs8KeyDer::from(cert.key_pair.serialize_der());
+        let mut server_crypto = rustls::ServerConfig::builder_with_provider(provider.clone())
+            .with_protocol_versions(&[&rustls::version::TLS13])?
+            .with_no_client_auth()
+            .with_single_cert(vec![cert.cert.der().clone()], key.into())?;
+        server_crypto.alpn_protocols = vec![ALPN.to_vec()];
+        let mut server_config = quinn::ServerConfig::with_crypto(Arc::new(
+            quinn::crypto::rustls::QuicServerConfig::try_from(server_crypto)?,
+        ));
+        server_config.transport_config(transport_config());
+
+        let mut client_crypto = rustls::ClientConfig::builder_with_provider(provider.clone())
+            .with_protocol_versions(&[&rustls::version::TLS13])?
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(SkipServerVerification(provider)))
+            .with_no_client_auth();
+        client_crypto.alpn_protocols = vec![ALPN.to_vec()];
+        let mut client_config = quinn::ClientConfig::new(Arc::new(
+            quinn::crypto::rustls::QuicClientConfig::try_from(client_crypto)?,
+        ));
+        client_config.transport_config(transport_config());
+
+        let runtime = quinn::default_runtime().context("no async runtime found")?;
+        let mut endpoint = quinn::Endpoint::new(
+            quinn::EndpointConfig::default(),
+            Some(server_config),
+            socket,
+            runtime,
+        )?;
+        endpoint.set_default_client_config(client_config);
+        Ok(Self(endpoint))
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.0.local_addr()
+    }
+
+    /// Opens a QUIC connection to `target` listening on `addr`, for the TCP connection with edge
+    /// `nonce`. Fails unless `target` authenticates itself with a matching `Hello`.
+    pub async fn connect(
+        &self,
+        addr: SocketAddr,
+        node_key: &SecretKey,
+        target: PeerId,
+        nonce: u64,
+    ) -> anyhow::Result<quinn::Connection> {
+        let conn = self.0.connect(addr, SERVER_NAME)?.await?;
+        let session_id = session_id(&conn)?;
+        let hello = Hello::new(node_key, target, nonce, &session_id);
+        tokio::time::timeout(HELLO_TIMEOUT, async {
+            send_hello(&conn, &hello).await?;
+            let reply = recv_hello(&conn, &session_id).await?;
+            anyhow::ensure!(
+                reply.sender == hello.target && reply.target == hello.sender,
+                "Hello sent by an unexpected peer"
+            );
+            anyhow::ensure!(reply.nonce == nonce, "Hello nonce doesn't match the edge nonce");
+            Ok(())
+        })
+        .await??;
+        Ok(conn)
+    }
+
+    /// Waits for the next inbound connection attempt.
+    /// Returns `None` once the endpoint is closed.
+    pub async fn accept(&self) -> Option<quinn::Incoming> {
+        self.0.accept().await
+    }
+}
+
+/// Completes an inbound connection, reads the `Hello` of the connecting peer and replies with
+/// a `Hello` signed with `node_key`. The caller is responsible for checking that the received
+/// `Hello` matches an established TCP connection.
+pub(crate) async fn accept_hello(
+    incoming: quinn::Incoming,
+    node_key: &SecretKey,
+) -> anyhow::Result<(Hello, quinn::Connection)> {
+    tokio::time::timeout(HELLO_TIMEOUT, async {
+        let conn = incoming.await?;
+        let session_id = session_id(&conn)?;
+        let hello = recv_hello(&conn, &session_id).await?;
+        anyhow::ensure!(
+            hello.target == PeerId::new(node_key.public_key()),
+            "Hello addressed to another node"
+        );
+        let reply = Hello::new(node_key, hello.sender.clone(), hello.nonce, &session_id);
+        send_hello(&conn, &reply).await?;
+        Ok((hello, conn))
+    })
+    .await?
+}
+
+fn transport_config() -> Arc<quinn::TransportConfig> {
+    let mut cfg = quinn::TransportConfig::default();
+    cfg.max_concurrent_bidi_streams(quinn::VarInt::from_u32(0));
+    // One stream per MessageClass, plus the stream carrying the Hello.
+    cfg.max_concurrent_uni_streams(quinn::VarInt::from_u32(
+        <MessageClass as enum_map::Enum>::LENGTH as u32 + 1,
+    ));
+    cfg.stream_receive_window(quinn::VarInt::from_u32(STREAM_RECEIVE_WINDOW));
+    cfg.keep_alive_interval(Some(KEEP_ALIVE_INTERVAL));
+    cfg.max_idle_timeout(Some(IDLE_TIMEOUT.try_into().unwrap()));
+    Arc::new(cfg)
+}
+
+/// Peers authenticate each other with `Hello` rather than with TLS certificates.
+#[derive(Debug)]
+struct SkipServerVerification(Arc<rustls::crypto::CryptoProvider>);
+
+impl rustls::client::danger::ServerCertVerifier for SkipServerVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp: &[u8],
+        _now: UnixTime,
+    ) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
+        Ok(rustls::client::danger::ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// Writes a frame in the same format as `stream::FramedStream`: u32 LE length followed by data.
+pub(crate) async fn write_frame(
+    stream: &mut quinn::SendStream,
+    frame: &stream::Frame,
+) -> anyhow::Result<()> {
+    stream.write_all(&(frame.0.len() as u32).to_le_bytes()).await?;
+    stream.write_all(&frame.0).await?;
+    Ok(())
+}
+
+/// Reads a frame written by `write_frame`.
+pub(crate) async fn read_frame(
+    stream: &mut quinn::RecvStream,
+) -> Result<stream::Frame, stream::RecvError> {
+    let io_err = |err: quinn::ReadExactError| {
+        stream::RecvError::IO(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, err))
+    };
+    let mut n = [0; 4];
+    stream.read_exact(&mut n).await.map_err(io_err)?;
+    let n = u32::from_le_bytes(n) as usize;
+    if n > stream::NETWORK_MESSAGE_MAX_SIZE_BYTES {
+        return Err(stream::RecvError::MessageTooLarge {
+            got_bytes: n,
+            want_max_bytes: stream::NETWORK_MESSAGE_MAX_SIZE_BYTES,
+        });
+    }
+    let mut buf = vec![0; n];
+    stream.read_exact(&mut buf).await.map_err(io_err)?;
+    Ok(stream::Frame(buf))
+}
+
+/// Sending half of a QUIC connection attached to a PeerActor.
+/// Closes the connection when dropped.
+///
+/// Frames queued for sending count towards the write buffer of the TCP connection (see
+/// `connection::Stats::bytes_to_send`), so that the total amount of data queued for the peer
+/// is bounded by `stream::MAX_WRITE_BUFFER_CAPACITY_BYTES`.
+pub(crate) struct Sender {
+    conn: quinn::Connection,
+    queues: EnumMap<MessageClass, tokio::sync::mpsc::UnboundedSender<stream::Frame>>,
+    stats: Arc<connection::Stats>,
+    errors: actix::Recipient<stream::Error>,
+}
+
+impl Sender {
+    /// Spawns a send loop per `MessageClass`, each writing to a dedicated stream,
+    /// and a receive loop forwarding the frames received from the peer to the actor.
+    pub fn spawn<Actor>(
+        ctx: &mut actix::Context<Actor>,
+        conn: quinn::Connection,
+        stats: Arc<connection::Stats>,
+    ) -> Self
+    where
+        Actor: actix::Actor<Context = actix::Context<Actor>>
+            + actix::Handler<stream::Frame>
+            + actix::Handler<stream::Error>,
+    {
+        let queues = EnumMap::from_fn(|class: MessageClass| {
+            let (queue_send, mut queue_recv) = tokio::sync::mpsc::unbounded_channel();
+            let conn = conn.clone();
+            let stats = stats.clone();
+            ctx.spawn(wrap_future(async move {
+                if let Err(err) = Self::run_send_loop(conn, &mut queue_recv, &stats).await {
+                    tracing::debug!(target: "network", ?class, ?err, "QUIC send loop stopped");
+                }
+                // Frames left in the queue won't be sent, release their share of the buffer.
+                queue_recv.close();
+                while let Ok(frame) = queue_recv.try_recv() {
+                    Self::release(&stats, &frame);
+                }
+            }));
+            queue_send
+        });
+        ctx.spawn(wrap_future({
+            let conn = conn.clone();
+            let addr = ctx.address();
+            let stats = stats.clone();
+            async move {
+                // Streams are closed together with the connection.
+                while let Ok(stream) = conn.accept_uni().await {
+                    tokio::spawn(Self::run_recv_loop(stream, addr.clone(), stats.clone()));
+                }
+            }
+        }));
+        metrics::PEER_QUIC_CONNECTIONS.inc();
+        Self { conn, queues, stats, errors: ctx.address().recipient() }
+    }
+
+    /// Pushes `frame` to the send queue of `class`.
+    /// Returns the frame back if the QUIC connection is broken, so that it can be sent over TCP.
+    /// Emits a critical error to the actor if the write buffer of the peer is full, like
+    /// `stream::FramedStream::send`.
+    pub fn send(&self, class: MessageClass, frame: stream::Frame) -> Result<(), stream::Frame> {
+        let len = frame.0.len();
+        let buf_size = self.stats.bytes_to_send.fetch_add(len as u64, Ordering::Acquire) as usize;
+        self.stats.messages_to_send.fetch_add(1, Ordering::Acquire);
+        if let Err(err) = self.queues[class].send(frame) {
+            Self::release(&self.stats, &err.0);
+            return Err(err.0);
+        }
+        metrics::PEER_QUIC_MESSAGES_SENT.with_label_values(&[class.into()]).inc();
+        if buf_size + len > stream::MAX_WRITE_BUFFER_CAPACITY_BYTES {
+            metrics::MessageDropped::MaxCapacityExceeded.inc_unknown_msg();
+            self.errors.do_send(stream::Error::Send(stream::SendError::QueueOverflow {
+                got_bytes: buf_size + len,
+                want_max_bytes: stream::MAX_WRITE_BUFFER_CAPACITY_BYTES,
+            }));
+        }
+        Ok(())
+    }
+
+    fn release(stats: &connection::Stats, frame: &stream::Frame) {
+        stats.messages_to_send.fetch_sub(1, Ordering::Release);
+        stats.bytes_to_send.fetch_sub(frame.0.len() as u64, Ordering::Release);
+    }
+
+    async fn run_send_loop(
+        conn: quinn::Connection,
+        queue_recv: &mut tokio::sync::mpsc::UnboundedReceiver<stream::Frame>,
+        stats: &connection::Stats,
+    ) -> anyhow::Result<()> {
+        let mut stream = conn.open_uni().await?;
+        while let Some(frame) = queue_recv.recv().await {
+            let result = if frame.0.len() > stream::NETWORK_MESSAGE_MAX_SIZE_BYTES {
+                metrics::MessageDropped::InputTooLong.inc_unknown_msg();
+                Ok(())
+            } else {
+                write_frame(&mut stream, &frame).await
+            };
+            Self::release(stats, &frame);
+            result?;
+        }
+        Ok(())
+    }
+
+    async fn run_recv_loop<Actor>(
+        mut stream: quinn::RecvStream,
+        addr: actix::Addr<Actor>,
+        stats: Arc<connection::Stats>,
+    ) where
+        Actor: actix::Actor<Context = actix::Context<Actor>>
+            + actix::Handler<stream::Frame>
+            + actix::Handler<stream::Error>,
+    {
+        loop {
+            let frame = match read_frame(&mut stream).await {
+                Ok(frame) => frame,
+                // Too large messages are abusive, let the actor handle them as on TCP.
+                Err(err @ stream::RecvError::MessageTooLarge { .. }) => {
+                    addr.do_send(stream::Error::Recv(err));
+                    return;
+                }
+                // QUIC connection is broken, but the TCP connection may be still alive.
+                Err(err) => {
+                    tracing::debug!(target: "network", ?err, "QUIC receive loop stopped");
+                    return;
+                }
+            };
+            stats.received_messages.fetch_add(1, Ordering::Relaxed);
+            stats.received_bytes.fetch_add(frame.0.len() as u64, Ordering::Relaxed);
+            if let Err(_) = addr.send(frame).await {
+                // Actor has stopped.
+                return;
+            }
+        }
+    }
+}
+
+impl Drop for Sender {
+    fn drop(&mut self) {
+        metrics::PEER_QUIC_CONNECTIONS.dec();
+        self.conn.close(quinn::VarInt::from_u32(0), b"closed");
+    }
+}
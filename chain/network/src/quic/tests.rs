@@ -0,0 +1,137 @@
+use crate::network_protocol::testonly as data;
+use crate::network_protocol::{Disconnect, PeerMessage};
+use crate::peer::stream;
+use crate::quic::{self, Endpoint, Hello, MessageClass, SERVER_NAME};
+use crate::testonly::make_rng;
+use near_o11y::testonly::init_test_logger;
+use near_primitives::hash::CryptoHash;
+use near_primitives::network::PeerId;
+use near_primitives::types::ShardId;
+
+#[test]
+fn message_class() {
+    assert_eq!(MessageClass::of(&PeerMessage::EpochSyncRequest), Some(MessageClass::StateSync));
+    assert_eq!(
+        MessageClass::of(&PeerMessage::StateRequestHeader(ShardId::new(0), CryptoHash::default())),
+        Some(MessageClass::StateSync)
+    );
+    // Control messages stay on TCP.
+    assert_eq!(
+        MessageClass::of(&PeerMessage::Disconnect(Disconnect {
+            remove_from_connection_store: false
+        })),
+        None
+    );
+    assert_eq!(MessageClass::of(&PeerMessage::BlockRequest(CryptoHash::default())), None);
+}
+
+fn bind() -> Endpoint {
+    Endpoint::new(std::net::UdpSocket::bind("127.0.0.1:0").unwrap()).unwrap()
+}
+
+#[test]
+fn hello() {
+    let mut rng = make_rng(89028037453);
+    let key = data::make_secret_key(&mut rng);
+    let target = data::make_peer_id(&mut rng);
+    let session_id = [1; 32];
+    let hello = Hello::new(&key, target.clone(), 7, &session_id);
+    assert_eq!(hello.sender, PeerId::new(key.public_key()));
+    assert!(hello.verify(&session_id));
+    assert!(!hello.verify(&[2; 32]));
+    assert!(!Hello { nonce: 9, ..hello.clone() }.verify(&session_id));
+    assert!(!Hello { target: data::make_peer_id(&mut rng), ..hello.clone() }.verify(&session_id));
+}
+
+#[tokio::test]
+async fn connect_and_exchange_frames() {
+    init_test_logger();
+    let mut rng = make_rng(3498671245);
+    let client_key = data::make_secret_key(&mut rng);
+    let server_key = data::make_secret_key(&mut rng);
+    let server_id = PeerId::new(server_key.public_key());
+    let server = bind();
+    let client = bind();
+
+    let (client_conn, accepted) = tokio::join!(
+        client.connect(server.local_addr().unwrap(), &client_key, server_id.clone(), 1),
+        async { quic::accept_hello(server.accept().await.unwrap(), &server_key).await }
+    );
+    let client_conn = client_conn.unwrap();
+    let (got_hello, server_conn) = accepted.unwrap();
+    assert_eq!(got_hello.sender, PeerId::new(client_key.public_key()));
+    assert_eq!(got_hello.target, server_id);
+    assert_eq!(got_hello.nonce, 1);
+    // Both ends derive the same session id.
+    assert_eq!(quic::session_id(&client_conn).unwrap(), quic::session_id(&server_conn).unwrap());
+
+    // Frames sent over different streams are delivered independently.
+    let small = stream::Frame(vec![1, 2, 3]);
+    let large = stream::Frame(vec![5; 1 << 20]);
+    let mut large_stream = client_conn.open_uni().await.unwrap();
+    let mut small_stream = client_conn.open_uni().await.unwrap();
+    quic::write_frame(&mut small_stream, &small).await.unwrap();
+    quic::write_frame(&mut large_stream, &large).await.unwrap();
+    for _ in 0..2 {
+        let mut recv = server_conn.accept_uni().await.unwrap();
+        let frame = quic::read_frame(&mut recv).await.unwrap();
+        assert!(frame == small || frame == large);
+    }
+
+    // Oversized frames are rejected before reading their content.
+    let mut stream = client_conn.open_uni().await.unwrap();
+    stream.write_all(&u32::MAX.to_le_bytes()).await.unwrap();
+    let mut recv = server_conn.accept_uni().await.unwrap();
+    assert!(matches!(
+        quic::read_frame(&mut recv).await,
+        Err(stream::RecvError::MessageTooLarge { .. })
+    ));
+}
+
+#[tokio::test]
+async fn reject_hello_to_another_node() {
+    init_test_logger();
+    let mut rng = make_rng(1290581233);
+    let client_key = data::make_secret_key(&mut rng);
+    let server_key = data::make_secret_key(&mut rng);
+    let server = bind();
+    let client = bind();
+
+    let (conn, accepted) = tokio::join!(
+        client.connect(server.local_addr().unwrap(), &client_key, data::make_peer_id(&mut rng), 1),
+        async { quic::accept_hello(server.accept().await.unwrap(), &server_key).await }
+    );
+    assert!(accepted.is_err());
+    assert!(conn.is_err());
+}
+
+#[tokio::test]
+async fn reject_hello_from_another_session() {
+    init_test_logger();
+    let mut rng = make_rng(2384901237);
+    let client_key = data::make_secret_key(&mut rng);
+    let server_key = data::make_secret_key(&mut rng);
+    let server_id = PeerId::new(server_key.public_key());
+    let server = bind();
+    let client = bind();
+    let server_addr = server.local_addr().unwrap();
+
+    // A Hello signed for one connection, e.g. relayed by a man in the middle,
+    // doesn't authenticate another connection.
+    let (_conns, accepted) = tokio::join!(
+        async {
+            let first = client.0.connect(server_addr, SERVER_NAME).unwrap().await.unwrap();
+            let second = client.0.connect(server_addr, SERVER_NAME).unwrap().await.unwrap();
+            let session_id = quic::session_id(&first).unwrap();
+            assert_ne!(session_id, quic::session_id(&second).unwrap());
+            let hello = Hello::new(&client_key, server_id, 1, &session_id);
+            quic::send_hello(&second, &hello).await.unwrap();
+            (first, second)
+        },
+        async {
+            let _first = server.accept().await.unwrap().await.unwrap();
+            quic::accept_hello(server.accept().await.unwrap(), &server_key).await
+        }
+    );
+    assert!(accepted.is_err());
+}
@@ -54,6 +54,14 @@ impl RateLimits {
         }
         true
     }
+
+    /// Scales the capacity and refill rate of all buckets by `factor`.
+    /// See also [TokenBucket::set_capacity_factor].
+    pub fn set_capacity_factor(&mut self, factor: f32) {
+        for bucket in self.buckets.values_mut().flatten() {
+            bucket.set_capacity_factor(factor);
+        }
+    }
 }
 
 /// Rate limit configuration for a single network message.
@@ -310,6 +318,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn capacity_factor() {
+        use RateLimitedPeerMessageKey::*;
+        let mut config = Config::default();
+        config.rate_limits.insert(BlockRequest, SingleMessageConfig::new(4, 0.0, None));
+
+        let block_request = PeerMessage::BlockRequest(CryptoHash::default());
+        let now = Instant::now();
+        let mut limits = RateLimits::from_config(&config, now);
+        limits.set_capacity_factor(0.5);
+
+        assert!(limits.is_allowed(&block_request, now));
+        assert!(limits.is_allowed(&block_request, now));
+        assert!(!limits.is_allowed(&block_request, now));
+    }
+
     #[test]
     fn configuration() {
         use RateLimitedPeerMessageKey::*;
@@ -35,6 +35,9 @@ pub struct TokenBucket {
     refill_rate: f32,
     /// Last time the bucket was refreshed.
     last_refill: Instant,
+    /// Multiplier in the range `(0, 1]` applied to both `maximum_size` and `refill_rate`.
+    /// It is used to shrink the bucket of peers with a bad reputation.
+    capacity_factor: f32,
 }
 
 impl TokenBucket {
@@ -58,7 +61,26 @@ impl TokenBucket {
     ) -> Result<Self, TokenBucketError> {
         let size = to_tokens_with_parts(maximum_size.min(initial_size));
         TokenBucket::validate_refill_rate(refill_rate)?;
-        Ok(Self { maximum_size, size, refill_rate, last_refill: start_time })
+        Ok(Self { maximum_size, size, refill_rate, last_refill: start_time, capacity_factor: 1.0 })
+    }
+
+    /// Shrinks (or restores) the bucket by scaling its maximum size and refill rate by `factor`.
+    ///
+    /// `factor` is clamped to the range `(0, 1]`. The effective maximum size never drops below
+    /// a single token (unless `maximum_size` is zero), so that a shrunk bucket still lets
+    /// some traffic through.
+    pub fn set_capacity_factor(&mut self, factor: f32) {
+        self.capacity_factor =
+            if factor.is_normal() { factor.clamp(f32::EPSILON, 1.0) } else { 1.0 };
+        self.size = self.size.min(self.effective_maximum_size());
+    }
+
+    /// Maximum amount of tokens (stored as `tokens * TOKEN_PARTS_NUMBER`) the bucket can hold
+    /// after applying `capacity_factor`.
+    fn effective_maximum_size(&self) -> u64 {
+        let maximum_size = to_tokens_with_parts(self.maximum_size);
+        let scaled = (maximum_size as f64 * self.capacity_factor as f64) as u64;
+        scaled.max(maximum_size.min(TOKEN_PARTS_NUMBER))
     }
 
     /// Makes an attempt to acquire `token` tokens.
@@ -92,15 +114,13 @@ impl TokenBucket {
         }
         // Compute how many tokens should be added to the current size.
         let duration = now - self.last_refill;
-        let tokens_to_add = duration.as_secs_f64() * self.refill_rate as f64;
+        let tokens_to_add =
+            duration.as_secs_f64() * self.refill_rate as f64 * self.capacity_factor as f64;
         let tokens_to_add = (tokens_to_add * TOKEN_PARTS_NUMBER as f64) as u64;
         // Update `last_refill` and `size` only if there's a change. This is done to prevent
         // losing token parts to clamping if the duration is too small.
         if tokens_to_add > 0 {
-            self.size = self
-                .size
-                .saturating_add(tokens_to_add)
-                .min(to_tokens_with_parts(self.maximum_size));
+            self.size = self.size.saturating_add(tokens_to_add).min(self.effective_maximum_size());
             self.last_refill = now;
         }
     }
@@ -285,4 +305,40 @@ mod tests {
         }
         assert_eq!(tokens_added, 1);
     }
+
+    #[test]
+    fn capacity_factor() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(10, 10, 2.0, now).expect("bucket should be well formed");
+
+        // Shrinking the bucket drops the tokens above the new maximum size.
+        bucket.set_capacity_factor(0.5);
+        assert_eq!(bucket.size, to_tokens_with_parts(5));
+
+        // Refill rate is halved as well.
+        assert!(bucket.acquire(5, now));
+        assert!(!bucket.acquire(2, now + Duration::seconds(1)));
+        assert!(bucket.acquire(1, now + Duration::seconds(1)));
+        // The bucket never holds more than the scaled maximum size.
+        assert!(!bucket.acquire(6, now + Duration::seconds(100)));
+        assert!(bucket.acquire(5, now + Duration::seconds(100)));
+
+        // Restoring the factor restores the original capacity.
+        bucket.set_capacity_factor(1.0);
+        assert!(bucket.acquire(10, now + Duration::seconds(200)));
+    }
+
+    #[test]
+    fn capacity_factor_keeps_one_token() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(1, 1, 1.0, now).expect("bucket should be well formed");
+        bucket.set_capacity_factor(0.1);
+        assert!(bucket.acquire(1, now));
+        assert!(!bucket.acquire(1, now + Duration::seconds(1)));
+        assert!(bucket.acquire(1, now + Duration::seconds(11)));
+
+        // Invalid factors reset the bucket to its original capacity.
+        bucket.set_capacity_factor(f32::NAN);
+        assert!(bucket.acquire(1, now + Duration::seconds(12)));
+    }
 }
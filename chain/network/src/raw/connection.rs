@@ -1,7 +1,9 @@
+use crate::capture;
 use crate::config::SocketOptions;
 use crate::network_protocol::{
     Encoding, Handshake, HandshakeFailureReason, PartialEdgeInfo, PeerChainInfoV2, PeerIdOrHash,
     PeerMessage, Ping, Pong, RawRoutedMessage, RoutedMessageBody, RoutingTableUpdate,
+    SignedAccountData, SyncAccountsData,
 };
 use crate::tcp;
 use crate::types::{
@@ -18,10 +20,12 @@ use near_primitives::hash::CryptoHash;
 use near_primitives::network::{AnnounceAccount, PeerId};
 use near_primitives::types::{BlockHeight, ShardId};
 use near_primitives::version::{PROTOCOL_VERSION, ProtocolVersion};
+use std::collections::VecDeque;
 use std::fmt;
 use std::io;
 use std::net::SocketAddr;
 use std::num::NonZeroUsize;
+use std::sync::Arc;
 use time::ext::InstantExt as _;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
@@ -41,6 +45,9 @@ pub struct Connection {
     // If this field is true, it means we expect to receive a message that won't parse as a proto, and
     // will accept and drop one such message without giving an error.
     borsh_message_expected: bool,
+    // messages already received, but not yet returned by recv(), because they arrived
+    // as a part of a single PeerMessage together with another message.
+    pending: VecDeque<(Message, Instant)>,
 }
 
 // The types of messages it's possible to route to a target PeerId via the connected peer as a first hop
@@ -83,6 +90,8 @@ impl fmt::Debug for RoutedMessage {
 #[derive(Clone, strum::IntoStaticStr)]
 pub enum DirectMessage {
     AnnounceAccounts(Vec<AnnounceAccount>),
+    Edges(Vec<Edge>),
+    AccountsData(Vec<Arc<SignedAccountData>>),
     BlockRequest(CryptoHash),
     Block(Block),
     BlockHeadersRequest(Vec<CryptoHash>),
@@ -102,6 +111,10 @@ impl fmt::Debug for DirectMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::AnnounceAccounts(a) => write!(f, "AnnounceAccounts({:?})", a),
+            Self::Edges(e) => write!(f, "Edges({} edges)", e.len()),
+            Self::AccountsData(a) => {
+                write!(f, "AccountsData({:?})", a.iter().map(|d| &d.peer_id).collect::<Vec<_>>())
+            }
             Self::BlockRequest(r) => write!(f, "BlockRequest({})", r),
             Self::Block(b) => write!(f, "Block(#{} {})", b.header().height(), b.header().hash()),
             Self::BlockHeadersRequest(r) => write!(f, "BlockHeadersRequest({:?})", r),
@@ -213,6 +226,7 @@ fn new_handshake(
         // we have to set this even if we have no intention of listening since otherwise
         // the peer will drop our connection
         sender_listen_port: Some(listen_port),
+        sender_quic_port: None,
         sender_chain_info: PeerChainInfoV2 {
             genesis_id: GenesisId { chain_id: chain_id.to_string(), hash: genesis_hash },
             height: head_height,
@@ -260,6 +274,7 @@ impl Connection {
             my_peer_id,
             route_cache: lru::LruCache::new(NonZeroUsize::new(1_000_000).unwrap()),
             borsh_message_expected: false,
+            pending: VecDeque::new(),
         };
         peer.do_handshake(
             &clock,
@@ -332,6 +347,7 @@ impl Connection {
             peer_id,
             route_cache: lru::LruCache::new(NonZeroUsize::new(1_000_000).unwrap()),
             borsh_message_expected,
+            pending: VecDeque::new(),
         })
     }
 
@@ -387,6 +403,16 @@ impl Connection {
             DirectMessage::AnnounceAccounts(accounts) => {
                 PeerMessage::SyncRoutingTable(RoutingTableUpdate { edges: Vec::new(), accounts })
             }
+            DirectMessage::Edges(edges) => {
+                PeerMessage::SyncRoutingTable(RoutingTableUpdate { edges, accounts: Vec::new() })
+            }
+            DirectMessage::AccountsData(accounts_data) => {
+                PeerMessage::SyncAccountsData(SyncAccountsData {
+                    accounts_data,
+                    requesting_full_sync: false,
+                    incremental: true,
+                })
+            }
             DirectMessage::BlockRequest(h) => PeerMessage::BlockRequest(h),
             DirectMessage::Block(b) => PeerMessage::Block(b),
             DirectMessage::BlockHeadersRequest(h) => PeerMessage::BlockHeadersRequest(h),
@@ -435,6 +461,26 @@ impl Connection {
         self.stream.write_message(&PeerMessage::Routed(Box::new(msg))).await
     }
 
+    /// Sends a captured message to the peer as is. Returns `Ok(false)` without sending
+    /// anything if the message belongs to the connection setup or teardown (handshakes,
+    /// disconnects), since replaying it would break this connection.
+    pub async fn send_captured(&mut self, record: &capture::Record) -> io::Result<bool> {
+        let msg = record.decode().map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("failed parsing message: {err}"))
+        })?;
+        match msg {
+            PeerMessage::Tier1Handshake(_)
+            | PeerMessage::Tier2Handshake(_)
+            | PeerMessage::Tier3Handshake(_)
+            | PeerMessage::HandshakeFailure(..)
+            | PeerMessage::LastEdge(_)
+            | PeerMessage::Disconnect(_) => return Ok(false),
+            _ => {}
+        }
+        self.stream.write_message(&msg).await?;
+        Ok(true)
+    }
+
     fn target_is_for_me(&mut self, target: &PeerIdOrHash) -> bool {
         match target {
             PeerIdOrHash::PeerId(peer_id) => peer_id == &self.my_peer_id,
@@ -471,6 +517,9 @@ impl Connection {
     /// Reads from the socket until we receive some message that we care to pass to the caller
     /// (that is, represented in `DirectMessage` or `RoutedMessage`).
     pub async fn recv(&mut self) -> io::Result<(Message, Instant)> {
+        if let Some(msg) = self.pending.pop_front() {
+            return Ok(msg);
+        }
         loop {
             let (msg, timestamp) = match self.stream.recv_message().await {
                 Ok(m) => m,
@@ -495,11 +544,21 @@ impl Connection {
                     }
                 }
                 PeerMessage::SyncRoutingTable(r) => {
+                    if !r.edges.is_empty() {
+                        self.pending
+                            .push_back((Message::Direct(DirectMessage::Edges(r.edges)), timestamp));
+                    }
                     return Ok((
                         Message::Direct(DirectMessage::AnnounceAccounts(r.accounts)),
                         timestamp,
                     ));
                 }
+                PeerMessage::SyncAccountsData(d) => {
+                    return Ok((
+                        Message::Direct(DirectMessage::AccountsData(d.accounts_data)),
+                        timestamp,
+                    ));
+                }
                 PeerMessage::BlockRequest(hash) => {
                     return Ok((Message::Direct(DirectMessage::BlockRequest(hash)), timestamp));
                 }
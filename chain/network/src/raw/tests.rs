@@ -1,3 +1,4 @@
+use crate::capture;
 use crate::network_protocol::testonly as data;
 use crate::raw;
 use crate::tcp;
@@ -191,3 +192,104 @@ async fn test_listener() {
     // at least the handshake logic has gotten exercised somewhat
     let _ = conn.recv().await.unwrap();
 }
+
+async fn recv_state_parts(conn: &mut raw::Connection, num_parts: u64) {
+    let mut received = 0;
+    while received < num_parts {
+        let (msg, _) = conn.recv().await.unwrap();
+        if let raw::Message::Direct(raw::DirectMessage::VersionedStateResponse(_)) = msg {
+            received += 1;
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_capture_replay() {
+    init_test_logger();
+    let mut rng = testonly::make_rng(921341);
+    let rng = &mut rng;
+    let mut clock = time::FakeClock::default();
+    let chain = Arc::new(data::Chain::make(&mut clock, rng, 10));
+    let genesis_id = chain.genesis_id.clone();
+    let capture_dir = tempfile::tempdir().unwrap();
+    let connect = |cfg: &crate::config::NetworkConfig| {
+        let addr = **cfg.node_addr.as_ref().unwrap();
+        raw::Connection::connect(
+            &clock.clock(),
+            addr,
+            cfg.node_id(),
+            None,
+            &genesis_id.chain_id,
+            genesis_id.hash,
+            0,
+            vec![ShardId::new(0)],
+            Some(time::Duration::SECOND),
+        )
+    };
+
+    tracing::info!(target:"test", "capture the state requests received by a node");
+    let mut cfg = chain.make_config(rng);
+    cfg.capture = Some(capture::Config {
+        dir: capture_dir.path().to_path_buf(),
+        ..capture::Config::default()
+    });
+    let _pm = crate::peer_manager::testonly::start(
+        clock.clock(),
+        near_store::db::TestDB::new(),
+        cfg.clone(),
+        chain.clone(),
+    )
+    .await;
+    let mut conn = connect(&cfg).await.unwrap();
+    let num_parts = 3;
+    for part_id in 0..num_parts {
+        conn.send_message(raw::DirectMessage::StateRequestPart(
+            ShardId::new(0),
+            CryptoHash::new(),
+            part_id,
+        ))
+        .await
+        .unwrap();
+    }
+    recv_state_parts(&mut conn, num_parts).await;
+
+    // Records are written by a separate thread, so wait until the responses show up.
+    let records = loop {
+        let records: Vec<_> =
+            capture::Reader::open(capture_dir.path()).unwrap().map(Result::unwrap).collect();
+        let count = |direction, msg_type| {
+            records
+                .iter()
+                .filter(|r| r.direction == direction && r.message_type() == Some(msg_type))
+                .count() as u64
+        };
+        if count(capture::Direction::Sent, "VersionedStateResponse") == num_parts {
+            assert_eq!(count(capture::Direction::Received, "StateRequestPart"), num_parts);
+            assert!(count(capture::Direction::Received, "Tier2Handshake") >= 1);
+            break records;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    };
+
+    tracing::info!(target:"test", "replay the received messages to another node");
+    let cfg = chain.make_config(rng);
+    let _pm = crate::peer_manager::testonly::start(
+        clock.clock(),
+        near_store::db::TestDB::new(),
+        cfg.clone(),
+        chain,
+    )
+    .await;
+    let mut conn = connect(&cfg).await.unwrap();
+    let (mut sent, mut skipped) = (0, 0);
+    for record in records.iter().filter(|r| r.direction == capture::Direction::Received) {
+        if conn.send_captured(record).await.unwrap() {
+            sent += 1;
+        } else {
+            skipped += 1;
+        }
+    }
+    assert_eq!(sent, num_parts);
+    assert!(skipped >= 1);
+    recv_state_parts(&mut conn, num_parts).await;
+}
@@ -279,6 +279,52 @@ pub(crate) static PEER_UNRELIABLE: LazyLock<IntGauge> = LazyLock::new(|| {
     )
     .unwrap()
 });
+pub(crate) static PEER_REPUTATION_EVENTS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "near_peer_reputation_events_total",
+        "Number of events which lowered the reputation score of a peer, by event type",
+        &["type"],
+    )
+    .unwrap()
+});
+pub(crate) static PEER_LOW_REPUTATION: LazyLock<IntGauge> = LazyLock::new(|| {
+    try_create_int_gauge(
+        "near_peer_low_reputation",
+        "Total peers with a reputation score low enough to be avoided when routing messages",
+    )
+    .unwrap()
+});
+pub(crate) static PEER_QUIC_CONNECTIONS: LazyLock<IntGauge> = LazyLock::new(|| {
+    try_create_int_gauge(
+        "near_peer_quic_connections",
+        "Number of TIER2 connections with an attached QUIC connection",
+    )
+    .unwrap()
+});
+pub(crate) static PEER_QUIC_MESSAGES_SENT: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "near_peer_quic_messages_sent",
+        "Number of messages sent over QUIC, by message class",
+        &["class"],
+    )
+    .unwrap()
+});
+pub(crate) static PEER_ENCRYPTION_SETUP: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "near_peer_encryption_setup_total",
+        "Results of the encryption negotiation of peer connections",
+        &["result"],
+    )
+    .unwrap()
+});
+pub(crate) static PEER_CAPTURE_MESSAGES: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "near_peer_capture_messages_total",
+        "Number of messages captured to, or dropped from, the network traffic capture",
+        &["result"],
+    )
+    .unwrap()
+});
 pub(crate) static PEER_MANAGER_TRIGGER_TIME: LazyLock<HistogramVec> = LazyLock::new(|| {
     try_create_histogram_vec(
         "near_peer_manager_trigger_time",
@@ -5,7 +5,7 @@ pub use crate::network_protocol::{
 };
 /// Exported types, which are part of network protocol.
 pub use crate::network_protocol::{
-    Edge, PartialEdgeInfo, PartialEncodedChunkForwardMsg, PartialEncodedChunkRequestMsg,
+    Edge, EdgeState, PartialEdgeInfo, PartialEncodedChunkForwardMsg, PartialEncodedChunkRequestMsg,
     PartialEncodedChunkResponseMsg, PeerChainInfoV2, PeerInfo, SnapshotHostInfo, StateResponseInfo,
     StateResponseInfoV1, StateResponseInfoV2,
 };
@@ -247,8 +247,9 @@ pub enum NetworkRequests {
     Approval { approval_message: ApprovalMessage },
     /// Request block with given hash from given peer.
     BlockRequest { hash: CryptoHash, peer_id: PeerId },
-    /// Request given block headers.
-    BlockHeadersRequest { hashes: Vec<CryptoHash>, peer_id: PeerId },
+    /// Request given block headers. `tip_height` is the height of the first hash, the tip of
+    /// our header chain.
+    BlockHeadersRequest { hashes: Vec<CryptoHash>, peer_id: PeerId, tip_height: BlockHeight },
     /// Request state header for given shard and given sync hash.
     StateRequestHeader { shard_id: ShardId, sync_hash: CryptoHash, sync_prev_prev_hash: CryptoHash },
     /// Request state part for given shard and given sync hash.
@@ -396,6 +397,8 @@ pub struct ConnectedPeerInfo {
     pub peer_type: PeerType,
     /// Nonce used for the connection with the peer.
     pub nonce: u64,
+    /// Reputation score of the peer, rounded to an integer. 0 is neutral, lower is worse.
+    pub reputation_score: i64,
 }
 
 #[derive(Debug, Default, Clone, actix::MessageResponse, PartialEq, Eq)]
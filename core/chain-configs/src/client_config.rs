@@ -9,6 +9,7 @@ use near_primitives::version::Version;
 use near_time::Duration;
 use num_rational::Rational32;
 use std::cmp::{max, min};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
@@ -119,6 +120,51 @@ pub struct GCConfig {
     /// How often gc should be run
     #[serde(with = "near_time::serde_duration_as_std")]
     pub gc_step_period: Duration,
+
+    /// Groups of data which are kept for longer than the blocks they belong
+    /// to, e.g. so that an RPC node can answer `tx` queries about older
+    /// transactions without keeping the full history.  A retention shorter
+    /// than `gc_num_epochs_to_keep` has no effect.
+    pub gc_retention: HashMap<GCDataGroup, GCRetention>,
+}
+
+/// Group of columns which can be retained by garbage collection independently
+/// of the blocks.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GCDataGroup {
+    /// Execution outcomes of transactions and receipts (`DBCol::TransactionResultForBlock`
+    /// and `DBCol::OutcomeIds`).
+    TransactionOutcomes,
+    /// Signed transactions (`DBCol::Transactions`).
+    Transactions,
+    /// Receipts (`DBCol::Receipts`).
+    Receipts,
+    /// State changes of blocks (`DBCol::StateChanges`).
+    StateChanges,
+}
+
+impl GCDataGroup {
+    /// Identifier of the group used in the database keys.  Must never change.
+    pub fn id(&self) -> u8 {
+        match self {
+            GCDataGroup::TransactionOutcomes => 0,
+            GCDataGroup::Transactions => 1,
+            GCDataGroup::Receipts => 2,
+            GCDataGroup::StateChanges => 3,
+        }
+    }
+}
+
+/// For how long a group of data is kept.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GCRetention {
+    /// Data of the blocks from the given number of the latest epochs is kept.
+    Epochs(u64),
+    /// Data of the blocks produced at most the given time before the head is
+    /// kept.  The time is measured with the block timestamps.
+    Duration(#[serde(with = "near_time::serde_duration_as_std")] Duration),
 }
 
 impl Default for GCConfig {
@@ -128,6 +174,7 @@ impl Default for GCConfig {
             gc_fork_clean_step: 100,
             gc_num_epochs_to_keep: DEFAULT_GC_NUM_EPOCHS_TO_KEEP,
             gc_step_period: Duration::seconds(1),
+            gc_retention: HashMap::new(),
         }
     }
 }
@@ -202,6 +249,17 @@ pub struct DumpConfig {
     /// Location of a json file with credentials allowing write access to the bucket.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials_file: Option<PathBuf>,
+    /// How many state parts may be generated at the same time.
+    /// Defaults to 4.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub num_concurrent_parts: Option<usize>,
+    /// How many generated state parts may be held in memory waiting to be
+    /// uploaded, across all shards. Bounds the memory used by the dumper.
+    /// Defaults to 16.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub max_parts_in_flight: Option<usize>,
 }
 
 /// Configures how to fetch state parts during state sync.
@@ -575,6 +633,10 @@ pub struct ClientConfig {
     /// - archive is true, cold_store is configured and migration to split_storage is finished - node
     /// working in split storage mode needs trie changes in order to do garbage collection on hot.
     pub save_trie_changes: bool,
+    /// Whether to index NEP-297 events emitted by contracts in
+    /// `DBCol::ContractEvents`, making them queryable with the
+    /// `EXPERIMENTAL_contract_events` RPC method.
+    pub index_contract_events: bool,
     /// Number of threads for ViewClientActor pool.
     pub view_client_threads: usize,
     /// Number of seconds between state requests for view client.
@@ -694,6 +756,7 @@ impl ClientConfig {
             tracked_shards_config: TrackedShardsConfig::NoShards,
             archive,
             save_trie_changes,
+            index_contract_events: false,
             log_summary_style: LogSummaryStyle::Colored,
             view_client_threads: 1,
             view_client_throttle_period: Duration::seconds(1),
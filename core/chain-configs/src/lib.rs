@@ -13,10 +13,10 @@ pub use client_config::{
     ChunkDistributionNetworkConfig, ChunkDistributionUris, ClientConfig,
     DEFAULT_GC_NUM_EPOCHS_TO_KEEP, DEFAULT_STATE_SYNC_NUM_CONCURRENT_REQUESTS_EXTERNAL,
     DEFAULT_STATE_SYNC_NUM_CONCURRENT_REQUESTS_ON_CATCHUP_EXTERNAL, DumpConfig, EpochSyncConfig,
-    ExternalStorageConfig, ExternalStorageLocation, GCConfig, LogSummaryStyle,
-    MIN_GC_NUM_EPOCHS_TO_KEEP, ReshardingConfig, ReshardingHandle, StateSyncConfig, SyncConfig,
-    TEST_STATE_SYNC_TIMEOUT, TrackedShardsConfig, default_chunk_wait_mult,
-    default_enable_multiline_logging, default_epoch_sync,
+    ExternalStorageConfig, ExternalStorageLocation, GCConfig, GCDataGroup, GCRetention,
+    LogSummaryStyle, MIN_GC_NUM_EPOCHS_TO_KEEP, ReshardingConfig, ReshardingHandle,
+    StateSyncConfig, SyncConfig, TEST_STATE_SYNC_TIMEOUT, TrackedShardsConfig,
+    default_chunk_wait_mult, default_enable_multiline_logging, default_epoch_sync,
     default_header_sync_expected_height_per_second, default_header_sync_initial_timeout,
     default_header_sync_progress_timeout, default_header_sync_stall_ban_timeout,
     default_log_summary_period, default_orphan_state_witness_max_size,
@@ -57,6 +57,7 @@ static CONFIG_DIFFS: &[(ProtocolVersion, &str)] = &[
     (77, include_config!("77.yaml")),
     (78, include_config!("78.yaml")),
     (129, include_config!("129.yaml")),
+    (150, include_config!("150.yaml")),
 ];
 
 /// Testnet parameters for versions <= 29, which (incorrectly) differed from mainnet parameters
@@ -240,6 +241,17 @@ impl RuntimeConfigStore {
         Self::with_one_config(RuntimeConfig::free())
     }
 
+    /// Applies `f` to the wasm config of every protocol version.
+    pub fn map_wasm_config(&mut self, f: impl Fn(&mut vm::Config)) {
+        for config in self.store.values_mut() {
+            let mut runtime_config = RuntimeConfig::clone(config);
+            let mut wasm_config = vm::Config::clone(&runtime_config.wasm_config);
+            f(&mut wasm_config);
+            runtime_config.wasm_config = Arc::new(wasm_config);
+            *config = Arc::new(runtime_config);
+        }
+    }
+
     /// Returns a `RuntimeConfig` for the corresponding protocol version.
     pub fn get_config(&self, protocol_version: ProtocolVersion) -> &Arc<RuntimeConfig> {
         self.store
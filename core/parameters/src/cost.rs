@@ -148,6 +148,22 @@ impl ExtCostsConfig {
             ExtCosts::bls12381_p1_decompress_element => SAFETY_MULTIPLIER * 27_000_000_000,
             ExtCosts::bls12381_p2_decompress_base => SAFETY_MULTIPLIER * 500_000_000,
             ExtCosts::bls12381_p2_decompress_element => SAFETY_MULTIPLIER * 55_000_000_000,
+            ExtCosts::p256_verify_base => SAFETY_MULTIPLIER * 216_000_000_000,
+            ExtCosts::p256_verify_byte => SAFETY_MULTIPLIER * 3_000_000,
+            ExtCosts::blake2b_base => SAFETY_MULTIPLIER * 1513656750,
+            ExtCosts::blake2b_byte => SAFETY_MULTIPLIER * 4_000_000,
+            ExtCosts::sha3_256_base => SAFETY_MULTIPLIER * 1959830425,
+            ExtCosts::sha3_256_byte => SAFETY_MULTIPLIER * 7157035,
+            ExtCosts::alt_bn128_poseidon_base => SAFETY_MULTIPLIER * 20_000_000_000,
+            ExtCosts::alt_bn128_poseidon_element => SAFETY_MULTIPLIER * 4_000_000_000,
+            ExtCosts::storage_read_account_base => SAFETY_MULTIPLIER * 25_000_000_000,
+            ExtCosts::storage_read_account_key_byte => SAFETY_MULTIPLIER * 10317511,
+            ExtCosts::storage_read_account_value_byte => SAFETY_MULTIPLIER * 1870335,
+            ExtCosts::storage_iter_batch_base => SAFETY_MULTIPLIER * 18785615250,
+            ExtCosts::storage_iter_batch_bound_byte => SAFETY_MULTIPLIER * 10317511,
+            ExtCosts::storage_iter_batch_entry_base => SAFETY_MULTIPLIER * 8333333333,
+            ExtCosts::storage_iter_batch_key_byte => SAFETY_MULTIPLIER * 10317511,
+            ExtCosts::storage_iter_batch_value_byte => SAFETY_MULTIPLIER * 1870335,
             // TODO(yield/resume): replicate fees here after estimation
             ExtCosts::yield_create_base => 300_000_000_000_000,
             ExtCosts::yield_create_byte => 300_000_000_000_000,
@@ -269,6 +285,22 @@ pub enum ExtCosts {
     bls12381_p2_decompress_element = 82,
     storage_large_read_overhead_base = 83,
     storage_large_read_overhead_byte = 84,
+    p256_verify_base = 85,
+    p256_verify_byte = 86,
+    blake2b_base = 87,
+    blake2b_byte = 88,
+    sha3_256_base = 89,
+    sha3_256_byte = 90,
+    alt_bn128_poseidon_base = 91,
+    alt_bn128_poseidon_element = 92,
+    storage_read_account_base = 93,
+    storage_read_account_key_byte = 94,
+    storage_read_account_value_byte = 95,
+    storage_iter_batch_base = 96,
+    storage_iter_batch_bound_byte = 97,
+    storage_iter_batch_entry_base = 98,
+    storage_iter_batch_key_byte = 99,
+    storage_iter_batch_value_byte = 100,
 }
 
 // Type of an action, used in fees logic.
@@ -410,6 +442,22 @@ impl ExtCosts {
             ExtCosts::bls12381_p1_decompress_element => Parameter::WasmBls12381P1DecompressElement,
             ExtCosts::bls12381_p2_decompress_base => Parameter::WasmBls12381P2DecompressBase,
             ExtCosts::bls12381_p2_decompress_element => Parameter::WasmBls12381P2DecompressElement,
+            ExtCosts::p256_verify_base => Parameter::WasmP256VerifyBase,
+            ExtCosts::p256_verify_byte => Parameter::WasmP256VerifyByte,
+            ExtCosts::blake2b_base => Parameter::WasmBlake2bBase,
+            ExtCosts::blake2b_byte => Parameter::WasmBlake2bByte,
+            ExtCosts::sha3_256_base => Parameter::WasmSha3256Base,
+            ExtCosts::sha3_256_byte => Parameter::WasmSha3256Byte,
+            ExtCosts::alt_bn128_poseidon_base => Parameter::WasmAltBn128PoseidonBase,
+            ExtCosts::alt_bn128_poseidon_element => Parameter::WasmAltBn128PoseidonElement,
+            ExtCosts::storage_read_account_base => Parameter::WasmStorageReadAccountBase,
+            ExtCosts::storage_read_account_key_byte => Parameter::WasmStorageReadAccountKeyByte,
+            ExtCosts::storage_read_account_value_byte => Parameter::WasmStorageReadAccountValueByte,
+            ExtCosts::storage_iter_batch_base => Parameter::WasmStorageIterBatchBase,
+            ExtCosts::storage_iter_batch_bound_byte => Parameter::WasmStorageIterBatchBoundByte,
+            ExtCosts::storage_iter_batch_entry_base => Parameter::WasmStorageIterBatchEntryBase,
+            ExtCosts::storage_iter_batch_key_byte => Parameter::WasmStorageIterBatchKeyByte,
+            ExtCosts::storage_iter_batch_value_byte => Parameter::WasmStorageIterBatchValueByte,
         }
     }
 }
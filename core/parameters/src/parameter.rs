@@ -166,6 +166,24 @@ pub enum Parameter {
     WasmBls12381P1DecompressElement,
     WasmBls12381P2DecompressBase,
     WasmBls12381P2DecompressElement,
+    WasmP256VerifyBase,
+    WasmP256VerifyByte,
+    WasmBlake2bBase,
+    WasmBlake2bByte,
+    #[strum(serialize = "wasm_sha3_256_base")]
+    WasmSha3256Base,
+    #[strum(serialize = "wasm_sha3_256_byte")]
+    WasmSha3256Byte,
+    WasmAltBn128PoseidonBase,
+    WasmAltBn128PoseidonElement,
+    WasmStorageReadAccountBase,
+    WasmStorageReadAccountKeyByte,
+    WasmStorageReadAccountValueByte,
+    WasmStorageIterBatchBase,
+    WasmStorageIterBatchBoundByte,
+    WasmStorageIterBatchEntryBase,
+    WasmStorageIterBatchKeyByte,
+    WasmStorageIterBatchValueByte,
 
     // Smart contract limits
     MaxGasBurnt,
@@ -196,6 +214,7 @@ pub enum Parameter {
     AccountIdValidityRulesVersion,
     YieldTimeoutLengthInBlocks,
     MaxYieldPayloadSize,
+    MaxStorageIterEntries,
 
     // Contract runtime features
     FlatStorageReads,
@@ -235,6 +254,11 @@ pub enum Parameter {
     ActionUseGlobalContract,
     ActionUseGlobalContractPerIdentifierByte,
     SaturatingFloatToInt,
+    P256Verify,
+    ExtraHashHostFunctions,
+    BulkMemory,
+    CrossAccountStorageRead,
+    StorageIterBatch,
 }
 
 #[derive(
@@ -306,6 +330,7 @@ impl Parameter {
             Parameter::YieldTimeoutLengthInBlocks,
             Parameter::MaxYieldPayloadSize,
             Parameter::PerReceiptStorageProofSizeLimit,
+            Parameter::MaxStorageIterEntries,
         ]
         .iter()
     }
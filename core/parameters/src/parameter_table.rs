@@ -329,6 +329,11 @@ impl TryFrom<&ParameterTable> for RuntimeConfig {
                 },
                 implicit_account_creation: params.get(Parameter::ImplicitAccountCreation)?,
                 eth_implicit_accounts: params.get(Parameter::EthImplicitAccounts)?,
+                p256_verify: params.get(Parameter::P256Verify)?,
+                extra_hash_host_functions: params.get(Parameter::ExtraHashHostFunctions)?,
+                bulk_memory: params.get(Parameter::BulkMemory)?,
+                cross_account_storage_read: params.get(Parameter::CrossAccountStorageRead)?,
+                storage_iter_batch: params.get(Parameter::StorageIterBatch)?,
             }),
             account_creation_config: AccountCreationConfig {
                 min_allowed_top_level_account_length: params
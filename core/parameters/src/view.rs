@@ -225,6 +225,16 @@ pub struct VMConfigView {
     pub implicit_account_creation: bool,
     /// See [VMConfig::eth_implicit_accounts](crate::vm::Config::eth_implicit_accounts).
     pub eth_implicit_accounts: bool,
+    /// See [VMConfig::p256_verify](crate::vm::Config::p256_verify).
+    pub p256_verify: bool,
+    /// See [VMConfig::extra_hash_host_functions](crate::vm::Config::extra_hash_host_functions).
+    pub extra_hash_host_functions: bool,
+    /// See [VMConfig::bulk_memory](crate::vm::Config::bulk_memory).
+    pub bulk_memory: bool,
+    /// See [VMConfig::cross_account_storage_read](crate::vm::Config::cross_account_storage_read).
+    pub cross_account_storage_read: bool,
+    /// See [VMConfig::storage_iter_batch](crate::vm::Config::storage_iter_batch).
+    pub storage_iter_batch: bool,
 
     /// Describes limits for VM and Runtime.
     ///
@@ -247,6 +257,11 @@ impl From<crate::vm::Config> for VMConfigView {
             vm_kind: config.vm_kind,
             eth_implicit_accounts: config.eth_implicit_accounts,
             saturating_float_to_int: config.saturating_float_to_int,
+            p256_verify: config.p256_verify,
+            extra_hash_host_functions: config.extra_hash_host_functions,
+            bulk_memory: config.bulk_memory,
+            cross_account_storage_read: config.cross_account_storage_read,
+            storage_iter_batch: config.storage_iter_batch,
         }
     }
 }
@@ -265,6 +280,11 @@ impl From<VMConfigView> for crate::vm::Config {
             vm_kind: view.vm_kind,
             eth_implicit_accounts: view.eth_implicit_accounts,
             saturating_float_to_int: view.saturating_float_to_int,
+            p256_verify: view.p256_verify,
+            extra_hash_host_functions: view.extra_hash_host_functions,
+            bulk_memory: view.bulk_memory,
+            cross_account_storage_read: view.cross_account_storage_read,
+            storage_iter_batch: view.storage_iter_batch,
         }
     }
 }
@@ -467,6 +487,39 @@ pub struct ExtCostsConfigView {
     pub bls12381_p1_decompress_element: Gas,
     pub bls12381_p2_decompress_base: Gas,
     pub bls12381_p2_decompress_element: Gas,
+    /// Base cost for calling `p256_verify`.
+    pub p256_verify_base: Gas,
+    /// Cost for each byte of the message passed to `p256_verify`.
+    pub p256_verify_byte: Gas,
+    /// Cost of getting blake2b base
+    pub blake2b_base: Gas,
+    /// Cost of getting blake2b per byte
+    pub blake2b_byte: Gas,
+    /// Cost of getting sha3_256 base
+    pub sha3_256_base: Gas,
+    /// Cost of getting sha3_256 per byte
+    pub sha3_256_byte: Gas,
+    /// Base cost for Poseidon hash over the alt_bn128 scalar field
+    pub alt_bn128_poseidon_base: Gas,
+    /// Cost per element of the MDS matrix for Poseidon hash over the alt_bn128
+    /// scalar field, the square of the number of inputs plus one
+    pub alt_bn128_poseidon_element: Gas,
+    /// Base cost for reading the storage of another account
+    pub storage_read_account_base: Gas,
+    /// Cost per byte of the account id and key read from the storage of another account
+    pub storage_read_account_key_byte: Gas,
+    /// Cost per byte of the value read from the storage of another account
+    pub storage_read_account_value_byte: Gas,
+    /// Base cost for reading a batch of storage entries in a key range
+    pub storage_iter_batch_base: Gas,
+    /// Cost per byte of the key range bounds of a storage entries batch
+    pub storage_iter_batch_bound_byte: Gas,
+    /// Cost per entry in a batch of storage entries
+    pub storage_iter_batch_entry_base: Gas,
+    /// Cost per key byte in a batch of storage entries
+    pub storage_iter_batch_key_byte: Gas,
+    /// Cost per value byte in a batch of storage entries
+    pub storage_iter_batch_value_byte: Gas,
 }
 
 impl From<crate::ExtCostsConfig> for ExtCostsConfigView {
@@ -566,6 +619,23 @@ impl From<crate::ExtCostsConfig> for ExtCostsConfigView {
             bls12381_p2_decompress_base: config.gas_cost(ExtCosts::bls12381_p2_decompress_base),
             bls12381_p2_decompress_element: config
                 .gas_cost(ExtCosts::bls12381_p2_decompress_element),
+            p256_verify_base: config.gas_cost(ExtCosts::p256_verify_base),
+            p256_verify_byte: config.gas_cost(ExtCosts::p256_verify_byte),
+            blake2b_base: config.gas_cost(ExtCosts::blake2b_base),
+            blake2b_byte: config.gas_cost(ExtCosts::blake2b_byte),
+            sha3_256_base: config.gas_cost(ExtCosts::sha3_256_base),
+            sha3_256_byte: config.gas_cost(ExtCosts::sha3_256_byte),
+            alt_bn128_poseidon_base: config.gas_cost(ExtCosts::alt_bn128_poseidon_base),
+            alt_bn128_poseidon_element: config.gas_cost(ExtCosts::alt_bn128_poseidon_element),
+            storage_read_account_base: config.gas_cost(ExtCosts::storage_read_account_base),
+            storage_read_account_key_byte: config.gas_cost(ExtCosts::storage_read_account_key_byte),
+            storage_read_account_value_byte: config
+                .gas_cost(ExtCosts::storage_read_account_value_byte),
+            storage_iter_batch_base: config.gas_cost(ExtCosts::storage_iter_batch_base),
+            storage_iter_batch_bound_byte: config.gas_cost(ExtCosts::storage_iter_batch_bound_byte),
+            storage_iter_batch_entry_base: config.gas_cost(ExtCosts::storage_iter_batch_entry_base),
+            storage_iter_batch_key_byte: config.gas_cost(ExtCosts::storage_iter_batch_key_byte),
+            storage_iter_batch_value_byte: config.gas_cost(ExtCosts::storage_iter_batch_value_byte),
             // removed parameters
             contract_compile_base: 0,
             contract_compile_bytes: 0,
@@ -661,6 +731,22 @@ impl From<ExtCostsConfigView> for crate::ExtCostsConfig {
                 ExtCosts::bls12381_p1_decompress_element => view.bls12381_p1_decompress_element,
                 ExtCosts::bls12381_p2_decompress_base => view.bls12381_p2_decompress_base,
                 ExtCosts::bls12381_p2_decompress_element => view.bls12381_p2_decompress_element,
+                ExtCosts::p256_verify_base => view.p256_verify_base,
+                ExtCosts::p256_verify_byte => view.p256_verify_byte,
+                ExtCosts::blake2b_base => view.blake2b_base,
+                ExtCosts::blake2b_byte => view.blake2b_byte,
+                ExtCosts::sha3_256_base => view.sha3_256_base,
+                ExtCosts::sha3_256_byte => view.sha3_256_byte,
+                ExtCosts::alt_bn128_poseidon_base => view.alt_bn128_poseidon_base,
+                ExtCosts::alt_bn128_poseidon_element => view.alt_bn128_poseidon_element,
+                ExtCosts::storage_read_account_base => view.storage_read_account_base,
+                ExtCosts::storage_read_account_key_byte => view.storage_read_account_key_byte,
+                ExtCosts::storage_read_account_value_byte => view.storage_read_account_value_byte,
+                ExtCosts::storage_iter_batch_base => view.storage_iter_batch_base,
+                ExtCosts::storage_iter_batch_bound_byte => view.storage_iter_batch_bound_byte,
+                ExtCosts::storage_iter_batch_entry_base => view.storage_iter_batch_entry_base,
+                ExtCosts::storage_iter_batch_key_byte => view.storage_iter_batch_key_byte,
+                ExtCosts::storage_iter_batch_value_byte => view.storage_iter_batch_value_byte,
         }
         .map(|_, value| ParameterCost { gas: value, compute: value });
         Self { costs }
@@ -129,6 +129,8 @@ pub struct LimitConfig {
     pub max_yield_payload_size: u64,
     /// Hard limit on the size of storage proof generated while executing a single receipt.
     pub per_receipt_storage_proof_size_limit: usize,
+    /// Maximum number of entries returned by a single `storage_iter_batch` call.
+    pub max_storage_iter_entries: u64,
 }
 
 /// Dynamic configuration parameters required for the WASM runtime to
@@ -171,6 +173,21 @@ pub struct Config {
     /// Whether to enable saturating float-to-integer wasm operators.
     pub saturating_float_to_int: bool,
 
+    /// Enable the `P256Verify` protocol feature.
+    pub p256_verify: bool,
+
+    /// Enable the `ExtraHashHostFunctions` protocol feature.
+    pub extra_hash_host_functions: bool,
+
+    /// Enable the `WasmBulkMemory` protocol feature.
+    pub bulk_memory: bool,
+
+    /// Enable the `CrossAccountStorageRead` protocol feature.
+    pub cross_account_storage_read: bool,
+
+    /// Enable the `StorageIterBatch` protocol feature.
+    pub storage_iter_batch: bool,
+
     /// Describes limits for VM and Runtime.
     pub limit_config: LimitConfig,
 }
@@ -307,6 +307,21 @@ pub enum ProtocolFeature {
     /// Move from ChunkStateWitness being a single struct to a versioned enum.
     VersionedStateWitness,
     SaturatingFloatToInt,
+    /// Host function to verify secp256r1 (P-256) ECDSA signatures, which are
+    /// used by WebAuthn / passkey authenticators.
+    P256Verify,
+    /// Host functions for the blake2b, sha3-256 and Poseidon (over the alt_bn128
+    /// scalar field) hash functions.
+    ExtraHashHostFunctions,
+    /// Enables the bulk memory operations wasm proposal (`memory.copy`, `memory.fill`,
+    /// `memory.init`, `data.drop` and the passive data segments).
+    WasmBulkMemory,
+    /// Host function to read the storage of another account on the same shard, as of the state
+    /// before the chunk being applied.
+    CrossAccountStorageRead,
+    /// Host function returning a bounded batch of the current account's storage entries in a key
+    /// range, replacing the deprecated storage iterator host functions.
+    StorageIterBatch,
 }
 
 impl ProtocolFeature {
@@ -408,6 +423,11 @@ impl ProtocolFeature {
             // that always enables this for mocknet (see config_mocknet function).
             ProtocolFeature::ShuffleShardAssignments => 143,
             ProtocolFeature::ExcludeExistingCodeFromWitnessForCodeLen => 148,
+            ProtocolFeature::P256Verify
+            | ProtocolFeature::ExtraHashHostFunctions
+            | ProtocolFeature::WasmBulkMemory
+            | ProtocolFeature::CrossAccountStorageRead
+            | ProtocolFeature::StorageIterBatch => 150,
             // Place features that are not yet in Nightly below this line.
         }
     }
@@ -427,7 +447,7 @@ pub const MIN_SUPPORTED_PROTOCOL_VERSION: ProtocolVersion = 75;
 const STABLE_PROTOCOL_VERSION: ProtocolVersion = 78;
 
 // On nightly, pick big enough version to support all features.
-const NIGHTLY_PROTOCOL_VERSION: ProtocolVersion = 149;
+const NIGHTLY_PROTOCOL_VERSION: ProtocolVersion = 150;
 
 /// Largest protocol version supported by the current binary.
 pub const PROTOCOL_VERSION: ProtocolVersion =
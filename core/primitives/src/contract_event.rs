@@ -0,0 +1,276 @@
+//! Events emitted by contracts following NEP-297, that is logs of the form
+//! `EVENT_JSON:{"standard": ..., "version": ..., "event": ..., "data": ...}`,
+//! and their encoding in `DBCol::ContractEvents`.
+
+use crate::hash::CryptoHash;
+use crate::types::{AccountId, BlockHeight};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Prefix of the logs carrying NEP-297 events.
+pub const EVENT_JSON_PREFIX: &str = "EVENT_JSON:";
+
+/// Maximal length of the standard and event names which are indexed.  Their
+/// lengths are encoded in a single byte of the key.
+const MAX_NAME_LEN: usize = u8::MAX as usize;
+
+/// A NEP-297 event parsed from a log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractEvent {
+    pub standard: String,
+    pub version: String,
+    pub event: String,
+    /// The `data` field of the event, serialized as JSON.
+    pub data: Option<String>,
+}
+
+impl ContractEvent {
+    /// Parses the event of a log, or returns `None` if the log isn't a valid
+    /// NEP-297 event.  Events with empty names or names longer than 255 bytes
+    /// are ignored as well, since they can't be indexed.
+    pub fn from_log(log: &str) -> Option<Self> {
+        #[derive(serde::Deserialize)]
+        struct Nep297Event {
+            standard: String,
+            version: String,
+            event: String,
+            #[serde(default)]
+            data: Option<serde_json::Value>,
+        }
+
+        let json = log.strip_prefix(EVENT_JSON_PREFIX)?;
+        let Nep297Event { standard, version, event, data } = serde_json::from_str(json).ok()?;
+        if !is_valid_event_name(&standard) || !is_valid_event_name(&event) {
+            return None;
+        }
+        Some(Self { standard, version, event, data: data.map(|data| data.to_string()) })
+    }
+}
+
+/// The parts of an event stored as the value of `DBCol::ContractEvents`, the
+/// rest of it is in the key.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+pub struct StoredContractEvent {
+    pub version: String,
+    pub data: Option<String>,
+}
+
+/// Key of an event in `DBCol::ContractEvents`.
+///
+/// The encoding is `account_id || standard || event || block_height ||
+/// block_hash || receipt_id || log_index`, where the strings are prefixed with
+/// their length in a single byte and the integers are big-endian, so the
+/// events of a contract for a given standard and event are sorted by height.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractEventKey {
+    pub account_id: AccountId,
+    pub standard: String,
+    pub event: String,
+    pub block_height: BlockHeight,
+    /// Hash of the block which included the outcome.  Outcomes of blocks on
+    /// forks are indexed too.
+    pub block_hash: CryptoHash,
+    pub receipt_id: CryptoHash,
+    /// Index of the log among the logs of the outcome.
+    pub log_index: u32,
+}
+
+impl ContractEventKey {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res = Self::event_prefix(&self.account_id, &self.standard, &self.event);
+        res.extend_from_slice(&self.block_height.to_be_bytes());
+        res.extend_from_slice(self.block_hash.as_ref());
+        res.extend_from_slice(self.receipt_id.as_ref());
+        res.extend_from_slice(&self.log_index.to_be_bytes());
+        res
+    }
+
+    pub fn from_bytes(key: &[u8]) -> std::io::Result<Self> {
+        let invalid_key = || std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid key");
+        let mut rest = key;
+        let mut read_name = || -> std::io::Result<String> {
+            let (&len, tail) = rest.split_first().ok_or_else(invalid_key)?;
+            let (name, tail) = tail.split_at_checked(len as usize).ok_or_else(invalid_key)?;
+            rest = tail;
+            String::from_utf8(name.to_vec()).map_err(|_| invalid_key())
+        };
+        let account_id = read_name()?.parse().map_err(|_| invalid_key())?;
+        let standard = read_name()?;
+        let event = read_name()?;
+        let rest: &[u8; 76] = rest.try_into().map_err(|_| invalid_key())?;
+        Ok(Self {
+            account_id,
+            standard,
+            event,
+            block_height: BlockHeight::from_be_bytes(rest[..8].try_into().unwrap()),
+            block_hash: CryptoHash::try_from(&rest[8..40]).unwrap(),
+            receipt_id: CryptoHash::try_from(&rest[40..72]).unwrap(),
+            log_index: u32::from_be_bytes(rest[72..].try_into().unwrap()),
+        })
+    }
+
+    /// Prefix of the keys of all the events of `standard` emitted by
+    /// `account_id`.
+    pub fn standard_prefix(account_id: &AccountId, standard: &str) -> Vec<u8> {
+        let mut res = vec![];
+        push_name(&mut res, account_id.as_str());
+        push_name(&mut res, standard);
+        res
+    }
+
+    /// Prefix of the keys of the events named `event` of `standard` emitted
+    /// by `account_id`.
+    pub fn event_prefix(account_id: &AccountId, standard: &str, event: &str) -> Vec<u8> {
+        let mut res = Self::standard_prefix(account_id, standard);
+        push_name(&mut res, event);
+        res
+    }
+
+    /// Prefix of the keys of the events named `event` of `standard` emitted
+    /// by `account_id` at `block_height`.
+    pub fn height_prefix(
+        account_id: &AccountId,
+        standard: &str,
+        event: &str,
+        block_height: BlockHeight,
+    ) -> Vec<u8> {
+        let mut res = Self::event_prefix(account_id, standard, event);
+        res.extend_from_slice(&block_height.to_be_bytes());
+        res
+    }
+}
+
+/// Returns whether `name` can be used as the standard or the event name of an
+/// indexed event.
+pub fn is_valid_event_name(name: &str) -> bool {
+    !name.is_empty() && name.len() <= MAX_NAME_LEN
+}
+
+/// Returns the smallest key greater than all the keys starting with `prefix`,
+/// or `None` if there is no such key.
+pub fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut res = prefix.to_vec();
+    while let Some(last) = res.pop() {
+        if last < u8::MAX {
+            res.push(last + 1);
+            return Some(res);
+        }
+    }
+    None
+}
+
+/// Position of an event in the results of a query of the events of a
+/// contract, which are sorted by block height, then by event name as in the
+/// keys and then by the rest of the key.  A query given a cursor returns the
+/// events after that position.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ContractEventCursor {
+    pub block_height: BlockHeight,
+    pub event: String,
+    pub block_hash: CryptoHash,
+    pub receipt_id: CryptoHash,
+    pub log_index: u32,
+}
+
+impl From<&ContractEventKey> for ContractEventCursor {
+    fn from(key: &ContractEventKey) -> Self {
+        Self {
+            block_height: key.block_height,
+            event: key.event.clone(),
+            block_hash: key.block_hash,
+            receipt_id: key.receipt_id,
+            log_index: key.log_index,
+        }
+    }
+}
+
+fn push_name(buf: &mut Vec<u8>, name: &str) {
+    debug_assert!(name.len() <= MAX_NAME_LEN);
+    buf.push(name.len() as u8);
+    buf.extend_from_slice(name.as_bytes());
+}
+
+/// Returns the events in the logs of an outcome, with the index of their log.
+pub fn parse_contract_events(logs: &[String]) -> impl Iterator<Item = (u32, ContractEvent)> + '_ {
+    logs.iter()
+        .enumerate()
+        .filter_map(|(index, log)| Some((index.try_into().ok()?, ContractEvent::from_log(log)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_event() {
+        let log = r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_mint","data":[{"owner_id":"alice.near","token_ids":["1"]}]}"#;
+        assert_eq!(
+            ContractEvent::from_log(log),
+            Some(ContractEvent {
+                standard: "nep171".to_string(),
+                version: "1.0.0".to_string(),
+                event: "nft_mint".to_string(),
+                data: Some(r#"[{"owner_id":"alice.near","token_ids":["1"]}]"#.to_string()),
+            })
+        );
+
+        let log = r#"EVENT_JSON: {"standard": "nep141", "version": "1.0.0", "event": "ft_burn"}"#;
+        assert_eq!(ContractEvent::from_log(log).unwrap().data, None);
+
+        for log in [
+            "transfer 10 tokens",
+            r#"{"standard":"nep171","version":"1.0.0","event":"nft_mint"}"#,
+            r#"EVENT_JSON:{"standard":"nep171","event":"nft_mint"}"#,
+            r#"EVENT_JSON:{"standard":"","version":"1.0.0","event":"nft_mint"}"#,
+            "EVENT_JSON:not json",
+        ] {
+            assert_eq!(ContractEvent::from_log(log), None, "{log}");
+        }
+    }
+
+    #[test]
+    fn test_key_roundtrip_and_order() {
+        let key = |account_id: &str, event: &str, block_height| ContractEventKey {
+            account_id: account_id.parse().unwrap(),
+            standard: "nep141".to_string(),
+            event: event.to_string(),
+            block_height,
+            block_hash: CryptoHash::hash_bytes(b"block"),
+            receipt_id: CryptoHash::hash_bytes(b"receipt"),
+            log_index: 1,
+        };
+        let a = key("token.near", "ft_transfer", 9);
+        assert_eq!(ContractEventKey::from_bytes(&a.to_bytes()).unwrap(), a);
+
+        let b = key("token.near", "ft_transfer", 10);
+        assert!(a.to_bytes() < b.to_bytes());
+        assert!(b.to_bytes().starts_with(&ContractEventKey::height_prefix(
+            &b.account_id,
+            "nep141",
+            "ft_transfer",
+            10
+        )));
+
+        // The events of a contract are next to each other, whatever their name.
+        let event_end =
+            prefix_end(&ContractEventKey::event_prefix(&a.account_id, "nep141", "ft_transfer"))
+                .unwrap();
+        let c = key("token.near", "ft_transfer_call", 0);
+        assert!(b.to_bytes() < event_end && event_end <= c.to_bytes());
+        assert!(
+            c.to_bytes().starts_with(&ContractEventKey::standard_prefix(&a.account_id, "nep141"))
+        );
+
+        // An account id which is a prefix of another one doesn't share its keys.
+        let d = key("token.near.sub", "ft_transfer", 0);
+        assert!(
+            !d.to_bytes().starts_with(&ContractEventKey::standard_prefix(&a.account_id, "nep141"))
+        );
+    }
+
+    #[test]
+    fn test_prefix_end() {
+        assert_eq!(prefix_end(b"ab"), Some(b"ac".to_vec()));
+        assert_eq!(prefix_end(&[1, u8::MAX, u8::MAX]), Some(vec![2]));
+        assert_eq!(prefix_end(&[u8::MAX]), None);
+    }
+}
@@ -16,6 +16,7 @@ pub mod block_header;
 pub mod challenge;
 pub mod chunk_apply_stats;
 pub mod congestion_info;
+pub mod contract_event;
 pub mod epoch_block_info;
 pub mod epoch_info;
 pub mod epoch_manager;
@@ -282,21 +282,21 @@ mod test {
             storage_remove_base -> 33 [0% host]
             storage_remove_key_byte -> 34 [0% host]
             storage_remove_ret_value_byte -> 35 [0% host]
-            storage_has_key_base -> 36 [1% host]
-            storage_has_key_byte -> 37 [1% host]
-            storage_iter_create_prefix_base -> 38 [1% host]
-            storage_iter_create_prefix_byte -> 39 [1% host]
-            storage_iter_create_range_base -> 40 [1% host]
-            storage_iter_create_from_byte -> 41 [1% host]
-            storage_iter_create_to_byte -> 42 [1% host]
-            storage_iter_next_base -> 43 [1% host]
-            storage_iter_next_key_byte -> 44 [1% host]
-            storage_iter_next_value_byte -> 45 [1% host]
-            touching_trie_node -> 46 [1% host]
-            read_cached_trie_node -> 47 [1% host]
-            promise_and_base -> 48 [1% host]
-            promise_and_per_promise -> 49 [1% host]
-            promise_return -> 50 [1% host]
+            storage_has_key_base -> 36 [0% host]
+            storage_has_key_byte -> 37 [0% host]
+            storage_iter_create_prefix_base -> 38 [0% host]
+            storage_iter_create_prefix_byte -> 39 [0% host]
+            storage_iter_create_range_base -> 40 [0% host]
+            storage_iter_create_from_byte -> 41 [0% host]
+            storage_iter_create_to_byte -> 42 [0% host]
+            storage_iter_next_base -> 43 [0% host]
+            storage_iter_next_key_byte -> 44 [0% host]
+            storage_iter_next_value_byte -> 45 [0% host]
+            touching_trie_node -> 46 [0% host]
+            read_cached_trie_node -> 47 [0% host]
+            promise_and_base -> 48 [0% host]
+            promise_and_per_promise -> 49 [0% host]
+            promise_return -> 50 [0% host]
             validator_stake_base -> 51 [1% host]
             validator_total_stake_base -> 52 [1% host]
             alt_bn128_g1_multiexp_base -> 53 [1% host]
@@ -318,19 +318,35 @@ mod test {
             bls12381_g1_multiexp_base -> 69 [1% host]
             bls12381_g1_multiexp_element -> 70 [1% host]
             bls12381_g2_multiexp_base -> 71 [1% host]
-            bls12381_g2_multiexp_element -> 72 [2% host]
-            bls12381_map_fp_to_g1_base -> 73 [2% host]
-            bls12381_map_fp_to_g1_element -> 74 [2% host]
-            bls12381_map_fp2_to_g2_base -> 75 [2% host]
-            bls12381_map_fp2_to_g2_element -> 76 [2% host]
-            bls12381_pairing_base -> 77 [2% host]
-            bls12381_pairing_element -> 78 [2% host]
-            bls12381_p1_decompress_base -> 79 [2% host]
-            bls12381_p1_decompress_element -> 80 [2% host]
-            bls12381_p2_decompress_base -> 81 [2% host]
-            bls12381_p2_decompress_element -> 82 [2% host]
-            storage_large_read_overhead_base -> 83 [2% host]
-            storage_large_read_overhead_byte -> 84 [2% host]
+            bls12381_g2_multiexp_element -> 72 [1% host]
+            bls12381_map_fp_to_g1_base -> 73 [1% host]
+            bls12381_map_fp_to_g1_element -> 74 [1% host]
+            bls12381_map_fp2_to_g2_base -> 75 [1% host]
+            bls12381_map_fp2_to_g2_element -> 76 [1% host]
+            bls12381_pairing_base -> 77 [1% host]
+            bls12381_pairing_element -> 78 [1% host]
+            bls12381_p1_decompress_base -> 79 [1% host]
+            bls12381_p1_decompress_element -> 80 [1% host]
+            bls12381_p2_decompress_base -> 81 [1% host]
+            bls12381_p2_decompress_element -> 82 [1% host]
+            storage_large_read_overhead_base -> 83 [1% host]
+            storage_large_read_overhead_byte -> 84 [1% host]
+            p256_verify_base -> 85 [1% host]
+            p256_verify_byte -> 86 [1% host]
+            blake2b_base -> 87 [1% host]
+            blake2b_byte -> 88 [1% host]
+            sha3_256_base -> 89 [1% host]
+            sha3_256_byte -> 90 [1% host]
+            alt_bn128_poseidon_base -> 91 [1% host]
+            alt_bn128_poseidon_element -> 92 [1% host]
+            storage_read_account_base -> 93 [1% host]
+            storage_read_account_key_byte -> 94 [1% host]
+            storage_read_account_value_byte -> 95 [1% host]
+            storage_iter_batch_base -> 96 [1% host]
+            storage_iter_batch_bound_byte -> 97 [1% host]
+            storage_iter_batch_entry_base -> 98 [1% host]
+            storage_iter_batch_key_byte -> 99 [1% host]
+            storage_iter_batch_value_byte -> 100 [1% host]
             ------ Actions --------
             create_account -> 1000
             delete_account -> 1001
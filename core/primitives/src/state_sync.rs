@@ -312,9 +312,41 @@ pub enum StateSyncDumpProgress {
     },
 }
 
+/// Records which state parts of a shard have been dumped for an epoch, so that a
+/// restarted dumper can resume where it left off instead of redoing the whole shard.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq, ProtocolSchema)]
+pub struct StateSyncDumpedParts {
+    /// The epoch whose state is being dumped.
+    pub epoch_id: EpochId,
+    pub num_parts: u64,
+    /// Bit `part_id % 8` of byte `part_id / 8` is set if the part has been dumped.
+    pub dumped: Vec<u8>,
+}
+
+impl StateSyncDumpedParts {
+    pub fn new(epoch_id: EpochId, num_parts: u64) -> Self {
+        Self { epoch_id, num_parts, dumped: vec![0; num_parts.div_ceil(8) as usize] }
+    }
+
+    pub fn is_dumped(&self, part_id: u64) -> bool {
+        self.dumped.get((part_id / 8) as usize).is_some_and(|byte| byte & (1 << (part_id % 8)) != 0)
+    }
+
+    pub fn set_dumped(&mut self, part_id: u64) {
+        if let Some(byte) = self.dumped.get_mut((part_id / 8) as usize) {
+            *byte |= 1 << (part_id % 8);
+        }
+    }
+
+    pub fn num_dumped(&self) -> u64 {
+        self.dumped.iter().map(|byte| byte.count_ones() as u64).sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::state_sync::{STATE_PART_MEMORY_LIMIT, get_num_state_parts};
+    use crate::state_sync::{STATE_PART_MEMORY_LIMIT, StateSyncDumpedParts, get_num_state_parts};
+    use crate::types::EpochId;
 
     #[test]
     fn test_get_num_state_parts() {
@@ -325,4 +357,21 @@ mod tests {
         assert_eq!(get_num_state_parts(STATE_PART_MEMORY_LIMIT.as_u64() * 100), 100);
         assert_eq!(get_num_state_parts(STATE_PART_MEMORY_LIMIT.as_u64() * 100 + 1), 101);
     }
+
+    #[test]
+    fn test_state_sync_dumped_parts() {
+        let mut parts = StateSyncDumpedParts::new(EpochId::default(), 10);
+        assert_eq!(parts.dumped.len(), 2);
+        assert_eq!(parts.num_dumped(), 0);
+        parts.set_dumped(0);
+        parts.set_dumped(9);
+        parts.set_dumped(9);
+        // Out of range parts are ignored.
+        parts.set_dumped(10);
+        assert!(parts.is_dumped(0));
+        assert!(!parts.is_dumped(1));
+        assert!(parts.is_dumped(9));
+        assert!(!parts.is_dumped(10));
+        assert_eq!(parts.num_dumped(), 2);
+    }
 }
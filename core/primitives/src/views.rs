@@ -14,6 +14,7 @@ use crate::block::{Block, BlockHeader, Tip};
 use crate::block_header::BlockHeaderInnerLite;
 use crate::challenge::SlashedValidator;
 use crate::congestion_info::{CongestionInfo, CongestionInfoV1};
+use crate::contract_event::{ContractEventCursor, ContractEventKey, StoredContractEvent};
 use crate::errors::TxExecutionError;
 use crate::hash::{CryptoHash, hash};
 use crate::merkle::{MerklePath, combine_hash};
@@ -376,6 +377,9 @@ pub struct PeerInfoView {
     pub is_outbound_peer: bool,
     /// Connection nonce.
     pub nonce: u64,
+    /// Reputation score of the peer. 0 is neutral, lower is worse.
+    #[serde(default)]
+    pub reputation_score: i64,
 }
 
 /// Information about a Producer: its account name, peer_id and a list of connected peers that
@@ -538,6 +542,18 @@ pub struct RequestedStatePartsView {
     pub shard_requested_parts: HashMap<ShardId, Vec<PartElapsedTimeView>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq)]
+pub struct StateSyncDumpStatusView {
+    pub shard_id: ShardId,
+    // The epoch whose state is being dumped
+    pub epoch_id: EpochId,
+    // Whether all parts of the shard have been dumped
+    pub done: bool,
+    // Set while the dump of the shard is in progress
+    pub num_parts: Option<u64>,
+    pub num_parts_dumped: Option<u64>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq)]
 pub struct BlockStatusView {
     pub height: BlockHeight,
@@ -2452,6 +2468,47 @@ pub type StateChangesView = Vec<StateChangeWithCauseView>;
 /// Maintenance windows view are a vector of maintenance window.
 pub type MaintenanceWindowsView = Vec<Range<BlockHeight>>;
 
+/// A NEP-297 event emitted by a contract, as indexed by the node.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ContractEventView {
+    pub account_id: AccountId,
+    pub standard: String,
+    pub version: String,
+    pub event: String,
+    pub data: Option<serde_json::Value>,
+    pub block_height: BlockHeight,
+    pub block_hash: CryptoHash,
+    pub receipt_id: CryptoHash,
+    /// Index of the log of the event among the logs of the receipt.
+    pub log_index: u32,
+}
+
+impl From<(ContractEventKey, StoredContractEvent)> for ContractEventView {
+    fn from((key, event): (ContractEventKey, StoredContractEvent)) -> Self {
+        Self {
+            account_id: key.account_id,
+            standard: key.standard,
+            version: event.version,
+            event: key.event,
+            // The data was serialized from a JSON value when indexed.
+            data: event.data.and_then(|data| serde_json::from_str(&data).ok()),
+            block_height: key.block_height,
+            block_hash: key.block_hash,
+            receipt_id: key.receipt_id,
+            log_index: key.log_index,
+        }
+    }
+}
+
+/// The NEP-297 events of a contract matching a query.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ContractEventsView {
+    pub events: Vec<ContractEventView>,
+    /// Set if there may be more events, the next ones are returned by the same
+    /// query with this cursor.
+    pub next_cursor: Option<ContractEventCursor>,
+}
+
 /// Contains the split storage information.
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct SplitStorageInfoView {
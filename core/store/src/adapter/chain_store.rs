@@ -3,9 +3,13 @@ use crate::{
     CHUNK_TAIL_KEY, DBCol, FINAL_HEAD_KEY, FORK_TAIL_KEY, HEAD_KEY, HEADER_HEAD_KEY,
     LARGEST_TARGET_HEIGHT_KEY, Store, StoreUpdate, TAIL_KEY, get_genesis_height,
 };
+use borsh::BorshDeserialize;
 use near_chain_primitives::Error;
 use near_primitives::block::{Block, BlockHeader, Tip};
 use near_primitives::chunk_apply_stats::ChunkApplyStats;
+use near_primitives::contract_event::{
+    ContractEventCursor, ContractEventKey, StoredContractEvent, prefix_end,
+};
 use near_primitives::hash::CryptoHash;
 use near_primitives::merkle::PartialMerkleTree;
 use near_primitives::receipt::Receipt;
@@ -16,9 +20,10 @@ use near_primitives::sharding::{
 use near_primitives::state_sync::{ShardStateSyncResponseHeader, StateHeaderKey};
 use near_primitives::transaction::{ExecutionOutcomeWithProof, SignedTransaction};
 use near_primitives::types::chunk_extra::ChunkExtra;
-use near_primitives::types::{BlockHeight, EpochId, NumBlocks, ShardId};
+use near_primitives::types::{AccountId, BlockHeight, EpochId, NumBlocks, ShardId};
 use near_primitives::utils::{get_block_shard_id, get_outcome_id_block_hash, index_to_bytes};
 use near_primitives::views::LightClientBlockView;
+use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::io;
 use std::sync::Arc;
@@ -370,6 +375,154 @@ impl ChainStoreAdapter {
             .unwrap_or_default())
     }
 
+    /// Returns the NEP-297 events of `standard` emitted by `account_id` in the
+    /// blocks of the canonical chain between `from_height` and `to_height`
+    /// inclusive, sorted by height.  If `event` is not given, events with any
+    /// name are returned.  If `after` is given, only the events after that
+    /// position are returned.  At most `limit` events are returned, together
+    /// with the cursor to get the next ones if there may be more.
+    pub fn get_contract_events(
+        &self,
+        account_id: &AccountId,
+        standard: &str,
+        event: Option<&str>,
+        from_height: BlockHeight,
+        to_height: BlockHeight,
+        after: Option<&ContractEventCursor>,
+        limit: usize,
+    ) -> Result<(Vec<(ContractEventKey, StoredContractEvent)>, Option<ContractEventCursor>), Error>
+    {
+        if limit == 0 || from_height > to_height {
+            return Ok((vec![], None));
+        }
+        let events = match event {
+            Some(event) => vec![event.to_string()],
+            None => self.get_contract_event_names(account_id, standard)?,
+        };
+        let mut res = vec![];
+        let mut more = false;
+        for event in events {
+            let Some(lower) =
+                Self::contract_events_lower_bound(account_id, standard, &event, from_height, after)
+            else {
+                continue;
+            };
+            let upper = match to_height.checked_add(1) {
+                Some(height) => {
+                    Some(ContractEventKey::height_prefix(account_id, standard, &event, height))
+                }
+                None => prefix_end(&ContractEventKey::event_prefix(account_id, standard, &event)),
+            };
+            let mut count = 0;
+            for item in self.store.iter_range(DBCol::ContractEvents, Some(&lower), upper.as_deref())
+            {
+                if count == limit {
+                    more = true;
+                    break;
+                }
+                let (key, value) = item?;
+                let key = ContractEventKey::from_bytes(&key)?;
+                // Events of blocks which were not included in the canonical
+                // chain are kept until the blocks are garbage collected.
+                let canonical_hash: Option<CryptoHash> =
+                    self.store.get_ser(DBCol::BlockHeight, &index_to_bytes(key.block_height))?;
+                if canonical_hash != Some(key.block_hash) {
+                    continue;
+                }
+                res.push((key, StoredContractEvent::try_from_slice(&value)?));
+                count += 1;
+            }
+        }
+        // The names are in the order of the keys and the sort is stable, so
+        // the events of a height stay sorted by name, receipt and log.
+        res.sort_by_key(|(key, _)| key.block_height);
+        if res.len() > limit {
+            res.truncate(limit);
+            more = true;
+        }
+        let next = if more { res.last().map(|(key, _)| key.into()) } else { None };
+        Ok((res, next))
+    }
+
+    /// Returns the first key of the events named `event` to return for a query
+    /// starting at `from_height`, after the position of `after` if given, or
+    /// `None` if there are none.
+    fn contract_events_lower_bound(
+        account_id: &AccountId,
+        standard: &str,
+        event: &str,
+        from_height: BlockHeight,
+        after: Option<&ContractEventCursor>,
+    ) -> Option<Vec<u8>> {
+        let from = ContractEventKey::height_prefix(account_id, standard, event, from_height);
+        let Some(after) = after else {
+            return Some(from);
+        };
+        // Compare the names the way they are sorted in the keys.
+        let name = ContractEventKey::event_prefix(account_id, standard, event);
+        let after_name = ContractEventKey::event_prefix(account_id, standard, &after.event);
+        let resume = match name.cmp(&after_name) {
+            // The events of this name at the height of the cursor were
+            // returned before it.
+            Ordering::Less => ContractEventKey::height_prefix(
+                account_id,
+                standard,
+                event,
+                after.block_height.checked_add(1)?,
+            ),
+            Ordering::Equal => {
+                let key = ContractEventKey {
+                    account_id: account_id.clone(),
+                    standard: standard.to_string(),
+                    event: event.to_string(),
+                    block_height: after.block_height,
+                    block_hash: after.block_hash,
+                    receipt_id: after.receipt_id,
+                    log_index: after.log_index,
+                };
+                // The smallest key greater than the one of the cursor.
+                let mut key = key.to_bytes();
+                key.push(0);
+                key
+            }
+            Ordering::Greater => {
+                ContractEventKey::height_prefix(account_id, standard, event, after.block_height)
+            }
+        };
+        Some(resume.max(from))
+    }
+
+    /// Returns the names of the indexed events of `standard` emitted by
+    /// `account_id`, jumping from one name to the next instead of iterating
+    /// over all the events.
+    fn get_contract_event_names(
+        &self,
+        account_id: &AccountId,
+        standard: &str,
+    ) -> Result<Vec<String>, Error> {
+        let prefix = ContractEventKey::standard_prefix(account_id, standard);
+        let upper = prefix_end(&prefix);
+        let mut lower = prefix;
+        let mut names = vec![];
+        loop {
+            let item =
+                self.store.iter_range(DBCol::ContractEvents, Some(&lower), upper.as_deref()).next();
+            let Some(item) = item else {
+                break;
+            };
+            let (key, _) = item?;
+            let key = ContractEventKey::from_bytes(&key)?;
+            let next =
+                prefix_end(&ContractEventKey::event_prefix(account_id, standard, &key.event));
+            names.push(key.event);
+            match next {
+                Some(next) => lower = next,
+                None => break,
+            }
+        }
+        Ok(names)
+    }
+
     pub fn get_state_header(
         &self,
         shard_id: ShardId,
@@ -4,6 +4,7 @@ use borsh::BorshDeserialize;
 use near_primitives::hash::CryptoHash;
 use near_primitives::shard_layout::ShardUId;
 use near_primitives::state::FlatStateValue;
+use near_primitives::types::BlockHeight;
 
 use crate::flat::delta::{BlockWithChangesInfo, KeyForFlatStateDelta};
 use crate::flat::{
@@ -140,6 +141,111 @@ impl FlatStoreAdapter {
         Ok(prev_block_with_changes)
     }
 
+    /// Returns the value `key` had at `height` if it was changed by a later
+    /// block, according to `DBCol::FlatStateHistory`.  Returns `None` if the
+    /// key was not changed since `height`, so its value is the one at flat
+    /// storage head.
+    pub fn get_history_value(
+        &self,
+        shard_uid: ShardUId,
+        key: &[u8],
+        height: BlockHeight,
+    ) -> Result<Option<Option<FlatStateValue>>, FlatStorageError> {
+        let db_key_from = encode_flat_state_history_db_key(shard_uid, key, height + 1);
+        let db_key_to = encode_flat_state_history_db_key(shard_uid, key, BlockHeight::MAX);
+        let mut iter =
+            self.store.iter_range(DBCol::FlatStateHistory, Some(&db_key_from), Some(&db_key_to));
+        let Some(result) = iter.next() else {
+            return Ok(None);
+        };
+        let (_, value) = result.map_err(|err| {
+            FlatStorageError::StorageInternalError(format!(
+                "failed to read FlatStateHistory value: {err}"
+            ))
+        })?;
+        decode_flat_state_history_value(&value).map(Some)
+    }
+
+    /// Returns the values that the keys starting with `prefix` had at
+    /// `height`, for the keys which were changed by a later block, in the
+    /// order of keys.  Other keys have the same value as at flat storage head.
+    pub fn get_history_values_with_prefix(
+        &self,
+        shard_uid: ShardUId,
+        prefix: &[u8],
+        height: BlockHeight,
+    ) -> Result<Vec<(Vec<u8>, Option<FlatStateValue>)>, FlatStorageError> {
+        let db_prefix = encode_flat_state_history_db_key_prefix(shard_uid, prefix);
+        let db_prefix_end = prefix_upper_bound(&db_prefix);
+        let mut values: Vec<(Vec<u8>, Option<FlatStateValue>)> = Vec::new();
+        for result in self.store.iter_range(
+            DBCol::FlatStateHistory,
+            Some(&db_prefix),
+            db_prefix_end.as_deref(),
+        ) {
+            let (db_key, value) = result.map_err(|err| {
+                FlatStorageError::StorageInternalError(format!(
+                    "failed to read FlatStateHistory value: {err}"
+                ))
+            })?;
+            let (key, changed_at) = decode_flat_state_history_db_key(&db_key)?;
+            // Rows of a key are ordered by height, the first one above
+            // `height` has the value at `height`.
+            if changed_at <= height || values.last().is_some_and(|(last, _)| last == &key) {
+                continue;
+            }
+            values.push((key, decode_flat_state_history_value(&value)?));
+        }
+        Ok(values)
+    }
+
+    /// Returns the first height for which the history of flat state of the
+    /// shard is available, or `None` if the history is not kept.
+    pub fn get_history_tail(
+        &self,
+        shard_uid: ShardUId,
+    ) -> Result<Option<BlockHeight>, FlatStorageError> {
+        self.get_history_keys_until(shard_uid, BlockHeight::MAX, 1)
+            .map(|rows| rows.first().map(|(height, _)| *height))
+    }
+
+    /// Returns up to `limit` rows of `DBCol::FlatStateHistoryKeys` for heights
+    /// lower than `height`, in increasing order of heights.
+    pub fn get_history_keys_until(
+        &self,
+        shard_uid: ShardUId,
+        height: BlockHeight,
+        limit: usize,
+    ) -> Result<Vec<(BlockHeight, Vec<Vec<u8>>)>, FlatStorageError> {
+        let db_key_to = encode_flat_state_history_keys_db_key(shard_uid, height);
+        self.store
+            .iter_range(DBCol::FlatStateHistoryKeys, Some(&shard_uid.to_bytes()), Some(&db_key_to))
+            .take(limit)
+            .map(|result| {
+                let (db_key, value) = result.map_err(|err| {
+                    FlatStorageError::StorageInternalError(format!(
+                        "failed to read FlatStateHistoryKeys: {err}"
+                    ))
+                })?;
+                let height = db_key
+                    .get(8..)
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .map(BlockHeight::from_be_bytes)
+                    .ok_or_else(|| {
+                        FlatStorageError::StorageInternalError(format!(
+                            "invalid FlatStateHistoryKeys key format: {db_key:?}"
+                        ))
+                    })?;
+                let keys = Vec::<Vec<u8>>::try_from_slice(&value).map_err(|err| {
+                    FlatStorageError::StorageInternalError(format!(
+                        "invalid FlatStateHistoryKeys value format: {err}"
+                    ))
+                })?;
+                Ok((height, keys))
+            })
+            .collect()
+    }
+
     /// Returns iterator over entire range of flat storage entries.
     /// It reads data only from `FlatState` column which represents the state at
     /// flat storage head. Reads only committed changes.
@@ -147,6 +253,12 @@ impl FlatStoreAdapter {
         self.iter_range(shard_uid, None, None)
     }
 
+    /// Returns iterator over flat storage entries for a given shard whose
+    /// state keys start with `prefix`, at flat storage head.
+    pub fn iter_prefix<'a>(&'a self, shard_uid: ShardUId, prefix: &[u8]) -> FlatStateIterator<'a> {
+        self.iter_range(shard_uid, Some(prefix), prefix_upper_bound(prefix).as_deref())
+    }
+
     /// Returns iterator over flat storage entries for a given shard and range of state keys.
     /// It reads data only from `FlatState` column which represents the state at
     /// flat storage head. Reads only committed changes.
@@ -270,10 +382,47 @@ impl<'a> FlatStoreUpdateAdapter<'a> {
         self.remove_range_by_shard_uid(shard_uid, DBCol::FlatStateDeltaMetadata);
     }
 
-    /// Removes flat storage in its entirety for a shard: deltas, values and status.
+    /// Saves the value `key` had before it was changed by the block at `height`.
+    pub fn set_history_value(
+        &mut self,
+        shard_uid: ShardUId,
+        key: &[u8],
+        height: BlockHeight,
+        value: &Option<FlatStateValue>,
+    ) {
+        let db_key = encode_flat_state_history_db_key(shard_uid, key, height);
+        self.store_update
+            .set_ser(DBCol::FlatStateHistory, &db_key, value)
+            .expect("Borsh should not have failed here");
+    }
+
+    pub fn remove_history_value(&mut self, shard_uid: ShardUId, key: &[u8], height: BlockHeight) {
+        let db_key = encode_flat_state_history_db_key(shard_uid, key, height);
+        self.store_update.delete(DBCol::FlatStateHistory, &db_key);
+    }
+
+    pub fn set_history_keys(&mut self, shard_uid: ShardUId, height: BlockHeight, keys: &[Vec<u8>]) {
+        let db_key = encode_flat_state_history_keys_db_key(shard_uid, height);
+        self.store_update
+            .set_ser(DBCol::FlatStateHistoryKeys, &db_key, keys)
+            .expect("Borsh should not have failed here");
+    }
+
+    pub fn remove_history_keys(&mut self, shard_uid: ShardUId, height: BlockHeight) {
+        let db_key = encode_flat_state_history_keys_db_key(shard_uid, height);
+        self.store_update.delete(DBCol::FlatStateHistoryKeys, &db_key);
+    }
+
+    pub fn remove_history(&mut self, shard_uid: ShardUId) {
+        self.remove_range_by_shard_uid(shard_uid, DBCol::FlatStateHistory);
+        self.remove_range_by_shard_uid(shard_uid, DBCol::FlatStateHistoryKeys);
+    }
+
+    /// Removes flat storage in its entirety for a shard: deltas, values, history and status.
     pub fn remove_flat_storage(&mut self, shard_uid: ShardUId) {
         self.remove_all_deltas(shard_uid);
         self.remove_all_values(shard_uid);
+        self.remove_history(shard_uid);
         self.remove_status(shard_uid);
     }
 
@@ -293,6 +442,95 @@ pub fn encode_flat_state_db_key(shard_uid: ShardUId, key: &[u8]) -> Vec<u8> {
     buffer
 }
 
+/// Encodes a key of `DBCol::FlatStateHistory`.  Zero bytes of the trie key
+/// are escaped as `0x00 0xff` and the trie key is terminated with
+/// `0x00 0x00`, so that the rows of a key are contiguous and sorted by
+/// height, while the rows of all keys starting with a prefix still share
+/// an encoded prefix (see `encode_flat_state_history_db_key_prefix`).
+pub fn encode_flat_state_history_db_key(
+    shard_uid: ShardUId,
+    key: &[u8],
+    height: BlockHeight,
+) -> Vec<u8> {
+    let mut buffer = encode_flat_state_history_db_key_prefix(shard_uid, key);
+    buffer.extend_from_slice(&[0, 0]);
+    buffer.extend_from_slice(&height.to_be_bytes());
+    buffer
+}
+
+fn encode_flat_state_history_db_key_prefix(shard_uid: ShardUId, prefix: &[u8]) -> Vec<u8> {
+    let mut buffer = shard_uid.to_bytes().to_vec();
+    for &byte in prefix {
+        buffer.push(byte);
+        if byte == 0 {
+            buffer.push(0xff);
+        }
+    }
+    buffer
+}
+
+/// Decodes the trie key and the height from a key of `DBCol::FlatStateHistory`.
+fn decode_flat_state_history_db_key(
+    db_key: &[u8],
+) -> Result<(Vec<u8>, BlockHeight), FlatStorageError> {
+    let invalid = || {
+        FlatStorageError::StorageInternalError(format!(
+            "invalid FlatStateHistory key format: {db_key:?}"
+        ))
+    };
+    let encoded = db_key.get(8..).ok_or_else(invalid)?;
+    let mut key = Vec::with_capacity(encoded.len());
+    let mut pos = 0;
+    loop {
+        match encoded.get(pos..pos + 2) {
+            Some([0, 0]) => break,
+            Some([0, 0xff]) => {
+                key.push(0);
+                pos += 2;
+            }
+            Some([0, _]) | None => return Err(invalid()),
+            Some([byte, _]) => {
+                key.push(*byte);
+                pos += 1;
+            }
+            Some(_) => unreachable!(),
+        }
+    }
+    let height =
+        encoded.get(pos + 2..).and_then(|bytes| bytes.try_into().ok()).ok_or_else(invalid)?;
+    Ok((key, BlockHeight::from_be_bytes(height)))
+}
+
+fn decode_flat_state_history_value(
+    value: &[u8],
+) -> Result<Option<FlatStateValue>, FlatStorageError> {
+    Option::<FlatStateValue>::try_from_slice(value).map_err(|err| {
+        FlatStorageError::StorageInternalError(format!(
+            "invalid FlatStateHistory value format: {err}"
+        ))
+    })
+}
+
+/// Returns the smallest key which is greater than all keys starting with
+/// `prefix`, or `None` if there is no such key.
+fn prefix_upper_bound(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut bound = prefix.to_vec();
+    while let Some(last) = bound.pop() {
+        if last < u8::MAX {
+            bound.push(last + 1);
+            return Some(bound);
+        }
+    }
+    None
+}
+
+fn encode_flat_state_history_keys_db_key(shard_uid: ShardUId, height: BlockHeight) -> [u8; 16] {
+    let mut buffer = [0; 16];
+    buffer[..8].copy_from_slice(&shard_uid.to_bytes());
+    buffer[8..].copy_from_slice(&height.to_be_bytes());
+    buffer
+}
+
 pub fn decode_flat_state_db_key(key: &[u8]) -> io::Result<(ShardUId, Vec<u8>)> {
     let (shard_uid_bytes, trie_key) = key.split_at_checked(8).ok_or_else(|| {
         io::Error::other(format!("expected FlatState key length to be at least 8: {key:?}"))
@@ -341,4 +579,50 @@ mod tests {
             assert_eq!(entries, vec![Ok((key, FlatStateValue::inlined(&val)))]);
         }
     }
+
+    #[test]
+    fn history_of_keys_with_common_prefix() {
+        let store = create_test_store().flat_store();
+        let shard_uid = ShardUId::single_shard();
+        let value = |i: u8| Some(FlatStateValue::inlined(&[i]));
+        let rows: [(&[u8], u64, Option<FlatStateValue>); 7] = [
+            (&[1], 10, value(1)),
+            (&[1], 20, None),
+            (&[1, 0], 5, value(2)),
+            (&[1, 0], 15, value(3)),
+            (&[1, 0, 0], 12, value(4)),
+            (&[1, 2], 18, value(5)),
+            (&[2], 11, value(6)),
+        ];
+        let mut store_update = store.store_update();
+        for (key, height, value) in &rows {
+            store_update.flat_store_update().set_history_value(shard_uid, key, *height, value);
+        }
+        store_update.commit().unwrap();
+
+        assert_eq!(store.get_history_value(shard_uid, &[1], 9).unwrap(), Some(value(1)));
+        assert_eq!(store.get_history_value(shard_uid, &[1], 10).unwrap(), Some(None));
+        assert_eq!(store.get_history_value(shard_uid, &[1], 20).unwrap(), None);
+        assert_eq!(store.get_history_value(shard_uid, &[1, 0], 10).unwrap(), Some(value(3)));
+        assert_eq!(store.get_history_value(shard_uid, &[1, 0, 0], 0).unwrap(), Some(value(4)));
+        assert_eq!(store.get_history_value(shard_uid, &[1, 0, 0, 0], 0).unwrap(), None);
+
+        assert_eq!(
+            store.get_history_values_with_prefix(shard_uid, &[1], 12).unwrap(),
+            vec![(vec![1], None), (vec![1, 0], value(3)), (vec![1, 2], value(5))]
+        );
+        assert_eq!(
+            store.get_history_values_with_prefix(shard_uid, &[1, 0], 0).unwrap(),
+            vec![(vec![1, 0], value(2)), (vec![1, 0, 0], value(4))]
+        );
+        assert_eq!(
+            store.get_history_values_with_prefix(shard_uid, &[], 11).unwrap(),
+            vec![
+                (vec![1], None),
+                (vec![1, 0], value(3)),
+                (vec![1, 0, 0], value(4)),
+                (vec![1, 2], value(5))
+            ]
+        );
+    }
 }
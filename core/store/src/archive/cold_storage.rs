@@ -6,11 +6,13 @@ use crate::{DBCol, DBTransaction, Database, Store, TrieChanges, metrics};
 use borsh::BorshDeserialize;
 use near_primitives::block::{Block, BlockHeader, Tip};
 use near_primitives::hash::CryptoHash;
+use near_primitives::receipt::Receipt;
 use near_primitives::shard_layout::ShardLayout;
 use near_primitives::sharding::ShardChunk;
-use near_primitives::types::BlockHeight;
+use near_primitives::transaction::{ExecutionOutcomeWithProof, SignedTransaction};
+use near_primitives::types::{AccountId, BlockHeight, RawStateChangesWithTrieKey};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io;
 use std::sync::Arc;
 use strum::IntoEnumIterator;
@@ -137,6 +139,111 @@ pub fn update_cold_db(
     Ok(())
 }
 
+/// Updates provided cold database of a partial archival node from provided hot
+/// store with information about block at `height`.
+///
+/// Unlike `update_cold_db`, only the history touching `accounts` is copied:
+/// state changes of the accounts, transactions signed by or sent to them,
+/// receipts sent by or to them and the outcomes of those transactions and
+/// receipts or executed by the accounts.  The blocks and chunks themselves are
+/// copied in full, so that the history can still be looked up by block height
+/// or hash.  Everything else is dropped by the garbage collection of the hot
+/// storage as usual.
+pub fn update_cold_db_for_accounts(
+    cold_db: &ColdDB,
+    hot_store: &Store,
+    shard_layout: &ShardLayout,
+    height: &BlockHeight,
+    is_last_block_in_epoch: bool,
+    accounts: &HashSet<AccountId>,
+) -> io::Result<()> {
+    let _span =
+        tracing::debug_span!(target: "cold_store", "update cold db for accounts", height = height);
+    let _timer = metrics::COLD_COPY_DURATION.start_timer();
+
+    let height_key = height.to_le_bytes();
+    let block_hash_vec = hot_store.get_or_err_for_cold(DBCol::BlockHeight, &height_key)?;
+    let block_hash_key = block_hash_vec.as_slice();
+
+    let key_type_to_keys =
+        get_keys_from_store(&hot_store, shard_layout, &height_key, block_hash_key)?;
+    let is_tracked = |account_id: &AccountId| accounts.contains(account_id);
+
+    let mut transaction = DBTransaction::new();
+    let mut copy_col = |col: DBCol, keep: &mut dyn FnMut(&[u8], &[u8]) -> io::Result<bool>| {
+        for key in combine_keys(&key_type_to_keys, &col.key_type()) {
+            let Some(value) = hot_store.get_for_cold(col, &key)? else { continue };
+            if keep(&key, &value)? {
+                rc_aware_set(&mut transaction, col, key, value);
+            }
+        }
+        io::Result::Ok(())
+    };
+
+    let mut columns = vec![DBCol::BlockHeader, DBCol::Block, DBCol::BlockPerHeight, DBCol::Chunks];
+    if is_last_block_in_epoch {
+        columns.push(DBCol::StateShardUIdMapping);
+    }
+    for col in columns {
+        copy_col(col, &mut |_, _| Ok(true))?;
+    }
+    copy_col(DBCol::StateChanges, &mut |_, value| {
+        let changes = RawStateChangesWithTrieKey::try_from_slice(value)?;
+        Ok(changes.trie_key.get_account_id().is_some_and(|account_id| is_tracked(&account_id)))
+    })?;
+    // The outcomes of the copied transactions and receipts are copied too,
+    // even if executed by another account, so that their status can be
+    // looked up.  Transactions and receipts are keyed by their outcome ids.
+    let mut copied_ids = HashSet::new();
+    copy_col(DBCol::Transactions, &mut |key, value| {
+        let tx = SignedTransaction::try_from_slice(value)?;
+        let keep =
+            is_tracked(tx.transaction.signer_id()) || is_tracked(tx.transaction.receiver_id());
+        if keep {
+            copied_ids.insert(key.to_vec());
+        }
+        Ok(keep)
+    })?;
+    copy_col(DBCol::Receipts, &mut |key, value| {
+        let receipt = Receipt::try_from_slice(value)?;
+        let keep = is_tracked(receipt.receiver_id()) || is_tracked(receipt.predecessor_id());
+        if keep {
+            copied_ids.insert(key.to_vec());
+        }
+        Ok(keep)
+    })?;
+    debug_assert_eq!(
+        DBCol::TransactionResultForBlock.key_type(),
+        &[DBKeyType::OutcomeId, DBKeyType::BlockHash]
+    );
+    let mut outcome_ids = HashSet::new();
+    copy_col(DBCol::TransactionResultForBlock, &mut |key, value| {
+        let outcome_id = &key[..CryptoHash::LENGTH];
+        let outcome = ExecutionOutcomeWithProof::try_from_slice(value)?;
+        let keep = is_tracked(&outcome.outcome.executor_id) || copied_ids.contains(outcome_id);
+        if keep {
+            outcome_ids.insert(CryptoHash::try_from(outcome_id).unwrap());
+        }
+        Ok(keep)
+    })?;
+
+    // Only the ids of the copied outcomes are kept, so that the outcomes of
+    // a block can still be listed.
+    for key in combine_keys(&key_type_to_keys, &DBCol::OutcomeIds.key_type()) {
+        let Some(ids) = hot_store.get_ser_for_cold::<Vec<CryptoHash>>(DBCol::OutcomeIds, &key)?
+        else {
+            continue;
+        };
+        let ids: Vec<_> = ids.into_iter().filter(|id| outcome_ids.contains(id)).collect();
+        if !ids.is_empty() {
+            transaction.set(DBCol::OutcomeIds, key, borsh::to_vec(&ids)?);
+        }
+    }
+
+    cold_db.write(transaction)?;
+    Ok(())
+}
+
 // Correctly set the key and value on DBTransaction, taking reference counting
 // into account. For non-rc columns it just sets the value. For rc columns it
 // appends rc = 1 to the value and sets it.
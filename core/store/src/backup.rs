@@ -0,0 +1,586 @@
+//! Incremental backups of the node storage.
+//!
+//! A backup is made by creating checkpoints of the hot and, if configured,
+//! cold databases.  Creating a checkpoint doesn’t block writes so backups can
+//! be made while the node is running.  The checkpoints are created next to the
+//! databases, where their files are hard links rather than copies, and are
+//! removed once the files missing from the backup are copied out of them.
+//!
+//! Neither RocksDB SST files nor `FilesystemDB` segments are modified once
+//! written, so each of them is exported only once into a directory shared by
+//! all backups and subsequent backups only reference it.  All other files
+//! (MANIFEST, OPTIONS, WAL etc.) are small and are stored with each backup.
+//! The backup directory is laid out as follows:
+//!
+//! ```text
+//! <backup_dir>/shared/{hot,cold}/<immutable files>
+//! <backup_dir>/<id>/{hot,cold}/<other files>
+//! <backup_dir>/<id>/manifest.json
+//! ```
+//!
+//! File names are reused when a database is recreated or restored, and by the
+//! merged `FilesystemDB` segments, so the name of a shared file also includes a
+//! fingerprint of its size and tail, which holds the SST properties and the
+//! segment index.  Hashing only the tail keeps incremental backups from reading
+//! the whole database.
+//!
+//! The manifest records size and SHA-256 hash of every file in the backup which
+//! are checked when the backup is verified or restored.
+
+use crate::{NodeStorage, Temperature};
+use anyhow::Context;
+use sha2::Digest;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const SHARED_DIR: &str = "shared";
+/// Extensions of the files which are never modified once written: RocksDB SST
+/// files and `FilesystemDB` segments.
+const IMMUTABLE_EXTENSIONS: [&str; 2] = ["sst", "seg"];
+/// Number of bytes at the end of an immutable file included in its fingerprint.
+const FINGERPRINT_TAIL_SIZE: u64 = 64 * 1024;
+
+/// Description of a single backup stored in its `manifest.json` file.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BackupManifest {
+    /// Sequential number of the backup within the backup directory.
+    pub id: u64,
+    /// Time the backup was created at, in seconds since Unix epoch.
+    pub created_at: u64,
+    /// Number of bytes exported by this backup, i.e. size of all of its files
+    /// excluding shared files already exported by earlier backups.
+    pub exported_size: u64,
+    /// Databases included in the backup.
+    pub databases: Vec<BackupDatabase>,
+}
+
+/// Files of a single database included in a backup.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BackupDatabase {
+    /// Temperature of the database, either `hot` or `cold`.
+    pub temperature: String,
+    pub files: Vec<BackupFile>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BackupFile {
+    /// Path of the file relative to the database directory.
+    pub name: PathBuf,
+    pub size: u64,
+    /// Hex-encoded SHA-256 hash of the file contents.
+    pub sha256: String,
+    /// Path of the file relative to the shared directory of the database if
+    /// it lives there rather than in the directory of the backup.
+    pub shared_name: Option<PathBuf>,
+}
+
+impl BackupManifest {
+    /// Returns total size of all files in the backup.
+    pub fn total_size(&self) -> u64 {
+        self.databases.iter().flat_map(|db| db.files.iter()).map(|file| file.size).sum()
+    }
+
+    fn file_path(&self, backup_dir: &Path, db: &BackupDatabase, file: &BackupFile) -> PathBuf {
+        match &file.shared_name {
+            Some(shared_name) => {
+                backup_dir.join(SHARED_DIR).join(&db.temperature).join(shared_name)
+            }
+            None => backup_dir.join(self.id.to_string()).join(&db.temperature).join(&file.name),
+        }
+    }
+
+    fn shared_files<'a>(
+        &'a self,
+        backup_dir: &'a Path,
+    ) -> impl Iterator<Item = (PathBuf, &'a BackupFile)> + 'a {
+        self.databases.iter().flat_map(move |db| {
+            db.files
+                .iter()
+                .filter(|file| file.shared_name.is_some())
+                .map(move |file| (self.file_path(backup_dir, db, file), file))
+        })
+    }
+}
+
+/// Creates a new backup of the storage in `backup_dir`.  `hot_path` and
+/// `cold_path` are the directories of the databases, see `restore_backup`.
+///
+/// Only immutable files which haven’t been exported by any earlier backup in
+/// the same directory are copied.
+pub fn create_backup(
+    storage: &NodeStorage,
+    hot_path: &Path,
+    cold_path: Option<&Path>,
+    backup_dir: &Path,
+) -> anyhow::Result<BackupManifest> {
+    std::fs::create_dir_all(backup_dir.join(SHARED_DIR))
+        .with_context(|| format!("failed to create {}", backup_dir.display()))?;
+    let backups = list_backups(backup_dir)?;
+    let id = backups.last().map_or(0, |manifest| manifest.id + 1);
+    // Hashes of the shared files, so that they don’t need to be read again.
+    let shared_hashes: HashMap<PathBuf, &str> = backups
+        .iter()
+        .flat_map(|manifest| manifest.shared_files(backup_dir))
+        .map(|(path, file)| (path, file.sha256.as_str()))
+        .collect();
+
+    // The backup is assembled in a staging directory and moved into place once
+    // complete so that partially written backups are never listed.
+    let staging_dir = backup_dir.join(format!("{id}.tmp"));
+    if staging_dir.exists() {
+        std::fs::remove_dir_all(&staging_dir)?;
+    }
+    std::fs::create_dir_all(&staging_dir)?;
+
+    let mut databases = vec![(Temperature::Hot, storage.get_hot_store().storage, Some(hot_path))];
+    if let Some(cold_db) = storage.cold_db() {
+        databases.push((Temperature::Cold, Arc::clone(cold_db) as _, cold_path));
+    }
+
+    let created_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let mut manifest = BackupManifest { id, created_at, exported_size: 0, databases: Vec::new() };
+    for (temp, db, db_path) in databases {
+        let temperature = temperature_dir(temp);
+        // Databases without a local directory, e.g. in an object store, are
+        // checkpointed into the staging directory.
+        let checkpoint_dir = match db_path {
+            Some(db_path) => checkpoint_dir(db_path),
+            None => staging_dir.join(format!("{temperature}.checkpoint")),
+        };
+        if checkpoint_dir.exists() {
+            std::fs::remove_dir_all(&checkpoint_dir)?;
+        }
+        let files = db.create_checkpoint(&checkpoint_dir, None).and_then(|()| {
+            export_checkpoint(
+                &checkpoint_dir,
+                &staging_dir.join(temperature),
+                &backup_dir.join(SHARED_DIR).join(temperature),
+                &shared_hashes,
+                &mut manifest.exported_size,
+            )
+        });
+        if checkpoint_dir.exists() {
+            std::fs::remove_dir_all(&checkpoint_dir).with_context(|| {
+                format!("failed to remove checkpoint {}", checkpoint_dir.display())
+            })?;
+        }
+        manifest
+            .databases
+            .push(BackupDatabase { temperature: temperature.to_string(), files: files? });
+    }
+
+    let manifest_path = staging_dir.join(MANIFEST_FILE);
+    std::fs::write(&manifest_path, serde_json::to_vec_pretty(&manifest)?)
+        .with_context(|| format!("failed to write {}", manifest_path.display()))?;
+    std::fs::rename(&staging_dir, backup_dir.join(id.to_string()))?;
+    tracing::info!(target: "store", id, exported_size = manifest.exported_size, total_size = manifest.total_size(), backup_dir = %backup_dir.display(), "Created backup");
+    Ok(manifest)
+}
+
+/// Copies the files of the checkpoint into the backup.  Immutable files are
+/// exported into `shared_dir` unless an earlier backup already did, all other
+/// files are copied into `backup_db_dir`.  Adds the number of copied bytes to
+/// `exported_size`.
+fn export_checkpoint(
+    checkpoint_dir: &Path,
+    backup_db_dir: &Path,
+    shared_dir: &Path,
+    shared_hashes: &HashMap<PathBuf, &str>,
+    exported_size: &mut u64,
+) -> anyhow::Result<Vec<BackupFile>> {
+    let mut files = Vec::new();
+    for name in list_files(checkpoint_dir)? {
+        let path = checkpoint_dir.join(&name);
+        let size = std::fs::metadata(&path)?.len();
+        let is_immutable = name
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| IMMUTABLE_EXTENSIONS.contains(&ext));
+        if !is_immutable {
+            let dst = backup_db_dir.join(&name);
+            std::fs::create_dir_all(dst.parent().unwrap())?;
+            let (size, sha256) = hash_file(&path, Some(&dst))
+                .with_context(|| format!("failed to copy {}", path.display()))?;
+            *exported_size += size;
+            files.push(BackupFile { name, size, sha256, shared_name: None });
+            continue;
+        }
+
+        let shared_name = shared_name(&path, &name, size)?;
+        let shared_path = shared_dir.join(&shared_name);
+        let sha256 = if shared_path.exists() {
+            match shared_hashes.get(&shared_path) {
+                Some(sha256) => sha256.to_string(),
+                // Left behind by a backup which failed before writing its
+                // manifest.
+                None => hash_file(&shared_path, None)?.1,
+            }
+        } else {
+            export_file(&path, &shared_path).with_context(|| {
+                format!("failed to move {} to {}", path.display(), shared_path.display())
+            })?;
+            *exported_size += size;
+            hash_file(&shared_path, None)?.1
+        };
+        files.push(BackupFile { name, size, sha256, shared_name: Some(shared_name) });
+    }
+    Ok(files)
+}
+
+/// Deletes all but the `keep` most recent backups in `backup_dir` along with
+/// the shared files no longer referenced by any of the remaining backups.
+/// Returns ids of the deleted backups.
+///
+/// Must not run concurrently with `create_backup` on the same directory since
+/// the files exported by a backup in progress aren’t referenced yet.
+pub fn prune_backups(backup_dir: &Path, keep: usize) -> anyhow::Result<Vec<u64>> {
+    anyhow::ensure!(keep > 0, "at least one backup must be kept");
+    let mut backups = list_backups(backup_dir)?;
+    let deleted: Vec<u64> =
+        backups.drain(..backups.len().saturating_sub(keep)).map(|manifest| manifest.id).collect();
+    for id in &deleted {
+        // The backup is renamed first so that it isn’t listed anymore if
+        // deleting it fails midway.
+        let path = backup_dir.join(format!("{id}.deleted"));
+        std::fs::rename(backup_dir.join(id.to_string()), &path)?;
+        std::fs::remove_dir_all(&path)
+            .with_context(|| format!("failed to delete {}", path.display()))?;
+    }
+
+    let referenced: HashSet<PathBuf> = backups
+        .iter()
+        .flat_map(|manifest| manifest.shared_files(backup_dir))
+        .map(|(path, _)| path)
+        .collect();
+    let shared_dir = backup_dir.join(SHARED_DIR);
+    let mut freed_size = 0;
+    if shared_dir.exists() {
+        for name in list_files(&shared_dir)? {
+            let path = shared_dir.join(name);
+            if !referenced.contains(&path) {
+                freed_size += std::fs::metadata(&path)?.len();
+                std::fs::remove_file(&path)?;
+            }
+        }
+    }
+    tracing::info!(target: "store", ?deleted, freed_size, backup_dir = %backup_dir.display(), "Pruned backups");
+    Ok(deleted)
+}
+
+/// Returns manifests of all complete backups in `backup_dir` ordered by id.
+pub fn list_backups(backup_dir: &Path) -> anyhow::Result<Vec<BackupManifest>> {
+    if !backup_dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut manifests = Vec::new();
+    for entry in std::fs::read_dir(backup_dir)? {
+        let entry = entry?;
+        let Some(id) = entry.file_name().to_str().and_then(|name| name.parse::<u64>().ok()) else {
+            continue;
+        };
+        manifests.push(read_manifest(backup_dir, id)?);
+    }
+    manifests.sort_by_key(|manifest| manifest.id);
+    Ok(manifests)
+}
+
+/// Checks that all files of the backup are present and match sizes and hashes
+/// recorded in its manifest.
+pub fn verify_backup(backup_dir: &Path, id: u64) -> anyhow::Result<BackupManifest> {
+    let manifest = read_manifest(backup_dir, id)?;
+    for db in &manifest.databases {
+        for file in &db.files {
+            check_file(&manifest.file_path(backup_dir, db, file), file, None)?;
+        }
+    }
+    Ok(manifest)
+}
+
+/// Restores the backup into the given database directories.
+///
+/// The directories must not exist.  Files are verified as they are copied and
+/// the directories are removed if the backup turns out to be corrupted.
+pub fn restore_backup(
+    backup_dir: &Path,
+    id: u64,
+    hot_path: &Path,
+    cold_path: Option<&Path>,
+) -> anyhow::Result<BackupManifest> {
+    let manifest = read_manifest(backup_dir, id)?;
+    let mut targets = Vec::new();
+    for db in &manifest.databases {
+        let target = match db.temperature.parse::<Temperature>().map_err(anyhow::Error::msg)? {
+            Temperature::Hot => hot_path,
+            Temperature::Cold => cold_path.with_context(|| {
+                format!("backup {id} contains cold database but no cold path was given")
+            })?,
+        };
+        anyhow::ensure!(!target.exists(), "{} already exists", target.display());
+        targets.push((db, target));
+    }
+
+    let restore = || -> anyhow::Result<()> {
+        for (db, target) in &targets {
+            for file in &db.files {
+                let dst = target.join(&file.name);
+                std::fs::create_dir_all(dst.parent().unwrap())?;
+                check_file(&manifest.file_path(backup_dir, db, file), file, Some(&dst))?;
+            }
+        }
+        Ok(())
+    };
+    if let Err(err) = restore() {
+        for (_, target) in &targets {
+            let _ = std::fs::remove_dir_all(target);
+        }
+        return Err(err);
+    }
+    tracing::info!(target: "store", id, hot_path = %hot_path.display(), "Restored backup");
+    Ok(manifest)
+}
+
+fn read_manifest(backup_dir: &Path, id: u64) -> anyhow::Result<BackupManifest> {
+    let path = backup_dir.join(id.to_string()).join(MANIFEST_FILE);
+    let data =
+        std::fs::read(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    serde_json::from_slice(&data).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// Returns the directory of the checkpoint of the database at `db_path`.  It's
+/// a sibling of the database so that both are on the same file system.
+fn checkpoint_dir(db_path: &Path) -> PathBuf {
+    let mut name = db_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".backup-checkpoint");
+    db_path.with_file_name(name)
+}
+
+fn temperature_dir(temp: Temperature) -> &'static str {
+    match temp {
+        Temperature::Hot => "hot",
+        Temperature::Cold => "cold",
+    }
+}
+
+/// Returns the name of an immutable database file in the shared directory.
+///
+/// The name includes a fingerprint of the file size and tail since the same
+/// name may be used for different files if the database was recreated.
+fn shared_name(path: &Path, name: &Path, size: u64) -> io::Result<PathBuf> {
+    let mut file = std::fs::File::open(path)?;
+    let tail_size = size.min(FINGERPRINT_TAIL_SIZE);
+    file.seek(SeekFrom::Start(size - tail_size))?;
+    let mut tail = vec![0; tail_size as usize];
+    file.read_exact(&mut tail)?;
+
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(size.to_le_bytes());
+    hasher.update(&tail);
+    let fingerprint = hex::encode(&hasher.finalize()[..8]);
+    let stem = name.file_stem().unwrap_or_default().to_string_lossy();
+    let ext = name.extension().unwrap_or_default().to_string_lossy();
+    Ok(name.with_file_name(format!("{stem}-{fingerprint}.{ext}")))
+}
+
+/// Moves the file from the checkpoint into the shared directory.  If the two
+/// are on different file systems the file is copied instead, through
+/// a temporary file so that partially copied files are never shared.
+fn export_file(src: &Path, dst: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(dst.parent().unwrap())?;
+    match std::fs::rename(src, dst) {
+        Err(err) if err.kind() == io::ErrorKind::CrossesDevices => {
+            let tmp = dst.with_extension("tmp");
+            std::fs::copy(src, &tmp)?;
+            std::fs::File::open(&tmp)?.sync_all()?;
+            std::fs::rename(&tmp, dst)?;
+            std::fs::remove_file(src)
+        }
+        result => result,
+    }
+}
+
+/// Returns paths of all files in `dir` relative to it.
+fn list_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::new()];
+    while let Some(relative) = pending.pop() {
+        for entry in std::fs::read_dir(dir.join(&relative))? {
+            let entry = entry?;
+            let name = relative.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                pending.push(name);
+            } else {
+                files.push(name);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Verifies that the file at `path` matches `file`, copying it to `copy_to`
+/// along the way if given.
+fn check_file(path: &Path, file: &BackupFile, copy_to: Option<&Path>) -> anyhow::Result<()> {
+    let (size, sha256) =
+        hash_file(path, copy_to).with_context(|| format!("failed to read {}", path.display()))?;
+    anyhow::ensure!(
+        size == file.size && sha256 == file.sha256,
+        "{} is corrupted: expected {} bytes with SHA-256 {}, got {} bytes with SHA-256 {}",
+        path.display(),
+        file.size,
+        file.sha256,
+        size,
+        sha256
+    );
+    Ok(())
+}
+
+/// Returns size and hex-encoded SHA-256 hash of the file, copying it to
+/// `copy_to` if given.
+fn hash_file(path: &Path, copy_to: Option<&Path>) -> io::Result<(u64, String)> {
+    let mut reader = std::fs::File::open(path)?;
+    let mut writer = copy_to.map(std::fs::File::create).transpose()?;
+    let mut hasher = sha2::Sha256::new();
+    let mut buf = vec![0; 1 << 20];
+    let mut size = 0;
+    loop {
+        let len = reader.read(&mut buf)?;
+        if len == 0 {
+            break;
+        }
+        hasher.update(&buf[..len]);
+        if let Some(writer) = writer.as_mut() {
+            writer.write_all(&buf[..len])?;
+        }
+        size += len as u64;
+    }
+    if let Some(writer) = writer {
+        writer.sync_all()?;
+    }
+    Ok((size, hex::encode(hasher.finalize())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DBCol, Mode, StoreConfig};
+
+    fn hot_path(home_dir: &tempfile::TempDir) -> PathBuf {
+        home_dir.path().join("data")
+    }
+
+    fn write_blocks(storage: &NodeStorage, keys: std::ops::Range<u8>) {
+        let store = storage.get_hot_store();
+        let mut update = store.store_update();
+        for key in keys {
+            update.insert(DBCol::Block, vec![key], vec![key; 1024]);
+        }
+        update.commit().unwrap();
+        store.flush().unwrap();
+    }
+
+    #[test]
+    fn test_incremental_backup_and_restore() {
+        let home_dir = tempfile::tempdir().unwrap();
+        let backup_dir = home_dir.path().join("backups");
+        let store_config = StoreConfig::test_config();
+        let storage = NodeStorage::opener(home_dir.path(), &store_config, None).open().unwrap();
+
+        write_blocks(&storage, 0..10);
+        let first = create_backup(&storage, &hot_path(&home_dir), None, &backup_dir).unwrap();
+        write_blocks(&storage, 10..20);
+        let second = create_backup(&storage, &hot_path(&home_dir), None, &backup_dir).unwrap();
+        assert_eq!((first.id, second.id), (0, 1));
+        // SST files exported by the first backup are not exported again.
+        assert!(second.exported_size < second.total_size());
+        assert_eq!(list_backups(&backup_dir).unwrap(), vec![first.clone(), second.clone()]);
+        verify_backup(&backup_dir, 1).unwrap();
+        // The checkpoint next to the database is removed once exported.
+        assert!(!checkpoint_dir(&hot_path(&home_dir)).exists());
+
+        let restored_home = tempfile::tempdir().unwrap();
+        restore_backup(&backup_dir, 0, &restored_home.path().join("data"), None).unwrap();
+        let restored = NodeStorage::opener(restored_home.path(), &store_config, None)
+            .open_in_mode(Mode::ReadOnly)
+            .unwrap();
+        assert!(restored.get_hot_store().exists(DBCol::Block, &[9]).unwrap());
+        assert!(!restored.get_hot_store().exists(DBCol::Block, &[10]).unwrap());
+    }
+
+    /// The same SST file names are used by a recreated database, which must
+    /// not be confused with the files of the old one.
+    #[test]
+    fn test_backup_of_recreated_database() {
+        let home_dir = tempfile::tempdir().unwrap();
+        let backup_dir = home_dir.path().join("backups");
+        let store_config = StoreConfig::test_config();
+        {
+            let storage = NodeStorage::opener(home_dir.path(), &store_config, None).open().unwrap();
+            write_blocks(&storage, 0..10);
+            create_backup(&storage, &hot_path(&home_dir), None, &backup_dir).unwrap();
+        }
+        std::fs::remove_dir_all(home_dir.path().join("data")).unwrap();
+        let storage = NodeStorage::opener(home_dir.path(), &store_config, None).open().unwrap();
+        write_blocks(&storage, 10..20);
+        let second = create_backup(&storage, &hot_path(&home_dir), None, &backup_dir).unwrap();
+        verify_backup(&backup_dir, 0).unwrap();
+        verify_backup(&backup_dir, 1).unwrap();
+
+        let restored_home = tempfile::tempdir().unwrap();
+        restore_backup(&backup_dir, second.id, &restored_home.path().join("data"), None).unwrap();
+        let restored = NodeStorage::opener(restored_home.path(), &store_config, None)
+            .open_in_mode(Mode::ReadOnly)
+            .unwrap();
+        assert!(!restored.get_hot_store().exists(DBCol::Block, &[9]).unwrap());
+        assert!(restored.get_hot_store().exists(DBCol::Block, &[19]).unwrap());
+    }
+
+    #[test]
+    fn test_prune_backups() {
+        let home_dir = tempfile::tempdir().unwrap();
+        let backup_dir = home_dir.path().join("backups");
+        let storage =
+            NodeStorage::opener(home_dir.path(), &StoreConfig::test_config(), None).open().unwrap();
+        for i in 0..3 {
+            write_blocks(&storage, i * 10..i * 10 + 10);
+            create_backup(&storage, &hot_path(&home_dir), None, &backup_dir).unwrap();
+            // Compaction replaces the SST files exported by earlier backups.
+            storage.get_hot_store().compact().unwrap();
+        }
+
+        assert_eq!(prune_backups(&backup_dir, 1).unwrap(), vec![0, 1]);
+        let backups = list_backups(&backup_dir).unwrap();
+        assert_eq!(backups.iter().map(|manifest| manifest.id).collect::<Vec<_>>(), vec![2]);
+        verify_backup(&backup_dir, 2).unwrap();
+        // Only the shared files of the remaining backup are kept.
+        let shared_files = list_files(&backup_dir.join(SHARED_DIR)).unwrap();
+        assert_eq!(shared_files.len(), backups[0].shared_files(&backup_dir).count());
+        assert!(prune_backups(&backup_dir, 0).is_err());
+    }
+
+    #[test]
+    fn test_corrupted_backup() {
+        let home_dir = tempfile::tempdir().unwrap();
+        let backup_dir = home_dir.path().join("backups");
+        let storage =
+            NodeStorage::opener(home_dir.path(), &StoreConfig::test_config(), None).open().unwrap();
+        write_blocks(&storage, 0..10);
+        let manifest = create_backup(&storage, &hot_path(&home_dir), None, &backup_dir).unwrap();
+
+        let db = &manifest.databases[0];
+        let file = db.files.iter().find(|file| file.shared_name.is_some()).unwrap();
+        let path = manifest.file_path(&backup_dir, db, file);
+        let mut data = std::fs::read(&path).unwrap();
+        data[0] ^= 1;
+        std::fs::write(&path, data).unwrap();
+
+        assert!(verify_backup(&backup_dir, manifest.id).is_err());
+        let target = home_dir.path().join("restored");
+        assert!(restore_backup(&backup_dir, manifest.id, &target, None).is_err());
+        assert!(!target.exists());
+    }
+}
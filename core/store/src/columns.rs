@@ -322,6 +322,40 @@ pub enum DBCol {
     /// - *Rows*: BlockShardId (BlockHash || ShardId) - 40 bytes
     /// - *Column type*: `ChunkApplyStats`
     ChunkApplyStats,
+    /// Keys of the data kept by garbage collection after the block they belong
+    /// to was removed, because of a retention policy of their group (see
+    /// `GCConfig::gc_retention`).  The data is removed once the retention of the
+    /// block at the given height expires.
+    /// - *Rows*: GCDataGroup (u8) || BlockHeight (u64 big-endian)
+    /// - *Column type*: `Vec<(DBCol index in the group, key)>`, i.e. `Vec<(u8, Vec<u8>)>`
+    GCRetainedKeys,
+    /// Past values of flat state, kept when `StoreConfig::flat_state_history_window`
+    /// is set.  Each row stores the value a key had before it was changed by
+    /// the block at the given height, so it is valid for the range of heights
+    /// between the previous change of the key and that height (exclusive).
+    /// - *Rows*: `shard_uid` + escaped and terminated trie key (see
+    ///   `encode_flat_state_history_db_key`) + BlockHeight (u64 big-endian)
+    /// - *Column type*: `Option<FlatStateValue>`
+    FlatStateHistory,
+    /// Trie keys changed by the block at the given height, used to prune
+    /// `DBCol::FlatStateHistory`.  The row with the lowest height of a shard
+    /// marks the first height for which the history is available.
+    /// - *Rows*: `shard_uid` + BlockHeight (u64 big-endian)
+    /// - *Column type*: `Vec<Vec<u8>>`
+    FlatStateHistoryKeys,
+    /// NEP-297 events emitted by contracts in the logs of outcomes, kept when
+    /// `ClientConfig::index_contract_events` is set.  Events of outcomes
+    /// included in blocks on forks are kept as well until they are garbage
+    /// collected.
+    /// - *Rows*: `ContractEventKey` (account_id || standard || event ||
+    ///   BlockHeight || BlockHash || OutcomeId || log index)
+    /// - *Column type*: `StoredContractEvent`
+    ContractEvents,
+    /// Keys of `DBCol::ContractEvents` added by the outcomes of a chunk, used
+    /// to garbage collect them.
+    /// - *Rows*: BlockShardId (BlockHash || ShardId) - 40 bytes
+    /// - *Column type*: `Vec<Vec<u8>>`
+    ContractEventKeys,
 }
 
 /// Defines different logical parts of a db key.
@@ -357,6 +391,10 @@ pub enum DBKeyType {
     ColumnId,
     LatestWitnessesKey,
     LatestWitnessIndex,
+    GCDataGroup,
+    ContractEventStandard,
+    ContractEventName,
+    LogIndex,
 }
 
 impl DBCol {
@@ -528,7 +566,13 @@ impl DBCol {
             | DBCol::FlatStorageStatus
             | DBCol::EpochSyncProof
             | DBCol::StateSyncHashes
-            | DBCol::StateSyncNewChunks => false,
+            | DBCol::StateSyncNewChunks
+            | DBCol::GCRetainedKeys
+            | DBCol::FlatStateHistory
+            | DBCol::FlatStateHistoryKeys
+            // ContractEvents is an optional index maintained by the hot storage.
+            | DBCol::ContractEvents
+            | DBCol::ContractEventKeys => false,
         }
     }
 
@@ -605,6 +649,21 @@ impl DBCol {
             DBCol::StateSyncHashes => &[DBKeyType::EpochId],
             DBCol::StateSyncNewChunks => &[DBKeyType::BlockHash],
             DBCol::ChunkApplyStats => &[DBKeyType::BlockHash, DBKeyType::ShardId],
+            DBCol::GCRetainedKeys => &[DBKeyType::GCDataGroup, DBKeyType::BlockHeight],
+            DBCol::FlatStateHistory => {
+                &[DBKeyType::ShardUId, DBKeyType::TrieKey, DBKeyType::BlockHeight]
+            }
+            DBCol::FlatStateHistoryKeys => &[DBKeyType::ShardUId, DBKeyType::BlockHeight],
+            DBCol::ContractEvents => &[
+                DBKeyType::AccountId,
+                DBKeyType::ContractEventStandard,
+                DBKeyType::ContractEventName,
+                DBKeyType::BlockHeight,
+                DBKeyType::BlockHash,
+                DBKeyType::OutcomeId,
+                DBKeyType::LogIndex,
+            ],
+            DBCol::ContractEventKeys => &[DBKeyType::BlockHash, DBKeyType::ShardId],
         }
     }
 }
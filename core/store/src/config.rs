@@ -5,7 +5,7 @@ use crate::trie::{
 use near_primitives::chains::MAINNET;
 use near_primitives::epoch_manager::EpochConfigStore;
 use near_primitives::shard_layout::{ShardLayout, ShardUId};
-use near_primitives::types::AccountId;
+use near_primitives::types::{AccountId, BlockHeightDelta};
 use near_primitives::version::{PROTOCOL_VERSION, ProtocolFeature};
 use near_time::Duration;
 use std::{collections::HashMap, str::FromStr};
@@ -88,6 +88,27 @@ pub struct StoreConfig {
     /// If true, load mem trie for each shard being tracked; this has priority over `load_memtries_for_shards`.
     #[serde(rename = "load_mem_tries_for_tracked_shards")]
     pub load_memtries_for_tracked_shards: bool,
+    /// Directory for on-disk images of mem-tries, or `None` (the default) to
+    /// disable them.  If relative, resolved relative to neard home directory.
+    ///
+    /// Images are saved when the node shuts down.  On start, mem-tries are
+    /// mapped from the images instead of being loaded from flat storage, which
+    /// takes seconds instead of minutes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memtrie_image_path: Option<std::path::PathBuf>,
+    /// Memory budget of mem-tries, or `None` (the default) to keep whole
+    /// tries in memory.  With a budget, only the upper levels of the tries
+    /// and the subtrees used the most are kept in memory, and the rest is
+    /// read from flat storage or the State column.  Shards which are about
+    /// to be resharded are always loaded entirely.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memtrie_memory_budget: Option<MemTrieMemoryBudget>,
+    /// Number of blocks below flat storage head for which past values of flat
+    /// state are kept, or `None` (the default) to keep only the state at head.
+    /// With the history, values of view queries at recent heights are read
+    /// from flat storage instead of traversing the trie.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_state_history_window: Option<BlockHeightDelta>,
 
     /// Path where to create RocksDB checkpoints during database migrations or
     /// `false` to disable that feature.
@@ -113,10 +134,27 @@ pub struct StoreConfig {
     #[serde(skip_serializing_if = "MigrationSnapshot::is_default")]
     pub migration_snapshot: MigrationSnapshot,
 
+    /// Directory where backups created through the `/debug/api/backup` RPC
+    /// endpoint or `neard database backup` command are stored.  If relative,
+    /// resolved relative to neard home directory.  Defaults to `backups`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup_path: Option<std::path::PathBuf>,
+
+    /// Number of most recent backups to keep.  Older backups, along with the
+    /// files only they reference, are deleted whenever a new backup is
+    /// created.  If not set, all backups are kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup_num_to_keep: Option<std::num::NonZeroUsize>,
+
     pub state_snapshot_config: StateSnapshotConfig,
 }
 
 impl StoreConfig {
+    /// Returns the directory where backups are stored, see `backup_path`.
+    pub fn backup_path(&self, home_dir: &std::path::Path) -> std::path::PathBuf {
+        home_dir.join(self.backup_path.as_deref().unwrap_or(std::path::Path::new("backups")))
+    }
+
     pub fn enable_state_snapshot(&mut self) {
         self.state_snapshot_config.state_snapshot_type = StateSnapshotType::Enabled;
     }
@@ -299,8 +337,13 @@ impl Default for StoreConfig {
             // requires more RAM and takes several minutes on startup.
             load_memtries_for_shards: Default::default(),
             load_memtries_for_tracked_shards: false,
+            memtrie_image_path: None,
+            memtrie_memory_budget: None,
+            flat_state_history_window: None,
 
             migration_snapshot: Default::default(),
+            backup_path: None,
+            backup_num_to_keep: None,
 
             state_snapshot_config: Default::default(),
         }
@@ -343,6 +386,24 @@ impl Default for MigrationSnapshot {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MemTrieMemoryBudget {
+    /// Memory budget of the mem-tries of a single shard.
+    pub default_max_bytes: bytesize::ByteSize,
+    /// Overwrites `default_max_bytes` for specific shards.
+    pub per_shard_max_bytes: HashMap<ShardUId, bytesize::ByteSize>,
+}
+
+impl Default for MemTrieMemoryBudget {
+    fn default() -> Self {
+        Self {
+            default_max_bytes: bytesize::ByteSize::gib(4),
+            per_shard_max_bytes: Default::default(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
 pub struct TrieCacheConfig {
@@ -385,6 +446,13 @@ pub struct SplitStorageConfig {
 
     #[serde(default = "default_num_cold_store_read_threads")]
     pub num_cold_store_read_threads: usize,
+
+    /// If set, the node is a partial archival node: only the history touching
+    /// these accounts (state changes, transactions, receipts and outcomes) is
+    /// copied to the cold storage, everything else is garbage collected from
+    /// the hot storage as on an RPC node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cold_store_tracked_accounts: Option<Vec<AccountId>>,
 }
 
 impl Default for SplitStorageConfig {
@@ -397,6 +465,7 @@ impl Default for SplitStorageConfig {
                 default_cold_store_initial_migration_loop_sleep_duration(),
             cold_store_loop_sleep_duration: default_cold_store_loop_sleep_duration(),
             num_cold_store_read_threads: default_num_cold_store_read_threads(),
+            cold_store_tracked_accounts: None,
         }
     }
 }
@@ -456,12 +525,22 @@ pub enum ArchivalStorageLocation {
     /// configures the hot-to-cold copy process).
     #[default]
     ColdDB,
-    /// Archival data is persisted in the filesystem.
-    /// NOTE: This option not implemented yet.
+    /// Archival data is persisted in the filesystem, as immutable segment files
+    /// holding the data of one column at one height (see
+    /// [`crate::db::FilesystemDB`]).
+    /// The files are written by the same hot-to-cold copy process as for the
+    /// ColdDB, configured by `Config.split_storage`.
     Filesystem {
         /// Root directory containing the archival storage files.
-        _path: std::path::PathBuf,
+        /// Relative paths are resolved against the home directory.
+        path: std::path::PathBuf,
     },
+    /// Archival data is persisted as content-addressed objects in an
+    /// S3-compatible bucket, with a local read-through cache (see
+    /// [`crate::db::ObjectStoreDB`]).
+    /// The objects are written by the same hot-to-cold copy process as for the
+    /// ColdDB, configured by `Config.split_storage`.
+    S3(S3ArchivalStorageConfig),
     /// Archival data is persisted in the Google Cloud Storage.
     /// NOTE: This option not implemented yet.
     GCloud {
@@ -470,6 +549,35 @@ pub enum ArchivalStorageLocation {
     },
 }
 
+/// Configures the archival storage in an S3-compatible bucket.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct S3ArchivalStorageConfig {
+    /// Bucket containing the archival storage objects.
+    pub bucket: String,
+    /// Region of the bucket.
+    pub region: String,
+    /// Endpoint of an S3-compatible service, such as MinIO.  If not set, AWS S3
+    /// is used.  The credentials are taken from the environment, as for the AWS
+    /// CLI.
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    /// Directory of the local cache of the objects.
+    /// Relative paths are resolved against the home directory.
+    #[serde(default = "default_archival_cache_path")]
+    pub cache_path: std::path::PathBuf,
+    /// Maximum size of the local cache of the objects.
+    #[serde(default = "default_archival_cache_size")]
+    pub cache_size: bytesize::ByteSize,
+}
+
+fn default_archival_cache_path() -> std::path::PathBuf {
+    "archival-cache".into()
+}
+
+fn default_archival_cache_size() -> bytesize::ByteSize {
+    bytesize::ByteSize::gb(100)
+}
+
 /// Contains references to the sub-configs from the Near node config that are related to archival storage.
 pub struct ArchivalConfig<'a> {
     pub archival_store_config: Option<&'a ArchivalStoreConfig>,
@@ -504,17 +612,24 @@ impl<'a> ArchivalConfig<'a> {
         split_storage_config: Option<&'a SplitStorageConfig>,
     ) {
         if archive {
-            // Since only ColdDB storage is supported for now, assert that cold storage is configured.
-            // TODO: Change this condition after supporting other archival storage options such as GCS.
-            assert!(
-                cold_store_config.is_some()
-                    && (archival_store_config.is_none()
-                        || matches!(
-                            archival_store_config.unwrap().storage,
-                            ArchivalStorageLocation::ColdDB
-                        )),
-                "Archival storage must be ColdDB and it must be configured with a valid StoreConfig"
-            );
+            match archival_store_config.map(|config| &config.storage) {
+                None | Some(ArchivalStorageLocation::ColdDB) => assert!(
+                    cold_store_config.is_some(),
+                    "ColdDB archival storage must be configured with a valid StoreConfig"
+                ),
+                Some(
+                    ArchivalStorageLocation::Filesystem { .. } | ArchivalStorageLocation::S3(_),
+                ) => {
+                    assert!(
+                        cold_store_config.is_none(),
+                        "Cold-store config must not be set for the filesystem or S3 archival storage"
+                    )
+                }
+                // TODO: Change this condition after supporting the GCS archival storage.
+                Some(ArchivalStorageLocation::GCloud { .. }) => {
+                    panic!("GCloud archival storage is not supported yet")
+                }
+            }
         } else {
             assert!(
                 cold_store_config.is_none()
@@ -0,0 +1,863 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read as _, Seek as _, SeekFrom, Write as _};
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+
+use parking_lot::{Mutex, RwLock};
+
+use crate::db::{DBIterator, DBIteratorItem, DBOp, DBSlice, DBTransaction, Database, refcount};
+use crate::{DBCol, StoreStatistics};
+
+/// Marks the beginning and the end of a segment file.
+const SEGMENT_MAGIC: &[u8; 8] = b"NEARSEG1";
+const SEGMENT_SUFFIX: &str = ".seg";
+/// Size of the footer of a segment: index offset, number of entries and magic.
+const FOOTER_SIZE: u64 = 8 + 8 + SEGMENT_MAGIC.len() as u64;
+/// Number of keys copied out of the index at once while iterating, so that the
+/// index is not locked for the whole iteration.
+const ITER_BATCH_SIZE: usize = 1024;
+/// Every `MERGE_FACTOR` segments of a column are merged into one, as are every
+/// `MERGE_FACTOR` of the merged ones and so on.
+const MERGE_FACTOR: u64 = 10;
+
+/// Offset and length of a value in a segment, `None` if the key has been
+/// deleted.
+type ValueLocation = Option<(u64, u64)>;
+
+/// Where the latest version of a key is stored.
+#[derive(Clone, Copy, Debug)]
+struct Location {
+    segment: u64,
+    value: ValueLocation,
+}
+
+type ColumnIndex = BTreeMap<Box<[u8]>, Location>;
+
+/// New values of the keys of a column written by a transaction, `None` for
+/// the deleted keys.
+#[derive(Default)]
+struct ColumnChanges {
+    /// Whether the transaction deletes all the previous keys of the column.
+    delete_all: bool,
+    entries: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+/// A database storing its data in immutable segment files, meant to be used as
+/// the archival storage of the nodes (wrapped in [`crate::db::ColdDB`]).
+///
+/// Every [`Database::write`] stores the changes of each column in a new
+/// segment file `<root>/<col>/<sequence number>.seg`, numbered separately in
+/// every column.  The cold store loop writes every column once per height, so
+/// a segment holds the data of one column at one height and is written with a
+/// single sync.  A segment contains the values, followed by an index of its
+/// keys sorted in the order of the keys, so that the keys can be loaded
+/// without reading the values.
+///
+/// Segments are never modified after they are written: they are written to a
+/// temporary file which is then renamed, so they are either complete or
+/// missing.  This makes it safe to back up the storage with ordinary file
+/// tools (or hard links, see [`Database::create_checkpoint`]) while the node
+/// is running.  Later segments override the keys of the earlier ones.
+///
+/// To bound the number of files, the segments numbered from `n - MERGE_FACTOR^k`
+/// to `n - 1` are merged after the segment `n - 1` is written, for the largest
+/// `k` such that `n` is a multiple of `MERGE_FACTOR^k`.  After `n` writes a
+/// column thus has at most `MERGE_FACTOR - 1` segments of every power of
+/// `MERGE_FACTOR` writes, so `O(log(n))` segments in total.  The merged
+/// segment keeps only the latest version of every key, replaces the last one
+/// and the others are deleted.  If the node is stopped in the meantime the
+/// remaining ones are merged again when the database is opened.  Deleted keys
+/// are kept in the merged segments so that such leftovers don't bring them
+/// back.  [`Database::compact`] merges all the segments of every column.
+///
+/// The index of all the keys, pointing at the segment holding their latest
+/// version, is loaded into memory when the database is opened.  The values
+/// are read with the index locked, so that their segments aren't merged in
+/// the meantime.
+///
+/// Unlike the RocksDB, a transaction is not applied atomically across columns.
+/// This is fine for the cold storage since the cold store loop updates the
+/// `COLD_HEAD` only after all the data of a height has been written, and simply
+/// copies the height again if the node was stopped in the meantime.  The
+/// writes are applied one at a time, and the segments of a column deleted by
+/// `DeleteAll` are only deleted once all the operations of the transaction
+/// have been processed.
+pub struct FilesystemDB {
+    root: PathBuf,
+    read_only: bool,
+    index: RwLock<enum_map::EnumMap<DBCol, ColumnIndex>>,
+    /// Number of the next segment of each column, locked for the whole write
+    /// so that the segments are written and merged in order.
+    next_segments: Mutex<enum_map::EnumMap<DBCol, u64>>,
+}
+
+impl FilesystemDB {
+    /// Opens the database at `root`, creating the directory if needed unless
+    /// `read_only` is set.
+    pub fn open(root: &Path, read_only: bool) -> io::Result<Self> {
+        if read_only {
+            if !root.is_dir() {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("{} is not a directory", root.display()),
+                ));
+            }
+        } else {
+            std::fs::create_dir_all(root)?;
+        }
+        let mut index = enum_map::EnumMap::<DBCol, ColumnIndex>::default();
+        let mut next_segments = enum_map::EnumMap::<DBCol, u64>::default();
+        for (col, col_index) in index.iter_mut() {
+            for (segment, path) in Self::list_segments(&Self::col_path(root, col))? {
+                for (key, value) in Self::read_segment_index(&path)? {
+                    col_index.insert(key, Location { segment, value });
+                }
+                next_segments[col] = segment + 1;
+            }
+        }
+        let db = Self {
+            root: root.to_path_buf(),
+            read_only,
+            index: RwLock::new(index),
+            next_segments: Mutex::new(next_segments),
+        };
+        if !read_only {
+            // Finishes the merges interrupted by stopping the node.
+            for (col, next_segment) in db.next_segments.lock().iter() {
+                if let Some(last) = next_segment.checked_sub(1) {
+                    db.merge_segments(col, last + 1 - Self::merged_segments(last), last)?;
+                }
+            }
+        }
+        Ok(db)
+    }
+
+    fn col_path(root: &Path, col: DBCol) -> PathBuf {
+        root.join(<&str>::from(col))
+    }
+
+    fn segment_path(root: &Path, col: DBCol, segment: u64) -> PathBuf {
+        Self::col_path(root, col).join(format!("{segment:020}{SEGMENT_SUFFIX}"))
+    }
+
+    /// Lists the segments of a column, ordered from the oldest.  Returns an
+    /// empty list if the column has no directory.
+    fn list_segments(dir: &Path) -> io::Result<Vec<(u64, PathBuf)>> {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err),
+        };
+        let mut segments = vec![];
+        for entry in entries {
+            let path = entry?.path();
+            // Skips temporary files left over by interrupted writes.
+            let segment = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(SEGMENT_SUFFIX))
+                .and_then(|segment| segment.parse::<u64>().ok());
+            if let Some(segment) = segment {
+                segments.push((segment, path));
+            }
+        }
+        segments.sort();
+        Ok(segments)
+    }
+
+    fn invalid_segment(path: &Path, msg: &str) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, format!("{}: {msg}", path.display()))
+    }
+
+    /// Reads the index of the segment, without reading the values.
+    fn read_segment_index(path: &Path) -> io::Result<Vec<(Box<[u8]>, ValueLocation)>> {
+        let mut file = BufReader::new(File::open(path)?);
+        let size = file.get_ref().metadata()?.len();
+        if size < SEGMENT_MAGIC.len() as u64 + FOOTER_SIZE {
+            return Err(Self::invalid_segment(path, "segment is too short"));
+        }
+        file.seek(SeekFrom::Start(size - FOOTER_SIZE))?;
+        let index_offset = read_u64(&mut file)?;
+        let num_entries = read_u64(&mut file)?;
+        let mut magic = [0; SEGMENT_MAGIC.len()];
+        file.read_exact(&mut magic)?;
+        if &magic != SEGMENT_MAGIC || index_offset > size - FOOTER_SIZE {
+            return Err(Self::invalid_segment(path, "invalid segment footer"));
+        }
+
+        file.seek(SeekFrom::Start(index_offset))?;
+        let mut entries = vec![];
+        for _ in 0..num_entries {
+            let mut key = vec![0; read_u64(&mut file)? as usize];
+            file.read_exact(&mut key)?;
+            let mut deleted = [0];
+            file.read_exact(&mut deleted)?;
+            let offset = read_u64(&mut file)?;
+            let len = read_u64(&mut file)?;
+            if offset.checked_add(len).is_none_or(|end| end > index_offset) {
+                return Err(Self::invalid_segment(path, "value out of bounds"));
+            }
+            let value = (deleted[0] == 0).then_some((offset, len));
+            entries.push((key.into_boxed_slice(), value));
+        }
+        Ok(entries)
+    }
+
+    /// Writes the segment `segment` of `col` with the given values (`None` for
+    /// deleted keys) and adds it to the index.
+    fn write_segment(
+        &self,
+        col: DBCol,
+        segment: u64,
+        entries: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+    ) -> io::Result<()> {
+        let mut writer = SegmentWriter::new(&Self::col_path(&self.root, col))?;
+        for (key, value) in entries {
+            writer.push(key.into_boxed_slice(), value.as_deref())?;
+        }
+        let (tmp, locations) = writer.finish()?;
+        tmp.persist(Self::segment_path(&self.root, col, segment)).map_err(|err| err.error)?;
+
+        let mut index = self.index.write();
+        for (key, value) in locations {
+            index[col].insert(key, Location { segment, value });
+        }
+        Ok(())
+    }
+
+    /// Returns the number of segments to merge after writing the segment
+    /// `segment`, ending with it.
+    fn merged_segments(segment: u64) -> u64 {
+        let mut count = 1;
+        while let Some(next) = count.checked_mul(MERGE_FACTOR) {
+            if (segment + 1) % next != 0 {
+                break;
+            }
+            count = next;
+        }
+        count
+    }
+
+    /// Merges the segments of `col` numbered from `first` to `last` into the
+    /// segment `last`, if there are several of them.
+    fn merge_segments(&self, col: DBCol, first: u64, last: u64) -> io::Result<()> {
+        if first == last {
+            return Ok(());
+        }
+        let dir = Self::col_path(&self.root, col);
+        let sources: Vec<(u64, PathBuf)> = Self::list_segments(&dir)?
+            .into_iter()
+            .filter(|(segment, _)| (first..=last).contains(segment))
+            .collect();
+        if sources.len() < 2 {
+            return Ok(());
+        }
+        // The latest version of every key and the source holding it.
+        let mut entries = BTreeMap::new();
+        let mut files = Vec::with_capacity(sources.len());
+        for (source, (_, path)) in sources.iter().enumerate() {
+            for (key, value) in Self::read_segment_index(path)? {
+                entries.insert(key, (source, value));
+            }
+            files.push(File::open(path)?);
+        }
+        let mut writer = SegmentWriter::new(&dir)?;
+        for (key, (source, value)) in entries {
+            let value = match value {
+                Some((offset, len)) => Some(read_at(&mut files[source], offset, len)?),
+                None => None,
+            };
+            writer.push(key, value.as_deref())?;
+        }
+        let (tmp, locations) = writer.finish()?;
+
+        let mut index = self.index.write();
+        let (last, path) = sources.last().unwrap();
+        tmp.persist(path).map_err(|err| err.error)?;
+        for (_, path) in &sources[..sources.len() - 1] {
+            std::fs::remove_file(path)?;
+        }
+        for (key, value) in locations {
+            index[col].insert(key, Location { segment: *last, value });
+        }
+        Ok(())
+    }
+
+    fn read_value(&self, col: DBCol, segment: u64, offset: u64, len: u64) -> io::Result<Vec<u8>> {
+        let mut file = File::open(Self::segment_path(&self.root, col, segment))?;
+        read_at(&mut file, offset, len)
+    }
+
+    fn get_value(&self, col: DBCol, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        let index = self.index.read();
+        match index[col].get(key) {
+            Some(Location { segment, value: Some((offset, len)) }) => {
+                self.read_value(col, *segment, *offset, *len).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn check_writable(&self) -> io::Result<()> {
+        if self.read_only {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("{} is opened in read-only mode", self.root.display()),
+            ));
+        }
+        Ok(())
+    }
+
+    fn iter_raw_range<'a>(
+        &'a self,
+        col: DBCol,
+        lower_bound: Option<&[u8]>,
+        upper_bound: Option<&[u8]>,
+    ) -> SegmentIter<'a> {
+        SegmentIter {
+            db: self,
+            col,
+            lower: lower_bound.map_or(Bound::Unbounded, |lower| Bound::Included(lower.into())),
+            upper: upper_bound.map_or(Bound::Unbounded, |upper| Bound::Excluded(upper.into())),
+            prefix: None,
+            batch: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Deletes all the segments of a column.
+    fn delete_column(&self, col: DBCol) -> io::Result<()> {
+        let mut index = self.index.write();
+        index[col].clear();
+        match std::fs::remove_dir_all(Self::col_path(&self.root, col)) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn read_u64(reader: &mut impl io::Read) -> io::Result<u64> {
+    let mut buf = [0; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_at(file: &mut File, offset: u64, len: u64) -> io::Result<Vec<u8>> {
+    file.seek(SeekFrom::Start(offset))?;
+    let mut value = vec![0; len as usize];
+    file.read_exact(&mut value)?;
+    Ok(value)
+}
+
+/// Writes a segment into a temporary file, with the entries pushed in the
+/// order of the keys.
+struct SegmentWriter {
+    writer: BufWriter<tempfile::NamedTempFile>,
+    offset: u64,
+    locations: Vec<(Box<[u8]>, ValueLocation)>,
+}
+
+impl SegmentWriter {
+    fn new(dir: &Path) -> io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+        let mut writer = BufWriter::new(tempfile::NamedTempFile::new_in(dir)?);
+        writer.write_all(SEGMENT_MAGIC)?;
+        Ok(Self { writer, offset: SEGMENT_MAGIC.len() as u64, locations: vec![] })
+    }
+
+    fn push(&mut self, key: Box<[u8]>, value: Option<&[u8]>) -> io::Result<()> {
+        let location = match value {
+            Some(value) => {
+                self.writer.write_all(value)?;
+                let len = value.len() as u64;
+                self.offset += len;
+                Some((self.offset - len, len))
+            }
+            None => None,
+        };
+        self.locations.push((key, location));
+        Ok(())
+    }
+
+    /// Writes the index and syncs the segment, which is then ready to be
+    /// moved to its place.
+    fn finish(mut self) -> io::Result<(tempfile::NamedTempFile, Vec<(Box<[u8]>, ValueLocation)>)> {
+        for (key, value) in &self.locations {
+            self.writer.write_all(&(key.len() as u64).to_le_bytes())?;
+            self.writer.write_all(key)?;
+            let (offset, len) = value.unwrap_or_default();
+            self.writer.write_all(&[value.is_none() as u8])?;
+            self.writer.write_all(&offset.to_le_bytes())?;
+            self.writer.write_all(&len.to_le_bytes())?;
+        }
+        self.writer.write_all(&self.offset.to_le_bytes())?;
+        self.writer.write_all(&(self.locations.len() as u64).to_le_bytes())?;
+        self.writer.write_all(SEGMENT_MAGIC)?;
+        let tmp = self.writer.into_inner().map_err(|err| err.into_error())?;
+        tmp.as_file().sync_data()?;
+        Ok((tmp, self.locations))
+    }
+}
+
+/// Iterates over the keys of a column in order, reading the values from the
+/// segments.  Copies the keys out of the index in batches.
+struct SegmentIter<'a> {
+    db: &'a FilesystemDB,
+    col: DBCol,
+    lower: Bound<Box<[u8]>>,
+    upper: Bound<Box<[u8]>>,
+    prefix: Option<&'a [u8]>,
+    batch: VecDeque<Box<[u8]>>,
+    done: bool,
+}
+
+impl SegmentIter<'_> {
+    fn fill_batch(&mut self) {
+        let index = self.db.index.read();
+        let range = index[self.col].range::<[u8], _>((
+            self.lower.as_ref().map(|key| &**key),
+            self.upper.as_ref().map(|key| &**key),
+        ));
+        let mut last = None;
+        for (key, location) in range.take(ITER_BATCH_SIZE) {
+            if self.prefix.is_some_and(|prefix| !key.starts_with(prefix)) {
+                break;
+            }
+            if location.value.is_some() {
+                self.batch.push_back(key.clone());
+            }
+            last = Some(key);
+        }
+        match last {
+            Some(key) => self.lower = Bound::Excluded(key.clone()),
+            None => self.done = true,
+        }
+    }
+}
+
+impl Iterator for SegmentIter<'_> {
+    type Item = DBIteratorItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.batch.is_empty() && !self.done {
+                self.fill_batch();
+            }
+            let key = self.batch.pop_front()?;
+            // The key may have been deleted since the batch was filled.
+            match self.db.get_value(self.col, &key) {
+                Ok(Some(value)) => return Some(Ok((key, value.into()))),
+                Ok(None) => {}
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+impl Database for FilesystemDB {
+    fn get_raw_bytes(&self, col: DBCol, key: &[u8]) -> io::Result<Option<DBSlice<'_>>> {
+        Ok(self.get_value(col, key)?.map(DBSlice::from_vec))
+    }
+
+    fn iter<'a>(&'a self, col: DBCol) -> DBIterator<'a> {
+        refcount::iter_with_rc_logic(col, self.iter_raw_range(col, None, None))
+    }
+
+    fn iter_prefix<'a>(&'a self, col: DBCol, key_prefix: &'a [u8]) -> DBIterator<'a> {
+        let iterator = SegmentIter {
+            db: self,
+            col,
+            lower: Bound::Included(key_prefix.into()),
+            upper: Bound::Unbounded,
+            prefix: Some(key_prefix),
+            batch: VecDeque::new(),
+            done: false,
+        };
+        refcount::iter_with_rc_logic(col, iterator)
+    }
+
+    fn iter_range<'a>(
+        &'a self,
+        col: DBCol,
+        lower_bound: Option<&[u8]>,
+        upper_bound: Option<&[u8]>,
+    ) -> DBIterator<'a> {
+        refcount::iter_with_rc_logic(col, self.iter_raw_range(col, lower_bound, upper_bound))
+    }
+
+    fn iter_raw_bytes<'a>(&'a self, col: DBCol) -> DBIterator<'a> {
+        Box::new(self.iter_raw_range(col, None, None))
+    }
+
+    fn write(&self, transaction: DBTransaction) -> io::Result<()> {
+        self.check_writable()?;
+        let mut next_segments = self.next_segments.lock();
+        let mut changes = enum_map::EnumMap::<DBCol, Option<ColumnChanges>>::default();
+        for op in transaction.ops {
+            let col = op.col();
+            let ColumnChanges { delete_all, entries } = changes[col].get_or_insert_default();
+            let current =
+                |entries: &BTreeMap<Vec<u8>, Option<Vec<u8>>>, key: &[u8]| match entries.get(key) {
+                    Some(value) => Ok(value.clone()),
+                    None if *delete_all => Ok(None),
+                    None => self.get_value(col, key),
+                };
+            match op {
+                DBOp::Set { key, value, .. } => {
+                    entries.insert(key, Some(value));
+                }
+                DBOp::Insert { key, value, .. } => {
+                    if cfg!(debug_assertions) {
+                        if let Some(old_value) = current(entries, &key)? {
+                            super::assert_no_overwrite(col, &key, &value, &old_value)
+                        }
+                    }
+                    entries.insert(key, Some(value));
+                }
+                DBOp::UpdateRefcount { key, value, .. } => {
+                    let existing = current(entries, &key)?;
+                    let merged = refcount::refcount_merge(existing.as_deref(), [value.as_slice()]);
+                    entries.insert(key, (!merged.is_empty()).then_some(merged));
+                }
+                DBOp::Delete { key, .. } => {
+                    entries.insert(key, None);
+                }
+                DBOp::DeleteAll { .. } => {
+                    // The column is deleted when the transaction is applied.
+                    *delete_all = true;
+                    entries.clear();
+                }
+                DBOp::DeleteRange { from, to, .. } => {
+                    let range = (Bound::Included(&from[..]), Bound::Excluded(&to[..]));
+                    let mut keys: Vec<Vec<u8>> =
+                        entries.range::<[u8], _>(range).map(|(key, _)| key.clone()).collect();
+                    if !*delete_all {
+                        keys.extend(
+                            self.index.read()[col]
+                                .range::<[u8], _>(range)
+                                .filter(|(_, location)| location.value.is_some())
+                                .map(|(key, _)| key.to_vec()),
+                        );
+                    }
+                    for key in keys {
+                        entries.insert(key, None);
+                    }
+                }
+            }
+        }
+        for (col, changes) in changes {
+            let Some(ColumnChanges { delete_all, entries }) = changes else {
+                continue;
+            };
+            if delete_all {
+                self.delete_column(col)?;
+                next_segments[col] = 0;
+            }
+            if entries.is_empty() {
+                continue;
+            }
+            let segment = next_segments[col];
+            self.write_segment(col, segment, entries)?;
+            next_segments[col] += 1;
+            self.merge_segments(col, segment + 1 - Self::merged_segments(segment), segment)?;
+        }
+        Ok(())
+    }
+
+    /// Every segment is synced to disk when written so there is nothing to flush.
+    fn flush(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Merges all the segments of every column into one.
+    fn compact(&self) -> io::Result<()> {
+        self.check_writable()?;
+        let next_segments = self.next_segments.lock();
+        for (col, next_segment) in next_segments.iter() {
+            if let Some(last) = next_segment.checked_sub(1) {
+                self.merge_segments(col, 0, last)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn get_store_statistics(&self) -> Option<StoreStatistics> {
+        None
+    }
+
+    /// Creates a checkpoint by hard linking all the segments into `path`,
+    /// which is cheap since the segments are never modified.  The index is
+    /// locked so that the segments aren't merged in the meantime.
+    fn create_checkpoint(
+        &self,
+        path: &Path,
+        columns_to_keep: Option<&[DBCol]>,
+    ) -> anyhow::Result<()> {
+        use anyhow::Context;
+        use strum::IntoEnumIterator;
+
+        let _index = self.index.read();
+        for col in DBCol::iter() {
+            // Keep DbVersion since it’s needed to open the checkpoint.
+            if col != DBCol::DbVersion
+                && columns_to_keep.is_some_and(|columns| !columns.contains(&col))
+            {
+                continue;
+            }
+            for (segment, src) in Self::list_segments(&Self::col_path(&self.root, col))? {
+                let dst = Self::segment_path(path, col, segment);
+                std::fs::create_dir_all(dst.parent().unwrap())?;
+                // Segments are immutable so they can be shared with the
+                // checkpoint unless it is on another file system.
+                match std::fs::hard_link(&src, &dst) {
+                    Err(err) if err.kind() == std::io::ErrorKind::CrossesDevices => {
+                        std::fs::copy(&src, &dst).map(|_| ())
+                    }
+                    result => result,
+                }
+                .with_context(|| {
+                    format!("failed to link {} to {}", src.display(), dst.display())
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::refcount::add_positive_refcount;
+    use std::num::NonZeroU32;
+
+    fn keys(iter: DBIterator) -> Vec<Vec<u8>> {
+        iter.map(|item| item.unwrap().0.into_vec()).collect()
+    }
+
+    #[test]
+    fn test_get_and_iter() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = FilesystemDB::open(tmp_dir.path(), false).unwrap();
+        let mut transaction = DBTransaction::new();
+        for key in ["a", "aa", "aa1", "ab", "bb1", "cc1"] {
+            transaction.insert(DBCol::Block, key.into(), format!("val_{key}").into());
+        }
+        db.write(transaction).unwrap();
+
+        assert_eq!(
+            db.get_raw_bytes(DBCol::Block, b"aa1").unwrap().as_deref(),
+            Some(&b"val_aa1"[..])
+        );
+        assert_eq!(db.get_raw_bytes(DBCol::Block, b"zz").unwrap(), None);
+        assert_eq!(db.get_raw_bytes(DBCol::BlockHeader, b"aa1").unwrap(), None);
+
+        let all: Vec<Vec<u8>> =
+            ["a", "aa", "aa1", "ab", "bb1", "cc1"].into_iter().map(Into::into).collect();
+        assert_eq!(keys(db.iter(DBCol::Block)), all);
+        assert_eq!(
+            keys(db.iter_prefix(DBCol::Block, b"aa")),
+            vec![b"aa".to_vec(), b"aa1".to_vec()]
+        );
+        assert_eq!(keys(db.iter_prefix(DBCol::Block, b"a")), all[..4].to_vec());
+        assert_eq!(
+            keys(db.iter_range(DBCol::Block, Some(b"aa"), Some(b"bb1"))),
+            all[1..4].to_vec()
+        );
+        assert_eq!(keys(db.iter_range(DBCol::Block, Some(b"ab0"), None)), all[4..].to_vec());
+        assert_eq!(keys(db.iter_range(DBCol::Block, None, Some(b"aa"))), all[..1].to_vec());
+        assert_eq!(keys(db.iter(DBCol::BlockHeader)), Vec::<Vec<u8>>::new());
+    }
+
+    #[test]
+    fn test_refcount_and_delete() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = FilesystemDB::open(tmp_dir.path(), false).unwrap();
+        let key = [1u8; 40].to_vec();
+        let value = add_positive_refcount(b"value", NonZeroU32::new(1).unwrap());
+
+        let mut transaction = DBTransaction::new();
+        transaction.update_refcount(DBCol::State, key.clone(), value.clone());
+        transaction.update_refcount(DBCol::State, key.clone(), value);
+        db.write(transaction).unwrap();
+        assert_eq!(
+            db.get_with_rc_stripped(DBCol::State, &key).unwrap().as_deref(),
+            Some(&b"value"[..])
+        );
+        assert_eq!(keys(db.iter(DBCol::State)), vec![key.clone()]);
+
+        let mut transaction = DBTransaction::new();
+        transaction.set(DBCol::BlockMisc, b"a".to_vec(), b"1".to_vec());
+        transaction.set(DBCol::BlockMisc, b"b".to_vec(), b"2".to_vec());
+        transaction.set(DBCol::BlockMisc, b"c".to_vec(), b"3".to_vec());
+        transaction.delete_range(DBCol::BlockMisc, b"b".to_vec(), b"c".to_vec());
+        transaction.delete_all(DBCol::State);
+        db.write(transaction).unwrap();
+        assert_eq!(keys(db.iter(DBCol::BlockMisc)), vec![b"a".to_vec(), b"c".to_vec()]);
+        assert_eq!(db.get_raw_bytes(DBCol::State, &key).unwrap(), None);
+    }
+
+    #[test]
+    fn test_segments_and_reopen() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = FilesystemDB::open(tmp_dir.path(), false).unwrap();
+        // Every write adds one segment per column, however many keys it has.
+        for height in 0..3u64 {
+            let mut transaction = DBTransaction::new();
+            for i in 0..100u64 {
+                let key = (height * 100 + i).to_be_bytes().to_vec();
+                transaction.set(DBCol::Block, key.clone(), height.to_le_bytes().to_vec());
+                transaction.set(DBCol::BlockHeader, key, vec![]);
+            }
+            db.write(transaction).unwrap();
+        }
+        let mut transaction = DBTransaction::new();
+        transaction.set(DBCol::Block, 0u64.to_be_bytes().to_vec(), b"new".to_vec());
+        transaction.delete(DBCol::Block, 1u64.to_be_bytes().to_vec());
+        db.write(transaction).unwrap();
+        let segments =
+            |col| FilesystemDB::list_segments(&FilesystemDB::col_path(tmp_dir.path(), col));
+        assert_eq!(segments(DBCol::Block).unwrap().len(), 4);
+        assert_eq!(segments(DBCol::BlockHeader).unwrap().len(), 3);
+
+        // The index is restored from the segments, with the later ones taking
+        // precedence, and iteration spans several batches.
+        drop(db);
+        let db = FilesystemDB::open(tmp_dir.path(), true).unwrap();
+        assert_eq!(
+            db.get_raw_bytes(DBCol::Block, &0u64.to_be_bytes()).unwrap().as_deref(),
+            Some(&b"new"[..])
+        );
+        assert_eq!(db.get_raw_bytes(DBCol::Block, &1u64.to_be_bytes()).unwrap(), None);
+        assert_eq!(
+            db.get_raw_bytes(DBCol::Block, &250u64.to_be_bytes()).unwrap().as_deref(),
+            Some(&2u64.to_le_bytes()[..])
+        );
+        assert_eq!(keys(db.iter(DBCol::Block)).len(), 299);
+        assert_eq!(keys(db.iter_raw_bytes(DBCol::BlockHeader)).len(), 300);
+
+        // Leftovers of interrupted writes are ignored, corrupted segments are not.
+        let dir = FilesystemDB::col_path(tmp_dir.path(), DBCol::Block);
+        std::fs::write(dir.join(".tmpXYZ"), b"partial").unwrap();
+        FilesystemDB::open(tmp_dir.path(), true).unwrap();
+        std::fs::write(FilesystemDB::segment_path(tmp_dir.path(), DBCol::Block, 100), b"bad")
+            .unwrap();
+        assert!(FilesystemDB::open(tmp_dir.path(), true).is_err());
+    }
+
+    #[test]
+    fn test_merge_segments() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = FilesystemDB::open(tmp_dir.path(), false).unwrap();
+        let key = |i: u64| i.to_be_bytes().to_vec();
+        // Every write overwrites the same key, adds a key and deletes every
+        // other key added by the previous write.
+        let write = |db: &FilesystemDB, writes: std::ops::Range<u64>| {
+            for i in writes {
+                let mut transaction = DBTransaction::new();
+                transaction.set(DBCol::Block, vec![0], i.to_le_bytes().to_vec());
+                transaction.set(DBCol::Block, key(i + 1), vec![]);
+                if i % 2 == 1 {
+                    transaction.delete(DBCol::Block, key(i));
+                }
+                db.write(transaction).unwrap();
+            }
+        };
+        let check = |db: &FilesystemDB, writes: u64| {
+            let expected: Vec<Vec<u8>> = std::iter::once(vec![0])
+                .chain((1..=writes).filter(|i| i % 2 == 0 || *i == writes).map(key))
+                .collect();
+            assert_eq!(keys(db.iter(DBCol::Block)), expected);
+            assert_eq!(
+                db.get_raw_bytes(DBCol::Block, &[0]).unwrap().as_deref(),
+                Some(&(writes - 1).to_le_bytes()[..])
+            );
+        };
+        let segment_path = |i| FilesystemDB::segment_path(tmp_dir.path(), DBCol::Block, i);
+        let segments = || -> Vec<u64> {
+            let dir = FilesystemDB::col_path(tmp_dir.path(), DBCol::Block);
+            FilesystemDB::list_segments(&dir).unwrap().into_iter().map(|(i, _)| i).collect()
+        };
+        write(&db, 0..125);
+        assert_eq!(segments(), vec![99, 109, 119, 120, 121, 122, 123, 124]);
+        // The merged segment has the latest version of each key, including
+        // the deleted ones.
+        assert_eq!(FilesystemDB::read_segment_index(&segment_path(99)).unwrap().len(), 101);
+        check(&db, 125);
+
+        // A segment left over by an interrupted merge is merged again when
+        // the database is opened.
+        let leftover = std::fs::read(segment_path(121)).unwrap();
+        write(&db, 125..130);
+        drop(db);
+        std::fs::write(segment_path(121), leftover).unwrap();
+        let db = FilesystemDB::open(tmp_dir.path(), true).unwrap();
+        assert_eq!(segments(), vec![99, 109, 119, 121, 129]);
+        check(&db, 130);
+        drop(db);
+        let db = FilesystemDB::open(tmp_dir.path(), false).unwrap();
+        assert_eq!(segments(), vec![99, 109, 119, 129]);
+        check(&db, 130);
+
+        db.compact().unwrap();
+        assert_eq!(segments(), vec![129]);
+        check(&db, 130);
+        drop(db);
+        check(&FilesystemDB::open(tmp_dir.path(), true).unwrap(), 130);
+    }
+
+    #[test]
+    fn test_delete_all_of_failed_write() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = FilesystemDB::open(tmp_dir.path(), false).unwrap();
+        let value = add_positive_refcount(b"value", NonZeroU32::new(1).unwrap());
+        let mut transaction = DBTransaction::new();
+        transaction.set(DBCol::Block, b"a".to_vec(), b"1".to_vec());
+        transaction.update_refcount(DBCol::State, b"b".to_vec(), value.clone());
+        db.write(transaction).unwrap();
+
+        // The refcount can't be updated once the segment of the key is gone,
+        // and the column deleted earlier in the transaction is kept.
+        std::fs::remove_dir_all(FilesystemDB::col_path(tmp_dir.path(), DBCol::State)).unwrap();
+        let mut transaction = DBTransaction::new();
+        transaction.delete_all(DBCol::Block);
+        transaction.update_refcount(DBCol::State, b"b".to_vec(), value);
+        assert!(db.write(transaction).is_err());
+        assert_eq!(keys(db.iter(DBCol::Block)), vec![b"a".to_vec()]);
+
+        let mut transaction = DBTransaction::new();
+        transaction.set(DBCol::Block, b"b".to_vec(), b"2".to_vec());
+        transaction.delete_all(DBCol::Block);
+        transaction.set(DBCol::Block, b"c".to_vec(), b"3".to_vec());
+        db.write(transaction).unwrap();
+        assert_eq!(keys(db.iter(DBCol::Block)), vec![b"c".to_vec()]);
+    }
+
+    #[test]
+    fn test_checkpoint_and_read_only() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = FilesystemDB::open(&tmp_dir.path().join("db"), false).unwrap();
+        let mut transaction = DBTransaction::new();
+        transaction.set(DBCol::DbVersion, b"VERSION".to_vec(), b"1".to_vec());
+        transaction.set(DBCol::Block, b"block".to_vec(), b"value".to_vec());
+        transaction.set(DBCol::BlockHeader, b"header".to_vec(), b"value".to_vec());
+        db.write(transaction).unwrap();
+
+        let checkpoint_path = tmp_dir.path().join("checkpoint");
+        db.create_checkpoint(&checkpoint_path, Some(&[DBCol::Block])).unwrap();
+        // Overwriting the value doesn't affect the checkpoint.
+        let mut transaction = DBTransaction::new();
+        transaction.set(DBCol::Block, b"block".to_vec(), b"new value".to_vec());
+        db.write(transaction).unwrap();
+
+        let checkpoint = FilesystemDB::open(&checkpoint_path, true).unwrap();
+        assert_eq!(
+            checkpoint.get_raw_bytes(DBCol::Block, b"block").unwrap().as_deref(),
+            Some(&b"value"[..])
+        );
+        assert!(checkpoint.get_raw_bytes(DBCol::DbVersion, b"VERSION").unwrap().is_some());
+        assert_eq!(checkpoint.get_raw_bytes(DBCol::BlockHeader, b"header").unwrap(), None);
+        assert!(checkpoint.write(DBTransaction::new()).is_err());
+        assert!(FilesystemDB::open(&tmp_dir.path().join("missing"), true).is_err());
+    }
+}
@@ -6,8 +6,10 @@ use std::sync::Arc;
 
 mod colddb;
 mod database_tests;
+mod filesystemdb;
 pub mod metadata;
 mod mixeddb;
+mod objectstoredb;
 mod recoverydb;
 pub mod refcount;
 pub(crate) mod rocksdb;
@@ -16,7 +18,9 @@ mod splitdb;
 mod testdb;
 
 pub use self::colddb::ColdDB;
+pub use self::filesystemdb::FilesystemDB;
 pub use self::mixeddb::{MixedDB, ReadOrder};
+pub use self::objectstoredb::{LocalObjectStore, ObjectStore, ObjectStoreDB, S3ObjectStore};
 pub use self::recoverydb::RecoveryDB;
 pub use self::rocksdb::RocksDB;
 pub use self::slice::DBSlice;
@@ -37,12 +41,16 @@ pub const GENESIS_STATE_ROOTS_KEY: &[u8; 19] = b"GENESIS_STATE_ROOTS";
 pub const GENESIS_CONGESTION_INFO_KEY: &[u8] = b"GENESIS_CONGESTION_INFO_KEY";
 pub const COLD_HEAD_KEY: &[u8; 9] = b"COLD_HEAD";
 pub const STATE_SYNC_DUMP_KEY: &[u8; 15] = b"STATE_SYNC_DUMP";
+/// Must not start with `STATE_SYNC_DUMP_KEY`, which is iterated over as a prefix.
+pub const STATE_SYNC_DUMPED_PARTS_KEY: &[u8; 18] = b"DUMPED_STATE_PARTS";
 pub const STATE_SNAPSHOT_KEY: &[u8; 18] = b"STATE_SNAPSHOT_KEY";
 
 // `DBCol::Misc` keys
 pub const FLAT_STATE_VALUES_INLINING_MIGRATION_STATUS_KEY: &[u8] =
     b"FLAT_STATE_VALUES_INLINING_MIGRATION_STATUS";
 pub const LATEST_WITNESSES_INFO: &[u8] = b"LATEST_WITNESSES_INFO";
+/// Prefix of the keys of the contracts recently called on each shard, followed by the `ShardUId`.
+pub const CALLED_CONTRACTS_KEY: &[u8] = b"CALLED_CONTRACTS";
 
 #[derive(Default, Debug)]
 pub struct DBTransaction {
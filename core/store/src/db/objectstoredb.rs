@@ -0,0 +1,866 @@
+mod cache;
+mod object_store;
+
+pub use self::object_store::{LocalObjectStore, ObjectStore, S3ObjectStore};
+
+use crate::db::{DBIterator, DBIteratorItem, DBOp, DBSlice, DBTransaction, Database, refcount};
+use crate::{DBCol, StoreStatistics};
+use cache::Cache;
+use near_primitives::hash::CryptoHash;
+use parking_lot::RwLock;
+use rayon::prelude::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Number of names listed at once when iterating over the objects.
+const LIST_PAGE_SIZE: usize = 1000;
+
+/// Keys longer than this are named by their hash, since the names of their hex
+/// would exceed the limits of the object stores (1024 bytes for the names of S3
+/// objects, 255 bytes for the file names of the local store and cache).
+const MAX_NAMED_KEY_LEN: usize = 100;
+
+/// Separates the hex of the first bytes of a long key from its hash in the
+/// name of its object.  It's sorted after the hex and base58 digits.
+const LONG_KEY_SEPARATOR: char = '~';
+
+/// Values of keys, `None` for the missing or deleted keys.
+type Values = HashMap<(DBCol, Vec<u8>), Option<Vec<u8>>>;
+
+/// A database storing the values as content-addressed objects in an object
+/// storage, meant to be used as the archival storage of the nodes (wrapped in
+/// [`crate::db::ColdDB`]).
+///
+/// The value of `key` in column `col` is stored in the object
+/// `objects/<hash of value>` and the object `keys/<col>/<hex of key>` contains
+/// the hash of the value.  Keys longer than `MAX_NAMED_KEY_LEN` bytes are
+/// stored in `keys/<col>/<hex of first bytes>~<hash of key>` instead, and their
+/// object also contains the full key after the hash of the value.  The objects
+/// are cached on the local disk when read.  The value objects are shared by
+/// the keys with the same value, so they are not deleted with the keys but by
+/// [`Database::compact`].
+///
+/// Writes to different keys are applied in parallel and are not atomic.  This
+/// is fine for the cold storage since the cold store loop updates the
+/// `COLD_HEAD` only after all the data of a height has been written, and simply
+/// copies the height again if the node was stopped in the meantime.
+pub struct ObjectStoreDB {
+    store: Arc<dyn ObjectStore>,
+    cache: Cache,
+    read_only: bool,
+    /// Held for reading by the writes and for writing by the garbage
+    /// collection, so that it doesn’t delete the objects being written.
+    gc_lock: RwLock<()>,
+}
+
+impl ObjectStoreDB {
+    /// Opens the database in the given object store, with the local cache of at
+    /// most `cache_size` bytes in `cache_path`.
+    pub fn open(
+        store: Arc<dyn ObjectStore>,
+        cache_path: &Path,
+        cache_size: bytesize::ByteSize,
+        read_only: bool,
+    ) -> io::Result<Self> {
+        let cache = Cache::open(cache_path, cache_size.as_u64())?;
+        Ok(Self { store, cache, read_only, gc_lock: RwLock::new(()) })
+    }
+
+    fn col_prefix(col: DBCol) -> String {
+        format!("keys/{}/", <&str>::from(col))
+    }
+
+    /// Returns the name of the object of the key.  Hex encoding preserves the
+    /// order so the names are sorted in the order of the keys, except for the
+    /// long keys sharing their first `MAX_NAMED_KEY_LEN` bytes, which are
+    /// sorted by their hash.
+    fn key_name(col: DBCol, key: &[u8]) -> String {
+        match key.split_at_checked(MAX_NAMED_KEY_LEN) {
+            Some((head, tail)) if !tail.is_empty() => format!(
+                "{}{}{LONG_KEY_SEPARATOR}{}",
+                Self::col_prefix(col),
+                hex::encode(head),
+                CryptoHash::hash_bytes(key)
+            ),
+            _ => format!("{}{}", Self::col_prefix(col), hex::encode(key)),
+        }
+    }
+
+    /// Returns the prefix of the names of the keys starting with `prefix`.
+    fn prefix_name(col: DBCol, prefix: &[u8]) -> String {
+        match prefix.split_at_checked(MAX_NAMED_KEY_LEN) {
+            Some((head, tail)) if !tail.is_empty() => {
+                format!("{}{}{LONG_KEY_SEPARATOR}", Self::col_prefix(col), hex::encode(head))
+            }
+            _ => Self::key_name(col, prefix),
+        }
+    }
+
+    /// Returns the name to list the objects after to start with the objects of
+    /// the keys from `key`.  The names of the short keys only contain hex
+    /// digits, which are all sorted before `~`, so no name is between the
+    /// returned one and the name of the first `MAX_NAMED_KEY_LEN` bytes of the
+    /// key.  The keys before `key` may still be listed and must be filtered.
+    fn name_before_key(col: DBCol, key: &[u8]) -> Option<String> {
+        let key = &key[..key.len().min(MAX_NAMED_KEY_LEN)];
+        if key.is_empty() {
+            return None;
+        }
+        let name = Self::key_name(col, key);
+        let (head, last) = name.split_at(name.len() - 1);
+        Some(format!("{head}{}~", char::from(last.as_bytes()[0] - 1)))
+    }
+
+    /// Returns the name to list the objects before to end with the objects of
+    /// the keys before `key`.  The keys from `key` sharing its first
+    /// `MAX_NAMED_KEY_LEN` bytes may still be listed and must be filtered.
+    fn name_after_keys_before(col: DBCol, key: &[u8]) -> String {
+        match key.len() > MAX_NAMED_KEY_LEN {
+            true => format!("{}{LONG_KEY_SEPARATOR}", Self::prefix_name(col, key)),
+            false => Self::key_name(col, key),
+        }
+    }
+
+    /// Returns the name shared by the long keys with the same first
+    /// `MAX_NAMED_KEY_LEN` bytes, `None` for the names of the short keys.
+    fn long_key_group(name: &str) -> Option<&str> {
+        name.split_once(LONG_KEY_SEPARATOR).map(|(group, _)| group)
+    }
+
+    /// Splits the object of a key into the hash of the value and, for the
+    /// long keys, the full key.
+    fn parse_key_object(object: &[u8]) -> io::Result<(CryptoHash, &[u8])> {
+        let invalid = |err: String| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("invalid key object: {err}"))
+        };
+        let (hash, key) = object
+            .split_at_checked(std::mem::size_of::<CryptoHash>())
+            .ok_or_else(|| invalid(format!("{} bytes", object.len())))?;
+        Ok((CryptoHash::try_from(hash).map_err(|err| invalid(err.to_string()))?, key))
+    }
+
+    fn object_name(hash: &CryptoHash) -> String {
+        format!("objects/{hash}")
+    }
+
+    /// Whether the values of the column may change.  Keys of such columns are
+    /// never cached, so that the readers sharing the bucket with the writer
+    /// see the updates (e.g. of `COLD_HEAD` or of the refcounts).
+    fn is_mutable(col: DBCol) -> bool {
+        matches!(col, DBCol::DbVersion | DBCol::BlockMisc) || col.is_rc()
+    }
+
+    fn check_writable(&self) -> io::Result<()> {
+        if self.read_only {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "the archival object store is opened in read-only mode",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Reads the object from the cache or from the object store.
+    fn get_object(&self, name: &str, cacheable: bool) -> io::Result<Option<Vec<u8>>> {
+        if cacheable {
+            if let Some(data) = self.cache.get(name)? {
+                return Ok(Some(data));
+            }
+        }
+        let data = self.store.get(name)?;
+        if let (true, Some(data)) = (cacheable, &data) {
+            self.cache.put(name, data)?;
+        }
+        Ok(data)
+    }
+
+    fn get_value(&self, col: DBCol, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        let name = Self::key_name(col, key);
+        let Some(object) = self.get_object(&name, !Self::is_mutable(col))? else {
+            return Ok(None);
+        };
+        let (hash, long_key) = Self::parse_key_object(&object)?;
+        if key.len() > MAX_NAMED_KEY_LEN && long_key != key {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("key object {name} is of another key"),
+            ));
+        }
+        Ok(Some(self.get_value_object(&hash)?))
+    }
+
+    /// Reads the key and the value of a listed key object, `None` if the key
+    /// was deleted in the meantime.
+    fn get_listed(&self, col: DBCol, name: &str) -> io::Result<Option<(Vec<u8>, Vec<u8>)>> {
+        let Some(object) = self.get_object(name, !Self::is_mutable(col))? else {
+            return Ok(None);
+        };
+        let (hash, long_key) = Self::parse_key_object(&object)?;
+        let key = match long_key.is_empty() {
+            true => Self::short_key(col, name)?,
+            false => long_key.to_vec(),
+        };
+        Ok(Some((key, self.get_value_object(&hash)?)))
+    }
+
+    /// Returns the key of a listed key object, reading the object only for
+    /// the long keys.  `None` if the key was deleted in the meantime.
+    fn get_listed_key(&self, col: DBCol, name: &str) -> io::Result<Option<Vec<u8>>> {
+        if Self::long_key_group(name).is_none() {
+            return Self::short_key(col, name).map(Some);
+        }
+        let Some(object) = self.store.get(name)? else {
+            return Ok(None);
+        };
+        Ok(Some(Self::parse_key_object(&object)?.1.to_vec()))
+    }
+
+    /// Decodes the key from the name of its object, for the short keys.
+    fn short_key(col: DBCol, name: &str) -> io::Result<Vec<u8>> {
+        name.strip_prefix(&Self::col_prefix(col)).and_then(|key| hex::decode(key).ok()).ok_or_else(
+            || io::Error::new(io::ErrorKind::InvalidData, format!("invalid key {name}")),
+        )
+    }
+
+    fn get_value_object(&self, hash: &CryptoHash) -> io::Result<Vec<u8>> {
+        let name = Self::object_name(hash);
+        let value = self.get_object(&name, true)?.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, format!("missing object {name}"))
+        })?;
+        if CryptoHash::hash_bytes(&value) != *hash {
+            self.cache.remove(&name)?;
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("corrupted object {name}"),
+            ));
+        }
+        Ok(value)
+    }
+
+    fn put_value(&self, col: DBCol, key: &[u8], value: &[u8]) -> io::Result<()> {
+        let hash = CryptoHash::hash_bytes(value);
+        // The value object is written first, so that the key never refers to a
+        // missing object.
+        self.store.put(&Self::object_name(&hash), value)?;
+        let name = Self::key_name(col, key);
+        let mut object = hash.as_ref().to_vec();
+        if key.len() > MAX_NAMED_KEY_LEN {
+            object.extend_from_slice(key);
+        }
+        self.store.put(&name, &object)?;
+        self.cache.remove(&name)
+    }
+
+    fn delete_object(&self, name: &str) -> io::Result<()> {
+        self.store.delete(name)?;
+        self.cache.remove(name)
+    }
+
+    /// Calls `f` with the pages of the names of the objects starting with
+    /// `prefix` and sorted after `start_after`, until it returns `false`.
+    fn for_each_page(
+        &self,
+        prefix: &str,
+        mut start_after: Option<String>,
+        mut f: impl FnMut(&[String]) -> io::Result<bool>,
+    ) -> io::Result<()> {
+        loop {
+            let names = self.store.list(prefix, start_after.as_deref(), LIST_PAGE_SIZE)?;
+            if !f(&names)? || names.len() < LIST_PAGE_SIZE {
+                return Ok(());
+            }
+            start_after = names.last().cloned();
+        }
+    }
+
+    fn iter_keys<'a>(
+        &'a self,
+        col: DBCol,
+        prefix: &[u8],
+        lower_bound: Option<&[u8]>,
+        upper_bound: Option<&[u8]>,
+    ) -> KeyIter<'a> {
+        KeyIter {
+            db: self,
+            pages: KeyPages::new(col, prefix, lower_bound, upper_bound),
+            items: VecDeque::new(),
+        }
+    }
+
+    /// Reads in parallel the values of the keys whose refcount is updated by
+    /// the operations.
+    fn read_refcounted(&self, ops: &[DBOp]) -> io::Result<Values> {
+        let keys: HashSet<(DBCol, &[u8])> = ops
+            .iter()
+            .filter_map(|op| match op {
+                DBOp::UpdateRefcount { col, key, .. } => Some((*col, key.as_slice())),
+                _ => None,
+            })
+            .collect();
+        keys.into_par_iter()
+            .map(|(col, key)| Ok(((col, key.to_vec()), self.get_value(col, key)?)))
+            .collect()
+    }
+
+    /// Applies the changes to the keys in parallel.
+    fn apply(&self, changes: Values) -> io::Result<()> {
+        changes.into_par_iter().try_for_each(|((col, key), value)| match value {
+            Some(value) => self.put_value(col, &key, &value),
+            None => self.delete_object(&Self::key_name(col, &key)),
+        })
+    }
+
+    /// Deletes the keys in the range a page at a time.  The value objects are
+    /// kept since other keys may refer to them.
+    fn delete_range(&self, col: DBCol, from: Option<&[u8]>, to: Option<&[u8]>) -> io::Result<()> {
+        let mut pages = KeyPages::new(col, &[], from, to);
+        while !pages.done {
+            let names = pages.next_page(self)?;
+            names.par_iter().try_for_each(|name| match self.get_listed_key(col, name)? {
+                Some(key) if pages.contains(&key) => self.delete_object(name),
+                _ => Ok(()),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Lists the names of the keys of a column a page at a time.  The names of
+/// the long keys sharing their first `MAX_NAMED_KEY_LEN` bytes are kept in the
+/// same page, so that sorting each page by the keys sorts all of them.
+struct KeyPages {
+    col: DBCol,
+    prefix: Vec<u8>,
+    lower_bound: Option<Vec<u8>>,
+    upper_bound: Option<Vec<u8>>,
+    /// Prefix of the names of the listed keys.
+    name_prefix: String,
+    start_after: Option<String>,
+    /// Name to list the keys before, excluded.
+    end: Option<String>,
+    /// Names of the long keys listed at the end of the last page, returned
+    /// with the next page.
+    group: Vec<String>,
+    done: bool,
+}
+
+impl KeyPages {
+    fn new(
+        col: DBCol,
+        prefix: &[u8],
+        lower_bound: Option<&[u8]>,
+        upper_bound: Option<&[u8]>,
+    ) -> Self {
+        Self {
+            col,
+            prefix: prefix.to_vec(),
+            lower_bound: lower_bound.map(<[u8]>::to_vec),
+            upper_bound: upper_bound.map(<[u8]>::to_vec),
+            name_prefix: ObjectStoreDB::prefix_name(col, prefix),
+            start_after: lower_bound.and_then(|lower| ObjectStoreDB::name_before_key(col, lower)),
+            end: upper_bound.map(|upper| ObjectStoreDB::name_after_keys_before(col, upper)),
+            group: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// Whether the key is in the listed range.  The names listed around the
+    /// bounds may be of keys out of it.
+    fn contains(&self, key: &[u8]) -> bool {
+        key.starts_with(&self.prefix)
+            && self.lower_bound.as_ref().is_none_or(|lower| key >= lower.as_slice())
+            && self.upper_bound.as_ref().is_none_or(|upper| key < upper.as_slice())
+    }
+
+    fn next_page(&mut self, db: &ObjectStoreDB) -> io::Result<Vec<String>> {
+        let listed =
+            db.store.list(&self.name_prefix, self.start_after.as_deref(), LIST_PAGE_SIZE)?;
+        self.done = listed.len() < LIST_PAGE_SIZE;
+        self.start_after = listed.last().cloned();
+        let end = self.end.as_ref();
+        let in_range: Vec<String> =
+            listed.iter().take_while(|name| end.is_none_or(|end| *name < end)).cloned().collect();
+        self.done |= in_range.len() < listed.len();
+        let mut names = std::mem::take(&mut self.group);
+        names.extend(in_range);
+        if self.done {
+            return Ok(names);
+        }
+        if let Some(group) = names.last().and_then(|name| ObjectStoreDB::long_key_group(name)) {
+            let group = group.to_owned();
+            let start = names
+                .iter()
+                .rposition(|name| ObjectStoreDB::long_key_group(name) != Some(group.as_str()))
+                .map_or(0, |last_other| last_other + 1);
+            self.group = names.split_off(start);
+        }
+        Ok(names)
+    }
+}
+
+/// Iterates over the keys of a column, listing a page of keys at a time and
+/// reading the values of each page in parallel.
+struct KeyIter<'a> {
+    db: &'a ObjectStoreDB,
+    pages: KeyPages,
+    items: VecDeque<DBIteratorItem>,
+}
+
+impl KeyIter<'_> {
+    fn read_page(&mut self) -> io::Result<()> {
+        let names = self.pages.next_page(self.db)?;
+        let (db, col) = (self.db, self.pages.col);
+        let entries: Vec<Option<(Vec<u8>, Vec<u8>)>> = names
+            .into_par_iter()
+            .map(|name| db.get_listed(col, &name))
+            .collect::<io::Result<_>>()?;
+        let mut entries: Vec<(Vec<u8>, Vec<u8>)> =
+            entries.into_iter().flatten().filter(|(key, _)| self.pages.contains(key)).collect();
+        entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        self.items.extend(
+            entries
+                .into_iter()
+                .map(|(key, value)| Ok((key.into_boxed_slice(), value.into_boxed_slice()))),
+        );
+        Ok(())
+    }
+}
+
+impl Iterator for KeyIter<'_> {
+    type Item = DBIteratorItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.items.is_empty() && !self.pages.done {
+            if let Err(err) = self.read_page() {
+                self.pages.done = true;
+                return Some(Err(err));
+            }
+        }
+        self.items.pop_front()
+    }
+}
+
+impl Database for ObjectStoreDB {
+    fn get_raw_bytes(&self, col: DBCol, key: &[u8]) -> io::Result<Option<DBSlice<'_>>> {
+        Ok(self.get_value(col, key)?.map(DBSlice::from_vec))
+    }
+
+    fn iter<'a>(&'a self, col: DBCol) -> DBIterator<'a> {
+        refcount::iter_with_rc_logic(col, self.iter_keys(col, &[], None, None))
+    }
+
+    fn iter_prefix<'a>(&'a self, col: DBCol, key_prefix: &'a [u8]) -> DBIterator<'a> {
+        refcount::iter_with_rc_logic(col, self.iter_keys(col, key_prefix, None, None))
+    }
+
+    fn iter_range<'a>(
+        &'a self,
+        col: DBCol,
+        lower_bound: Option<&[u8]>,
+        upper_bound: Option<&[u8]>,
+    ) -> DBIterator<'a> {
+        refcount::iter_with_rc_logic(col, self.iter_keys(col, &[], lower_bound, upper_bound))
+    }
+
+    fn iter_raw_bytes<'a>(&'a self, col: DBCol) -> DBIterator<'a> {
+        Box::new(self.iter_keys(col, &[], None, None))
+    }
+
+    fn write(&self, transaction: DBTransaction) -> io::Result<()> {
+        self.check_writable()?;
+        let _gc_guard = self.gc_lock.read();
+        // The values of the refcounted keys before the transaction, `None` for
+        // the missing keys.
+        let mut refcounted = self.read_refcounted(&transaction.ops)?;
+        // The final values of the keys modified by the transaction, `None` for
+        // the deleted keys.
+        let mut changes = HashMap::new();
+        for op in transaction.ops {
+            match op {
+                DBOp::Set { col, key, value } | DBOp::Insert { col, key, value } => {
+                    changes.insert((col, key), Some(value));
+                }
+                DBOp::UpdateRefcount { col, key, value } => {
+                    let key = (col, key);
+                    let existing = match changes.get(&key) {
+                        Some(existing) => existing.clone(),
+                        None => refcounted.get(&key).cloned().flatten(),
+                    };
+                    let merged = refcount::refcount_merge(existing.as_deref(), [value.as_slice()]);
+                    changes.insert(key, (!merged.is_empty()).then_some(merged));
+                }
+                DBOp::Delete { col, key } => {
+                    changes.insert((col, key), None);
+                }
+                DBOp::DeleteAll { col } => {
+                    self.apply(std::mem::take(&mut changes))?;
+                    self.delete_range(col, None, None)?;
+                    refcounted.retain(|(key_col, _), _| *key_col != col);
+                }
+                DBOp::DeleteRange { col, from, to } => {
+                    self.apply(std::mem::take(&mut changes))?;
+                    self.delete_range(col, Some(&from), Some(&to))?;
+                    refcounted
+                        .retain(|(key_col, key), _| *key_col != col || *key < from || *key >= to);
+                }
+            }
+        }
+        self.apply(changes)
+    }
+
+    /// Every write is sent to the object store right away so there is nothing
+    /// to flush.
+    fn flush(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Deletes the value objects no key refers to anymore, after the keys
+    /// were overwritten or deleted.
+    ///
+    /// The hashes of all the values in use are kept in memory.  The writes of
+    /// this database wait for the compaction to finish, but no other process
+    /// may write to the object store in the meantime.
+    fn compact(&self) -> io::Result<()> {
+        self.check_writable()?;
+        let _gc_guard = self.gc_lock.write();
+        let mut used = HashSet::new();
+        self.for_each_page("keys/", None, |names| {
+            let hashes: Vec<_> =
+                names.par_iter().map(|name| self.store.get(name)).collect::<io::Result<_>>()?;
+            used.extend(
+                hashes
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|object| Some(Self::parse_key_object(&object).ok()?.0)),
+            );
+            Ok(true)
+        })?;
+        self.for_each_page("objects/", None, |names| {
+            names
+                .par_iter()
+                .filter(|name| {
+                    let hash = name.strip_prefix("objects/").and_then(|hash| hash.parse().ok());
+                    hash.is_some_and(|hash: CryptoHash| !used.contains(&hash))
+                })
+                .try_for_each(|name| self.delete_object(name))?;
+            Ok(true)
+        })
+    }
+
+    fn get_store_statistics(&self) -> Option<StoreStatistics> {
+        None
+    }
+
+    fn create_checkpoint(
+        &self,
+        _path: &Path,
+        _columns_to_keep: Option<&[DBCol]>,
+    ) -> anyhow::Result<()> {
+        anyhow::bail!("checkpoints of the archival object store are not supported")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open(dir: &Path) -> ObjectStoreDB {
+        let store = Arc::new(LocalObjectStore::new(&dir.join("bucket")).unwrap());
+        ObjectStoreDB::open(store, &dir.join("cache"), bytesize::ByteSize::kb(1), false).unwrap()
+    }
+
+    fn keys(iter: DBIterator) -> Vec<Vec<u8>> {
+        iter.map(|item| item.unwrap().0.into_vec()).collect()
+    }
+
+    #[test]
+    fn test_get_and_iter() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = open(tmp_dir.path());
+        let mut transaction = DBTransaction::new();
+        for key in ["a", "aa", "aa1", "ab", "bb1", "cc1"] {
+            transaction.insert(DBCol::Block, key.into(), format!("val_{key}").into());
+        }
+        db.write(transaction).unwrap();
+
+        assert_eq!(
+            db.get_raw_bytes(DBCol::Block, b"aa1").unwrap().as_deref(),
+            Some(&b"val_aa1"[..])
+        );
+        assert_eq!(db.get_raw_bytes(DBCol::Block, b"zz").unwrap(), None);
+        assert_eq!(db.get_raw_bytes(DBCol::BlockHeader, b"aa1").unwrap(), None);
+
+        let all: Vec<Vec<u8>> =
+            ["a", "aa", "aa1", "ab", "bb1", "cc1"].into_iter().map(Into::into).collect();
+        assert_eq!(keys(db.iter(DBCol::Block)), all);
+        assert_eq!(keys(db.iter_prefix(DBCol::Block, b"aa")), all[1..3].to_vec());
+        assert_eq!(
+            keys(db.iter_range(DBCol::Block, Some(b"aa"), Some(b"bb1"))),
+            all[1..4].to_vec()
+        );
+
+        // Reads go through the cache, which is persisted across restarts.
+        drop(db);
+        std::fs::remove_dir_all(tmp_dir.path().join("bucket")).unwrap();
+        let db = open(tmp_dir.path());
+        assert_eq!(
+            db.get_raw_bytes(DBCol::Block, b"aa1").unwrap().as_deref(),
+            Some(&b"val_aa1"[..])
+        );
+    }
+
+    #[test]
+    fn test_content_addressing() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = open(tmp_dir.path());
+        let bucket = LocalObjectStore::new(&tmp_dir.path().join("bucket")).unwrap();
+        let mut transaction = DBTransaction::new();
+        transaction.set(DBCol::Block, b"a".to_vec(), b"value".to_vec());
+        transaction.set(DBCol::BlockHeader, b"b".to_vec(), b"value".to_vec());
+        transaction.set(DBCol::BlockMisc, b"HEAD".to_vec(), b"1".to_vec());
+        transaction.set(DBCol::BlockMisc, b"HEAD".to_vec(), b"2".to_vec());
+        db.write(transaction).unwrap();
+        // The same value is stored once.
+        assert_eq!(bucket.list("objects/", None, usize::MAX).unwrap().len(), 2);
+        assert_eq!(
+            db.get_raw_bytes(DBCol::BlockMisc, b"HEAD").unwrap().as_deref(),
+            Some(&b"2"[..])
+        );
+
+        // Mutable columns aren't cached so the updates by another writer are visible.
+        let hash = CryptoHash::hash_bytes(b"3");
+        bucket.put(&ObjectStoreDB::object_name(&hash), b"3").unwrap();
+        bucket.put(&ObjectStoreDB::key_name(DBCol::BlockMisc, b"HEAD"), hash.as_ref()).unwrap();
+        assert_eq!(
+            db.get_raw_bytes(DBCol::BlockMisc, b"HEAD").unwrap().as_deref(),
+            Some(&b"3"[..])
+        );
+
+        // Corrupted objects are detected.
+        let name = ObjectStoreDB::object_name(&CryptoHash::hash_bytes(b"value"));
+        bucket.put(&name, b"other value").unwrap();
+        assert!(db.get_raw_bytes(DBCol::BlockHeader, b"b").is_err());
+    }
+
+    #[test]
+    fn test_refcount_and_delete() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = open(tmp_dir.path());
+        let key = [1u8; 40].to_vec();
+        let value =
+            refcount::add_positive_refcount(b"value", std::num::NonZeroU32::new(1).unwrap());
+
+        let mut transaction = DBTransaction::new();
+        transaction.update_refcount(DBCol::State, key.clone(), value.clone());
+        transaction.update_refcount(DBCol::State, key.clone(), value);
+        db.write(transaction).unwrap();
+        assert_eq!(
+            db.get_with_rc_stripped(DBCol::State, &key).unwrap().as_deref(),
+            Some(&b"value"[..])
+        );
+
+        let mut transaction = DBTransaction::new();
+        transaction.set(DBCol::Block, b"a".to_vec(), b"1".to_vec());
+        transaction.set(DBCol::Block, b"b".to_vec(), b"2".to_vec());
+        transaction.set(DBCol::Block, b"c".to_vec(), b"3".to_vec());
+        transaction.delete_range(DBCol::Block, b"b".to_vec(), b"c".to_vec());
+        transaction.delete_all(DBCol::State);
+        db.write(transaction).unwrap();
+        assert_eq!(keys(db.iter(DBCol::Block)), vec![b"a".to_vec(), b"c".to_vec()]);
+        assert_eq!(db.get_raw_bytes(DBCol::State, &key).unwrap(), None);
+    }
+
+    #[test]
+    fn test_paging() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = open(tmp_dir.path());
+        let bucket = LocalObjectStore::new(&tmp_dir.path().join("bucket")).unwrap();
+        let all: Vec<Vec<u8>> =
+            (0..LIST_PAGE_SIZE as u16 * 2 + 10).map(|i| i.to_be_bytes().to_vec()).collect();
+        let mut transaction = DBTransaction::new();
+        for key in &all {
+            transaction.set(DBCol::Block, key.clone(), key.clone());
+        }
+        db.write(transaction).unwrap();
+
+        let names = bucket.list("keys/Block/", None, 10).unwrap();
+        assert_eq!(names.len(), 10);
+        let next = bucket.list("keys/Block/", names.last().map(String::as_str), 5).unwrap();
+        assert_eq!(next[0], ObjectStoreDB::key_name(DBCol::Block, &all[10]));
+
+        assert_eq!(keys(db.iter(DBCol::Block)), all);
+        assert_eq!(keys(db.iter_prefix(DBCol::Block, &[3])), all[0x300..0x400].to_vec());
+        for (lower, upper) in [(0, 1), (0x0f, 0x10), (0x10, 0x3ff), (0x3ff, 0x7d0), (5, all.len())]
+        {
+            let lower_key = &all[lower];
+            let upper_key = all.get(upper).map(Vec::as_slice);
+            assert_eq!(
+                keys(db.iter_range(DBCol::Block, Some(lower_key), upper_key)),
+                all[lower..upper].to_vec()
+            );
+        }
+
+        let mut transaction = DBTransaction::new();
+        transaction.delete_range(DBCol::Block, all[1].clone(), all[all.len() - 1].clone());
+        db.write(transaction).unwrap();
+        assert_eq!(keys(db.iter(DBCol::Block)), vec![all[0].clone(), all[all.len() - 1].clone()]);
+    }
+
+    #[test]
+    fn test_long_keys() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = open(tmp_dir.path());
+        let bucket = LocalObjectStore::new(&tmp_dir.path().join("bucket")).unwrap();
+        let head = vec![7u8; MAX_NAMED_KEY_LEN];
+        let long = |tail: &[u8]| [head.as_slice(), tail].concat();
+        // Sorted keys, most of them sharing their first `MAX_NAMED_KEY_LEN`
+        // bytes, with a group of long keys spanning several pages.
+        let mut all = vec![head[..1].to_vec(), head.clone(), long(&[0; 2000]), long(&[0; 2001])];
+        all.extend(
+            (0..LIST_PAGE_SIZE as u16 + 10).map(|i| long(&[&[1], &i.to_be_bytes()[..]].concat())),
+        );
+        all.push(vec![8u8; 2048]);
+        let mut transaction = DBTransaction::new();
+        for key in all.iter().rev() {
+            transaction.set(DBCol::Block, key.clone(), key[key.len() - 2..].to_vec());
+        }
+        db.write(transaction).unwrap();
+
+        for name in bucket.list("keys/", None, usize::MAX).unwrap() {
+            assert!(name.len() <= 1024);
+            assert!(name.split('/').all(|segment| segment.len() <= 255));
+        }
+        for key in &all {
+            assert_eq!(
+                db.get_raw_bytes(DBCol::Block, key).unwrap().as_deref(),
+                Some(&key[key.len() - 2..])
+            );
+        }
+        assert_eq!(db.get_raw_bytes(DBCol::Block, &long(&[1; 2000])).unwrap(), None);
+        assert_eq!(keys(db.iter(DBCol::Block)), all);
+        assert_eq!(keys(db.iter_prefix(DBCol::Block, &long(&[1, 0]))), all[4..260].to_vec());
+        assert_eq!(
+            keys(db.iter_range(DBCol::Block, Some(&all[3]), Some(&all[100]))),
+            all[3..100].to_vec()
+        );
+
+        let mut transaction = DBTransaction::new();
+        transaction.delete_range(DBCol::Block, all[2].clone(), all[all.len() - 2].clone());
+        db.write(transaction).unwrap();
+        let rest = [&all[..2], &all[all.len() - 1..]].concat();
+        assert_eq!(keys(db.iter(DBCol::Block)), rest);
+    }
+
+    #[test]
+    fn test_refcount_not_cached() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let writer = open(tmp_dir.path());
+        // Another node reading from the same bucket, with its own cache.
+        let store = Arc::new(LocalObjectStore::new(&tmp_dir.path().join("bucket")).unwrap());
+        let reader = ObjectStoreDB::open(
+            store,
+            &tmp_dir.path().join("reader_cache"),
+            bytesize::ByteSize::kb(1),
+            true,
+        )
+        .unwrap();
+        let key = [1u8; 40].to_vec();
+        let value =
+            refcount::add_positive_refcount(b"value", std::num::NonZeroU32::new(1).unwrap());
+
+        let mut transaction = DBTransaction::new();
+        transaction.update_refcount(DBCol::State, key.clone(), value.clone());
+        writer.write(transaction).unwrap();
+        assert!(reader.get_with_rc_stripped(DBCol::State, &key).unwrap().is_some());
+
+        // The reader sees the refcount going down to zero.
+        let mut transaction = DBTransaction::new();
+        transaction.update_refcount(DBCol::State, key.clone(), value);
+        transaction.update_refcount(
+            DBCol::State,
+            key.clone(),
+            refcount::encode_negative_refcount(std::num::NonZeroU32::new(2).unwrap()).to_vec(),
+        );
+        writer.write(transaction).unwrap();
+        assert_eq!(reader.get_with_rc_stripped(DBCol::State, &key).unwrap(), None);
+    }
+
+    #[test]
+    fn test_garbage_collection() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let db = open(tmp_dir.path());
+        let bucket = LocalObjectStore::new(&tmp_dir.path().join("bucket")).unwrap();
+        let mut transaction = DBTransaction::new();
+        transaction.set(DBCol::Block, b"a".to_vec(), b"shared".to_vec());
+        transaction.set(DBCol::BlockHeader, b"b".to_vec(), b"shared".to_vec());
+        transaction.set(DBCol::BlockHeader, b"c".to_vec(), b"deleted".to_vec());
+        transaction.set(DBCol::BlockMisc, b"HEAD".to_vec(), b"1".to_vec());
+        db.write(transaction).unwrap();
+        let mut transaction = DBTransaction::new();
+        transaction.set(DBCol::BlockMisc, b"HEAD".to_vec(), b"2".to_vec());
+        transaction.delete(DBCol::Block, b"a".to_vec());
+        transaction.delete(DBCol::BlockHeader, b"c".to_vec());
+        db.write(transaction).unwrap();
+        assert_eq!(bucket.list("objects/", None, usize::MAX).unwrap().len(), 4);
+
+        db.compact().unwrap();
+        let objects = bucket.list("objects/", None, usize::MAX).unwrap();
+        let expected: HashSet<String> = [&b"shared"[..], b"2"]
+            .into_iter()
+            .map(|value| ObjectStoreDB::object_name(&CryptoHash::hash_bytes(value)))
+            .collect();
+        assert_eq!(objects.into_iter().collect::<HashSet<_>>(), expected);
+        assert_eq!(
+            db.get_raw_bytes(DBCol::BlockHeader, b"b").unwrap().as_deref(),
+            Some(&b"shared"[..])
+        );
+        assert_eq!(
+            db.get_raw_bytes(DBCol::BlockMisc, b"HEAD").unwrap().as_deref(),
+            Some(&b"2"[..])
+        );
+    }
+
+    #[test]
+    fn test_split_store() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let storage = crate::NodeStorage::new_with_cold(
+            crate::db::TestDB::new(),
+            Arc::new(open(tmp_dir.path())),
+        );
+        let mut store_update = storage.get_cold_store().unwrap().store_update();
+        store_update.insert(DBCol::Block, b"a".to_vec(), b"cold".to_vec());
+        store_update.commit().unwrap();
+        let mut store_update = storage.get_hot_store().store_update();
+        store_update.insert(DBCol::Block, b"b".to_vec(), b"hot".to_vec());
+        store_update.commit().unwrap();
+
+        let split_store = storage.get_split_store().unwrap();
+        assert_eq!(split_store.get(DBCol::Block, b"a").unwrap().as_deref(), Some(&b"cold"[..]));
+        assert_eq!(split_store.get(DBCol::Block, b"b").unwrap().as_deref(), Some(&b"hot"[..]));
+    }
+
+    #[test]
+    fn test_cache_eviction() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let cache = Cache::open(tmp_dir.path(), 100).unwrap();
+        cache.put("objects/a", &[0; 60]).unwrap();
+        cache.put("objects/b", &[0; 30]).unwrap();
+        assert!(cache.get("objects/a").unwrap().is_some());
+        // Evicts the least recently used object.
+        cache.put("objects/c", &[0; 30]).unwrap();
+        assert!(cache.get("objects/a").unwrap().is_some());
+        assert!(cache.get("objects/b").unwrap().is_none());
+        assert!(!tmp_dir.path().join("objects/b").exists());
+        // Objects larger than the cache aren't cached.
+        cache.put("objects/d", &[0; 200]).unwrap();
+        assert!(cache.get("objects/d").unwrap().is_none());
+        drop(cache);
+
+        // The cached objects are kept across restarts.
+        let cache = Cache::open(tmp_dir.path(), 100).unwrap();
+        assert!(cache.get("objects/a").unwrap().is_some());
+        assert!(cache.get("objects/c").unwrap().is_some());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::metrics;
+use parking_lot::Mutex;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Local on-disk cache of the objects, evicting the least recently used ones
+/// once the total size exceeds the capacity.
+pub(super) struct Cache {
+    dir: PathBuf,
+    capacity: u64,
+    /// Sizes of the cached objects, in the order of use.
+    entries: Mutex<Entries>,
+}
+
+struct Entries {
+    lru: lru::LruCache<String, u64>,
+    size: u64,
+}
+
+impl Cache {
+    /// Opens the cache in `dir`, keeping the objects cached by the previous
+    /// runs.
+    pub fn open(dir: &Path, capacity: u64) -> io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+        let mut files = vec![];
+        Self::list_files(dir, "", &mut files)?;
+        // Approximates the order of use with the order of writes.
+        files.sort();
+        let mut entries = Entries { lru: lru::LruCache::unbounded(), size: 0 };
+        for (_, name, size) in files {
+            entries.size += size;
+            entries.lru.put(name, size);
+        }
+        let cache = Self { dir: dir.to_path_buf(), capacity, entries: Mutex::new(entries) };
+        cache.evict(&mut cache.entries.lock())?;
+        Ok(cache)
+    }
+
+    fn list_files(
+        root: &Path,
+        dir: &str,
+        files: &mut Vec<(std::time::SystemTime, String, u64)>,
+    ) -> io::Result<()> {
+        for entry in std::fs::read_dir(root.join(dir))? {
+            let entry = entry?;
+            let Some(file_name) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            let name =
+                if dir.is_empty() { file_name.clone() } else { format!("{dir}/{file_name}") };
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                Self::list_files(root, &name, files)?;
+            } else if file_name.starts_with(".tmp") {
+                // Left over by an interrupted write.
+                std::fs::remove_file(entry.path())?;
+            } else {
+                files.push((metadata.modified()?, name, metadata.len()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> io::Result<Option<Vec<u8>>> {
+        let cached = self.entries.lock().lru.get(name).is_some();
+        let result = if !cached {
+            None
+        } else {
+            match std::fs::read(self.dir.join(name)) {
+                Ok(data) => Some(data),
+                // Evicted in the meantime.
+                Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+                Err(err) => return Err(err),
+            }
+        };
+        let label = if result.is_some() { "hit" } else { "miss" };
+        metrics::ARCHIVAL_OBJECT_STORE_CACHE.with_label_values(&[label]).inc();
+        Ok(result)
+    }
+
+    pub fn put(&self, name: &str, data: &[u8]) -> io::Result<()> {
+        use std::io::Write as _;
+
+        if data.len() as u64 > self.capacity {
+            return Ok(());
+        }
+        let path = self.dir.join(name);
+        let dir = path.parent().unwrap();
+        std::fs::create_dir_all(dir)?;
+        let mut tmp = tempfile::NamedTempFile::new_in(dir)?;
+        tmp.write_all(data)?;
+        tmp.persist(&path).map_err(|err| err.error)?;
+
+        let mut entries = self.entries.lock();
+        Self::remove_entry(&mut entries, name);
+        entries.size += data.len() as u64;
+        entries.lru.put(name.to_string(), data.len() as u64);
+        self.evict(&mut entries)
+    }
+
+    pub fn remove(&self, name: &str) -> io::Result<()> {
+        let mut entries = self.entries.lock();
+        if Self::remove_entry(&mut entries, name) {
+            Self::remove_file(&self.dir.join(name))?;
+        }
+        metrics::ARCHIVAL_OBJECT_STORE_CACHE_SIZE.set(entries.size as i64);
+        Ok(())
+    }
+
+    fn remove_entry(entries: &mut Entries, name: &str) -> bool {
+        match entries.lru.pop(name) {
+            Some(size) => {
+                entries.size -= size;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn evict(&self, entries: &mut Entries) -> io::Result<()> {
+        while entries.size > self.capacity {
+            let Some((name, size)) = entries.lru.pop_lru() else {
+                break;
+            };
+            entries.size -= size;
+            Self::remove_file(&self.dir.join(name))?;
+        }
+        metrics::ARCHIVAL_OBJECT_STORE_CACHE_SIZE.set(entries.size as i64);
+        Ok(())
+    }
+
+    fn remove_file(path: &Path) -> io::Result<()> {
+        match std::fs::remove_file(path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::metrics;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Timeout of a single request to the S3 bucket.
+const S3_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+/// Maximum number of names S3 returns in a single list request.
+const S3_MAX_KEYS: usize = 1000;
+
+/// Minimal blocking interface to a bucket of an object storage.
+///
+/// Object names are `/`-separated paths.
+pub trait ObjectStore: Send + Sync {
+    /// Returns the content of the object, or `None` if it doesn’t exist.
+    fn get(&self, name: &str) -> io::Result<Option<Vec<u8>>>;
+
+    /// Creates or replaces the object.
+    fn put(&self, name: &str, data: &[u8]) -> io::Result<()>;
+
+    /// Deletes the object.  Deleting an object which doesn’t exist is not an
+    /// error.
+    fn delete(&self, name: &str) -> io::Result<()>;
+
+    /// Returns the names of the first `limit` objects starting with `prefix`
+    /// and sorted after `start_after`, in order.  Returns fewer than `limit`
+    /// names only if there are no more objects.
+    fn list(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> io::Result<Vec<String>>;
+}
+
+fn record_request<T>(op: &str, result: io::Result<T>) -> io::Result<T> {
+    let label = if result.is_ok() { "ok" } else { "error" };
+    metrics::ARCHIVAL_OBJECT_STORE_REQUESTS.with_label_values(&[op, label]).inc();
+    result
+}
+
+/// A bucket in AWS S3 or in an S3-compatible service such as MinIO.
+pub struct S3ObjectStore {
+    bucket: s3::Bucket,
+}
+
+impl S3ObjectStore {
+    /// Connects to the bucket, with the credentials taken from the environment.
+    /// If `endpoint` is set, path-style requests are sent to that endpoint
+    /// instead of AWS S3.
+    pub fn new(bucket: &str, region: &str, endpoint: Option<&str>) -> io::Result<Self> {
+        let region = match endpoint {
+            Some(endpoint) => {
+                s3::Region::Custom { region: region.to_string(), endpoint: endpoint.to_string() }
+            }
+            None => region.parse().map_err(io::Error::other)?,
+        };
+        let credentials = s3::creds::Credentials::default().map_err(io::Error::other)?;
+        let mut bucket = s3::Bucket::new(bucket, region, credentials).map_err(io::Error::other)?;
+        if endpoint.is_some() {
+            bucket = bucket.with_path_style();
+        }
+        bucket.set_request_timeout(Some(S3_REQUEST_TIMEOUT));
+        Ok(Self { bucket })
+    }
+
+    fn check_status(name: &str, op: &str, status_code: u16) -> io::Result<()> {
+        if !(200..300).contains(&status_code) {
+            return Err(io::Error::other(format!("{op} {name} returned status {status_code}")));
+        }
+        Ok(())
+    }
+}
+
+impl ObjectStore for S3ObjectStore {
+    fn get(&self, name: &str) -> io::Result<Option<Vec<u8>>> {
+        let result = match self.bucket.get_object_blocking(name) {
+            Ok(response) if response.status_code() == 404 => Ok(None),
+            Ok(response) => Self::check_status(name, "GET", response.status_code())
+                .map(|()| Some(response.bytes().to_vec())),
+            Err(s3::error::S3Error::Http(404, _)) => Ok(None),
+            Err(err) => Err(io::Error::other(err)),
+        };
+        record_request("get", result)
+    }
+
+    fn put(&self, name: &str, data: &[u8]) -> io::Result<()> {
+        let result = match self.bucket.put_object_blocking(name, data) {
+            Ok(response) => Self::check_status(name, "PUT", response.status_code()),
+            Err(err) => Err(io::Error::other(err)),
+        };
+        record_request("put", result)
+    }
+
+    fn delete(&self, name: &str) -> io::Result<()> {
+        let result = match self.bucket.delete_object_blocking(name) {
+            Ok(response) if response.status_code() == 404 => Ok(()),
+            Ok(response) => Self::check_status(name, "DELETE", response.status_code()),
+            Err(s3::error::S3Error::Http(404, _)) => Ok(()),
+            Err(err) => Err(io::Error::other(err)),
+        };
+        record_request("delete", result)
+    }
+
+    fn list(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> io::Result<Vec<String>> {
+        let mut names: Vec<String> = vec![];
+        while names.len() < limit {
+            // Continues after the last listed name rather than with the
+            // continuation token, which isn't returned by the v1 API.
+            let start_after = names.last().map(String::as_str).or(start_after);
+            let page = self.bucket.list_page_blocking(
+                prefix.to_string(),
+                None,
+                None,
+                start_after.map(str::to_string),
+                Some((limit - names.len()).min(S3_MAX_KEYS)),
+            );
+            let (page, _) = record_request("list", page.map_err(io::Error::other))?;
+            names.extend(page.contents.into_iter().map(|object| object.key));
+            if !page.is_truncated {
+                break;
+            }
+        }
+        names.truncate(limit);
+        Ok(names)
+    }
+}
+
+/// An object store keeping the objects as files in a local directory.
+///
+/// It stands in for an S3 bucket in tests, and can also be used to keep the
+/// archival storage on a network mount.
+pub struct LocalObjectStore {
+    root: PathBuf,
+}
+
+impl LocalObjectStore {
+    pub fn new(root: &Path) -> io::Result<Self> {
+        std::fs::create_dir_all(root)?;
+        Ok(Self { root: root.to_path_buf() })
+    }
+
+    fn list_dir(&self, dir: &str, prefix: &str, names: &mut Vec<String>) -> io::Result<()> {
+        let entries = match std::fs::read_dir(self.root.join(dir)) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        for entry in entries {
+            let entry = entry?;
+            let Some(file_name) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            let name =
+                if dir.is_empty() { file_name.clone() } else { format!("{dir}/{file_name}") };
+            if entry.file_type()?.is_dir() {
+                // Only descend into the directories which may contain matching objects.
+                if name.starts_with(prefix) || prefix.starts_with(&format!("{name}/")) {
+                    self.list_dir(&name, prefix, names)?;
+                }
+            } else if name.starts_with(prefix) && !file_name.starts_with(".tmp") {
+                names.push(name);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ObjectStore for LocalObjectStore {
+    fn get(&self, name: &str) -> io::Result<Option<Vec<u8>>> {
+        match std::fs::read(self.root.join(name)) {
+            Ok(data) => Ok(Some(data)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn put(&self, name: &str, data: &[u8]) -> io::Result<()> {
+        use std::io::Write as _;
+
+        let path = self.root.join(name);
+        let dir = path.parent().unwrap();
+        std::fs::create_dir_all(dir)?;
+        let mut tmp = tempfile::NamedTempFile::new_in(dir)?;
+        tmp.write_all(data)?;
+        tmp.persist(&path).map_err(|err| err.error)?;
+        Ok(())
+    }
+
+    fn delete(&self, name: &str) -> io::Result<()> {
+        match std::fs::remove_file(self.root.join(name)) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    /// Walks the whole directory for every page, which is fine for the tests
+    /// and the small deployments this is meant for.
+    fn list(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> io::Result<Vec<String>> {
+        let mut names = vec![];
+        self.list_dir("", prefix, &mut names)?;
+        names.retain(|name| start_after.is_none_or(|start_after| name.as_str() > start_after));
+        names.sort();
+        names.truncate(limit);
+        Ok(names)
+    }
+}
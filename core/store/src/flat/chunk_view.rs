@@ -2,6 +2,7 @@ use crate::adapter::flat_store::FlatStoreAdapter;
 use near_primitives::hash::CryptoHash;
 use near_primitives::shard_layout::ShardUId;
 use near_primitives::state::FlatStateValue;
+use near_primitives::types::BlockHeight;
 
 use super::FlatStorage;
 use super::types::FlatStateIterator;
@@ -23,6 +24,9 @@ pub struct FlatStorageChunkView {
     /// The block for which key-value pairs of its state will be retrieved. The flat state
     /// will reflect the state AFTER the block is applied.
     block_hash: CryptoHash,
+    /// Height of the block if it is below flat head, so that its state is read
+    /// from the history of flat state.
+    history_height: Option<BlockHeight>,
     /// Stores the state of the flat storage, for example, where the head is at and which
     /// blocks' state are stored in flat storage.
     flat_storage: FlatStorage,
@@ -30,7 +34,18 @@ pub struct FlatStorageChunkView {
 
 impl FlatStorageChunkView {
     pub fn new(store: FlatStoreAdapter, block_hash: CryptoHash, flat_storage: FlatStorage) -> Self {
-        Self { store, block_hash, flat_storage }
+        Self { store, block_hash, history_height: None, flat_storage }
+    }
+
+    /// Creates a view of the state of the canonical block `block_hash` at
+    /// `block_height`, read from the history of flat state.
+    pub fn new_at_height(
+        store: FlatStoreAdapter,
+        block_hash: CryptoHash,
+        block_height: BlockHeight,
+        flat_storage: FlatStorage,
+    ) -> Self {
+        Self { store, block_hash, history_height: Some(block_height), flat_storage }
     }
     /// Returns value reference using raw trie key, taken from the state
     /// corresponding to `FlatStorageChunkView::block_hash`.
@@ -40,16 +55,39 @@ impl FlatStorageChunkView {
     /// could charge users for the value length before loading the value.
     // TODO (#7327): consider inlining small values, so we could use only one db access.
     pub fn get_value(&self, key: &[u8]) -> Result<Option<FlatStateValue>, crate::StorageError> {
-        self.flat_storage.get_value(&self.block_hash, key)
+        match self.history_height {
+            Some(height) => self.flat_storage.get_history_value(&self.block_hash, height, key),
+            None => self.flat_storage.get_value(&self.block_hash, key),
+        }
     }
 
     pub fn contains_key(&self, key: &[u8]) -> Result<bool, crate::StorageError> {
-        self.flat_storage.contains_key(&self.block_hash, key)
+        match self.history_height {
+            Some(height) => {
+                Ok(self.flat_storage.get_history_value(&self.block_hash, height, key)?.is_some())
+            }
+            None => self.flat_storage.contains_key(&self.block_hash, key),
+        }
+    }
+
+    /// Returns the values of the keys starting with `prefix`, in the order of
+    /// keys, if the state is read from the history of flat state.  Returns
+    /// `None` for the state at or above flat head, which is read with
+    /// `iter_range`.
+    pub fn get_history_values_with_prefix(
+        &self,
+        prefix: &[u8],
+    ) -> Result<Option<Vec<(Vec<u8>, FlatStateValue)>>, crate::StorageError> {
+        let Some(height) = self.history_height else {
+            return Ok(None);
+        };
+        self.flat_storage.get_history_values_with_prefix(&self.block_hash, height, prefix).map(Some)
     }
 
     // TODO: this should be changed to check the values that haven't yet been applied, like in get_value() and contains_key(),
     // because otherwise we're iterating over old state that might have been updated by `self.block_hash`
     pub fn iter_range(&self, from: Option<&[u8]>, to: Option<&[u8]>) -> FlatStateIterator {
+        debug_assert!(self.history_height.is_none(), "iterating over history is not supported");
         self.store.iter_range(self.flat_storage.shard_uid(), from, to)
     }
 
@@ -5,7 +5,7 @@ use crate::flat::{
 use near_primitives::errors::StorageError;
 use near_primitives::hash::CryptoHash;
 use near_primitives::shard_layout::ShardUId;
-use near_primitives::types::{BlockHeight, RawStateChangesWithTrieKey};
+use near_primitives::types::{BlockHeight, BlockHeightDelta, RawStateChangesWithTrieKey};
 use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -42,14 +42,25 @@ pub struct FlatStorageManagerInner {
     /// Set to Some() when there's a state snapshot in progress. Used to signal to the resharding flat
     /// storage catchup code that it shouldn't advance past this block height
     want_snapshot: Mutex<Option<SnapshotBlock>>,
+    /// Number of blocks below flat head for which past values of flat state
+    /// are kept. See `StoreConfig::flat_state_history_window`.
+    history_window: Option<BlockHeightDelta>,
 }
 
 impl FlatStorageManager {
     pub fn new(store: FlatStoreAdapter) -> Self {
+        Self::new_with_history_window(store, None)
+    }
+
+    pub fn new_with_history_window(
+        store: FlatStoreAdapter,
+        history_window: Option<BlockHeightDelta>,
+    ) -> Self {
         Self(Arc::new(FlatStorageManagerInner {
             store,
             flat_storages: Default::default(),
             want_snapshot: Default::default(),
+            history_window,
         }))
     }
 
@@ -86,7 +97,8 @@ impl FlatStorageManager {
         let disable_updates = want_snapshot.is_some();
 
         let mut flat_storages = self.0.flat_storages.lock();
-        let flat_storage = FlatStorage::new(self.0.store.clone(), shard_uid)?;
+        let flat_storage =
+            FlatStorage::new(self.0.store.clone(), shard_uid, self.0.history_window)?;
         if disable_updates {
             flat_storage.set_flat_head_update_mode(false);
         }
@@ -252,6 +264,32 @@ impl FlatStorageManager {
         Some(FlatStorageChunkView::new(self.0.store.clone(), block_hash, flat_storage))
     }
 
+    /// Creates `FlatStorageChunkView` to access state for `shard_uid` and the
+    /// canonical block `block_hash` at `block_height`.  Unlike `chunk_view`,
+    /// the block may be below flat head if the history of flat state is kept
+    /// for its height.  Returns `None` if flat storage can't provide the state
+    /// or if the history is not kept at all.
+    pub fn chunk_view_at_height(
+        &self,
+        shard_uid: ShardUId,
+        block_hash: CryptoHash,
+        block_height: BlockHeight,
+    ) -> Option<FlatStorageChunkView> {
+        self.0.history_window?;
+        let flat_storage = self.get_flat_storage_for_shard(shard_uid)?;
+        if flat_storage.get_blocks_to_head(&block_hash).is_ok() {
+            return Some(FlatStorageChunkView::new(self.0.store.clone(), block_hash, flat_storage));
+        }
+        flat_storage.has_history_for_height(block_height).then(|| {
+            FlatStorageChunkView::new_at_height(
+                self.0.store.clone(),
+                block_hash,
+                block_height,
+                flat_storage,
+            )
+        })
+    }
+
     // TODO (#7327): consider returning Result<FlatStorage, Error> when we expect flat storage to exist
     pub fn get_flat_storage_for_shard(&self, shard_uid: ShardUId) -> Option<FlatStorage> {
         let flat_storages = self.0.flat_storages.lock();
@@ -5,6 +5,7 @@ use near_primitives::{shard_layout::ShardUId, types::BlockHeight};
 
 pub(crate) struct FlatStorageMetrics {
     flat_head_height: IntGauge,
+    history_tail_height: IntGauge,
     distance_to_head: IntGauge,
     hops_to_head: IntGauge,
     cached_deltas: IntGauge,
@@ -18,6 +19,8 @@ impl FlatStorageMetrics {
         Self {
             flat_head_height: flat_state_metrics::FLAT_STORAGE_HEAD_HEIGHT
                 .with_label_values(&[&shard_uid_label]),
+            history_tail_height: flat_state_metrics::FLAT_STORAGE_HISTORY_TAIL_HEIGHT
+                .with_label_values(&[&shard_uid_label]),
             distance_to_head: flat_state_metrics::FLAT_STORAGE_DISTANCE_TO_HEAD
                 .with_label_values(&[&shard_uid_label]),
             hops_to_head: flat_state_metrics::FLAT_STORAGE_HOPS_TO_HEAD
@@ -40,6 +43,10 @@ impl FlatStorageMetrics {
         self.flat_head_height.set(height as i64);
     }
 
+    pub(crate) fn set_history_tail_height(&self, height: Option<BlockHeight>) {
+        self.history_tail_height.set(height.unwrap_or(0) as i64);
+    }
+
     pub(crate) fn set_cached_deltas(
         &self,
         cached_deltas: usize,
@@ -1,11 +1,11 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use near_primitives::errors::StorageError;
 use near_primitives::hash::CryptoHash;
 use near_primitives::shard_layout::ShardUId;
 use near_primitives::state::FlatStateValue;
-use near_primitives::types::BlockHeight;
+use near_primitives::types::{BlockHeight, BlockHeightDelta};
 use parking_lot::RwLock;
 use tracing::{debug, warn};
 
@@ -15,7 +15,7 @@ use crate::flat::delta::{BlockWithChangesInfo, CachedFlatStateChanges};
 use crate::flat::{FlatStorageReadyStatus, FlatStorageStatus};
 
 use super::FlatStorageReshardingStatus;
-use super::delta::{CachedFlatStateDelta, FlatStateDelta};
+use super::delta::{CachedFlatStateDelta, FlatStateChanges, FlatStateDelta};
 use super::metrics::FlatStorageMetrics;
 use super::types::FlatStorageError;
 
@@ -46,6 +46,11 @@ pub(crate) struct FlatStorageInner {
     deltas: HashMap<CryptoHash, CachedFlatStateDelta>,
     /// Defines whether flat head can be moved forward or not.
     move_head_enabled: bool,
+    /// Number of blocks below flat head for which past values of flat state
+    /// are kept in `DBCol::FlatStateHistory`, or `None` if they are not kept.
+    history_window: Option<BlockHeightDelta>,
+    /// First height for which past values of flat state are available.
+    history_tail: Option<BlockHeight>,
     metrics: FlatStorageMetrics,
 }
 
@@ -62,6 +67,71 @@ impl FlatStorageInner {
 
     const BLOCKS_WITH_CHANGES_FLAT_HEAD_GAP: BlockHeight = 2;
 
+    /// Maximum number of heights for which history is pruned during a single
+    /// update of flat head, so that shrinking the window doesn't stall it.
+    const HISTORY_PRUNING_BATCH_SIZE: usize = 100;
+
+    /// Whether the state at the given height, which must be on the canonical
+    /// chain, can be read from the history of flat state.
+    fn has_history_for_height(&self, height: BlockHeight) -> bool {
+        self.history_tail.is_some_and(|tail| tail <= height && height <= self.flat_head.height)
+    }
+
+    /// Saves the current values of keys changed by the block at `height`,
+    /// before the changes are applied to flat state.
+    fn save_history(
+        &self,
+        store_update: &mut FlatStoreUpdateAdapter,
+        changes: &FlatStateChanges,
+        height: BlockHeight,
+    ) -> Result<(), FlatStorageError> {
+        let mut keys = Vec::with_capacity(changes.len());
+        for key in changes.0.keys() {
+            let value = self.store.get(self.shard_uid, key)?;
+            store_update.set_history_value(self.shard_uid, key, height, &value);
+            keys.push(key.clone());
+        }
+        if !keys.is_empty() {
+            store_update.set_history_keys(self.shard_uid, height, &keys);
+        }
+        Ok(())
+    }
+
+    /// Removes the history which is not needed for the heights in the window
+    /// below flat head anymore.
+    fn prune_history(
+        &mut self,
+        store_update: &mut FlatStoreUpdateAdapter,
+    ) -> Result<(), FlatStorageError> {
+        let (Some(window), Some(tail)) = (self.history_window, self.history_tail) else {
+            return Ok(());
+        };
+        let mut new_tail = self.flat_head.height.saturating_sub(window);
+        if new_tail <= tail {
+            return Ok(());
+        }
+        let rows = self.store.get_history_keys_until(
+            self.shard_uid,
+            new_tail + 1,
+            Self::HISTORY_PRUNING_BATCH_SIZE,
+        )?;
+        if rows.len() == Self::HISTORY_PRUNING_BATCH_SIZE {
+            // The rest is pruned during the next updates.
+            new_tail = rows.last().map_or(new_tail, |(height, _)| *height);
+        }
+        for (height, keys) in rows {
+            for key in keys {
+                store_update.remove_history_value(self.shard_uid, &key, height);
+            }
+            store_update.remove_history_keys(self.shard_uid, height);
+        }
+        // Values saved for heights up to `new_tail` are only valid below it,
+        // so an empty row marks the new tail.
+        store_update.set_history_keys(self.shard_uid, new_tail, &[]);
+        self.history_tail = Some(new_tail);
+        Ok(())
+    }
+
     /// Creates `BlockNotSupported` error for the given block.
     /// In the context of updating the flat head, the error is handled gracefully.
     fn create_block_not_supported_error(&self, block_hash: &CryptoHash) -> FlatStorageError {
@@ -233,7 +303,11 @@ impl FlatStorage {
     /// Create a new FlatStorage for `shard_uid` using flat head if it is stored on storage.
     /// We also load all blocks with height between flat head to `latest_block_height`
     /// including those on forks into the returned FlatStorage.
-    pub fn new(store: FlatStoreAdapter, shard_uid: ShardUId) -> Result<Self, StorageError> {
+    pub fn new(
+        store: FlatStoreAdapter,
+        shard_uid: ShardUId,
+        history_window: Option<BlockHeightDelta>,
+    ) -> Result<Self, StorageError> {
         let shard_id = shard_uid.shard_id();
         let flat_head = match store.get_flat_storage_status(shard_uid) {
             Ok(FlatStorageStatus::Ready(ready_status)) => ready_status.flat_head,
@@ -273,12 +347,35 @@ impl FlatStorage {
             );
         }
 
+        let history_tail = store.get_history_tail(shard_uid)?;
+        let history_tail = match (history_window, history_tail) {
+            (Some(_), Some(tail)) => Some(tail),
+            (Some(_), None) => {
+                // Start keeping history from the current flat head.
+                let mut store_update = store.store_update();
+                store_update.set_history_keys(shard_uid, flat_head.height, &[]);
+                store_update.commit().map_err(|_| StorageError::StorageInternalError)?;
+                Some(flat_head.height)
+            }
+            (None, Some(_)) => {
+                // History isn't updated anymore, so it can't be used.
+                let mut store_update = store.store_update();
+                store_update.remove_history(shard_uid);
+                store_update.commit().map_err(|_| StorageError::StorageInternalError)?;
+                None
+            }
+            (None, None) => None,
+        };
+        metrics.set_history_tail_height(history_tail);
+
         let inner = FlatStorageInner {
             store,
             shard_uid,
             flat_head,
             deltas,
             move_head_enabled: true,
+            history_window,
+            history_tail,
             metrics,
         };
         inner.update_delta_metrics();
@@ -317,6 +414,61 @@ impl FlatStorage {
         Ok(value)
     }
 
+    /// Whether the state at the given height, which must be on the canonical
+    /// chain below flat head, can be read with `get_history_value`.
+    pub fn has_history_for_height(&self, height: BlockHeight) -> bool {
+        self.0.read().has_history_for_height(height)
+    }
+
+    /// Returns the value of `key` after applying the canonical block
+    /// `block_hash` at `height`, from the history of flat state.
+    pub fn get_history_value(
+        &self,
+        block_hash: &CryptoHash,
+        height: BlockHeight,
+        key: &[u8],
+    ) -> Result<Option<FlatStateValue>, crate::StorageError> {
+        let guard = self.0.read();
+        if !guard.has_history_for_height(height) {
+            return Err(guard.create_block_not_supported_error(block_hash).into());
+        }
+        // Flat head can't move while the lock is held, so the value at head
+        // is consistent with the history.
+        match guard.store.get_history_value(guard.shard_uid, key, height)? {
+            Some(value) => Ok(value),
+            None => Ok(guard.store.get(guard.shard_uid, key)?),
+        }
+    }
+
+    /// Returns the values of the keys starting with `prefix` after applying
+    /// the canonical block `block_hash` at `height`, in the order of keys,
+    /// from the history of flat state.
+    pub fn get_history_values_with_prefix(
+        &self,
+        block_hash: &CryptoHash,
+        height: BlockHeight,
+        prefix: &[u8],
+    ) -> Result<Vec<(Vec<u8>, FlatStateValue)>, crate::StorageError> {
+        let guard = self.0.read();
+        if !guard.has_history_for_height(height) {
+            return Err(guard.create_block_not_supported_error(block_hash).into());
+        }
+        let mut values = BTreeMap::new();
+        for item in guard.store.iter_prefix(guard.shard_uid, prefix) {
+            let (key, value) = item?;
+            values.insert(key, value);
+        }
+        for (key, value) in
+            guard.store.get_history_values_with_prefix(guard.shard_uid, prefix, height)?
+        {
+            match value {
+                Some(value) => values.insert(key, value),
+                None => values.remove(&key),
+            };
+        }
+        Ok(values.into_iter().collect())
+    }
+
     /// Same as `get_value()?.is_some()`, but avoids reading out the value.
     pub fn contains_key(
         &self,
@@ -392,7 +544,6 @@ impl FlatStorage {
                 .store
                 .get_delta(shard_uid, block_hash)?
                 .ok_or_else(|| missing_delta_error(&block_hash))?;
-            changes.apply_to_flat_state(&mut store_update, guard.shard_uid);
             let metadata = guard
                 .deltas
                 .get(&block_hash)
@@ -400,6 +551,10 @@ impl FlatStorage {
                 .metadata;
             let block = metadata.block;
             let block_height = block.height;
+            if guard.history_tail.is_some() {
+                guard.save_history(&mut store_update, &changes, block_height)?;
+            }
+            changes.apply_to_flat_state(&mut store_update, guard.shard_uid);
             store_update.set_flat_storage_status(
                 shard_uid,
                 FlatStorageStatus::Ready(FlatStorageReadyStatus { flat_head: block }),
@@ -407,6 +562,8 @@ impl FlatStorage {
 
             guard.metrics.set_flat_head_height(block.height);
             guard.flat_head = block;
+            guard.prune_history(&mut store_update)?;
+            guard.metrics.set_history_tail_height(guard.history_tail);
 
             // Remove old deltas from disk and memory.
             // Do it for each head update separately to ensure that old data is removed properly if node was
@@ -477,10 +634,12 @@ impl FlatStorage {
         &self,
         store_update: &mut FlatStoreUpdateAdapter,
     ) -> Result<(), StorageError> {
-        let guard = self.0.write();
+        let mut guard = self.0.write();
         let shard_uid = guard.shard_uid;
         store_update.remove_all_values(shard_uid);
         store_update.remove_all_deltas(shard_uid);
+        store_update.remove_history(shard_uid);
+        guard.history_tail = None;
         store_update.set_flat_storage_status(shard_uid, FlatStorageStatus::Empty);
         guard.update_delta_metrics();
         Ok(())
@@ -1264,4 +1423,112 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn flat_storage_history() {
+        init_test_logger();
+        // 1. Create a chain 0 -> 2 -> 4 -> ... -> 18 and keep history for 6 blocks.
+        //    Block at height h sets key &[1] to &[h] and key &[1, 0] to &[100 + h],
+        //    block at height 6 also sets key &[2].
+        let chain = MockChain::linear_chain_with_skips(10);
+        let shard_uid = ShardUId::single_shard();
+        let store = create_test_store().flat_store();
+        let mut store_update = store.store_update();
+        store_update.set_flat_storage_status(
+            shard_uid,
+            FlatStorageStatus::Ready(FlatStorageReadyStatus { flat_head: chain.get_block(0) }),
+        );
+        store_update.set(shard_uid, vec![1], Some(FlatStateValue::value_ref(&[0])));
+        store_update.set(shard_uid, vec![1, 0], Some(FlatStateValue::value_ref(&[100])));
+        for i in 1..10 {
+            let height = i * 2;
+            let mut changes = FlatStateChanges::from([
+                (vec![1], Some(FlatStateValue::value_ref(&[height as u8]))),
+                (vec![1, 0], Some(FlatStateValue::value_ref(&[100 + height as u8]))),
+            ]);
+            if height == 6 {
+                changes.insert(vec![2], Some(FlatStateValue::value_ref(&[6])));
+            }
+            let delta = FlatStateDelta {
+                changes,
+                metadata: FlatStateDeltaMetadata {
+                    block: chain.get_block(height),
+                    prev_block_with_changes: None,
+                },
+            };
+            store_update.set_delta(shard_uid, &delta);
+        }
+        store_update.commit().unwrap();
+
+        let flat_storage_manager =
+            FlatStorageManager::new_with_history_window(store.clone(), Some(6));
+        flat_storage_manager.create_flat_storage_for_shard(shard_uid).unwrap();
+        let flat_storage = flat_storage_manager.get_flat_storage_for_shard(shard_uid).unwrap();
+
+        // Checks values at the given heights, skipped heights have the state of the previous block.
+        let check_history = |heights: std::ops::RangeInclusive<BlockHeight>| {
+            for height in heights {
+                let block_height = height - height % 2;
+                let chunk_view = flat_storage_manager
+                    .chunk_view_at_height(shard_uid, CryptoHash::default(), height)
+                    .unwrap();
+                assert_eq!(
+                    chunk_view.get_value(&[1]).unwrap(),
+                    Some(FlatStateValue::value_ref(&[block_height as u8]))
+                );
+                assert_eq!(
+                    chunk_view.get_value(&[1, 0]).unwrap(),
+                    Some(FlatStateValue::value_ref(&[100 + block_height as u8]))
+                );
+                assert_eq!(
+                    chunk_view.contains_key(&[2]).unwrap(),
+                    block_height >= 6,
+                    "height {height}"
+                );
+                assert_eq!(
+                    chunk_view.get_history_values_with_prefix(&[1]).unwrap(),
+                    Some(vec![
+                        (vec![1], FlatStateValue::value_ref(&[block_height as u8])),
+                        (vec![1, 0], FlatStateValue::value_ref(&[100 + block_height as u8])),
+                    ])
+                );
+                let num_keys =
+                    chunk_view.get_history_values_with_prefix(&[]).unwrap().unwrap().len();
+                assert_eq!(num_keys, if block_height >= 6 { 3 } else { 2 }, "height {height}");
+            }
+        };
+
+        // 2. Move the flat head to height 8. History is kept from height 2.
+        flat_storage.update_flat_head_impl(&chain.get_block_hash(8), true).unwrap();
+        check_history(2..=8);
+        assert!(!flat_storage.has_history_for_height(1));
+        assert!(!flat_storage.has_history_for_height(9));
+        assert!(
+            flat_storage_manager
+                .chunk_view_at_height(shard_uid, chain.get_block_hash(0), 0)
+                .is_none()
+        );
+
+        // 3. Move the flat head to height 18. History below height 12 is removed.
+        flat_storage.update_flat_head_impl(&chain.get_block_hash(18), true).unwrap();
+        check_history(12..=18);
+        assert!(!flat_storage.has_history_for_height(10));
+        assert_eq!(store.get_history_tail(shard_uid).unwrap(), Some(12));
+        assert_eq!(store.get_history_keys_until(shard_uid, 12, 100).unwrap(), vec![]);
+        assert!(
+            flat_storage_manager
+                .chunk_view_at_height(shard_uid, chain.get_block_hash(4), 4)
+                .is_none()
+        );
+
+        // 4. Without the window, history is not used and removed on restart.
+        let flat_storage_manager = FlatStorageManager::new(store.clone());
+        flat_storage_manager.create_flat_storage_for_shard(shard_uid).unwrap();
+        assert!(
+            flat_storage_manager
+                .chunk_view_at_height(shard_uid, chain.get_block_hash(14), 14)
+                .is_none()
+        );
+        assert_eq!(store.get_history_tail(shard_uid).unwrap(), None);
+    }
 }
@@ -7,7 +7,7 @@ pub use crate::config::{Mode, StoreConfig};
 pub use crate::db::{
     CHUNK_TAIL_KEY, COLD_HEAD_KEY, FINAL_HEAD_KEY, FORK_TAIL_KEY, GENESIS_STATE_ROOTS_KEY,
     HEAD_KEY, HEADER_HEAD_KEY, LARGEST_TARGET_HEIGHT_KEY, LATEST_KNOWN_KEY, STATE_SNAPSHOT_KEY,
-    STATE_SYNC_DUMP_KEY, TAIL_KEY,
+    STATE_SYNC_DUMP_KEY, STATE_SYNC_DUMPED_PARTS_KEY, TAIL_KEY,
 };
 use crate::db::{DBTransaction, Database, StoreStatistics, metadata};
 pub use crate::node_storage::opener::{
@@ -30,6 +30,7 @@ pub use near_primitives::shard_layout::ShardUId;
 
 pub mod adapter;
 pub mod archive;
+pub mod backup;
 mod columns;
 pub mod config;
 pub mod contract;
@@ -242,6 +242,29 @@ pub static COLD_COPY_DURATION: LazyLock<Histogram> = LazyLock::new(|| {
     )
     .unwrap()
 });
+pub(crate) static ARCHIVAL_OBJECT_STORE_REQUESTS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "near_archival_object_store_requests_total",
+        "Number of requests made to the object store of the archival storage",
+        &["op", "result"],
+    )
+    .unwrap()
+});
+pub(crate) static ARCHIVAL_OBJECT_STORE_CACHE: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "near_archival_object_store_cache_total",
+        "Number of lookups in the local cache of the archival object store, by result",
+        &["result"],
+    )
+    .unwrap()
+});
+pub(crate) static ARCHIVAL_OBJECT_STORE_CACHE_SIZE: LazyLock<IntGauge> = LazyLock::new(|| {
+    try_create_int_gauge(
+        "near_archival_object_store_cache_size_bytes",
+        "Size of the local cache of the archival object store",
+    )
+    .unwrap()
+});
 
 pub(crate) static HAS_STATE_SNAPSHOT: LazyLock<IntGauge> = LazyLock::new(|| {
     try_create_int_gauge("near_has_state_snapshot", "Whether a node has a state snapshot open")
@@ -400,6 +423,14 @@ pub mod flat_state_metrics {
         )
         .unwrap()
     });
+    pub static FLAT_STORAGE_HISTORY_TAIL_HEIGHT: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+        try_create_int_gauge_vec(
+            "near_flat_storage_history_tail_height",
+            "First height for which past values of flat storage are kept",
+            &["shard_uid"],
+        )
+        .unwrap()
+    });
     pub static FLAT_STORAGE_CACHED_DELTAS: LazyLock<IntGaugeVec> = LazyLock::new(|| {
         try_create_int_gauge_vec(
             "near_flat_storage_cached_deltas",
@@ -1,6 +1,7 @@
-use crate::config::ArchivalConfig;
+use crate::config::{ArchivalConfig, ArchivalStorageLocation, S3ArchivalStorageConfig};
 use crate::db::rocksdb::RocksDB;
 use crate::db::rocksdb::snapshot::{Snapshot, SnapshotError, SnapshotRemoveError};
+use crate::db::{FilesystemDB, ObjectStoreDB, S3ObjectStore};
 use crate::metadata::{DB_VERSION, DbKind, DbMetadata, DbVersion};
 use crate::{DBCol, DBTransaction, Mode, NodeStorage, Store, StoreConfig, Temperature};
 use std::sync::Arc;
@@ -171,6 +172,10 @@ pub struct StoreOpener<'a> {
     /// Opener for an instance of Cold RocksDB store if one was configured.
     cold: Option<DBOpener<'a>>,
 
+    /// Opener for the archival storage used as the cold storage instead of the
+    /// Cold RocksDB, if one was configured.
+    archival_storage: Option<ArchivalStorageOpener<'a>>,
+
     /// A migrator which performs database migration if the database has old
     /// version.
     migrator: Option<&'a dyn StoreMigrator>,
@@ -179,6 +184,15 @@ pub struct StoreOpener<'a> {
     archival_config: Option<ArchivalConfig<'a>>,
 }
 
+/// Opener for an archival storage other than the Cold RocksDB.
+enum ArchivalStorageOpener<'a> {
+    /// Opens a [`FilesystemDB`] at the given path.
+    Filesystem { path: std::path::PathBuf },
+    /// Opens an [`ObjectStoreDB`] in the S3 bucket, with the cache at the
+    /// given path.
+    S3 { config: &'a S3ArchivalStorageConfig, cache_path: std::path::PathBuf },
+}
+
 /// Opener for a single RocksDB instance.
 struct DBOpener<'a> {
     /// Path to the database.
@@ -215,6 +229,10 @@ impl<'a> StoreOpener<'a> {
                         .map(|config| DBOpener::new(home_dir, config, Temperature::Cold))
                 })
                 .flatten(),
+            archival_storage: archival_config
+                .as_ref()
+                .and_then(|config| config.archival_store_config)
+                .and_then(|config| ArchivalStorageOpener::new(home_dir, &config.storage)),
             archival_config,
             migrator: None,
         }
@@ -242,6 +260,18 @@ impl<'a> StoreOpener<'a> {
         &self.hot.path
     }
 
+    /// Returns path to the cold database if one is configured and stored
+    /// locally.
+    ///
+    /// Does not check whether the database actually exists.
+    pub fn cold_path(&self) -> Option<&std::path::Path> {
+        match (&self.cold, &self.archival_storage) {
+            (Some(cold), _) => Some(&cold.path),
+            (None, Some(ArchivalStorageOpener::Filesystem { path })) => Some(path),
+            (None, _) => None,
+        }
+    }
+
     #[cfg(test)]
     pub(crate) fn config(&self) -> &StoreConfig {
         self.hot.config
@@ -257,6 +287,10 @@ impl<'a> StoreOpener<'a> {
     pub fn open_unsafe(&self) -> Result<crate::NodeStorage, StoreOpenerError> {
         let mode = Mode::ReadWrite;
         let hot_db = self.hot.open_unsafe(mode)?;
+        if let Some(archival_storage) = &self.archival_storage {
+            let cold_db = archival_storage.open(mode)?;
+            return Ok(NodeStorage::new_with_cold(Arc::new(hot_db), cold_db));
+        }
         let cold_db = self.cold.as_ref().map(|cold| cold.open_unsafe(mode)).transpose()?;
         let storage = NodeStorage::from_rocksdb(hot_db, cold_db);
         Ok(storage)
@@ -308,7 +342,13 @@ impl<'a> StoreOpener<'a> {
     /// exists.
     pub fn open_in_mode(&self, mode: Mode) -> Result<crate::NodeStorage, StoreOpenerError> {
         let (hot_db, hot_snapshot, cold_db, cold_snapshot) = self.open_dbs(mode)?;
-        let storage = NodeStorage::from_rocksdb(hot_db, cold_db);
+        let storage = match &self.archival_storage {
+            Some(archival_storage) => {
+                let cold_db = Self::open_archival_storage(mode, archival_storage)?;
+                NodeStorage::new_with_cold(Arc::new(hot_db), cold_db)
+            }
+            None => NodeStorage::from_rocksdb(hot_db, cold_db),
+        };
 
         hot_snapshot.remove()?;
         cold_snapshot.remove()?;
@@ -345,6 +385,42 @@ impl<'a> StoreOpener<'a> {
         Ok((hot_snapshot, cold_snapshot))
     }
 
+    /// Opens the archival storage, creating it if it doesn't exist and the
+    /// mode allows.  Verifies that it has the expected kind and version.
+    /// There are no migrations for the archival storage.
+    fn open_archival_storage(
+        mode: Mode,
+        opener: &ArchivalStorageOpener,
+    ) -> Result<Arc<dyn crate::Database>, StoreOpenerError> {
+        let store = Store { storage: opener.open(mode)? };
+        match store.get_db_version()? {
+            Some(_) if mode.must_create() => return Err(StoreOpenerError::DbAlreadyExists),
+            Some(_) => {}
+            None if mode.can_create() => {
+                tracing::info!(target: "db_opener", "The archival storage doesn't exist, creating it.");
+                store.set_db_version(DB_VERSION)?;
+                store.set_db_kind(DbKind::Cold)?;
+            }
+            None => return Err(StoreOpenerError::DbDoesNotExist),
+        }
+
+        let DbMetadata { version, kind } = DbMetadata::read(store.storage.as_ref())?;
+        if version > DB_VERSION {
+            return Err(StoreOpenerError::DbVersionTooNew { got: version, want: DB_VERSION });
+        }
+        if version != DB_VERSION {
+            return Err(StoreOpenerError::DbVersionMismatch { got: version, want: DB_VERSION });
+        }
+        if kind != Some(DbKind::Cold) {
+            return Err(StoreOpenerError::DbKindMismatch {
+                which: Temperature::Cold.into(),
+                got: kind,
+                want: DbKind::Cold,
+            });
+        }
+        Ok(store.storage)
+    }
+
     // Creates the DB if it doesn't exist.
     fn ensure_created(mode: Mode, opener: &DBOpener) -> Result<(), StoreOpenerError> {
         let meta = opener.get_metadata()?;
@@ -499,6 +575,47 @@ impl<'a> StoreOpener<'a> {
     }
 }
 
+impl<'a> ArchivalStorageOpener<'a> {
+    /// Returns the opener for the archival storage, or `None` if the archival
+    /// data is kept in the Cold RocksDB.  Paths are resolved against home_dir.
+    fn new(home_dir: &std::path::Path, storage: &'a ArchivalStorageLocation) -> Option<Self> {
+        match storage {
+            ArchivalStorageLocation::Filesystem { path } => {
+                Some(Self::Filesystem { path: home_dir.join(path) })
+            }
+            ArchivalStorageLocation::S3(config) => {
+                Some(Self::S3 { config, cache_path: home_dir.join(&config.cache_path) })
+            }
+            ArchivalStorageLocation::ColdDB | ArchivalStorageLocation::GCloud { .. } => None,
+        }
+    }
+
+    /// Opens the database without checking its version and kind.
+    fn open(&self, mode: Mode) -> Result<Arc<dyn crate::Database>, StoreOpenerError> {
+        match self {
+            Self::Filesystem { path } => {
+                tracing::info!(target: "db_opener", path=%path.display(), "Opening the filesystem archival storage");
+                if mode.read_only() && !path.is_dir() {
+                    return Err(StoreOpenerError::DbDoesNotExist);
+                }
+                Ok(Arc::new(FilesystemDB::open(path, mode.read_only())?))
+            }
+            Self::S3 { config, cache_path } => {
+                tracing::info!(target: "db_opener", bucket=%config.bucket, cache_path=%cache_path.display(), "Opening the S3 archival storage");
+                let store =
+                    S3ObjectStore::new(&config.bucket, &config.region, config.endpoint.as_deref())?;
+                let db = ObjectStoreDB::open(
+                    Arc::new(store),
+                    cache_path,
+                    config.cache_size,
+                    mode.read_only(),
+                )?;
+                Ok(Arc::new(db))
+            }
+        }
+    }
+}
+
 impl<'a> DBOpener<'a> {
     /// Constructs new opener for a single RocksDB builder.
     ///
@@ -746,4 +863,41 @@ mod tests {
         check_keys_existence(&store.get_hot_store(), &DBCol::Chunks, &keys, false);
         check_keys_existence(&store.get_hot_store(), &DBCol::BlockHeader, &keys, false);
     }
+
+    #[test]
+    fn test_open_filesystem_archival_storage() {
+        let home_dir = tempfile::tempdir().unwrap();
+        let store_config = StoreConfig::test_config();
+        let archival_store_config = crate::config::ArchivalStoreConfig {
+            storage: ArchivalStorageLocation::Filesystem { path: "archive".into() },
+        };
+        let archival_config =
+            ArchivalConfig::new(true, Some(&archival_store_config), None, None).unwrap();
+        let opener = NodeStorage::opener(home_dir.path(), &store_config, Some(archival_config));
+
+        let keys = vec![vec![0], vec![1]];
+        {
+            let node_storage = opener.open().unwrap();
+            assert_eq!(node_storage.get_hot_store().get_db_kind().unwrap(), Some(DbKind::Hot));
+            let cold_store = node_storage.get_cold_store().unwrap();
+            assert_eq!(cold_store.get_db_kind().unwrap(), Some(DbKind::Cold));
+            assert_eq!(cold_store.get_db_version().unwrap(), Some(DB_VERSION));
+            let mut store_update = cold_store.store_update();
+            for key in &keys {
+                store_update.insert(DBCol::Block, key.clone(), vec![42]);
+            }
+            store_update.commit().unwrap();
+        }
+        assert!(home_dir.path().join("archive").is_dir());
+
+        let node_storage = opener.open_in_mode(Mode::ReadOnly).unwrap();
+        check_keys_existence(&node_storage.get_cold_store().unwrap(), &DBCol::Block, &keys, true);
+        let mut store_update = node_storage.get_cold_store().unwrap().store_update();
+        store_update.insert(DBCol::Block, vec![2], vec![42]);
+        assert!(store_update.commit().is_err());
+        assert!(matches!(
+            opener.open_in_mode(Mode::Create),
+            Err(StoreOpenerError::DbAlreadyExists)
+        ));
+    }
 }
@@ -1,7 +1,7 @@
 use crate::StoreConfig;
-use crate::config::{PrefetchConfig, TrieCacheConfig};
+use crate::config::{MemTrieMemoryBudget, PrefetchConfig, TrieCacheConfig};
 use near_primitives::shard_layout::ShardUId;
-use near_primitives::types::AccountId;
+use near_primitives::types::{AccountId, BlockHeightDelta};
 use std::str::FromStr;
 use tracing::error;
 
@@ -37,6 +37,15 @@ pub struct TrieConfig {
     pub load_memtries_for_shards: Vec<ShardUId>,
     /// Whether mem-trie should be loaded for each tracked shard.
     pub load_memtries_for_tracked_shards: bool,
+    /// Directory with on-disk images of mem-tries, if enabled. See
+    /// `StoreConfig::memtrie_image_path`.
+    pub memtrie_image_dir: Option<std::path::PathBuf>,
+    /// Memory budget of mem-tries, if they are partial. See
+    /// `StoreConfig::memtrie_memory_budget`.
+    pub memtrie_memory_budget: Option<MemTrieMemoryBudget>,
+    /// Number of blocks for which past values of flat state are kept. See
+    /// `StoreConfig::flat_state_history_window`.
+    pub flat_state_history_window: Option<BlockHeightDelta>,
 }
 
 impl TrieConfig {
@@ -64,10 +73,21 @@ impl TrieConfig {
         this.kaiching_prefetch_config.clone_from(&config.kaiching_prefetch_config);
         this.load_memtries_for_shards.clone_from(&config.load_memtries_for_shards);
         this.load_memtries_for_tracked_shards = config.load_memtries_for_tracked_shards;
+        this.memtrie_memory_budget.clone_from(&config.memtrie_memory_budget);
+        this.flat_state_history_window = config.flat_state_history_window;
 
         this
     }
 
+    /// Memory budget in bytes of the mem-tries of the given shard, or `None`
+    /// if the whole trie should be kept in memory.
+    pub fn memtrie_memory_budget(&self, shard_uid: ShardUId) -> Option<usize> {
+        let budget = self.memtrie_memory_budget.as_ref()?;
+        let max_bytes =
+            budget.per_shard_max_bytes.get(&shard_uid).unwrap_or(&budget.default_max_bytes);
+        Some(max_bytes.as_u64() as usize)
+    }
+
     /// Size limit in bytes per single value for caching in shard caches.
     pub fn max_cached_value_size() -> usize {
         TRIE_LIMIT_CACHED_VALUE_SIZE
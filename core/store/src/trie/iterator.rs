@@ -8,7 +8,7 @@ use super::mem::iter::STMemTrieIterator;
 use super::ops::interface::GenericTrieInternalStorage;
 use super::ops::iter::{TrieItem, TrieIteratorImpl};
 use super::trie_storage_update::{TrieStorageNode, TrieStorageNodePtr};
-use super::{AccessOptions, Trie, ValueHandle};
+use super::{AccessOptions, AccessTracker, Trie, ValueHandle};
 
 pub struct DiskTrieIteratorInner<'a> {
     trie: &'a Trie,
@@ -16,11 +16,25 @@ pub struct DiskTrieIteratorInner<'a> {
     /// This is used only for TrieViewer.
     /// TODO: Remove this once we shift to using recorded storage in trie iterator.
     visited_nodes: Option<RefCell<Vec<Arc<[u8]>>>>,
+    /// If not `None`, the nodes and values read by the iterator are reported
+    /// to this tracker, so that they can be charged for.
+    access_tracker: Option<&'a dyn AccessTracker>,
 }
 
 impl<'a> DiskTrieIteratorInner<'a> {
     pub fn new(trie: &'a Trie) -> Self {
-        Self { trie, visited_nodes: None }
+        Self { trie, visited_nodes: None, access_tracker: None }
+    }
+
+    pub fn with_access_tracker(self, access_tracker: Option<&'a dyn AccessTracker>) -> Self {
+        Self { access_tracker, ..self }
+    }
+
+    fn access_options(&self) -> AccessOptions<'a> {
+        match self.access_tracker {
+            Some(access_tracker) => AccessOptions::contract_runtime(access_tracker),
+            None => AccessOptions::DEFAULT,
+        }
     }
 
     pub fn remember_visited_nodes(&mut self, record_nodes: bool) {
@@ -44,21 +58,20 @@ impl<'a> GenericTrieInternalStorage<TrieStorageNodePtr, ValueHandle> for DiskTri
         &self,
         ptr: TrieStorageNodePtr,
     ) -> Result<TrieStorageNode, StorageError> {
-        let node = self.trie.retrieve_raw_node(&ptr, true, AccessOptions::DEFAULT)?.map(
-            |(bytes, node)| {
+        let node =
+            self.trie.retrieve_raw_node(&ptr, true, self.access_options())?.map(|(bytes, node)| {
                 if let Some(ref visited_nodes) = self.visited_nodes {
                     visited_nodes.borrow_mut().push(bytes);
                 }
                 TrieStorageNode::from_raw_trie_node(node.node)
-            },
-        );
+            });
         Ok(node.unwrap_or_default())
     }
 
     fn get_and_record_value(&self, value_ref: ValueHandle) -> Result<Vec<u8>, StorageError> {
         match value_ref {
             ValueHandle::HashAndSize(value) => {
-                self.trie.retrieve_value(&value.hash, AccessOptions::DEFAULT)
+                self.trie.retrieve_value(&value.hash, self.access_options())
             }
             ValueHandle::InMemory(value) => panic!("Unexpected in-memory value: {:?}", value),
         }
@@ -91,6 +104,20 @@ impl<'a> TrieIterator<'a> {
             TrieIterator::Memtrie(iter) => iter.seek_prefix(key),
         }
     }
+
+    pub fn seek<K: AsRef<[u8]>>(&mut self, key: K) -> Result<(), StorageError> {
+        match self {
+            TrieIterator::Disk(iter) => iter.seek(key),
+            TrieIterator::Memtrie(iter) => iter.seek(key),
+        }
+    }
+
+    pub fn next_key(&mut self) -> Option<Result<Vec<u8>, StorageError>> {
+        match self {
+            TrieIterator::Disk(iter) => iter.next_key(),
+            TrieIterator::Memtrie(iter) => iter.next_key(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -144,9 +171,11 @@ mod tests {
                 assert_eq!(result1, result2);
             }
             test_seek_prefix(&trie, &map, &[], use_memtries);
+            test_seek(&trie, &map, &[], use_memtries);
 
             for (seek_key, _) in &trie_changes {
                 test_seek_prefix(&trie, &map, seek_key, use_memtries);
+                test_seek(&trie, &map, seek_key, use_memtries);
             }
             for _ in 0..20 {
                 let alphabet = &b"abcdefgh"[0..rng.gen_range(2..8)];
@@ -154,6 +183,7 @@ mod tests {
                 let seek_key: Vec<u8> =
                     (0..key_length).map(|_| *alphabet.choose(&mut rng).unwrap()).collect();
                 test_seek_prefix(&trie, &map, &seek_key, use_memtries);
+                test_seek(&trie, &map, &seek_key, use_memtries);
             }
         }
     }
@@ -347,4 +377,15 @@ mod tests {
             .collect();
         assert_eq!(got, want);
     }
+
+    fn test_seek(trie: &Trie, map: &BTreeMap<Vec<u8>, Vec<u8>>, seek_key: &[u8], is_memtrie: bool) {
+        let lock = trie.lock_for_iter();
+        let mut iterator = lock.iter().unwrap();
+        assert_eq!(matches!(iterator, TrieIterator::Memtrie(_)), is_memtrie);
+        iterator.seek(&seek_key).unwrap();
+        let got: Vec<_> = iterator.map(Result::unwrap).collect();
+        let want: Vec<_> =
+            map.range(seek_key.to_vec()..).map(|(k, v)| (k.clone(), v.clone())).collect();
+        assert_eq!(got, want);
+    }
 }
@@ -6,6 +6,7 @@ use crate::trie::mem::arena::metrics::{
     MEMTRIE_ARENA_ACTIVE_ALLOCS_BYTES, MEMTRIE_ARENA_MEMORY_USAGE_BYTES,
 };
 use crate::trie::mem::flexible_data::encoding::BorshFixedSize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use near_o11y::metrics::IntGauge;
 
 /// Simple bump allocator with freelists.
@@ -44,6 +45,17 @@ pub struct Allocator {
     memory_usage_gauge: IntGauge,
 }
 
+/// State of an `Allocator` stored in memtrie images. Freelists point into the
+/// arena memory, so the state is only meaningful together with the chunks of
+/// the arena it was taken from.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(crate) struct AllocatorState {
+    freelists: Vec<ArenaPos>,
+    next_alloc_pos: ArenaPos,
+    active_allocs_bytes: u64,
+    active_allocs_count: u64,
+}
+
 const MAX_ALLOC_SIZE: usize = 16 * 1024;
 const ROUND_UP_TO_8_BYTES_UNDER: usize = 256;
 const ROUND_UP_TO_64_BYTES_UNDER: usize = 1024;
@@ -103,6 +115,61 @@ impl Allocator {
         allocator
     }
 
+    /// Restores an allocator from a state returned by `state`. Returns `None`
+    /// if the state is not compatible with this version of the allocator.
+    pub(super) fn from_state(name: String, state: AllocatorState) -> Option<Self> {
+        let mut allocator = Self::new_with_initial_stats(
+            name,
+            state.active_allocs_bytes as usize,
+            state.active_allocs_count as usize,
+        );
+        allocator.freelists = state.freelists.try_into().ok()?;
+        allocator.next_alloc_pos = state.next_alloc_pos;
+        Some(allocator)
+    }
+
+    /// Checks that the freelists and the next allocation position restored by
+    /// `from_state` point into `memory`, so that a corrupted image can't make
+    /// the allocator hand out memory outside of the arena.
+    pub(super) fn validate(&self, memory: &STArenaMemory) -> Result<(), String> {
+        let in_bounds = |pos: ArenaPos, size: usize| {
+            pos.chunk() < memory.chunks.len()
+                && pos.pos() % 8 == 0
+                && pos.pos() + size <= memory.chunks[pos.chunk()].len()
+        };
+        if !self.next_alloc_pos.is_invalid() && !in_bounds(self.next_alloc_pos, 0) {
+            return Err(format!("next allocation position {} out of bounds", self.next_alloc_pos));
+        }
+        for (size_class, head) in self.freelists.iter().enumerate() {
+            let size = allocation_size(size_class);
+            // A longer freelist would need more memory than the arena has, so
+            // it must have a cycle.
+            let max_len = memory.chunks.len() * CHUNK_SIZE / size;
+            let mut pos = *head;
+            let mut len = 0;
+            while !pos.is_invalid() {
+                if !in_bounds(pos, size) {
+                    return Err(format!("freelist {size_class} entry {pos} out of bounds"));
+                }
+                len += 1;
+                if len > max_len {
+                    return Err(format!("freelist {size_class} has a cycle"));
+                }
+                pos = memory.ptr(pos).read_pos();
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn state(&self) -> AllocatorState {
+        AllocatorState {
+            freelists: self.freelists.to_vec(),
+            next_alloc_pos: self.next_alloc_pos,
+            active_allocs_bytes: self.active_allocs_bytes as u64,
+            active_allocs_count: self.active_allocs_count as u64,
+        }
+    }
+
     pub fn update_memory_usage_gauge(&self, memory: &STArenaMemory) {
         self.memory_usage_gauge.set(memory.chunks.len() as i64 * CHUNK_SIZE as i64);
     }
@@ -110,7 +177,7 @@ impl Allocator {
     /// Adds a new chunk to the arena, and updates the next_alloc_pos to the beginning of
     /// the new chunk.
     fn new_chunk(&mut self, memory: &mut STArenaMemory) {
-        memory.chunks.push(vec![0; CHUNK_SIZE]);
+        memory.chunks.push(vec![0; CHUNK_SIZE].into());
         self.next_alloc_pos =
             ArenaPos { chunk: u32::try_from(memory.chunks.len() - 1).unwrap(), pos: 0 };
         self.update_memory_usage_gauge(memory);
@@ -175,7 +242,9 @@ mod test {
     use crate::trie::mem::arena::alloc::CHUNK_SIZE;
     use crate::trie::mem::arena::hybrid::HybridArena;
     use crate::trie::mem::arena::single_thread::STArena;
-    use crate::trie::mem::arena::{Arena, ArenaMut, ArenaSliceMut, ArenaWithDealloc};
+    use crate::trie::mem::arena::{
+        Arena, ArenaMemoryMut, ArenaMut, ArenaPos, ArenaSliceMut, ArenaWithDealloc,
+    };
     use std::mem::size_of;
 
     #[test]
@@ -246,6 +315,24 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_validate_freelists() {
+        let mut arena = STArena::new("".to_owned());
+        let first = arena.alloc(64).pos;
+        let second = arena.alloc(64).pos;
+        arena.dealloc(first, 64);
+        arena.dealloc(second, 64);
+        arena.allocator.validate(&arena.memory).unwrap();
+
+        // The freelist is `second` → `first`; make `first` point out of the
+        // arena and then back to `second`.
+        let out_of_bounds = ArenaPos { chunk: 1, pos: 0 };
+        arena.memory.slice_mut(first, 8).write_pos_at(0, out_of_bounds);
+        assert!(arena.allocator.validate(&arena.memory).is_err());
+        arena.memory.slice_mut(first, 8).write_pos_at(0, second);
+        assert!(arena.allocator.validate(&arena.memory).is_err());
+    }
+
     #[test]
     #[should_panic(expected = "Cannot deallocate shared memory")]
     fn test_hybrid_arena_panic_on_dealloc_shared_memory() {
@@ -23,8 +23,8 @@ use super::{
 ///
 /// More information about HybridArena in section below.
 pub struct HybridArenaMemory {
-    owned_memory: STArenaMemory,
-    shared_memory: Arc<STArenaMemory>,
+    pub(super) owned_memory: STArenaMemory,
+    pub(super) shared_memory: Arc<STArenaMemory>,
 }
 
 /// Conversion from FrozenArenaMemory to HybridArenaMemory.
@@ -80,8 +80,8 @@ impl ArenaMemoryMut for HybridArenaMemory {
 /// For typical MemTries usage, most of the time shared memory will be empty. The only time we use
 /// shared memory is during resharding when the child shards need access to the parent shard's memory.
 pub struct HybridArena {
-    memory: HybridArenaMemory,
-    allocator: Allocator,
+    pub(super) memory: HybridArenaMemory,
+    pub(super) allocator: Allocator,
 }
 
 /// Conversion from STArena to HybridArena. We set shared memory as empty.
@@ -133,7 +133,7 @@ impl HybridArena {
         self.allocator.num_active_allocs()
     }
 
-    #[cfg(test)]
+    /// Total size of active allocations, excluding the shared memory.
     pub fn active_allocs_bytes(&self) -> usize {
         self.allocator.active_allocs_bytes()
     }
@@ -0,0 +1,108 @@
+//! Reading and writing of arena memory for memtrie images, see
+//! `trie::mem::image`.
+
+use super::alloc::{Allocator, AllocatorState, CHUNK_SIZE};
+use super::hybrid::{HybridArena, HybridArenaMemory};
+use super::single_thread::{ArenaChunk, STArenaMemory};
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_primitives::hash::CryptoHash;
+use rayon::prelude::*;
+use sha2::Digest;
+use std::fs::File;
+use std::io::{self, Write};
+use std::sync::Arc;
+
+/// Arena state stored in a memtrie image next to the arena chunks.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(crate) struct ArenaImageState {
+    pub(crate) num_chunks: u32,
+    /// SHA-256 hashes of the chunks, each including its padding.
+    chunk_hashes: Vec<CryptoHash>,
+    allocator: AllocatorState,
+}
+
+impl ArenaImageState {
+    /// Size in bytes of the chunks written by `HybridArena::write_image_chunks`.
+    pub(crate) fn chunks_size(&self) -> u64 {
+        self.num_chunks as u64 * CHUNK_SIZE as u64
+    }
+}
+
+impl HybridArena {
+    /// Writes all chunks of the arena, each padded to `CHUNK_SIZE` so that it
+    /// can be mapped individually by `map_image_chunks`.
+    ///
+    /// Arenas with shared memory cannot be written.
+    pub(crate) fn write_image_chunks(
+        &self,
+        writer: &mut impl Write,
+    ) -> io::Result<ArenaImageState> {
+        if self.has_shared_memory() {
+            return Err(io::Error::other("cannot write an arena with shared memory"));
+        }
+        let chunks = &self.memory.owned_memory.chunks;
+        let padding = vec![0; CHUNK_SIZE];
+        let mut chunk_hashes = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            let padding = &padding[chunk.len()..];
+            writer.write_all(chunk)?;
+            writer.write_all(padding)?;
+            let mut hasher = sha2::Sha256::new();
+            hasher.update(chunk);
+            hasher.update(padding);
+            chunk_hashes.push(CryptoHash(hasher.finalize().into()));
+        }
+        Ok(ArenaImageState {
+            num_chunks: u32::try_from(chunks.len()).unwrap(),
+            chunk_hashes,
+            allocator: self.allocator.state(),
+        })
+    }
+
+    /// Creates an arena from chunks written by `write_image_chunks` at the
+    /// beginning of `file`.
+    ///
+    /// The chunks are mapped copy-on-write, so the file must not be modified
+    /// for as long as the arena exists; images are only ever replaced by
+    /// renaming a new file over them.
+    ///
+    /// The chunks are checked against their hashes and the allocator state
+    /// against the chunks, which reads the whole image once.
+    pub(crate) fn map_image_chunks(
+        name: String,
+        file: &File,
+        state: ArenaImageState,
+    ) -> io::Result<Self> {
+        let invalid = |reason: String| io::Error::new(io::ErrorKind::InvalidData, reason);
+        if state.chunk_hashes.len() != state.num_chunks as usize {
+            return Err(invalid("wrong number of chunk hashes".to_string()));
+        }
+        let mut chunks = Vec::with_capacity(state.num_chunks as usize);
+        for index in 0..state.num_chunks as u64 {
+            // SAFETY: see the function comment; the mapped file is never modified.
+            let chunk = unsafe {
+                memmap2::MmapOptions::new()
+                    .offset(index * CHUNK_SIZE as u64)
+                    .len(CHUNK_SIZE)
+                    .map_copy(file)?
+            };
+            chunks.push(ArenaChunk::Mapped(chunk));
+        }
+        let corrupted = chunks
+            .par_iter()
+            .zip(&state.chunk_hashes)
+            .position_any(|(chunk, hash)| CryptoHash::hash_bytes(chunk) != *hash);
+        if let Some(index) = corrupted {
+            return Err(invalid(format!("chunk {index} doesn't match its hash")));
+        }
+        let allocator = Allocator::from_state(name, state.allocator)
+            .ok_or_else(|| invalid("incompatible allocator state".to_string()))?;
+        let owned_memory = STArenaMemory { chunks };
+        allocator.validate(&owned_memory).map_err(invalid)?;
+        allocator.update_memory_usage_gauge(&owned_memory);
+        Ok(Self {
+            memory: HybridArenaMemory { owned_memory, shared_memory: Arc::new(Default::default()) },
+            allocator,
+        })
+    }
+}
@@ -12,9 +12,12 @@ mod alloc;
 pub mod concurrent;
 mod frozen;
 pub mod hybrid;
+mod image;
 mod metrics;
 pub mod single_thread;
+pub(crate) use alloc::CHUNK_SIZE;
 pub use frozen::FrozenArena;
+pub(crate) use image::ArenaImageState;
 
 /// An abstraction of a read-only arena.
 pub trait Arena: Sized + 'static {
@@ -2,12 +2,48 @@ use super::alloc::Allocator;
 use super::{
     Arena, ArenaMemory, ArenaMemoryMut, ArenaMut, ArenaPos, ArenaSliceMut, ArenaWithDealloc,
 };
+use std::ops::{Deref, DerefMut};
+
+/// A chunk of arena memory. Chunks are normally allocated on the heap, but the
+/// chunks of memtries loaded from an on-disk image are privately mapped from
+/// the image file, so only the pages that are actually accessed are read and
+/// modifications are never written back to the file.
+pub(super) enum ArenaChunk {
+    Heap(Vec<u8>),
+    Mapped(memmap2::MmapMut),
+}
+
+impl Deref for ArenaChunk {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            ArenaChunk::Heap(chunk) => chunk,
+            ArenaChunk::Mapped(chunk) => chunk,
+        }
+    }
+}
+
+impl DerefMut for ArenaChunk {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        match self {
+            ArenaChunk::Heap(chunk) => chunk,
+            ArenaChunk::Mapped(chunk) => chunk,
+        }
+    }
+}
+
+impl From<Vec<u8>> for ArenaChunk {
+    fn from(chunk: Vec<u8>) -> Self {
+        ArenaChunk::Heap(chunk)
+    }
+}
 
 /// `ArenaMemory` implementation for `STArena` (single-threaded arena). Stores the in-memory trie
 /// data as large byte arrays called "chunks".
 #[derive(Default)]
 pub struct STArenaMemory {
-    pub(super) chunks: Vec<Vec<u8>>,
+    pub(super) chunks: Vec<ArenaChunk>,
 }
 
 impl ArenaMemory for STArenaMemory {
@@ -52,7 +88,7 @@ impl STArena {
         active_allocs_count: usize,
     ) -> Self {
         let arena = Self {
-            memory: STArenaMemory { chunks },
+            memory: STArenaMemory { chunks: chunks.into_iter().map(ArenaChunk::Heap).collect() },
             allocator: Allocator::new_with_initial_stats(
                 name,
                 active_allocs_bytes,
@@ -109,8 +145,8 @@ mod tests {
     #[test]
     fn test_arena_ptr_and_slice() {
         let mut arena = STArenaMemory::default();
-        arena.chunks.push(vec![0; 1000]);
-        arena.chunks.push(vec![0; 1000]);
+        arena.chunks.push(vec![0; 1000].into());
+        arena.chunks.push(vec![0; 1000].into());
 
         let chunk1 = ArenaPos { chunk: 1, pos: 0 };
 
@@ -0,0 +1,225 @@
+//! On-disk images of in-memory tries.
+//!
+//! Loading memtries from flat storage takes many minutes per shard. To allow
+//! fast restarts, the arena of loaded memtries can be saved, together with its
+//! roots, into an image file. On the next start, if the image contains the
+//! state root at the flat storage head, the arena chunks are mapped from the
+//! image and only the flat storage deltas missing from the image are applied.
+//!
+//! An image consists of the arena chunks, each `CHUNK_SIZE` bytes long so they
+//! can be mapped individually, followed by a borsh-serialized header, the length
+//! of the header as a little-endian u64, and `IMAGE_MAGIC`.  The header holds
+//! hashes of the chunks, which are checked when the image is loaded, together
+//! with the allocator freelists and the positions of the roots.
+
+use super::arena::hybrid::HybridArena;
+use super::arena::{Arena, ArenaImageState, ArenaPos, CHUNK_SIZE};
+use super::memtries::MemTries;
+use super::node::MemTrieNodeId;
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_primitives::shard_layout::ShardUId;
+use near_primitives::types::{BlockHeight, StateRoot};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Version of the image format; bumped whenever the format or the encoding of
+/// nodes in the arena changes.
+const IMAGE_VERSION: u32 = 2;
+const IMAGE_MAGIC: &[u8; 8] = b"NEARMTI\0";
+const TRAILER_SIZE: u64 = 16;
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct MemTrieImageHeader {
+    version: u32,
+    shard_uid: ShardUId,
+    arena: ArenaImageState,
+    roots: Vec<(StateRoot, Vec<ArenaPos>)>,
+    heights: Vec<(BlockHeight, Vec<StateRoot>)>,
+}
+
+/// Returns the path of the image of the given shard's memtries.
+pub fn memtrie_image_path(image_dir: &Path, shard_uid: ShardUId) -> PathBuf {
+    image_dir.join(format!("{shard_uid}.memtrie"))
+}
+
+/// Saves an image of the memtries into `image_dir`, replacing the previous
+/// image of the shard if any. Partial memtries are not supported, as their
+/// nodes are loaded and unloaded as they are used.
+pub fn save_memtrie_image(image_dir: &Path, memtries: &MemTries) -> io::Result<()> {
+    if memtries.is_partial() {
+        return Err(io::Error::new(io::ErrorKind::Unsupported, "memtries are partial"));
+    }
+    std::fs::create_dir_all(image_dir)?;
+    let path = memtrie_image_path(image_dir, memtries.shard_uid);
+    let tmp_path = path.with_extension("memtrie.tmp");
+    let mut writer = BufWriter::new(File::create(&tmp_path)?);
+    let arena = memtries.arena.write_image_chunks(&mut writer)?;
+    let header = MemTrieImageHeader {
+        version: IMAGE_VERSION,
+        shard_uid: memtries.shard_uid,
+        arena,
+        roots: memtries
+            .roots
+            .iter()
+            .map(|(state_root, ids)| (*state_root, ids.iter().map(|id| id.pos).collect()))
+            .collect(),
+        heights: memtries
+            .heights
+            .iter()
+            .map(|(height, state_roots)| (*height, state_roots.clone()))
+            .collect(),
+    };
+    let header = borsh::to_vec(&header)?;
+    writer.write_all(&header)?;
+    writer.write_all(&(header.len() as u64).to_le_bytes())?;
+    writer.write_all(IMAGE_MAGIC)?;
+    writer.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+    // Renaming makes sure that the file mapped by running memtries is never
+    // modified.
+    std::fs::rename(&tmp_path, &path)
+}
+
+/// Loads memtries of the shard from its image in `image_dir`.
+///
+/// Returns `None` if there is no image or if the image doesn't contain
+/// `state_root`, which happens when the image is older than flat storage.
+pub(super) fn load_memtrie_image(
+    image_dir: &Path,
+    shard_uid: ShardUId,
+    state_root: StateRoot,
+) -> io::Result<Option<MemTries>> {
+    let path = memtrie_image_path(image_dir, shard_uid);
+    let mut file = match File::open(&path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    let invalid = |reason: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid memtrie image {}: {reason}", path.display()),
+        )
+    };
+
+    let file_size = file.metadata()?.len();
+    if file_size < TRAILER_SIZE {
+        return Err(invalid("file too short"));
+    }
+    let mut trailer = [0; TRAILER_SIZE as usize];
+    file.seek(SeekFrom::Start(file_size - TRAILER_SIZE))?;
+    file.read_exact(&mut trailer)?;
+    if &trailer[8..] != IMAGE_MAGIC {
+        return Err(invalid("bad magic"));
+    }
+    let header_size = u64::from_le_bytes(trailer[..8].try_into().unwrap());
+    let header_offset = (file_size - TRAILER_SIZE)
+        .checked_sub(header_size)
+        .ok_or_else(|| invalid("bad header size"))?;
+    file.seek(SeekFrom::Start(header_offset))?;
+    let header = MemTrieImageHeader::deserialize_reader(&mut (&file).take(header_size))
+        .map_err(|_| invalid("bad header"))?;
+    if header.version != IMAGE_VERSION {
+        return Err(invalid(&format!("unsupported version {}", header.version)));
+    }
+    if header.shard_uid != shard_uid {
+        return Err(invalid(&format!("image is for shard {}", header.shard_uid)));
+    }
+    if header.arena.chunks_size() != header_offset {
+        return Err(invalid("chunks don't match the file size"));
+    }
+
+    let roots: HashMap<StateRoot, Vec<MemTrieNodeId>> = header
+        .roots
+        .into_iter()
+        .map(|(state_root, ids)| {
+            (state_root, ids.into_iter().map(|pos| MemTrieNodeId { pos }).collect())
+        })
+        .collect();
+    let Some(ids) = roots.get(&state_root) else {
+        return Ok(None);
+    };
+    let num_chunks = header.arena.num_chunks as usize;
+    let out_of_bounds = |id: &MemTrieNodeId| {
+        id.pos.chunk() >= num_chunks || id.pos.pos() % 8 != 0 || id.pos.pos() >= CHUNK_SIZE
+    };
+    if roots.values().flatten().any(out_of_bounds) || ids.is_empty() {
+        return Err(invalid("root out of bounds"));
+    }
+    let heights: BTreeMap<BlockHeight, Vec<StateRoot>> = header.heights.into_iter().collect();
+    let arena = HybridArena::map_image_chunks(shard_uid.to_string(), &file, header.arena)
+        .map_err(|err| invalid(&err.to_string()))?;
+
+    // The root node stores its hash, which guards against mixing up images.
+    let root_hash = ids[0].as_ptr(arena.memory()).view().node_hash();
+    if root_hash != state_root {
+        return Err(invalid(&format!("root node hash {root_hash} doesn't match {state_root}")));
+    }
+    Ok(Some(MemTries::from_image_parts(shard_uid, arena, roots, heights)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_memtrie_image, memtrie_image_path, save_memtrie_image};
+    use crate::trie::mem::arena::CHUNK_SIZE;
+    use crate::trie::mem::memtrie_update::TrackingMode;
+    use crate::trie::mem::memtries::MemTries;
+    use near_primitives::hash::CryptoHash;
+    use near_primitives::shard_layout::ShardUId;
+    use near_primitives::state::FlatStateValue;
+
+    fn apply(memtries: &mut MemTries, root: CryptoHash, height: u64, keys: &[&[u8]]) -> CryptoHash {
+        let mut update = memtries.update(root, TrackingMode::None).unwrap();
+        for key in keys {
+            update.insert_memtrie_only(key, FlatStateValue::Inlined(key.to_vec())).unwrap();
+        }
+        let changes = update.to_memtrie_changes_only();
+        memtries.apply_memtrie_changes(height, &changes)
+    }
+
+    #[test]
+    fn test_save_and_load_memtrie_image() {
+        let image_dir = tempfile::tempdir().unwrap();
+        let shard_uid = ShardUId::single_shard();
+        let mut memtries = MemTries::new(shard_uid);
+        let root1 = apply(&mut memtries, CryptoHash::default(), 1, &[b"alice", b"bob"]);
+        let root2 = apply(&mut memtries, root1, 2, &[b"carol"]);
+        save_memtrie_image(image_dir.path(), &memtries).unwrap();
+
+        // Images without the requested state root are ignored.
+        assert!(
+            load_memtrie_image(image_dir.path(), shard_uid, CryptoHash::hash_bytes(b"x"))
+                .unwrap()
+                .is_none()
+        );
+
+        let mut loaded = load_memtrie_image(image_dir.path(), shard_uid, root1).unwrap().unwrap();
+        assert_eq!(loaded.num_roots(), 2);
+        for (root, key, exists) in
+            [(root1, b"alice", true), (root1, b"carol", false), (root2, b"carol", true)]
+        {
+            assert_eq!(loaded.lookup(&root, key, None).unwrap().is_some(), exists);
+        }
+
+        // Loaded memtries can be updated and garbage collected as usual.
+        let root3 = apply(&mut loaded, root2, 3, &[b"dave"]);
+        loaded.delete_until_height(3);
+        assert_eq!(loaded.num_roots(), 1);
+        assert!(loaded.lookup(&root3, b"dave", None).unwrap().is_some());
+        assert!(loaded.lookup(&root3, b"alice", None).unwrap().is_some());
+        loaded.delete_until_height(4);
+        assert_eq!(loaded.arena().num_active_allocs(), 0);
+
+        // Corrupted images are rejected, whether the header or the chunks are
+        // corrupted.
+        let path = memtrie_image_path(image_dir.path(), shard_uid);
+        let data = std::fs::read(&path).unwrap();
+        for offset in [data.len() - 1, CHUNK_SIZE - 1] {
+            let mut data = data.clone();
+            data[offset] ^= 1;
+            std::fs::write(&path, data).unwrap();
+            assert!(load_memtrie_image(image_dir.path(), shard_uid, root1).is_err());
+        }
+    }
+}
@@ -5,20 +5,28 @@ use super::node::MemTrieNodeId;
 use crate::Trie;
 use crate::trie::ops::interface::GenericTrieInternalStorage;
 use crate::trie::ops::iter::TrieIteratorImpl;
-use crate::trie::{AccessOptions, OptimizedValueRef};
+use crate::trie::{AccessOptions, AccessTracker, OptimizedValueRef};
 use near_primitives::errors::StorageError;
 use near_primitives::hash::CryptoHash;
 use near_primitives::state::FlatStateValue;
+use std::sync::Arc;
 
 /// Tiny wrapper around `MemTries` and `Trie` to provide `GenericTrieInternalStorage` implementation.
 pub struct MemTrieIteratorInner<'a> {
     memtrie: &'a MemTries,
     trie: &'a Trie,
+    /// If not `None`, the nodes and values read by the iterator are reported
+    /// to this tracker, so that they can be charged for.
+    access_tracker: Option<&'a dyn AccessTracker>,
 }
 
 impl<'a> MemTrieIteratorInner<'a> {
     pub fn new(memtrie: &'a MemTries, trie: &'a Trie) -> Self {
-        Self { memtrie, trie }
+        Self { memtrie, trie, access_tracker: None }
+    }
+
+    pub fn with_access_tracker(self, access_tracker: Option<&'a dyn AccessTracker>) -> Self {
+        Self { access_tracker, ..self }
     }
 }
 
@@ -35,9 +43,19 @@ impl<'a> GenericTrieInternalStorage<MemTrieNodeId, FlatStateValue> for MemTrieIt
 
     fn get_and_record_node(&self, node: MemTrieNodeId) -> Result<MemTrieNode, StorageError> {
         let view = node.as_ptr(self.memtrie.arena.memory()).view();
-        if let Some(recorder) = &self.trie.recorder {
-            let raw_node_serialized = borsh::to_vec(&view.to_raw_trie_node_with_size()).unwrap();
-            recorder.write().record(&view.node_hash(), raw_node_serialized.into());
+        if self.access_tracker.is_some() || self.trie.recorder.is_some() {
+            let node_hash = view.node_hash();
+            let raw_node_serialized: Arc<[u8]> =
+                borsh::to_vec(&view.to_raw_trie_node_with_size()).unwrap().into();
+            // Same accounting as for the nodes accessed by a memtrie lookup.
+            if let Some(access_tracker) = self.access_tracker {
+                if access_tracker.track_mem_lookup(&node_hash).is_none() {
+                    access_tracker.track_disk_lookup(node_hash, Arc::clone(&raw_node_serialized));
+                }
+            }
+            if let Some(recorder) = &self.trie.recorder {
+                recorder.write().record(&node_hash, raw_node_serialized);
+            }
         }
         let node = MemTrieNode::from_existing_node_view(view);
         Ok(node)
@@ -45,7 +63,11 @@ impl<'a> GenericTrieInternalStorage<MemTrieNodeId, FlatStateValue> for MemTrieIt
 
     fn get_and_record_value(&self, value_ref: FlatStateValue) -> Result<Vec<u8>, StorageError> {
         let optimized_value_ref = OptimizedValueRef::from_flat_value(value_ref);
-        let value = self.trie.deref_optimized(AccessOptions::DEFAULT, &optimized_value_ref)?;
+        let access_options = match self.access_tracker {
+            Some(access_tracker) => AccessOptions::contract_runtime(access_tracker),
+            None => AccessOptions::DEFAULT,
+        };
+        let value = self.trie.deref_optimized(access_options, &optimized_value_ref)?;
         if let Some(recorder) = &self.trie.recorder {
             let value_hash = optimized_value_ref.into_value_ref().hash;
             recorder.write().record(&value_hash, value.clone().into());
@@ -1,6 +1,8 @@
 use super::arena::single_thread::STArena;
+use super::image::load_memtrie_image;
 use super::memtries::MemTries;
 use super::node::MemTrieNodeId;
+use super::partial::{PartialMemTries, load_subtree};
 use crate::adapter::StoreAdapter;
 use crate::flat::FlatStorageStatus;
 use crate::trie::AccessOptions;
@@ -16,8 +18,9 @@ use near_primitives::shard_layout::{ShardUId, get_block_shard_uid};
 use near_primitives::types::chunk_extra::ChunkExtra;
 use near_primitives::types::{BlockHeight, StateRoot};
 use std::collections::BTreeSet;
+use std::path::Path;
 use std::time::Instant;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Loads a trie from the FlatState column. The returned `MemTries` contains
 /// exactly one trie root.
@@ -87,6 +90,37 @@ fn load_memtrie_single_thread(
     Ok((arena, root_id))
 }
 
+/// Loads the upper part of the trie from the State column into partial
+/// memtries, using at most half of the memory budget. The rest of the trie
+/// is loaded as it gets used, see `PartialMemTries`.
+fn load_partial_trie(
+    store: &Store,
+    shard_uid: ShardUId,
+    state_root: StateRoot,
+    block_height: BlockHeight,
+    memory_budget: usize,
+) -> Result<MemTries, StorageError> {
+    info!(target: "memtrie", %shard_uid, memory_budget, "Loading partial trie from the State column...");
+    let trie_store = store.trie_store();
+    let mut stubs = Vec::new();
+    let mut memtries = if state_root == StateRoot::default() {
+        MemTries::new(shard_uid)
+    } else {
+        let mut arena = STArena::new(shard_uid.to_string());
+        let root_id = load_subtree(
+            &mut arena,
+            &trie_store,
+            shard_uid,
+            state_root,
+            memory_budget / 2,
+            &mut stubs,
+        )?;
+        MemTries::new_from_arena_and_root(shard_uid, block_height, arena, root_id)
+    };
+    memtries.set_partial(PartialMemTries::new(trie_store, memory_budget), stubs);
+    Ok(memtries)
+}
+
 fn get_state_root(
     store: &Store,
     block_hash: CryptoHash,
@@ -115,11 +149,20 @@ fn get_state_root(
 /// flat storage currently has, i.e. one for the final block, and one for each
 /// block that flat storage has a delta for, possibly in more than one fork.
 /// `state_root` parameter is required if `ChunkExtra` is not available, e.g. on catchup.
+///
+/// If `image_dir` is given and contains an image of the shard's memtries with
+/// the state root at the flat storage head, the tries are mapped from the image
+/// instead of being constructed from flat storage.
+///
+/// If `memory_budget` is given, the tries are partial: only their upper part
+/// is loaded, from the State column, and images are not used.
 pub fn load_trie_from_flat_state_and_delta(
     store: &Store,
     shard_uid: ShardUId,
     state_root: Option<StateRoot>,
     parallelize: bool,
+    image_dir: Option<&Path>,
+    memory_budget: Option<usize>,
 ) -> Result<MemTries, StorageError> {
     debug!(target: "memtrie", %shard_uid, "Loading base trie from flat state...");
     let flat_store = store.flat_store();
@@ -138,9 +181,35 @@ pub fn load_trie_from_flat_state_and_delta(
         None => get_state_root(store, flat_head.hash, shard_uid)?,
     };
 
-    let mut memtries =
-        load_trie_from_flat_state(&store, shard_uid, state_root, flat_head.height, parallelize)
-            .unwrap();
+    let image = match image_dir {
+        Some(image_dir) if state_root != StateRoot::default() && memory_budget.is_none() => {
+            load_memtrie_image(image_dir, shard_uid, state_root).unwrap_or_else(|err| {
+                warn!(target: "memtrie", %shard_uid, ?err, "Failed to load memtrie image");
+                None
+            })
+        }
+        _ => None,
+    };
+    let loaded_from_image = image.is_some();
+    let mut memtries = match image {
+        Some(memtries) => {
+            info!(target: "memtrie", %shard_uid, "Loaded trie from memtrie image");
+            memtries
+        }
+        None => match memory_budget {
+            Some(memory_budget) => {
+                load_partial_trie(store, shard_uid, state_root, flat_head.height, memory_budget)?
+            }
+            None => load_trie_from_flat_state(
+                &store,
+                shard_uid,
+                state_root,
+                flat_head.height,
+                parallelize,
+            )
+            .unwrap(),
+        },
+    };
 
     debug!(target: "memtrie", %shard_uid, "Loading flat state deltas...");
     // We load the deltas in order of height, so that we always have the previous state root
@@ -156,18 +225,29 @@ pub fn load_trie_from_flat_state_and_delta(
         if let Some(changes) = delta {
             let old_state_root = get_state_root(store, prev_hash, shard_uid)?;
             let new_state_root = get_state_root(store, hash, shard_uid)?;
-
-            let mut trie_update = memtries.update(old_state_root, TrackingMode::None)?;
-            for (key, value) in changes.0 {
-                match value {
-                    Some(value) => {
-                        trie_update.insert_memtrie_only(&key, value)?;
-                    }
-                    None => trie_update.generic_delete(0, &key, AccessOptions::DEFAULT)?,
-                };
+            if loaded_from_image && memtries.roots.contains_key(&new_state_root) {
+                // The delta was already applied before the image was saved.
+                continue;
             }
 
-            let memtrie_changes = trie_update.to_memtrie_changes_only();
+            // Partial memtries may need to load more nodes for the update,
+            // in which case it is retried.
+            memtries.load_paths(&old_state_root, changes.0.keys().map(|key| key.as_slice()))?;
+            let memtrie_changes = loop {
+                let mut trie_update = memtries.update(old_state_root, TrackingMode::None)?;
+                let result = changes.0.iter().try_for_each(|(key, value)| match value {
+                    Some(value) => trie_update.insert_memtrie_only(key, value.clone()),
+                    None => trie_update.generic_delete(0, key, AccessOptions::DEFAULT),
+                });
+                match (result, trie_update.unloaded_node()) {
+                    (Ok(()), _) => break trie_update.to_memtrie_changes_only(),
+                    (Err(_), Some(stub)) => {
+                        drop(trie_update);
+                        memtries.load_stub(stub)?;
+                    }
+                    (Err(err), None) => return Err(err),
+                }
+            };
             let new_root_after_apply = memtries.apply_memtrie_changes(height, &memtrie_changes);
             assert_eq!(new_root_after_apply, new_state_root);
         }
@@ -262,6 +342,7 @@ mod tests {
         for key in keys.iter().chain([b"not in trie".to_vec()].iter()) {
             let mut nodes_accessed = Vec::new();
             let actual_value_ref = memtrie_lookup(root, key, Some(&mut nodes_accessed))
+                .unwrap()
                 .map(|v| v.to_optimized_value_ref());
             let expected_value_ref =
                 trie.get_optimized_ref(key, KeyLookupMode::MemOrFlatOrTrie, opts).unwrap();
@@ -459,30 +540,36 @@ mod tests {
         // Load into memory. It should load the base flat state (block 0), plus all
         // four deltas. We'll check against the state roots at each block; they should
         // all exist in the loaded memtrie.
-        let memtries = load_trie_from_flat_state_and_delta(&store, shard_uid, None, true).unwrap();
+        let memtries =
+            load_trie_from_flat_state_and_delta(&store, shard_uid, None, true, None, None).unwrap();
 
         assert_eq!(
             memtrie_lookup(memtries.get_root(&state_root_0).unwrap(), &test_key.to_vec(), None)
+                .unwrap()
                 .map(|v| v.to_flat_value()),
             Some(FlatStateValue::inlined(&test_val0))
         );
         assert_eq!(
             memtrie_lookup(memtries.get_root(&state_root_1).unwrap(), &test_key.to_vec(), None)
+                .unwrap()
                 .map(|v| v.to_flat_value()),
             Some(FlatStateValue::inlined(&test_val1))
         );
         assert_eq!(
             memtrie_lookup(memtries.get_root(&state_root_2).unwrap(), &test_key.to_vec(), None)
+                .unwrap()
                 .map(|v| v.to_flat_value()),
             Some(FlatStateValue::inlined(&test_val2))
         );
         assert_eq!(
             memtrie_lookup(memtries.get_root(&state_root_3).unwrap(), &test_key.to_vec(), None)
+                .unwrap()
                 .map(|v| v.to_flat_value()),
             Some(FlatStateValue::inlined(&test_val3))
         );
         assert_eq!(
             memtrie_lookup(memtries.get_root(&state_root_4).unwrap(), &test_key.to_vec(), None)
+                .unwrap()
                 .map(|v| v.to_flat_value()),
             Some(FlatStateValue::inlined(&test_val4))
         );
@@ -1,25 +1,77 @@
 use super::arena::ArenaMemory;
 use super::flexible_data::value::ValueView;
 use super::metrics::MEMTRIE_NUM_LOOKUPS;
-use super::node::{MemTrieNodePtr, MemTrieNodeView};
+use super::node::{MemTrieNodeId, MemTrieNodePtr, MemTrieNodeView};
 use crate::NibbleSlice;
+use near_primitives::errors::StorageError;
 use near_primitives::hash::CryptoHash;
 use std::sync::Arc;
 
+/// Result of a lookup which may reach a part of the trie that is not loaded
+/// into memory, see `PartialMemTries`.
+pub enum MemTrieLookup<'a, 'k> {
+    /// The lookup was completed in memory.
+    Complete(Option<ValueView<'a>>),
+    /// The lookup reached the unloaded `stub`. It has to be continued from the
+    /// node with `node_hash`, stored on disk, with the `remaining_key`.
+    Unloaded { stub: MemTrieNodeId, node_hash: CryptoHash, remaining_key: NibbleSlice<'k> },
+}
+
 /// If `nodes_accessed` is provided, each trie node along the lookup path
 /// will be added to the vector as (node hash, serialized `RawTrieNodeWithSize`).
 /// Even if the key is not found, the nodes that were accessed to make that
 /// determination will be added to the vector.
+///
+/// Fails if the lookup reaches a node that is not loaded; use
+/// `memtrie_lookup_partial` for partial memtries.
 pub fn memtrie_lookup<'a, M: ArenaMemory>(
     root: MemTrieNodePtr<'a, M>,
     key: &[u8],
-    mut nodes_accessed: Option<&mut Vec<(CryptoHash, Arc<[u8]>)>>,
-) -> Option<ValueView<'a>> {
+    nodes_accessed: Option<&mut Vec<(CryptoHash, Arc<[u8]>)>>,
+) -> Result<Option<ValueView<'a>>, StorageError> {
+    MEMTRIE_NUM_LOOKUPS.inc();
+    match memtrie_lookup_impl(root, NibbleSlice::new(key), nodes_accessed, None) {
+        MemTrieLookup::Complete(value) => Ok(value),
+        MemTrieLookup::Unloaded { node_hash, .. } => Err(StorageError::MemTrieLoadingError(
+            format!("Memtrie node {} is not loaded", node_hash),
+        )),
+    }
+}
+
+/// Like `memtrie_lookup`, but stops at the first node which is not loaded.
+/// Loaded stubs that the lookup passed through are added to `stubs_passed`.
+pub(super) fn memtrie_lookup_partial<'a, 'k, M: ArenaMemory>(
+    root: MemTrieNodePtr<'a, M>,
+    key: &'k [u8],
+    nodes_accessed: Option<&mut Vec<(CryptoHash, Arc<[u8]>)>>,
+    stubs_passed: &mut Vec<MemTrieNodeId>,
+) -> MemTrieLookup<'a, 'k> {
     MEMTRIE_NUM_LOOKUPS.inc();
-    let mut nibbles = NibbleSlice::new(key);
+    memtrie_lookup_impl(root, NibbleSlice::new(key), nodes_accessed, Some(stubs_passed))
+}
+
+pub(super) fn memtrie_lookup_impl<'a, 'k, M: ArenaMemory>(
+    root: MemTrieNodePtr<'a, M>,
+    mut nibbles: NibbleSlice<'k>,
+    mut nodes_accessed: Option<&mut Vec<(CryptoHash, Arc<[u8]>)>>,
+    mut stubs_passed: Option<&mut Vec<MemTrieNodeId>>,
+) -> MemTrieLookup<'a, 'k> {
     let mut node = root;
 
     loop {
+        if let Some((node_hash, target)) = node.as_stub() {
+            let Some(target) = target else {
+                return MemTrieLookup::Unloaded {
+                    stub: node.id(),
+                    node_hash,
+                    remaining_key: nibbles,
+                };
+            };
+            if let Some(stubs_passed) = &mut stubs_passed {
+                stubs_passed.push(node.id());
+            }
+            node = target;
+        }
         let view = node.view();
         if let Some(nodes_accessed) = &mut nodes_accessed {
             let raw_node_serialized = borsh::to_vec(&view.to_raw_trie_node_with_size()).unwrap();
@@ -28,9 +80,9 @@ pub fn memtrie_lookup<'a, M: ArenaMemory>(
         match view {
             MemTrieNodeView::Leaf { extension, value } => {
                 if nibbles == NibbleSlice::from_encoded(extension).0 {
-                    return Some(value);
+                    return MemTrieLookup::Complete(Some(value));
                 } else {
-                    return None;
+                    return MemTrieLookup::Complete(None);
                 }
             }
             MemTrieNodeView::Extension { extension, child, .. } => {
@@ -39,31 +91,32 @@ pub fn memtrie_lookup<'a, M: ArenaMemory>(
                     nibbles = nibbles.mid(extension_nibbles.len());
                     node = child;
                 } else {
-                    return None;
+                    return MemTrieLookup::Complete(None);
                 }
             }
             MemTrieNodeView::Branch { children, .. } => {
                 if nibbles.is_empty() {
-                    return None;
+                    return MemTrieLookup::Complete(None);
                 }
                 let first = nibbles.at(0);
                 nibbles = nibbles.mid(1);
                 node = match children.get(first as usize) {
                     Some(child) => child,
-                    None => return None,
+                    None => return MemTrieLookup::Complete(None),
                 };
             }
             MemTrieNodeView::BranchWithValue { children, value, .. } => {
                 if nibbles.is_empty() {
-                    return Some(value);
+                    return MemTrieLookup::Complete(Some(value));
                 }
                 let first = nibbles.at(0);
                 nibbles = nibbles.mid(1);
                 node = match children.get(first as usize) {
                     Some(child) => child,
-                    None => return None,
+                    None => return MemTrieLookup::Complete(None),
                 };
             }
+            MemTrieNodeView::Stub { .. } => unreachable!("stubs are resolved above"),
         }
     }
 }
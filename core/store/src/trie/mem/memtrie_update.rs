@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use near_primitives::errors::StorageError;
 use near_primitives::hash::{CryptoHash, hash};
@@ -47,6 +47,7 @@ impl MemTrieNode {
                 extension: extension.to_vec().into_boxed_slice(),
                 child: child.id(),
             },
+            MemTrieNodeView::Stub { hash } => unreachable!("Node {hash} is not loaded"),
         }
     }
 
@@ -147,6 +148,12 @@ pub struct MemTrieUpdate<'a, M: ArenaMemory> {
     /// Tracks trie changes necessary to make on-disk updates and recorded
     /// storage.
     nodes_tracker: Option<TrieChangesTracker<'a>>,
+    /// Updated nodes which were converted from loaded stubs of a partial
+    /// memtrie. The new nodes are put behind stubs again, so that their
+    /// subtrees can still be unloaded.
+    stubbed_node_ids: Vec<UpdatedNodeId>,
+    /// The stub which the update failed to convert because it is not loaded.
+    unloaded_node: Option<MemTrieNodeId>,
 }
 
 impl<'a, M: ArenaMemory> GenericTrieUpdate<'a, MemTrieNodeId, FlatStateValue>
@@ -158,7 +165,7 @@ impl<'a, M: ArenaMemory> GenericTrieUpdate<'a, MemTrieNodeId, FlatStateValue>
         _opts: AccessOptions,
     ) -> Result<UpdatedNodeId, StorageError> {
         Ok(match node {
-            GenericNodeOrIndex::Old(node_id) => self.convert_existing_to_updated(Some(node_id)),
+            GenericNodeOrIndex::Old(node_id) => self.convert_existing_to_updated(Some(node_id))?,
             GenericNodeOrIndex::Updated(node_id) => node_id,
         })
     }
@@ -229,12 +236,29 @@ impl<'a, M: ArenaMemory> MemTrieUpdate<'a, M> {
                 Some(TrieChangesTracker::with_recorder(Some(recorder)))
             }
         };
-        let mut trie_update =
-            Self { root, memory, shard_uid, updated_nodes: vec![], nodes_tracker };
-        assert_eq!(trie_update.convert_existing_to_updated(root), 0usize);
+        let mut trie_update = Self {
+            root,
+            memory,
+            shard_uid,
+            updated_nodes: vec![],
+            nodes_tracker,
+            stubbed_node_ids: vec![],
+            unloaded_node: None,
+        };
+        assert_eq!(
+            trie_update.convert_existing_to_updated(root).expect("Root must be loaded"),
+            0usize
+        );
         trie_update
     }
 
+    /// If the update failed because it needed a node which is not loaded,
+    /// returns the stub of the node, which has to be loaded (see
+    /// `MemTries::load_stub`) before the update is retried.
+    pub(crate) fn unloaded_node(&self) -> Option<MemTrieNodeId> {
+        self.unloaded_node
+    }
+
     /// Creates a new updated node, assigning it a new ID.
     fn new_updated_node(&mut self, node: UpdatedMemTrieNodeWithSize) -> UpdatedNodeId {
         let index = self.updated_nodes.len();
@@ -249,15 +273,33 @@ impl<'a, M: ArenaMemory> MemTrieUpdate<'a, M> {
     ///
     /// If the original node is None, it is a marker for the root of an empty
     /// trie.
-    fn convert_existing_to_updated(&mut self, node: Option<MemTrieNodeId>) -> UpdatedNodeId {
+    ///
+    /// Fails if the original node is a stub which is not loaded.
+    fn convert_existing_to_updated(
+        &mut self,
+        node: Option<MemTrieNodeId>,
+    ) -> Result<UpdatedNodeId, StorageError> {
         let Some(node) = node else {
-            return self.new_updated_node(UpdatedMemTrieNodeWithSize::empty());
+            return Ok(self.new_updated_node(UpdatedMemTrieNodeWithSize::empty()));
         };
-        let node_view = node.as_ptr(self.memory).view();
+        let node_ptr = node.as_ptr(self.memory);
+        let node_view = node_ptr.view();
+        if let MemTrieNodeView::Stub { hash } = node_view {
+            self.unloaded_node = Some(node);
+            return Err(StorageError::MemTrieLoadingError(format!(
+                "Memtrie node {} is not loaded",
+                hash
+            )));
+        }
         if let Some(tracked_trie_changes) = self.nodes_tracker.as_mut() {
             tracked_trie_changes.record(&node_view);
         }
-        self.new_updated_node(MemTrieNodeWithSize::from_existing_node_view(node_view).into())
+        let updated_node_id =
+            self.new_updated_node(MemTrieNodeWithSize::from_existing_node_view(node_view).into());
+        if node_ptr.as_stub().is_some() {
+            self.stubbed_node_ids.push(updated_node_id);
+        }
+        Ok(updated_node_id)
     }
 
     /// Inserts the given key value pair into the trie.
@@ -409,7 +451,11 @@ impl<'a, M: ArenaMemory> MemTrieUpdate<'a, M> {
             .map(|(node_id, hash, _)| (*node_id, *hash))
             .collect();
         (
-            MemTrieChanges { node_ids_with_hashes, updated_nodes: self.updated_nodes },
+            MemTrieChanges {
+                node_ids_with_hashes,
+                updated_nodes: self.updated_nodes,
+                stubbed_node_ids: self.stubbed_node_ids,
+            },
             hashes_and_serialized_nodes
                 .into_iter()
                 .map(|(_, hash, serialized)| (hash, serialized))
@@ -458,9 +504,13 @@ impl<'a, M: ArenaMemory> MemTrieUpdate<'a, M> {
 
 /// Applies the given memtrie changes to the in-memory trie data structure.
 /// Returns the new root hash.
+///
+/// Nodes that replace loaded stubs of a partial memtrie are put behind new
+/// loaded stubs, which are added to `new_stubs` together with their hashes.
 pub(super) fn construct_root_from_changes<A: ArenaMut>(
     arena: &mut A,
     changes: &MemTrieChanges,
+    new_stubs: &mut Vec<(MemTrieNodeId, CryptoHash)>,
 ) -> Option<MemTrieNodeId> {
     let mut last_node_id: Option<MemTrieNodeId> = None;
     let map_to_new_node_id = |node_id: OldOrUpdatedNodeId,
@@ -475,9 +525,12 @@ pub(super) fn construct_root_from_changes<A: ArenaMut>(
     let mut updated_to_new_map = HashMap::<UpdatedNodeId, MemTrieNodeId>::new();
     let updated_nodes = &changes.updated_nodes;
     let node_ids_with_hashes = &changes.node_ids_with_hashes;
+    let root_node_id = node_ids_with_hashes.last().map(|(node_id, _)| *node_id);
+    let stubbed_node_ids: HashSet<UpdatedNodeId> =
+        changes.stubbed_node_ids.iter().copied().collect();
     for (node_id, node_hash) in node_ids_with_hashes {
-        let node = updated_nodes.get(*node_id).unwrap().clone().unwrap();
-        let node = match &node.node {
+        let updated_node = updated_nodes.get(*node_id).unwrap().clone().unwrap();
+        let node = match &updated_node.node {
             UpdatedMemTrieNode::Empty => unreachable!(),
             UpdatedMemTrieNode::Branch { children, value } => {
                 let mut new_children = [None; 16];
@@ -501,7 +554,18 @@ pub(super) fn construct_root_from_changes<A: ArenaMut>(
                 InputMemTrieNode::Leaf { value, extension }
             }
         };
-        let mem_node_id = MemTrieNodeId::new_with_hash(arena, node, *node_hash);
+        let mut mem_node_id = MemTrieNodeId::new_with_hash_and_memory_usage(
+            arena,
+            node,
+            *node_hash,
+            updated_node.memory_usage,
+        );
+        if Some(*node_id) != root_node_id && stubbed_node_ids.contains(node_id) {
+            let stub_id = MemTrieNodeId::new_stub(arena, *node_hash);
+            stub_id.replace_stub_target(arena.memory_mut(), Some(mem_node_id));
+            new_stubs.push((stub_id, *node_hash));
+            mem_node_id = stub_id;
+        }
         updated_to_new_map.insert(*node_id, mem_node_id);
         last_node_id = Some(mem_node_id);
     }
@@ -630,8 +694,8 @@ mod tests {
                 };
                 let disk_trie =
                     self.disk.get_trie_for_shard(ShardUId::single_shard(), self.state_root);
-                let memtrie_result =
-                    memtrie_root.and_then(|memtrie_root| memtrie_lookup(memtrie_root, key, None));
+                let memtrie_result = memtrie_root
+                    .and_then(|memtrie_root| memtrie_lookup(memtrie_root, key, None).unwrap());
                 let disk_result = disk_trie
                     .get_optimized_ref(key, KeyLookupMode::MemOrTrie, AccessOptions::DEFAULT)
                     .unwrap();
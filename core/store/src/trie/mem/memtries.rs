@@ -5,11 +5,13 @@ use near_primitives::errors::StorageError;
 use near_primitives::hash::CryptoHash;
 use near_primitives::shard_layout::ShardUId;
 use near_primitives::types::{BlockHeight, StateRoot};
+use parking_lot::RwLock;
+use tracing::warn;
 
-use crate::Trie;
-use crate::trie::MemTrieChanges;
 use crate::trie::mem::arena::ArenaMut;
 use crate::trie::mem::metrics::MEMTRIE_NUM_ROOTS;
+use crate::trie::{AccessTracker, MemTrieChanges};
+use crate::{NibbleSlice, Trie};
 
 use super::arena::Arena;
 use super::arena::FrozenArena;
@@ -17,9 +19,10 @@ use super::arena::hybrid::{HybridArena, HybridArenaMemory};
 use super::arena::single_thread::STArena;
 use super::flexible_data::value::ValueView;
 use super::iter::{MemTrieIteratorInner, STMemTrieIterator};
-use super::lookup::memtrie_lookup;
+use super::lookup::{MemTrieLookup, memtrie_lookup, memtrie_lookup_impl, memtrie_lookup_partial};
 use super::memtrie_update::{MemTrieUpdate, TrackingMode, construct_root_from_changes};
 use super::node::{MemTrieNodeId, MemTrieNodePtr};
+use super::partial::{LoadedSubtree, ON_DEMAND_LOAD_BYTES, PartialMemTries};
 
 /// `MemTries` (logically) owns the memory of multiple tries.
 /// Tries may share nodes with each other via refcounting. The way the
@@ -33,14 +36,16 @@ pub struct MemTries {
     /// deduplication mechanism so we can't guarantee that nodes of the
     /// same hash are unique. During lookup, any of these nodes can be provided
     /// as they all logically represent the same trie.
-    roots: HashMap<StateRoot, Vec<MemTrieNodeId>>,
+    pub(super) roots: HashMap<StateRoot, Vec<MemTrieNodeId>>,
     /// Maps a block height to a list of state roots present at that height.
     /// This is used for GC. The invariant is that for any state root, the
     /// number of times the state root appears in this map is equal to the
     /// sum of the refcounts of each `MemTrieNodeId`s in `roots[state hash]`.
-    heights: BTreeMap<BlockHeight, Vec<StateRoot>>,
+    pub(super) heights: BTreeMap<BlockHeight, Vec<StateRoot>>,
     /// Shard UID, for exporting metrics only.
-    shard_uid: ShardUId,
+    pub(super) shard_uid: ShardUId,
+    /// Set if only a part of the trie is kept in memory, see `PartialMemTries`.
+    pub(super) partial: Option<PartialMemTries>,
 }
 
 /// Frozen arena together with supported roots and heights.
@@ -59,6 +64,7 @@ impl MemTries {
             roots: HashMap::new(),
             heights: Default::default(),
             shard_uid,
+            partial: None,
         }
     }
 
@@ -71,9 +77,22 @@ impl MemTries {
             roots: frozen_memtries.roots,
             heights: frozen_memtries.heights,
             shard_uid,
+            partial: None,
         }
     }
 
+    /// Creates a new `MemTries` from the contents of a memtrie image. The
+    /// refcounts stored in the arena must be consistent with `heights`.
+    pub(super) fn from_image_parts(
+        shard_uid: ShardUId,
+        arena: HybridArena,
+        roots: HashMap<StateRoot, Vec<MemTrieNodeId>>,
+        heights: BTreeMap<BlockHeight, Vec<StateRoot>>,
+    ) -> Self {
+        MEMTRIE_NUM_ROOTS.with_label_values(&[&shard_uid.to_string()]).set(roots.len() as i64);
+        Self { arena, roots, heights, shard_uid, partial: None }
+    }
+
     pub fn new_from_arena_and_root(
         shard_uid: ShardUId,
         block_height: BlockHeight,
@@ -85,11 +104,28 @@ impl MemTries {
             roots: HashMap::new(),
             heights: Default::default(),
             shard_uid,
+            partial: None,
         };
         tries.insert_root(root.as_ptr(tries.arena.memory()).view().node_hash(), root, block_height);
         tries
     }
 
+    /// Makes these memtries partial. `stubs` are the stubs already present
+    /// in the arena, which `partial` takes over.
+    pub(super) fn set_partial(
+        &mut self,
+        mut partial: PartialMemTries,
+        stubs: Vec<(MemTrieNodeId, CryptoHash)>,
+    ) {
+        partial.register_stubs(&mut self.arena, stubs);
+        self.partial = Some(partial);
+    }
+
+    /// Whether only a part of the trie is kept in memory.
+    pub fn is_partial(&self) -> bool {
+        self.partial.is_some()
+    }
+
     /// This function should perform the entire construction of the new trie, possibly based on some existing
     /// trie nodes. This internally takes care of refcounting and inserts a new root into the memtrie.
     pub fn apply_memtrie_changes(
@@ -97,7 +133,14 @@ impl MemTries {
         block_height: BlockHeight,
         changes: &MemTrieChanges,
     ) -> CryptoHash {
-        if let Some(root) = construct_root_from_changes(&mut self.arena, changes) {
+        let mut new_stubs = Vec::new();
+        let root = construct_root_from_changes(&mut self.arena, changes, &mut new_stubs);
+        if let Some(partial) = &mut self.partial {
+            partial.register_stubs(&mut self.arena, new_stubs);
+        } else {
+            debug_assert!(new_stubs.is_empty(), "Stubs created in complete memtries");
+        }
+        if let Some(root) = root {
             let state_root = root.as_ptr(self.arena.memory()).view().node_hash();
             self.insert_root(state_root, root, block_height);
             state_root
@@ -142,6 +185,10 @@ impl MemTries {
     /// `block_height`. This internally manages refcounts. If a trie root
     /// is expired but is still used at a higher height, it will still be
     /// valid until all references to that root expires.
+    ///
+    /// For partial memtries, this also maintains which subtrees are loaded.
+    /// Loads of subtrees are only planned here; they are done by
+    /// `load_pending_subtrees` without holding the lock on the memtries.
     pub fn delete_until_height(&mut self, block_height: BlockHeight) {
         let mut to_delete = vec![];
         self.heights.retain(|height, state_roots| {
@@ -157,6 +204,9 @@ impl MemTries {
         for state_root in to_delete {
             self.delete_root(&state_root);
         }
+        if let Some(partial) = &mut self.partial {
+            partial.maintain(&mut self.arena, self.shard_uid);
+        }
     }
 
     fn delete_root(&mut self, state_root: &CryptoHash) {
@@ -187,9 +237,15 @@ impl MemTries {
         Ok(MemTrieUpdate::new(root_id, &self.arena.memory(), self.shard_uid.to_string(), mode))
     }
 
-    /// Returns an iterator over the memtrie for the given trie root.
-    pub fn get_iter<'a>(&'a self, trie: &'a Trie) -> Result<STMemTrieIterator<'a>, StorageError> {
-        let iter_storage = MemTrieIteratorInner::new(self, trie);
+    /// Returns an iterator over the memtrie for the given trie root. The nodes
+    /// and values read by the iterator are reported to `access_tracker`.
+    pub fn get_iter<'a>(
+        &'a self,
+        trie: &'a Trie,
+        access_tracker: Option<&'a dyn AccessTracker>,
+    ) -> Result<STMemTrieIterator<'a>, StorageError> {
+        let iter_storage =
+            MemTrieIteratorInner::new(self, trie).with_access_tracker(access_tracker);
         STMemTrieIterator::new(iter_storage, None)
     }
 
@@ -201,8 +257,170 @@ impl MemTries {
         key: &[u8],
         nodes_accessed: Option<&mut Vec<(CryptoHash, Arc<[u8]>)>>,
     ) -> Result<Option<ValueView>, StorageError> {
+        match self.lookup_partial(state_root, key, nodes_accessed)? {
+            MemTrieLookup::Complete(value) => Ok(value),
+            MemTrieLookup::Unloaded { node_hash, .. } => Err(StorageError::MemTrieLoadingError(
+                format!("Memtrie node {} is not loaded", node_hash),
+            )),
+        }
+    }
+
+    /// Like `lookup`, but for partial memtries the lookup may stop at a node
+    /// which is not loaded, to be continued on disk.
+    pub fn lookup_partial<'a, 'k>(
+        &'a self,
+        state_root: &CryptoHash,
+        key: &'k [u8],
+        nodes_accessed: Option<&mut Vec<(CryptoHash, Arc<[u8]>)>>,
+    ) -> Result<MemTrieLookup<'a, 'k>, StorageError> {
+        let root = self.get_root(state_root)?;
+        let Some(partial) = &self.partial else {
+            return Ok(MemTrieLookup::Complete(memtrie_lookup(root, key, nodes_accessed)?));
+        };
+        let mut stubs_passed = Vec::new();
+        let result = memtrie_lookup_partial(root, key, nodes_accessed, &mut stubs_passed);
+        let missed = match &result {
+            MemTrieLookup::Complete(_) => None,
+            MemTrieLookup::Unloaded { stub, .. } => Some(*stub),
+        };
+        partial.record_lookup(self.shard_uid, &stubs_passed, missed);
+        Ok(result)
+    }
+
+    /// Loads the nodes needed to look up the given keys in the trie with the
+    /// given state root. Used before updates of partial memtries, so that
+    /// they rarely need to be retried. Does nothing for complete memtries.
+    ///
+    /// Subtrees loaded on demand since the previous update are unloaded
+    /// first if the memtries do not fit into the memory budget.
+    pub fn load_paths<'k>(
+        &mut self,
+        state_root: &CryptoHash,
+        keys: impl IntoIterator<Item = &'k [u8]>,
+    ) -> Result<(), StorageError> {
+        if self.partial.is_none() || state_root == &CryptoHash::default() {
+            return Ok(());
+        }
+        self.shrink_to_budget();
+        for key in keys {
+            while let Some(stub) = self.find_unloaded_stub(state_root, key)? {
+                self.load_stub(stub)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Like `load_paths`, but reads the nodes from disk without holding the
+    /// lock on the memtries.
+    pub fn load_paths_unlocked<'k>(
+        memtries: &RwLock<Self>,
+        state_root: &CryptoHash,
+        keys: impl IntoIterator<Item = &'k [u8]>,
+    ) -> Result<(), StorageError> {
+        if !memtries.read().is_partial() || state_root == &CryptoHash::default() {
+            return Ok(());
+        }
+        memtries.write().shrink_to_budget();
+        for key in keys {
+            loop {
+                let stub = memtries.read().find_unloaded_stub(state_root, key)?;
+                let Some(stub) = stub else { break };
+                Self::load_stub_unlocked(memtries, stub)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the first unloaded stub on the path of the key, if any.
+    fn find_unloaded_stub(
+        &self,
+        state_root: &CryptoHash,
+        key: &[u8],
+    ) -> Result<Option<MemTrieNodeId>, StorageError> {
         let root = self.get_root(state_root)?;
-        Ok(memtrie_lookup(root, key, nodes_accessed))
+        Ok(match memtrie_lookup_impl(root, NibbleSlice::new(key), None, None) {
+            MemTrieLookup::Complete(_) => None,
+            MemTrieLookup::Unloaded { stub, .. } => Some(stub),
+        })
+    }
+
+    /// Loads the subtree of the given stub, needed by an update which failed
+    /// because of it (see `MemTrieUpdate::unloaded_node`).
+    pub fn load_stub(&mut self, stub: MemTrieNodeId) -> Result<(), StorageError> {
+        let Some(partial) = &mut self.partial else {
+            return Err(Self::complete_memtries_error(self.shard_uid));
+        };
+        partial.load_stub(&mut self.arena, self.shard_uid, stub, ON_DEMAND_LOAD_BYTES)
+    }
+
+    /// Like `load_stub`, but reads the subtree from disk without holding the
+    /// lock on the memtries.
+    pub fn load_stub_unlocked(
+        memtries: &RwLock<Self>,
+        stub: MemTrieNodeId,
+    ) -> Result<(), StorageError> {
+        let load = {
+            let memtries = memtries.read();
+            let Some(partial) = &memtries.partial else {
+                return Err(Self::complete_memtries_error(memtries.shard_uid));
+            };
+            partial.subtree_load(&memtries.arena, memtries.shard_uid, stub, ON_DEMAND_LOAD_BYTES)?
+        };
+        if let Some(load) = load {
+            let subtree = load.read()?;
+            memtries.write().insert_subtree(subtree);
+        }
+        Ok(())
+    }
+
+    /// Does the loads of subtrees planned by the maintenance of partial
+    /// memtries in `delete_until_height`, reading the subtrees from disk
+    /// without holding the lock on the memtries.
+    pub fn load_pending_subtrees(memtries: &RwLock<Self>) {
+        let loads = match &mut memtries.write().partial {
+            Some(partial) => partial.take_pending_loads(),
+            None => return,
+        };
+        if loads.is_empty() {
+            return;
+        }
+        let subtrees: Vec<_> = loads
+            .into_iter()
+            .filter_map(|load| {
+                let shard_uid = load.shard_uid;
+                load.read()
+                    .inspect_err(|err| {
+                        warn!(target: "memtrie", %shard_uid, ?err, "Failed to load memtrie subtree")
+                    })
+                    .ok()
+            })
+            .collect();
+        let mut memtries = memtries.write();
+        for subtree in subtrees {
+            memtries.insert_subtree(subtree);
+        }
+        memtries.shrink_to_budget();
+    }
+
+    pub(super) fn insert_subtree(&mut self, subtree: LoadedSubtree) {
+        if let Some(partial) = &mut self.partial {
+            partial.insert_subtree(&mut self.arena, self.shard_uid, subtree);
+        }
+    }
+
+    /// Unloads subtrees of partial memtries until they fit into the memory
+    /// budget.
+    pub(super) fn shrink_to_budget(&mut self) {
+        if let Some(partial) = &mut self.partial {
+            partial.shrink_to_budget(&mut self.arena, self.shard_uid);
+        }
+    }
+
+    fn complete_memtries_error(shard_uid: ShardUId) -> StorageError {
+        StorageError::MemTrieLoadingError(format!(
+            "Cannot load nodes into complete memtries of shard {}",
+            shard_uid
+        ))
     }
 
     /// Freezes memtrie. The result is used as a shared data to construct new
@@ -29,3 +29,39 @@ pub static MEMTRIE_NUM_LOOKUPS: LazyLock<IntCounter> = LazyLock::new(|| {
     )
     .unwrap()
 });
+
+pub static MEMTRIE_PARTIAL_LOOKUPS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "near_memtrie_partial_lookups",
+        "Number of lookups in partial in-memory tries, by whether they completed in memory",
+        &["shard_uid", "result"],
+    )
+    .unwrap()
+});
+
+pub static MEMTRIE_PARTIAL_SUBTREES_LOADED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "near_memtrie_partial_subtrees_loaded",
+        "Number of subtrees loaded into partial in-memory tries from disk",
+        &["shard_uid"],
+    )
+    .unwrap()
+});
+
+pub static MEMTRIE_PARTIAL_SUBTREES_UNLOADED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "near_memtrie_partial_subtrees_unloaded",
+        "Number of subtrees evicted from partial in-memory tries",
+        &["shard_uid"],
+    )
+    .unwrap()
+});
+
+pub static MEMTRIE_PARTIAL_MEMORY_BYTES: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    try_create_int_gauge_vec(
+        "near_memtrie_partial_memory_bytes",
+        "Memory used by partial in-memory tries and their memory budget",
+        &["shard_uid", "kind"],
+    )
+    .unwrap()
+});
@@ -2,9 +2,10 @@ mod arena;
 mod construction;
 pub(crate) mod flexible_data;
 mod freelist;
+pub mod image;
 pub mod iter;
 pub mod loading;
-mod lookup;
+pub(crate) mod lookup;
 pub mod memtrie_update;
 pub mod memtries;
 pub mod metrics;
@@ -12,6 +13,7 @@ pub mod metrics;
 pub(crate) mod nibbles_utils;
 pub mod node;
 mod parallel_loader;
+pub mod partial;
 
 /// Check this, because in the code we conveniently assume usize is 8 bytes.
 /// In-memory trie can't possibly work under 32-bit anyway.
@@ -18,6 +18,7 @@ pub(crate) enum NodeKind {
     Extension = 1,
     Branch = 2,
     BranchWithValue = 3,
+    Stub = 4,
 }
 
 impl NodeKind {
@@ -25,6 +26,7 @@ impl NodeKind {
     const DISCRIMINANT_EXTENSION: u8 = Self::Extension as u8;
     const DISCRIMINANT_BRANCH: u8 = Self::Branch as u8;
     const DISCRIMINANT_BRANCH_WITH_VALUE: u8 = Self::BranchWithValue as u8;
+    const DISCRIMINANT_STUB: u8 = Self::Stub as u8;
 }
 
 #[derive(BorshSerialize, BorshDeserialize)]
@@ -109,12 +111,27 @@ impl BorshFixedSize for BranchWithValueHeader {
         + EncodedChildrenHeader::SERIALIZED_SIZE;
 }
 
+#[derive(BorshSerialize, BorshDeserialize)]
+pub(crate) struct StubHeader {
+    common: CommonHeader,
+    hash: CryptoHash,
+    target: ArenaPos,
+}
+
+impl StubHeader {
+    const TARGET_OFFSET: usize = CommonHeader::SERIALIZED_SIZE + size_of::<CryptoHash>();
+}
+
+impl BorshFixedSize for StubHeader {
+    const SERIALIZED_SIZE: usize = Self::TARGET_OFFSET + ArenaPos::SERIALIZED_SIZE;
+}
+
 impl MemTrieNodeId {
     /// Encodes the data.
     pub(crate) fn new_impl(
         arena: &mut impl ArenaMut,
         node: InputMemTrieNode,
-        node_hash: Option<CryptoHash>,
+        hash_and_memory_usage: Option<(CryptoHash, u64)>,
     ) -> Self {
         // We add reference to all the children when creating the node.
         // As for the refcount of this newly created node, it starts at 0.
@@ -135,11 +152,14 @@ impl MemTrieNodeId {
             }
             _ => {}
         }
-        // Prepare the raw node, for memory usage and hash computation.
-        let raw_node_with_size = if matches!(&node, InputMemTrieNode::Leaf { .. }) {
+        // Compute memory usage and hash, unless they are given.
+        let nonleaf_header = if matches!(&node, InputMemTrieNode::Leaf { .. }) {
             None
+        } else if let Some((node_hash, memory_usage)) = hash_and_memory_usage {
+            Some(NonLeafHeader::new(memory_usage, node_hash))
         } else {
-            Some(node.to_raw_trie_node_with_size_non_leaf(arena.memory()))
+            let raw_node_with_size = node.to_raw_trie_node_with_size_non_leaf(arena.memory());
+            Some(NonLeafHeader::new(raw_node_with_size.memory_usage, raw_node_with_size.hash()))
         };
 
         // Finally, encode the data.
@@ -168,13 +188,9 @@ impl MemTrieNodeId {
                     arena,
                     ExtensionHeader::SERIALIZED_SIZE + extension_header.flexible_data_length(),
                 );
-                let raw_node_with_size = raw_node_with_size.unwrap();
                 data.encode(ExtensionHeader {
                     common: CommonHeader { refcount: 0, kind: NodeKind::Extension },
-                    nonleaf: NonLeafHeader::new(
-                        raw_node_with_size.memory_usage,
-                        node_hash.unwrap_or_else(|| raw_node_with_size.hash()),
-                    ),
+                    nonleaf: nonleaf_header.unwrap(),
                     child: child.pos,
                     extension: extension_header,
                 });
@@ -187,13 +203,9 @@ impl MemTrieNodeId {
                     arena,
                     BranchHeader::SERIALIZED_SIZE + children_header.flexible_data_length(),
                 );
-                let raw_node_with_size = raw_node_with_size.unwrap();
                 data.encode(BranchHeader {
                     common: CommonHeader { refcount: 0, kind: NodeKind::Branch },
-                    nonleaf: NonLeafHeader::new(
-                        raw_node_with_size.memory_usage,
-                        node_hash.unwrap_or_else(|| raw_node_with_size.hash()),
-                    ),
+                    nonleaf: nonleaf_header.unwrap(),
                     children: children_header,
                 });
                 data.encode_flexible(&children_header, &children);
@@ -208,13 +220,9 @@ impl MemTrieNodeId {
                         + children_header.flexible_data_length()
                         + value_header.flexible_data_length(),
                 );
-                let raw_node_with_size = raw_node_with_size.unwrap();
                 data.encode(BranchWithValueHeader {
                     common: CommonHeader { refcount: 0, kind: NodeKind::BranchWithValue },
-                    nonleaf: NonLeafHeader::new(
-                        raw_node_with_size.memory_usage,
-                        node_hash.unwrap_or_else(|| raw_node_with_size.hash()),
-                    ),
+                    nonleaf: nonleaf_header.unwrap(),
                     children: children_header,
                     value: value_header,
                 });
@@ -226,6 +234,47 @@ impl MemTrieNodeId {
         Self { pos: data.raw_pos() }
     }
 
+    /// Encodes a stub of the node with the given hash, see
+    /// `MemTrieNodeView::Stub`. The stub starts out unloaded.
+    pub(crate) fn new_stub(arena: &mut impl ArenaMut, node_hash: CryptoHash) -> Self {
+        let mut data = RawEncoder::new(arena, StubHeader::SERIALIZED_SIZE);
+        data.encode(StubHeader {
+            common: CommonHeader { refcount: 0, kind: NodeKind::Stub },
+            hash: node_hash,
+            target: ArenaPos::invalid(),
+        });
+        Self { pos: data.finish().raw_pos() }
+    }
+
+    /// Loads this stub with the given node, which must have the stub's hash,
+    /// or unloads it if `target` is `None`. The reference the stub held to its
+    /// previous node, if any, is passed on to the caller.
+    pub(crate) fn replace_stub_target(
+        &self,
+        memory: &mut impl ArenaMemoryMut,
+        target: Option<MemTrieNodeId>,
+    ) -> Option<MemTrieNodeId> {
+        debug_assert!(self.as_ptr(memory).as_stub().is_some(), "Not a stub");
+        if let Some(target) = target {
+            debug_assert!(target.as_ptr(memory).as_stub().is_none(), "Stub of a stub");
+            target.add_ref(memory);
+        }
+        let target_pos = target.map_or(ArenaPos::invalid(), |target| target.pos);
+        let target_memory = memory.raw_slice_mut(
+            self.pos.offset_by(StubHeader::TARGET_OFFSET),
+            ArenaPos::SERIALIZED_SIZE,
+        );
+        let previous = ArenaPos::try_from_slice(target_memory).unwrap();
+        target_memory.copy_from_slice(&borsh::to_vec(&target_pos).unwrap());
+        (!previous.is_invalid()).then_some(MemTrieNodeId { pos: previous })
+    }
+
+    /// Returns the current refcount of the node.
+    pub(crate) fn refcount(&self, memory: &impl ArenaMemory) -> u32 {
+        let refcount_memory = memory.raw_slice(self.pos, size_of::<u32>());
+        u32::from_le_bytes(refcount_memory.try_into().unwrap())
+    }
+
     /// Increments the refcount, returning the new refcount.
     pub(crate) fn add_ref(&self, memory: &mut impl ArenaMemoryMut) -> u32 {
         // It's possible that in a hybrid memory setup, we are accessing the read-only part of memory.
@@ -258,8 +307,12 @@ impl MemTrieNodeId {
         if new_refcount == 0 {
             let mut children_to_unref: SmallVec<[ArenaPos; 16]> = SmallVec::new();
             let node_ptr = self.as_ptr(arena.memory());
-            for child in node_ptr.view().iter_children() {
-                children_to_unref.push(child.id().pos);
+            if let Some((_, target)) = node_ptr.as_stub() {
+                children_to_unref.extend(target.map(|target| target.id().pos));
+            } else {
+                for child in node_ptr.view().iter_children() {
+                    children_to_unref.push(child.id().pos);
+                }
             }
             let alloc_size = node_ptr.size_of_allocation();
             arena.dealloc(self.pos, alloc_size);
@@ -282,6 +335,18 @@ impl<'a, M: ArenaMemory> MemTrieNodePtr<'a, M> {
         header[CommonHeader::SERIALIZED_SIZE - 1]
     }
 
+    /// If this is a stub, returns the hash of the node it stands for and the
+    /// node if it is loaded.
+    pub(crate) fn as_stub(&self) -> Option<(CryptoHash, Option<MemTrieNodePtr<'a, M>>)> {
+        if self.get_kind() != NodeKind::DISCRIMINANT_STUB {
+            return None;
+        }
+        let header = self.decoder().decode::<StubHeader>();
+        let target = (!header.target.is_invalid())
+            .then(|| MemTrieNodePtr::from(self.ptr.arena().ptr(header.target)));
+        Some((header.hash, target))
+    }
+
     /// Decodes the data.
     pub(crate) fn view_kind(&self, kind: u8) -> MemTrieNodeView<'a, M> {
         let mut decoder = self.decoder();
@@ -322,13 +387,17 @@ impl<'a, M: ArenaMemory> MemTrieNodePtr<'a, M> {
                     value,
                 }
             }
+            NodeKind::DISCRIMINANT_STUB => match self.as_stub().unwrap() {
+                (_, Some(target)) => target.view(),
+                (hash, None) => MemTrieNodeView::Stub { hash },
+            },
             _ => panic!("unknown node type"),
         }
     }
 
     /// Calculates the size of the allocation with only a pointer to the start
     /// of the trie node's allocation.
-    fn size_of_allocation(&self) -> usize {
+    pub(crate) fn size_of_allocation(&self) -> usize {
         let mut decoder = self.decoder();
         let kind = decoder.peek::<CommonHeader>().kind;
         match kind {
@@ -352,6 +421,7 @@ impl<'a, M: ArenaMemory> MemTrieNodePtr<'a, M> {
                     + header.children.flexible_data_length()
                     + header.value.flexible_data_length()
             }
+            NodeKind::Stub => StubHeader::SERIALIZED_SIZE,
         }
     }
 }
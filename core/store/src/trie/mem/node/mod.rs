@@ -32,12 +32,15 @@ impl MemTrieNodeId {
         Self::new_impl(arena, input, None)
     }
 
-    pub fn new_with_hash(
+    /// Like `new`, but with the hash and memory usage already known, so that
+    /// the children do not need to be visited to compute them.
+    pub fn new_with_hash_and_memory_usage(
         arena: &mut impl ArenaMut,
         input: InputMemTrieNode,
         hash: CryptoHash,
+        memory_usage: u64,
     ) -> Self {
-        Self::new_impl(arena, input, Some(hash))
+        Self::new_impl(arena, input, Some((hash, memory_usage)))
     }
 
     pub fn as_ptr<'a, M: ArenaMemory>(&self, arena: &'a M) -> MemTrieNodePtr<'a, M> {
@@ -114,6 +117,12 @@ pub enum MemTrieNodeView<'a, M: ArenaMemory> {
         children: ChildrenView<'a, M>,
         value: ValueView<'a>,
     },
+    /// A node of a partial memtrie (see `PartialMemTries`) whose subtree is
+    /// not loaded into memory. Only its hash is known; the rest of the
+    /// subtree must be read from disk.
+    Stub {
+        hash: CryptoHash,
+    },
 }
 
 impl<'a> InputMemTrieNode<'a> {
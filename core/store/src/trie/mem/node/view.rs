@@ -18,6 +18,7 @@ impl<'a, M: ArenaMemory> MemTrieNodeView<'a, M> {
                 debug_assert_ne!(hash, &CryptoHash::default(), "Hash not computed");
                 *hash
             }
+            Self::Stub { hash } => *hash,
         }
     }
 
@@ -34,6 +35,7 @@ impl<'a, M: ArenaMemory> MemTrieNodeView<'a, M> {
             Self::Extension { memory_usage, .. }
             | Self::Branch { memory_usage, .. }
             | Self::BranchWithValue { memory_usage, .. } => *memory_usage,
+            Self::Stub { hash } => panic!("Memory usage of unloaded node {hash} is unknown"),
         }
     }
 
@@ -64,6 +66,7 @@ impl<'a, M: ArenaMemory> MemTrieNodeView<'a, M> {
                 );
                 RawTrieNodeWithSize { node, memory_usage: self.memory_usage() }
             }
+            Self::Stub { hash } => panic!("Cannot convert unloaded node {hash}"),
         }
     }
 
@@ -71,7 +74,7 @@ impl<'a, M: ArenaMemory> MemTrieNodeView<'a, M> {
         &'b self,
     ) -> Box<dyn Iterator<Item = MemTrieNodePtr<'a, M>> + 'b> {
         match self {
-            Self::Leaf { .. } => Box::new(std::iter::empty()),
+            Self::Leaf { .. } | Self::Stub { .. } => Box::new(std::iter::empty()),
             Self::Extension { child, .. } => Box::new(std::iter::once(*child)),
             Self::Branch { children, .. } | Self::BranchWithValue { children, .. } => {
                 Box::new(children.iter())
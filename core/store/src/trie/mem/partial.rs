@@ -0,0 +1,607 @@
+//! Partial memtries keep only a part of a shard's trie in memory, to bound
+//! the memory used by memtries of large shards.
+//!
+//! The upper levels of the trie are always loaded. Below them, subtrees are
+//! placed behind stub nodes (`MemTrieNodeView::Stub`), which may be loaded,
+//! in which case they behave as the node they stand for, or unloaded, in
+//! which case only the hash of the node is known. Lookups which reach an
+//! unloaded stub are continued from flat storage or the on-disk trie, and
+//! updates which need an unloaded node load it and are retried.
+//!
+//! Which subtrees stay loaded is decided after each garbage collection of
+//! the memtries: lookups passing through a loaded stub increase its score,
+//! subtrees which lookups missed the most are loaded, and subtrees with the
+//! lowest scores are unloaded until the memtries fit into the memory budget.
+
+use super::arena::hybrid::HybridArena;
+use super::arena::{Arena, ArenaMemory, ArenaMut, ArenaWithDealloc};
+use super::metrics::{
+    MEMTRIE_PARTIAL_LOOKUPS, MEMTRIE_PARTIAL_MEMORY_BYTES, MEMTRIE_PARTIAL_SUBTREES_LOADED,
+    MEMTRIE_PARTIAL_SUBTREES_UNLOADED,
+};
+use super::node::{InputMemTrieNode, MemTrieNodeId};
+use crate::adapter::trie_store::TrieStoreAdapter;
+use crate::trie::Children;
+use crate::{RawTrieNode, RawTrieNodeWithSize};
+use borsh::BorshDeserialize;
+use near_primitives::errors::StorageError;
+use near_primitives::hash::CryptoHash;
+use near_primitives::shard_layout::ShardUId;
+use near_primitives::state::{FlatStateValue, ValueRef};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Maximum size of the nodes read from disk when a subtree which lookups
+/// missed is loaded during maintenance.
+const MAINTENANCE_LOAD_BYTES: usize = 4 << 20;
+
+/// Maximum size of the nodes read from disk when a subtree is loaded because
+/// an update needs it.
+pub(super) const ON_DEMAND_LOAD_BYTES: usize = 64 << 10;
+
+/// State of the partial memtries of a shard. Lives inside `MemTries`, which
+/// owns the arena with the nodes.
+pub struct PartialMemTries {
+    store: TrieStoreAdapter,
+    memory_budget: usize,
+    /// All stubs created in the arena, with their scores. Each of them holds
+    /// a reference from this map, so that the stubs stay valid until they
+    /// are no longer reachable from any root and are released.
+    stubs: HashMap<MemTrieNodeId, StubInfo>,
+    /// Accesses recorded by lookups since the last maintenance. Lookups only
+    /// hold a read lock on memtries, hence the mutex.
+    accesses: Mutex<StubAccesses>,
+    /// Loads of the subtrees which lookups missed, planned by the last
+    /// maintenance and not yet taken by `take_pending_loads`.
+    pending_loads: Vec<SubtreeLoad>,
+}
+
+struct StubInfo {
+    hash: CryptoHash,
+    score: u64,
+}
+
+#[derive(Default)]
+struct StubAccesses {
+    /// Number of lookups which passed through each loaded stub.
+    passed: HashMap<MemTrieNodeId, u64>,
+    /// Number of lookups which stopped at each unloaded stub.
+    missed: HashMap<MemTrieNodeId, u64>,
+}
+
+#[derive(Clone, Copy)]
+enum LoadReason {
+    /// An update needs a node of the subtree.
+    OnDemand,
+    /// Lookups stopped at the stub `missed` times since the last maintenance.
+    Maintenance { missed: u64 },
+}
+
+/// Load of the subtree behind an unloaded stub. Reading the subtree from
+/// disk does not need the memtries, so it is done without holding their
+/// lock; the result is then inserted by `MemTries::insert_subtree`.
+pub struct SubtreeLoad {
+    store: TrieStoreAdapter,
+    pub(super) shard_uid: ShardUId,
+    stub: MemTrieNodeId,
+    hash: CryptoHash,
+    max_bytes: usize,
+    reason: LoadReason,
+}
+
+impl SubtreeLoad {
+    /// Reads the nodes of the subtree from disk.
+    pub fn read(self) -> Result<LoadedSubtree, StorageError> {
+        let nodes = read_subtree(&self.store, self.shard_uid, self.hash, self.max_bytes)?;
+        Ok(LoadedSubtree { stub: self.stub, hash: self.hash, reason: self.reason, nodes })
+    }
+}
+
+/// Subtree read from disk by `SubtreeLoad::read`.
+pub struct LoadedSubtree {
+    stub: MemTrieNodeId,
+    hash: CryptoHash,
+    reason: LoadReason,
+    nodes: Vec<SubtreeNode>,
+}
+
+impl PartialMemTries {
+    pub fn new(store: TrieStoreAdapter, memory_budget: usize) -> Self {
+        Self {
+            store,
+            memory_budget,
+            stubs: HashMap::new(),
+            accesses: Default::default(),
+            pending_loads: Vec::new(),
+        }
+    }
+
+    /// Takes ownership of stubs which were just created in the arena.
+    pub(super) fn register_stubs(
+        &mut self,
+        arena: &mut HybridArena,
+        stubs: impl IntoIterator<Item = (MemTrieNodeId, CryptoHash)>,
+    ) {
+        for (stub, hash) in stubs {
+            stub.add_ref(arena.memory_mut());
+            self.stubs.insert(stub, StubInfo { hash, score: 0 });
+        }
+    }
+
+    /// Records a lookup which passed through `stubs_passed` and, if it could
+    /// not be completed in memory, stopped at the `missed` stub.
+    pub(super) fn record_lookup(
+        &self,
+        shard_uid: ShardUId,
+        stubs_passed: &[MemTrieNodeId],
+        missed: Option<MemTrieNodeId>,
+    ) {
+        let result = if missed.is_some() { "unloaded" } else { "in_memory" };
+        MEMTRIE_PARTIAL_LOOKUPS.with_label_values(&[&shard_uid.to_string(), result]).inc();
+        let mut accesses = self.accesses.lock();
+        for stub in stubs_passed {
+            *accesses.passed.entry(*stub).or_default() += 1;
+        }
+        if let Some(stub) = missed {
+            *accesses.missed.entry(stub).or_default() += 1;
+        }
+    }
+
+    /// Prepares the load of the subtree behind the given stub, which an
+    /// update needs. At most `max_bytes` of nodes are read from disk.
+    /// Returns `None` if the stub is already loaded.
+    pub(super) fn subtree_load(
+        &self,
+        arena: &HybridArena,
+        shard_uid: ShardUId,
+        stub: MemTrieNodeId,
+        max_bytes: usize,
+    ) -> Result<Option<SubtreeLoad>, StorageError> {
+        self.prepare_load(arena, shard_uid, stub, max_bytes, LoadReason::OnDemand)
+    }
+
+    fn prepare_load(
+        &self,
+        arena: &HybridArena,
+        shard_uid: ShardUId,
+        stub: MemTrieNodeId,
+        max_bytes: usize,
+        reason: LoadReason,
+    ) -> Result<Option<SubtreeLoad>, StorageError> {
+        let Some(info) = self.stubs.get(&stub) else {
+            return Err(StorageError::MemTrieLoadingError(format!(
+                "Unknown memtrie stub {:?} in shard {}",
+                stub, shard_uid
+            )));
+        };
+        let (_, target) = stub.as_ptr(arena.memory()).as_stub().unwrap();
+        if target.is_some() {
+            return Ok(None);
+        }
+        Ok(Some(SubtreeLoad {
+            store: self.store.clone(),
+            shard_uid,
+            stub,
+            hash: info.hash,
+            max_bytes,
+            reason,
+        }))
+    }
+
+    /// Inserts a subtree read from disk behind its stub. Does nothing if the
+    /// stub was released or loaded since the load was prepared, or if the
+    /// load was planned by maintenance and the budget is already used up.
+    pub(super) fn insert_subtree(
+        &mut self,
+        arena: &mut HybridArena,
+        shard_uid: ShardUId,
+        subtree: LoadedSubtree,
+    ) {
+        let LoadedSubtree { stub, hash, reason, nodes } = subtree;
+        // If the stub was released, its memory may have been reused, in
+        // which case it is either not a stub or a stub of another node.
+        if !self.stubs.get(&stub).is_some_and(|info| info.hash == hash) {
+            return;
+        }
+        let (_, target) = stub.as_ptr(arena.memory()).as_stub().unwrap();
+        if target.is_some() {
+            return;
+        }
+        if matches!(reason, LoadReason::Maintenance { .. })
+            && arena.active_allocs_bytes() >= self.memory_budget
+        {
+            return;
+        }
+        let mut new_stubs = Vec::new();
+        let target = insert_subtree_nodes(arena, nodes, &mut new_stubs);
+        stub.replace_stub_target(arena.memory_mut(), Some(target));
+        self.register_stubs(arena, new_stubs);
+        if let LoadReason::Maintenance { missed } = reason {
+            self.stubs.get_mut(&stub).unwrap().score += missed;
+        }
+        MEMTRIE_PARTIAL_SUBTREES_LOADED.with_label_values(&[&shard_uid.to_string()]).inc();
+    }
+
+    /// Loads the subtree behind the given stub, reading at most `max_bytes`
+    /// of nodes from disk. Does nothing if the stub is already loaded.
+    pub(super) fn load_stub(
+        &mut self,
+        arena: &mut HybridArena,
+        shard_uid: ShardUId,
+        stub: MemTrieNodeId,
+        max_bytes: usize,
+    ) -> Result<(), StorageError> {
+        if let Some(load) = self.subtree_load(arena, shard_uid, stub, max_bytes)? {
+            let subtree = load.read()?;
+            self.insert_subtree(arena, shard_uid, subtree);
+        }
+        Ok(())
+    }
+
+    /// Releases stubs which are no longer reachable, unloads the subtrees
+    /// with the lowest scores until the memtries fit into the memory budget,
+    /// and plans loads of the subtrees which lookups missed the most since
+    /// the last maintenance. The loads are not done here, so that disk reads
+    /// do not happen under the lock of the memtries; see `take_pending_loads`.
+    pub(super) fn maintain(&mut self, arena: &mut HybridArena, shard_uid: ShardUId) {
+        self.release_unreachable_stubs(arena);
+
+        let accesses = std::mem::take(&mut *self.accesses.lock());
+        for info in self.stubs.values_mut() {
+            info.score /= 2;
+        }
+        for (stub, count) in accesses.passed {
+            if let Some(info) = self.stubs.get_mut(&stub) {
+                info.score += count;
+            }
+        }
+        self.shrink_to_budget(arena, shard_uid);
+
+        let mut missed: Vec<_> =
+            accesses.missed.into_iter().filter(|(stub, _)| self.stubs.contains_key(stub)).collect();
+        missed.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        let mut remaining = self.memory_budget.saturating_sub(arena.active_allocs_bytes());
+        let mut pending_loads = Vec::new();
+        for (stub, missed) in missed {
+            if remaining == 0 {
+                break;
+            }
+            let max_bytes = MAINTENANCE_LOAD_BYTES.min(remaining);
+            let reason = LoadReason::Maintenance { missed };
+            match self.prepare_load(arena, shard_uid, stub, max_bytes, reason) {
+                Ok(Some(load)) => {
+                    pending_loads.push(load);
+                    remaining -= max_bytes;
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    warn!(target: "memtrie", %shard_uid, ?err, "Failed to load memtrie subtree")
+                }
+            }
+        }
+        self.pending_loads = pending_loads;
+
+        let shard_uid = shard_uid.to_string();
+        MEMTRIE_PARTIAL_MEMORY_BYTES
+            .with_label_values(&[&shard_uid, "used"])
+            .set(arena.active_allocs_bytes() as i64);
+        MEMTRIE_PARTIAL_MEMORY_BYTES
+            .with_label_values(&[&shard_uid, "budget"])
+            .set(self.memory_budget as i64);
+    }
+
+    /// Takes the loads planned by the last maintenance.
+    pub(super) fn take_pending_loads(&mut self) -> Vec<SubtreeLoad> {
+        std::mem::take(&mut self.pending_loads)
+    }
+
+    /// Unloads the subtrees with the lowest scores until the memtries fit
+    /// into the memory budget.
+    pub(super) fn shrink_to_budget(&mut self, arena: &mut HybridArena, shard_uid: ShardUId) {
+        // Unloading a subtree does not free the loaded subtrees of the stubs
+        // inside it until these stubs are released, so repeat until nothing
+        // more can be unloaded.
+        while arena.active_allocs_bytes() > self.memory_budget {
+            let memory = arena.memory();
+            let mut loaded: Vec<_> = self
+                .stubs
+                .iter()
+                .filter(|(stub, _)| matches!(stub.as_ptr(memory).as_stub(), Some((_, Some(_)))))
+                .map(|(stub, info)| (*stub, info.score))
+                .collect();
+            if loaded.is_empty() {
+                break;
+            }
+            loaded.sort_by_key(|(_, score)| *score);
+            for (stub, _) in loaded {
+                if arena.active_allocs_bytes() <= self.memory_budget {
+                    break;
+                }
+                let target = stub.replace_stub_target(arena.memory_mut(), None).unwrap();
+                target.remove_ref(arena);
+                MEMTRIE_PARTIAL_SUBTREES_UNLOADED
+                    .with_label_values(&[&shard_uid.to_string()])
+                    .inc();
+            }
+            self.release_unreachable_stubs(arena);
+        }
+    }
+
+    /// Releases the stubs referenced only by this struct, which also releases
+    /// their subtrees.
+    fn release_unreachable_stubs(&mut self, arena: &mut HybridArena) {
+        loop {
+            let memory = arena.memory();
+            let unreachable: Vec<_> =
+                self.stubs.keys().filter(|stub| stub.refcount(memory) == 1).copied().collect();
+            if unreachable.is_empty() {
+                break;
+            }
+            for stub in unreachable {
+                self.stubs.remove(&stub);
+                stub.remove_ref(arena);
+            }
+        }
+    }
+}
+
+/// Node read from disk by `read_subtree`.
+enum SubtreeNode {
+    Read {
+        hash: CryptoHash,
+        node: RawTrieNodeWithSize,
+        value: Option<FlatStateValue>,
+        children: Vec<usize>,
+    },
+    Unread {
+        hash: CryptoHash,
+    },
+}
+
+/// Loads the subtree of the node with the given hash from the State column
+/// into the arena. Nodes are read breadth-first until `max_bytes` of nodes
+/// are read; the children which were not read become unloaded stubs, which
+/// are added to `new_stubs`. Returns the root of the subtree, which is never
+/// a stub.
+pub(super) fn load_subtree(
+    arena: &mut impl ArenaMut,
+    store: &TrieStoreAdapter,
+    shard_uid: ShardUId,
+    hash: CryptoHash,
+    max_bytes: usize,
+    new_stubs: &mut Vec<(MemTrieNodeId, CryptoHash)>,
+) -> Result<MemTrieNodeId, StorageError> {
+    let nodes = read_subtree(store, shard_uid, hash, max_bytes)?;
+    Ok(insert_subtree_nodes(arena, nodes, new_stubs))
+}
+
+/// Reads the nodes of the subtree for `load_subtree`, in breadth-first order.
+fn read_subtree(
+    store: &TrieStoreAdapter,
+    shard_uid: ShardUId,
+    hash: CryptoHash,
+    max_bytes: usize,
+) -> Result<Vec<SubtreeNode>, StorageError> {
+    let mut nodes = vec![SubtreeNode::Unread { hash }];
+    let mut bytes_read = 0;
+    let mut next = 0;
+    // The root is always read, so that the result is not a stub.
+    while next < nodes.len() && (next == 0 || bytes_read < max_bytes) {
+        let SubtreeNode::Unread { hash } = nodes[next] else { unreachable!() };
+        let bytes = store.get(shard_uid, &hash)?;
+        bytes_read += bytes.len();
+        let node = RawTrieNodeWithSize::try_from_slice(&bytes)
+            .map_err(|err| StorageError::StorageInconsistentState(err.to_string()))?;
+        let mut children = Vec::new();
+        let mut add_child = |child_hash: CryptoHash| {
+            children.push(nodes.len());
+            nodes.push(SubtreeNode::Unread { hash: child_hash });
+        };
+        let value = match &node.node {
+            RawTrieNode::Leaf(_, value_ref) => Some(read_flat_value(store, shard_uid, value_ref)?),
+            RawTrieNode::Extension(_, child_hash) => {
+                add_child(*child_hash);
+                None
+            }
+            RawTrieNode::BranchNoValue(children_hashes) => {
+                for (_, child_hash) in children_hashes.iter() {
+                    add_child(*child_hash);
+                }
+                None
+            }
+            RawTrieNode::BranchWithValue(value_ref, children_hashes) => {
+                for (_, child_hash) in children_hashes.iter() {
+                    add_child(*child_hash);
+                }
+                Some(read_flat_value(store, shard_uid, value_ref)?)
+            }
+        };
+        nodes[next] = SubtreeNode::Read { hash, node, value, children };
+        next += 1;
+    }
+    Ok(nodes)
+}
+
+/// Creates the nodes read by `read_subtree` in the arena. Returns the root.
+fn insert_subtree_nodes(
+    arena: &mut impl ArenaMut,
+    nodes: Vec<SubtreeNode>,
+    new_stubs: &mut Vec<(MemTrieNodeId, CryptoHash)>,
+) -> MemTrieNodeId {
+    // Children always come after their parents in breadth-first order, so
+    // nodes are created in the reverse order.
+    let mut node_ids = vec![MemTrieNodeId::default(); nodes.len()];
+    for (index, node) in nodes.iter().enumerate().rev() {
+        let (hash, node, value, children) = match node {
+            SubtreeNode::Unread { hash } => {
+                let stub = MemTrieNodeId::new_stub(arena, *hash);
+                new_stubs.push((stub, *hash));
+                node_ids[index] = stub;
+                continue;
+            }
+            SubtreeNode::Read { hash, node, value, children } => (*hash, node, value, children),
+        };
+        let mut children_ids = children.iter().map(|child| node_ids[*child]);
+        let input = match (&node.node, value) {
+            (RawTrieNode::Leaf(extension, _), Some(value)) => {
+                InputMemTrieNode::Leaf { value, extension }
+            }
+            (RawTrieNode::Extension(extension, _), None) => {
+                InputMemTrieNode::Extension { extension, child: children_ids.next().unwrap() }
+            }
+            (RawTrieNode::BranchNoValue(children_hashes), None) => InputMemTrieNode::Branch {
+                children: branch_children(children_hashes, &mut children_ids),
+            },
+            (RawTrieNode::BranchWithValue(_, children_hashes), Some(value)) => {
+                InputMemTrieNode::BranchWithValue {
+                    children: branch_children(children_hashes, &mut children_ids),
+                    value,
+                }
+            }
+            _ => unreachable!("values are read for nodes with values only"),
+        };
+        let node_id =
+            MemTrieNodeId::new_with_hash_and_memory_usage(arena, input, hash, node.memory_usage);
+        node_ids[index] = node_id;
+    }
+    node_ids[0]
+}
+
+fn branch_children(
+    children_hashes: &Children,
+    children_ids: &mut impl Iterator<Item = MemTrieNodeId>,
+) -> [Option<MemTrieNodeId>; 16] {
+    let mut children = [None; 16];
+    for (nibble, _) in children_hashes.iter() {
+        children[nibble as usize] = children_ids.next();
+    }
+    children
+}
+
+/// Returns the value as it would be stored in flat storage, so that loaded
+/// nodes are the same as the ones loaded from flat storage. Only values
+/// which would be inlined are read.
+fn read_flat_value(
+    store: &TrieStoreAdapter,
+    shard_uid: ShardUId,
+    value_ref: &ValueRef,
+) -> Result<FlatStateValue, StorageError> {
+    if value_ref.length as usize > FlatStateValue::INLINE_DISK_VALUE_THRESHOLD {
+        return Ok(FlatStateValue::Ref(*value_ref));
+    }
+    let value = store.get(shard_uid, &value_ref.hash)?;
+    Ok(FlatStateValue::on_disk(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PartialMemTries, load_subtree};
+    use crate::test_utils::{TestTriesBuilder, test_populate_trie};
+    use crate::trie::mem::arena::single_thread::STArena;
+    use crate::trie::mem::lookup::MemTrieLookup;
+    use crate::trie::mem::memtrie_update::TrackingMode;
+    use crate::trie::mem::memtries::MemTries;
+    use crate::trie::ops::insert_delete::GenericTrieUpdateInsertDelete;
+    use crate::trie::{AccessOptions, Trie};
+    use near_primitives::shard_layout::ShardUId;
+    use near_primitives::state::FlatStateValue;
+
+    fn key(i: usize) -> Vec<u8> {
+        format!("key{:05}", i).into_bytes()
+    }
+
+    #[test]
+    fn test_partial_memtrie() {
+        let shard_tries = TestTriesBuilder::new().build();
+        let shard_uid = ShardUId::single_shard();
+        let changes =
+            (0..1000).map(|i| (key(i), Some(format!("value{}", i).into_bytes()))).collect();
+        let state_root = test_populate_trie(&shard_tries, &Trie::EMPTY_ROOT, shard_uid, changes);
+        let store = shard_tries.store();
+
+        // Load only the upper part of the trie.
+        let mut arena = STArena::new(shard_uid.to_string());
+        let mut new_stubs = Vec::new();
+        let root =
+            load_subtree(&mut arena, &store, shard_uid, state_root, 2000, &mut new_stubs).unwrap();
+        assert!(!new_stubs.is_empty());
+        let mut memtries = MemTries::new_from_arena_and_root(shard_uid, 1, arena, root);
+        memtries.set_partial(PartialMemTries::new(store, 1 << 20), new_stubs);
+
+        // Lookups either complete in memory or stop at an unloaded stub.
+        let mut num_unloaded = 0;
+        for i in 0..1000 {
+            match memtries.lookup_partial(&state_root, &key(i), None).unwrap() {
+                MemTrieLookup::Complete(value) => assert_eq!(
+                    value.unwrap().to_flat_value(),
+                    FlatStateValue::inlined(format!("value{}", i).as_bytes())
+                ),
+                MemTrieLookup::Unloaded { .. } => num_unloaded += 1,
+            }
+        }
+        assert!(num_unloaded > 0);
+
+        // Updates load the nodes they need, and are retried until they do
+        // not need any more nodes.
+        let keys: Vec<_> = (0..1000).step_by(7).map(key).collect();
+        memtries.load_paths(&state_root, keys.iter().map(|key| key.as_slice())).unwrap();
+        let new_root = loop {
+            let mut update = memtries.update(state_root, TrackingMode::None).unwrap();
+            match keys
+                .iter()
+                .try_for_each(|key| update.generic_delete(0, key, AccessOptions::DEFAULT))
+            {
+                Ok(()) => {
+                    let changes = update.to_memtrie_changes_only();
+                    break memtries.apply_memtrie_changes(2, &changes);
+                }
+                Err(_) => {
+                    let stub = update.unloaded_node().unwrap();
+                    drop(update);
+                    memtries.load_stub(stub).unwrap();
+                }
+            }
+        };
+        assert_ne!(new_root, state_root);
+
+        // Maintenance plans loads of the subtrees that lookups missed, which
+        // are read separately and stay within the budget.
+        memtries.delete_until_height(2);
+        let loads = memtries.partial.as_mut().unwrap().take_pending_loads();
+        assert!(!loads.is_empty());
+        let num_allocs = memtries.arena.num_active_allocs();
+        for load in loads {
+            memtries.insert_subtree(load.read().unwrap());
+        }
+        memtries.shrink_to_budget();
+        assert!(memtries.arena.num_active_allocs() > num_allocs);
+        assert!(memtries.arena.active_allocs_bytes() <= 1 << 20);
+
+        // With no budget, everything but the upper levels is unloaded.
+        memtries.partial.as_mut().unwrap().memory_budget = 0;
+        memtries.delete_until_height(2);
+        for i in 0..1000 {
+            let value = match memtries.lookup_partial(&new_root, &key(i), None).unwrap() {
+                MemTrieLookup::Complete(value) => value.map(|value| value.to_flat_value()),
+                MemTrieLookup::Unloaded { .. } => continue,
+            };
+            let expected =
+                (i % 7 != 0).then(|| FlatStateValue::inlined(format!("value{}", i).as_bytes()));
+            assert_eq!(value, expected);
+        }
+
+        // Subtrees loaded on demand are unloaded before the next update if
+        // they do not fit into the budget.
+        let used = memtries.arena.active_allocs_bytes();
+        let keys: Vec<_> = (0..1000).map(key).collect();
+        memtries.load_paths(&new_root, keys.iter().map(|key| key.as_slice())).unwrap();
+        assert!(memtries.arena.active_allocs_bytes() > used);
+        memtries.load_paths(&new_root, []).unwrap();
+        assert!(memtries.arena.active_allocs_bytes() <= used);
+
+        // Once all roots are deleted, all memory is released.
+        memtries.delete_until_height(3);
+        assert_eq!(memtries.arena.num_active_allocs(), 0);
+    }
+}
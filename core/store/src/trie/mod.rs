@@ -17,8 +17,10 @@ use borsh::{BorshDeserialize, BorshSerialize};
 pub use from_flat::construct_trie_from_flat;
 use iterator::{DiskTrieIterator, DiskTrieIteratorInner, TrieIterator};
 use itertools::Itertools;
+use mem::lookup::MemTrieLookup;
 use mem::memtrie_update::{TrackingMode, UpdatedMemTrieNodeWithSize};
 use mem::memtries::MemTries;
+use mem::node::MemTrieNodeId;
 use near_primitives::hash::{CryptoHash, hash};
 pub use near_primitives::shard_layout::ShardUId;
 use near_primitives::state::PartialState;
@@ -438,6 +440,8 @@ pub struct MemTrieChanges {
     /// It implies that the root node is the last one in the list.
     node_ids_with_hashes: Vec<(UpdatedNodeId, CryptoHash)>,
     updated_nodes: Vec<Option<UpdatedMemTrieNodeWithSize>>,
+    /// Updated nodes which replace loaded stubs of a partial memtrie.
+    stubbed_node_ids: Vec<UpdatedNodeId>,
 }
 
 ///
@@ -717,6 +721,34 @@ impl Trie {
         self.flat_storage_chunk_view.is_some()
     }
 
+    /// Returns the values of the keys starting with `prefix` from the
+    /// history of flat state, or `None` if the trie does not read its values
+    /// from the history (see `ShardTries::get_view_trie_at_height`).
+    pub fn get_history_values_with_prefix(
+        &self,
+        prefix: &[u8],
+    ) -> Result<Option<Vec<(Vec<u8>, Vec<u8>)>>, StorageError> {
+        let Some(flat_storage_chunk_view) = &self.flat_storage_chunk_view else {
+            return Ok(None);
+        };
+        let Some(values) = flat_storage_chunk_view.get_history_values_with_prefix(prefix)? else {
+            return Ok(None);
+        };
+        let values = values
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    FlatStateValue::Inlined(value) => value,
+                    FlatStateValue::Ref(value_ref) => {
+                        self.retrieve_value(&value_ref.hash, AccessOptions::DEFAULT)?
+                    }
+                };
+                Ok((key, value))
+            })
+            .collect::<Result<_, StorageError>>()?;
+        Ok(Some(values))
+    }
+
     pub fn internal_get_storage_as_caching_storage(&self) -> Option<&TrieCachingStorage> {
         self.storage.as_caching_storage()
     }
@@ -1235,11 +1267,27 @@ impl Trie {
     /// lookup incurs any gas.
     fn lookup_from_state_column(
         &self,
+        key: NibbleSlice<'_>,
+        use_trie_accounting_cache: bool,
+        operation_options: AccessOptions,
+    ) -> Result<Option<ValueRef>, StorageError> {
+        self.lookup_from_state_column_at(
+            self.root,
+            key,
+            use_trie_accounting_cache,
+            operation_options,
+        )
+    }
+
+    /// Like `lookup_from_state_column`, but starts at the node with the given
+    /// hash, with `key` relative to that node.
+    fn lookup_from_state_column_at(
+        &self,
+        mut hash: CryptoHash,
         mut key: NibbleSlice<'_>,
         use_trie_accounting_cache: bool,
         operation_options: AccessOptions,
     ) -> Result<Option<ValueRef>, StorageError> {
-        let mut hash = self.root;
         loop {
             let node = match self.retrieve_raw_node(
                 &hash,
@@ -1305,6 +1353,11 @@ impl Trie {
     /// `map_result` closure.
     ///
     /// This function also takes care of the accounting cache for gas calculation purposes.
+    ///
+    /// If the memtries are partial and the lookup reaches a node which is not
+    /// loaded, the lookup is continued from flat storage when neither the
+    /// accounting cache nor recording is needed, and from the State column
+    /// otherwise.
     fn lookup_from_memory<R: 'static>(
         &self,
         key: &[u8],
@@ -1317,11 +1370,9 @@ impl Trie {
         }
 
         let lock = self.memtries.as_ref().unwrap().read();
-        let mem_value = if use_trie_accounting_cache
-            || access_options.enable_state_witness_recording
-        {
+        let lookup = if use_trie_accounting_cache || access_options.enable_state_witness_recording {
             let mut accessed_nodes = Vec::new();
-            let mem_value = lock.lookup(&self.root, key, Some(&mut accessed_nodes))?;
+            let lookup = lock.lookup_partial(&self.root, key, Some(&mut accessed_nodes))?;
             if use_trie_accounting_cache {
                 for (node_hash, serialized_node) in &accessed_nodes {
                     if access_options.trie_access_tracker.track_mem_lookup(node_hash).is_none() {
@@ -1338,11 +1389,37 @@ impl Trie {
                     }
                 }
             }
-            mem_value
+            lookup
         } else {
-            lock.lookup(&self.root, key, None)?
+            lock.lookup_partial(&self.root, key, None)?
+        };
+        let (node_hash, remaining_key) = match lookup {
+            MemTrieLookup::Complete(mem_value) => return Ok(mem_value.map(map_result)),
+            MemTrieLookup::Unloaded { node_hash, remaining_key, .. } => (node_hash, remaining_key),
         };
-        Ok(mem_value.map(map_result))
+        drop(lock);
+
+        if !use_trie_accounting_cache && !access_options.enable_state_witness_recording {
+            if let Some(flat_storage_chunk_view) = &self.flat_storage_chunk_view {
+                let value = flat_storage_chunk_view.get_value(key)?;
+                return Ok(value.map(|value| match &value {
+                    FlatStateValue::Ref(value_ref) => map_result(ValueView::Ref {
+                        length: value_ref.length,
+                        hash: value_ref.hash,
+                    }),
+                    FlatStateValue::Inlined(value) => map_result(ValueView::Inlined(value)),
+                }));
+            }
+        }
+        let value_ref = self.lookup_from_state_column_at(
+            node_hash,
+            remaining_key,
+            use_trie_accounting_cache,
+            access_options,
+        )?;
+        Ok(value_ref.map(|value_ref| {
+            map_result(ValueView::Ref { length: value_ref.length, hash: value_ref.hash })
+        }))
     }
 
     /// For debugging only. Returns the raw node at the given path starting from the root.
@@ -1571,6 +1648,44 @@ impl Trie {
         changes: I,
         opts: AccessOptions,
     ) -> Result<TrieChanges, StorageError>
+    where
+        I: IntoIterator<Item = (Vec<u8>, Option<Vec<u8>>)>,
+    {
+        let memtries = self.memtries.as_ref().unwrap();
+        if !memtries.read().is_partial() {
+            return self.try_update_with_memtrie(changes, opts, &mut None);
+        }
+
+        // Partial memtries may not have all the nodes the update needs in
+        // memory. Load the paths of all changed keys first, then load any
+        // other node the update turns out to need, and retry. Recording the
+        // same nodes again on retries is harmless.
+        let changes = changes.into_iter().collect_vec();
+        MemTries::load_paths_unlocked(
+            memtries,
+            &self.root,
+            changes.iter().map(|(key, _)| key.as_slice()),
+        )?;
+        loop {
+            let mut unloaded_node = None;
+            let result =
+                self.try_update_with_memtrie(changes.iter().cloned(), opts, &mut unloaded_node);
+            match (result, unloaded_node) {
+                (Err(_), Some(stub)) => MemTries::load_stub_unlocked(memtries, stub)?,
+                (result, _) => return result,
+            }
+        }
+    }
+
+    /// Applies the changes using the memtries. If the update fails because
+    /// it needs a node of partial memtries which is not loaded, the stub of
+    /// the node is put into `unloaded_node`.
+    fn try_update_with_memtrie<I>(
+        &self,
+        changes: I,
+        opts: AccessOptions,
+        unloaded_node: &mut Option<MemTrieNodeId>,
+    ) -> Result<TrieChanges, StorageError>
     where
         I: IntoIterator<Item = (Vec<u8>, Option<Vec<u8>>)>,
     {
@@ -1604,7 +1719,7 @@ impl Trie {
 
         // Insert key, value into both the child memtries as well as the main memtrie
         for (key, value) in changes {
-            match value {
+            let result = match value {
                 Some(arr) => {
                     // Update all child memtries. This is a rare case where parent shard has forks
                     // at the resharding epoch boundary.
@@ -1612,7 +1727,7 @@ impl Trie {
                     for trie_update in &mut child_updates {
                         trie_update.1.insert(&key, arr.clone())?;
                     }
-                    trie_update.insert(&key, arr)?;
+                    trie_update.insert(&key, arr)
                 }
                 None => {
                     // Update all child memtries. This is a rare case where parent shard
@@ -1620,8 +1735,12 @@ impl Trie {
                     for trie_update in &mut child_updates {
                         trie_update.1.generic_delete(0, &key, opts)?;
                     }
-                    trie_update.generic_delete(0, &key, opts)?;
+                    trie_update.generic_delete(0, &key, opts)
                 }
+            };
+            if let Err(err) = result {
+                *unloaded_node = trie_update.unloaded_node();
+                return Err(err);
             }
         }
 
@@ -1772,10 +1891,24 @@ impl<'a> TrieWithReadLock<'a> {
     /// If memtries are present, returns an iterator that traverses the memtrie.
     /// Otherwise, it falls back to an iterator that traverses the on-disk trie.
     pub fn iter(&self) -> Result<TrieIterator<'_>, StorageError> {
+        self.iter_with_access_tracker(None)
+    }
+
+    /// Like [`Self::iter`], but the nodes and values read by the iterator are
+    /// reported to `access_tracker`, the same way as for the other trie
+    /// operations of the contract runtime.
+    pub fn iter_with_access_tracker<'b>(
+        &'b self,
+        access_tracker: Option<&'b dyn AccessTracker>,
+    ) -> Result<TrieIterator<'b>, StorageError> {
         match &self.memtries {
-            Some(memtries) => Ok(TrieIterator::Memtrie(memtries.get_iter(self.trie)?)),
-            None => Ok(TrieIterator::Disk(DiskTrieIterator::new(
-                DiskTrieIteratorInner::new(&self.trie),
+            // Partial memtries are iterated on disk, as most of their nodes
+            // are usually not loaded.
+            Some(memtries) if !memtries.is_partial() => {
+                Ok(TrieIterator::Memtrie(memtries.get_iter(self.trie, access_tracker)?))
+            }
+            _ => Ok(TrieIterator::Disk(DiskTrieIterator::new(
+                DiskTrieIteratorInner::new(&self.trie).with_access_tracker(access_tracker),
                 None,
             )?)),
         }
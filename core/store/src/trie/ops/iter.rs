@@ -116,6 +116,14 @@ where
         Ok(())
     }
 
+    /// Position the iterator on the first element with key >= `key`. Unlike
+    /// [`Self::seek_prefix`], the iteration is not limited to the keys having
+    /// `key` as a prefix and continues until the end of the trie.
+    pub fn seek<K: AsRef<[u8]>>(&mut self, key: K) -> Result<(), StorageError> {
+        self.seek_nibble_slice(NibbleSlice::new(key.as_ref()), false)?;
+        Ok(())
+    }
+
     /// Returns the hash of the last node.
     fn seek_nibble_slice(
         &mut self,
@@ -261,25 +269,10 @@ where
         };
         Some(step)
     }
-}
-
-#[derive(Debug)]
-enum IterStep<N, V> {
-    Continue,
-    PopTrail,
-    Descend(N),
-    Value(V),
-}
 
-impl<N, V, I> Iterator for TrieIteratorImpl<N, V, I>
-where
-    N: Copy,
-    V: Clone,
-    I: GenericTrieInternalStorage<N, V>,
-{
-    type Item = Result<TrieItem, StorageError>;
-
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Advances the iterator to the next node holding a value and returns the
+    /// reference to that value. The key is available through `Self::key`.
+    fn next_value_ref(&mut self) -> Option<Result<V, StorageError>> {
         loop {
             let iter_step = self.iter_step()?;
 
@@ -299,13 +292,40 @@ where
                     Ok(_) => {}
                     Err(e) => return Some(Err(e)),
                 },
-                (IterStep::Value(value_ref), false) => {
-                    let value = self.trie_interface.get_and_record_value(value_ref);
-                    return Some(value.map(|value| (self.key(), value)));
-                }
+                (IterStep::Value(value_ref), false) => return Some(Ok(value_ref)),
             }
         }
     }
+
+    /// Like `next`, but returns only the key. The value is neither read nor
+    /// recorded, only the nodes visited to find the key are.
+    pub fn next_key(&mut self) -> Option<Result<Vec<u8>, StorageError>> {
+        Some(self.next_value_ref()?.map(|_| self.key()))
+    }
+}
+
+#[derive(Debug)]
+enum IterStep<N, V> {
+    Continue,
+    PopTrail,
+    Descend(N),
+    Value(V),
+}
+
+impl<N, V, I> Iterator for TrieIteratorImpl<N, V, I>
+where
+    N: Copy,
+    V: Clone,
+    I: GenericTrieInternalStorage<N, V>,
+{
+    type Item = Result<TrieItem, StorageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self
+            .next_value_ref()?
+            .and_then(|value_ref| self.trie_interface.get_and_record_value(value_ref));
+        Some(value.map(|value| (self.key(), value)))
+    }
 }
 
 /// *************************************************************************************************
@@ -3,9 +3,10 @@ use super::mem::memtries::MemTries;
 use super::state_snapshot::{StateSnapshot, StateSnapshotConfig};
 use crate::adapter::StoreAdapter;
 use crate::adapter::trie_store::{TrieStoreAdapter, TrieStoreUpdateAdapter};
-use crate::flat::FlatStorageManager;
+use crate::flat::{FlatStorageChunkView, FlatStorageManager};
 use crate::trie::TrieRefcountAddition;
 use crate::trie::config::TrieConfig;
+use crate::trie::mem::image::save_memtrie_image;
 use crate::trie::mem::loading::load_trie_from_flat_state_and_delta;
 use crate::trie::prefetching_trie_storage::PrefetchingThreadsHandle;
 use crate::trie::trie_storage::{TrieCache, TrieCachingStorage};
@@ -127,7 +128,8 @@ impl ShardTries {
     }
 
     fn trie_cache_enabled(&self, shard_uid: ShardUId, is_view: bool) -> bool {
-        is_view || self.get_memtries(shard_uid).is_none()
+        // Partial memtries read the nodes which are not loaded from disk.
+        is_view || self.get_memtries(shard_uid).is_none_or(|memtries| memtries.read().is_partial())
     }
 
     fn get_trie_for_shard_internal(
@@ -135,7 +137,7 @@ impl ShardTries {
         shard_uid: ShardUId,
         state_root: StateRoot,
         is_view: bool,
-        block_hash: Option<CryptoHash>,
+        flat_storage_chunk_view: Option<FlatStorageChunkView>,
     ) -> Trie {
         let storage: Arc<dyn TrieStorage> =
             if let Some(cache) = self.get_trie_cache_for(shard_uid, is_view) {
@@ -143,8 +145,6 @@ impl ShardTries {
             } else {
                 Arc::new(TrieDBStorage::new(self.0.store.clone(), shard_uid))
             };
-        let flat_storage_chunk_view = block_hash
-            .and_then(|block_hash| self.0.flat_storage_manager.chunk_view(shard_uid, block_hash));
         // Do not use memtries for view queries, for two reasons: memtries do not provide historical state,
         // and also this can introduce lock contention on memtries.
         if is_view {
@@ -187,13 +187,31 @@ impl ShardTries {
         block_hash: &CryptoHash,
         is_view: bool,
     ) -> Trie {
-        self.get_trie_for_shard_internal(shard_uid, state_root, is_view, Some(*block_hash))
+        let flat_storage_chunk_view =
+            self.0.flat_storage_manager.chunk_view(shard_uid, *block_hash);
+        self.get_trie_for_shard_internal(shard_uid, state_root, is_view, flat_storage_chunk_view)
     }
 
     pub fn get_view_trie_for_shard(&self, shard_uid: ShardUId, state_root: StateRoot) -> Trie {
         self.get_trie_for_shard_internal(shard_uid, state_root, true, None)
     }
 
+    /// Returns a view trie for the state of the canonical block `block_hash`
+    /// at `block_height`.  Values are read from flat storage if it keeps the
+    /// history of flat state and can provide this state, see
+    /// `FlatStorageManager::chunk_view_at_height`.
+    pub fn get_view_trie_at_height(
+        &self,
+        shard_uid: ShardUId,
+        state_root: StateRoot,
+        block_hash: &CryptoHash,
+        block_height: BlockHeight,
+    ) -> Trie {
+        let flat_storage_chunk_view =
+            self.0.flat_storage_manager.chunk_view_at_height(shard_uid, *block_hash, block_height);
+        self.get_trie_for_shard_internal(shard_uid, state_root, true, flat_storage_chunk_view)
+    }
+
     pub fn store_update(&self) -> TrieStoreUpdateAdapter<'static> {
         self.0.store.store_update()
     }
@@ -440,18 +458,48 @@ impl ShardTries {
     }
 
     /// Loads in-memory-trie for given shard and state root (if given).
+    /// The trie is partial if a memory budget is configured.
     pub fn load_memtrie(
         &self,
         shard_uid: &ShardUId,
         state_root: Option<StateRoot>,
         parallelize: bool,
     ) -> Result<(), StorageError> {
-        tracing::info!(target: "memtrie", "Loading trie to memory for shard {:?}...", shard_uid);
+        let memory_budget = self.0.trie_config.memtrie_memory_budget(*shard_uid);
+        self.load_memtrie_impl(shard_uid, state_root, parallelize, memory_budget)
+    }
+
+    /// Loads the memtrie of a shard, depending on whether it is pending
+    /// resharding: resharding requires the whole trie in memory.
+    fn load_memtrie_for_shard(
+        &self,
+        shard_uid: &ShardUId,
+        state_root: Option<StateRoot>,
+        parallelize: bool,
+        shard_uids_pending_resharding: &HashSet<ShardUId>,
+    ) -> Result<(), StorageError> {
+        if shard_uids_pending_resharding.contains(shard_uid) {
+            self.load_memtrie_impl(shard_uid, state_root, parallelize, None)
+        } else {
+            self.load_memtrie(shard_uid, state_root, parallelize)
+        }
+    }
+
+    fn load_memtrie_impl(
+        &self,
+        shard_uid: &ShardUId,
+        state_root: Option<StateRoot>,
+        parallelize: bool,
+        memory_budget: Option<usize>,
+    ) -> Result<(), StorageError> {
+        tracing::info!(target: "memtrie", ?memory_budget, "Loading trie to memory for shard {:?}...", shard_uid);
         let memtries = load_trie_from_flat_state_and_delta(
             &self.0.store.store(),
             *shard_uid,
             state_root,
             parallelize,
+            self.0.trie_config.memtrie_image_dir.as_deref(),
+            memory_budget,
         )?;
         self.0.memtries.write().insert(*shard_uid, Arc::new(RwLock::new(memtries)));
         tracing::info!(target: "memtrie", "Memtrie loading complete for shard {:?}", shard_uid);
@@ -475,7 +523,12 @@ impl ShardTries {
         // It should not happen that memtrie is already loaded for a shard
         // for which we just did state sync.
         debug_assert!(!self.0.memtries.read().contains_key(shard_uid));
-        self.load_memtrie(shard_uid, Some(*state_root), false)
+        self.load_memtrie_for_shard(
+            shard_uid,
+            Some(*state_root),
+            false,
+            shard_uids_pending_resharding,
+        )
     }
 
     /// Loads in-memory tries upon startup. The given shard_uids are possible candidates to load,
@@ -513,13 +566,47 @@ impl ShardTries {
         tracing::info!(target: "memtrie", "Loading tries to memory for shards {:?}...", shard_uids_to_load);
         shard_uids_to_load
             .par_iter()
-            .map(|shard_uid| self.load_memtrie(shard_uid, None, parallelize))
+            .map(|shard_uid| {
+                self.load_memtrie_for_shard(
+                    shard_uid,
+                    None,
+                    parallelize,
+                    shard_uids_pending_resharding,
+                )
+            })
             .collect::<Result<(), StorageError>>()?;
 
         tracing::info!(target: "memtrie", "Memtries loading complete for shards {:?}", shard_uids_to_load);
         Ok(())
     }
 
+    /// Saves images of all loaded in-memory tries, so that they can be quickly
+    /// loaded on the next start. Does nothing unless memtrie images are enabled.
+    ///
+    /// Updates of the tries are blocked while their images are being written,
+    /// so this is only called when the node shuts down.
+    pub fn save_memtrie_images(&self) {
+        let Some(image_dir) = &self.0.trie_config.memtrie_image_dir else {
+            return;
+        };
+        let memtries = self.0.memtries.read().clone();
+        for (shard_uid, memtries) in memtries {
+            let memtries = memtries.read();
+            if memtries.is_partial() {
+                continue;
+            }
+            let start = std::time::Instant::now();
+            match save_memtrie_image(image_dir, &memtries) {
+                Ok(()) => {
+                    tracing::info!(target: "memtrie", %shard_uid, elapsed = ?start.elapsed(), "Saved memtrie image")
+                }
+                Err(err) => {
+                    tracing::warn!(target: "memtrie", %shard_uid, ?err, "Failed to save memtrie image")
+                }
+            }
+        }
+    }
+
     /// Retrieves the in-memory tries for the shard.
     pub fn get_memtries(&self, shard_uid: ShardUId) -> Option<Arc<RwLock<MemTries>>> {
         let guard = self.0.memtries.read();
@@ -531,6 +618,7 @@ impl ShardTries {
     pub fn delete_memtrie_roots_up_to_height(&self, shard_uid: ShardUId, height: BlockHeight) {
         if let Some(memtries) = self.get_memtries(shard_uid) {
             memtries.write().delete_until_height(height);
+            MemTries::load_pending_subtrees(&memtries);
         }
     }
 
@@ -564,6 +652,14 @@ impl ShardTries {
             )));
         };
         let mut guard = memtries.write();
+        if guard.is_partial() {
+            drop(guard);
+            outer_guard.insert(parent_shard_uid, memtries);
+            return Err(StorageError::MemTrieLoadingError(format!(
+                "On freezing parent memtrie, memtrie of shard {:?} is partial",
+                parent_shard_uid
+            )));
+        }
         let memtries = std::mem::replace(&mut *guard, MemTries::new(parent_shard_uid));
         let frozen_memtries = memtries.freeze();
 
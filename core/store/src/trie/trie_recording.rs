@@ -301,6 +301,7 @@ mod trie_recording_tests {
     use crate::trie::{AccessOptions, AccessTracker, TrieNodesCount};
     use crate::{DBCol, KeyLookupMode, PartialStorage, ShardTries, Store, Trie};
     use borsh::BorshDeserialize;
+    use bytesize::ByteSize;
     use near_primitives::bandwidth_scheduler::BandwidthRequests;
     use near_primitives::congestion_info::CongestionInfo;
     use near_primitives::hash::{CryptoHash, hash};
@@ -698,6 +699,88 @@ mod trie_recording_tests {
         }
     }
 
+    /// Verifies that partial memtries, which continue lookups of nodes that
+    /// are not loaded on disk and load the nodes that updates need, give the
+    /// same node counts, trie changes and recorded storage as complete
+    /// memtries.
+    fn test_partial_memtrie_consistency(use_flat_storage: bool) {
+        for _ in 0..NUM_ITERATIONS_PER_TEST {
+            let p_existing_key = thread_rng().gen_range(0.3..1.0);
+            let p_missing_key = thread_rng().gen_range(0.7..1.0);
+            let PreparedTrie {
+                store,
+                shard_uid,
+                data_in_trie,
+                keys_to_get,
+                keys_to_get_ref,
+                updates,
+                state_root,
+            } = prepare_trie(true, p_existing_key, p_missing_key);
+            let lookup_mode = if use_flat_storage {
+                KeyLookupMode::MemOrFlatOrTrie
+            } else {
+                KeyLookupMode::MemOrTrie
+            };
+
+            let mut results = Vec::new();
+            for memory_budget in [None, Some(ByteSize::b(2000))] {
+                let mut builder =
+                    TestTriesBuilder::new().with_store(store.clone()).with_flat_storage(true);
+                if let Some(memory_budget) = memory_budget {
+                    builder = builder.with_memtrie_memory_budget(memory_budget);
+                }
+                let tries = builder.build();
+                tries.load_memtrie(&shard_uid, None, false).unwrap();
+                assert_eq!(
+                    tries.get_memtries(shard_uid).unwrap().read().is_partial(),
+                    memory_budget.is_some()
+                );
+
+                let tracker = AccountingAccessTracker::default();
+                let opts =
+                    AccessOptions { trie_access_tracker: &tracker, ..AccessOptions::DEFAULT };
+                let trie = get_trie_for_shard(&tries, shard_uid, state_root, use_flat_storage)
+                    .recording_reads_new_recorder();
+                for key in &keys_to_get {
+                    assert_eq!(trie.get(key, opts).unwrap(), data_in_trie.get(key).cloned());
+                }
+                for key in &keys_to_get_ref {
+                    assert_eq!(
+                        trie.get_optimized_ref(key, lookup_mode, opts)
+                            .unwrap()
+                            .map(|value| value.into_value_ref()),
+                        data_in_trie.get(key).map(|value| ValueRef::new(&value))
+                    );
+                }
+                let trie_changes = trie.update(updates.iter().cloned(), opts).unwrap();
+                results.push((
+                    tracker.get_trie_nodes_count(),
+                    trie_changes,
+                    trie.recorded_storage().unwrap(),
+                ));
+            }
+
+            let (partial_count, partial_changes, partial_storage) = results.pop().unwrap();
+            let (count, changes, storage) = results.pop().unwrap();
+            assert_eq!(partial_count, count);
+            assert_eq!(partial_changes.old_root, changes.old_root);
+            assert_eq!(partial_changes.new_root, changes.new_root);
+            assert_eq!(partial_changes.insertions(), changes.insertions());
+            assert_eq!(partial_changes.deletions(), changes.deletions());
+            assert_partial_storage(&storage, &partial_storage);
+        }
+    }
+
+    #[test]
+    fn test_partial_memtrie_consistency_no_flat_storage() {
+        test_partial_memtrie_consistency(false);
+    }
+
+    #[test]
+    fn test_partial_memtrie_consistency_with_flat_storage() {
+        test_partial_memtrie_consistency(true);
+    }
+
     #[test]
     fn test_trie_recording_consistency_no_accounting_cache() {
         test_trie_recording_consistency(false, false, false);
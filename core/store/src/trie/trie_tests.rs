@@ -141,6 +141,25 @@ fn test_reads_with_incomplete_storage() {
             };
             test_incomplete_storage(get_trie(), trie_update_keys);
         }
+        {
+            let (key, _) = trie_changes.choose(&mut rng).unwrap();
+            let key_prefix = &key[0..rng.gen_range(0..key.len() + 1)];
+            let (start, _) = trie_changes.choose(&mut rng).unwrap();
+            let limit = rng.gen_range(0..5);
+            println!("Testing TrieUpdateIterator over prefix {:?} from {:?}", key_prefix, start);
+            let trie_update_keys = |trie: Trie| -> Result<_, StorageError> {
+                let trie_update = TrieUpdate::new(trie);
+                let keys = {
+                    let lock = trie_update.trie().lock_for_iter();
+                    trie_update
+                        .locked_iter_from(key_prefix, start, &lock, None)?
+                        .take(limit)
+                        .collect::<Result<Vec<_>, _>>()?
+                };
+                Ok((trie_update.trie, keys))
+            };
+            test_incomplete_storage(get_trie(), trie_update_keys);
+        }
     }
 }
 
@@ -1,5 +1,5 @@
 pub use self::iterator::TrieUpdateIterator;
-use super::{AccessOptions, OptimizedValueRef, Trie, TrieWithReadLock};
+use super::{AccessOptions, AccessTracker, OptimizedValueRef, Trie, TrieWithReadLock};
 use crate::StorageError;
 use crate::contract::ContractStorage;
 use crate::trie::TrieAccess;
@@ -283,6 +283,19 @@ impl TrieUpdate {
         TrieUpdateIterator::new(self, key_prefix, Some(lock))
     }
 
+    /// Like [`Self::locked_iter`], but skips the keys smaller than `start`.
+    /// The values are not read from the trie, and the nodes visited to find
+    /// the keys are reported to `access_tracker`.
+    pub fn locked_iter_from<'a>(
+        &'a self,
+        key_prefix: &[u8],
+        start: &[u8],
+        lock: &'a TrieWithReadLock<'_>,
+        access_tracker: Option<&'a dyn AccessTracker>,
+    ) -> Result<TrieUpdateIterator<'a>, StorageError> {
+        TrieUpdateIterator::new_from(self, key_prefix, start, lock, access_tracker)
+    }
+
     pub fn get_root(&self) -> &StateRoot {
         self.trie.get_root()
     }
@@ -422,6 +435,7 @@ mod tests {
     use crate::test_utils::TestTriesBuilder;
     use near_primitives::hash::CryptoHash;
     use near_primitives::shard_layout::ShardLayout;
+    use near_primitives::state::PartialState;
     const SHARD_VERSION: u32 = 1;
 
     fn test_key(key: Vec<u8>) -> TrieKey {
@@ -575,4 +589,102 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn trie_iter_from() {
+        let tries = TestTriesBuilder::new().build();
+        let mut trie_update = tries.new_trie_update(ShardUId::single_shard(), Trie::EMPTY_ROOT);
+        trie_update.set(test_key(b"dog".to_vec()), b"puppy".to_vec());
+        trie_update.set(test_key(b"dog1".to_vec()), b"puppy".to_vec());
+        trie_update.set(test_key(b"dog3".to_vec()), b"puppy".to_vec());
+        trie_update.set(test_key(b"xxx".to_vec()), b"puppy".to_vec());
+        trie_update
+            .commit(StateChangeCause::TransactionProcessing { tx_hash: CryptoHash::default() });
+        let trie_changes = trie_update.finalize().unwrap().trie_changes;
+        let mut store_update = tries.store_update();
+        let new_root = tries.apply_all(&trie_changes, ShardUId::single_shard(), &mut store_update);
+        store_update.commit().unwrap();
+
+        let mut trie_update = tries.new_trie_update(ShardUId::single_shard(), new_root);
+        trie_update.set(test_key(b"dog2".to_vec()), b"puppy".to_vec());
+        trie_update.remove(test_key(b"dog3".to_vec()));
+        trie_update.set(test_key(b"dog4".to_vec()), b"puppy".to_vec());
+
+        let prefix = test_key(b"dog".to_vec()).to_vec();
+        let lock = trie_update.trie().lock_for_iter();
+        let keys = |start: &[u8]| -> Vec<Vec<u8>> {
+            let start = test_key(start.to_vec()).to_vec();
+            trie_update
+                .locked_iter_from(&prefix, &start, &lock, None)
+                .unwrap()
+                .map(Result::unwrap)
+                .collect()
+        };
+        assert_eq!(
+            keys(b"dog1"),
+            vec![
+                test_key(b"dog1".to_vec()).to_vec(),
+                test_key(b"dog2".to_vec()).to_vec(),
+                test_key(b"dog4".to_vec()).to_vec()
+            ]
+        );
+        assert_eq!(keys(b"dog20"), vec![test_key(b"dog4".to_vec()).to_vec()]);
+        assert_eq!(keys(b"dog5"), Vec::<Vec<u8>>::new());
+        assert_eq!(keys(b"zzz"), Vec::<Vec<u8>>::new());
+        assert_eq!(keys(b"a").len(), 4);
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingAccessTracker {
+        db_reads: std::cell::Cell<u64>,
+    }
+
+    impl AccessTracker for CountingAccessTracker {
+        fn track_mem_lookup(&self, _: &CryptoHash) -> Option<std::sync::Arc<[u8]>> {
+            None
+        }
+
+        fn track_disk_lookup(&self, _: CryptoHash, _: std::sync::Arc<[u8]>) {
+            self.db_reads.set(self.db_reads.get() + 1);
+        }
+    }
+
+    /// Checks that iterating from a key doesn't read the values, but reports
+    /// the visited nodes to the access tracker and records them.
+    #[test]
+    fn trie_iter_from_keys_only() {
+        let tries = TestTriesBuilder::new().build();
+        let mut trie_update = tries.new_trie_update(ShardUId::single_shard(), Trie::EMPTY_ROOT);
+        for key in [b"dog".as_slice(), b"dog1", b"dog2", b"xxx"] {
+            trie_update.set(test_key(key.to_vec()), b"puppy".to_vec());
+        }
+        trie_update
+            .commit(StateChangeCause::TransactionProcessing { tx_hash: CryptoHash::default() });
+        let trie_changes = trie_update.finalize().unwrap().trie_changes;
+        let mut store_update = tries.store_update();
+        let new_root = tries.apply_all(&trie_changes, ShardUId::single_shard(), &mut store_update);
+        store_update.commit().unwrap();
+
+        let trie = tries
+            .get_trie_for_shard(ShardUId::single_shard(), new_root)
+            .recording_reads_new_recorder();
+        let trie_update = TrieUpdate::new(trie);
+        let access_tracker = CountingAccessTracker::default();
+        let prefix = test_key(b"dog".to_vec()).to_vec();
+        let keys = {
+            let lock = trie_update.trie().lock_for_iter();
+            trie_update
+                .locked_iter_from(&prefix, &prefix, &lock, Some(&access_tracker))
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+        assert_eq!(keys.len(), 3);
+
+        let PartialState::TrieValues(recorded) =
+            trie_update.trie().recorded_storage().unwrap().nodes;
+        assert!(!recorded.is_empty());
+        assert!(recorded.iter().all(|node| &node[..] != b"puppy"));
+        assert!(access_tracker.db_reads.get() >= recorded.len() as u64);
+    }
 }
@@ -2,7 +2,7 @@ use std::iter::Peekable;
 use std::ops::Bound;
 
 use crate::StorageError;
-use crate::trie::{TrieWithReadLock, update::*};
+use crate::trie::{AccessTracker, TrieWithReadLock, update::*};
 
 use crate::trie::TrieIterator;
 
@@ -35,7 +35,33 @@ impl<'a> Iterator for MergeIter<'a> {
     }
 }
 
-pub struct TrieUpdateIterator<'a>(Option<(Peekable<TrieIterator<'a>>, Peekable<MergeIter<'a>>)>);
+/// Iterator over the keys of the trie.
+struct TrieKeys<'a> {
+    iter: TrieIterator<'a>,
+    /// If set, the values are not read, so they are neither charged for nor
+    /// recorded in the state witness.
+    keys_only: bool,
+}
+
+impl<'a> Iterator for TrieKeys<'a> {
+    type Item = Result<Vec<u8>, StorageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.keys_only {
+            self.iter.next_key()
+        } else {
+            self.iter.next().map(|item| item.map(|(key, _)| key))
+        }
+    }
+}
+
+pub struct TrieUpdateIterator<'a> {
+    iterators: Option<(Peekable<TrieKeys<'a>>, Peekable<MergeIter<'a>>)>,
+    /// Only the keys starting with the prefix are returned. Needed because the
+    /// trie iterator is not bounded by the prefix when it starts from a key
+    /// other than the prefix itself.
+    prefix: Vec<u8>,
+}
 
 impl<'a> TrieUpdateIterator<'a> {
     #![allow(clippy::new_ret_no_self)]
@@ -44,19 +70,53 @@ impl<'a> TrieUpdateIterator<'a> {
         prefix: &[u8],
         lock: Option<&'a TrieWithReadLock<'_>>,
     ) -> Result<Self, StorageError> {
-        let mut trie_iter = match lock {
+        let iter = match lock {
             Some(lock) => lock.iter()?,
             None => TrieIterator::Disk(state_update.trie.disk_iter()?),
         };
-        trie_iter.seek_prefix(prefix)?;
+        Self::new_impl(state_update, prefix, prefix, TrieKeys { iter, keys_only: false })
+    }
 
+    /// Iterates over the keys starting with `prefix` which are not smaller
+    /// than `start`. Unlike [`Self::new`], the values stored in the trie are
+    /// not read and the nodes visited to find the keys are reported to
+    /// `access_tracker`.
+    pub fn new_from(
+        state_update: &'a TrieUpdate,
+        prefix: &[u8],
+        start: &[u8],
+        lock: &'a TrieWithReadLock<'_>,
+        access_tracker: Option<&'a dyn AccessTracker>,
+    ) -> Result<Self, StorageError> {
+        let iter = lock.iter_with_access_tracker(access_tracker)?;
+        Self::new_impl(state_update, prefix, start, TrieKeys { iter, keys_only: true })
+    }
+
+    fn new_impl(
+        state_update: &'a TrieUpdate,
+        prefix: &[u8],
+        start: &[u8],
+        mut trie_keys: TrieKeys<'a>,
+    ) -> Result<Self, StorageError> {
         let end_bound = make_prefix_range_end_bound(prefix);
+        // Clamping keeps the range valid, a start past the prefix keys yields
+        // an empty iterator.
+        let mut start = std::cmp::max(prefix, start);
+        if let Some(end_bound) = &end_bound {
+            start = std::cmp::min(start, end_bound.as_slice());
+        }
+        if start == prefix {
+            trie_keys.iter.seek_prefix(prefix)?;
+        } else {
+            trie_keys.iter.seek(start)?;
+        }
+
         let end_bound = if let Some(end_bound) = &end_bound {
             Bound::Excluded(end_bound.as_slice())
         } else {
             Bound::Unbounded
         };
-        let range = (Bound::Included(prefix), end_bound);
+        let range = (Bound::Included(start), end_bound);
 
         let committed_iter = state_update.committed.range::<[u8], _>(range).map(
             |(raw_key, changes_with_trie_key)| {
@@ -80,7 +140,10 @@ impl<'a> TrieUpdateIterator<'a> {
             right: (Box::new(prospective_iter) as Box<dyn Iterator<Item = _>>).peekable(),
         }
         .peekable();
-        Ok(TrieUpdateIterator(Some((trie_iter.peekable(), overlay_iter))))
+        Ok(TrieUpdateIterator {
+            iterators: Some((trie_keys.peekable(), overlay_iter)),
+            prefix: prefix.to_vec(),
+        })
     }
 }
 
@@ -95,16 +158,22 @@ impl<'a> Iterator for TrieUpdateIterator<'a> {
             Both,
         }
         // Usually one iteration, unless need to skip None values in prospective / committed.
-        let iterators = self.0.as_mut()?;
+        let Self { iterators: state, prefix } = self;
+        let prefix = prefix.as_slice();
+        let iterators = state.as_mut()?;
         loop {
-            let res = match (iterators.0.peek(), iterators.1.peek()) {
+            let trie_peek = iterators.0.peek().filter(|item| match item {
+                Ok(key) => key.starts_with(prefix),
+                Err(_) => true,
+            });
+            let res = match (trie_peek, iterators.1.peek()) {
                 (Some(Err(_)), _) => {
                     let err = iterators.0.next().unwrap().unwrap_err();
-                    self.0 = None;
+                    *state = None;
                     return Some(Err(err));
                 }
 
-                (Some(Ok((left_key, _))), Some((right_key, _))) => {
+                (Some(Ok(left_key)), Some((right_key, _))) => {
                     match left_key.as_slice().cmp(right_key) {
                         std::cmp::Ordering::Less => Ordering::Trie,
                         std::cmp::Ordering::Equal => Ordering::Both,
@@ -114,7 +183,7 @@ impl<'a> Iterator for TrieUpdateIterator<'a> {
                 (Some(_), None) => Ordering::Trie,
                 (None, Some(_)) => Ordering::Overlay,
                 (None, None) => {
-                    self.0 = None;
+                    *state = None;
                     return None;
                 }
             };
@@ -124,11 +193,11 @@ impl<'a> Iterator for TrieUpdateIterator<'a> {
             // delete the value.
             let trie_item = if res != Ordering::Overlay { iterators.0.next() } else { None };
             if res == Ordering::Trie {
-                if let Some(Ok((key, _))) = trie_item {
+                if let Some(Ok(key)) = trie_item {
                     return Some(Ok(key));
                 }
             } else if let Some((overlay_key, Some(_))) = iterators.1.next() {
-                return Some(Ok(if let Some(Ok((trie_key, _))) = trie_item {
+                return Some(Ok(if let Some(Ok(trie_key)) = trie_item {
                     debug_assert_eq!(trie_key.as_slice(), overlay_key);
                     trie_key
                 } else {
@@ -1,4 +1,5 @@
 use crate::adapter::{StoreAdapter, StoreUpdateAdapter};
+use crate::config::MemTrieMemoryBudget;
 use crate::db::TestDB;
 use crate::flat::{BlockInfo, FlatStorageManager, FlatStorageReadyStatus, FlatStorageStatus};
 use crate::metadata::{DB_VERSION, DbKind, DbVersion};
@@ -80,6 +81,7 @@ pub struct TestTriesBuilder {
     shard_layout: ShardLayout,
     enable_flat_storage: bool,
     enable_in_memory_tries: bool,
+    memtrie_memory_budget: Option<MemTrieMemoryBudget>,
 }
 
 impl TestTriesBuilder {
@@ -89,6 +91,7 @@ impl TestTriesBuilder {
             shard_layout: ShardLayout::single_shard(),
             enable_flat_storage: false,
             enable_in_memory_tries: false,
+            memtrie_memory_budget: None,
         }
     }
 
@@ -112,6 +115,13 @@ impl TestTriesBuilder {
         self
     }
 
+    /// Makes the memtries partial, with the given memory budget per shard.
+    pub fn with_memtrie_memory_budget(mut self, max_bytes: bytesize::ByteSize) -> Self {
+        self.memtrie_memory_budget =
+            Some(MemTrieMemoryBudget { default_max_bytes: max_bytes, ..Default::default() });
+        self
+    }
+
     pub fn build2(self) -> (ShardTries, ShardLayout) {
         let shard_layout = self.shard_layout.clone();
         let shard_tries = self.build();
@@ -129,6 +139,7 @@ impl TestTriesBuilder {
             store.trie_store(),
             TrieConfig {
                 load_memtries_for_tracked_shards: self.enable_in_memory_tries,
+                memtrie_memory_budget: self.memtrie_memory_budget,
                 ..Default::default()
             },
             &shard_uids,
@@ -573,6 +573,7 @@ fn process_peer_manager_message_default(
                 connection_established_time: near_async::time::Instant::now(),
                 peer_type: PeerType::Outbound,
                 nonce: 3,
+                reputation_score: 0,
             })
             .collect();
         let peers2 = peers.iter().filter_map(|it| it.full_peer_info.clone().into()).collect();
@@ -708,7 +709,7 @@ fn process_peer_manager_message_default(
                 }
             }
         }
-        NetworkRequests::BlockHeadersRequest { hashes, peer_id } => {
+        NetworkRequests::BlockHeadersRequest { hashes, peer_id, .. } => {
             for (i, peer_info) in key_pairs.iter().enumerate() {
                 let peer_id = peer_id.clone();
                 if peer_info.id == peer_id {
@@ -1249,6 +1250,7 @@ pub fn setup_synchronous_shards_manager(
                 ReshardingConfig::default(),
                 "resharding_config",
             ),
+            index_contract_events: false,
         }, // irrelevant
         None,
         Arc::new(RayonAsyncComputationSpawner),
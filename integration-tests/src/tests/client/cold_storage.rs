@@ -1,27 +1,43 @@
+use assert_matches::assert_matches;
 use borsh::BorshDeserialize;
-use near_chain::Provenance;
-use near_chain_configs::{Genesis, MutableConfigValue};
-use near_client::ProcessTxResponse;
+use near_async::messaging::{Handler, IntoMultiSender, noop};
+use near_async::time::Clock;
+use near_chain::{ChainGenesis, Provenance};
+use near_chain_configs::{
+    ClientConfig, DEFAULT_GC_NUM_EPOCHS_TO_KEEP, Genesis, MutableConfigValue, TrackedShardsConfig,
+};
+use near_client::adversarial::Controls;
+use near_client::{
+    GetStateChanges, ProcessTxResponse, TxStatus, TxStatusError, ViewClientActorInner,
+};
 use near_crypto::{InMemorySigner, KeyType, Signer};
 use near_epoch_manager::EpochManager;
+use near_epoch_manager::shard_tracker::ShardTracker;
 use near_o11y::testonly::init_test_logger;
 use near_primitives::block::Tip;
 use near_primitives::sharding::ShardChunk;
 use near_primitives::transaction::{
-    Action, DeployContractAction, FunctionCallAction, SignedTransaction,
+    Action, DeployContractAction, ExecutionOutcomeWithProof, FunctionCallAction, SignedTransaction,
+};
+use near_primitives::types::RawStateChangesWithTrieKey;
+use near_primitives::views::{
+    FinalExecutionOutcomeViewEnum, FinalExecutionStatus, StateChangeValueView,
+    StateChangesRequestView,
 };
 use near_primitives_core::hash::CryptoHash;
 use near_primitives_core::types::AccountId;
 use near_store::archive::cold_storage::{
     copy_all_data_to_cold, test_cold_genesis_update, test_get_store_initial_writes,
-    test_get_store_reads, update_cold_db, update_cold_head,
+    test_get_store_reads, update_cold_db, update_cold_db_for_accounts, update_cold_head,
 };
 use near_store::db::metadata::{DB_VERSION, DbKind};
+use near_store::db::{FilesystemDB, TestDB};
 use near_store::test_utils::create_test_node_storage_with_cold;
-use near_store::{COLD_HEAD_KEY, DBCol, HEAD_KEY, Store};
-use nearcore::{NearConfig, cold_storage::spawn_cold_store_loop};
+use near_store::{COLD_HEAD_KEY, DBCol, FINAL_HEAD_KEY, HEAD_KEY, NodeStorage, Store};
+use nearcore::{NearConfig, NightshadeRuntime, cold_storage::spawn_cold_store_loop};
 use std::collections::HashSet;
 use std::str::FromStr;
+use std::sync::Arc;
 use strum::IntoEnumIterator;
 
 use crate::env::nightshade_setup::TestEnvNightshadeSetupExt;
@@ -345,6 +361,246 @@ fn test_cold_db_copy_with_height_skips() {
     }
 }
 
+/// Sending money from test0 to both test1 and test2 and copying to the cold
+/// storage only the history of test1.  Checks that only the data touching
+/// test1 ends up in the cold storage.
+#[test]
+fn test_cold_db_update_for_accounts() {
+    init_test_logger();
+
+    let epoch_length = 5;
+    let max_height = epoch_length * 4;
+    let test2: AccountId = "test2".parse().unwrap();
+
+    let mut genesis = Genesis::test(vec![test0(), test1(), test2.clone()], 1);
+    genesis.config.epoch_length = epoch_length;
+    genesis.config.min_gas_price = 0;
+    let mut env = TestEnv::builder(&genesis.config).nightshade_runtimes(&genesis).build();
+
+    let (storage, ..) = create_test_node_storage_with_cold(DB_VERSION, DbKind::Hot);
+    let cold_db = storage.cold_db().unwrap();
+    let accounts = HashSet::from([test1()]);
+
+    let mut last_hash = *env.clients[0].chain.genesis().hash();
+    for height in 1..max_height {
+        let signer = InMemorySigner::test_signer(&test0());
+        // Same as in `test_storage_after_commit_of_cold_update`, no transactions
+        // in the last two blocks.
+        if height + 2 < max_height {
+            for (i, receiver) in [test1(), test2.clone()].into_iter().enumerate() {
+                let tx = SignedTransaction::send_money(
+                    height * 10 + i as u64,
+                    test0(),
+                    receiver,
+                    &signer,
+                    1,
+                    last_hash,
+                );
+                assert_eq!(
+                    env.rpc_handlers[0].process_tx(tx, false, false),
+                    ProcessTxResponse::ValidTx
+                );
+            }
+        }
+
+        let block = env.clients[0].produce_block(height).unwrap().unwrap();
+        env.process_block(0, block.clone(), Provenance::PRODUCED);
+
+        let client = &env.clients[0];
+        let client_store = client.runtime_adapter.store();
+        let epoch_id = client.epoch_manager.get_epoch_id(block.hash()).unwrap();
+        let shard_layout = client.epoch_manager.get_shard_layout(&epoch_id).unwrap();
+        let is_last_block_in_epoch =
+            client.epoch_manager.is_next_block_epoch_start(block.hash()).unwrap();
+        update_cold_db_for_accounts(
+            cold_db,
+            &client_store,
+            &shard_layout,
+            &height,
+            is_last_block_in_epoch,
+            &accounts,
+        )
+        .unwrap();
+
+        last_hash = *block.hash();
+    }
+
+    let client_store = env.clients[0].runtime_adapter.store();
+    let cold_store = storage.get_cold_store().unwrap();
+
+    let mut num_tracked_txs = 0;
+    for (key, value) in client_store.iter(DBCol::Transactions).map(Result::unwrap) {
+        let tx = SignedTransaction::try_from_slice(&value).unwrap();
+        let tracked = tx.transaction.receiver_id() == &test1();
+        num_tracked_txs += tracked as usize;
+        assert_eq!(cold_store.exists(DBCol::Transactions, &key).unwrap(), tracked);
+    }
+    assert!(num_tracked_txs > 0);
+
+    let mut num_outcomes = 0;
+    for (key, value) in cold_store.iter(DBCol::TransactionResultForBlock).map(Result::unwrap) {
+        let outcome = ExecutionOutcomeWithProof::try_from_slice(&value).unwrap();
+        // Outcomes of the transactions sent to test1 are executed by test0.
+        let id = &key[..CryptoHash::LENGTH];
+        assert!(
+            outcome.outcome.executor_id == test1()
+                || cold_store.exists(DBCol::Transactions, id).unwrap()
+        );
+        num_outcomes += 1;
+    }
+    assert!(num_outcomes > 0);
+
+    let mut num_state_changes = 0;
+    for (_, value) in cold_store.iter(DBCol::StateChanges).map(Result::unwrap) {
+        let changes = RawStateChangesWithTrieKey::try_from_slice(&value).unwrap();
+        assert_eq!(changes.trie_key.get_account_id(), Some(test1()));
+        num_state_changes += 1;
+    }
+    assert!(num_state_changes > 0);
+
+    // Blocks and chunks are copied in full so that the history can be found.
+    for col in [DBCol::Block, DBCol::BlockHeader, DBCol::BlockPerHeight, DBCol::Chunks] {
+        let num_checks = check_iter(&cold_store, &client_store, col, &vec![]);
+        assert!(num_checks > 0, "{col} should be copied");
+    }
+    assert!(cold_store.iter(DBCol::State).next().is_none(), "State should not be copied");
+}
+
+/// Runs a partial archival node storing the history of test1 until the first
+/// blocks are garbage collected from the hot storage.  Checks that the view
+/// client, which answers the `tx` and `EXPERIMENTAL_changes` RPC requests, finds
+/// the history of test1 in the cold storage but not the history of test2.
+#[test]
+fn test_partial_archival_view_client() {
+    init_test_logger();
+
+    let epoch_length = 5;
+    let max_height = epoch_length * (DEFAULT_GC_NUM_EPOCHS_TO_KEEP + 2);
+    let test2: AccountId = "test2".parse().unwrap();
+
+    let mut genesis = Genesis::test(vec![test0(), test1(), test2.clone()], 1);
+    genesis.config.epoch_length = epoch_length;
+    genesis.config.min_gas_price = 0;
+
+    let (storage, ..) = create_test_node_storage_with_cold(DB_VERSION, DbKind::Hot);
+    let hot_store = storage.get_hot_store();
+    let cold_db = storage.cold_db().unwrap();
+    let mut env = TestEnv::builder(&genesis.config)
+        .stores(vec![hot_store.clone()])
+        .nightshade_runtimes(&genesis)
+        .archive(true)
+        .save_trie_changes(true)
+        .build();
+    let accounts = HashSet::from([test1()]);
+
+    let signer = InMemorySigner::test_signer(&test0());
+    let mut tx_hashes = vec![];
+    let mut block_hashes = vec![];
+    let mut last_hash = *env.clients[0].chain.genesis().hash();
+    for height in 1..=max_height {
+        if height == 1 {
+            for (i, receiver) in [test1(), test2.clone()].into_iter().enumerate() {
+                let tx = SignedTransaction::send_money(
+                    i as u64 + 1,
+                    test0(),
+                    receiver,
+                    &signer,
+                    1,
+                    last_hash,
+                );
+                tx_hashes.push(tx.get_hash());
+                assert_eq!(
+                    env.rpc_handlers[0].process_tx(tx, false, false),
+                    ProcessTxResponse::ValidTx
+                );
+            }
+        }
+
+        let block = env.clients[0].produce_block(height).unwrap().unwrap();
+        env.process_block(0, block.clone(), Provenance::PRODUCED);
+
+        let client = &env.clients[0];
+        let epoch_id = client.epoch_manager.get_epoch_id(block.hash()).unwrap();
+        let shard_layout = client.epoch_manager.get_shard_layout(&epoch_id).unwrap();
+        let is_last_block_in_epoch =
+            client.epoch_manager.is_next_block_epoch_start(block.hash()).unwrap();
+        update_cold_db_for_accounts(
+            cold_db,
+            &hot_store,
+            &shard_layout,
+            &height,
+            is_last_block_in_epoch,
+            &accounts,
+        )
+        .unwrap();
+        update_cold_head(cold_db, &hot_store, &height).unwrap();
+
+        block_hashes.push(*block.hash());
+        last_hash = *block.hash();
+    }
+    // The blocks with the transactions are gone from the hot storage.
+    for block_hash in &block_hashes[..epoch_length as usize] {
+        assert!(!hot_store.exists(DBCol::Block, block_hash.as_ref()).unwrap());
+    }
+
+    // The view client of an archival node reads from the split storage.
+    let split_store = storage.get_split_store().unwrap();
+    let epoch_manager = EpochManager::new_arc_handle(split_store.clone(), &genesis.config, None);
+    let shard_tracker = ShardTracker::new(TrackedShardsConfig::AllShards, epoch_manager.clone());
+    let tempdir = tempfile::TempDir::new().unwrap();
+    let runtime = NightshadeRuntime::test(
+        tempdir.path(),
+        split_store,
+        &genesis.config,
+        epoch_manager.clone(),
+    );
+    let mut view_client = ViewClientActorInner::new(
+        Clock::real(),
+        MutableConfigValue::new(None, "validator_signer"),
+        ChainGenesis::new(&genesis.config),
+        epoch_manager,
+        shard_tracker,
+        runtime,
+        noop().into_multi_sender(),
+        ClientConfig::test(true, 10, 20, 1, true, true, false),
+        Controls::default(),
+    )
+    .unwrap();
+
+    let tx_status = |view_client: &mut ViewClientActorInner, tx_hash| {
+        view_client.handle(TxStatus { tx_hash, signer_account_id: test0(), fetch_receipt: false })
+    };
+    let status = tx_status(&mut view_client, tx_hashes[0]).unwrap();
+    let Some(FinalExecutionOutcomeViewEnum::FinalExecutionOutcome(outcome)) =
+        status.execution_outcome
+    else {
+        panic!("unexpected status of the transaction to test1: {status:?}");
+    };
+    assert_matches!(outcome.status, FinalExecutionStatus::SuccessValue(_));
+    assert_matches!(
+        tx_status(&mut view_client, tx_hashes[1]),
+        Err(TxStatusError::MissingTransaction(_))
+    );
+
+    let mut changed_accounts = HashSet::new();
+    for block_hash in &block_hashes[..epoch_length as usize] {
+        let changes = view_client
+            .handle(GetStateChanges {
+                block_hash: *block_hash,
+                state_changes_request: StateChangesRequestView::AccountChanges {
+                    account_ids: vec![test1(), test2.clone()],
+                },
+            })
+            .unwrap();
+        for change in changes {
+            if let StateChangeValueView::AccountUpdate { account_id, .. } = change.value {
+                changed_accounts.insert(account_id);
+            }
+        }
+    }
+    assert_eq!(changed_accounts, HashSet::from([test1()]));
+}
+
 /// Producing 4 epochs of blocks with some transactions.
 /// Call copying full contents of cold columns to cold storage in batches of specified max_size.
 /// Checks COLD_STORE_MIGRATION_BATCH_WRITE_COUNT metric for some batch_sizes:
@@ -527,3 +783,113 @@ fn test_cold_loop_on_gc_boundary() {
         end_cold_head
     );
 }
+
+/// Runs the cold store loop with the archival data stored in the filesystem
+/// and checks that everything copied to the cold storage matches the hot
+/// storage, also after reopening it.
+#[test]
+fn test_cold_loop_with_filesystem_db() {
+    init_test_logger();
+
+    let epoch_length = 5;
+    let max_height = epoch_length * 4;
+
+    let mut genesis = Genesis::test(vec![test0(), test1()], 1);
+    genesis.config.epoch_length = epoch_length;
+
+    let cold_dir = tempfile::tempdir().unwrap();
+    let storage = NodeStorage::new_with_cold(
+        TestDB::new(),
+        Arc::new(FilesystemDB::open(cold_dir.path(), false).unwrap()),
+    );
+    let hot_store = storage.get_hot_store();
+    hot_store.set_db_version(DB_VERSION).unwrap();
+    hot_store.set_db_kind(DbKind::Hot).unwrap();
+    let cold_store = storage.get_cold_store().unwrap();
+    cold_store.set_db_version(DB_VERSION).unwrap();
+    cold_store.set_db_kind(DbKind::Cold).unwrap();
+
+    let mut env = TestEnv::builder(&genesis.config)
+        .archive(true)
+        .save_trie_changes(true)
+        .stores(vec![hot_store.clone()])
+        .nightshade_runtimes(&genesis)
+        .build();
+
+    let mut last_hash = *env.clients[0].chain.genesis().hash();
+    for height in 1..max_height {
+        let signer = InMemorySigner::test_signer(&test0());
+        if height == 1 {
+            let tx = create_tx_deploy_contract(height, &signer, last_hash);
+            assert_eq!(
+                env.rpc_handlers[0].process_tx(tx, false, false),
+                ProcessTxResponse::ValidTx
+            );
+        }
+        for i in 0..5 {
+            let tx = create_tx_function_call(height * 10 + i, &signer, last_hash);
+            assert_eq!(
+                env.rpc_handlers[0].process_tx(tx, false, false),
+                ProcessTxResponse::ValidTx
+            );
+        }
+
+        let block = env.clients[0].produce_block(height).unwrap().unwrap();
+        env.process_block(0, block.clone(), Provenance::PRODUCED);
+        last_hash = *block.hash();
+    }
+
+    let signer =
+        InMemorySigner::from_random(AccountId::from_str("test").unwrap(), KeyType::ED25519);
+    let mut near_config = NearConfig::new(
+        nearcore::config::Config::default(),
+        genesis.clone(),
+        near_crypto::KeyFile {
+            account_id: signer.account_id,
+            public_key: signer.public_key,
+            secret_key: signer.secret_key,
+        },
+        MutableConfigValue::new(None, "validator_signer"),
+    )
+    .unwrap();
+    near_config.client_config = env.clients[0].config.clone();
+    near_config.config.save_trie_changes = Some(true);
+
+    let epoch_manager =
+        EpochManager::new_arc_handle(storage.get_hot_store(), &genesis.config, None);
+    let handle = spawn_cold_store_loop(&near_config, &storage, epoch_manager).unwrap().unwrap();
+
+    let final_head =
+        hot_store.get_ser::<Tip>(DBCol::BlockMisc, FINAL_HEAD_KEY).unwrap().unwrap().height;
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(60);
+    loop {
+        let cold_head = cold_store.get_ser::<Tip>(DBCol::BlockMisc, COLD_HEAD_KEY).unwrap();
+        if cold_head.is_some_and(|cold_head| cold_head.height >= final_head) {
+            break;
+        }
+        assert!(std::time::Instant::now() < deadline, "cold head didn't reach {final_head}");
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    handle.stop();
+    drop(storage);
+
+    // Everything in the cold storage has to be read back from the segments.
+    let storage = NodeStorage::new_with_cold(
+        TestDB::new(),
+        Arc::new(FilesystemDB::open(cold_dir.path(), true).unwrap()),
+    );
+    let cold_store = storage.get_cold_store().unwrap();
+    assert_eq!(
+        cold_store.get_ser::<Tip>(DBCol::BlockMisc, COLD_HEAD_KEY).unwrap().unwrap().height,
+        final_head
+    );
+    for col in DBCol::iter() {
+        if !col.is_cold() || col == DBCol::BlockMisc || col == DBCol::DbVersion {
+            continue;
+        }
+        let num_checks = check_iter(&cold_store, &hot_store, col, &vec![]);
+        if [DBCol::Block, DBCol::BlockHeader, DBCol::Chunks, DBCol::State].contains(&col) {
+            assert!(num_checks > 0, "{col} wasn't copied to the cold storage");
+        }
+    }
+}
@@ -748,7 +748,7 @@ fn client_sync_headers() {
             false,
             Box::new(move |msg, _ctx, _client_actor, _rpc_handler_actor| {
                 match msg.as_network_requests_ref() {
-                    NetworkRequests::BlockHeadersRequest { hashes, peer_id } => {
+                    NetworkRequests::BlockHeadersRequest { hashes, peer_id, .. } => {
                         assert_eq!(*peer_id, peer_info1.id);
                         assert_eq!(hashes.len(), 1);
                         // TODO: check it requests correct hashes.
@@ -779,6 +779,7 @@ fn client_sync_headers() {
                     connection_established_time: near_async::time::Instant::now(),
                     peer_type: PeerType::Outbound,
                     nonce: 1,
+                    reputation_score: 0,
                 }],
                 num_connected_peers: 1,
                 peer_max_count: 1,
@@ -3478,6 +3479,7 @@ mod contract_precompilation_tests {
     use crate::utils::process_blocks::produce_blocks_from_height;
 
     use super::*;
+    use near_primitives::action::DeleteKeyAction;
     use near_primitives::test_utils::MockEpochInfoProvider;
     use near_store::TrieUpdate;
     use near_vm_runner::{
@@ -3488,6 +3490,17 @@ mod contract_precompilation_tests {
 
     const EPOCH_LENGTH: u64 = 25;
 
+    /// Waits for a contract to be compiled in the background, and asserts that it is cached.
+    fn wait_for_compilation(cache: &FilesystemContractRuntimeCache, key: &CryptoHash) {
+        for _ in 0..100 {
+            if cache.has(key).unwrap() {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        panic!("contract {key} has not been compiled");
+    }
+
     fn state_sync_on_height(env: &TestEnv, height: BlockHeight) {
         let sync_block = env.clients[0].chain.get_block_by_height(height).unwrap();
         let sync_hash = *sync_block.hash();
@@ -3555,6 +3568,7 @@ mod contract_precompilation_tests {
             *env.clients[0].chain.get_block_by_height(height - 1).unwrap().header().epoch_id();
         let runtime_config = env.get_runtime_config(0, epoch_id);
         let key = get_contract_cache_key(*contract_code.hash(), &runtime_config.wasm_config);
+        wait_for_compilation(&caches[0], &key);
         for i in 0..num_clients {
             caches[i]
                 .get(&key)
@@ -3671,8 +3685,8 @@ mod contract_precompilation_tests {
         );
 
         // Check that both deployed contracts are presented in cache for client 0.
-        assert!(caches[0].get(&tiny_contract_key).unwrap().is_some());
-        assert!(caches[0].get(&test_contract_key).unwrap().is_some());
+        wait_for_compilation(&caches[0], &tiny_contract_key);
+        wait_for_compilation(&caches[0], &test_contract_key);
 
         // Check that only last contract is presented in cache for client 1.
         assert!(caches[1].get(&tiny_contract_key).unwrap().is_none());
@@ -3741,9 +3755,58 @@ mod contract_precompilation_tests {
         );
 
         // Check that contract is cached for client 0 despite account deletion.
-        assert!(caches[0].get(&contract_key).unwrap().is_some());
+        wait_for_compilation(&caches[0], &contract_key);
 
         // Check that contract is not cached for client 1 because of late state sync.
         assert!(caches[1].get(&contract_key).unwrap().is_none());
     }
+
+    /// Deployed contracts are compiled in the background once the chunk is applied, and only
+    /// if the deployment succeeded.
+    #[test]
+    fn test_compile_deployed_contracts_in_background() {
+        init_test_logger();
+        let mut genesis =
+            Genesis::test(vec!["test0".parse().unwrap(), "test1".parse().unwrap()], 1);
+        genesis.config.epoch_length = EPOCH_LENGTH;
+        let cache = FilesystemContractRuntimeCache::test().unwrap();
+        let mut env = TestEnv::builder(&genesis.config)
+            .contract_caches([&cache])
+            .nightshade_runtimes(&genesis)
+            .build();
+
+        let wasm_code = near_test_contracts::rs_contract().to_vec();
+        let deploy_tx = env.tx_from_actions(
+            vec![Action::DeployContract(DeployContractAction { code: wasm_code.clone() })],
+            &InMemorySigner::test_signer(&"test0".parse().unwrap()),
+            "test0".parse().unwrap(),
+        );
+        // The deployment is rolled back as the key to delete does not exist.
+        let failed_wasm_code = near_test_contracts::trivial_contract().to_vec();
+        let failed_deploy_tx = env.tx_from_actions(
+            vec![
+                Action::DeployContract(DeployContractAction { code: failed_wasm_code.clone() }),
+                Action::DeleteKey(Box::new(DeleteKeyAction {
+                    public_key: PublicKey::empty(KeyType::ED25519),
+                })),
+            ],
+            &InMemorySigner::test_signer(&"test1".parse().unwrap()),
+            "test1".parse().unwrap(),
+        );
+        for tx in [deploy_tx, failed_deploy_tx] {
+            assert_eq!(
+                env.rpc_handlers[0].process_tx(tx, false, false),
+                ProcessTxResponse::ValidTx
+            );
+        }
+        produce_blocks_from_height(&mut env, 4, 1);
+
+        let runtime_config = env.get_runtime_config(0, EpochId::default());
+        let key = |code: Vec<u8>| {
+            let code_hash = *ContractCode::new(code, None).hash();
+            get_contract_cache_key(code_hash, &runtime_config.wasm_config)
+        };
+        wait_for_compilation(&cache, &key(wasm_code));
+        assert!(!cache.has(&key(failed_wasm_code)).unwrap());
+    }
 }
@@ -14,6 +14,7 @@ use near_primitives::block::Tip;
 use near_primitives::shard_layout::ShardUId;
 use near_primitives::state::FlatStateValue;
 use near_primitives::state_part::PartId;
+use near_primitives::state_sync::{StateSyncDumpProgress, StateSyncDumpedParts};
 use near_primitives::transaction::SignedTransaction;
 use near_primitives::types::{BlockHeight, ShardId};
 use near_primitives::validator_signer::{EmptyValidatorSigner, InMemoryValidatorSigner};
@@ -53,6 +54,8 @@ fn slow_test_state_dump() {
         restart_dump_for_shards: None,
         iteration_delay: Some(Duration::ZERO),
         credentials_file: None,
+        num_concurrent_parts: None,
+        max_parts_in_flight: None,
     });
 
     let validator = MutableConfigValue::new(
@@ -116,6 +119,105 @@ fn slow_test_state_dump() {
     }
 }
 
+#[test]
+/// Simulates a dumper that was restarted after uploading some parts of the epoch: parts recorded as
+/// dumped in the DB must not be generated again, and the shard dump must still be completed.
+fn slow_test_state_dump_resumes_after_restart() {
+    init_test_logger();
+
+    let mut genesis = Genesis::test(vec!["test0".parse().unwrap(), "test1".parse().unwrap()], 1);
+    genesis.config.epoch_length = 25;
+
+    let mut env = TestEnv::builder(&genesis.config)
+        .clients_count(1)
+        .use_state_snapshots()
+        .real_stores()
+        .nightshade_runtimes(&genesis)
+        .build();
+
+    const MAX_HEIGHT: BlockHeight = 37;
+    for i in 1..=MAX_HEIGHT {
+        let block = env.clients[0].produce_block(i as u64).unwrap().unwrap();
+        env.process_block(0, block, Provenance::PRODUCED);
+    }
+
+    let chain = &env.clients[0].chain;
+    let epoch_manager = env.clients[0].epoch_manager.clone();
+    let head = chain.head().unwrap();
+    let epoch_id = head.epoch_id;
+    let epoch_height = epoch_manager.get_epoch_info(&epoch_id).unwrap().epoch_height();
+    let shard_ids = epoch_manager.shard_ids(&epoch_id).unwrap();
+    assert_ne!(shard_ids.len(), 0);
+
+    // What the previous run of the dumper persisted before it was stopped.
+    let num_parts = 1;
+    for shard_id in &shard_ids {
+        let mut dumped_parts = StateSyncDumpedParts::new(epoch_id, num_parts);
+        dumped_parts.set_dumped(0);
+        chain.chain_store().set_state_sync_dumped_parts(*shard_id, Some(&dumped_parts)).unwrap();
+    }
+
+    let mut config = env.clients[0].config.clone();
+    let root_dir = tempfile::Builder::new().prefix("state_dump").tempdir().unwrap();
+    config.state_sync.dump = Some(DumpConfig {
+        location: Filesystem { root_dir: root_dir.path().to_path_buf() },
+        restart_dump_for_shards: None,
+        iteration_delay: Some(Duration::ZERO),
+        credentials_file: None,
+        num_concurrent_parts: None,
+        max_parts_in_flight: None,
+    });
+    let validator = MutableConfigValue::new(
+        Some(Arc::new(EmptyValidatorSigner::new("test0".parse().unwrap()))),
+        "validator_signer",
+    );
+    let arbiter = actix::Arbiter::new();
+    let mut state_sync_dumper = StateSyncDumper {
+        clock: Clock::real(),
+        client_config: config,
+        chain_genesis: ChainGenesis::new(&genesis.config),
+        epoch_manager: epoch_manager.clone(),
+        shard_tracker: chain.shard_tracker.clone(),
+        runtime: env.clients[0].runtime_adapter.clone(),
+        validator,
+        future_spawner: Arc::new(ActixArbiterHandleFutureSpawner(arbiter.handle())),
+        handle: None,
+    };
+    state_sync_dumper.start().unwrap();
+
+    for attempt in 0.. {
+        let all_dumped = shard_ids.iter().all(|shard_id| {
+            matches!(
+                chain.chain_store().get_state_sync_dump_progress(*shard_id),
+                Ok(StateSyncDumpProgress::AllDumped { epoch_id: dumped_epoch_id, .. })
+                    if dumped_epoch_id == epoch_id
+            )
+        });
+        if all_dumped {
+            break;
+        }
+        if attempt >= 100 {
+            panic!("Failed to finish the resumed state dump");
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+    state_sync_dumper.stop_and_await();
+
+    for shard_id in shard_ids {
+        // The part was dumped before the restart, so it must not have been uploaded again.
+        let path = root_dir.path().join(external_storage_location(
+            "unittest",
+            &epoch_id,
+            epoch_height,
+            shard_id,
+            &StateFileType::StatePart { part_id: 0, num_parts },
+        ));
+        assert!(!path.exists(), "part {:?} was dumped again after the restart", path);
+        // The record of dumped parts is dropped once the shard dump is done.
+        assert_eq!(chain.chain_store().get_state_sync_dumped_parts(shard_id).unwrap(), None);
+    }
+}
+
 /// This function tests that after a node does state sync, it has the data that corresponds to the state of the epoch previous to the dumping node's final block.
 /// The way the test works:
 /// set up 2 nodes: env.client[0] dumps state parts, env.client[1] state syncs with the dumped state parts.
@@ -169,6 +271,8 @@ fn run_state_sync_with_dumped_parts(
         restart_dump_for_shards: None,
         iteration_delay: Some(Duration::ZERO),
         credentials_file: None,
+        num_concurrent_parts: None,
+        max_parts_in_flight: None,
     });
     let arbiter = actix::Arbiter::new();
     let mut state_sync_dumper = StateSyncDumper {
@@ -339,6 +339,8 @@ fn ultra_slow_test_sync_state_dump() {
                 restart_dump_for_shards: None,
                 iteration_delay: Some(Duration::milliseconds(500)),
                 credentials_file: None,
+                num_concurrent_parts: None,
+                max_parts_in_flight: None,
             });
             near1.config.store.enable_state_snapshot();
 
@@ -0,0 +1,44 @@
+use near_jsonrpc_primitives::errors::RpcError;
+use near_jsonrpc_primitives::types::backup::{BackupHandler, BackupView};
+use near_store::NodeStorage;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+
+/// Creates storage backups requested through the `/debug/api/backup` endpoint.
+pub struct BackupHandlerImpl {
+    pub storage: NodeStorage,
+    /// Directories of the hot and cold databases.
+    pub hot_path: PathBuf,
+    pub cold_path: Option<PathBuf>,
+    pub backup_dir: PathBuf,
+    /// Number of most recent backups kept after creating a new one.
+    pub num_to_keep: Option<NonZeroUsize>,
+    /// Backups are numbered sequentially and pruning must not delete files of
+    /// a backup in progress, so they must not be created concurrently.
+    pub lock: std::sync::Mutex<()>,
+}
+
+impl BackupHandler for BackupHandlerImpl {
+    fn create_backup(&self) -> Result<BackupView, RpcError> {
+        let _guard = self.lock.lock().unwrap();
+        let internal_error =
+            |err: anyhow::Error| RpcError::new_internal_error(None, format!("{:#}", err));
+        let manifest = near_store::backup::create_backup(
+            &self.storage,
+            &self.hot_path,
+            self.cold_path.as_deref(),
+            &self.backup_dir,
+        )
+        .map_err(internal_error)?;
+        if let Some(num_to_keep) = self.num_to_keep {
+            near_store::backup::prune_backups(&self.backup_dir, num_to_keep.get())
+                .map_err(internal_error)?;
+        }
+        Ok(BackupView {
+            id: manifest.id,
+            created_at: manifest.created_at,
+            total_size: manifest.total_size(),
+            exported_size: manifest.exported_size,
+        })
+    }
+}
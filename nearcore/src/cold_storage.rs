@@ -1,16 +1,18 @@
+use std::collections::HashSet;
 use std::sync::{Arc, atomic::AtomicBool};
 
 use near_chain::types::Tip;
 use near_epoch_manager::{EpochManagerAdapter, EpochManagerHandle};
 use near_primitives::errors::EpochError;
-use near_primitives::{hash::CryptoHash, types::BlockHeight};
+use near_primitives::hash::CryptoHash;
+use near_primitives::types::{AccountId, BlockHeight};
 use near_store::config::SplitStorageConfig;
 use near_store::db::metadata::DbKind;
 use near_store::{
     DBCol, FINAL_HEAD_KEY, NodeStorage, Store, TAIL_KEY,
     archive::cold_storage::{
         CopyAllDataToColdStatus, copy_all_data_to_cold, get_cold_head, update_cold_db,
-        update_cold_head,
+        update_cold_db_for_accounts, update_cold_head,
     },
     db::ColdDB,
 };
@@ -91,12 +93,14 @@ impl From<EpochError> for ColdStoreError {
 /// Checks if cold store head is behind the final head and if so copies data
 /// for the next available produced block after current cold store head.
 /// Updates cold store head after.
+/// If `tracked_accounts` is set, only the history of these accounts is copied.
 fn cold_store_copy(
     hot_store: &Store,
     cold_db: &ColdDB,
     genesis_height: BlockHeight,
     epoch_manager: &EpochManagerHandle,
     num_threads: usize,
+    tracked_accounts: Option<&HashSet<AccountId>>,
 ) -> anyhow::Result<ColdStoreCopyResult, ColdStoreError> {
     // If HEAD is not set for cold storage we default it to genesis_height.
     let cold_head = get_cold_head(cold_db)?;
@@ -143,14 +147,24 @@ fn cold_store_copy(
     let shard_layout = epoch_manager.get_shard_layout(&epoch_id)?;
     let is_last_block_in_epoch =
         epoch_manager.is_next_block_epoch_start(&next_height_block_hash)?;
-    update_cold_db(
-        cold_db,
-        hot_store,
-        &shard_layout,
-        &next_height,
-        is_last_block_in_epoch,
-        num_threads,
-    )?;
+    match tracked_accounts {
+        Some(accounts) => update_cold_db_for_accounts(
+            cold_db,
+            hot_store,
+            &shard_layout,
+            &next_height,
+            is_last_block_in_epoch,
+            accounts,
+        )?,
+        None => update_cold_db(
+            cold_db,
+            hot_store,
+            &shard_layout,
+            &next_height,
+            is_last_block_in_epoch,
+            num_threads,
+        )?,
+    }
     update_cold_head(cold_db, hot_store, &next_height)?;
 
     let result = if next_height >= hot_final_head_height {
@@ -287,6 +301,12 @@ fn cold_store_migration(
             tracing::info!(target: "cold_store", "Hot store DBKind is Hot.");
             genesis_height
         }
+        Some(DbKind::Archive) if split_storage_config.cold_store_tracked_accounts.is_some() => {
+            tracing::error!(target: "cold_store", "Cannot migrate a full archive to partial archival storage.");
+            return Err(anyhow::anyhow!(
+                "cold_store_tracked_accounts is set, but hot store DBKind is Archive"
+            ));
+        }
         Some(DbKind::Archive) => {
             tracing::info!(target: "cold_store", "Hot store DBKind is Archive.");
             hot_store
@@ -375,6 +395,13 @@ fn cold_store_loop(
     epoch_manager: &EpochManagerHandle,
 ) {
     tracing::info!(target : "cold_store", "Starting the cold store loop");
+    let tracked_accounts = split_storage_config
+        .cold_store_tracked_accounts
+        .as_ref()
+        .map(|accounts| accounts.iter().cloned().collect::<HashSet<_>>());
+    if let Some(accounts) = &tracked_accounts {
+        tracing::info!(target : "cold_store", ?accounts, "Copying only the history of the tracked accounts");
+    }
 
     loop {
         if !keep_going.load(std::sync::atomic::Ordering::Relaxed) {
@@ -389,6 +416,7 @@ fn cold_store_loop(
             genesis_height,
             epoch_manager,
             split_storage_config.num_cold_store_read_threads,
+            tracked_accounts.as_ref(),
         );
         let duration = instant.elapsed();
 
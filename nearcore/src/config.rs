@@ -38,6 +38,7 @@ use near_jsonrpc::RpcConfig;
 use near_network::config::NetworkConfig;
 use near_network::tcp;
 use near_o11y::log_config::LogConfig;
+use near_parameters::RuntimeConfigStore;
 use near_primitives::hash::CryptoHash;
 use near_primitives::shard_layout::ShardLayout;
 use near_primitives::test_utils::create_test_signer;
@@ -280,6 +281,11 @@ pub struct Config {
     /// needs trie changes in order to do garbage collection on hot and populate cold State column.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub save_trie_changes: Option<bool>,
+    /// Whether to index NEP-297 events emitted by contracts, so that they can
+    /// be queried with the `EXPERIMENTAL_contract_events` RPC method.  Events
+    /// are garbage collected together with the transaction outcomes.
+    #[serde(skip_serializing_if = "is_false")]
+    pub index_contract_events: bool,
     pub log_summary_style: LogSummaryStyle,
     #[serde(with = "near_async::time::serde_duration_as_std")]
     pub log_summary_period: Duration,
@@ -394,6 +400,7 @@ impl Default for Config {
             tracked_shard_schedule: None,
             archive: false,
             save_trie_changes: None,
+            index_contract_events: false,
             log_summary_style: LogSummaryStyle::Colored,
             log_summary_period: default_log_summary_period(),
             gc: GCConfig::default(),
@@ -589,6 +596,7 @@ impl NearConfig {
                 tracked_shards_config: config.tracked_shards_config(),
                 archive: config.archive,
                 save_trie_changes: config.save_trie_changes.unwrap_or(!config.archive),
+                index_contract_events: config.index_contract_events,
                 log_summary_style: config.log_summary_style,
                 gc: config.gc,
                 view_client_threads: config.view_client_threads,
@@ -677,6 +685,18 @@ impl NightshadeRuntime {
         store: Store,
         config: &NearConfig,
         epoch_manager: Arc<EpochManagerHandle>,
+    ) -> std::io::Result<Arc<NightshadeRuntime>> {
+        Self::from_config_with_runtime_config_store(home_dir, store, config, epoch_manager, None)
+    }
+
+    /// Same as `from_config`, with the runtime config store of the chain replaced by
+    /// `runtime_config_store` if it is given.
+    pub fn from_config_with_runtime_config_store(
+        home_dir: &Path,
+        store: Store,
+        config: &NearConfig,
+        epoch_manager: Arc<EpochManagerHandle>,
+        runtime_config_store: Option<RuntimeConfigStore>,
     ) -> std::io::Result<Arc<NightshadeRuntime>> {
         #[allow(clippy::or_fun_call)] // Closure cannot return reference to a temporary value
         let state_snapshot_config =
@@ -696,6 +716,9 @@ impl NightshadeRuntime {
             config.config.store.path.as_ref(),
             config.config.max_loaded_contracts,
         )?;
+        let mut trie_config = TrieConfig::from_store_config(&config.config.store);
+        trie_config.memtrie_image_dir =
+            config.config.store.memtrie_image_path.as_ref().map(|path| home_dir.join(path));
         Ok(NightshadeRuntime::new(
             store,
             ContractRuntimeCache::handle(&contract_cache),
@@ -703,9 +726,9 @@ impl NightshadeRuntime {
             epoch_manager,
             config.client_config.trie_viewer_state_size_limit,
             config.client_config.max_gas_burnt_view,
-            None,
+            runtime_config_store,
             config.config.gc.gc_num_epochs_to_keep(),
-            TrieConfig::from_store_config(&config.config.store),
+            trie_config,
             state_snapshot_config,
         ))
     }
@@ -1692,6 +1715,7 @@ mod tests {
                     gc_fork_clean_step: 420,
                     gc_num_epochs_to_keep: 24,
                     gc_step_period: Duration::seconds(1),
+                    gc_retention: Default::default(),
                 }
             } else {
                 GCConfig {
@@ -1699,6 +1723,7 @@ mod tests {
                     gc_fork_clean_step: 100,
                     gc_num_epochs_to_keep: 5,
                     gc_step_period: Duration::seconds(1),
+                    gc_retention: Default::default(),
                 }
             };
             assert_eq!(want_gc, config.gc);
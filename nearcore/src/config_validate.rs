@@ -1,5 +1,6 @@
 use near_chain_configs::{ExternalStorageLocation, SyncConfig};
 use near_config_utils::{ValidationError, ValidationErrors};
+use near_store::config::ArchivalStorageLocation;
 use std::collections::HashSet;
 use std::path::Path;
 
@@ -46,6 +47,23 @@ impl<'a> ConfigValidator<'a> {
             self.validation_errors.push_config_semantics_error(error_message);
         }
 
+        // The filesystem and S3 archival storages are written by the same process as the cold storage.
+        let archival_storage =
+            self.config.archival_storage.as_ref().and_then(|config| match config.storage {
+                ArchivalStorageLocation::Filesystem { .. } => Some("filesystem"),
+                ArchivalStorageLocation::S3(_) => Some("S3"),
+                _ => None,
+            });
+        if let Some(archival_storage) = archival_storage {
+            if self.config.save_trie_changes != Some(true) {
+                let error_message = format!(
+                    "{archival_storage} archival storage is configured, but save_trie_changes is {:?}. Trie changes should be saved to support archival storage.",
+                    self.config.save_trie_changes
+                );
+                self.validation_errors.push_config_semantics_error(error_message);
+            }
+        }
+
         if self.config.consensus.min_block_production_delay
             > self.config.consensus.max_block_production_delay
         {
@@ -99,6 +117,15 @@ impl<'a> ConfigValidator<'a> {
                     }
                 }
 
+                if dump_config.num_concurrent_parts == Some(0)
+                    || dump_config.max_parts_in_flight == Some(0)
+                {
+                    let error_message = format!(
+                        "'config.state_sync.dump.num_concurrent_parts' and 'config.state_sync.dump.max_parts_in_flight' should be greater than 0."
+                    );
+                    self.validation_errors.push_config_semantics_error(error_message);
+                }
+
                 match &dump_config.location {
                     ExternalStorageLocation::S3 { bucket, region } => {
                         if bucket.is_empty() || region.is_empty() {
@@ -290,6 +317,19 @@ mod tests {
         validate_config(&config).unwrap();
     }
 
+    #[test]
+    #[should_panic(
+        expected = "\\nconfig.json semantic issue: filesystem archival storage is configured, but save_trie_changes is None. Trie changes should be saved to support archival storage."
+    )]
+    fn test_filesystem_archival_storage_without_save_trie_changes() {
+        let mut config = Config::default();
+        config.archive = true;
+        config.archival_storage = Some(near_store::config::ArchivalStoreConfig {
+            storage: ArchivalStorageLocation::Filesystem { path: "archive".into() },
+        });
+        validate_config(&config).unwrap();
+    }
+
     #[test]
     #[should_panic(
         expected = "\\nconfig.json semantic issue: 'config.tx_routing_height_horizon' needs to be at least 2, got 1."
@@ -1,3 +1,5 @@
+#[cfg(feature = "json_rpc")]
+use crate::backup::BackupHandlerImpl;
 pub use crate::config::NightshadeRuntimeExt;
 pub use crate::config::{NearConfig, init_configs, load_config, load_test_config};
 #[cfg(feature = "json_rpc")]
@@ -41,13 +43,15 @@ use near_primitives::types::EpochId;
 use near_store::db::metadata::DbKind;
 use near_store::genesis::initialize_sharded_genesis_state;
 use near_store::metrics::spawn_db_metrics_loop;
-use near_store::{NodeStorage, Store, StoreOpenerError};
+use near_store::{NodeStorage, ShardTries, Store, StoreOpenerError};
 use near_telemetry::TelemetryActor;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::broadcast;
 
 pub mod append_only_map;
+#[cfg(feature = "json_rpc")]
+pub mod backup;
 pub mod cold_storage;
 pub mod config;
 #[cfg(test)]
@@ -192,8 +196,8 @@ fn get_split_store(config: &NearConfig, storage: &NodeStorage) -> anyhow::Result
         return Ok(None);
     }
 
-    // SplitStore should only be used if cold store is configured.
-    if config.config.cold_store.is_none() {
+    // SplitStore should only be used if cold store (or another archival storage) is configured.
+    if !storage.has_cold() {
         return Ok(None);
     }
 
@@ -232,6 +236,8 @@ pub struct NearNode {
     pub state_sync_runtime: Arc<tokio::runtime::Runtime>,
     /// Shard tracker, allows querying of which shards are tracked by this node.
     pub shard_tracker: ShardTracker,
+    /// Shard tries of the node, used to save memtrie images on shutdown.
+    pub shard_tries: ShardTries,
 }
 
 pub fn start_with_config(home_dir: &Path, config: NearConfig) -> anyhow::Result<NearNode> {
@@ -290,6 +296,7 @@ pub fn start_with_config_and_synchronization(
         epoch_manager.clone(),
     )
     .context("could not create the transaction runtime")?;
+    let shard_tries = runtime.get_tries();
 
     // Get the split store. If split store is some then create a new set of structures for
     // the view client. Otherwise just re-use the existing ones.
@@ -475,6 +482,19 @@ pub fn start_with_config_and_synchronization(
 
     let hot_store = storage.get_hot_store();
     let cold_store = storage.get_cold_store();
+    #[cfg(feature = "json_rpc")]
+    let backup_handler = {
+        let opener =
+            NodeStorage::opener(home_dir, &config.config.store, config.config.archival_config());
+        BackupHandlerImpl {
+            storage: storage.clone(),
+            hot_path: opener.path().to_path_buf(),
+            cold_path: opener.cold_path().map(Path::to_path_buf),
+            backup_dir: config.config.store.backup_path(home_dir),
+            num_to_keep: config.config.store.backup_num_to_keep,
+            lock: Default::default(),
+        }
+    };
 
     let mut rpc_servers = Vec::new();
     let network_actor = PeerManagerActor::spawn(
@@ -511,6 +531,7 @@ pub fn start_with_config_and_synchronization(
             #[cfg(feature = "test_features")]
             _gc_actor.with_auto_span_context().into_multi_sender(),
             Arc::new(entity_debug_handler),
+            Arc::new(backup_handler),
         ));
     }
 
@@ -565,5 +586,6 @@ pub fn start_with_config_and_synchronization(
         resharding_handle,
         state_sync_runtime,
         shard_tracker,
+        shard_tries,
     })
 }
@@ -69,6 +69,16 @@ pub(crate) static STATE_SYNC_DUMP_NUM_PARTS_DUMPED: LazyLock<IntGaugeVec> = Lazy
     .unwrap()
 });
 
+pub(crate) static STATE_SYNC_DUMP_NUM_PARTS_IN_FLIGHT: LazyLock<IntGaugeVec> =
+    LazyLock::new(|| {
+        try_create_int_gauge_vec(
+            "near_state_sync_dump_num_parts_in_flight",
+            "Number of parts being generated or uploaded in the epoch that is being dumped",
+            &["shard_id"],
+        )
+        .unwrap()
+    });
+
 pub(crate) static STATE_SYNC_DUMP_SIZE_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
     try_create_int_counter_vec(
         "near_state_sync_dump_size_total",
@@ -91,6 +91,9 @@ impl<'a> near_store::StoreMigrator for Migrator<'a> {
             42 => near_store::migrations::migrate_42_to_43(store),
             43 => Ok(()), // DBCol::ChunkApplyStats column added, no need to perform a migration
             44 => near_store::migrations::migrate_44_to_45(store),
+            45 => Ok(()), // DBCol::GCRetainedKeys column added, no need to perform a migration
+            46 => Ok(()), // DBCol::FlatStateHistory columns added, no need to perform a migration
+            47 => Ok(()), // DBCol::ContractEvents columns added, no need to perform a migration
             DB_VERSION.. => unreachable!(),
         }
     }
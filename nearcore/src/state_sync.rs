@@ -21,7 +21,7 @@ use near_epoch_manager::shard_tracker::ShardTracker;
 use near_primitives::block::BlockHeader;
 use near_primitives::hash::CryptoHash;
 use near_primitives::state_part::PartId;
-use near_primitives::state_sync::StateSyncDumpProgress;
+use near_primitives::state_sync::{StateSyncDumpProgress, StateSyncDumpedParts};
 use near_primitives::types::{EpochHeight, EpochId, ShardId, StateRoot};
 use parking_lot::{Condvar, Mutex, RwLock};
 use rand::seq::SliceRandom;
@@ -37,6 +37,12 @@ use tokio::sync::oneshot;
 /// A node must check external storage for parts to dump again once time is up.
 pub const STATE_DUMP_ITERATION_TIME_LIMIT_SECS: u64 = 300;
 
+/// Default number of state parts that may be generated at the same time.
+const DEFAULT_NUM_CONCURRENT_PARTS: usize = 4;
+
+/// Default number of generated state parts that may be held in memory at the same time.
+const DEFAULT_MAX_PARTS_IN_FLIGHT: usize = 16;
+
 // TODO: could refactor this further and just have one "Dumper" struct here
 pub struct StateSyncDumper {
     pub clock: Clock,
@@ -108,6 +114,7 @@ impl StateSyncDumper {
         if let Some(shards) = dump_config.restart_dump_for_shards.as_ref() {
             for shard_id in shards {
                 chain.chain_store().set_state_sync_dump_progress(*shard_id, None).unwrap();
+                chain.chain_store().set_state_sync_dumped_parts(*shard_id, None).unwrap();
                 tracing::debug!(target: "state_sync_dump", ?shard_id, "Dropped existing progress");
             }
         }
@@ -122,6 +129,8 @@ impl StateSyncDumper {
                 chain_id,
                 external,
                 dump_config.iteration_delay.unwrap_or(Duration::seconds(10)),
+                dump_config.num_concurrent_parts.unwrap_or(DEFAULT_NUM_CONCURRENT_PARTS),
+                dump_config.max_parts_in_flight.unwrap_or(DEFAULT_MAX_PARTS_IN_FLIGHT),
                 self.validator.clone(),
                 handle.clone(),
                 self.future_spawner.clone(),
@@ -243,6 +252,9 @@ struct ShardDump {
     // meaning they've already been dumped. We periodically check this (since other processes/machines
     // might have uploaded parts that we didn't) and avoid duplicating work for those parts that have already been updated.
     parts_missing: Arc<RwLock<HashSet<u64>>>,
+    // The parts we know to be dumped. This is periodically persisted to the DB so that
+    // a restarted node can resume the dump without listing or regenerating these parts.
+    dumped_parts: Arc<Mutex<StateSyncDumpedParts>>,
     // This will give Ok(()) when they're all done, or Err() when one gives an error
     // For now the tasks never fail, since we just retry all errors like the old implementation did,
     // but we probably want to make a change to distinguish which errors are actually retryable
@@ -263,7 +275,8 @@ struct DumpState {
 
 impl DumpState {
     /// For each shard, checks the filenames that exist in `external` and sets the corresponding `parts_missing` fields
-    /// to contain the parts that haven't yet been uploaded, so that we only try to generate those.
+    /// to contain the parts that haven't yet been uploaded, so that we only try to generate those. Parts recorded in
+    /// `dumped_parts` are never considered missing.
     async fn set_missing_parts(&self, external: &ExternalConnection, chain_id: &str) {
         for (shard_id, s) in &self.dump_state {
             match get_missing_part_ids_for_epoch(
@@ -276,7 +289,9 @@ impl DumpState {
             )
             .await
             {
-                Ok(missing) => {
+                Ok(mut missing) => {
+                    let dumped_parts = s.dumped_parts.lock();
+                    missing.retain(|part_id| !dumped_parts.is_dumped(*part_id));
                     *s.parts_missing.write() = missing;
                 }
                 Err(error) => {
@@ -346,7 +361,9 @@ enum NewDump {
 /// `ShardDump` struct, which we check in `check_parts_upload()`.
 ///
 /// Separately, every so often we check whether there's a new epoch to dump state for (in `check_head()`) and whether other processes
-/// have uploaded some state parts that we can therefore skip (in `check_stored_parts()`).
+/// have uploaded some state parts that we can therefore skip (in `check_stored_parts()`). At the same interval we persist the set of
+/// parts dumped so far for each shard (in `persist_dumped_parts()`), so that after a restart `get_shard_dump()` resumes the dump
+/// of the current epoch instead of starting the shard over.
 struct StateDumper {
     clock: Clock,
     chain_id: String,
@@ -361,6 +378,9 @@ struct StateDumper {
     future_spawner: Arc<dyn FutureSpawner>,
     // Used to limit how many tasks can be doing the computation-heavy state part generation at a time
     obtain_parts: Arc<Semaphore>,
+    // Used to limit how many parts can be held in memory between generation and upload, across all shards
+    parts_in_flight: Arc<Semaphore>,
+    max_parts_in_flight: usize,
 }
 
 // Stores needed data for use in part upload futures
@@ -380,7 +400,10 @@ struct PartUploader {
     // know not to touch that metric anymore.
     parts_dumped: Arc<AtomicI64>,
     parts_missing: Arc<RwLock<HashSet<u64>>>,
+    dumped_parts: Arc<Mutex<StateSyncDumpedParts>>,
     obtain_parts: Arc<Semaphore>,
+    parts_in_flight: Arc<Semaphore>,
+    max_parts_in_flight: usize,
     canceled: Arc<AtomicBool>,
 }
 
@@ -400,14 +423,27 @@ impl PartUploader {
     }
 
     /// Attempt to generate the state part for `self.epoch_id`, `self.shard_id` and `part_idx`, and upload it to
-    /// the external storage. The state part generation is limited by the number of permits allocated to the `obtain_parts`
-    /// Semaphore. For now, this always returns OK(()) (loops forever retrying in case of errors), but this should be changed
-    /// to return Err() if the error is not going to be retryable.
+    /// the external storage. The number of parts held in memory between generation and upload is limited by the
+    /// number of permits allocated to the `parts_in_flight` Semaphore.
     async fn upload_state_part(self: Arc<Self>, part_idx: u64) -> anyhow::Result<()> {
         if !self.parts_missing.read().contains(&part_idx) {
+            self.dumped_parts.lock().set_dumped(part_idx);
             self.inc_parts_dumped();
             return Ok(());
         }
+        let _permit = self.parts_in_flight.acquire().await.unwrap();
+        let in_flight = metrics::STATE_SYNC_DUMP_NUM_PARTS_IN_FLIGHT
+            .with_label_values(&[&self.shard_id.to_string()]);
+        in_flight.inc();
+        let result = self.generate_and_upload_part(part_idx).await;
+        in_flight.dec();
+        result
+    }
+
+    /// The state part generation is limited by the number of permits allocated to the `obtain_parts` Semaphore.
+    /// For now, this always returns OK(()) (loops forever retrying in case of errors), but this should be changed
+    /// to return Err() if the error is not going to be retryable.
+    async fn generate_and_upload_part(&self, part_idx: u64) -> anyhow::Result<()> {
         let part_id = PartId::new(part_idx, self.num_parts);
 
         let state_part = loop {
@@ -461,6 +497,7 @@ impl PartUploader {
                 .await
             {
                 Ok(()) => {
+                    self.dumped_parts.lock().set_dumped(part_idx);
                     self.inc_parts_dumped();
                     metrics::STATE_SYNC_DUMP_SIZE_TOTAL
                         .with_label_values(&[
@@ -500,7 +537,9 @@ impl PartUploader {
                 let task = me.upload_state_part(part_id);
                 respawn_for_parallelism(&*future_spawner, "upload part", task)
             })
-            .buffer_unordered(5);
+            // The number of parts actually held in memory is bounded by `parts_in_flight`, which is shared
+            // between shards, so one shard may use all of it while the others are done.
+            .buffer_unordered(self.max_parts_in_flight);
 
         while let Some(result) = tasks.next().await {
             if result.is_err() {
@@ -635,6 +674,8 @@ impl StateDumper {
         runtime: Arc<dyn RuntimeAdapter>,
         external: ExternalConnection,
         future_spawner: Arc<dyn FutureSpawner>,
+        num_concurrent_parts: usize,
+        max_parts_in_flight: usize,
     ) -> Self {
         Self {
             clock,
@@ -647,7 +688,9 @@ impl StateDumper {
             current_dump: CurrentDump::None,
             external,
             future_spawner,
-            obtain_parts: Arc::new(Semaphore::new(4)),
+            obtain_parts: Arc::new(Semaphore::new(num_concurrent_parts)),
+            parts_in_flight: Arc::new(Semaphore::new(max_parts_in_flight)),
+            max_parts_in_flight,
         }
     }
 
@@ -672,6 +715,10 @@ impl StateDumper {
                     .chain_store()
                     .set_state_sync_dump_progress(shard_id, None)
                     .context("failed setting state dump progress")?;
+                self.chain
+                    .chain_store()
+                    .set_state_sync_dumped_parts(shard_id, None)
+                    .context("failed setting dumped state parts")?;
             } else if done {
                 dump.dump_state.remove(&shard_id);
                 senders.remove(&shard_id);
@@ -699,10 +746,12 @@ impl StateDumper {
     }
 
     /// Generates the state sync header for the shard and initializes the `ShardDump` struct which
-    /// will be used to keep track of what's been dumped so far for this shard.
+    /// will be used to keep track of what's been dumped so far for this shard. If the DB records
+    /// parts already dumped for this epoch, those are not dumped again.
     fn get_shard_dump(
         &self,
         shard_id: ShardId,
+        epoch_id: &EpochId,
         sync_hash: &CryptoHash,
     ) -> anyhow::Result<(ShardDump, oneshot::Sender<anyhow::Result<()>>)> {
         let state_header = self
@@ -718,6 +767,23 @@ impl StateDumper {
             .with_label_values(&[&shard_id.to_string()])
             .set(num_parts.try_into().unwrap_or(i64::MAX));
 
+        let dumped_parts = match self
+            .chain
+            .chain_store()
+            .get_state_sync_dumped_parts(shard_id)
+            .context("failed getting dumped state parts")?
+        {
+            Some(parts) if &parts.epoch_id == epoch_id && parts.num_parts == num_parts => {
+                tracing::info!(
+                    target: "state_sync_dump", %shard_id, ?epoch_id, num_parts_dumped = parts.num_dumped(), num_parts,
+                    "Resuming state dump."
+                );
+                parts
+            }
+            _ => StateSyncDumpedParts::new(*epoch_id, num_parts),
+        };
+        let parts_missing = (0..num_parts).filter(|part_id| !dumped_parts.is_dumped(*part_id));
+
         let mut header_bytes: Vec<u8> = Vec::new();
         state_header.serialize(&mut header_bytes)?;
         let (sender, receiver) = oneshot::channel();
@@ -727,7 +793,8 @@ impl StateDumper {
                 header_to_dump: Some(header_bytes),
                 num_parts,
                 parts_dumped: Arc::new(AtomicI64::new(0)),
-                parts_missing: Arc::new(RwLock::new((0..num_parts).collect())),
+                parts_missing: Arc::new(RwLock::new(parts_missing.collect())),
+                dumped_parts: Arc::new(Mutex::new(dumped_parts)),
                 upload_parts: receiver,
             },
             sender,
@@ -771,7 +838,8 @@ impl StateDumper {
                 .with_label_values(&[&shard_id.to_string()])
                 .set(epoch_info.epoch_height().try_into().unwrap_or(i64::MAX));
 
-            let (shard_dump, sender) = self.get_shard_dump(shard_id, sync_header.hash())?;
+            let (shard_dump, sender) =
+                self.get_shard_dump(shard_id, sync_header.epoch_id(), sync_header.hash())?;
             dump_state.insert(shard_id, shard_dump);
             senders.insert(shard_id, sender);
         }
@@ -849,7 +917,10 @@ impl StateDumper {
                     num_parts: shard_dump.num_parts,
                     parts_dumped: shard_dump.parts_dumped.clone(),
                     parts_missing: shard_dump.parts_missing.clone(),
+                    dumped_parts: shard_dump.dumped_parts.clone(),
                     obtain_parts: self.obtain_parts.clone(),
+                    parts_in_flight: self.parts_in_flight.clone(),
+                    max_parts_in_flight: self.max_parts_in_flight,
                     canceled: dump.canceled.clone(),
                 });
                 let dump_shard = uploader.dump_shard_state(sender, self.future_spawner.clone());
@@ -943,6 +1014,10 @@ impl StateDumper {
                 }),
             )
             .context("failed setting state dump progress")?;
+        self.chain
+            .chain_store()
+            .set_state_sync_dumped_parts(shard_id, None)
+            .context("failed setting dumped state parts")?;
 
         if dump.dump_state.is_empty() {
             self.current_dump = CurrentDump::Done(dump.epoch_id);
@@ -950,6 +1025,21 @@ impl StateDumper {
         Ok(())
     }
 
+    /// Writes the parts dumped so far for each shard to the DB, so that they're skipped if the node restarts.
+    fn persist_dumped_parts(&self) -> anyhow::Result<()> {
+        let CurrentDump::InProgress(dump) = &self.current_dump else {
+            return Ok(());
+        };
+        for (shard_id, s) in &dump.dump_state {
+            let dumped_parts = s.dumped_parts.lock().clone();
+            self.chain
+                .chain_store()
+                .set_state_sync_dumped_parts(*shard_id, Some(&dumped_parts))
+                .context("failed setting dumped state parts")?;
+        }
+        Ok(())
+    }
+
     // Checks which parts have already been uploaded possibly by other nodes
     fn check_stored_parts(&self) -> impl Future<Output = ()> + Send {
         let CurrentDump::InProgress(dump) = &self.current_dump else {
@@ -1014,6 +1104,8 @@ async fn state_sync_dump(
     chain_id: String,
     external: ExternalConnection,
     iteration_delay: Duration,
+    num_concurrent_parts: usize,
+    max_parts_in_flight: usize,
     validator: MutableValidatorSigner,
     keep_running: &AtomicBool,
     future_spawner: Arc<dyn FutureSpawner>,
@@ -1030,6 +1122,8 @@ async fn state_sync_dump(
         runtime,
         external,
         future_spawner,
+        num_concurrent_parts,
+        max_parts_in_flight,
     );
     dumper.init(iteration_delay).await?;
 
@@ -1049,6 +1143,7 @@ async fn state_sync_dump(
                 dumper.check_head().await?;
             }
             _ = check_stored_parts.tick(&clock) => {
+                dumper.persist_dumped_parts()?;
                 dumper.check_stored_parts().await;
             }
             result = dumper.check_parts_upload() => {
@@ -1057,6 +1152,9 @@ async fn state_sync_dump(
         }
     }
 
+    if let Err(error) = dumper.persist_dumped_parts() {
+        tracing::error!(target: "state_sync_dump", ?error, "Failed to persist dumped state parts");
+    }
     if let CurrentDump::InProgress(mut dump) = dumper.current_dump {
         tracing::debug!(target: "state_sync_dump", "Awaiting upload task cancellation");
         dump.cancel().await;
@@ -1074,6 +1172,8 @@ async fn do_state_sync_dump(
     chain_id: String,
     external: ExternalConnection,
     iteration_delay: Duration,
+    num_concurrent_parts: usize,
+    max_parts_in_flight: usize,
     validator: MutableValidatorSigner,
     handle: Arc<StateSyncDumpHandle>,
     future_spawner: Arc<dyn FutureSpawner>,
@@ -1087,6 +1187,8 @@ async fn do_state_sync_dump(
         chain_id,
         external,
         iteration_delay,
+        num_concurrent_parts,
+        max_parts_in_flight,
         validator,
         &handle.keep_running,
         future_spawner,
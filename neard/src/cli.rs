@@ -18,7 +18,7 @@ use near_o11y::{
     BuildEnvFilterError, EnvFilterBuilder, default_subscriber,
     default_subscriber_with_opentelemetry,
 };
-use near_ping::PingCommand;
+use near_ping::{PingCommand, ReplayCaptureCommand};
 use near_primitives::epoch_manager::EpochConfigStore;
 use near_primitives::hash::CryptoHash;
 use near_primitives::merkle::compute_root_from_path;
@@ -103,7 +103,13 @@ impl NeardCmd {
 
             NeardSubCommand::StateViewer(cmd) => {
                 let mode = if cmd.read_write { Mode::ReadWrite } else { Mode::ReadOnly };
-                cmd.subcmd.run(&home_dir, genesis_validation, mode, cmd.store_temperature);
+                cmd.subcmd.run(
+                    &home_dir,
+                    genesis_validation,
+                    mode,
+                    cmd.store_temperature,
+                    crate::neard_version(),
+                );
             }
 
             NeardSubCommand::VerifyProof(cmd) => {
@@ -112,6 +118,9 @@ impl NeardCmd {
             NeardSubCommand::Ping(cmd) => {
                 cmd.run()?;
             }
+            NeardSubCommand::ReplayCapture(cmd) => {
+                cmd.run()?;
+            }
             NeardSubCommand::Mirror(cmd) => {
                 cmd.run()?;
             }
@@ -229,6 +238,10 @@ pub(super) enum NeardSubCommand {
     /// us after the handshake is completed, printing stats to stdout.
     Ping(PingCommand),
 
+    /// Connects to a NEAR node and replays to it the network traffic captured by
+    /// another node with `network.experimental.capture` set.
+    ReplayCapture(ReplayCaptureCommand),
+
     /// Mirror transactions from a source chain to a test chain with state forked
     /// from it, reproducing traffic and state as closely as possible.
     Mirror(MirrorCommand),
@@ -566,6 +579,7 @@ impl RunCmd {
                 cold_store_loop_handle,
                 mut state_sync_dumper,
                 resharding_handle,
+                shard_tries,
                 ..
             } = nearcore::start_with_config_and_synchronization(
                 home_dir,
@@ -591,6 +605,7 @@ impl RunCmd {
             }
             state_sync_dumper.stop_and_await();
             resharding_handle.stop();
+            shard_tries.save_memtrie_images();
             futures::future::join_all(rpc_servers.iter().map(|(name, server)| async move {
                 server.stop(true).await;
                 debug!(target: "neard", "{} server stopped", name);
@@ -965,6 +980,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn replay_capture() {
+        let cmd = NeardCmd::parse_from(&[
+            "test",
+            "replay-capture",
+            "--chain-id=testnet",
+            "--peer=ed25519:7PGseFbWxvYVgZ89K1uTJKYoKetWs7BJtbyXDzfbAcqX@127.0.0.1:24567",
+            "--capture=network_capture",
+            "--message-types=Block,BlockHeaders",
+            "--speed=0",
+        ]);
+        assert!(matches!(cmd.subcmd, NeardSubCommand::ReplayCapture(_)));
+        // The capture to replay is required.
+        assert!(
+            NeardCmd::try_parse_from(&[
+                "test",
+                "replay-capture",
+                "--chain-id=testnet",
+                "--peer=ed25519:7PGseFbWxvYVgZ89K1uTJKYoKetWs7BJtbyXDzfbAcqX@127.0.0.1:24567",
+            ])
+            .is_err()
+        );
+    }
+
     #[test]
     fn verify_proof_test() {
         assert_eq!(
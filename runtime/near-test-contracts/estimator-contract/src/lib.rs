@@ -42,6 +42,8 @@ extern "C" {
     fn alt_bn128_g1_multiexp(value_len: u64, value_ptr: u64, register_id: u64);
     fn alt_bn128_g1_sum(value_len: u64, value_ptr: u64, register_id: u64);
     fn alt_bn128_pairing_check(value_len: u64, value_ptr: u64) -> u64;
+    #[cfg(feature = "nightly")]
+    fn alt_bn128_poseidon(value_len: u64, value_ptr: u64, register_id: u64);
     fn bls12381_p1_sum(value_len: u64, value_ptr: u64, register_id: u64) -> u64;
     fn bls12381_p2_sum(value_len: u64, value_ptr: u64, register_id: u64) -> u64;
     fn bls12381_g1_multiexp(value_len: u64, value_ptr: u64, register_id: u64) -> u64;
@@ -56,6 +58,10 @@ extern "C" {
     fn sha256(value_len: u64, value_ptr: u64, register_id: u64);
     fn keccak256(value_len: u64, value_ptr: u64, register_id: u64);
     fn keccak512(value_len: u64, value_ptr: u64, register_id: u64);
+    #[cfg(feature = "nightly")]
+    fn sha3_256(value_len: u64, value_ptr: u64, register_id: u64);
+    #[cfg(feature = "nightly")]
+    fn blake2b(value_len: u64, value_ptr: u64, register_id: u64);
     fn ripemd160(value_len: u64, value_ptr: u64, register_id: u64);
     fn ecrecover(
         hash_len: u64,
@@ -74,6 +80,15 @@ extern "C" {
         pub_key_len: u64,
         pub_key_ptr: u64,
     ) -> u64;
+    #[cfg(feature = "nightly")]
+    fn p256_verify(
+        sig_len: u64,
+        sig_ptr: u64,
+        msg_len: u64,
+        msg_ptr: u64,
+        pub_key_len: u64,
+        pub_key_ptr: u64,
+    ) -> u64;
     // #####################
     // # Miscellaneous API #
     // #####################
@@ -190,6 +205,23 @@ extern "C" {
     fn storage_read(key_len: u64, key_ptr: u64, register_id: u64) -> u64;
     fn storage_remove(key_len: u64, key_ptr: u64, register_id: u64) -> u64;
     fn storage_has_key(key_len: u64, key_ptr: u64) -> u64;
+    #[cfg(feature = "nightly")]
+    fn storage_read_account(
+        account_id_len: u64,
+        account_id_ptr: u64,
+        key_len: u64,
+        key_ptr: u64,
+        register_id: u64,
+    ) -> u64;
+    #[cfg(feature = "nightly")]
+    fn storage_iter_batch(
+        start_len: u64,
+        start_ptr: u64,
+        end_len: u64,
+        end_ptr: u64,
+        limit: u64,
+        register_id: u64,
+    ) -> u64;
 }
 
 // Function that does not do anything at all.
@@ -465,6 +497,55 @@ pub unsafe fn keccak512_10kib_10k() {
     }
 }
 
+// Function to measure `sha3_256_base` and `sha3_256_byte`. Also measures `base`, `write_register_base`,
+// and `write_register_byte`. However `sha3_256` computation is more expensive than register writing
+// so we are okay overcharging it.
+// Compute sha3_256 on 10b 10k times.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn sha3_256_10b_10k() {
+    let buffer = [65u8; 10];
+    for _ in 0..10_000 {
+        sha3_256(buffer.len() as u64, buffer.as_ptr() as *const u64 as u64, 0);
+    }
+}
+// Function to measure `sha3_256_base` and `sha3_256_byte`. Also measures `base`, `write_register_base`,
+// and `write_register_byte`. However `sha3_256` computation is more expensive than register writing
+// so we are okay overcharging it.
+// Compute sha3_256 on 10kib 10k times.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn sha3_256_10kib_10k() {
+    let buffer = [65u8; 10240];
+    for _ in 0..10_000 {
+        sha3_256(buffer.len() as u64, buffer.as_ptr() as *const u64 as u64, 0);
+    }
+}
+// Function to measure `blake2b_base` and `blake2b_byte`. Also measures `base`, `write_register_base`,
+// and `write_register_byte`. However `blake2b` computation is more expensive than register writing
+// so we are okay overcharging it.
+// Compute blake2b on 10b 10k times.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn blake2b_10b_10k() {
+    let buffer = [65u8; 10];
+    for _ in 0..10_000 {
+        blake2b(buffer.len() as u64, buffer.as_ptr() as *const u64 as u64, 0);
+    }
+}
+// Function to measure `blake2b_base` and `blake2b_byte`. Also measures `base`, `write_register_base`,
+// and `write_register_byte`. However `blake2b` computation is more expensive than register writing
+// so we are okay overcharging it.
+// Compute blake2b on 10kib 10k times.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn blake2b_10kib_10k() {
+    let buffer = [65u8; 10240];
+    for _ in 0..10_000 {
+        blake2b(buffer.len() as u64, buffer.as_ptr() as *const u64 as u64, 0);
+    }
+}
+
 // Function to measure `ripemd160_base` and `ripemd160_block`. Also measures `base`, `write_register_base`,
 // and `write_register_byte`. However `ripemd160` computation is more expensive than register writing
 // so we are okay overcharging it.
@@ -588,6 +669,86 @@ pub unsafe fn ed25519_verify_16kib_64() {
     }
 }
 
+/// Function to measure `p256_verify_base`. Also measures `base`,
+/// `write_register_base`, and `write_register_byte`. However
+/// `p256_verify_base` computation is more expensive than register writing so
+/// we are okay overcharging it.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn p256_verify_32b_500() {
+    // private key: 0x1f3c6d9a2b4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8
+    let public_key: [u8; 65] = [
+        4, 181, 196, 255, 182, 163, 237, 215, 66, 114, 152, 191, 200, 59, 149, 184, 218, 29, 14,
+        207, 1, 112, 255, 172, 87, 60, 155, 137, 94, 82, 23, 148, 8, 227, 159, 146, 175, 217, 18,
+        192, 246, 186, 222, 176, 34, 175, 204, 113, 239, 111, 113, 174, 7, 243, 123, 42, 51, 188,
+        209, 73, 121, 242, 172, 155, 93,
+    ];
+
+    // 32 bytes message ("kajdlfkjalkfjaklfjdkladjfkljadsk")
+    let message: [u8; 32] = [
+        107, 97, 106, 100, 108, 102, 107, 106, 97, 108, 107, 102, 106, 97, 107, 108, 102, 106, 100,
+        107, 108, 97, 100, 106, 102, 107, 108, 106, 97, 100, 115, 107,
+    ];
+
+    let signature: [u8; 64] = [
+        137, 9, 7, 144, 8, 0, 150, 74, 194, 123, 148, 214, 87, 50, 103, 153, 67, 33, 74, 144, 146,
+        54, 12, 192, 204, 59, 233, 47, 103, 222, 77, 29, 124, 98, 51, 244, 146, 253, 23, 201, 56,
+        55, 35, 162, 238, 245, 18, 188, 216, 138, 233, 170, 239, 198, 49, 135, 114, 216, 70, 169,
+        224, 26, 122, 24,
+    ];
+
+    for _ in 0..500 {
+        let result = p256_verify(
+            signature.len() as _,
+            signature.as_ptr() as _,
+            message.len() as _,
+            message.as_ptr() as _,
+            public_key.len() as _,
+            public_key.as_ptr() as _,
+        );
+        // check that result was positive, as negative results could have exited
+        // early and do not reflect the full cost.
+        assert!(result == 1);
+    }
+}
+
+/// Function to measure `p256_verify_byte`.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn p256_verify_16kib_64() {
+    // 16kB bytes message
+    let message = [b'a'; 16384];
+
+    // private key: 0x1f3c6d9a2b4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8
+    let public_key: [u8; 65] = [
+        4, 181, 196, 255, 182, 163, 237, 215, 66, 114, 152, 191, 200, 59, 149, 184, 218, 29, 14,
+        207, 1, 112, 255, 172, 87, 60, 155, 137, 94, 82, 23, 148, 8, 227, 159, 146, 175, 217, 18,
+        192, 246, 186, 222, 176, 34, 175, 204, 113, 239, 111, 113, 174, 7, 243, 123, 42, 51, 188,
+        209, 73, 121, 242, 172, 155, 93,
+    ];
+
+    let signature: [u8; 64] = [
+        173, 19, 221, 185, 169, 6, 168, 66, 177, 13, 18, 185, 206, 4, 93, 86, 74, 227, 126, 140,
+        12, 28, 1, 78, 199, 153, 255, 140, 107, 152, 145, 48, 67, 106, 67, 187, 173, 167, 234, 151,
+        239, 175, 243, 129, 94, 117, 207, 249, 16, 76, 169, 38, 74, 155, 163, 87, 232, 221, 112,
+        103, 113, 183, 148, 76,
+    ];
+
+    for _ in 0..64 {
+        let result = p256_verify(
+            signature.len() as _,
+            signature.as_ptr() as _,
+            message.len() as _,
+            message.as_ptr() as _,
+            public_key.len() as _,
+            public_key.as_ptr() as _,
+        );
+        // check that result was positive, as negative results could have exited
+        // early and do not reflect the full cost.
+        assert!(result == 1);
+    }
+}
+
 #[repr(C)]
 struct MultiexpElem([u8; 64], [u8; 32]);
 
@@ -698,6 +859,46 @@ pub unsafe fn alt_bn128_g1_sum_10_1k() {
     }
 }
 
+// Function to measure `alt_bn128_poseidon_base` and `alt_bn128_poseidon_element`. Also measures `base`, `write_register_base`,
+// and `write_register_byte`. However `poseidon` computation is more expensive than register writing
+// so we are okay overcharging it.
+// Compute poseidon on 1 element 100 times.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn alt_bn128_poseidon_1_100() {
+    let mut buffer = [[0u8; 32]; 1];
+    for (i, element) in buffer.iter_mut().enumerate() {
+        element[0] = i as u8 + 1;
+    }
+    for _ in 0..100 {
+        alt_bn128_poseidon(
+            core::mem::size_of_val(&buffer) as u64,
+            buffer.as_ptr() as *const u64 as u64,
+            0,
+        );
+    }
+}
+
+// Function to measure `alt_bn128_poseidon_base` and `alt_bn128_poseidon_element`. Also measures `base`, `write_register_base`,
+// and `write_register_byte`. However `poseidon` computation is more expensive than register writing
+// so we are okay overcharging it.
+// Compute poseidon on 12 elements 100 times.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn alt_bn128_poseidon_12_100() {
+    let mut buffer = [[0u8; 32]; 12];
+    for (i, element) in buffer.iter_mut().enumerate() {
+        element[0] = i as u8 + 1;
+    }
+    for _ in 0..100 {
+        alt_bn128_poseidon(
+            core::mem::size_of_val(&buffer) as u64,
+            buffer.as_ptr() as *const u64 as u64,
+            0,
+        );
+    }
+}
+
 #[repr(C)]
 struct PairingElem([u8; 64], [u8; 128]);
 
@@ -1067,6 +1268,92 @@ storage_bench!(key, 10240, value, 10, 1000, storage_read_10kib_key_1k, {
     storage_read(10240, key.as_ptr() as _, 0);
 });
 
+// Function to measure `storage_read_account_base`.
+// Reads from the storage of the current account 1k times.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn storage_read_account_10b_key_1k() {
+    storage_read_account_1k::<10>();
+}
+
+// Function to measure `storage_read_account_base + storage_read_account_key_byte`.
+// Reads from the storage of the current account with 10kib key 1000 times.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn storage_read_account_10kib_key_1k() {
+    storage_read_account_1k::<10240>();
+}
+
+// Reads the keys written by `storage_bench!` from the storage of the current account, through
+// `storage_read_account`, 1k times.
+#[cfg(feature = "nightly")]
+unsafe fn storage_read_account_1k<const KEY_LEN: usize>() {
+    let account_id = [0u8; 64];
+    current_account_id(1);
+    let account_id_len = register_len(1);
+    read_register(1, account_id.as_ptr() as _);
+
+    let mut key = [0u8; KEY_LEN];
+    for i in 0..1000 {
+        key[0] = (i % 256) as u8;
+        key[1] = ((i / 256) % 256) as u8;
+        key[2] = ((i / 256 / 256) % 256) as u8;
+        storage_read_account(
+            account_id_len,
+            account_id.as_ptr() as _,
+            KEY_LEN as _,
+            key.as_ptr() as _,
+            0,
+        );
+    }
+}
+
+// Function to measure `storage_iter_batch_base`.
+// Looks up an empty range starting at one of the keys written by `storage_bench!` 1k times.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn storage_iter_batch_10b_bound_1k() {
+    storage_iter_batch_empty_1k::<10>();
+}
+
+// Function to measure `storage_iter_batch_base + storage_iter_batch_bound_byte`.
+// Looks up an empty range with 10kib bounds 1k times.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn storage_iter_batch_10kib_bound_1k() {
+    storage_iter_batch_empty_1k::<10240>();
+}
+
+// Looks up the empty ranges `[key, key)` for the keys written by `storage_bench!`, 1k times.
+// The trie is still searched for the first key of the range.
+#[cfg(feature = "nightly")]
+unsafe fn storage_iter_batch_empty_1k<const KEY_LEN: usize>() {
+    let mut key = [0u8; KEY_LEN];
+    for i in 0..1000 {
+        key[0] = (i % 256) as u8;
+        key[1] = ((i / 256) % 256) as u8;
+        key[2] = ((i / 256 / 256) % 256) as u8;
+        storage_iter_batch(
+            KEY_LEN as _,
+            key.as_ptr() as _,
+            KEY_LEN as _,
+            key.as_ptr() as _,
+            1,
+            0,
+        );
+    }
+}
+
+// Function to measure `storage_iter_batch_entry_base`, `storage_iter_batch_key_byte` and
+// `storage_iter_batch_value_byte`, depending on the entries written by the setup.
+// Reads the 1k entries written by `storage_bench!` in a single batch.
+#[cfg(feature = "nightly")]
+#[unsafe(no_mangle)]
+pub unsafe fn storage_iter_batch_1k_entries() {
+    let empty = [0u8; 0];
+    storage_iter_batch(0, empty.as_ptr() as _, 0, empty.as_ptr() as _, 1000, 0);
+}
+
 // Storage removing.
 
 // Function to measure `storage_remove_base`.
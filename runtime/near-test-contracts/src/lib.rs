@@ -264,7 +264,9 @@ fn normalize_config(mut config: wasm_smith::Config) -> wasm_smith::Config {
 
 impl<'a> Arbitrary<'a> for ArbitraryModule {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
-        let config = normalize_config(wasm_smith::Config::default());
+        let mut config = normalize_config(wasm_smith::Config::default());
+        // The fuzzers run with the nightly configuration, which enables bulk memory.
+        config.bulk_memory_enabled = true;
         Ok(Self::new(config, u))
     }
 }
@@ -280,6 +282,15 @@ impl std::fmt::Debug for ArbitraryModule {
     }
 }
 
+/// Generate the same module as the fuzzers would from the random bytes of `seed`.
+pub fn arbitrary_module(seed: u64) -> ArbitraryModule {
+    let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+    let mut buffer = vec![0u8; 10240];
+    buffer.try_fill(&mut rng).expect("fill buffer with random data");
+    ArbitraryModule::arbitrary_take_rest(arbitrary::Unstructured::new(&buffer))
+        .expect("arbitrary won't fail")
+}
+
 /// Generate an arbitrary valid contract.
 pub fn arbitrary_contract(seed: u64) -> Vec<u8> {
     let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
@@ -1,6 +1,14 @@
+use near_parameters::vm::VMKind;
+use near_parameters::{RuntimeConfig, RuntimeConfigStore};
+use near_primitives::version::{PROTOCOL_VERSION, ProtocolFeature, ProtocolVersion};
 use near_vm_runner::ContractCode;
+use near_vm_runner::internal::VMKindExt;
 use near_vm_runner::internal::wasmparser::{Export, ExternalKind, Parser, Payload, TypeDef};
-use near_vm_runner::logic::VMContext;
+use near_vm_runner::logic::errors::FunctionCallError;
+use near_vm_runner::logic::mocks::mock_external::{MockAction, MockedExternal};
+use near_vm_runner::logic::{VMContext, VMOutcome};
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
 /// Finds a no-parameter exported function, something like `(func (export "entry-point"))`.
 pub fn find_entry_point(contract: &ContractCode) -> Option<String> {
@@ -50,3 +58,69 @@ pub fn create_context(input: Vec<u8>) -> VMContext {
         output_data_receivers: vec![],
     }
 }
+
+/// Protocol version whose configuration the fuzzers run with. It is the nightly version even
+/// in the stable builds, so that bulk memory and the other features that are not stable yet get
+/// fuzzed too.
+pub const FUZZ_PROTOCOL_VERSION: ProtocolVersion =
+    if PROTOCOL_VERSION > ProtocolFeature::WasmBulkMemory.protocol_version() {
+        PROTOCOL_VERSION
+    } else {
+        ProtocolFeature::WasmBulkMemory.protocol_version()
+    };
+
+/// The runtime configuration of [`FUZZ_PROTOCOL_VERSION`].
+pub fn fuzz_config() -> Arc<RuntimeConfig> {
+    let config = Arc::clone(RuntimeConfigStore::new(None).get_config(FUZZ_PROTOCOL_VERSION));
+    assert!(config.wasm_config.bulk_memory, "the fuzzers must run with bulk memory enabled");
+    config
+}
+
+/// Everything observable about the execution of a contract, which must be the same on all the VM
+/// backends.
+#[derive(Debug, PartialEq)]
+pub struct ExecutionEffects {
+    pub outcome: VMOutcome,
+    pub storage: BTreeMap<Vec<u8>, Vec<u8>>,
+    pub actions: Vec<MockAction>,
+}
+
+/// Runs the entry point of `code` on `vm_kind`, with [`fuzz_config`] and a fresh `MockedExternal`.
+pub fn run_contract(code: &ContractCode, vm_kind: VMKind) -> ExecutionEffects {
+    let mut fake_external = MockedExternal::with_code(code.clone_for_tests());
+    let method_name = find_entry_point(code).unwrap_or_else(|| "main".to_string());
+    let mut context = create_context(vec![]);
+    context.prepaid_gas = 10u64.pow(14);
+    let config = fuzz_config();
+    let fees = Arc::clone(&config.fees);
+    let wasm_config = near_parameters::vm::Config::clone(&config.wasm_config);
+    let gas_counter = context.make_gas_counter(&wasm_config);
+    let res = vm_kind
+        .runtime(wasm_config.into())
+        .unwrap()
+        .prepare(&fake_external, None, gas_counter, &method_name)
+        .run(&mut fake_external, &context, fees);
+
+    // Remove the VMError message details as they can differ between runtimes
+    let mut outcome = res.unwrap_or_else(|err| panic!("fatal error: {err:?}"));
+    if outcome.aborted.is_some() {
+        outcome.logs = vec!["[censored]".to_owned()];
+        outcome.aborted = Some(FunctionCallError::LinkError { msg: "[censored]".to_owned() });
+    }
+    ExecutionEffects {
+        outcome,
+        storage: fake_external.fake_trie.into_iter().collect(),
+        actions: fake_external.action_log,
+    }
+}
+
+/// Runs `code` on NearVM and Wasmtime, and panics if the two executions differ in any way.
+pub fn assert_backends_agree(code: &ContractCode) {
+    let near_vm = run_contract(code, VMKind::NearVm);
+    let wasmtime = run_contract(code, VMKind::Wasmtime);
+    // Compare the parts separately first, as the `Debug` output of `VMOutcome` omits the logs.
+    assert_eq!(near_vm.outcome.logs, wasmtime.outcome.logs, "logs differ");
+    assert_eq!(near_vm.storage, wasmtime.storage, "storage effects differ");
+    assert_eq!(near_vm.actions, wasmtime.actions, "actions differ");
+    assert_eq!(near_vm.outcome, wasmtime.outcome, "outcomes differ");
+}
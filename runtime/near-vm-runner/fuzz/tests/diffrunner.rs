@@ -0,0 +1,45 @@
+//! Deterministic replay of the `diffrunner` fuzz target, so that CI checks that NearVM and
+//! Wasmtime agree without running the fuzzer.
+#![cfg(target_arch = "x86_64")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use near_test_contracts::ArbitraryModule;
+use near_vm_runner::ContractCode;
+use near_vm_runner_fuzz::assert_backends_agree;
+use std::path::Path;
+
+/// Number of contracts generated from fixed seeds.
+const NUM_SEEDS: u64 = 16;
+
+#[test]
+fn test_diffrunner_seeds() {
+    for seed in 0..NUM_SEEDS {
+        let code = ContractCode::new(near_test_contracts::arbitrary_contract(seed), None);
+        assert_backends_agree(&code);
+        // Unlike `arbitrary_contract`, these may use bulk memory.
+        let module = near_test_contracts::arbitrary_module(seed);
+        assert_backends_agree(&ContractCode::new(module.0.to_bytes(), None));
+    }
+}
+
+/// Replays the inputs saved by `cargo fuzz run diffrunner`, if any.
+#[test]
+fn test_diffrunner_corpus() {
+    let fuzz_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for dir in ["corpus/diffrunner", "artifacts/diffrunner"] {
+        let Ok(entries) = std::fs::read_dir(fuzz_dir.join(dir)) else {
+            continue;
+        };
+        let mut paths: Vec<_> = entries.map(|entry| entry.unwrap().path()).collect();
+        paths.sort();
+        for path in paths {
+            let data = std::fs::read(&path).unwrap();
+            // Decode the input the same way as `libfuzzer_sys::fuzz_target!` does.
+            let Ok(module) = ArbitraryModule::arbitrary_take_rest(Unstructured::new(&data)) else {
+                continue;
+            };
+            println!("replaying {}", path.display());
+            assert_backends_agree(&ContractCode::new(module.0.to_bytes(), None));
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Ahead-of-time compilation of contracts in background threads.
+//!
+//! Compiling a contract the first time it is called is expensive and happens on the
+//! critical path of chunk application. [`BackgroundCompiler`] lets the caller queue
+//! contracts that are likely to be called soon (freshly deployed ones, or ones called
+//! recently) so that they are already in the [`ContractRuntimeCache`] by then.
+
+use crate::ContractCode;
+use crate::cache::{ContractRuntimeCache, get_contract_cache_key, precompile_contract};
+use crate::errors::ContractPrecompilatonResult;
+use crate::logic::Config;
+use near_primitives_core::hash::CryptoHash;
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::sync::{Arc, mpsc};
+
+/// Reads the code of a contract, given its hash.
+type LoadCode = Box<dyn FnMut(CryptoHash) -> Option<ContractCode> + Send>;
+
+struct CompilationTask {
+    /// Cache keys and code hashes of the contracts to compile.
+    contracts: Vec<(CryptoHash, CryptoHash)>,
+    load: LoadCode,
+    config: Arc<Config>,
+    cache: Box<dyn ContractRuntimeCache>,
+}
+
+/// Compiles contracts on a bounded number of background threads.
+///
+/// Submissions beyond the capacity of the queue are dropped. Such contracts are then
+/// compiled when they are first called, as they would be without this compiler.
+///
+/// Clones share the same threads, which exit once all the clones are dropped and the
+/// queue is drained.
+#[derive(Clone)]
+pub struct BackgroundCompiler {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    sender: mpsc::SyncSender<CompilationTask>,
+    /// Cache keys of the contracts queued or being compiled, so that the same contract
+    /// is not compiled several times concurrently.
+    pending: Arc<Mutex<HashSet<CryptoHash>>>,
+}
+
+impl BackgroundCompiler {
+    pub fn new(num_threads: usize, queue_size: usize) -> Self {
+        let (sender, receiver) = mpsc::sync_channel(queue_size);
+        let receiver = Arc::new(Mutex::new(receiver));
+        let pending = Arc::new(Mutex::new(HashSet::new()));
+        for index in 0..num_threads.max(1) {
+            let receiver = Arc::clone(&receiver);
+            let pending = Arc::clone(&pending);
+            std::thread::Builder::new()
+                .name(format!("contract-compiler-{index}"))
+                .spawn(move || run_compilation_thread(&receiver, &pending))
+                .expect("failed to spawn a contract compilation thread");
+        }
+        Self { inner: Arc::new(Inner { sender, pending }) }
+    }
+
+    /// Queues the compilation of `code` into `cache`, unless it is already cached or
+    /// queued. The contract is dropped if the queue is full.
+    ///
+    /// Returns whether the contract has been queued.
+    pub fn submit(
+        &self,
+        code: ContractCode,
+        config: Arc<Config>,
+        cache: &dyn ContractRuntimeCache,
+    ) -> bool {
+        let code_hash = *code.hash();
+        let key = get_contract_cache_key(code_hash, &config);
+        if matches!(cache.has(&key), Ok(true)) {
+            return false;
+        }
+        let mut code = Some(code);
+        self.enqueue(vec![(key, code_hash)], Box::new(move |_| code.take()), config, cache)
+    }
+
+    /// Queues the compilation of the contracts with the given code hashes into `cache`,
+    /// as a single task. The code is read with `load` on a compilation thread, and only
+    /// for the contracts that are not cached yet. The contracts are dropped if the queue
+    /// is full.
+    ///
+    /// Returns whether any contract has been queued.
+    pub fn submit_stored(
+        &self,
+        code_hashes: impl IntoIterator<Item = CryptoHash>,
+        load: impl FnMut(CryptoHash) -> Option<ContractCode> + Send + 'static,
+        config: Arc<Config>,
+        cache: &dyn ContractRuntimeCache,
+    ) -> bool {
+        let contracts = code_hashes
+            .into_iter()
+            .map(|code_hash| (get_contract_cache_key(code_hash, &config), code_hash))
+            .collect();
+        self.enqueue(contracts, Box::new(load), config, cache)
+    }
+
+    fn enqueue(
+        &self,
+        mut contracts: Vec<(CryptoHash, CryptoHash)>,
+        load: LoadCode,
+        config: Arc<Config>,
+        cache: &dyn ContractRuntimeCache,
+    ) -> bool {
+        {
+            let mut pending = self.inner.pending.lock();
+            contracts.retain(|(key, _)| pending.insert(*key));
+        }
+        if contracts.is_empty() {
+            return false;
+        }
+        let num_contracts = contracts.len();
+        let task = CompilationTask { contracts, load, config, cache: cache.handle() };
+        #[cfg(feature = "metrics")]
+        crate::metrics::BACKGROUND_COMPILATION_QUEUE_LENGTH.add(num_contracts as i64);
+        match self.inner.sender.try_send(task) {
+            Ok(()) => true,
+            Err(err) => {
+                let task = match err {
+                    mpsc::TrySendError::Full(task) | mpsc::TrySendError::Disconnected(task) => task,
+                };
+                let mut pending = self.inner.pending.lock();
+                for (key, _) in &task.contracts {
+                    pending.remove(key);
+                }
+                #[cfg(feature = "metrics")]
+                {
+                    crate::metrics::BACKGROUND_COMPILATION_QUEUE_LENGTH.sub(num_contracts as i64);
+                    crate::metrics::BACKGROUND_COMPILATIONS_TOTAL
+                        .with_label_values(&["dropped"])
+                        .inc_by(num_contracts as u64);
+                }
+                #[cfg(not(feature = "metrics"))]
+                let _ = num_contracts;
+                false
+            }
+        }
+    }
+
+    /// Returns whether there are contracts queued or being compiled.
+    pub fn is_busy(&self) -> bool {
+        !self.inner.pending.lock().is_empty()
+    }
+}
+
+impl std::fmt::Debug for BackgroundCompiler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackgroundCompiler")
+            .field("pending", &self.inner.pending.lock().len())
+            .finish_non_exhaustive()
+    }
+}
+
+fn run_compilation_thread(
+    receiver: &Mutex<mpsc::Receiver<CompilationTask>>,
+    pending: &Mutex<HashSet<CryptoHash>>,
+) {
+    loop {
+        // The lock is released before compiling, so that other threads can pick tasks.
+        let task = receiver.lock().recv();
+        let Ok(CompilationTask { contracts, mut load, config, cache }) = task else {
+            // All the senders have been dropped.
+            return;
+        };
+        for (key, code_hash) in contracts {
+            #[cfg(feature = "metrics")]
+            crate::metrics::BACKGROUND_COMPILATION_QUEUE_LENGTH.dec();
+            let _span = tracing::debug_span!(
+                target: "vm",
+                "background_compile_contract",
+                %code_hash,
+            )
+            .entered();
+            let start = std::time::Instant::now();
+            let outcome = compile(key, code_hash, &mut load, &config, cache.as_ref());
+            #[cfg(feature = "metrics")]
+            {
+                crate::metrics::BACKGROUND_COMPILATION_TIME.observe(start.elapsed().as_secs_f64());
+                crate::metrics::BACKGROUND_COMPILATIONS_TOTAL.with_label_values(&[outcome]).inc();
+            }
+            #[cfg(not(feature = "metrics"))]
+            let _ = (start, outcome);
+            pending.lock().remove(&key);
+        }
+    }
+}
+
+/// Compiles a single contract into `cache`, and returns the outcome reported in the metrics.
+fn compile(
+    key: CryptoHash,
+    code_hash: CryptoHash,
+    load: &mut LoadCode,
+    config: &Arc<Config>,
+    cache: &dyn ContractRuntimeCache,
+) -> &'static str {
+    // Avoid reading the code of the contracts that are already compiled.
+    if matches!(cache.has(&key), Ok(true)) {
+        return "already_cached";
+    }
+    let Some(code) = load(code_hash) else {
+        return "missing_code";
+    };
+    match precompile_contract(&code, Arc::clone(config), Some(cache)) {
+        Ok(Ok(ContractPrecompilatonResult::ContractCompiled)) => "compiled",
+        Ok(Ok(_)) => "already_cached",
+        // The compilation error is cached as well, there is nothing else to do.
+        Ok(Err(_)) => "compilation_error",
+        Err(err) => {
+            tracing::warn!(target: "vm", ?err, "failed to compile contract in background");
+            "cache_error"
+        }
+    }
+}
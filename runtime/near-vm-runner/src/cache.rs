@@ -21,7 +21,9 @@ use std::sync::Arc;
 #[cfg(not(windows))]
 use rand::Rng as _;
 #[cfg(not(windows))]
-use std::io::{Read, Write};
+use sha2::{Digest as _, Sha256};
+#[cfg(not(windows))]
+use std::io::{Read, Seek, SeekFrom, Write};
 
 #[derive(Debug, Clone, BorshSerialize, ProtocolSchema)]
 enum ContractCacheKey {
@@ -227,6 +229,7 @@ pub struct FilesystemContractRuntimeCache {
 #[cfg(not(windows))]
 struct FilesystemContractRuntimeCacheState {
     dir: rustix::fd::OwnedFd,
+    path: std::path::PathBuf,
     any_cache: AnyCache,
     test_temp_dir: Option<tempfile::TempDir>,
 }
@@ -267,6 +270,7 @@ impl FilesystemContractRuntimeCache {
         Ok(Self {
             state: Arc::new(FilesystemContractRuntimeCacheState {
                 dir,
+                path,
                 any_cache: AnyCache::new(memory_cache_size),
                 test_temp_dir: None,
             }),
@@ -279,6 +283,149 @@ impl FilesystemContractRuntimeCache {
         Arc::get_mut(&mut cache.state).unwrap().test_temp_dir = Some(tempdir);
         Ok(cache)
     }
+
+    /// Writes all the compiled contracts of the cache to `writer`, in a format which can be
+    /// imported with [`Self::import`] by another node of the same build. `build_id` identifies
+    /// the build of the node, e.g. its version and commit. Returns the number of exported
+    /// contracts.
+    ///
+    /// The cache keys don't tell which config they have been computed with, so the contracts
+    /// compiled for other configs (e.g. of older protocol versions) are exported too. They are
+    /// harmless: the importing node never looks them up.
+    pub fn export(
+        &self,
+        config: &Config,
+        build_id: &str,
+        mut writer: impl Write + Seek,
+    ) -> std::io::Result<u64> {
+        // The checksum of the entries is only known once they are written, so the header is
+        // written again at the end.
+        let start = writer.stream_position()?;
+        borsh::to_writer(&mut writer, &ContractCacheExportHeader::new(config, build_id, [0; 32]))?;
+        let mut hasher = Sha256::new();
+        let mut write_entry =
+            |entry: &Option<(CryptoHash, CompiledContractInfo)>| -> std::io::Result<()> {
+                let bytes = borsh::to_vec(entry)?;
+                hasher.update(&bytes);
+                writer.write_all(&bytes)
+            };
+        let mut count = 0;
+        for entry in std::fs::read_dir(&self.state.path)? {
+            // Temporary files of concurrent `put`s are skipped, since their names aren't keys.
+            let name = entry?.file_name();
+            let Some(key) = name.to_str().and_then(|name| name.parse::<CryptoHash>().ok()) else {
+                continue;
+            };
+            let Some(value) = self.get(&key)? else {
+                continue;
+            };
+            write_entry(&Some((key, value)))?;
+            count += 1;
+        }
+        write_entry(&None)?;
+        let end = writer.stream_position()?;
+        writer.seek(SeekFrom::Start(start))?;
+        let header = ContractCacheExportHeader::new(config, build_id, hasher.finalize().into());
+        borsh::to_writer(&mut writer, &header)?;
+        writer.seek(SeekFrom::Start(end))?;
+        writer.flush()?;
+        Ok(count)
+    }
+
+    /// Adds the compiled contracts written by [`Self::export`] to the cache. Returns the number
+    /// of contracts which were not in the cache yet.
+    ///
+    /// Fails if the contracts have been exported by a node of another build, as given by
+    /// `build_id`, or with another config, since this node would never look them up. The
+    /// checksum of the file is verified before any contract is added to the cache.
+    ///
+    /// The compiled contracts are machine code which is executed without being checked, so the
+    /// file must come from a trusted source, e.g. another node of the same operator. The
+    /// checksum only detects files which were damaged after they were written, anybody can
+    /// write a file with a valid checksum.
+    pub fn import(
+        &self,
+        config: &Config,
+        build_id: &str,
+        mut reader: impl Read + Seek,
+    ) -> std::io::Result<u64> {
+        let header = ContractCacheExportHeader::deserialize_reader(&mut reader)?;
+        let expected_header = ContractCacheExportHeader::new(config, build_id, header.checksum);
+        if header != expected_header {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "compiled contracts were exported with {header:?}, expected {expected_header:?}"
+                ),
+            ));
+        }
+        let entries_start = reader.stream_position()?;
+        let mut hasher = Sha256::new();
+        let mut buf = vec![0; 64 * 1024];
+        loop {
+            let len = reader.read(&mut buf)?;
+            if len == 0 {
+                break;
+            }
+            hasher.update(&buf[..len]);
+        }
+        if <[u8; 32]>::from(hasher.finalize()) != header.checksum {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "checksum of the exported compiled contracts doesn't match",
+            ));
+        }
+        reader.seek(SeekFrom::Start(entries_start))?;
+        let mut count = 0;
+        while let Some((key, value)) =
+            Option::<(CryptoHash, CompiledContractInfo)>::deserialize_reader(&mut reader)?
+        {
+            if !self.has(&key)? {
+                self.put(&key, value)?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+}
+
+/// Bytes at the start of the files written by [`FilesystemContractRuntimeCache::export`].
+#[cfg(not(windows))]
+const EXPORT_MAGIC: [u8; 8] = *b"NEARCCC\0";
+/// Version of the format of the files written by [`FilesystemContractRuntimeCache::export`].
+#[cfg(not(windows))]
+const EXPORT_FORMAT_VERSION: u32 = 2;
+
+/// Header of the files written by [`FilesystemContractRuntimeCache::export`].
+///
+/// The cache keys depend on the VM and its config, so the contracts can only be imported by a
+/// node computing the same header.
+#[cfg(not(windows))]
+#[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+struct ContractCacheExportHeader {
+    magic: [u8; 8],
+    format_version: u32,
+    build_id: String,
+    vm_kind: String,
+    vm_hash: u64,
+    vm_config_non_crypto_hash: u64,
+    /// SHA-256 of the entries following the header.
+    checksum: [u8; 32],
+}
+
+#[cfg(not(windows))]
+impl ContractCacheExportHeader {
+    fn new(config: &Config, build_id: &str, checksum: [u8; 32]) -> Self {
+        Self {
+            magic: EXPORT_MAGIC,
+            format_version: EXPORT_FORMAT_VERSION,
+            build_id: build_id.to_string(),
+            vm_kind: format!("{:?}", config.vm_kind),
+            vm_hash: vm_hash(config.vm_kind),
+            vm_config_non_crypto_hash: config.non_crypto_hash(),
+            checksum,
+        }
+    }
 }
 
 /// Byte added after a serialized payload representing a compilation failure.
@@ -670,6 +817,54 @@ mod tests {
         assert!(matches!(result, Err("mikan")));
     }
 
+    #[cfg(not(windows))]
+    #[test]
+    fn test_export_import() {
+        let config = crate::tests::test_vm_config();
+        let cache = FilesystemContractRuntimeCache::test().unwrap();
+        let compiled = |wasm_bytes| CompiledContractInfo {
+            wasm_bytes,
+            compiled: CompiledContract::Code(vec![wasm_bytes as u8; 10]),
+        };
+        let key1 = CryptoHash::hash_bytes(b"contract1");
+        let key2 = CryptoHash::hash_bytes(b"contract2");
+        cache.put(&key1, compiled(1)).unwrap();
+        cache.put(&key2, compiled(2)).unwrap();
+
+        let mut exported = std::io::Cursor::new(vec![]);
+        assert_eq!(cache.export(&config, "build", &mut exported).unwrap(), 2);
+        let exported = exported.into_inner();
+        let import = |config: &Config, build_id, exported: &[u8]| {
+            let cache = FilesystemContractRuntimeCache::test().unwrap();
+            cache.import(config, build_id, std::io::Cursor::new(exported))
+        };
+
+        let other_cache = FilesystemContractRuntimeCache::test().unwrap();
+        other_cache.put(&key1, compiled(1)).unwrap();
+        let imported = other_cache.import(&config, "build", std::io::Cursor::new(&exported));
+        assert_eq!(imported.unwrap(), 1);
+        assert_eq!(other_cache.get(&key2).unwrap(), Some(compiled(2)));
+
+        // Contracts exported with another config or by another build can't be imported.
+        let mut other_config = config.clone();
+        other_config.regular_op_cost += 1;
+        let err = import(&other_config, "build", &exported).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let err = import(&config, "other build", &exported).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        // Nothing is imported from a damaged file.
+        let mut damaged = exported.clone();
+        *damaged.last_mut().unwrap() ^= 1;
+        let err = import(&config, "build", &damaged).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let mut damaged = exported.clone();
+        let len = damaged.len();
+        damaged[len - 5] ^= 1;
+        let err = import(&config, "build", &damaged).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
     #[cfg(feature = "test_features")]
     #[test]
     fn test_clear_compiled_contract_cache() {
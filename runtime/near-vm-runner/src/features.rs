@@ -6,7 +6,6 @@ use opts::*;
 mod opts {
     pub(super) const REFERENCE_TYPES: bool = false;
     pub(super) const MULTI_VALUE: bool = false;
-    pub(super) const BULK_MEMORY: bool = false;
     pub(super) const SIMD: bool = false;
     pub(super) const THREADS: bool = false;
     pub(super) const TAIL_CALL: bool = false;
@@ -27,12 +26,16 @@ mod opts {
 #[allow(unused)]
 pub struct WasmFeatures {
     saturating_float_to_int: bool,
+    bulk_memory: bool,
 }
 
 impl WasmFeatures {
     #[allow(unused)]
     pub fn new(config: &vm::Config) -> Self {
-        Self { saturating_float_to_int: config.saturating_float_to_int }
+        Self {
+            saturating_float_to_int: config.saturating_float_to_int,
+            bulk_memory: config.bulk_memory,
+        }
     }
 }
 
@@ -48,7 +51,7 @@ impl From<WasmFeatures> for finite_wasm::wasmparser::WasmFeatures {
             reference_types: REFERENCE_TYPES,
             // wasmer singlepass compiler requires multi_value return values to be disabled.
             multi_value: MULTI_VALUE,
-            bulk_memory: BULK_MEMORY,
+            bulk_memory: f.bulk_memory,
             simd: SIMD,
             threads: THREADS,
             tail_call: TAIL_CALL,
@@ -76,7 +79,7 @@ impl From<WasmFeatures> for near_vm_types::Features {
             threads: THREADS,
             reference_types: REFERENCE_TYPES,
             simd: SIMD,
-            bulk_memory: BULK_MEMORY,
+            bulk_memory: f.bulk_memory,
             multi_value: MULTI_VALUE,
             tail_call: TAIL_CALL,
             multi_memory: MULTI_MEMORY,
@@ -120,6 +120,8 @@ imports! {
     sha256<[value_len: u64, value_ptr: u64, register_id: u64] -> []>,
     keccak256<[value_len: u64, value_ptr: u64, register_id: u64] -> []>,
     keccak512<[value_len: u64, value_ptr: u64, register_id: u64] -> []>,
+    #[extra_hash_host_functions] sha3_256<[value_len: u64, value_ptr: u64, register_id: u64] -> []>,
+    #[extra_hash_host_functions] blake2b<[value_len: u64, value_ptr: u64, register_id: u64] -> []>,
     ed25519_verify<[sig_len: u64,
         sig_ptr: u64,
         msg_len: u64,
@@ -129,6 +131,13 @@ imports! {
     ] -> [u64]>,
     ripemd160<[value_len: u64, value_ptr: u64, register_id: u64] -> []>,
     ecrecover<[hash_len: u64, hash_ptr: u64, sign_len: u64, sig_ptr: u64, v: u64, malleability_flag: u64, register_id: u64] -> [u64]>,
+    #[p256_verify] p256_verify<[sig_len: u64,
+        sig_ptr: u64,
+        msg_len: u64,
+        msg_ptr: u64,
+        pub_key_len: u64,
+        pub_key_ptr: u64
+    ] -> [u64]>,
     // #####################
     // # Miscellaneous API #
     // #####################
@@ -254,9 +263,24 @@ imports! {
     storage_read<[key_len: u64, key_ptr: u64, register_id: u64] -> [u64]>,
     storage_remove<[key_len: u64, key_ptr: u64, register_id: u64] -> [u64]>,
     storage_has_key<[key_len: u64, key_ptr: u64] -> [u64]>,
+    #[cross_account_storage_read] storage_read_account<[
+        account_id_len: u64,
+        account_id_ptr: u64,
+        key_len: u64,
+        key_ptr: u64,
+        register_id: u64
+    ] -> [u64]>,
     storage_iter_prefix<[prefix_len: u64, prefix_ptr: u64] -> [u64]>,
     storage_iter_range<[start_len: u64, start_ptr: u64, end_len: u64, end_ptr: u64] -> [u64]>,
     storage_iter_next<[iterator_id: u64, key_register_id: u64, value_register_id: u64] -> [u64]>,
+    #[storage_iter_batch] storage_iter_batch<[
+        start_len: u64,
+        start_ptr: u64,
+        end_len: u64,
+        end_ptr: u64,
+        limit: u64,
+        register_id: u64
+    ] -> [u64]>,
     // Function for the injected gas counter. Automatically called by the gas meter.
     @as gas: gas_seen_from_wasm<[opcodes: u32] -> []>,
     // ###############
@@ -270,6 +294,7 @@ imports! {
     alt_bn128_g1_multiexp<[value_len: u64, value_ptr: u64, register_id: u64] -> []>,
     alt_bn128_g1_sum<[value_len: u64, value_ptr: u64, register_id: u64] -> []>,
     alt_bn128_pairing_check<[value_len: u64, value_ptr: u64] -> [u64]>,
+    #[extra_hash_host_functions] alt_bn128_poseidon<[value_len: u64, value_ptr: u64, register_id: u64] -> []>,
     // #############
     // # BLS12-381 #
     // #############
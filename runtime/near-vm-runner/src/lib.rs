@@ -1,6 +1,7 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(enable_const_type_id, feature(const_type_id))]
 
+mod background_compiler;
 mod cache;
 mod errors;
 mod features;
@@ -18,9 +19,12 @@ mod runner;
 mod tests;
 mod utils;
 #[cfg(feature = "wasmtime_vm")]
+mod wasm_profiler;
+#[cfg(feature = "wasmtime_vm")]
 mod wasmtime_runner;
 
 pub use crate::logic::with_ext_cost_counter;
+pub use background_compiler::BackgroundCompiler;
 #[cfg(not(windows))]
 pub use cache::FilesystemContractRuntimeCache;
 pub use cache::{
@@ -32,6 +36,10 @@ pub use metrics::{report_metrics, reset_metrics};
 pub use near_primitives_core::code::ContractCode;
 pub use profile::ProfileDataV3;
 pub use runner::{Contract, PreparedContract, VM, prepare, run};
+#[cfg(feature = "wasmtime_vm")]
+pub use wasm_profiler::{
+    CONTRACT_LOADING, WasmGasProfile, configure_for_wasm_profiling, profile_wasm_gas,
+};
 
 /// This is public for internal experimentation use only, and should otherwise be considered an
 /// implementation detail of `near-vm-runner`.
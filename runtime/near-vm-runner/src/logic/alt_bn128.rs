@@ -2,7 +2,7 @@ use super::{HostError, VMLogicError};
 use bn::Group;
 
 const BOOL_SIZE: usize = 1;
-const SCALAR_SIZE: usize = 256 / 8;
+pub(super) const SCALAR_SIZE: usize = 256 / 8;
 const POINT_SIZE: usize = SCALAR_SIZE * 2;
 
 pub(super) struct InvalidInput {
@@ -105,6 +105,10 @@ fn encode_fq(val: bn::Fq) -> [u8; SCALAR_SIZE] {
     encode_u256(val.into_u256())
 }
 
+pub(super) fn encode_fr(val: bn::Fr) -> [u8; SCALAR_SIZE] {
+    encode_u256(val.into_u256())
+}
+
 fn encode_u256(val: bn::arith::U256) -> [u8; SCALAR_SIZE] {
     let [lo, hi] = val.0;
     stdx::join_array(lo.to_le_bytes(), hi.to_le_bytes())
@@ -148,7 +152,7 @@ fn decode_fq2(raw: &[u8; 2 * SCALAR_SIZE]) -> Result<bn::Fq2, InvalidInput> {
     Ok(bn::Fq2::new(real, imaginary))
 }
 
-fn decode_fr(raw: &[u8; SCALAR_SIZE]) -> Result<bn::Fr, InvalidInput> {
+pub(super) fn decode_fr(raw: &[u8; SCALAR_SIZE]) -> Result<bn::Fr, InvalidInput> {
     let val = decode_u256(raw);
     bn::Fr::new(val).ok_or_else(|| InvalidInput::new("invalid fr", raw))
 }
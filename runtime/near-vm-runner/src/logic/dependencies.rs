@@ -256,6 +256,70 @@ pub trait External {
         key: &[u8],
     ) -> Result<bool>;
 
+    /// Read `key` from the storage trie associated with `account_id`, as of the state before the
+    /// chunk that is being applied.
+    ///
+    /// Unlike [`Self::storage_get`] this does not observe the changes made in the current chunk,
+    /// including the changes made by the current account.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HostError::StorageReadAccountOnOtherShard`](super::HostError) if `account_id`
+    /// does not live on the shard of the current account.
+    ///
+    /// # Example
+    /// ```
+    /// # use near_vm_runner::logic::mocks::mock_external::MockedExternal;
+    /// # use near_vm_runner::logic::{External, ValuePtr};
+    /// # use near_vm_runner::logic::gas_counter::FreeGasCounter;
+    /// # use near_primitives_core::types::AccountId;
+    ///
+    /// # let mut external = MockedExternal::new();
+    /// let mut gas = FreeGasCounter;
+    /// let oracle: AccountId = "oracle.near".parse().unwrap();
+    /// external.accounts_storage.insert((oracle.clone(), b"price".to_vec()), b"42".to_vec());
+    /// assert_eq!(external.storage_get_account(&mut gas, &oracle, b"price").unwrap().map(|ptr| ptr.deref(&mut gas).unwrap()), Some(b"42".to_vec()));
+    /// // Returns Ok(None) if there is no value for a key
+    /// assert_eq!(external.storage_get_account(&mut gas, &oracle, b"no_key").unwrap().map(|ptr| ptr.deref(&mut gas).unwrap()), None);
+    /// ```
+    fn storage_get_account<'a>(
+        &'a self,
+        access_tracker: &mut dyn StorageAccessTracker,
+        account_id: &AccountId,
+        key: &[u8],
+    ) -> Result<Option<Box<dyn ValuePtr + 'a>>>;
+
+    /// Returns up to `limit` keys of the storage trie associated with the current account, in
+    /// ascending order, which are not smaller than `start` and, if `end` is given, smaller than
+    /// `end`.
+    ///
+    /// The changes made in the current chunk, including the ones made by the current execution,
+    /// are observed. Only the keys are returned, the values are not read and are expected to be
+    /// read with [`Self::storage_get`]. The trie nodes visited to find the keys are reported to
+    /// `access_tracker`.
+    ///
+    /// # Example
+    /// ```
+    /// # use near_vm_runner::logic::mocks::mock_external::MockedExternal;
+    /// # use near_vm_runner::logic::gas_counter::FreeGasCounter;
+    /// # use near_vm_runner::logic::{External};
+    ///
+    /// # let mut external = MockedExternal::new();
+    /// let mut gas = FreeGasCounter;
+    /// external.storage_set(&mut gas, b"a", b"1").unwrap();
+    /// external.storage_set(&mut gas, b"b", b"2").unwrap();
+    /// external.storage_set(&mut gas, b"c", b"3").unwrap();
+    /// assert_eq!(external.storage_iter_keys(&mut gas, b"b", None, 10), Ok(vec![b"b".to_vec(), b"c".to_vec()]));
+    /// assert_eq!(external.storage_iter_keys(&mut gas, b"a", Some(b"c"), 1), Ok(vec![b"a".to_vec()]));
+    /// ```
+    fn storage_iter_keys(
+        &self,
+        access_tracker: &mut dyn StorageAccessTracker,
+        start: &[u8],
+        end: Option<&[u8]>,
+        limit: u64,
+    ) -> Result<Vec<Vec<u8>>>;
+
     fn generate_data_id(&mut self) -> CryptoHash;
 
     /// Size of the recorded trie storage proof.
@@ -265,6 +265,11 @@ pub enum HostError {
     BLS12381InvalidInput {
         msg: String,
     },
+    /// Invalid input to P-256 signature verification function (e.g. signature
+    /// or public key of a wrong length).
+    P256VerifyInvalidInput {
+        msg: String,
+    },
     /// Yield payload length exceeds the maximum permitted.
     YieldPayloadLength {
         length: u64,
@@ -276,6 +281,11 @@ pub enum HostError {
     RecordedStorageExceeded {
         limit: ByteSize,
     },
+    /// The storage of `account_id` was read, but the account does not live on the shard of the
+    /// current account.
+    StorageReadAccountOnOtherShard {
+        account_id: String,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -509,6 +519,9 @@ impl std::fmt::Display for HostError {
                 write!(f, "ED25519 signature verification error: {}", msg)
             }
             BLS12381InvalidInput { msg } => write!(f, "BLS12-381 invalid input: {}", msg),
+            P256VerifyInvalidInput { msg } => {
+                write!(f, "P-256 signature verification error: {}", msg)
+            }
             YieldPayloadLength { length, limit } => write!(
                 f,
                 "Yield resume payload is {length} bytes which exceeds the {limit} byte limit"
@@ -519,6 +532,10 @@ impl std::fmt::Display for HostError {
                 "Size of the recorded trie storage proof has exceeded the allowed limit ({})",
                 limit
             ),
+            StorageReadAccountOnOtherShard { account_id } => write!(
+                f,
+                "Cannot read the storage of {account_id} which is not on the shard of the current account"
+            ),
         }
     }
 }
@@ -70,6 +70,12 @@ impl ExecutionResultState {
         }
     }
 
+    /// Gas burnt so far by the execution.
+    #[cfg(feature = "wasmtime_vm")]
+    pub(crate) fn burnt_gas(&self) -> Gas {
+        self.gas_counter.burnt_gas()
+    }
+
     /// A helper function to subtract balance on transfer or attached deposit for promises.
     ///
     /// ### Args
@@ -983,6 +989,54 @@ impl<'a> VMLogic<'a> {
         Ok(res as u64)
     }
 
+    /// Computes the Poseidon hash of a sequence of alt_bn128 scalar field
+    /// elements, with the parameters used by circomlib, and writes the
+    /// resulting field element into `register_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - sequence of 1 to 12 elements of Fr, encoded as packed,
+    ///   little-endian `[u256]` slice, like the scalars of
+    ///   `alt_bn128_g1_multiexp`. The output is encoded the same way.
+    ///
+    /// # Errors
+    ///
+    /// If `value_len + value_ptr` points outside the memory or the registers
+    /// use more memory than the limit the function returns `MemoryAccessViolation`.
+    ///
+    /// If an element is not in the field, the number of elements is not
+    /// between 1 and 12 or `value.len()%32!=0`, the function returns
+    /// `AltBn128InvalidInput`.
+    ///
+    /// # Cost
+    ///
+    /// `base + write_register_base + write_register_byte * num_bytes + alt_bn128_poseidon_base + alt_bn128_poseidon_element * (num_elements + 1)^2`
+    ///
+    /// The state of the permutation holds the inputs and one more element, and
+    /// each round multiplies it with a square MDS matrix of the same width.
+    pub fn alt_bn128_poseidon(
+        &mut self,
+        value_len: u64,
+        value_ptr: u64,
+        register_id: u64,
+    ) -> Result<()> {
+        self.result_state.gas_counter.pay_base(alt_bn128_poseidon_base)?;
+        let data = get_memory_or_register!(self, value_ptr, value_len)?;
+
+        let elements = super::alt_bn128::split_elements(&data)?;
+        let width = elements.len() as u64 + 1;
+        self.result_state.gas_counter.pay_per(alt_bn128_poseidon_element, width * width)?;
+
+        let res = super::poseidon::poseidon(elements)?;
+
+        self.registers.set(
+            &mut self.result_state.gas_counter,
+            &self.config.limit_config,
+            register_id,
+            res,
+        )
+    }
+
     bls12381_impl!(
         r"Calculates the sum of signed elements on the BLS12-381 curve.
 It accepts an arbitrary number of pairs (sign_i, p_i),
@@ -1483,6 +1537,62 @@ bls12381_p2_decompress_base + bls12381_p2_decompress_element * num_elements`
         )
     }
 
+    /// Hashes the given value using SHA3-256 and returns it into `register_id`.
+    ///
+    /// Unlike `keccak256`, this is the hash function standardized in FIPS 202,
+    /// which uses a different padding.
+    ///
+    /// # Errors
+    ///
+    /// If `value_len + value_ptr` points outside the memory or the registers use more memory than
+    /// the limit with `MemoryAccessViolation`.
+    ///
+    /// # Cost
+    ///
+    /// `base + write_register_base + write_register_byte * num_bytes + sha3_256_base + sha3_256_byte * num_bytes`
+    pub fn sha3_256(&mut self, value_len: u64, value_ptr: u64, register_id: u64) -> Result<()> {
+        self.result_state.gas_counter.pay_base(sha3_256_base)?;
+        let value = get_memory_or_register!(self, value_ptr, value_len)?;
+        self.result_state.gas_counter.pay_per(sha3_256_byte, value.len() as u64)?;
+
+        use sha3::Digest;
+
+        let value_hash = sha3::Sha3_256::digest(&value);
+        self.registers.set(
+            &mut self.result_state.gas_counter,
+            &self.config.limit_config,
+            register_id,
+            value_hash.as_slice(),
+        )
+    }
+
+    /// Hashes the given value using BLAKE2b with a 32 byte digest (BLAKE2b-256)
+    /// and returns it into `register_id`.
+    ///
+    /// # Errors
+    ///
+    /// If `value_len + value_ptr` points outside the memory or the registers use more memory than
+    /// the limit with `MemoryAccessViolation`.
+    ///
+    /// # Cost
+    ///
+    /// `base + write_register_base + write_register_byte * num_bytes + blake2b_base + blake2b_byte * num_bytes`
+    pub fn blake2b(&mut self, value_len: u64, value_ptr: u64, register_id: u64) -> Result<()> {
+        self.result_state.gas_counter.pay_base(blake2b_base)?;
+        let value = get_memory_or_register!(self, value_ptr, value_len)?;
+        self.result_state.gas_counter.pay_per(blake2b_byte, value.len() as u64)?;
+
+        use blake2::Digest;
+
+        let value_hash = blake2::Blake2b::<blake2::digest::consts::U32>::digest(&value);
+        self.registers.set(
+            &mut self.result_state.gas_counter,
+            &self.config.limit_config,
+            register_id,
+            value_hash.as_slice(),
+        )
+    }
+
     /// Hashes the given value using RIPEMD-160 and returns it into `register_id`.
     ///
     /// # Errors
@@ -1693,6 +1803,74 @@ bls12381_p2_decompress_base + bls12381_p2_decompress_element * num_elements`
         }
     }
 
+    /// Verify a secp256r1 (P-256) ECDSA signature given a message and a public
+    /// key. The message is hashed with SHA-256 as part of the verification,
+    /// which is what WebAuthn authenticators sign.
+    ///
+    /// The signature is expected as the 64 byte concatenation of the `r` and
+    /// `s` scalars and the public key as a 65 byte uncompressed SEC1 point
+    /// (`0x04 || x || y`).
+    ///
+    /// Returns a bool indicating success (1) or failure (0) as a `u64`.
+    ///
+    /// # Errors
+    ///
+    /// * If the public key's size is not equal to 65, or signature size is not
+    ///   equal to 64, returns [HostError::P256VerifyInvalidInput].
+    /// * If any of the signature, message or public key arguments are out of
+    ///   memory bounds, returns [`HostError::MemoryAccessViolation`]
+    ///
+    /// # Cost
+    ///
+    /// Inputs are read the same way as in [`Self::ed25519_verify`], see there
+    /// for the definition of `input_cost`.
+    ///
+    /// `input_cost(num_bytes_signature) + input_cost(num_bytes_message) +
+    ///  input_cost(num_bytes_public_key) + p256_verify_base +
+    ///  p256_verify_byte * num_bytes_message`
+    pub fn p256_verify(
+        &mut self,
+        signature_len: u64,
+        signature_ptr: u64,
+        message_len: u64,
+        message_ptr: u64,
+        public_key_len: u64,
+        public_key_ptr: u64,
+    ) -> Result<u64> {
+        const SIGNATURE_LENGTH: usize = 64;
+        const PUBLIC_KEY_LENGTH: usize = 65;
+
+        self.result_state.gas_counter.pay_base(p256_verify_base)?;
+
+        let signature = get_memory_or_register!(self, signature_ptr, signature_len)?;
+        if signature.len() != SIGNATURE_LENGTH {
+            return Err(VMLogicError::HostError(HostError::P256VerifyInvalidInput {
+                msg: "invalid signature length".to_string(),
+            }));
+        }
+
+        let message = get_memory_or_register!(self, message_ptr, message_len)?;
+        self.result_state.gas_counter.pay_per(p256_verify_byte, message.len() as u64)?;
+
+        let public_key = get_memory_or_register!(self, public_key_ptr, public_key_len)?;
+        if public_key.len() != PUBLIC_KEY_LENGTH {
+            return Err(VMLogicError::HostError(HostError::P256VerifyInvalidInput {
+                msg: "invalid public key length".to_string(),
+            }));
+        }
+
+        // Public keys that are not on the curve are rejected by `verify` like any
+        // other invalid signature.
+        let public_key = ring::signature::UnparsedPublicKey::new(
+            &ring::signature::ECDSA_P256_SHA256_FIXED,
+            &public_key[..],
+        );
+        match public_key.verify(&message, &signature) {
+            Err(_) => Ok(false as u64),
+            Ok(()) => Ok(true as u64),
+        }
+    }
+
     /// Consume gas. Counts both towards `burnt_gas` and `used_gas`.
     ///
     /// # Errors
@@ -3248,6 +3426,186 @@ bls12381_p2_decompress_base + bls12381_p2_decompress_element * num_elements`
         Ok(res? as u64)
     }
 
+    /// Reads the value stored under the given key in the storage of another account, which must
+    /// live on the same shard as the current account. The value is read from the state before
+    /// the chunk that is being applied, so the changes made by the receipts of the chunk,
+    /// including the ones of the current execution, are not visible.
+    /// * If key is used copies the content of the value into the `register_id`, even if the content
+    ///   is zero bytes. Returns `1`;
+    /// * If key is not present then does not modify the register. Returns `0`;
+    ///
+    /// # Errors
+    ///
+    /// * If `account_id_len + account_id_ptr` or `key_len + key_ptr` exceeds the memory container
+    ///   or points to an unused register it returns `MemoryAccessViolation`;
+    /// * If the account id is not UTF-8 returns `BadUTF8`;
+    /// * If the account id is not a valid account id returns `InvalidAccountId`;
+    /// * If the length of the key exceeds `max_length_storage_key` returns `KeyLengthExceeded`;
+    /// * If the account does not live on the shard of the current account returns
+    ///   `StorageReadAccountOnOtherShard`;
+    /// * If returning the value into the registers exceed the memory container it returns
+    ///   `MemoryAccessViolation`.
+    ///
+    /// # Cost
+    ///
+    /// `base + storage_read_account_base + storage_read_account_key_byte * (num_account_id_bytes + num_key_bytes)
+    ///  + storage_read_account_value_byte * num_value_bytes + cost to read the account id and the key
+    ///  + cost to write value into register`.
+    pub fn storage_read_account(
+        &mut self,
+        account_id_len: u64,
+        account_id_ptr: u64,
+        key_len: u64,
+        key_ptr: u64,
+        register_id: u64,
+    ) -> Result<u64> {
+        self.result_state.gas_counter.pay_base(base)?;
+        self.result_state.gas_counter.pay_base(storage_read_account_base)?;
+        let account_id = self.read_and_parse_account_id(account_id_ptr, account_id_len)?;
+        if AccountId::validate(account_id.as_str()).is_err() {
+            return Err(HostError::InvalidAccountId.into());
+        }
+        let key = get_memory_or_register!(self, key_ptr, key_len)?;
+        if key.len() as u64 > self.config.limit_config.max_length_storage_key {
+            return Err(HostError::KeyLengthExceeded {
+                length: key.len() as u64,
+                limit: self.config.limit_config.max_length_storage_key,
+            }
+            .into());
+        }
+        self.result_state
+            .gas_counter
+            .pay_per(storage_read_account_key_byte, (account_id.len() + key.len()) as u64)?;
+        let read =
+            self.ext.storage_get_account(&mut self.result_state.gas_counter, &account_id, &key);
+        let read = match read? {
+            Some(read) => {
+                // Here we'll do u32 -> usize -> u64, which is always infallible
+                let read_len = read.len() as usize;
+                self.result_state
+                    .gas_counter
+                    .pay_per(storage_read_account_value_byte, read_len as u64)?;
+                if read_len > INLINE_DISK_VALUE_THRESHOLD {
+                    self.result_state.gas_counter.pay_base(storage_large_read_overhead_base)?;
+                    self.result_state
+                        .gas_counter
+                        .pay_per(storage_large_read_overhead_byte, read_len as u64)?;
+                }
+                Some(read.deref(&mut FreeGasCounter)?)
+            }
+            None => None,
+        };
+
+        self.recorded_storage_counter.observe_size(self.ext.get_recorded_storage_size())?;
+        match read {
+            Some(value) => {
+                self.registers.set(
+                    &mut self.result_state.gas_counter,
+                    &self.config.limit_config,
+                    register_id,
+                    value,
+                )?;
+                Ok(1)
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// Reads a batch of the key-value pairs stored by the current account with keys between
+    /// `start` and `end`, where `start` is inclusive and `end` is exclusive. An empty `end` means
+    /// that the range is not bounded from above. The pairs are visited in the lexicographic order
+    /// of the keys and at most `min(limit, max_storage_iter_entries)` of them are returned, so a
+    /// contract scans a larger range by calling this again with `start` set to the successor of
+    /// the last returned key.
+    ///
+    /// The pairs are written into `register_id` as a borsh-serialized `Vec<(Vec<u8>, Vec<u8>)>`,
+    /// even if there are none. Returns the number of the pairs.
+    ///
+    /// # Errors
+    ///
+    /// * If `start_len + start_ptr` or `end_len + end_ptr` exceeds the memory container or points
+    ///   to an unused register it returns `MemoryAccessViolation`;
+    /// * If the length of `start` or `end` exceeds `max_length_storage_key` returns
+    ///   `KeyLengthExceeded`;
+    /// * If returning the pairs into the registers exceed the memory container it returns
+    ///   `MemoryAccessViolation`.
+    ///
+    /// # Cost
+    ///
+    /// `base + storage_iter_batch_base + storage_iter_batch_bound_byte * (num_start_bytes + num_end_bytes)
+    ///  + storage_iter_batch_entry_base * num_pairs + storage_iter_batch_key_byte * num_key_bytes
+    ///  + storage_iter_batch_value_byte * num_value_bytes + cost to read the range bounds
+    ///  + cost to write the pairs into register`.
+    ///
+    /// The trie nodes visited to find the keys and touched while looking up the values are paid
+    /// for the same way as in `storage_read`.
+    pub fn storage_iter_batch(
+        &mut self,
+        start_len: u64,
+        start_ptr: u64,
+        end_len: u64,
+        end_ptr: u64,
+        limit: u64,
+        register_id: u64,
+    ) -> Result<u64> {
+        self.result_state.gas_counter.pay_base(base)?;
+        self.result_state.gas_counter.pay_base(storage_iter_batch_base)?;
+        let start = get_memory_or_register!(self, start_ptr, start_len)?;
+        let end = get_memory_or_register!(self, end_ptr, end_len)?;
+        for bound in [&start, &end] {
+            if bound.len() as u64 > self.config.limit_config.max_length_storage_key {
+                return Err(HostError::KeyLengthExceeded {
+                    length: bound.len() as u64,
+                    limit: self.config.limit_config.max_length_storage_key,
+                }
+                .into());
+            }
+        }
+        self.result_state
+            .gas_counter
+            .pay_per(storage_iter_batch_bound_byte, (start.len() + end.len()) as u64)?;
+        let limit = limit.min(self.config.limit_config.max_storage_iter_entries);
+        let end = if end.is_empty() { None } else { Some(&*end) };
+        let keys =
+            self.ext.storage_iter_keys(&mut self.result_state.gas_counter, &start, end, limit)?;
+
+        let mut entries = Vec::with_capacity(keys.len());
+        for key in keys {
+            self.result_state.gas_counter.pay_base(storage_iter_batch_entry_base)?;
+            self.result_state.gas_counter.pay_per(storage_iter_batch_key_byte, key.len() as u64)?;
+            // The keys come from the same view of the state as the values, so every key is
+            // expected to have a value.
+            let Some(read) = self.ext.storage_get(&mut self.result_state.gas_counter, &key)? else {
+                continue;
+            };
+            // Here we'll do u32 -> usize -> u64, which is always infallible
+            let read_len = read.len() as usize;
+            self.result_state
+                .gas_counter
+                .pay_per(storage_iter_batch_value_byte, read_len as u64)?;
+            if read_len > INLINE_DISK_VALUE_THRESHOLD {
+                self.result_state.gas_counter.pay_base(storage_large_read_overhead_base)?;
+                self.result_state
+                    .gas_counter
+                    .pay_per(storage_large_read_overhead_byte, read_len as u64)?;
+            }
+            entries.push((key, read.deref(&mut FreeGasCounter)?));
+        }
+
+        self.recorded_storage_counter.observe_size(self.ext.get_recorded_storage_size())?;
+        let num_entries = entries.len() as u64;
+        // Serialization only fails for collections longer than `u32::MAX`, which the gas limit
+        // rules out.
+        let data = borsh::to_vec(&entries).expect("failed serializing storage entries");
+        self.registers.set(
+            &mut self.result_state.gas_counter,
+            &self.config.limit_config,
+            register_id,
+            data,
+        )?;
+        Ok(num_entries)
+    }
+
     /// Debug print given utf-8 string to node log. It's only available in Sandbox node
     ///
     /// # Errors
@@ -1,10 +1,10 @@
 use crate::ContractCode;
 use crate::logic::dependencies::{Result, StorageAccessTracker};
 use crate::logic::types::ReceiptIndex;
-use crate::logic::{External, ValuePtr};
+use crate::logic::{External, HostError, ValuePtr};
 use near_primitives_core::hash::{CryptoHash, hash};
 use near_primitives_core::types::{AccountId, Balance, Gas, GasWeight};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 #[derive(serde::Serialize)]
@@ -12,7 +12,7 @@ use std::sync::Arc;
 #[allow(dead_code)] // The value is never read because this is a mock.
 struct GasWeightSer(u64);
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
 pub enum MockAction {
     CreateReceipt {
         receipt_indices: Vec<ReceiptIndex>,
@@ -78,6 +78,10 @@ pub enum MockAction {
 /// Emulates the trie and the mock handling code.
 pub struct MockedExternal {
     pub fake_trie: HashMap<Vec<u8>, Vec<u8>>,
+    /// The storage of the other accounts, keyed by account and key.
+    pub accounts_storage: HashMap<(AccountId, Vec<u8>), Vec<u8>>,
+    /// Accounts that are considered to live on another shard than the current account.
+    pub other_shard_accounts: HashSet<AccountId>,
     pub validators: HashMap<AccountId, Balance>,
     pub action_log: Vec<MockAction>,
     pub code: Option<std::sync::Arc<ContractCode>>,
@@ -160,6 +164,42 @@ impl External for MockedExternal {
         Ok(self.fake_trie.contains_key(key))
     }
 
+    fn storage_get_account(
+        &self,
+        _: &mut dyn StorageAccessTracker,
+        account_id: &AccountId,
+        key: &[u8],
+    ) -> Result<Option<Box<dyn ValuePtr>>> {
+        if self.other_shard_accounts.contains(account_id) {
+            return Err(HostError::StorageReadAccountOnOtherShard {
+                account_id: account_id.to_string(),
+            }
+            .into());
+        }
+        Ok(self
+            .accounts_storage
+            .get(&(account_id.clone(), key.to_vec()))
+            .map(|value| Box::new(MockedValuePtr { value: value.clone() }) as Box<_>))
+    }
+
+    fn storage_iter_keys(
+        &self,
+        _: &mut dyn StorageAccessTracker,
+        start: &[u8],
+        end: Option<&[u8]>,
+        limit: u64,
+    ) -> Result<Vec<Vec<u8>>> {
+        let mut keys: Vec<_> = self
+            .fake_trie
+            .keys()
+            .filter(|key| key.as_slice() >= start && end.is_none_or(|end| key.as_slice() < end))
+            .cloned()
+            .collect();
+        keys.sort();
+        keys.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        Ok(keys)
+    }
+
     fn generate_data_id(&mut self) -> CryptoHash {
         // Generates some hash for the data ID to receive data. This hash should not be functionally
         // used in any mocked contexts.
@@ -6,6 +6,8 @@ pub mod errors;
 pub mod gas_counter;
 mod logic;
 pub mod mocks;
+mod poseidon;
+mod poseidon_constants;
 pub mod recorded_storage_counter;
 pub mod test_utils;
 #[cfg(test)]
@@ -0,0 +1,76 @@
+//! Poseidon hash over the scalar field of the alt_bn128 curve.
+//!
+//! The parameters are the ones used by circomlib (x^5 S-box, 8 full rounds and
+//! a width dependent number of partial rounds), with the round constants and
+//! MDS matrices of the reference implementation, so hashes computed here match
+//! the ones of circuits built with circom. The constants are converted to field
+//! elements once per width, the first time it's used.
+
+use super::alt_bn128::{InvalidInput, SCALAR_SIZE, decode_fr, encode_fr};
+use super::poseidon_constants::{MDS, ROUND_CONSTANTS};
+use std::sync::OnceLock;
+
+/// Maximal number of inputs the reference parameters are hardcoded for.
+const MAX_INPUTS: usize = 12;
+const FULL_ROUNDS: usize = 8;
+/// Number of partial rounds for width `t` is `PARTIAL_ROUNDS[t - 2]`.
+const PARTIAL_ROUNDS: [usize; MAX_INPUTS] = [56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65];
+
+struct Params {
+    round_constants: Vec<bn::Fr>,
+    mds: Vec<Vec<bn::Fr>>,
+}
+
+/// Hashes the given field elements, of which there must be between 1 and
+/// `MAX_INPUTS`.
+pub(super) fn poseidon(elements: &[[u8; SCALAR_SIZE]]) -> Result<[u8; SCALAR_SIZE], InvalidInput> {
+    if elements.is_empty() || elements.len() > MAX_INPUTS {
+        return Err(InvalidInput {
+            msg: format!(
+                "invalid number of poseidon inputs: {}, expected 1 to {MAX_INPUTS}",
+                elements.len()
+            ),
+        });
+    }
+    let mut state = Vec::with_capacity(elements.len() + 1);
+    state.push(bn::Fr::zero());
+    for element in elements {
+        state.push(decode_fr(element)?);
+    }
+
+    let width = state.len();
+    let partial_rounds = PARTIAL_ROUNDS[width - 2];
+    let params = params(width);
+    for round in 0..FULL_ROUNDS + partial_rounds {
+        let constants = &params.round_constants[round * width..(round + 1) * width];
+        for (x, c) in state.iter_mut().zip(constants) {
+            *x = *x + *c;
+        }
+        if round < FULL_ROUNDS / 2 || round >= FULL_ROUNDS / 2 + partial_rounds {
+            state.iter_mut().for_each(|x| *x = pow5(*x));
+        } else {
+            state[0] = pow5(state[0]);
+        }
+        state = params
+            .mds
+            .iter()
+            .map(|row| row.iter().zip(&state).fold(bn::Fr::zero(), |acc, (m, x)| acc + *m * *x))
+            .collect();
+    }
+    Ok(encode_fr(state[0]))
+}
+
+fn pow5(x: bn::Fr) -> bn::Fr {
+    let x2 = x * x;
+    x2 * x2 * x
+}
+
+fn params(width: usize) -> &'static Params {
+    static PARAMS: [OnceLock<Params>; MAX_INPUTS] = [const { OnceLock::new() }; MAX_INPUTS];
+    PARAMS[width - 2].get_or_init(|| {
+        let to_fr = |&[lo, hi]: &[u128; 2]| bn::Fr::new(bn::arith::U256([lo, hi])).unwrap();
+        let round_constants = ROUND_CONSTANTS[width - 2].iter().map(to_fr).collect();
+        let mds = MDS[width - 2].chunks(width).map(|row| row.iter().map(to_fr).collect()).collect();
+        Params { round_constants, mds }
+    })
+}
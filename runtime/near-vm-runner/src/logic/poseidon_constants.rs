@@ -0,0 +1,7248 @@
+//! Poseidon parameters for the scalar field of the alt_bn128 curve.
+//!
+//! The round constants and MDS matrices were generated with the
+//! `generate_parameters_grain.sage` script of the Poseidon reference
+//! implementation (https://extgit.iaik.tugraz.at/krypto/hadeshash), for an
+//! x^5 S-box, 8 full rounds and the number of partial rounds circomlib uses for
+//! each width `t`:
+//!
+//! ```bash
+//! sage generate_parameters_grain.sage 1 0 254 <t> 8 <partial rounds> 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+//! ```
+//!
+//! Field elements are stored in canonical form as little-endian pairs of
+//! `u128`, like `bn::arith::U256`. Do not edit the values manually.
+
+/// Round constants for width 2, 64 rounds of 2 elements each.
+const ROUND_CONSTANTS_2: [[u128; 2]; 128] = [
+    [0xa71aa177534cdd1b6c7dc0dbd0abd7a7, 0x09c46e9ec68e9bd4fe1faaba294cba38],
+    [0xc5142b3ae405b8343c1d83ffa604cb81, 0x0c0356530896eec42a97ed937f3135cf],
+    [0xa00ea5aabd6268bd317ea977cc154a30, 0x1e28a1d935698ad1142e51182bb54cf4],
+    [0x0e5757c3e008db964cf9e2b12b91251f, 0x27af2d831a9d2748080965db30e298e4],
+    [0x41291462f214cd0879aaf43545b74e03, 0x1e6f11ce60fc8f513a6a3cfe16ae175a],
+    [0x04462ed14c3613d8f719206268d142d3, 0x2a67384d3bbd5e438541819cb681f0be],
+    [0xb985e381f025188936408f5d5c9f45d0, 0x0b66fdf356093a611609f8e12fbfecf0],
+    [0x0b26c83cc5cebeeddaa6852dbdb09e21, 0x012ee3ec1e78d470830c61093c2ade37],
+    [0x6cd1c431b099b6bb2d108e7b445bb1b9, 0x0252ba5f6760bfbdfd88f67f8175e3fd],
+    [0x54391a8935ff71d6ef5aeaad7ca932f1, 0x179474cceca5ff676c6bec3cef542963],
+    [0x9c1f974a264969b37e1a2589bbed2b91, 0x2c24261379a51bfa9228ff4a503fd4ed],
+    [0xc2f63f5001fc0fc553e66c0551801b05, 0x1cc1d7b62692e63eac2f288bd0695b43],
+    [0x00784dbf17fbacd05d9eff5fd9c91b56, 0x255059301aada98bb2ed55f852979e96],
+    [0xaea24234970a8193b11c29ce7e59efd9, 0x28437be3ac1cb2e479e1f5c0eccd32b3],
+    [0x18548da8fb4f78d4338762c37f5f2043, 0x28216a442f2e1f711ca4fa6b53766eb1],
+    [0xeee1efce03094581131f2377323482c9, 0x2c1f47cd17fa5adf1f39f4e7056dd03f],
+    [0xd9dafca276638a63646b8566a621afc9, 0x07abad02b7a5ebc48632bcc9356ceb7d],
+    [0x9429f90880a69cd137da0c4d15f96c3c, 0x0230264601ffdf29275b33ffaab51dfe],
+    [0xa864414557ee289e717e5d66899aa0a9, 0x1bc973054e51d905a0f168656497ca40],
+    [0xd249aff5c2d8421f2a6b22288f0a67fc, 0x2e1c22f964435008206c3157e86341ed],
+    [0x9e8ea1598e46c9f7a70452bc2bba86b8, 0x1224f38df67c5378121c1d5f461bbc50],
+    [0xd7bfe8cd9dfeda1969d2989186cde20e, 0x02e4e69d8ba59e519280b4bd9ed0068f],
+    [0x4f19ee364e653f076d47e9735d98018e, 0x1f1eccc34aaba0137f5df81fc04ff3ee],
+    [0x1424448032cd1819eacb8a4d4284f582, 0x1672ad3d709a353974266c3039a9a731],
+    [0xcda6961f284d24991d2ed602df8c8fc7, 0x283e3fdc2c6e420c56f44af5192b4ae9],
+    [0x683751f8fdff59d6614fbd69ff394bcc, 0x1c2a3d120c550ecfd0db0957170fa013],
+    [0x143a9a43773ea6f296cb6b8177653fbd, 0x216f84877aac6172f7897a7323456efe],
+    [0xceaa47ea61ca59a411a1f51552f94788, 0x2c0d272becf2a75764ba7e8e3e28d12b],
+    [0x170a5480abe0508fcb4a6c3d89546f43, 0x16e34299865c0e28484ee7a74c454e9f],
+    [0x772047765802290f48cd939755488fc5, 0x175ceba599e96f5b375a232a6fb9cc71],
+    [0x6aa410bfbc354f54d8c5ffbb44a1ee32, 0x0c7594440dc48c16fead9e1758b02806],
+    [0x760e20013ccf912c92479882d919fd8d, 0x1a3c29bc39f21bb5c466db7d7eb6fd8f],
+    [0x0855d349074f5a6695c8eeabcd22e68f, 0x0ccfdd906f3426e5c0986ea049b25340],
+    [0x66a7a8a3fd065b3ce0e699b67dd9e796, 0x14f6bc81d9f186f62bdb475ce6c94118],
+    [0xcc099810c9c495c888edeb7386b97052, 0x0962b82789fb3d129702ca70b2f6c5aa],
+    [0x13284ab01ef02575afef0c8f6a31a86d, 0x1a880af7074d18b3bf20c79de25127bc],
+    [0x20af2924fc20ff3f4c3012bb7ae9311b, 0x10cba18419a6a332cd5e77f0211c154b],
+    [0xfa27b7319cae3406756a2849f302f10d, 0x057e62a9a8f89b3ebdc76ba63a9eaca8],
+    [0xb961303bbf65cff5afa0413b44280cee, 0x287c971de91dc0abd44adf5384b4988c],
+    [0x1e562bc46d4aba4e6f7f7960e306891d, 0x21df3388af1687bbb3bca9da0cca908f],
+    [0x789df8f70b498fd83eff8b560e1682b3, 0x1be5c887d25bce703e25cc974d0934cd],
+    [0xd2cb5d42fda5acea48d59c2706a0d5c1, 0x268da36f76e568fb68117175cea2cd0d],
+    [0xc5d8bb74135ebd05bd06460cc26a5ed6, 0x0e17ab091f6eae50c609beaf5510ecec],
+    [0x91ef62d8cf83d270040f5caa1f62af40, 0x04d727e728ffa0a67aee535ab074a430],
+    [0x33702ac10f1bfd862b15417d7e39ca6e, 0x0ddbd7bf9c29341581b549762bc022ed],
+    [0x34f5b0d1d3af9b588a29c49c8789654b, 0x2790eb3351621752768162e82989c6c2],
+    [0x395f3d9ab8b2fd0984b764206142f9e9, 0x1e457c601a63b73e4471950193d8a570],
+    [0xfa90ecd0c43ff91fc4c686fc46e091b0, 0x21ae64301dca9625638d6ab2bbe7135f],
+    [0x854be9e3432e09555858534eed8d350b, 0x0379f63c8ce3468d4da293166f494928],
+    [0xca0e4921a46686ac8c9f58a324c35049, 0x002d56420359d0266a744a080809e054],
+    [0xda8d62459e21f4090fc2c5af963515a6, 0x123158e5965b5d9b1d68b3cd32e10bbe],
+    [0x0fbb2b6f52836d4e60c80eb49cad9ec1, 0x0be29fc40847a941661d14bbf6cbe042],
+    [0x36db9d859cad5f9a233802f24fdf4c1a, 0x1ac96991dec2bb0557716142015a453c],
+    [0x5e12c9fa97f18a9251ca3355bcb0627e, 0x1596443f763dbcc25f4964fc61d23b3e],
+    [0xf1857d9f17e715aed6d049eae3ba3212, 0x12e0bcd3654bdfa76b2861d4ec3aeae0],
+    [0xceabac7f37154b1904e6c76c7cf964ba, 0x0fc92b4f1bbea82b9ea73d4af9af2a50],
+    [0xb14f7c7722236f4f9c7e965233872762, 0x1f9c0b1610446442d6f2e592a8013f40],
+    [0x050d914da38b4c058d159f643dbbf4d3, 0x0ebd74244ae72675f8cde06157a782f4],
+    [0x3b0671e97346ee397a839839dccfc6d1, 0x2cb7f0ed39e16e9f69a9fafd4ab951c0],
+    [0xce761d0514ce526690c72bca7352d9bf, 0x1a9d6e2ecff022cc5605443ee41bab20],
+    [0xd0c13326a9a7ba3087da182d648ec72f, 0x2a115439607f335a5ea83c3bc44a9331],
+    [0xe738b56305cd44f29535c115c5a4c060, 0x23f9b6529b5d040d15b8fa7aee3e3410],
+    [0xa3ce97c16d58b68b260eb939f0e6e8a7, 0x05872c16db0f72a2249ac6ba484bb9c3],
+    [0x0219b6151d55b5c52b624a7cdeddf6a7, 0x1300bdee08bb7824ca20fb80118075f4],
+    [0x8d7ad29916d98cb1072e4e7b7d52b376, 0x19b9b63d2f108e17e63817863a8f6c28],
+    [0x13d1c88726b5ec4224a20128481b4f7f, 0x015bee1357e3c015b5bda237668522f6],
+    [0xfe1e1ce4bab218cbea92c785b128ffd1, 0x2953736e94bb6b9f1b9707a4f1615e4e],
+    [0x851b98d372b45f544ce7266ed6608dfc, 0x0b069353ba091618862f806180c0385f],
+    [0x55aeb7eb9306b64e4f588ac97d81f429, 0x304f74d461ccc13115e4e0bcfb93817e],
+    [0xaad2a164a4617a4cb8ee5415cde913fc, 0x15bbf146ce9bca09e8a33f5e77dfe4f5],
+    [0x9b8f4b850405c10ca9ff23859572c8c6, 0x0ab4dfe0c2742cde44901031487964ed],
+    [0xef5eedfea546dea9251de39f9639779a, 0x0e32db320a044e3197f45f7649a19675],
+    [0x6797733a82774896a3078efa516da016, 0x0a1756aa1f378ca4b27635a78b6888e6],
+    [0x95e61d328f85efa94254d6a2a25d93ef, 0x044c4a33b10f693447fd17177f952ef8],
+    [0xfe0879d79a496891d37b07b5466c4b8b, 0x2ed3611b725b8a70be655b537f66f700],
+    [0xadfdeb9cfdd347b5d8339ea7120858aa, 0x1f9ba4e8bab7ce42c8ecc3d722aa2e0e],
+    [0xa38e82ac4502066db74056f865c5d3da, 0x1b233043052e8c288f7ee907a84e518a],
+    [0x902053bfc0f14db0ca2f97b020875954, 0x2431e1cc164bb8d074031ab72bd55b4c],
+    [0xa13e322097583319a791f273965801fd, 0x082f934c91f5aac330cd6953a0a7db45],
+    [0xc77245e2ae7cbe999ad6bb930c48997c, 0x2b9a0a223e7538b0a34be074315542a3],
+    [0x8164163e75a8a00eb0b589cc70214e7d, 0x0e1cd91edd2cfa2cceb85483b887a9be],
+    [0x99774f19854d00f588d324601cebe2f9, 0x2e1eac0f2bfdfd63c951f61477e36989],
+    [0x7345784d8efdb33c23d748115b500b83, 0x0cbfa95f37fb74060c76158e769d6d15],
+    [0x76d991e3a77513d9980c232dfa4a4f84, 0x08f05b3be923ed44d65ad49d8a61e9a6],
+    [0xe7284925dc0758a325a2dd510c047ef6, 0x22719e2a070bcd0852bf8e21984d0443],
+    [0x7bdbf036119982c0f41f62b2f26830c0, 0x041f596a9ee1cb2bc060f7fcc3a1ab4c],
+    [0x021be1c2d0dc464a19fcdd0986b10f89, 0x233fd35de1be520a87628eb06f6b1d4c],
+    [0xd31e078aa1b4707eefcb453c61c9c267, 0x0524b46d1aa87a5e4325e0a423ebc810],
+    [0x4227bb954b0f3199cc4486237c515211, 0x2c34f424c81e5716ce47fcac894b8582],
+    [0x2dd2025b5457cc97f33010327de4915e, 0x0b5f2a4b63387819207effc2b5541fb7],
+    [0xc17435d2f57af6ceaefac41fe05c659f, 0x22207856082ccc54c5b72fe439d2cfd6],
+    [0xcdfb210194caf79f27854048ce2c8171, 0x24d57a8bf5da63fe4e24159b7f8950b5],
+    [0x374ad7097bb01a8573919bb23b79396e, 0x0afab181fdd5e0583b371d75bd693f98],
+    [0x76c0057194c16c0bf16290d62b1128ee, 0x2dba9b108f208772998a52efac7cbd56],
+    [0xcbb83de0bd592b255aff13e6bce420b3, 0x26349b66edb8b16f56f881c788f53f83],
+    [0xd81a56d28ecc193b235288a3e6f137db, 0x25af7ce0e5e10357685e95f92339753a],
+    [0x70eed7aae88b2bff1f7c0187fe35011f, 0x25b4ce7bd2294390c094d6a55edd68b9],
+    [0xde9cef28ebdf30b18cb9d54c1e02b631, 0x22c543f10f6c89ec387e53f1908a88e5],
+    [0x425e906a919d7a34df668e74882f87a9, 0x0236f93e7789c4724fc7908a9f191e1e],
+    [0x652bdae114eb01659cb497af980c4b52, 0x29350b401166ca010e7d27e37d05da99],
+    [0x65edb75d844ebb89ee1260916652363f, 0x0eed787d65820d3f6bd31bbab547f75a],
+    [0xfdcd0e99bd94297d1906f656f4de6fad, 0x07cc1170f13b46f2036a753f520b3291],
+    [0x908786d7f9f5d10c2059435689e8acea, 0x22b939233b1d7205f49bcf613a3d30b1],
+    [0x740f083a5aa85438add650ace60ae5a6, 0x01451762a0aab81c8aad1dc8bc33e870],
+    [0xe32eaa61dec7da57e704fec0892fce89, 0x23506bb5d8727d4461fabf1025d46d1f],
+    [0x2a3709531e1efea97f8bd68907355522, 0x2e484c44e838aea0bac06ae3f71bdd09],
+    [0xaf4a594545cedc2545418da26835b54c, 0x0f4bc7d07ebafd64379e78c50bd2e42b],
+    [0x51582388725df460e620996d50d8e74e, 0x1f4d3c8f6583e9e5fa76637862faaee8],
+    [0xfae02abc7b681d9153eb9bcb48fe7389, 0x093514e0c70711f82660d07be0e4a988],
+    [0x43ee83ece47228f24a58e0a347e153d8, 0x1adab0c8e2b3bad346699a2b5f3bc036],
+    [0x378c1b94b8072bac1a22dbef9e80dad2, 0x1672b1726057d99dd14709ebb474641a],
+    [0xc5d8e2fae0acc4ee30d47b239b479c14, 0x1dfd53d4576af2e38f44f53fdcab468c],
+    [0xe6d77d640f6fc3debc7f207753205c60, 0x0c6888a10b75b0f3a70a36263a37e17f],
+    [0x611a61e00ee6848b85091ecca9d1e508, 0x1addb933a65be77092b34a7e77d12fe8],
+    [0xf638ff5425f0afff7935628e299d1791, 0x00d7540dcd268a845c10ae18d1de933c],
+    [0x26fedde4acd99db1d316939d20b82c0e, 0x140c0e42687e9ead01b2827a5664ca9c],
+    [0xc20a0f9b24f8c5edc091e2ae56565984, 0x2f0c3a115d4317d191ba89b8d13d1806],
+    [0x08a0cff670b22b82d8c538a1dc958c61, 0x0c4ee778ff7c14553006ed220cf9c810],
+    [0x9424ed26c0acc66227c3d7485de74c69, 0x1704f2766d46f82c3693f00440ccc360],
+    [0xf0769c9f8544e40239b66fe9009c3cfa, 0x2f2d19cc3ea5d78ea7a02c1b51d244ab],
+    [0xdd7ee6cb9cfed9c7d6c766a806fc6629, 0x1ae03853b75fcaba5053f112e2a8e8dc],
+    [0x3556921b2d6f014e4e41a86ddaf056d5, 0x0971aabf795241df51d131d0fa61aa5f],
+    [0xda624f8380df1c875f5c29f7bfe2f646, 0x1408c316e6014e1a91d4cf6b6e0de73e],
+    [0x6c89f1f773ef285341691f39822ef5bd, 0x1667f3fe2edbe850248abe42b543093b],
+    [0x5b81718409e5c133424c69576500fe37, 0x13bf7c5d0d2c4376a48b0a03557cdf91],
+    [0x024b95347856b79719bc0ba743a62c2c, 0x07620a6dfb0b6cec3016adf3d3533c24],
+    [0x75d2959e2f322b731675de3e1982b4d0, 0x1574c7ef0c43545f36a8ca08bdbdd8b0],
+    [0x5bd4184571c254fdc06e03a7ff8378f0, 0x269e4b5b7a2eb21afd567970a717ceec],
+];
+
+/// MDS matrix for width 2, row by row.
+const MDS_2: [[u128; 2]; 4] = [
+    [0xaf07f38af8c952a7bceca70bd2af7ad5, 0x066f6f85d6f68a85ec10345351a23a3a],
+    [0x20a7c02bbd8bea7305462b9f8125b1e8, 0x2b9d4b4110c9ae997782e1509b1d0fdb],
+    [0xb6c09d557013fff1f573f431221f8ff9, 0x0cc57cdbb08507d62bf67a4493cc262f],
+    [0xade857e86eb5c3a121bcd147943203c8, 0x1274e649a32ed355a31a6ed69724e1ad],
+];
+
+/// Round constants for width 3, 65 rounds of 3 elements each.
+const ROUND_CONSTANTS_3: [[u128; 2]; 195] = [
+    [0x14c4993c11bb29938d21d47304cd8e6e, 0x0ee9a592ba9a9518d05986d656f40c21],
+    [0x887b08d4d00868df5696fff40956e864, 0x00f1445235f2148c5986587169fc1bcd],
+    [0x30c728730b7ab36ce879f3890ecf73f5, 0x08dff3487e8ac99e1f29a058d0fa80b9],
+    [0x56c35342c84bda6e20966310fadc01d0, 0x2f27be690fdaee46c3ce28f7532b13c8],
+    [0xb763fe04b8043ee48b8327bebca16cf2, 0x2b2ae1acf68b7b8d2416bebf3d4f6234],
+    [0x5952c175ab6b03eae64b44c7dbf11cfa, 0x0319d062072bef7ecca5eac06f97d4d5],
+    [0xc8b7bf27ad49c6298ef7b387bf28526d, 0x28813dcaebaeaa828a376df87af4a63b],
+    [0x2033865200c352bc150928adddf9cb78, 0x2727673b2ccbc903f181bf38e1c1d40d],
+    [0x6efbd43e340587d6b8fb9e31e65cc632, 0x234ec45ca27727c2e74abd2b2a1494cd],
+    [0xab10a8150a337b1ccd99ff6e8797d428, 0x15b52534031ae18f7f862cb2cf7cf760],
+    [0x8e0e8a8d1b58b132d701d4eecf68d1f6, 0x0dc8fad6d9e4b35f5ed9a3d186b79ce3],
+    [0xea4eb378f62e1fec97805518a47e4d9c, 0x1bcd95ffc211fbca600f705fad3fb567],
+    [0xc76da36c2578937817cb978d069de559, 0x10520b0ab721cadfe9eff81b016fc34d],
+    [0x2932498075fed0ace88a9eb81f5627f6, 0x1f6d48149b8e7f7d9b257d8ed5fbbaf4],
+    [0xfff8dc1c816f0dc9ca34bdb5460c8705, 0x1d9655f652309014d29e00ef35a2089b],
+    [0xa731ff67e47032058fe3d4185697cc7d, 0x04df5a56ff95bcafb051f7b1cd43a99b],
+    [0x8690a10a8c8424a7f6ec282b6e4be828, 0x0672d995f8fff640151b3d290cedaf14],
+    [0x0c9a9dcc06f2708e9fc1d8209b5c75b9, 0x099952b414884454b21200d7ffafdd5f],
+    [0x48e43586a9b4cd9183fd0e843a6b9fa6, 0x052cba2255dfd00c7c483143ba8d4694],
+    [0x82de55707251ad7716077cb93c464ddc, 0x0b8badee690adb8eb0bd74712b7999af],
+    [0x49c15d60683a8050b963d0a8e4b2bdd1, 0x119b1590f13307af5a1ee651020c07c7],
+    [0x2c4acfc884ef4ee5ce15be0bfb4a8d09, 0x03150b7cd6d5d17b2529d36be0f67b83],
+    [0x5374efb83d80898abe69cb317c9ea565, 0x2cc6182c5e14546e3cf1951f17391235],
+    [0x4218cadedac14e2b92d2cd73111bf0f9, 0x005032551e6378c450cfe129a404b376],
+    [0x6469c399fcc069fb88f9da2cc28276b5, 0x233237e3289baa34bb147e972ebcb951],
+    [0x23037f21b34ae5a4e80c2d4c24d60280, 0x05c8f4f4ebd4a6e3c980d31674bfbe63],
+    [0x52bcf35ef3aeed91ee1f09b2590fc65b, 0x0a7b1db13042d396ba05d818a319f252],
+    [0xf156e2b086ff47dc5df542365a404ec0, 0x2a73b71f9b210cf5b14296572c9d32db],
+    [0xec18f2c4dbe7f22976a760bb5c50c460, 0x1ac9b0417abcc9a1935107e9ffc91dc3],
+    [0x269f3e4d6cb104349015ee046dc93fc0, 0x12c0339ae08374823fabb076707ef479],
+    [0x52900aa3253baac68246682e56e9a28e, 0x0b7475b102a165ad7f5b18db4e1e704f],
+    [0x7c843e379366f2ea32ab3aa88d7f8448, 0x037c2849e191ca3edb1c5e49f6e8b891],
+    [0xd5206c5c93a07dc145fdb176a716346f, 0x05a6811f8556f014e92674661e217e9b],
+    [0x4076e87a7b2883b47b675ef5f38bd66e, 0x29a795e7d98028946e947b75d54e9f04],
+    [0x6e8c7382c8a1585c507be199981fd22f, 0x20439a0c84b322eb45a3857afc18f582],
+    [0xbb50f27799a84b6d4a2a6f2a0982c887, 0x2e0ba8d94d9ecf4a94ec2050c7371ff1],
+    [0x17822cd2109048d2e6d0ddcca17d71c8, 0x143fd115ce08fb27ca38eb7cce822b45],
+    [0xf8611659323dbcbfc84323623be9caf1, 0x0c64cbecb1c734b857968dbbdcf813cd],
+    [0xe74f348d62c2b670f1426cef9403da53, 0x028a305847c683f646fca925c163ff5a],
+    [0x6a6bcb64d89427b824d6755b5db9e30c, 0x2e4ef510ff0b6fda5fa940ab4c4380f2],
+    [0x8925b4f6d033b078b96384f50579400e, 0x0081c95bc43384e663d79270c956ce3b],
+    [0xe2491b349c039a0bba8a9f4023a0bb38, 0x2ed5f0c91cbd9749187e2fade687e05e],
+    [0x3448a22c76234c8c990f01f33a735206, 0x30509991f88da3504bbf374ed5aae2f0],
+    [0xf0a1119fb2067b41a7529094424ec6ad, 0x1c3f20fd55409a53221b7c4d49a356b9],
+    [0xc46bb2213e8e131e170887b47ddcb96c, 0x10b4e7f3ab5df003049514459b6e18ee],
+    [0xdd80f804c077d775039aa3502e43adef, 0x2a1982979c3ff7f43ddd543d891c2abd],
+    [0xba431ebc396c9af95cad0f1315bd5c91, 0x1c74ee64f15e1db6feddbead56d6d55d],
+    [0x9e4f2e8b82708cfa9c2fe45a0ae146a0, 0x07533ec850ba7f98eab9303cace01b4b],
+    [0x285c68f42d42c1808a11abf3764c0750, 0x21576b438e500449a151e4eeaf17b154],
+    [0xbce8384c815f0906743d6930836d4a9e, 0x2f17c0559b8fe79608ad5ca193d62f10],
+    [0x9775a4201318474ae665b0b1b7e2730e, 0x2d477e3862d07708a79e8aae946170bc],
+    [0xafba2266c38f5abcd89be0f5b2747eab, 0x162f5243967064c390e095577984f291],
+    [0xd7a8596a87f29f8a7777a70092393311, 0x2b4cb233ede9ba48264ecd2c8ae50d1a],
+    [0x6db2eece6d85c4cf4254e7c35e03b07a, 0x2c8fbcb2dd8573dc1dbaf8f462285477],
+    [0x9e1b61e9f601e9ade5e88db870949da9, 0x1d6f347725e4816af2ff453f0cd56b19],
+    [0xf9e6ac02b68d31324cd49af5c4565529, 0x204b0c397f4ebe71ebc2d8b3df5b913d],
+    [0x9ecb827cd7dc25534ff8fb75bc79c502, 0x0c4cb9dc3c4fd8174f1149b3c63c3c2f],
+    [0x1e5c49475279e0639a616ddc45bc7b54, 0x174ad61a1448c899a25416474f493030],
+    [0xeaaa28c177cc0fa13a9816d49a38d2ef, 0x1a96177bcf4d8d89f759df4ec2f3cde2],
+    [0x05202c126a233c1a8242ace360b8a30a, 0x066d04b24331d71cd0ef8054bc60c4ff],
+    [0x381cc65f72e02ad527037a62aa1bd804, 0x2a4c4fc6ec0b0cf52195782871c6dd3b],
+    [0x5e727f8446f6d9d7e55afc01219fd649, 0x13ab2d136ccf37d447e9f2e14a7cedc9],
+    [0xc1b04fcec26f55194c2e3e869acc6a9a, 0x1121552fca26061619d24d843dc82769],
+    [0xa6cd267d595c4a8909a5546c7c97cff1, 0x00ef653322b13d6c889bc81715c37d77],
+    [0x400c776d652595d9845aca35d8a397d3, 0x0e25483e45a665208b261d8ba74051e6],
+    [0x395ac3d4dde92d8c46448db979eeba89, 0x29f536dcb9dd7682245264659e15d88e],
+    [0x5a124e2780bbea170e456baace0fa5be, 0x2a56ef9f2c53febadfda33575dbdbd88],
+    [0xf2ae2999a46762e8ee416240a8cb9af1, 0x1c8361c78eb5cf5decfb7a2d17b5c409],
+    [0x83e8e68a764507bfd3d0ab4be74319c5, 0x151aff5f38b20a0fc0473089aaf0206b],
+    [0xa9f52fc8c8b6cdd1e76e47615b51f100, 0x04c6187e41ed881dc1b239c88f7f9d43],
+    [0x4b81c61ed15776449e801b7ddc9c2967, 0x13b37bd80f4d27fb10d84331f6fb6d53],
+    [0x2ce3664c2a52032c9321ceb1c4e8a8e4, 0x01a5c536273c2d9df578bfbd32c17b7a],
+    [0x4a9a2c666b9726da832239065b7c3b02, 0x2ab3561834ca73835ad05f5d7acb950b],
+    [0xacaf6af4e95d3bf69f7ed516a597b646, 0x1d4d8ec291e720db200fe6d686c0d613],
+    [0xb925351240a04b711514c9c80b65af1d, 0x041294d2cc484d228f5784fe7919fd2b],
+    [0x98f57939d126e392042971dd90e81fc6, 0x154ac98e01708c611c4fa715991f0048],
+    [0x50b3684c88f8b0b04524563bc6ea4da4, 0x0b339d8acca7d4f83eedd84093aef510],
+    [0x98f0e71eaff4a7dd81ed95b50839c82e, 0x0955e49e6610c94254a4f84cfbab3445],
+    [0xa9a6f41e6f535c6f3525401ea0654626, 0x06746a6156eba54426b9e22206f15abc],
+    [0x38e5790e2bd0a196ac917c7ff32077fb, 0x0f18f5a0ecd1423c496f3820c549c278],
+    [0x4bb563583ede7bc92a738223d6f76e13, 0x04f6eeca1751f7308ac59eff5beb261e],
+    [0x8811eb116fb3e45bc1768d26fc0b3758, 0x2b56973364c4c4f5c1a3ec4da3cdce03],
+    [0x8e1392b385716a5d83feb65d437f29ef, 0x123769dd49d5b054dcd76b89804b1bcb],
+    [0xa989f6446471150994257b2fb01c63e9, 0x2147b424fc48c80a88ee52b91169aace],
+    [0x647e6f34ad4243c2ea54ad897cebe54d, 0x0fdc1f58548b85701a6c5505ea332a29],
+    [0xbceff28c5dbbe0c3944f685cc0a0b1f2, 0x12373a8251fea004df68abcf0f7786d4],
+    [0xa642756b6af44203dd8a1f35c1a90035, 0x21e4f4ea5f35f85bad7ea52ff742c9e8],
+    [0xb57366492f45e90d8a81934f1bc3b147, 0x16243916d69d2ca3dfb4722224d4c462],
+    [0xabc21566e1a0453ca13a4159cac04ac2, 0x1efbe46dd7a578b4f66f9adbc88b4378],
+    [0xd468d5525be66f853b672cc96a88969a, 0x07ea5e8537cf5dd08886020e23a7f387],
+    [0x650f19a75e7ce11ca9fe16c0b76c00bc, 0x05a8c4f9968b8aa3b7b478a30f9a5b63],
+    [0x3f7818c701b9c7882d9d57b72a32e83f, 0x20f057712cc21654fbfe59bd345e8dac],
+    [0x6dcd8e88d01d49019bd90b33eb33db69, 0x04a12ededa9dfd689672f8c67fee3163],
+    [0xbd136ce5091a6767e49ec9544ccd101a, 0x27e88d8c15f37dcee44f1e5425a51dec],
+    [0x6e096619a7703223176c41ee433de4d1, 0x2feed17b84285ed9b8a5c8c5e95a41f6],
+    [0x94e5942911312a0d6972b8bd53aff2b8, 0x1ed7cc76edf45c7c404241420f729cf3],
+    [0xac6783476144cdcadf2874be45466b1a, 0x15742e99b9bfa323157ff8c586f5660e],
+    [0x77107454c6ec0317284f033f27d0c785, 0x1aac285387f65e82c895fc6887ddf405],
+    [0x832e2e7a49775f71ec75a96554d67c77, 0x25851c3c845d4790f9ddadbdb6057357],
+    [0x53b7ebba2c5523370ddccc3d9f146a67, 0x15a5821565cc2ec2ce78457db197edf3],
+    [0xdcf64f36044277502f15485f28c71727, 0x2411d57a4813b9980efa7e31a1db5966],
+    [0x47e9a98e12f4cd2558828b5ef6cb4c9b, 0x002e6f8d6520cd4713e335b8c0b6d2e6],
+    [0xaf8f0e91e2fe1ed7398834609e0315d2, 0x2ff7bc8f4380cde997da00b616b0fcd1],
+    [0x2e9521f6b7bb68f1e93be4febb0d3cbe, 0x00b9831b948525595ee02724471bcd18],
+    [0x1ca408648a4743a87d77adbf0c9c3512, 0x0a2f53768b8ebf6a86913b0e57c04e01],
+    [0x0f599ff7e94be69b7f2a290305e1198d, 0x00248156142fd0373a479f91ff239e96],
+    [0x397196aa6a542c2350eb512a2b2bcda9, 0x171d5620b87bfb1328cf8c02ab3f0c9a],
+    [0x60c952172dd54dd99d1045e4ec34a808, 0x170a4f55536f7dc970087c7c10d6fad7],
+    [0xcc37e38c1cd211ba482eca17e2dbfae1, 0x29aba33f799fe66c2ef3134aea04336e],
+    [0xe70d114a03f6a0e8b5ba650369e64973, 0x1e9bc179a4fdd758fdd1bb1945088d47],
+    [0xfeaad869a9c4b44f9c9e1c43bdaf8f09, 0x1dd269799b660fad58f7f4892dfb0b5a],
+    [0x7ccd426fe869c7c95d1dd2cb0f24af38, 0x22cdbc8b70117ad1401181d02e15459e],
+    [0xfd3150f52ed94a7cd5ba93b9c7dacefd, 0x0ef042e454771c533a9f57a55c503fce],
+    [0x1318e8b08a0359a03b304ffca62e8284, 0x11609e06ad6c8fe2f287f3036037e885],
+    [0xfecd58c076dfe42708b08f5b783aa9af, 0x1166d9e554616dba9e753eea427c17b7],
+    [0x177fbf4cd2ac0b56f855a888357ee466, 0x2de52989431a859593413026354413db],
+    [0xf51aee5b17b8e89d74bf01cf5f71e9ad, 0x3006eb4ffc7a85819a6da492f3a8ac1d],
+    [0x2993fe8f0a4639f962344c8225145086, 0x2af41fbb61ba8a80fdcf6fff9e3f6f42],
+    [0x8718ab27889e85e781b214bace4827c3, 0x119e684de476155fe5a6b41a8ebc85db],
+    [0xb51248c23828f047cff784b97b3fd800, 0x1835b786e2e8925e188bea59ae363537],
+    [0xd152bac2a7905c926c40e285ab32eeb6, 0x28201a34c594dfa34d794996c6433a20],
+    [0x864c82eb571187724a761f88c22cc4e7, 0x083efd7a27d1751094e80fefaf78b000],
+    [0x11c16df7774dd8519e079564f61fd13b, 0x0b6f88a3577199526158e61ceea27be8],
+    [0x589511ca00d29e1014390e6ee4254f5b, 0x0ec868e6d15e51d9644f66e1d6471a94],
+    [0xecd3e74b939cd40d00d937ab84c98591, 0x2af33e3f866771271ac0c9b3ed2e1142],
+    [0x34568c547dd6858b364ce5e47951f178, 0x0b520211f904b5e7d09b5d961c6ace77],
+    [0xa0c56ac4270e822cca228620188a1d40, 0x0b2d722d0919a1aad8db58f10062a92e],
+    [0x57b54a9991ca38bbe0061d1ed6e562d4, 0x1f790d4d7f8cf094d980ceb37c2453e9],
+    [0x0885c16235a2a6a8da92ceb01e504233, 0x0171eb95dfbf7d1eaea97cd385f78015],
+    [0x790b40defd2c8650762305381b168873, 0x0c2d0e3b5fd57549329bf6885da66b9b],
+    [0x7cbcafa589e283c35d3803054407a18d, 0x1162fb28689c27154e5a8228b4e72b37],
+    [0x282c5a92a89e19921623ef8249711bc0, 0x2f1459b65dee441b64ad386a91e8310f],
+    [0xbc489d46754eb712c243f70d1b53cfbb, 0x1e6ff3216b688c3d996d74367d5cd4c1],
+    [0xd741a6f36cdc2a0576881f9326478875, 0x01ca8be73832b8d0681487d27d157802],
+    [0x680286080b10cea00b9b5de315f9650e, 0x1f7735706ffe9fc586f976d5bdf223dc],
+    [0x21ac10a3d5f096ef4745ca838285f019, 0x2522b60f4ea3307640a0c2dce041fba9],
+    [0x3418cad4f52b6c3f8ce16c235572575b, 0x23f0bee001b1029d5255075ddc957f83],
+    [0x5d142633e9df905f66d9401093082d59, 0x2bc1ae8b8ddbb81fcaac2d44555ed568],
+    [0x62371273a07b1fc98011fcd6ad72205f, 0x0f9406b8296564a37304507b8dba3ed1],
+    [0x17e75b174a52ee4acb126c8cd995f0a8, 0x2360a8eb0cc7defa67b72998de90714e],
+    [0xb4815a5e96df8b006dcbbc2767f88948, 0x15871a5cddead976804c803cbaef255e],
+    [0x362f4f54f72379544f957ccdeefb420f, 0x193a56766998ee9e0a8652dd2f3b1da0],
+    [0x3b2e63c8ad334834e4309805e777ae0f, 0x2a394a43934f86982f9be56ff4fab170],
+    [0x892cd11223443ba7b4166e8876c0d142, 0x1859954cfeb8695f3e8b635dcb345192],
+    [0x2b11bc25d90bbdca408d3819f4fed32b, 0x04e1181763050e58013444dbcb99f190],
+    [0xb10eb82db08b5e8b1f5e5552bfd05f23, 0x0fdb253dee83869d40c335ea64de8c5b],
+    [0x75f08686f1c08984a9d7c5bae9b4f1c0, 0x058cbe8a9a5027bdaa4efb623adead62],
+    [0x23b4b83bef023ab0d15228b4cceca59a, 0x1382edce9971e186497eadb1aeb1f52b],
+    [0x7f61b8eb99f14b77e1e6634601d9e8b5, 0x03464990f045c6ee0819ca51fd11b0be],
+    [0x6fcab4605db2eb5aaa5bc137aeb70a58, 0x23f7bfc8720dc296fff33b41f98ff83c],
+    [0x18c3ffd5e1531a9219636158bbaf62f2, 0x0a59a158e3eec2117e6e94e7f0e9decf],
+    [0xe2c4eba065420af8f4c23ed0075fd07b, 0x06ec54c80381c052b58bf23b312ffd3c],
+    [0xb09340f7a7bcb1b4962f0ff9ed1f9d01, 0x118872dc832e0eb5476b56648e867ec8],
+    [0x52e0b0f0e42d7fea95e1906b520921b1, 0x13d69fa127d834165ad5c7cba7ad59ed],
+    [0xde143942fb71dc55fd8a49f19f10c77b, 0x169a177f63ea681270b1c6877a73d21b],
+    [0x3abeb032b922f66ffb7e9a5a7450544d, 0x04ef51591c6ead97ef42f287adce40d9],
+    [0x19ec61805d4f03ced5f45ee6dd0f69ec, 0x256e175a1dc079390ecd7ca703fb2e3b],
+    [0x75cc360d3205dd2da002813d3e2ceeb2, 0x30102d28636abd5fe5f2af412ff6004f],
+    [0x0443a3fa99bef4a31fd31be182fcc792, 0x10998e42dfcd3bbf1c0714bc73eb1bf4],
+    [0x9f3362eaf4d582efecad76f879e36860, 0x193edd8e9fcf3d7625fa7d24b598a1d8],
+    [0xd1c7a561ce611425f2664d7aa51f0b5d, 0x18168afd34f2d915d0368ce80b7b3347],
+    [0xa328ec77bc33626e29e2e95b33ea6111, 0x29383c01ebd3b6ab0c017656ebe658b6],
+    [0x702db6e86fb76ab600bf573f9010c711, 0x10646d2f2603de39a1f4ae5e7771a64a],
+    [0x2f90c25b40da7b3864d0242dcb1117fb, 0x0beb5e07d1b27145f575f1395a55bf13],
+    [0x30f95bb2e54b59abdffbf018d96fa336, 0x16d685252078c133dc0d3ecad62b5c88],
+    [0x0a555bbbec21ddfafd672dd62047f01a, 0x0a6abd1d833938f33c74154e0404b4b4],
+    [0xb12dffeec450317270a6f19b34cf1860, 0x1a679f5d36eb7b5c8ea12a4c2dedc8fe],
+    [0x26a423eada4e8f6ffbc7592e3f1b93d6, 0x0980fb233bd456c23974d50e0ebfde47],
+    [0xcece3d5628c9282003ebacb5c312c72b, 0x161b42232e61b84cbf1810af93a38fc0],
+    [0x493f09787f1564e5d09203db47de1a0b, 0x0ada10a90c7f0520950f7d47a60d5e6a],
+    [0xd3f07a8a2b4e121bb50ddb9af407f451, 0x1a730d372310ba82320345a29ac4238e],
+    [0x908377feaba5c4dffbda10ef58e8c556, 0x2c8120f268ef054f817064c369dda7ea],
+    [0xb930e95313bcb73e6e7b8649a4968f70, 0x1c7c8824f758753fa57c00789c684217],
+    [0xf400ad8b491eb3f7b47b27fa3fd1cf77, 0x2cd9ed31f5f8691c8e39e4077a74faa0],
+    [0xa5e022ac321ca550854ae23918a22eea, 0x23ff4f9d46813457cf60d92f57618399],
+    [0xaf5a2c5103529407dff1ea58f180426d, 0x09945a5d147a4f66ceece6405dddd9d0],
+    [0x7c7da6eaa29d3f268a6dd223ec6fc630, 0x188d9c528025d4c2b67660c6b771b90f],
+    [0xdba7d926d3633595e0c0d8ddf4f0f47f, 0x3050e37996596b7f81f68311431d8734],
+    [0x6ceae5461e3f95d89d829518d30afd78, 0x15af1169396830a91600ca8102c35c42],
+    [0xdae933e351466b2904284da3320d8acc, 0x1da6d09885432ea9a06d9f37f873d985],
+    [0x4e4fad3dbe658945e546ee411ddaa9cb, 0x2796ea90d269af29f5f8acf33921124e],
+    [0x1e15612ec8e9304a7cb0319e01d32d60, 0x202d7dd1da0f6b4b0325c8b3307742f0],
+    [0xa2d7f9c788f4c831a29dace4c0f8be5f, 0x096d6790d05bb759156a952ba263d672],
+    [0x438da23ce5b13e1963798cb1447d25a4, 0x054efa1f65b0fce283808965275d877b],
+    [0xd8aa690113b2e14864ccf6e18e4165f1, 0x1b162f83d917e93edb3308c29802deb9],
+    [0xedfefc1466cc568ec5ceb745a0506edc, 0x21e5241e12564dd6fd9f1cdd2a0de39e],
+    [0xcb73ab5f87e161927b4349e10e4bdf08, 0x1cfb5662e8cf5ac9226a80ee17b36abe],
+    [0x2c99af346220ac0129c53f666eb24100, 0x0f21177e302a771bbae6d8d1ecb373b6],
+    [0xc4236aede6290546bcef7e1f515c2320, 0x1671522374606992affb0dd7f71b12be],
+    [0xbe2ec9e42c5cc8ccd419d2a692cad870, 0x0fa3ec5b9488259c2eb4cf24501bfad9],
+    [0xd36edce85c648cc085e8c57b1ab54bba, 0x193c0e04e0bd298357cb266c1506080e],
+    [0x9f6f7291cd406578ce14ea2adaba68f8, 0x102adf8ef74735a27e9128306dcbc3c9],
+    [0x316aa24bfbdd23ae40a6d0cb70c3eab1, 0x0fe0af7858e49859e2a54d6f1ad945b1],
+    [0x2da5f1daa9ebdefde8a5ea7344798d22, 0x216f6717bbc7dedb08536a2220843f4e],
+    [0x3c23b2ac773c6b3ef88e2e4228325161, 0x1da55cc900f0d21f4a3e694391918a1b],
+];
+
+/// MDS matrix for width 3, row by row.
+const MDS_3: [[u128; 2]; 9] = [
+    [0x94be7c11ad24378bfedb68592ba8118b, 0x109b7f411ba0e4c9b2b70caf5c36a7b1],
+    [0x9314dc9fdbdeea55d6c64543dc4903e0, 0x16ed41e13bb9c0c66ae119424fddbcbc],
+    [0xf706ab640ceb247b791a93b74e36736d, 0x2b90bba00fca0589f617e7dcbfe82e0d],
+    [0x2cc8fdd1415c3dded62940bcde0bd771, 0x2969f27eed31a480b9c36c764379dbca],
+    [0xb89d743c8c7b964029b2311687b1fe23, 0x2e2419f9ec02ec394c9871c832963dc1],
+    [0x148d4e109f5fb065c8aacc55a0f89bfa, 0x101071f0032379b697315876690f053d],
+    [0xe6cd79e28c5b3753326244ee65a1b1a7, 0x143021ec686a3f330d5f9e654638065c],
+    [0xd057e12e58e7d7b6b16cdfabc8ee2911, 0x176cc029695ad02582a70eff08a6fd99],
+    [0xa644470307043f7773279cd71d25d5e0, 0x19a3fc0a56702bf417ba7fee3802593f],
+];
+
+/// Round constants for width 4, 64 rounds of 4 elements each.
+const ROUND_CONSTANTS_4: [[u128; 2]; 256] = [
+    [0x02bb86744edc26238b0878e269ed23e5, 0x19b849f69450b06848da1d39bd5e4a43],
+    [0x20eb2cc7450acc1dad47f80c8dcf34d6, 0x265ddfe127dd51bd7239347b758f0a13],
+    [0x4108ac845015c2aa3dfc36bab497d8aa, 0x199750ec472f1809e0f66a545e1e5162],
+    [0x4d743ea25067f0ffd032f787c7f1cdf8, 0x157ff3fe65ac7208110f06a5f74302b1],
+    [0x0b15c590692f8beefe18f4896ac94902, 0x2e49c43c4569dd9c5fd35ac45fca33f1],
+    [0xcb2f0b6973c24fa82731345ffa2d1f1e, 0x0e35fb89981890520d4aef2b6d6506c3],
+    [0xa9d9e7806d667ffec6fe723002e0b996, 0x251ad47cb15c4f1105f109ae5e944f1b],
+    [0xf8beb56fdd05e5f3563fa39d9c22df4e, 0x13da07dc64d428369873e97160234641],
+    [0xa553939689d350cd46e7b89055fd4738, 0x0c009b84e650e6d23dc00c7dccef7483],
+    [0x04eb650c6d535eb0203dec74befdca06, 0x011f16b1c63a854f01992e3956f42d8b],
+    [0x2f3f78d0467ad45485df07093f367549, 0x0ed69e5e383a688f209d9a561daa7961],
+    [0x3aec507f5eb3d33f463672264c9f789b, 0x04dba94a7b0ce9e221acad41472b6bbe],
+    [0x6036757d4bb50bf7ce732ff1d4fa28e8, 0x0a3f2637d840f3a16eb094271c9d237b],
+    [0x39b1f075569564b6e54a485d1182323f, 0x259a666f129eea198f8a1c502fdb38fa],
+    [0x7745d4271038e5157a32fdf7ede0d6a1, 0x28bf7459c9b2f4c6d8e7d06a4ee3a47f],
+    [0xc37c85bbcce6a2aeec91bd6941432447, 0x0a1ca941f057037526ea200f489be8d4],
+    [0x5535ed1539f051dcb43a26fd926361cf, 0x0c6f8f958be0e93053d7fd4fc5451285],
+    [0xa9e8a00708e296e084dd57e69caaf811, 0x123106a93cd17578d426e8128ac9d90a],
+    [0x5e8fa83ff1e8f1877b074867cd2dee75, 0x26e1ba52ad9285d97dd3ab52f8e84008],
+    [0xbe4d8b7bf9e095864471537e6a4ae2c5, 0x1cb55cad7bd133de18a64c5c47b9c97c],
+    [0x2a53043d5060a41c7143f08e6e9055d0, 0x1dcd73e46acd8f8e0e2c7ce04bde7f6d],
+    [0x0cda294a0eb4e9b9b12b9bb4512e5574, 0x011003e32f6d9c66f5852f05474a4def],
+    [0xbadfe5903f58bafed7c508dd2287ae8c, 0x2b1e809ac1d10ab29ad5f20d03a57dfe],
+    [0xef995d05ab2fc5faeaa69ae87bcec0a5, 0x2539de1785b735999fb4dac35ee17ed0],
+    [0xf4e1c3d41c86d46e43982cb11d77951d, 0x0c246c5a2ef8ee0126497f222b3e0a0e],
+    [0xbb09e6a6ad1a1c2f3f0305f5d03b527b, 0x192089c4974f68e95408148f7c0632ed],
+    [0x8529097d91096b756d8fdc2fb5a60d85, 0x1eae0ad8ab68b2f06a0ee36eeb0d0c05],
+    [0xdb6e2fdc0dee99e69768bd98c5d06bfb, 0x179190e5d0e22179e46f8282872abc88],
+    [0x14528f7db00f31bf6cafe794a9b3cd1c, 0x29bb9e2c9076732576e9a81c7ac4b832],
+    [0x52652645882aac35b10e590e6e691e08, 0x225d394e42207599403efd0c2464a90d],
+    [0x2be13557451c087de09efd454b23fd59, 0x064760623c25c8cf753d238055b44453],
+    [0x4d67f4bf42a75c10922910a78f6b5b87, 0x10ba3a0e01df92e87f301c4b716d8a39],
+    [0xcb511bc0c242eb9d361b77693f21471c, 0x0e070bf53f8451b24f9c6e96b0c2a801],
+    [0xccd6cb11d2491d8aa7f921014de252fb, 0x1b94cd61b051b04dd39755ff93821a73],
+    [0x9d4e917d5713bb050487b5aa7d74070b, 0x1d7cb39bafb8c744e148787a2e70230f],
+    [0x8785c2961829f701bb74ac1f303b17db, 0x2ec93189bd1ab4f69117d0fe980c80ff],
+    [0xeac404a19ae07a9082ea46bd83517926, 0x2db366bfdd36d277a692bb825b86275b],
+    [0x985275428450359adc99cec6960711b8, 0x062100eb485db06269655cf186a68532],
+    [0x20243f92fa59e4f900c567bf41f5a59b, 0x0761d33c66614aaa570e7f1e8244ca11],
+    [0x5d78608a0f7de4ccf7a72e494855ad0d, 0x20fc411a114d13992c2705aa034e3f31],
+    [0x102c67e8b3effb5fc3a30f317250bc5a, 0x25b5c004a4bdfcb5add9ec4e9ab219ba],
+    [0x038b186d8474155ad87e7dff62b37f4b, 0x23b1822d278ed632a494e58f6df6f5ed],
+    [0x0f14d13bfcfcccaa16102a29cc2f69e0, 0x22734b4c5c3f9493606c4ba9012499bf],
+    [0xe5bdff409aa3610254413d3fad795ce5, 0x26c0c8fe09eb30b7e27a74dc33492347],
+    [0x196be3083a809829bbd626df348ccad9, 0x070dd0ccb6bd7bbae88eac03fa1fbb26],
+    [0xc2c0a6de46d8c5ad6067c4ebfd4250da, 0x12b6595bdb329b6fb043ba78bb28c3be],
+    [0xc06fca9b275c671c5e33d95bb7e8d729, 0x248d97d7f76283d63bec30e7a5876c11],
+    [0x8b45eb759ddde4aa106d15d9bd9baaaa, 0x1a306d439d463b0816fc6fd64cc93931],
+    [0xf4f1b54ddc27821b0d62d3d6ec7c56cf, 0x28a8f8372e3c38daced7c00421cb4621],
+    [0x071ce320c829f388bc852183e1e2ce7e, 0x0094975717f9a8a8bb35152f24d43294],
+    [0x593f74d4f653ae83f4103246db2e8d65, 0x04d5ee4c3aa78f7d80fde60d716480d3],
+    [0xc7bef54b8822cc76d08495c12efde187, 0x2a6cf5e9aa03d4336349ad6fb8ed2269],
+    [0xf792180808fd6e43baae48d7efcba3f3, 0x2304d31eaab960ba9274da43e19ddeb7],
+    [0xbff08a7e0726fcb4e1c11d39d199f0b0, 0x03fd9ac865a4b2a6d5e7009785817249],
+    [0x798afc3a209193073f7954d4d63b0b64, 0x00b7258ded52bbda2248404d55ee5044],
+    [0xb13d3a74f3298db36272c5ca65e92d9a, 0x159f81ada0771799ec38fca2d4bf65eb],
+    [0x9000130ea25f0c5471e144cf4264431f, 0x1ef90e67437fbc8550237a75bc28e3bb],
+    [0x8df739bc176b08ec95a79ed82932e30d, 0x1e65f838515e5ff0196b49aa41a2d256],
+    [0xb18c844e570e1f826575c1068c94c33f, 0x2b1b045def3a166cec6ce768d079ba74],
+    [0x65dc2d73bef715e3f1c6e07c168bb173, 0x0832e5753ceb0ff6402543b1109229c1],
+    [0xb1b841c2e8b6451bc5a8e3c390b6ad16, 0x02f614e9cedfb3dc6b762ae0a37d41ba],
+    [0x7370ebb777bedff40f6a0be27e7ed705, 0x0e2427d38bd46a60dd640b8e362cad96],
+    [0x049f0ec098c3c7c50768bbe29214a53a, 0x0493630b7c670b6deb7c84d414e7ce79],
+    [0x6bb1515355d5461a3dc06cc85327cea9, 0x22ead100e8e482674decdab17066c5a2],
+    [0x583f1ae35626d04de5084e0b6d2a6f16, 0x25b3e56e655b42cdaae2626ed2554d48],
+    [0xb599c336349e4c584b4fdc0a0cf6f9d0, 0x1e32752ada8836ef5837a6cde8ff13db],
+    [0x23c00995f05078f672a9864074d412e5, 0x2fa2a871c15a387cc50f68f6f3c3455b],
+    [0x54ccbf10661bab7fcd18e7c7a7d83505, 0x2f569b8a9a4424c9278e1db7311e889f],
+    [0xa7df93f7332ffd2144165374b246b43d, 0x044cb455110a8fdd531ade530234c518],
+    [0x91fe8c90adfe118178ddc723a5319025, 0x227808de93906d5d420246157f2e42b1],
+    [0x5d03781ae090ad4a8579d2e7a6800355, 0x02fcca2934e046bc623adead87357986],
+    [0xbad3f3c5ab91a8ddcbec2e060d8befac, 0x0ef915f0ac120b876abccceb344a1d36],
+    [0xab0fb85f6be63b09f3b16ef2b1405d38, 0x1797130f4b7a3e1777eb757bc6f287f6],
+    [0x8c7f497c20156d4d36c668555decc6e5, 0x0a76225dc04170ae3306c85abab59e60],
+    [0xf8fa76d48acb664796174b5326a31a5c, 0x1fffb9ec1992d66ba1e77a7b93209af6],
+    [0x5f8fbba6c6b9c6090611889b797b9c5f, 0x25721c4fc15a3f2853b57c338fa538d8],
+    [0xadb4c3790705da95eb63b982bfcaf75a, 0x0c817fd42d5f7a41215e3d07ba197216],
+    [0xdf8cf86ce00a22002bc15866e52b5a96, 0x13abe3f5239915d39f7e13c2c24970b6],
+    [0x5c1bc3dc29bdbd7a92cd60acb4d391ce, 0x2106feea546224ea12ef7f39987a46c8],
+    [0xf1ca5a28c748bc7157e1b3345bb0f959, 0x21ca859468a746b6aaa79474a37dab49],
+    [0x2911d14d0321662a8f1a48999e34185b, 0x05ccd6255c1e6f0c5cf1f0df934194c6],
+    [0x16c4fb267fe44fe6ea28678cb09490a4, 0x0f0e34a64b70a626e464d846674c4c88],
+    [0x47dbfcfe350d64838f5b1a8a2de0d4bf, 0x0558531a4e25470c6157794ca36d0e96],
+    [0x924cadad3f655a60b72f5864961f1455, 0x09d3dca9173ed2faceea125157683d18],
+    [0xf23f92d68aaec48617d4c722e5bd4335, 0x0328cbd54e8c0913493f866ed03d218b],
+    [0xe79e7bcc9798c648ee3347dd5329d34b, 0x2bf07216e2aff0a223a487b1a7094e07],
+    [0xd6f78ed6a6dffc82111e11a63fe412df, 0x1daf345a58006b736499c583cb76c316],
+    [0x1ef39039b2edc7ff391e6f2293d2c404, 0x176563472456aaa746b694c60e182361],
+    [0xca964d2b7d1083d4fb0225035bd3f8db, 0x2ef1e0fad9f08e87a3bb5e47d7e33538],
+    [0x9c1803dec5ae8f0a1779ed36c817ae2a, 0x226c9b1af95babcf17b2b1f57c731017],
+    [0xf8148c89f13fb35d35734eb5d4ad0def, 0x14bce3549cc3db7428126b4c3a15ae0f],
+    [0xb8e923d301f372f8e550cfd4034212c7, 0x2debff156e276bb5742c3373f2635b48],
+    [0x41afe1b6b29c47add7d0432d1d4760c7, 0x2d4083cf5a87f5b6fc2395b22e356b64],
+    [0x91bf79a10c0184d89c317c53d7161c29, 0x0c225b7bcd04bf9c34b911262fdc9c1b],
+    [0x99aa0200db66d5aa7b835265f9c9c8f3, 0x03152169d4f3d06ec33a79bfac91a02c],
+    [0xddc8f51bfdfebbb87afe8b7aa7d3199c, 0x0b61811a9210be78b05974587486d58b],
+    [0xb8ae48acf7048f16046d637a533b6f78, 0x203e000cad298daaf7eba6a5c5921878],
+    [0xba7ee386fda1112c0757143d1bfa9146, 0x1a44bf0937c722d1376672b69f6c9655],
+    [0xb8e0fde75a2106d7002f59c5611d4daa, 0x0376b4fae08cb03d3500afec1a1f56ac],
+    [0xd6fc241d3214177f3d553ef363182185, 0x00780af2ca1cad6465a2171250fdfc32],
+    [0xb75dbe18d5221c87e9d857079bdc31d5, 0x10774d9ab80c25bdeb808bedfd72a8d9],
+    [0x0d03f98929ca1d7fb56821fd19d3b6e8, 0x10dc6e9c006ea38b04b1e03b4bd9490c],
+    [0xb21f75bb60e3596170067d00141cac16, 0x00544b8338791518b2c7645a50392798],
+    [0x52e105a3b8fa852613bc534433ee428c, 0x222c01175718386f2e2e82eb122789e3],
+    [0xb77f7bdb7f7e2b46151a1430f608e3c5, 0x2840d045e9bc22b259cfb8811b1e0f45],
+    [0x04554574c2990196508e01fa5860186b, 0x062752f86eebe11a009c937e468c335b],
+    [0x1c9950c12a80bc0a55a8e83eaaf04746, 0x06041bdac48205ac87adb87c20a478a7],
+    [0x2c7a2ae092f308d82b1dcbbf51f5000d, 0x04a533f236c422d1ff900a368949b002],
+    [0xfe066d1e7dc33df04bde50a2b2d05b2a, 0x13e31d7a67232fd811d6a955b3d4f25d],
+    [0x9279d1648ff2c95d2f79905bb13920f1, 0x011c2683ae91eb4dfbc13d6357e8599a],
+    [0xd56c928e3e2c2bd0a1ecaed015aaf6ae, 0x0b0d219346b8574525b1a270e0b4cba5],
+    [0xb65080781ef9fd13d84c7a726b5f1364, 0x14abdec8db9c6dc970291ee638690209],
+    [0x01eb12202ef47ced988d0376610be106, 0x1a0b70b4b26fdc28fcd32aa3d2664788],
+    [0x6401deb2ef99c4d12704882e7278b607, 0x278543721f96d1307b6943f9804e7fe5],
+    [0x3f0738a325638d8ba36535e011d58259, 0x16eb59494a9776cf57866214dbd1473f],
+    [0x9a9e53eeab6b7f8c41c3479dcf8c644a, 0x2567a658a81ffb444f240088fa5524c6],
+    [0xab78e0215a5715a6b882ade840bb13d8, 0x29aa1d7c151e9ad0a7ab39f1abd9cf77],
+    [0x6a4f017f9a85388ce206b91f99f2c984, 0x15c091233e60efe0d4bbfce2b3641500],
+    [0xe7673ad5f1915f9feb679a8115f014cf, 0x16bd7d22ff858e5e0882c2c999558d77],
+    [0xc0182d9b668b8e08ffe6769250042025, 0x02db50480a07be0eb2c2e13ed6ef4074],
+    [0x86330ef2bf7adb4c13ba866343b73119, 0x05e4a220e6a3bc9f7b6806ec9d6cdba1],
+    [0xb5f70bc424d39fa4104d37f1cbcf7a42, 0x1dda05ebc30170bc98cbf2a5ee3b50e8],
+    [0x91da214414d89ba5cd301f22b0de8990, 0x0184bef721888187f645b6fee3667f3c],
+    [0x37414b84494e1577ad1a6d64341b78ec, 0x1498a307e68900065f5e8276f62aef1c],
+    [0xcef737b8fab1f864fe33548ad46bd49d, 0x25f40f82b31dacc4f4939800b9d2c3ea],
+    [0xe83056ce4907bfbbcb1ff31ce5bb9650, 0x09d317cc670251943f6f5862a30d2ea9],
+    [0xa41132cd467a86ab29b913b6cf3149d0, 0x2f77d77786d979b23ba4ce4a4c1b3bd0],
+    [0x1bbd336963f254c152f89e785f729bbf, 0x0f53dafd535a9f4473dc266b6fccc684],
+    [0x0e6976e1c00baf16de96de85deef2fa2, 0x25c1fd72e223045265c3a099e17526fa],
+    [0x41af95c84eaea3cf893e65d6ce4a8f62, 0x2a902c8980c17faae368d385d52d16be],
+    [0x6676dd114d1dc8d25527405762f83529, 0x1ce1580a3452ecf302878c8976b82be9],
+    [0x01c5ec569609034d2fc50f7f0f4d0056, 0x24a6073f91addc33a49a1fa306df0088],
+    [0x83e07ca554b5d1577f256c68b0be2b74, 0x25e52dbd6124530d9fc27fe306d71d45],
+    [0x5974be4d0a7b29946796e5b6cd70f15d, 0x23dffae3c423fa7a93468dbccfb02985],
+    [0x80615d50be36243a99591bc9924ed6f5, 0x06342da370cc0d8c49b77594f6b027c4],
+    [0x4778303d0405c1b4cc7df0d8e9f63925, 0x2754114281286546b75f09f115fc751b],
+    [0xabf214153833d7bdb59ee197f8187cf5, 0x15c19e8534c5c1a8862c2bc1d119edde],
+    [0x366f3be0a821061679b4b3d2d77d5f3e, 0x265fe062766d08fab4c78d0d9ef3cabe],
+    [0xd377ac5cd0146f048debfd098d3ec7be, 0x13ccf689d67a3ec9f22cb7cd0ac3a327],
+    [0xd92a5e05bdf3fe6b9fbccca4524aaebd, 0x17662f7456789739f81cd3974827a887],
+    [0x82ca6a5cca70cee4e809fd624be7ad5d, 0x21b29c76329b31c8ef18631e515f7f2f],
+    [0x9c06738165215319939eb17b01fa975c, 0x18137478382aadba441eb97fe2790198],
+    [0xd34f761935ffd3b739ceec4668f37e88, 0x2bc07ea2bfad68e8dc724f5fef2b37c2],
+    [0xb4173203c2bd94ad0e602077aef9a03e, 0x2ddb2e376f54d64a563840480df993fe],
+    [0xe8a20f8d72f613708adb25373596c3f7, 0x277eb50f2baa706106b41cb24c602609],
+    [0xb33fc4b450c0db50bb7f87734c9a1fe5, 0x0d4de47e1aba34269d0c620904f01a56],
+    [0x9f445697058f134aae908d0279a29f0c, 0x0b8442bfe9e4a1b4428673b6bd3eea6f],
+    [0x26e186a65945e96574247fddb720f8f5, 0x11fe5b18fbbea1a86e06930cb89f7d4a],
+    [0xdf5b774dcad4d883170e4ad89c33a0d6, 0x224026f6dfaf71e24d25d8f6d9f90021],
+    [0xa96bc9e37d1091f61bc9f9c62bbeb824, 0x0b2ca6a999fe6887e0704dad58d03465],
+    [0x1d0a4ce41d364797a1a7e0c96529f421, 0x221b63d66f0b45f9d40c54053a28a06b],
+    [0x7493bce64d4d24aedce2f4836bb84ad4, 0x30185c48b7b2f1d53d4120801b047d08],
+    [0x533356f0faa48f27f8267318632a61f0, 0x23f5d372a3f0e3cba989e223056227d3],
+    [0x88e1e0090d06162e8e6dfbe4328f3e3b, 0x2716683b32c755fd1bf8235ea162b1f3],
+    [0x3d140770c80ac67dc930c69748d5d4bc, 0x0977545836866fa204ca1d853ec0909e],
+    [0x5f51682d31472b05e81c43c0f9434b31, 0x1444e8f592bdbfd8025d91ab4982dd42],
+    [0xbe3ffbfe583f7012a00f874e7718fbe3, 0x26e04b65e9ca8270beb74a1c5cb8fee8],
+    [0x00f48f4febe29ad6df69816fb1a914d2, 0x22a5c2fa860d11fe34ee47a5cd9f8698],
+    [0x7ec338f3a0964c629f7474dd44c5c8d7, 0x174b54d9907d8f5c6afd672a738f4273],
+    [0x8d2189b87c8c8143d56c871907b39b87, 0x1db1db8aa45283f31168fa66694cf280],
+    [0x6f65faf8cce0ab66387341d813d1bfd1, 0x1530bf0f46527e889030b8c7b7dfde12],
+    [0x36ead9edc8f2814889330a2f2bade457, 0x0b73f613993229f59f01c1cec8760e99],
+    [0x587ab977fc8227787bd2dc0f36bcf41e, 0x29c25a22fe2164604552aaea377f448d],
+    [0x7abe82795dc272b377df57d77c875526, 0x2b30d53ed1759bfb8503da66c92cf407],
+    [0x756c08c85ede7227cf5f0a2916787cd2, 0x12f6d703b5702aab7b7b7e69359d53a2],
+    [0x55ad01071028d4841ffa9ac706364113, 0x2520e18300afda3f61a40a0b8837293a],
+    [0xac9bc59278277393c68f09fa03b8b95f, 0x1ec9daea860971ecdda8ed4f346fa967],
+    [0x83bf5cbf70ed407c08aae24b830ad725, 0x0a99b3e178db2e2e432f5cd5bef8fe44],
+    [0x07ee0abac3c817a1317abad7c5778492, 0x07cda9e63db6e39f086b89b601c2bbe4],
+    [0x49bd8290963203b35d48aab38f8fc3a3, 0x08c9c65a4f955e8952d571b191bb0adb],
+    [0x9af54a2a3f2719d33801c9c17bdd9c9e, 0x2737f8ce1d5a67b349590ddbfbd709ed],
+    [0x909432bd0c129813a9f179ba627f7d6a, 0x1049a6c65ff019f0d28770072798e8b7],
+    [0xde8868944fdf64ee60a5122361daeddb, 0x18b4fe968732c462c0ea5a9beb27cecb],
+    [0xa6f478cfcf11f1b2a4f7473483885d19, 0x2ff2b6fd22df49d2440b2eaeeefa8c02],
+    [0xe8be4057cbd8dbd18a1b352f5cef42ff, 0x2ec5f2f1928fe932e56c789b8f6bbcb3],
+    [0x424a4c6a7794ee3f08c1d100378e545e, 0x265a5eccd8b92975e33ad9f75bf3426d],
+    [0x778e656cfcb366bf20517da1dfd4279c, 0x2405eaa4c0bde1129d6242bb5ada0e68],
+    [0xfdb51955d8b2d66b76dd98a2dbf60417, 0x094c97d8c194c42e88018004cbbf2bc5],
+    [0x508b705221e6a686330c9625c2afe0b8, 0x2c30d5f33bb32c5c22b9979a605bf64d],
+    [0x4886ea583e87299e6aa2fc716fdb6cf5, 0x01a75666f6241f6825d01cc6dcb1622d],
+    [0x6d359ab9a66979fcf47bf2e87d382fcb, 0x0a3290e8398113ea4d12ac091e87be7c],
+    [0xc31219d8fa0dfc75ecd21bf69aa0cc74, 0x154ade9ca36e268dfeb38461425bb0d8],
+    [0x99231ef5dc69d8dc13a4b5095d028772, 0x27aa8d3e25380c0b1b172d79c6f22eee],
+    [0x56bbdf485afa1f549d395bbcbd806461, 0x2cf4051e6cab48301a8b2e3bca6099d7],
+    [0x5131feab8afa5eebb0843d7f84b23e71, 0x301e70f729f3c94b1d3f517ddff9f201],
+    [0x32dc4cef113ae60d17a8d7a4c91f83bc, 0x298beb64f812d25d8b4d9620347ab023],
+    [0xd1c14a15b221680acf11a3f02e46aa95, 0x1b362e72a5f847f84d03fd291c3c471e],
+    [0xaa1ef6e78e1e5ebcbc1d9ba41dc1c737, 0x0dc8a2146110c0b375432902999223d5],
+    [0xcb21729a72ddc03a08afa1eb922ff279, 0x0a48663b34ce5e1c05dc93092cb69778],
+    [0x0fe46f143b702d74545bb314881098ee, 0x0a87391fb1cd8cdf6096b64a82f9e95f],
+    [0x8826edd7ea9c29f382ba8a2a0892fd5d, 0x1b5b2946f7c28975f0512ff8e6ca362f],
+    [0x173a8bbcb8a5b987b4eac1f533315b6b, 0x01001cf512ac241d47ebe2239219bc6a],
+    [0x7ac093d3fb5f5febc72beb17d8358a32, 0x2fd977c70f645db4f704fa7d7693da72],
+    [0x761d5355c05444d99be763a97793a9c4, 0x23c0039a3fab4ad3c2d7cc688164f39e],
+    [0xaec356cf435888e79f27f22ff03fa25d, 0x19d43ee0c6081c052c9c0df6161eaac1],
+    [0x8bf29b646d020830919f9d5ca1cefe59, 0x2d9b10c2f2e7ac1afddccffd94a56302],
+    [0x2799283e166fc81cdae2f2b9f83e4267, 0x2457ca6c2f2aa30ec47e4aff5a66f5ce],
+    [0x811ee8676ed6f0c3044dfb54a7c10b35, 0x0abc392fe85eda855820592445094022],
+    [0x1161ac3993acf3101d2c2bc30eac1eb0, 0x19d2cc5ca549d1d40cebcd37f3ea54f3],
+    [0x3ee0e4ec041ba644a3d3ab546e98c9c8, 0x0f97ae3033ffa01608aafb26ae13cd39],
+    [0xfa15537ea4e168e88a166496e88cfeca, 0x16dbc78fd28b7fb8260e404cf1d427a7],
+    [0xef8344e576f8ad3d1827820366d5e07b, 0x240faf28f11499b916f085f73bc4f22e],
+    [0xeaba808c8fdb6dbf46f8cab58d9ef1af, 0x0a1bb075aa37ff0cfe6c8531e55e1770],
+    [0x38d5b085ac1042fdc4a705a7ce089f4d, 0x2e47e15ea4a47ff1a6a853aaf3a644ca],
+    [0x673ab059935f4df35fb14528375772b6, 0x166e5bf073378348860ca4a9c09d39e1],
+    [0x0cacccd027233001ed10f96538f0916f, 0x18b42d7ffdd2ea4faf235902f057a274],
+    [0x4fc368020b3ed38221deab1051c37702, 0x089cb1b032238f5e4914788e3e3c7ead],
+    [0x89f9339c7b971921d9e70863451dd8d1, 0x242acd3eb3a2f72baf7c7076dd165adf],
+    [0xeac9a068283f326474af860457245c3b, 0x174fbb104a4ee302bf47f2bd82fce896],
+    [0x2891fb2bb318613f780c275fe1116c6b, 0x17340e71d96f466d61f3058ce092c67d],
+    [0xf098b9f8fd455953a2fd380c4df7f6b2, 0x1e8e40ac853b7d42f00f2e383982d024],
+    [0x5198c55cad66e8a9bf40f92938e2e961, 0x0529898dc0649907e1d4d5e284b8d107],
+    [0xe8c77aa017ee1d7bf65f21c4d4e5df8f, 0x2162754db0baa030bf7de5bb797364dc],
+    [0xa9f9291efbde4c8421bef44741752ec6, 0x12c7553698c4bf6f3ceb250ae00c58c2],
+    [0xa733c93353e9d9c79cb723136526508e, 0x292643e3ba2026affcb8c5279313bd51],
+    [0xb6c07c5d98e66ff71db6e74d5b87d158, 0x00ccf13e0cb6f9d81d52951bea990bd5],
+    [0xb6723873cb30fc22b0f86c15ab645b4b, 0x185d1e20e23b0917dd654128cf2f3aaa],
+    [0x6778e3de0f024c0f13fe53f8d8764e1f, 0x14c61c836d55d3df742bdf11c60efa18],
+    [0x2919e2af53008184d03ee1195d72449e, 0x0f356841b3f556fce5dbe4680457691c],
+    [0x83143374fd2080ba0c0a6b6e8fa5b3e8, 0x1b8fd9ff39714e075df124f887bf40b3],
+    [0xfc3c8ae04e9df0b3e9103418796f6024, 0x0e86a8c2009c140ca3f873924e2aaa14],
+    [0x373fc43820ca2b16b0861421e79155c8, 0x2e6c5e898f5547770e5462ad932fcdd2],
+    [0xff9fe1a0ecd377972ce5fd5a0c014604, 0x05d797f1ab3647237c14f9d1df032bc9],
+    [0x1daf2dcd65519ef5ca8929851da8c008, 0x29a3110463a5aae76c3d152875981d0c],
+    [0xdc71640a8bbd1f864b732f8163883314, 0x2974da7bc074322273c3a4b91c05354c],
+    [0xa29cb91aa082c8bfcce9c522889b47dc, 0x1ed0fb06699ba249b2a30621c05eb12c],
+    [0xae29e8c572eca912d80c8ae36e40fe9b, 0x1c793ef0dcc51123654ff26d8d863fee],
+    [0x8c91e82589a78169fbb4a8770977dc2f, 0x1e6aac1c6d3dd3157956257d3d234ef1],
+    [0xd037748080a47d948fcda33256fb6bf5, 0x1a20ada7576234eee6273dd6fa98b25e],
+    [0x642d772045ece51335d49306728af96c, 0x191033d6d85ceaa6fc7a9a23a6fd9996],
+    [0xc76f200b3740b8b232ef481f5d06297b, 0x006e5979da7e7ef53a825aa6fddc3abf],
+    [0xfbd57f596c8f29831eff8c0174cdb06d, 0x0b0d7e69c651910bbef3e68d417e9fa0],
+    [0x4ac46dbbb033c5112c4b20a25c9cdf9d, 0x25caf5b0c1b93bc516435ec084e2ecd4],
+    [0xf7f77442d62fd4c8085b2f150f72472a, 0x12c1ea892cc31e0d9af8b796d9645872],
+    [0xe5a929d9f928b9b81de6dadc78c32aae, 0x16af29695157aba9b8bbe3afeb245fee],
+    [0x705b87ec5a4cfdc168d31084256b67dc, 0x0136df457c80588dd687fb2f3be18691],
+    [0x9e07b1efbc74434db95a285060e7b089, 0x1639a28c5b4c81166aea984fba6e7147],
+    [0x816c28b700bdc50f7e232bd9b5ca9b76, 0x03d62fbf82fd1d4313f8e650f587ec06],
+    [0xf77a1e40fc6da97c249830de1edfde54, 0x11aeeb527dc8ce44b4d14aaddca3cfe2],
+    [0x6a670e6bc68c7a49642b645807bfc824, 0x13f9b9a41274129479c5e6138c6c8ee3],
+    [0x5ddeeed7a939440c506cae8b7ebcd15b, 0x0e4772fa3d75179dc8484cd26c7c1f63],
+    [0xb5971752067a612b39fc46a68c5d4db4, 0x1b39a00cbc81e427de4bdec58febe8d8],
+    [0xf66463c2eb54a245444d1c0a3a25707e, 0x2bedb66e1ad5a1d571e16e2953f48731],
+    [0xb08b193b608582a27379ce35da915dec, 0x2cf0a09a55ca93af8abd068f06a7287f],
+    [0x7d1a512050ba7db0753c8fb863efb387, 0x2d1bd78fa90e77aa88830cabfef2f8d2],
+    [0xf7c0d49c1387062e630d7fd283dc3394, 0x065610c6f4f92491f423d3071eb83539],
+    [0xcc5f9969033f15ec642fb464bd607368, 0x2d933ff19217a5545013b12873452beb],
+    [0x00dae5354e79508c3c49c8aa99e0258b, 0x1aa9d3fe4c644910f76b92b3e13b30d5],
+    [0x27095fa773e1aca078cea1f1c8450bdd, 0x027ef04869e482b1c748638c59111c6b],
+    [0x9f67a2605d9ec03802e3fa136ad0b8fb, 0x2b7d524c5172cbbb15db4e00668a8c44],
+    [0x4693ae25b1e55df13f7c3c1dd735db0f, 0x0c7c382443c6aa787c8718d86747c7f7],
+    [0x7a1f43c2d30d0fe4a627dcdd9bd79078, 0x00b4567186bc3f7c62a7b56acf4f7620],
+    [0xb07fe739e4c1e61d0337490883db4fd5, 0x1e41fc29b825454fe6d61737fe08b47f],
+    [0x21e1af872d8c0e89002ae8d3ba0653b6, 0x12507cd556b7bbcc72ee6dafc6165844],
+    [0x97eb36617ef36fe4d77d3e97f71cb5db, 0x13d437083553006bcef312e5e6f52a5d],
+    [0x467d90b22f0b38664686077c6a4486d5, 0x163ec73251f85443687222487dda9a65],
+];
+
+/// MDS matrix for width 4, row by row.
+const MDS_4: [[u128; 2]; 16] = [
+    [0xe5e39942296127fd87947223ae5108ad, 0x236d13393ef85cc48a351dd786dd7a1d],
+    [0x967f1dc58718e59e3cedc821b2a7ae19, 0x277686494f7644bbc4a9b194e10724eb],
+    [0x5129c16479973b0a84a4529e66b09c62, 0x023db68784e3f0cc0b85618826a9b350],
+    [0x8af08cdbd63017c57b3a75646ff382c1, 0x1d359d245f286c12d50d663bae733f97],
+    [0xfe9bc7fb1f70943ff049bc970e841a0c, 0x2a75a171563b807db525be259699ab28],
+    [0x08b4dd3e15ccc3706f38ce4157b6770e, 0x083abff5e10051f078e2827d092e1ae8],
+    [0x24d5c4741eab8b7568a9ff8253a1eb6f, 0x1a5ad71bbbecd8a97dc49cfdbae303ad],
+    [0x945004a7bc2c59e8790f725c5d84f0af, 0x0d745fd00dd167fb86772133640f02ce],
+    [0x497ad2eecbaa7e42f366b3e521c4ed42, 0x2070679e798782ef592a52ca9cef820d],
+    [0x06ece318cd224ab6b3a2be979e2d7eab, 0x2e18c8570d20bf5df800739a53da75d9],
+    [0xf2e4386d3e5b9f38fa283c6aa723b608, 0x0fa86f0f27e4d3dd7f3367ce86f684f1],
+    [0x57035ee3da6b2ca83f0c2491e0b403eb, 0x03f3e6fab791f16628168e4b14dbaeb6],
+    [0x8ff0613fd79375f8ba8b3d30958e7677, 0x2f545e578202c9732488540e41f783b6],
+    [0xb8104c32ba4cd701596a15623d01476e, 0x23810bf82877fc19bff7eefeae3faf4b],
+    [0x1c068ef930f10be2207ed58d2a34cdd6, 0x014fcd5eb0be6d5beeafc4944034cf32],
+    [0x8ba4a8b627627cc2bb661c25d20fb52a, 0x00c15fc3a1d5733dd835eae0823e377f],
+];
+
+/// Round constants for width 5, 68 rounds of 5 elements each.
+const ROUND_CONSTANTS_5: [[u128; 2]; 340] = [
+    [0xd889bb4ebd47c3864f3c2bd81a6da891, 0x0eb544fee2815dda7f53e29ccac98ed7],
+    [0xa197aeb12ea64713ba733f28475128cb, 0x0554d736315b8662f02fdba7dd737fbc],
+    [0x54907df0c0fb0035f5087c58d5e8c2d4, 0x2f83b9df259b2b68bcd748056307c377],
+    [0x61f15f8b41a75ef31915208f5aba9683, 0x2ca70e2e8d7f39a12447ac83052451b4],
+    [0x994196f12ed22c5d4ec719cb83ecfea9, 0x1cb5f9319be6a45e91b04d7222271c94],
+    [0x21c7bb47b93750c2b9ea08d18446c122, 0x2eb4f99c69f966ebf8a42192de7ff616],
+    [0x0fc7da8b93ee13b6d5f7d099e299520e, 0x224a28e5a35385a7c5198169e405d9ea],
+    [0x36f33ecbd9a0f97823796b993bbd82f7, 0x0f7411b465e600eed8afdd6afca49c30],
+    [0x19b208ae3370f99a0626f9ff5d90e4e3, 0x0f9d0d5aad2c9555a2be7150392d8d98],
+    [0x9732b25259cf744b6a12d30702d6fba0, 0x1e9a96dc8292bb596f52a59538d32922],
+    [0x3cfe52eab4b945c6c2cd4d528fb3fe3c, 0x08780514ccd90380887d578c45555e59],
+    [0xced64717e3556d5a59f119d629ccb5fc, 0x272498fced686c7ac8149fa3f73ef8c2],
+    [0x45bd350aff585f10e3d0ef8a782ef7df, 0x01ef8f9dd7c93aac4b7cb80930bd06eb],
+    [0x8e886e64bf3c40aa97ea0ae754934d30, 0x045b9f59b6595e614dc08f222b469b13],
+    [0xa3e41e24e247a387adf2584295d61c66, 0x0ac1e91c57d9da919fd6f59d2a40ff8e],
+    [0x0fd67061aee99979d12e68f04e62d134, 0x028a1621a94054b0c7f9a421353cd89d],
+    [0x50c19c3fb3c96d09d02aae2a0dcd9dbc, 0x26b41802c071ea4c9632647ed059236e],
+    [0x05c9de06758db6a94af34384aedb462b, 0x2fb5dda8072bb72cbaac2f63e468215e],
+    [0xad8ab8ba2a18d383dd05c56ee894d850, 0x2212d3a0f5fccaf244ff3547fd823249],
+    [0xa3276fdb19f44c015cd0c7eed465e2e3, 0x1b041ad5b2f0684258e4dfaeea09be56],
+    [0x144fb7e3ac14e846a91e64afb1500eff, 0x0a01776bb22f4b6b8eccff33e76fded3],
+    [0xa4f33ae8c15cf827f7936440810ace43, 0x2b7b5674aaecc3cbf34d3f275066d549],
+    [0x60b042257b78fc004c1b803381a3bdfd, 0x29d299b80cd4489e4cf75779ed54b48c],
+    [0xa427110032b5e1dd19dde30424be401e, 0x1c46831d9a74529357641c219d721a74],
+    [0x36296c0657674f80739ae1d883e91269, 0x06d7626c953ccb72f37141dc34d578e0],
+    [0xdc5c440a3022cd960f108c71cda2930c, 0x28ffddc86f18c136c54002748e0c410e],
+    [0xbe67f1b7ed2ab6adb8ec0619f6fbc5e9, 0x2e67f7ee5e4aa295f85deed09e400b17],
+    [0xca56859ef759e53ce7abf22c24e80f27, 0x26ce38fa636c90630e97f25114a79a2d],
+    [0x42cb3d16a1f72721afacb4c4cfd35db1, 0x2e6e07c3c95bf7c34dd7a01d00a7ffec],
+    [0x8890d276612e1246384b1470da24d8cc, 0x2aa74f7597f0c9f45f91d7961c3a54fb],
+    [0x8a71313c1509183e2ec0ca639b7f73fe, 0x287d681a46a2faae2c7c090f668ab45b],
+    [0xa5d3106ff565aa3b11e29f3305e73c04, 0x212bd19df812eaaef4a40600528f3d7d],
+    [0xf97fd9740926dab93809c28404713504, 0x1154f7cf519186bf1aafb14b350eb860],
+    [0xbf5152905be36583da747e79661fc207, 0x1dff6385cb31f1c24637810a4bd1b16f],
+    [0x4011a34d5476252863ea3c606b551e5c, 0x0e444582d22b4e76c081d34c44c18e42],
+    [0x815773e9c2846323ff72d3aab7e4eff8, 0x0323c9e433ba66c4abab6638328f02f1],
+    [0x25b8cf002740112db70f2c6876a9c29d, 0x12746bbd71791059193bba79cdec448f],
+    [0xd466c837cf50d73efd049eb4438a2240, 0x1173b7d112c2a798fd9b9d3751842c75],
+    [0xa8e5713b25026ebe5fdb4808703243da, 0x13d51c1090a1ad4876d1e555d7fed13d],
+    [0xcb72743f0394efe7f4a58ebeb956baa1, 0x00874c1344a4ad51ff8dcb7cbd2d9743],
+    [0x0eea48d3546e97d6a32a562074fef08f, 0x22df22131aaab85865ce236b07f244fa],
+    [0x984524a59101e6c18bf5eb05a919f155, 0x0bf964d2dbd25b908708b437a445fc3e],
+    [0x640b9d73a9ab298c69b435b5fc502f32, 0x09b18d9b917a55bca302be1f7f181e0e],
+    [0xfbbbc70a6365366dd6745a5067289e43, 0x094f5534444fae36a4bfc1d5bf3dc05b],
+    [0xa3e240c0da5701cb784fddc0dc23f01f, 0x2999bab1a5f25210519fa6622af53a15],
+    [0x84301bce8a93d13669575a11b03a3d23, 0x2f6898c07581f6371ca94db73710e880],
+    [0x565deb1e8e5742f862174b1a6866fccb, 0x07268eaaba08bc19ec16d7e1318a4740],
+    [0x62603e078e1c6689a6c9582c41a0529f, 0x186279b003454db01339ff77113bc9eb],
+    [0xb67e2cc5de9a22750768e5524737172c, 0x18a3f736509197d6e4915bdd04d3e5dd],
+    [0x725e2d4bcb2d3a007b5987b87085671d, 0x0a21fa1988cf38d877cc1e2ed24c808c],
+    [0x8328c184a2c43bc00b36a135e785fba2, 0x15b285cbe26c467f1faf5ef6a6462522],
+    [0x0b7775b7c902f5788cd28de3e779f161, 0x164b7062c4671cf08c08b8c3f9806d56],
+    [0x61c61d3d43b6e65d7a24f651249baa70, 0x0890ba0819ac0a6f86d9865fe7e50ef3],
+    [0xa627ac5cb0eb878ccc2ee0aed543e922, 0x2fbea4d65d7ed425a42712e5a721e4ea],
+    [0x7b70a58e854ab9b9103d7f5f379abaaa, 0x0492bf383c36fa55540303a3b536f85e],
+    [0x1d6185a9ce85675f6a969d56292dc24e, 0x05e91fe944e944104e20251c565142d6],
+    [0x995b9621e6e49c3b7e380a76e36e6c1c, 0x12fe5c2029e4b33893d463cb041acad0],
+    [0x1f2629fadc89496906cd01dc6fa0784e, 0x024154adf0255d47958f772392147413],
+    [0xd798815644f2bbde8813c13457a45550, 0x18824a09e6afaf4a36ed2462a86bd0ba],
+    [0xdf84a630af68d50bbb06983e3d5d58a5, 0x0c8b482dba0ad51be9f255de0c3dbddd],
+    [0x5a4fa67fcd6aaf86441392878fdb05e6, 0x17325fd0ab635871363e0a1667d3b67c],
+    [0xe550773fa8d18bf71cc6d0e911fa402e, 0x050ae95f6d2f1519122f5af67b690f31],
+    [0x4bbb6295f07569885771ec84edc50c40, 0x0f0d139a0e81e943038cb288d6263676],
+    [0x79b39ebc7a1b1c549dbbca7b8e747cd6, 0x1c0f8697795689cdf70fd2f2c0f93d1a],
+    [0x49be23a4b13598f9fe536a16dc1d81e6, 0x2bd0f940ad936b796d2bc2e048bc979e],
+    [0xa06ebb275e096d167ce54d1e96ee62cb, 0x27eb1be27c9c4e934778c09a0053337f],
+    [0x284fbd307d1f71b0f151be62548e2aea, 0x2e4889d830a67e5a8f96bdd3155a7ca3],
+    [0xc9891f2cadc165db6064bbe6fcc1e305, 0x193fe3db0ab47d3c5d2ec5e9c5bd9983],
+    [0x6e9661c009679e4e37cb13027c83e525, 0x2bf3086e96c36c7bce415907ad0c40ed],
+    [0xd5e7e413f741ccf29ff2ea486e59bb28, 0x12f16e2de6d4ad46a98cdb697c6cad5d],
+    [0x33f3d5d6ec6c4bf0ad919b0343b92d2f, 0x2a72147d230119f3a0262e3653ddd19f],
+    [0x0a2d9bcc26412e2977df79acc10ba974, 0x21be0e2c4bfd64e56dc47f957806dc5f],
+    [0x71b84fb911aa57ae137fd4b6c21b444a, 0x0e2d7e1dc946d70b2749a3b54367b25a],
+    [0xc31adb0eae3325dc9f3f07d4b92b3e2e, 0x2667f7fb5a4fa1246170a745d8a4188c],
+    [0x1550c12b08dfeb72830e107da78e3405, 0x2ccc6f431fb7400730a783b66064697a],
+    [0xae6dbee9e8ca0c242ec50621e38e6e5d, 0x08888a94fc5a2ca34f0201462420001f],
+    [0xa0d2fbe753af88b36acfcd3ccbc53f2a, 0x02977b34eeaa3cb6ad40dd42c9b6fdd7],
+    [0xbfcfe0d7e6ff8e9610b5e9f971e16b9a, 0x120ccce13d28b75cfd6fb6c9ea13a648],
+    [0x92787a4575b2bd73a0bf2af32f86ff3c, 0x09fad2269c4a8e93c81e1b9770ea098c],
+    [0x0debdb70775eeb8af630cffb60092d6f, 0x026091fd3d4c44d50a4b310e4ac6f0fa],
+    [0x2543cc56afad6afcb904fd2bca893994, 0x29404aa2ba565b77bb7fba9dfb6fc321],
+    [0xf1ffd86590e0827de7201351b7c883f9, 0x2749475c399aaf39d4e87c2548695b4e],
+    [0xa2ebe2dc2e4da70ac7557dab65ffa222, 0x098c842322479f7239912b50424685cb],
+    [0x758ace14c93c4da40191d0c053b51936, 0x18cef581222b647e31238e57fead7d5c],
+    [0x3cbc0ca4a108f98d63b2aa681698de60, 0x13177839c68a5080d4e746745e43711d],
+    [0x26cc4df712c0e5f0a907d88e5f0deffd, 0x020ca696f531e43ec088f56f4b743256],
+    [0x93d13708c646841d16e028387c7ac022, 0x27230eede9cccfc9fa805a30fc548db6],
+    [0x6497c05969a015439057d2fe75bb281c, 0x01645911c1198b01d64fde34a342a178],
+    [0x198971e14487056cfdca4a451a5d8643, 0x2c323fe16481bf496e439c88341ce25f],
+    [0xb022c124d3bffe8b5af88ae6db5085c8, 0x0fc082dfe70728e8450bd2074c3e22e1],
+    [0xbe9642116ac4c77efe8a488b423521ee, 0x2052c174800db209d8cdca568dcc25b3],
+    [0x0be351ce8129065a8dd9fd05b3ece9c0, 0x28e420e10df2fbb5af96d621d5542319],
+    [0x55d6ae1bdacd1cb549d6e0bc3ae5069a, 0x25698ca5e24a1b799f783c4462a24db6],
+    [0xa297b61074422ac134d9b857d6984d35, 0x160a9981a5c89a57cf8ffbfa57d51049],
+    [0x8b7cebf59ddbb0a064642b069997f3d4, 0x21c91a39e145c3bc34d9b694b843f3bf],
+    [0xd6eaa029d93f03b6d10975461e41734c, 0x1ac8d80dcd5ee876d2b09345ef112345],
+    [0x5d822895e2755544b8dbcea5657ce02c, 0x0ab3e6ad0ecf8b8e7c1662a4174c5222],
+    [0xa52ef3ef5909b4e1c5b2237cbdab3377, 0x1c675182512620ae27e3b0b917b3a21c],
+    [0xe37a4a3e07a7d75d0c8a9092ac2bed45, 0x2cdbc998dfd7affd3d948d0c85bad2e2],
+    [0x24337350309dff833e491a133bb63b2e, 0x23b584a56e2117b0774bf67cc0dee333],
+    [0x0d133bc6ba4ec1152f3d20de1465e9a5, 0x1e9e2b310f60ba9f8cb73030a3c9d2a1],
+    [0xc9ab5dab987520c460bcd4f1fa5b22db, 0x0e01e365ba5b3031abc3e720140ae746],
+    [0x43382011b61c9a4b1387d85bc1264e68, 0x040884cdcfc64bfc7b7127340498d5c4],
+    [0x353f1724d6067ed0a0a17de311ef9668, 0x190b1ee1205eb9500c74a3998f2bea36],
+    [0xc1dfcf65ce61e165fc28d1f832bd3b2c, 0x1647c72aec6c4388d04f52fc23cd9c08],
+    [0x9f5494d89fb48b02842e595b71e4541d, 0x2430006346a0145f799880cc4c873626],
+    [0x9d853902bb16bacb3221850252b757af, 0x177b9a08343917e1365107a3da3ae7f6],
+    [0x09cd53d0ae29423439b11d04666df4f8, 0x04a420e642b11ae94e58862a68f5e326],
+    [0xde2461858e956ccccdfabeddb6a25c8f, 0x25d0e0f739fb39fc105a88fab0afd810],
+    [0x320cb15610eaed452c4d4ffa0c740a27, 0x04476d91b7eff2fd85905cbf58651edc],
+    [0x1c28f6d5e1250cb5e9780fd9ca286fae, 0x1090c0b68b3d7d7b8bc9ca2419eb8dea],
+    [0x376f2d435855c10ebf2899cb5c6617be, 0x25393ce3b9256d50448a725c5c7cd5ad],
+    [0xed824388d6b9342697d144f0fab46630, 0x25931c0c7371f4f1fc862f306e6e5830],
+    [0x8a4d353185d5808203f73f22afbf62f6, 0x2396cb501700bbe6c82aad51b0fb79cf],
+    [0xa3dc9068c3cca7b5b3f0ce03b8724884, 0x26a363483348b58954ea748a7129a7b0],
+    [0xc99b893334215f6ba7a0e5b45fcd6897, 0x27ca107ca204f2a18d6f1535b92c5478],
+    [0xac15f7301178581d8d2d02b3b2d91056, 0x26da28fc097ed77ce4662bde326b2cce],
+    [0x655774c1bb35d57572971ba56ee0cb89, 0x056ab351691d8bb3703e3055070ac9cc],
+    [0xc22547a11ffc50152d729af632376a90, 0x2638b57f23b754aec76d109a2f481aa3],
+    [0xc33e46a532bdec80ea7bc5519ede7cef, 0x304754bb8c57d60732f492c2605184fd],
+    [0x48ec6857fc554010752ac93a9b7619ac, 0x00d1727f8457ee03514f155b5806cbf7],
+    [0xb5bca86805ec9419c588e50947761fa3, 0x00ee1f3c66fbc05c43ba295a303c72fa],
+    [0x10a19fbcfc59078c61f9297eb675d972, 0x0afafadcf5b4dd4a4a76b5a1d82415fd],
+    [0x65a234835a0a6a5ea8996d124dd04d0a, 0x0b2449f39746085e86ce45e8eed108ee],
+    [0xf6c6f071ec3bdda76a7ddf4823dd5dd6, 0x206b0ce2f1b2c5b7c9f37b0045227095],
+    [0xcaffc3a4ef20fea852c7e1029459409c, 0x0feba4fb87834c7cb696e67433628cd6],
+    [0x3b06f1315e6d70e18173e972336e55d3, 0x254dbfac74c49b0b8926752e084e0251],
+    [0x9606c5bd17910aeb37719edfa0ca8762, 0x0addb1372cee4e164655168c367559e1],
+    [0x6ca4d8d81b1ae16221a589d68831d759, 0x26b25b7e257f3e97c799024fb019f65c],
+    [0xa4657b9ab00bdb5b1960b1059e113ba3, 0x090995b79acec240413b8d4c658787e5],
+    [0xeb0d8e40e99131f42974178d44f73b7b, 0x08dbdc2e21ef11f2c57299687843cea3],
+    [0x42fe9c491596ab6758f170939785179f, 0x09e8aba671481197679faf752a0f78e3],
+    [0x7efd12a7f9eec94b7bc7c683f1363d5c, 0x1deb05180e833e45659052a7ebaf816c],
+    [0x248997e8b2c24af335fd6523e5250879, 0x19a70ec6bdfc9098a926efbcc04aa9ee],
+    [0x66353a3777d8a3f1eb93abe10bbf1f64, 0x21d773660adafb8a879986f9aab48905],
+    [0x97a6b01fcd667347f6f46617841c3901, 0x09f1890f72e9dc713e20ba637b89d5d3],
+    [0x48fa1f897bf219d608c2145c33b111c3, 0x05af459361eb454d2a300c61e446998d],
+    [0x5f336f15f340756cfa12fc850cc8b513, 0x0fa1a1d6829f0345664a66dc75a65733],
+    [0xf4280fcf87f636f853655cf97a628bb0, 0x02e47a35bcc0c3a0bda0b1c0307ad543],
+    [0x203411460112491036621895204d0f12, 0x14f773e9834c6bdeb8f90e78bf4c24b7],
+    [0x642abe7cfd639992ffb091962fc8f7cc, 0x102d98cf502ed843255cf19d29bc7d8e],
+    [0x20d4c73ad3294738cba686a7e91373c2, 0x043dd5f4aa5a76dd4c47f6c65da7ca23],
+    [0x6f0e7c79743a306f4cfdb2b26bd11efa, 0x21833819c3337194a6c0d29a48d4f267],
+    [0xeb4eca246c311ecadc53262a3cff2b53, 0x0f281925cf5ee649b474a6819d116ca3],
+    [0xdf625dd60504d5dc93ce662f15c238d6, 0x0d3e2477a7b10beb44709c7746d6824e],
+    [0xd80f80ab51e73b49acbfc3eff5aefc44, 0x2cd7f641bedbf66956ff8a01be9cde35],
+    [0x9149d24045811d7a86dd861310463cf8, 0x29e95b492bf2f95f4d09380f98b74e38],
+    [0x9ae4c51af6ffeb57f8b3c50df83cc13e, 0x22da66bc62e8f011266efca86a6c810f],
+    [0x220db79e8129df3643072d841925554a, 0x0fe6d30de7a82d163023491794f4aca3],
+    [0xd312d03fef1adfecc7e07ecb298fd67f, 0x0050e842a1299909123c46eff185c23a],
+    [0x666f9ddf714ed7c5885cbbdb63108c21, 0x2130a3a7b3221222be34cc53a42d7733],
+    [0xaa66731f34a93280e1d328e67b33c9fa, 0x2df9ee294edf99e3d8d5883fe0566c24],
+    [0x58132396dc250aeba4b6fc5fc3372762, 0x1bf7d6e489ad8c0cf26eb68cc21ff541],
+    [0xcf7b807728bf7fe35d4778d311780e54, 0x0c602fa155be958761eaf739617ab136],
+    [0x5154080a24972faeb63faf0121ed7f21, 0x2e50e2c5b36aa20532407d86b8d22d7d],
+    [0xf674995ee8409b42b459123b180332e1, 0x17c2510982a7b5825710d6290ec4f782],
+    [0x21337b538eabd2f6b2ab255f376b42a8, 0x0b0d52f03c8af7276803ecf2465b885b],
+    [0xa9ac77c6c0f85d4561972dd8fab8bd14, 0x0f5633df1972b9455953d88a63f80647],
+    [0x24780ff43e76e929035498130a7f1572, 0x0ebf7ad29ca13804e1422e9396811551],
+    [0xe18f94bee27c8a57661b1103a720ffe2, 0x1aff13c81bda47e80b02962173bba343],
+    [0xd3f31de1a3b58ff35713be57efac6c07, 0x210449dbf5cf3061da2465be85505862],
+    [0x19d1e2f1d3001044b93ad1c3ee629817, 0x088230c2794e50c57d75cd6d3c7b9dbe],
+    [0x8cce9a9e16c7705617a4d6dbb20e7e3a, 0x1c408c256490b0a1da08dc464138dfc7],
+    [0x8f7c77654d58440490dd6f557e9e3903, 0x074517e081eb4c1f22d1771200fb0765],
+    [0xcee5530559d6cf0fa05adf61e12fdcbf, 0x02d04e9c21df1dbd88524bdb203691b4],
+    [0xeb9b4650dae9f11aa81db32cf1b67b13, 0x2eb7a011b8bce91082e13ebd75de3b58],
+    [0x4d2ac6bf95368304e6030c18f0cf17b5, 0x2efda77ed35f4af0299f75d6e8a849b5],
+    [0x61a73d10852b8114c51b2440192ae064, 0x09199dcafd50ce642eddbeda65206d4f],
+    [0xcb655d8c1797e9fa76181cb4216e1562, 0x268c5cfc446d399c4dd319db666a75b5],
+    [0x97b44e912cce6687012854eda11a18dc, 0x2303a652c949071826b0e9a36c805786],
+    [0xbc5353eb110868d237073f4efb35fbdf, 0x27c53563b12a6ee2c3f041f31dc45922],
+    [0x9969b5248cfe90f42c278f22615d2b0e, 0x1201a87eaf4ae618f02bd82d0a510904],
+    [0xcafcb1ba2c51e5706cb4b43dab2a443d, 0x2c43169439fcd69ead8214997bb069be],
+    [0x4f46cbb36d702e3c4fb9847e6304d944, 0x0683597315359040ea03c45d6984c689],
+    [0xb0425e158314197b77795ad3a798d183, 0x03545706706eab36afb93b128febd16f],
+    [0x740bed23a6a37870edb393b71a0c0e6b, 0x1a33c254ec117619d35f1fc051b31728],
+    [0x6e88f71e759b87e6f338e517f1690c78, 0x1ffe6968a4470cd567b0c002281caf99],
+    [0x140ddd5d2a5c4483107f4e02e355b393, 0x0fd66e03ba8808ffecb059c899fd80f4],
+    [0x6c8617a7bdd5d74a7be3396b7fe013ab, 0x263ab69f13b966f8197394552906b17e],
+    [0x3bd87d5aa3958fdd6eb7e03e39ba4046, 0x16a425e47d1110625054d5a165de413e],
+    [0x53cc31d13e39e909fcc5f73af9138d9a, 0x2dc510a4719ec10cad752f03c673f0e2],
+    [0x423c5179329b7a82cb4aa709a94576e5, 0x24df8e8d856c5b5e1bd1cad23d07dda3],
+    [0x7628249a01b09561bdf24a6cdce5620f, 0x2bcc94ff4fc3c76f3cd5c68915a042e8],
+    [0xd3295f52c38cffe6b48be86852da97df, 0x076c1e88dc540c8d8de54e343df7c429],
+    [0xe40ee1601120947da990fb8e12cb46e1, 0x09b5f209a451ac431c051fb12d9a5e4f],
+    [0x64e9424f55b0f1ea0683af75eb677c07, 0x205f17b0d8729e2eaa88d6a44135a6ab],
+    [0x091681f0a41761720cdd1edf9f237029, 0x281c5c688836f6cf912638c38be046cd],
+    [0x009a44e7a02ea50e4afa44f2592621f5, 0x1a053e6878e900f45f4d67448c471cf3],
+    [0x68efcb897e7bbee981742839d59e064c, 0x100dc7d426debe3007fb7ceac84e4f54],
+    [0xb28905bdb62c82c80b1cb31b411e49c8, 0x17022672a016a957bb87e2cfadc8b75f],
+    [0x9fb8b547182b170de0c27203f954f4d2, 0x1086db7e2760fc8b71053a87ebe15123],
+    [0x2b41fb65a185536fb85dd24fd7584064, 0x15384fe39d73b63302460ae4c2942fac],
+    [0x47b1a853205fcfb5519e551357709008, 0x2ebb599fe9136d424bf4abc5342c6c74],
+    [0x4cf278292b4ce3eed996fac6f4d37288, 0x1b4b5e87cfb9262cfec3c0f0542e4c5a],
+    [0xbb4a7581bb4fba60b637febe659e5057, 0x2465053ae50b6885801f3f82e302cafb],
+    [0xda9f05e18b3708bf6e0ab9d3d54859ef, 0x114f32edcdea09cd095c5bb5d38f1b97],
+    [0x2e5e5519f3d18123ee28d8c2543c7148, 0x2bc70dfeb2baab2f6b387cd77be779ac],
+    [0xa78348b9f6ec68a412e49bfe32c05415, 0x01c9bf7a203ce22b775e3a61ad7e77b6],
+    [0x55cfb575ff6a97cd9f4ad00ccb57ee9b, 0x0514b0fe5909ea887bedb0295fbbcec3],
+    [0x2092520b12a201af03e3202d7b6c1b7e, 0x267c76ec81934cc81a132a8b058910a1],
+    [0xf1622493ce83e95cfb151cf757bde5d6, 0x29170e3322b3d8d5c78c84babbb470ad],
+    [0x5c6f09a45486cab536dd596ca41d9519, 0x019f6a8124b19e33af33e5d3873f9c33],
+    [0x009ed8ea171518ae5405d036242b60e9, 0x1904aa4d6908544a8b348e9db1981c27],
+    [0xb3cee1de9dd6f6473b932a476455ff1a, 0x26f17873949bc679f7f043956694e422],
+    [0x40c476031197131ebdcac9b18bc48f75, 0x1ac668f612b8243c193b33720b8aa540],
+    [0xccfbf8555be9796da52f81568ef0663d, 0x0996d961a75c0d07196dae45bf624766],
+    [0x74d171f99c63febb5425b395c24fc819, 0x030c97e1b8cad1d4fd50d1b4383fbe66],
+    [0xb3e523f1fe502642ee226f2d8bd0848f, 0x06e3ad6a46900e2d3953370255b68f89],
+    [0x65cb94b0e0455153a329890588cc916e, 0x1d6b3755331cd0216b6880e42f9880f5],
+    [0xc35189dc0b85ac033dd38c08bae531f2, 0x28e4dcba4b96f12a59b041535e730ac8],
+    [0xb0712a476260376c7a3b3e4bc4a47a14, 0x08b6086046a835508ccf484f2974b6a6],
+    [0xb6fd85fba6a0536a89643e15b9bb3b52, 0x162cd2ca7fe3b5f1444bcec97812019b],
+    [0xb5c9a9c1192063d1998c01c64d483a76, 0x28f1e03baaea9bbc05af5b11937e4f5c],
+    [0x0127d2aab4aa71366031a0bb6791ce10, 0x1bdb062778d7c15da395af2734c25faa],
+    [0x0fc98870b2324a8b50329ebdd24749cb, 0x2375839502e09890cb2914e829627e0e],
+    [0x332dc87cfb2df456e92d33611ed7bb50, 0x1fa8662fbcb61fb3ad7c55668dc9423a],
+    [0x46e2fb2c47a5138f3f7f9736079d7694, 0x1e4fad2dd6b0a6f1f8707f721716c8a4],
+    [0xba3bd050059f53d261b0f5f13731ffe7, 0x211256d16c7269fd6df6f5fcdd1fa788],
+    [0xf42060e574dda341f4a1079b12bcc5a5, 0x2e49084b336eceaa4f8e2a2e6af08318],
+    [0x1aecea08dfe14cab758d25891fb00bb9, 0x0ce19f54cdc39f7f3bf35192ac680821],
+    [0x8dc60451e4ae4e1c84a8468bab2c14cb, 0x0011c5d56c390e893cc394221261d874],
+    [0xca60e17bfa39b47514a8cd8051579b4c, 0x17d79ff06b63ac2a8a9e05ee6af3dbb7],
+    [0xb1a8b35cd6416a2ecab00173639015fa, 0x19a7d3a446cb5393dc74560093592b06],
+    [0x31f04596d8928da7aa6c3c97237037a6, 0x030c00a0933dcdba2a808b2e1b9282f3],
+    [0x935d2d00184c4acc9370be8aab64139c, 0x16bcb447ce2d50f3ae25ad080695382e],
+    [0xe62124f37cab7b6d39255b7cd66feb1d, 0x12341b46b0150aa25ea4ec8715312997],
+    [0x002fc28e296d1044dc89212db6a49ff4, 0x0e86d13917f44050b72a97b2bf610c84],
+    [0x1052a181f8f2eb14d059025b110c7262, 0x08e6eb4089d37d66d357e00b53d7f30d],
+    [0x0415ccb351a1e0cee10c48ce97ca7b18, 0x2ea123856245f6c84738d15dd1481a0c],
+    [0x4195789025413abf664db0f9c84dfa6f, 0x2dca72b2ebcab8c23446e00330b16310],
+    [0xf2f6b4235f036fa4c64a26cbd42b6a6b, 0x06ff9ed50d327e8463329f585ec924b3],
+    [0xe2a60e0cca84ea2ac630a4535afbf730, 0x246a10b7e3e0089947f7c9bda3d54df8],
+    [0x2f846a715ae67ad75c96b39d688b6691, 0x22a63501c5f04b9018719ed99d700ee5],
+    [0xb9164f58351d8aa140ec07e514fae937, 0x2f4c50477f7fd9c671799ac5d2e224cd],
+    [0x38f0ba8a4a23d4b50cda32cad851567e, 0x10ffb7aad1f51c7d13b17f4d876d9a1e],
+    [0x784027352187e7af1a056935c35803ae, 0x0e9cefddc3c2d3bea4d39722532d5420],
+    [0xa1656f96a33c8978a3e83bdd4ba62b41, 0x07af84a4d3141e7ac23352e6dc6ea4af],
+    [0x1a39323d6e89b638e940f3ec8a22c3c5, 0x2d9e31a10aebc761f8de00d14b1e566d],
+    [0xd36034b314dad8447656747be27e64c7, 0x27f19a6532e66b5333db1afd592f66f1],
+    [0x180ed99f8f3155cd7e4d617d47d07ffd, 0x0058fa3c8454d63354b2024c3b4a577a],
+    [0xb0414a205d3a175d708964956816a5d5, 0x041627b6715b780967957c080699343e],
+    [0xd98201471cf1f66589888f12b727c52d, 0x006ac49dd9253edc7f632e57b958ccec],
+    [0xec0c9c0d6d25a9a4ec46a6bf18301398, 0x0131adffd8bd7254b1d8c3616bbe3386],
+    [0x77977ad7e25e49a3cf030e1cd8f9f5b6, 0x1c4a6f52c9fccf7a4138e413ef62a283],
+    [0xfee048ae2078aeb7d1f7958d2c2645f6, 0x03f2a6be51ec677f946551b3860ea479],
+    [0x8a1372d8543119564d7376396b8ddc62, 0x2da770aad2c2eb09391a0cb78ef3a964],
+    [0xc9d45fe866c359c738062afb75d64a03, 0x15278463665f74cddc1802febfab02ce],
+    [0x34e38ea966a08a6f8d580638ac54c773, 0x12fe278aa36544eac9731027090518d4],
+    [0xd8587604ca4f0d6e21b06ff30b6a23b6, 0x149b9c802182558a4c45d119d3f4cc7f],
+    [0x55e044fd60dbac9a0adc4959b691dfe4, 0x0812e7b4d847bc8517d19319772f3c98],
+    [0x555813c7e7503aea2a66973703a0c61b, 0x02ed8d8ddeafe3d9d8df7f28a0bfaa7f],
+    [0x55e5e4d9a03d6b6ba1304038662d4db8, 0x0ebd073ba0537b514deb6029f921029e],
+    [0x55b3e792c6afa08b44ee75b62eff9f59, 0x15c754d5b14b2c4205c6ba8d2ccd0282],
+    [0x1cc1235270f4cbc5c29e7cbc30e8732a, 0x169515c89ac5479db0ed8fa6fa311b39],
+    [0x08bdc29f6ff037696d9161f5cd9a4fef, 0x25479fbfb3a68f982388f26210011016],
+    [0xa7f8e6e972182196ce09486e94be6071, 0x14475c4bd520451f3c852cb0311a578c],
+    [0xd3a847502aec8d5f5c4e73363d097799, 0x045a691066cc66bec9baf2798833a1df],
+    [0x147a8ca037221b90013b8bcb37eba683, 0x26029c0c267c799fb833ac8a11e3a3f0],
+    [0x73ce12a6a94a3e45b8101d5b948d1641, 0x163facb34ff572fbf7c946969c1c2608],
+    [0x13e06a6275e58688af8ee00c4240ee28, 0x2c714e96e1913b351d969320cc69d5ec],
+    [0xddd6268f06debfe2d52b804eff1d5fa6, 0x1c1661e2a7ce74b75aba84665ecd2bf9],
+    [0x6635b34c2a0889fea8c3c068b7dc2c71, 0x06a69ae795ee9bfe5e5af3e6619a47d2],
+    [0x74dbdbadf54195c731416c85d731d46a, 0x113d58535d892115c5d28b4c19a36093],
+    [0xe534f1ff47f7917a2ca1a74063b46101, 0x2ab89102e2b8d5e638ff97d761da6042],
+    [0x349031893da2b4fd39804fd6a15ad1b3, 0x03c11ca79e41fdfe962730c45e699546],
+    [0xf7f67b4d4cfe846c6adf040faaf2669c, 0x27096c672621403888014ddbbbfc9da1],
+    [0x66db790ce486130caa9dc2b57ef5be0d, 0x2de32ad15497aef4d504d4deeb53b13c],
+    [0x28c738dddaec9f3d255754448eefd001, 0x0dc108f2b0a280d2fd5d341310722a2d],
+    [0xbcdc3eebc409be7c7d34ca50365d832f, 0x1869f3b763fe8164c96858a1bb9efad5],
+    [0xb616945e16a568d48c6d33767129682d, 0x022ed3a2d9ff31cbf82559fe6a911843],
+    [0x92fca1f27c19c26610c6aec077d026bc, 0x2155d6005210169e3944ed1365bd0e72],
+    [0xec0c0556c91af3db3ea5928c8caeae85, 0x0de1ba7a562a8f7acae93263f5f1b4bb],
+    [0x126f740bce8d637b6351dfa7da902563, 0x05dbb4406024beabcfce5bf46ec7da38],
+    [0x00e789e3fcd72dccc97e5427a368fd5e, 0x05d4149baac413bed4d8dc8ad778d32c],
+    [0xf0b6802fa941c7879212e22172c27b2e, 0x01cdf8b452d97c2b9be5046e7397e76f],
+    [0xc4d7c5015eced8c71b0a22279d46c07c, 0x1fc6a71867027f56af8085ff81adce33],
+    [0xa9e185b75306d9c3568e0fda96aaafc2, 0x1040bef4c642d0345d4d59a5a7a3a42b],
+    [0x2b21e95676431918a8081475ab8fad0d, 0x16b79c3a6bf316e0ff2c91b289334a4d],
+    [0xa34070e52b601fc13cbe1cdcd59f474e, 0x20dff1bc30f6db6b434b3a1387e3c8c6],
+    [0x062dd4171a726a8bdfb7fd8512ae060d, 0x0212ac2ab7a6eaaec254955030a970f8],
+    [0xb02dc03134f0044213c8119f6996ae09, 0x2f29377491474442869a109c9215637c],
+    [0x73be9f3866aa284c5837d9f32d814bfa, 0x0984ca6a5f9185d525ec93c33fea6032],
+    [0xe491361c8a6bd19ceb0ee9294b24f028, 0x0d080a6b6b3b60700d299bd6fa81220d],
+    [0x21acc85b6400264dce929ed7c85a4544, 0x0e65cd99e84b052f6789530638cb0ad8],
+    [0xe30e3d20380ff6a655975da12736920b, 0x2e208875bc7ac1224808f72c716cd05e],
+    [0xae2e3b894afd29f64a60d1aa8592bad5, 0x2989f3ae477c2fd376a0b0ff3d7dfac1],
+    [0x918106a463290a3e3a74c3cea7189459, 0x11361ce544e941379222d101e6fac0ce],
+    [0xd122a822b8fb366c34c8bd05a2061438, 0x1e8d014b86cb5a7da539e10c173f6a75],
+    [0xa0c2324167ef6c91212ee2c28ee98733, 0x173f65adec8deee27ba812ad29558e23],
+    [0x5da5df7ad45499d0991bd695310eddd9, 0x01c36daaf9f01f1bafee8bd0c779ac3e],
+    [0xf277d1a3f2fc89944a6f5741f381e562, 0x1353acb08c05adb4aa9ab1c485bb85ff],
+    [0x009d33deb4f93aeb20f1c87a3b064d34, 0x2e5abd2537207cad1860e71ea1188ee4],
+    [0x13deb4eb34913a13421726ba8f69455c, 0x191d5c5edaef42d3d02eedbb7ab85625],
+    [0x82a04c74c127de9dee7939dd2dcd089e, 0x11d7f8d1f269264282a263fea6d7599d],
+    [0x3445cb4cd6bc6f951bad085286cac971, 0x04218fde366829ed90f79ad5e6799797],
+    [0x027b73b489301c3227b71c730d76d6dd, 0x0070772f7cf52453048397ca5f47a202],
+    [0x67681a98cd051634c1dc04dbe3d2b861, 0x038a389baef5d9a7c865b065687a1d9b],
+    [0x0f39d00966a50beaf19860789015a6e5, 0x09a5eefab8b36a80cda446b2b4b59ccd],
+    [0x3d9ec99edfacb7489d16212c7584cd8c, 0x01b588848b8b47c8b969c145109b4b58],
+    [0x545e5abfa323d817fed91e30d42954a6, 0x0b846e4a390e560f6e1af6dfc3341419],
+    [0xeb1513bc394fc4f985cabfe85ce72fe3, 0x23a6679c7d9adb660d43a02ddb900040],
+    [0x4c3402fb7c85ecccf72f31d6fe089254, 0x2e0374a699197e343e5caa35f1351e9f],
+    [0x4b8cc0b1176bb9ec37d41913a7a27b48, 0x0752cd899e52dc4d7f7a08af4cde3ff6],
+    [0x0275142b664b802c99e2873dd7ae55a7, 0x068f8813127299dac349a2b6d57397a5],
+    [0x86872d04a295b5b8b374330f2da202b5, 0x2ba70a102355d549677574167434b3f9],
+    [0x09f9099b825dd289b8609e70a0b50828, 0x2c467af88748abf6a334d1df03b55213],
+    [0xe3a52c2d1a31957296d29e5763e8f497, 0x05c5f20bef1bd82701009a2b448ae881],
+    [0x0cd083a2c649d9f23ac8c9fe61b73871, 0x0dc6385fdc567be5842a381f6006e2c6],
+    [0x0378d5b84150d25ed255baa8114b369c, 0x142d3983f3dc7f7e19d49911b8670fa7],
+    [0xf36f86a7a99aa35c00fa18c1b2df67bf, 0x29a01efb2f6aa894fd7e6d98c96a0fa0],
+    [0x0ab9e8afc64555b7d2a1af04eb613a76, 0x0525ffee737d605138c4a5066644ec63],
+    [0x7e164f614910264ef177cf4238301dc8, 0x1e807dca81d79581f076677ca0e82276],
+    [0x4c0223e0f733a52fdba56082dbd8757c, 0x0385fb3f89c74dc993510816472474d3],
+    [0xcfabd7016fd6ef1af558f337bab0ea01, 0x037640dc1afc0143e1a6298e53cae59f],
+    [0x5fd56cf031da8050b7e4c0de896074b4, 0x1341999a1ed86919f12a6c5260829eee],
+    [0x73afd642efdcc5657b244f65bed8ece7, 0x069eb075866b0af356906d4bafb10ad7],
+    [0xd2726101d3afaa02ea1909a619033696, 0x171c0b81e62136e395b38e8e08b3e646],
+    [0xcbbdcb39bfe696f95575107502acced8, 0x2c81814c9453f51cb6eb55c311753e84],
+    [0xb04b39032adca92ce39b8a86a7c3a604, 0x29d843c0415d35d9e3b33fadcf274b2a],
+    [0xd8e15ea5947f2cdaa7669cf3fae7728b, 0x085d6a1070f3513d8436bccdabb78750],
+    [0xdbf1dd4e6706b02c9e2a5cda412fc394, 0x11820363ed541daa10a44ba665bf302c],
+    [0xdfd3150e05f1df5d105840b751a16317, 0x201935a58f5c57fc02b60d61a83785bd],
+    [0x46794eedd686cd8ecfed610e87c02e9a, 0x0a8c2820c56971aae27a952abd33a03d],
+    [0x650b65ff33e6be1fd50dbc163a281877, 0x180638ff301a64ca04abd6d0bd7500b6],
+    [0xf85ca8a937cfbec63e9b321a812dd36b, 0x095c716266f1de59044f97114a4158a3],
+    [0x83b618c5c1a687818d4087a497d73490, 0x17c31ea02fbc378320d86ffed6c7ca15],
+    [0x3c17a9764ccd660de4d50a77f192a91b, 0x05b86c4bb8ef318b6a7227e4192d149d],
+    [0xab15f4a6ae0d237cd6ce74ba986c7a7b, 0x265bc95df4a4c4876ff70d7ea2fde2c7],
+    [0x08701739c5f5b4b3d6c886d4715c7929, 0x24752b47bc6c6bc8d9bbe48f5fef2f69],
+    [0x78d624b98da96ee5e583b9324d974efe, 0x14814a1e0f492a4ea0d86e527a964821],
+    [0x997c8e041d3cfb3dc7523bca906f00bd, 0x10def931073b6479bd60577378f29381],
+    [0xfa0b880d28c69d031b4592c98610175f, 0x14f7ae770bf7e95f7f706c0d8ab4ed03],
+    [0x9decc9d428ebe4e775c5cc9dce1ce589, 0x1aef50a0cee751b59f926af40e8035d1],
+    [0x086c2a2d57d09602951214b57e73cf5a, 0x041935607172f68eba65ca60068dfe3b],
+    [0x0493fbefe83c819837a151d3bf452cb8, 0x26863e9dd24255d1573bd083959b856c],
+    [0xf5adf251ba62052c652738e63ff8b3af, 0x2036efb6f9830965eb3d7a068bd087c9],
+    [0x30be4f75a753f854b00fa4f1b4f4ee9b, 0x0c712a975b74dc9d766b639a029969ca],
+    [0x7afdff6456492ca3375731d387539699, 0x08014dab3cd1667e27afc99bfac1e680],
+    [0x97c4dd4d37b4e8f3b53009f79b34e6a4, 0x198d07192db4fac2a82a4a79839d6a2b],
+    [0x2709b29d53bb946dfd15784d1f63e572, 0x29eb1de42a3ad381b23b4131426897a3],
+];
+
+/// MDS matrix for width 5, row by row.
+const MDS_5: [[u128; 2]; 25] = [
+    [0x9f22e57ace3cd7f64fc6fdbcf38d7da1, 0x251e7fdf99591080080b0af133b9e436],
+    [0xfe26ea9ca238d6e348550486e91c7765, 0x25fb50b65acf4fb047cbd3b1c17d97c7],
+    [0x6deb5325f367a4556ea1e31ed5767833, 0x293d617d7da72102355f39ebf62f91b0],
+    [0x66599e575a9b7edf6145f14ba6d3c1c4, 0x104d0295ab00c85e960111ac25da4743],
+    [0x792b3813954fe9bf3ed5b90f2f69c977, 0x0aaa35e2c84baf117dea3e336cd96a39],
+    [0x052903dc6609ea6969f661b2eb74c839, 0x2a70b9f1d4bbccdbc03e17c1d1dcdb02],
+    [0xba9f92ad8ef4b979115b8e2e991ccd7a, 0x281154651c921e746315a9934f1b8a1b],
+    [0x8ccd8fdb9ee2b45fb86a894f7db36c37, 0x28c2be2f8264f95f0b53c732134efa33],
+    [0x9b626d8cb4dc18dcc4ec8fa75e530a13, 0x21888041e6febd546d427c890b1883bb],
+    [0xe810930e3ea4574a350d65e2cbff4941, 0x14ddb5fada0171db80195b9592d8cf2b],
+    [0x55b91bff652ad69aa4fa8478970d401d, 0x2f69a7198e1fbcc7dea43265306a37ed],
+    [0x67ba312b34140e716d6a3747594d3052, 0x001c1edd62645b73ad931ab80e37bbb2],
+    [0x39c40c603049466fa7f9a4b228bfc32b, 0x15b98ce93e47bc64ce2f2c96c69663c4],
+    [0xcba8458b28e44d9265051de33163cf9c, 0x12c7e2adfa524e5958f65be2fbac809f],
+    [0xaf67ce79816ef468531ec2de53bbd167, 0x2efc2b90d688134849018222e7b8922e],
+    [0x3c3ffa4550bd2514f1afd6c5f721f830, 0x0c3f050a6bf5af151981e55e3e1a29a1],
+    [0x8b2efe2ecd424a73eda7784320a1a36e, 0x0dec54e6dbf75205fa75ba7992bd34f0],
+    [0x98364a11f4d988fb7cc75cf32d8136fa, 0x1c482a25a729f5df20225815034b1960],
+    [0x0812ac2fc9a14a5fb8b607ae9fd8514a, 0x2625ce48a7b39a4252732624e4ab9436],
+    [0xed7ef8e300b9a8bb9fb9f28af710251f, 0x07f017a7ebd56dd086f7cd4fd710c509],
+    [0x7c5e55c20146259be2f78c2ccc2e3595, 0x2a20e3a4a0e57d92f97c9d6186c6c3ea],
+    [0x701673aed820d9c4403b01feb727a549, 0x1049f8210566b51faafb1e9a5d63c0ee],
+    [0xef357a69e3e86b5561b9299b82d69c8e, 0x02ecac687ef5b4b568002bd9d1b96b4b],
+    [0xbdcb6b58ba40441ed4ebcf11bbe1e37b, 0x2d3a1aea2e6d44466808f88c9ba903d3],
+    [0xb39c4a7a72dbb6d9c98d803bfed65e64, 0x14074bb14c982c81c9ad171e4f35fe49],
+];
+
+/// Round constants for width 6, 68 rounds of 6 elements each.
+const ROUND_CONSTANTS_6: [[u128; 2]; 408] = [
+    [0xd968653ef8390cde2e86b706ad40c651, 0x1448614598e00f98e7ae7dea45fbd83b],
+    [0xcdb65022e1b70af43b8a7b40c1dff7c3, 0x0ab7b291388e5c9e43c0dc1f591fb83e],
+    [0x84cde754a3cef5b15c4d5466612d6adf, 0x2b7cbb217896f52c9a8c088e654af21e],
+    [0x0da59ff3bbbb95fc2bc71c0c6e67a65c, 0x2bc6b0ddbe1d701b6570428bdc1ca1bf],
+    [0x0c38c11f7d20e9271efab9a905eefd3c, 0x123a55a31980384f3d20b2cecbc44ed6],
+    [0x88b01858bc8eb7e279e2883be9fb8c53, 0x037501cc8c9dc819309a769f4df098e5],
+    [0xb9a460b1eb951ab01c259eca3fd47d51, 0x1c2116e47e03a86bb11695b0a5f6dab6],
+    [0xfaed13e57bc0fae49dbdaebf54f56f93, 0x2c18213489032e85a9c8cb8e9a65839b],
+    [0x8f29e2227985fdf2ad8703c835b9e031, 0x2ee8fed3d4d2c71a0429eafd8e5db171],
+    [0xba9436d1cce94b9316d111c70a0c1714, 0x28c64d8f5ed7aac004c92029d9e9bf91],
+    [0x682c84f15abf571b34254a03478665e0, 0x18a01d9ffb747ae0de3e83c707f8b24f],
+    [0xe642a56cee71515d54e1ac71cde72bd3, 0x1c21d92bef197e73b234e4777b60db14],
+    [0x9d66a6677dbbedad8b6455fe62d807b1, 0x0ad404ccbcb1e195897cb60c80981ebb],
+    [0x5cb654cb7bd14c8b97af8b60cc1fb387, 0x0a9b6de833064f93b6adb99af6c00594],
+    [0x60efa58ddce2c3e6180d45bec3aa3a6f, 0x13129e3f930aed6d47690331ff09dc51],
+    [0x2f18bf7bfc9e280ccca18079839387f1, 0x0d7a614c8991508ab1ce4895813bb1c8],
+    [0x49bddf2f5af5febe8c31f2f4094ffea5, 0x0532f7ec36e30041b0486986875c913a],
+    [0xbbee808c9d64263c84d9d8ae155cb892, 0x06bbcb8e8e180201293e712f4950f1b0],
+    [0x586bf40f211ceb4f687ca5678a9dcb33, 0x0f558a4db1a3ac07f61e2e6bee947f73],
+    [0x0c643e419bcf0b577c24a0d0e7acfe98, 0x2be140a60b5b5f2f8edd78a818a969b2],
+    [0x12b8e867bace27cb0dea06e89b96f631, 0x1c49c4b9a9f09f7b9ad5f74ebacc7105],
+    [0xa77a81c06e2eac503847b00d597652db, 0x170c1a732721b12cde7f33e476a39a1a],
+    [0xc6bfc39d9241f9d4c302041615cf27f1, 0x19c27d0e52f65ca34f4e31a068e49331],
+    [0xb26b385fa40b6b04bed9960e2543ba08, 0x2f1bdc5254f9220c1a731fc5276964da],
+    [0xdc6d921e855cd91154b639d28d4a1cf0, 0x05b42d2fbccbf4d39d2be9339cabe9d0],
+    [0x5fd32ac52fe9d06f88188d71f61e0935, 0x1220040715a41ad59f4f410e0c05a42c],
+    [0x4925ef871dee916d9144ebb4ece1343c, 0x25f9526526155b83946609f7bb9507dd],
+    [0x14a8a5d0452419147978a95b34742444, 0x017bfe4284299ae7740b6d0e204951e3],
+    [0x88d2ceaf3578c4111cf8b359eef089cf, 0x2a5d4764021ca71d78a9674cb6708f15],
+    [0x7b8d070b2514d29b2a7b7d89cc0815da, 0x17f02dab745fbe3c081321fe5cef845e],
+    [0xbaa1764a7bd155708ee6f378c89ef013, 0x19da62626db7199b65f4adcf57fa4a3d],
+    [0xedf75f39bf18634cd266ec4038829e05, 0x0f88e295fa2ed81b426c91fa69366a73],
+    [0x280eff7d20cb09aa85f49f2766148017, 0x1fe31c5548546c7948fe4ee1bd7412e3],
+    [0xcc48b59f98d643638fdc0afadbfe126e, 0x10fdc1613bdbf67f38bdde561b2f91e4],
+    [0xbfddad1a03fdd6bca6ef7f0093127bef, 0x1f2618c2ebe9574508b9c52f0000e33e],
+    [0xda11f5953cf98e28269a0dba2a745dd3, 0x129fe7fc3efac6a8ab23dba6d886f394],
+    [0x3bafcfee72beade9fae60523102448e3, 0x15afd4cdf1e4f820c1631d4ab85ca4ba],
+    [0x51a7bb8b45528cb734956079ac99b012, 0x1f2c74ba5c367e370d728e71e15b2688],
+    [0x19f01fa89c9c36b26e09def6786dad3c, 0x1130e1872d76f2f9369cf59b95edf9ce],
+    [0xe242c61bc68656493327533a5c1b1dca, 0x13523d173f7e6badb73b63fc1c9bbdbe],
+    [0x53368f7254cb5667ddadbbad7a574cd4, 0x14da40d0af427a65f1841b5adc965386],
+    [0x82638e57fd631ff6315976e1a5dd8b86, 0x0091f96400e4297ea85bb186c17b304e],
+    [0x0267bc7b54a0deca5c450277a002cdcb, 0x303329bf9031c5515b9a34d49a64bb6a],
+    [0x509fc8336ecfe9db737916e283fa821b, 0x14ed47e55c1da1c2f05d3c1a1b2e6c18],
+    [0xdd2b03251c747deb04ee94c565e58d6b, 0x1161f10b357775d810ad53bcc4a20d5a],
+    [0xca914eb94cc68eafbb8a96a714eb8221, 0x17a8a50ae72ce707f22bc070eb992851],
+    [0x645a1c153791db6312b932dc250e4f62, 0x1a6c61d795dbaf62f99250b37ec5df88],
+    [0x4b05772bb2a0869da6722b15f447a133, 0x1f8bd2ab8aa840664c4eee198c4684dc],
+    [0xcfb8cbc06349d8fefcc62f10c8fb3e2f, 0x1ffcb852a4f0027a9799f131cd74b98c],
+    [0xd77cd53bc2db5dddb246b23021f79f18, 0x035e742ec52f19b36d489c720f467ffa],
+    [0x55673aa818d305fd42d175a05f2e3d86, 0x1dfaaee41bdf94d783aa29fc62b7ec7b],
+    [0x45bd333f2d1455f038a219b8c4796b3a, 0x2821378477a02e995005a56350885409],
+    [0x31ffdd4a7c19e763174f238d04897421, 0x1db4a4d0f238a570b1061c6eec81c02f],
+    [0x7d693b52426aff40a4bb72893b1784ca, 0x14bf7889457b20b7a1367b34a3a53821],
+    [0xa796d164f6049127109ff0d3a9c08465, 0x2ced52c2bf296f87e57410c3ec9a9483],
+    [0x9d4ce6584f49ae5bdfd46d6766eea334, 0x1ddeac5805a7f4ada4d0441ed108e314],
+    [0xe1c9f2161ba040673f911123f042ae70, 0x2e36b4e5e9c97b462304e8e2b5f9dc88],
+    [0x164b447c5ed64347caee63502c238d5e, 0x0c6840d1cb0666dc59e89b1865275d8a],
+    [0x262a4d1db55c589b2caeaac01238f58c, 0x137e2e3e89e71d461f4c9bc3e8f12183],
+    [0x82e55aa94751157b1d45794b081c8aad, 0x250932e7b0adcf2c84ed4bfb60a36b6b],
+    [0xd4b00d5ce2f35f972b4555391f16b42d, 0x170a7292f5634c06dd3bf09ab5c9c4ec],
+    [0xa215df4e7bd0149d10b2c50f2a4f3b81, 0x0d68cbbe77289e78d5cbf51d70f1b75b],
+    [0x6fa1dc680b49d9ce4ed24c9749973178, 0x0caf74563b90525f645a6d2036ecd130],
+    [0x166f9456ed46cdeb255e395b7d30d42a, 0x20a7d1c0a27fcce78ffe372f4c58306b],
+    [0x3385e96b9cfa0be4d35015158a468465, 0x0623f3226b5470b2789b8a53040e4444],
+    [0x42a56305347de4a5009ce46b8cdcb91f, 0x1632308688c25e790f57d68a53502412],
+    [0xbcfa361597c544ffcb5a567e9076f47f, 0x2de4793a6f99cd14e3f6642211f4d0b7],
+    [0x25a554fa99559ca2f09f3bb73d728c66, 0x1d4d06d19ea1b09cad79086d51bde117],
+    [0x908350abc4aafc18cd75e33dd130c144, 0x0480e7479a66a7cd9ea61c8b28974389],
+    [0x7d78681463e2e7d744bfb44335dac24d, 0x30430b03368ebcaa91246960490bcf91],
+    [0x2b1f1352241a5d12103283e089c0c742, 0x0b57b37320127d4c50f269124b0dbdcb],
+    [0x249b8dd614b23376b50178d2df6d2b8f, 0x2cf4890650d27240e195f60a4f698eda],
+    [0x751a2f239008ab5f9b7d3b6911c64184, 0x1e221c5526898bfd12de86851a0d9703],
+    [0xe4ec693ebddbb2952e54d339f2eebda5, 0x28e07485ad7d992ed1a58f329ca12adc],
+    [0x8381c84520c12854dd5ef3a079acd4e0, 0x2f44d64f84de16dc67bd5ead51efb1dc],
+    [0xce7167ab7ba1516f4021c62cf0d4fac2, 0x050a76bc32ebd1dfe2be330f304edc7a],
+    [0x8cb46361d97b28947d29421c270594a9, 0x2f58c45e5d659a67d781367241f6c35d],
+    [0xdb67534844325ba36fc7e5e160c66a74, 0x25e8da9ae0e42e840e04b230370e782b],
+    [0x4f38a368ba10c29544252be796040f7f, 0x2fec734da20fe32003ea04f127f84472],
+    [0xcb14f4e3a3bbb91dd6d1facafffd5eef, 0x288a6778f3a83988a8ed1727f15e93b4],
+    [0x9a25a8cd2715d245b9175390ac922c84, 0x20dcc6c75fd89259be7f406750b3db67],
+    [0xd31cf965d11178c7b04ac45b4dea5dd3, 0x17f42ba10942df25cb8a541782a18b6f],
+    [0x3e9cedabc664abee764aafd455986ba5, 0x028eeb85d115a904020e0c6148eec660],
+    [0x5d80c0299ecc918e9f8c9c3d38d59d40, 0x0b1d7cecf3a79b2ad3fa298f6cea7ae9],
+    [0x1db8e3f12ce1cf722d23c0e11ff4cf07, 0x0440339c9764cec79c16efdb834a2626],
+    [0xf4b07a5f7eb57c79b88e6b7abbbdad5c, 0x06ca647c29727c1962a002177da2d504],
+    [0xb8ef778b04d2ba5bfc3cab28619ba9e3, 0x2ea120a864f5c4093dd1a962e8f013c7],
+    [0x77c479ebfb5dc76adbb39cf8697633fd, 0x2bb737546c4aee7c0cc2ba87c1157e2a],
+    [0xa2c28c2f449a2bd51a25d15686803a93, 0x0e30da6490625d33e79cd50176f568f9],
+    [0xf71035a7c20430d4131d903ab7f57521, 0x0df7ca7278a13650b919d85497b2ebb0],
+    [0x2f784c0990b80fcaa6944097f870e2d5, 0x27cc589f5bf585794abace589fb8a74a],
+    [0x332d33042f78e60c43c7455421b325bf, 0x2255c36a38c8735de45cedf452afa842],
+    [0x98b7d86cca63d73a60caed485784d087, 0x133d9602bd3378d69f681c27b05bdffc],
+    [0x17a9948616c93ef4accd981b1dc3d78a, 0x0e1548e942ae9d3e26860699b93727c8],
+    [0x7482e4bde0a1602a8e2c8d6e84e8a6ae, 0x0f20f0e55d93689fe09ec312f6af4762],
+    [0x6232c9bc17b7292954c0a9f6bfa51bb9, 0x2e52328483cb5b7ff2eb4e45b12e51b2],
+    [0x3a7860beea95dd8a4abfc957660804f4, 0x02b2162d533e059a6eda2abb74712edb],
+    [0xad733974c6b2e13e5b3d432519516c74, 0x19e0927715d1cc6d389429947fb3737d],
+    [0xffcb46048872ac9f74ef7f27ee573705, 0x0d3a800457d77785636303b8b94f17dc],
+    [0xa619b73f441c4e504dc8fe9cfb559e32, 0x2c974d1952557a1aac5f7bae4996616d],
+    [0x2c1d434cdbe8f55514babc2dd5d97bef, 0x0766bfeeede2ccf3708e1b4ff30714c2],
+    [0xd4ca7c871b2a0a479c1e74e7f191e62c, 0x23dac8ea54082fc131e173ae55e4630c],
+    [0x870ed33186b8eae49ad3b47e340a8d7f, 0x17d5fb6c2cb37010e3e358ab2d575376],
+    [0x0246e43e783fa6903ec8007f178c0023, 0x175dcac76d8a8126139b583ae3885329],
+    [0xe5be7e85bf1fd2a611bf182cc2e71655, 0x0c4fd08fede5d221adb7abf549898c91],
+    [0x38ad59b128303fc5002f02a65bdfe729, 0x277934b909e72d3a3475bb1ec766ab7a],
+    [0x6004ccacf9cb952896e8cfdb0b078b68, 0x0e88349998dfe703f1b184524f9c394d],
+    [0xee673514b0070a8b45710cc78cbb9942, 0x1f1b2078b60b0fce07824e2a2bc8cae8],
+    [0x40557657700f5b8a846e812a0ed334d1, 0x2eb1559566c536ddbc316f6482d51fa3],
+    [0x4541d623c669dec2c3ba066bbeaf6773, 0x1c4dbdc335cf6764355208b4c9d243d3],
+    [0xdfca1e8b7eba5dc8574021fd1241e3b4, 0x2374a6b2da6f8cab8e5cfe8d805dd3a2],
+    [0x94ef71b3e49f90fb874f6161733072f4, 0x19dd342533ccc603a99738e3fb5a569b],
+    [0xc6d03d8385132f2fcce7f35f2705cccf, 0x217d66db6c7fb3effa508800587d2eb3],
+    [0xfca77c618d3bfc6c2a7fa89296c7e64f, 0x0815fb8591fe01038cd3a3b38b236f9e],
+    [0x0ff36f5974b2ff9a5f5093e9bf63a16f, 0x2bb943b40c2bd456a6c17853b1ca88eb],
+    [0x81e3b4cd14570c5709fec3d8d3fc82e9, 0x11a5153fce659513ee7cb9974ae6cba5],
+    [0xae74c7653cf90e6d5c9f144426836df4, 0x1b72bfd07635d8501b2eff8785a2495b],
+    [0x93f1d87512bea0ecea39cf6b1fee233d, 0x14902c0700eec897ae178ba8caf850d7],
+    [0xb745b78865856dc4c1a45e2fd84cb3f4, 0x09c138c6e0a616a49ff90d43a6b043f3],
+    [0x7458a715783e8317e024a61a35ec10bc, 0x05b58a3dce57b281a271d69895052d88],
+    [0x558e55fbe867f024457a96765474d037, 0x2be8d29525c0cfdd5e6b3125e3bde3bf],
+    [0x81bdfc1b5ad6c24369f9c0560523d9ad, 0x061d72f78f1ba9dc6b4d7f7784225d6a],
+    [0x738b4803b36145bb9516db501a0692e9, 0x0bf18aefcacffabdf4112eddadca6145],
+    [0x3a9801a93071a7d5ea30659255f03bc6, 0x2e73dd105fa8b2ec931d8cdf29ec679e],
+    [0x47de7296860b211cad1ecbfb5d3598ef, 0x0f8440ef667c9ae81337ba5d8c927a53],
+    [0x7d14d178a13800558ca7396395eb118f, 0x004d303b2dea627b2731be83f93ac34e],
+    [0x94699eef313a4d480508008cbc3d37c1, 0x234541ad72040a70da2996a35269230c],
+    [0x12ad4c23acb46b2e08c157104409e174, 0x0d123f1e72d26b92bdd8fd73d14286c3],
+    [0xee6b05a8be460a774f3a048e138545bb, 0x2fb360776f0de79d7098ee7aa4123c05],
+    [0x7dfdc41c156ea1e8b7f99b6956a55326, 0x03685c079434e167276c57d3cc79703b],
+    [0xb47aaada3681eb9034c6f75c3705c1c7, 0x260af0e0fffcc9772c1631b179344566],
+    [0x916e2bff9ff672545fc2f49563f62767, 0x2862b41374f89b695274b33b773f2554],
+    [0x2f18b38b400b0eff192ca59513eba8d5, 0x02a9912fe170310227189ea1e691d036],
+    [0x32c713d5058e1224e070348c281f4a6f, 0x08e513ade694a0d8ac1f3ebf1a96440d],
+    [0x3213c628264ff80f21ac7a6b673d09ab, 0x140a4a431e2ee79400ed7465978d8477],
+    [0x1202b4eba8b53dc5ee979ed143377927, 0x296af4d019cb5df7d959b29d549c3f07],
+    [0x85573bd2023e3e505765470bb812b349, 0x01832e284a7f4c81614882b6939fc0f1],
+    [0x4fe93434496f2d103861fd19d66d7260, 0x1a84d56a67bfdd3d965abdcd329aa78d],
+    [0x9d1f7631eca75c9fb0dda0cb9dbde143, 0x040cb82847773927d2aefdc07489037a],
+    [0xed2b019f2887cee7b1a78f89d3fabe2f, 0x010dcf084cc29cb7caecf26aa633bce4],
+    [0x3b0fe5817810d40c72ca98aabd9afa83, 0x07edc22a0911ea214425ef542b776db2],
+    [0x936da58ba48bd1d2d3acd48173aaabe7, 0x2eea4ab08aec775f2148479ea36fbb96],
+    [0xf47d1b5cfddfaa082738695518245f19, 0x1e40c0e8257fe4a61005cdcfad148cf7],
+    [0xf69d44cdf51dc1f03e21948b4980b876, 0x23a27809583bd1ea51f436de5443e108],
+    [0x39abfbd2051ee68adc754080d49250a9, 0x2e4652b044dbfe40e63b6b232fcd5f3f],
+    [0x0475eb475db3c2caf7f7d67f485775f2, 0x11e7abdb6ecbafc2e7d8cdefe9c7b9c5],
+    [0x47a13551b27bf6b899396f63ac5cf8e7, 0x199d52350cc30e8c73821f802096f0e5],
+    [0x859a7cbef30b3fddc949cd0978484101, 0x0f575d6ee67cbecd98345624e032a37c],
+    [0x184d07028e5fb784f3475ae7772ff057, 0x1c4b6f9a2ae2b418e6265acba9c96b06],
+    [0x626fb9bc225206383db20360abf0c925, 0x2dcb5cf8896de39f228e157c0c5593f4],
+    [0x0b531a107f863ca544575e3cf870f8e1, 0x1340abb9f4e113186bdc26cbdf4bcca5],
+    [0x6f59d2b4460cfb74c48a8cc73b1d1a5b, 0x2368e692b72787cb8870ea888e714e00],
+    [0x922a7632a94f96374b7dc852756f54b6, 0x1fab9add9baa4a4f56f23165775c6f2d],
+    [0x4c4c015e20d9765d220571c16ab8680f, 0x0c7f7b82300d3c6ce3f8957ba1e4add5],
+    [0x6947929f9f1f99ab578a4c3a922eff03, 0x15d63e86beacd93c6083688e5d9c8f3c],
+    [0x05d36b12c0607862929355ea74023d9e, 0x0be843ae5f9b07e52572178af7dae8ed],
+    [0x05967ce1d950c0b731058ec92a7a0c9a, 0x1332749c523694cb6935e0963a07e81b],
+    [0x892bb95ca51029ecf009bffa5b9b9682, 0x25439408810e074c0bdd4598b9815fee],
+    [0x9fd15db0b0f64996dff67feb55f9a742, 0x057e8d19dd999a918da29b0940b383ba],
+    [0x3534d0a6be98e9e357fa43f01e70a29d, 0x1e014e37e9b117cf3b4870d999f2b55d],
+    [0x597afe15c851b4b863f6e889084c6479, 0x1a4ed24e6e03aebcd6bdb100533dc966],
+    [0x9ed4111c9f053f27710452f83ce36a90, 0x253420007083f1aa863ad4760905c103],
+    [0xc45ec72155c575ddeecac0e32759ab06, 0x2276a1441971709affe6d2a99320001e],
+    [0xadf7ae8ae1281f753302fe7d31dfa7b0, 0x28957dd1218ea799fd3411eb19325853],
+    [0x6692f2914cf802267ddf01e37902a008, 0x2fd925726ab794c88bd75796aa3e7f1e],
+    [0xb47c0c43d82966c4636a28674705fd9a, 0x1cf8a5c9c76a84b147c8238d9253cd55],
+    [0x0b4b2e2e4a1db9c674b8c359a41e9108, 0x0373cbbc306e1bab9e7077368715e623],
+    [0x48bc48f531ce0c1eab5920bf23290a40, 0x060283d2fe7f23dff513d9110b3dc624],
+    [0xf8119bdfd5a3527dc8bbfadfaa40263c, 0x0dab465d6d910740f33ef6cc0eadc71b],
+    [0x86cd6421dc0ca5346f3446b62439c4c3, 0x0cba7bcbc8224b2a8e4aba17977230a6],
+    [0x1db96c38b4076ba8bd68bcb4dea6911d, 0x1e4365db0790c9c4f445b0653c466ff2],
+    [0xca13d60eab42ced68de98fc643800a8d, 0x1bb2dba2199a9ab3bc86ef5f9de7f6c5],
+    [0x913d9d62e80bfbacae812e082021f9ca, 0x0ad3c1870c6d6ef40eebad52123cd1a2],
+    [0x5300ddf614935630c0ce3a2627245308, 0x01b098c91e7b0cbb5c34588077c0ddf9],
+    [0x98b0c168493b6d72ae41b576e55b9c3f, 0x19fd5c0eac14fae7598bd4ceea3b1e29],
+    [0xc6434f3fe67b7e7b4ccfa6ab95bd7e18, 0x0d4749d79cc163f17110a404a46fe427],
+    [0xeb940ad8728c8a516d40ada440dbfdcf, 0x1ebbfe8114a41bb809e0b33399241232],
+    [0x3e3937f80eb9faeabbfba9baf4b4c1b8, 0x2704e5b6133d9764d6d3f17d49d83322],
+    [0x540d13d710c3f9a390b6913f14d035e3, 0x2165e1c8027305b1ae0e323571635e5d],
+    [0xe55d4eeba4aceb60e65081ad63aa8b8a, 0x2e3497e4d35fda596c06afa63bc3a0f2],
+    [0xdd1fe0546d738f4d79ab5c57aa841edf, 0x031da4345eecd6db6c0f7b07c7815d7a],
+    [0x36967089006acab1c9cd19eac4a20876, 0x089ece54e47aa5c908e43e5f08737c14],
+    [0x7f3821fbf8aa40677d0552ed9d10d847, 0x2f53c15e2aded33c47f55a070483e6cc],
+    [0xb0aa2857094801eaafd72befed077f93, 0x142aa34f4b2e8ad0df7a21b3e39c00c8],
+    [0x9dd2725eda4e6ace331982467c7ff2bf, 0x17aea4da4c7bcf0d7588b014eb8b4097],
+    [0x63f9a41786f02f1827742b20dc0d1727, 0x0e970c19d19748d8c465104d8f022003],
+    [0x32fc45a0a0b77f1aba3a9823476df9b9, 0x04bcad9e553795642f59baf714a6bdb4],
+    [0xfed671c1b1901fa484c87f810315d0ce, 0x242c0bfbcdaa76f715dbd4ba825c71fc],
+    [0x078311e1efe85af0a11b19114fa9e790, 0x25db1343c24104071023fb6ed34d9909],
+    [0x7ca147cb898a3942deb3677786036a80, 0x2ffe4d9c420a59e9cdc7c31ab2bf3518],
+    [0xd0d1ba63be696483e98f283bc7cd07a3, 0x125bb03af3e2cf18bbe6f5b590eb3bf8],
+    [0xfd8b719deba50037ac7b75948745c6bc, 0x0816be42745b7dbb4ceffe5b8e24ea60],
+    [0xcbe23e64420055471d2ee4c5dedb35d4, 0x111160f9acf6ec360d1b6a712313a0db],
+    [0xfb59ab9542342419283d8203435c9391, 0x1377978e1b1f6a8925fa8e7b7941bdf8],
+    [0x83d384621937cee57e421a513d0d3397, 0x0defc1d8882166ef3ccde53a4f236fba],
+    [0x46d7e94cb88a7ef350e67b5ba0f0debf, 0x2f8fa5c78c706e3a5d4a03f2a7a39530],
+    [0xeb00e6dcd84690c230dcb9e58da94827, 0x1a2a957ec0a723da61c2134bab0bf17b],
+    [0xfd01f0ea167a8dfc1ddf37e2805addef, 0x1cdf8710995f5e03412b4a7f699532f9],
+    [0x0c4fe5239b301699e3765eebecc18946, 0x26fd31471828c36ae36c27b748054b0c],
+    [0xe11a921d9fea3f7b926143b99d2fa0be, 0x0775d996cc2c4456f303a2c1f9007647],
+    [0xb97224ef7b2062672addd1bd18bb8900, 0x016fb9337708ca638cdfda91bd0daea6],
+    [0x1258255356f184af6f76f119054117d7, 0x2c392fbe7d3fde42fca4f9478bb43933],
+    [0xdb47c55eece77aa228aa3de1b486bcb1, 0x187a2a3bf79a69fa3e5089ef9f1fd56f],
+    [0x78c98630359532f3e506b275fd5d20ce, 0x0271a863a280a32641ffa33510b2edd2],
+    [0xfb69537ce897ec0c718958d96516f2ab, 0x1557459c9c74c94aa00e5af69a1e3112],
+    [0x083d539e58168c2a50c6dae30f109f21, 0x2a8e26ca8d647d9a6388516ea9dcff89],
+    [0xde2bcfb1c2a3fae97924b710f26832cd, 0x21cb752194cf43f3b51942eb0040eba9],
+    [0x5a471e6322188d02c137e7cb484377ec, 0x2c26daf996be247acd6dd4acad60d38b],
+    [0x26821acc0f5d058cf8c137bca2d26f1b, 0x0240176ee0e7982eebe92a68d3e3a38c],
+    [0x84cdaf6be1ad47ecf2a0d18f117318f2, 0x2636e0973c865c1bd974dd78daaa8d0a],
+    [0x81f6e06744cee89b7be5d9fde1744ac0, 0x19e84f4f25a799496041661dc5d975b6],
+    [0xe271e0b326c4fb26664e89e1454ca110, 0x0ebf89064a3af247ca1f36f6f3570188],
+    [0x4c9c531fecb8ac698f7fd73ce22e71ec, 0x25c7e97b475be00e8b559a38c452364f],
+    [0x4d8c55270a160afed4429ef5598ad74f, 0x0444c99e592353e5aecaa302add901c1],
+    [0xe144967f0ba53b03519aba764b5c994a, 0x138db8887830565f2693d0e0f02e4e79],
+    [0x4e1ae365dc75c3b52bb13cbbb2f21edd, 0x24d40f462114fe9ee02aafcf74b4fca2],
+    [0xcf3a8a732db148f4b2951b4c61d68e8c, 0x21e65d6d8ee43760bca40e730b5df4c4],
+    [0x9bdaacefca14d51300978f13d1ab6d1c, 0x248dd79669ec09dbf0350a15d6c75c6a],
+    [0x0ebda7e639edf69fe8d5aba7924d5362, 0x2b8238c1548f9cbe29fd35cf91e7b48f],
+    [0x7235eba94f6b8942a94cbddecf6f62f7, 0x2439fd239257f38181c7be3cf513f1bf],
+    [0xc74b6d262f5de7c2e33d2bb3e893dfec, 0x200958235281a61ba2c4be0aa3282a18],
+    [0x19fad8211fa9b2d02fcc233190c17f12, 0x0e1eca5df88ee5f60cfa7e1fe5befbb7],
+    [0xa169c12732d001b7158ee4b1b842ca24, 0x26b53427f9b3ea2c769d9c660fc60881],
+    [0x8fa2812522b9d70a67402cff8db2b1b4, 0x20f3b3f4acafe9f8af3e06661b3a8f77],
+    [0x9f97506def4ec286928ba6c27d463b17, 0x211e5d2b39d62520a7a627ece8cacbac],
+    [0xcd085313ce572f6242d13832e536b4b4, 0x0bb743ee34802129c556731aed9d302d],
+    [0x5d8a44094f66b6532977e22140eba5cb, 0x23cb2661b488ee71e4c753ff23ae4bd2],
+    [0x6a5205b9c93ef31d35323a47807b8bc9, 0x03a35aa3123911cdb4535baed3359f5f],
+    [0x217e137f4ade74a62d7917322714b697, 0x27803848a0aed96a93fa943b6635e450],
+    [0xbe5e47c76d61538231bd8162996d6f67, 0x0cb37839c2c9a7ff79884cbec75f41e9],
+    [0xdaa1ecdc4c3cb218dbceef77c00d2f93, 0x1f0026d0bf1f8e1dd5423cc2fec1fb5c],
+    [0x6da3b100f5b5fb928eef42f9708273c9, 0x02a7d7bb970b8a6ed2ee66fabbba956b],
+    [0x5ad1ab2e90aa494351e4ee40bbc491fa, 0x0cfd7f4215e434c8da17ec3258b0bc60],
+    [0x1659be69682230c5ed9ac339700a7cde, 0x180b11b720622a156849dc6f7f6e7f57],
+    [0x2c871349ce7eee1aabfe578a94c65011, 0x04e96a965bce3d3a0a24a4a457c95158],
+    [0x353a23fe1d30a5a46a1522ed160df3ad, 0x15931f782b45f7fb656f2cdbd1f7705c],
+    [0xa5444c1c5f703ad88a0d7b97b9dd38b1, 0x2e296e57c97a5309acd26febf55ac963],
+    [0x1bde5a5bce8f0419952f97ffd13eaca8, 0x26157bceb78e846bbb262f9a1e06d427],
+    [0xb61b13248eff3ca36e34fb9d1c79ee43, 0x2194eb89847d6b0f1897f675f19c0c56],
+    [0x57e15501447008dab6b3d27c8ffa274f, 0x2350bf35477656899151ad7dde96ea78],
+    [0x5405435340819e003f0469d110b7752b, 0x1a486f0ae591cacdaf09c58a49c4d179],
+    [0x3571402b7df309d59bc3105d42a8c301, 0x1b56dcf76fb23cc4a834d455a4065e13],
+    [0xb4bfb65ceab7b685233ab59ce3bb6925, 0x1a749d7964af0b7202913ef204c653f2],
+    [0xbf97f8cc787e37cddd1f321be3beadbb, 0x18ae590073f969696af762ffa4e8f0eb],
+    [0x44b1c5a9da59294ade9cbb317103f249, 0x21c47b275d82dde6460d5e769a994211],
+    [0x5c38d5fd6cc32b272102b082cd1518fb, 0x0473ddbd52e737e527364e8eb6320797],
+    [0x5ac4e6b00515f902d594574302b26e02, 0x0b12fac95b6d3a881d892657c8424e64],
+    [0x2c5f07ff425d837aa7cdcef63e3e2103, 0x08ae7616a260cf6657f8f73ac284588d],
+    [0xa83c69757badd58a888ef819e926ce28, 0x039daf6876280b80e873bf2a32fd2834],
+    [0xf2d8efeff06ef766f9c783ca869d130d, 0x25e7b1d7470a3c75f13f0b56546c8e09],
+    [0x11b5b15aeb9c75262da3f1ea2c2a9e7a, 0x1e8fd3634c3ff764184d03435f98584b],
+    [0xff11789dc0cafdd77a35484e0993f9a4, 0x241dcc51ac37808a415dd1e3c281f05a],
+    [0x9bdafe1c4fa0781a4b8b4078ce9d7547, 0x1ffc3153c56ef9755932cea2be057374],
+    [0xc0fa00b66031adec29bd9543fd3a17ee, 0x17630d62d9a3e510c88a4d43c360f92b],
+    [0xabdb236ec16a82a4301a0ab59ea4a6e9, 0x2980400edd1d74e3d69db5458d2ccd5f],
+    [0xb16194c036dcd648fa69439bfcd00cd4, 0x3034fb24366123ec6dcafcad35726dbf],
+    [0x392995ce46041e04680dca8ad7232df0, 0x1aa7e8f4189ca9dff3db2ab7648be0a2],
+    [0x646eae14153d803b39dc5d11f5d7800b, 0x1fa195f834a69e62372f60eb497da167],
+    [0x3c5477e8453f5b93a0d7bafd333036d3, 0x0f23f1c74d5fbf6195ad5a6aee5e5699],
+    [0xb3caaf0a20a34fb9d9370505a1515729, 0x016556fac9348a735ab50aa089c97151],
+    [0xacb0dbbca1879b8a6f6e5df445e5f70b, 0x23d92b793648110fc5aeef0633f0c77c],
+    [0x686ab40fd79a304b05d611b823f23b73, 0x2e4c10ec5e65e2f239bbc43c13031df2],
+    [0xfc5a3dd9b78ec2b79329fe5e0a8d2953, 0x12418bbfd77b63ad5e16864ad9c32ffb],
+    [0x06a78f72b6bebb1293e6c58e5185696d, 0x1e4a8aace15abc1d5b76a9e848431d2c],
+    [0xdf17a3ee1d1331488092d96176deb755, 0x0f3e96107decdbd6872c20ea09acf92c],
+    [0xeea32498ddba6ce897a8f9f0c2357067, 0x012c3780207f395cc21deb0abd951681],
+    [0xe5659d03ea10c61de2477ff0ac221421, 0x13eab1b4e672ba1b1c1bb901769301f1],
+    [0x1a27a4e8b24e31b48b9c4635aa30323e, 0x20dc664abb20b7456c06629ce37a1ecb],
+    [0x57e9471f24f5b391175c3decb01e000f, 0x2c6b1e2cfea78e2c36785e76a8cfb1b0],
+    [0x70ab4c2c70400481b2ac903740e0c5ab, 0x188c926255f5b7af3da96355729c2a86],
+    [0xab89f2662976e3e30e0870bb30eb54f2, 0x2f9913208e09e3d6e9e6fba6384fd076],
+    [0xfb60b545a1f3e9ce49a6ae912b024086, 0x2b33803d90889706e714f720b5628d26],
+    [0x097086c97e738c133c9f57077e748b09, 0x26ccabc10eb04327cb5cc3dde2abb36f],
+    [0x2b0c3dc20d79e82a2531b76c22b4d5df, 0x1b16ae0d7c54408cb75fd931f246751f],
+    [0x9518b2511344742d1f5438fe3d415ae4, 0x11d0bb461bd8af2844f49f0f840c94ef],
+    [0x33ec420d673e504ad647259df655571e, 0x233031847b476bead0118d3db338e891],
+    [0xad29c164387b06aeb6ed1841ed8c4dca, 0x1f84e97895bee438eb3c92dc9b1846c9],
+    [0x4536010c3cf9452d2273193327f801d6, 0x277f7f9b542f0c2bb5f45bed054f0962],
+    [0x7d27e80fc23077161535c44682ee57a9, 0x1efc9c9069e5068baac13d2e6645641b],
+    [0xa99e64c3f25fe104faf988b20fe5ff44, 0x0d6ec477761e2efbac4f14b3bf3d5257],
+    [0xf203bef21aebf00251881439ccf93013, 0x0e0e7d7c5501999b7d16173b59b7cae1],
+    [0x77de53ca6e44dcdc6ea3d36fea326ea6, 0x217bef2f4f12c6dcc91c2058a23391cb],
+    [0xd29b35aa8a389c71e8cf1226acef68ba, 0x05780c88adf01531b50f817e3fe44447],
+    [0x445e47d7ea5939ebfb89a3ee3d67b4bd, 0x18733887a6b2b3b4c90d8e4990196e23],
+    [0x85ba597d37b0905720c4f15db231b07a, 0x20bace63acfcae0b1c9f2bee24b8e9da],
+    [0x6bf550876abcaf3ac92b42c808b00d8f, 0x166ea595375a6786ac527ee9eced73ed],
+    [0xae2f853ec2e7a80db00c488cf917c74e, 0x304262a9eff4040acf43e322d6f52676],
+    [0x05b6e8c9a055114ad7709e6f57245b6b, 0x226bac7050166e5f6db78cd0b12d36f3],
+    [0xc5c3eb4e1df1d638582717fbdf2388cc, 0x26b2f539c573829f6aca91baa954505b],
+    [0xee96297f8ad200f2f1cff252e769b551, 0x06a0fbf4cd52e93ba5e4c6c4af65db02],
+    [0x24e58b6ecac321b9fb29e6aa3368ff23, 0x2cb9c24112d35341aceac88360fb5289],
+    [0x9129eb87fcc0b13aac8fe8afd9301521, 0x20e88a4d607526dd07fe08a3552a4466],
+    [0x01dd8faf51de325fbaedb9d536ad94fc, 0x1544649a2bd73e3ba72f396df91dd654],
+    [0x3f8eb354bfd80ed9eaf3ecbaf6960105, 0x1980077457995712c44da7e17713258e],
+    [0x914b82ffaa5d2091ec64dac423765ef7, 0x25d1d22ff13e7705d3c085f97fc4e4f6],
+    [0x067445cd54abcaf6b8c0399fe0d24cfc, 0x2fec990ef556efe1035a464ff5581e74],
+    [0xc579379c2dcc1330416c4ae49bc4ec61, 0x1bd9563506d9544ef3e4830e13545012],
+    [0x7b225a80d480e7dd7edec01f14f23010, 0x00affcd17ba6003c56dfa85571fc2973],
+    [0xc7b23cf767a477b2b2e0762bc0dbc18b, 0x23670dbaef966881f07f919a2d883128],
+    [0xc66fd7ae2dd9f02f860f6d96947f0edd, 0x1f93a532917394c7e22fd17abeea6389],
+    [0x5512be07b6a50934734e2c5beb40be37, 0x2de42e9f537b7d61b0213771c0e74f55],
+    [0x67f78b37782c885b456bb96d55e88404, 0x25c557f45b99781cd37d3bb22931662a],
+    [0x647731bd16f8e254fa74ea9f2be7662c, 0x2074c8b709705c9888538a7f8a3c4aff],
+    [0xb74ef2065740db7cfc3a0b60fd573acb, 0x2738355956298138949e442171d6a4e4],
+    [0xb7d0c41cc519871fdb71a9ea6a0caa56, 0x13d36ad0a4ebeb81969778649659c65c],
+    [0x06b462a1691bc2e343b79b8085e376b0, 0x08a2c18ba41381348c1acfbf96177168],
+    [0x41f59c079822b1088457882fee7b612c, 0x059092fc395aed285807bbf557ad9a10],
+    [0x906f15e8531dc70567f6b28371cc24e3, 0x161924151b5a5ad2d8cac119522a991a],
+    [0x9a298b9a4d72f3bb854b309e4ba87a1b, 0x1c68ca8f7aa17659075405ef6341b8e6],
+    [0x59a15aa37e7bdddf3aab05eec5ce5592, 0x27f5d03bca1c8207f7239a4b2cf73ae5],
+    [0xb46861557622f2c2564a7e639833c169, 0x0ecbff4846962a975d347ea9a8fc465f],
+    [0xf2ec8daa59855f5ad64bc0521f3ac567, 0x277c4de2363d8b5b456cfc5a7ff8e46f],
+    [0xfec99f832b63226f95d175c8d2fc08b2, 0x1b11862c52acd351b7a464793f4fbb57],
+    [0xbd86c92e3dfb3c73e1527201aa51234e, 0x06a719c584c74ffbdd7218eb565cb4c8],
+    [0xd533584a195c1d77a313abe1c7d126bd, 0x230e4adeecb799877f7ce9a58c836b99],
+    [0x88628173b8ea51e8cca85830ca7de522, 0x10b109b864809c4767a133cce6cbad6c],
+    [0x538d3b4faaeb5a8b24bf6200d14cc591, 0x0e21117970dcfbd4b1526b253636f377],
+    [0xf55607ebdb35c7071db46bb3e7ba4778, 0x2667349978401362f6b17939eeb0e64f],
+    [0x0d86c72c039babf6d3ff02ee246be02e, 0x05000fa5fda505e029a13bfe304c267b],
+    [0x089087b2436a9bfce8174cc9be8c2e20, 0x264d9e094aed5f41a60242220a34a284],
+    [0xc92f24bfe114f3c5e724891746315c47, 0x08076f9c4743de6130ff622cf401edd2],
+    [0xd7cede94b95bbf5c2151d6d88e641b64, 0x132370abddbb0b1dd57f2a520c25336b],
+    [0xb615c8c4c17f28d8d24958edf602ddcb, 0x08ff1116b7a227bfdfd4465a67890882],
+    [0xc6cd98562332e648d8a3e0ab94597520, 0x2bcb0b0db8b9e3e02b7e9c1c9460fdd9],
+    [0x76f7abd597f903b0bf230d20d21a943a, 0x12ea68ce6881becad7f8a6b117b03ab9],
+    [0x8d5f88e274e0d8fea5ea6b7406fdda7f, 0x27439c98a76688067a097b19b6fdd7d7],
+    [0x8419e393493937f05f00499d02a99e36, 0x02f40d0ad05f5652e31ef9440ad71ebc],
+    [0x0d13acc74b9c5b14b63059b8cc7abff5, 0x2fbf04284327ee4f680f06bd390e309d],
+    [0x6978c7027edbf247f6b6723c21df86e7, 0x1be686d53e2a8ad57a828b0651425cfc],
+    [0xe9f7ad8b17bfefc0e348c47ca78bb57f, 0x2683b425e85a508f96852f14b4220fcf],
+    [0xbe61a1aa3e1693e0eddfc0de0a9dd951, 0x16dace9b2e8012e31db1c7ebe672d86b],
+    [0x8b4539dbb75aa139f30430fe545bcedb, 0x27a321f8c7d3c9022e962f7fef2e3c84],
+    [0xd8078b788d71007b95e7a774ed86a651, 0x06ccd7210dee1d6b0e22b79e12d19082],
+    [0xb6cbb1fd76a3a12166f4d0be978e4026, 0x0a41dd42221653752bef350f6d74a917],
+    [0xd1782e27cbd0d845978deec91298f165, 0x220a02881e4d47ac94d950cdf8386274],
+    [0x9cd92639339c6b017298a4ad3408b4b9, 0x0e2155a545fe5f3cbb6397606589eac1],
+    [0xb9d088bac530782d891ec1af4b847073, 0x0f0f19c6291e51546a267c60cc774e5f],
+    [0x0c53c0d33e7aeef1b46795aed5943c9d, 0x0e925bcd1c6ddb4a3a1c67ec8deefbd4],
+    [0x3ef0a5bd3d259a36e45ef05b9eb5beeb, 0x2ad000b1748abb812cd6e5411286b9ff],
+    [0x3fa616465c27aaf97e8cd3dcff648652, 0x0a65aa232d32ed6e8de63d1cdffebc2f],
+    [0x261dcb167a3a06923893d7cb2c93d6a1, 0x0263d8470ab4b1c61d74d8e896242f4f],
+    [0xd1926540c7a8612cec1c58cb60c2b3a5, 0x2901d946addc94b040fd58004d9a5f8c],
+    [0x717a0696da41cee64937d0cd6321e693, 0x1889cfa8209f4952df9022db9dc583b5],
+    [0x1502e5f03bff87561b85a116b1f39aca, 0x236064d71cb6c64c84747ac25fcf8d88],
+    [0x3661570033576290c0c1f6cd8ced27ae, 0x2ff7a174ffcec29862e04f5dbdc73ebf],
+    [0xa2696a71084c93e322715450dd4d6f5b, 0x19e724a1d742cab103455f0040edf745],
+    [0x5835aa283500a8129904a94c87f161bf, 0x03eed3892b6f0e6c5da1059c5f379398],
+    [0x9e8d05092aee4cbc80c687248463ba30, 0x08e2b8273bfa30c1ac850306d91e468a],
+    [0x2afb87d72af98f52f416f2ec58b28cec, 0x07adcca76d8337728839a1b6ac3b3ed4],
+    [0x4ce11bb4237d548c171169d32fa19b40, 0x171ef37896bae2b1020a0a5839bd5178],
+    [0x2d32fbeff1729de5056a26abbc3a35fa, 0x20ffdfcb86f4d005064edbc296918c33],
+    [0xf38dbb398fcfed4b994383a0ca8e4647, 0x08ecd7a6f1735eed86baa094e608f488],
+    [0x54288600e6928bc182ac4d5e4c9f0ccb, 0x1c3f5d86e5921fde9890189f1d8c6187],
+    [0xe3c0975e4014ed8ebcad4a25d51eabf3, 0x29c61184ed9d460f337558a1af639aa7],
+    [0x5e9b1a64902a734f402b8cefb8ab3b56, 0x0defd45b28958728228adbb2dbdaef6a],
+    [0xffa9492df76deed4ea60e0133b0811a8, 0x0a74ea22d8a09336060610179ac1d82f],
+    [0x17dc262db16c8523deee4e0ccde9a680, 0x03a37bf12daf1400d297ac4ac13ba24c],
+    [0x69966ada2f9136b54f830eacb0a65368, 0x11fe1790d5abbf5935ff22318e4f7ffe],
+    [0xbf1fe0564c8858ee3bde0aba3de5f68f, 0x018165842f406375f2346686915afb14],
+    [0x67300fb7d361b50fd5c0e8b6995b05f9, 0x261db25e7cff5a9fb72f276b1f9260b6],
+    [0x6972320cc43ec807048826b6704ba7c4, 0x2a3ac3314b2b66e796fbe36df778c5e4],
+    [0x69d1bfc8937d03d074061c30fd8cd76b, 0x23caa4b80ecfa99e9d3fea2bbc1dbbf3],
+    [0x66d8e4e124e581e8c6261929b1dfe107, 0x27db260085e224898df145f23f635f20],
+    [0xc0babbf369e79ab138b8167b5618ec7f, 0x274f6c5fd34a784d6b915ef05d424ee6],
+    [0xcf5840aada0701d57e23dfc796da6da1, 0x2c3a29e13a84d26a0911c9289daf1aa4],
+    [0xda7aaf317a553b4282aa78eaa2282e6d, 0x1ea210f2001a334d3e801f4e53270d42],
+    [0x0903b18a3e509c36351ccc3b024946e3, 0x254dbeb52884b699c1ba7fa0d6e80d61],
+    [0xf47bcdeda4a2ca2c713d8505ea31fd5d, 0x059e781d65896ebe0e4ba26dc2f29907],
+    [0xef303ec78c972dd17cdbb3e915cc4ffb, 0x0b5b1cec63d42d5e615dc269b885a24c],
+    [0x85d6ffacb7d9fbd66d2a8f1d64ed0c92, 0x2a7c015e9c3b2c57ca8b7d26d39a1bcc],
+    [0xd5abee24161dc567a7c851eae1e43b51, 0x29b736b911d71a79cf63d8a6f786f11b],
+    [0x6f1701e573912df5cc1e265d596c4141, 0x285745a90a7fe3d09af5a808704bc69c],
+    [0xc2b8304ae810bd30da554e3080826715, 0x2d901b8195c3c96c8c36eb99fec0134e],
+    [0x253980f0450dbdf54d7a7782ba058392, 0x1905d3518355eaba7859b591ed7b8c9c],
+    [0xdf7efea80bfa8ec4095272bfdb7b4c9f, 0x23e813026fc0b80064d19b5c5428942f],
+    [0x00c3fbff3e3300e7e5568071de9efb81, 0x23c0a19a252c87e6b1c1c21b1a798002],
+    [0xed1fec6dc0631b067ca60fab125b9e2a, 0x11c4ae607bae492413bf62cdaa2c2868],
+    [0x2405fae1b1afc9fb01707cf81da0e3b9, 0x2cd055ebb7ee4686365dea450f046ff6],
+    [0x5b58a3159bbb47337dbf791b215b1452, 0x053c9fef2e021fa9a20fada22fdea150],
+    [0x863b4c631c21938c9a5395bf97872a9f, 0x0a35bd74e87cbabaabe89ad1319d2c9e],
+    [0x0fbe83b25901be36f5ddc4666fc383fe, 0x1c115056539ce20cd5a04d1a5c43e2b0],
+    [0x542f2e264c8c3e284cdc473505c51a90, 0x242954047e5772fd3bded590ec8beb4c],
+    [0x1cbdbdb200a6e7c2b67edf7cb0174214, 0x0e2abd315b47c0dc93849c0cdf267e81],
+    [0x668e1dfeae621dd552d2de887da2ea75, 0x282b37020c0890d751c3fd76950d8068],
+    [0x2e5a5be5c778e75e07942c234b643bd9, 0x28933852266b52d9ea6b5bb923d9d94f],
+    [0x4fbe79a056ce72bace39c048c00da3cf, 0x099ab6765505ba1198ef140e77b7954d],
+    [0x5c6186b55762c7a7b2ae2d1dd85c57cb, 0x2af211d8e0ac2d8fda7f849b8f229a22],
+    [0x98a96322fedd99b6aaae1ca104f3facf, 0x0cd070f234014a2809ab90c71c1da61e],
+    [0x3587d6bd15833d448b116264b930256a, 0x26793e2abc8d3c30c60626fbaa158f26],
+    [0x6699152113ff61b5056b6d8bde60c19d, 0x225be36ed0ee85e1f845ada84e5748a5],
+    [0xc595383da1fde8f09e4b5ee3ea5a065e, 0x02174f49edb02d5154d2beca2dc92b9c],
+    [0xdcdbd09bd0308816fb41734a869872c3, 0x0f64891c2c8b020e46c3594cb758f0bd],
+    [0xd1c156c239e10e9f56140e1854576067, 0x192a84ca2f99d36991e2d2b1deff3989],
+    [0x0c6243b04c9a1b8b3320bba04a4d4787, 0x29dfcd7b63f05abf2753a8c341d6b7a6],
+    [0x52ba3e4bd5e657ade91fc038819dba5b, 0x1ee27ad6b9b5a867733afc61a2b3e76a],
+    [0x5a7e3731ae973fefeb623a09217e64c7, 0x0ab4773f150c3f8ad3bc9538f43cec39],
+    [0x42ab67f9b6d3a0afd103deeff6d80f41, 0x13c352a02f595186202cb0b99fa58c55],
+    [0x2c05b7dac56a41c4dd4fe87c8246ce14, 0x2a97cf2c10c4bfbfd299f67c52a169f9],
+    [0xed089a28482f7fd16ab06a13285fe702, 0x00becbb47042bd7f8c9f6bb422162d1a],
+    [0xc5bd9f7a4450fcf53290a92cc2ca2176, 0x008e44da21d738691b881757ef37ed29],
+    [0x3843fe7fbe4b83c17883f86527882a18, 0x2b205a8b6d4b7063d931f3bb5d346405],
+    [0x338fcd63b2bf84c19280d427298863fc, 0x2d9e32a7c90556fe108d255ac01e75df],
+    [0xabd6349a02266ac1dbba520738ceca97, 0x29a322a84c25bd2ddf6e2e4200228d95],
+    [0x14ced1df0d02152792089d046d828c43, 0x0678c9bfc6f2df012f4fe55e33bb68ac],
+    [0x1760394b7f2304286a3ae4009124db23, 0x0faff3a5e7425794fe20a7e0eb615b8b],
+    [0x53e404103aee213f5a41d1d02541c0d3, 0x1f8f5b611af9feb9cea86c0840581205],
+    [0xc1588b6a29de4284965a6dc6c930ea07, 0x160875d8479602f96f40acc2d042ee52],
+    [0x67c77328fcfda458cbf5fe3045f46d9e, 0x16d87a5183a316a1d70afc951efe2cd6],
+];
+
+/// MDS matrix for width 6, row by row.
+const MDS_6: [[u128; 2]; 36] = [
+    [0x6d53f44d273d956a0c87b917692a4d18, 0x124666f80561ed5916f2f070b1bd248c],
+    [0xe3d7bfeef47421609491011db0bd0b02, 0x11924f02fd19b09255aaa1cf46ea0518],
+    [0xf9b717369498d3ffce446601ed9df845, 0x247fa7f022304a1994ff505456c2201e],
+    [0x7bc94eb76c02d79d986be3dcdf051c3f, 0x03fd7b19ef2c861f22f77ff810f54e27],
+    [0x5a81eeeea69352a68b67c8563c0c2a06, 0x18bd41239c3e71579a677443ecffbd55],
+    [0x5730ca7f40493277078cd75da8b4ebdc, 0x2d78c3a5d28de9ff35bf0a257635196e],
+    [0x34895aa46bb27c0911f3780d5015540a, 0x0a514a5c227f4cec95dfa029e8dd124c],
+    [0x09589eac5be80915775723d2cb1da06d, 0x192e16d17d956b257b85a652eefdf2ee],
+    [0xb1aa9db0c0926bdc8917e5605ca3ac10, 0x298ce0c1e3113bb935c7058e7772b533],
+    [0x8ebd612239ac9d698b17fe4be05b7fc8, 0x094cb4e83621afd271e41bc71727f015],
+    [0x33918cb8841d5a28173bd5cf7d328791, 0x03d880395be93c27d649af5fd142e76b],
+    [0xd7e2b3037e38fef61b42cbccffceca81, 0x28eeae6b5866ad68e443bbaf91680db7],
+    [0x01a6e60fdd5b5c23c63cd65ccce4fe0a, 0x278bb49a7b4e44aea46eb0f882cb6928],
+    [0x145345887e8bdcff109035a1d9b674d7, 0x063edec1bed831f506af8db648d6fdea],
+    [0xb3232500e855192cbe8c940e068c475f, 0x1baef1cb5509b526a42061fb53657f99],
+    [0x68b165eaea3daf4eb67ee59c0df2e5d4, 0x1324564ac7bdf9e22164e9858d7fa8e3],
+    [0x064a4a8004ceeda210a55240562ddc13, 0x005761b8c6aecb1a8ca4ea4dfc2c8376],
+    [0x1fdc28002ed9963d2a99f186178a148d, 0x10c9e283159d58cb4cb2e35fde83a3ba],
+    [0xbdadcec5ca34901d253d026a2419f6a2, 0x0c399e9f67aa40707a203feefb0b958b],
+    [0xc353b7f6e762c7107184cd7b423449f6, 0x083f0df3f1a0351d0330ec3ff602ca8c],
+    [0x1984307287a58b9b5f9f5d58212cb263, 0x1a6764d5943fc4a720b4c0a19fdb8c71],
+    [0x2c96382d76c6a7ff48f1537beaed683a, 0x011a63a26feabf87fa66bde66cc25a92],
+    [0x8a41c0b251099ad27f9434307e3e64d4, 0x08ca7b64657c3548f32bef5b63ad2428],
+    [0x15ec0675f1cd648ddcb04303507a4489, 0x01998270471e9361955446b0cdb8bea9],
+    [0x31ea35ec0a77c1fbd9e01ca1523e4432, 0x1d6b3d5f6ea369c26f825d2362933eaa],
+    [0x64be687e7a69692da27da215a6f06d40, 0x119ef188bb3dd0d32306976c19941e86],
+    [0x6e6292df5609d71f2c64b2d9a79f809e, 0x2d9e0ab5c06893dfdfd03481381ba86b],
+    [0x5784319ef08cda2e31419ee0a529e658, 0x25f16631bf77060f7ea34087c025bf13],
+    [0x86d18bc594c79a497ecb9894edb554f1, 0x144c7a11da5a7c5dabae3f33fbd03cad],
+    [0xb8f74de508752fa8d7c0db2af13de8ee, 0x0f971162627723f3feadacb28b0c104c],
+    [0x83ab304aa52598b1a69306ec981a688d, 0x24be510095436206dd0abd0b0cbb95c8],
+    [0xc299a164c1c1a603eaed944870d0b9b9, 0x211610e2ad4a377426fadf7068b0c1a6],
+    [0x779d3af08157786ac077505e50ec79fc, 0x15a67d981041b1f6f09f3f9ebefd864e],
+    [0x6775b0287468b3136877701dbe7c9598, 0x049327fa79d28c12a2c82406947f77f0],
+    [0xd170e8c998f1aa85dea0c42d79f951aa, 0x230940dcc5232658ff9c29697a3fd416],
+    [0xcc4bab4c26fb888ec3972a8a2e656964, 0x1b121c049cd1159e289007e0c9da9995],
+];
+
+/// Round constants for width 7, 71 rounds of 7 elements each.
+const ROUND_CONSTANTS_7: [[u128; 2]; 497] = [
+    [0xd14e554db66d09102ff84824743fe4e7, 0x2197703fceb4cbf07c6dbf46c4ad93e7],
+    [0x1f59bd5061d8b565aca2fd3efe512f79, 0x1322f0fffc7e4854bd8b5fc23e7106b5],
+    [0xa8c5894c2f88861ee46f448fa9d61359, 0x19e414f80380aa2d222133118c337d82],
+    [0x65f16460e79a7939cbfdd6d7b9209d9a, 0x22241199a4df9cb7d65249d9562cad6c],
+    [0x7b51c78b211615f41c77f6d85e8a41b6, 0x0301061735384dfcecf9a311c22ade32],
+    [0xb7dc5d19ac95fd8d379548ff2b6850ee, 0x229e1d86c1f2810f4a2633b7b4b5bb61],
+    [0x3f56bff65883afe5fdc28ac3dfbea675, 0x0e31dcf9c53209a817eeb292e989727c],
+    [0x6b8eb25272eb88407e8c29a358b40f2a, 0x222c7318ebe96306c9d79ac11237263c],
+    [0xd12593394cd529053ff4e3604974a2c6, 0x0cb416b42c53a829cf4fc823be8f4fed],
+    [0x99dee612842b6db3d2d2c2aaddb6c0aa, 0x0892729d09594a8d8b1adfbf2e2ed753],
+    [0x24375d9f482a4487a0e334dee2d27c71, 0x18e39e9ae45f14ba97a15bd672711ae1],
+    [0x0461d18eaf0f8992d4d7ac649edd6610, 0x2560c9ff672e6ea69dbb33844d0d2287],
+    [0xda7ccf1e487f9916e5f963e814c57312, 0x21bee2b8ed54b726ef17f77366b1dcfb],
+    [0xe4eda8425dd0c7c6e82fec147cb070ba, 0x300e1b28dabebdc0d4bdbc19be29a3de],
+    [0x82b9405f9fe38de4a732d2aeae88cc23, 0x2c44a5ff45deea69dd2961d67011af49],
+    [0xc93d876ba3234dd1ac5ec64108291bfd, 0x0c8d59fe1b7f55095e6aaad9e3b4e7b1],
+    [0xcca1660446ba049759fa53103fcd78b5, 0x06cf82bc5fd115d348a30b0c45378d90],
+    [0x0efdf95d8abeaee8829293347dc9061c, 0x2f80ed01a2118d02e2145111c3fe6ca7],
+    [0xb8414e3d651dd12fda67df08dded9e42, 0x0bdfe7f6803e4dee7b026e079075908c],
+    [0xb31a963a0dba66aef17c19ad622e29b1, 0x06cc48ed882405595b34d39138f50bb5],
+    [0x3801bf16a50336a4518258bcc6867c95, 0x2a67f3ba6d15949932ac5622cf9e2b49],
+    [0x80c8f395aeb6a20cd833c4b14fef412a, 0x2572725a67c60718df656934aa78fbeb],
+    [0x6579cb753330b1879b79685d1df0bfa7, 0x03bb7f6ca43545b11f19721265b41616],
+    [0x393300b25133e9221b8eb3156ed5c12b, 0x2412815e863f8f3bc28bfcbb957faa5f],
+    [0xdb09a93e5bc305cebdcd0630f433af45, 0x0dbf64d58df212e4307871966de097a5],
+    [0xb190529df8f50b6386273f810f15c6ab, 0x274c06c01158e047ff4a791e25b7bb3d],
+    [0x2111d0abecfc18acd0fe3991ee5fd33d, 0x23cf40693f52abf0ed3d842f7bc85d1a],
+    [0x3ff8daefbcf900e6dcc4ee63c4099698, 0x1daaa926528f3368f4e4cf924166b8bd],
+    [0x9bd92fd20ee1a32d0de3ea2817a01555, 0x10103789dc9471ad718657bebb6781e3],
+    [0xd1085f19e9ffc8965a4660a4d9b6d607, 0x0009bc25065080e6c6e9932e34bfde78],
+    [0xab31a2999b377dabece466e9dcd6afee, 0x1312c727cb5489bb174e1dcb82588edf],
+    [0x16682ae3d726824f15f00f7cb9a7009f, 0x20294a462c7d0c25aeb5374618e5e773],
+    [0x0e9d73fdb06259f47a05513ad7a23e91, 0x25e0c6be89bbb04bd8391c90cf35588f],
+    [0x38be313efc420d28b9ec287f28810fd3, 0x0084a0b87e0b336c9a911069f89d875a],
+    [0xa1d97f5807ba2e1812f4d350d7c6f7da, 0x28a03ee29f612aab268f76241d54d477],
+    [0x06ee6a4035527c4d329a6f33068d7eda, 0x0b6794182b723a12e0b35f76bd55a7d1],
+    [0x95b92b85122f6a6932940836facea86d, 0x015b9a698465428fc686b79db4959c84],
+    [0xa1279bb76e8cec9a8936fdc36f59df7b, 0x1bfe52289f811f5c3619f14866138ae9],
+    [0x76e1d309a7e259052c31bdc9af091fa3, 0x1cd574fe8d5e4c428b92b23a97173421],
+    [0xbfa4f607f5da122e1f63afaecd38cab7, 0x212d1bbdfaaf0200735bc5851a156adc],
+    [0x3788e9e1ca64ddf2abb94b13fee9edbc, 0x0b0479e28820e883d903a951f95b1309],
+    [0x3c77a15cc8ee0ef726d8460d4fbbdaaa, 0x0110f5257c21c9379d73b76e9ca95c51],
+    [0xc186b6c2838e38eb5a50cca4f0bc6994, 0x08c52e9401a9764f0c2487dfed34e215],
+    [0x44c538abccc7643f339440dc3aaeeca3, 0x2e422b4323cb4c0af46f4b0e5052f757],
+    [0xc4a327c4bb9d78c2573b835b05c55fe6, 0x2e12482ba160472d45549ff79cd04aeb],
+    [0x02f09100eed203ae4267aa637a032d36, 0x124fa5f9c5a96d6cce92cfc73ea24462],
+    [0xe08f5e1603f933a1723adf4135796de7, 0x0bf651f82f83ca1fa037a941d4e2a4b3],
+    [0xa5554c6c9f92e801cb1fe4382b619463, 0x21c674ea7b834741cbad39b21b242ab9],
+    [0x193e1caf16d7f6dc1559d144a271b341, 0x214f91d6c4aef57e83186b57fd9fa48a],
+    [0x62785ec68c759599c2d8e3f68035055b, 0x203e2218389729c26c6f66213f2269c2],
+    [0xc22fc80391405ce76c92b2349b7eb3a2, 0x1aa934198713ce17658f51293fad44b1],
+    [0xaf7fc56e8b799616a67bbc6678e45351, 0x1014d4e5ddd9c17b3c2b29684476afdb],
+    [0xf9d4e24546e20dc596911fb991710358, 0x033c13ed45b4762a47e1d2d45d7fc8fb],
+    [0x3b7e435138f4f3217016a2d70ce71d5e, 0x09bc1c3274c569e7e5c5d77c51fe69b2],
+    [0x99ca90137f6ab8cdab2a91b36b63322a, 0x1803171c9dbc0610e38d8308db101d6a],
+    [0x7bd4454c0e7da8c74b15b084488cfdcf, 0x0ba6071aa98afec3fbdeb46a88e4a5d1],
+    [0x077b710879911f215fbd6e8ebf838e97, 0x179e90ab4b08a4ead281a8709057487e],
+    [0x08adbad808e6510897238ea007f491a4, 0x1cbca88b8ab3f0588ff87deea978d000],
+    [0x7d7923ef2fa8ede08831e10f7e9b9f5a, 0x2f50b20b33692bb0f62777de5e319837],
+    [0x1a1cfc1232f7ec7148ff076d1ba68707, 0x15ba030e8f1ef6f260566fd6fcfffcd2],
+    [0xb8281bb4940f97a5516cfb9dcd543a6f, 0x0dbe053da01aa4b7c0fad83a3cc966f0],
+    [0xd799afb795e68d4d8b0ce6d839f6dd7e, 0x222eca5d754afeca8997f31d6fcb94a5],
+    [0x91112618362f1b29a33ee2131b46a446, 0x2abffa43d756ef242db7f584bd624059],
+    [0xcf962d6ad41bf2685f077bfd788eb3fd, 0x03c92524ebe67300d0dd0aac464f0e6e],
+    [0xa52209295d1a4bc89d885b2f5dc3341a, 0x282241758ade4ef8ff3858305288d3b4],
+    [0xc607fc5487e898c4c2d83c1dc2817687, 0x02635cb973d71bb3d2137bd81680dd3b],
+    [0x91994887d35622f4cfcf014bbea223e9, 0x13c866aa66e8d506079dc24c2df7b1e6],
+    [0xe6594bb5344d2839fee6620b60e2c023, 0x151f0007ccf42a7ef969f1b6f6315992],
+    [0xf6e198d2b140ddcc97cb15c92ba1409c, 0x1c1c6a5ebccee8caa4dc7ef18e194274],
+    [0x04b0dd459f2b254fc7cc53dd28e45a65, 0x18330c9e67d62f7861a996d7ed69bba9],
+    [0xbdb29a90f158933a1822cdde5387290d, 0x26b747740c95bf139cb11bdf02fa63a4],
+    [0x214f426852dcb2ec7dc3772f579af8b1, 0x1475676fb205084246ef9bc7234f312c],
+    [0xe73ee269dc032d2e32bd190767bc380d, 0x1b03ad9080d29b70aaf30bbc3200efff],
+    [0x89577b27a1f84fa24642eceb552f4455, 0x21c175f937c2f82e99735bc7c201284f],
+    [0xde91cf8c71ebda835df2cdccdd4a4e4b, 0x1192e0269a6b66a8c5887527aa4474c2],
+    [0x48aeb3d5121d311113bba9792a45a58d, 0x0f3a12c0ee52f0d34f2b4a0dcd13541b],
+    [0x9458a061bcb87eeb3b1e183c6d7eb5b7, 0x07fb521883bdf78b33689205b45e5226],
+    [0x1fe648b96f95ef6f5b8e474f64cf3169, 0x248d0e8051c1a965640c96c6f010c493],
+    [0xf3521875f7e90a9b8b7a3c4de4877cd6, 0x2ab553db9ead2c82ce91febe180529ef],
+    [0xe8444e42a72a8200bab6a133846d5b5c, 0x211ee3bda3c95f1954eef22a0a82c2ed],
+    [0x4ee65a51c2062d66732f54bcdf767be4, 0x111db3b5184ff7412f79c129904a9cac],
+    [0x4fa98337e5c10096c630b449ec4a5f67, 0x1a73843264cf9a3a1bb6fb68ef76037c],
+    [0x44353add09c27cd07da02f9144da855e, 0x1c2e5a2d9332f65128c85358662d6a7c],
+    [0x8bbdea39e2bf468a1a24e3625cafff98, 0x0e2b35fe1021b879de5b67a7458fa618],
+    [0x85c4fa94ce9e87fa8a9a37e32c7a28de, 0x2e568594e5dfbfadf272b8d6e8c99bd7],
+    [0xa4bcb58238ed6a6a248e7917ddaf0c3a, 0x07043c7571cb7dc5205bfda42d693527],
+    [0x378f3c691dadfb228da6ff7aa79efe1f, 0x239e18a0c85b845ffd6b85e96343f1a8],
+    [0x06600f173cb65ef0780af9f5a1b676c8, 0x25e247af250c564c00f5ff1cf1f2bc86],
+    [0x3a6049b2504e9cdc2c6e3eb2fd12100d, 0x031ba3385664544e3e0801f1698b1503],
+    [0x9b20370cbeeccad32da5c3609431ff65, 0x2a33777739e2ab0adfb1ca5687db1e05],
+    [0x903d413b2df29b777d05384b3e7af952, 0x2bfd6f0e9e996ba13749327b9c5cec69],
+    [0x9e02e692d0748c07fd43eafc2cc5e823, 0x2d453d93fb36fbf8e36747ee92d90cad],
+    [0x818880eb80f6b730381d974ac5d5037e, 0x187bd8043f8642cda2b6bad82958d115],
+    [0x35c3e48ccec441217713b08649443930, 0x21aca605f92a48ac35708fe5140729fb],
+    [0x9df113de30aa485e8bbcc7f0fd2d1e03, 0x173fd164f5224ac06c2e99f3e2eaa637],
+    [0x1142d73cd1db590e89b61b57d44d69bd, 0x071c66a6463c3fd4292bf1e5acb6de61],
+    [0xaaef6675bebe92266c666982ff72bbf8, 0x044a40e396855be550d8ccce7b82ee2c],
+    [0x1ded293ffdf99599fedef2e51569e4f6, 0x1176d9f52e12df67f087110a15aaf98d],
+    [0x5b67b4a671cfcdfee5797e8457b63916, 0x06e8eb7dd026d257b15c6624bee2f37d],
+    [0xefbb612e32c91d5ef306def38dc5673a, 0x0696aa48c1d5fe84ca5cf59009ab0baa],
+    [0x5db82d241eef9f3fac417004a3a1accc, 0x0853c33a9c7a7c3b355c92c75de44436],
+    [0x8154f5f9802591717e66e95ba25ab77f, 0x3016c0ba34678e0a67f59cf96ce16e90],
+    [0xa682caa93256f2f09a4f746542603b0e, 0x0af008601dc9552517f303ff9e22c793],
+    [0xc25fc16f8d7d1855a38765f847f731b0, 0x04cb6d76b5a27a5aae387cb647790318],
+    [0x6933d62614f266e6b4f586177148565f, 0x2ecf70c372d8e5edcad49e58d47875f2],
+    [0xf8c4889742313c06caa6ecc55f3dd413, 0x01f393a6ee74dd6c4d52b0e7820a7132],
+    [0x5e14721282769f7cf8c3a73723f094db, 0x2e4d4ce3f97bf96ff86b88e969d13e41],
+    [0x4ea59bf8efa0bd8bc5776460c18c1add, 0x306000ef24a7f5a9c652e3a936757855],
+    [0xc43a69024ebf249c8bde40617c2b3d49, 0x26e58d6287678f3db9c225c1d3b93ae7],
+    [0xf0b8b98ddc05b9612c0a0d317310655a, 0x09eee09f873d1bf683971df4d7ce56c8],
+    [0x4cc6f4d18553b08bcb1dd604392ec00c, 0x0f08879dfd4fa0dc11ded556ef01c6f9],
+    [0xa25bd867171523bad5be0d5a20029eb7, 0x11459f7e9f0e3c727777e34d6d93ec28],
+    [0xf78c1f5acd227880f7f37f4e5b0528ad, 0x04e9cbb6f6e8e805a20fbd801a53b723],
+    [0xae88b3e9af5dad01a7304f9868f7c7c6, 0x2d2ff32eb7a0c0266dd46bd5939c9a9a],
+    [0x3d9b405fa2538a5cb76fc5fdae16d839, 0x23b77eb0f27e950200a590447d645a72],
+    [0x6849886318a6c1c27233a469e9998c2f, 0x2cd6d2c5658ec0792daee2067e2f958d],
+    [0xaf83953e26eca839da76861e739855e7, 0x0ae1daa420bac90780d0c83026bfcc84],
+    [0xf0e81fe32b5cb072d3f580a914967bf0, 0x2b495b854553d02a75b68763bda6dd8e],
+    [0x6c9256a4b72e12a35640b53ea959e296, 0x056fa5d7ce2c32bc5735351d2d48af5d],
+    [0x0e1a0ac7a3476c5cc3c4e50da961a87b, 0x2ecbe1a06b28ec78ea7d22df11cd224a],
+    [0x792bffeecf02d6aa0fe8f627579fe6cf, 0x143053c1c387f9353bd677e5db6e9975],
+    [0x494189bc63342bb1182e234212d42bdb, 0x04064b25584499a011ea74ac047a8b37],
+    [0x44c82deab5b90e6ac66de8b4a069564a, 0x100f61f6b81542156760a6d29af5dd86],
+    [0x4f02b0e2a2078bff67688ed65b51d6dd, 0x18d331b2e8180b7aa7055c029fa77aff],
+    [0xfb6aafde2b46207d873fac80ccef085c, 0x0fcf9b89e19b1a55f9b03255cf80ccb9],
+    [0xf231bb1f76c6c7b497f7708dbcfdc17b, 0x254f801f3c89c62436ef63df67d912c1],
+    [0x63c4f95afba3e4d8fd0d6ec882b9d161, 0x16d8e85e57819a10a61461bcd8d3d3c9],
+    [0xfb93253a8f0f04e5cf408c92820ada84, 0x29c9565aa702088bd443e4620d84bb2b],
+    [0x1b5f1408624eda46d47b583a60d29cf9, 0x294fb68064643d8beb237bd705a55787],
+    [0x84aa11770451e6a846de7dc795197313, 0x2c26f3083140c104baf24c3df9223175],
+    [0x21a949a1d9671380a7d6944c838904cb, 0x231811279854707dbaa537cb27241e70],
+    [0xc7ad0570cc6cf3638be9c5a2a275b495, 0x1419365adbb91f1f7d1bb0aa8a4b5d11],
+    [0xb2a29e86d451f00d176ea35b4e02fb84, 0x1674425f2e9fcaa66eb001feca48ddd4],
+    [0xdcc2fe1dd5a75dc180d6d5bed898cd30, 0x291f956def59b878028000cef03d7b54],
+    [0x012394d78de494549c7057b7ef965bcc, 0x2f1302894fe6a26b5bc03fbaa69c4d95],
+    [0x8de13d278a2b24c99ac5d92d0a1a1ce2, 0x0627d6d6d94d0a19a17db45c0796f7db],
+    [0x3025515b10465d59f3a294f0bfbb2fc5, 0x2ee297662bafebcb01df8e62bd5f50f0],
+    [0x2eeae7d967062fdd879c60a1bfaba75b, 0x118029945df773f52fef5429da766b90],
+    [0x79752ffec6b8536cc68b53bd4df0e858, 0x306042d262f6268ba789eb4323c21692],
+    [0x5c82a0ebd9278def2b34f8ce709aee4a, 0x0472a13ac7da9ebe1132e05f9ef60913],
+    [0xbf96019bf0d76dbb4743a970f42a130f, 0x2e8f76b27b474180f00c5a6c2d65850b],
+    [0xb7d846a7d5ea45280ede4b362c32aaa4, 0x1e33984165f47c204a33fee31f613544],
+    [0xce4fea7adfbacb2ea9fdf28524b7b43d, 0x17f22e27252bdcf91a72b59eb276a666],
+    [0x823a0f02bc963b43039b8847cff6e6c9, 0x00547e7c70ee4e3fb4a4d386c8baa55d],
+    [0x7153ab09c54a87a852b2a99480724e25, 0x08ea4ccbf815be325ccd15494f38f3f9],
+    [0x7404df9eff5401cebe45d194c67fa8db, 0x277cb45f3e632718673ba05d48c712fe],
+    [0x807b0d099d3c40b69df866a92dc87b37, 0x0159ec7ea49aadb3a8b0a23c1dba0023],
+    [0xc7fb2d89866a28418f660a86ee18524e, 0x0c33489297e244956de579c5c112ab40],
+    [0x7d6ddf4fe6adabb591151679ed37d21f, 0x01bb4fcbae28a2f3399568c6b3849da2],
+    [0xce4c1d5b9bf34b03dbc6d733d52305c9, 0x2a23343d24a9cfa3b8f413f52ee1b233],
+    [0xa850134aacd120f38fa93264016b7786, 0x0707a6d515b3bad03a9b06d6be048def],
+    [0x8db439ffd04154ec469b62120ce35cb4, 0x1837db5401dd6ec30ba6b866184da56e],
+    [0x8e1409a0f0026de36c101793952f6cfb, 0x244872a0d2766b8547e6b6a5afeabf19],
+    [0x679ca331832a59fddd2ae31e84eba1f5, 0x204fb17927ce873c761196395f7da46f],
+    [0xbf818bbba70b86dd9b763a12072f4c2e, 0x056f53037d5d8efc7cf6a6feba18d102],
+    [0x8ad8fca543be01f5f40fea2952071bd6, 0x1df4bc5d73239f5ec3b89efad53df9cd],
+    [0xbf2892ac87cd9c347c734ab5ef658367, 0x1ffb6ccbba001155d46e167d1fad1c13],
+    [0x63fef91fc944880006f2c26c2ecf2a24, 0x08ee36e881496539b0abd647d353e51b],
+    [0xce8aaa68487b9ee4e372cbc6cc172665, 0x1c3080c254443332a3e6283d5bab6f7f],
+    [0x47085b1660f8873b3e81b7b1af82af53, 0x2dc6040500e7329f75a3cefa4db49f9b],
+    [0xe9385ce67d7f33568cd4c216a89994b7, 0x2c8bca9dd293bb67db1f66ff2e82047a],
+    [0xc7a7087ed6eb8ab20c6c5469d401b7df, 0x2a157b9786f9224cf63648aaec1b9f61],
+    [0x417e4e6522b472bfe61ff89c39bc40e9, 0x1e0fb0cdd8a54591c42e7c26b9027b77],
+    [0xd6a42f7c00e2d7ee6794d464207d2b3d, 0x250144d1cea033fd78bd73ee4ea4cfdc],
+    [0x957aae0403116d22983a2e680eedff65, 0x0b6b3fe9a7a525ca60443c4b78a8e830],
+    [0xe52482fa32e705247eb650bc7bd616e9, 0x2fdf613e67d176569f4743b4963f8c53],
+    [0x06752827c3c04463be5bea66fbd7b38c, 0x048f64b14275fb86fc55b3c0469cd893],
+    [0x6cdc16e690b6a4b3c924dc9d6e854832, 0x0f2027b781c2c52490e11ed4b7b494d6],
+    [0x7cc3191f33b74ebf17d44ce2f88b0f91, 0x29652785160b261c1c20c741524a2614],
+    [0x7fea130470aa44bdf6b058c4d359e179, 0x1176909e02a5b37bfd47810fcbe73af8],
+    [0xc2bf0156d636f2255e1bc35c01e98993, 0x180f26c6ce2a3a477baf0da58d069769],
+    [0xd65f62601c1ca4884a86c7a6a05f083d, 0x2afeedcc2cec57285ad22e734fb7fe3d],
+    [0xb49d47a02e995a41bca26399103087d2, 0x0dd21a87ee22626998dbcd73dd751aff],
+    [0x46d1c7db5d1566469da6a85aa7224962, 0x1d21baa09d7f50f0159fa8a0b6e0d614],
+    [0xc014bea4236b1bc27a856351b48af802, 0x2dc59c4c9e275cd8c2506282cc5bb842],
+    [0x91c42d1447a41513ab9d5f755eb1f38a, 0x2cf62d08541dff0919ba68a697f44f47],
+    [0x83c97acd4b687aebd2264e7a0eb354b1, 0x2f5b8853e2cd2e47a9fedeea4047e460],
+    [0xc66eef9938e4da5bae33f2703c470730, 0x05865328a9b2fd469170c391c5ba8db5],
+    [0x7b8f2725d327c6d1ca923c2aa8092b1e, 0x29761c9fc3d2c4cc1b126be1dc0470a9],
+    [0x3b96552d4bbb57fdb01c2aeeb1d9a048, 0x1d3fb1f8dc3c77cb515c37bc810d3a5e],
+    [0xa0ceeb47b3f7c4c87bb3b052c8705d90, 0x05f2fbeb7cae0a35ee2f3a4d2964d241],
+    [0x19062154f4d7c13288746954b97998f8, 0x1f445014a0f266082a50257152609cc2],
+    [0xfc84bd1a4bfd7db3ce39e05b38f698b1, 0x2d35917cb1d0ca611ee37cfd9078c924],
+    [0xe76716951c500fc8dabc835775335ced, 0x05a89a4eb40387b8512f2f398b6526ef],
+    [0x4f4e5bd5d7d53d5df6c8633a94dd01de, 0x04e26aa3f02601174f46ced15594329e],
+    [0xeb8730f2e2dfb7bc7350d939bac5b90f, 0x204d587d61526c1e047e1dc6b6d403b9],
+    [0x7c2cb53ed699f36675ea8280a2a420e5, 0x21c8ca213d2f44446ae90a6bdae5843b],
+    [0xd69e2e6a2c396716217787b46e822ebf, 0x1303bd921f32fa4c4e73af21457c46b6],
+    [0x45ace79c0e4433d7c4d352097e5c8399, 0x0613823c5b6abeef14387465317e713b],
+    [0x01f51144ce1ea89bb5046930f5ec55d1, 0x0f27c5305ee978f4f775e213e3ba12a9],
+    [0xea8a495115f5a5388827792120c7bdb0, 0x2cad11c693c6a627531950a307e07b30],
+    [0x3da97a9d4ae7d5c585959de3272f82b1, 0x16bb256883c44cf37366618e46c6db4e],
+    [0xfd67157a5ccdb7ae45b00032e1d57a51, 0x29afe50bd78f72cc763695976410324b],
+    [0x07148609ab3698139725aefe29aeb167, 0x09329902e59e5243a726ae5ca35408a2],
+    [0xdc10bf55c3314f42acd0531ad53420fd, 0x04b1800e600c90ec4776bc6af1764ccb],
+    [0x7ee9342a591b4330e27276aa13b67ad1, 0x298d0110bb90e4c35e293c7a7dacedef],
+    [0xb7ea4a350e510c07eee43929395e4f82, 0x024ea6e68cb1aa6b407e022649aa32d1],
+    [0xbd9f6835a0f0d03f4dd934e1320a3d8d, 0x22ae3a1a81c4550f5fa0116e135d2df2],
+    [0xfe0115c1e199c28e22705e681442ed37, 0x1f0f5eee9e9abb2a2f4ae04d6fe09311],
+    [0xe80c2fca32064bcbf83e8cd35dac06c7, 0x023c86606616f317af022464f350b4be],
+    [0xf904bec4759b33dad6b2c86ebfd2b9d5, 0x2a89c4e5b074fbe67d04cb03efe18c4b],
+    [0x75df2cf60b5fd11470eaa7394a82727e, 0x04b0f48c4f2e05bda4b9cf806075f9c2],
+    [0x8f107c99eb7c02900c931a7bd8cb349e, 0x0edde5a2c01327ad042a686f443417bd],
+    [0x0675a8bd853aad1dc50672f137212cc1, 0x2cacacc9c9d8b0004d8932080f9378b6],
+    [0x1030e4b218e44c90a88cecaf07170dec, 0x0816f33508621f2d56371156b0a3f773],
+    [0x1ae9248608e2a52d53f4b76313c97920, 0x2ef8e0a455d472b49bffda935c8d3bee],
+    [0x2e3055ce408068eccaaf3ea7c0a334af, 0x17184a8af9199aba605dd1f2dd18ebc0],
+    [0x836436602777d81f0607130b44881448, 0x16395e153937a83660db6f78446cb995],
+    [0xc32784c9d2f591e2437eaea9b8f5e2bb, 0x0e174088223c38e9440fc77307d6720a],
+    [0xd0dd096140f39064865c65c31b47d402, 0x19e70fa937788f7a2127573d56c8197b],
+    [0x230beb344b918f6f8d6bd7d21b03692e, 0x0e434c329d5242adbdab24780495ff97],
+    [0x75ac157c7f3868fff35af9f55a8c22bc, 0x2c88a82eb87c81ba42a211bf2ef3c158],
+    [0x5f33adf4cf5b49fd2e01c51d09ccf436, 0x111dc223e3c9fc7452a9c2de46938391],
+    [0x393b000842e8baa1218af097f871baea, 0x10647353de3197b9cb48f4539b507e45],
+    [0xabce698525d0a47724354c07152f703b, 0x0e53d64121c0a851c1b3f07423582034],
+    [0x531081dbca4dec226258ea92984b4348, 0x2891938acb83ee193754969f4aa5a62a],
+    [0x62ba00c7c63ed8c457c3227532c12ce3, 0x04106f95e4ba3fb74dbe4bc53abc1702],
+    [0x92c5613ba25dd2ff4d099f7dd3c19fe5, 0x2a73e61b8f1babfbb38b186f401eed0d],
+    [0x6e4856d6b21b729aed2281ab154f7c5f, 0x18e16b24642f19193b798b923b8dd25f],
+    [0x9f359a2d0f194030c4dd875e12ae3485, 0x0d5f849b166ab9f6fc1a64467c555702],
+    [0x523eaa93c1d70acd0aa7cd7844dd0442, 0x0e25250c57365a29c63a4f1531b8b0ef],
+    [0x335853c60188526d80761274074a599a, 0x0f813d14af0c85bcedfbf480b3d80b9f],
+    [0xef2f469460f166a6e774377e78055435, 0x0d2356a92dbaae8c24a8cebed6428083],
+    [0x773ea1003cdd092fd77a43961e9d7dc7, 0x1fb6dceb495bf90fff56c8aad70b40bf],
+    [0x2ab87c381cfc3c269081627741c2bbe8, 0x2c0a70ced79951a75e23c5a323fd706c],
+    [0xfd22db5e229e4c6ba55e8382321dc5ee, 0x13dc6b3bdb05167dceb4b11037064ac5],
+    [0x004eb3425d498025da6c90a47c35025c, 0x210fa8b777907ec30bb88dbd7c0e6ca9],
+    [0x454a982668baf24b0dd3c04e4d599cd9, 0x0c5cfa957139b45a72fbbf80a410aab5],
+    [0xf65cedc06bb2fc93a873b6ef12e0f52b, 0x1f1f3bc4c21d633400fcf8bdb7be59b7],
+    [0x933711644ee7bd57490374c0d67532e1, 0x188f0ee95a9dfb03cd5e3fb30827057e],
+    [0x409727101b186cae2b80d0cfa726ec8c, 0x0823877ebf8a842b77e8e0f3b44873e9],
+    [0x395ca2f3f50878283d98f1f0e61b1aff, 0x0fe81603de39ccf148ca6390d6f8bdc9],
+    [0xccae35cabcaa12963566768ff1b83706, 0x2ef61fe192f52a9d7cebf41c134098f2],
+    [0xfc6a38fef7f9ae1d4c2f8c44b1510f51, 0x0db6d076b2dd1b9f4d56d2f66fab4d7b],
+    [0xf279e6a0f8ca8bc9cb0aa581831c7d06, 0x2a194a4ae8583f28189f9c011d107b82],
+    [0x72f2eeaae2e500b7e7201718c8da8de9, 0x0ae539fa9ca67d4af48fb292f9188c9e],
+    [0x11faffd4605b36cfd12bb4c6dc2bb4d7, 0x23f66a894b1df4d26a8baa6d05b1ace5],
+    [0xe64601f466b56d32245669d9fe5ba707, 0x137cef522af2942aee9a4fb8017bf330],
+    [0x221767e4634f0d550063a0bf92788c32, 0x187aafcb87841b2df9d64c1767789a6b],
+    [0x38bb9a9851e13d7b6e52daf66c4cbd25, 0x23212e75f4b89992a3647036ee36d749],
+    [0x489bb7d266212654d326de6b9af16d63, 0x0f54cbe967770dfa9d068b2fc53abc96],
+    [0xb81367955fde72a62002d3cd51101fcc, 0x23d83a60b9887ab4359b8569ff3a219b],
+    [0xcb22f044607ee15bfac7dd66da5ebe76, 0x2ea676fe7f21fc583ab0b0e189020d42],
+    [0xf045029cb515f58ede001f05a807890b, 0x16f37f357a0c584821a356ab56a16d41],
+    [0xbe916e4c97d35a5cf18e4b7e47d13b03, 0x139093b78fc9603a4288ae5811ed1311],
+    [0x68d60bad285cc15f55900f52778a11ba, 0x0aaba5fcad1c047030c8bf246f41610b],
+    [0x55eaa0196cddc5d5a6187fa0a2839efb, 0x1611827956f3435c1fc67dd3d37954a2],
+    [0x9e6d2bb59db30a52818e41964b7d8126, 0x23db56362f248912421e5f087ad16e0c],
+    [0x7331d19ea3de1decfe8f2e478224209c, 0x2f215e640207a88b02999c98f1af8fa1],
+    [0x78e9d3e1afb451e9d162b3d93eed8612, 0x2b2a261917c0fecf2bce19021f8506e3],
+    [0xf17b08f930f54bab4324a0363e23384b, 0x2f662e4cdfef5f822cb956df75b17b46],
+    [0x25d426749a538b4ea669eaa31f661a00, 0x0870eeab2e7bb1e82c426c2ab1d65fda],
+    [0x409349d846457985725b15ac6a152fac, 0x169418dffc99f2ef5dc6b1013717c134],
+    [0x0919ce6b90162e51ec6895a7cabb4375, 0x021d3cb240d6400339ad5bc535b4fbb4],
+    [0x6fb19474c8cce4d78e34c3cc18024afb, 0x12e45c46c45b660b6addc149e4216eeb],
+    [0x152fd310b5d05970375a11b5abd03548, 0x0c7c86394b7ad61d8c348d185403dc77],
+    [0xc3e84c238d9c21a04121b498a2fbb131, 0x3024e78b217f85d8efeb60008ff70d33],
+    [0x9a3ed7679bb54ad40811ba318e1afc81, 0x235977101b56071ced3163c1ec718790],
+    [0xed79ad6fc6ba511c5a8ec383e1a73a70, 0x14bf885e66f7c01fd974efdc0bc2d628],
+    [0xf82610f1283f265f56548b38072f38d3, 0x223325ae7e73cf093e1d5891aa92ed43],
+    [0xb8025dadf040559226f54870d318a57e, 0x239a5ae138dce6a019f44560ef7c19d2],
+    [0x48e01359c974855bc99738f0b247672e, 0x01dcc35115d9057efaf468d7c8a7d460],
+    [0x269c282ba240cca0a6d77b343e76bbf3, 0x1359a40012c5332587a4deb5604a436e],
+    [0x5fc8e31acaee0c07354d3798dc5003e7, 0x0a9ee39712223d4c0c37c26a96f267c8],
+    [0x29868f7cd7c5e13275391a84167e0231, 0x11812d1ca3671ae688a9877233eeb5ec],
+    [0x98d40c0e28b6b5979069e0590952f0b8, 0x2435cd699bc94985945d878960770f92],
+    [0xf9aab2a1ea0d6685391f40eeab2af0a0, 0x2bf020785e4117a1ea5930cd5355303b],
+    [0x69ac53e9b7439dffbac2eda5c6758024, 0x2cef6c78577c9e97a76855292ca92e42],
+    [0xafca506a5ce562c877b3b8d543135429, 0x09a30e00c9f0f6f260f05f029cdaa7c7],
+    [0xbf9ae8063e0e89670165b9ba66a3e7cd, 0x1fd85928f8c8053c9b264a74f121b90b],
+    [0x6950606df8cdf8fbcaed4b674fdade91, 0x24482279d9efa57862a6cd4524aa289e],
+    [0xa455fc1de48c417d6ce6b27f36462185, 0x10260a39cfb0d82ad8182a6951f9c780],
+    [0xc97192a4420578d2474a37ae7d7e3148, 0x25207827a76052d489bb2698730d5c3f],
+    [0x771f15d97f2d5c401edf6a6e375874bd, 0x28e36b634ecbdf7b8e4f9e1a347df95d],
+    [0xab7cc0d34babae859f6bf66d46a1e165, 0x073f0dea6f83d443ee3ba27c5b7f0064],
+    [0xf87b9b0d169048e84c553ba9888c451e, 0x14c6618569f748ff65514437adbcfa97],
+    [0x2d1f119a0fe6ef111be6a2d45b4bbcad, 0x0c7675a042ba6cc74fa588a0b26fdc4f],
+    [0xe1c29dbff250c684c955f09e495fb34c, 0x03dfad7db3f32c6ed8130c43cff604db],
+    [0xc1d9ec433efc677480bd2cdcb35b64e6, 0x20114281fbe35c188164fc6d4144200f],
+    [0x3df08a2c4a823a3be4b998955da8a21d, 0x29e42f5218b1ea3fad06a48e2292c0fe],
+    [0x6c7c80fad2726d6e9be11e17c2352816, 0x13bf4039488d94b1b6a67f256296daeb],
+    [0xc9c17a464e6dbd2083c2938c227ee3fa, 0x1847a4e36b3eace13de58fa4662a9f88],
+    [0x5f51f6f8ca5d1a344e295ca7663ada27, 0x0a7a2056c7b79384af9fe3c682683014],
+    [0x6a45962d4979dfc0d6c94993bb7de86d, 0x181278caa15bdfb5940cff2bc3216ff0],
+    [0x789cf3f9f641dae011520c8f10e57a43, 0x0a8a6f4fbba2ee09a36d390fbb1485fb],
+    [0x9c067b6841fc833aa37514435e987cea, 0x08f6b4c0d7bd5e4c87fbc6874520e332],
+    [0x13dd914b0c7d523210424ff0f2817df7, 0x2206b2c18f3292e7a9a91039683a55d8],
+    [0x59df57be8e93291acbeba3f487329cfc, 0x0186cef5c04b3a6c32da26405a16cdd3],
+    [0x33c6080b6d15a5bebd1204fdcde772f0, 0x075aff67570ea17c7c79c9114c7a10d5],
+    [0x2c97389caaf7f97a35700be66c1655a8, 0x2b06d8389ba9621c3869d2e8b25ac15e],
+    [0xc0e95dede804b2b47a18055acd737aeb, 0x04fc6d0a6b67744428161cb5228a94e7],
+    [0x124e58c234448a028a2c3bc6ad25e69b, 0x25e4a295b4e7a2dc71394d41ec2a8f4b],
+    [0xa54301439340e889b6ca9cb6cba067b0, 0x0094c24ab01c6db594fda907b639a7b3],
+    [0x8850745ec6cbbea413e7254d2a514549, 0x2751821a6e3b2206cd6bc3cd5d98cbae],
+    [0xd5b42df025ecbd1a8e97d8b817904bb9, 0x145aaecc24e3878db9a8d77149b2419e],
+    [0xb5e5151c57e4c163da3403f9a95755f2, 0x0d2aa00cc6e5167d845ee1877b7aa059],
+    [0x3c242b69ff6e3d4c6090c2bd08a764c2, 0x24233b69861c70c449ebd36e4420ddfc],
+    [0xac4184ba1927dd56a76dc056a9895667, 0x2c55fe1a75147c9c826f762f836d5645],
+    [0xee1a78b64579de93ed2d67b9e49f098e, 0x19779b1cb16a9c79654016674978b8b6],
+    [0x0bce692cfc00102ae50bd9376c5a22a1, 0x1807721ba60fbae476ff60120c1f47ec],
+    [0xe00ada708f167d4b167bd7d4b03d76f1, 0x1c0208f5aba12750802fbdc3bf12eac9],
+    [0xbe888d21021f29d2bddb9127d3c273be, 0x090b6e28ff541cc38869abe2f5dbfb9f],
+    [0x96a8dda8c90887d65214c8665ecda01b, 0x193d6c7e76ad94eb59a20010a799c7bf],
+    [0xc7e7540cda285a564ea7c4753af8a8d1, 0x09ddd5bc0b1f8d4c4f1a7a102c1ba12e],
+    [0x68721da2f970139549e16811f555be1f, 0x300a1dd5fda15d1a9cfe2a2878018171],
+    [0x5a1ee181ce492016e152706e493cf470, 0x1520908df2eefba075bb41cfe0740e1e],
+    [0x33ae9518f9450f480689e7cf30137846, 0x0b3f215d52e85ec3c682698ebcb62282],
+    [0xb4972bfcddb194a0efeb1954daa8a4d7, 0x050996a07af6765c56a8ee2f736650de],
+    [0x8cd9608e44138686399dc4f1ee807add, 0x23a59326daf5f94f3b5192c18ed06cc5],
+    [0x03976e815c0cf7d3f6b8bf967ad56496, 0x1d220b9add0592866898ce834b1bdc20],
+    [0x477c504e76a38a82b1b1247104f681e1, 0x19b49c4da3d9e3a26d36566738658923],
+    [0xe1f25d5b7ac554445c37dd285beaae7a, 0x139decc567b06b495bd6f3cf006c05b7],
+    [0xe3099e99af3080debbb21f90f8e5b425, 0x1a5a7d8bca8d4f07d4cd6e9bcd00c009],
+    [0x88c93274c8549e625f98fd15983a809e, 0x0621e3811d3dcb9584d0a8be41e431d5],
+    [0xd4a3b0c3aded38635bff97e3785ed6bd, 0x02a41c2d2bcf14664a0130a021daa6ef],
+    [0x5355416e2aae19084d84471eedca399d, 0x21cfb54ba72fc06794947cc3bda33d81],
+    [0x83cbe0ece741160f9975cf01b7c85dc6, 0x05115093894089a202a4cf8d0aea329d],
+    [0xa1c06a6f48c974291470c65fc070dfb6, 0x2e40541caca7653212827129559ab422],
+    [0x27970001aa63e46ebb7f9cda1157600e, 0x07eeadd730284f5ab60778900b60300d],
+    [0x324d24d3f4858407f4fd60d3333bd98d, 0x0cf33e4964d350adf2051f8e2e8448d3],
+    [0xb65ef0b99df4acfb75709edf6a3bfcb9, 0x287c60483e2db313f1a2bda92c1e1c28],
+    [0x139c1a1ecef53c3febde0b5f6d9bb4a5, 0x289440f4b7fdb8b2b22661d0742e9695],
+    [0xe8b9eb25222f97f4817ef2e5b0bc7208, 0x291bda2c37fac962a24555a7abeb36d8],
+    [0x94ff99d04cea894d6ecc315119f42b73, 0x0e105449589412404cda7b82f14d5275],
+    [0xb7ffa8dc8cf60a1f836be23e41ec25e5, 0x0e6097f0ad418ac7123ae7eba6cd4522],
+    [0x4ff52c1445ad41b94c3e406e33e0def1, 0x22fdb676724017afa8a8069c62d307aa],
+    [0x2c5338817aab7ee7bf631c3aa92160f1, 0x0204d594ad7855ad0f6950742b19e60c],
+    [0x8c8fc11d868bc72e76cd76c3b2f7dc1b, 0x0b6a643dad3f953877110517f7b33651],
+    [0xb7d76353a10bd07d0942639447cb43d3, 0x04189ea6c2bd4b00fa6349adde17027e],
+    [0x2e14935134f8c3baf7f1e13ea2deec3c, 0x23bc8449d898a7524b2dfde263036cac],
+    [0x2576dba257fab323d4c99c7a42107a16, 0x0017efa608d9941bc64d39e0b2593b74],
+    [0x16f91e3a34a5856f15ad0b9f2f92e2b4, 0x02f061475e9d8888b3877f9308e7758a],
+    [0x35da7cb0cba318686872fe1ef6d52e25, 0x1f257d4d6f3277674a061330d73d6ba1],
+    [0x9cea488edbfd80fc9b9cc61c384853ab, 0x195340f6c1f7b5f6158884bd23771514],
+    [0xc1008f942459d5cdbed633ede031aa1d, 0x273e200179c5c45d860b26a20b2c0853],
+    [0x6e2c8e02e808a158bdbe01431c5e4a48, 0x24bd0ecea1e1df97af0f296c0e792d67],
+    [0xb41ab671599d6350df38e82104bc6835, 0x0c6e6f0f7c20811f404599d458af5c6c],
+    [0x06bc08d58f6a3ef3d80370d8d19683a2, 0x1dee1352470235cd8e129b5611fa9537],
+    [0x6afddbfb7265878080725a12fa12ad4c, 0x1be0cc30343a168fda7c757ac0c7e5e1],
+    [0x07ca8d9c619208580eb76bbd9215d984, 0x1a449abf644f68a64c217c6a042a2311],
+    [0x2daf476a9a2ca018faa3c5fecaac1789, 0x080117dde58f228a459f59d90e64babe],
+    [0x7d15166fab51f2b62c809de318d6d176, 0x24ab9a167b61855e0f2c60bff098d5eb],
+    [0x823201f71b54bda684c93eb0893784f6, 0x2b56aca9d595460503363292bccdc80e],
+    [0xc95a12afbfc48ab5e1e4de0162a41b5a, 0x057361ec23c87b30080ff1cdf3467d57],
+    [0xade3689ba1378cd9362cc14145089f95, 0x1baed177177a25ecbbfaf4f204d9921e],
+    [0xe0e0309a841849fd808d75f593a71481, 0x00ccdb6d5f4913d7656889c312975fdb],
+    [0x839929df98ee743d9eff962515561910, 0x2c52f5e1ef57de5dde528471cc1f0ee7],
+    [0xf54b2ca3d53ce13fc9052414ad7f7555, 0x0234a4a32f767f2e1ca48dad3a2fdd3f],
+    [0xa9cc62abcc90a89efdd80776558e3433, 0x182b4c0c551ab0ce1990b472278f794b],
+    [0x1fddf19edf10d7cdb9550e1846e53fd5, 0x001ab2bbdbd043d2d7d1a4eb76db4de7],
+    [0x07b41e90e7c2d43aa256ea5249978358, 0x206e6cd1e3154c113a762a628e249409],
+    [0x8a261bb175137177d46ba95dd03dc8bb, 0x27816cab4bec18577bd335bdaf829879],
+    [0x9526b2ad82cf0b8839d3e7b0f585bc8c, 0x1680b632a04be3eb1ed64ef589440b3f],
+    [0x0c75b0342511efea4c3724c4fedd6e75, 0x196b2cc0e6e07628df8234f58c027c19],
+    [0xc693770c38bf91b4be38fe6f72647486, 0x29ab0b6065ebbefc684608acc6bf3b5f],
+    [0x56ede18c5efcb8e25515b727e540a859, 0x224ce814d39c382da2d65eccd9deb480],
+    [0xd1d42559b6a368264fd0fbfefa66559f, 0x195e1cb28f38a9cb57b2c719b504f5fd],
+    [0xcdd58acdf138785ce98ee3848ffb7840, 0x0e9e5eb70f4490ba6dbd1c0ee1d6724c],
+    [0x548cf71f46fbeb51919582226cbd5b21, 0x1995c9c8e520014e4412ec653839d781],
+    [0x1008982f27104afee198fc377f26e324, 0x0886095cc376c87fa653000283a6eae1],
+    [0xb0d9a36d486aa115565f9f52369c124d, 0x13761e206b3e89b23de2342bfca860f9],
+    [0x2506ec0d508de25942c10f51f0dfab81, 0x22a8c6cfc0e38a500a47515c519ad6a7],
+    [0xa3086938b13f8ee9465a5ec7bebdee03, 0x18985e9c7ea6f1a16962e4e27cf1aa4a],
+    [0x406a2abd64081043e0d15492845b31a4, 0x215b523ce3f055c92eb148c00027d1ad],
+    [0x98801b3e4019cdd42239d76448588c31, 0x0102f78c7513b093b885726b7df2e702],
+    [0x058f9ace11fdabd24e8dfa1b0a4ae727, 0x0bce88602fd3a0f0ab8885891ad44045],
+    [0x0b6227517f5200251129c5481f52fa4b, 0x0745f760f8d466fc21043e2292e6cbb2],
+    [0x98a1530fcca991535a2a262de4e2ee56, 0x0406de3807445c6d3cbdf7b174c410a9],
+    [0x3cc27cab59e887f7ed7a0117887d1acd, 0x26779e7346349068dbba66f784f87e24],
+    [0x17259a1d914a6ea41db75014267949d8, 0x25150652a08af8ca574c7c5c77ffd98b],
+    [0x6e67de6fc3f6b65ca837ceab795fcc6f, 0x00bb8d13438040f674a79ed42202c5bf],
+    [0x7f66eff6229d045d5a801067639ccf2c, 0x0c1ebf40eeaac3d4a192dd50ee25c006],
+    [0xcab86947aad12d490d4951932ff20727, 0x1a39731eb32fdb51dd793754f76103b5],
+    [0x6988524edded36943672a0e857b960f8, 0x2baad3d41b18ae10c463af1662ecb28c],
+    [0xfe82f4f8df1b5c218cdd3b7e49af67e1, 0x2e778da1518b6a4fad2e833e41466fb2],
+    [0x634ae049b53adab281698645f8b16dc6, 0x156dc717930d6490167743911e9bf4d4],
+    [0xf5e0104f9e5281329349d908b23e231c, 0x117f2ef1712692ab9dbdc51db4968b45],
+    [0x8d6ab08330f47512aa047b2f88ba68cc, 0x0b281b5d9dd2ca1a92e2d83b5087b6a0],
+    [0x916f2a2dd640b6eec0942ccb75d40767, 0x1c2803766dd9096567a3366061764f8c],
+    [0x668bd55800000aab6f34766b7b6575a2, 0x120174d53ba57d1a141bf40e4c705683],
+    [0xd01795d31b5ed73466e7d8b8ad376d46, 0x0c37b25020f8c76ea5536de54c722e47],
+    [0xd8398025a4000a5c2500228c44fa91b8, 0x0a508f345cc9a8b0c30ba27651ba1c3a],
+    [0xcb9497eeacc847a340f61f1823cb2a8d, 0x12afa49ff4d8b4c6c372f7e065840783],
+    [0xf4f7f6e7772f443e0730b01dd5071a55, 0x29fc37eb5ec7cd004809516b7aa3c716],
+    [0xe57dbcbff7514ad1e52a271bf84dd1a8, 0x2048fb9c1fdaced0fdc5159490db2977],
+    [0x3181f1d5e03dbc11c8a4d02550fab64f, 0x00a6c4223aabe583e899bbcd0314a474],
+    [0x0cca93c8ed68a35655cff745686764d0, 0x15f462ab5b21c31557e25d9c22d8dc6a],
+    [0x1472e7e3bba0d35e079b2d1b9e4271ef, 0x27bc1c9af68edf8e3730197124be559f],
+    [0x46929c71eb82da3139a3a85968ec6ebc, 0x1a2ec148f900eeae38c0d3d6efda64e1],
+    [0xb71b2354bcd1c55d7eabcaea8904c22e, 0x04ff41cb553ca616d0616545922f2030],
+    [0x14a984619260a7b3d38712e03a0849b7, 0x2f2c4e7b18a845fcd6add67009fc237b],
+    [0xea6a140031106826ac53a9c69d84c0ab, 0x22925c606a1f091c1d5c7122f8e1a8ff],
+    [0x8811633f77822d7c232b329c5b4e7279, 0x10e2a8c7b12bf70266c257573ca2594d],
+    [0xfa45b3548cd3f162d586bce31ec7184c, 0x1712319595ae464992524bf8e488b783],
+    [0xf279ff19454a64ade43daed4dcda5c0b, 0x2e9616987d3333388ea5a1cb56b4c161],
+    [0x12fc6137cf5b35a68fc1f03acfbfe4ca, 0x2c68210d75660df5d9a8392a2805ea16],
+    [0xb0b3533061a35bcb0cb0f9bbe4e8adc2, 0x053cf3707283df8730b6e1f637d23c67],
+    [0x7081c0095decdce3d227c059ddeddb66, 0x07577491483d03d7769dacc79607b989],
+    [0xb95eaf855fc26cedbf90411312de4a12, 0x05639b2324c0b0af81c5b256ca6b2d46],
+    [0x1cea1ff487ea39d70bd94e9b7f8f16d5, 0x0ec90944eb8fc36c52f3fd13763714f4],
+    [0xaf303d6fb227fc68f703b93665f5ea96, 0x1e96cd3ac345bb2e5bd3e755922f84af],
+    [0xb42ffebb0df885a824942da1e2e36d58, 0x0408425405c66baa7d49858e6d5871e5],
+    [0x26e0805aadb9957a534b022b081aa977, 0x284d77566b42f3913d41ebb0528b8994],
+    [0x26deb5624f644516239427948d7d06bc, 0x0b044e5984887cda337f58c7ca50a781],
+    [0xc0c3f71b58150c9c525063a6f2b340d8, 0x0486972179ee3496795bded1c2fc9674],
+    [0x7a7bfe406623e47cb68e5c79296e6bee, 0x25b7568316c4b2fef9b72543b6033b31],
+    [0xf6c5c15738aa5a54a19269261ec6a8a8, 0x0cec9e74c344198703ceff28a4d11275],
+    [0xa9957c9d736f0ca39b98130801d1a114, 0x2bcc34a99345174c97a65e8978194486],
+    [0x786940a1e8c558d8dbed8d6f44b02551, 0x2ddb2ebc0ac98f6498c0f2be2d73e86a],
+    [0x43da183ed284ed7a385ef7cdca80bea8, 0x2642b4a208e503e3480718d4d205351f],
+    [0xf71c7762f8bad04a2de3f7a025cb5087, 0x23fabdb09b481bc764102ae85ccf1e6f],
+    [0xf5f6c467480c8aa5f495124cf70b823e, 0x0ae4ed0ea6adb74b9d327fbcd01dbf37],
+    [0x4faf8adcc7c71bc2ed6e03b0b4492016, 0x09034e1a646d6674bef041afa774e363],
+    [0x0015e0ca147b650683df4b3372b55ef2, 0x2817ea20b471a4bf138dec79d84f9f6c],
+    [0x298397de78fd2aa17f6d1dd2f1379ca9, 0x2dfb7f81425c2def6bfa4e833e51e912],
+    [0xd54c9ff7099f2bf5ce894c24a8ef1f37, 0x137b5bdb746523c051d680be08daa6b0],
+    [0x024e382aebe02bcb535243ddca21aa85, 0x03900ec7adc8e28af7046833dbad3fa1],
+    [0x0c33df8f191551ead53ea508511cb555, 0x2b285b6c23b25c52f2079863c759e6a1],
+    [0x977e6fd12d8b35281e84c375864613d8, 0x1242067be2202d1b2c32b79c464e2d6c],
+    [0xf3eee805237b158d997db63b17525eef, 0x1e8690f5b0a8729b9536ef2920abe7e6],
+    [0x17a91f261ef9d81764a5b279ad9c13ba, 0x047f5113e1287b5572c82edb369fb0f7],
+    [0x3593d173b687122a4d61c44743902105, 0x2d8800f21cd6774d5a6aa58ae2cd3259],
+    [0xdeef088685d1976979bad813756f84b0, 0x274ba1521d5bc5c580647f10d1a4c4ca],
+    [0xddca8f1eee9d8d56e7fe4934c8b56441, 0x17895b7c10d6a475310d34a0737efaed],
+    [0x8cf5becad2f66d15761bf61e7dc13440, 0x06eed11ae9f70df5ced09b1a6db25897],
+    [0x8842cefcbcd2c6bf84e2db5ded748f09, 0x3036b521c631ce5d3599e37f6a5daf92],
+    [0x645c90d72e367609d7d7805a6367482d, 0x1f52d6e9ac28976c5a6fbf168c818478],
+    [0x9c3dd871091e96a323c1596cedb35e6b, 0x2a0e60356875d6ace1055932b3dd9ef3],
+    [0x7f434938fdebb9c0e1830dbe5311c38f, 0x02089633a6194f3ed38eeec2c8f54d0c],
+    [0x57cfef0612baa963336bac1240b604c2, 0x178c73d2769765d85383283fdb0d7694],
+    [0x7d5d0db849e789f28c4be5004afa19a0, 0x2fc20bcaba9e3d96ebcdd710e3b34183],
+    [0x7e0f21494982df872354c01f0f4a722c, 0x151a423bee67fb14d5ad279f425213db],
+    [0xad0f8e25c20735366895b31eebcdf47e, 0x25d61619e135690c4e10e4afeb4f5578],
+    [0x32bf9f36c51277d09f2c3a664b045cd7, 0x15dd579bbd075429301d7295d3e685d8],
+    [0xf4423c13cf5b8cc1032b7283bcc959b1, 0x236fa29b81678c8a109fd361c1ca90ad],
+    [0x20537a977da10e53c5baea3446c01d1d, 0x2a80cbac9b884e20368691bf1567cb73],
+    [0xc0c179b0c22b2c13fffbde85fd912378, 0x11d4cb8957937b3628a3b3f4d27f276c],
+    [0x0a52fbeb1637c5010fa54eadab402946, 0x1289bf8ec507bf752a07c9b71efc0f80],
+    [0x4903e77d2ddc03ec2fb155466da6f303, 0x26f842b1a99394297d25799f2f03dc9e],
+    [0xcc40eadc48d37d39bdd5f09905c3a369, 0x15944b9954b6182314e878fa2f9e091d],
+    [0x93928f84442c7465a0012f584426fadf, 0x0742166dcd8ebd8576a6bfe0f1fed137],
+    [0xde792e72fd61c06344cbd735707b68ff, 0x08603d5a0e937b5353b56b05768fd50b],
+    [0x0bf58d86032d03bfd25b9777edb74a75, 0x0caa1711c66e9301b6f25e21a31683d8],
+    [0xfab00c32ae45c75b3db345f3fe74a311, 0x2d6506da695d2087dff87b5f636ee676],
+    [0xd75dc4cb68533606e976b8b300758c84, 0x0d9244b07af9b6eaeff6f84049cb2e01],
+    [0x3f030ef6a1f5401aa37acd15cb1c8c46, 0x243abc3c3382faa5dde3f1c3b432042d],
+    [0x024727d3b0791b06f8e1bfe8efe40198, 0x168416e0a6d275d30eda3989242e0723],
+    [0xfe634aa14cb11ae3a491681d9c5f8eeb, 0x1bcdeef03693d1ad0ea80c65688654af],
+    [0x551bef58d94fad7f7c231107e5653909, 0x02f66bfda3d2a1bdedb0ab8bf7eb1c4e],
+    [0xeb8ac1fd0a1ed349afe9a7b94298975a, 0x019f6802171ab82a5922f023fdecc097],
+    [0x2645a1dd862d87c1f508c949ad52c3ae, 0x195a1c738398feed8c760468a0556c23],
+    [0x6f24924810e838cb24ed1b0fce37f1e3, 0x0a36a3a46e6ec16567dfae1f853f360e],
+    [0x81bbc3974c3c17c607cd840c09f374ae, 0x002123c29ea87f7ea7b35c25f9e8dd3e],
+    [0xf3005b80e93a520b6e42b3985789e2d3, 0x02a717499cb6c31f71f0f0352b0f5bfe],
+    [0x2fdc933e9ba90fd327aaa8c8cfc4daa1, 0x0fc053584b0a4607a2293e18baa309c5],
+    [0xb1b99ae3c6d71bafef7b494794855541, 0x1310d1942129737b32e87a86f4573032],
+    [0x18e6b2a6657e74b540969eeb49e0b8de, 0x1bfadae53fc82900f5761c558fee5e12],
+    [0x650c5b6afb392da142d32de8677556ea, 0x28c08a69e5f3641460ff2c60b7579395],
+    [0x040f47fe8cd922163d9d9d8b2622d909, 0x223222ee42b6ca997f44d643ed6a3f2b],
+    [0x1448b43949345690031cb125588876a0, 0x064dad4752e708ff29bb0842d2c452a7],
+    [0x82b71e9a1bc5919db8befd35411056a5, 0x2d5ddc21797043996b22dcabb9a1ba6c],
+    [0x0bcdb22f926d61591e83fa21069c0e0b, 0x26315fcd9b5e1a266ec8e9f6600f5a6e],
+    [0xd4f22e82f346aa754b0a1331bc8371ee, 0x0759273a4177fcdeab23da38b478faf2],
+    [0x6ddd84c364d6279d34a0b9d1478fcf2a, 0x09e13300eade70568fa0a840ea972989],
+    [0x34b22f8cc054463a145cbe0240d4f8bc, 0x13e97ed3c16fad6cb5b7bb954f278f24],
+    [0x027cde5765971bb1f61c272c5aa51ad0, 0x05cd3486dca58f4eb221bc2cbedb8aa3],
+    [0x65e1c1697816a9a671a3b2b6149c7de4, 0x005ce523f6bc21daf1df468b12fd3e86],
+    [0x1f089d671c83c5b537abea482b2d8cbb, 0x2103066cc5df55e67cd7914028145fb7],
+    [0xc539f72329317aaf8173d2edd7636928, 0x039f8077bd9b71f91ca0502eaccc1672],
+    [0xaf3a6422e81b4f4885dc3af7cadcc5c5, 0x2247bc0cc4373705b89672a2205346e6],
+    [0x426762706188790ea20b2e819638df80, 0x27bcc768bd31b9d00687a7e53c3ce53c],
+    [0x79ac350159814ea536487368cfdfd708, 0x0d91cef51a76f7a62c51c73a2809732d],
+    [0x67bad46d6aa1cd008537f627ba140730, 0x20a9ecdc649a26388dc68dc02343a0f1],
+    [0x1016220d39e0c230325dae2941f528bb, 0x23134149a0d4567c6dfc911d5cb80c1a],
+    [0xe5a5b0dbf05874d5edadc03fe74b9474, 0x2a07507e9f030a42c6f29ccf1c9cc4f3],
+    [0xb2729b718ce81152cf3aafc64966110b, 0x1bdf66bb1d57fd788e54ef29f06f35fd],
+    [0xbe619bf77a12020cf5630b78e7e0d0b9, 0x275c33d07f9e7338ddf50783bf158d51],
+    [0x2bfd0f32c4070b55bf1ad234e8b28116, 0x16411554e55fe604a1ff1b3b5b7afb6a],
+    [0xa36c641bc2851a0dfcfb60831f6759a8, 0x073e6ac7e6af227557249ca1a5c55222],
+    [0xc202bbc3baca06838f31331b0556e12f, 0x10a6fde88c5f621d748543c76a218f95],
+    [0xc97a1bcec23e136f038d29dcf96b0eb6, 0x29c5865a26588ca8a88128a072c8434c],
+    [0xfea8c17ca64e4d5dd516fcca07212c1a, 0x233e0ef6ad132ed211b1911cdd569576],
+    [0x5b6adc238d878a476bded3e4d2e0b840, 0x096f9b92b9cf2c7d664408a29860365f],
+    [0xd7b1f1e835de1fbf3192e395db785554, 0x2fc788fe8aad4c28b943bb57cb90edf6],
+    [0xef7c9d722bc648dd0338d9689f6e023a, 0x1bac0ed609233148503795fc5e114076],
+    [0x94e561573d8c72a69fc383643e2adc82, 0x001c35268aa030d6cd148e106e3fc230],
+    [0xa39ce39ff94fc167ddcc95a7202e34b2, 0x2bb1f8fe5d998d0b8b92837ffb27bfe7],
+    [0x422da23945630b10b8e821dd61f9a2b2, 0x1a98576450a43e5e705b9af68cd0c145],
+    [0x6af966f123d653f38388bcbdd50e6037, 0x2714d37ee506efbc2ba9941043fb23f1],
+    [0x1ff7ed75413917b53e50ea00cc2a4ad0, 0x175d0480ba14abf0bcb8075a03f4a2a0],
+    [0xbe7709701ec8158c17fc026fd084d541, 0x203de45a037b75ef63761750dce08da2],
+    [0x2560f76b01cc3d431a7283022193abf8, 0x265aabfc78b70ca985c329080d6329a1],
+    [0x2b2ad1520ac0819c127af2ea5f3a87a8, 0x135468dfbeb70e8c2edaffac8658a6c2],
+    [0x286c61758447ff1bfa9d9293b6711fdd, 0x216a41e24329deda65a8a82001c84ce8],
+    [0x70d1a27cdb21b9460bb6fd0d4fe482e6, 0x2887ed49797bd6652bed8bb99ae345ad],
+    [0xea53a9811e2424a19fae446b8ae49cad, 0x03e2bf82690c804bb6ee3c79dabd1892],
+    [0x67414676dd8a8ce9946649b1c4e81116, 0x0e6dce1bbe6e9e465cbe14dcc6156118],
+];
+
+/// MDS matrix for width 7, row by row.
+const MDS_7: [[u128; 2]; 49] = [
+    [0xa15cc1625102ae511bca45f39d337e9d, 0x2abd9e16b7b48289aa19cdfec726fa3f],
+    [0x7362ae381898acaf8b63cad04ecb36c6, 0x1b4bc5885d1cfc8eb691875300db5a0c],
+    [0x953695029eedbcf91eaeb012751c7f83, 0x08a72fbb55dde3e93c5818571a424a54],
+    [0x1b3d3763d8fc762400af039b0abb83f5, 0x0f95359a1f6845d4921892f83e500bbb],
+    [0x43e6c7b5dadf52d0f82472fbce7770d9, 0x16f01751ce985328afde34d81619defb],
+    [0x55e8d885e3c07cecc8af1f4d56596417, 0x1c391626328457fcafa629f3e6d33e25],
+    [0x023ed5bac91c2c1056f03965a42c5297, 0x00970f671b9ba35e3e3c642d1e0b8032],
+    [0x278b6c6b2cc57a871213fcafd6427989, 0x1fe9feb5c7f7c7a426bff032cd6a3158],
+    [0xb8dec0fe9e5686150ff6e88b1a7b4bc4, 0x0cc7eabb154aa7955eac47a19f6437a2],
+    [0x15e429906ca0d15076b5a990fea75ace, 0x0393efd96399aa0726ef9af79be8b6c7],
+    [0x6eeb33f697d1b0825db47d39ac9e5955, 0x0871233835b752ea030cf8a4f4377129],
+    [0xe96a5fad1d2db4270497b6e6504891cb, 0x1a02798f047a51baaea34fcfb26ca6af],
+    [0xa31d6e3e4c3bcc657dba4c664d5a0da0, 0x055665d76df3cd0fcb713c234ea79d06],
+    [0xe7758286a44845ad88fd7de3bb1052c1, 0x26ee9691a638edc7c91887e302fc55e8],
+    [0x65d49e5a2d41556bcd53d0c4d433224c, 0x00cdb2db9e4f9d732d217c5e31148211],
+    [0x6a3a787c4bcd9c798a131b70856d1cbe, 0x012f3bb29c972793182123f5c49306e1],
+    [0xd43fd1e0dbdc5528dd2cd1f17ad73481, 0x0bba9006404c5ede82b527677daf444e],
+    [0xe17c9f84b1713820837ca070be84a2dc, 0x17e4e73bf701f88d219acf1ca6390cc1],
+    [0x8ad60760dd60276d485015f73d53d207, 0x234b8a077ef52b53a343b8da5b20cc35],
+    [0x835d7217a4c50a487ae9a35a7fd45d9d, 0x2dc3fd77394b7b5ac1cbd1b46e6ded5b],
+    [0xcf882f12276bd12f97f82c6ee2e61e48, 0x1668477640fab14c72b1c16ff0192e48],
+    [0x6904b3877f2159dcc999895adeb4d70b, 0x09664216ca565c1a44fc2b98347ea8b3],
+    [0xabf76d77305857b8df30914e4849ecef, 0x111fd8dfce2bd3b7ba0cfa93e20fb685],
+    [0x3598265b1ab6774da48eec04e4d93426, 0x19f53c0a592b3ea93497a511e56f6675],
+    [0x8b1dbdf18dd1c318e6a541e8cf66a174, 0x19b683c39d3b002fc3523b7bee5a6014],
+    [0xb8036bb3e882dd5e6456c35311c1bd5b, 0x2547113a818779f13e3448719c7ef100],
+    [0x6a3e35cba9a970441ba8d5bc76943b0b, 0x00cf148f8045cdbe7bb1013f72ebd412],
+    [0x038168cb28cd2e1f709076c00b869599, 0x3008421eed767a093322a09c3733973a],
+    [0xfe77b43c244c9988a3c658d2f56493d6, 0x1463f51d4a4cb1eba16dbc115566aa48],
+    [0x31c0f83832a61b82250ed90118e8fec2, 0x16afd8f4c39038b8006a59d292aea847],
+    [0xdff8352711f958638172055333e07579, 0x2296361f3bf154efbab996921936e9b1],
+    [0xa198d1f8e3439be29beb92d909ec891c, 0x1c3160ebaeb10f809f69843cf8d5bbac],
+    [0xb5b4d414c6e09acca24912a7635404c6, 0x094c66bdcff54382cd9213abe43312ed],
+    [0x434ab5bb84f3420e5d94b853b1746f89, 0x2e883bd408c4c826d004ff777d7c0938],
+    [0xa053f2f23c094b875f27cb3abba2030b, 0x228af6d425e91a783dd18fd89a6b38bd],
+    [0x4dfceb1a1e6da38165873df66ce51f59, 0x214e41788bd2b990347c7f2a8f47612d],
+    [0xb529d76f54e4b5d7d4805272af9b9775, 0x03fba82d3f783620873da94e9f4e84c8],
+    [0x785329dfb12ad9bd1b4e46ed36289511, 0x066782fe3e869de2cebd7ebbf60fb435],
+    [0xe5d1643fceeb212cdff51588835fb537, 0x1c5cd6b23ef336be01efa37a27e6b7aa],
+    [0xd9ff3fad9a794e15830bd2b311539453, 0x2f3b4bf0f105f88e27248af094e29fee],
+    [0xf64729b311783fcfab3a0a36fc1ff432, 0x2f4085eaa9f80a69b739fc23f2fe4057],
+    [0xae361934078aa59a16214c088185638b, 0x2363603349e94f520fa1f98fe2507f6a],
+    [0xdff184a1957a9385fb715923bf0fc79d, 0x0548541724f64e20128260899abe8fbc],
+    [0xeeb095680a72928369cfd2de71b14f98, 0x21c5e25a6991364e59a205e5b01c811d],
+    [0xce8fff171300d41455a2edac37663d02, 0x11c5cc53f6338c825817aff7792a2efb],
+    [0xad8eef68f577da8b96f9d87a39504bf9, 0x27a0949fdc9f10495c4ee7214aa0edd2],
+    [0xe556a0194e6d552c0455cbb5b0c230e2, 0x1c7fc68f3fbde4a23c455ef0d0dbce0c],
+    [0x8abc1ced0c3f3c530cbfb4500d63de9c, 0x11d0f56fc00e7d85b3a2939fd33991dc],
+    [0x6363bec886679cbd91f332fb2bade44f, 0x01b2e9d20d9b55deb048cabe1636f0e9],
+];
+
+/// Round constants for width 8, 72 rounds of 8 elements each.
+const ROUND_CONSTANTS_8: [[u128; 2]; 576] = [
+    [0x48e134333684c1596feb0e8d8c3ad596, 0x123992df3b9daa65139ec13fbb52f7d3],
+    [0x0dfec978be92b49119a3959c7f623703, 0x2fa46f5dba9909ce9984a01e2af30b55],
+    [0x13b2dd8a8cf68fc36d94ae29401b2d2c, 0x21d8f94d3bd98f0b63a7aacb175b2cca],
+    [0x7fd7db34e68f73579da89e116af87eb8, 0x1a8c06c61046e0f8dc6a39bc57aea0d0],
+    [0x401b89e7ae5eef4994442d167f7ad1d3, 0x298cd7046f58c0cab77523a58a6fdfd1],
+    [0xa07f3e830fca475a6574d67fc5f19f63, 0x0eac737966715df4a20b31ab693eda86],
+    [0xa77beb056821750d072262af2a70a33e, 0x06b9b718bb3b6d45e0cbe6d5d9a2f096],
+    [0x3f96e435d653eec3972565e1fbbc302a, 0x0bccdad6e8b1fdfba846a9553fc7905b],
+    [0x1c007f49240ffc2ae1f1057cfcd8c7ad, 0x1b3ff4a6bc8490993abb7a002393cd1c],
+    [0x2da1de59750d6d1709751831b7e4ce26, 0x017f0c9452d70eecdf5cc8b6b0b205ec],
+    [0x144a6ed0718fdeed42971c160791b269, 0x0cf1ab805bc47a10349ae77135ea95a4],
+    [0x9a8aecfa0282e9fc325b5c0287103ce0, 0x18dcacac61318af644ab0f2e61ef48c2],
+    [0x711685db7e4638dec90ab1cc794350fe, 0x16fbb3bdf4066e2b2f050b89b685d408],
+    [0x96487a1b1146e9f3d53275af18fc6c6f, 0x0eefd0b8617a1ecd7711ffd5f6c4cd04],
+    [0x63b85b778ca11d9bd2988d41b600aae2, 0x03c707d1835066fa30e7295a097a6ff4],
+    [0x9606e84aae5e3ad30ed60d7a660738cb, 0x063ad3f6e3f8443e0908af99ce32d2c0],
+    [0x501f790bbe35a166392a51d90d117353, 0x2cf67cc9cd3a5313d992e315010d90cb],
+    [0x780e28f6b71e893716f70140f4406b34, 0x2a6e7a7bf7c190d4e4f7f258d890dd41],
+    [0xcf98440da6c2b97b66a52b98cbdf1d41, 0x26140420009b6d8c7314723e66925bad],
+    [0x3b265392c73480b9cb02276906e445ba, 0x212c611149d057b2ffefc9938ab1498d],
+    [0xf82632dd5933e27cd01129518cd9e952, 0x08918ab686c1a160a55db12010b20150],
+    [0xab20d465c65ebf4bab422f743f17d8b0, 0x173ef7767749a3249b004eac7aa7e94d],
+    [0xc13c97b18d67bb9199dc765355fcb1fe, 0x049d90fb549e0e25790f5a6570755c68],
+    [0x4afbb8c15a7cd9e0222566f9922a1547, 0x0d1aa7cfe6ca9e048ac6ce2cda40c6d6],
+    [0x142a4408acf1dad452a66e8219f667c9, 0x2d82e2ccd8bd63b2ba44bc0f4cb5ec88],
+    [0xcf2fc289b939471d6b465b7202eb2354, 0x2ab73a842e0bbfe229ea1154bf8aeb48],
+    [0xf710173a118f9ed21153c99a6b0783fc, 0x233f36bb205dd59d045310064408c6ea],
+    [0x0449f35986242a2ff79a644f510cef27, 0x18c14a50595b8df32124bf57f8b8df43],
+    [0xcddf973d8cfcdb4e5a5b0cac6b5b22d0, 0x186508d6b75c7902ed6a1948f91088c7],
+    [0xb001f8ec39a313d259be4309d6429bd2, 0x10bf42990104c39b27ef0ba451b510cb],
+    [0xee1f3c7d2b93799aa1570bf73153f3ac, 0x163e875fd9e04b255971072c0d2e7614],
+    [0xc2ebec9a9f0f830bb5c826061c65eb8c, 0x2925a59f82c1472b8120988e3af6cf24],
+    [0xbef3d4c3fe62e2937ce590a38df2da1b, 0x25caf9ff6543dcc34e2d0b8c2af3b36e],
+    [0x3f38ca5ba87f4889e510ada924f6b799, 0x14cc417fd674083090f4463f66180e4a],
+    [0xf7db3b943103ae7ba2e09ec0f4a08517, 0x182550aed7fb3b7d92ef0b91f4916ac7],
+    [0x42fb79fb6bb91d948f391ac9e1c3f1c8, 0x216305852467475a9f934d3b1132a996],
+    [0x6bb9f8097c77a2b6dc67bb5848689b8d, 0x0423b771f7215716cf97b3a72ccffe15],
+    [0xd3fc5a2cbbeb8d16ad43fa2c3a882537, 0x2247cd5af8b5ce0a983aede949f81dc7],
+    [0x07ba4ae5a30c6a0119f77b51c3643d2f, 0x04990ce49123ed1eca92df7bca2b271d],
+    [0xa6548dec41bac10f0319fbefc1bfef9d, 0x064e8bb29cc072af727af1b79af50896],
+    [0xcadb930ceb51c91b07894f0160f8fa42, 0x05f9be83351a037f574fd0f54d4e9ae5],
+    [0xd5a5167168f9d5f3877c4c5ce03629b6, 0x202ce17e96cb24e8986cd09351ab9576],
+    [0xb8f550a7f03e7fcd8b5121516b3f452d, 0x098a2fb5cb01868cde6424c4211b9549],
+    [0xb6b08451e637fe53f3b4a8ea7832aa27, 0x2cbd947e532cda0f8f1b899ffbcdd5e0],
+    [0x74e55900f800645ba66137b6b7c728a0, 0x0e0fa4620cfc943da81b40b6b42f86fe],
+    [0xe8a8ef5d76e6d03e9bb06741c2184123, 0x2e1ca081c96d5abf2cc40b3797c38b0a],
+    [0x3542e405c70855787181163e4ac20b45, 0x271cf3ea7b06b4e743585ae68997c014],
+    [0xc0ac0077312b9550f81b7716a92f5eab, 0x09dea127972d2109cadb7e96abba7577],
+    [0x31baa97c76c82376999637d8ef1c8a07, 0x1982cc716da20b119525c64a3fe85f5e],
+    [0x88c690f16a5eedb730d939311d0c3ff1, 0x226600de8bb65b38ecf996e5bb26fd62],
+    [0xc2550a3db90fe28944e73d01d24ae6cb, 0x020f7b77fbbcad998e7744ca27c0f7c0],
+    [0xebac5027071d1057a945bc90437c49c2, 0x1c2e445cfc98637aaebc2c1ab7b6d790],
+    [0x9bde135f0b0896f0d9cde04f1004171e, 0x244b349502df4a33563b30237ee750e7],
+    [0x8c890b1cffc35fa707fe6a94941281cd, 0x10860a875d80bd71c6267c1d54b02fb5],
+    [0xdc327dc694f229b2526d2f30b2c499c1, 0x2eec5addecc4e24650dbbea531a0fef0],
+    [0x2b1e85b5b724bc0ec512f8fa641182ca, 0x188bb7150b6e40624b02ea2821aeb6b5],
+    [0x53ecd69e135ef0204d2d3b6cc1f49aa1, 0x05e56007dabde2cc83d498371e99ada9],
+    [0x474a0a8cdc6dba8f04f3031b07687b16, 0x2553755b36de611ed557768bb5513731],
+    [0x2b57066a8626b52c0fd5ff42418aa65d, 0x1cf267dd9bfb6e678a82c7745857a63e],
+    [0xd3716af2bfd0b6b0ca6e24ab4b4c25e1, 0x106b2a40087375ea2988b8e0700fdd71],
+    [0xdf674819cb611d93b4cbf5f2ff9a0a16, 0x0b978e4a10dbcdcbc7e90aae2ca19615],
+    [0x35ad096d6d34fe1ca67e532b5186bbf4, 0x2ed68ffba6a646e5047666b8ad69f6cb],
+    [0x7d349797f07053df2ed33520ea3e94cd, 0x23c632c2fe68d4f0a71b1ee63951c72b],
+    [0xf0a4ea3c9d33eae8d6c0e23a5a9897e3, 0x27fda5f826ce6a8340d12a584d4b9c84],
+    [0xb5e9dc132a4fb07faf8eddaa30da7674, 0x2d24aa4c0772256e90344d20d46dfbdb],
+    [0x053e387d77369eedf8d9bbc663fb98f7, 0x048d52c4e3d71485ce6af122ea136e60],
+    [0x5fe151b5c2192255bd757b3ced899f92, 0x0e0c1f005a1759dd161302fdb233cbac],
+    [0x751de5ca53e8654af8d422eec627efb2, 0x230d641c4552e5e55416e360eaa32900],
+    [0x9f957dd024f8138d535c6380a8b5d38a, 0x02ff6fa8364a8cfb19e30be87d4cdeed],
+    [0x8513c57da7f546a2017ff128afe16171, 0x1689a04762a5ab1388f6a7257e7c7283],
+    [0x1a470312ab2fd00afbc7694f95b827cd, 0x288d8c7325204250e8632f42debee8a3],
+    [0xaed8da2e6e338068d78f5408552c83c1, 0x23996135af9f3a42a6db33fd55319a7c],
+    [0xac382d2b6734726236cf9c7be278bbe4, 0x15d052dadce14506fccd2690c333f8ec],
+    [0x68a2de52299b4809cc119e37d8e042e6, 0x1494fb65e5993d96c30bf0bce3f56dce],
+    [0x83c3b131be5ad5bda4200992fbff053c, 0x0b800ef8749f91b307947c581095ec92],
+    [0xfdd5f1270f72805fb05f5c52d77c1cb0, 0x0bac1f53d5a7d39811bae7c1a79dc997],
+    [0x2bc921ae0e082c303e32b809d939786e, 0x179249893fa86ee067b47bbe8335b894],
+    [0x3449fd5b401ff4d5eac75dce7fcb420d, 0x0985a7c9b42805d910a89e2dc9563e7e],
+    [0xf3caab81981d621fb7fea525ccda9bfe, 0x2c1905b5f00c927a57a154c474427e31],
+    [0x81c2ef223936f07ef57856f0a9bb4f70, 0x0b23502ce04c0f4cb30c0bdb6c4f2a4f],
+    [0xdeb51b7a3525820bdd6eb65e578519ba, 0x2dc051c1bcf637233ea7c5e086aed7dc],
+    [0x07a282b526c7239eec81e92f51c741ae, 0x273d8a4166dc3f53b1ff24c61f1a3b30],
+    [0x755f01ba3c8e57d82d9b969565dbf0fa, 0x257988be7dc257a0c5dd7078b72f692c],
+    [0x654445ebd72492a32ad3991f3a5a6218, 0x213d0fe5a3e7608fa602f5b5ad5b8260],
+    [0xe502601a5e4d54b3370c4c502b097a8a, 0x07a383e7a221bc76fb14370d4b3eee8d],
+    [0x895d596a54958cc9702d8da219623e81, 0x18ce7b3e647ce9a3a90d0ed9aa57eb9a],
+    [0xdfaf8f29c183abd123392e3c2829900a, 0x12320ee10efc1f8118d3ea697574eae2],
+    [0xa83114827566cbcde26ef4c87c9fa89e, 0x093d0e1304c660fddafdb48295928fb6],
+    [0xbf3dc9f48ab3364da9dc6a7234d714c6, 0x2931b9295d96e5b97364b37daefda7dc],
+    [0x81ce43b1627d0ba46596906cf3281624, 0x1f533a1e4b4878c2fc2e9706e541d284],
+    [0x5466e98d8cac3ffbfb1a1bcdee0cf064, 0x2517cb724d12ee6ebfa7b4b0d25cf031],
+    [0x9187185624149a7f274492d9bd8389ea, 0x00e43dc983bc8febeaad40130444ab7f],
+    [0x3eed0b1b83f21ddfb899039ceb77f379, 0x186bc09a7ffc4edd291579876b77c2fd],
+    [0x42796af2bfe6ef74ac9abf53ac65eeb4, 0x05d22a2f48f64658e181b8ae5e72e1a9],
+    [0x3ad2c2e466ae6324f5d39f3eee58f0cd, 0x1f006e8d2463636bca787f765fd6ac72],
+    [0x799d9d100196f6ccc30dc08f39a479f7, 0x28913d42bdf680478ffd17374e895fe7],
+    [0x6e22694225d4029dfdcd836cb7c0ad7b, 0x11b557c8d7e97bd99ae5f49e823308bd],
+    [0xae1866a68983c41de56fb7cc7dcce297, 0x0744b53926e1a0ef395bb04908b13326],
+    [0xd28362935cabe516114980f141422a03, 0x2bfb2f2456a70989b165fb6a3ee6f944],
+    [0x2c5d28a74302de0efd6d4addd4dc2db2, 0x3027047944ec82cef6240d1637070c62],
+    [0xcb3bc47cc1286657cdfe88c5c411d4a2, 0x1ed0b5e4b29aeb0b5b3eb128cf031759],
+    [0x4aff48f0e730f4782b225cb22777104f, 0x263479ee77e44e8b2ab953e82ef63a7e],
+    [0xe20c1bbccbec2a5c8b950417ca2ee471, 0x19904c84e55e1af3147cdb7f3a428265],
+    [0x0a8da30c5ec5922fac89ab92ad81a3a4, 0x2fa8468fea3204ed2a934a1eef7c7858],
+    [0x2e5b962f73dfd78d23384dc7ea71afa5, 0x267c0b4b19662cf09b870e05f7441e04],
+    [0x5849ba7bea0d12512547ae684b83ac2d, 0x2f1504675041755409946e8c5f5432d3],
+    [0x53b25d59ae91ce0b9d31fdbba1ee2b0c, 0x204199d64982f574327ff3f400705e0a],
+    [0x6187a2efb9568a3d390d3a51c2297ccf, 0x0a087554e5511426780e457757c175cf],
+    [0x052268455f1ab145ccf1693be92416ab, 0x2ef9b88428465f535cb04119f518d1c2],
+    [0x2a4384b26c97c7b4ce877cb65ff5634f, 0x2a96c9f200b477eba78316fc4497e08b],
+    [0x7c54f12d2db746f7c0cdcb0ec551cfc2, 0x043293e61ef1b614850bc015dd316e2c],
+    [0x04b818b385d04d96ad298dbed9a7bdfb, 0x1d81e0e280611bf30989c1a740884935],
+    [0xa074c5e3aa0b2e12f4b768cc4804bde3, 0x1ad9cf17b8368d98002ced2cf6ef9385],
+    [0xa4b099694c31acc182ab453fc000ef95, 0x19b30a27119ff50da2734b127ab83299],
+    [0x7a63832c30d653063f67fd86482e1998, 0x132d81f00bbef2804901c4073fcd9c81],
+    [0x84dea78a1ec98f4d593947f6a4e73924, 0x292ea3ef2313863a1276b1f0e4973fa7],
+    [0x7079f2ffa650102f2feb735c7d9bce84, 0x028cfb5e0c41b0c77d987e737a501878],
+    [0xeb45546b92396233a3f29f4e8f6791b3, 0x11afd75dc84353e2f9c2f05d1f3ca734],
+    [0x4ea101b678dff427843c0fc816409ed6, 0x1aaf43ed6da39b453120bd0312f25929],
+    [0x9f37e5a4658dbee0785dab5dcce32868, 0x19db09df83387b1e4185cd79400609e7],
+    [0x2d11db8c1fcf35e38189afca16c0cbfd, 0x029cb3dcaf2ebb0f5b0b949e84278fed],
+    [0xd81fcdab794082e2107e1b0426a730a2, 0x2881df9352d274f0cfeb55df8ffeb1d0],
+    [0x1df2ce582b7604a8e64a7015ebddb65b, 0x0628a0f9594c159206c7627a8f10748a],
+    [0xd1fbce295c2a95f1afc6441de60d7fb8, 0x22657393eb7ed9f4572c22cd7b9d5134],
+    [0x62fcf8098608005e7d08b509bba33624, 0x006325f509f0362714c4850daed87440],
+    [0x6ef449b1f8e5bdc5ac72d9f93dc3dd9a, 0x171d6498b42b39345d56e8661ccc2b57],
+    [0x6b6e4b4cbdf43ceeedbf04735315a8d2, 0x1be82f3b1cc9d849595d85e339833118],
+    [0xa8d26323f8864de7e36e5543bcd7a38f, 0x154711be62f23cd46086b9311b28eead],
+    [0xc8d496ac1e1d95cd5bb8d769a6745cd8, 0x19ece5d8c0b6cc07fa0119746e7f36c9],
+    [0x65fef97a53a168c62a43b9e6f4cb2d16, 0x26d6162d8d9c9c60244cbcc1bb788142],
+    [0x586cdd88bba7c51259b1fe6a375fe4af, 0x28bb85fef9f65884e4a99a63a59a906d],
+    [0xceef2f1a6fddaac1de2299e92d9d8ce0, 0x267ccac1e34271971e474179c5800526],
+    [0x8ee9eebfa64025f961ece49a9ffad985, 0x2e6b4868d15390d8014248eec0c7f10d],
+    [0xf35dde6fe093ed788e4839d2bcecf840, 0x17039023ba24e37bc4cde497e95d134f],
+    [0xc75a84651183dcf43b9a256320c33e57, 0x214455d91fc747c6aa9908ad92d19b2d],
+    [0xf2cff5877c3c67b7ab23b9f2d8dad2a4, 0x0a07405f036d30ec77ec64b034b9fc5d],
+    [0xa60617e0ee33a358cb316811b3d8864a, 0x29f6807284b6e435ccc2fc14579baed0],
+    [0x05084114d2efcf231780b5d4b5c4a0c6, 0x246894f6071fc9630f69187cb5ab506c],
+    [0xc01926f7ec25053de56215c145e5da67, 0x04224081b90a67774d15ff98e6134493],
+    [0x3645ce4212787ed369c0a06b602bab21, 0x0d99cfe7649d1dfd292f11773bfff1ce],
+    [0x33b753fd77e6b1bdc4c796a031b7815a, 0x1fc76023333ba4dc0f2a8dbafbba1bff],
+    [0x5b20166537ffeb65707e5f7503e8acf3, 0x2f60e05fa1f7446735a3717a9d73728a],
+    [0x3663c8979d6216ec9ee1240b8c894e80, 0x1a2b7508921fa054f58ca4d6eeb13345],
+    [0x1db7d756bb4aade0a58e5bddd7ddcb4d, 0x0a0aca4924bea6979a56e47af0bfa19c],
+    [0x22e6fdff8eadb90949faa5abd63a7f82, 0x262a2365cc780f62557c854566fa2c88],
+    [0x7b1ca0a246768b3a2b0014ba5a302439, 0x19ad5a882f1dc4971429545dda3db185],
+    [0xef07bb48a6f1226a64cdc7d4de7e95ca, 0x261e3360fb23c08e87b8f588bd59f08c],
+    [0x4e445c990271b0e2d2ccc42fdce0bf59, 0x069cc67cae46ded18fdc725c9cad9dd6],
+    [0xc8ee19216ae189a6ef2d7ebdb1ce62c8, 0x162e21896c8d1f6c2b7ec16fb7ae7ef8],
+    [0x4248ff9581139122ed9c9d55464ee9b3, 0x128d42c08444d8956879995a105a336c],
+    [0xc64c7a41b6764e687cee8e507433e3e9, 0x12e9391f7f131f13c39725c4414669bc],
+    [0x9bcb36422aab6052fd1f927df741c170, 0x1de988e46c2c9a1c00d85dfafb1da750],
+    [0x28e0771d65011f9954b424e67802cf0d, 0x2002cb8a779f93de8b44d7fb08cff508],
+    [0xb164989c20f905ccfc0982c29365d631, 0x28f6e5af2752c79d01a3c6c7d2930fdf],
+    [0x9e14aaa4efbf987377337ffa3c026b2b, 0x19ebacb5354f7f272f5c12d6c71b39d8],
+    [0xb304a3c3380a94e8b7c070682ca869a1, 0x05dd47c695cc6c95e2acd1bfc0ce335c],
+    [0x5e1672a7418801cc0743954534d9c0c7, 0x246bc3c037a3c9ae2bba7e73578b1a82],
+    [0x5c0eeba268a72cecf9e3fd1a6870b3ae, 0x1b7ee255815ba7020f0cf78ceb986081],
+    [0x52bdaf4bccd9ca18590c1703a2d120e1, 0x1ce04f643bc9513316211fdc0bc23c9a],
+    [0x5d7ce10c88d73411b7271f52ed4c44cb, 0x2e3a6532afa95388b41ad59d7c270544],
+    [0x23721a895a87d73394cc9c1809fccfbb, 0x1f91121f2cbd1ead2f70f4f74729a257],
+    [0xbcf71f8bea6e358f5b0f17ca5276d926, 0x11082403388a99da0de3ed7d3b873f5a],
+    [0x329ed9eeb7857f1cecc46dc93046e1f6, 0x03962a691eb42cf5ef779bee82508641],
+    [0x141bb2a353fcdd93fc3ef83f4fd7863a, 0x0a67910e294583739c2b6d18ea5ad74e],
+    [0x5f44c5e48287bf0f43b45443ddcbae5c, 0x1c20f9bd1323709fda124f71e8890123],
+    [0x0c8cef5b6de579de67f579e86b506fbd, 0x111ab56e19274efd47392b70171dfdab],
+    [0xfe288ee5863a6892915d125eaed786b4, 0x006e6b88e6f0cdf982de483aa04bb9d0],
+    [0xbcfccc57e2bcbdddd65881af445fff84, 0x29bc59d6fba6d692e2a5e85ba50d6fd9],
+    [0x244c2c070211275a37d49b1d70340edd, 0x261d13ac441b2f30096c1f7f66a6d973],
+    [0x5f95986d26163f00d41c9e3c366ccbfc, 0x077f8d9273ffda37ed1ea683f6934971],
+    [0x208542355ed7db420a609acb8e8ebdce, 0x1678f9fc6c4502c235267f4b841d9543],
+    [0x9f72fb707500b270837c9a249590075b, 0x1a65b41dc302b906fd144a594bea6d90],
+    [0x1a23b10579368f40b433710b97f807a9, 0x265359bf0ead777264d18dbb4c466430],
+    [0x8ecc4973ea091e5dc67125cc9045e41e, 0x2e944ce0eb653745a45632c070b931d0],
+    [0x5643b86835926aed2eb0a7aa4f06219a, 0x27e3025a2d3e82614a4dd0550f59359f],
+    [0x986e575054840f238cab4cb5b03fffc2, 0x0d9848e0d3b8bc8d43e5e6954e6f0181],
+    [0x6a87b104bf57ef38cda855877fca7c75, 0x1ac3aad815d8ec13b035decf67a68549],
+    [0x0d5ce9c80cc51f6f931d0c75b9c0a3af, 0x0c910186f912eef95fd673ead2281ffd],
+    [0x01b1849dac31fd545a7cd74c5c3a1e54, 0x248b63a76a024d5cf2e42a1bf70a954f],
+    [0xdf466b5e69f93503858c69f5a868695e, 0x1a71fba60cd363b0be3ea3604a386cd1],
+    [0x17242cedeab83084f0eedf28343178b1, 0x15a2869b6363cd02a472cbcec5c91bfa],
+    [0x443fb1891619243b8d4d9a529a733548, 0x2813c10731f7694540eacde857786ab2],
+    [0x5ce94062936fde48fc29f8698ed5ec50, 0x2aa96905ef42bc1e8452087fe13dc3d5],
+    [0x98a1900cf359b3e7d9688f6591b8d384, 0x0f093d369fad0a0d255bfc311c4a58dc],
+    [0x08d486b420d5339626c8411ca984027d, 0x068dbbc530967765cf7c7e69b8bee4ee],
+    [0xa8ed8022adff356a67fcab3a6eaf5aee, 0x1dd0cab71570941ee962bd61dad1cb68],
+    [0x3ae960cd1979f78d7f55d78259553777, 0x29a857f084ae7f2b506efd6cfb0672d6],
+    [0xbc8829cd1f8471b7e2e17f480d853c33, 0x2081f7713f51b3930dfa0a6df8e83611],
+    [0x599ba601538635c8ccee00cabc85727b, 0x2eea374a60ab1a20648cb908e605c6d8],
+    [0x65bc412da6d99c2197aa397d8d3ee5ba, 0x1a1b68bd2951131e6d71952233647e41],
+    [0x1822b8734c9df91c80e08de57b2d4f2b, 0x1defdf14415a78d5e6f287ae6e49ebb1],
+    [0xac94040cc6224e2bfdc76661106a7249, 0x10040cc4fd20bacca69e26287138be4d],
+    [0x5be96fc9ab5b2eb71012f5f80a55ff40, 0x20ce67f32e823b988011d0c33abb0f72],
+    [0x51c058c21b6eece43585bcd28cc4ea87, 0x202ecd50c82c9e1c79f0fe136518b225],
+    [0x243338210d145d6187926798b747087d, 0x10fba50c060fceb989d7536c3692f0c3],
+    [0x3c507a7c551123c0f9dd59a58bf8dcb5, 0x02680936e402765dd6ccfdfe2dea719d],
+    [0x620d7e9de84364fe0583be79b4de7cfb, 0x102beb4a5c4c903eaef9ecb0d5794154],
+    [0x8ab012d7efb2675f4ae1d6f5e1370622, 0x08d01db18faecd1a26c285be65414bf1],
+    [0x6ba782b4bddfa33bda836fa2de419010, 0x112fe5d217f33c2d2d19762d28684136],
+    [0xc717affcb4449ceb31a2792d5eea6765, 0x064885f457cfafe6ece2944a9a935efd],
+    [0xd00c44cf51eec0ad4ad0aab9c09cd886, 0x0bbdc371ab6d4296e73ed2078b99fa47],
+    [0x34800d6b440f5f6b6c426b30ad078887, 0x0b9e59b699aa688dd12143e890b6fd0e],
+    [0x2d9267d7f986b610170ce5543bfb30b7, 0x0a5aa31d4fab8e109b0fdfc55eb14a5c],
+    [0x5903eb1bb7704c6c0cdd4c31408a7a6b, 0x03239d50b4a0128e39c7673f2f99d88b],
+    [0x730cce66ea046f8f5eb781e7c45f8c9d, 0x11a6945949a182759b6f1da60eadaf2c],
+    [0x74956d834f2d63817f2a9efed3dc931e, 0x0c502ff1bed8a68726803e222b87d590],
+    [0xb9eb6988d03894b6fbf51336634c917e, 0x222d9daa384ae2b40e43e274cc07d2db],
+    [0xd4b00c09545351a1177805c50f6bc009, 0x1df8fe0dda8e51971b917b0ff0f3e05e],
+    [0xf9ba8eb772f2497c55c0f551775b2635, 0x222bb8290405c5b3373f1b199969c6d0],
+    [0xac030289dd5eacd8cafdd31b93264d10, 0x07902bec23d9f91a003a928eaaff9647],
+    [0x4098ff87d05a82d738fd8883a82b9d5e, 0x2c706f059d6a86ea496c2e4693d1717e],
+    [0xf252922d33e94280aa805bfd843786f5, 0x05ae261694bee2446a1813608edec696],
+    [0x33b750f99b77655011c64b3e8ccdf93d, 0x186ee2ca779c5c26a193adf0ff47d9f3],
+    [0x4a437b1f209c55e6ef99a1ac0736bd11, 0x1c2ab6e96f68e3b2cce6da3465b19e3e],
+    [0x7c7507f8ef88dc5d6f04b02203c37e55, 0x2fd17db7e1827169c32ccfc0ad14d9f9],
+    [0xe100aeebd62a6c73804f080de66d9720, 0x241fad7570628418ab9748ced53646c2],
+    [0x0b40af6be277e98b71032f894e6f6a2f, 0x0016b5b53403ee6f47e6100c2778ca97],
+    [0x857c8c935b135b0cb25df1541d38c7ff, 0x000a095842f718e71645b2e81c90b3f0],
+    [0xb9d2ef2bf478b9e9b8ce6ff1668909bd, 0x303f2ba04eedf1425b5d7cc4be2c5088],
+    [0x163c6ac2e4e162056fa8feee51956667, 0x1494435142c96fc94e8d66d9b643e019],
+    [0x04b25a28abcadae83a5573e3f3d4bfea, 0x031cdbb9ad28ec57c4e1ac8ff66c791d],
+    [0x28d7107d218d62d51b0b2d8cc9850498, 0x0c787dff012f136b89ce3516c1cf8679],
+    [0x8cb956556cf077457c1e4a9ad5dc0b0d, 0x0b20b314907f522e17a9d5da8c10fcf8],
+    [0x0adc7ed70bf25afe25df9e2ffd9bc35f, 0x11d5cadfd04189c6c14a49e8ba678354],
+    [0x0e29aab6626531aaa1185ff0cab8a1ff, 0x0852309d50f14538c8b7d35658d89ba3],
+    [0x5008a30593c69293fd7105622602604a, 0x257395e7c190b3cdce5f934dbea90770],
+    [0x9fb414273cc87931610a4b3144aebfb7, 0x0aae37af5c3a542202935f590b667f97],
+    [0xded03e13c08a6274c47edb41134e2444, 0x1c1654a77feafcd7feeba94c32605f95],
+    [0x3dcd2edf198f7095ee5c70a9200995e0, 0x1480cc4cb3b9d9fc97b99e340fec1982],
+    [0xa34797a9dd96a94cc87b8ea462417655, 0x18711057869189126f88536accea4882],
+    [0xcb962f070caaa649466fdf9bbe5828cd, 0x0e9c4735ee797b9c70833a7a6be7c2cf],
+    [0x0ac0f9d27de19d70039bbd1f90ff74fc, 0x2e2e38ccea7ef6c29a922b027d31d987],
+    [0x8f91ea5dd60e5a316b3dced27fc137a3, 0x1d66369e95c2e76030e380d6538a9327],
+    [0x484e7ee7402a32ccc77fde2e7866a083, 0x29ef9102b5e671edc05f87bcceb6d900],
+    [0xd626fb60937ba3761dad73a84478e3dd, 0x2103b04c1735f7511eb775c7334a0bb6],
+    [0x711bc1d6bb28eefe38a0e4861ad13963, 0x0dd1b6ba84a3c29b62923765068cf63c],
+    [0x880b77b0f0dfd30d38e1c955e9dd721b, 0x1eab4c7faabec58ab9b73b2baaf21e42],
+    [0xe114d5be5db8571469f774cf6f6c8022, 0x002e1a3f669dfc929b9cc84d96d489ec],
+    [0xbbc8d75d1929938460a7c67f0c791f11, 0x1214ca424ea151785150d7db57b93cc6],
+    [0x9260c86218510a4d910341f0b4e01d38, 0x2e174dd4aafff6103164d9165ff8319d],
+    [0xff6497ffaba30decf6ffe62ae952c435, 0x20b32f4a423617fef146da5f1a2695fc],
+    [0x9951f3b83ab64efb8dd2e56cc9b31a8e, 0x29f4dd468db0873491468b7009dbafc7],
+    [0x87f279a38afc4cb3c4d26cf41c642af0, 0x2519801de4e58e4164f7f0d86994311c],
+    [0xf22eaf5b6109ef41a65d2d286f602cc1, 0x2ca123faf875de4eaad80eba73cb2a00],
+    [0xa302a1aab706687b2233ae9b20331267, 0x0efed651a61fa30b70937bde9d02b9b6],
+    [0xf45a0ed2c880b29b7dbd5c9e6439ee3f, 0x13e21dbbfd7e5526bdc5075e73dee0ae],
+    [0x8cf5bf7451afcb394659741cf6be5821, 0x2c2486d85aadbc358b1065dd7b14957e],
+    [0x43d945b96b0982c8a3f5e63b73550d6b, 0x2c97a5d5b9751943bc11e04c2d60a56c],
+    [0x70e2f86b336ff78040419e5edccd8cf5, 0x0d3876b0553694380adc086cc9272e17],
+    [0x958c816a0bba374278e8b7ad79ab9679, 0x12365c7cdd616d258f9ae3504b37d44e],
+    [0x58ebe5e42b46d22aeb618e2db4ade65f, 0x1b60354d16b01843540b8fc6961ff84a],
+    [0xdea639d2524ad972d8496d129e1c2434, 0x03778fc723ccd03ad8c90c311c141963],
+    [0xb3d864e6c8a14a327e2e558b9df57662, 0x2ee721dde852879e1238cb7a79207147],
+    [0x92a038bcb61aec4ccea2ec4df2d0af4c, 0x11020d0581ff8e79c8f5aa4d50be2dc7],
+    [0xae46712e15e63aec715f3f7031cf4f8f, 0x1e0e921193e35b3c42ded003dcbbed8e],
+    [0x3286b67703ac4d2c24dcf5a3add9020d, 0x2a6c13d7afd6636c2d4d5d36f42c09ac],
+    [0x92217609b109c14574f932bb02c88440, 0x27bddedea7d8d398b4b6e9152e17c2d0],
+    [0xc9b900bb98901593d021d61eba6555a3, 0x2e13f9cbc356728e6d2b7a5c593aa9ec],
+    [0xa0045d405d81bd2a151690bdafba3b66, 0x109af33773e416156885bab0513dc199],
+    [0x415ae480954bf49552dd8c5f3e50301a, 0x0bf7a121a985d20eebebf624f3837307],
+    [0x83271b5fe2ee56a63814d5db19290567, 0x0f78ba7c3e6d15d11292dd121aad4594],
+    [0x0ac5e6d5981e2ad0df9b290a56bd5001, 0x1625df035eaa992f424ddc6dc88c1b70],
+    [0xace99ead01981fcf2c502a71afd56762, 0x1ac7d1f33bb598fe9581ac73b0aad4e9],
+    [0xb202e2e1c2546d989417d303ec319337, 0x2d37296ef0cd5676d0809608ba340cd7],
+    [0x805be3fe44a957d8aa3bf07cacf1c114, 0x019caa62c5238e03ecdf75bb2c276be1],
+    [0xd496882791146c522a8acaceae3e4a48, 0x0f32dea104320ce0f00919d90f9c6c76],
+    [0x865be9a29f266f88a8affa850f5ec6e5, 0x2ce5d2452c0a5a9fb65a4a6482f34998],
+    [0xa25e5c310d92df83120e891cced24a8b, 0x135d590de80a0e717da7325c781aa167],
+    [0xc736499850722f7fe7dd1589706ab693, 0x1d3ce96a7cd79e94492e32b8983d62fa],
+    [0xf6a4e6516c8038a9306b36dc818321b2, 0x0ea3a1e1f5dca3ff7d8671f2fecfb467],
+    [0x8e3d869f342e0ef8b4a47afe74a83a25, 0x1ed08c4f0e16d144059100f05fb46309],
+    [0xe1bd249f7424f1970b52e8bb24d986ed, 0x0adf612bc0c24e826135c7de0dba90fb],
+    [0x69eb9b68746ad9c356b93fb6eaa3e22b, 0x240c424a024e59bea3a14fca73d7b638],
+    [0xd5c70a181a7e9fe35daf0479e2b1cbd4, 0x24dfab7238b05bf08f66fbe599763f63],
+    [0xce117d094e820d5e9f7ce1debd3bb08c, 0x160d79a03959c968dafb76ea3e08ffd2],
+    [0xf725bfa50248aa89d57f570c2fe9345a, 0x1b65216187a1ca1679642d4c40aa05bf],
+    [0x77f6f7a0fff571c1275b18f54bd77351, 0x243503bad8adaf14a8e565013863c13a],
+    [0x782a8addfad1c18ee4391163c541a62c, 0x06c2ad47bf4de574cacfba515d18ddaf],
+    [0xe0ce4eb08661904026b30ba10f3a1341, 0x214366673d387148578fef89434bcfc7],
+    [0x22a32e2b7884860e85257f5f7b7e6de4, 0x1db81d98b4d4d83226ae4131301c8e36],
+    [0xfaa38861d7c92299a6bef42f7c443f0f, 0x29d2bdbc19896e8faf0ce15d7c883bcb],
+    [0xa86404cbb7a85522f7fc33857b7a9c88, 0x19295f72c5907e8d9f8d8cdecf7d193f],
+    [0x58f2cd092e33d3ed62d8d19d144baca8, 0x0bf015760770cbe8e6f69080f3aca182],
+    [0x3a918530976ff118d08764cd42e0b240, 0x06cb21aac0be8dc2528c3974724b38d7],
+    [0xa0588ede807db1bd583c3d6ae6941356, 0x1276fe2c751ba49fd5b75cab5b71f64a],
+    [0xf9296ea57374806c39896fe9499b98cf, 0x2a17fd8041ecd762f528d12067a09204],
+    [0x259058f7d68b9d7d6da1a8f9f5caf674, 0x1904e2bf9c0512c72559e71e3f132f65],
+    [0x04c601b5d8d4656a138212d66eb89d38, 0x115b5eb99233a8a2200170733c4743b8],
+    [0xbc16d680f888a3d92f31a3bf213e7ef8, 0x006b58885f0e09be6ba91176fc8a6172],
+    [0x74d50beb7a5b6be30af0e46fbd7325ce, 0x13c6704e90535352934dcdc0483b4bb9],
+    [0x43732feccc872975b8eb73e3fb4d54a5, 0x0fbbcb96ccaf43c773ba1ce706138925],
+    [0x6c338fcc38dbf9e9c46041918bdd8bfc, 0x059b4df63dbd2bb66e0b2a826c5cbda9],
+    [0x3a694623a89bbe57a6c8e7ee54be21fe, 0x27d859e7868c99d80f99d181e6ef7ea9],
+    [0x98e9ca50d6cd1dd49b84fdc0646ed68f, 0x21eaced08f9d0b42c6fa51f59d65f965],
+    [0x3363f38d0af5c0ff8abeaa71a323a2a7, 0x06815b7a3b80971d05fde6d7dc5cd282],
+    [0x51217b6005248e0e716bd5a10d558c6f, 0x06a778d29aabebb5a3cd940b1c33f055],
+    [0x62b60ef3add0f3a22c9fe7a4918e94c7, 0x14fe9fb1f167c6257ea010088115769e],
+    [0x8fd85843e7d2627db607b2816e357dc1, 0x090305dff314174e27edebe574bd8f0c],
+    [0xfb0497555cfd3dfaf3888aa492d20445, 0x2ce23dfbeb8e1874b9ba894cb85f068b],
+    [0xcb2c7495a708247f5763c2380e5c1cd5, 0x1b0fd94dd9cfe774d9146be584ed0b25],
+    [0x74619d1e0cd91bf4789a1aadb3e612aa, 0x060bb716ba723a4e5f5faa179363a367],
+    [0xf54da5a93b701cba4d6822f9c9dcf436, 0x043b10c508e8681843eddc05e73b95b7],
+    [0x890e304a430e6c9f4fd60cb8ad69950d, 0x126734e9502ba9fae4a314b67ade6ce8],
+    [0x317a25a688f9743ef0dd9cc2619db7e7, 0x26edd35db97c2ab2ce27b503592859b4],
+    [0xa95a4beb6d5c9abc5e03ba906ed72062, 0x27b225e4a981af36cea863f591c8bc5a],
+    [0xbd511b51d6121b3bfa60f47db12518da, 0x1102728832f751c313ac6610fae58b9c],
+    [0xc7c9079c8c9920fa389b116620ee4141, 0x18276cb011bdc6d75eb1c7d5d06cce10],
+    [0xc64f835dfe143cee18480c7c3fcada76, 0x2502d7dece8cd7d7a04a1730bfd2868f],
+    [0xbbee1ae48285ba2c3d99c14e62581a2c, 0x06b20beaa575dd846254695068484611],
+    [0xd17aceae994e2c27a1ba876139f8ec1a, 0x063ab4a078fcb212275ff5fd06042bdb],
+    [0x0b07ad4ab4124dd4066a6cf66f406993, 0x06372d5952bb69a460bf1333ef4ac6b2],
+    [0x41f52a5a174f7abec522476e3c623ce0, 0x0f41d4e3ef55da71cf4037c49d44c0a6],
+    [0x6b0ebcb7dafbe2b802bb8165c6011773, 0x0f0ee9841b21b9b223c1d1a906993bd6],
+    [0x8004a6a5f140a2b18ed658068167521a, 0x01df2eb2fa423f01396e073390104cad],
+    [0x22e403d38474cfa90f5ed30c8d30198c, 0x1ce05bc55407c883a5a41792ef3486ff],
+    [0x67254e5df2b5b62caed0500a2594ddad, 0x037f1fc7934bbd5fdc34388bfbd0f954],
+    [0xf6de06c819fedea24aed16e4c47164e7, 0x1bd6a614004398f329b7080ffe69d3df],
+    [0xc9f1a3bd8917e2e1cf5aa334d29d2a06, 0x0fc2e5cff7e9efd46fee62aa3b3f22bc],
+    [0xed2f2eb4627de75781a813f106a377e7, 0x274fa4e3d86e7ef8b0fdf1a81acce8f9],
+    [0x35a22b5aff46ea2f803a29421aa950d6, 0x193af1c5fa057ce682ec728cea907359],
+    [0xf75444fc4e14988b42268f0787e29875, 0x0e72c33ed6055471adad61d027c5ac1b],
+    [0x1a7e517dfc602c9a2443c59f32ee9614, 0x1c75804a4ef1c81c83684d688c4429ab],
+    [0x4e423437008cabad2e50110456c8c60d, 0x08803f69d83e0c3397e09dd47a80cf9f],
+    [0xfbf47426044cb22db538478e942b07e6, 0x2d1214ed17bf81162a5dd43a3fdcf195],
+    [0x1e3a5f0a06103c2486ee6a3eefac7288, 0x0c85b24b79ab57504422135fb1cb0c5e],
+    [0x0202f0eb8267ef3f71ccec40e91d95e6, 0x2e29f517dd1b30b791bfab03ce747f95],
+    [0x503509efd74c1ed993bc6afaf3d64174, 0x13281c94a670fe5ab09773af42606740],
+    [0x1e1d58c9e98f381a7eb48f7bda0f1e05, 0x21a211407cef7b0442160acc9dbfce1a],
+    [0x8b3632e1e1a1ccd4ecb01602759e087e, 0x085ef1e77f929bcffad1a93f920c1d8c],
+    [0x4b504170c59a8ec4581e9757d74ba759, 0x2b6f796de3509ea703c8da6276e3ea15],
+    [0x230b9a1c1e4d08a8069fc8d45b1de3cd, 0x1fda5074fa5bae5e16fb4f2d18d589a7],
+    [0x82c372dfde4861ef86ef15c3155dcf14, 0x02d22a3c0d324750d3be31219af32a82],
+    [0x29f3239c4d24aef92ea79bfac9c2fa25, 0x0c21f4f4bdae07a79bb3a98cfb351aa6],
+    [0x2ff4297aabb6f58100b5dab06d3dc404, 0x1b721ec1c1df81c83acd7a95ace16b3e],
+    [0x5249c5aef78758c7f511cf87b953561f, 0x27ec9e9e923edee58189d6c7f303ee3e],
+    [0xb9620090a160d5a760a75d12d5d72e78, 0x1ecf1060366dea9506fda0d01cba016d],
+    [0x575034da26392760d17acc80c39506cd, 0x25d432df312e005c69a06a1f004ec899],
+    [0xbc6f5d5bc0e4957e1c0dfe4b202e4a24, 0x17a0ea51f9a201ba2a0f857c9969a7f6],
+    [0x23ab01127806ff6c922d8763f142024b, 0x02d34c47b847d67c9b4dc313bc714ff2],
+    [0x94e7ccd5f9c79839282ebd9e119f96ca, 0x020e59e5e5bb0951a660122fc1aefea2],
+    [0x0121c3d9990dfe2e917377467759267b, 0x249c9c850173dba0ffea3568c2841602],
+    [0x775869ef093de92014cdf85ee2d459da, 0x01e8ec57b7681d631a02d530c9aeef93],
+    [0x98bb6cba3629bb6c60e423b7eb6b5ffc, 0x00f739dcb9f3ee6e7b5519774b540b28],
+    [0x6e5c26aa7996edf504e4167285fda66e, 0x0e016f0724fff85d781aeb1548d76c56],
+    [0x68c844947ed5ed94b3180cb874c68325, 0x275270828b9ddc964bb8d68a62585435],
+    [0x64cc339de13e04c74fce820f4f93e48d, 0x00f1ca3fc28aacb8ac21a5b44bbeb0ae],
+    [0x664ffe01811cb777f90376719c0e20f3, 0x216b2f89c90105f570e0911bd1ce20e0],
+    [0x61ec41fb061f1b6002f72265694c784e, 0x1a91edf90950cb2689d2c29cb22f8dea],
+    [0xe53df55bed5f5d50d67d9138cd78fe84, 0x2da3342eaf39a2b242713484462971af],
+    [0x2afaad4865cb9090848249158e2ea4b1, 0x29f79c86f5a9c96fcaf2296be36425b7],
+    [0x454b46539c9499c65f6195b55c760337, 0x0519426a15a599f20d2979302067dbd1],
+    [0x9eacd3ab340210a9c5e0de6fc082e89d, 0x0f9c475cd72b7e2e3865e96baef89a14],
+    [0xdb3d27594985890b88c9bf276922173a, 0x15f1d711979dfe13e45eeb9759a89f51],
+    [0x48f60c9569e7989941c3f50fd6acf760, 0x1f083ac0549a1ab00d4bc9066f054941],
+    [0xaa082b8d452c63b17ac3a591451b747e, 0x1505ee4f79bc8187856d5f3f5a24de97],
+    [0xea28447df529eb41342948fc2d8f096b, 0x0f20876d712f147e2fb9f7be6a51f346],
+    [0x1ea93af2673715a2df7731db91e360dc, 0x1696d12d9ae779c92120ee715f6b8da1],
+    [0x7fb4006bd7f5bb15a267e7746b37bad1, 0x28485f438eec133b0be36b32fae82da5],
+    [0xa13386e0e6a5309b050ca819e10ad945, 0x2780436f9da12461fbe31d979656e6d1],
+    [0x37ea8318ea5b32379583f611e06b3da6, 0x271ea863673332df2bf2471e6c8935b0],
+    [0xbe68391ca540f68b484a4f8e96a9dc61, 0x29f1ba4b29c1daecb9839bdc08431bca],
+    [0x6f4d3000f1a559bf4c449103306031e3, 0x294f4cc728dd423fdaf37377e069aba6],
+    [0xefd87ddeb1bfc5e5d17ca951f55abd17, 0x146b521b1c08c9181213ebdbc2a34fc3],
+    [0xeed5eb664b84ef67eaa255fe4e55f3e2, 0x0dae0af973ea0b0fcdd8158ea51b9efd],
+    [0x131dddf2697853276c079ebfb883d8c2, 0x2604cccc370f0a50f3f726edf1242bcc],
+    [0xade5e7b2e6833e4d5ce4661186bbbb9f, 0x2873b19db1d03896d3d52d6f9361e3fd],
+    [0x5f33a6de205dfdf0e3d099d4a8394e08, 0x0b04d090bcc4cfabfa47f60043859aeb],
+    [0xf6917318b8ed6ebb7347b77d72dcf748, 0x0508a195c0ca583ec10156f205ca1bb6],
+    [0x3808c53fc05dce3d71f17c0e3f2a5eeb, 0x09262dfa80aaa0bc61c5891c237a519d],
+    [0x2ab2fde708d30923669a49fe25b3c7dc, 0x0ce6d25b1c6c1b89fa005c23619d877f],
+    [0xd7216b2c5e76108136d6895ded3ddc44, 0x2e69685b46c8050268dbc1040e23c739],
+    [0x7b8ab6e062a5ddeadfdddd5b305ff822, 0x0278fb3df3fe913657bccceb553138fa],
+    [0xa9dc1639d9b6de65e479165ca49f7a4e, 0x16da892ade35754e57d8f03f3e6f4110],
+    [0x9927a3c26dc43e661e09a1261bd7c94a, 0x159754924e7334854ff803a1782ffc05],
+    [0xb1a16e341e6c593d7fabadada3929d7a, 0x2212ca6487b845ba4d16c09e224297d4],
+    [0x5dee0b73e18fca91cdcef9b90396a047, 0x2dd088de831852d169aee1d6be69b52c],
+    [0x15deecf421b9753032533e28491db30f, 0x24ab42ad6749a14fe2f707ec53ac3b03],
+    [0x06e2e97d3551887af808d67e1ce9ae27, 0x2f6466e50ef3c61a0e6493b18d349114],
+    [0xeb6e462b8f5e5349f85631f059594480, 0x1f5886fb8618c8711ec08de9477571f0],
+    [0x5d6a523110437cea9c2b94453dcdf2a0, 0x05ba34953959784a57c0019e916e2ade],
+    [0xd86bc06c776f145048bb2401dcf17341, 0x27aee67a3c6ef783cb55915165a5bbfd],
+    [0x6eefbd5c71710a56f460682a6ba5d5ef, 0x0f57efc05e9f97e7f68c694ba2b3bf21],
+    [0xd7336dcb72f15b182a6702ca53b94eef, 0x1d35eb4a40a41dd77506d872a458ac93],
+    [0xa77918255c2dffbefc537e40bd5951ff, 0x18a33064586d5a702bc4bf64f70b44b5],
+    [0x0a4a674550b7bd036207e006ac7a2d21, 0x0b0ae5716e42b440b7423bd899319c88],
+    [0x27b140b36d3594c1cdc1b0e8707b62f1, 0x1136a0f6c004a2c57d21a00c40dec6f0],
+    [0x591977d50b677b8ff10b0a3d028532ac, 0x21cec52ac4b2aa7f80f98eb75b4e0280],
+    [0x440cf0c586f1b089cc45e8b830fad22e, 0x284a636786fa63579b1541746901d48e],
+    [0x76748b0833cba6bbfcac5243e4bcb4da, 0x1d4a0aba0cac51c3261f7c483d89628d],
+    [0xc8193a10c48b5d84fb5e9cf2123ae24f, 0x0410a065925e90fc70ed1aaa46477a8a],
+    [0x520637fe751ff7b769409d512fc92f0f, 0x301453b23d055c184a65ad6a6acffd9f],
+    [0x2c46c4ea224f4e0dc0c3bb541f053b79, 0x037827104287d94bbdc06584d36d4d5e],
+    [0xdc2c3f2e8032f864295a64b08a93d993, 0x294641ba8a02768c5e3b7f542d281c69],
+    [0x8738b336bbe6f976f5f7a0a178ffeab0, 0x1c12f993f0f1edd809eeefae6932e5b8],
+    [0xfd912e0a627eee756b8d22af8b9e5e43, 0x0cb2d948d38fb8e17c60ba6c67608c9f],
+    [0x9fd5a4b70d60b553904f5e2d05e894c5, 0x26f1ff731268d9bbd26ab33e588acec0],
+    [0x1174d8b8b89c59707c123473120b4c7d, 0x09aef8a2029f82b76d80ad8a3926cb6e],
+    [0xc6b0046c327bcbf3a36e26406c775c74, 0x1be80cab12f4bd1737796bc770f17073],
+    [0x0dad89762576cbdd918428a75828836a, 0x2476e7bc637f243d23d762cb3e2cd843],
+    [0xd1c9e577354d930be0d5b6d03617c209, 0x196d707ca5a9edcc0e3103aedfc60abd],
+    [0xb8c0faccc056eddd8aa87d0a66b07d24, 0x1d262487237e8503908d5375a1a798a9],
+    [0x3d40bc01d027c2b4841fb23dc9ff715b, 0x2a21e03d41154bf3191f1a834b9af6b2],
+    [0xcbb3ad730deb6d2e6618fbf9e74daad9, 0x029c647822a1ae565b2bf1249edaeba0],
+    [0x3f708774c286e4ed2f8dfaedaf3db5e3, 0x23e1971175b8fbb26c0989dea82d8f4a],
+    [0xbb34f175e01c92457959ee1c6da0ff3d, 0x1306188b792368ebda1f2e31e846854c],
+    [0x521645fffa86b0c221d9223fa0e9d2b2, 0x127d0f7eb9804fc20ab4f8b9409834ae],
+    [0xf0ba42441e450fc65e3519a0393b1196, 0x2441985bcace35a81b1f81fc6621215d],
+    [0x3f09f639253e9e3549d5987b0e097136, 0x2df7d4eb115114544ac4f52fca36d6b3],
+    [0xef9aa3c2164c317aca92203e3b99f940, 0x0f6c5518f92ee2e549d31adbb4e78036],
+    [0xa7f2f5ea86ac094e5d5a22fc3433dcdc, 0x26e2cc9dea47dc6807f87cd87c531b17],
+    [0x5771d1601a0e04d399cb2397064c5cf8, 0x1c71e33c864b0a8de4d10dc26f76bed5],
+    [0xff2b207a1981c05b1f396178f01207af, 0x2a2f0b563b4083766c8b6c60872c32ee],
+    [0xaf74ebb979535e0b343676dc9f9433ca, 0x0c1af0bc5546250a7fddf1cbd3e7bcdb],
+    [0x32120499c4c972a6d43b92ce167ddfe5, 0x018bdf2e7078dc40af1cbe488cb562fd],
+    [0x1f779660a1f9a25ef652019f640bef06, 0x22b36859a16a8cdc3df10af282071589],
+    [0x4d5e48ab66d635b41e7b96fbc7c6289e, 0x10e697dbbc43c7ae2030f5d49974f834],
+    [0x207a0ad79423920622b0c4a12fef3552, 0x057deb4fe56390abbbdcd8822cab5da1],
+    [0x37682ee7eb1632e791b4628aff4aac23, 0x12b27c1639f162f846fb37ae7144f291],
+    [0xb6f3559d66c3bbf3d115dc9d70db5af2, 0x0b2c7c76d01843a2d14655c23e8091af],
+    [0xe83abe72a3679b3678a8c9b1a13a4895, 0x0b7d9684c0c8eca0e8c31634c9fb68b2],
+    [0xd2d7f7b2f8e60e261cef29cd916e9a2b, 0x13ab0a7fea9b2c828766d2fabf574248],
+    [0x53a5c37d5391e92a6a5615c9d60be979, 0x2de3579d790ac367f66d90557b546681],
+    [0x1642c6a6735d89c9808fab7bada21f6e, 0x1ba010b6d63b9538b281be3764e8e623],
+    [0x12b1a7f100c5902584e4dcbe1f2119ac, 0x26ffdea8a8d9894ebbe02a705fb7506e],
+    [0xcb01b951a734218b3cbda4e8052ccba2, 0x11ff1619a49d6abbb56390e3dbaaf0c1],
+    [0xb3f6b11ded47aae2c0d5e0c48d8e5174, 0x260c36b80890befae89cbb3478b9f7b5],
+    [0xf7f0c3262131cef29948a1a49fd184fb, 0x05ca88857cb0dd5f380e592aa37f6f94],
+    [0xec19046b3b9eb7fb277a48ee8390e485, 0x18b3832a19eb7ee1a9dcf574302ee063],
+    [0x30188fda58be783067698a1739f79369, 0x2535291f03dad1e3c540e7f26201c2e3],
+    [0x50083cf87f0ba86b95053e9182d1373e, 0x014f832b38a9f43af26e5ec2fabf6c42],
+    [0xe89ea08a4232fa0a223fde869bfd3ea4, 0x2548e6c4767c67691af55162d9ce0e45],
+    [0x1ce1425039f44781a7696f48d29dfbd1, 0x023ecdaa3f942bd45ba46f528cbc81a5],
+    [0x22ec16845472abd68570ac771c8f15ee, 0x1611bb59a3b28756b840db3189a3a5ea],
+    [0xb72d0ca9d8a7e633048c11bf2c46c7ad, 0x0bf7618845013330ff465f12ca95079a],
+    [0x75662ffa438144352d684717cdcd3ccd, 0x15c88fa2eba3d087fd29f6de6fd864b8],
+    [0x887d858656b1f6af4010c80091b0c662, 0x0d58d12798ab552f96f0d951e97cbeef],
+    [0x445e4f82f4a241efb8bab06eaf9c7cbc, 0x0406eecd7bbc21eb04fa1e565745cc65],
+    [0xd6f48d02b2415ec61ed209ad8ddc3164, 0x2fa88eda065714b9ae5e18456130e31b],
+    [0x3fac53df6b04022c7cb7f17638f51c29, 0x01749863aeab13ca512261103eab93b4],
+    [0xb72f5c32ad56e900abf47672f86f9a62, 0x01553263a318edf34429d901f3879932],
+    [0xc03fca9c6a58d3df6f234dfe82f96aca, 0x028b7a8be47c2239079a01dd6d0e57dd],
+    [0x6f34b289c2e8adc76ecbdbb5105fc1c9, 0x2c489bef0fac19bb187e5f8a064e6763],
+    [0x99c9914cf4842e793fd5b5194bd0c3dd, 0x1ff548c6690f5227d576668fef6741f9],
+    [0xafc5f0efdeb7a8feb27547724727a344, 0x03c1b551c84903040faf3d6db934e83d],
+    [0x9bcef2c8d8f6f266e04b7b80ac8cf867, 0x1ee3620e8f0dda8c6f155ce81a937600],
+    [0x0f22147e14421f336fe6e0bcbb8a0014, 0x18e45fe7ea171fb11d0c4af533856899],
+    [0x0c18682814882f45aadd131b9f08753d, 0x27393404521a55e0e1a4e58c56118004],
+    [0xcefff729940e346ab18f9d2600f83d05, 0x07ff6857011a24b20d698ad43a5f959a],
+    [0xaf9d022a604ffad61fe0077d76748bfe, 0x2981b3a288b4327839542c5796ce2f09],
+    [0xfd3587011fa586cfe02ce800f3064033, 0x00dd5b0b8158e8a73995efd0b9d898d4],
+    [0x8337c6874b87b88ec0b45a55d393623d, 0x084c05fae73cbd6432e622cf9880f0d2],
+    [0x8e5ec6319fa6d8997dc115e5de2ea8b1, 0x1e2ee2fee9b9bf8d3f3722e7028ff3af],
+    [0x971050939e99ee371f6412e44e0ef577, 0x0003418fc5909b62b0296e6eb42aa07a],
+    [0xaab74803f67c12c64e65aaf7f1ef56b2, 0x1c4994a2d41258064376be5cf3c99887],
+    [0xfebcd77952b8806ff583e8c40b31032e, 0x0413c2b38733961feda0a68493d3cf44],
+    [0x66504f81ae9534bdd091ea98994997f1, 0x01c46ad39455255525b267b8a0533c5c],
+    [0x18c9458b75b0ba3b7d677add820be289, 0x232fa2c090ff3ddea83adca0b9cd0e09],
+    [0xcfccb6c87ccf9aece35abab37cf05fba, 0x1cd5701c80a10f420eb34f534738c0dc],
+    [0xb816118aae94bf8518eb07a529e4ea54, 0x03b981fe0c71882c6194465537d5e402],
+    [0xf0ac451d1e0bfd5a6c6a3aee85723bea, 0x168a588900100006443658a61ca9d659],
+    [0x9ab06e3580d46f318de84edc795e6f88, 0x1e51f558bced240c65b7cebcdcbbdb7a],
+    [0xb7cbc3ee1f8e9a3bccd6d870022b750c, 0x1afa5a8c5fce396de56ec97dcf0317a6],
+    [0xebf7900d90d66dd5fa816054d4017dca, 0x1135efd63e8f8cc9cc0661dd49d36ac8],
+    [0x9c8755015641db03c54df337103440ad, 0x2914d21c2d2845ce2b14e951f4210359],
+    [0x52e711e39eaf5a8b07100a197127eff6, 0x20c87a2d759749aea9b2f0f8cc014524],
+    [0x0d511b869db67dee0408785ed922905d, 0x0db07920dd779c19fc1ea8ec5323d5af],
+    [0xee276378cd2e46baba90d56468cc50b6, 0x0d631f18fadaccbfdd3b3be46772a3bc],
+    [0x6e7d7c49bce18395b3014c9fc72d005a, 0x2c500f790d463fb136f2d8d4cade6229],
+    [0xb1a27376a16dec7317aae4afee02bfb4, 0x176a9eba97635d1e59b19faa2b3325a5],
+    [0xbaeb3b54e37d1cc80f3d6ec0e09d4747, 0x1202fce1a501b88b455ca44788d55214],
+    [0x6b825decb95096c9aedfa8e6453a5cbc, 0x222948377ee9d27fc6406007e1727b56],
+    [0x10f3c6e12456269d57ea9095f41166eb, 0x2ec66fc27d82aab2c98eb50e67a09322],
+    [0xc0844fec3d0bf5a9b968ca58c45846a3, 0x2f5c233dbe3571836fdac0915ce6596f],
+    [0x0e6f272426103adcefdef4dbd2865da2, 0x13889bb59b9c07953b52f66123c8c138],
+    [0xf1e55a3da349cc8dcc6dca81cf99ac3a, 0x1cf78f25faa52c747312d09fb4e9abb4],
+    [0xc8d7f36af6a9c6fe07ec89219f1cc735, 0x2c0eae2866d65c97077047d0f4c43c28],
+    [0xe06ff1c690b81b918c7ddd08a6b7066a, 0x28b816cd8b8c98163380066d0a50d8b0],
+    [0xfdaeee189adee381abfb75ede1488cfa, 0x1d46d91669ce5be6554fc3ecee895d41],
+    [0xec8599db6b0f7222dca26cd43d22bb05, 0x1841e03b9fc527450e4470aa8f0888d9],
+    [0x55744b49a73bbef04c899313bafa0f57, 0x2d2ead7b2078b09085805c2777c90f6c],
+    [0x38c55d44daae47b14fc888e5bf745095, 0x01830a8db342676cf7492281b4cfc7b1],
+    [0x18fe443fb708738a860f1910bdfa4678, 0x074e4ce32bd14b01fe9f6729de2a12f2],
+    [0x494d239af679a030e7d1b7c9cffa949d, 0x1fad4cb140fa4d559cf9525fb24e7f17],
+    [0xa5bd6c0efcb225260224556d76a28331, 0x1e50c6526c7324b97b7f338752bd700c],
+    [0x93f5d1112fe898e8af0c61e79e673e5b, 0x02cf0359b8da1abffffd73f907586457],
+    [0x857bb2fd1f27d15c3cbc8ce3df9895cd, 0x0a14ae497b49785ae7771f0dfef4bf41],
+    [0xc4bd26cd2792177b276068916954cd41, 0x16bfa02f57735662eac1c1f520b4b3f9],
+    [0x9afd9cdd4a90e13629eeabfd84525e9b, 0x0f6fa95e0117b734579f68dc59736c7f],
+    [0xdaf93f863c3213c1a849d1c3169ba771, 0x1d59cafe27bdfd78f564894300763436],
+    [0x08b13bbb93b3ade772a63bee300bbc3a, 0x289e39d6a2d4bc817ab104443b89b8a6],
+    [0xf5d6515af1a3a3512f59e9e3ebdcc331, 0x0d47edafd6a8d4651c27f6bee48e9756],
+    [0x994a10c4957262770cc4f59e6c6d332f, 0x198d6aa9072a06de13128d7b17157f85],
+    [0xe8065f7222e80cbbd3cf985d6aab0f05, 0x174b80e4210f9d81be7bf70e3ab0b21d],
+    [0x67f0d78a9c8965e1bbbfa356158532bf, 0x2d78036ba0948752ae78b1138b4b9c4b],
+    [0x1b75812c670543e390cb02e3087ce924, 0x0c1ff404599af823908f32e607564f52],
+    [0xf4940aa5b48f8c012a9c181d811b8f34, 0x0a561b578f4be4c533151da1a529bc98],
+    [0x6d3eb2e439bbc4f16f256adceb40bdec, 0x2012a6ed245e48cf65030289a1cd01d2],
+    [0xd82a9ffacb95f9eff9e018d5afb30baf, 0x0824422abb64b758f600c18e6b998c19],
+    [0x1c0bc8e68161a3a4e5e4a8a7e30964ad, 0x250a091bc91301bb1393782a7e21eea8],
+    [0xdeba3dec2e44accd3acfd7fae6d076b3, 0x1a4adbc8530eace4e19696d77a06e1ee],
+    [0xee12b1d298fce27ccd6787b5a86e26bb, 0x25a511f7b8595b12278f2b05b4ed6684],
+    [0xfa1903776636e38f7188d8c2b73c3a54, 0x07df25e8f76f32d99b99dd04d927c1b2],
+    [0x39dd66bcd6f0bd41bd124a929823f75c, 0x059421ced9bb92262f97e7c7beb81355],
+    [0x95b8cfe7c4c95b5956ed477a07813842, 0x26e1cdacde91e112796df70dd3a479b5],
+    [0xfe93a8e0fe21181cb1351d97fc0d954b, 0x298083deae9e0cc40a9b60e494e44e6e],
+    [0x6fc44387d515185f49a2443ad2a14ede, 0x0f01fc7bf4d2f1ec8cb1af8ff1771531],
+    [0xa78b8f32efbf0510d9fcc8fa7e73c5f0, 0x186049573b3286b17003f9e5b27b8a36],
+    [0x58086676b4d3912da39f2ccdc4a28d14, 0x07335829d8e3758cbbfc4cc49b655ec7],
+    [0x47df51f35dbbf4a99d34293fc3d59624, 0x019d072d6e66aa6e034843cc3d7ca51c],
+    [0xa05aae26d349a6c4af07e3458ab9be5d, 0x2bea340a223b6665f5be6dde2eb55b40],
+    [0x95f5256fabb16006c009bcade1eecda7, 0x1b90f5c0e53409f8b573056438bd9e63],
+    [0xd8b97603565f6314184776d865ba2e7e, 0x01932c3f3e1c4481420c603e23f16029],
+    [0x353552da362921074e023f14580b5e73, 0x12e4e9f31be336777527af3ad443884e],
+    [0xf12f2959a7656c0dcf35ad9a7926f3f1, 0x25e7a09a55698b0c7323472b2409654e],
+    [0x7686a34aed0fde1ddece6b1d08d422d4, 0x178c9238fb91fe512f6885e512e2a59b],
+    [0x948b515d28861f0dfaef24921e7e352c, 0x2b568832e6262628c31e405305f88402],
+    [0xd7311e0d15d2e48def85f9d207bd175b, 0x146ca0dbfb0fbb98c162eb84b37228cf],
+    [0x0268c83df7f50d44d29d366e1d7a9373, 0x0095371673ed3ee55892e373d5ad5c76],
+    [0x660bfcf6ea1c1c7ae51427fb298c9db4, 0x051c57cc37343989744272869cdc94c0],
+    [0xfe7a69fce1620f376f770e1325aa65df, 0x1c8bd96ca390714a2b0079ec38e25af0],
+    [0x475d6e6043a5c46eda3170839785072a, 0x1712f67e82f90446550d51c1d2e4ebb1],
+    [0x6c385a8864dad5b43aff09c963573925, 0x1c3363715dfea957de9614b6cdea1706],
+    [0xe5e1cae03719aed0fccfb7ff432fe916, 0x2672b8adef74adb0b71c0166de766a39],
+    [0x8734ffa59038d8b7136cb5d0c646e1aa, 0x10635771a37850c2417c96b7f5fa48a9],
+    [0x908cfe8a1fc24e5326461973d09bf0e7, 0x0b16fd5ba97f28f5619f6989cf0d665e],
+    [0xd262d4aafddfe34bf8db619c37a66068, 0x0027df27e1618278ed22497590175692],
+    [0xd196d25534b22667f50628769ae136ec, 0x036ab8de1d7df77301d16cb576a1346f],
+    [0x90cc00afdad5ba3d5081706df5e75e6c, 0x049ab0f37ec5bcc724a3ea321a91951f],
+    [0xf1e4a600c29631d059bc6ec343450c49, 0x112fe3f664849af9f494ccf3ccf0d4b8],
+    [0xfa56878c9aa054cc33064e2a5d30f567, 0x232b3e1d4715272d5f18a036c137f0c6],
+    [0x57224a09657ed82f7141e7619a5db545, 0x056c91dceace41ab131a1c59ce4a3208],
+    [0xba9b5d8e6e76b84ddae2ab086e7a7952, 0x144cd983cfa259516382148e290dab14],
+    [0xa32a39e918bb32bc8d45c28094a634af, 0x1d21aad5c6b407e6eee7d0b55aa92ce0],
+    [0xa8ee84a0d34339bef675d064e6e0a466, 0x176f60be5a21b98d3469e1339bd5c7a0],
+    [0xc26636f13262176d650a1a7a2044d34b, 0x0329d048658d0eefed7f3aaa9bc143b0],
+    [0xbd4a5a02d58081621711ddd2887c757b, 0x0fef28f114d6420d1a288f960fd9dd40],
+    [0x60c905c7202459cfad0ab47658276a81, 0x08818813ba3e9a9329254e78e00a83b3],
+    [0xf13a957bb4d0ba6a0d97cf1914b65b07, 0x020a128b7112994e52c4849bba8d1217],
+    [0x78585951ea4acbac245b61cf55a703af, 0x0980db3c7d2c8866e9ca0902c3b8e0a0],
+    [0x75cfa704c5267b848a2ff9a12bb188c4, 0x14f2bd6dc317fe19126b583da18e2d71],
+    [0x45061f2045361e757aa0c5ba7c5b3152, 0x11226fe9d81faa6c6a35d3a12230d632],
+    [0xdc45906b2e846e2703228e5d5328c1fb, 0x1fc144aa275cad950ee4e3182bb87783],
+    [0x61908dbfd1c91b7e9b991760c1d47749, 0x07858a3e15adfc14141090d972cdda34],
+    [0x9b31446eb3668c65d85ad094727d45e0, 0x19284489d7b53ed2549c5fc66331c71b],
+    [0xaf71b428e3ae90f9f5d2ec8e7140f5d8, 0x0587702c19b9737857f99cfd021f8fc6],
+    [0x6842ff3470b98e1cba2a1b3ad87539eb, 0x30602b296ce2450d72b00ee8cece9f89],
+    [0xbf7c13fa17d065a2d69d2987a718e78e, 0x2776dbec079a5034700cd9c6e21404ea],
+    [0x5d864aee05ad1886fb918d371ad29e90, 0x28bdd009549a97fd0c29e9c30f7af955],
+    [0x7f2be949aa6d061f886f21d0401aad14, 0x29791a2e17dc204afe645306f584ad76],
+    [0xffb0f18f00c0ba7292ec8e05470fbd76, 0x22a667b65908699a22edc1697324bd37],
+    [0x8f45b60bd036c2f94907d873e5d95ca2, 0x200d4863b2f35a853b7d5dcdc22bcffd],
+    [0x7dd9cdbecd5f841e87303e3a312e9e23, 0x18bdccaca11c8a10a12be6f7cc96d455],
+    [0xe8aff3543975235bf3510c7ab22d897c, 0x126ff502714b58c3a40ffa3d96df0de4],
+    [0x55b569b4a58891a07f96c500c28bbce2, 0x0570fa4d1c92cf2917036fb2d477727e],
+    [0x514dcf49b40fc4d301a17ce5d7228feb, 0x08c84000db8f725655f47432db3a4dde],
+    [0xc704b22c898b20ebbf3ac9f1bf513038, 0x07212da8afb35376a4b9f93c20f7419e],
+    [0xc8af6a059390353bee16d8c8dba971e0, 0x28c6bd8667a7e5336ebb7e53c724990f],
+    [0x848a8f5c69b3cd7a7b63a1b1e6d4c926, 0x01790e7a8c59ebbdce18ea7b9be261b8],
+    [0x3c8a0d286e93411fc66588a70a14a4fe, 0x09ce10751c2b7b90bce0d62e8fcb29fd],
+    [0xcda66c9901fbb0bc27b8d773de7f665f, 0x203ec579417ebd5f056e1346a8c5be34],
+    [0x76ecf2b693e33913b8fcffe65f57f4cf, 0x2297981100bfd0bdbc495572aae5ed56],
+    [0x75d343d15c0772a67ce88bb1736db3e5, 0x00e3fff36bb554a017f94e88130957a9],
+    [0xaeee92226b6f13e2c4678689ee1304ba, 0x22214f9dc31c1766784846260971d051],
+    [0x62dbf8bcd8b3b37218f4199281263327, 0x1eeccaaca23a1446309ca4c3d9b7d079],
+    [0x9bc83eb74256efa17edc1abad196bf63, 0x1bd52cf0abbd32e888d402aac0a35830],
+    [0x012345c68750009745735edfc4e29d71, 0x26cd8ce2ace79114e2ee96fc1ca3801d],
+    [0xff3ad7af6a187ef0e90635008406dd02, 0x1dd921013e6519e29b9aa0f52cc8c02f],
+    [0x921f030a6926a00d57236283ea0c0ac2, 0x10358e1cb8ac12e54988a64bee4f1e94],
+    [0x7ebd1d70431c30b1c0c0df81fc0408c3, 0x1ca1168b167aa5298bff96d82356b738],
+    [0x061578a2dfc37997222c81ef301fa942, 0x08d1ab2692d291aaef2e50985c869485],
+    [0xf6fc39e0f9ead60507cb24c14444899c, 0x2d59780dd0a00a869516c6a0b300ed06],
+    [0x70f8ab8abd6989993d489a9b17180cb2, 0x19b30f06355690afa779693f7f99c4a4],
+    [0x6aa1279be2572c1a59e65345d873768d, 0x1b93c9367d56b207bb1a2f171b56208c],
+    [0xcaeef004d956142347a08467e1961809, 0x2f8b45e1e079448ade818bcb785b0c8b],
+    [0x69de99399cef819dcc37b4609b6cb4e0, 0x239af691b7d6edf2761f88deeaec6651],
+    [0xfed3a36f36c4d397072de293d34cf8b3, 0x1260bd299d9e99321561090559b3f90a],
+];
+
+/// MDS matrix for width 8, row by row.
+const MDS_8: [[u128; 2]; 64] = [
+    [0x70944f929fc178216ad847d4430f3f27, 0x1aa4d470abeaa2357079129d114bab11],
+    [0x0be61d7d3db1d9754feca30879adc644, 0x0e1f4e4d26e9da5f8ee3deaf48b49361],
+    [0x00385aafd8ea41d9746729964ef75f34, 0x0c298c14356a5a7f226c5a549e591849],
+    [0x5e528b676706882eb8f1afe1c5045ea1, 0x19e070982d3ee675e1622ca20d13b237],
+    [0x5a20a72e69e10863a19d17b7beea9295, 0x0333e2923992e89dee570a650125eda7],
+    [0x510d5f07439a1b6ff41f652990be0139, 0x1944aac72bf6fbcdec04361238c3c4d9],
+    [0x96a730605890401319661675736359f3, 0x27d802ad019a42fd04892ad167d9e835],
+    [0xa2c527a970ad2815b273471eb9623e73, 0x2bd05a040a0987ac6bc01f67242d54b7],
+    [0x6b35952d56c62678c148e73cdbad3718, 0x254b8126f45243f14dd8211df41fe551],
+    [0x00e294c9e33000e513b2a216491d474a, 0x2089c597feaa4ca9a202edfbc8237631],
+    [0x90c253c0f7f99ecef88786fefe0e5cc3, 0x0ddeaa906cb65f3e9c9bea6344dda8db],
+    [0xf3b75ab183209235396ce83d8ee9916f, 0x1114c9120442750493d999d4dd45dbd4],
+    [0x0ee467863f6efcb355fa1534ef70783f, 0x2e8b35a8c29e639dde8dfd49b4b347f0],
+    [0x7f8859ee20aad08320d0a4f9bbfe8512, 0x20e6cc613ae6cb0a518425f53db1298a],
+    [0x5f137b9cc320d5d5daa9df3f3d43d3e6, 0x2420dd1281fc0dfac176a752d15906b5],
+    [0x1c1869428c13b1e397bb0099191723a3, 0x17bd4f2726748d99925af9a2f2929e23],
+    [0x2fb70ffab31d8a4d8a1934be68792369, 0x270712f18b1518fafe093660f492cdbc],
+    [0x5b2bbf272ca635d8d3e8801099bb97d0, 0x107cfefa1e864045a668396526ed9f67],
+    [0x4a630d29dff28b628fc98ef708230b57, 0x04b96a296a35acf415929b8784b6bba6],
+    [0x12a2d062c34dbef92de46955b83e7bd9, 0x1f0465a9c133a1c6805d705be6b3ea99],
+    [0x71ab7af0801f1bdd23eaad91f2229a13, 0x27a2b7f5549e5eedb90105c0f8e20f05],
+    [0x48591cc67905d651dbcafce94b14d073, 0x031b0afb7ab2f1d241f882178f7dcdad],
+    [0xe3bf10e84dfc71fe395089ac197af744, 0x22e0323bbbdace5fbc74a0b3dedea6e1],
+    [0x44e33a2d5bfb82f05bef9900c54e745a, 0x1eef36d62f3f864eae67c63324e47a63],
+    [0x4e6fe75598ee964fc85cf30d50df584b, 0x2bfc3981a249aeaaec4ab9001727d32d],
+    [0x67dbee99a63c528544ea31b27cde49fb, 0x021630ec3f236ed219f73cc617b9f8b2],
+    [0x4c053c97930fbb6c52358b0d7d104c16, 0x28890a03199b727fede5ed0090ed6949],
+    [0x4970455a1beaed7a6fa308dac75fb582, 0x1b80f3f439396c2f466b8e9b25765a01],
+    [0xff297b8f1d1d9098fe9c518c261a1b0a, 0x2a4bbad245a33e5d9701537f751410e4],
+    [0xc352c2a215a870d739617f4b017c6d16, 0x1e431f2b1b7edae4f8cbb5d307bf5131],
+    [0xfde1e3a666b35d8e6ec601d4d0b22689, 0x255639889a259ebe59f74a8fdf2efce7],
+    [0x295829fc59bf2a89f13b4ead0eb056a8, 0x2d0d8a814cfa16c2323f174be2dcbe0c],
+    [0x78d582ea050014843101485f65ab723d, 0x16060a17e1b11f851ceab20464572b3d],
+    [0x9c961cc1e9659a458af74cbee16d71bf, 0x2741203d66c523c7639ea713b2034611],
+    [0x168fe62ec0497afc6bdd2938c12d9a23, 0x2208c5004806c7aaf58314590bcbea16],
+    [0xb9d3eb4cee366674b07bdd194ff349fd, 0x1c01f6db435dc4885eef9cdfed193250],
+    [0xa0b3186916a08cfa306f6bb36d21e548, 0x1f0e36c2cf9ac2c4388e74966d9ee7d1],
+    [0xb3b918e5b3e07c2cd8141333934e178f, 0x199fc2be60e49e679ec2ede6cf3345dc],
+    [0x9bd971a25a656baefbdbdfa3628e678f, 0x0a38d163b8a2fa3092999c1797b1af90],
+    [0xd5b509c2b670afce400aba1aaa9ed51f, 0x198949eef2b27c2b9c3d699e3fe57da3],
+    [0x2a0b16ac1cb43646bc1c726f83177770, 0x00e73217a106c402e4c6c166cd9ef1fd],
+    [0x12bd4c01559fff83217129082e2b2b20, 0x17b2092b0f634576b654c6de21a1a8d8],
+    [0x02c42d8bf904fb656202736d97b2eb69, 0x2f24d609cb23720eda55fae29ec6f199],
+    [0xe582a80b656af5c58763e7024ea4189c, 0x2df183fe2bc9b8b75773fa961c8d4117],
+    [0xdb82e63528afa4d4703e9881eda118d1, 0x15fe963f5bd0d92878c10a894e118018],
+    [0x126ad72a7af0fff344eee82a31ce77ff, 0x0af594dc4030e8509701fbd3d8995f73],
+    [0x7a436438603af1b28d4e4ee379960ce7, 0x0b8e857d9fb1d554f3a4ec307ad907de],
+    [0x19d9e0e08241e21789696cb76ee06297, 0x07ebcd25a10e56693a28155fcdaf7b35],
+    [0x7ab676f740b02331637ea1434c8545ec, 0x04b959ca07e7182ca08f2351a03545bd],
+    [0x80d3ba15bbfdd1226effa3fd6bd47b3c, 0x068e8e63411df2b3a9d7c567f66419a5],
+    [0xae5d2ab3d1c1a18150754ab4b836afd3, 0x0344d0c772c2f053664a7b9266b19d48],
+    [0x8f4c51293927562acea8eab817c6b84b, 0x07e0cca1a35ed15fbdb619bfaecc7a9a],
+    [0xe8e6d6fad341d7ff50748dd739749df6, 0x222edf3e26bf74b2f503983189201d4e],
+    [0xd23cd12f844f72f7548d49e55055a601, 0x2d4308b4823b465db653d36818181209],
+    [0x5afd7300a4c370987c83c15cf73cae3c, 0x2e8e67fc85f9b4d00e9e1197b35bedf5],
+    [0x4cc262318e17ad21d45c552477f53e61, 0x22f1bc4ec138b596128ce5ee5b818496],
+    [0xcc38f314071e50e0f3bc9ec674a8aacc, 0x0fc9cc95222f92715cf7476010225e14],
+    [0x82c68dcf1e209167a45ae9cf8570fed2, 0x2f5a2a0cd0cb24896c94878a8106d57e],
+    [0x8935da19cfc563e7048d6bd1970a0c8e, 0x2cd19b5c55bab1809e52cc9fc1a038c0],
+    [0xa5ea7bddbc127d431547c1f6a8f5cedd, 0x24ff2986781fe40ae61e8f5ca0ef17c5],
+    [0x6ace00963d987f2a1008913d98a27114, 0x12a87d0c5419f448419840e5e5a92d27],
+    [0x833d642f46c6493ca030ef595061ec6c, 0x0af3ceff2c865a901b9406d5d9b2fc58],
+    [0x79f097aaecce55bba0b0fdfd5b190aa7, 0x27f42d86842f4d8a8c3aff2f47ffc1e1],
+    [0x1216ab3b639945dd92a984943241f1d0, 0x2b6b3bc340d4119e57831e4a2187013f],
+];
+
+/// Round constants for width 9, 71 rounds of 9 elements each.
+const ROUND_CONSTANTS_9: [[u128; 2]; 639] = [
+    [0x58d66e0c07b9cc001a580bd42cda0e77, 0x2088ce9534577bf38be7bc457f2756d5],
+    [0xd21c9e37d73dfff9742a58e0ec356be1, 0x1b86e63908c4b12af43cec6810356c94],
+    [0xa4ace33e16825f540d5d50d5a021a169, 0x09ca022ba1142d170f755212e2edc9d0],
+    [0xe22c849c6d8ad8c938eaf613bc1aecd4, 0x277e69d47a133804241f6aebe9449be6],
+    [0xac9ba5457a18cec594545a235db6b269, 0x17b57ea03754e24ae0ef425aa2ad931a],
+    [0x75c9077755eb1a37a77d5a1fd74abac6, 0x11c740983395e0f4a026e2c7c9392363],
+    [0x27556a479c59b822efc5a81eb37bc5bd, 0x1cd85529010dd7e2fb7ea403515bab57],
+    [0x2ced83d197aa374b5a1f96f87b12f292, 0x2cb9354002cec4bcbfa985de34f43d70],
+    [0xac52f56fbbd878506544f9acd61aa32d, 0x1f7af98e08f6877923f27a3dad40c525],
+    [0x46b1075de58c7064c0ff92c431d31dd5, 0x2b15176925df33a45c9e99a7c4f26ad9],
+    [0x061358493f3cb3653fda940e988c1ca3, 0x22c89859b4d399d3a60a322d6b399b88],
+    [0x597039373af5055aa67fc18e5b1938fe, 0x1a9f2730c80854d8f6dd98109363d405],
+    [0x2563ebbac58ff8fc4a1a0a40575f0ed3, 0x040bae2c57fc68e3ce5e294998ea060e],
+    [0xbc4d885cb99699aa2dd8565c4faf8b7e, 0x16bf4f5622729467ee9749cc380605d2],
+    [0xa3ea075001b21637ec7fcef61b11329a, 0x1045ecea044ba1cb1c3bf07ff4968390],
+    [0x23492ece769f70f66d3b6197a262fd09, 0x26a319fe6b7d6e08dd6cea9587cfe3f5],
+    [0x172ef1ed9c0738686da84216ba81bd6e, 0x28a5629b4c2083387eacb09c1571256d],
+    [0xfc6d8897e32d6a0b19de280532fa9726, 0x186be22b6a063d18cc8760dba63b119c],
+    [0x15bd9b73218c6af88137cb8aa5c574e7, 0x2c41eaf9c0128c486c5595a1ced6b3dd],
+    [0x6ab7213321dbe0683727fa2df7e3cd7a, 0x11864265d76e8873b4d2ceccd18dada4],
+    [0x8166ede6fb6ac68b02cb60d8322467e4, 0x1383e7371d7f3eb75f97461ff7956553],
+    [0x1351de9642f398163602e138c59c7469, 0x1a005346e9edc59573e3989f34c304ff],
+    [0xc6299c69a5d24b460e36f3b4eb486052, 0x2b541345bc4fe1589d649270ceed4b2d],
+    [0x90f87e8bd993310b982881b4ea7eb58d, 0x213e74e3b88692068b420fdac7a62f3e],
+    [0x79c86cc6c9db77b47e9e037d41ae2b84, 0x0254370fdf60e319df5d27b984e21d89],
+    [0x19adaa660bb6a79ceee627f5a6369ecb, 0x083b7377987bed3fbd0c5ee3279cccb3],
+    [0x7bade4ecb0999e2a70021cccd4fbce1c, 0x0a2b26bbf2b84a55bc4c5b09b209d4a7],
+    [0x4785c51889ac9ca053faef068533eb09, 0x287090d407c4c5293cd72bdd881ace1a],
+    [0x476001ea6e32931277ca4cdab62a8587, 0x00a9fcc21a234bc22a473c04931dde66],
+    [0x3f9722c5d10d6411386b8cf06606d116, 0x1d79fcc5066ff2a62d6ec0c096f3c062],
+    [0x022a82291934ae8d646814f38b7c9cc3, 0x1d2da676081b7b0a7d87a57c6a6f2c91],
+    [0xe85ff4b3ceabb4eadac26f7a79d371ad, 0x0121189d7362dba92f3bba28737ea247],
+    [0x5da5a7f472f497564362ee63bd6d6102, 0x03ba01bdc7f31b4b3626831d5e82852a],
+    [0xa3312df5ed0b6e606a169bdec89a35cb, 0x216487ac4eea81e4d835b220c7f1e7b7],
+    [0xc333b4d98e4cede698c1d8f3069fc804, 0x0eb5eb424bc72e5b20779d6138a7296d],
+    [0x71875fafec3aac6831fa17811afc45f6, 0x1476087ee68863fe69d538de90f90bf2],
+    [0x440e7adfb00da7e4dfd8a5aaec009f32, 0x25995ecf079f4f81925ce8050e6bdd0b],
+    [0xeb378d3dc8585a8195a8fc6b03dddb21, 0x01b590ea145df6152ff0e8eccb97f057],
+    [0xc85f07c3d055eb987dd42470e016bd52, 0x0ebfed12777ad9747734d82fb7ab59eb],
+    [0xc5c073355890f26d6437c9990a3e232c, 0x2e03b519ff070e31bad1ced25d02de18],
+    [0x30ff6f7bffeda93a22663117c3038109, 0x028ed48befb70534a157c0bc80373478],
+    [0x6645d7878339792169d109945aa879cc, 0x21d5480ef2288cf37627083f5c6ff580],
+    [0x22666a268eed190c92513679ff531866, 0x2a017f4ca62717fe9d616fc3b74f426a],
+    [0x3e0a9db664958560e13102dca258a182, 0x085cf123084d6b4b0b2b34cf06cf6f89],
+    [0xd063cf8ea6ecb3a326bc3f2f24fc30ab, 0x01cab1eb61ae5a9c4f1b1f0f4cf71073],
+    [0xee94369715042367495f0305e89bb96f, 0x08b659a737c0a7e198c51de07438e7fa],
+    [0xa9c895a81386e769744e7b5b2c307df6, 0x02b3adfef956007ba949e044a68115b0],
+    [0x9b04c40af2fecc172b057b4fc00cafa6, 0x1a502a7b0883deaf9b24a7b451cf439d],
+    [0xb19c46c00e4072516a2cce33a42ce949, 0x3048a536a2a75af41a0fa596752c83ed],
+    [0xe69a7595a3d2e8725e75b3e9c66ab22c, 0x01d008216832f77736b9f2c8eaf18d2a],
+    [0x4f1b7d54e69e1d1896c1b15ee85c0c20, 0x067389fa6719bb9e493424576d30d625],
+    [0x7e08d6b19650359452705699bcdcabf4, 0x17da04cb5060861588a4dee60dacec40],
+    [0x903a08d3a7658e05ff0104ce6de14af0, 0x1e110b24526cf19448a04b71f91f834d],
+    [0xc4406708f1a7bec26160e2352a820e34, 0x0b0a28b67269dcfb242231c7384d9b96],
+    [0xad1d4b29b81ae33cbd7391ad0dca0c61, 0x0226ba223bf5cc831bca604a2f55b324],
+    [0xaccac5f7cff145947093bfce2f3f5cfc, 0x1fa506fae71f617db6a04dba21eb6224],
+    [0x9e7e0467a5b4b5a16d596f48844f0394, 0x29724cc2973b5b34c4e53fb981f27358],
+    [0xdb44fada44534a9e41a181a7cd90cac4, 0x20185815e6d5bd43d769b1e7b3a42369],
+    [0xd8649d73a9f53133b614af6d89376612, 0x2cb5ecab94a2faf89986b565545493d5],
+    [0x5c5986fbdbce0a3fd879bc27ee2134bf, 0x06772704a6a6c396f2f186272db82513],
+    [0x1e168efe6c37fd0beeb6e64d30591851, 0x0112bff4736679a477440e744d7fc619],
+    [0xea8d00be172832ad07ac0e268e391a57, 0x2faacc248d2f742b7dc50b013be9c4b2],
+    [0x6d08af6e8fe7e42a3800136498df40aa, 0x115ea053915c9231d6b9a71b4f3437e7],
+    [0x06164d10dbaf756127621b5a22181235, 0x0353e2c2845c0fdfc53f58394e2dbaca],
+    [0xcae2c52c7ede3ec010e2afeac748710e, 0x29b3685900bbaca910363ae0cdec5f61],
+    [0xd9227b21bfd8e8a3a9f1c2fa89fac1a4, 0x1a73ba985b824209d0a9f310920791fa],
+    [0x5b9b5e845c8c9dceb83be943309a305f, 0x274682d63a1d6a9eb5ea22e0558b145f],
+    [0x398f38e6089d92e2c892ccf949fcafcd, 0x249de663dfdf6682ddc122e02aac0d79],
+    [0xafd4992f0d7d4023cbb677ea4376d4e2, 0x076346a494ca499918229d2faf87b6b5],
+    [0xe220daf6931b84464741e64e9926b50e, 0x0712154eb43b0a3e8bdd94e29ad85939],
+    [0xe249ddf327fc6c92afdedd89befc6c32, 0x15bb7e127b9fc937642692a92d864960],
+    [0x8ae05c60052c10ab80d80076800d8e11, 0x0d438d885c3fce5b06a80584015eaf7a],
+    [0xdcf7a5fd881894b7dc383c8aa34a9e0c, 0x2bffb873c9b04762b2e362d6195c8013],
+    [0xf8552b9564e5ba2da45b76652707d6c0, 0x24ea42c76f7350d08e8b1016663fdaa3],
+    [0x05b1193b579ecece0da34523c481dfbc, 0x2a62ea21a0ade4740b808b9d838b4ef2],
+    [0xa8ca864c6d32f2c644b1ab89edf475e9, 0x0c49774a68f5274b468cea0f32211cc9],
+    [0xffd61cbffaec957151fead13246073cc, 0x0a9b4d3d32564d83c24d4e052dc3072c],
+    [0xf000dcfce572ac310fc64bd4d201c3a1, 0x221179d848ae3c29baa0de8e19ad399c],
+    [0xa70a45de25203435e2340692f7f5e0a0, 0x1702294352579a43d19da15dee1fdcb2],
+    [0xbceb1aefe37f779663a7ef5019d4d8e0, 0x059ab788273600685cf6de04f87ca9db],
+    [0x6a034029a109ce435f12c1b21b41f599, 0x23af64b1622be07735af1999691398ad],
+    [0x5e8132433e5fba6ed8f49b2038594516, 0x109ed21e2456ad7f94a0c6ca6ed942ef],
+    [0x37872271d891f19f67f70c39e0893326, 0x26d9ccfa5379d3fd6927a58958338d60],
+    [0x647207816ae5a901fb00a6ccd991d0fe, 0x24ea9e2db358c1becf7dcd7d0e46979a],
+    [0x98eeca1d27735974cf502bfb20c71bbc, 0x0015a9b8c33fce93ae3971d193a430be],
+    [0x826bd3a85347e559550bf351f296dc1c, 0x2bbb366f79bc3baa138d79a96ab5cd14],
+    [0x1d9e7b29eb14d07654af052686b70e29, 0x0a1c76641eded157eaa1bf4aadc95679],
+    [0xce2ca16c07bc747d103abcb48ab21fc2, 0x0a8a9f9ee8ecf8ad0b5cd84217af26fd],
+    [0x2b77d8e566fe3b3901ba6de2011dead6, 0x26d6852b5917f4e229c46824d00b4444],
+    [0x1ed67e0f3c1fcec7096e5f01fbae6885, 0x19601734c30cb2a44ee4a323b35f2c17],
+    [0x02505587f386cbb71070bc17cc87d044, 0x07895f7f029f84c3f17e359b0ee926d4],
+    [0x8eb4d9503bf25cc2b0f35a939e0ef483, 0x05807e0a2b2473f3b87130586c786822],
+    [0x2aa490a355e07982828be3e5099d2fde, 0x1ac9ba8eb9e2fde0a79886aa8c7dbd36],
+    [0xcd00e7a07346e2588af5638e3b321b40, 0x231c7595f76fea5828672afc5bbb6463],
+    [0x946e2eff2b80d17f581ca78f0068672d, 0x2e3d1677310217ce5fcc58f4039532ff],
+    [0x17b2bdce7b7eb02c7830c5980464000e, 0x0974163441712975a33b4093f922d7df],
+    [0xedb87977e98ee29139bb51671b9919b2, 0x1cf5c1a4280822a59deecb82a64baab4],
+    [0x84bfd5f23de46f1df78ed7e94cc81289, 0x11ad46e13792150cf57933aa9476ec91],
+    [0xed792a9c3cc4002b41e7a1bb8e63dae7, 0x0828fbfc0951c1ea0c0f3d5af2fe5796],
+    [0x854535c4ff404fe82e30e99e8500fcf6, 0x02c720a8486e7122305304ecf3a00f99],
+    [0x360d2881aa425028f1cd3a7fc3ef72c2, 0x09bdfc942fba8824c0a38d6f525f9176],
+    [0x2a943dde51e262e7dcc4a1b2ca82e2bb, 0x1d954a0f93250e5581c6b897fbd72f4f],
+    [0x35edf37e7ab0e1a7f92f14c98aed56eb, 0x2b829352e297ab2106e74f9fe702f696],
+    [0x23dfac75482e108af1c33368734a904e, 0x12d3b917e58f1f44e1babaa341d1dac9],
+    [0x509305f85326062ad5015077afe4bbfb, 0x28a9e6c65d06ed6ea47795bde9b177b5],
+    [0x0c311fc8d232fbc2dc535fa593221ca8, 0x2bd3c33ae311c2f8d4ad82508b76df89],
+    [0xf3a0923bf9059bae3d84da23767d6873, 0x272d00d7a8fb1463bb624f1dd774edc8],
+    [0x068f5070440552eee0acf2a4d07d03bd, 0x1355a796abdfaa903b81dfae32101a13],
+    [0x02cc7d74ca8ef1592cd16afe2a033728, 0x0734bcbce4180ae16d4a6689b9b94f2b],
+    [0x627464b620d91e7b6d3d15dbd4799d54, 0x0778d4a614c2f76e28199d989a9052e2],
+    [0x27db377ae144ff634cb8574fe8bf869c, 0x16bbc980a67b2a65434d087c501859d1],
+    [0x326db7c7aaf2c1c0d59e4bac10d633cf, 0x12a3e7e197b0504c6894366245bed65e],
+    [0x99160fa9f7e20d811f4b8fea7dd5d33f, 0x1f35f8c4431fb0dbf42d9d07334fd67f],
+    [0x2ff1556e77fbcc6d71e8bdf349bfdfbc, 0x25d5bf1a863a0a6441cf55e7c85b8f23],
+    [0x87ee4ce30cc24cad19587a23332320aa, 0x15e6b8d9ab7e063a3638216ae471176e],
+    [0x41eb2641bfba35a9cba7b708a534c9ca, 0x01929ef47c7322b77ebd0d1a40f9ec4d],
+    [0xb0e864d7a8f678c7708ac65c51e3bb79, 0x184f51e791edaa2dea7911151247bad4],
+    [0x71eee0595ce1422ac535a410597cd866, 0x2605adf5562d5055ffb284df1ec0d4e3],
+    [0x85bc9eae1612b8bc3d2a4925c239ad51, 0x06e6d41feb832c2fe007e1bccc814425],
+    [0xe1c0d633cdd113bf0fde4345ac9c954d, 0x137eb7da068955cde28db6ae5e780b7a],
+    [0x64d7d187023ce2de4bf9e70eba6e9bb6, 0x2e97930eb419f7353ba27b25879753fe],
+    [0x07405bf9c75a9efd9321736a1d812413, 0x13af409362087866e62d6860fdab1592],
+    [0x4966264560ebafb3ff2fd19259d47d62, 0x0fd2f95404a9e92f46d57c8050737572],
+    [0x4384add521d98135df3ce70e47d38116, 0x0bdd93ac21aedf31826fc3012fc8fff1],
+    [0x811a6137788bb873fefd5521f7d99f03, 0x083ea678d5a53e8a9b601906befbef23],
+    [0x2df6c6ccff6364c2ed6986d61f16af29, 0x08a9906be6e9ab93cbf1b94f090df2d0],
+    [0xed19c604e65fb768b0333d8d535eaa47, 0x187b1c82cfaf880449337cfa77f618e3],
+    [0xc173f0ca4ea2a81b1b8f3e90d7fec4f3, 0x245005f814e38250751143117f5e27d5],
+    [0xfab69a61274fb36eada71deaf1614db2, 0x25aa5a53ec5e71f3f47fb1cf8ea8c757],
+    [0xd91b14cd9b6578b64492c2cc4b0c8bb9, 0x01c244a2ed1922753b39ec6b775f07ca],
+    [0x0f3e59eae24fcd7e7daaa853d46c382e, 0x01664e5c861216c4cb10cf3a3bd51afa],
+    [0x4da2beada0449ea1d2e0f0a00e424074, 0x2af001a296c111e41e327ad212ed3023],
+    [0x5433b270f28c24e6066e45039aed813b, 0x16022bdd67d2ade15f661a287dfe2391],
+    [0xdf36916384a54b812c74a749a26826f6, 0x132d7ca5f935f3e8864268ad723e8780],
+    [0x4a273808807ade4ec5db95fd184c4f37, 0x20bdab9829e48c89a99d0e8b0d69b79a],
+    [0xc2afe87bc1afe556161e61772258a951, 0x28db494350ae9c81ab52d719ea05f98d],
+    [0x0ab9f6041e0e51d1b24d9cb9f3fb3ed0, 0x19fc92f1d2c1dc043af3427b77ea74e8],
+    [0xb90c7e5fd02bbdba1dd733c3cb884705, 0x16bea7126b8f072ab6c86afca80b606c],
+    [0x28fd09e0dff889550a126f5833f1fd5d, 0x1fc57b168a5badca15a417ebb4bf5beb],
+    [0x7d56699cf37dd7b97d23dcbc65bd9afd, 0x1ad20d1a3ee559b6334be4ba911dec95],
+    [0x59a3e5bcd20895ade0fcd7bebcd88885, 0x2173430a6842061f027393a443b6e25e],
+    [0x7a23d7f3003680f3e1534b183fe27cd8, 0x11673173043e15137361db05a0b06714],
+    [0x84493eb998add05556eff65129259ff2, 0x037c80af5ed51c1c22181f3e77388069],
+    [0xb63fbd25f3038285c36f6b44e9b9ff5f, 0x1d70b961246d2262cacd8c0954040548],
+    [0x9635e4610c25864b2c63252186b6c44b, 0x2184448a7bb80d176dbe3de73f383a90],
+    [0x6c7d3c96c1bf7d5013f36521a5dba7d0, 0x03bdca6b22fb80cf9e7b317190ebbb9f],
+    [0x436f4eea91a100ec0d1af952eb64b247, 0x2b65873313d5bffa44d510dcb3f55048],
+    [0xf06f37ea10e7e3f539b53778ca274616, 0x2a7fd02ef10db1c9dc03c9cbe82e857c],
+    [0x72da23b3d2142d265fbc2196fb8b569f, 0x1cb880cea192153865713565ee4255ce],
+    [0x27484f594a7e3ba8d47cdfa548a91a56, 0x16628b6f83fc36bbc1d60029a69c843d],
+    [0xb760d4919bed798e2e4b8dbe0080f16e, 0x26fa9b84e855f1069e76e037104f8886],
+    [0xf05b9161856d658c1b9ecd80da9e9687, 0x0ee86c87bdf0ecd526e36a6c4ac9fc9b],
+    [0x84c63cd115ffed3a6734ffa85267191f, 0x08a5179993a8056405527cb82b6f6199],
+    [0xbcc2ad3949dcf96e875f45df7eeb5437, 0x047aa61e4bf0721ac4e0a2f291e62124],
+    [0xf5d1fec21ea8bbfe2eb1ef5bc4f21148, 0x263a511f033e1fbdc6d282b52712a09b],
+    [0x31b86432a2c100dd7386c9b1f993f34e, 0x226520bf730486e2d8c859b449bbda76],
+    [0xf0d18d38b893cbb1157b386a7d356357, 0x2baef27107a52854788f0c5a940f5a4d],
+    [0xd9c92122f7de5651a60cf70c8da5fc52, 0x18022cca17f26818d54e60e300b5c949],
+    [0x785caef8565777fed94dd67de6717d25, 0x07baad301d58460d1e6ed10f52abf1bd],
+    [0x1a7f71b4135be3662f4b01047e248a6a, 0x1e476106c4bce3903a5aac6e110df2cc],
+    [0xc3fc1ebdedccb8045c7e9d1bb6e77a98, 0x1e019dea5028d23b2df063c210466a88],
+    [0x7174c6b6067e730a5f9a9908186308e2, 0x0dd55f006559bb6747b254d29a73f5ea],
+    [0xebc81be408cb96fe38a48a952c029189, 0x0993085a8024a598e0c9059d3b919fb2],
+    [0x7893787bfcfb13b39f165869ec7fb9b7, 0x143f3cbf98e40971b2115493ee06e26c],
+    [0xa030d497312cc17caafd008524eda07d, 0x0525ff28fa24291b8d033b021395754f],
+    [0x025095182d5f40d5911f708eb05cd852, 0x2d9fdc18cd2586b9f449142017909392],
+    [0x8e3621d613fe0e78e6dfb400efb43f45, 0x230997202fcd6f051e1bf1e59a9d0118],
+    [0x0d11981a767e507b80aef7ed69e7171f, 0x044b56469ffbbc9d0c0f075e20f390d3],
+    [0xaa71672e002450393eaf7a23736150b7, 0x1507d88bbe10b21c23a94555d1e4d379],
+    [0x151b3cf6a9ea1be93c6840594824e9b6, 0x104d6752a761b50026377ec798404f71],
+    [0x46a9fd2226aa604d44bbff12b1e0ec23, 0x25276305cdeae4c16558de60da729414],
+    [0xdca683dad8cc35a6d0c30cbd9812051c, 0x10f3cefb054d630bd7dc4c7cabd97940],
+    [0x1baff0585afc4fcecc7cccec809df03d, 0x0e406cbfa384c6b24ed043ff3cb1d689],
+    [0x863efdb8966db7ed3534cfda40aeaf0d, 0x18f36033fcf64f1bda0c12616b00b611],
+    [0xba2a6db07572dba106dc56ca6c937c52, 0x165d022a34cf10ad3255723635a51a35],
+    [0xc4b10e58baa1365827a539c47819424f, 0x223c8c9aed305b3d18265b56ecb7a4cf],
+    [0x511a2b5268f1ac96d7b28c8984fde6d6, 0x2103fee94674840b1aa185a1f8221ee4],
+    [0x23ec5cd55297a387a92806f78ef9d384, 0x1c1396433bb9eac9a02abf2390973c17],
+    [0x06996fd32fecb72b0cb4decf109f1b92, 0x1befee24005a88d2c9ae9226169e8981],
+    [0xc2fc742c806f0d5950c8ac41350f5184, 0x1adc49f1f1b405df727a64e98675ea0a],
+    [0x4ef739173cfaab2d03eae240c9c847cb, 0x10a0e0cab76db0b6a00799dab1f2e4a7],
+    [0x1e047c0b27ef49b10d2933af1af3f997, 0x1a9aff62d6c66fdfed0eb91b6c4f974c],
+    [0x725f95e21fc36c8ed191873985f95cb4, 0x261967f6840995daf7411034526eb5ac],
+    [0xe1af2f88fe7dbe29d183c89ed5286098, 0x1338030f85fcd2fae09eb016f4254086],
+    [0x50c1134914cbb4d52bdf1649c7911ada, 0x02c53745d1f0e66499ff109e777727e7],
+    [0x0e88d8b0b82d10c1276ea7d9f62a05d0, 0x2740100354406de4d94eb857a1c5df1c],
+    [0x3e4f150d1c32ac8905a6226887c9bcef, 0x0b8a9153d3d643f0a9f7dc08f24d7086],
+    [0x3801ed1aa4059d94eb72cf548b2eea1d, 0x25dd73d81ea8918c9c1ff69b0b86a0ed],
+    [0x277b4d0312a5888a9dcd12891ad1526f, 0x253d813cf05f2ff1c7d6622bdb9faaff],
+    [0xd2c8e62e3af51e020eb2c15ed830f5f6, 0x2523322d2d2a78cc54c944b57752bdd8],
+    [0x2377f3b4ac98e10e2ae3e4f83b0071b4, 0x2ac9c2d60d456848e8dfd47499cdbd4f],
+    [0x8a9716174f47fb0830d94f9bc4386bcf, 0x2a4e35f1df833442f9ccb8c75523b1ac],
+    [0xb676d174acb65b730522dccb096f9fdb, 0x270e7f6b15f2c0e0926147cbf79a7330],
+    [0xe899f15214f0b8feaebb2ca2ef52ced2, 0x1933ef75d4b862f2274634e1f5a4e50c],
+    [0x4108d364bbdc6acbb1b0fa7aa5175dc5, 0x165130ddf77a82db84278a31841109da],
+    [0x5505e965b7e7dc4c19895b9f6fceda6c, 0x2fc1fe031dcd4538d5d8b1340d0d37df],
+    [0x5d546422e7730e360a1e4bcc0f62f16f, 0x0e1ead2be6f64559d903eaeb6e02a7c8],
+    [0x4f161fda3d05b1a5067d278e21c8e54c, 0x0a983360bf32392d5df30a2e4b22535a],
+    [0xaeef782e879c5230b86d6a79d729f4ce, 0x2e27e61828693607c17eff9bb1e5ed0b],
+    [0xce7b105cbd04e7c838c1acd006ffa53f, 0x0d1cea26a08207e522ace505e837caa2],
+    [0x396a03568376fb44ccde4c38cb2a1944, 0x0b958dabc9a8066de9e4cef89abc82e3],
+    [0xb1cdce307261dec86cf2034a9b88bbe8, 0x16f5b42d88f8d4f787ffff796bcf8175],
+    [0xdbcf7546ef27f47d7435a288c2b6c63a, 0x12b68aec2db262e0a61f0fd4b976b00d],
+    [0xf3f81ef863300944c87b77628ab6d84d, 0x1d2238d8d9768dc639d06ca5870e3503],
+    [0x95e75d4833ae232ae382bdc6b790d91a, 0x0d164e32fca3c3c8da3ae332a831cc9a],
+    [0xb762a5c47b57739a62000d7f6ca8ef3b, 0x02fcdde6d6eabd7f2fa19234fb157661],
+    [0xc802a1590df5e5b8f9c8c21908efbe37, 0x0f133b8b1e54f43484574647812ca377],
+    [0xe60afd13ba4e90afb472d9dd29c42bde, 0x03057ea8932e9bf7d1a4bad8241e14f1],
+    [0x1bc93f33645825d0e45c88b0088a9f18, 0x23dbcb6ecb4807740debdc825f776457],
+    [0x9423bc3a76f8289579927ecdf63edac9, 0x01b90754a2058217cdb64ab45e24c2e2],
+    [0xf9d0271c74d957c6bb9514a0f038fa91, 0x28db800ae16d7033c8e107e198aeaabb],
+    [0xe58eaf37b5b9439473cbe477c3fab178, 0x02f97a36ef93a7904cbdbdc36e5ef374],
+    [0x5b9e8089d1697445a130743e03ed3caf, 0x1bad1d9637a6ad6db5cbd19ba7b955a4],
+    [0x5b9c43220b0a3279179753f740edae80, 0x2b96f520dda8251a878b28bf96d28185],
+    [0x0999e0e0e40c654378ae12843212909d, 0x041f97f76c60b28e0fdf7974bf922b7a],
+    [0x3aef9726cbd9835b95616c2aed5588e0, 0x13b5cf57475057a2f5fb6d0809312397],
+    [0xf84d44f3d2e398b5f7491fa2df12deee, 0x2ca43aed5aea162e8c9be92a15e7ad0a],
+    [0xf0f2f96ea11df270ab0c5d9adb5c3df0, 0x089f6b1ec8b51cd1e6ea69b9649520a6],
+    [0x6041772627793a0f3711d1300b4d1275, 0x04f510cfc7e528370babb8ed7c50c34c],
+    [0xbad5b7af4ee037ea5f8164d0f13d1059, 0x260e12c47d0ab52563affcdd5c2862f4],
+    [0x6cc0d60f8dce451f7bb35b12dec772b7, 0x1a656424760e75468aedd8515b3111e1],
+    [0xa6c70cf72f222b80879e9bdfbc6711a9, 0x00cc850e959c567bed20058b6769fc27],
+    [0x0e19d0bbe875a7f3a0b699bc60f18cac, 0x088947910eb3b43ebd8688695c64ac02],
+    [0xace9f3f9afb09071e790dc4babdc86be, 0x2c25c236bb48035f45fde5b178105655],
+    [0xf2bde0d79ddbdda21704904b2905da68, 0x12dfe1bc28654f5c9ba65865769c9e47],
+    [0xab5b0442f0b8efaa2a986a6cfb2fd8fb, 0x05645b68f99a564ff08f745e1413bd83],
+    [0x2e9c259126bf6310fa4b110e43864c75, 0x17cf3ec9831b4d6ead640d912e41b4c7],
+    [0x7dd721ab44af286e72fbbd5689a66f61, 0x12b15f28fefe5d6fa519ab9cc67a78c5],
+    [0xedda8eedb6fe6d037ffaf8e59842a00b, 0x2da44f7210520779cbdacfaab2e1ef01],
+    [0x3bd393d5c9a961cd2f7da2e5d9a894cc, 0x137ff7323fe757f32afa165f11588b40],
+    [0xb91ee0bf27b4451195de945c8cf78aff, 0x1318105fa9fe1549f04ef48679c73d46],
+    [0x121708c312e635ef8d1f843c3ba3438d, 0x1408a4259df52a491775936b2c2d476a],
+    [0xc4f447f20cac98c95193eb7571585d07, 0x10cf20dd5734c18880dec5be44ae2ddc],
+    [0xfeb1e37fbc3a9c8a4ea74d6010b7d08e, 0x2787fe4623bd3264e0c8b5a471974ac1],
+    [0x1f703258bc03707e33db582d45ab172e, 0x1b90e21074907545c1dec3c59dbf2a6b],
+    [0xdfd9429a7b3632bb5022fdbc40636cfb, 0x0b8e912a5ec5a0bfb779a0801b90863e],
+    [0x3ee6b0d8abf31f1f547a3993044188db, 0x0d9982378cd9a8370bb7a555c34df318],
+    [0x29a468d1cd3ecde56f6940221d248af4, 0x1e497bcfd5c95cdfd630b0afbf6d376f],
+    [0x9a5a4acbc935686ccff9a15076585b7a, 0x2d602401cabbc69dff3dd552e271eec2],
+    [0x803c0d355f3e10a5784951f2abefa707, 0x170a5f4518f58544330671ad2a361f2c],
+    [0x38776af671edfe44c784956acf98865e, 0x21b3a23c3a553d401c08502eca497be9],
+    [0x7490e824a14204ed88c2b1166e8e479a, 0x224dd497bf3a4247b9c48c9ac3cee530],
+    [0xbaaf0f68d42664e300624a375302e0ef, 0x2654965a3bf3c92422fb54e676ac8401],
+    [0x726e95be160ee1a3e299677820839f32, 0x1d525f4105f51b69c006828670e7785b],
+    [0x50ec41a1ede53612c52db74479b4b323, 0x28f88d089f421ca2bfe697270fc0f34f],
+    [0x560ef9af0fa0f948db24d1c8e6f07a9c, 0x082e621153ee34324e9fb7ff3d123fc9],
+    [0x9c0d7d5bcde4f19d8752e12a4d5805f7, 0x07f8578337523867b1d9403e9ca61ad9],
+    [0x2ba1cf2eb8d59208b5bda8e35d69819a, 0x2e52f30dd05931adb7d7e85255849b06],
+    [0x1caf26f7c233bb183f83f72ef4c8f345, 0x14cb3e56b2b8c6605ba04261a8b11779],
+    [0x45eab305be627df4d36c8d325c9bd7ac, 0x2fd6b4e6c892609fda21414dd8712de8],
+    [0xdb0c9940a69e9191fd996a4a96157bf8, 0x0325898c8434f7ca32ebd73f53df3b1a],
+    [0xb8a7ec4dcf5ec066f99fc4644d6d5d48, 0x08f50a7b584dc022b85d6a16a0d3dff6],
+    [0xd9ceb8a19ff3c970f2bbf4c9bae9e11f, 0x29c5bbb8eb214fb285ca30a43cfd7141],
+    [0x088f1eb7eaf5934999e1997307e7432c, 0x1264cbd9ec001175211e2856a78901e4],
+    [0x51dc9416533ae33f7cb97fd177688504, 0x28a0e7819aa0251e9cf2bdabf64fadbd],
+    [0x4c52fc23e51ade9c48f6af307ec621d6, 0x1a9be651c412fee6c800848477cbb67e],
+    [0x03c33005cbe7f34ac11296e5b0c75f6d, 0x1bf70a24fe7fc8946fdde6f556b3ed4b],
+    [0xcad0719e932a36195c5138e0c6ac83c2, 0x2f56c8c0387e5e44cf4921abd33b1590],
+    [0xf5f211920eb8d638e3a7c943b293fede, 0x1109db87b7684f4927b2afeeff00608a],
+    [0xa51d13032cd576d332ca89222bc75787, 0x1851c29804025b6f9c69c80891035590],
+    [0x5c7137e1d5ac9a986c9f66fd5dfadfba, 0x0909e4cdc0bc47f2df4898106b5c40f2],
+    [0x233659701b319aa228e16af9f010e07e, 0x2ae3e90e2650e286d5913d4dd1e71d4f],
+    [0xb60fbc4dc72fdab7dd81ec557235d530, 0x06a6c015f95173441f7a40d4f1bde04e],
+    [0xff30d6c46816a3bd3d484c97f8d2446c, 0x2f6ab5d35ccfe9ea366da257c8596fa6],
+    [0x8745d9c9127050899bf6426f7ab57cdd, 0x01f9c60308182eb6d4a83f9272bc220a],
+    [0x4c4b29609d3ea5617ded35bc01d799d8, 0x2f514c412bfd9754e6798e4c3cb13d5e],
+    [0x5d28294d092cb8917afcb3e08abcd9cc, 0x14c4162b9c9eefb97941b1f133bb1822],
+    [0x3cb650e1e72b3fc98884b00354085b32, 0x19921fd625c66ec67ecd8af3d6ff0e0a],
+    [0xd21de3436c816f75f93b8debf37eb6ca, 0x1c852aefc9296753e1268739ac15d2c6],
+    [0xf559e8167edddac1eb88ad9d02745fb8, 0x04bf4a69b1aa4f304afaa1dd1bf60de1],
+    [0x9fe3fe72e7183d8e5830165e4d123753, 0x240a791f3b8bb62d5d9aae80f341cb56],
+    [0xc8551654186d62cc663ee4f28ff5e764, 0x151b295b4242e0c09fef7bc5ef40f1e3],
+    [0x497c93cf7e657954b0cbf7676165f23d, 0x086eb3d30bc4d80664a074a09739dc8c],
+    [0x2a292a7121ab0ebbebe0dd62622c21c4, 0x17aa8992d7b901eade7eacd95a8e8938],
+    [0x32f489b23a3c43962008570f96eb8de8, 0x1a5b3345a4c0d875663657607b5c7ff8],
+    [0xe3f31d1364f03345b2a7964b01e6347c, 0x2f03b46d9eeedcd7f8f4cf2cf710e48c],
+    [0x70a0291723aa354a35259e0a40050d45, 0x0fef583e7e8e102feba879f7bd602418],
+    [0x0fa5e523abbd895086a36288b969fa6a, 0x2e07b5d70ca583cf4d9baffacd02d7d8],
+    [0x394b858af8e904c0517baedbf2f176df, 0x24599dbfa1dd1f4714d585248cf23ca1],
+    [0xe61377388d8727e407b500fb5291f8ee, 0x00a826afd370bcb65b66189e8d986777],
+    [0x0a2bf23e905e7d33cad03de907081e3b, 0x28745950870bc1848adbc5f4bc03415f],
+    [0x8b5747b46dd9914d1be4081714cace58, 0x219e6dcda590fc88d405673d2cb4710f],
+    [0xd95185ff7253d9d1f41747c6f27dbe6a, 0x296692077b42e972399f17ba71ffeded],
+    [0x9fb5d826a6a483dd5078de859f9cbceb, 0x2ccc5f0e2049d426841862f32f806282],
+    [0x367700bbb9bc061d07fa90a70feffda2, 0x04f915a4fabc8ab208a615dd89fa207d],
+    [0xccea4ed5928a34f4e59ea9eba8794708, 0x2e04b7c1aa853f9ee5e4fbb5cd7b6e60],
+    [0xec69b04a2e1b865108036391b4b6db3a, 0x08700df51138b36c0ef2bed12bd78340],
+    [0x710416fd63d7aadcec41bb7062c24782, 0x2503336398f165bdea8d4929db1dd0b3],
+    [0xcc2df072ed8eab9a2996a47a527bdaed, 0x05633785a50a9c77573ab10d893341a0],
+    [0x4622513b7c96e9fccb2c3be2d3901e1c, 0x0ba4084e44c50e1412a4c982dc0d8a0d],
+    [0x106dc4519c61c3996cc9cccd8f307c86, 0x2d6f32cb761406dfa9e03d7ff8a43bae],
+    [0x3c8e6bae3aea2edb73491244f8373687, 0x2f959c830737e631395846373b1f7d00],
+    [0x498d999250cc0a858db27aed54fbaa38, 0x14863af59792adb1bb40901664349217],
+    [0xe7552826e4d6b58d6df8d986937cdc82, 0x27623d101b1f922899f9c5783e1fdbb1],
+    [0x5c21f09305301bf1411c0798602a6268, 0x247328a4d9ce81603e37ea72cc5d1c89],
+    [0xd93a25a5496cafaca12af4ce324c6081, 0x1921909aaad03cc78a6cfbed6c1056e3],
+    [0x92df154f649b7213e29cf101aed00d80, 0x0a85c0358cf388b47d14d42feafbdd43],
+    [0x621beb56f902ca483860f7077537512e, 0x01bac04734a5920d65d67aaee04165de],
+    [0x6dee5454f784134b93d8c70ab3eed999, 0x1dd1720966b3231bb00b08d6b8bac6c7],
+    [0xd37ad696535d82d39ccfbbe92e86c08e, 0x22897debc6eb2a7f36bee1e7f42ed4b0],
+    [0x1e94dc85512bc5168ee73065b387e175, 0x2ad01bb42b95d113b3b91ad88c69a33c],
+    [0x1529a57f8360a2cd697eaa177c6000b0, 0x2e82bd0a053ce03af9aa947df5f13179],
+    [0x91c3bc4efbfc8e7c2f08ae3e44e1f4bb, 0x2d2daeaeaa93a5f9d8b6529f00c4c6d6],
+    [0xc1a9c1e58c76ef2e716cc305727a0f72, 0x0ab08914b2de57397983bace0b327f98],
+    [0x2ffa1bc884c3cd1ccca107cb2bc91998, 0x2758796cd1c178ed0c433c4f1b6b19d4],
+    [0x4a30fc4f1b59fb713a824992311e30f4, 0x063df5b0349016e7bda4741ec898c74e],
+    [0x4344b7c05838048ed0641cedc5fc6728, 0x23713016555546753d41abd69e6fb91e],
+    [0xa86ab57eb031b96b53fe8b5adb74c075, 0x26bdfc0a894998e693b7e2059d6a528a],
+    [0x407a1de135b632541bb734f53985286a, 0x08897118f75cc8e9115dac581b9553cb],
+    [0xfa9b3a5557abe81146ea6f6d4960c346, 0x02ef8eb2c7b26f7a57691ecb1e15a40a],
+    [0x586bcaaf12603658b25910f63cf08b01, 0x254e8e5fc11b992348fd7a2c9f4a5a0b],
+    [0x77301da5fbbd59db03d3a32b89c57b7c, 0x02f41c21b73dd4e7b510dca16e7b4b42],
+    [0x2871e5fc67bff13e303db99618e1d0a0, 0x253f09ce9ecfd2aeeae98ba1b29806bc],
+    [0xaaf7c63ceffbbea9226b04b0e795bfc9, 0x04395eb655582d03f25775e66ae67be0],
+    [0xfa2e796e26b72e05c999e6e6126e402c, 0x13711235c3d21dd749e3e41bd048a32b],
+    [0xf33590ddcc297e3cdd9ea42add7a175b, 0x083c75117510a37d43b5513963fbd0f9],
+    [0x4c6525ddcd7488cff21d4d0d823dcea9, 0x26cc9b5718d84e606582d35d8fdbda94],
+    [0x20b9a46d0b1187d54504ed8a344d56e4, 0x0e8978f047929b1a94601499634a8da0],
+    [0x9a6d41234acabed2006247688f6fa805, 0x2b11423af39a5d15be397439b5ca50aa],
+    [0x92685625e5aa77bbc430382573b402b6, 0x21a56381040c702dda5a31a5aa275ce6],
+    [0x14c800945ba49db74aad8e9346af07f6, 0x19a869e15b3f9b965016feca2cb82a2f],
+    [0xbf3ae097aa930771b76f24afa6be50d5, 0x2b01c5a2baa16f9569d4670098466f05],
+    [0x4a71b3fc67a35448d64eaea532582124, 0x0586148573b6b56617f94602fd6c86ad],
+    [0x07872e0599a3127a3e7704411894f98a, 0x040352e19809dd93bc7a2c549bc19cb0],
+    [0x36974e842e1fef876561e1346c5a893a, 0x2d6486b8e7b9549ea86cf044e8950ffa],
+    [0xc9debe589044f305c1b5872c74e5a49f, 0x2ea9d2e1b7d07c9a3895565840e25843],
+    [0xaeb8f6d00ecf84b4f062270413a8087a, 0x12e0dc4105acd8692b7cea0bd76da34a],
+    [0x3db4df8307599a33a491f24b6368290f, 0x0da9c10656e6f42c3ce7c980a70c9d79],
+    [0x9360d9b89aec23c18ac76af21b11a850, 0x02f3551c6a648387da629c96633d5cc9],
+    [0x093386c8a553d3a3da46e134731dde14, 0x202de33a6fce9dfe8aeea34af1ed5c74],
+    [0x3f043575f3427e35768a5a52482b1b7c, 0x07372d41054dbc009e3e91f902d57a21],
+    [0xe8e938cd999cd911a9edbc716e2454fa, 0x02f7e35a4bbe9699949a819262943c97],
+    [0x11606c819b4baa6824b6123036d73ff1, 0x2a27b84a7422e29b8cc73bf54bf535b0],
+    [0xf5d8e5f98e74b312fa0c63aff7161a3e, 0x19527e9093b313d5d2638c863499839f],
+    [0x231d1e355d93894c8562ba033147f025, 0x08571a6b8b730363299666231e3c49ed],
+    [0xd4fb64caea2cc2d4e3f213a5d2a1b9ed, 0x2c0f32778edfe932d912961c81d88e98],
+    [0x7f84a4577610b19cc58191b2b1453ea0, 0x2710a63e84f8bf3b15da09e69bc24c56],
+    [0xb0fb3f148e4ee77b525ebc258a953dd7, 0x2d4b8440f168745ed84c3e393f75bdc0],
+    [0x9aea7c808ca9ee79b6b796895917684f, 0x234901ff3ec65e0a4f629bf6e96eaf37],
+    [0x7720b450975339e87865c36be526e5e6, 0x28a529a627cfc689ba1bb3372072518c],
+    [0xc6e83d73b5c5c0fed554af11c6cef5eb, 0x1a1af260473d83489a6a466ddc1855fd],
+    [0x088264cc16a8119e8f02ab7270952924, 0x2fd594dc14997e8e0123f3af16c9289d],
+    [0x9658234bc4db59147fa3a5dc23eec833, 0x0d3564e44716de8c02d8f8278b0d2f81],
+    [0x5e886e0f8e43f01b38266aada3cfaa0d, 0x293e9ac448bada42377dd8b02d5ae569],
+    [0xcfb1ee1288aefb7e0b2381296daecdb8, 0x2186e961df0728bcd7b667d35e95f354],
+    [0xdce44bf8368a0c43e1e3b8c8a7f49e74, 0x1b4972ee03b2787a27219cdeab7acdb5],
+    [0x0f05738fce996b2fa905ce1c3279522a, 0x01105ad26651d5ac52e817c32d86a3b1],
+    [0x5cf521ba7c91478d72e45358569ef30c, 0x072b770a70dab242ac30d03f5d247759],
+    [0x837195bb936206997f5a93021b70d275, 0x1e399a42196a8061079e9d61ec97ff8c],
+    [0xa3c59735dd3303bcfadb6218aefb7d6a, 0x24f50ccc3a68ab3e2cba453ec40a8796],
+    [0xbc119f01dfe708b9118f75616714bd3c, 0x14fe92dc9227b42df23f8a5a0a7a2f50],
+    [0x82271958c98fd42d6b12696a7065916f, 0x24713d85951736c77b66941c007d1702],
+    [0xd1b698dede7feea9a97b263cce815ceb, 0x05cf2ad05c75de38238f63258c8dfce0],
+    [0xffaaea6e6bfce85ae1580dc48218aa26, 0x2d62695c7008a11d0c874b40395a695f],
+    [0xea6f348565c3aadad0830b3ef5d775a0, 0x19e47227e74d5563126770fa5ba61405],
+    [0x594537f98565f2d65ed8c15e5ca1084a, 0x20e43ff580378bafe2074a6b2fd50c74],
+    [0x37406d50d2849c7091bf1c1bf5c1273e, 0x264e4841309ac1aae92ddd9cb4d973e2],
+    [0xf4289680658fb26ae1b4c0b2f710e604, 0x1238beacedd4f53b35b578af1c30c077],
+    [0xade903de9bc02fa5fc7c137cce05fe35, 0x0d90474c262aac1913f37459d4f2055d],
+    [0xebf3388383e692573b95cae4f653ad82, 0x0f5aaf4827d5f40e00235ebd1cd1b9f6],
+    [0x967c28d62d0c45c34d0cee4008834893, 0x2cdda4350f35334773cd823df37097ee],
+    [0x5bc2c78832a618bb6a2d5d0911b7d768, 0x1c0c8c2ebb6860e64dc46755a7e07279],
+    [0xb85642d4f243d5e68d7240b8e8c03276, 0x269fa26069db1cf0cb705bc3584c0150],
+    [0xc7b6226341ac8389258881d994fa91c8, 0x09b443b0a7676575b9a6d3f003609fa7],
+    [0x3fb6998e9131e99a86f22bd33e7bddbc, 0x191b4697a8e295ab80f81b25743fe13f],
+    [0x8577e8f906a971ad4bb9d91775645730, 0x0b488b6a875f238bc324f04288ad865c],
+    [0xbb0cd31c36cd172bde1db83287f5f5e6, 0x275928b25e503c9c95c01b18a5d30081],
+    [0x9f60e4afc39cfdcde65d70f65c3b026f, 0x1fde053dd87291d7e14f0f2e7b7461b4],
+    [0xff88ac29199a181358dc231aa1dd3e0b, 0x29b2c386816e9d3f76100e8fb9543329],
+    [0xfe1ce9c2aa7d7c54a26f425cd19767c5, 0x04987de3e3121811ec3c10421005e133],
+    [0x0628665eb496ed8732d409942fb6e16b, 0x19a23ba97426bfa81b757e35aa355905],
+    [0x04d001d2b393791a861fdc26c1b7f767, 0x12333e97d1f0e3280b5e2f9d96561b2c],
+    [0xd25c95a7672f3a1f4917ca03baff3227, 0x23e48a5b646d353895d06928a181d75a],
+    [0x93b8f2bb0a52245cedcd94587d596382, 0x1b6bff423317d2c2721965972823ca2e],
+    [0xf378b3524680aafda4f7c4df4b088b07, 0x16af0f387b9ba94c0ba6d176df30701f],
+    [0xe908c098b28bbe712cb75b92797a2103, 0x1bfad9c1a92234ed97c51ac7dc2d80f2],
+    [0x07d90ea8962530f388442ca4d95e6256, 0x29bb5064073e9a6aaec7bfd94d48fe22],
+    [0x647edc3e765f4de7489264f5e34033e5, 0x1cbe456ec3f8d8849e10fea36509acc3],
+    [0x12749781f03b39b181495a62aae42424, 0x0087a35062dbd735636f76cef6fab3b4],
+    [0x7f9a6a5f3dea9a9bf9495174d228bcc3, 0x1f1219145ff0bf15d9645e347879a734],
+    [0x7cef14ad2b907702afa13d76ce02f88c, 0x07f93fb41605d3cae1b42b92d527b2ce],
+    [0xbf32115679d2629a5ebd26c1c7d34299, 0x0ae3de8a6edd589ab62767e92f3c19ef],
+    [0x5eb8844423c71a98ebcc188c23888dcb, 0x15b6688e877724b41ce08fb17695347b],
+    [0xec8b176c1ca5584d76d6f9f6ad26bb13, 0x131cdc252f00cfe48cd6cb1b15344b6f],
+    [0xd898444d0e70d64909ec789ff11e8eed, 0x1bd570cf878b3dc1616ca32bf55e0b3f],
+    [0x2c3b43d1d6960094b8e508d1e11f03cb, 0x1e5a54e71ef7b9eabf717c01dd99b726],
+    [0x94856233dfbccbdd1e9f0f35c9e7fccd, 0x21c88de360b36a24fb641014feceacee],
+    [0xe1f91820b61ab86f45ecb3ae21e23ac3, 0x2ef387dc1012ae94898b8afc9aa54a3b],
+    [0xc2873fc3c1543afdbfc1c0c55cc6d5f4, 0x283914da97f036349936af1157c536d9],
+    [0x5a14246a6e7d76ea79f1189066dd42c0, 0x276ad4f7ba33971e4db2292c7ff704d1],
+    [0x64f5e6892d48e4a71cd4f63e9e2c63d8, 0x24ca7fd6a2014d9821a624bf07950e8f],
+    [0xf666673ecae78bd7e22dc2276364afd5, 0x0fe8f370d24968de4dec0fae5919d8f4],
+    [0x78775ace589d4657dcc1223aafbf2586, 0x2f0bbec7f6a255512df348122df4b917],
+    [0x80013cc671d68c69a72d5955678703d1, 0x180732ee6d3ae2aa4866158f54f4d8b9],
+    [0x974a7033a69be30407bec6b153590703, 0x05a0b0ba16b7b0631dbe2c92f9121284],
+    [0x4b664edaeba4106e1a57ac77290193db, 0x2908a5a3204a9a144f6565e561bbfe9a],
+    [0xc66cb5979908a8ca8cd7f2d8a7029c0f, 0x18c860a5d2537a84944a1075e804c32e],
+    [0x90c364e37f7a1f07ddb9ab292afc0e91, 0x1ef80617b025bd54de5361dc2fb16cfb],
+    [0x429464e7788a229feae561be3d62c2c5, 0x2ab495215ccdfda498510f6d5dbfdac1],
+    [0xcb2c38e67d64eac680aea6c19952a7ec, 0x251dc073bf23010a06bea25560e1be3c],
+    [0xf50772e8c150bcec0943a8af3f106847, 0x02201c3662a59685a511e2555643567d],
+    [0x72a842f97627a82065e5e67a22f465f9, 0x2bb9277b8021c5d354300da09101d24c],
+    [0xd62a2017b6d2f30ed61339cca435e348, 0x14f5ee46878e605585b3ded3fa8b9b7f],
+    [0xabd855f3fba503d5f5e0486047bf3fc4, 0x114578668b59522fc7d794abcd2497ba],
+    [0x228017055096ee68cd33eab3773a1146, 0x20bc06a35fe07cd507d0dd0a9da6987f],
+    [0xc983d1cd94cada67237670051d5fac5f, 0x184801f655e82f8d32b94fb0612baa4e],
+    [0x892147a64bfee54b25d3906ab5b3490f, 0x1404d5fc7a2e8940778158fb2d303364],
+    [0x09d71cb59d6e303db0560b0c1ee79e0f, 0x251df4335e5164fcbbae73beb8cb57b8],
+    [0x82486d3f8777eba4103eac7190faad57, 0x09a45be49f35c4f987b58d749c78c690],
+    [0x02c0051f1f203c788fb907df00e51177, 0x2ede228b9b96b21dd5e1e6ea260a87f9],
+    [0x07e96de582c2c923a99cd14e45d6f209, 0x100a3118e3248ac400e6aab0e4e6b10f],
+    [0xd92ff71b6a6a2b32f27adce084894e10, 0x15872dba1f1047ac1faeac076b57513a],
+    [0x039da22e42959520737e4a6843283fa9, 0x1f68a35d81c05733a573076ccd7946a4],
+    [0xb1515db27ea710af3683a8919e99a282, 0x2a165a1a2b018ab2fa4e581d7cb567d9],
+    [0xd2ef854c66f917ae526f719fb40c6759, 0x064ff94b98a0588aa924e943ca627fcb],
+    [0x4b673b72d673aaa39f9691e08283d5ef, 0x1c8349f898cbf2d1fe6db936c53bd356],
+    [0x0576739c07050bcb0158f156db6e7c5a, 0x2e4628a8655d2fc84a32f10e0a95e92d],
+    [0x517e7ea1e4800a22c510755a4a1ec152, 0x0068316de71fed80213380a8757b3a9d],
+    [0x5b9f5f1e8b4ebbd995ec5b8289a54289, 0x229e32cdf952fd49ddac8bacca9ec0a8],
+    [0x14cfafb05ad000afa7383f01b9b5b892, 0x26c9af69ab4a7e08335cdfdf33ce102a],
+    [0xe5e48678f97c1bb58e17af4db8f232d4, 0x14bc5140566915518f20d51498ca60f6],
+    [0x1291bacee5c5226fbd0cdb308734339c, 0x22fe06bbaccc5b18b4a8bf2cc8c47e35],
+    [0xa81902626de0d81d80f53d75d247af04, 0x1dca1403773581144b083aa9b9768db5],
+    [0x4b7e3025a592d4625f420c45c56a1f52, 0x04152e8ca329e892a4503613f3472ee9],
+    [0x261f379f1fbb48a8d556cc5ad7914e36, 0x13ce7edc9f1c7d2ed533026150f2bb6d],
+    [0x81ec03c394fb2eec544a631f4e1cb1de, 0x24f4fa36a48be01d0bbe57854d48399d],
+    [0xf9ccefc47bd5c487a0b3c29a01e559ec, 0x26e0659af8c838aacc352710f1433d9d],
+    [0x46724707601b7a4a72476c4f73e7818d, 0x222f80f3327ff730c8ca585c067b5ce9],
+    [0x9c58d993ee168ceae5ebed3049ab2f87, 0x19cd3a66216e859e433f64e4f9f3e2ad],
+    [0x101fc0ef222e3c2b44d8680e8a76188e, 0x0d01aa823ee846c2cb695543c8499803],
+    [0x7c7207924e177d0826ebcc45c76488b3, 0x2e17901918cb6f4fad9ac0d387757aa7],
+    [0x15ce5f34d17c016430a9fa75bf484ad4, 0x2e30cc49639069e2101cb479c7281e87],
+    [0x2b70249db92a74e1404683b2f00dee5c, 0x18694d18fe6758b36c38ac70d1344c6d],
+    [0x38b4c52af4e55fb5be529996b56bffbc, 0x2597efc11329ee3e0c8bd40b4a786817],
+    [0x640893186e6ff6c045dfc91edbbd1c94, 0x003ead8cba0d24715aea9ff5443d25ee],
+    [0xc7b7c04cc2afaabd7f3bbe071cf1ca2c, 0x1e17e4583d68f0e1e7234d41915f2fa3],
+    [0x97b7547bdfa1433a5527168ee9f9d5c0, 0x2d05098ef2828d71e8758837f5c973ec],
+    [0xb7c1b837ade64a37e8e1f0d436051a7e, 0x09220ca57e2ddf5a1b2f2971627d1a74],
+    [0x868adc52b4fccfdb68b3f5fba518e03f, 0x22ee5c6d36a980b7a428f8afc999eb09],
+    [0x77bc0ca8bef907ae8a4b4aac270842c1, 0x1b70154b6e76cf9bcf4411203db1c19c],
+    [0x00f58c8605fd112ef6b7a25baa7dfb88, 0x1a2ad9a834a4d41bc0d5bac3b7e94531],
+    [0xfa28844595b1f2b36f6473ffda38380d, 0x075aa06f43a273433cf8ee196e8577e5],
+    [0x008f994efa4f556520f11ee8bcedf82a, 0x070f884d5d0093ff75fb09ce934c8733],
+    [0x12d33a73be02f078f7b9df6f9225ddf9, 0x07d5a9301209de0fa56d9dee45f8480b],
+    [0x018853218ce565a8f615995a0f2466ea, 0x0100dd4a2833b127fd800d28af04254a],
+    [0x9397bcb0089ce03bafc5f0e627e0d836, 0x164c79e2906202e73bcfb8105c5c1082],
+    [0xbad1d849fad58d34163d741923f8fdac, 0x29dec9bfa2a12f2fa1bc9158dc1d3d1e],
+    [0x645f33c7b0ce03d55e07978096257f88, 0x27d590c95d3dddcdd43e3c40f7579dde],
+    [0xc4e962e75955401c1e6a66157dfb965c, 0x0c32e71be02fa8302d8abcb8ab2c6a3d],
+    [0x60a3126433bf9b9e47710d298c31e9d3, 0x0cecd591ea4f3cfe0c7d3edf52f4f93d],
+    [0x4d44f1a06184aa81f75aa227335f264e, 0x070627a0421fde844216f5f24789b3f5],
+    [0x7fe285811ec2cfd733f1206f449d1de6, 0x116f5232abcabac8a8b9f1a3ac86bfa9],
+    [0xa2ba7d3bae30aad7a35cfb4a6d7dbec1, 0x2a8ecaecf1355850d03fa2f1a7904cf3],
+    [0x24e491ddd37195211f95918d3d39b911, 0x252ee8a2dfbaab265c060600b3f6e19f],
+    [0xd3c120aa31e2f1b4c89054d5218258e8, 0x23ed5e73a4223df85afe948fdef2b6e0],
+    [0xbb9fb86173625037b86ba4c62a0600fe, 0x0ddef510d2c7ba32e4331ddba93aa688],
+    [0x5a65bf8ad06c38a82d38d317f0febe8d, 0x2339cd5cb769acf2dafb5073bd988be1],
+    [0x762b585c422bf8dcb37afbfdf076ab49, 0x26fb4a101c97c06acc999150483d44aa],
+    [0xfb8ed9e0acada998ab5cb833b09d1858, 0x077d5874dba21d260cb02f280c5fdf78],
+    [0x28511313b40b4a9e812b69aeb6113220, 0x22ddb2bebc8cd1c946f8522d73267023],
+    [0x2a3da64be2c3f381698e2c0ddadc93b5, 0x2e7c43c6222e6323f682c05c571f6aa6],
+    [0x2acdbbfa0a9eab7a7c94a50c9c682de5, 0x19ee0e60fc2da7a295371c667ce85077],
+    [0x38c0e199ce5968e57a82e780f1fa79b9, 0x04d46d32c131ca79258ef2c525659b81],
+    [0x2635a6dc7ea3cf3889a5e8faca46bd82, 0x2ddd25bd37dea23386a030f4bcc1d438],
+    [0x8549347b44526893312fd5ec3d2726ad, 0x0be3e9d472b8118ce3d01a92fc168de7],
+    [0x9594798707b477f343b48ff528e49b8e, 0x27fa8f235db616a5909a2f31896dc8de],
+    [0x6a4dd6495e29ca47ce8b96f8c87aea21, 0x17226412ab2fab6223eeda0b84833381],
+    [0x9bdce68646a435a6918277a21d03d4a8, 0x008a2aca609e6344086eed8a710a3546],
+    [0xaecf7bc84098dc1c58232ac25093e35a, 0x27a10619eec3b5211b7ce9c318df7edf],
+    [0x81897fe0f1adc5c0499755f25497fc46, 0x06def2df638eab9899865393563ec8bc],
+    [0x538a7faa110a438b9d0ec7be8c50c098, 0x0c86bd5b00467b98563b7ad1b362a058],
+    [0x8be8845df471e4d4fb3f2e7f30d90214, 0x02237c3870ca51c0c9daf086524707a7],
+    [0xd00530e01e2a704d70494cfd3c2982cf, 0x2aef4c14a514a36ce1afa9f2c5f1ede8],
+    [0xcea281735a913d24eea75b55833b328f, 0x0f425f81861ada856f01fcf0d5ef108a],
+    [0xaec9c66e6cc8fda18999d5baa0b4630f, 0x1e562f2a3a83be1321eeaf564fb19f57],
+    [0xa814a756782e2cd85b79e8e1e748c705, 0x0627d8f4c1a8c8f8c5e1eb617598954a],
+    [0x380c25992e5cd14c670fd3c317b0ea5b, 0x15e5ad36f719cca83a41f67782388cca],
+    [0xe500e3529cdefd4f0b18d16c5e757ce2, 0x0cc8d138d0854f9b62697b7791da960b],
+    [0xe9de247d2cf8d8175086f975d24c1e8b, 0x17ff555b52d28be1b3c46c80946df519],
+    [0x0a02693252b74f7fdee8bd404e6c79e6, 0x0fd5dd57c2cede876671978d2fd289aa],
+    [0x93a1da1fb9e22b7d0c6733bd72f06f69, 0x203144a77c72ea89fcaff39b141def7e],
+    [0x1e1c855b5704d8bf755bed7daaf721d4, 0x1979320524dfa45aa8ef35eebbcf6f62],
+    [0xf8b894d7f8bbcb95cade4e736ea28998, 0x089b6683b1f849dbbab0f1092ca04880],
+    [0x25c908396b5c3f2bd51109517fa28162, 0x2c2dd8e6610a0495784aa08bb9661fbe],
+    [0xb3a33249be5cc6b56dc3607b17e42477, 0x2b9627b579ec4019306886e1ff4003ae],
+    [0x049f8c988936696519344ddd931174f5, 0x178f70a99501a6e05bf4ca79b2d6da47],
+    [0xa01b523548ea0ca23a3f156c04083abe, 0x224ffa0f9d0d57eb0c7d718e99cf3b6b],
+    [0x7d304d88cdee555ef67a799759c39ed4, 0x00f2ee1e2be1ece2cd8aef1dbfc82699],
+    [0xb1a3d21d3cbed1930dd1ba2cbf7de236, 0x25757740ab14ca93745a76000e24328e],
+    [0x1b4675edf79ee79a7f280eae6496f16f, 0x0fa6b963bc65bd930510b3ca6e1ae62c],
+    [0x3f4eb1f1117d14c1caef37beced0e32d, 0x0ccb794ddf870bb92a9cba5f859fb3cc],
+    [0xca00fc23ea9447a7c6b68c7f6ba50005, 0x123fc6d306f17fcd5a4309d4fe6e0cc1],
+    [0x2a09814a9557bcb9fc72cf90f0a29961, 0x22162170a1f845eb0aa1ee7baea48621],
+    [0x422c9ee861c43036257242484e497e3a, 0x13e385a5cd2fbda978dc7740e927ce8d],
+    [0x10cecf47f9f57e237bb4be69410a7803, 0x13bebd80c2022724017d8e3eb84c2698],
+    [0x158a6fc84f4ba6f0246c1de057d8ff1c, 0x13ed291502566a7033b4c4607245156a],
+    [0x6c541142742179217095a6e54f743247, 0x2ffa06928536af91c7ac3b7714f204e2],
+    [0x10fd55a368624c148b5e528d4313183e, 0x2a6df655e926c4aa029749aaa7c9a40a],
+    [0x0db502a580b8a4cf8651f1bf6b9b0238, 0x27d8531ec6121a3eca74db524323fc10],
+    [0x06693422b3846b3a2b4aa12a3b0e747d, 0x1ea104a44d82fbe1bc1ccfd4626cea0c],
+    [0x5989114a4667251b0da5915412ac5ff4, 0x1d69a3852720a88758034d954dea602e],
+    [0x2e647f6926da913cb5e10337d7f3d7e5, 0x1b8e232710f6c1ceeff9c1086d49cd8d],
+    [0x169f88067574ffc9210f7da599c4fb3d, 0x2d40d3f8e6f9e3aa6014c69630dbfdf3],
+    [0x02f00cc696df64f76df54a6fb6f0508b, 0x24e1c2f60b1cd36101770cefc4334a8c],
+    [0xac0c9cb5cbcb4b4054017abb3db8c04d, 0x2408f2eb6b1e958ccadbff81c9a44913],
+    [0xb07c1353dad96e87d14e5ea33ff8ecde, 0x0e3c6ecd281ba2e16aa37dacd2abc38e],
+    [0x6cd0f6e3212e254090f0006d9ec543e8, 0x248c7cfb7ba2e71819bfc755eb9e9a30],
+    [0x1226d8ce39574d72b4ae4f39d21721ed, 0x267fdcdeecf69d799e79f68632217df5],
+    [0xb261603c2c9afcf4ad831de9fa34bab9, 0x2bd9173ef276b7d4911ebf78dd15342e],
+    [0x0fabad8fa3ddb365c1d8582f4dc65f14, 0x1cc0af33d07e50b2adda334672300625],
+    [0xb05440c20dd9754bc59c11447caecebc, 0x221f2bc4b7dce9195f9ca9ce8fdeaefc],
+    [0x8dbf1996bcd1b3489f3c2499ecf92b07, 0x18ebd5c63b0bf20f45d238fd0cab5dec],
+    [0xe58a5efa19ddc891be47a5e77e9f5a4b, 0x2f338bc5a586f2774af3beb92aa92ce8],
+    [0x9da5907f8c7118ae492939bfe4e9fb1e, 0x0556b9c3784145eb4f2ff6e0c88f4198],
+    [0x51238a3637e47022a3294cbde38b2284, 0x1ec100367f65684538bc6956805ed370],
+    [0x073c8baac6e5db87e26c215bf448f84f, 0x1645a29dd4527ab91a4b798c7096e832],
+    [0xddd375e9b3bfb42409a95ad105ac9350, 0x22a78abf7d5b362a743ed52ef2737bbb],
+    [0x1a49d5f181e8036540830bb18686eb37, 0x1a9921dead26c55aafb3ac9f3f35acb6],
+    [0xa588272465d319e15fcc6c3262191aa9, 0x0eb8645b0d2b8f0c3927d4643574c0b1],
+    [0xa47c31b5592c4afa9077d60e5e7ea655, 0x0e48af0cb275af55deb825a34ca48904],
+    [0x1b5bc894ee6b62e626a62e85a3ea4b66, 0x17dfbac5a26f06fff967cfef597a6da1],
+    [0x6f78917d7fbdbbc1b3631027c866aaa1, 0x0dffbd016ffd2b795226a83edbdb3c5a],
+    [0xe7ebfc750e16ebd1e2c2d13825094310, 0x2f948d5f70856b5f23a4338d244f6ba7],
+    [0x9dca60fe174ea321d80435301511713c, 0x194c9f7615cbe3eaa0f885c59e420bb8],
+    [0x663d621562fb3701240cbfbb4b17b3cf, 0x16e81a0540143cbbe662c7ae726051ec],
+    [0xec78decdbeed34bc8eaa42927ea6eee2, 0x11ef0c2d88131f715e47e9a4953f789f],
+    [0x1d1d5c3e1414308db7d548400f668535, 0x0876e45a4f862901bfc620a55951802a],
+    [0xa8676363bfcf3165317dcc98017e6c75, 0x2e78d35e1dd86776a4ddaee1192c055a],
+    [0xa444da42164b6ef289009a2cd7d15a6e, 0x19f6e589ba384db3c36adae4bc034fb9],
+    [0x7acceadace687aa735dc9f6ab14404fe, 0x09fed90b1f10f84f6739bdb1b4b50895],
+    [0xd5192adcd70dc33721714137221f4831, 0x055e35bbef7ce5ee7a3603222e09005d],
+    [0xf9bb736e0d47cec22a96e7cac65a9828, 0x05e8dc359ee98d3bb6990fd928ecf056],
+    [0x752a7bd3943c7b97e038bcd2315d290f, 0x0c732297f8b0c272fac76944ee969930],
+    [0x34de248310887dc13cfd13fb6c2a10ba, 0x16703fb8b02c94ade315d5b62aa1bf78],
+    [0x0059635d01fd6fe40f3f1e059d7f7f4e, 0x28715ac5265d7a812af4e39e43bf9ac8],
+    [0x227b480a6aca67ab1593ce6f837bcaf8, 0x2f683b0027994e095e71eec8475730fe],
+    [0x3c5aa6140a858f21595b9a08da85b323, 0x0854d9c3be72187a2f198590decd4394],
+    [0xb9101a3cce55c66801a0d379497deadd, 0x1e3d592b71a473c85b2104acc1d8db8c],
+    [0xbc1d7ce047e83d0e069dee8b95a9a70a, 0x1eb27377cfa38a3967a71e697725c195],
+    [0xbc2d657c47b122f4e3ea30a659bdd9be, 0x0045b9fd1e0f115801232c7aaafb5e73],
+    [0xd68295910dd39245f1e1de65a87dba6a, 0x027aefe3d1130e9394b61a804dfe2231],
+    [0x625e4c169415487d4df0482a9b1df4eb, 0x2e4c5fa1a42064e556fc50dd7fca7b48],
+    [0x2e82441be0b90523c7b9b9f74dd3315d, 0x05767cb28f1019a39627a19196a7e467],
+    [0xeb3f945e99a6809502c5439c807fb4dd, 0x17e443e32ab352d209d3e038d1364b24],
+    [0x4acef4a2cdcfd8c335bce42793513e43, 0x017a0c0d83cead8ffb70978edec1bf3c],
+    [0x20738a481a7a76a19bf1030008a252b3, 0x28b749b2a9becb34665e38f3c49b767f],
+    [0xe0acee47da26c40436c3f1ffca690356, 0x1f5252eb629922c70f2fc1b5ec5ea0a0],
+    [0xa7724da53ccedb3e56cc26b73358aa0e, 0x29324c40ee7bc85d33a056c3fa8f44d6],
+    [0xf8bec6c9abede582054fb3ecf7860cde, 0x00567e9bd8a2b947d75b376a9a1af17b],
+    [0x2fc9988485f6184355c3176d5c11e8d6, 0x1e96bab6ed6f45550f2621c0adad747c],
+    [0x48f6cd7c1f61874a0836f599e995a399, 0x08d47dc23b1ff527992c9eddc91259a7],
+    [0x0809a0b888c85743af671050cf6736a2, 0x22733cdd318150f40d89d1ac142f2675],
+    [0x1de6bff4a377803025864c5ca1927347, 0x2168174bb9c45be2ee87441174a24fd4],
+    [0x65af4a9267b5d62083e7b8726043a1e0, 0x0b48fdaba88524ef8d0495ba63e8713b],
+    [0x35a4ce8d7b733a9b17a866e50b3ec8a9, 0x18658643fcb63644520c0fa02dce2329],
+    [0xed0df05f6c6b28a3834865fd4974bc21, 0x2fc94cda5238fc785871bca97960b299],
+    [0x244fcf42c38db35513d65c6d5994ab6f, 0x1a54a91a89dd7e0ce87530303c12ef76],
+    [0xb2d13f04d4673537b72510c5c4c3da22, 0x15610d8d64ba02952f4170eaf42a27cf],
+    [0x42b277467d8a0436cf66ea44a6661212, 0x13597b4707657f437ee42087cf8b5890],
+    [0x7e3871d3f16ad0c3845d5e961a87837f, 0x2dbb95e5114e740028bc5edf58b2e9fc],
+    [0xafb43cb6492b2c363ec71e11f86c52f9, 0x2cb3a3e7ce16cad9596eab92d772f6b8],
+    [0xec27de7147ce50b8edffeca5d70020a4, 0x286e8b9468bd2d392dad5d90ef65d28d],
+    [0x226237b077cb76ed9f6bbfcce9989317, 0x166e762a73b519001d753737d7d908ef],
+    [0x78a57fad3845e7c9eaf4be54ab844f61, 0x1dc5bee6477d7e7879fa2f33ba9adce8],
+    [0x41d614abc7b34bb45174b40d3877155c, 0x1f16f7f1cd61d9563e064c86b508afec],
+    [0xadf9691e4ea5f6209f8751af58c8eb15, 0x28df04cdd7f79cb06fbf044e631ab11f],
+    [0x61b7075d38a7c0b2ba95646526588fb6, 0x18aa6490047ab52105dc1e60f4487624],
+    [0xbddf4f65388cae8357bc6bd9400ab99b, 0x0076ab8143a290792147c6a6465c9a83],
+    [0xbd2e344b403bbd8a26f821bcb6f1f8de, 0x2616217a94f407053cef3b234cc11acb],
+    [0xbfe1bc4e0301ec1cbd4e260d81675684, 0x2296862cb9a14424a34d3a11080d6ed5],
+    [0x0fda227a3e7a40a4834e513f3ef97c53, 0x0828428022a299903406314c159732bc],
+    [0x1780a0719b3f590175b394f38279c77b, 0x0771ff9f397469dda6f20cf1841e702c],
+    [0x228d06dd4567fce1444965a749567975, 0x0dc489dcf61aba1104925eb9439a4f44],
+    [0x3b6ff11b2ebb64a63bdb912d43865fa1, 0x104470f92923d3b9dafa39eabdf7eaa2],
+    [0x35aa6586b27cc9ccd4f78efbec993a6d, 0x14a490ce09c929505ad223e2f3971861],
+    [0x4cf330835825fb8ac9563fed9a7853ea, 0x206893bb747ca2504461fb24a2f72eae],
+    [0x3fbd4f37c80ba508618c800acf626546, 0x03c2a738e753f35b068c6ac8171244c2],
+    [0xf1308100d34a569ef3646b52cfb06e16, 0x129edb7a013029be80efc7400212cd7a],
+    [0xc7ece1ec2e262f226f4d1524a1ddc158, 0x0874b0edfee05d8cddac89e7b562f917],
+    [0x8078841896f7be41cdf30ad2517b2aae, 0x10a0bab2786cda30537c696bb1ffaf89],
+    [0xed928364cac984e17a6c72a47e13fc2b, 0x0bab661c2a17fbe0221f435ba90520bc],
+    [0xad8dd02ac7d4663005e1380c54f843b2, 0x2042016e686c984057a28aa2ae13c981],
+    [0xa887739d7cee663fff5d6e0710cf4a42, 0x1fc295ac71911a59a81d087f967f16a3],
+    [0xaf4fa291de888e9b1dd32c19cb1a8777, 0x05922697b04ae608cc3bb0d9d345cade],
+    [0x23bc90b469cee37b51144bb3b0e4b9db, 0x271b87d8b895c6e1d864b9cd030729e5],
+    [0x9d0c0ffab8f55c21c674edb9d7bba552, 0x24aa401343e4705f22900fee892cca26],
+    [0xef0268d5d2b76d7271c617004f05922d, 0x1b07068cafe17b55b5e00f7037c9ac37],
+    [0x84a2b74aaf3e5b13a6ee30c90097cb33, 0x1e35bad319a036cc6a2094f2a1bf2d19],
+    [0x03945e039c4ddbe05eff2d5e754fc219, 0x2ab91b9a85ce69e7bea68f4fb5b1fa09],
+    [0x5edb8e28fa7ec307aab14b547858f666, 0x21e979fa3a2594d2365ec70b5b3e0ddc],
+    [0x15e73784ac6b15c596fdd68469d1ad0e, 0x187cffd76c5fb29795523ebe065c9c21],
+    [0xd11d9f91c0f7fde9ef6af2180a007392, 0x18d2ff882c52b396a8c068f88c3d23f9],
+    [0x5c1ac114e599c24e5adc1d9fb91b31f3, 0x0a878a77de615e83c3d430f11f326b78],
+    [0x957a615576d7620a0b9af2609d39afcc, 0x286b9c6381700288bcc75d020ea09c45],
+    [0x89a1ac5031c35ee1b6c422d9080c3f12, 0x1c4e5ed7db96db52059ae2da709879d4],
+    [0xd59457b96630e0a5c04912092a4d2abd, 0x27141679bb9260a693b880784ff5fbc5],
+    [0xb5d019ce7dd4144a8b49eba02bf085fe, 0x15e337c65fa58edb6a74fdbe7cdd1901],
+    [0x91d88a557c69b2a956fd2dc90cae3631, 0x08256792218c8227eca89f5114f1e7f0],
+    [0x5e83367204ee67209ee7ed1bca5185da, 0x0c08d19af9c30a31acd3928ffb5a57de],
+    [0xac29246f93c24a1bd0f9d2b60709b6fa, 0x1919839ad91604c370f237d2a0aa98ef],
+    [0xf49375acb5958bf944c77ff6c3561a41, 0x15bf65b02b92f40b9f0ea893ddbb0572],
+    [0x202d335af7bd2cf032e3a7c75ef033b5, 0x122e02f2e0169809b22def001acc797b],
+    [0x6a21f5176d380141fb39977305182469, 0x12075100dddb8098654e718605211f7f],
+    [0x1bbef018a63f50f68d00b72d8adf0878, 0x2dadbbb8fecf5ccdf01f73d0cc60b15f],
+    [0x8092ec0cee24c9fa3009edcd62ea8b71, 0x14334ece05a631ab375e7cfe7b89fa0d],
+    [0x5ba5479d8714a09f0f6de08b34e3942c, 0x09a51856d49ec654f7d44aca57b17436],
+    [0x5ce9de018f4a4647ac1f685bb8f6715b, 0x048e8feea9b828528dbc63cd3d811ed0],
+    [0xa8fa4c13e7fa4e89730a76253c9b07f6, 0x204ba6f18193f747ff10980957022eca],
+    [0x9bff4fa82b79fde5d0c9a0d174df130e, 0x0079e1be1622edc5742e4f811ea2c037],
+    [0x4e8d4dbf4360c81d1ffae4d7683f637a, 0x27e48eabb0ef3b22305ab720ee4c7f41],
+    [0x4767215f659c91ba354372d999881d5a, 0x15571becc7f5454e45ae416a6b357627],
+    [0xedddbd2ac1df4f5101f7f8cdd316655e, 0x2e6a4545ebf7c2e44af91ca7192eec4a],
+    [0x08606cbf8a07524457492867f8f6bca5, 0x2de19fcc78800027b1932cdf6f9e9592],
+    [0xf3c4bdd53d6382ca86b113f3209d55f2, 0x2e1ca8efd1876e209fc2d62cb14446e8],
+    [0xf03cf8f23befc4d7c6177ac00ebad368, 0x154b3f85eaed8b65718e733ca2ca7c8a],
+    [0xa5a50503860d20c5cd0ba2a2cf6373bd, 0x29e58f39555d9dde7bac3df18d8eaeb2],
+    [0x504cd2d86a12c7d937ff518e57b4c3f0, 0x032d32032e52103ad798fb36ef7c0681],
+    [0x5dc7ff12d20888d02cec650e1a245a3a, 0x281de0191d30af73105e39dc174015d6],
+    [0xbb062bf0afe1419133e1ffdcf70c808f, 0x0f7e76af800ebab14281645d290607ea],
+    [0x47501f6f77a588a75c014d46283f75b3, 0x27ea405bdfc6557a4dd338a2bca98195],
+    [0xd395ac82381491a80c825c621c7ac22b, 0x0570af543efefdfddab63fb05d31edca],
+    [0x894b0a229c8e89f56ebaec4c46692bfe, 0x027a797e0014302478a413cede7a45cd],
+    [0x43554a8d3c7e41d91719b36bace81516, 0x1dd0e283a8ef7d0f4966a517687fc532],
+    [0xb73ee5bccbb43a05c2d8be6c59979242, 0x2006444695987560869df6dcde2673e5],
+    [0xff3f6d83ee71394008831d24bb1b6cb6, 0x271926392e5f1c94437afe2c2969bea4],
+    [0x8d6e1f00cc82f00176935a8b54cabf8e, 0x1b607ed2f130580d9a8c20ea26b33b47],
+    [0x95c10d62b8b981784b0487e63df03e0a, 0x0b7a6e33808d217a9e55c23871cfdfe4],
+    [0x599b79edef17cf70236ecb1ef872cbc9, 0x28ac790d462bb345ab80cd1670403943],
+    [0xc498be314210180c6c3077231226bb94, 0x2f8d745b1b4750fd2f932f7913095515],
+    [0x9523956d8bc684092eecb7a7d56d71be, 0x2a3ef20e60142f17d2f3b82eef613507],
+    [0x772d067489d42672b988cb638c71a0f0, 0x08119b14b83922e4b224697c2c6536cf],
+    [0xc83d91e7ddfd5110614a52d84ce73205, 0x1a4bae6c9cf73ea22739a275bc722c8c],
+    [0x250046c09d5326312d8d94cdb384d1be, 0x1d659aebe4b37b888be3a7d7f23e362c],
+    [0x564458d95f732aae2406af4c03969f8f, 0x28e2fb92bf6da4e6b96730479f170760],
+    [0x786ede8e6e14b7c66311d47b8778aa68, 0x10f4ec3ad6f3ed3387729990456c9685],
+    [0x3c595de6e0aa51c805272ae1bcddc2d8, 0x2ad366d90419107866504d4d34346e0a],
+    [0x0cd8f80351724d313ca02ad24c6dac81, 0x02f1dc29a06c64c9e43d3379db0f2e8f],
+    [0x74f173f37d0e4a1747b7f020652da64e, 0x20086a672c397e65cb37cfb64ddab080],
+    [0x458c3cd8f90c825c1f36110ca2ee9076, 0x0ef2861f4ec9ba5fec74ba22c0b7af9d],
+];
+
+/// MDS matrix for width 9, row by row.
+const MDS_9: [[u128; 2]; 81] = [
+    [0xd1c64bc22f1c556aaa24925d158cac56, 0x0190f922d97c8a7dcf0a142a3be27749],
+    [0x89f6f8f74d16e4fd02f3675d5fe2baad, 0x1f8d3a9d2d31ab32d9bdb6375170dbba],
+    [0x5c3c631b346854523970eaea58a37df4, 0x230c694139416f40422d713e13c056c4],
+    [0x367624afc2833ccbb83af92ca0f6a334, 0x2063a461ad06315ae5992a53a14bb598],
+    [0x06dd68cda2383d64ce365927098c046f, 0x0c574e628f84b6ba9d17b9d2bfd4747e],
+    [0x9becf3fb6ca02c1b5c61b5ddc53ceb90, 0x276428d88040a3e0f442424d2ffcb8d1],
+    [0x5b04a14c38fdf1494393cc6fdb3e42a5, 0x24bdf6101b2f223174e869d6aecbe8ea],
+    [0x4b68e8274943f4dd8b0c64eca25dc68e, 0x180fca184150c0e354b4b3a4a075824d],
+    [0x1bc2ad24453c1bab26b726b82d756ebb, 0x10726dcff87299c8532159976607633f],
+    [0x9d470cd7c6004106caae95c10c424419, 0x0607cd8ff9b889773c9eb68b8a436646],
+    [0xe7f6a18a255e4376fde3eb31dfa51d79, 0x2591580b73e7b5d5f34d9fdc0cf7fe3d],
+    [0xdcc1eba21d429132c2260dd3d12ea441, 0x093cff12150aecb3465c4c99f044be85],
+    [0x046c3297ccb65f9e0fe0d50b4db44ec6, 0x059116da88a081267dd888204fc6fb79],
+    [0x337ddb661043d6c628cecb8f55173bd8, 0x2c948497373514e7d9846dea9ddf5755],
+    [0xa14e1b6ea31c1279b7c3e495deaf108e, 0x2c2729812be9ed432e75709133119cb0],
+    [0x070e455ed63377e910be087fb79bd191, 0x2280d6d4718f9f06b551a988408f0515],
+    [0xcc83533c9d555be398afa09cbf95d6a7, 0x1b1bba125505ae9675bcec7c9af579c5],
+    [0x844136049ffbca05ca201523032c64d4, 0x17013257716d3cbae28e1c272cb6aaa2],
+    [0x7b182da26908f6ba6e590adebf91b40c, 0x1da524cff60b64856dc1f5dde0ff62da],
+    [0x49c2c65db48672abaf24c5b3813c0e2b, 0x2bb4565893b6ffc5799139cdc1511587],
+    [0x51f6fd377f608368c12ba4e3bf89935f, 0x25081a00fc20ae366b1fe7ea6b09e62a],
+    [0x3c12c9c475ade13b388c7ec47240be11, 0x1b43719cd10b60c5647c097d1fa84b8f],
+    [0xaa54d9a19ab9c7f20a0bedcfb9d191f2, 0x22dd201ecbab94a2ee393796fefcca63],
+    [0xc0f433d543b79c668cf379b6623bac84, 0x0b8e79838cd30707999c621621e77e69],
+    [0xcd0538572aa6122ca68ccced68f432f4, 0x02082d5647658eae648936cefbedfad2],
+    [0x31eddb574c6a5142c1c5a48c51f82e81, 0x277bae2fc597a536adbe9c24bc8ee817],
+    [0x6f7fa69a71594a290eae7293c5a48441, 0x30354c9f29c920c05e99d7cc747510e7],
+    [0x8f066fe21dab5fa5ec1d4c6e99dd6dfc, 0x22f33eaee3c481e65801b761a7283290],
+    [0xa29c95707de832756c739eab8e87658f, 0x1c5ce77776893d48147444b9f6d66a4d],
+    [0x82ccac28af4d876fc946203070c920bd, 0x2ab8edd22fbe2a1cd3ce9a4212d0efb2],
+    [0x4de82eb7887329cbc83a56ae01dee4a2, 0x26e49154acca24424d5f8d29f650f982],
+    [0x001591ad9919f08ce9718aeeb2bca4d6, 0x0badd77f516d4fa321d1099bc1e51909],
+    [0xdc5ffedd4bdbaa2e96205704da5864f5, 0x23671c5d1fb556063a64b5f664340936],
+    [0xd78d6c9398ba7a4996f116adef14def3, 0x020d7202ccde24901d6ce2d107e79e1f],
+    [0x68775c3e5ac6b5f54285f4a73c2171ae, 0x1d86496995f14264e7a054cc82249003],
+    [0x3d1d05291c584928d97258fd5fb4fb4e, 0x136961746d34fd196025173c4d79bf8b],
+    [0x9aaa4337454e5ba804257787470f2f54, 0x075f84e9c719bb8de8e61083290daff1],
+    [0x1e603d8449d1e070017ee8ec0a4d25f9, 0x175505391c619b75530fa83e51318438],
+    [0xdf10e4f6bde629199e803cd1c426be4e, 0x29c5ce97710c1578a0990d862cf881ba],
+    [0x7bb90f948644bfaa8f80b0afcd4cde10, 0x2caf86cff60521ccc5ec6e2e2c83b470],
+    [0xd0e1b250dc274d746f2f7d0544185b62, 0x24f92793d67638b4abfdf48ce3b62ab5],
+    [0x8a340b60f0733fd4530cd4269b4811e4, 0x02809dfe24c7fd7cd49b97b27150f4d3],
+    [0x8ee946b770173dbe50390ab8c08c5f9c, 0x26709688150ed035f959687edf7747ee],
+    [0xa6fb85637631b306d3705f80effac24a, 0x1e5ae4c295ad5a17e00f973f136f1230],
+    [0x55af583591bcc317eba842df247deaa9, 0x2310e2337e4d53c1635da18a2722178d],
+    [0x7adb6815168b36a1d363b38425437135, 0x2084be9a57e9adb80303a8dcaffd4bb7],
+    [0xf20d2b4e86a0db86692a269767d577e3, 0x06ed1d14dedf763a3065f27e7a46d7fb],
+    [0x2319d9b88c1779b89b1916018f3fb9b7, 0x1324544a54174cf7b3cd8ffc4b86320f],
+    [0x5eea6629b7532b3af246809398859633, 0x2e923ea46d492247e12e0a7dccf6620f],
+    [0x514d68ddd93cf31c8ed2d2cc7f23b22b, 0x000bbbbb87eccc91d5fb07b6c55088ca],
+    [0xaf0f9905e4c76bf54b870ef0ffefa1c8, 0x26010a3f5295bb4481310cbca5384a47],
+    [0x51b8628f3cd902077775c8fa488e7519, 0x0751a4a4af246255ce412c4bb47fa34a],
+    [0x4d9b2292b28463e53e4771f10670ddbf, 0x28d2351619ccc6fb31ad4206e2c064bf],
+    [0x4300cf6fd4953c2ac2c77625ef44ae04, 0x1815e4f7b40a7027878640a6cdc4b285],
+    [0xb6dd7af010747a705b2a6e3398882922, 0x0b303449f1bf4b92d2cbc26ab34b4215],
+    [0x7df569b9f2c3da542d3b8d5b76b356ce, 0x140cdda33bb7a79b2baa851df5ad459e],
+    [0x169a5c30bae52bba280ed517f3f82fef, 0x1d18b25aac98b282e10b5a3a74ce5caa],
+    [0xe54aeb188a84f8ca46bd7e9741b9a389, 0x1ec6c32f513b8f30c7aab42e7d91aecb],
+    [0x1a2258d56893ce5a948d9c645ecbc9bb, 0x089beeb21c346cb9bc182aa2b70f853f],
+    [0xeea84961f8134b1be36947f803527e67, 0x2790643a66038cce4a6488847d8d2da2],
+    [0xa51ccf368d9974cc6d93cce8eb81d37c, 0x159e25e00326a76f25c1143a5be6f53f],
+    [0x8ab61b5e947997461ee3284241f3baaa, 0x2486b884370d49a003fd041e800f0a89],
+    [0x0fbf62fd0ce03b409fecd932a8281b10, 0x111efc136a9dfe0ebcffae8943e679cc],
+    [0xfc09de8f96d72f3b3e0aaded45e18b4a, 0x0c099bc68243537203f97254f08dfb7e],
+    [0x7e843ed1324c1e971179fd1ad9fd51a0, 0x035e9ecc464cb431cc92c3356e8a9854],
+    [0x4936cbbb03a03a5918552fe565270a8d, 0x02f35e921dae87f1284c0c8ae737dbdc],
+    [0x693b2db1e8ca3d096739753dc5678303, 0x23a72c27f4f5e6ea5325d5b368f98b40],
+    [0xb5dac3b2a91f50272876efb17158be2c, 0x0e07b1d22eed54fdf966ffdfbcebb653],
+    [0x90bd541ea41748f2026f65025ea9d2f2, 0x05fa56d431d4bd09625a86d3623464dc],
+    [0xbb2e956e27a5870784fd5403b39867cc, 0x2d6f3e0e98413d5d6a1101b245c9e711],
+    [0x152ed24f309bff3939a0d91d54c67a66, 0x213176bc1a04939fe7d6bc30691aabd6],
+    [0x5753e9617a5154e93f7e43e6860687ea, 0x0a5a1148dad4562eab18162b3914b64d],
+    [0x570bd1675b09f977708b1ca48e616388, 0x1c5fd9060d4e0d999264429a5004c165],
+    [0xa2e3bcdbe756d883716cead6bc7063cb, 0x129f06c930edd36fc2fb7044a6332751],
+    [0x9ba46d1b25566f12c28debcbedc5b4f4, 0x14a5ce1603228d6e6fade57c1da53665],
+    [0xbb39392e2a462850b1f9cd8532f5075f, 0x143617efcdbbc0d27a091f28039eba8a],
+    [0xbfb585bb23eae2a2ea50806bab8b7bd7, 0x2885f2d44be0ecff57d92a4384edb5be],
+    [0xe3b1a8bd702c167559ede97da2c0076d, 0x04715db6d86b513d002589f1f9522f7d],
+    [0x2d326959bada59b99a653feaadf6c467, 0x088887966c079d2e689a8d6d7bd913e9],
+    [0xd027e560048db4c0f96ca9fa85e17bc3, 0x12ded41f8047da3e6c22bfed3ec5bb52],
+    [0x9e6c62419a9c204cc7e8821892431aae, 0x1f47c67a4325672f17b8bd1ddbd80e4e],
+];
+
+/// Round constants for width 10, 68 rounds of 10 elements each.
+const ROUND_CONSTANTS_10: [[u128; 2]; 680] = [
+    [0x8cfef19d290a55fc49d6debd061cd2f6, 0x0e1962c232fd0a6bb54ad8962a82b983],
+    [0x317128953eb47d8d2d79b847b355cf51, 0x01e1f45cff3e74168accd0a94536fca6],
+    [0x5ef7f8aae5ffe96fc8f7d9a02e37bd1a, 0x1367eec3b20cb74953618fd926104775],
+    [0xbd274b09f78c100479f3a4d72956d706, 0x2ff6ad40533afa2acb78c9b91650d7cc],
+    [0x1fdcda0b18d7bd8374ffda68d1a5fc71, 0x152c8c3d9e43d90ad8094831a74af815],
+    [0x5d0ac5597a309559b075406351a20977, 0x241707db26f96df502364d26712c7f46],
+    [0x114a9fad38a530bca6fd06ca1ffc1f56, 0x15a4e4728a197dae6303d0cdd2b6d1e4],
+    [0x987a68748c8f54f385887e64b1075598, 0x0cbfd63f363978c387d656e93f11e336],
+    [0xac27d2895921ac64e93e524e4ffd25c4, 0x1fb594474b8334f9aea6fe5e323fb0e0],
+    [0x24ba225871bce224addb5af901676aeb, 0x05ac55361da931c1b4c560aa8dbabc86],
+    [0x6df6be985029f226de256803ea704d78, 0x135f83314252585f7e011692cd9acff3],
+    [0xe3df561bb3914e314e3f43f167f5c04a, 0x0a68f262c9e14d8a9ee116bf90cd7d65],
+    [0xd6891b6ce78e82a3bd39fb7e51193d10, 0x0a8360a837d4c97b946633c353298b77],
+    [0xc2318bdac830154cc37330ccad9a3fe6, 0x136a21bd13b11857e0f7670e12d23290],
+    [0xb8b659327a03e8e193c15a54dbb73c04, 0x2a60989b8092f8e1c1cb0238ec85bbd6],
+    [0x142cea640d33acafc8deab155f37345f, 0x1b5eb81f143bf326da0f4eb95d4f45c8],
+    [0xa9f9345de911f2340ab7324d0bcb47e0, 0x08903cf6b684826737a348acaa452e1b],
+    [0xf0529ae837766e1a269522105635d818, 0x03b8b717ba10035a12ae4dc40f4210b0],
+    [0x211daea73f80fa53a22f8ce55906027c, 0x0623ee7c19eabd13115398893e826eee],
+    [0x45bc3f93e8b06e76901bbe5bf20db5c6, 0x0bc8b24369ab76647fe0ea278c866d4d],
+    [0x1c5cad9f1ee126c44670b782d46c2f50, 0x1a327d5f540190e1c9cb1e31d857b5c5],
+    [0x184c05bb58abfd43a3fc2dd483380592, 0x1087efac87bfa4807aca410a9c297d23],
+    [0xccd56de9cbd813da270ad7ee699e8065, 0x2997117304dfbb464b5cd4a4188aa4f6],
+    [0x65596866f32b1f8433390f3e1f0e4063, 0x2296482e919fbd46f7a518155e72a559],
+    [0x09a4f62047e4747a051e29dc187525d9, 0x2152d5f164ce09f6928ace2566bebc61],
+    [0x57823a77acb1deb6c48fa267ee5d302f, 0x1054109861b3349e419216f1bbe82f4a],
+    [0x41cda3ade13ece2ea0a0b157d65c2c93, 0x1772f4029540432ab8c6968b3739c3a2],
+    [0x6008d98cbf75be4e3ec3cc1d44e8459e, 0x00c296afdb2e037d7979878671c78a5f],
+    [0x8e34ddf3167d1e353514ec868e729a5e, 0x0ec85b22c62686e52cb79ab29a0181dc],
+    [0xb85bd64311a2576ff352668962c21866, 0x149e2435c20d48a3ec56c4e45dbbc583],
+    [0x5fad33fd7c530699bbd610e3bf5240de, 0x1d44140dcb4597c7214a6ed85bff1a3b],
+    [0x71e1c38a16f610a8097cd27a1325d4d3, 0x03cfb0570f824856fbf41513be01ed32],
+    [0xea3aae89517bc41957eac79ce9761379, 0x0f223b396423edc29d51e58872b2dab7],
+    [0x37746ccd01c2f2be100080be32a59520, 0x1fe6dc2e6e83ab88e84337a451309633],
+    [0x67c6efc5b93f70c410bc76f8088b4a3f, 0x047310ebb459fe860c954517249440d3],
+    [0x03e10e3edc1a2988df768498ad667cdf, 0x21858ce69a019e4cd46096a61790bf7e],
+    [0xef042037db741f317b4d80a81cf98a8e, 0x0da4f87da196dacc964fb6928a51f3ac],
+    [0x908e57613eefd35026358080e329bc12, 0x198eeea981eb256b4fa43f05fa4e1aec],
+    [0xa18ee08a9992f133a417d23b78cb1b0c, 0x11964efd21ea079340c66ad92fdb62b8],
+    [0x0928bdf0f5ec42e16f8c2934877b4143, 0x04b07b1960518122f5725ceadc0c1f06],
+    [0x24487b4c775983cea49500f8637709ec, 0x0246f5fbb5f719b0896e07b4c23fcf1d],
+    [0x9d6583060938cb052dce50125b01cdd0, 0x24d78daede9327f49eba679539f85d09],
+    [0x63ff790d3904cfb3dd10af8af4d8865b, 0x23de932658d2f181f59d7234a67e1747],
+    [0x42287a38c9a5c4c758f72638e3bdb403, 0x0c4f5431daaa994ddf5f3fe6b1df0c0c],
+    [0xf9c6588d523c7846433212233f64d04b, 0x175999589895b6947d386d83a5e6f5fa],
+    [0x74c2e56886a31d79257b2762ee7ab517, 0x02d54cef5e3cad1a80471bc6ef0c6e4e],
+    [0x3df4ecda29c76aeb09437ffdbf01c778, 0x1539cdc69fd93f2ea50dd12ebb0437c2],
+    [0x12cee0a908db1c2808979dedc8f29942, 0x2359a45452824ba97dbc050086bb910c],
+    [0x6228a5af5a6efd5461f634a4476a15f2, 0x0880e5da2125b0842203bb98e5f24ae2],
+    [0x7cfd0da005aee44ec5ff642872764c19, 0x16110fc6c8167930bde230d5df954f10],
+    [0xd6bd5d01e6a31e804d81a2f5adc63fdf, 0x1310c844b70d6a5856f6d18669e532c2],
+    [0x7330fd8de757eb7555765879fe42ce2c, 0x2ea868217a99a6cbe79b442e2152853c],
+    [0x8d4e0d383009f17c4a367a877a3df4bd, 0x29c5042471d909ee4813cd1a36e8eca0],
+    [0x42172a027c6d174ce0ddfbb017d51dff, 0x25e36eda0eab576075e88ada3914c294],
+    [0xb691e3faac0427089e867f9ed856bc73, 0x2d21058ec44674d8493e702aade5bdf3],
+    [0xd39e9d3443dd0ed11d95045409a7c788, 0x18d17c3835ecab44bdb8b67efe2acc71],
+    [0x953e6d6ccc3aa3203b39618f5d698f5a, 0x28a2f63b0e940afd2c78d00de1aa6fd6],
+    [0xa9aa83637910229beffdb91e23ebc10f, 0x02635f5bd9bebaff0ce7614ec7843418],
+    [0x56c4e9c58ea92752d581d1a51702b882, 0x1fb8e8726525268018845f8c826b0e4d],
+    [0xa80c4b43533b49077fb569f837ea1fe5, 0x087e2ce4d2ae5c93ea5611d50c313202],
+    [0xfbc687998cf1c14aff5fc1eab115a7da, 0x0788fa9532cf00f13c817f38ee1bab85],
+    [0x2741a019effbd4c50334b0c15c3815ce, 0x1c5c85c57a1f7587f8d2fcefc1d4e98c],
+    [0xf6ea0637b20e972a63315dbdd5be1472, 0x0c1fb832b7cbe0e7e11e8edc2baf8749],
+    [0x4508c77bfdbc679c1b3f876b46caf809, 0x229758a22b52ec3907f94a117f663916],
+    [0xe01e3a2f0a2a3bc6622c791552190a2a, 0x05e066ca1257d8d5edca65a2921fb5b5],
+    [0x927a358e8eabe75f6a70d7db25a31917, 0x2b1a935a4ff16c2500bdfee2086d9df3],
+    [0xe987f4277f729ba5b924d25b39e0669a, 0x19a55993108088e03fe7fbc2a014509c],
+    [0xb5a5dd6a7ee152aa6ccce44813e95db9, 0x1bb65a3f9a4375ad926d44c188dcfe51],
+    [0x57452e218d8a2e19163067d4af128d37, 0x1f721c4d0db7592f9bda01fd1fbebc8e],
+    [0xae898a9847c2b33c2ad30bcdc4254776, 0x2bd32a02899498a973382a4d40266dac],
+    [0xe94d17d7c8f6dc1d54f58589066e4a31, 0x28585b052d22283650a9139d6361b08c],
+    [0xa347d7950bee431e7165647bf1c5ccdf, 0x03eb34352610227beb26f8edfe466cbf],
+    [0x4ddb207ab54a9b99f07c4044589e0986, 0x1c1347ef418d657abbe6e4c019011bd3],
+    [0x3f044fac5f5b11f754e6feaf54ec4747, 0x04a8aa3b18263e171363e9396847a193],
+    [0x28585a03afac3b2f4c17141203944239, 0x12744a063cabe92e2e18f000afef29c9],
+    [0x69b274129704c021a01388ff8662fae7, 0x10af4d39a72d2dcb65eeeabfca40a08a],
+    [0x35af12906d13652d1d8c6b0f509b27fc, 0x1038c656b332e519f483d54e5222b34b],
+    [0xfb45f01dd65889e5b65128f781d39d93, 0x10ee02ed1176804b7d43696eb87643b4],
+    [0x88248f9cc60af2343098d35d9e4f6d35, 0x1d08ccd47541575c60b567fd9b6705de],
+    [0x34cbdf6870e3818cfe49dd3a4c70897d, 0x06bbf6e32d26e922baac8c62bcf85ec9],
+    [0xc71ee529c4dcc3be32d6bc0621d42355, 0x03ae60403f93a98370688100dcd87ad4],
+    [0x5c39937ac281a3b0eacb58b1012393c8, 0x0fd0f776d267dc3c01a377d217e33a25],
+    [0x8ae28b7e6d5aa4ac12a2dcc07b9ba6f7, 0x2d4ab3b85ee6f55bc17d051fcef73916],
+    [0xb922db7582068d76d552184131e0832f, 0x2ec07b3fadbe296f9bd1fcd1758f95e6],
+    [0x777b86a577c2d2d83ec7cd13db319376, 0x0a6013e37f560f540dbd3e9790600200],
+    [0x2265950e0796c73d8f9e53a767c7e888, 0x0dcbc3cb4aafe08c62731dca44c84301],
+    [0xf28cab915b70eb5568f175f842355c27, 0x198c373fbc6ba598020456f624dfc906],
+    [0xcc2510fb29f5f3607f8ad1256ada07ee, 0x2a9515770f3053638e3c064f5901da7a],
+    [0x3013163f57b8039773cf86348d5318c8, 0x2d4154739ed8a6cf9a92e590812f15f9],
+    [0x46415fc130430a65b24e9f47533077d9, 0x02bbeae4c716ee136c4ceef194e7985d],
+    [0xfc69afec2a75e4e071e6c8f55fb9ef54, 0x192b5b08320766f45be7bba9e9a70c0e],
+    [0x30d4fd8112aa7f169670876b028f0702, 0x2bbe0e0bbda936b305829e3a74a82df0],
+    [0x136366dff544947845b6d487dc7fa62a, 0x0963722d8db532fc95cf2240a570cd7a],
+    [0xaf513f02d0f17dd306f05cd57dc694cf, 0x16010c0ed7a37bca9682ac858fc8d2c4],
+    [0xd0cb76b4d92dd3ff54bbb813f3d09ea1, 0x24486122987e17077b026baa81e705e5],
+    [0x573a918c10dd17e8e564eb6da113e5c4, 0x28da35a85262582b220f7262ea68c381],
+    [0x6bb670e6ca24d27d90dc8efa5e81638a, 0x1d3196aa6209085c34db2f0e2b3dd4bb],
+    [0x166d79a0a0c3c695341fbdb407b9a506, 0x0e56fe4b7ba62dcda0d2f7b9de86bce6],
+    [0xc8c3d270c6bd97f92a18ab94f973dbcf, 0x145fd47676ade5c380b90bf08020faef],
+    [0xe2d430c937306ab3045b39b8b74e333e, 0x0b6704486fdd2a633bf9e40a5c5e4091],
+    [0x924de69728107898b4701a6636df3cf4, 0x276747994473ba9152275d15beafe42b],
+    [0x17c0f83f0a479571d8f61a47f3982d21, 0x12665324c9fc8d246c1518e040a15e3a],
+    [0xca73f69e43eebf24b29cd53fa111cc8d, 0x0288c589327ff97824eaa3dff40bbcc9],
+    [0x3e6b93e047357840123f30724fda336c, 0x2c3b3f266f21d21da33e4c39001c7a70],
+    [0xf5a6391a492731534826a5575e8a7725, 0x1d089260f53d9b6d912460c6a9d9ff9d],
+    [0x7125a03d533223c8667040fbfcf7062e, 0x052226cd4ff2c85dc3fcbbfdcaa0ecad],
+    [0xbed9a833fdb70ed93dc6274865869356, 0x2712f9171804273a9c1973f7e87fae28],
+    [0x5764a759c231199db5d5e0e776e15aac, 0x276f2f781e0c9ca0424a9a5135593be9],
+    [0xaacab81957e268e175f46876e9ac6f62, 0x2b21ffc3ba163ea61b7c0b717d519c64],
+    [0xaaed6c8a3360a868f18bf23736288c09, 0x2f3c2b6874b81531b890a603be7e36ce],
+    [0xf66ec5063c6660ff554913394cd5df67, 0x056721148d9a370a8e8128ee00178695],
+    [0x22d2d2693983e99c76a556e75e63313f, 0x0e1712fd1798dbb4a2976dfaa085bb98],
+    [0x4a13bfa4710cbefe80befe5119c0b608, 0x26c9846c7ca0b8227b6eaa7634cfaa6c],
+    [0x39f0effde9b7aad7a34748507d69a8ae, 0x1ebb9eb479a644d25f53dab3e6dca083],
+    [0x2661a9ac9713fa4c70895997441bba88, 0x1611f5e2add8ccfe9d093bea1d55eac6],
+    [0xc419943c1aa600e5b13bc47a3810f63b, 0x2462c3d5555611aeea4a146b8da50d30],
+    [0xffda026826a98b298672d81c826b6292, 0x0f376eb9e7de4c45ff508a22b34e55f0],
+    [0x2517aa65826291ae6e75e0503c8b8a3c, 0x0e7ff80c3d19a2ad98c8c52a5d19d171],
+    [0x6f3f8538c526a1a64fa65bd3b3fc2d4f, 0x2eb13ff66ab49a73aaad760f5bf27131],
+    [0x5b43a6cb1f83d89ddbd3c56c2a512edb, 0x22a40e530de7dddc1f842c9421192c63],
+    [0x208f7d0ee189823adb47baffdd5ee21d, 0x2740baa318c02fde162c920a6245b0f2],
+    [0x0d54b59c0411e5ca427f8c1c789d29b0, 0x1965522cce8bd29b3ceb508ce40431f4],
+    [0xcea4285fd8a90717c2760232a906be7e, 0x10a0db7c2b0969dc250647af0bdb0d99],
+    [0xcf21bdca3caa0e020a6d88ead1f2bb11, 0x0295486271ada3cae4cfe3721e6d7b0a],
+    [0x8ee313cc40a01c090e5ccb118a1af13d, 0x1d00206d65a92466ddef22e5583661c6],
+    [0x6878e5a6f43fb2666e2fdc5937971bc5, 0x112222605052423fd6da21d95545d6e9],
+    [0x9a60f31a44e5b5ad423ae52b0e3ff2ef, 0x25cfafdb7df32a86f2ff44a32ad7b2fd],
+    [0x9fdaf3d9c73cdfe95011f7c1ac779d15, 0x1a42aca3307df84a81ff5b9fbec011ac],
+    [0xae638028d8e6072a6e4fdff4e039a4ff, 0x14fb6c4e0df0063275d380e67c251a29],
+    [0x51293807a28bc43d73ac4b5640445264, 0x1687f501a8c07c9e43eb4ab2244914b6],
+    [0xddb14182f613e46642919b43c89b8159, 0x2d64ba4067ab898f7b434409319d7cad],
+    [0x6e408f180cfaf05506e11a02d6d4b142, 0x265e19e2a2bcff9489cb749daf9b7725],
+    [0x3ad16ce59c5e8bb5f11b4ab8e5ee6801, 0x1120d9116a584ecad323cb8b8825b9e4],
+    [0x1b0db889ddc3c24a207f5c7c4098034a, 0x135c200f7c145c042fdc94ad5bd2e466],
+    [0xd14e4d8794bf76e66beca2974dc6c65a, 0x2f9d365d9b5f9fffc5491c0cc39e6b9a],
+    [0x609a285004593c52fdcffe7d85812df4, 0x2bb27c1a1e8452f317bf86a6384954f8],
+    [0x38b4e1a5b53ac3b457b26d35d8cc553b, 0x245f28da6986368776968d7e5da1763d],
+    [0xa3d156ca4c35e0084903836bd12893be, 0x16047c3f4ca23c6f5daca5d01ebc6721],
+    [0x5f8d15cc7483de7087e8ad96909c0565, 0x13299dddb9e277b122240d08656164d1],
+    [0x12d092463cf9545b7c62260ab2d434b9, 0x1590dde29e11424a0e7f7d4b3e0fc7e4],
+    [0xa40b394f2818366c76d05b77e91e2b36, 0x19fd60baa2486bfdffffc8f672ab64cb],
+    [0xec02191653f95c7392c01725444ee849, 0x265828edfb0391d860822c4119b39cee],
+    [0xec7793cd80c62ca44cf8b2e415132bb1, 0x023bda9a3c2e8bb1b227b126ea574f97],
+    [0x8b0d2aa414e007ede000714714aa09d3, 0x1b770c804bfb918b1976e3357ab88466],
+    [0x3daa80f7de10ec27351f1369a3729d13, 0x1d84eaaba6521eb866b600749069e664],
+    [0x48fc441be88ab1dc5315a0cbb401c9b3, 0x073a0aa1ba4c1e1e982ef00539183ef6],
+    [0xf5ca8c7b5739e267f12034f705797f81, 0x25ac099aa85c9e2aee6789f2592258cd],
+    [0x1b853001d156eb590e6ec522e6850c31, 0x1c1f0dc22ad54da9f26321b793e014ac],
+    [0xe04ec0f77c8c6505bc21a1194da6c14b, 0x103749ccae90e434a1d961319f96c744],
+    [0x6ad00d046b252d2264d06d9a377e9c68, 0x093c47c9dad5ea61c8566620e4f436c3],
+    [0x734df7ed34587f85d850a5158c0a274e, 0x12d48a22d443711b05bba73fe7cbf5e0],
+    [0x3d3c099bf3c1a779334834f89f7da334, 0x0d1487ee7bfca46febcedb83e9dc73c3],
+    [0xf8e0f70cc54e739f5ae4c48945e534a8, 0x2ebab1e544f6ad4525eb5592152ecb46],
+    [0x157a6485e5062cafa3d9e8523543d460, 0x17260110bc0fa2d859a65a519b679cab],
+    [0xc73e1e0bad2bf3e7cbbe997c40c22cb0, 0x14b60bb1b813533655b74d1948fafd94],
+    [0x73259539a163c78197210cd16c886641, 0x144ca9b18104b5bb5a9d21d73bd957f3],
+    [0x520051215f5ce4915d80444032a3ba49, 0x0b698540c0ab22734bad58e48515ff84],
+    [0xbfc1663c187799bb35958d3613e25bda, 0x1f458fdf92a4c03bce2bf69dd3dd4e26],
+    [0xb27ed377481e2a2552798dc952a0acd7, 0x26c0c3f9f2abd19392330ee93e2554ab],
+    [0x7ae9c6735f9be0a6924293d2ba1145c0, 0x00fe825bdde1abafb480e985d6fa8f4c],
+    [0x2a5f4b828182a30f5195674997b1f734, 0x24deed3988546ae6520a6c20c7659ebd],
+    [0x132ba14aa26fae5bca4cfa0ef71c90b8, 0x0bbbcd555c184aae0891582c61d65195],
+    [0x8e3f674cbaa51f2fc53555779ce9aa4d, 0x0f9f78d85dede378437afbec3f6ba40c],
+    [0x86c83996e89f706c39cf9a673a79c8f0, 0x269cd2fb8c03936aa6e30dfd09c34ff7],
+    [0xc0ab8cb2ab31e62505f799b0a535537e, 0x040a5ed55c1541ad1431b2e0a5c2370c],
+    [0x33018877ec879067a2f9548fa035b613, 0x0b10db52d69a08382c9b0f60d53c5863],
+    [0x80f1d1886a509cab6bc23e81767a2aa0, 0x1e6cc5c4090d2d91f567237064c57abb],
+    [0xe4fad5d09cd05e6e4814b752e51f570b, 0x104f9966b6a892138319ca037d4298fa],
+    [0x23bf6569acb90abfb9d68c495cbb9814, 0x29d96f530ed5cc92c904d21bf560c829],
+    [0xdefb48f9cffc756fef6667439eaba1fd, 0x16b917a241c5e2a1941d745666d8adfe],
+    [0x14f33f03beca54a67d729cff65971fa0, 0x28049dc8703e7eeb4ae5cb7eec9d0b96],
+    [0x071e70959d53871a23a27bcc4a514b46, 0x2bc236714aefeb86454cf972676c646a],
+    [0xc57f95dc299ef95fdce2dd130b0e09f2, 0x0e85e3f0b62dfac04defe89326b1f1c9],
+    [0x1f1ae18f1f841daf0ad5a235793b862e, 0x1576f55650e0dfa819a1273e6a0fa04c],
+    [0x258ad00487129ab3438da90c9ec307f1, 0x2e6d1f984d90d778bc0e0990dbe6be66],
+    [0x0391c6f600a85514de1d5cfd2e29fa95, 0x0a5216c240378084d7410cc778801c59],
+    [0x01fb42532e51e957c735e02403ecd492, 0x16af0c6ec857714b9e9e7932ac8a6b1b],
+    [0xa8e7e4c21578ef0584874d36de491aac, 0x07ef3829b21e86dcdbc3aa02fd246b8f],
+    [0xc196b7442e82b370a1187b484d5d62d5, 0x021cdfdcab1ffdac2baf015ddad7ef6f],
+    [0xf5e7b94bf0a25f8bccb3535ebf1c5846, 0x0911aa1ce608f4234ff3b9f35d9be546],
+    [0xf2a2dbbd4c604df9739082c5db91713a, 0x1c861eb01c4b335ecfdb63207c5167f2],
+    [0x74b6988489fa1922d3f6a130e6e98c0b, 0x032ab5c0093afc059d72265152026bc6],
+    [0x1b75bebbda8acde2d114311e1166b850, 0x1dbb7c4e30b3bdb3a248e0118c9259c3],
+    [0x0ac7ce6870a1cef620b9e3167121e565, 0x295721987b2a072f968216f69e14afc1],
+    [0xd11c38bef5204e1987c6e0016cc87728, 0x0d848bbb53ea8c72c8ca0a933711061c],
+    [0x50088384916ab57ee90e4df260533e40, 0x125437f6105b57329c045376be9801f1],
+    [0x62500d50ee294167b0cfc9af4707ac24, 0x16a6f85cf070a9343769dfbde8cec30d],
+    [0x0dfb79f493ade38be21c0a604073d359, 0x23e4ba166e2b5a1506df9dd4dc74c7a3],
+    [0x4ba9855fab92eaf9ca379d08e856414f, 0x0d7a570d5c131bf78031c66c8ae5fd45],
+    [0x356c02e4c2b07a6450a1005543665821, 0x21793d46a696ee5040f599f482bd9845],
+    [0x03525ff7dfcb5606d0f67f0a7250d0a6, 0x04cf9f12178ba86fab11c12d690e6206],
+    [0xdbebdfbb6018937f680d3cfadb5b9224, 0x1e1c8714aeba7e7549e08477b4365ec2],
+    [0xe503c1c704b3c7d3ff0ed356f9a5bfd4, 0x125f99c81db5d7e74be0c6e6b4476dd0],
+    [0xd2a24e4804ad1ac6e652f0858326ceb2, 0x111bc3215b3bb2a5d17257fa73a77851],
+    [0x2eeb0be36688343826faf639f684b31a, 0x09e73771faea0065c80653dfaa8bff40],
+    [0x1cc9b733033b632070d47bc9474f4b0c, 0x0964f621946810a123469fce6b544ff6],
+    [0x32d663947390c12b02954918a18e47ad, 0x09532bf9f11051f863a4e80679782b07],
+    [0x38a0eb07395c3085b449d3135be322f5, 0x1d14808c54583a620e6587c24b993b18],
+    [0xcb4a231123c303e98cd897ffad598d44, 0x09b88cebccbb2a0a18e2d2e799e77cab],
+    [0x4a8566491a91a52bd788343c429d0a93, 0x24ff5ab38ef457484493054c9bd3ec89],
+    [0x17ba86f9772bb68c0461d115535daec3, 0x13eeaea2298ca24ee7a73162ec3a21d8],
+    [0xeae0a04e46db399eec5c1d49020510f0, 0x11600a36cb41fde2dd26a7cd4a98cd8b],
+    [0xa1f669f04d9f7a4bee13b3c97d3eaa3a, 0x2c7682d3ee4ec3ce7e1f67cce9bdeba4],
+    [0xbb7c7dc67b2378eff707507b2db06cb7, 0x2d9b1db246b004e185b08c953dc0af4f],
+    [0x2583cc95831301b4e3e06a3ca9628318, 0x17f1407c2872d3810b7474bebe3711f7],
+    [0x8f205301065932bc9d526fe54d297f02, 0x089fd58055d20602bdbc2171ae485ff4],
+    [0x42a8a13c514ff97501fdca080fb3bafc, 0x2779eaf53710f3a5848a8dffbe40b5f4],
+    [0x596e8ee1a8cab49d022f0bca8780ec15, 0x07d6c3ddb807b8634c89aef35615da73],
+    [0x22cb66fb8ef1f7dd82939e991cd021e8, 0x1b2918da0b9beea69a80b05a151e6817],
+    [0xf958524affeb28c18fde739c8189cabb, 0x1a21a1409ddfca99e3c0987f97450840],
+    [0x8173e2379f25caa137ab6964714d1fe1, 0x1f9e67e3979195d368172267a6ba3b44],
+    [0xe0ee69474716b08008a9f68a428787a6, 0x07e57931399bcc4119d9cd7b2191793d],
+    [0x78d14315df9dee07401693d199599482, 0x09ef7d4aeadf7bf593c5a79279e9e655],
+    [0xb4fe69ff170ac641b18c7fb105d2d711, 0x285fe3b15f4b43269d4cc0183f9f75b0],
+    [0xc4b9a994877dd778646e9dfd64f24dbc, 0x19a7682bfecfe18fbab22301273895c1],
+    [0x9f112abe71d3a4d19385202c209501d0, 0x0ab794b4433b5d0ca9f3f9236159ee7b],
+    [0x3f69dc6a0970dc05118ab1071a28980c, 0x08ff64f5bb9e3f8be81573117e011c80],
+    [0x0f90c8c37047c6b258c07c0c9d0388a9, 0x2f0d454b49e0366171d95142230002d7],
+    [0xa4bc5c7b8289b0cd4286deb5c93554c8, 0x17b463c68fcba461e149e56eda9e8db1],
+    [0xaa9e21a60a48ef0f4557c03f9b7d11f1, 0x2d1a83ac832e49280df94b1e27bab65f],
+    [0x7132a4ed29f9a8dc3d882209b63b07c2, 0x1e182411560c80d7d4c480e4fd65b999],
+    [0xe7624eca3b83be88647080f794d7fab3, 0x04bc5c19cbb312941ba1b731f096349a],
+    [0xdce4a1745e139e6949f99216acd8305a, 0x0f10a1319508c09aa4427ea79071da02],
+    [0xf4af1d20ea560f48e6f8c04df64f2077, 0x07579d96bab793ef1f740713f70f3d2c],
+    [0xd42433dc148318c99c4e32af13c5144a, 0x1fd8b55d0a28cfd009b298b30e226aa3],
+    [0x7f633d46840c928c0373ca46432fa731, 0x2affd50c6aeba57687d824aeffa55c21],
+    [0xa76e4077bf6d1140d8e428eaeb7a3872, 0x2fc1634b69dc697b668fad3e89c88186],
+    [0xc064f8f74ccd330503d7abfa85f015d3, 0x23a53e514ab262e22eca7ee722497818],
+    [0x22f69b6f132c99ecaf9e950fbf26c87b, 0x086ea98e8a4edb6d8500bca03d53a03a],
+    [0x648cd4e53f5806d499a8de87ee10f2e2, 0x0f48915855b4d191b7c37d85e8a5d491],
+    [0xe40c95637574e95a933d9f2a7aa56c5b, 0x13e0bb95d9d3078c10ed2f731f57f5e9],
+    [0xfeb65f0ce5b44548280f46c16ff51feb, 0x2bc35b0c5b335dfc6f1a9362e5b389da],
+    [0x69db2b07fc25c9cc3138467ca750b5ac, 0x05b2ddcc86a8504b00808acbb4a643b1],
+    [0xf665b589ac278bca8c0fa01bfc683cba, 0x1750d1a47da211d8e8386c198095b83f],
+    [0x643b85efbbf1a3b2cd4be0e7d123880f, 0x1bb5321e95d1fba2b22cf4d75e2ea2c3],
+    [0xd4732fb55370901b3292d663bde41ecd, 0x2a4e8ad4dfa27652839ce179e0964cc4],
+    [0x455122c443eeac7ff1584bcdd632787f, 0x19b984a2d5a0323b956fae09b529bdcf],
+    [0xfe980038727b9e2b33436a55c2f9fd3b, 0x11105f818cc27232a8445ff886ded838],
+    [0x7f5184c555fb52a7f514c6c1b1fa80a0, 0x2228205732fe6b9af281b5b2b5fda2d0],
+    [0x857db85643aa798bc264519daa463fdd, 0x20b4c468bb8e29c186e7f45feeef84bd],
+    [0x5c717d4ce51137b8751b828386a7a5f6, 0x14bcd2fd5c7392bf9d93e49b5855b5f3],
+    [0x4469f98b8d10181f2848029c795e0576, 0x161cb26e6fa48dbb05a5c3bdab4a1a52],
+    [0x411f4d6ddc03276bac261768953bd745, 0x26d537ccd8d4f3e62fd0fac41c6a24c9],
+    [0xa76adff165cd40ac747ed49e61c611f5, 0x1de7d3df97d11a081106a4bd21b97603],
+    [0x07af35f4e223904acc6a14d8c6d14ac0, 0x29800fb0020ae18d4efd8fac1def5c76],
+    [0x1f3f0ea6789be3a56ffb49776579eb25, 0x271271f850f692a3503fb72124b583a8],
+    [0x6f972c935579f2da300d8f3c663481fc, 0x16694bf37fe421340b186cbc817d0b13],
+    [0x6c1d0ad6dd23caf40f0c2dea764b4538, 0x19ccaf839bcde3fb9764e57b40f8be03],
+    [0x2e0ac660f6f75fe34d3c7f3453823d2f, 0x1345bc63be8e3e86d3eacdcc4cf14254],
+    [0x86a1803b9bc1984f836da9e5bde26cf4, 0x07e733e36a63b1c54e453050ae43078c],
+    [0x126d400c0f674115d764a07a053f6431, 0x067df85fc461d1e13b0a744c4f7894d3],
+    [0xd2f43453515ec04257ae1fc6ce5334af, 0x03c2025ea4ee2c19b2f8d8be0a2b90a1],
+    [0x24cc4f8f67cdd8bca3a9276c1f06fbb7, 0x2cbf1dc7af58fa9adb8ba31b19308476],
+    [0x2228a3e71b53afd4d2fe6b3c964f91fb, 0x012035a5219b900dedaec32f62cbb85a],
+    [0x93157acd56e10755d10699928f3505ae, 0x1b8c713c90d62b367382c0ceebebe298],
+    [0x9eb1af0ab36bcf26ff93e4860541dd56, 0x14475f758d5db01a2de2add46c405d93],
+    [0xd47e70fd0e936f751037fc33cf9c4127, 0x213776f1e0fac855f958cb20aa090d4f],
+    [0x6bf8b21107724a94aff2f190906e6980, 0x2acf42c1eca1a093f10198dd5a16bce1],
+    [0x5a6648234abbbf9ca592cc2c53703380, 0x19194b68b898476596a9e2d4056e15c9],
+    [0x83cbbb1fd085fd53bdd1c9217cc5a3b8, 0x0b20a1d9a2470011eb2f8180215400b5],
+    [0x034f7a95d2da2a06433c6c4160ef9c8a, 0x14a7d1d7065ed6f6ad72a0ef3b848d76],
+    [0x95a29a31d92bf74a122a8efe799f9bbd, 0x19142416e3b168abb99babc17ce6821d],
+    [0xf77566a9eda06cc8b6466fe9e1b3c528, 0x2cd1571dfb32abe6e5dfec8371f8130c],
+    [0x30f5f145f9400b5d4d7136a61c6eb458, 0x1cb1ebc4b42052876e83040bde7b3802],
+    [0x15b3ffcaeca1fdc8669e73d8a6cbc96d, 0x05c4ab72cdd1de95419689661e5e735e],
+    [0xb102b64e68d5889772483e8d0e3974d7, 0x24c8da03cd8fb497e4f0450069b5d4de],
+    [0xe3fdd059d01a5fc7c705d60971fa4840, 0x0de8195805706ff74cb251cfb0bad9b9],
+    [0x256dca0c12dc1e855157a59daa4508f4, 0x304ce8cb3cd5519623520a4883d35792],
+    [0xba003daeb792c8d4df307c55c9efa8b1, 0x0f36b586984d1959a79c38a0e06450ed],
+    [0xef7482f10a099c5b4656752e24551869, 0x1a8325d55480646ac7faa3addecb2ec0],
+    [0x89073505deda846651fafc8c20185aec, 0x2d7f6744d06196ae0879f406814aabf8],
+    [0x290b366b5df2367d437a82dc9e086681, 0x09205507dfc37672dd9711d7bf42e7de],
+    [0x8b076818fc8823425ccb1b732bf5493f, 0x1c22e9bd3f23dfc3f73dc73c9131e16a],
+    [0x96dfaf5f92d48fd4dd94ee5d29c51ca1, 0x1c5976233750078411d6c93680d88795],
+    [0x26690a61b6e31d10e980137879e42673, 0x07cb86c6973abbc69f89d248d744147e],
+    [0xa66af45f52eb010457579107dcc99d39, 0x0c3c9880a8248520b611ee4df7fdcc16],
+    [0x19938effa7fc4f8c9b31bbdee1e479ad, 0x03928e6de66d490cba3a1e3e36651239],
+    [0x124723dda9c4b0f3b962e0137667e87f, 0x0340f788573b536fe1e42c7fd409d367],
+    [0x4c0d543385252da017b116146fe80d82, 0x030a65bd5db79f009cc1b3fac1ce464c],
+    [0x17ed03c08a2bc61b3e43e0539357de14, 0x2a42a802de9085d92c3bdd4086371a3f],
+    [0x8094446a6bba526d5473e8241b5004aa, 0x1549a1cb250435699b3a2467f8390112],
+    [0xbc6a6f97fb361e7692c96cfd54a1d414, 0x30501645d7f67d6d4e1f64b95a344a85],
+    [0xf256982fac9ea0994a3eee26c1f5d00a, 0x1c3ea369de18dcdc3378f61dd563a1aa],
+    [0x3c701281c233807e744910b3ddb971f3, 0x004f2d2437252a0d4d9ca4c1b01b6608],
+    [0xc8fb3c4abade9326e1d87becbe1392a0, 0x132fe529e4aabd030412962480562c95],
+    [0x0c4c544d4240c1f7f43c77c63f144857, 0x19fa107156b961886fb9d99ec13bcd8d],
+    [0x86f45e8bbaae484c6b79ccff34ac5ee1, 0x2d5f91cd3cb552dac7f7843f60552bde],
+    [0x9c5540d17b9ba19bb29c48c85dbfe71a, 0x2dadd9a69ad146e65bbabfbaac3378af],
+    [0xc71aba0e8a094a7660be1f4638eabc8b, 0x03c9003074e450209212b3bd1622da61],
+    [0xb06b46ca31cc5c4fc74c1cb04b71cf14, 0x0ba91729271f6b814378bf2b847490d8],
+    [0xd0d5ef3672c1d13dc7dd794aa84f3061, 0x0ee583d817f1705ac7ac0b9ae042158a],
+    [0xbb46ec0861ce5b3b7c1cf69fe5c40f62, 0x1140026a5abd89c4012d2c43a478b52f],
+    [0x77ed77ab87fb9f8228175a9ad8e6f5fa, 0x176ec0bfa58b3976290ac145954418c5],
+    [0x4aa7d3b13470fdc5232d56a3e59b47ea, 0x13e5899c46c14f7004fd0fd376476cd3],
+    [0x3c6662ea12ca4502bb968873f3e14f86, 0x16730ce766f65c06cb611f8d7f4fc3d5],
+    [0xd48b4a7cc6220709de00e61cd9f8f525, 0x165db0eb885ffed5f77a557b3eb21ef2],
+    [0x9095e8c8cbc07a171714e9afee2670f9, 0x0c428278c217c1e7e76e3110f7e1b26a],
+    [0x7d10fe9e12327718b8ecc2b40a2e92be, 0x2ef183424c590a0a5d3b8a639f37e4e7],
+    [0xfba5ca299ed9545351c974e612f5fdd3, 0x0bbbe09fbbfda7b6e06cad868cb1a389],
+    [0xa1a0a6f854c73a788443bc13e8fa5ea5, 0x2f42003eccab3bff0dce2b22a8769a5b],
+    [0x64149ec96f0409673be860cdc1edded1, 0x059113aaf928acd9154ea9295a5a69f8],
+    [0xd3279363c5e4643783dfb9a5d5946825, 0x1fbfaae77bc060fbbd03ffaeabae2cd4],
+    [0xd32fb69b4debe1c12de50db246e3c805, 0x25b3cef1d524c48156e9a24aa0d2c20e],
+    [0x321e204fd38c99ccb72a35784b94026e, 0x15e6f0329ed4e44d0323f02744b4f6d0],
+    [0x704b46de0d7c99dcb2b480707c1a3bf7, 0x16e47f642bf13ce66c2c2bca01a2763d],
+    [0x57c4ba194e32b421aa07de9e0a8700ec, 0x1d9f848cb9dfdb81cad62dd5fd43156e],
+    [0x3ffd884279259eaeeee00627dd985cfe, 0x070e206946f4cb2c2a7c5c7c0ca84fe5],
+    [0x243c840041b974e4fbdeaccf72b19da7, 0x0a5f91aed115950ce6348b0f0f001569],
+    [0x1ed0e3f42798215ba85056bea765540e, 0x27736747795d235597047a57b524dd4c],
+    [0x2160f6310470a7f5165f1572d6c27ea4, 0x0167c1d76479106953282f945c8d8cb7],
+    [0xd543b2e6d7cb51aec0ca89d6c1e3c355, 0x1becd457d21ba094804c47c3a688734b],
+    [0xd417338feac1eed42def7150d4a21358, 0x1f43386fe739e7f9c63fbdcbb82bccdc],
+    [0x83a68c99bdfa7c2008d9c454b0eade16, 0x2d091c358175583b32cb97c58168f32d],
+    [0x350900dce184c312426065346dbdb1ba, 0x017257c5bc8dad57ef6f66cc041a1606],
+    [0x522a92a7fc6e4b47d4f1ff98ea1f21c4, 0x0b4adc03c7bfd313902dda678c2fa251],
+    [0xf2d9cf06a09005fea6e3b708ccce2cb5, 0x1af84524441ac03fa2d8b5b391a7c371],
+    [0xfd3940a048e771ee4668830744a5a9bd, 0x23ecaf81286336251a1fcc5dd44a4ab9],
+    [0xc2046adbe58b3ff252d7b635ed990c20, 0x187bc703cc893e2cba22fbb598c0e778],
+    [0xcfc97c4f8cba26e828ff5c057a0e55f3, 0x260b02db111d63998b6af09a81928f62],
+    [0x14ff13b5f871ce2f6ee35742ad050ace, 0x2f0232a9940c4c4cdd0e44e3c4785ced],
+    [0x79a3786a3bb2c8de24536a7ca5de2d8e, 0x050adc4d5d64807f730d0f450da55174],
+    [0xdbce82c0d59bccaa89c254729440f981, 0x16ab8f682a4083d0f999f5816f41cc7e],
+    [0xa6e51b77e72c38b1147ef865415d4dac, 0x1127fb2f0ca9cc953fbb1d4793474263],
+    [0xcbc1c25af4e4ca8b76cc4fad24eec674, 0x1f6d04ad97f2f4b710f91f5226a8c55e],
+    [0x5b6799028d3a7a6dde1ed149faa49678, 0x141be544be4c23b3625efa886da65e67],
+    [0xe4bd664b7ce3699174b28c0fb3243906, 0x1bf785f9867194b45e21dc76b200325c],
+    [0x887c9ae74d15a7712dae802864c08651, 0x24d9be7c32c7e33bf76816e19a3c1e4a],
+    [0x2eaf994aef8a551a3d0022d77205ad6c, 0x28a481ef8515851320db9cfde816826a],
+    [0xca17975cb4a448c688bc56495907261f, 0x20573c158a49c54731fedb7c494eba7c],
+    [0x334376ee64bf76db4bf5e7c8542c21c0, 0x0a15b156592972641eef2970904de3e1],
+    [0x9b73574739b66eac27e65aa1fdb78c70, 0x1b2bc4a3f3f448efbfa21a4e41d5d6e0],
+    [0xce6188df4dff938155301593d4cc73aa, 0x1a79a5d83a28bbc4c113600038135e86],
+    [0xc7cb8484388a2c95258e2125164df413, 0x1e15e7f11ea36757fdcfcc558e7c6bd8],
+    [0x3e12e9509c124459f15d9df783953816, 0x298a03bc1edd776ec3372c82ce73c44b],
+    [0x699e0bd83b7770d03b918d24a0129316, 0x0acaed186bd21b6bf01884c2e80dfb84],
+    [0xeaa31da43903d3385b2ab25864812963, 0x2529c4ffb6c39cfd1aa6e04800cb59ba],
+    [0x3eb82c0b890c4199706ad944391fa6a4, 0x19c47c2a37278f183f3b58427114139b],
+    [0x8caed685c636382249dd9acf45d4c989, 0x0d2701bdfa57cf911f1cd1fb435fee06],
+    [0x7488d1661a444dbc07cb9c7219409f12, 0x1e58e36e0ad7b4bad27ac7f76d34ab49],
+    [0xfbb83e0b65982d7a7acb2971cc5825b8, 0x2698b2f66fb15f3b16a7e50cbd835a31],
+    [0xcc836f11b629c12c9b61a76a6f6dbe2d, 0x1c3a8e3737856514071a5891b7eb286f],
+    [0x66a8f9d9a169f685c47aa989b9e0f5ac, 0x2927ce5173086473fa445d53172a05aa],
+    [0x5aa389b3d3d55a345d35663a1b143bf1, 0x25aa17d824580d6d533236412a489aa4],
+    [0xed45003f76667d34c1d34153d8fca994, 0x0fcf1112dacc31179819d66574a5cb8d],
+    [0xcabd1a9da592731a4048ae8d19b3d351, 0x06032d6e50d059057d461477999efb4a],
+    [0xf30d84934ccabf99e9876351d1716b5e, 0x01e40e819f426fae3dbb00926a7d6556],
+    [0xbdc97b9d7417e437710e1a7f4b80d08a, 0x27908496d0906f524c251653251098cf],
+    [0x6262cb5fc5da605836a08d47f3fdf134, 0x02db1814f1435cd07ee54f8c3205c7d7],
+    [0xcc41ca8a99feae5eec07fe5233d289bc, 0x19377a45d6a5de00751ea901198e1a0a],
+    [0x70ec80f875895a3ffe2370c0aaa5cfba, 0x2d004fc82e3df9a9b893218234588327],
+    [0x76975b1efe30aeef0ac12a83b3434c0e, 0x17b836256a7de413cdcf93a4c4f8fb6b],
+    [0xdd125f33383b77841b913128cb86a3fc, 0x1b234a28dab9149e2cd39bb34f794a85],
+    [0x6c3ff1aefa88803f32770adb1a6f17a6, 0x158861fd76bd8a58950c0c55d9578c9c],
+    [0x373b1be0db0685be371bb601888aacb4, 0x0eef809302d3141584285618b0ff2e6a],
+    [0xe185bb23356b6e2592032eb732dba44e, 0x1acf3ee76f19de157996768dafafe604],
+    [0xfa1c4f065517b9bbf8c3658040e120bb, 0x2f4f55f57b771ecc15813526d73b52b2],
+    [0xfd737f020459e74a533cea55829072bc, 0x0622964ca65a48b30d40a9fd4e8fa757],
+    [0x96facd421f789bd7df6aead030cfd1eb, 0x1c8cb349b5eba594e3e96ee041eeb7e0],
+    [0x8d3e50ad2b0c52ed0185780e83470697, 0x1ef519f3b82f280469c1ea7b2551d3a3],
+    [0xdf50b7b5dac99f2cf9c87f25a1383354, 0x071f966c5bb87111e1d0f03d17e2885d],
+    [0x51ad7c8fd075971bc95de6c2422ff2ab, 0x0768f94a8cc8222f9d2eb36abaeae3b3],
+    [0x118c97ad2c951b6b3fe4933d5a4faa70, 0x136fb9d089170fcb343dd1f2cf254468],
+    [0xea8c6829e2a9d33f646df24212c2ca81, 0x14204ad52e17efcc8d6c3b29ce5220a5],
+    [0x933af583322dd5c0fa6166cf1cd58c6f, 0x19349a56f15918f1e9c6c587cd23bb3f],
+    [0x814aae9add4764bb7e951816e06b4fd8, 0x2ffd15400e3d57af1d503755eb97d331],
+    [0x2f239327e1dfbb0fe9fa74d947c04304, 0x001880d406f04352ba0e70d1ea4e5530],
+    [0x2225e99c530173cc28fb1577a0ebaa4f, 0x2194fd04a6d3e80a04f53235a5f46e8e],
+    [0xb7d1b7e2b57c0dee6521d315d60d8a11, 0x1384ccdde57742281a3d117c0dbc825c],
+    [0xe12c9c19e8b096f9d7a58a6e9155afc9, 0x0ab80f679adf8789e9f68615f8e65cad],
+    [0x009ac69d26eaa4ae6b8299d3fa27da6b, 0x1be3a55706d4814487268bf10196ee80],
+    [0x55db2b53b46f1080ab25b31b538acdd4, 0x1f8a7889404464277e3fa7dad1438e5a],
+    [0xf99ee4f7e47f1bc1bb22c46cefadbaee, 0x263c85c58282f656ea6193f72641aa2c],
+    [0x2d074337b27f474060dc35e72ccd18a1, 0x01019099267ff1ed724e463573701f4c],
+    [0xd5c26d05b203ba9bcd5fb9058c7545f9, 0x0429bebc430d4062e38733619b8d3fdd],
+    [0x61c12274fc5008cc63180a26004147df, 0x0a03e113f74fc0aee3e546e5937bf8c5],
+    [0x9fdc15e508fbd4fa83a5e722be92612a, 0x0688887334f483422f47a9397e45666c],
+    [0x81b0c209334aac58951709278ccf7c3f, 0x1cab73177feeca8c863601745b40138d],
+    [0x4f3786783a9205271f79813500826f3a, 0x0589e112f43c979ab3b8f131a7909a28],
+    [0xd06b7677fbe7bfd2e1c73c5b055cf6f8, 0x081a1d79b226d02f3edc79d6756b2b78],
+    [0x17efcf1aa5b97478d90843f0a618e923, 0x1d126b23da26bd262a0ba45cb83b78ae],
+    [0x6cba6eaa7334352eb047885db22f97dd, 0x19d02ccaf1f728c35b88592e300daf8c],
+    [0x54d665eeccff928e29ddd0587bdabdec, 0x1e7e21dbf66da47b76b4e025bbd438a6],
+    [0xff2126b2c513f473e258a738c34e84a3, 0x169ca09f61d1010465b3d6a04ea6f323],
+    [0x3cd59ec3c9e867e58f7c83489cf8e1bb, 0x1afc1d0db8f4d43d32193344634043bd],
+    [0x5e9d5a62855beda8781afcd668357eef, 0x219f8604bfa2f5aebd196ece21fbaa2b],
+    [0x768c1ab2824303b141f4b2f274c6f098, 0x0db71d9be8548133a2605d713b9f50cc],
+    [0xcda644e5d9abd0f5417f7bb8c6c24544, 0x13603e5cd20c12ccf12da4f7d04e9555],
+    [0x7ec30cecc3ba8c7ee66d8ed9877c40d3, 0x0035c4356044389e8d8d3d84619243b5],
+    [0x1cee55187faf97b612dcd930cddb452a, 0x0ba68c56af0f045fee1b2266f53980c3],
+    [0x7f53aa2422435da2698f1b65a9515787, 0x23f4759e8cf82af4e51b1a31233dc527],
+    [0x6ac6f2919157ab97dc1d5fec6fa4f017, 0x12012879b9f59dece1ed0e03b17ec94a],
+    [0x8ba3e1a2d42b8b587bab86f80b9594ea, 0x1915797a54b7038336310e46e2faa0b4],
+    [0x9bf0f51ed8996339759d0453429b4425, 0x190ed18777ce88aa8ad85e8ec239d825],
+    [0x7685fe86b8dc37a4444d5fbf2ab701b0, 0x24e04081c1519eac3990439d5f86f296],
+    [0x5e387d3f24d2aa7326f59399a10c85fd, 0x1345cdab47510fdbe7fc389be0425633],
+    [0xeff78597aca6fd95b3dbbf4952511e7c, 0x119a3ac7803dfbda5564dc8178a79cc1],
+    [0x336594ee48a4f2e82ceefd9c81267b49, 0x197b3596d3b66cadac6cc4d88c2da1de],
+    [0xf3b15a44dca4d640da40453d0ebccb13, 0x015740cbf5b80c058f671ab7b14b2e0b],
+    [0x838011dc6e4535c478efc8a0e7ea1afc, 0x23da644943d6f890a344249f0d499702],
+    [0xeee01d1c48d7e37d7210b5a8e471281e, 0x2344f76a1be8f4bba48d9dec02fa88b6],
+    [0x054ef8f69a05ec58fc8ec7f0a8fc6085, 0x2ed6f7c7b4188acd5623022b461c2672],
+    [0x494d902d7ceadf613d7e0f7b56ae962d, 0x0f5a919e83a103cc62a73502819fa163],
+    [0xc50dda86c1ab464f13711db7a2f6c4e8, 0x168506fb5ae2170331ee463708ad0124],
+    [0x02eb97a393e18a600bb8edbc8d029af0, 0x065064ae2c2846b1e8c73942bdb1e2b4],
+    [0xfa49f880abb14d9dddd2739ee6adde2b, 0x1710ff94c079aec5589d7a0487ee074f],
+    [0xc143d16f9ee9ed316229b4f5a0a0103a, 0x2af3554c9bbe595b39616669ca1984c2],
+    [0xd5128056b6fb525e100f684366c3891a, 0x22964fc3479b20e70b3e98306ec30bf3],
+    [0x73d469f0415eefd81b56d2048ebacb62, 0x25de0b4cd61c81940d0fea87d9389eb4],
+    [0x44a3413b49e016ea12634876310edb38, 0x1fd575da1c5352daae237b0e8f76723d],
+    [0xe0c3b5d880e25f5a52774d056e9658ac, 0x0533b56af329976350645c7cb7fa29c1],
+    [0xb73d88398616fbdbe56f231b8b2bc825, 0x167d885c645bd9cfb4a2d63d57268000],
+    [0xf21e3fd109e7a1946131880516bf86f8, 0x0cc2f243dd60cd41e04b2430c96abe40],
+    [0xc4703f8f81abb45f58f86ff7897eee12, 0x0341715bcd52b75e84c60edd47292611],
+    [0xc2eebdef5778fb88e69571374f2c2481, 0x1ccc6d8301ac14bcb7d8cb9feec228f9],
+    [0x5365e33017fb4cd4d5aadfea672b3b88, 0x26d54cfcd51f1d76c8a6f67f37dfe4cb],
+    [0xcccd218a8b111a67e0ec28c559be72c3, 0x2445313440a20b29650a00e2efa7432e],
+    [0x6f56a54f860d1533b28a0c03407558b7, 0x08287cd7099b718a473e3a2dbff7354e],
+    [0xfa580ffbec5295dac6f26d87ca67a71d, 0x26c24628356a67c0a450cf53f60453de],
+    [0xb05560a7af1a825fe788587692091aa4, 0x1d1cf3eea9d6f23c1722071241367ee8],
+    [0xed22518718e050946a211b9b0b035091, 0x29a6cc340dc509321a8db99a1e4a6f92],
+    [0xfec0283c06cccb04554899c28dddb385, 0x22b0472d8d18e2207c7faa819e646aaf],
+    [0x19b821ef37e1335d65d14b2fad555568, 0x0aca637d3f71393f5cb5d01e083778f5],
+    [0xfa9861163b5bff627765b6de8e1e9719, 0x0cc06cdda9bbac58a1e162ef5c910ed9],
+    [0x8292d0d503dc16a5c60513bf88863c44, 0x21c547728fc964c17b65a60176da2a1a],
+    [0xe4bf58f57d16f57997eb61d6a19bffbf, 0x08512df52d8a94319bcc6daa9f814853],
+    [0xa5dec4149be2b759bddb53b6f7593c39, 0x25c0d9feb0c02a64b753bca57cc68248],
+    [0xe05eb102075612c26b0f38670e69995e, 0x2352d3944fa61a2c4ed5c84ec318fbba],
+    [0x7ff8cf89d751fbfcd9f52a4287441763, 0x24bb393dedb3f8114acdc77cda2e74a9],
+    [0x3f8c4ae9b6a0baccb4b840969a3b4f3b, 0x033ba091bc665e268b65a8f2278ab311],
+    [0x7ad0f5c4706f0ddc9545fa62ea0224b3, 0x21cb4b445e8a579c73436f6e08b6b248],
+    [0xb8f5c0da69f16bfb29403926e8d7d50d, 0x04a925ae34260dfdeb17f5c793e4b672],
+    [0x7079dc345a79f6d673d64fb27d7ff499, 0x2df42667610a6bc6602aba2674626687],
+    [0x776812ee19c813f5a67202bdf36c9807, 0x179934386a6423cdb3a10950fb65d1c7],
+    [0xa70e6d5ebd907543c9561da4ee9fcf63, 0x0c0f3cc0643c9053e7e87a1ed590dea8],
+    [0x63775709d5486a995e93336184fe0aa8, 0x051a858defc040eaf0847bc5714fca3d],
+    [0xd3a08f43a0476cfefd70372d6274fa47, 0x10a0da93f5e24e02e1da3b703eece97f],
+    [0x971b6fefb7e09ec91336103e2fb072b3, 0x2d7beb4067840b65456935fe808f57cc],
+    [0x3d7387e991b67d58a8065c3e4dc18c55, 0x13f1bbb6a850cbb536a0197c016a4672],
+    [0xe517f639855fbaf21c0b9590b23bd8d8, 0x041fd980971d4897e5e3f42f4342db10],
+    [0x750ef572691424a8a0a01663a45c5031, 0x09636927f5b349d290067029f74050c9],
+    [0x0facba8e0515b0df5eb317a1dd0e65bf, 0x2af36e704001f6023acbe0757637f6cb],
+    [0xd1b22e5dc099a2ea132e88087e72cca0, 0x2f97558fe6f6004109ac4658609a6d2c],
+    [0xe1abc83834d9fe0873fc9d714b0e26d0, 0x23cc4c0f96eb9152447440a55e3a3d0b],
+    [0xea06db8dc1b54796d80fb1c7db293b90, 0x2a5b6bd888a8a36578d8625f5b77e527],
+    [0x21bcc03de8c4ab8d7f1d5baaaf638f0c, 0x27220be37f223c82ef3157df596b7c3f],
+    [0x9684066bec0f478a6f7f41e4738f94c2, 0x09b92d05c05908c5d9aaf4e8d234481f],
+    [0xbe01752899ddb717831df659c2e2f60b, 0x103686d84f6209572cdb829b7aa67962],
+    [0x4cf39243288d591a4ba0803b15b48735, 0x23b62723d58ed5c2c6ba818383acd396],
+    [0xae52fd226b179221bbfe73f747eae139, 0x18870f436ccb65a0e1dc5e6837248474],
+    [0x2d34071705f9a33faf2613c907f3325d, 0x028691d2f0c336148b5b5f1c5dc06b42],
+    [0xd897cc0ad052136e60b5bd0a8e560282, 0x1191fc63b79d85edaca6a27d88e92d40],
+    [0xb5470d8dfa214d023579cb6c1d09a3b6, 0x16680b82f6e755d8b419d1e303203457],
+    [0xc8c34f0bd8a1e1623a3a0e383ca42237, 0x180fd65f8c737e7e07fbd0e7646c1541],
+    [0x87cbdfb65dff4a955da9c71d152241b2, 0x06c7ac54dd40d3891a14b48e742a06e5],
+    [0x1aef296eb581541d876bab6c3bfe6daf, 0x2c65c95a72571373a6ab7a18b7f4392c],
+    [0x57f4e13c040c31923bde3e9288552bc7, 0x0d9c0008a4f07214c24fd0e733864d7c],
+    [0x663b9b84ee290e481169dab2b0a48d0e, 0x12d3a04f3439bf85b6525221a3f314a2],
+    [0x7bedfe6582644dba0fb5118cfdf255eb, 0x2c75ef5fcee292d05dee7a7ea27eebe8],
+    [0x8cda24b330e94a2c95d12549168283e3, 0x17c01330bb570b1ca9970d5872c1aaab],
+    [0xc35bd402977c50d252892c70ff3a66f9, 0x035fa265ba78efec1b389d50f1880191],
+    [0x3ce10df63473c254ed2e3994796f3b28, 0x146a648e47158802e1fd7b6e211561de],
+    [0x4cd4810598bd0afb230fcdca28f0dc9c, 0x05c2f2d7318f91f0398ab541c72b9abe],
+    [0x872294ff31da7306e8a092eb070acd47, 0x1a14bac926bc804faab0ad62fa213583],
+    [0xf9f761713b7b07814f4bbad71610d89d, 0x0883cd6e7dbe3bb29320db1da117618f],
+    [0x0d818fcc94ce9d96f4e73e80caa23e55, 0x06f98d2bbeb45ad82e7624bc3e387a1b],
+    [0x89f6844ef462de336f6a8b693e568d96, 0x0c2ec98dc0ee676d862cc05b09e7d743],
+    [0x8da70da30ec970657c38781a7b002641, 0x255191cfa833cd6a862ac869e520575e],
+    [0x1711e9cc1348dbc553be1c8d68b8b067, 0x2636c3de1dd779e5577de214b3e6c635],
+    [0x0c3fe948c398cf9fcba25ac02e652956, 0x06437037937218fcf4e73ce9b338baca],
+    [0x11c42867e3b718b24a4789acfb7d6dc9, 0x1008550284340174d1e47ac5a0277933],
+    [0x19bb87db5f9fb0cbf37320ec726d448e, 0x152b99159e87dd6e681aaef38bf72954],
+    [0x33258818753b88e266280f74d8dca806, 0x0d382ade9b281a2e726cb96db54d26f4],
+    [0xade7b2a07c4ab94c368da356cd884e6d, 0x0f544e2459e6a636d8da23834e317ea9],
+    [0xcc5dd8669529e77d0069db364ccb08ca, 0x2a6122af7c1cf2946b33735d2a9f5020],
+    [0xcaeb11aa9bec688c033ef7830dd0cd07, 0x2bd710638fa09fa70cb0aab6bcde56ca],
+    [0xa942d834d945fb9880fae95d2594939e, 0x17e172b03f25c98e9680cd0bbacf1933],
+    [0x346ef63c4eb67e220b11b0f5350c6331, 0x0759acc9cb975aefb18a833ff4c84df8],
+    [0x8a71930da6c8b1b8827aa648bdcea63c, 0x229f41e153a4409fa2c0c7c75811813d],
+    [0x8e5931afe24fa90ac1911fe322d3d9f7, 0x0d3153d6d1440aa8e8dc0bf1058667a1],
+    [0xf69f02b756e98eb07d4601a52c77a33c, 0x198a6f179f92ba427490fb90b8cf3455],
+    [0xdbb5f0506e96676d2d4b1a91570bc7e4, 0x29c2be07e30d93dd927e80aa49c44df0],
+    [0x974240549c0bb4e7d83ce4630cdabdcd, 0x13e75b095ad21ac9d107b2a7f1e62088],
+    [0x7754cc1a7a4a5f2f318b7437465ca869, 0x186d1e26c4e415c8aab305f162f00d8a],
+    [0x69806bc158648da822e1bc9f6212886c, 0x24462e17b2b3ff3912d0e41f1750cff1],
+    [0xad38580c35f4e3b5af79e24055662bc2, 0x04c8ff3b9a174fc06d3b3302ff57c510],
+    [0xed49fe7c75770f87a52b5a672413289f, 0x174a26f79f26fa7c4c2682cec14da70a],
+    [0x6f1779581a85201fd46a5bfb7baa952f, 0x1794c80fa206e99862d7e1a753d7754f],
+    [0x5af66a449d6d39f6fdbe63c976e1ca11, 0x025b4632d29770667d09f79c33bb2a8a],
+    [0x0dd2395063e8d332be42ea99ca368547, 0x11adf7def96c53dbdffd06a85d63145f],
+    [0x8c4a04fdff082b4f542e094d973a399b, 0x01b928b7f293beab032350dd3ea4c4b4],
+    [0x9fc2e561974f79f7481aa6d2991832ba, 0x281187bf98a4c5a6bf90203092c6aecb],
+    [0x3ea57874756f9d9d734a7fbc45c000b9, 0x247168cd8fc0ee2cc9fea561bc4e01ad],
+    [0x36b901c2de64a05b2b80991a1f3f19e8, 0x23097d74125e04278e5fd0e14f52ee19],
+    [0xa156264bbf59013f1d8195a30838e649, 0x2cafdfff6422239399753a96110feaa8],
+    [0x7844576377636d8cc22a5b89838b5e81, 0x1a5820280d1cc66e2cbb99320a29d80e],
+    [0x0ac524d2d7953bce65dc54e116546a47, 0x0439af191c824775b86b6ae9cdded69e],
+    [0x1f9f78177f0ad66d50009725ed6864b3, 0x059216dce06bd797a98709ad2b90c15d],
+    [0xb90cbcc9a2161b7cef52c599c6286cd2, 0x17453fd8ce97c60079f3d00aa29be8b9],
+    [0xa98f706b6d1467dce344bfe266397ec7, 0x023a1ac8f3a07c86e93184c5f082be51],
+    [0x91fad40a694ef0df12e89d617263eb98, 0x13b7bfd3ad519190d49beb27b00df309],
+    [0x26a58810463208b9b39566f073163dfd, 0x03d37546147228b5faf95ba080b2b402],
+    [0x396f15242a5fd0c7e3ac1538004d19ee, 0x05f3bcfb8ddc917835430a22e50cfb51],
+    [0xbf51866a5894a76552d1349523cf65ff, 0x0b75558f92567fc1ec54e9954a33efaf],
+    [0x5819b7d74365be4f353a88b427d90697, 0x09415183df1031dad23297c334ef7a25],
+    [0x2e068efb50dd37c395fb8744771c0e34, 0x2dc2c7f19b5024315cf5b0c76546f943],
+    [0x5de9a902c8c6efd02f7a92cb1d4eda5d, 0x115a5ac148ff0c29901220bf5b9cbd3d],
+    [0xdfcab69c122d9f6ca22f7cfa72280ba2, 0x1ec78942eb0fed168fbe55b730675f3d],
+    [0x87739e25079c2842dcdf2f974f9bb686, 0x24bc460c697257bb30552b45ad373139],
+    [0xd4dfa284555ebd7fe7f3dddc234d1a5b, 0x278a2b29bde54b5e1d0f87216de48f51],
+    [0xd679fb3b0d7a52698593bf50cf203cb4, 0x10f5c4b02af508777c4cd3cd0c6c11f4],
+    [0x5cfb0a6e4f24145f952d8baf8572f573, 0x2d695324f885445840f526b71e519f29],
+    [0x50ddbe971da50f13fa6cf26c9e05bd1e, 0x18113527536434b631fb3777ac733ab2],
+    [0x2a9efc154629ce413c989efe2c878592, 0x040730e3684e391d5a166b5487ef2fa4],
+    [0x15ca124b15e0797883af2a80adec4a12, 0x1ebb21dae6af4dc7d80bd2bb938982c2],
+    [0xf836176c595c6a1fda124a05ecefc10e, 0x0c68022eb63cb3a73859b577b59b17e6],
+    [0x90e103ef53b9a1b3abdbbe293e56f5ae, 0x088f4a9af5d668475bbaa35610bfac57],
+    [0x5173fc82cfce43743eb3f0d1fccc98b4, 0x1325e2e84e281caae87f905df719eb66],
+    [0x4f02456889b64ab7124b2479978ed3ee, 0x2f3ea3a22a56db970cbbedaafe9d85f1],
+    [0x4462bfe8281b05e95557598167ee9f30, 0x055564b7831acfd8f46df96ef819622a],
+    [0x73de66834e3f015f4689043aab341015, 0x08c29e9a22ec7f02449273a35136949b],
+    [0xec7581256613b05b8cd4237ea596dda3, 0x212a65492c6e2992484160e5e2f6fe8f],
+    [0xaa92bfb42e38e48335db3adf267416fc, 0x20e629bb5b6c1ef07efa2fc1f8f63b12],
+    [0x5e57c5b6a3df6534839a817299ce7713, 0x04086fd6c2128b11a5aa58fa2a8b25ed],
+    [0x1ea54e6a0e650b6ab8c9bf94dcf1e9a3, 0x065245cf59148ef3cf923d2866e6f1f6],
+    [0xe61a063cfdc2630f7565064af179fa54, 0x0b6c1b1badc00e60dc7e286bf1b76198],
+    [0x7cc1cc24d5efd94345352d153c898097, 0x023a3829defce44de2819dbeddcd9bd1],
+    [0x3e12cba9aa5ceaf5fc0c6265ea446bdf, 0x1b51d50b9f2a1ce039e1bf33a4395f69],
+    [0x2475bcc995a5687023ea33d3a949382d, 0x0ada2b8bf069b0a8622be85bec5cc150],
+    [0x6aab3688f818b54ad378764ae1a2d3cc, 0x0832620a355e131a479d163bd702e2e9],
+    [0xd59f65f4f370be00726cf5c8e3a72fc5, 0x01dd7cf51102ea8b1048d3dfca649308],
+    [0xbb4cd780b262c7aa0beb67def5d1975e, 0x22cf9eadd9580935f02cd8a788978c53],
+    [0x6c2e71fd57a7bd9fe96832af6bf7215b, 0x17e3b5605516db11b62512348f8e7f20],
+    [0x6520eb92bce2b265ab8864c51ac489d3, 0x1aad1cbdd185bc5a2bbdceef570edb98],
+    [0x6662a8b5b9839856e4094222dfb4e3d3, 0x28d5eced9d9e5515d0c29b800e333bf6],
+    [0xdbc1b9be0fdd639feffd0246a313e886, 0x15660d8c936e1eeeedb10437c4cae544],
+    [0xb37305246fea62aa7e16c301c89fe3e6, 0x1f5e8f3f715cba0dba6f1271e1cc8940],
+    [0xc54973da67f3b6aeeb9c2b2b1ed32628, 0x03bc3a131bcea5b2f4ddb5cd58f71a92],
+    [0xe68468f37066142d042d951afa013609, 0x10989c21815e815e3695b1e86eac0ad5],
+    [0x46c7569e009201965f13223a77feff88, 0x13a14796be2c62bfcef389accb50952a],
+    [0x5098e070dab986c02cd91005c37a9385, 0x0f307148b769d5a3aa0d70b54514d1de],
+    [0x10565a065884b82fdd9abaed3d224b99, 0x1e720c542b831a978860eca880ad5222],
+    [0xa7ff0109b349c2b3114447bfe6420d5f, 0x136981fc4f37a5ddbd57a28438eb003c],
+    [0xe960f80e1ffbded2349f7857defcc5e6, 0x23c0f4dca797a7ffeed6185b6270045a],
+    [0x853edf2ecc1dcab33e585d7f9f33f215, 0x0cadec3532eef6e435656436bdf7ec56],
+    [0xe822bb07a1a011506d198eb80b831421, 0x1d3fd0cddca96749c0e76b1094747f66],
+    [0xfb070ae2e54365037bf152b45ac085ef, 0x0fa4bf5751e05cb1879a7e9d1be18c0c],
+    [0x98a3ee9d9727cf027bb17bced7992263, 0x0801c4500a511b5f9b6f0bcb6deb2d08],
+    [0xde8314798115ac1de5981ccbeea58e56, 0x13b257c8b7194f0ccfcd859b03f7451c],
+    [0x67871000da2b1bc02be951330f7a5670, 0x0c8e8cb135dd381120087376a03c386e],
+    [0x870a9b885628cb7bc0b8b7cb941c070f, 0x165762ac8bae66f3f3807dd925a4334e],
+    [0xd3c73926e9e3b0a62b92a5b920c47cc0, 0x042543556c325a58e3ac753f4a5aec40],
+    [0xc34f58ad687b799c849d2d12afb2b005, 0x10884137e86600bdf06f81d79045eac6],
+    [0x9808c6e5e45533a3b267cea612a3bc10, 0x243aa9a7d3478eb73fc5c1107b39becd],
+    [0xbdbac3901c33c71f490a29f198cf837f, 0x0d56cd4f582b5719a3301be7873142cd],
+    [0xd6adf84d02a08165ecc982868612e504, 0x01bfb79eb37264d67c12bc4b214fd728],
+    [0xf7c716766cb58c14f3db8ee1daa707b3, 0x082b38037b96a47f54fea54f4f851430],
+    [0x7b0911408f8c8ab283bd36fc1c62ba78, 0x1d92ef0b67d46a991de603c82917a32b],
+    [0xca243a151441e1b758c5a4c0ae6ba181, 0x1e85bcc91932f9ca07b35984a41c4bfd],
+    [0xed9d1f354c8e3aafcc01b47802c57a34, 0x25e30f6d84da989e0d4d0db84cf57739],
+    [0x6600ffaf309fc6c5e3a3d6318ca98c83, 0x11ee0dd308c65edc88ce2ad2edeca29b],
+    [0x11b094c20f53d9e3a4351896e3d1a1c0, 0x18f3ca75188fff5274224cde36c2d852],
+    [0x8edcbdd144102b28c8617e53b11e4e7b, 0x05b6b21c1f708032911b6e0dc4e57d1e],
+    [0xbc56d9362e49f13d002394f6c635f20f, 0x00eb79b07bcbdf2d2258177bfd15ba43],
+    [0xb36d20b4869ffcee44ee578d23d6488d, 0x1d963571ce7313a17110b17d64d45001],
+    [0x9fdb40e2ffcf61f5b918e3ad42ccf153, 0x28c45144eadce345f87378b432bcf30e],
+    [0xe57cdbb6352ff7853e668007714bd718, 0x2776530993a11fb7e5222ee0df505be5],
+    [0xfd54b7fbdb8c337cb1493561e778fe8f, 0x210a7004456a4c7e56642e7940230842],
+    [0xd8495db91d90e5dae8f0dd264d9f19ba, 0x0cc1cb51986ab1a0f628c12d7c6656a9],
+    [0x2c270688e702495231d057c7676fb1dd, 0x13d1e0cbd9e0f421626c8db09dd24df9],
+    [0x8d6ae3cd1b10a1570b00bf0bd3a26e09, 0x1c2b5ad45cf4181611eda67060a0490d],
+    [0x0744d28920f5762687d2d31dfae14235, 0x25edc11d3b5e6e968ca9a27c340cf935],
+    [0xa3a90a9c24c3ed821eafd08383f389a0, 0x230c8e7ac3ff2b39daeba2788119cd58],
+    [0x08cc92c151f7e673ffbcc27b25cd0b1f, 0x2937cb33c930ac4be787b6e890e67f8a],
+    [0xe2a92476c199e1c819c907ce456812e5, 0x28a3ac677b6f19ace69e3d06049557fd],
+    [0x47038c01206e0f780349e5c2ca247076, 0x2dec341eed7524a26b7b91bab3003233],
+    [0x1fbcbed87effde9f23d4403d9b8b03e5, 0x0e83c9f68c5feb4210d633821cc840bd],
+    [0x9232403fb2b31b48e7ec9022df2b7971, 0x112fb21185598e785bff0f0f7e482128],
+    [0x81c6e3f26bde278b13c34cd651bd8782, 0x1faa621f0c039fdc8024cbddf4ca8175],
+    [0xea5b537fa1f880445c647e8d12c98c91, 0x04e3d26adfcb4ef7cc322ee557878606],
+    [0x309f46c37e535f0c64486995ea5b9061, 0x1a70c2015bf1c5dfa637e8debf8f759e],
+    [0xd5b6879fed7eed95231c674c016c7fea, 0x228f60de5b5f3ae8a52bcedecf7b3830],
+    [0xcf5e320745572a5d4f70097c1074406a, 0x0e4ddc512fdf839ac48b7ddb03e765e9],
+    [0xf655ca5e19f7d13b5a89a185f3a3cea1, 0x1f8e572e2e0b48f9b6bdc474210a1c8d],
+    [0x5300f75deb9ff971fafd35441f5cac78, 0x03a183213151c33d6c58b6631cafa0c0],
+    [0x3bad7d4327c9754b5e3fd674e02c8286, 0x1b7da980828048a7498cdd2b49ec08be],
+    [0x8d8f4ce4433d41f13a372e92c35e21b5, 0x285dd9c3bde7c361a16cbf14bfb0b5a7],
+    [0xff452e3df32d50a4e221e72a793cba06, 0x06f952d66d60cd18949ec08a5c203707],
+    [0x3c9601ada5b8256b49f2c2259ab8abdf, 0x2f6843da088f675ea773a0377ecfcee3],
+    [0x0012aaff03291747553781384c28d800, 0x1bee4d9e168ac606a73d79a990c59090],
+    [0xd3e33921a46522e54ccb6b806e55f192, 0x0c8c85c0320d6911ab65a50deb2b2825],
+    [0xab6508e999578d85f57fe22946120d55, 0x119ffc6ee4a000ec8b6bd175bc99718d],
+    [0x254ecfe5a70b08716d1950b6635cff06, 0x300d0e8681f117fb491827fa12101206],
+    [0x8a9480a644e69b0d2c6a8e3572070181, 0x04f424c26471906086dad2de5452b314],
+    [0x16f096fdeadef7beb1c423c04eddde55, 0x2aeea0843be6d263700672c426e9820c],
+    [0xead9eb1ed89a25bed2bb358cb4edb6f4, 0x270aa0ae5c5a5ddf685bc432b47c996e],
+    [0x17ff6d11f27cf952436383ac901bd17f, 0x0ba4f42fa43b005e31fe2fbf27ae1df0],
+    [0x83cc1c2479967904a801f92c4db08818, 0x189ac9f58d25991d10ca6233b1ad8cc5],
+    [0xd79662b3ef4c122f73f7a3383fb52af5, 0x0ac7c203b5b06e4b6f56de45fbd9e502],
+    [0xe4ef522a250660344c21a8320a478cfe, 0x2ed482bccb4a7c8a75dfd31a443b74d1],
+    [0x86a0b53436225d8d6d06c3094d032be8, 0x0f4eadae92ccf071dcdb7d67241db822],
+    [0x52d0b69cae7aaa30ed3d254ba8931a73, 0x2f3ee4a76715b3bc3feb74e528948ff1],
+    [0x6b8ebe1935729e811689b398a9dd7b40, 0x0a666ccbfa6ed09c57f58ee296340723],
+    [0xa22df97393673145211d0fe9f5efca24, 0x2abf8b9e553379c8bf68fbc67a67dee5],
+    [0xd857634b4510101958e7d80686ee9274, 0x1e0e4a52a2fe011eafaace83d54b92e6],
+    [0x23b4785d7ba6a031c8357fd55ea7540f, 0x13ee73f6525acc520a0987c161e51957],
+    [0x7edb93ab92127ca6227a6c029de19e11, 0x094ff2c24cfd2859ef66c4f62696fcdc],
+    [0xdabb7fb7bbacf3129344061909556cc7, 0x026fdd4092be37092fa13e02d03b3c93],
+    [0x04e88e33386f43e272154888d56fefe6, 0x060cb0ebf20906790098d260ed00a757],
+    [0x06b1a7b479c6623e9128364d9297f7dd, 0x0ab0fa8ccd280804c537d2e09aec4c13],
+    [0x48cf9ec7b8fc40b2f78e831e977f3d13, 0x13dd22e61ff5e9e3387f90488037e06c],
+    [0xf824a6bc8619f830a5a5bf86846c1d41, 0x24c62af21d61cc277ed3eecb6b53da40],
+    [0x7bdf7f7dcf5c0775b785a2c5ea3a0c60, 0x2a025c7888cb0ec6a2338a4be571a7c6],
+    [0x803e94ee0110c17a5bbe594cbb270e14, 0x035c1ff077b016b8069c044a2bf5cc09],
+    [0x28a9e1b21ddb02278e48b7c8fd354d18, 0x2f296505bf1353b23a672791a4ee325a],
+    [0xb669c762bf0411010099c1162e26a9a7, 0x13b16c3cc0ca470c75bc2ad7bfd6cbf0],
+    [0xb68f47a0bd2521f3813ef831f690f5af, 0x2d3c900ba9a9d5494fced5d70e8a40ac],
+    [0x3092738bfc196e10db8cc34b31f1af2d, 0x0ce9e5f31feb0a8d20eb1aba890083cc],
+    [0x23ce2e00ec365235cabb2235c32c93d1, 0x0da413bc6c64a52d068bbc66aa2517c3],
+    [0x59d327816e6a79d83f64f11f29f9a26e, 0x1897ae1a23df0f99f8c1cd4d480958b9],
+    [0x4c053dee6db6b2bb5d35966760a232d0, 0x0d116dfe5362123684539d6841825a76],
+    [0xcc432835a8e78ac727a64674b34e4314, 0x21e25952e98f9615b696b4cdc5403cd7],
+    [0x501b544afc63aea283022642d3b74528, 0x1a3dad57e7ae205a48f3a024984c4da0],
+    [0xb016f1decb5b51d8dc14927ec770f186, 0x19bd3efa0a6092951ee71b313c1b6cd2],
+    [0xdbcfcefb6eb400f85a7a278ae184e381, 0x266efc42a30cd04d6778f50a3fbd5c51],
+    [0x007aa05eb2a2d04e07b03013d7c90b6a, 0x1c8d90d85ef64bf4bedde609957ff4dd],
+    [0xe1f36252687227e082f213b219e72703, 0x02ac06d4cdbd30847a9fce14472ae320],
+    [0xfe0669f33da07014dbb8c0a8e29e85cf, 0x0522f2c9b0876d27388b8b52a51002e5],
+    [0x9ef5c0b8342d865985ff22d17b47239b, 0x26bf744624ce575461c466270c3c7cb0],
+    [0xa1b3159f810493c846eec25cc2df2854, 0x2375100f868f06ec614e0ded6d99f714],
+    [0x4cea1f9d9a058297b94cdeac7758325c, 0x0bf461146b46b3e73e0edd4936989f40],
+    [0xb173c5a3a66936209e4af629ae6771c4, 0x0e98376ed641425e2716faefd8ed7544],
+    [0xe7cb59a5c93a87dfe6069b5f4eca2710, 0x2f9c4d96056a1391d89f28720a20d0a1],
+    [0xb729d65a4d28a27d6d3f00f1a02aa23a, 0x192f200bf3b555bbac797819bb21b990],
+    [0xcb605a0ae34d6d204ac1cbc80cb49126, 0x2bf8984b5626accf10b7667f4fdccdb7],
+    [0xf8e3abbf8335b49205c1fcc1f6aac1aa, 0x0bca21c12464247244b2a19fd30fed65],
+    [0x266f8c40f6d7b1f86b1d1935b09f15f8, 0x14078d4ee11f463471a49516c3c85f9a],
+    [0xbde0000b50b5aa57c5800d52df4de011, 0x0bb8a04ecf9f98e1697d330e922d0c8a],
+    [0xf7e9115382221caaae628a334917b035, 0x21526121c05e09a0abd2ca6efe5b42eb],
+    [0xe5bbe044c9665d96109018d9729c84f4, 0x00f6a443b0e7ebb0bf777511512f66c5],
+    [0x9078542458af14ba4cbd9258b8acd236, 0x10092751e94fb6b5e49bea158a014bd9],
+    [0xbd36efc642cb0b57d8f10819d5c0f5f4, 0x029389903d0d3c29a173ddf452a66b4a],
+    [0x0ff0741c3d51f74545e2139112177376, 0x2b6d20e6cc7ca916f542668724179d8d],
+    [0x1e305d5eb313f2cf57dbb05939036558, 0x2900b4277fb3a0ff83b4c58fac79a886],
+    [0x9b3938007cc50f5699250240cd2ee1a9, 0x10afdc0066dda9b64bdd43b86d0ed62b],
+    [0x12c15a6647ae8103edca272fbd5f8f11, 0x301ed1566f5220e17fbd4083a57bf42c],
+    [0xe10bc969f1342ab0d02de635fddaff11, 0x27f1833229c3a5998686903e824bb3e3],
+    [0x3abbd47a151a9e4efbdb922029fb64af, 0x0c4ef35ff9f906d0bea489d201c210c5],
+    [0x84af3e3545dbb4c932ca49115f957d27, 0x0ba5ab9246af723bff457ab8228be064],
+    [0xc88b3245cee9593f02c250149ae87ac0, 0x21c572bd4264d821c5e2aef993006499],
+    [0x0dde4f4de01d2429a23511b1fd0fd171, 0x04d1cc4795008719326f8106c9ce9a4b],
+    [0xd87f1eefe2ac2271370a7f3be27065cf, 0x0a379f7d6eedca7c4f07bb5df2d8ea0d],
+    [0xb6c8c60e3f7560fca990be0ff4639720, 0x28daef7d37a43f2ba44065f2213912ea],
+    [0x35b0e0ed373b27e02e5235a3d34f65c3, 0x2906e8996458504f2c044f053c4ad24d],
+    [0x8c552213b2f9a134df341baf3e1379c3, 0x260738b3d0a746fb0612dca6db53bb96],
+    [0xe924bf422430011ef27af82df0abb237, 0x05f177ae8c6eec8b9712cfa042776690],
+    [0xb73561c8cfd10bbf5b29a77f56b1d95f, 0x26811027f445e86c192e8ae371d2e83f],
+    [0x0cb70363fef89995f34926ee84be514f, 0x12def5ee59c1b4a96b50ab3de3617564],
+    [0xce621b5b06793f5e95b4034e2ef7e9bb, 0x069b58a6aa41994a11cc7dd3a428f6c4],
+    [0x7f2d27660ca4cdf6e79121fbfbb6c5e6, 0x0dbb162fce1812bf2a16aeb6aac7f173],
+    [0xc3a84f28a24b261cd1dcdab039a309f8, 0x287ba84aa5087d8cc9f89450f1fe391c],
+    [0x96debb9cb06af003849fd099e9c504e9, 0x1e878a1f2fc77cbe232aea7683d46d89],
+    [0xd6af9943cd7f2336f31c896456cba2f1, 0x1c8d1ef246dd75f42de13c5bfe069c08],
+    [0xd0ded5f2d5ece54769f15abc1994538c, 0x074f1a678cb029488356dcb477a88303],
+    [0xa6e18556bf51d20f687b669e7815d47d, 0x2766e558172ac70b1f3dd85f4c80517b],
+    [0xad0d07727ede7a80af1c505c6ff5d534, 0x20636353e29facebaee5f04ede42fc3e],
+    [0xf8db695fe07601fe5a71e0fcca916b46, 0x11ed975aa3d3f9bac1e0bab123041671],
+    [0xef376618bfe5a556f24bc3247099c441, 0x18100160418fd858f3e4fe8d5d6b80e2],
+    [0x12774d041e65ef8430224cf31f960f14, 0x2abca18d9b3f2522e21ee57a1c6634ec],
+    [0x9a7026139daab3174fbe71b0d9522c8d, 0x16625241533c582b9b57f6e0963dfbba],
+    [0xe8d82d07ca24655d94c5a27b57c780b7, 0x1bd3cf1bd3124abaa627850ff77a6778],
+    [0x835c3749ed21c2842e7c46b4bfad4e38, 0x084e7ae8a515bbe6fd8370e7c13e7d09],
+    [0x2df09a71a5b5a165ad2011ebf48a6447, 0x0105b64fdb0f02988732b58e06db800b],
+    [0xa086d188e8444cabd5234983904bcef1, 0x043ed436d6b9af567aac7e24206137fa],
+    [0x2fce7c87d0a92c9f699bc550032a0a7b, 0x2451cb7a5dec1f93fd2b3f8b97c4b1dd],
+    [0x6ef951f3b57420a46c7160bdfaa576c1, 0x18f37c624e96ef9c494d2f016bb1ed95],
+    [0x7c3841f34220c10797af76f62e258980, 0x1bf84d6d70c748ccd981c21b19d555ce],
+    [0xc08852720bdc749e1f9a62720f62719a, 0x0bf9b051f2021f38a294eadfd0811f05],
+    [0xc2ef10e8236d2cd66a70a3f0d187988a, 0x19f1fd2199e3aac88c7ccd7dd858d158],
+    [0xe34b795394231214317f036e5954eea4, 0x1af9d63ace6db7a8ae20a04b776fa39d],
+    [0xcda80937fd7d74f27d6e550386f5443e, 0x1b3a2d08d1c3763b7678b9d9de1fee10],
+];
+
+/// MDS matrix for width 10, row by row.
+const MDS_10: [[u128; 2]; 100] = [
+    [0xd6329ac3c34712db570de886cf569098, 0x0b1e797eee3f15b88ce3a1fafec88da1],
+    [0x9fe6368ff097eab97aaa09be4c630e27, 0x2fe429db8068e61b82624f25766273cd],
+    [0xec9e3593c67de12a4255c6b01648b690, 0x2e750867942439083365d21fe760c7df],
+    [0x29991b8324abd6b07dbb96f5c21ba17f, 0x23237985a96b177ff7c01486ab0d603d],
+    [0x1d00dc1c3fb83745a31d8131ea5baa85, 0x15ccec857b25934ffd429af92c910db3],
+    [0x5fb62d074760806aa675764660eb56d2, 0x17102ad2d98043e5509e76c331e6b22d],
+    [0xbee7ad0bf4eb78397b71b8f989cf9812, 0x0a4ecaabde9d1ea11c81dafa8d5d4869],
+    [0x14923981940f5542737a468d4d887c8f, 0x0ec1821648fddc448a1512aecc8ac377],
+    [0x84c1233f6c5fdf5d65c0832af543e415, 0x255a493aa4becd9d137eb76941c41207],
+    [0x01682af4c8658719727678fd9ab7ef2e, 0x120f44670301b1435249294d9833d59e],
+    [0xe5513ec28994a6f60e57c98d3bc8f586, 0x1abb95b6e33243a050e563b5c4fd059d],
+    [0x9e0c8dddfb641131fef39daa58a39923, 0x0a5b604c958bc90071ad10618b177259],
+    [0x039adeb1190592bca18cf4f907c1a6fe, 0x24e82df5873953792baca345b7c19f61],
+    [0x8e54b5f4537cd018aee3dfbb90541581, 0x10e3349eefd3a112417e110fd9e83207],
+    [0x0eca5679c90c843d9527184a525fcca0, 0x165adebe00c7ba019499018c96f5c587],
+    [0xd65c6b7d4233f32e073571ff862d89c3, 0x01fb81218feeedf4e7e0e33dd8ecbe62],
+    [0x58377b108f8b020c82b8460a8d603afa, 0x1be2802fbea51003aafae82ff4499d11],
+    [0x2c66cad04835f4fbd81e696de6398769, 0x0418081de8c2958355a1e45e28ee51ba],
+    [0x5209aa6ac9f141319f3bb201e7fd6716, 0x1f3e937ab29f3a88c6fe438698a7198a],
+    [0xc96f13dc2827a8e5e611695e0797fc3c, 0x0113e968769b0ea32cb7b5ff70484aac],
+    [0x07d87745b3abfba5bf282afd21f7242a, 0x110d213a836478aa301bc1fd4edd3885],
+    [0x28aa7319e2eaa094a693b38b5cf9a242, 0x2cdee0c635a4fcd9549f0f91b4f4a9b6],
+    [0x6e49925150f1784f29fb6b96dbd8f0ec, 0x00d60f0585755d48577b465746de8e28],
+    [0x89d02519f3052e87eff9ccb8aa299c46, 0x2168dcf216d8369a626ccc61453236a3],
+    [0xfe9458be15174cbbbf3a806de5f63cef, 0x03530dd962ef23dc16f79b28c1fc1dda],
+    [0x42892aa36e2d0a793f670781a21b0dbd, 0x1cedc6d4e1476741c1099557e58e642c],
+    [0x808c9071083b0a339dff9e4904b443d6, 0x269c8c139a4f61e16bfaae9e8bbb1c69],
+    [0xded5bc687c6ff97194976f3a28bd30c6, 0x0ea7bc52503e2039d277912e022fd6d0],
+    [0xbfa4127296e497f86e6441e052a23b45, 0x2180b9eb156eb33cad1aa750ea0fa3dd],
+    [0xe319bd2fc0619c99207dfacdb657bbf7, 0x18cd1471430adad31e45b529494fc8a7],
+    [0xbf6d1303a1f8ee4aadf76d8f81990588, 0x18edb2d105bb53b768ae0137850dc2d2],
+    [0x3f42071a17c6c05f470b44958f57ef15, 0x2c962d7d54a98c3a82a638d8c8245081],
+    [0x9e09fa00fce87929d1e0ece95a449345, 0x1f495e93014314988dace5e4e867f510],
+    [0xbc30927ef185c6356bc33e5c31faef9f, 0x262903f36acf0dc055f610397df0794f],
+    [0xb908cb2b98b5fbe1e4c2fb982455baa2, 0x1b7e922b3dd503e6441ada966c8b3ba7],
+    [0xc163bdedaaa8824474cae5869e5ec20f, 0x1a88ae2fde12ba32e5f4eccacf11c25b],
+    [0xdc2124ae648e2cb549fc76362bc2b97d, 0x23407728269c70fe712b021915059dc8],
+    [0xe0ab95e3cb17aedbaa25f4d084cfda57, 0x12ab318626d511c23d28baa9fb5b5802],
+    [0x3e9acaf3d693aa64cae04faa225a2e1e, 0x1cc69d8f34ee877838950e20a9d4c4bd],
+    [0xd5fc2821fc918c0844a003b7141825ad, 0x23d0fa965e920dfc530b52670b1e32e2],
+    [0x0c5545f2abcc1fabf4ccb84a0a6e2c27, 0x1e53e92df6c53ecec4c0de5b07dc4c9a],
+    [0xdaea067fe49e3f1d9334e5470438b454, 0x2bc761185f59efb7b67bcf1d3e54da00],
+    [0xbe930c46fee5a30d5ad07976a5bc0263, 0x124331e0ecb1d9808a1d91efaa98950a],
+    [0xdf773c04baa097c352a4165395cca786, 0x0046887724f29321895c2ef8bc0cf545],
+    [0x19f7d4387435707696c40bac3bfbdd33, 0x247460ad4580a18639eada69b904c91b],
+    [0xf707b7d425ba3c09f3b6392b6f1f4339, 0x265412a4e3b5560aba502d20ed833def],
+    [0xc18be94f1de3fce6a3e5d2335a01e90e, 0x02ad06fc9f81684542e0458aae19c0a7],
+    [0x2b0835a403c7227dbcbd72fd8000c6f2, 0x0d1e34192343d66fc1b6591cb6a3b060],
+    [0x70ee2ebb410617d2321f6d8537f98970, 0x0b565179887ee30e381631d8e5dd357c],
+    [0x2a21e19b291400854f56393c56d69460, 0x2ea6b32af37cd765d728ddefa3091890],
+    [0xddf33c84a8b5cfe33f44b73fe18c0e36, 0x10fcb817e10f69f7074e64c393b807cd],
+    [0xc844c94b56fc0bb806eb9982ac553428, 0x2e450d1fe7773c9889a0be55e44453bc],
+    [0xdd3d3f1947371215a66c894d637e89b9, 0x2f421fcf54d77dd38930cd69076f9baa],
+    [0x180d2ecc33854cdd35f9ceb07bf40d53, 0x13276081db012d1d4f4b7dd2d8a2f8a5],
+    [0x7c13702d86af39ba0b4220ec9a7568a2, 0x144d5be38d44c5bf8f3c5735334fb1a0],
+    [0x5b18f4bf773fa1051cabd2c49aaabf6b, 0x12691b599d32edc48ae99c7a73abd59e],
+    [0x68e4444ac691764f1644a626498218e4, 0x0578a55f0fda29909b9f850c3d74be66],
+    [0x61d6ffcf7efa1aea1c2e0d8a49de64be, 0x12e3b04924b2cf6e407bd26b3cd47bca],
+    [0x2130086da4d5588a70926423ff8c242f, 0x1162a56ed3bbbb4b6760a16a300ba720],
+    [0x8baa3fc714c2575aa9d85f739113c50b, 0x03340cacb04d5a85614eacc4be335293],
+    [0xc9371dedb96f08bbbccffdc6cc44db98, 0x2b1d8c11fc652c593543b6f9800bb7d0],
+    [0x4e86f4ad182715bcfcbf2b28df6b4a0d, 0x25c0f87c946f375e341f72f6a8dd63a9],
+    [0xc70c0b437ac23ac5bb9868e6df24485e, 0x0543b2bc66f3b2d93d4c1882039ddb37],
+    [0x5f5bffa8028ff52f96a99d77fe927066, 0x2dd117218538e8acb0301eb40adfa243],
+    [0x068136fce65f2fc55a1dcb939b7fc6f5, 0x269b06b31e89ff132daebc200efdc8ab],
+    [0x0aec552fae9518dcf9218c38c1a0bc90, 0x20d4401cbbf92877ecf616d7d28551cb],
+    [0xffa38e14b1faac198a860a5767d2feed, 0x2ee2da24b7f8e7035fb73b08c3414833],
+    [0xe679c5bb0dd785403f3ff49bbe61c8e7, 0x06dd89fabf92546847b9c13b3a36bbc7],
+    [0x94565654597e42779f33400f1d17d652, 0x1360f719034572b59410c529b62a62aa],
+    [0x6db983e496ea0519c0ff41dea8180628, 0x15ff827e034c02a90767f0152840e7fc],
+    [0x5cb32828c397cc8125f1dc667b6cadf7, 0x19f473e40c05676d5f8d51e11336e9c6],
+    [0xfcd105a490a4ab943b25558cf3d054b6, 0x0bfe6fc08e2a2e1b8911ae065b985388],
+    [0x73b7f9b3d63aea4120c601129c16660f, 0x203d9ee6462a586914fe7dbfc1bfa297],
+    [0x9c207d4d08e4bf274a79dca80e52fe24, 0x1b912abf2e1649ad4834ca5b2a3831cc],
+    [0x8571cb31bfd5c9c3c59dfd97e99f701a, 0x062caa3c9fd39b69864faf701dc8a458],
+    [0x5b0bb98297c1545a72197dc6ddd9335f, 0x138c03a2ecea183cb8f265eecd7e9327],
+    [0xad7cd6d3533c3b00a57dae833cc701e5, 0x11589959fc2493292fb6c50730e74994],
+    [0xd012ed4357604e7185750d677c6b357f, 0x17b9facdbe747d7c5163ca00e3b0b825],
+    [0x45099aea50f1a362edb2f2a7ddfe90ff, 0x0c603d03f92860da8a68c6dcb1924d1a],
+    [0xd6329d54cfceed4b947d78a568681147, 0x05d56f0c844d169248503135e4deb9ff],
+    [0x7ecb80c51be17d47f79750201f1fcd01, 0x0fcf3a4d9c6c3392de1bdfe25cfb8681],
+    [0x4e507ac597cb47dc31d913a7a540fd9a, 0x0c182e438257c4ad7e7b79c3b68d6098],
+    [0xc94354737884fe6c26ae8155760db8aa, 0x025a1ecbd7e7893f646f35d3e07ee997],
+    [0x689c088b2933a361c775cf430748ef1f, 0x20135ff263867f708ee0275170cc9788],
+    [0xebc6d8b71821cd5336e2df14341c95ee, 0x20e160f772998a2f7d0064c7efa15884],
+    [0x0e704ab4b554ea5d8c07b70b2b96202a, 0x150df11291b680e3ba925a0471c7fbb3],
+    [0x4b91ca856e7ad3f118ad922127b87054, 0x17572029e8e897202d0e65c2172c571d],
+    [0x14b7349af8924e796009f8a4f1b7264c, 0x0aff15650b805bff6f8b3515d5e29cf9],
+    [0x11034b95384d98eee47ae896f92a7d67, 0x1a918fc117d9a6445e363ee5833dc014],
+    [0x087c1e122e02ebb117db0c2dff04fc0c, 0x2e5618411efea30cc7de310d4872597d],
+    [0xb9f62652b1c0626a19af8d6085d70822, 0x23b02d00fe2634889673a7d04736fe15],
+    [0x223d589cdd079362f1e8c2e01d54476a, 0x2ff4d0b8798a82667793533730083c25],
+    [0xee7ff9dcf179c08e05e9e5cdd8238ac7, 0x1d897f60e87fce5ceed8da7f2f4a63fd],
+    [0x1f7f4cc0fec91ad53e92bf8ada3e278f, 0x0ba6d4ceee2182d3f2ab2e4a3ad08fc3],
+    [0x7246266ae8fa5d3af3ea81776b5b8c07, 0x05ce94b8e9a60b28947710461d87c775],
+    [0xb75e590bb2d422ea83c09a5da1ba0c65, 0x0c346b046af03cb9048c29353e1107d6],
+    [0x403360e56e88b6c5cbaec34a80bdc7f4, 0x2fbf8cc6ec7efb30106423f2e67b0aa9],
+    [0x9490b912bb1f72f542f41d62ab2d847a, 0x0d064e72449efc11d1e90b97749de390],
+    [0xa8d1001c71debcf2733ca0c71a19e1ea, 0x02a88e0c35617665f3cc2fc77d90afc7],
+    [0x540b974d439c93e19ea4d02f9975d446, 0x15eeb3e0541bcf010da0c47adb12f6df],
+];
+
+/// Round constants for width 11, 74 rounds of 11 elements each.
+const ROUND_CONSTANTS_11: [[u128; 2]; 814] = [
+    [0x749e1706a82917b64ec2ef847f804559, 0x0752af3c6fdccaf3868276685f0a69b9],
+    [0xc0fa19c1b348bc7e18a4c1ace1d15418, 0x069503d417e25f4568c7858ba8334301],
+    [0x3d22aa81ca3595e801c5fa7d9770a096, 0x25c1b121f33f4cf7b308ec4630f27b60],
+    [0x2d213e825c1d2f0c9ff99b2273751f6e, 0x23d6b55145bdb32827c1456af4d7be4c],
+    [0x14cf6bf37fe2357c79aa7d6b32acaeca, 0x1b357bce3ef3f2b76afc2b5804805adb],
+    [0x3c276fe5689f2c35c9027e80944877d9, 0x28ec5ac0881c1e6426ba499409894a6f],
+    [0xbd398d028acf690e4b8ed4005dd06eed, 0x2c9862524ac8a07b47d211c9c504c8b0],
+    [0x644cd43e290da0baee92891c4c065ac8, 0x1f6e6f0ac4aeb7a52343f30418078b40],
+    [0xcacc577769edea09aae39407b9af2fdd, 0x03db3517e233f8a76d21b2fb23c85fa7],
+    [0x79a8ac6e0cce9e9b0a2142247bfd0877, 0x0fc333dca4183dab479c5962eb05ee4b],
+    [0xf2b65e1a135264527e250a5cdf99aca4, 0x1eed281978a8395c04289b86197673b0],
+    [0x080954fee357dca36fa5d78f33b5be3f, 0x2568bdf6511034343fb5d5d021e052d3],
+    [0x5a63d11cfe64661fadd9a001dff82330, 0x1dafade374e63251deca970591da4a28],
+    [0x60eaa97f1dc32b622e895df3a6915b39, 0x0af25c781abf56fa4cd6833580e97c7a],
+    [0x9757c9367fcc48fa11deb135427c5c1c, 0x13501451cb7d3e0cf3cb57741fbe8dd5],
+    [0xf2d065f923108559a1730d71c73e56ce, 0x197045fcdcf15bcdb247d8fbf6c85c80],
+    [0x5e8ff8a0a821d1b305c4cdb1313c8661, 0x1a390d7281375fac249b9855f8c67a27],
+    [0x47755e5e84c7b9957659be1de64b737c, 0x17f0706f7e12fb022d15f94297c7b459],
+    [0xf251ffc75243a8d7aee8924d563f7e49, 0x0a8f5f03f19d49799b6fba650c06e4ae],
+    [0xc7c7206a0598044acf0c18fc5042f0e2, 0x24c9e6c35f4f819f5bc1ef26576e16d2],
+    [0xb9e979072330d547133a14b25e461fd5, 0x002405ca70d51550dc14abfee18e3823],
+    [0x44663caf8f1d31d86b156e107fef38cb, 0x1eab4cd56f1474b270d502945621d93e],
+    [0x97d4deef598b873c4b4a1f32ae425d00, 0x066b9750f73f4561ede55939407e74b4],
+    [0x5b271179408235f13bb3f84ea28e7a42, 0x1ee3dfa596f5d87030a6c9d27d61dd17],
+    [0x61ff7b2c65b638a4046eab217c02aae3, 0x1ea94b9ac6fc1bde29d5893fdcb660ed],
+    [0xb1bae02a2dd54878281ac6e7a3c069cd, 0x131388351f0796d4753ce564e754591c],
+    [0xf64be10bf05ce16c90af3d1f13886a2d, 0x1a45a017fdbe53619a29d8d31f27e408],
+    [0x8fe57822dddf9d58d9d2215057aa94af, 0x1b4a0d68e54312b9fa67550c5c0ff0d8],
+    [0x5ced1d29c7b5a39da358ad226691dbb0, 0x1d45d369ec0399905bb029dd9fe0b97a],
+    [0xc8f3d3d813dc4af414adce1d69920e8f, 0x26154c9c51df8059745bb2d97d9a9b8c],
+    [0x11251a7080ec5b59206e7ac8c701730e, 0x2b748ad3da43c5cb9412cc64313c8d47],
+    [0x6e05f2f2fb4104b2cb48d830fed13758, 0x17a5468fdb1e5fdacd9c9fd0a7f5fb75],
+    [0x5ef809de03ee4129b2baee5cb3bd94e6, 0x2697a5e22542123f0923b86b1529a540],
+    [0xd9d962e84d8ddb109efdba69916dd489, 0x09b07ad43370fc4bf9ade610e1addd38],
+    [0x4a75ca2f8a009e15ad4ca11a17c81a1e, 0x069c194b5b9f2301bc48d6363c8e5afa],
+    [0x729cdee681eb0e4479b90bb720f542c9, 0x1a1974ad418cb372d555ec9af0f348b9],
+    [0x85b7098895826ecdf2e1bf040dcf0287, 0x18a9d8d34cf2c633138a9eb9eb29820c],
+    [0x4685eca623d662585186d2f124ba8bb0, 0x2e0554839dd70a4b9998fd915327fae1],
+    [0x67948ad87fab25aea6257f8cb7012803, 0x1e6d0216c8b678bd9e890ccd72d23bc2],
+    [0x6169a24442d8f8c9ed79ccc7a24a495d, 0x0920932a6c405b1ee871ae173949731e],
+    [0x7f10cf39fbeafb8d39aef30e884fa3b8, 0x142c42de6abfff58054f6a4bda8eba5f],
+    [0x444bb0712d646a8bce67b2e451ee0d81, 0x1418edd88e64d3ddad03f33ca0936172],
+    [0x2e9958a5999abd7e5cdd706cb7e082b1, 0x05c5dcf2bd5e04ea65654fb473c4d469],
+    [0x1e4d16959ec936a9e8f1b2f97fd35ddf, 0x114464311fdfa11eec166d202aeefc60],
+    [0x23b2a9d1fcca758321a1d9175c327ef5, 0x21b713b0fdb238f3b053f6d596caf072],
+    [0x8bc98e3cd7bf76d5fa033093ddc97581, 0x15ef67cae5838a50bc671a5ea78e87b4],
+    [0xfe583306a6ca69338c349026fe66bfe9, 0x0bf88917cb7c570f68b39429d8fee0fc],
+    [0x57e568d63497982780770b4bc3b437f9, 0x1881f0129a64b7f861085f6b3669dcef],
+    [0x368d648d09cb50142575430a110e89ce, 0x2055eb732a72d37cada83f04ef741785],
+    [0xb0b9e2b29954395b39946a4f5f26d003, 0x19b848c33b3876bb168846d13897cde6],
+    [0x3dc1bda25b541f3d4a059b48fc952da6, 0x01e19797232964cd5f26ae8a2e04bd7b],
+    [0x66b1996552c74bd2fa4bfbcba3913457, 0x028b8dd78e8712e172486a4b278ac8e1],
+    [0xa5741aa0e05788a9d4fb2032aff0d586, 0x1fb1cff73196d134c30359da15a530b1],
+    [0xd7c64aa93637ee27aefecdf288326039, 0x23bd3301f59bd655225f85c42a9466bc],
+    [0x13d3f2beaba25ea4c1dc6f0e3fbc8e34, 0x0e2adaeb2ac7d6944e287dc7c8330153],
+    [0x0c48003c0d1386db7288260d29a52d0a, 0x2f5d08548ef73d65c2853178cbcbc8a7],
+    [0xc544b5c8544ae59ad76cbc03889e79b4, 0x2f55c0e96de74a61fc974df0da85b11a],
+    [0xd635ad52e3294d3e126a92a64c5a4543, 0x18885f739a9c3dfeed39ceb7c8eef77f],
+    [0xf88f9edf5684a05bfcacb11167983082, 0x1267cace9cec3c1a96fbda5f20df309d],
+    [0x6c6af8e3e399ddd393b820cdec66f902, 0x2a45c0484448da31b253b632f39c3d4f],
+    [0x81d6382967868a9643a76e53a2b25316, 0x2260279c332583a36011973aa7626d4e],
+    [0x79bc0ead04d7a313769f662c8e74a0eb, 0x28ec82446ae8bb21783f54cd4daf07e2],
+    [0x578cb112969bc6eef3a1321befdac9b0, 0x16e13570deffb3bebe118958b2d86470],
+    [0xd077e620279986f4e2f0b3e5facaabd5, 0x2288e83beb79459807cb77408c9bbdbd],
+    [0x9ad970785a8329de8e225a410e243389, 0x2db5b06f1102b72ce49946084d75f210],
+    [0x5103b5887e5d63c48eedfdc533699758, 0x2fce39a19ea115f3a917041fdb267c1b],
+    [0xad9f862fd1f97da54459c5db0bda55af, 0x2c65ef055b28fb41707c324a36a894c6],
+    [0xc18a9063a34066f8b6f56683cff48ffe, 0x29e63a24c91235dc00a6770b9e572358],
+    [0x35e2b5c99c5d8b55de20d9aa612c72c3, 0x054211c56cf9cdee64b7daa7677fe9f2],
+    [0x73032ab66e2e0fb6db9787b39559d73b, 0x108e8e83433f7cb1728723837ede94d0],
+    [0xff033cd31476589149a9e60d5058114e, 0x0ff9bfb41914e199a553e070eed9438f],
+    [0x418d7c11a1d20d8484e95b067f935bf0, 0x06913bc2e37230d4b6f56b44697d6f80],
+    [0x5dbde395388a81c5ce3bc3cc8b488099, 0x300d3b773b9a41ef818fdd12202f499d],
+    [0x42b344c71ddd0f957d02d9295fabe594, 0x29f9b289721fe3001937f6c487bb30e3],
+    [0x51449350b5dfceb0650667f7db7f33fc, 0x14de0dbb50a0aafffb3994762d1496cf],
+    [0xb857ef66cb39cc6630a3ac6157e9e0ed, 0x045cf1d37629dee9289fcbe4823519de],
+    [0x322d3ad6d5803cef88b6693ed13590a0, 0x1e7de46fdd23c6aeb94addf6cb999856],
+    [0x59f666598a0a9032afcf58aea772939b, 0x2c033fdc57232e685abe9aeb18a5950e],
+    [0x4e89cabe3d8f9f55cbc74ddbd9843e4b, 0x117eae65ffe6634241265348d9847207],
+    [0xf2483364c189996bcab079912d25993d, 0x2988e81a9b5dc22c8b3c7a9d01344422],
+    [0x56fddbea4e666c0f14465e09ccaddff5, 0x0dbea591b66a2448fbfb886d10b899d6],
+    [0x4000ec4c6a8628d5e2b3b1263234755d, 0x1edf424bb6ca08ac8c4cdfc98d6d3fd0],
+    [0x41b9d5115048d1ac8483fc688a7034b1, 0x214306c0105f363d5259e2141e97b7da],
+    [0x311877265a70210e01216328452fdc98, 0x242e00b6930be5835aa5027493d3b11e],
+    [0xbeb1ebac0f63fa47be96e3832012197a, 0x29154d0128cc650989dabb8ca0b18dd5],
+    [0x506a2bfab5d300db784d25d260827d0b, 0x25d22d54d9c82d7cfb89c61aa62ca150],
+    [0x2612873923ee88eaa2db8cc10108f5c6, 0x2f23f0ba299f5c0976c16eb3b8830689],
+    [0xb096708315a9812039f215899f4294c0, 0x0b743a8913bd8e7e709e68311a3585ef],
+    [0x1325872864ffdf84d5adb61af8dbb3d0, 0x264efa00929546cb4ec30a621c3c6f66],
+    [0x7acf1b65fbb13edb346df1f2a10ace37, 0x1c49d76663a358387762d208dc85400e],
+    [0x6dacaa511bdf82d099fbeee5110b682d, 0x2f40725d1eb518ae4562ee25ca08b3e5],
+    [0x8555acff7d4c8be7ee3e206f44ee7c00, 0x2651bbf39215d6d6632a652ace6ff511],
+    [0xe50761ba8f1f2b018613d65b091f3eaf, 0x2198f3b0a3fbd0bfab07578d40d7fa03],
+    [0xd92a4f7db96c2d9139bdd6fb11fea408, 0x18d4c4699f7d01fa00edadc43c8e1f10],
+    [0xd24b6e49f457190bcfd062a5822957cc, 0x059edbc553491ec6b94233d53b5328a5],
+    [0x4beaa822b3954e551288564bd2b14552, 0x129a61614b4e9e421e6667daa9b85fe1],
+    [0x7597ea3f7b7792fb44377e71e1ec9f16, 0x2e2bfe4d7b3234df6b62688bdcb2c876],
+    [0x4572f6a355ee47cdb827a7f20dc389c9, 0x01f8397cfd8eaeeba4798e9863737ec5],
+    [0xb87e1781d2ae191d011c6d64e02dcf8b, 0x117db9cc9140e4670bc74e7fd3a6fe99],
+    [0x287db0b6bae3cafb231dcf951598f89f, 0x11e0cce35b68def7ded5522f434c486f],
+    [0x8980c6d48e33b6c63737c82f942b9230, 0x25bf4ae30c44ddfc8700e2369aed7f56],
+    [0xa41d0fd1380b58d2f77c058339886f6f, 0x1153f5c16d5608a17dcfa2fcc5ff92d0],
+    [0xcddfe641fa3a096c8013f38b5cd85b7f, 0x041bed39c29afb7004cee993fc222605],
+    [0x44e6abec3657699a116b84fc42ad5d3d, 0x047cd5c5b0c2a604ae99c842540fa37d],
+    [0xae7b9739f2eb6c8f748a30649aa4180e, 0x154ef25e671486db8e882181a331826d],
+    [0xf288999454496a29a492eee79efb9622, 0x1b483e74f41c2658fafd081b9cb7709d],
+    [0x196cb34e0ceddfeca0a40904e2b8cb2d, 0x12db88342d014b56fdbf93dbf9d9c082],
+    [0x205cf835f42a95c0336de6c42ea5a990, 0x0944263fcad7635a7e8ede76f4fe3afb],
+    [0x4429b98b78b8f14165c501546970b734, 0x2e7ac6fa9dffaf83a9ebb6966ef5464e],
+    [0x9d14cff8fa57e2fdaa115a472dd4ec12, 0x04bca07a15b585cd27790768caa382e9],
+    [0x00c839ea855a50f1916b03c6761c034b, 0x0a230ab485e781468fdbfedf32413aa4],
+    [0xaec8ed93c49c53bdd2587339b216ec74, 0x1621dc3c8c3db86fa83059f4a3c41298],
+    [0xd05094fafebd6619348e1dd9fce168ca, 0x1b4e4bc4f028fded0daa5c1a094feca0],
+    [0x9738b357287fe6d8727e10ff4452c799, 0x1a739e55ca644e9ca0ff28062320977d],
+    [0x8c367a2439adea3d942ee3c3185cfd92, 0x292800e8452a5666cd02b1756950f804],
+    [0x81a91439a39c0fa1311ffc28c4484dd8, 0x1fa371d9106b81aa664c11f50cd41d24],
+    [0xa19cda5d76c463bff4ef12200bd6cdf1, 0x2a786941b8ab631a455f1f0bdbbd3eb9],
+    [0x1fbaeeee821fa2e11e29e70c843fc859, 0x1521f303fd41509e122d6b7661d62c91],
+    [0x4c677827c5663418bb9a2c9dcef29157, 0x242af7a107cc7bd9c1c5b2537e5eeb79],
+    [0xafa9d217ea012ce12d14e0271301e77d, 0x1cc30d4813bbff4b91740cc8ba20bd06],
+    [0xff010ab030431664899a4a323b29735f, 0x23bd2922b425af4d8bdb587b1cc08ccd],
+    [0x0d9f54cb7e65633cd5c310555e0b390c, 0x150fab5986eef5bd1fefa92aab500253],
+    [0xc730df7e5c6ae3bbcaef613bc0ae707a, 0x18b43cb064bbf3c83fc2f158ae785aab],
+    [0x2e5a28a1181d777e2aac1c993acd6698, 0x13bb42eb4e73787060889be175018b8f],
+    [0xba34cd3b35d0b7c97bd2d705ef842abb, 0x1ab89b566dff36a95f8050e843494740],
+    [0x0d005940af09546de8e34ff991435fb6, 0x2d5fd90e717d8af8d5b10f73844c6ca5],
+    [0x972a479b35c933ccad984a0b6ce94bc9, 0x0b836d0d581914218db0e93c8598b388],
+    [0x44a94003996fe3c7fb9d64d72d352e3a, 0x02ecdfb150fa2eecf4c34c76d56eb29a],
+    [0x025fefd00652195dad122b400a5196b5, 0x23578d7d5ec8ed31b2d7eb794564d389],
+    [0xc9df759375fffa6835a4c1c27f222796, 0x1ccfb8b4d93e5e08c7c4c4f30635706f],
+    [0x8873d4441e308375e8e6b738a1c0cfd3, 0x1c0cb735346164ba93308881d536f588],
+    [0xc33029c06dbbf6f410774f242d0023e3, 0x0b73e5b78e0d23f77b0fdfd001738191],
+    [0xe9b8311421f5b1f38484ab7692c6c632, 0x044715c00e5fcb99c435189c1ec24b3c],
+    [0xa3b3a8551d0bf1a5b95214cf2310f700, 0x1d3b3647b8ea345e92cc286f1bc18b6e],
+    [0xbd6182f9946ada1e5d3f764ce1ab0ce0, 0x1044b915a339a2582a6e7fb6a66f2b97],
+    [0x803a92b87375dfd68f976f4622a51534, 0x1813db2251e3d904d0d30bb86be2d7c5],
+    [0x5a08e0f6fcd8d92182df7fb3b2b3924e, 0x2808152f5685bfe577c94fca9d70962e],
+    [0x9b5c6987ba11f3f51d9a53d3fd1d4011, 0x2da940a1e8c89a6f983e3f988a4e7975],
+    [0xf997b4526a3d3f9c9fedf51cd1670269, 0x20fb1d869cd192c87e34254e4b48f222],
+    [0x698c0f5d5febcfd48ec3bc1059959f1a, 0x1bd49eeeaac3927d1982829065a4db42],
+    [0x84dd024e0ad55ae02728ac322689e66d, 0x135112b374480642311b6b142704f216],
+    [0xd7cdd39570bedc14a87ace5f726ffeac, 0x2c9e245c71832c690a7ca3db1cdaadcd],
+    [0x856929186a1ee3eb2872ad86be73f564, 0x1a319a5ab3b2a9e95eb0c11a5a12b79e],
+    [0xf4ccc1fc26bece9f4f3eb44b90513808, 0x1a123c31f99d5ea55bc42ee322b7c070],
+    [0x36d6602afa94328439590ff3e200ef76, 0x0b23ffdea065c8e75ea0d3bfd966dff1],
+    [0xb1be1eaedd875a7539678046bd1a3314, 0x1c81f8e014ab25b8ed79c5e1bdbf869f],
+    [0x3543ef523858bf7de922363354ac3370, 0x01bfc092f4701d4caeff6ed32605cc0f],
+    [0x689d1e6f978925deed56f2ada6d01fec, 0x16068fc89b3388fe5bd541a72eebf297],
+    [0xd5a9d40e3020de4d1a71039ef2630336, 0x03bd9dc121e7e98b549441bcf073b564],
+    [0xe94e1893c88ef2a544fb5f6295da72c2, 0x1d0bbb93d9c927a609eff76c3ca95ac6],
+    [0x21d4e8eb954202f2b90c211cac96c9c8, 0x20e9e2dc88aea45883e13db206222e4c],
+    [0x331f51ad13a7ee5a71efd7eb8594dae5, 0x0a69dec0cd8a399fff11902a34afe9a1],
+    [0x3264d5b6f2661105b675c5ff41575c89, 0x0867c910850d2f230f7e5a03b3cf0dc0],
+    [0x27c2d0bb368de23c69fc7a94c844c404, 0x145603f8fb5a0cf56bf71b9e6ffff831],
+    [0x6ffe2144b7be46d9a56a410d17da75c4, 0x2b8bb76dceafdd4a12be1fc9437b9d40],
+    [0x5eac7d054f1fa5fa7db91c07fb3fa7d6, 0x089bc5ce0fd41d90b1aa659de78346e0],
+    [0x8f1339cb21f2cc32905d82feb4e93f93, 0x27bad78b8cfde47a264cb63481b5a038],
+    [0x7bcbcc034abcfd46fe967d8417fd3720, 0x07043ee703d2b1b6ab8641fcffaecd10],
+    [0x1e0da370d2f07e6f854f67049e54c0cc, 0x04f5ccdf4036bb78889335f68633fe09],
+    [0xc534bcc58fa9f80a8130509dc0820256, 0x15b1ffe12147ee71e3401b3335e78681],
+    [0x873a44edbd265fc152d65018783cd573, 0x109b0548eef07fbb9230e92b4cfb7441],
+    [0xc5a3796ad74c872a7426fbeb7fece87d, 0x0f07c58aa725aeb731b6af087e7c5cce],
+    [0x3b1e75b3733ce7c9a237a4c5e5274514, 0x1d1342ddce6e4469f70a948ce109108d],
+    [0xdaa9578c82a38e252885c4f3bb138b10, 0x0635be9e7e8443d3b2886d154344d243],
+    [0x618ad0b714ab468b5eab988cfd8c2959, 0x1bd4922ac758c0f4c50bb3035b7ed4b5],
+    [0x76e21ec6180c580e4a58634a7b74759d, 0x2e1a0e6f84c93342b4d529f881845549],
+    [0xa9088c7c9edd9aadf9152b9dd839f2b5, 0x2f1589be5a127698a4783ba48722e4c9],
+    [0xf8415b9fa2e1dd6fc6435f0a3ef35526, 0x273dbaf645f1185466bfc99b676c6187],
+    [0x695919e047b4807bd6e9a3cd4b4e0a22, 0x1c11b76df042385ceb6d0fe269cfa150],
+    [0x5394c9b3ccfc6a0bb444dfc6c7867359, 0x16a0988d4234ecb090f09db6bde79af3],
+    [0xce804a93a2fc60452a0cf948fb03a8b3, 0x215d025730ada1788e2024cce27f2a53],
+    [0xe91fcf64325af2202de42eac92bbdf21, 0x2cdfdb0847de8099ed8a941822b15ef9],
+    [0xe449e26a4f9aa87c28c7e2f118961342, 0x0946dbe9998dec541da39a9d8ea544a7],
+    [0x5c89fb94e0c724a7a65c1c3218b67e40, 0x0f958982b43f8fdc2c3785c4ac3e631d],
+    [0xcc3b36fd36188a1b8c12e56d998c5e84, 0x185021b192274b1b61952ad219cab61a],
+    [0x7c0e7d4212a2e6de0eac44a210260b35, 0x24d56b4d52b47f9bc6dcb7ad353eec92],
+    [0xca004b549468cbd201b0a31c8b7e1d17, 0x1098d58fe7b53523eee81510b083fdad],
+    [0x21ccff028ad504583976e7e30efc3c88, 0x24a99bc48481068390bc48330c113b79],
+    [0x18705146f72f7b4e4ab24934dbb4c673, 0x12a7fd0a7a6ed9c0eaca3465ae557cf3],
+    [0x52848089a845d1fcffae9f47e0716b16, 0x0d42adee447d6aea66365bf01e5b607c],
+    [0x930dddef69c2023b167ffea54b0c4aaa, 0x00d5d109de2579f4e5ca2763a37d1310],
+    [0x2a077d194a02210022acc59e7ea469d4, 0x2efe7e744a2f0e450693507731d9c049],
+    [0x6fcad311b77b5fe3030a96d5334bb833, 0x1dc37644bbecdc24f85b713fd19af55b],
+    [0xfda366c9d07bcf56c7b0f4821c263764, 0x243115568da36482d004008176a75427],
+    [0x957327beae9834fc428046a0f5926e71, 0x2ac9106e5d82d4e200b257b0b222cb30],
+    [0x35820ce4883fd0938260f85555c6c0b5, 0x16b852944570287d6e02c35a63003926],
+    [0x6f3e862b7b8235119430f7678a2a2a74, 0x29dfa400161b661f5264b4158460d477],
+    [0xd79dce7535aedc57aaee994326c386b3, 0x25908a5cfb1c210f3afcd6e8d63173a6],
+    [0xdbf19d50f0e990965fcc89bdc3bf3546, 0x1480f56b3a68ef41e5ea44dff254aadd],
+    [0x0807bcfa3e49a76efc9032f5f53af5b8, 0x2c1490f9335e14dbfaeac6675595f6b1],
+    [0xa6e2170d31c1031a255cd800fcb262b1, 0x1833f0ce5dc529d3576a7fb481d8f70f],
+    [0x1fd51cc430f25bee2cccf55e2e762733, 0x26d36461a1c9f3a3653590e8273554f8],
+    [0x07dc6bed2710362256f16dbda69a90ae, 0x29027f0fb381318f8eac14af24f76e17],
+    [0x94176cdf07a2ecc3dafb54f9d45d9b03, 0x0a4cde8f3d42ed48fb3daa7589036f49],
+    [0xec482785e190dda2536e2c4011da8704, 0x1fbec12feffe0889cc4a31fcae7d819c],
+    [0x09274684014ce72714005d98b8d8c666, 0x1b26f238c7bb8636782bc0ee48a931a3],
+    [0x43fa60c66d74a33dc844ec1c34598ee9, 0x08ff4df0c027e88807b8b6e55e6bf7e6],
+    [0x915de22629a612a1b6da389ab86fbc24, 0x2207c1318e959e4a002ad5015d381b29],
+    [0x6a09f84216bcc1e46f11ac84d5d75ef5, 0x09fcb07e82193539b71d0a052d397799],
+    [0x7ee7533153678dd2ef33f1e91fbf98cf, 0x1969f5c20e80f10e71ff42fe5af7bec4],
+    [0x23bdfbc34769e11ac34ef2494f2bff85, 0x07b65d6a874f5706282479ce323f36bf],
+    [0x65f1b069142f144f80c46649a65bc425, 0x09e9ac4c51b61ceada2c861e276a1c06],
+    [0x8feacfd5aefb9546e1e5adf59ab4acf7, 0x08b74eb90b0e5b3013347515fbcfe94d],
+    [0x56f68ecf8d1c7faba519dea116e0217a, 0x19351714ce3d2e3e93ec9015c02e0fa0],
+    [0xad5bc7893a53b3cf1c9ba21d51b00410, 0x03c4a15bc97488139ce1cb928f474505],
+    [0x290a0b17d5060f0222f263c7417d25cc, 0x0d8b7773df335b6a9cf298b08721d640],
+    [0x6599f620652b8bdfd244251667aed9ce, 0x1e14c212fb7679676af50c3711b1917d],
+    [0x62a28043d90aef654171bb70e429fade, 0x2186cc6cdc5271291a9b111b98bde50c],
+    [0x4b174685aeeca35788de665f2812a195, 0x0768dbd4b9074b435467927d1187378c],
+    [0xbe013618ad5f811cc317fc1e558c79da, 0x063f5a888b7bcd3f8ae406ba40e11207],
+    [0x553eb4ce92e9398d702f938cf9ee741f, 0x17807cdc2e72f05b3371fce98fdee5f3],
+    [0x6601549304475ca580d13741e2a7fd92, 0x25f3dd0be3bdf8e632afdcbd431be7ae],
+    [0xef4f1d9dcccd157bdceef7aae145f6d5, 0x2274a45e2d69064bce67e2eb664db45a],
+    [0x3fb1ce85df07ab68a028b46dea841701, 0x087ee7936deb77a97b8d7473f53436cf],
+    [0xbeb633ece1180f1f8437abc9099df1be, 0x0aaec0f75e53087db937a974edeb32ea],
+    [0x01ed91fad9891f9c6fcb7b5ce438ba95, 0x10cdf01e8c61263b446c3f7a10cc29fd],
+    [0x5bedcf3f22c91fe991cc6568a4fb54ce, 0x176627a92072fa2bb64dd5dff9cc4a0d],
+    [0x79a417eb0c65b1b617644e1c52c4b425, 0x0eab0e158e2f672c68a7a0a48328e211],
+    [0x2f7965741da5a7c7fabee25eac3b47ee, 0x2edf1ea1cd97d1a91fe7901e64c0f2d1],
+    [0x739680a69eed5da0bf35062f4d4cacc1, 0x1fc07137863b1d337bb5dac18ad91237],
+    [0x9eb298966d79308ae414d34227aaf862, 0x037bdff68025bddcbed554ceac3df66a],
+    [0xe5f363b74a3c16169481c57c5b5c07a3, 0x177f68957ba07da671b9dd919f2433df],
+    [0x02b85f8f42362cf43671b60a69e9e60a, 0x06d47b9921e2502c2abb0138d3b1028b],
+    [0x003e49c8cd1f0f5101c861b177715da5, 0x131d58cb8a41a753e767e1e97cef60d0],
+    [0x33b3fd4fd5f543810a0c5d2143ed4c9c, 0x236ca76235d9e69472c9985f842e626f],
+    [0x96b64d1f61e813730cebc06d068537f2, 0x2fe7f85a579916545907f2a02202b836],
+    [0x528e3a341260125588e75aaa88f62d71, 0x04952326bd26efed64637d4fc0f7a4bb],
+    [0xf32325bb418f4f66263a2b63f3f98384, 0x00c2dfb577b78dad18837bb70c8e2990],
+    [0xe25140bd6b37337ea7dfad5e1242328a, 0x122c682293b4892d51255991f9cfaf96],
+    [0xb3ca3ee54711498db1a372b9cc23d145, 0x11ddc540d4a110e482a2455112ba67ff],
+    [0xee48a337f1fe3d7ce01bb89242106a1b, 0x2f6bd71f6b2f3af3d468820c80aaad72],
+    [0xbdc259e715848ce047be056a4a09f787, 0x0d6a16d136ee77c35f216f1aa0ca9e09],
+    [0x93a07d4605dca6422109096164b22b2c, 0x2e7c6013d1be62a78b4c0015465fb0b2],
+    [0xb36fd0f569fb36474f694b11b2948ba5, 0x0d6bbf18b7eb684adec412713570d3bf],
+    [0xb7fcde2e6ab48121288dc04d5b51e594, 0x12403cf8877ae510b7314b7c75ab5b0f],
+    [0x17382150c080ca3f8012bb0ba2fed47b, 0x151b3acf4ce3297d86e823a72067c717],
+    [0x1d88b5681a7e47fd114cfb682faf4f98, 0x2a1396702063c62e07c512b9d4d181d7],
+    [0x041d90ce535c638d2b523a61dd7869c6, 0x25cc49f3508fca1873a6f85b6550b6e1],
+    [0x9cf4040bcced94e8a37e1c9e307683e1, 0x1fd18327a0482c394723b152c9054472],
+    [0xfe65a5b0da123cb31bbc89533e518b3b, 0x0b27566ce02bd4cd2273fd531336c0eb],
+    [0xf111d262c82cf3450f9ca80608ec15ff, 0x1a9e153ce82bf2a6fa0d71d1889c24b5],
+    [0x472c7446e4e27fc1fa4cdcdc8605acc7, 0x07ca794d5ac4f40f0fec4d69e3a3cd1f],
+    [0x6494fa9e9e782e0e410405efbaf504ad, 0x2fdaf44c707d8289da9373536a6f61b6],
+    [0xe8dc7f12aeec0752b79179267d643a74, 0x154d7c7e94ee06a436fdf59b501b763b],
+    [0x3532bf5cf394f40bb8db5ec5fb7a64ee, 0x2c753b7779a3909fa0cb0695a9f33a67],
+    [0x3feae75c3533c0ab4bb9ae43d61201a8, 0x144ef40df7a262ff41795d09590112d6],
+    [0xd138e148d6d80b469d6e95197d164883, 0x22c68d6cd4bb18163791274a28ba33b0],
+    [0x3e56da932c24d73095177fe7f8aa979b, 0x27ca38cbe8b2ec8a927b6fa011e98232],
+    [0x6b139addd7ccc0c0f353be33d8873ea0, 0x036994c16973ea012441dbae792fb3d0],
+    [0x38c56c7fa9c0586978056aa77decfd3a, 0x242d7d1bb8ed3658035034b55e28b431],
+    [0xca5c18ac5db90108bd76021bb9b0cef2, 0x194576547027c4224e9cf6de762a5169],
+    [0x563fc9898a7d0ce684e535fec79fcdd8, 0x0e823aa11624ee571f23da021d4d7990],
+    [0x24b90868a4ba170bb1ccf5e112205c4c, 0x082858a4db283919b56c0bc08b893867],
+    [0x884aa670f142da689569db24f5b0d650, 0x0217822675afac3da4a9137e0b172532],
+    [0x52b7681959fbfd7913b43af0f5858bb6, 0x274c614c2e392cee896b7850aa7b6fe9],
+    [0x440e5d120133252049385ee1be25aae3, 0x27d35572895d61ef77994592fc75251f],
+    [0x00d3e625413d27f6d60c39d00c74bf7a, 0x2b391acc9c8e452b35f31c91bc7de27e],
+    [0xde368474dce727a0069e97cc0a546b5c, 0x1b6320cc8acd98c92ca849a5b3ae52f2],
+    [0xe87016864aed62735ff0d428c0ac91f4, 0x2286075e6a96f1a55fba6f84b3ca4974],
+    [0xa523bbf82123dc98f4c097e17e11deaa, 0x18aada171822e8e1dc3da76917fed163],
+    [0x3885e52023e6e36e5d71f05cf5f3035a, 0x0fc3fab45d405fa715ee3ac4a279c700],
+    [0x55cd12e1d65881777ecda694a5e52a32, 0x1ce99509af24e048925eaad26d294cd5],
+    [0xd0dc4449b94c90ea3eb4e4fef138f727, 0x2e26c17c921163b5bb3aad23ffadd47d],
+    [0x2aa2fa762c690991804aea7df5cfd3f5, 0x15116a7c92e6d9d46fb7fb51adee5a86],
+    [0xe6656c87be903bf19a92e7c2a499c396, 0x18a26a0800fcf288aa7e98f047317210],
+    [0x9b7fbfdd055a3f953cfcfeeb47a85c7b, 0x22d7a40e7e4c1e76dbfbcce1601c2246],
+    [0x99ec5fc437921508fd29d5bf21807afe, 0x0ae771488eb4bf107cda3042dda34958],
+    [0x13c1bd3e10de8ec967a5deee234cd701, 0x17104e0440a812a33f825fe57da99dde],
+    [0xe1ce822ce02a629c3696e2cdb2894a5e, 0x2680e4afc93fbbeb972d842f18fa3a35],
+    [0xb13d11b27d89323d2ae2748fd54f16d9, 0x0d64fda93aba5c384eb39bfc144bea57],
+    [0x087abc601709b1f923f04ea67b5f58f3, 0x0aeacc635139009646db8941410c819e],
+    [0x21db193335616c785a073f69cf15bf38, 0x0a8a2ed2b2158fe6443bc3b11754c145],
+    [0x00fd8c0a386942794c29187aab6273bc, 0x07b133a201739ee204c81df78b20a13e],
+    [0x1142ad7c4af11563e1adff97f863ceb5, 0x118fbc76b7f861e02e9ae5ba516960c9],
+    [0x4123a0ccad5597d42779a8120f54f022, 0x2754b4ff473681f3069edfabe21dc75f],
+    [0xfa57634f4db0c2c24a80d26951c1ddc2, 0x190360a036668714837675f7e59fdf15],
+    [0x87f3c303c76bed52017073a0a18dbf53, 0x222922f3670b5f65cf34b2b2665b40a1],
+    [0xf30e1172a5d506d1241818aee9cc7c55, 0x109e9fc20acfcecf3d47c04afdfba926],
+    [0x8851787374dceee4d45a6645a3a69b30, 0x16213b42dc9dd4c9ef91500adf2ef870],
+    [0x42b67ce952b0e1a03ad175066f755920, 0x24023ea68a2a2b008acbc5d4a15fcd09],
+    [0xebd774596b4cfe6bb4d57ac38070859e, 0x247404c48faa06e6c72b1d050b905fa3],
+    [0x17cb0f71c4cd827f5353806230d8916b, 0x1d868231e2df133dc0212c6248179cea],
+    [0xdc33028d8eacd341f510f1dea2be37ae, 0x0c314749559c134886fc12ba1326e4ff],
+    [0xb25bcd26e3711d28cb152cbc5b87d2ce, 0x0cc1c33b5320c911b47eee03c4d96ea2],
+    [0xa74d332bc19e6d3e8ba947f0eb3999ac, 0x05f4c3605ea09d4b737b29fee87e1c2b],
+    [0xadbcb847b06476f98c6b7f8b8f042457, 0x05bd7ce441a3a72786312e63866bb986],
+    [0x7c0b335e0f36bc1cdde5f3952dedc132, 0x13fc4c0e950281ef4e96eca60355152f],
+    [0x76884faee1bd3aec26bb93493a064f03, 0x153c2f89d6681e7f94df511b9f193e28],
+    [0x010be47978764ee4d14e952dc4a3a118, 0x16fcfd6ddd242be8cd4982678cbabaf4],
+    [0xfc0a9fa99788927e504721ea0668cf7c, 0x155435d02b33ce45b9c3b6f3f1090930],
+    [0x5bb693bb7960646421621fc2c391c5c1, 0x0669fa7d440e3a06db6fb80c876a3624],
+    [0xf69d96cf45bc807e55d89bf980e8ac76, 0x2b57df93a096e78d7f14eb117a9a5c5e],
+    [0xe76be0b6e5c9ac107a12fb9e655c8713, 0x1e8a7d19a676b3263f85a8482d95d0f3],
+    [0xbfa69005a0c185897c72efb56bd98a87, 0x2a6de0bded2bbe2b5866801f880e47e6],
+    [0xbd066cc32e5942a3fa2572de1348f840, 0x099ed3a2ae3f0f6d0a67a1f79b55a135],
+    [0x3d8027a2faa810517efbdfdca997fbf7, 0x1e7d7ce084654213565d6ee21bf1f27b],
+    [0x99143210bbd8c66847764aa4f63c65b9, 0x2e703dfbdc86f5586edfa0221c81bb68],
+    [0x826d5672798fe7482572b5f41f37194d, 0x0505b0c3df9f92959a41fb36c72d85bb],
+    [0x8275087ff9116e356fde302840ffd3ac, 0x26d1fc920ab60d7fc4e826056f7d19c7],
+    [0x7b9f5e10aae7cce40ce6a937506d510a, 0x2b3439c16b1af27bd7a4c8d9ddc6934e],
+    [0x0ee7123b3a1190475c6e25e06f832154, 0x1e45d86bc184f0e086020deae34e53ab],
+    [0xdbc6c9f8321cc75e9f15a66c3bcb09c0, 0x23f3e2f49ca1c95434b0342156172e65],
+    [0x48c03bc876cd7915d888b5b0a11a21a3, 0x1d8c2e51db59d6e96ee69f68480d1f8e],
+    [0x0b662f8a56ad862ea1247ba2ab0f3d2b, 0x1e1dc3546a86032cdf8b8bf7f94a4473],
+    [0x99178da5f92d8f9f9153c695e7859447, 0x1147e82c00df45e182d416b8338ffae2],
+    [0x1d20cb11f5624faeb3f78f38247e90b9, 0x0be94456b9a03c6cbfab9d8a34b411e9],
+    [0x4b266ff2f7c0c063b5c44d6b48cca3f7, 0x0c4bd9a3aaa923f649b9cd1cbd787957],
+    [0x1e2871d4cf377969ca151fed5d7b4dee, 0x06198c1fe412e25887115b54ff87eb4f],
+    [0x679ceb41b587f619d620e1f5108b839d, 0x1ee90aae68a6bda42c34a797d4d31f79],
+    [0xddc6b6c98b57b84772d6fc6b8be017ca, 0x11aa5360b586c21447711b49721e9f4f],
+    [0x1d66b6730825eb98a24d25987e87be58, 0x1a41a67390f922f10cdbaaa0ce5eaef6],
+    [0xe920788393df128f8a5416d26231615c, 0x24a2997c832fba549618926afbcad00c],
+    [0xb22f8e88d454bc1f8ef0c82cc93a2772, 0x067036caf8b57daa59ad4514773898e1],
+    [0x035fd2b4231a584139adb7a11221bc33, 0x2f18e853f9b7b7c4b1b4c3f0d1a2570b],
+    [0xf2be7f8dd9dedac6d91fc5b9e53186a1, 0x1b3874144923943beb66ca8b798d0bb8],
+    [0xc62241c3c285afe3a1b14cf71574c3fe, 0x0ef5849ff458a11dc413a2d8abea9888],
+    [0x201a549af584ffa9a70a8026817ca668, 0x196a36a231753ffd0c094642e27d80ba],
+    [0x7898a361847e58b67576f12e3034a8c6, 0x2e02fc222dd56fe6568a345add3c1d57],
+    [0xc67ad39366ed8c43b42520c6292452c1, 0x2383bbeafd391d96aaf0ba7481467867],
+    [0x92637321cc9f0f4aa7214790676a3f75, 0x20de2cd5ce733ea00a7f167f686ae671],
+    [0xb1980b83514095d65e513b79351a9907, 0x1f1bce33439afb8dbab1354b46a5b9ce],
+    [0xf1812ef53cec6e05baec160c054d6747, 0x057b8de9d8ed593b5d214302ce88ee08],
+    [0xab8b1d626b685502253a10c8050a4a55, 0x26777acbd88e932b2552f7813e62f368],
+    [0x01fec7a530ca18fe4bd2f096ff44af40, 0x01589c939e2e3efeb8cb6c1034768791],
+    [0x2346a54ae48bc3f573adf719b4e09719, 0x2a36b79d87c963b1f47590ecc4cd6914],
+    [0xf22278be0ee6344f2a775132fe107a0d, 0x0eed87708b5b00a42af3e6de58d4cdda],
+    [0x083d936ce64bd51a32dfa9f1b0e71325, 0x2bf84fed63a11695b639727e18111ac2],
+    [0xec7adba6f6db9a58ad59a678f8f18a39, 0x164a6098075941c6467319c2372463c3],
+    [0x09e3cd5d82f8df8c3f74b1b73b08973e, 0x205075ea3558a17b0c51ae3a107d8986],
+    [0x10c2bcdce6d8f4d1f7b76d50419b4376, 0x1f1e3c76fc69fd8acfa3a9bfd0af695c],
+    [0x81e1733be57f7573f2fe44dd35618c54, 0x0069982a9b25bd75814397b6d3c8a907],
+    [0x43d4432ce11153a0fe735e55474a2393, 0x2f17582941481b5489211befd4bc0dca],
+    [0xbadbd4edb7767f990c46b0fdbe9fb297, 0x164531eab380bf81f6313f32f5c255a9],
+    [0x507a17c888931f3e99d356a9a06ef8ac, 0x031f46a8b4455ff121bc184a1c3551d4],
+    [0x8dcc782c5ff82f1ebf41ca094815741a, 0x11334dbaa6f7f4140d1493bd188c7762],
+    [0x2de229698e5414b36be8997f060e8913, 0x0218b1d38a6fd11d6566a52fe136e2c8],
+    [0x187e16a75f2ebe40467060500eb93c31, 0x2effca369cf34f74248e37ae3bc74299],
+    [0xc5876550ff0ee776663c3997a8f1a116, 0x07e54138f828c24b98b3511392861ae9],
+    [0x9ac5b0e7906d8588238187b795001fb1, 0x297e1fb7da5497d3dbd0798a146d2fab],
+    [0x06a5bdbda365a33bb0ae228900e53b31, 0x149016a717fd85cc7df7f039ca6b1b8b],
+    [0x2f92db5268ad2148ba534dc853bfc54f, 0x0f9e7a22124bf50d94db9231d6368512],
+    [0xa0e43bcf03c7ea81970b1d802a867b88, 0x1a3519a62fa692b3716d11b88e9d62aa],
+    [0xa7c2c6088e7b7f9adece3f873dedfb95, 0x2bb67c5d6c48cf458a293d69b2d1d44f],
+    [0x237a445d5195c5e361c5bca31edfa972, 0x1fa713dde3903e41e68f09ac2f34c9b1],
+    [0xb4e32bb6520c711102aebdd0c1516843, 0x2019aa92adf7b5e795f0e539682e4b39],
+    [0x999432a9c7df06621b2504a46f120cda, 0x2253b9233d047afa018c40c49fe9ff1f],
+    [0x24be1d81354911a18926a98e047bdae1, 0x1cea08c49328f5f053c6b47cbbc95592],
+    [0xe2646af9adaac20fd8e58f308d2736e1, 0x123ad23966a42c729713094fc352e466],
+    [0x92ce5f80d2280daf826619b7ebdf79a5, 0x1ef59a7e266477a644527ebecf0bbbcf],
+    [0x702b7bac80b4e33eceb592f86d5de3a1, 0x19ec1d74d7f3672f9bc66be9ac40f5be],
+    [0x3ff07c778c2ae68c55d4893a927a3a45, 0x242646c43d7f28cf38c9acaf8c63b1b3],
+    [0xccfbcbd79992430fcb50b04a42cbe9b3, 0x0e2c8a604553db3b81980fe40d801cac],
+    [0x2cd1f7f8ae64c88f5e8a500a34056aee, 0x2e77da327c869b63fadf3824d718983f],
+    [0x9742a1167792e33d70d35b5fd0ac2736, 0x191ca3be9eacc6be4808bc1668d81449],
+    [0x50b48a0e5de7936b2cf25eee46631964, 0x2a26e10b714c8c92c423313687360188],
+    [0x2364872d01e51e969b14d984930c10f0, 0x05aa6b2e25b7ce4c170427081a01190f],
+    [0xde3850493371e1b10bcef4cf32ff2085, 0x07acb3904e2ccd87ac5d9d7b6b7b7bbc],
+    [0xbfe69d42d1ba4400bcf0d8ad53a18c09, 0x05aa9ca5f20dfc104c85eb2bcd80dfe7],
+    [0x65280186985f72fac6da310e80175dd9, 0x0411a0b6eb09fffbe966876683e86ab9],
+    [0x862caf71e348b42027553ab1e7f0cd1e, 0x1e165682a562b6dd29cef850b2759ebf],
+    [0xd224ec7c1c67b10d1e5f634fdf526b74, 0x2b65e92a1f91e38b2e7f192ebf0bc60c],
+    [0x3cbfaacb0f9f531b36b0bc74d364811f, 0x2208ea7d1fc63767b1d3dd1e7fcfd4b1],
+    [0x91e7c345891b6ab4760e6e2bb62059b4, 0x1ac718a287105fdd6dcbdd6e3a9f8337],
+    [0x30c92d0138f90dc555f6a48971f50e6b, 0x098090f723dea7b927edc36a8eecf846],
+    [0xfb5d4e5bfa4a0b3d0a62223ed66b4d6e, 0x1dbe00faa3343aff72d37b302260143b],
+    [0x68a43f88ce053575a2ebcaf718860998, 0x0642c365181ca2ec3e423d6ce74e1450],
+    [0xe345efbe3ea7f6c7a4fcbd3c642246eb, 0x245611d0d8047a24db3d78131bdedc67],
+    [0xdc62a1793e3811ec5161c800b487f54e, 0x091ffe3d519525ba080e1fab546709b2],
+    [0x4b7c8cef458cf9db494d786a26b69e4e, 0x2a617ed588722d3acf4900b3dc501572],
+    [0x3ca436e2d7c0f9ada90040affba0a8cc, 0x1302868a9ab3b6a36cced0f80f53f1a1],
+    [0x95bc3fb377e5903f89ee9b3efac052ab, 0x1d903daf4300755be0acaaf9ebb44147],
+    [0x8f523a0050dc0122f5d9669b26347770, 0x0f671792ec6b8bbd0936abbc039d2a86],
+    [0xe9c35054a36fa51cd892d5d2133cf271, 0x1045a4241667a8cd38078067970b02dc],
+    [0xdd7a41bd07096c36c2204320a6760b2b, 0x28d738f79c07b35d2df772d562d54346],
+    [0x35be9d49cb4f3f3bb4e5e1fcd1789d51, 0x2d245b5c1c1a732f8283446ede7e846f],
+    [0x7c8f2e14bf99f0bc2b8b86bb89b4eea1, 0x25a499ca1907638351edbc836c1498ba],
+    [0x9423f9be115ca9ac4584d4ac75296bdd, 0x1be3f4d7663b949402307b82b828a15f],
+    [0xd1151469ad4d851d0c3366ed6b99320a, 0x1b67eb51facef5814a30cf5794a95d4a],
+    [0x91022c78cb6fa047f825a41dd3e495c2, 0x06ad69201f948e4749ef9095707fdc65],
+    [0xe5e61d99b1d52d0f82e8371509654119, 0x0a4b8288548d5fb3dde7cf4bbd1a0881],
+    [0xf7c7d8f9ad1de8d1d8d48a98b0054c93, 0x0eb4a9342e89d9cf0f482c41ce66e40f],
+    [0x5e5a58a6b651a12a8969ad5a0dfad97e, 0x1a645a223471e2cf776be1bfdb706018],
+    [0x3e7c2b01c465777bceae1a67e6b412c1, 0x0302c4499d41e259ff761ddb305a366f],
+    [0x2f5a644dcea6444357a121bc2aa4274c, 0x2c17e0b7f901b93630c9da99baef3c5c],
+    [0xd0e0ffa247c54ef9617a80f76d4195cc, 0x0b0e36da8335e243dc7fd59cc2365974],
+    [0xe61399f2311c09331c7ef2f9beab22bb, 0x129e733c1dda2ede8d396d85fd6bb8b6],
+    [0xeed01d5e464e8870447c5c8b4befc4ff, 0x2cdea7eb18f6146b67c437f37c13f856],
+    [0x614f407fb60097d4505ccfcb31d67745, 0x2ad4f0216033018e7d2a550c2e082432],
+    [0xc44d0d63fd5860fd475ce818276691f6, 0x2aac4f54256835bad96eec256641a10f],
+    [0xf1f76c4a86f2fd9c9c29d2433f4d228b, 0x17e25132c09ead8dfded691b9081b581],
+    [0x5e1f1e39f163e5f4b9948de5b4be3296, 0x2ed95e493ac979a091bb6d28e81672ec],
+    [0xc110c33cfbb445c9cf3b70d9081e4f0a, 0x161ce63d596de69443d7f542fff36cd3],
+    [0x5a80bdd7796a6b3d9a0daf66a0b8a4bb, 0x193efc77ea76010547c5bcc600a11f1d],
+    [0x86e2ca22a8435f241cfa443c6000f7e1, 0x1449a38a938cee3b56f505f1ba2325b1],
+    [0xe7f1bb93d550187ca2dbbd4a4205a7d2, 0x2060ecf9aa31db3120201827fcdf6b5e],
+    [0x6779546c93340748ef2e05794b48ab1e, 0x048dbcdfe2fb6c50968e825a95835764],
+    [0x1413408497e6c3c2fa49d9c8aa36f408, 0x0302fbe9b13a3eed91a823b0b4d3e362],
+    [0xed91276cbaf4ce0860817ac7bb838e9b, 0x03e81925a3fd5f25081532f2986507e0],
+    [0x22224d76ba5b3e4de34b7ec490407440, 0x1c549879138d434cf3143517dd9bf2a3],
+    [0x2e91e410315dcf3475f82d270d16d0ef, 0x08120c7ec356e16945d94e55e835ee7e],
+    [0x38d8ba7514c903aaa591dcde7bfc1863, 0x130646400633005168c1e14ee27ab8d2],
+    [0x4f54aba1c067ac1ef1ace082ab43479c, 0x23e9b4dd3d1f30c37cfdfb3a83d45e52],
+    [0x6473162956247894711ec767994fc3be, 0x285b38aaba20c38b0459f4a48843122b],
+    [0x8c2248ca08e5794806d1841f3a08184c, 0x2559fdf58d3c2200c83ed1fb4f1eed56],
+    [0xf7913b5d81d7da1aafe203a49ecb983b, 0x0e33c73626ab1ed741e47ac52be4e9f7],
+    [0xc1f9ba8901a925dc44c0dc6779ff5496, 0x051d4123632497aa324257ae52a51e2f],
+    [0x7ae9893d247ca9cd05672e9aff613148, 0x1fbabed28208178778241052b236f0bc],
+    [0x3550a51cdbfe2a76827237fb85beec7d, 0x1c2f74139538bd253c3dd45a2ffb9617],
+    [0xce924e5adaee5a3738d4f942bb0bf956, 0x157767e2a7fdf25c184755b2f47369f2],
+    [0x9e3d24fba5420dba8d1ea6d10dbf47f7, 0x0fdf1e7c86898044ddb397feb48e5ede],
+    [0x0428101b2c84b588ee40a443ac9e08b0, 0x0769e84ed17e6c1eac40b5e00ccd2d82],
+    [0x6dafc8153721b44f1b3561890c02e85c, 0x2f2a0f50ded1e6ab34f60f8c56904a51],
+    [0xbf2538aed1333df56e1b2d861928e2c4, 0x0b1dc04fe75dcb36de0bfe15ca2d5ad5],
+    [0xdc0b823b2af037d3623ea103a2078b24, 0x17aed8d117c277ed0d1d888b35ec4bd3],
+    [0xe6d32ddf431ff33ee05b9f429c0c9481, 0x153140aa66586cee231a8f3fd8605976],
+    [0x8027bce9de25c1ce5b0bb88b6a17d1aa, 0x2ae922e11ef4d41b7b84d52ab835f5bc],
+    [0x63b7e9390bac077747bbbe167a055b55, 0x0f818243cc3faecb7c73dfc21c2968be],
+    [0x155137cce327a27f4b66e2204075000e, 0x1f62838a30bad6eed4ede2ab52866db5],
+    [0xf018c5b53309ffd26f39ecf1e7f795b5, 0x2fd97957b9460d3414008cae825bb610],
+    [0xcc00df2d1b90557227cd49fc37ec2b51, 0x2692d11e64695e0d210374b482a47267],
+    [0xb4e9540a5308c96e25823d8787a5a040, 0x0fcaa78988a35202687ff0dda691b035],
+    [0xfaff3990e48866670f52a1db33d691cc, 0x1a742b2cce21c83d7943fe6b492a9b88],
+    [0x42a1bfeff1d85b6e7a163b8c3bfa8203, 0x073a59e2c9c1aeb2a2bb89b1fb9b64fc],
+    [0xf29648fc90a186c97e512140cfc5d966, 0x04c604d77b356b485d215a9fdf7e5ec3],
+    [0x5d1198ea3a969efe5e3bcf12d167882a, 0x1d380aad6383bd9f5c4b9fb4b127e075],
+    [0x4204f670d6b8e2b1b501d1f1b3fd7951, 0x0cb07cbf2019493088e17d16ce9d14fc],
+    [0x4b0874112c4489ff7aa0050e41fbba1b, 0x166eb6ad5855dfd3c45e27df9154853d],
+    [0x9c7220780b247aed82ee2079efadeedc, 0x0372a3beb9db36031b7f0c72baf1e53e],
+    [0x7a676f7d3d6017c6496ad031fd5f7b34, 0x05bd655204d55cc6b6390bc165030195],
+    [0x2a4e74b8ee661c27d5b651f45656021b, 0x144179199dba135cc5f9e91b76880f34],
+    [0x5f3de70bed632730f92299b3c32ba1ca, 0x0e6fe8c96ce98f3cc97c11993b5087b5],
+    [0x03ede836c44ac2b51bb3e35aa9576d4e, 0x07a2eb5886375517d399bfabe4c7ce5c],
+    [0x62bae0d572639e17bffad24590c3b89f, 0x0764ec896ba7a374f5fd5a1cbed8a2be],
+    [0x767a0785c3a14ac5ba29328c4da5f4f2, 0x07ba9ec6781cb474fa4d622908f09a01],
+    [0xb036a4013f2f67f350f3fc9e74049160, 0x0c325b498965e3770243012dafa0dd4f],
+    [0x6502ca23b3c85b5b8959a29691e34271, 0x219dd3ca3c98d1613393838c73c88a6c],
+    [0x76489f6260380af40ddab5ffc1c5b33e, 0x0eb2b45fd518f54e1b0d4825026fffdd],
+    [0x36b35d200bd3e43ce165eb38711a67ea, 0x2305b8b5abffdc552cd96956ebfa456a],
+    [0xbe594cf232fb5e8914407a43ae57b982, 0x1a7cb11ea9463f6390abb707987615ce],
+    [0xb7428d40c36305bbe0893e194a25b325, 0x1e67ce0524d1079208a94dc25483d9e6],
+    [0x0cf6020b4724b49ef031190dc4bb1120, 0x00ffc539a853778970432d8631bc0fdb],
+    [0x2f51cadcbf19b332f75ce1f46b5978ad, 0x29945c9ca4c39d45540c035c10678a3f],
+    [0x712487c687dd9664a4b167dc01804452, 0x19cb401323dbd3fd8be1da954c0291d6],
+    [0xadd0b2abf706caff421927506619dc48, 0x285fd86adb21908cc4441249fd005cb1],
+    [0x127ae552d00339b1dc4c59b1cc7ab4ee, 0x2faabfd09b63a81c1cf4dbfc64323d8c],
+    [0x617110c8cd4b2bb3b4a2b339e9857d4c, 0x2fc7539c406bce26fbe61517b9b8efd3],
+    [0x1d2bc1fad76b842940f293ec0bc2ac95, 0x1296cd089d4750e55c1c01d768b5e8f2],
+    [0xaa465a40cf60b07cc26549b577a1f228, 0x21a0c3daaf57fdcf2e88ebd4d1d1a44e],
+    [0x8ee511475e21e982fa1ce50467511ab7, 0x1acf97f4cacc97d888e4e3b5ad02dd9a],
+    [0x5482112d23d0ebd7f8bbd61c435df612, 0x0b3556b9eef69e8447d1869d1286e0d1],
+    [0x64da2bea71894005f4acb1ce6a684b70, 0x0b82cf5e2db8b88b32b37978b1176f2a],
+    [0xbde9f451eca15051413f36bb565ae3e7, 0x2c8ad1acc7636f65a12bf83ce68ac92a],
+    [0x8c92ae49ed7d814700ad1e13eb5bef89, 0x2fdcc46499cfb1102f55408079365822],
+    [0xbdc70f047b5b9166e3dd8a60bd2a8d0c, 0x2c8d7b9323fe30ec21eb7b8e0b88248f],
+    [0x59c9e971fc97506371af9489de93e918, 0x0d1ebf973c9d26a64362620e65b0ca9f],
+    [0x9866a0b653d0f4670233e9f59cd630db, 0x1bdd929216a105b1351c6047b43c53d7],
+    [0x21b625699202934b0a1d29ffd038fe97, 0x162aba246750dcd856c975a9a473a7c9],
+    [0xafde2932e4b15228679b540ff45e2eaa, 0x2e681db0e74c3b6cc499a9afbe2229c8],
+    [0xb8cd8099293c3a129504808d3ee39bd1, 0x2fcc235134792fb4a81da0c1ffd8084c],
+    [0x24399f6690a55416def3241ceece74a0, 0x1eb6661adbbb0a1774c6cf1637fa1e5e],
+    [0x97b0f3ed254cffab2926ce159e9f5627, 0x174045c72f6848838b2a08a7a0117560],
+    [0xfc74c4ee8621c95fbb2ca2e5f2dc3a11, 0x02a0575f09f3282fa07e9b5cf5538993],
+    [0x3225ad91b227b886b6b981aefdaca0c6, 0x282f62aeb167ef5963da6c6faa867112],
+    [0x0cfdbee1d249803238eac244351d7eb9, 0x1b9abbb81bcff5f1704632def6d989e5],
+    [0x8fb4626d18339c077a06e5c5c810badc, 0x18f8a4669faf9cff11dd19f3d73c3774],
+    [0x5c49c8496ee86d1175bdb7a431086a84, 0x21af0de4ddfc449368db59be9e924118],
+    [0x3a68d372ce6da9d455d80a780b15f8b7, 0x0b9a1ec9fd21cb95dc93c0ac1911135b],
+    [0x81d90844f367e3dd0a7bb9cc118dc502, 0x1b413f709358008f44c3e60877d810a0],
+    [0x98fffcea1f350826359bffb7fd4f31d2, 0x24b4efb5028575888feb5b8b59d40e34],
+    [0x788c7b5802339be9cd74d2ba57620117, 0x2b11edef3505a9dad6000607ffcbdd02],
+    [0x8bd89efd9f63741299dff53745dee8de, 0x202279706096cd42f906239c40c6713e],
+    [0xf52c8e321cc6bce3af0804a7c159205e, 0x207ed9f7ff57bb17d278f833b61cf678],
+    [0xb0443f3161fe46515ae8d3ac0b0e551d, 0x05041c088d4e637431b2a49baa3772a9],
+    [0x4a0259c59ea3c5e012f99534fc02374a, 0x001b7c7bc15682259ad0881dccbce0a6],
+    [0xefb5fa3cd7ad50f3f44ccca99128c84e, 0x1ec19711623ebc49ef4d857325aa9dd7],
+    [0x923e2c214c50a145518837a2b01ca900, 0x06cb1be75179c19f889920ea93186dff],
+    [0xf63cafb4821303664d5b331d22218d83, 0x2712a82039769317c21b225a4e0ba45d],
+    [0xbb101428d7200b731adf3b1c220f3b02, 0x1d7d0968738734d0710ae851377e340d],
+    [0xb56fb3b6a5567fab1e4061b86fa3e66d, 0x180502bae2da039d85f537212bade3ed],
+    [0xdd74caceac0e5a4ccf25ae0373e9213a, 0x1c3f1a329f00f6f7115a5fabbb73a67b],
+    [0xf42854d1a91608e39dbcda872bde960c, 0x0b888038bf1e8afa2e444b9f47cebf28],
+    [0x4adf56eae1214dc774baa10658962c12, 0x2a036e70e2987d85453ed7a3d9a68d57],
+    [0x98cd9001459c9672e6f96a92e5023d3c, 0x03f11abd551e44d9b6a0f4440f47006e],
+    [0xf543e235c9dd5b371c5ea5c26aa7b9ce, 0x1f0fa04bcd63b7a2bfdc870efbb36966],
+    [0x486ba70bc77be21e74c4e15381e1cb4c, 0x0154e2bbd796692b8942dc37abd9534c],
+    [0x6d18cb1474f5db86501a4db1fba04b0a, 0x0a0525b3f96a7507890e7f65e7b59388],
+    [0xf8d5b463a1379fcd7d6af14dbdeb54dc, 0x04a473932e524fc0e604d5ee9cf3ba9e],
+    [0x48c70115a1a07b88736d04aeb85e0f8d, 0x21150d11c7232f615628e7392f6a0c93],
+    [0x27be1c4e9740db36cf7c7c5e8f313087, 0x0c71b12a8e8a7d8fd2b4ea9b587f1c72],
+    [0x29d951abfbd791fee0aa3a32321ac661, 0x2d94cdbe04f7f77c5af2d36c37bbb817],
+    [0x0a57041aac64b4a82db0b63600f5581a, 0x14e45c5e2b09d6d631cc60c59fb5c24b],
+    [0xf88ee0601636ed3d6e4cc14bb3b1dff5, 0x0abc5c5c8489780283c139c36dddfa39],
+    [0x8403a42a800c26e6284a93bb233d5c39, 0x0b937ba15135dbef736446fb4923206c],
+    [0x155f0f8d11bfe9a7154452e2bf084057, 0x13869afcef8309bfd43752610031bd0d],
+    [0x04a0cda240f1762335f02dcd58c38395, 0x06d42cef5ae2ec34e497c596139ef1d5],
+    [0x8277b386f65ccf2c8b5614e343031a31, 0x1554308cc7124ccbdc04ffb1bb97fe1e],
+    [0x8fcf9a0a87a290c4873897e49791391c, 0x1e4fbc4c3e4f57e90ad50bc6e7a10b46],
+    [0xe40a31ca8396852f3977369af24d70b7, 0x08d2ca5bcf00e9769dfc868d1c86f288],
+    [0xba89070879978fa4b7787bfae7f83b56, 0x1f5bbebe4a188434ac69933fb8df5050],
+    [0x555acf76a219024ca7002501240a0e43, 0x11a2dcf45d740445dd1e82ef88829813],
+    [0xd26e3c7416f36d62ce6217cf967e1c67, 0x2a8a12e68b1fcba10e0a6d1a86c14a10],
+    [0xced2cf79d3044820812223e146647f35, 0x0452848d3c0c00fe60d721380650c849],
+    [0x113ca7dbaf11d9c08f507c46a9aac9be, 0x2a47a54f59ff22745597acf600754759],
+    [0xaec58cedb831281c51f3799f07e72b8d, 0x185bf57472562cca45f87ac1b1ae8122],
+    [0x6e85742175ff86ebdfc53ab588d6d8d6, 0x0ecef6489e709ad5c587636b3bdd9c7b],
+    [0x9962713c6a13683fd5531723cbb3889a, 0x1608b3e2624ffc2c0091e47e90e5ac3c],
+    [0x0a647f2e7d34415ba37989dafa94c60a, 0x0d3f60746bca57e5c968372ec0e98751],
+    [0x7237a8d9ff9dd8d8f39fb58162c3bd61, 0x098ecf0484e127aa679523cad148ea22],
+    [0x30fe517b627f0248f789fb7b30ac105d, 0x302f10e11c13f3767223bc501a5f9dd1],
+    [0x0177c69731baf22c0982cf76278899e2, 0x0924717fe7babfe9541b3386fa40455a],
+    [0x23b22b9e6e5a918a9f008f47cdbfa885, 0x17382965ba7be709d54d70ca3e13feca],
+    [0xa841d972128aaa95eb7b27e160c2d2b1, 0x20d30010d977f9d912be79737775178a],
+    [0x14ac536c37ed01608489ac1c721c0706, 0x0594bdb78b3b69993449609386a251fa],
+    [0xe402e0af2ec8068796c7c3753330aff6, 0x2ceec6c13c11bbd47664844320ccfdee],
+    [0x078c76cf0637a8983c0617f27b4b3472, 0x279fada83f830dfd97b7288e670459dc],
+    [0xc276fcf1d462a6822892738533fb450e, 0x029d593a3112c3bf57ec8ab687d8398f],
+    [0x8d50c3ae5b59a167e5847ba33d19c050, 0x07175181f0554da6ffea4a860c03124b],
+    [0xd6e4a77be5dc3e8221087e4a6ce23ead, 0x1280cb7bde9bac1c3d7f557f27a5acea],
+    [0x91ff551c0f449697b546c8dcfcb90bfc, 0x1a994958d44c343f59489056b8cd7254],
+    [0x3412ab8473e2b00fc75593eb4e3f5509, 0x1f004034e8810c1f1de507b7eed84298],
+    [0xc1e52542d338f24c47fd99a94207800a, 0x0405fcc7b759b8ca30e9273814efb350],
+    [0x0e7a8929b084602541230807dafb3c8b, 0x0b872545cb5ccc73d220bbbe01fe3994],
+    [0xd3729d477c73835ea553be5873ce2730, 0x2819facca2cd6cc16a6aea3191f833c2],
+    [0x4d8e485d66cba799723253b26b68c53f, 0x22e725f6cecf165754ce1a52ffbaea77],
+    [0xc573c7b0d4d3e79c48e3a4bfbd62cd1c, 0x01d122e4654861c4b3f76c3bc49529a8],
+    [0x37d34521b511c9360bdcd1b29f89a2f2, 0x1ed4f564d147f3faa38336a0985022d5],
+    [0xa4d16b0e77b3dc3fd87f869c83e5b9dc, 0x2fad9912c4caea5d440517f0e5688695],
+    [0xed0c09f9b30352471f2b0b80321deae1, 0x2b5a8561f0dc586d6e3bb83ed5ec3248],
+    [0x9be3808e6ca8344132f60f5595a33d57, 0x186f4d63b014de8c9ea7ab01a79abe5b],
+    [0x8bbda0115b66f7f7ca429824faee4d16, 0x1ae5e940fc5d1295d32457a840581c3b],
+    [0x632d64ea1839979d3d67d405af32778c, 0x14afe0880ad92f816292a452cd011384],
+    [0x439b98f44b15a8c6f4f5ae8b75a3849d, 0x1ac869e754be8b071a3ee9c64af49096],
+    [0x08f795bcc4431bfb0ea7028ef2fd32a9, 0x2dc29a1a317499b43a5a4ba1b36cac15],
+    [0xe6510637e2733ac0f6946d908de51312, 0x2ee8d03afe43c2b88599f0a35b90926a],
+    [0x3cd5a4deda9225b8525c94836dd51eb9, 0x266d80fb5c10b2655e14177709704047],
+    [0xc8c3e14dafb6beb3a02390544e52c22d, 0x2e4d66f6559483f76f1f55682d1edb56],
+    [0x789d93e479e4fb44bd73218cf3db7500, 0x10123aed5142580dc67408e337d4c773],
+    [0x0b2992d53883100f0dbfc67f132a5a91, 0x19526f25b7ebe2c461395e66f866ffb8],
+    [0x511a4ad8d86621f9bb313222dd37d656, 0x2e77d96a8736e6c38fb67b935a55832b],
+    [0x2c2b98ec21bbfa2df5d57324ffbb4b18, 0x09f2c6c969d48ec291576bbcd917fd62],
+    [0x4999d6c4480081fa738878c3e1b11e57, 0x0dd360058171b02326c9e4c4618c8e19],
+    [0x2c70949ed966c86ba29d9bcc37f29365, 0x198a52b8282cad7eba3a3800b59a794a],
+    [0x455684cdea4ec58d76a83a07b428dc47, 0x17eb8c8e8003d6b93783e67bd14d12a9],
+    [0xec10d0f38ecefc1e3e8786b95fdf9626, 0x1fb9c160b09797e9d4eecc79c50e2658],
+    [0x3e6dd13f8aab893174c0cafd78c8a510, 0x0202c3b6495a8af89ddc6e3fbf4d66d7],
+    [0xd50dfc7000465084a701f47b15763083, 0x114ba7f12c0e1a3b12c2b5291bd8141b],
+    [0xe1c230c02a6ac90037895728f82b3347, 0x019ad3e86ad82a3765aa64ea022d1117],
+    [0x89bb1b9eba0d8bfcf7713dabf7f64e01, 0x254ff8ec9eca4d3fb26c72cd63a7d4e9],
+    [0x772742c601bf8f230252925ad2c9a702, 0x0be8c9b5aee37efa93ee79b5ffc00e8b],
+    [0xa56330931336abe715d2c1eb7d931ba2, 0x0c71775341a98ac909ba1f26ace40de8],
+    [0xa27ab69bbc7188e4302131e20fee9dee, 0x0b833ec8305c2cb5002b59beb9e58216],
+    [0x00a60cca5eaa1b16fec85c2e2ff974e1, 0x174aee31c36b81a6212b9d446065af88],
+    [0xb5b6dff1e56fa35ff18506c64b2a8b95, 0x17831dd406c7785a5364d3939277da9e],
+    [0xac4936ff54b07caa0a7ca53cc09dc445, 0x121e1c1bdb7e4a3574bfd1c3a4cb3994],
+    [0x8bee33d3ed4904525a4145bce35fff1b, 0x0fb2ca5d37066fd7292478917498f878],
+    [0xc3016049ffac09957e69d0b4501e7ff4, 0x1c131cf8a1d5440337fa13e595cf6d42],
+    [0x84aacc648634b0ff48f83de4f76a5856, 0x070ee4b2f7a90add1355f9cdbc3a557f],
+    [0xf3c9135d48f95d67c9314b2c48ee0dda, 0x0ac05c9b13d1f28281053b12af198776],
+    [0x6954c0bca794929225cfdc4278961574, 0x0c95a176bf9b37e0eef92426ccfadd29],
+    [0x10c0b29bfa1a3cc9f41470a91884445f, 0x16635a13512870b72fa81e4648421a22],
+    [0xf801ea5c4cdb77a34cf176ad85bfa6ed, 0x0cdc33eb19f21017be8a4cf0b37b6b12],
+    [0x6cf314e3403d0f93fdb3afd64a150472, 0x0e876459770109bd315fd932ea7c69c9],
+    [0x913a4791d4b04e30437670966d840037, 0x1f26a00bfd677bfe57b879c71cbc54ca],
+    [0x1ed5f7dbb5a31dd603eadfa165bccf8d, 0x229a17692c8cf3377225426ce0cba5d6],
+    [0x19c449bf86c33eb4f793e0747a6445fa, 0x21ae714094395df65267acb6549d5802],
+    [0x569adffece0cb39fe08bc9d0ec2ea2d6, 0x2afbdd235c79565be2f6e2cdb1d527bd],
+    [0xa1329821a17baf65a6a0746b38a4ac56, 0x0a95c11af3ef7a0c585ebd2068bdfab2],
+    [0xf796d225e3eaa587bcbf87c4ec0a19a9, 0x2ed822401a485a621ee0b04f6f7b50ad],
+    [0x1a7e913fcd58f8a1bcd4afb520ae01b5, 0x10f52269c0c47e3d8a4c878ba77b098d],
+    [0xaf375d36401c121e8ffe8535dab67482, 0x0bab4900dc219d1a737b11c61bde5da6],
+    [0x9a957946b2c761c8505ececcb2ff2237, 0x0f0f8530f0310cb2f6c999ee2dc69432],
+    [0xcc251752a9364b7759888eb1800a254a, 0x2015cb8f32acceab144d968136e87d67],
+    [0xd119f66ff97465dcc929cb02aeb602d6, 0x0660194d882e378a167b870ce7095e5a],
+    [0x89e89ef18bfeb3a5af5a3deadabde0e8, 0x0312bad9275ac67f2bb34610bfdf3c69],
+    [0xf839dad380e8e07742dc0af514804938, 0x05cbc68e5b05a1fc3e784569ef325011],
+    [0x5d36966ce9a384a0f659ab02a7bbe01d, 0x23e6688ad84690b2fcc1d166fa672110],
+    [0x5b6c116e534c85043fe458cf4d1f9120, 0x26201713078d367d68a33ce6ebb82ab5],
+    [0x38cccd281148038498c47c733baef3f0, 0x2ad17744e079b70e3175ef0cc86ebd72],
+    [0x81b3bc1d20f3fb79fef34d38faa39773, 0x00227b2456b2df3801ed64047464250e],
+    [0x9f52df324cb481eb9a13067364a0fdd0, 0x1936c25ab78e54a947a7452dd65c0473],
+    [0x21ef8cd61ad2c20cca4fe8d5d65772da, 0x2aa9d353b8afaebffabba8ed09220a81],
+    [0x7a57c36d493708f8f1503346e224ec73, 0x2c37de8445ceb0e6e16551a08251fd47],
+    [0x94284916ab9582732584857312fc6228, 0x0b6016b413068fd8119603ac0ae0afdf],
+    [0x7f7e261792fb69195382b8ab565b2814, 0x1c2da4c3fcb0215aef82e75f67b62db5],
+    [0x516ac0ddaa177839581770a27616041e, 0x0d9019e067b8a2dd481299ac904a14cb],
+    [0x62b99766997a2754498f1c10c708f516, 0x0add8f2e12999625d665e7ec804c2598],
+    [0x8f265137c4e6eaff003dc34cc06ae9a2, 0x1b04c94fba0160faf1a69ae28901f6e5],
+    [0x23817e15d06bf1d887ca21feee481b82, 0x19b58c38a368d1bfb49de8aab1453681],
+    [0xd4663f69e60f295ed750430f6f7fd7d1, 0x268512a6191579be4c51cc8bb8b9dcac],
+    [0xd7ed9138fedfc85181a048972d34e1b4, 0x13d268b42c3385b68c54f2bcb9f72457],
+    [0xb9c4b7c7486df3236c8f92e16200941f, 0x2cb0a99448e388e04f5de4c9cba52f97],
+    [0x0c9eee06ca36c551d8fbeea255cd18ce, 0x1f0101328d89d8c934d72ca36c83de0f],
+    [0x387103ed4b3e1d995fdced66760720bc, 0x14262fa1b33f7ae3d7666719900f64a1],
+    [0x0f747bbb66f30d764d0e60a796d30a5e, 0x01364121b3d6d64c4ccdb10584b4bc6b],
+    [0x8462e15c24637a6a5fc443cc6445032b, 0x2ffda5ed3ea18847ecd8fbbb951e8362],
+    [0xe95c6e41b2df2945d2efeded3199efc3, 0x1b6a9f9c7c09354bb8f206f9fe4a9ca8],
+    [0x8dc9d2ea6462f5e86d081770bebfd2aa, 0x2954182782b8a9c2aaae675e5d8c81a3],
+    [0xbeb8a932a29b6162675a6fee7dae1574, 0x0cad57ec1b88059bda6d41d957f17178],
+    [0x5b130e908aa7a5d6d88e08b3b8716492, 0x29d6f606caf5aad785e9677fbaa1f871],
+    [0x43fa16f4a9ec5c240f8981699f2f8c7b, 0x303a687f559ed6e770c9a84115ae7266],
+    [0xf2ade1fe67df255cd62a27cdad188086, 0x2318a5a800de467ac7406848d22ba7e2],
+    [0x3a6760e73767cba67dbfeac461d0a8a8, 0x228ea9efaf3b18701d3aebf00c7432d1],
+    [0x33553d0cc8357cfd2c4dcf543b229be4, 0x2ed4c42bf04aeb7873c2fa14d5646585],
+    [0xf4d4f6cce57d8e4f030eb7c0ed347b86, 0x1dafc53a1dee87dc061e12435c932ae1],
+    [0x87ad7d0235beca496dc61aeae235db3a, 0x181f880921711f6128e2dfab54440c20],
+    [0x2a0a500852d84ae29d8410e4d2dc7e1c, 0x143fbacc8b948ff561d61cff210e8af8],
+    [0x1616a42094c69be83939570a4751e093, 0x06c4e0679e8b4d1b8249e09bae2238b9],
+    [0xe2997ced3fed3091fa0b259c743347cd, 0x14f7cda89bbbcd7cdd778ff878c8df66],
+    [0xe4970c702a3c96e2a680a3bd9cd1159e, 0x21b40bdb3db178afb97e6c8f509a3a54],
+    [0xd74ad9842d0ad08b826916d3a3b43bac, 0x02233afa3456f472cdfe3f610d0612f0],
+    [0xf80900ff6b4956680bde41adfc90110d, 0x195838cd762c2a0ac237c6bb111d274b],
+    [0x048b43b53e3c8d1e558b2ce81627f0d2, 0x0982b8802216422f46f29c0985967102],
+    [0x66a8bc51d9cfb6cd08163e569e5f546d, 0x011cbc741c1bf48f7080f491c26d1167],
+    [0xa22ab6541fdd5d9491d613d3fc36d64a, 0x04c26770bfd30be7f086c9c707f29e38],
+    [0xaa2f2ebf85cb97bb19e8d7340ccfa556, 0x21da2bd83ee80170d4cb123e322f86c4],
+    [0x06c1b2df0c68d503f8b0a95c17239ba3, 0x1380aa59b03b58c68d377f62ee0b3bdc],
+    [0x07578fc7d428bb010fc06f0ea36d0762, 0x21309cec6120b32041475ba9bacb7aa5],
+    [0xf3f9cd6376454346fae4717f7980e554, 0x08792ccf88cf6e450d137ed7b5496295],
+    [0x5a4f531e319fd0d57f1881a55e3a037e, 0x0aef0f9086c9e942432200e8b5955569],
+    [0x2b8b8be4d6318a82cad3e0bac505766f, 0x0e0b748d5856936b3fd8f2eb1eb4c2cd],
+    [0x5d3591e8032c517e4595f3064155ae8e, 0x172fad32593c374ceef1a42abbd70856],
+    [0x89a48c511bd760b3d9db03b9b105c4b8, 0x26f5f9ce4d72145d3e72c42c974ffdfc],
+    [0x8728e5149a9582a72c1c309efcf7a407, 0x10e053336934904ff6d7b672a3bd2ca5],
+    [0xf4117471cab015d4146b05f1deb04116, 0x1f01a98934ddde51f300d542d1fad8ff],
+    [0xdb42dc6237fffe77f5ba14c6dfa1b393, 0x087cd7a95fdf5fb9d7f0431a0466625c],
+    [0x0c1018ca0b45e7b43796b045f65a7a55, 0x1f9ae8ef71dbcd0d48a124bd265d37cc],
+    [0xbd1c5435d2686c31618d4128c538f0c8, 0x1571a2b0a25158c0c5d297a397715b00],
+    [0x26de08bbf5e71af17069df29497a5838, 0x2322fdf2761e45f7cf265e4d62725196],
+    [0x9355ca4b9eac9b2fdcc37df2ed0264f9, 0x2ca8980f9fdcdb1b35022e02f6be62f1],
+    [0x81424eb61545782a6c17040dc7d38048, 0x1a8881c334f39420d4672c5050f45d92],
+    [0xf2f67cb5fcd92b1c9cdc97a15f7761bf, 0x1f319a0dc0f9b7429e3ee9bed57a96a0],
+    [0x11137c608eed29b5c9510695e1a467c6, 0x2419336f26dc5d498f279af95b4d7d12],
+    [0x209bd257ca117b5141b29e06e4bd5798, 0x1f514d22f672cbb16363befa56678778],
+    [0xd8088f2f68f795d3b35519bb1fbf1a1d, 0x28034a6c901cec4c56675311def10fbc],
+    [0xfef9814c87b071d5a11efc2cb1103cf2, 0x26c2b92118cd2704063241fb919806a0],
+    [0xeefe731ad3262eda857c56a06d9cf8df, 0x03d539ea2ddcb358763861791cc434d1],
+    [0x21b43210e71dd7970fb4283337d241c7, 0x2206f49436409e8be8f542926d9e2991],
+    [0x0ceeaf204eaac7a09b96ad22de683467, 0x0df25598607771039fef577a6f6dbb61],
+    [0x6413a9e84b524431436d0b136695664e, 0x283a898de5a21465cca3213af584b7f5],
+    [0x09b54f2418df66545c30cb93814e446a, 0x26ab264e89611039dc9cfd4b216df447],
+    [0xfdf1aca64351791e0fe7eed428493c8e, 0x2210d7f2ef43e229c5631d4e701ef89f],
+    [0x24743b5a385d04a2474d52ffe8abfd09, 0x07da0affb47d64b7edc682dabe5c6db2],
+    [0xd1643cdc299f12ac096fd44c501ff9af, 0x02ad8280ae5cf82936069f72e49010f8],
+    [0x3257fe8aeef515f86cc1c0056f46f6aa, 0x267b89f84f4845e3924565fcf1a54e8a],
+    [0xbf802d32d52da054a5edeee276148caa, 0x2d46f5c35e5a81cd902f418b4b93f25a],
+    [0x0226af1fe160a96b177854d736f2bd2a, 0x20ae8e71570f8ae5e655eb42a4f27465],
+    [0x8f3650590ddd164ce5af593f43c798f4, 0x020b9b29a5644790a5a31aa0a724fb57],
+    [0x97f4714396635e8791395937010ef0a2, 0x1390c1d95991972b3b8efa1a367383ab],
+    [0x2bd80bc0adb90216aed9574da9e97c58, 0x04973b4363f1f55da12d4eab985012d3],
+    [0x07a20e941d7c686bc53bbe324d184cd3, 0x25f7a653fc5aadc4af0fe534953bc883],
+    [0x03d559776a25e2f69de28362be180bc9, 0x124d7810821f47782fe6bf64a7c5eacf],
+    [0x726eb0f612f703ac1e378603d33309a8, 0x2f6f04909dc360d1add503953c11648f],
+    [0x1899ef1ab29548828a375e4bc00a1b52, 0x13c2cf1b21b786238faa93bfbff5c092],
+    [0x00ed68e59bce5cddf1137ec7d61a4ea4, 0x084c200f10eff59bf43cf3cc3a506de5],
+    [0x14ebdc4721546a1f5f1e9741644f2913, 0x053b5ad5902dfb5ca36d76b2f980906b],
+    [0xa78d528d3bd3e3d3b27af6033afe1262, 0x23bd4498b4c0c9f248822f15b5c88bc7],
+    [0x36683f28df1c16e28c08bc4fd269a99e, 0x2b5ff61b0e1fe18cd84e352c417ab5b8],
+    [0x67aad076c098b8fbba8521f18a2485b4, 0x28f774b7936bc5095e77eb655005ab87],
+    [0x326cc4d3b18f6339125621262128c845, 0x0c7f6d2ead683772565984ac819dc2c8],
+    [0x953757037b272b369b14bb5d22ab6433, 0x1e03532e35111565173f76b67ee9c713],
+    [0xb76d07b6429675bfad7e778cc0bee076, 0x1cdae1682f2c10e440de32eb51726d9d],
+    [0x2498f022b856c551fb4ba6fe033e4e1a, 0x24de40666f59de97deeff6d34c873b7c],
+    [0x040ceaa6910683ee44d37b08679f4825, 0x0fdd0166d15c0f4266ed7296dba4b639],
+    [0x862afbdffbd4470dab55c9fa24f50a85, 0x0318ee2254c3888ce4534d9090448b7a],
+    [0x3eef19d7e4d0ba007f3854b4904c5f37, 0x07a8d19a1979c8fa3401bfe964ed5d3f],
+    [0xc56a48d98a7a07f30eb553424a00b27a, 0x2846f15a121456f0f74b5072112a90ec],
+    [0x58facf448ca101405407aafc1fe50496, 0x1295ad2de68e05b113031de854959d98],
+    [0x69443d0dda35be2ea50fc485ff8f1c88, 0x2745210ecc2533017a43305fcd13078d],
+    [0x228d889cb8254c4900cb56ea784919b2, 0x0dc27f0b35fec0e0ff8cbe131c7ee42a],
+    [0xe2e70602994e975d34c3fc31cac19839, 0x09780925cdb1429cc17c1e70b0b43755],
+    [0x66d6993fa89e4a2fbccbb9ce28ed58ad, 0x2ef89ecae1006a20f739aa4da6987bb8],
+    [0xc4e5ab3d131ee958275326ff94d29a54, 0x0f6feab3215156ac3f878ce67c5953db],
+    [0xfdb658108f059bc38c14098571a87d22, 0x2049c1bcb035cb59a2350bcecc2a62f4],
+    [0x48e0e6e4e0d4a8c3aa7e635aed20da45, 0x09c5298f6696836b2a6544c9ca1c0f64],
+    [0x7dc4b7792fe258255af742b348e4ea64, 0x1d48ec8d786be28f71bd71994da04f78],
+    [0x7350c235096d9d05633992d477ef2567, 0x0766ba3f360f9cb8d27593cee1d890f5],
+    [0x6f51c652822bc0c23797d73a93d47493, 0x18893956ce600734714ccfebc44f6703],
+    [0x716aca05e14ce4729ec91496dc74a1de, 0x003ac5e6a3c0373c58379bf38c87165d],
+    [0x4451b4ea0a1333bfaa31be8f50da12aa, 0x171f4b3d04a6eaf7db0b86db0ba5be7d],
+    [0x0db34c847f59f5f63ac6c03b55383aa0, 0x181955a3fed4ff19d90d947c2f157f05],
+    [0x9228f5e5aadea7035169b9801e58a800, 0x15e503ae66cd37b3a58ac65549aa1de5],
+    [0x54b4b30955e358490ea169738f9e6169, 0x0feeb8aec6c963774b27f86a55b4594d],
+    [0x72b95519325d2989fc933035c68ad37d, 0x0dd9531f7a34f1a001d4ebc74cb4ea47],
+    [0xbae38305e11ab3481aebdb9ddb4b2cb8, 0x219fb59ac221747972bce66f78ca33c9],
+    [0xd73213dd925e940bf55ced88231ce09f, 0x1fd60dc0c476def3b624c219b3055373],
+    [0x595452801f08c876c2525dc504c520b9, 0x1f8d07ef3e16cbeba789241956211cfa],
+    [0x5d09447155bab001ac6ee205698f16ec, 0x0515741548eba397d14e6269b3256661],
+    [0xe9034cb6e9b653fe7428d768f8e9b2d5, 0x2530b37d2dd1b6166039f29fb1d78e90],
+    [0x8980d648390e89aff50e55a1bbcad4b5, 0x1602e869d50b280951eccb3ce7171562],
+    [0x796b0cbf7890463ede54882fc774cc7e, 0x233887217af672bbacd873f1285a3612],
+    [0xf1642ec66804753f02ff63f0ff21d648, 0x1849b3aa9e7053c8e95252858980abe8],
+    [0x8ffd62acbac998f6c1a80dde5aedc1a3, 0x1a0049780885e0827447af9ba9eb9e65],
+    [0x3e5934b6ab51a2ea2549fbc2e7562913, 0x1ca9cbfa808ca9c85559425af31c0aec],
+    [0x81312d8d5087e2e58ae89b34b8cc93a1, 0x28a2a0f0622c2a8e4f16f2e0365b72b4],
+    [0x7331a52fb3566953e6ddf9eca280153e, 0x21d1beed10bff96ca9f615bded99674a],
+    [0x01b85abbdb5b47c41ce6ecf2fc7edbe3, 0x187114f2bbba9a993297bd740c2735ef],
+    [0xb434ba13f72cedc39d1408bfa73fc06c, 0x11d0a386775c6e670a360a6fd957c403],
+    [0x944d1296131b6d2dce954dd11d724f65, 0x0ff4ef2f46abef24ca15d8b389e02cd3],
+    [0x3f74c5b63f98a630a00108464bb28925, 0x20338e47b520d281448f3f8bf008aa74],
+    [0x397d545f07584eae65e1b3e50fb6a616, 0x256fa0fef5853dc10aefa865c5653169],
+    [0x5017329573661b69880c38ca45ae3189, 0x144ba3619d5fc9d84f0632df296e8dea],
+    [0x5aaf4a4950a66134bc8b6029472a3564, 0x298498c8df31dd315380b3651fe9c7ed],
+    [0x2fb62f78ecc2d844557355ec8a43b0c1, 0x18caf9e264a32dbca23da51a3514fba9],
+    [0xd96ccc218b3b90d04bc5f1c8665ff641, 0x194c812828f1cdbf0c4f0b0486afb4c5],
+    [0xb3105f502a61eabdd8b1abc803c04ae2, 0x23f93e1c44119ba856a10380c2333191],
+    [0x046717d839af547edc3de931969ac006, 0x25d2cc988e47ce70fa99cf18105000d3],
+    [0xcc6e94226d00c7ca44dafd755614a80e, 0x1d1083600023cccdc7daeebeb19783aa],
+    [0x4f09dfdd5b979911047bee4b698004cf, 0x1943a9ef811abfe657fff92504f2a891],
+    [0xfd95a652993c8f17ce18869c261bc6c7, 0x01f96db1392a3ae5cbfee4c1863f0bdd],
+    [0xd77b1e7126c9e6258a3c9821d663d778, 0x12d4814ebb315331d4fba9dfc2ef909c],
+    [0x9843f3c7518121aa49515a65ee552e30, 0x27a2ad69b9eb5697b85b431eba85dbb1],
+    [0x24abfed3567121f656e8191a78c5ddf0, 0x066f7f2981ecc4c4415da62eabd32304],
+    [0xf2b4823921deb44f063c92430fc40a12, 0x16e298f60b265f06f8ec9227e54e8bbc],
+    [0x961f60f8d9821d1716c5a6a2fe0a9734, 0x1c10ab39cb04ce02ba72d996049ad688],
+    [0x2e9c5b2128eb373b820ca8beec970a73, 0x2cd7c8a78dd99aedc18c8d47452b4d5b],
+    [0x88569bf9a207addbd4e30a3a8b97311f, 0x23391ac9f29c1a99ed6fbd9bbfbe72b4],
+    [0x797b00726702a98a319c7ccba9fed2f2, 0x3041c5599a51eda33a6398eb31232519],
+    [0x848512b534d9bf4f30ef093c797403ae, 0x04e64908d79f35ed77fa8196f07a948c],
+    [0x1dc19062e4ec3abc9e82b7fa4e25be6f, 0x19251e907d7a903bfa7bed87eb04bd92],
+    [0xf4f82374fd5b9059614ecc134f2b29d5, 0x1b39681c2c125cae8d38cc5ef6fac252],
+    [0x128fee65bdca131659d324ae636696b0, 0x217a54dd68dcd53e336cea49d89925fd],
+    [0xdf17d7e1afe61e11eda8caf78762af3c, 0x2c054bf6f91776020de3fb9705ba29e0],
+    [0x475f6e223989eda35d1ab746275fa4e9, 0x1f88611d6c0287ce53543d87ed8182ba],
+    [0x79d71e480ebffbb13c4cb3af566371cf, 0x204d5a9829d570e203bde491b862a969],
+    [0x0baba69a6181071d1d1a4e0dd320b4b5, 0x0eac95c688afaa773ace485625278817],
+    [0x4ed0ff9e4305c65c503e0ffebae5dd2f, 0x2245a0967d777ac5160082563d5815b4],
+    [0x6d1ced4dea6818dc3cedfc67ffe5ffbe, 0x1b732905466ad921ab2430cbdf772a81],
+    [0xd49931bae369b82c30011041879f2894, 0x08caf1a4cd826d19e1c27f970cad05b2],
+    [0xb7314e8c83d9727f3a21d0f162c561cd, 0x27d8134423cd987b249f4bedc7773fb7],
+    [0xd267c22a29eb050ef246edd84f48f9fa, 0x09a2ea37b3d739dcd70862ce62a8837d],
+    [0xea1c7352c59e5aeaa8ed24b8530bdfb6, 0x1adcfb436b238f7461757a9665d2dccf],
+    [0xab31c142716e16779583c17d3cbcd700, 0x2a2354db535feba0800ad6659761f29a],
+    [0xf88f5721a1132ba71ba0dc4168719a6d, 0x01031b876fc704c3296bb38110b87476],
+    [0xe543c606fda007aed20257c8e4ee3597, 0x0b85e9fb664cc3b4ee539936f885885a],
+    [0x09778912d5c76ebfd9df7ecff6207186, 0x18067bd085c60da630ab24cdf419afda],
+    [0xb6d55d63557af13d9bea711aafc5e1db, 0x03771dbe5c3340fd289917d6fac9a154],
+    [0xcaa1af1fbad6d6cbd4623592101f1f77, 0x03a120381b15967cde1a33939fca7830],
+    [0xd4b18be857931218fbbd1d394d67f781, 0x040324fbad7390bd98c3553232cea681],
+    [0xdb710f198de168890dfd73acf786cd98, 0x294a9378accf723c26266df89bff1a2b],
+    [0x5f77cdd95880c457dd9cd46e99bf0f25, 0x203d8640466332ce0c493396731a23b8],
+    [0x92b6aa12056794c9dc856fc27ec91ef8, 0x0d339481cf52ab1f652ec8967a89776e],
+    [0x45c8ce5c484aada197994b618b37cba0, 0x158be4217747076a28aa6f617c7ecc60],
+    [0xa81fe60cda556d73121dbf17c8a05ebd, 0x2daa17b35ac2ea21eda36bb06db40a7f],
+    [0x13c9c03bc3c59a8cd7f8d10b0c779210, 0x1a2f7903bd643c61cc5eb68455202f66],
+    [0x9a82474b2f9f771227f022fa762ba56a, 0x1ca7835f4f1c5f35aa1e0054afb11476],
+    [0xd64c2213bf44b01b0005fee067804beb, 0x043ab3c95b64cefa7f97e1e8abf9db25],
+    [0xc2318283226eb7dbd9c1154e5be774a2, 0x0eed1694086cdd6dd84002c69365120c],
+    [0xbe57a1b6c1fc593a50e7e9cd384f7ef3, 0x031b726aa5dda456ffac29cd38b64926],
+    [0x385886953f75832be375c37ea9ad6750, 0x2652cfbf90110f7727750d68eeab7a49],
+    [0x7be734f05a4f4edef0572279a28a3bd8, 0x24a58d087af381a6f27b0655de795b6a],
+    [0xb3bb3d033294413a398da24464272a42, 0x1b04d3e92a338f6beeb8091382d93141],
+    [0x3c296cc2c044edbbe091172360b24c1f, 0x290b9ee02a3a29eda94ce08a6752191c],
+    [0x4cda2a080845c79cc7d0f39146b0b358, 0x2a012d2f441545c914d25d0cd28fa810],
+    [0x2e324cf0f7128f707c433eac1eded47f, 0x01d4e5bc180cedcb8ef0a59d25498a54],
+    [0xe963238c342264ae9fc39a69d75462a8, 0x054659f012ec8b757b1d2a175a9fa643],
+    [0xab024ac59c7b57e535224ffb807dc720, 0x19fc0305e1e5b2afcb86a742152ba895],
+    [0x15b5bb1f3fc3985e9d74dd71ea86ad66, 0x21d1fd0c2affce674ab016661a216f06],
+    [0x1adddb48176bcbb03efa853ab09c3b17, 0x26956d93f01776d23bc7f1e346499258],
+    [0x963eb0c863d8aab74112f512ed8ad9e2, 0x2403120e5a687895bcbe57b5b11fba0d],
+    [0x47650312f0629a40bbccdb590a298cf3, 0x26684fa9b9548b08ac79e7a14bc28f8d],
+    [0x9fcb948de9725fc597ac626968715c18, 0x216969c221bdc3daa1c2adb9be456ca1],
+    [0xa703876062ed0366a475ae9ac0776c0d, 0x06d4e54362d5dc7cd489ef466713c1b8],
+    [0x1ab9788b5904a78326ef099a1eb953fa, 0x10c21ebf642d38aaf3de15ac4a0de3b7],
+    [0xf079d29b2034a9e1dd8be270e7e3a803, 0x0a4b8e91b02da94aa621acff16bbdd44],
+    [0x567fd449b61e4aaa2965f3177badf7bf, 0x2b1a5b52b5f1c739f8e167a8561b7c5a],
+    [0xf3b55da7fad6896298f14bb7af4e6356, 0x1e858a1edb097f168f10b9130e51d5a4],
+    [0xe08100afe5a09cb01877265967aafcd3, 0x28362c3ff6abe4fb017450c7dd344bd4],
+    [0x440854761a0cd463766041d7e38dcd44, 0x204e017d42e6b32cff2091368e344201],
+    [0x1e310316e23540f35f1bc9ee437e92aa, 0x182307aa6e06bf1b20cbe534913a0b56],
+    [0xb8d179868c1fd6a6dd9497744ef9ba9d, 0x1ee400aed2b9f1ed67a8116438ccb7bd],
+    [0x64cbf0a68c7d928c2220db643db9d410, 0x288590acd331f4499efc2e7e8aa2656e],
+    [0xce2cbe122df4f1c51296e6b65fff1795, 0x008634f649b3d25022a2125f5f300d2c],
+    [0xffec77ea449a20882aeafa4aee274e6a, 0x03c462cfcb45169d9cead1d0715c8cec],
+    [0xe7b8e28ca51ae323e56d9094782a30cd, 0x18fd54d4986a2e7804cbcb77e6812c06],
+    [0xd3bd1e97cbded742e2173d3fe9e429fe, 0x01b9efe04a809a43c624c726e9294bdc],
+    [0x38206223a57c07b2a4490771c921e2ea, 0x109ca591b9350c71a7f2886c6e55628b],
+    [0x6baf8199d78c7e35f451912a80fb1bfa, 0x0f98134e9077529768e4ec3dce4b62ca],
+    [0x689a52beae8659ad4d0b437bec229526, 0x2205834ab1c8a66de1bc766adee18eaf],
+    [0x1bb4a118ad0c67e77a7637544bc6c21d, 0x292a6caba071b9fd8fb5f0e46dde4d71],
+    [0xdf2f14957bcf5c7b5b2cb9317a871740, 0x0f218ac63a89d96ea37d62ea0387c145],
+    [0x02b2fe124a8e4ea83181891a710b0a50, 0x21393e5f2e1121136a25f071465cb85b],
+    [0x2aa5451fc7f4cf6cc5898278a6747bb9, 0x06e22d6946484dad3f4ad0da41be11da],
+    [0x7b715fc464f1d7ac9d47f0979a4c59c7, 0x1ec8117c5590074a29a019140efd1c62],
+    [0x32bddb72483055a0563444a0e4f6dd70, 0x0e6f5d2ef82945dfb392e447138bc94c],
+    [0x4a47a2f9fb1121cf8c6dacd91a5b32ca, 0x169abfcb3ee96d7e02f9711eadc0a9e2],
+    [0x60dd42d7fee0cccd20a6ae4214d21990, 0x2d1dcd8258d2c559535ec62861788dea],
+    [0xbe7d09a628ff97eac4fceed7bfed5c45, 0x1f8a46cec465bba964496d25caf9a351],
+    [0x98c9b98667aa13f80f768a2204620b28, 0x0ddae5753015e7c1f3b07dae4be77473],
+    [0x2cc64c5cbf1a4bf1077a042ebf11af81, 0x1d961204697279203f4721dd0858244f],
+    [0xb98d758bc74ed3719bf65b172635abbd, 0x2611bbf19addb97f4b761abca43999a4],
+    [0x1566d028c1816bfbfcf9003593b98190, 0x2c93c5c5b057f52c0b3d54338659f05d],
+    [0x99a090450d11ee1a87a1e0e65cd287f8, 0x2a926fd3e066a9fb75f76fd02aa99969],
+    [0x39807fa69fbe79216563f6c5709f72e4, 0x211dcee0fee3f8571fd96f2b5c55de11],
+    [0x34b77906de2d4ce8b301ac35d33bd761, 0x2b7ddc28b2bc2d2718e9d6b4c790d558],
+    [0xf44bf2e55174b6a09029b9e3fd83e0dc, 0x17ba5159321a180ddefe96bc9d0f9ab7],
+    [0x3a6dd6a1bb980c20f91fd59e518a030e, 0x25e12b63262139695e50a1e96b648035],
+    [0xf5ae53d67becc4fb0ca2e96e241016b0, 0x095eca3482e28057b54118da6c45a3f0],
+    [0xe8d9cfeb83d756b40acb4454e77e3ab4, 0x1112aeb9622bd2e00306dfa76088ba87],
+    [0xafc0f41974a6ba962f77da7ee03b2353, 0x1b69507de83452ce3977a83e01648114],
+    [0x1b2049dcecb5261c0244336342681283, 0x18c36a227f30ba8be835022cda0891d5],
+    [0x387216dd501acaa18a3565112c3cef30, 0x032dff6502adbf3eae1edb0aa2a142c2],
+    [0xb5c55d217941207850d984c264ea64b9, 0x067e9b2df10e463ff4e65fa21d18b8e7],
+    [0x58076254fc4d851bb67c15c855da68c9, 0x186a8c1ddfd9fb6731243a6c190d74c2],
+    [0xe6db7e0d3818d82ab3f5179652acf1a4, 0x25f12e0904af730ec68920122e8d7e83],
+    [0x0c50781b7f68371eb082974914cc2f8a, 0x292a053be9013f374156454727329ca3],
+    [0x0a3d3e83b956e619951cc99af22e3d7b, 0x12867bb541ea17c532cde157ebe2ff6f],
+    [0xa2e15b26100516e0eab737fb70049b56, 0x09dd2a75726a265e98ab42ca0d013e7b],
+    [0x6e5c95c45e5b2541334f94dfd93b220f, 0x137cfd0054dbae6b635b4e4f9e617522],
+    [0x1beac7bb2d36b25436f04bf7dcb60de7, 0x0d14636d7b7afd1186f0b9d5c8f8ef55],
+    [0x3c35781c2b901bd722babe0a5f6dc613, 0x1dbc81002d8a5cae1fd910532f08a432],
+    [0x82cdb3388cc632907f4c2081834d8da5, 0x241057b0d9977eaa50e04ba893702ba1],
+    [0xbad7b498370b5d50e275a88cb6b06339, 0x07ca75c9d51c65bfc2ca99c9c773bca2],
+    [0x881cef8ce82fe4a0c0f860502f6d3cc5, 0x15fab6f34ae8d22bdedd760610dd463d],
+    [0x5d83938e6eb0dbd19e6d537eaa581e00, 0x0cf846e67ab815b7bfb255008733f8b4],
+];
+
+/// MDS matrix for width 11, row by row.
+const MDS_11: [[u128; 2]; 121] = [
+    [0x8f219dbd924d7786b0f98fb799fefa2e, 0x026dba58ade8cda188f49e52fce7167b],
+    [0x63d3abbae18322a7e77a511881665f93, 0x011a131a3778c3774233bc54096419d4],
+    [0x70d1cb909544243abdacd4547c8ec3aa, 0x2a18ab4dc4777e13cb8358c1c4231535],
+    [0xaf5847a9a60889c4cf6aa318d6866b1e, 0x16ebd782cbcf6d63c38b87b27db2a4a5],
+    [0x1014de040c26a591595b227ba7bc6fb2, 0x02b7ed47fac275d34f842f09b01ef942],
+    [0xf3e3f24a35dcb46cab67dfc648efaf30, 0x0c5e49e319fb9535073f07f9e055acca],
+    [0xf90d404e37e003f1055d9a96e406d30d, 0x0161e4ae5b2d24cd638ba5c32bc2317b],
+    [0x0ceec592187d2c7a6b0b147b6292b81e, 0x223c7fc6d24fc53d1b428edb8f1c52d2],
+    [0x74cd4af926217f5732be2b6cb7fe72a6, 0x1ae060e39b338d09845e2e9ebeb70bfb],
+    [0xc8d5083328d4b2f04828dca3e69098c3, 0x046191ab41e93929ea51f190fd4e773a],
+    [0xe54507ed33bf7544c86388191eca3e40, 0x14ba1b5383e3733c63d00c32c2bbffd8],
+    [0x615275dc18ca654c799a70440946ccf0, 0x009813c803ff84a3694eedaf4b1c2172],
+    [0x2e6b0d1b80370e7cbe5745dafc433523, 0x1495af03dc9180c886f0e1bdaf9d18e3],
+    [0x1297122d1acc41332f1b49895a3c97fc, 0x0d95e8cf8a66b56c32e7d11e0107e41e],
+    [0x9dcdcce67d2d21f852816bd234e9872b, 0x0b05f16108c4616a973b01d82e50d4fc],
+    [0x0e03c747b14c432f170715e588d605aa, 0x230b62315b30bc49d73dff8a9de79c62],
+    [0x0b5aae734f06779e698864039c92e123, 0x15d217a31784741048f326e155af96e9],
+    [0xbdeaf341e85f1082958f9cb72f7b55d4, 0x0af9afbecae1761cc17d44b51ca66d1d],
+    [0xa50cc82a4e7f1ed3c5ba8cb944ba4689, 0x1da4f4e3c6790ae1b89c4118861d8ca2],
+    [0x189d031430c11a919a5aa895bcfb6151, 0x2860aa52dc2f967c0c1a0b942ac81e02],
+    [0x7f1c92b4d1d21c2a07b6ae18b2b7b9b7, 0x16ff33d67c85121bcee7d81cff161892],
+    [0x8be29c39d90337aa5fc10764e2321a1b, 0x1ba0e835f47626b0d82426900c49170a],
+    [0x29010d41090f63f1bb290c1d5da1ad60, 0x00202973f7fba5bd21ed58bfe7156d7b],
+    [0x0f8af80e5871933e58ff87c3a1f48b93, 0x119344c53707f00243983c6d024fd49a],
+    [0xa04609b0b79eecfacb1485dceb7bc873, 0x07c22f96f2f55653d04edc63150567a7],
+    [0x5b743c7076ca4115a9fbbb7c6b07ee5d, 0x2f394cfd3e442503c3a22512aa4b76b9],
+    [0x90c2b5857f8bfbd86d7c001e4cf2ca18, 0x206351750037bdc90d4f3e7918619fc6],
+    [0x6a07e773e54d906da166215201a58136, 0x1accea09c2bfd17f4061725c712fd197],
+    [0xe63e35898934b00f4333b38fe614f913, 0x05e52c0ff8c04a8e5e3c7eafa1d0902f],
+    [0xfaef99086d12b0a3a7f3f85bbca61d28, 0x2e708baaf894bc5835e5fea81227fa8c],
+    [0x72d17818be7ad427c45eb3ff1828727d, 0x0ac23f00ff015baab1c8be9a90943c3c],
+    [0x7432b8665aeedf373e7ffa6fe0c13d00, 0x02134798f4ca7fb9928012c937f37de8],
+    [0x0c940c9bf9883c1fb0fe929f78873c1b, 0x0219f5e28a5fa52689d2b7be43262428],
+    [0x5f48355576c671e56fde9cc7c8344ffd, 0x12305eadf64dac24a7913a62d95aefd4],
+    [0x3ca57c10ec80cfb926722b5bed408b79, 0x00a92bd6a919d1605615786f2efe8074],
+    [0x9b9523e98637a5b113d61ccc30ba0213, 0x0f716c54de41920c401a62df8f9186e1],
+    [0xc0fb3c05b90f526bac0871bca84677f5, 0x0a157e244f939125d57f9c8e66f7d88e],
+    [0xaa7cefee72d0e6ef80732ee9d7e6e2c0, 0x2218be861d524eaf03b9420b1368e9c5],
+    [0xe66b183ad89f13704930416e1d5cb7ec, 0x2f9bd19d0c8c3887a7fc0173f863f075],
+    [0xaa0f55c351545c781703c01ce87d6b29, 0x14eb401c1084a9ddcd5d0092e5d1a299],
+    [0xfceed4a858d1c61fc2cf2b71dbdb6936, 0x13fe23be24261ba5b92d363989f58e17],
+    [0x3cab2ba82c9495607921755ab018f690, 0x032fa2f166622f057f61a9405c925622],
+    [0xa7593b195cc950324f95edc9eda42aaf, 0x2aeddbfa245756ad1e1658629f747b8d],
+    [0x387a973690b9e6761be22f9941d7ef76, 0x123a6055681023b16570c70fcdf3ae8e],
+    [0x7097dffb0378d7e0395f8c0b86b505f9, 0x25c783ad66d6a1fc6c84770bd637f620],
+    [0x81634cc9bf4e534df77736e06d768c3c, 0x1c7d3a92caa435cdd974520bb6fb6cac],
+    [0xba2ae7e9aa50c5f1915275a81887190a, 0x2cd68fc31b67dc45fb6b16f42b5eb89d],
+    [0xb76a07730fad72ef9e490a748674985c, 0x1d4567c12db648d4a9c41324c0329325],
+    [0x53aa354562ecd873e0e625af70746f26, 0x069ee4eb20b4860d4af91afe1781338f],
+    [0x731484c36d1f6738b6e98601e9fb498d, 0x06cad0c2bd438c4dba2ed64bbf75066e],
+    [0x41a33f8105430a3ee70abca83ba6ec6e, 0x1e0e166d734aa614c6b53db4f6911798],
+    [0x7c59fd01e52afb80486081851f9a5284, 0x1c879c9428e19607d647f5ef1415e76f],
+    [0x1be90023d7a220e8f93b21c8add41ffa, 0x215c0cd4e3f1bdb482b93e571879d793],
+    [0x05281f6c6a4d37fbf68fc1b5f62802c6, 0x1f41fc67ca1322b37b7aa01b631fd191],
+    [0xf9f28e88d476cde1391eb30408d5d266, 0x1dce36062af7e4b0098f5590622ced7a],
+    [0x5958d5d292ba10ef3bc2c10f5a0b613f, 0x222deb606f13cf28c73a67216d88b16f],
+    [0x8442827248d67f989fd14b5c9cd4d4c7, 0x12fb88a0f6f749d68da9250d5adecf08],
+    [0xbd4e1d3b5270f6b2e9982590c3f8400e, 0x0066292e64e6a2c64673aed365cb50f7],
+    [0x6d7e747ba24645b515ac2a241ffdda81, 0x0a94a2d7c4093c638823b095733f3717],
+    [0xdef3369ab13359dcb7d212eefca86229, 0x0a226128da1d9bd5b329f55f52c75682],
+    [0xa80a9b476db21290474ed1977fcef22f, 0x1b5da83966361586e8810fed032435ca],
+    [0x2f93e2eea1d3dcee34c81ab1e9fcc0ee, 0x2fa75deed281db734faba5b70eaf5796],
+    [0x682b52cabfc8d3419dfd98109d19ecc0, 0x128cb3150b15cbc4faab14bf7d676ef3],
+    [0x308eea1f72f2a3428b1e721bfc32c6ad, 0x052700b19ef6d42ea392e044a094cbfc],
+    [0xe937f5a90990382c97867c45b7940071, 0x22d1e64d4ee15e60c459d851ef9f3495],
+    [0x04f9528ef600737e9913fe4b5698eb28, 0x252e74746677fd7584ceb0f8aa6936bd],
+    [0xc5494e7d679824d8fe1212177d5fa2ec, 0x1614d025472771fd870a9e9d422b3571],
+    [0x06f310b78048d695b4aad301ffc0d268, 0x2f6c4e097bb5851be12bbe2c712c2fa9],
+    [0x9b0af8af2f7888eee766c0c24871188b, 0x08a1ef65f52128eb3b22df2b6aca3050],
+    [0x24967517ae8b2e9592daf451840bb98e, 0x2f6f14502f20d958a9ff23b696cac3ae],
+    [0x73b52a5618263eebfa49d84518ea3bef, 0x17ceb42a19de6dd32e3b38cc2990e642],
+    [0xe689c65c428c2397cd273923cf68ddbd, 0x2adbde5c7b73e9a7e22b913f99e40d95],
+    [0x47acc4b60249e5b1c8cae376665cd580, 0x14bfe33115df293670393e1dc8da4d69],
+    [0x4b72a4d4838484de842acc782fd8bd32, 0x2bf523cd8aa3be20ce7d891f8727a7b2],
+    [0x1af8e42e01d3a7ee159b273ca0a6520c, 0x033b93a5cf15ffbafa2726f2527824a1],
+    [0xe8d54266e4d5643307551902423f7cc6, 0x249f416fae5ab52a435d65201006dd8a],
+    [0xb7635ae8ca0ede2dd3a42a75c1c6b81a, 0x19a9079a9897a237074252767912bed7],
+    [0x6ccdcfb9c27faad0806e8ef718d0e2b1, 0x01358bdd6b7a51b1f5b5d094dbd8ee08],
+    [0xefc668cd4255d6cfe208e9e7c8b9a1c4, 0x1c06c782fbc0980b9e493b29a5e4a04a],
+    [0x14e112e10c039190c103a75ced11b426, 0x24529638ebcdf26de8816287fec63706],
+    [0x613e9e281a51cdf313cbc8111922242c, 0x12641cc3cf50ca67bc93bc8c19ed89d4],
+    [0x6d4a1803b4d192a4329359f0f903266f, 0x29012deffb112eb14034159c59162f86],
+    [0xfc03dd44e0b28b18e1074e404a9e0f4c, 0x1a8b8ad1ecb6cb888273a90bef8b1a2c],
+    [0xe088a709e8b06a18a3dc2108c2096864, 0x17ea198f3ab261da000152f26b0eb0eb],
+    [0x57a43b79797773e83216ca969e873380, 0x045aaa99ce1e06a3c02150804a91087f],
+    [0xd2e092ab5e5719551b5da635b13e9471, 0x254e3acdbc75ac592679b032b65a9c91],
+    [0x06e9511187f0aadff6bb640e4316d26c, 0x12a996abc8577021ac7880c16f058d9e],
+    [0xe540a0fdb527b4b1a3a854fe346b8e7f, 0x0777edef4d91520fcd490a22dc569a97],
+    [0x5de5d24620f75cfcd9f11feeeb3f3748, 0x1c2007eba4d1629846bb69404acc8456],
+    [0x756324349afc8912a19b83de2cd06a0c, 0x211865b7f9c1c791a33d703ad3280f69],
+    [0x240b82aa51a42de2d4dc1e8225908187, 0x12e4b5408f48641951739ea27e521067],
+    [0x3fd614d305e475ddd19f26f6d4b3f9ce, 0x02185c7a4ad8cb21f19009396f134a2c],
+    [0x8dfc35bd3df7b23fc641ef01bcc71cce, 0x09c4b2f61adf245266168cc918e96f7f],
+    [0x4ff18e1dd1171412ff5e663d74a073c1, 0x281eca14bbc3920919e001150451c3e4],
+    [0x52933e1a6b1d423cbc54d0533a72599b, 0x0533e8a454b8cd6df8d48fa9b0a9cb29],
+    [0xe51726ec81b58eb01c1a1f8ec7a9eb70, 0x284ccf70859547fcb9c3c7b64cf0ad62],
+    [0x1068190fdc3df8761991cab50a01a449, 0x04fa5620a2c057dc552a59fdefe50a90],
+    [0x92b13337dad8fc6152fab34b55419908, 0x2d2640a74ede9af6c9888e5f68a84fac],
+    [0x47b862380ba00357849d8840059463ee, 0x121a3764269fd35cdf1e621c3dc6318f],
+    [0x6e3aa15ffdb4a0bdb883ffe7a1a87217, 0x22f49d7244077e41f409c0b353afd169],
+    [0xa3dc750806cf37f2c02ff53b4bb9e66d, 0x25542e07c1c13979bb14f8d4ee93dab7],
+    [0x3157d4193c8a8f1157459f6a34ec32fb, 0x0084b88b1ce4598b701cf96a3379a02a],
+    [0x2b91d809307492f7ee578ae34d6f5fb4, 0x2f6da385450911cd272e13601e7ce66c],
+    [0x5b03680325d26ea190c0f1ebc7362ea3, 0x1802b97e54bf3a8978632cbe10e42970],
+    [0xbf1968d77f87a2081d13054f51118f7c, 0x0054cc81c7d1d59581ebcd26b16ccbdc],
+    [0x719f1a888fff625e04652c77ee2b2de3, 0x19681b4242d11e28aa72530cf33a7d15],
+    [0x8abbbdc9af56720470a26a3dfa2ba855, 0x1abfe4b1cf5db29d4078e5289bb14349],
+    [0x361680b2e59d7ec19348819f5b3d5a14, 0x1d2279673f6cabf0a1c4b8eebf8e9c78],
+    [0xc36751e6ad8d6fb16810e3395143ad80, 0x0f246d2fbb4e1217f09631fbc8770827],
+    [0x57f8da444dda16d8149bb6c1061ed0aa, 0x2f80ca151b077b50767281ea5d4c1f74],
+    [0xefa43114f1b19fb2c2821a4374718b9d, 0x1951523a4270c54403dfe3218fd3d4cc],
+    [0xee2ba976fa22827ffa6dd03c8b25a64f, 0x12b5a09e1e95e0d01fbcd234fad473d8],
+    [0xf6b9313c8f193f929ac9e24d10d7a36f, 0x2c184e904d7bfd368d4495b9e3027f9a],
+    [0x397fc3a7e6c88af808a566de0223f0c0, 0x16fc48e55d64d83010ad573377ea2422],
+    [0x96d87415bb035b149d28ad3da98c6fcc, 0x2db1ac4c2b1c23a00027fdcf0f663af8],
+    [0x358917a19a28b4c47e46f3e73d42a085, 0x170c05a5f8a55d5c9fdc5e8238b367be],
+    [0xabbf04aa426cd07da536cb3f909f6e57, 0x1eb7f6e5520215c15e274355b19bbf71],
+    [0x4ed16fcf0fecc1655b76f2437d4a0c9a, 0x14111a74bd24730c833699eb41b38f1d],
+    [0x008db311fe31063cf4fed54629113585, 0x2d46b48e5090c0c56a390de373f4c9a2],
+    [0xc7a8de77f2cc6fed754b703ea92dde53, 0x15f30ebdfb8b8ee644241039a26b408d],
+    [0x63d4fe5b414afa05c1a284674409f165, 0x1ee950c45a0c2f4a4b64688a7935ed2b],
+];
+
+/// Round constants for width 12, 68 rounds of 12 elements each.
+const ROUND_CONSTANTS_12: [[u128; 2]; 816] = [
+    [0xf775c642b300da2fb4ad5c6e23ad4e5f, 0x1512df0135b6692589f071140a60749c],
+    [0x356589896d6a8849482ed3f9b66e1198, 0x2b109ae037ed30361cf6544b2f4ce58f],
+    [0xe2111ae4e1ecb1d61fc10cfe1c077f34, 0x02585bece06eff553d485478a19eb149],
+    [0x3b813a8cc343b9c2d2bc4819c70b2f3c, 0x01ff896f4c0662f4b2ad926741b481d0],
+    [0x8b7386a5afd6c67076ecbc3704aa45dc, 0x1b0caf02f434fa3d4cd4fbb13d3116ac],
+    [0x06f2f153a6f51f60f8e508684dfe5bcd, 0x0fcb7c9f13c85517a7805894ac82b57c],
+    [0x68ad8bf554541182354504d32cbf9954, 0x2dc2bdf7f43812e39cef210e42e92b49],
+    [0xb07c9a65635c90f45c0a08febad13664, 0x05add9df20c1898da46bbf74594438be],
+    [0x1cdc4194fbdbb6ca5895447c8a58859f, 0x21cada6641664daa997fd55ace8b8819],
+    [0x16ed66667a04df572b103590b848bf04, 0x19623504589a60f058b04489b153324d],
+    [0x2d5f84452890c444162848ee1d57dec2, 0x1776442d850a436a005e02c6475b72fd],
+    [0x74f24eff853938d87face89234a09a98, 0x06c1691fd6d2ee98396610f3c868d708],
+    [0x7afb348a45d85e30bc5227881493f45b, 0x22c3aba15f3a170a1bd11761cc21794a],
+    [0x96c0be0f779b9e63b06c48e90b0274fa, 0x1f77b18f06e1458877c2b18cae4e92c1],
+    [0x45f6b80a78ddb0bd67e363e63b85c546, 0x226c3dfe7a6a67319c1dadda1b4ef04e],
+    [0x68ca89d2fb75706f6946a0081f01f621, 0x056efbe3aade68e6886ab92e98a3d28a],
+    [0xe1940260d48313de807fc2df0f755d7f, 0x296406451f201c0d09587b7f4e00a7d0],
+    [0x2e75e2a9c87e478287700b593356f84b, 0x2000e0b6012b20f09df8eed25b095bf2],
+    [0xf654b544ae8636818db8e27db124ad31, 0x227f3766b6642d44c6d836a17c30cecf],
+    [0x199807249b886c975f26385dfb254a74, 0x2404641b2f5dad9722542bf151d497e9],
+    [0x4212d2c30926d4e4efee57baa652ba7f, 0x037493ac64d1deb34b9b83490f35170c],
+    [0x6325ecc7d9c790922b68ce5e7941595d, 0x18ea07488f02c6925f4b01d01b809c9a],
+    [0xf982f21f994e105c2578fc4afff91c91, 0x11952bfcba06442b3c1ebd959f1d4c51],
+    [0x9f896c5becfe57e24c0af04fac720544, 0x12be2a32f27a4e203517ab2028e65b94],
+    [0x02153b9ef9e8bb9c5f179dfe3036c358, 0x23e8097f69bbe45f02faec5e26dc523a],
+    [0x4ef21536ae7085e123c8d3e23235136e, 0x0763fc4b7da94badbd97ba933613c353],
+    [0x44c46c3232ee713141938cb019b3f907, 0x27a4fbd1c43e0ac15eac974e0ce2e253],
+    [0xad785a56707611fc4845f4d849e7f86d, 0x2dee4ada593d756654dec12b86bfb041],
+    [0x1f52124338cc70f3d1ed95b0e9da3355, 0x0b5151d0ddf61b2291772eb93a4e3e21],
+    [0xc073bd016d370ab3cce7c695a7eef96b, 0x2116e26c7086e70c806605a1671397d7],
+    [0x31e55f2d143f0a76d00023a38e4efe5e, 0x0941849147ab2cd8d189c4f8b17b5a97],
+    [0xb5d1e3b92e424cd6597c77d9fe672e91, 0x2ba07a6f84d2d2dc2f1b17e2352deabd],
+    [0x3a1776fd2955a878ef5d227d215a8071, 0x1aafef7a2d2f0516b4ef8cee33b4e8fa],
+    [0x2fa26e29c13bc13f315786d10ea01dad, 0x19434cc86e2f53e5db3cebfd2db52b40],
+    [0xdb8427580193e2ac8517349805b6623b, 0x075519c0bd7a6e6fe4bfbdf61addde94],
+    [0xa4914a1d9e53477b211e853eefbde02f, 0x12165d475cf1c4baf662bc6cd8744f9a],
+    [0x4c441ede53eeb0c4f9a6a4047bdae7c2, 0x0baf434f56cb6bbd08a0a58713b949b5],
+    [0xb287261bc8456ed91c2bbd0998444ead, 0x2e295b06bbef75a6d76dd10edba1eb93],
+    [0x4cd2445f5da16cf934442e7365847e4f, 0x146567927180cac0a771d5830f1431d1],
+    [0xf4e46c17c3047acafeabe4ad02cbc44c, 0x231d26e7d3ed945791a52013f46d02f5],
+    [0x219261e364cfc1f2a392948fafae1e76, 0x01a2f5ce4406246fbd42a57e6d5ae3b2],
+    [0xebf9aebf9829c253385cf9f0e3e76b39, 0x00661cc8a39d60be9b3c8bcd81d91fa8],
+    [0xa09b43bcc8988930dbb2079fb291fcb3, 0x1f565da70f04f96f26b3b36265f977dd],
+    [0x214b40d2d0f3ce442a28d764a9fb0821, 0x12fd46d935d45a5c8489ae0cb02c2fc0],
+    [0xc816868c8e91b029f4afbd45fc52ed0f, 0x2924153dcf47a15be1fa67f8ddf00ec5],
+    [0x87a92674346f9fd628ea5d20eba0ff4c, 0x1d4e61f8c6455809e19b563d168b4bbb],
+    [0xd955864d152ff339f2b2c7cccb4ec3eb, 0x2c4e7acecaca8e2ea9fd849be562d4d5],
+    [0xb1ea7520f3cc4250abfa8b4b600ac986, 0x080300849f022b572d62fa787583b6c9],
+    [0xcde45f75b17733294e87596a304878ce, 0x193a37fbfd3671dbfe759399d018df64],
+    [0x28c6bcd12ebbb9a3f0656ea0ebb87c98, 0x25e2563688957cf61178a5f0073ab7dd],
+    [0xf9eb4217eae2514566a39db44a09d516, 0x1e0d91980c59a652465ce702e4e8de73],
+    [0x07fa794af4a411f89bf5752f36561fb9, 0x1391b258dafec594799dfa817544e8a9],
+    [0x4828a4175195e47a97cbead60d026925, 0x12c30d5f593d4b0b80fc8c967c2a50c4],
+    [0xf2fb317a4cbf004c89e55a9e37aa9ade, 0x209ac8a2abb70eb33f20d5ee2b44ef71],
+    [0x90e54506b5084c5c89691a364d268ea9, 0x0bc7ad09a98b59dd1677ab30302fdf0c],
+    [0x47d3022cd8548ab8c838c8c163401a64, 0x1a7462cd2353a35701592a1c54fd3637],
+    [0x4204fab54c4ef63159d064d68ec5f36d, 0x0e251dc6fdd76bdbebebf70808d05250],
+    [0xfcccb380aaf7c8e89b4610e586f8f9fc, 0x2aaa85771e835599b13e6c43c0228167],
+    [0x3ba01a4eb270259f6e9a3f1c3fb61414, 0x2c429c35219ac033ca9ff5436fffc49c],
+    [0x994be9d2e3eb615468b22a72fc324daa, 0x011a5a9e38934a5912bbee49d509564c],
+    [0xeaebd37cc79587497be8ffd58b4b6c0e, 0x22a724029f009c75b8a4358aa7636d13],
+    [0x7027cd95fc152a84b6e003b841c337bf, 0x229236c15bb19327d244457a311d69f0],
+    [0x850a8cee4582fe4a8828296356811696, 0x304e4b8a0950a39ab2943c3b68eb6f16],
+    [0x69de53bb2d20f94010883f837c99f9e4, 0x0834178a75d083a131ead09424b3e3e9],
+    [0x7a03f9a076f8a1a22c10f4492063049f, 0x105cc405ecb40ae3f79cf5b9bd76a7f9],
+    [0xb2885a065a352a46a361549e1f8fa288, 0x2a37b4b65f08c8addb53e8f024c07e47],
+    [0xb9697d94e17a7258757927a864cfc9ba, 0x1078c0ee085dd1bd24fe71fae426d7d6],
+    [0x0cda286dec7c3a2c99c0b5f235ed7e2d, 0x1be49bbab70ef67a5d4e1706624a2286],
+    [0xedd0822384d2d4ce9b0efe2c63316f3f, 0x068ca82d18b3e7dbbc769a29559b1d87],
+    [0x69a1a284b60053edb972a059136ad71d, 0x2d8989373a556639e33f298fb0498249],
+    [0xddd3f6390db216742c517f2df71581df, 0x0d3e416c0ae12aff59d8dd5cd5dc509a],
+    [0x1febf9f512c12abd0df3466a29b49bbd, 0x14b2a67181fbd9c813c7babc9d1f7a7f],
+    [0x683b024f0d5a855d0c86a1bcb432bd8d, 0x22cfe9304894cf79a84cbb1b6e0b577d],
+    [0x5c955f5956d61b34c02d0b062bdd2a8f, 0x1adfe9a64e8029331ebd2fa3def2961f],
+    [0x5b157b5555168220cf3b7969d613cf76, 0x2751b2da7db871ba5fdb40e0bcda7cab],
+    [0x209e88ffe2bdc6a240a812f3b571aba1, 0x057f90a4c08130418250b790236f23f3],
+    [0x39ebafd5ec2a55fc8399b04ab922839e, 0x0ad74ef09771099c040c2c546719783c],
+    [0x5badea435cbbfef67523e3b3b1b73ef3, 0x0b00c2fa7613b52e25e4a6cf633a3335],
+    [0xf18d38c43af1bc588cdc65ed8e877b84, 0x2795381be694e0991a369e8e6ce75e7d],
+    [0x2fa0bb1dea660c3ceb006a6a3021de2b, 0x09177d18cbff59ce67ee76d3d10d7df1],
+    [0x074647bf4b3b9fa3707b681c1a855a43, 0x1bc77e5718826efce52a86b8610d9480],
+    [0xcaa03d2fa45c5cb2f82c1a0c048cebb1, 0x2f2d128b42d32102d05761240817ecbb],
+    [0x0f7eb357edd21699d5b3261b5762880b, 0x215bfd258ebc79352a7a4faabacb3084],
+    [0xfc09235d37a4133a51be17fbe9f8274b, 0x0e6b332b4ae5b95d518e6aebb7a07fb4],
+    [0x601e1015ac0198dc2880a21bbac65420, 0x272936773fc1a38aa82f7d3172a2477b],
+    [0xc15a7460e1afd9c23af7ac2a6de03427, 0x26bc4d42a17a0ffc93798bfbf2e2b561],
+    [0xd12c0228f8edb9b86959684611367ffd, 0x2d093621fd40ab76ef16fdf49a7923d2],
+    [0xc4bbfba1b7064b6b38330d950cba0e84, 0x0275ba0e2a2a4c3b1f5d718b5b8cb8dc],
+    [0x861a52bff2847af2ab22592487879daa, 0x1ed6384ca9de788c235c7fbf0c31a59b],
+    [0x916d94b7ce0729a08cc4efb9eeef98ec, 0x2c337c808bfe0d2cab544bd9af01f08f],
+    [0x984b5667539fd10c3e8e71246adad09a, 0x2d3e0934a4371484c958df1e97e7f0f3],
+    [0x4161302289467c9327ed08e35755cc13, 0x054e425aa02adacfeb729cae9df1907e],
+    [0xc89a433ecb236d18f57dac5aff85ef96, 0x024957fa9d48f942073ef90a54cb0632],
+    [0xab927fe5787f1723c2535944b2fe6971, 0x194b561a91334bd0dd4eaecfb94f03eb],
+    [0x8223226a3cc0c7a473cfc4a52c74ac03, 0x1f5f3bb77a487905e3d39b2656b91e0a],
+    [0x3b557c6542bf7bbc5cf01bd3369cba06, 0x1b3a3afed7a0821a684fc0d0f9ad9dfa],
+    [0xad636ea7d6d4babe8d2947e069390736, 0x2b96619ddc3b2a292fceb07e2aa80c86],
+    [0xfaadce88ead0a1658b0b481406613177, 0x0ba31e2d1e807374a710cdbf81f05af4],
+    [0xa228ad23360e4def3919146e8c44fa52, 0x25d7d5bdcb74b92b74a604d6fab3b2f3],
+    [0x38b061095621152e8abd01654380fadb, 0x1ca9f911f0b08d1a6205925cde7aa5fd],
+    [0xea64527e5c841ea9358825e2191b48b7, 0x1a90d4e090aaabf652053435e3cdea5d],
+    [0x1f5e6f08369e8a36eac406f29c7854e4, 0x0caaf3b76909b0b1e1c7f4ec493f2750],
+    [0xce214b04999b3b99531d103fd2577cd9, 0x1c8f94286e329069d1e80b45787cb308],
+    [0x151359f9f260a5b9770873e3cb98a900, 0x1acd43271d4b04976db5ec42700e83fb],
+    [0xdf44a3a87e85fc7bbc19b7839647a704, 0x046cbdd4dfe88c67791bb0c5ac749b5c],
+    [0x29c9d3f3051e4b01fa4dc573eeebc2af, 0x2b4b03cfb4487c1a19d7c05ca15133be],
+    [0xaa4fc6ff21ff5fb49c852ac59b65e5c7, 0x1a9a01f0040a3bea6124946a77e67fd3],
+    [0xacdfee50de5823ae0688e60714292b6f, 0x09eb5e077a7d0110673a617e59369830],
+    [0x15c5d119057ce0c721620af6e4666fa4, 0x0d006ce7c187ec6472562d212dfc29f6],
+    [0xdcaaf2ecf1ff8fd3f206fe34fa84722e, 0x054bc8d659b31001a2a529f45640a998],
+    [0x6323698c6b68738166af8e7f3f8dd9bb, 0x1ca1ff244c5106ea88045116081c201c],
+    [0x7357ccb34a3c18e94019627610cab1db, 0x04f254de8093f3fb3330dd7423d9d9e1],
+    [0x3692d91cc832f87c76c04bb20950153d, 0x18fa6af934100cda0123fee0b5636b69],
+    [0xfc6995f153f6bf05c59c09a4eeeab142, 0x0bc175cd6a0ad546d33298d27d2ce18f],
+    [0x028d6ca70604e36568b15f3408eea618, 0x2e2b792abb6b6310e2cd5b188fdccf53],
+    [0x37848e6dcb0c77f253e2cd6833aa3af6, 0x1216fc0f6e7ac8f0ea59bf7840fc720b],
+    [0xe6c12c44af2a00a11fcf0787421d8a02, 0x15381275074173542cd250ba7cda6d1b],
+    [0x769341f381de9c27af0eda1453183bcf, 0x25aa562c4dfb0a38dd92e020b4d6f9b2],
+    [0x0a0f634f771742a517e00bc2cc7cbe3c, 0x19ac9e3549ff20909488ab728a6e88d1],
+    [0x6a8005a9ab55ef69a91f847e760bf302, 0x13c02339462d082f13ecc8ded6b2bcb2],
+    [0x76a3526c6b45f75403e814a0bc620a1d, 0x1fef0dcd8f5858f240c8986003e577a2],
+    [0xe6fc9d68637a093889cf3c7fee5e3ea6, 0x031d9be212424a643c6c9bf144d81a06],
+    [0x28b8dfe8c0e55bea3564928b07de42f1, 0x1a6cca07ef30d34314047214f1a87297],
+    [0x931b260a3ff043c66cf232f4909c12a6, 0x2d3b9eda96cf186f8e0b5e1446543281],
+    [0xa23a130e98eee7f7766a4a3558b5d6af, 0x07da58325965bec24b4d12d606fb4563],
+    [0xc674b884b9c58ea8165d2934930d6ffc, 0x2655571c23f7f1cd1be67bb24d4c4e7c],
+    [0xc2b2cba256c61706b6997feb577a42d2, 0x2e5ba95e4a2ce5c433f15ab0a026b1ab],
+    [0xda8d802d9c47855050117926342bca87, 0x3000c7959df31ee4bfd1118c7ae22906],
+    [0xef1084679b539b058c81a17006abe053, 0x10e13d0881d27b08cf5b64ceef72c428],
+    [0x22acb3cf5abaa6c215871601b888f489, 0x2be2a93b372be664eebf809dd873c741],
+    [0xffa8b61ff606390b2eb0eca95d4465ac, 0x21310218217a726cc850c0a7dc919d29],
+    [0x24d8978959b44dbe9df7a7e52114a7e5, 0x14d907f470707542205bfd78df274bdf],
+    [0x1c92fc6e5dac2466c0af0b277816a3de, 0x05acfa1e857d8001ce311fd3b5d9eb3a],
+    [0xd04de3a77a483c662b0b5cb98cd449da, 0x123ffe61d17cbd6623ed486af0eb8e76],
+    [0xeec95f4e895650810aa77f4ba7027af7, 0x26368aa29474645edda1e155a5cf0df1],
+    [0xd2e6be9b72731255b05be5fee793f880, 0x21050e2060dceeb56367512af3fa11f3],
+    [0x9d936af296756129574bc79af2fef0a4, 0x22ee0d90c7d715326a0383c7abf6824b],
+    [0x0bb2c3fde1ebbbee6ef83729f25f3c2c, 0x0e457467bae8c132f687fc06235fa92a],
+    [0x6a3b4de751939d5eb09a3432a3e29638, 0x1dfb9cb0002a56e38b73913636fa56e2],
+    [0x6934319b81ab4b27beb44a133839dc16, 0x06c5f60480c00ca60c016f34a8ae166b],
+    [0xad394a6990d4e47a614a00999e4b8e78, 0x01284904157c07148e61e56297ff04fd],
+    [0xb62cfa5a3ac70a69bd470be5d82cf32d, 0x13d10300ac391c78f9c215dbc5d2d461],
+    [0x4c728b665eb17202297266c422a96188, 0x063d65844b8805daa8fd771b07c27840],
+    [0x8641d6e7fedf468c305d08220dbc8a38, 0x15e3bf17c52830ed3255cd5c1149f361],
+    [0x2d9651e3a5dda05c0f66e5bf8faa3df0, 0x12bf5dd5c3caad12e0875e57e19b1075],
+    [0x648e21e939400f9f5eb67d6c0cc094c7, 0x243c3b797e831a55046ae8916158fe53],
+    [0xc99d1e5e81e92345f62319ab96df03e5, 0x001e28fa9b34c2c6155b8421edd4de58],
+    [0x3cce0494a86ea452d9b4033623f386bc, 0x2ad1dba5506ee064064963527bdecd82],
+    [0x046734145f334a7cc1e4a57c740c8d82, 0x23137879a024cbc1b4810ee54c7f9b85],
+    [0xe30235da2275481bc6c3b056937c773c, 0x074f6fa62972968e22189384563d6752],
+    [0x627619378edbf751b46bda0b12dcccf5, 0x22b10a5253716d165a670736ee243de0],
+    [0x80849177ece7bfef7b446513f9ebe112, 0x0c190040f1e4eaa11b4fc70469959896],
+    [0x256188d32c3072c519d7069b0d10db76, 0x301875852c9ad256211b7f9f31ced8e2],
+    [0x2bc1272efae98b85c815db6b318dda42, 0x1c0f67d05ebfd650d22dead7a1113032],
+    [0x3fac60b0daeb6e973aabebce587a78f9, 0x286e9a17364f36946a3d2c7ba0612fef],
+    [0xdb14fe35c48580eb3165c61ddbceb52f, 0x2b729468126eaa101f4233baaa099d02],
+    [0xe473e832fc0ce811c28f09a31d5416fa, 0x1f30f71406a5da98e929062cb48e0a34],
+    [0x229fc11dc698db8bb81c822e2c832173, 0x259546f8419fa15978ab360084fe0a13],
+    [0x0ec8faaaf5d733ecc6960bd7aa5d7deb, 0x06e36124121afaca19887a7ca428e12c],
+    [0x92de6b7b6ef2f8de911f731494260be2, 0x10f1ce0bf57c0778bf4de60a1e2bac88],
+    [0x0d6ca0d9f930f53d3abb55e6d75ad103, 0x177fc951f639fcc274b4ee28f3f7ff56],
+    [0x2efba23c56ec3dddc5836e2e919cd610, 0x1f34032aa46cc08d68555bbc2469a66a],
+    [0x9ebe06d10610dc91d9f4cc57111eab41, 0x269cd0b774f8625f5de7519be61857cc],
+    [0x0f0d0feeb99d4688ff8871f6349f3c66, 0x074f437828140fcfb1ae00b38c29a508],
+    [0x5aa5e9f56b406f015d9b095fe1905945, 0x0396f2fce8cd5ab3d2601d37f80fd977],
+    [0xe1aba810a6312480cce343ec663f210f, 0x2e03afbff73fb05f32f50239907f48aa],
+    [0x1d6c22d8426af54d2c74970b33ad2a90, 0x2ae899455a6a7c49997c82bbe36066ef],
+    [0x87cb997cd687ef9f7f88b30c3def2690, 0x2bfedee3eaf7340a10fc0971ef0003dc],
+    [0xe78932a68051e792c23a99ff95139a00, 0x2b50b39a43cf9f1d9feaf04a1f100311],
+    [0x147f8a7ab4b62ceb26dc3e2b16c3bbbe, 0x24d16eb1fc9ee39362dc66338ea0d275],
+    [0x7305bbba935e5a7dec940e236dba2f71, 0x1539d4f6178da10200463590c6efb1ca],
+    [0x2ca7a04075a78c1bfd29dddbc7be7528, 0x09e85ddcb02bfe0709021dc9b9f4efba],
+    [0x51f7cc16cac7e1c6794611eed06a3813, 0x01fe7f77ba2c018282073ab4d2229593],
+    [0x07a83782df55438cc8fcee6ae75659f9, 0x1d3220cf7ceba197899e792fd2b51658],
+    [0x3425391f7ed124a035b94133404517d8, 0x1364bb46fe7c79bc9156a13a8c86b77d],
+    [0x5de97e63e364028f0c0b1904b075b06e, 0x0b70eea0fa95971c52b4c91fd9cf6ffb],
+    [0xc731fe673eb289d15a374eb0d055d217, 0x2a9ab96b4de33f8ccfdccd8e86510e8c],
+    [0x691d28698440e7b41190a8b8e0c98525, 0x2ec7ab4d439e22d1d97eff2501e94577],
+    [0x573b05c99e4bcb26213126fe4dbb5de4, 0x1c1b59ff9212224422968e7616ceae71],
+    [0x969e3492584085f7dd3fff9d8814cd49, 0x1fdfaa4da540e50d2bcd1a2a0975350f],
+    [0x91f08869a99391caf70e2effdf7f0866, 0x0cc8ef6a3dc250d7a4c94bb890be797b],
+    [0x9f79fae9b601cc6567569e8ee9e24813, 0x09e595faec8506700a42a5a41190cb6b],
+    [0x2f0101a60bf6c2e96371be54e63d7346, 0x06047cdc0ff8bd94c6ab60e3ffea2191],
+    [0xf0b2facfa5c9e691a1c4924fffba4950, 0x2e40288a4a681e1ff5318fbfb796e8b7],
+    [0xe20a7d25c7dfb13460b0722681596807, 0x12616992bf6faf5144c8ce58355ec2b6],
+    [0x6e6b45485b4c06fc38e052818b5b3caf, 0x0a78425d56372af995d8930d48cd645a],
+    [0x25d8174d067471295a983cebb1c9a0fa, 0x0fe9c60a64dce8b45b53462ca6e68be3],
+    [0x3319bf41007bdc4ea0fea40ef97cba1d, 0x2e7aa9cee7624f71fa4902971d07cd30],
+    [0xa3bee6192df0e6cdb15b6f302f476c8d, 0x104690cf04a0666f4921a4ef9f808fb9],
+    [0xa395002e22c9a6155930533b29959471, 0x2e63c4212a402284deddf9ef260bcfe7],
+    [0x6378510615815351bc58d3b5d6ad7826, 0x23de0c9527d4ede516b956c920031586],
+    [0x9d32a0ba6ccd7fc5158ec97c9d32c447, 0x25439ed1737440d79e4be009403949ec],
+    [0x09ddf0c0cbd0b226df54958e680afe90, 0x14e35080e8da90b17e72b6fbfffe126b],
+    [0x01b3c0aff73e81bd25847ff0a45d1fa6, 0x2e0e87ca0a2090d9ae1824cec8a389e0],
+    [0x80f0b650831d8f424b6af3fb361c6deb, 0x1319e0f608a12fa01b7740a8e14066b8],
+    [0x1c0a61436b71d24ee7723c7fb8875af9, 0x262b82f1d1d07bb18b9920597c3925a4],
+    [0xc229735b259c03d4c439ec2ae468b77f, 0x27773d7ae7ed008296a572adb64f4fb5],
+    [0xa81240e80837a519152d6542a5f4074c, 0x05a65188ac6d6467e028dbe4b941bac5],
+    [0xec455f67325324c45119c0329195efce, 0x00a2a8b07719ecfb2c94e90f8e045b18],
+    [0x9e9b6081bb09bfda6456dcd3fd8025a2, 0x12cc316a669ced0290bec967576e8e2a],
+    [0xd408749b7d2165038d702b303ebf1037, 0x25a05ba9a3f7dcc29a43df45efc404be],
+    [0xb061e0db126ec59a8af095a310c284d8, 0x06bd45d9b13af28e309348d1c06cf153],
+    [0xa1a382934e7168c30a1e9daad2b96fbb, 0x2f55fdda2dc8faf2cf83086758c7bc77],
+    [0xc69191759e553eebbe674ee9763b1900, 0x1a468a18b185dc342ac6b8ebe53ef265],
+    [0xe17ac99689417b1d92698607ee005142, 0x031b0f9b6ffcb451f2617ee915cd6daf],
+    [0xa36c6576b19c36fbe06b4455ecd20f37, 0x28f95d6eb7547e7abe25d2484193bbfa],
+    [0x1ef56aa8d084b2301e62f08e0777420b, 0x04f70c340b166e834080afc44ae444ea],
+    [0x4e8069e2c71fd663a9c9cd0d13086165, 0x0fd721d14305b2fd9b20975775f54e6c],
+    [0x79c0d2c755b7e59da7726d0d54943783, 0x0e79eb513eabd8ca3fc6109650505108],
+    [0xa1763bfb2de23352ea55aa5dd7bb8f5d, 0x2e3636a0f8c3014fd963ff1cfefcc844],
+    [0xc107f61e3e0de9b8b4c234a321a75589, 0x0edeaf35f44f339dafb57e3080c99c74],
+    [0x11bf8c97daf1dafc4ae6106ebb347643, 0x20e4391350654d0463b6ea16cc68d2c4],
+    [0x4c7e6d573480ab4b2d85926e95b0b9d6, 0x1aa0b9abab5c77259b24ba6df035bf86],
+    [0x8eaa9324e6347ae9fca7c2a3af2b95af, 0x2b582ae7e00f3c6dd5163f3e44f8ae1f],
+    [0x30a09b20adb31dbd4fcbfac0e4ca93b5, 0x1107e912d26d0c4253496352f14b263a],
+    [0xa06046c12f01413ad00a19b8457f804c, 0x131dec28abb0eba0e9821ffce7980bcc],
+    [0x417c8ce35d907d95376756d23236bb66, 0x300ed80f4915fcb5dc8d420adacdc6b5],
+    [0x977938a5335be74325e0669e7edb551d, 0x188e211e215ded9c861d3a21f43f1578],
+    [0x1880bb66fd6b3abfe5cc2c3a8e6a227c, 0x17d321855d1ca0303dc7b0b2807c43de],
+    [0xf82ca1aa08a6def3f35256914b050210, 0x1528e096fbd27e0e0900460be22a69b7],
+    [0x5ddef5c07ff4e720604754739fd56381, 0x271672fbb529a7df27dd20e888bf331f],
+    [0xd4d87a8aedf97b24de2f32e86855f5d1, 0x2d7d508df128fa96da79116c1491d6d4],
+    [0xe2a1dadbd8c9915ac402f2378994b3ee, 0x02c12ae06490344d658455338a0ba557],
+    [0xbe8a81f9c9fdef9428ff3f46bed81b70, 0x1ef7f571d7daac5ada2f5b94de137402],
+    [0x5535dc3634d8359e730685a1d7eb8666, 0x186213e6d9a5ecc85192d790388eafed],
+    [0x74beea0f05abaaf67af1698ab3d025e5, 0x0370eb6d2e98282ce7eabd8dfadbc46b],
+    [0x896e05398ef373088bc86327b37bc103, 0x104ffffbff2258b1d4a2c661d2d0cbf7],
+    [0xbc043ca985cee17f0bfd3e68bc897ae4, 0x206d39d5af5e1e50b477e46c62534200],
+    [0x8baf8ad6c279558de1696b2e8b2907df, 0x01aaa20931d5de5931734e80546c966b],
+    [0x77e4d01e712d43a35b9f542d649488f9, 0x1f4aa1784a2403f3c0b312e20fffc29f],
+    [0x6497ea97a6ceb82d3947508bbb5202e6, 0x2701a0b09e785acd9711e5e6ff6232bc],
+    [0x00319f645b2b956a34056355d3b30047, 0x07e99ad5f3317c1ace19b4a7cb94b509],
+    [0x8c9fcfe530b417de4d423aa02ca98f59, 0x2250455670deae50875f75ae9779a600],
+    [0x1d15292ff00a8684f5e28b5f2495979e, 0x260d62893e254abd10f0e4f8be37b522],
+    [0x7cb6dc045a595417834bf39c558f9f26, 0x15abbcd2e0c442449275eca5e4911d15],
+    [0xcac1907d031085ff8daa9efda95e419a, 0x09751f79321823f4bfb93804919c64e5],
+    [0xa0cbc0dce4cef8d72ce3a59407b2e25a, 0x08b3d82cfbec8cc13f75f77c3a61848a],
+    [0x728af4e8f5cdfd7d29943dfc29a7b2c5, 0x1976bc0dbbd83a1a26f9e14aa431e24a],
+    [0xa08a48989dc2464b3bd5976534eb38e9, 0x04f59d201dbb263dd04d2c2fa396b12b],
+    [0x03fe75880e9ab76eb4d46a020ca6e30d, 0x2bb23243e841c564e7add2d7b528d9af],
+    [0xa1d6e3548569abec7d7a49e1e0474983, 0x2f66b96144ef6c5cae5bfd2511dd2456],
+    [0x99453d8233e796a25075c41fa7e19d43, 0x29b569f2f67f1c0363f604936cd59c32],
+    [0x2e79eb39d44756caef259268a06d274e, 0x1aab6103104bd50f3a65eb42bbf90179],
+    [0xd0655371859bc704dcea5ecfc885ea23, 0x100955f3e56bd17a4eaff6f79b697742],
+    [0x2f4edf5c39c17ad5a7b8f479052711ea, 0x271b363c4f44f4faafa02db2752dcc19],
+    [0xf2a6ca36e63a37b5cedd98a318a7fb76, 0x1021eaa8fa200bb0d3d7ef502c5d7eed],
+    [0x08f58404df6c2b578462f4ac181c5e1a, 0x13c6a203321170c046d7d44e25f21c47],
+    [0xebe687083f4ec299600c84657ef2bc9f, 0x066691a02754dbd02ada281da41893fe],
+    [0x0999c30a05fbddf1a43037a8dbd4f199, 0x16379af37c274e663b7e3c04ad065ee3],
+    [0xb6e65a2b93a9c990f6a53ba7d5775696, 0x2e01cf9813252a5171624a4963d572e1],
+    [0xfc92aed79c3724b3e9cfcf5c8d35bf77, 0x05a4ec77a21ca15f804aed87b98dc3d5],
+    [0x1d390a3c2b094197a5591c6b0c29b4b2, 0x0032f3bab069abd4a66aa9845e84a3ea],
+    [0x3215b7d2904c7c5631bb4b72e83de7d9, 0x056416328cf6d47d3d291cd725948144],
+    [0x9da7bc8776c76c5dcc6b4bd49c7217fd, 0x16c0689d4828c935eafdaafac08e4d90],
+    [0x06ec1139ba2240b733ad3acb847f7992, 0x1d8d23ed1dd6a53d2c18f91537564eb7],
+    [0x62d5e12b8e3effbab2a4eed9b8a58de4, 0x1aa1a21e3784b97843ec620ca8961e8c],
+    [0x98a08916048d6b48f9540b451b7e66dc, 0x0bb54439d4c6f812a70581ab7c1da56d],
+    [0xd7acb860a188b08a6ed1d00cb90b3c3e, 0x24421b73eeaf63134b46c29646c2b24d],
+    [0x35965acd0ea551a671f1b625e684d7d4, 0x3045c2012f7445890f79f1567060c3c9],
+    [0x1b75551065d6d01f6deb04c63102c9bd, 0x08ec863991a053feedaa5281098afc03],
+    [0xc94952cfb248cc46d92ee60b8c4c95af, 0x250cb2ab9d5579a24a57faaa87f7c828],
+    [0x15e6f5fd2cd0f493f96265369642f93d, 0x0f25b63b5eebc3198122c606b0a5b2de],
+    [0x79e1df0c8f2d9d5e8d65b792dbc36d0d, 0x2ef6abc5ab02c8431de8f7a64ba3699c],
+    [0x93c3b0592512340206635332b5c22b11, 0x1e84897766aa9de056bb5d97c6797bde],
+    [0xf3aec3054d4b2b0a1fbaeb356384d70e, 0x08923f05b050e6e0fec0efef4dea500f],
+    [0x8f379db3a5c1c3b9f57b725b1fd8437a, 0x11687ac05e24f50db06335e1875db73d],
+    [0x29f5a6971ee0f33ac930f5be13b154a6, 0x2755837b80549adaba47e09809075912],
+    [0x623e12da019460363a49f5e5efc076c5, 0x09f80c8230bc5a78138f9952ba6b64ce],
+    [0xf30872a92416552a1dfb4b75d4092d4b, 0x104009746d61c0bb6643e64ddfeaa2d4],
+    [0x4a6013598bf73b28eeb36c41e76fc54a, 0x196e821f653863b14b72b19a068aac43],
+    [0xa50672522536ec7663869f758ac720c7, 0x2c8a8a85e2618452a8d0a9ea592bc495],
+    [0xa840dc97fd1679e653d5fa32b8ad6195, 0x07bd4de2aec5a7acfa66678754ef95ee],
+    [0xbf2b2c58a1b7dea25f000eb9adca396f, 0x2464a17f4353a76d911cc8349f5dd02c],
+    [0x12a5cc41c93a2037985eecf4c2fbe4a5, 0x26831294e338f44440adcf5dd1ca4d14],
+    [0x7da74b207bc57a15ac9ac92cae2ecfab, 0x16d69ee3ae6b20ddd62dd1a13d279d4d],
+    [0x0457a9980e6050da2500a409415f207b, 0x16b55a792f1b19dcce6b9f4c9b61be0e],
+    [0x4843aea5da2fa4e686f183be95d4170a, 0x0d49e31e27e1c71c16d524b2a8adb0a6],
+    [0x0452793d1d1a11ead3bb8284eb65f97a, 0x1affb8fd8b243d62d5d4a7b07b624f9a],
+    [0x9aba2e863c2deb8fae7525e42c96cebb, 0x27da7ba10cb86c06758c55e7e8ac76dc],
+    [0x443704d18c42a96f3fdcb7c4536fba09, 0x27e1be0db1fdd736d218443f56d06987],
+    [0x2b001b9275e670d73c81eb61fe028322, 0x2fb1dd1f7461d75fa3c1e25df670c144],
+    [0x31831dcf080bff42ff1d14974f82ffe6, 0x0f5f0cc9a3d4d3066c99194feb229f0e],
+    [0xcd8818a23550f3753decb1ec24405ffe, 0x2bcd23585401b0f7fe33fe366ce92e3a],
+    [0x6e15b438cf346516ba2147356904f01d, 0x005c552a02e2a39af0e21c8944b9c4f5],
+    [0x0ca94b95fcb79b65f14753c58222b4ac, 0x0362a8a2865a2b2fe902831c8044c54b],
+    [0xc669907250fd7064868fd313810faa29, 0x029bb183a6a291b93f0989746105e33c],
+    [0x2af970e6e5045f7913cf4c40ca6df18c, 0x0608f62214871578ed58a12dcc4d1c15],
+    [0x7579006ca22ecd7422511c0391b5c4ee, 0x1f8a4cf532f5e835704ed7ca83effb51],
+    [0x978152e15b344892a94c44e2f0b1b338, 0x213882bb31291dbc346cae58fbfdcf03],
+    [0xeb0a972dd34764910f059508d7e8622c, 0x297125f6728b65f189fab61c3896016b],
+    [0xf1bfc69f2dd6ee1516a46e4865b7c7ae, 0x0f76029cc3ff0861e048a601eb52a11c],
+    [0x6a22b19c7ff7a7fccc6237af1142bb17, 0x05a506ed7382db51851c224d5213c7ce],
+    [0x6888e44c60dfec0a98b7262a977744a1, 0x157ddb6fb6d427b97ba69e8798f3950d],
+    [0x351da540cae037eba37543b045e91eaf, 0x277aa399e55c3e5cb49c4bd8789f6551],
+    [0xb995b5341d29c548da08391e6f1453a5, 0x1499091ae8dc567f8d050a0835eaecf4],
+    [0x8ec02b110abd379c2d26cbea9167b6f8, 0x200bdae3ef70a75a12979041cde33a17],
+    [0x35e8afad6acbba6aab16f455939dc77a, 0x2e6835de44957130dd724a0ac6335b7c],
+    [0xda3cba855e15ff634893209bbfe55d3a, 0x09e302222c01cdb2c45e5c3a920edd82],
+    [0xca19cec068f155d6954d279f3959cd99, 0x0a5b412cccce0a371617dada267bc783],
+    [0x79e68ef2c8bf39efda1a0332e63debc4, 0x2ffb034bf2ba629ead6f8a6022e23397],
+    [0x2dc1ab2479a10f33d7c5b7121c58176b, 0x1f3ab3fbf5fd24859380ab59a8f72cc5],
+    [0x010ece609b5b2c6eb137ef5dc2769b28, 0x08303d8ea6c4af050a906aebae492e32],
+    [0xfb9e99939dc170c735f407148c792532, 0x19f0945d9f71950f6c2b298ad3c646e4],
+    [0x33d25af7f7711c3ed99995d983c4c3a4, 0x1a621a5b24758bb036344639e427c03f],
+    [0x752ea6f8c876361d327329579339928b, 0x218a7b745f35f497b4428e61c3b79563],
+    [0xb899ca7769b445a7387db118088963fb, 0x16ecebebe58dff59a7b0ba40693aa431],
+    [0xc659f5236e896a4d447ab3da68280fac, 0x1daee46cfcbbd63435fa6f8791b36a4f],
+    [0x3940b5654d369c61156db2927de5ed18, 0x260875ca1e98e895c635db3bed500d39],
+    [0x788815614bec66f68735914c3b8ade35, 0x1cbf71e0ebebc2d151966acc2be11116],
+    [0x2bbebdb1caef32aadadb22a918a8a99f, 0x03b2a320eaddf48ea570e643f89ef227],
+    [0xd36d8b7cc42e1dd64a83081357189f6f, 0x072f534bf07ba555426152c37e210cc1],
+    [0x93d867b0daa30dd334ee5ca08d304b07, 0x17fe62a93a9efb1236012e2ca1064b1b],
+    [0x3d4696ddd8c8dcdb1d2fdd460ab4cd1d, 0x2ed3b5db7ad2fc083734fb6147162946],
+    [0x8037c25c7209425ba83e998115335c19, 0x26e83b9a8a7c7bd6929f22021fea7808],
+    [0xeceba4be517a01e51a51a9c11c1510ab, 0x1758fde5e2d8b8886ae2134943407d8b],
+    [0x3590f67e3484a67d846bc1cff2cbe1d5, 0x233e22ff85bffcb57cf35077fc6c8196],
+    [0xf5064398045dc7a9a8f31669282ce92e, 0x2bdd734a2d8a75f5e1cda7114a8c418b],
+    [0xc42f6604df893509a629c351f57b95d9, 0x21c2e28d54733906bd138386d7f05b1c],
+    [0xd3a01961e5f884148ab3f6afbecca3f9, 0x03ec3db419b789b9a13811dfa2c51840],
+    [0x6d2a329d634ec53db009dfbe331062fa, 0x04452cebb0cd94f7163266aab365b366],
+    [0xf5a4d1d483ff38c4b0cd49214b12ee2a, 0x2374e7cd3492dde40a21a4111b32313d],
+    [0x8d2b72bd508e05d6f8bceb7f3fc668a3, 0x18aace16399c7fd6e9d2197c5d787959],
+    [0x417bd7a882e46ba06ba075731f56b563, 0x041d469f85bfadec96c07f7756a6735f],
+    [0xe0358bb45dfcd6db64f90df964cb92e5, 0x10b3c1cea302fb6b1aeefe7a701890ce],
+    [0x54284897d14b2eaa009400a8d77a4beb, 0x086dff039d2271895d0a8cdf944d75ba],
+    [0x48c4533a72851fdba34a5083f00802ef, 0x072e72d8677f6a51f4c2386e2e830d66],
+    [0xa721062c50f14453fbfce12812dec477, 0x13dedd574590f362b835c05151d6d7c1],
+    [0x69bcb883d4b4e757e8eb52bc26958084, 0x0f7b730b9f5abc35b874b224ebf120d4],
+    [0x68e13c2cf3982c52777e6c5339472083, 0x23f2dfd0242e0dd9ddf76b575563e698],
+    [0xe27717b8e964f60404d2a98e8c4408ed, 0x264032c844dabcb025545315588b4509],
+    [0x187a48ee96064d3f094d15a931c09d65, 0x2a505c683ed009b84ea09e705b1c9256],
+    [0x1522aacd9262c5503deca09ed3b5bfa5, 0x12195fb5314e5dca6dbb5301a0bab374],
+    [0x2a84da2a5d24c07f2e6990175e362138, 0x2b96748cbb6147ef4860f0d7d93c21e4],
+    [0x99adb041c6e16707f9f8701ca3535bde, 0x168dc4dfaf6fecd4884881fd5f0feb27],
+    [0xaae4e7bd4b4b91d727a80ce850585439, 0x293fdf5e9741851b6efdd21641894eab],
+    [0xe53e0161b2337bc5bfbd81c668144e60, 0x108d4122ffa4a9bed486d89bf9ce7188],
+    [0xdd422203a306f961707b4a179e8a8096, 0x1d34ef90f1f5eb9be9a718c2f5893fe4],
+    [0x9d13f571edd32a1a3b1e57efc7967049, 0x2d6db62f91949f12dd7df0846c7d73de],
+    [0xdf06ceb5bd3d362fac4b106111ee2d52, 0x18ba73783f66e8f96616221f4959a5a6],
+    [0xaed11646ffe696ee065bc2ea650cf6e9, 0x0a17603d2299bbf990ee1a95b5acc089],
+    [0xca866fce11dfadf427fb770d8c25d2fc, 0x0fc9ee6b510c00bf2cfaa5e6122b6e68],
+    [0xc203d0efc4011447c543d051656122cc, 0x28222e08995c93e38c8389bd32f39dbc],
+    [0xdd41313b93989de7e5e9faab97ce9b46, 0x07d27a8bba7349dd716d1cf2a9595740],
+    [0x73d3aa17895b331bccd09f1892788a8c, 0x14498b47c4b8f2663e422f7171b4a3c0],
+    [0x85c1c6b596b72abbcbd0d1ef0f067fcd, 0x0bd8e776f3ba7553933619f60711184e],
+    [0x983031b9c8f5198d32c50d5f25b03a01, 0x20fadf38540e1e46da22ae56b75324b4],
+    [0xbac71585073928a74ea6f594dd2ea05c, 0x269997eeaebbe58b6ea9f9e7fd79d185],
+    [0x8a8fff1685e7ddf656debc7b3b6190d5, 0x287d91c8eb0d2d0882fc5764dab5404c],
+    [0xa864deaa4c16a6753c1e72454c2c630a, 0x0878d3344a7d147ffb4c047b7d6dfa85],
+    [0xf7c5dcab7a8d5f9abe92d7b2b2aa67ea, 0x2e3c4acca0611112c29b18e0f9b59357],
+    [0x629ef5436799594edcad6055c5bc6943, 0x1e288d37cca3d0ba558ade49cc5220ef],
+    [0x18d1420f958be0348fed76ec4f705786, 0x1a1d7f65598460c079f75ba724389d51],
+    [0x66679ac37eab5b27ddff668615ccad88, 0x0f1a6c587f154cb9e0922c5c4d8d277e],
+    [0x0df22d36c9d207da85ddbca5ec747197, 0x2d782792e3cfa8411cf6233e5be30dec],
+    [0xd1b863b4e76471da01544f06d78a9be0, 0x1bf4dc59b31c01ca9dd2d9a70520ac09],
+    [0x62b170818106e544ef147291047c3fc5, 0x29abe14d44a7721ffeb5283606628421],
+    [0x173cbc68c4d935d0f7e654f104696fa8, 0x02f4b020edf60204c8dc75abcfb07605],
+    [0xc2f7956a82819e3a9c853df4606b9a9e, 0x1c692a1214bb6ae2c5b3482b96ec4678],
+    [0xb77b94fbdc24eb5782d55622db5afd20, 0x25cb9034345fd345c79824069a5b9a05],
+    [0xaa409689c9c74bfdd2538d78025a632c, 0x15ba65b32365befa2f7cb0c5210eb901],
+    [0x56090f4cec023448ae1199fcbc58aa88, 0x301a02d45faba3c161f8250a2e7e280d],
+    [0xedcfa806abd731c91a4c3003fa20f355, 0x2e95c6f2a67a136f5124345e3a43ef22],
+    [0x40d8e695f2cf080fbb83e61ed285e871, 0x0a1dc1da90f177ad3abccb3513e1bb74],
+    [0x30753750d1156a52207b82ce63a92c51, 0x26feb10533a8b990dcce9ec02e87b7cd],
+    [0x8aa7ba817d6d4b1dce5dd3f03a841d4b, 0x190eaf914232f96cdd1de4bbde137a2b],
+    [0x5846878e97071ee0e99e560efff58b23, 0x1d441c3fc47fe77a1883e06966c8980e],
+    [0x2e386db46dbe0d8064e11c8ae74a2122, 0x27028a819695508e70882dd56a022e91],
+    [0x0c2a198b6a58bd822c722f7e42ee2c53, 0x0a26bbfd50a68ff9f1622e344e20bf89],
+    [0x2df31b0bd3ee502c8c20ab8bca826fd7, 0x2551e3511c114035df498e07d8354c04],
+    [0x4c078c00a29002e6553a625efcf0b0ac, 0x17c6203deb0a803272043d5ba927b598],
+    [0x062ee2dddab6d050b1450c0b95a51c7e, 0x0dea4d4c52bdec214ee4e0b88bcef03e],
+    [0xe1156df506c39c0ab1d6dab1f6f9a62c, 0x05e660b1552d34ff3f62dbc5b1ab67e8],
+    [0x162362cf9b18243c886421a90a646c30, 0x0b9870c2d17bcb2bb8b2259ecb2e54ee],
+    [0xab614d47cb0cb3c79180ce551849993a, 0x0d83ef93bf551550434dc0763cfeb5c0],
+    [0x985d48e916285a64e676a811d5733ba0, 0x2bd44845898d89a005f5c1bbbf5f5b8e],
+    [0x0920c71bfeb39be6bc82291d322e7f34, 0x255721e12fbc82235e64a106731c1291],
+    [0x6ab1ed3afa884f6aeaac3e8657b52d6f, 0x11b41910ed33ae016c7d059a373657f5],
+    [0x88ff0dfd8834fcb085968a35870c96cd, 0x1634afe1bbae1766b7ff7b11b011ef9f],
+    [0x285399a12b6c1df22f6570bd00e5a237, 0x17e992284359e923536eed41bd61e308],
+    [0x47127b0d441d88dff53443d3d7db9d90, 0x19808c7e543688ca243719dfc2da1301],
+    [0x65064bfc982225d5946c7ebff7945979, 0x2aa19c14548a4fcee170c0196d363e88],
+    [0x130c00a731134bf66692cd5d7460616d, 0x2e6a2b023ac766e7485bd83cbaba016a],
+    [0x6bcbc81d2ff49af4da8c7cb0ce8d5125, 0x04e13213097cb24a2a8b3819019a0b67],
+    [0xc79c2352600f5859b30e218f07326ad8, 0x0436db88b4f66de384baea73c505b871],
+    [0xab7bb0e6e8f9d3e9cd09a9b1b16a9094, 0x00c97e95688ee07ffa790567816e31d6],
+    [0x83d0d660ebeb5e7af3c6024d3e577747, 0x2f04c8c72c8a00180ae6d0c29f2dfabd],
+    [0xc56f2c99961e1ae996608516cfd08791, 0x15070cdd99b9b5cdc0694685bdb06489],
+    [0xd33155ba10f9834e95f3a96e25e2f70e, 0x134e38e18c1f42b857ba214be75ce4b5],
+    [0x1cdec854fc170a064be745471ab5deee, 0x1711629053d5021bafa111ac20ac8516],
+    [0xaad8bf3501c6a55ef01dd4b21acf017e, 0x0d072e1c8ff17213b27848557a426701],
+    [0xb4e5dd95fd04610ffe27a99687fa98a0, 0x0d6cba818cc9b0f4e2323a073869c4d4],
+    [0xf0d95eec0b51ce06f6ae3b40f10a54de, 0x1bb7ec720107065b5d75fc4539b3b91a],
+    [0x7ad04861de3e653a767efa5036bc59a5, 0x0f4b198af2aae4c1f15035affbdd3064],
+    [0x533158c689ed2e6b14f537f7f8af4891, 0x26bd5d4e6a15ed6e2ad4caf87762a8e2],
+    [0x57a9cdb2a7d8d5af899ce5c8a304ef4b, 0x1db762c3e18bc6c00dae8124eb139213],
+    [0x22a3ac41f9a81e048fd5c80119ff62d8, 0x2421f2e7882947e068b590493df453dd],
+    [0x3e8483b529a57bd6b8f3240947310cdc, 0x0bd9d71697d6c92e376850493ba68728],
+    [0x37e2c0ac2fd9e7ec416e84bb44835025, 0x264e510e5a7eb58fe37c891194e72287],
+    [0x4b1ab7154cf94bb948171ff092f084a5, 0x1a28efe15e5ba6bc2ee602ea858829d5],
+    [0x2d66e9bdcfeca4cfc133855662a0ae15, 0x14a206642fa9a6cbe78d6994d4472aec],
+    [0xe184d5af2a55979e85d8c37d5fa377c3, 0x29195f6f5eb29605ebb51348adb72b86],
+    [0x0d4d8d87c7c5085cc57a36872cdcf496, 0x2becbd1aa2fc1d6a0cb87a7432ba1807],
+    [0x0f3a0b932daab80580ef19f2eca4dc7e, 0x2eb311c7d96af1bbbeedc811dcd0cf3b],
+    [0xe450da4b719eadc02e8e6f0ae8a9c8be, 0x06b13b0ec69efec0dda80dde05a22982],
+    [0x07327ab830fa43c7616c6bbd7b337fa5, 0x2951569586c452e57a16deffed6643b9],
+    [0x8790bd17fb71aba3be68235da31fae9a, 0x0f80913d6a98d68e7d20314908342159],
+    [0x752a939343f52d4cfaa1ad0f9ea80627, 0x0f6c6ebc152c7142355f365056108ca2],
+    [0x94ccefe991b43482d3accc1a5e6bc41b, 0x2651a3545d55a179b95c1d5ec9f47900],
+    [0x924a88f25a682a604814ab0f83fdda64, 0x19ffed697be990bd22c33a3152f1b61d],
+    [0xa15633041e31caccdae57a2cf2e6e30f, 0x0514f51112b2131ff7446bdc8e6035f8],
+    [0x6f3103204b0ec153bde1b310ad8ab960, 0x27b250eedea12787b0e74c67fd29ee3d],
+    [0x86f50ddfc68b5bf59e57c5f93c71e1c9, 0x034e3b092aeeecf9fcbe012250071b7d],
+    [0x1e34ec457f3e1186e1ea3369be4b923a, 0x1453aabd4ab309ce11df060b36906f22],
+    [0x094dcb5e74c08e22f170fda744ee17c6, 0x11680b19c0f622c3aece37013d9d9c6b],
+    [0x5ee6c42c3fbb6c30ba0fb457bca33d99, 0x25121ba4a0ade9a8b4ddabdc337b0964],
+    [0xdc6e2eed6daeb368f6678ccb41b08350, 0x3010c1d25cce64f6dd36355891cfa681],
+    [0xcacfa54189b6d66af5d759fcb6bda79e, 0x1d50c97e2fc85017040cc192d199ce96],
+    [0xe8db866fef3ecdcccc3cfad29e7ab2aa, 0x15cdcbb1c5317192281df60267008a9c],
+    [0x452e4dc0f664cc600d2cb6963b7df87c, 0x0bae86292d004b61dea91dab5addecd8],
+    [0x39d76048361592d38632a20c8d0246f7, 0x0966e0ae7b6e52fd18185266c449aca1],
+    [0xaa12a12656250bf6cdb92909a8510867, 0x1cae92fe86f9a7ef83ba0419a261dc83],
+    [0x06686ea62a6f13dd9535dba248fcbd47, 0x00a2cb83a054988cee1a7a847c228c80],
+    [0x5f2e4acf02f6f6160714bb4d86d8a7c4, 0x1417d0b749fda05bc8a36f41f3f71402],
+    [0xa142046d2a83291da792cfa54b234782, 0x052c5a70723442134e3532806e57d283],
+    [0x2609c08276a171c88dc02e85db49aecd, 0x22a8c9d3df25905c7bf99c0697e13988],
+    [0x31b5e1f531f91cdb5127baf89c83c73c, 0x1c388261e076a0643d5479187cb6ed24],
+    [0xd5ab0114a1ac7826460f34635a55c7b1, 0x0ab262c3215d90263f02d9cf1a2350d9],
+    [0x8f992aec9b53b65b9cff6390b28ac2cc, 0x21aa865cd024cd02104da17621544418],
+    [0x6a969d2475762b9d21fdff8ada4a0bce, 0x0b048e05aefa0fb2c192ec55690d16ea],
+    [0x013c2361dc5671b98f13855c15732ae6, 0x1cfb664fac48b01372f86f107860398c],
+    [0xc8298fb4074ee140622e9cbdf39cb479, 0x1c63978d82984a8f5b105d597d65a437],
+    [0x85e61f33afcf5cd35986732216509c77, 0x068727ddec782a2d0c02028fca3757fa],
+    [0x1dbcb63ee7c6ce19a411e809b731a563, 0x0d890582cc215340d3476b50bd8d2226],
+    [0x11f22b25219ac6cadc08bd27444c59f5, 0x184cd8e67d3346b0b1ed513225df4f88],
+    [0x4a3ce266715ea85b990f01027910000f, 0x11aedcd490fc770af451bb54a698ddbd],
+    [0xb8d5f6604897b1cc751bc426ace0de83, 0x296da6e5e32c20aedc69a743f6a45e4b],
+    [0x60387158cacf5f08288954e11133f8bb, 0x0ba576970da392c54477158abd25968b],
+    [0xf88ddaae7dd50dbfab07019bc425f6df, 0x197e9d5129d713e382ae5ce666cd1276],
+    [0x1f8dfde4f6132e61b26933726cd7162e, 0x298529230c8efca126f2e5f127eb577c],
+    [0xf56387df2dcf210639c9d9623efb5041, 0x097ec32e1e10cb019fa5ba87a78e31b4],
+    [0x51b25960efdd13ae477852993d9743fb, 0x0a696ad199a61dffa5210f5a67ef7979],
+    [0x667893cd881bbd0adbb07306bddf4ed6, 0x20e69110246861565203f2b3fefffe68],
+    [0x0434475394ec120d77088043513a10b0, 0x27890f322bfd0fabc9682bf60620d1fc],
+    [0x5fc58b95d9dcbc6aae0fe50e5a3f0671, 0x19df61b7b8dbe8152c57a8d9524609ad],
+    [0x9ce23388fa7f48d0341e184beed4df69, 0x1f159a0092e8e13753d9a47082ccfd4e],
+    [0x89159576e54c7b1a177fea5a093bbfcb, 0x03ef8afb36ddbabdcefa9b43d1e5557e],
+    [0xf8616a32159842f55744cced8fb463b0, 0x1ccdffa3a8f918cd338b85146b057f87],
+    [0xc710f9dc7048a98063fbca9b0e9ee3a1, 0x10c6ce580770b491e04cf22221cece5e],
+    [0xf7265858a6a499924f961e4241d7ea1c, 0x155154344c44bbae48882ab681e1e402],
+    [0x92b1fc073f6b0e62afa8cc9161748c0a, 0x168f28d28a2797945e7b4e7b585dcab1],
+    [0xc5d0a96b5812547969b3f09073d33a6d, 0x25d99c080a836ecf0aa7e1299a757cf7],
+    [0xd05eee42d0bafbf3212c656c305ec55f, 0x0955ad1ab1074db1280c78753ea4343a],
+    [0x973eafcbfbd965cb2455ed3f2186f33c, 0x08d466d64b8949e4bd175392e4219922],
+    [0xc03c3a7323e7a25a4f8abfc14d492b68, 0x1349923426749433b7e0014e827b3540],
+    [0xe6465cb6238ef540189f5e69412dd9c9, 0x15792bbe2a6f84147f008f84b591b583],
+    [0x7b72ffd0602030b63b783c1e23923096, 0x061a685b4ed05b65843a41c24b236a2a],
+    [0x8fbeda393d95bd8e9cfbeaa19efeb642, 0x25d7f0dc3e0a9e71090c72b48b051b8d],
+    [0x135b2ac6cd82b0e8fbf7c814a9f38af8, 0x2301c0c319cfd5520bc7f972b6646661],
+    [0xdfa5c6d70bf2fd88e428ad1df73fb523, 0x2d6992f2df7b56bc9fa14e65d0070763],
+    [0x0d83ca8031014576baa10ecfd8411024, 0x01e6621a98c65ef7ebeb275f696d1111],
+    [0x3c342340a2855ae746a9aa6d7d0be2e8, 0x0690e58eda24665799a2a1566986f05b],
+    [0x1352850f32fc7b75647a537189e981e7, 0x282924ad2ab431d50d479c2d43940b47],
+    [0x71e693e14f75835e7ce3aafcf2099d72, 0x03983afede48f8107d3c43d8ef200500],
+    [0xc4b88c053ccf888161a702d9d08314d7, 0x2d3e2cf3dd17405faa9cabdac559cab5],
+    [0x15fb9cc105a6439859e8d09c6aae3b32, 0x0a4118cb11e35af09c80a762b665cfd9],
+    [0x10c0a9b6dd952090027f65aed87c2701, 0x1f4f2acbf7868a099c0cf30ecfcf2542],
+    [0x1b9640762704c9343c5c93ca26dbfe2f, 0x127456c9cf4b04c64ea3280f18b4ab9e],
+    [0x53713d431c32f26bb50f1a068d92b70e, 0x22f606187ade91dcd38f9cc7ffbcd517],
+    [0xd5aafda34f955f2fb5578c8eb6fad2e9, 0x231cc62f25f6169d570976385132724c],
+    [0xa922157d67dccdead1b2315d1f008cb2, 0x0fc97d0e65f7aeab397cafc36966abde],
+    [0x14bd2be58b9f9d556aa1b75511cc8b7a, 0x2a07cc4343ea1b38982820bc9f3d8df0],
+    [0x67f7f74e5c1a3af99d2b53b21f904527, 0x205612cbc125d00b4a26d5a2c748cb7b],
+    [0x197af3feb03e27e55ce1b6da8c7f8151, 0x113b94fa0bb37a7ce20e747694e19c4e],
+    [0x77001aa3c399b46cbe4b3d45584aecb6, 0x135f57c88890b109164b1f19934b0e30],
+    [0x5e5f5caa1dbef3d907c92625252e2702, 0x12fec523a77be075ac0c47862f2b18cc],
+    [0x6d65f5f76042fe46c8a028016c99163e, 0x24f864be35ef6057de078a85d1e96a6b],
+    [0x95578cfc4dac4f59ad26adff03b41df3, 0x0032a288d270185bc167bbc21bf43bd6],
+    [0x050b9b235d6074565c93938cf9b5e564, 0x012a7a229b6de329cf40d61de15118d6],
+    [0x6c0fdef2be8263e23b8603fe2004241a, 0x058b63645e3963bebdb6eb639106c4e1],
+    [0xf052fdd09033541683fc54fa7cecdfcd, 0x19a5af0c2b00b1da07b9d4b535bbbbdd],
+    [0x3d8b024c36eb2f29a03d0a6a69d472fa, 0x0a2512fff94ebd00571d056704d5cd4a],
+    [0xc88dfd6bbeca37d2c1e6acf28a00e5a5, 0x17775fe72d1785abe11b76379bdb3541],
+    [0x5ff85fcb9e5c10f642c41f906c8c2cb4, 0x144777351320b13697917dbba1c25231],
+    [0x31df897915b70b38009d083111edefb6, 0x0cce87088d369ea25cf20315db73239b],
+    [0x48882a091380583f3c14a5797c3ebfbd, 0x1c83604318f78c187a80c78b8e2ef66b],
+    [0xf0c233ea68fb74be5b8b801df4fe3972, 0x195b1051102ed693e26286faef407469],
+    [0x105c03116b254c20bd8a196285cf6ee3, 0x2454f53ba4ff1c09b2a650cdfcc32735],
+    [0x505ae33b0656425e2dd21a61045c4aa9, 0x02d0fe154a855bd683d4676c3607774c],
+    [0x38ac1eebf433993e27376bcdc6da398f, 0x1a9c1aa65d05e90763237b686a59f2d7],
+    [0x510b5fa976400221113df0adc2283e0a, 0x0380c6ffec35d91b268052e8575072da],
+    [0xc879a5e0e7a79b63a8af8e3c02359769, 0x2f4835b7c8938e6e3d5a8d853cda5e24],
+    [0xfdf45be249083c3f65762dadedb5dab7, 0x1278111dbbaa46ab94479485683e473e],
+    [0xfd975aa7cfa45ce9eb4b6799ff903538, 0x2f6d0e205916919cd7c5381ab9263e9b],
+    [0xf13aa0aafefb98a6c11305e7068f9a15, 0x0987b79561d0d24c5fcd6c6bf1189b13],
+    [0x6b8f1beff58f420feb1a0629938ea44a, 0x20a1f94ea39d9674beb0e237b74d9270],
+    [0x25dea00bfa55ff2ebd6b4b6a4bbcfa0d, 0x046624944f67281a6082d30e31a2d782],
+    [0xcae2baaec96f1c93b5718c46e32f4b5e, 0x0ec86df4e2404b4d4c5c1c7f2b7cf4ff],
+    [0x2af680f9175c4e95269ff265641a7c28, 0x17a988b62f34331bc23c5c66fe5555dc],
+    [0xd622067c7cac56890403c74408a1efc3, 0x2f933a4009c38406c103874cde91ec02],
+    [0x3dd52c23c8c513a8c85c21205adaf4e2, 0x29c60a6ee7e22c0b8d8a51cf87215d4e],
+    [0xe5124a1a92893a8f1b8b6dd17dd020d5, 0x1e2e3cdd712c6ac039aeb3d4dca4e92d],
+    [0x1d4f54691a9b90a2f48220fb26fb534e, 0x1cc0cf1b5a677bb56afa928d7084c9e3],
+    [0xd123b90dff98e0447c801007e6404865, 0x03f5f53cb34ca5b6476f61d4b4cc01e1],
+    [0x5b8c90dc4a915fa551f3ae56d50db503, 0x264f523407a076b04f72e26834ce8bd4],
+    [0xc0f7f6b3cfdb4bfdc763d0a1f28f370b, 0x1790319745f7932afc826f8c296f92b2],
+    [0x01c9037ba736c49806f1da48291041b5, 0x03099a72a9c353b329a1bc4baabb7845],
+    [0xe4caabcaf109eaf7af718ebacb090fe5, 0x1604aaa41bce072f44b6adbcc0757542],
+    [0xe7dca8f8808ccc248fdb5728a799db67, 0x10eeb59a16fa139267e3c45cef01b53c],
+    [0x5534f64c4461d162fcb3ba06715947e5, 0x12eab8cffcc06cef8d112bbe5dfba7e7],
+    [0x7dad2405d225a30b5a75da8ae19c23b0, 0x0c58e04653d88bba71e9f539b5030b93],
+    [0xf52e391b113985ca6a30eed588cba4f9, 0x1b157f2ed740cdbf798af092aae5833b],
+    [0x4ee4c2833bb9cd0da1aff2399da436e6, 0x0d0837d097cee0020345d038d3656de1],
+    [0x6ed22248763d190ed9dd0f3d35934154, 0x0a4225a5d367e0ce78a1255c9a69aa62],
+    [0xed7feca0b85058411d09a6e756ffe8e4, 0x1f6a2819a67e201a2576f0affcb09496],
+    [0xac2d4b6a1b4d06b96f75e31605c38cb3, 0x1c674a9b9fdb49fc966e064ba3da32c1],
+    [0xd9b846dba93db5eff2200acce3dc5312, 0x20844cd477a2921e8ecb26eec231d8c6],
+    [0xd9ea7d3d52125b9fd77f8d44cb729864, 0x0a8fa6b656ac797be797f42e294b3ac4],
+    [0x844a4b74de709fdf77156f4c338c8007, 0x265947fe0877c73e0c65af54f643b0f3],
+    [0x5414b960dc77a19e8d6e38c5bced7d48, 0x1f04e4663f2f9937cf07d3c2fe5f06e8],
+    [0xd63f6459055f85e1b8d65c257ad64046, 0x06d020f03d9dd918c985f1e7d5cd39fa],
+    [0x244d213df4344c3dcbce797969873951, 0x0f4fb8263bc813190c44c383d548741b],
+    [0xbff6bbec099c68d5755f096075c21025, 0x0e3b1e6adfa05450afba7cfbe8e8a08b],
+    [0xc6bcd53cb1224f3cccd425331fd6f831, 0x22fb7c00042e5e4ba4b512efb900224b],
+    [0x58ca19317505bd446ae80dda553ac71d, 0x19375dd909e5ec26e7274bfb8b402c5d],
+    [0x173a72dec287f6b19353c3bb540dd34e, 0x11d0952461edb9d24c6bb08afd369692],
+    [0x77889bfa122b5bc54b4be05e898702a8, 0x1da0872acf3b35eda099ccc9e9a5ff24],
+    [0x11740b9b9d99b6bef9f44cbf757b7376, 0x1a74a3b2de9a16c14c026b9c511152cc],
+    [0xc533cdebb44b095080064a93e6ceecc3, 0x221b345ba4b49dcbaad49fe51917d928],
+    [0x7980963143c3e275f29ec6386c9c2bdb, 0x0de529ef32404116fc0dd6041c8a9d3f],
+    [0xf59cf6940ba48757c860e4d5afe912bc, 0x1df03417e70bc8a58129a73f2bfed475],
+    [0x6e1681769b10291fa65fbc362c57f7c4, 0x01a6a6c5e41dd7ca162312935ffa4caa],
+    [0x83883973d0dfeb2d8584bb3941f7b92d, 0x26be4ab855efa2c395a0bad51338e242],
+    [0xdf52609360d366e0afce5913a809f932, 0x01f1c7d227986e5a9a329bac19a23fc7],
+    [0x10d40f57ce4c54569e0349bdde64f25f, 0x075852ae085011a3420eef6fc4d51c88],
+    [0x8b8ab0eb3e6a75aa3a7cdc2ea9510e18, 0x2e69ca1551e48a93009319aef40fccd7],
+    [0x94c1df4ed550a87505fd6889bbc70940, 0x21d1d8183e5430aeb1be773620504ba9],
+    [0x97b0d5eb88071c2785ac3a757ea5a973, 0x11866d8bbb8cf8fe585de19d816f4440],
+    [0xfd43c7d9b305f42e9b676ed7855086fe, 0x18eb3fd0f6793a01b4e4a35050555a79],
+    [0xb39726bf75a18a99892b2bb6dbb141ae, 0x2874e162935850eb2b1e7a99847dbe48],
+    [0x2c18b4e24968614daa909f3a664fc898, 0x043164e5c0d618af751f9458ad6107cc],
+    [0x33bc3c0e9d754db747659d9845285f15, 0x1c7fad75476d7966314daa926e188ac0],
+    [0x46c4911b3336bd15a3895e10d0a39378, 0x001cafa60986645969073ad8c0e29d8d],
+    [0xa8b49740ddbc4c7e709af251f7e5c002, 0x059b39a84beaab3bd16e649114d507ac],
+    [0x7c5368cc9d7ff7c427e3c97adceafaa5, 0x2ef7ed14755d43e71ea6eba6d478e419],
+    [0xf6ee10185e911e2991845730df2acd78, 0x07e262f43947c3e4380bed5989b35368],
+    [0xfd82929274f8ef4ba503096752083517, 0x0f54ef80d9664876e7b9e9cf8ce300b9],
+    [0xde0565e34ebaee7c9957696f92a3fae3, 0x17b19a0cd7597c0b95a9d1b1f3aa38fe],
+    [0x618ffd4a8c088fd51133b0be784417db, 0x23724db078fd410f494e5381886a2848],
+    [0x4d842a084fea42fcdb2d0a16dc4cb3a9, 0x087f6029159cf77cf228c9cf137d25fa],
+    [0x7312b7e80ffc2c70264ff06560f3f52d, 0x2c91b436e22203f5cdf3b1c218862600],
+    [0xc901d7074715b445b14fb64a55e43bce, 0x08b9793cf23fb5b0eebd2647d2bc7d4c],
+    [0xd361820633e27b91cf0505a8950b3bed, 0x214143f8421174ad70d3431efe9d40be],
+    [0x9130a2ffa28cd00b07286fe3a982214d, 0x1d5b88560517176724a85325f6279ebd],
+    [0x87028d866e2b991974e6e63b0f838fa4, 0x14c9577c063d4dfaabc8eed7c32b99a1],
+    [0xee89adae8c6e27b01f7792493074bb34, 0x046273375bf435bedde590cb2bac88ef],
+    [0x4f6b1e5ba5a2bfd6f2273d2d6bb6a81b, 0x1fd89f063226990387b017ebe985f55d],
+    [0xd730c1bcd151adecaa53302fc2ee7bfd, 0x0502081f3b1250555aef587cb5b4b307],
+    [0x6a92be51f475eb386f96c026772e1cb5, 0x10baa6c318c7e6a3a3628756ad42eca6],
+    [0x6a8949766636308b02c66ac9823edc65, 0x01dfe4501e8b2ef4184e1a4d0f0c9cc7],
+    [0xed25a09bc14754665e7fb5f28ea3fcbd, 0x1a72d320faeaf05e0cf44db930bdbd8e],
+    [0xd90f8aa53f455343339ed5b5320b0691, 0x266b33340a8bf66136e7999ba473455b],
+    [0x5703090216d607de4bfb678e1357b958, 0x02448e52e069c6b44402d59573822c3b],
+    [0x3d872ea7596647bf9517ac628b79a226, 0x2aa25e459065532b65ab2780b63cf6f0],
+    [0xab81e39bf3a15a9b1f479b7499b2efd7, 0x00b1d54e7898b63a3adafee6e3a36b2a],
+    [0x612c3cf4623a6260ddc931952512c4cf, 0x246f7f7d8e5ee80022d45348a5aedba8],
+    [0xfaa44551f00b83d24a9e33e01df07ba0, 0x027907ad369fc4b8372c78e6ab39d4a3],
+    [0x6efc62e0325327f8190c7ad9b09ea5e8, 0x2b67bd277bdc4c08c7908bb15ad39725],
+    [0x08b2017e8f8b1170459c2c767a7ee354, 0x08a5e948d1db145e20bf0abfd419e707],
+    [0x01755d50eb869ac50ebfb775b49599fd, 0x0491c93811381bbf080cddda20357bbd],
+    [0x6dd8bc8396f30c1e4b3d89884035946c, 0x0bbe5810c339505f191659c51feeac4d],
+    [0x57ef70e46e1742ef750ce63d22b24810, 0x03b065bd291e288a177f161e35c3652c],
+    [0x63c714fb2bc5f288970608ab353a6aed, 0x11f25b85db841ff7648ff0e35ac0b6c4],
+    [0x0aa310e763486126d0d36c29e68014ae, 0x2f14f75123210cf69d38f2f32b833cae],
+    [0x45aadbf16420d828e91f7d8ba5d6b6ab, 0x267179f1f050aa24d1777e348a99dace],
+    [0x6860767cc3aba88b35d47d95551a1283, 0x02d760a9ccd958f723e082c804dfc290],
+    [0x4695761da4647d330b180728d9db97c1, 0x042540133fc254876967d2739f101493],
+    [0x44cad1504c4e147115610c3affb06557, 0x00d4fcd586135326975e12057cde1932],
+    [0xd3aa04364fae60e3e714f684d05a8966, 0x27a2352f8c5f88f643d6dea7251b027c],
+    [0x1d0df603c5492f1d75747161c2112ecf, 0x1c7e955c7dea3de14499e1b00c64512b],
+    [0xa51c2bccef5b1c441d8301fdc7335956, 0x050e7bcbfd9fa9c1c4d29415b03c0d00],
+    [0x715c3c4ebe023abc608a8ff3704413cb, 0x1abb6a8eadef0940704d611503c9f295],
+    [0x7e8b51497f5796521999bf0e0d1e80a4, 0x0656203914995f78f4a1d5f2546387ef],
+    [0x0af565c71cbd0d3367efa7779eadecb5, 0x1a2a69c7673f072aeb2ecc5e750c81bd],
+    [0xbf3f51a4a4ace856d8f687f6845c8926, 0x1599622b5b0b75fc66ccd9ef20ec559b],
+    [0x5b1b87d888bf8db5c8cfb4b45eb03a22, 0x0eb54dfc23710746af102dc417a3645f],
+    [0xf72006ee92a36eb59f32cf304d889864, 0x19e1a6f91e97deb1adf0d25cbaab09a1],
+    [0xacf146c6b32f85beb35149479c891d4a, 0x201081529413a6f1f49f3d52dcddfc74],
+    [0x50440c935bd34a4a17f2a5bbff287f1b, 0x20c393584e2857f57c23efeb7820186a],
+    [0x0e7c2fc9bfdbe500a0a512f6be936934, 0x179cf0461c98a99f70c433f74f60fba5],
+    [0x4d6e91c83be4cec3da085bf250c18d81, 0x138cb76d3780d42942bfe0043d625bdd],
+    [0x29b1e45ac6aab7d37297decf2063fdce, 0x0e70444af01939c62a8ca5ff3ddce46e],
+    [0x513c126a168488c376c21adb62827961, 0x142b10f58204bef4cb15113136fee579],
+    [0xeee11a4eb711e11fae7700ebc6387fb0, 0x124c8c607612b5fbd2af84ac37bee103],
+    [0x557c452fa5f76b542426b9fbc94f7446, 0x143fde70dd6bde8b26a0859cda512daf],
+    [0x01cb403a2d386d58ec733736cc21692c, 0x24694de53a139a6435ecf716b4139ebd],
+    [0xe3a6352cb647d47346014bc486f9c0f2, 0x168618466211f05e88a1b771ac8959fc],
+    [0x2d4a8e7bdca43448ab05a7275e74e43e, 0x1b9be7105266245e707a46e2ba3de832],
+    [0x9ae76d7f069f98f8fcf8f367c0fb3b88, 0x0fc2c8e1f54e166049d5ff37f6b5fad7],
+    [0xcee720690bd349e645f0d8f59684ac48, 0x0c6f5833230640ce8e70af4dca674821],
+    [0x519c9b9976aefa3514c0b065a052e340, 0x24560e4af49ff08a2226a7f095e44890],
+    [0x1d314add6a79d553c642dcee363ea047, 0x0d61f061762684231c5f469ee0ac33f4],
+    [0x7cdbe086d98c94cb1d003a59265d40de, 0x1adfa57f4ec443e497de3046f64d189a],
+    [0x5e50971f7dcb7738629505532272651a, 0x09fc22885ab01975ba9aa4c8692a04d7],
+    [0xbc95e1d36160e286f5a373032cbb49f8, 0x1c01bf8f85c8bcc035cce929aeae20e5],
+    [0x210db37af2e9c036c47978c9842ae7a9, 0x2dfbcc48d7241a6674803aa64253bcb3],
+    [0x9aca88a180b31be985b85fa89d0abd49, 0x27b945d4398db7c68d13576a8928aac9],
+    [0x7f9e689940d9db0fdbe79c1a154a7f89, 0x277572bf0b290106380c4bf58959da21],
+    [0x7d04361f9af65ced7f0329e7deb715a1, 0x0831d1b88af8561c0eee6964bd720c37],
+    [0x9de7c8619ff83a8d32a566c2b15336a4, 0x119459ca339e5c8a1b36593a8a091bbe],
+    [0xd2553ae84632a8dde85774f86a714f94, 0x27e8489bc8ced6a9f209bfe83f6e664f],
+    [0xeb0542c05b9bd84aaf4ae89328d6f53d, 0x10fb6c8fab6b135d7a50c54f8b1fb574],
+    [0x12d352f08aadd1d4a14b9fa9b2ac057a, 0x1dad3864816666ca3a5d1fcb0dff07bf],
+    [0x48d54c398cdfe14eecb7ed7d9e09642f, 0x2439afd2542a9c564b85fa68fec6f9fd],
+    [0x53b829dc245970082770a03546636a4d, 0x2bea4058794d8bd725705e650875da21],
+    [0x9b8383e3df3c13af7e57365b48d985dc, 0x292712ecd08d349bc03fe610ec2a0e7d],
+    [0x7029ca1b36d3eb348a0b1a0b64c43530, 0x241c1f77219d178d4d61f50838a69b6d],
+    [0x2fd647a7679689972cf02c2863db6e00, 0x21919178d0ca9874c90f875407e6de74],
+    [0x822cd8a0637c9096efe2e9aa3d2c7b53, 0x303d796d52867e994221af51b2120ff3],
+    [0xbaddaf1357f7d81b79f227314e9aa843, 0x1f0f30243212eb4bec6bf9e8b794989a],
+    [0x6be9a8b6db13a25889218223f850de7a, 0x0c12581aed963a169ff1c33716c8bafc],
+    [0x7bd998547979253ecdcbccaa3f54007c, 0x11459ddfc0f592b264269706501c04ce],
+    [0xa1dd84331a8d39ea02961f938b5efa1a, 0x0f2b37146a0a04ac53e7b630871db252],
+    [0xeb551d6b21f65f362f08208eafbe4d3d, 0x21efcae659790676b5d9114b81d60490],
+    [0x5b9db9b842272100245dbbe7c876184d, 0x1e1b56c6f76e3cc26284b04a42b5a02c],
+    [0xb9216f1d0b474a781c85e8ed1478eca6, 0x1087be4d0c47bcf20a815f60ce803d5f],
+    [0x5a1298a2592dbf3f9b698e4a791d9311, 0x20c7a2c101e34270fc18b4f05d2b2f5a],
+    [0x5ae906a11cf011e261faf48734a3b07e, 0x2d4886f200b99c57f4f09c0c362fd353],
+    [0xe464024fc2936097215c05ee14924f5c, 0x2de6da1551b5f1d178de4dd889d03aee],
+    [0xe1b86559d71c55a13b4a92aba2187aa4, 0x00d90348bd745e5cd779a6a6b5c4b625],
+    [0x50ab36b4433a7e61b815b6101a5bb703, 0x141c3084044d2bcfe9594da6bf8175b9],
+    [0x83b507553a06ca6b37a3872000412e28, 0x067c3241799e3c89402d1048ba300302],
+    [0xc215660dad8d446b0f597edc42a1fe07, 0x148f39fd35a56bc7d20ba1c8922e4ad5],
+    [0x3fffc6b00fbe475a2e0222a63c68c54f, 0x1dd1fd608be4764ee1dce05067899de5],
+    [0x9a1d3fe589e5848bbbc5f5a5d77beef4, 0x15bb0f86dd0a37802b115a81d4e3f68f],
+    [0x63a7df70c86adaad87dfab06493ec72b, 0x2642fdf5df1faf7aa6a2f37b33a665f1],
+    [0xfd95381e78c88bb1d3f347dc7dc543aa, 0x25c726bb20a1ba08c73808e00f829bd3],
+    [0x4f4d0e56cb496cd10f5de368966b867b, 0x1ca5808c3d68b51afc586ed44f44189a],
+    [0x7e75d8556bfd8f241a11fd903af4fa87, 0x1cdaaea17a2d78385a56e99849637edd],
+    [0x3256a0723ec161b987cef062877948af, 0x255b06d48523f97d2d30bdcae93ce980],
+    [0x2f61da413743c4a79e67ab6c32dbafc6, 0x1cb3ff20f743a845c435fd3e2d10e931],
+    [0x54455fc2481d31c3548d1e725488651e, 0x047a32238532a7a734ddabb15024b8a1],
+    [0x0a5ab490a4abeb30fce348e54dcc4e78, 0x0995b210131910f01df8976ae742e95e],
+    [0xe635099bcfddc1ee41def1d86adadc98, 0x1bf16ee530c9c94a408f2ffd49ace7dd],
+    [0xa1c6b7ea9a04928533266275fa6f521f, 0x13107a5ea2a3406acdb6563a5e3f0809],
+    [0x3eebe766a192d130a6c737fc9ce3db39, 0x2d562cc0650028df6e30c07bdbec07f7],
+    [0x22f8c43a2fce8642893698df53c23529, 0x2efd12b5e4fa102fbd60bedc120b1dc0],
+    [0xa804bcbc762bfeb6c25c810ced552162, 0x110098c916b523211e18b985f8fcc7e8],
+    [0xcd60a708291f5c86ab84da22ca596ad3, 0x20a9d5216caac17db5ab45fcb6b45dfe],
+    [0x594be82f488d66f91b43af660dcf38b6, 0x048c825409094d056259dd3ca7e15a08],
+    [0x6ac36a7dd4f70e47e188bf7b410ded20, 0x1984479ffca6b64a3b950372747c1b92],
+    [0x0535b552c11d5fdacc80305fd825c8f8, 0x209cec7ee37d47a8a56f8484b94f5db2],
+    [0xbeb7edcfd6a19c48cde742e99cd49308, 0x2da9a692b08915b7b61930f011f76b09],
+    [0xb0151a43e3982ef5e71a38c74c36ef59, 0x302f50ece4afa17d552370a4e12c25a3],
+    [0xf4985073b9a732153ab69c929470283e, 0x1b46e081e756c42c783153f73a64054f],
+    [0x1a2d3927c65d70b72391b062df7cb4f4, 0x15103e3988d0baf626bf9de54d75b030],
+    [0x57ca1de688b1de481e7c0726376aeed2, 0x1564e70062ed6c3b3ff272dea80ff3dc],
+    [0x0cf807c6c10260c85ce04ac3d41efc5e, 0x1e545f388b9e05a46dee5160c8706da8],
+    [0xe50e72bf58f54ea91eca40df80173dc6, 0x088cd407f0657fc8b6e8acf3ee2b0473],
+    [0x0043e1c1d537d06beed997e0b52abcc5, 0x08b8e42fa1c4104467d67c7a3e384a3c],
+    [0x8d37bdec65c7b4c33133dce2b1990846, 0x14f1b3f9e72031ba74e2e032804da786],
+    [0x20b10d3ff5c20fb67a66badabeef018a, 0x1619c0ecca142ed142f88a2fe0b03812],
+    [0xa4e8d3182bef22175d29542796c99925, 0x10f620a862c7ec986c3ee7e92ee431ca],
+    [0xe01bdc083c03fe6ef89842cb81f57936, 0x2f2f73f3953af6ecea86f5ef29075fc4],
+    [0x50e699c10ae783b8efec04f6d0e4d40f, 0x124ae93fdb1b32754036196428519c83],
+    [0x5e18d60b41f5794117c800ea9564f579, 0x23bba8fd9c1d888cc9f3268fc54eefdf],
+    [0x8efe474fb511f46955ad3145341e0d33, 0x1da248f4d90e0056b683e2a892876fdd],
+    [0xa6aa07d09323f994339b43bcd98ee670, 0x2fd8234511e54966d00f3ea4a65dfcf0],
+    [0x737dfe249efbff2d05f6cc285b4adbbf, 0x1cf83450d23c5fd64ea5ed6003ed6503],
+    [0x6590f9d2de022298c0350636586dd7b0, 0x0730028703b63880fa47aa0b948a2a95],
+    [0x64985ff1e175d8828f4e0252e908110d, 0x2aee39a51669c14763094fb8c42d6490],
+    [0x69b942f9e8029734dc9a5f450b0a40bc, 0x236a48fec7f4871e2a0fc6305b4ed351],
+    [0x79b470c1d54a5eb1dedad874a4a77acb, 0x26917d74db7165800ebb0bb05afd8cdc],
+    [0x7021985b2c6c1967dcd8c3a4198a38aa, 0x217279c9d9ad568bfde19952276e8a75],
+    [0x24b8fb974dd9d8b0319c7cf6d3491e12, 0x1f84a26602f83970a38afdfa335e740e],
+    [0x954a2ad824285e563b67d2838e5dc265, 0x1429fc9a53a6937f151562283326d9f9],
+    [0x50232ecd10dbbc01e9a0d52f81bbb7a3, 0x253264e5b4725993807f799d6b066d38],
+    [0x03252b489120d0a74ee2aa203b587f23, 0x1f193de050e1e6fc55939e38c86903ea],
+    [0xeb6d5adc36917fa51649537396bdf99a, 0x0b179e9fcf3598400ce988278421f7a5],
+    [0x0801c608c1a53b503eba6153819f9016, 0x0dd3cfd2e74ac6443f1089c4c0884a89],
+    [0xfafbc4fee14c34a8343eb03d1c1978ce, 0x11e4f43e73c18df506683d89a511b493],
+    [0x78c9b542bb59d79461a4c25493571eec, 0x07e9613f5cf6551a97ecdcc24d8810e3],
+    [0x0b86c806c33e60f4cdf06789cd0241c3, 0x08f250aabaf2b7bb58a09c6cd958e13c],
+    [0x57ae1ead3508ea994c31530e29c5a242, 0x1076a7855f3b7d1ecd5bdc7c0860391c],
+    [0xd00e8a836b8a2f94e165149005a2204b, 0x1b57606bfd71ab8e3b2ea9cd32a78569],
+    [0x0796b85bd3bded82bcc2b8e6d4a61c5f, 0x16a0fec8e2bd755596396f1d1cfb7921],
+    [0x43c10dd40c2ac6471598c4ad136889ac, 0x2fa939b78fd3589160bb09b35aedddeb],
+    [0x7e2e593c4cbf7474fc20e5154227d91e, 0x2fec4aa75563f7b8ce369ac2d239486a],
+    [0x5343f54c62977e28f6be301418d8c3de, 0x0e1158e63c9b7df32d7c3f32a97e8e41],
+    [0x8a9bcb55bf5835335580be1eaab2fee4, 0x0e11893c32e63b42a74bed81dd71e634],
+    [0xde7bbd330ca940d71d5ccdb7332452d0, 0x0fc0701b9435b305b610c7fe0c667b33],
+    [0xb908470fb803fd004d434ae664765fc4, 0x0fee4aa70a97580a7395d67a37f55330],
+    [0x600d2a303dd8dac971b7b920714ee131, 0x146742f4531fa49c48480406498c6541],
+    [0xc871849e7475e7f9c0890b907f9f6bd7, 0x26f145dc528340cb9335cbb6fb1e70d0],
+    [0x3d33026535f1b2b7c03ab075b681cfb9, 0x0e307a3d20bd16381e2cb9d0b802046f],
+    [0xdce4dd04dc0e938162d2c3a541394e5c, 0x037518e3a0fd47c9a1e33a918c30fe9f],
+    [0x49386a813c60718db08ca3e8aae680b7, 0x0520c4f6bdc375ba8ef5e4f4d55ee739],
+    [0xb4399107600c5f6664b145b7eb8c9a2f, 0x1a5ee091b74dcc7c3962590d3042486b],
+    [0xf0b6626d4930abce17b1a64dae036314, 0x1986256424084fb797529cfab403b2cb],
+    [0x4b7405276f483dc98319047b951a156e, 0x03867c71d3cfa8039ab6407ab46053ab],
+    [0x65b8a0a9de14e2b052039700bdba23fb, 0x06d563050f5097774e456405b8c40d1f],
+    [0xf85de6513d50d09c2e337d22eb11c20b, 0x10767e56882be4809328a58d747c6d3f],
+    [0x9b6cd893ebfb903a2a41f28ff0b56332, 0x1017176674f93d48207e239674d99ee3],
+    [0xea26e51a83313e4c1649cce22366b3cf, 0x0ee7e6ce359254247932f4e03d3113c2],
+    [0x544cb04228b8b17fde68bf54a791d63d, 0x26ff82c65be4374fccd75b3238534dfa],
+    [0x0fb840c5ac75084146d4ba1a6a6fd617, 0x07e88a6fce601c48d5cd2ec5945e2408],
+    [0xada28a5de79ea5fba1b6a7d13550b85d, 0x129b8c69e8518bd16636ab0f1a3d7e39],
+    [0x49d5c3d3fee8947032c40069aeeff68a, 0x0d794f086bc4e45eea6337cd1e653910],
+    [0xf6f909488cf516056bd81dafcfd60a07, 0x2622b4fa8fb07226532b779e904eea44],
+    [0x3f4c63f8f51ef9d0948ea2595f158a26, 0x25630c8d104693e7a5fe41ae65db35fd],
+    [0x8de2a543847a619b6a6dcf7c64711b5a, 0x29023fc4f33c9028cdfa300500337f66],
+    [0xbabb34c45b24b7097c841bb3cbdab46d, 0x2c05672e9a763ea209baa0f7c31ec56e],
+    [0xd6b6ba00ac262ee58ff6220167ba201f, 0x1f501c00c1ba8aaed173524be2f2b217],
+    [0x321b81fae29be0d71d777d49c4f4bb8d, 0x19ba085b36795a55dbf040e54c356e70],
+    [0xda8605a40f7559216fb86482fe69022d, 0x19b51d2fe788314270e14d1bc248c090],
+    [0x782f9b07a41dbe14629bab713c229635, 0x135565fdd6d3e651191d7ed1ab010433],
+    [0x7b09835c534a81ab365148e148fd1327, 0x22461ed7ba44596bf3a7402dcaed5c24],
+    [0xac77e8d7877666a85ac3da15805cdf8a, 0x0e09c39935650773975a411bc093718d],
+    [0xb27686c6b0ff2e4948aae4ee6cb5077e, 0x084ab85314402a0b68cd3021e1ae6790],
+    [0x6bbddb9d31843a9dafbc2936fc8cbf69, 0x143b5de83814814bc1553bc7ec9bc475],
+    [0x7580b0237cdb08135baeee610a460522, 0x2a9b9ce26f0b8721be06da85ee147472],
+    [0x189b7e06fe8d49ebf452ca4627a387d7, 0x0aab3ea72f43d0064b776a4746ea6e2b],
+    [0x228ad832454abefca598c2318a3ec653, 0x1656ffa30c037ac4573b8a0726888d99],
+    [0xb758b0c9178ceb7ff3fa681da5c9bcdd, 0x014a2aa2a598b2e2370d14210dab5c5e],
+    [0x6efa0da96c687b5dc125f681850567eb, 0x153c93de24e01c07ccd75236e4ec929b],
+    [0x8b7f4f7b20096f78020e948778a90f55, 0x141707bdc7698398e59f4ad04ec44fab],
+    [0xf32785bd91cc918589dae4212af7b979, 0x021381259d9cd18a837dc34fb95017a3],
+    [0x7948b0fdeb19e723b2bfa1292d97547f, 0x1d5d10c13222b1716d7a1def6b384d72],
+    [0x3e24b30a6c4660acc0b5290d30a0da80, 0x136ff9edb9c0568cd585b259aaa562ce],
+    [0x8f16cdd8cea44c14de0432e6e2881464, 0x2f3ab4418bb0cb7b859675b9ffce8c48],
+    [0x311b05d020ca878718dbf561db8bea39, 0x2f03727d0894a74dc97e60cbfe74c7c7],
+    [0xfb0a5e1076285b24357f229128fe25ad, 0x04ddf88beb916a6628e60a7da981781d],
+    [0x4427e7bcc576dfa5c15bc4fc9fed263b, 0x0818519ea2b71fbec6c6bfff87c978af],
+    [0xdce99231c0f427e8629ac905234e9eb5, 0x1154d1aa344585c4ecf9ee9390c84668],
+    [0x160c37afa477b3829afd49d6a49fcb00, 0x0304298c6b5e2144ae9a508cd4c9f4c8],
+    [0x8bed24df36d694a7a533147548ad0234, 0x13650a58dca9fdae806e76454c94a219],
+    [0x68e217516d1c54db6ff73582bf4d3263, 0x07ccf19c8b8714b18d280a4c0ca0e8cc],
+    [0xabe281be29b3246a50feded647083d32, 0x1b28476e34df146b4f9d530b6f59cd03],
+    [0xbeb3fa30d876bd5025ecf0701257b73f, 0x1c58347a64fd5be62d478ca9d8fe383a],
+    [0x21915092504126a6ff3b684383f9eaae, 0x1cc9002d4119596aff974310da5bc627],
+    [0xb8b0488487d65822895a5267b35a5772, 0x13d99de748b4fed4476bb2d6e9a4ec72],
+    [0xc494839e8c384f090605a94e7b396da8, 0x21a780abb2efafce61f4d67da7b8bdfa],
+    [0x870a0d3f8de4fd8b8c965cdaf6571886, 0x0d9dc88bf3861f705b9aaf1f82df9d53],
+    [0x14316ae520ab12fe61381c0d2d4a0469, 0x078f3af2d13ebf855e840a8c858a3f2f],
+    [0x52f22c8b438f00c32ac888d00c99713b, 0x062f5b577804a423ff3a473952caf7f0],
+    [0x148f2e962475f241ed67051bec38bc7b, 0x25a8c33d7adb7ddd6a19dd8307a935d9],
+    [0x09ac73b8910df407b0343b9dd03fb56c, 0x0431652b03c94333a0ed76c36f1968d5],
+    [0x9b50289f9095e7224a81d5f2d39987c0, 0x22123ad39910862a3a25397329e3dc14],
+    [0xab3be01674de449b15349a75cb937b3f, 0x2b43334743fc38c1bdcc7cc7d36ce441],
+    [0x78a69fd1e244195e2ddaeaef5ca10dd0, 0x2d0a0bc92e109c5fd70afbdb871bcec1],
+    [0xaf72e509ad6d21f6af1a267d2fefc6b3, 0x299db5f9e49f4aceb760a4aac36abc37],
+    [0x8ebd5fda5b1365a54f793ac2bd4dc0f8, 0x0c813f6de081bf25808656d58811a88b],
+    [0x9871c6d5b615d14da978d104132fa938, 0x0e32673a68ba96aa959b25e655b9422d],
+    [0x91cc590a0a3fcf2463dcef92b5edc389, 0x1a9e9dc3dec2e712da952fb4ce7f64e9],
+    [0x9e09c46769d7028b9d68e979abf59442, 0x159ae72e80b1b65f43b5074ddc664237],
+    [0xde36046df216e254dda9c043b34d076b, 0x2479b211ad9e81f8180fa2d44d0675f8],
+    [0xe5373763329dd904c5199ea19e3547dd, 0x062f8b312f39bb2e5f11a786ea6bb051],
+    [0x70acb2e415fb343cddcfccb77906da55, 0x22963c036561dcec8e1dcfed6ce50a72],
+    [0xcfc8b94b64e0c776442fb1f189ad23b4, 0x04440fb323547f46c673d0de5a4fb198],
+    [0x56e10e90904c29a5a09654d56cc39f88, 0x2bd6e08f96c7801d3ec73c04ec706e3a],
+    [0x3ce78e2d63a2c1d6deb6a15f7eef493b, 0x0531d0d6dca62ed2cb479cc249020847],
+    [0x0af6af8a7d4e99928942b6a687340edc, 0x1611c38d07cbce2b51577b64073338f5],
+    [0x9a19c5527e9feb4165e4f711d04d84af, 0x148604fd234d2f542477c7a0c73a59fd],
+    [0x3bcafad4adbf57fb621f8ffe3eec8e02, 0x0ffb01eb4752741b05b51b6331c2b1a0],
+    [0x59721d7dc9ebaeb1c9de2450bc7d2199, 0x14af647ed36be4e16593f8a813353004],
+    [0x8f14e2b6ee75d59640fd62f0606d6c98, 0x011e2a2bf16b4b70d91546fe3e974151],
+    [0xe533730d927809879de0d023726fe4f4, 0x1fc2fdde6c5ac257f4052f0120335407],
+    [0x7c317ba55b7909b5fbaa4adf56a9a075, 0x049e892045b8d924d757eecef6660298],
+    [0xa66332b5869a9bf4928124e170eaa6c1, 0x018b84c59e8b5bdc9e4c55ae91457be4],
+    [0x8751cd755ad31a3a75b7f52420208b1d, 0x263c7be5644463e12efe717157993fd5],
+    [0xb0eaab0ad0cec96c9bba0e6a2e459af7, 0x0cb27cd239ea5d6f988acfb4b7de9a95],
+    [0x643914df478aec0c457f62e8d1550027, 0x03634831e8b0e73bb4578749a7875f57],
+    [0x7bc35b362e1047386378d85c24d26a4b, 0x221ca9a31e8a11788017591eb067b1f8],
+    [0x27156b82ab4536120ab6548dc99adefa, 0x08d5dccfd2d2164c0086fbdab916a755],
+    [0x5781564361adeea254850458ad482560, 0x1ea22f826469f0434f9dfe6749427fd1],
+    [0xf26b0b1690e682ecaa548867fd161a27, 0x16259979de9e13b79aaac6ab8a02ec0e],
+    [0xa8cea208b0162c82b57f9d07a5b6bcdd, 0x25616133ee91e772b020d4c4c5953c05],
+    [0x72422cdd68f9372759c3a31b514d8704, 0x109451ff7ee8fbfd9563b8c2e7df834f],
+    [0xf3b650a9ca00d0b52b6120da4bd97885, 0x29e9eb61d0ce33c89954b0530ba55771],
+    [0xb2119fdf226bbcc4d56f9337ee940df8, 0x176b236b779aa963de6e283bc06f4d40],
+    [0xdac366e8534a33b62d0ec919855520c3, 0x16a033d6c26609b062f9c369b06ebe1e],
+    [0x62507ecad62e8947658f466f2acbbbc2, 0x27cd66a32dd969ba9fdb038201742c80],
+    [0x7f302ef61751a6a84946c997e634375d, 0x0f90038779e72e988033ffcb1fecab08],
+    [0xb5b805c7573d7708e2c68e4bdde851fc, 0x205f70b96d6e7fed7449ff0f70be698c],
+    [0xa95d463d1a589826f993bf60d8670061, 0x1ba89c27c091cf85f997ba0e3dfbb84b],
+    [0x58db9efaae71b5bd3ee0a3de9a9f9eec, 0x182ea2d905630567136f83ae671ac631],
+    [0x06b2f0967bbfff02c849c5272a100481, 0x06509a885fe116b967ad2cc9999d4421],
+    [0x04c02dafe42a6cc83205c0c1653aa855, 0x02283f7d31bc75e8ec3c8f1db4cd383c],
+    [0xd8928714641e72b41b74febff037d4ec, 0x17faf24d23b240bc3a43066d218db8a1],
+    [0xf593dfc50e14b8b834ab893431a2a620, 0x0a03a9fc7aa508da326142e62c7d4818],
+    [0x971594f3e161f62f4129e0b72a7a9407, 0x071cdad090dd06cea45044f25a6adb52],
+    [0x3451d6e99de165a29775f1e056bae3b4, 0x11f176589b8244f55560096a18423ec4],
+    [0xae0e9a4102a6bbf893178bee26a53d08, 0x259f50c13a176f8513da7744fc31afec],
+    [0xf6f926d8d606bbe66c2bc7c051ea538a, 0x268070c17f66e82a1219f144e97ac1a9],
+    [0x3b701931b7f73751d5e86a48f0aef56c, 0x2feb1ea6480d6caaad0a25a3b660d016],
+    [0x579ed784647b378772b4b66cc4c189f9, 0x267a8004c0a4a65b4ac33b8658b1b683],
+    [0x7a0010f771e909dc38cb40117e401308, 0x25d67a760f2a17a9435e5ba4d7486bce],
+    [0x16c8d16dac6dfbae73c7e75189f20d11, 0x1e14c2d3209d43e73f72524efda3960e],
+    [0xa9b42ab1001f9eb89cdc83a32656b448, 0x1c12410d09b3364340ac571b40bc1d74],
+    [0xd1e4f443664e6981df1b4232a9916f55, 0x2797b79c67a48c4e5210e93402f7f739],
+    [0xe02c6713fdc53ee530111131afd2dca3, 0x2f6fee780ac5a58cdafb653ff250ac96],
+    [0x1acd69fc941ce2e126c3ac394ca5b1a3, 0x0096d875d77642579ca91708c5bccef6],
+    [0xc44e5c201475ab083daaa270e43599a0, 0x09c918b1f1284e123af4a4279598dae5],
+    [0x17c35be75d444e54288a3cdc85b8ed19, 0x14c0b0619695bf075a237947bc425adf],
+    [0x0627ec26e4fba33bd8d60979be27cb74, 0x26d8f29cc41aac3e0e39e354547c53b3],
+    [0x3e4c1791ef011148cb2196901dec70cf, 0x09d9f04394b4b2f5ba5c2e4f83e4f0f6],
+    [0xa9a2c403261fa2201b4fe6ba10dcdcb0, 0x1704c408583e92deb63655c36b90cba6],
+    [0x18b35cfe62e136472e08616938ff0ec7, 0x030702b64d0a76cb0c9e651eb5792637],
+    [0x25cbce4eb5e2fefec5cf68d90383297f, 0x19decf83dece35005309897a3eb81d5e],
+    [0x108c0d5376cf97944ca1074964f7ea95, 0x038c625f54e4d2ec49c3811c37063a1d],
+    [0x410b1e1dd1ed0e282ae484d6b94c33ea, 0x0cd726d0859a55c8063056d8356ad29b],
+    [0x6aeefcc96b4ac50e7f430d980b47fbd2, 0x0e9a0db14a18282d439947ef7412fe1e],
+    [0xde7a0357a1be28359c1518d07d5cf985, 0x1d3b00e67012591f3fe5bddc1eb36574],
+    [0x9a0d44c037d96612e99b82eef3dbd87c, 0x09745ec5d24cf1837696f5a867c3c612],
+    [0x4adfc8513b37b17fb2a46f2b09bf6b1b, 0x2dd5e2ea41588db7b01cc61152885011],
+    [0x925360cbcab20c3ddecf83dbaa7e4c97, 0x0b3dad731b91ed4c2aeeb10410c51a6e],
+    [0x56a9904ee051c84b0ee1fec9798cfb42, 0x049998b294f8aebbbf2fbbec695e4d2d],
+];
+
+/// MDS matrix for width 12, row by row.
+const MDS_12: [[u128; 2]; 144] = [
+    [0x64386ad905e240575fca86539b272dc6, 0x2cb13179d6e8a4ad1b9fec37376253cf],
+    [0xe5073f4484f34be951ee16a276fdeb9a, 0x0fd547840f3a30a533a994d1fdb3f60a],
+    [0x963f56ce327fcf78451fba2bdb3ac1c6, 0x05019671bda026670c7ffa9081795105],
+    [0x5f173a9097db404db765ab02906b27dc, 0x2952c18c15558f29b2ae77fc39e51a39],
+    [0xad07380654d4792378daf752e5cc33fa, 0x2353a60af6bd61e5eac2c9a4263d451f],
+    [0xa5e3f4c0f61cf32d3157ad3e3b86f6bd, 0x0ed6a3bd48174e370b4f264b37488b03],
+    [0x12c6100c5d88085cc2e42a52302f4483, 0x21103a7aa20c8d15501be8c23504c6a9],
+    [0x2884451e677b0d1cd0fb4dc4c27536b7, 0x2fb232573e85d68c536d100c3a6ea917],
+    [0xe11e1d17409abaa34fc45de29b541535, 0x302a085be040ff9a709ad0c73238b0d1],
+    [0xebf7e292e63fa84cf0a883c2f1faa8ba, 0x0683e640e6154ef73c3cd6b1d0f0fd86],
+    [0x5e014b28f23a07ac9fc7077144ac3979, 0x1f09f3fb07f7f6476fac98946b737509],
+    [0x191d52608955b583e5826ebebd1857b5, 0x0598c38d2e2c78dfe4907c74e82325cb],
+    [0x211f1b12d91b0562ed3d63285d7a714a, 0x1f930557a284998225f5f4e4dfbcf5ac],
+    [0x4f1e8623d0a4f6142cca1f19a4c275fb, 0x04c4061820cda9bf472df3f64d6afa00],
+    [0xc263755dfcb38b27ef4f880e202cb5fa, 0x0dbc22c3e54c924a882c22000951239c],
+    [0xab35ffc975edfb43c53ed07dbf0d9c76, 0x2fbb1c59e9e8999d91c40f4d44233122],
+    [0x3bbecec3b2b3a63f27028f2ca8a71251, 0x0b8f40d2c4d28c472085203a0e460af7],
+    [0x595052f782e46857d81229e64e6bda02, 0x028c01a9f01601bb46417ed466be6008],
+    [0xd54a24ee6cabe4fc7f814dfbf26f85ea, 0x23ce8e402939cccf36176a5bd83183a0],
+    [0xfe26243702bd3d57e71f7ce0d470a40f, 0x0db82abbcd3a1b56f7fc8d116cb54c54],
+    [0x62d85b671656d61c2cdee3a72390213f, 0x2e703ea9af99161acf9949da7afb18d4],
+    [0xdd78f8d0cde53a66bc90206c82c4b597, 0x16512c150c1d2fb7235efd2d4f0fad5f],
+    [0x01d98a75e822ef553b1761c58d6fdf32, 0x0298aa5d5a44565b75ef68df902d791d],
+    [0xdaeab2aefca31bc04a476065697cabf8, 0x203fd2078ab2a8f88b7606543145ee34],
+    [0x28b8b710aa85fe729f9ea73a3ef0099d, 0x018e8222f6907da5745eeb9cd4a9e62f],
+    [0x8106f6b656b45e7636181e113a2cdf32, 0x107757eb0c0799f781308d83d2e057ef],
+    [0x6039512dc9c8e1196891eb77f856b608, 0x0966a21b9615af2b7563dfe41b8b9fc0],
+    [0xeba1b5272a77bba9cfddb56afa53c82d, 0x082046081498eee63c287c6fa3efcdef],
+    [0xec975acc3e3eddb77c831a509af59196, 0x2c7ecbf14b2d6049a6439d0f29f10e26],
+    [0x5aef046d0a1f2cb5f36191ab04eae4ed, 0x144022e7e077ff22a2ce3dfe9923107d],
+    [0x8edc009f46b00693ce3d9c5800fadd0a, 0x170403d414148f4681b8213550951e23],
+    [0x720ecd4ca3e42c3ab8d088611880861a, 0x2997d92a5e9bc66c84f635e8cbf080eb],
+    [0x271d72c18b0261be68cca44865dece11, 0x21427b3a9a2cdf1e2e6c628906b21e24],
+    [0x946d78ba6cb26d227e615131f71d337d, 0x24bba58086a78541a8ebfd535c107c36],
+    [0x283d737c18eb06e090b6fd2dc88e0a14, 0x01fd5a6eba52e9ea1db389a1fc8dd3d0],
+    [0xec8e9cd7aee6d6ceacc1a6f521aae4a4, 0x1276442d9383c52d50fc9b5ac34442a4],
+    [0xe38acf9df4957432653f370f905b8b07, 0x180e924d757d0be4066feb146ed56212],
+    [0x746b55bb974694a4e77b9a19abb55001, 0x2acd39e3892ac429ed812863efa05eda],
+    [0x700269c7df4d98ce4851f90abcbb3631, 0x17b18621dbd61df085da4cf8a03395e3],
+    [0x04abd652468de5a85b5d5cbfd9c23e6e, 0x02a429c2d06dcf34d34730c3af8bc19b],
+    [0xea27fffba704202d5286d62bd0eed372, 0x197dfb64189c09aaef94a1f542d8c34a],
+    [0xc26cf4903d56b8976200723846c6577e, 0x0fd3bdb79cad913432b7d3828e072dad],
+    [0x70497c7df4b97db2f0624836576a6b29, 0x29b9ffe05db2c52ddd1bf0e5c532eefe],
+    [0xb9c15bf88471cb6d479fb4563f016497, 0x0a2a006e3b086bf502c5b1540eaaec14],
+    [0xe731e3228b892fc5f64fdfa9b9daa670, 0x237a9e020908cd0edbd9eae8772350e0],
+    [0x7f7e1e175c0d324e6added6337a72c2b, 0x006d349d6182ec30d68c9be010587c70],
+    [0xde3b79f5baa70a3a7a0ca02ae1b080c5, 0x0aabba39d220f30604d0c44dd47c3c70],
+    [0x7c45966a6e8bc6aa1a1dbc7ef6a9bab2, 0x16ee980a2c67de24fa1a34082f11c71b],
+    [0xac3142e21f08ca02c21755eae77a55bc, 0x2f7d0715d1391190e7b14a1242b6799e],
+    [0x3fcc2e40bd623ea2b2442b55d98dc5a6, 0x29788bb6cd947ea9efaeefd0eeb7fd56],
+    [0x566a28011f69dd0874f01c56bc44c3e2, 0x12cc274647fc572bb79035bfc3332a4e],
+    [0x0a97294156ad7ddf0de43b845249f534, 0x2ef584c3aaeedbbe2b4b103a61e94ed4],
+    [0xf99dd30e57a7d99829cd8757b75b772f, 0x05f9a6a8d64cca12725dd15110f74aec],
+    [0xb7f3d026946abbb9d5397645e6e81825, 0x2979d1aeafdf97988560d4ebbacfa6e2],
+    [0xe05de78adfaa2c9686488e0c47ce1fc0, 0x153891052fca1290fbf83240d334fd4c],
+    [0xe45aaedafdeb42c1c23503b122639c7b, 0x1b8c7eb04d351aff4e76af7f9fa1114f],
+    [0xc64f5687b09b960ab92b14f69efbea57, 0x2d8125dbef602bd970d11fad7cfe06c3],
+    [0xf16bc02e84ada5df51d7bd37243ee1ee, 0x10924807f6fdc4e9e586792e3ace3001],
+    [0x36d565e100b696a3be7afaedb2abba6b, 0x230918919b6826faa7124c5bd15d10c0],
+    [0xf7770f3dd7b056191ae9ab38a5ccf6ed, 0x1db922b8c249ff7bf96121eae9d9555e],
+    [0x4a4fc7dac683defcc0348a8862c6ce71, 0x02283ed8d5e6f9470b3f4f49a771061e],
+    [0x2922f0fb8b5fab3a4f5e3872fb582ed3, 0x06ab8672c936070a10757adb45031a5e],
+    [0xdead1fef6a740a11e9953f8e8e9ee057, 0x2c7d538b9e6d2b2e06e72f8aaab26dbd],
+    [0xbf215933bae4eec639efbd51f4bb6557, 0x28e4620d210536bcaa42ee46b1cdcb21],
+    [0x1b5a52fe8fd7edb754aee2b182d64b66, 0x00fe0def70bfba40679aef8d5a239b3a],
+    [0xdc8f5fcb6ad15223a5da7e0f91c7b4f5, 0x22ae023520979741e5c58fb04fd25d12],
+    [0xa2c432c0dc9321cb50abb98a10cd1bae, 0x105c0b246660c4bd8a6f1b36fc3cff86],
+    [0x84aecfbfc0b4cd50cda1ce4854338597, 0x2a5aba3b45e79eb2606b2a5b47eb9075],
+    [0x142291c0e0ee666682e946b1d57937d6, 0x23a3b23d5edf5cb66160fd2523b3a383],
+    [0x8bfbf486471039f9e65a9c8462a2c9c2, 0x26635f4df7172107dad6b7c3068d2d43],
+    [0xdeb063ae3081d587bb1598878590f1cc, 0x2bb28ef42f5fadab6a6ffd5655b36580],
+    [0x2e5936807704593ac72c74001383f7e2, 0x096dd43a832ab68e35e57c98d7b01f2a],
+    [0xc02abc3ccb54c5c8e8c9c5664c6913d6, 0x21f60efb132b62909990b9f994f381a9],
+    [0x3b455f46eabd8957ba671e9c31e5092b, 0x1db98a562fcd1a5e4496e61264aad5ea],
+    [0xb02912a020bc4708598854f65822f15c, 0x2f30800c0ee461010569d36b60685a38],
+    [0x0c95b36884fe5faf371de3d564213c3b, 0x1e32b1e2e6b59273a113d609663af6b3],
+    [0x2ea373f8b8c1bf16c74a375d5060c662, 0x1b1523463a45a2982889048681a422c0],
+    [0xe5f0d02a5bfd5e79d3f9ac6c79fa609b, 0x05246ee7854b2e20e8612da021b30798],
+    [0xae93eab171ac383ed2cedb460fcb04c4, 0x06cdf8055439ac8424c435e7e9bea3f0],
+    [0xf24ef603454c02076fc4c9d7548cc455, 0x2f9a5cdb79645e3454c617d77a774fea],
+    [0x8a502aa4462ca78f7da2d7c22b37d7f9, 0x01b1625d757786a19a93b37adf7f22ca],
+    [0xfe8e4c810154056eb0f4d6fe61091cf5, 0x28bf5488a525f5514400dce722430287],
+    [0x93299af0d9825db9be73e7b20097923c, 0x21891bee31fb7bd89f0e4422b3c8f5b3],
+    [0x5c6daa90bc93943b59a92dbb77b63681, 0x2282e86ade85855d4ea0657c4add2062],
+    [0xe05d1cf0b70469208a675635ac715495, 0x1cde82acee60b84424e9bcee49a5464e],
+    [0x8b4e1804b5209ce55942d7afb24c6401, 0x1c4dde07d0f94d1aaa832ac2f5712d43],
+    [0x2775d5f5b435d94d2d3d38b583fa7841, 0x116c45e4d88ceae4565c0e94da844eaf],
+    [0x56f7933defa1059d634b977d9d3abcde, 0x18820364804d22119347b728135b4099],
+    [0x3424a0678f94699b23f76952afa4514c, 0x0c849d07b1d9e6ecbac517fc19237ff7],
+    [0x47a86e7728c34b2d3cb0cbcf95bbb74f, 0x2b60a4d43d12cf31076e0690e931716c],
+    [0xf3156ff04536b3dd3101f7b2ebc3811b, 0x03161d7b94e1b0411fc01dcfbd748a6d],
+    [0xe502c5de269db82f91d88fe1b72834fa, 0x06438e8b0220464039a7b8022f06beae],
+    [0x3ed89a22569d47ba586c284e45bd0ae0, 0x14898babc8810cbc626688531511a847],
+    [0x83c5f9214177093010fd3fbf279aafd4, 0x0090dfcc0327a541672cfc26c7e13705],
+    [0xec95b74e8bb82e4385c0585637c885f8, 0x038e0c2e29c79ccf46b5ea1bc589276a],
+    [0xe5b84adb4ca2c5599a87f331908130f4, 0x28331d0d31ca8675ad4767c490261bbf],
+    [0x587805f16cdb1939d9406941f958e8e1, 0x118a24510a2153f818f0a996c87de913],
+    [0xac195794f8d910286c152efd9f959bee, 0x2cfc82712ed9d71ff4fdf63ee4ad116f],
+    [0xdc4d45eceb8b8998f1a667bee369ed62, 0x2ffccbc40031ebafecfc3a97284bd0f6],
+    [0xbd380c6ce9f4f96e0f0879237ffa510b, 0x09b6c00a37df2770cec0f1bf62a6aac3],
+    [0x2f238e64cbd69d068aabb4b58116886f, 0x2c4036616c156006337771c1e355fc0f],
+    [0x38baca48f6b66399ac5c649e68dd3aa4, 0x041b909a658d136993b3f509a9e9d501],
+    [0x760faf885f2c6e9c09407ebb1c4dc274, 0x25c075b41ea2d827a4955b6469db9d41],
+    [0xb9c16afced390cc14435e5cfefb4e96e, 0x115a91867233cc75d1c906a42930e93c],
+    [0x1ebcf8e1425379500c8245c741ba3fc4, 0x2f8fb37178b525758bf6aa527f25773e],
+    [0x453d0d797246b64732d50f176a7ca257, 0x29a14dc4bac0a4c52bf30aaf6b9b8e05],
+    [0x0b6bc62ec631db41cc5edac1fb142be4, 0x2c89dc7562b91d8f2f7daa209b756052],
+    [0x08f39ed4eb8a9721dffc4a586dd9a1df, 0x14972bfc85de037b07e55332e6bc5b4f],
+    [0x779524325e4ca5e78a86078881660264, 0x164567b0de16b7a5550fca229bac7cb0],
+    [0x3333835b6cdd333580b2b4bf48f3b27e, 0x18b49b773f5799888e9abe3f6883d706],
+    [0x0d1360c4fbb8c5f31330f938558b3ace, 0x2007424b129ade2194c73e6e2c9d7936],
+    [0xa2f48c703614980fa798971cd8ec60f5, 0x0445e26e26f91707da2df5c188baf80e],
+    [0x46838d9b98cf4242d601c7e8e501b49b, 0x176804ec1cf85d35298351245d322365],
+    [0x8f64da9fe3df483fc4f4977ca36a7b1c, 0x25b6e65e90ffbf910d19fe3ae23d037f],
+    [0x60f76751e55b4ff634dd46cc4de660d8, 0x2fa2af7970e131f7efe3a7c788890ab8],
+    [0x079bf49835d5c8b8dfbb11af956ccaf3, 0x0c8fd4ece5c205190cf6c842d1683444],
+    [0x7a1186252efd6c9fae9e5f0c1f6d8266, 0x0e992f44463e2dfd267e6a4a1c3303ea],
+    [0x59a5b928258dd1469826a191dcbf5bd0, 0x02609ff97ef574fd68d16a3de0872c3b],
+    [0x20f0e03fad87746d8575af009c96033d, 0x15dc57879cb41d6015da9881f948b40d],
+    [0x8f20e82e23489382ec2d176190587896, 0x06d039b64e82d7975dd21a37b5ee7930],
+    [0xb0960c9c106ce115178dd7087e4e8c02, 0x188ff303194112a7034527cc9dd0b4de],
+    [0x4a86a51d4d2037788e0d4f38e8f4eab2, 0x0b300de5b99c27d332091f8fb0d97cf0],
+    [0x8d103a3388d59a7c0c3de51e2623aa3a, 0x2c53d6676d4d4c87d9eaf5d4da08d1e9],
+    [0x58e1ba2be18a59b07a07e4a93e038075, 0x19f5101715162976766e2b57a58674bc],
+    [0x7e9ddcd2a7219b487a76b17c525b50ca, 0x2f5855a876a37f7bbbd6104accfcae50],
+    [0x701e1a538d69098bc94087cc35930966, 0x2c0de448a88dfeaacc9bcf2850b6ff4f],
+    [0xcdb081bee52b243a1e6de6d4f82451d7, 0x0ce587066eae0c345c3368c53dff2170],
+    [0x34141cb53ac6311b2566edb957982713, 0x2cea98cc64abd926df963da57f2ef4c4],
+    [0x4b8536fee6c0389984bf9e4dbb1aae6d, 0x2c7ae475d90c475f73c0d29be8889d0c],
+    [0x292945bddbd587241714fd3964af5f10, 0x23238fda1417302dd03ce54607749610],
+    [0x123e59793c0fe21075e819ce56ce1e83, 0x1c674ff2ead27ce002f4befc7ad5f0f5],
+    [0xf9acf09f1a9cc503a29ec306f8606ae7, 0x2627be68d9cd0ccda3828d51621ba409],
+    [0xf53263dcf9ef4311781d09920d094ead, 0x083d832adf9bb4a25f686abeda9f16eb],
+    [0x8d66d91179745b213679c2a2bbc94968, 0x0541af6b96524662084d1208d71b6b2a],
+    [0xa04b44eb544ee3c0fadb8dc5eef2a585, 0x2cf2c992c02a36334ff5dc851646ee7d],
+    [0x6a3a3b48c10ded13133ffeab6fd153e3, 0x06c5df8fd61eca2fb4923f0a46dd4e41],
+    [0xcf291e84093aae36cc68002ffb83639b, 0x1a233c7cebfb0c6eb72dfd07aa2b537c],
+    [0x83583eca4062405400acca58ca9d2235, 0x05f6d62f54518f039e79aa726bf15a59],
+    [0xbb66ca896ecbe4089d2d22cbdb09777e, 0x2b09de93c6e3148cf7ff5731c8e144de],
+    [0x2223760fb1b81c091b74a6a4a82968a4, 0x0239ecd92517825174f796345db29656],
+    [0x3ce290d0156430a8dce61c9df89a8bd5, 0x2945cb85e34532e3a22fc57670379789],
+    [0x8881c6c2ec00e857685c0599262a3cf0, 0x00d3be6a5b12bcf1bc705c20c30f320b],
+    [0x5bb5e4fbca5d6a19eb7fe14abe845dbd, 0x11e7bc4a39d2730196b6c334f99750de],
+    [0x3b82276d9e288082ac7f83b10bcb6d17, 0x0c7c9db952865fb9470f8aa1402536cb],
+];
+
+/// Round constants for width 13, 73 rounds of 13 elements each.
+const ROUND_CONSTANTS_13: [[u128; 2]; 949] = [
+    [0xef9fe0038a3bc26a28e3196578a0000d, 0x1373c771cdf15121a224f330d84b6688],
+    [0x5ce78bb48fa867a6a95e5b9db387533a, 0x29f9fbda3c999836017f76e121af3aca],
+    [0xee7a2525b565bbc262209a8cda490528, 0x1c7eb5116a20075c169b786d9dc86a24],
+    [0xa3449a6238546bbdf2a1c308c9cc52e6, 0x21a549eb554f0f859f61d68f1b6bbe9a],
+    [0xd3250ac6d4a1afa71a349ed195873508, 0x0b53fff701347d1b9df98b46175800e0],
+    [0xdbabba3cad4713718f95e6346e1583b7, 0x02129ac4742415c020db2473999b34df],
+    [0x30b68bea9401a15f5124d64c5454bc29, 0x01d28f305e8b0ec88da37c8251b0c8d2],
+    [0x96700c03720705939d61cee451faff93, 0x1ed301e02e3fc930999fce6d3744c235],
+    [0x3dbba9f7c802c83ee628530d0e26ae1b, 0x2edb1832966e34eaeee0903858afedb1],
+    [0xfe81fbd0501e8f89ac31a698756d87d9, 0x123daaddc930c65fc67bc77ca8bf4123],
+    [0xe9d5478d057141d3b17259fed8e4a8fc, 0x2bcc36362c90210acf67d5fe21d53104],
+    [0x269144f7900815b17095f7ff76e3c9d5, 0x1fa928cab5938207e898e61d99c7d616],
+    [0xc18fb426c254f5a905fd3e831fc7c837, 0x29c2f1cfa72a0fb8f53a631e84cfdf5c],
+    [0x7c418210273c7d048bf300aa7be9c65e, 0x17a732ad13f1b6a1aff42664c390b901],
+    [0x135484c6cfa084c2a512ea1977a60268, 0x0c18c6e773ea7eb43b98cac66722de9d],
+    [0xcacbc3ed7b498dcf6600f530b6545e49, 0x1954c5c238d8a30c9f1ba4fb963e091a],
+    [0x179dd314597cfde78b74869d74dad600, 0x2591ab77813f34d8a0e0b7b0caacd6ef],
+    [0x2f40ed848d8e33b756c9a794b5932fec, 0x179db9fb8aab4619e67a6a8667c7b4b3],
+    [0x04fb8dc4e747077c0e462f51d1c59575, 0x305d014214b4107c11fd83870458738e],
+    [0xbca9ac4563b12b90e1fef4f76f9b7bbf, 0x298723d70694578fe291635898762bce],
+    [0xd0802a45d06e649a5e53719aab29a913, 0x02b336e9b38b504c412a683309ae8024],
+    [0x0eca0443251ef35f0a905704f155d339, 0x08687e699b527b1341f9ab7f17113d9e],
+    [0xe7c4ac4ddea9a8da1a36ab3be057c9bd, 0x0a5ff9f7d45b3ca9b79b18d79db4307e],
+    [0x54324c5657265353add7dbf4f2504656, 0x20e44747601319223535f4566cdc86ca],
+    [0xd1a4c7b9bc860ce9ce436c33a202a96d, 0x0254dc31e3afd304e85c9ef8fabc1a1c],
+    [0xbc1eda3a1490b6a4bcebd94e0742cbf0, 0x11cd6492dc520c57dc3e55e33f8a387c],
+    [0x603119a1a4fe15c36dc986cb4f8c7cf1, 0x2f98b10265a30475bb0348d4a787cfb6],
+    [0xc073465ef7077550246f69c58ba989fa, 0x153d814e4e55ad782bccef998c974250],
+    [0x7131744d65d289e59964f930d13b9ed6, 0x04db11a9ea014aed2ed08e46c06306e8],
+    [0x30c3f3f3e01eedd3633e606aa0b1a1dd, 0x0e9a9a6dee9339f5de7135ca3222fd22],
+    [0x911e7a073856faee78398811cb976757, 0x0e6d8e464bfcdb8f9ee60fb1f2f33079],
+    [0xac16e9846f9d41fd68796d7a28ed728a, 0x0237fe2151186437a1cf1901106ae25a],
+    [0xbda1d848aa5a680935825e73f1f5aa84, 0x1e3d3d1eea8b7615a9897ef86229776c],
+    [0xbcdf688ca3f5870f1ee6e174179fb440, 0x09724144c7a4aec0ddcbba9f9a9e9ec9],
+    [0x1e0232e3486cd9a014be3fc8cf066129, 0x11139987e1147467d927db25eec88f0c],
+    [0x4af04912d7ca1df83e65a080ea96269d, 0x1524e5becef72c2697e34c8055ec8197],
+    [0x4fd72380afa4c092ce28ec8c31e80c99, 0x064d1299b11b1a6bb50b3e5ac7592a05],
+    [0x7cb4804091a1803dd977788a9f588e4c, 0x12d9fbd27be24eb620b7743cfc529656],
+    [0xcc0fd0daae1546bef30079f84b651bc3, 0x088d226e69e1b273eba31d4da8576085],
+    [0x8a3139920c19497c3f47119baa50670f, 0x15126de187213ebb40dce5da1ab3adc5],
+    [0x4a66a9961f01eef51684ef792126859d, 0x1169c1fe739bc528eac16a5263d22dee],
+    [0x0c9fa80b6c963e2fa3a1ead3d74f3aaa, 0x2a7006c683094f6f84d122ad5774ad2f],
+    [0xec7ed8e9302d0f950cd9405ae94bb258, 0x2607c22ca9aefe8cdea30fff05b05c4f],
+    [0x09dbdfdd8317a384465931c1c58c2638, 0x24530b086f441e3834f36c2b11ebc0a8],
+    [0xc93fc29a2a1a84824ace50467c9aa652, 0x06354e02d8c4de97a811481e390c26e5],
+    [0x3640692151b295dcf42cac0d8ab0757b, 0x1f6fbab3e2ea2791c94f5ff43ca12894],
+    [0x7537e2834e6c8905c1ae69008df70bfd, 0x1747ca45b7282ef09928f7649f5cc833],
+    [0x5ab4ec3fb1c8e76f82e035991200a633, 0x03efd4b9b4d41974e36884e3b1055a70],
+    [0x1b475dc91ebd2d685c96b349c220b4b6, 0x2d9cd189d47b0b1b32c9a2412db6a59b],
+    [0x75ce393d424c9751814fd03541b4756b, 0x01c9982650aacd1a449ed04605bd65fd],
+    [0x221359851ae226c1c6d6a7ba1b1ffdb4, 0x243ffeaa82f3c038481e84b728b459eb],
+    [0x9491a83b55caff300339885481dc65e8, 0x0650daafd003bed0bed22bada31bea70],
+    [0xae2d0cd15c1f9d0164547082b3099df9, 0x16d20c619674228b767fc625aac650ba],
+    [0x29da3f4831eb1e4f2f1c1230cf5d149b, 0x05ee8d5d661d39f15be923fa2944ef2b],
+    [0xe441de256273ffe551eee0ed7e4dad73, 0x23ebc8fc40feec52402e18f881eacebf],
+    [0xb6b1b130f35bc6c428a9533c003a70e8, 0x292bb59e7a6c70116ad7b7e11951c3b8],
+    [0xa89b8cf94703fdc12950d9f3c21a63ea, 0x133d42d9f83d054db43e4354d1bd7ec3],
+    [0xcf07a8e71467e6110b94c90f9323745e, 0x1fd6bed0d347a88e46fcb42f3397aeb0],
+    [0x9b8c1fcab4a3420622511c867f79d776, 0x023b091a0c1f0822c8156747a907c8a7],
+    [0x4d139b5cf1b752c87d96f44420512eef, 0x075fc73bcb6ea2c158e721a9aa6c3f1c],
+    [0x0e56c01c079cb550e3f169fb2a9a451b, 0x017e3bcba0b2d22d27cdabbcb360c802],
+    [0x6e0cb72d5c46fee296ea6f8e73ad1882, 0x194299f0fb7951a80ace994a3ea3123e],
+    [0x77e8cc77150b87b42c8c118f1473b514, 0x06f0e522924a4cf25d9011d477a2746c],
+    [0x36d65f0fc977993203d4b311cbf5d0ef, 0x0cc91c18c5fee1b579da0900f04e5a6c],
+    [0x563fdb06926b6dc4e4d93d04165ed309, 0x1abd94c2a5b28e661310e3315b67adbf],
+    [0x8efb72e846e3ba10344ffae655e56f2e, 0x24142c9001a7bfff363f3f67f4a2bd12],
+    [0x6012aa747dcd7a3af382ed781252e478, 0x1931b9d4523cb3f78bfbcf6b1dde68c4],
+    [0xf93c952d11fd11a8e8f9a49dff9df85e, 0x2ce3bd1161a012a572836e97ea8f0694],
+    [0xaa30d192c87c03cce47a2b2453d5a48c, 0x15a09fa373041c239e8149657c98b4f3],
+    [0x8738417a5786898da316f528998af665, 0x0b1dc38f74a71b7d909e5ce7fe560176],
+    [0xe643c545496df0bd1c25490e34d0f1f6, 0x13ea22f9316bb5694d69024fee2cb95e],
+    [0xa9614e022f7f4d7bc7dfc974b0aad71d, 0x1ffe58bd14b12b9fafdc365e7081a395],
+    [0x406e24ca58b305a6257b7c772bce2447, 0x11bab1f186cda03c01e85dfedd40bc5d],
+    [0xe6f5ca9fd4055b417f00c9632abe30ec, 0x14007eb2d1440b7b8e0936c79672051a],
+    [0x0431873e8e0b5004a1f242ac963fee72, 0x1037f0193d40f682ed9623a772de679f],
+    [0x77a0f840ee57ebcfe1cf77236338fabd, 0x20b18be760e87d7c6149b810fe496abf],
+    [0xa61b910bf6fdcd33b34368cf96e95a2d, 0x1de835e6623f426711276dde7a2a1c20],
+    [0x6b0820c4b5f07a47e8b10b05e7a99d4f, 0x211c38050b07c4a747c5dc44939ce1f7],
+    [0x212a4887e4bd1275c205fae2e17f452e, 0x0372fc2cd832015e119c2a12d3622fd7],
+    [0x7d4e5dc161ce9816f020ae4d1410d0be, 0x01438dee77b0825cbef6b0f412e9a727],
+    [0xc513f6f8337e22968a65cd50b0d99211, 0x07f2ece16c12c9757aaa60953dedd709],
+    [0xc89649b5bc4260d73e4324848452510f, 0x0c57a69d09575d8e1f525e012d73729b],
+    [0x921a366468a0d15efb308f57ce658513, 0x1c8d0a9db77f14f835309f2d3c6821b9],
+    [0xd80215b69ca262cc2d12a5eb30c00b8e, 0x169da093e7a5b39bf088277072d4fd58],
+    [0xf8250bf2d765b10d6da2e3ef1e2d787b, 0x2f3034ab20af0fd83ac60b0236696866],
+    [0x781543620f3eb28ff7dc102656583073, 0x0e31b4fa627dd0e5f2dacaf105865a77],
+    [0xcb8c95a36be164be5fa86be9feb8a594, 0x2a9007126f67e1631d29512f433a6a0b],
+    [0xccefe8a92b6a6033e772c22183bdc0d7, 0x080af40215b6078be84fb47acd79b59e],
+    [0xd39e7ada9047c926efb88e1cbf2ebb52, 0x0de8a234d208861ebb05ab3812e5c4fd],
+    [0x1cc6b0adc062efe641f96e3005af3659, 0x22aaf24c0f1e3f941606a527376ddff9],
+    [0x8909851e3b212c64a0b3051ffaa2eb66, 0x1f4f5b7709cf584bf0704861e94234cd],
+    [0x4f6ded4eb72b5d0ab559bd57667aa2f1, 0x1296d46427eb2e38816a5e817133565d],
+    [0x8c55d045c69627f5d8adeb9683a129be, 0x03fa86767c1fa2d7b4eb45b4a255b129],
+    [0x25facfbcaff649364352d4a88d928f38, 0x08022805abe02bad75306d226478e49d],
+    [0x8be7ed273441d5d280456b2d3b8d8a0a, 0x1d58b33a2a9638ba180cd36029308a2e],
+    [0x6c4364e85ca8e01911a634aa4ad657a7, 0x1f8cae7359c800f307a625b66ff2154c],
+    [0xc3bf0462d1451a9f5a58748958717bf2, 0x039ca66f2fd7f80c17171a4ed8c82ae3],
+    [0x541eaf2497653b6db1a9335da6c882dc, 0x074f7e13e74f2b7b316a3839956f4bf2],
+    [0x879ad7886cfcafe1ec55fe6bf4ad5cb0, 0x1f63cf1148c0efa656d5af850cd02a0d],
+    [0x63d7a62296ecca17efbea97356a73e23, 0x1d9bf6ebc0464b9cb47583494a27b457],
+    [0x9be557fd4ee2e040792da16e28bc8ac6, 0x076492874603b021892e5c2a03c70697],
+    [0x900e0085c785166d27b7ec8960825238, 0x07012338c5c626e267f76d428388940b],
+    [0x5a95f040526c3c9224d2801a1bd4ff81, 0x09c29059e2013d0ce2cce0b24e394676],
+    [0x287c2c9fad9095205ab21582ba0ea641, 0x1f2de0350981ab374c9ba7ea1a69d81b],
+    [0x801b4c56ea8036ff95a67f434f3b7425, 0x0c52bab86e259a3bd8c061197028873b],
+    [0x9e629213a6422032dfcb26760426a3e5, 0x2f262e06178670c4ff861f670c7b51ef],
+    [0x0786a691a5d972ea211af12d4ce384c2, 0x2af6aa2335400114453435e97010dbe7],
+    [0xa46faa122d30c37380b54b6b5d01ab3e, 0x18cad65567f0322fd6f0626cb5caf567],
+    [0xd7e57f1945eea251f8acad86e849858e, 0x145667e19067dddbd4269db6ca76d0c0],
+    [0xe54d8bd3dbed06e215b2bf2623b21847, 0x2ebaa7e10194cd2957a13d1a358fc93c],
+    [0x1ffd5376a1299b45ba805c4b4e0754d0, 0x28b85b95982ebc76c4762654f932db03],
+    [0x73f34172e065b24c9684dd3cffc340a5, 0x17cb0c473841ed2eb1f1a1ddf021b74f],
+    [0x7c39d1978ca63c97e39ea06588db07b0, 0x24f46ecb8583ca97c5b018736d227fde],
+    [0x2d1a95a86c1d0961b15d4a24a7185dec, 0x274b5c409ea9809bf7f04a7cb5ee4d32],
+    [0x42b3348524d76101836646627ded4477, 0x2cba8960d543fd9846377348ea55b7d0],
+    [0xbbab1d4dc73c8a18c00cfb1fb2469988, 0x27f02e416dd70b44907f97d7ecb945ce],
+    [0x1693b1b939ec09dbeedf26f37724a151, 0x2ab21865691229fa440b6528ad7c39c4],
+    [0x92c1ead008d92fcf9ca867b165f5e275, 0x2910da916e438c04837ce799b81b0e97],
+    [0x309519a7e52300e397d9d90368207afa, 0x0f03ce98366111de12ccbe5a1121c9f8],
+    [0x044a1fedaca3d151edd3121a44fa19e1, 0x236b0371e7ce97213d8aeb6683f02f44],
+    [0x3afbc580be1897dc4c5b6cd5df3b350f, 0x2c50cab6a867b1e801a36ffbcee521b8],
+    [0xf93e8548d923d8a49e6df81b9c3f277c, 0x0cbf8c8dccd837d1dbac09151e4ee378],
+    [0xb0dbede9ef479830d0ae483346e54be4, 0x1d18a9fb001245c1a6a17f7cd24ed820],
+    [0x283b2a7efd999d59090c434c88837ed3, 0x1476bb1c30603f7f94493d2e016bee63],
+    [0x5d727d5234ac4bd9a9600455ccb5ad58, 0x24d565fb578dd252f56a7a0d5d8d4d1c],
+    [0x438e0f5fbe0d7de436fa3cfad596369e, 0x0c68e7e9567deab8e8b95051a7788443],
+    [0x16840d44c3ae109a669eacddc4a35611, 0x1ce5b25a539d3e1c0c5dcd1ebd0f4ee7],
+    [0xf626db73bec5d9ff06ba9d43ba84e208, 0x2d3210e66407f6c343eef28fbb7bae59],
+    [0xfae6d411392de254d3b72f1d450ad2f9, 0x118ab3427747ef1a45a609cdf7e7102a],
+    [0xcb14a2acce4fdddfe50b39fb97423b5d, 0x0b45c70d2856c7e5b3fd6f8f0fde1a7f],
+    [0xf1783d9b1cf0153e4a2918894faa46b3, 0x2ce7c2bad5608153c5d1e40ee17cc33e],
+    [0xf4e090a3e99e03a5a64742f98dce5af8, 0x0854bd46fef6c2e6480b3b0998215f55],
+    [0x770104a196076b74e6f5d7a96c8e693d, 0x15c77e7600df711f58f2f0fa88b7d435],
+    [0x309478b167a6099e54d2f5a221724ed8, 0x2a0a3ee38edfda0761bb9b3017924d09],
+    [0x37f8de94198aee42f5be6659765a4ad9, 0x0e8c14fd43b9ace2b7e98e1ac65659cd],
+    [0x4faed9cfdc0a458bc95b59ff381e21e4, 0x163276e836c4ee6ab9466613c61fc692],
+    [0xa632b104de4159c0e00c5352d078ccfd, 0x23d1584a5cb0b74c00071c687cddacb4],
+    [0xae2aefabfa47d1f290eb5a628522f5a0, 0x15685bc903a1c4c031ce016c093860a2],
+    [0x11972457efd9485834b83f0fa04260a5, 0x1b33f50e90e90b5dcacb1a158641d633],
+    [0x7d3f31c0315ab315d6a9457304f21dc1, 0x28380c55cd667894c638647e6aa14b85],
+    [0xaab18c8e4485808f4b1e55a73cda6246, 0x29d108541f3b451943eacf723acc6886],
+    [0xb728d305bbda193afc259c99932f95f0, 0x1d4c2cba59d5551bc3fe976ae5ac09e5],
+    [0xdfa4b71c1b9db8cc7b82780359c70d33, 0x16a8d69970561003650337aabe5e4e5d],
+    [0x14f56dd5d7345bfa6ad0d2e43bf92e43, 0x289e50c1f0b0bddedc98b7e8a35be62f],
+    [0x495e7b6c142dffca003f58383c6dff65, 0x0a41765a5352ecbe0b327ca7827a9fc6],
+    [0x3527ef2b82ceea6006e7f71bf77666d4, 0x24c0d42378a0c8a613c0402ede6de9bd],
+    [0x13ed706b5d91ea4fb9b13fd19fa4d47b, 0x277cae5057d5b5ace72c47e60cc4b8e1],
+    [0xb8a7f71622bffdc1c1684e8958c3765d, 0x0a303567cbf94968090438a23e2fd2cc],
+    [0xa884e42c37e0e6fb4d4385cb027ab949, 0x1f7ed8c267a29a69d9450dc74afaf768],
+    [0x8a60313040f236e815fd22e8473bbc65, 0x086c4b8301656b6acb8a735019556a27],
+    [0xfefddf1504bd487fd6282d9e3d6e3337, 0x27b63fbc29594760dbffef8d30fef49a],
+    [0xab839c282881ede8b0e5a9e723766d70, 0x00b9c0a45621716f9849af8a3c9075b7],
+    [0xf5ab6e2b91355d2c0a8d226040652395, 0x1b5ba2fbced922c3796d57b4962fd253],
+    [0x026e13dc4986791f54c4030cac6f1938, 0x2e1ad57ebdf932603f51bb1ddab204dc],
+    [0xb17afde3b2b97d1eafafbd1862ac6e16, 0x2f865e1df1169a35771f617046485f8f],
+    [0xad052146b869d50dd58085ea65d9d141, 0x0062ace5be380c5e4361b209b80befdc],
+    [0x01fecd5b3f2379c24b45d59842536337, 0x2dbcbb1b4ea08e3769844bed522b92ee],
+    [0x83c48b26cca092747b4ceaf07c7ba847, 0x1ce3d90034dfda11e9bf7d92b65f8104],
+    [0x596aef3db638fee1895d3f5a5845252c, 0x086583a59497474b0d1eb0c3cec4f1df],
+    [0xf6ca88cfed3957d2c5e8a6fc0e78d76c, 0x2b984d5c91b7b320f7f297bddd68789e],
+    [0xda831101eb310399356e4b52df11fc28, 0x08edeaf9ddcd25a730751b32cd43c630],
+    [0x9f1c58ff1fd66d930cc234a7e959c41f, 0x28df39cc7f96672569cf86ee373623da],
+    [0xf3aff1780455c32d8d3598cfb91b24df, 0x15af6a84c6b142eacc58137fb371799b],
+    [0xec8446d6717563bd3a80cc52a1be6961, 0x19cf0dca22781a9bd56173491ebebd18],
+    [0x7337996ce50180eba97775618b003558, 0x04e0e0b52923bff8b67b1210f23a1127],
+    [0x5b540bc397c3436d2e64b6a2da9ac49e, 0x15ab0a5fda2b5191cc2bd0485a30c2e7],
+    [0x810ee071941969f87b62b6c6ea8f55ef, 0x018e83f7a6bbf457fde7b8ba4fc53ad4],
+    [0xbe60c3f93e2b45309ab559a854e63355, 0x171b0539cf1d6e8519789fbeb31292b6],
+    [0x142ae6e93712492265d2992473da1021, 0x2ea76a14e0d177f355f681c822de2b37],
+    [0x6cda5d3ff836576e983da9b7d93564b3, 0x144292627b643b5180c710eba6d74a07],
+    [0x2127d8c6ff173ec42f0e421ea53f7c1c, 0x07ad2bf37ce1ef4a8fac5b44305f1a5d],
+    [0x29bb5597edcffca145156af3324b0d84, 0x055514dc9b01480ae57ba24fd5dd12f2],
+    [0x642f6c358e233ecb8ed7e575ec6a34a5, 0x0b05a45bb9377e1597507fde0dbbfa2d],
+    [0x042b42f9301bafe2e1b4c2ad9e46fa86, 0x198b369f024a0561af9ad7a0cf7ac107],
+    [0xac5ec75c78755fbec6f46d9ef73c2f04, 0x253f82b21addaa86724677bad4a4c464],
+    [0xed7674e3cecbcc9cdbcb46c7cfbc7429, 0x0ef256e9d5e9e3da1f56d7d2115fdf10],
+    [0xd925a464e995d7a1d02f90c7264f7b38, 0x2d8926570b080260164e6b66afafbad3],
+    [0x2fb732cc191618d525400b2fdc516896, 0x19ce4c36c51210787f71cbb080cc340f],
+    [0xe1f9978585c88b57b9a87e8d8cdfc6cb, 0x059df907c9fe5249d484183f2e89f102],
+    [0x1ac7050295b388b6b5cc3179a842f803, 0x2afc27a41832e0bd3bd8e947284d9acd],
+    [0x7c0778e4c5421dba2eba694b0854a21b, 0x06e960a5b2385417429dfa89cd14d778],
+    [0xd710e7a7a908a233ec5afe1148476098, 0x1e8b790ca2d081624486acfd613245ef],
+    [0x1785329a370053300d792a0421cf3ae9, 0x2f68d78df4e08b23c96fa9597ab7ba5a],
+    [0x8cce3084ee85dc83d57937facf3e56c3, 0x0a2e82f0c2f9e345689b2f2da2108d01],
+    [0xc25fe8ebb88bb768dc435caa19143111, 0x108b2af65d8a2b8ef81434d73646cff6],
+    [0xc3d0c1ee740d8777971c936ddce26601, 0x0c2f90eb77fb2855d39b745a85d3c960],
+    [0x9fa84c3da6cf4eac9c2b9cbc94db005c, 0x03daddc5903de3d5302c7a07d4c54603],
+    [0x80596b3c6380effb23cb3b3eac954134, 0x010f4d48188805d3126738d24976ef4c],
+    [0xa1cff2796e18eb9eafe9c2ec4f9e4280, 0x06701317708659ce74515f62f9128ea9],
+    [0x65e0091e01072ef08630056d907fc6d9, 0x2dd0349f0f0ef52d77602037a211e5c5],
+    [0x5ad4690cbd8022437f402a6b25eb5436, 0x1b15957d8c8774076df16d49d788b11a],
+    [0xc9b8e3dd17fdcd5c40e17db738a27592, 0x02a92a1f50c4658b687f2b6c8afb5323],
+    [0xc04e0b137fa4b57216d1f2ba5e64ad56, 0x0d4cb316f072fc8e2463a4a42d168d6d],
+    [0x1c40e0952c6f66e6024fadbd2248e16c, 0x1cf0f9bf90e207f3a0aa5fc108538c64],
+    [0xd4beb80674d0c88dddeef543d3600ae8, 0x0b81c03e6806a49a8a5edfc3db4bf78f],
+    [0x4d3f29b5a18148cc8d5740e48ecc9d35, 0x1dbd32d049764d6ab6f677b89c408f9f],
+    [0x41b966790438a2997be9cc660103ad90, 0x16d52bbf838448b0e44c2bf05229c3f7],
+    [0x0cf5bd55c293991281bfe5401b9e35de, 0x2c8ef880d5788e5359b97d1be554305e],
+    [0x92b9dc992127c235aec46a2b323f2f11, 0x1f007ef167c9b99e948007dd80608571],
+    [0xd2035264394841b2b6f65c61519cd842, 0x0cd33683ec08207c56a80210d9929731],
+    [0xc93ddfaf6051e68fe739f161b4380fd9, 0x0af1b0f70f93bb8f5377db6517c4482a],
+    [0x9e49d2eb2fddd171613e69f4eb07bb40, 0x24d03d522118e544c04d49d38affea78],
+    [0x27c9b4824796953025027a37942428eb, 0x2f1f0e4bf9adcd2a4f1aee5cef4c2d3f],
+    [0x81958f99a2eec0c2f1a52a65b3e589b6, 0x248a412f287cd3b3448930a1039ab322],
+    [0x9992b81beb2d3d4cd4d16038dcbab231, 0x21e374b45da55c1d3d89c6d2173e1146],
+    [0x67cd8abef24b5458eb10e53346694a65, 0x1c2f85c42f6a331092ca033b4ae06ab6],
+    [0x36f177be8ed62d32ed35973d3de94e76, 0x29eb555ddd4c74ab9031084127bfc250],
+    [0xf47ec0a7c0acd54ba0cb551c912ec6e3, 0x07d30101df711f25364a955a7002fe48],
+    [0xe4ad1230f91d369a961b4dcd4e15a36f, 0x2afaedc7a657941c9e77e728f202d568],
+    [0x29e9451be0a5acb7e4fc81b2afae5a83, 0x2daf426a65d37cc81125c3d1cca527cb],
+    [0xdee8371a9fcf902d43533e649c7bf48d, 0x0c774e81279520eeca70f8a7b3d7799b],
+    [0x684be8a37a25197b467acdd367ee030b, 0x0308212a0a56cac99561515c83f01dc3],
+    [0x653235aba484fa0dab9cac4aa744d295, 0x20fa9447e92e4f68025e1bec51f115fb],
+    [0xe08d140549bc36c82f739293ce8b6245, 0x2f4f647ef450cdc4b3954ad14c7cb1c3],
+    [0xda56cbbb341f82b4a63ecd41afc79bd8, 0x25a6d02422b9e00b1ca5e1156b87b4cd],
+    [0xf35c56c95470bb86b9668d0063d095cc, 0x0565834c7cf03c8e542f1c3288006ba9],
+    [0x7fedffaa584aa431b2d0e90da3cd4fbd, 0x24a6f88d5d7090b24a151c68aede9f34],
+    [0x3e78225b646d25c97b504e63dba4ccea, 0x014b0d44ddf5cea576cb82d399c8e4a5],
+    [0x737e4ff9b84cef7050e37456e8f03acf, 0x251d320b7603c1c86b6102493219cb1f],
+    [0x3268155f3363bcdefd3cd8ae5f63766b, 0x238c2bec6cfad388d477c55c0ddeab2d],
+    [0xc08de0ed567568448ca3c0c3555da9da, 0x1337d778bf0f0bc001ad9dbf9999379c],
+    [0x65d3cecfd767ed4408a025ac7ba97448, 0x269ae35f7eb384ad1965b82f8c9e8b4f],
+    [0xc64ae9ab2fe48701baee5e7853ea1891, 0x2b01d8797cfb9616e3471921b610dc78],
+    [0xf4270746900d71368b1e8afe9a684cd2, 0x1acc5f89dfc24a03458156ff7b156b22],
+    [0xedc62327e0407abdc7bd71060f8d5b78, 0x0a66d6f919f7dce7144ec8f66cece8db],
+    [0xab4a6c62146b2a84acd9af50954e8ca8, 0x17978e79a55cc077d4bc92b38ee5d357],
+    [0x6cd7b02fd4b449db1895c833a64ad04f, 0x11cbbbe66bb4d957813408009843b2c9],
+    [0x2f8148eaf5e83bd0686112dc646fc93a, 0x0bc7843529e13dbe304210922ae9515a],
+    [0xd9658d29a35e96208bf53f8fb4ca770c, 0x23d644fab01d806361de665c8bc6e972],
+    [0x350e1e3c470d24d3ad23eb41e3d93b3c, 0x048371c6200b923b1a0ad3f008310dbc],
+    [0xcd3b11a126547f2144e501c18a656f66, 0x21207b1d47a4bac52ba3ae9078c265e5],
+    [0x136b03d8cdce695e750c257104564704, 0x1ab86eda61a6bc9606e65f455c3c48d4],
+    [0x62ba78d561941c4beaa1de9827676444, 0x0471b41c6418b6afadf0c9517369b01f],
+    [0x755ffbd7187eb43d5ae0850c923ce6fc, 0x1f67ab3d71646c039ce09ee72320b2ad],
+    [0xf36d5d8db6551bcdbc836ba7197daff9, 0x1d78da55a4c0c3fd78ef34b4316eddd7],
+    [0x12159b89afa5e2ecb35063aa91c3b791, 0x14b37257c1f8852aed448036afe59b3a],
+    [0xdf5b6717e6b0deaefff1f2a988b3a5a1, 0x00b904b1e068cb7712bb63b1721dbf5a],
+    [0x445c5feae4f6d1c5886018a0efe4a8d0, 0x2fc100ecb79693aea91c6118b70209fb],
+    [0xece2939a3b63c5ac24cad31bcc70074b, 0x0a9d77bc8f430233b59f6737b9c0197a],
+    [0x14cd9499d75027a6ca7200000a539b9c, 0x1f4bdc685882ae1149affc74fa1e4ca6],
+    [0x3a67edac4e84b238facb8a445fb73fd3, 0x0dd11b1befe293110cc4f6b5c0de6fae],
+    [0x67df69e076d1e1c310de929bcc1ebbb5, 0x21012864a44b6479d44bdb1e2e977640],
+    [0xa8bfe1abccb4b3145d0087410a5cf8fe, 0x142cea93e5949c06c37dac88b664ed36],
+    [0x7d43ce7739acb9e5818ae8339e9335e3, 0x25d074e31dd093d43fa3a3a6b5c04417],
+    [0x6599bb5ba3f63401ae9b3689f5afb943, 0x152368e8dd72e9bd3f00fa47ad019709],
+    [0x13eca57d57479ec98ed3eebe3a23e50d, 0x224450a6f24dd8427d8d6f9dd9856303],
+    [0x2b9c122cb8ea937cdc1e31878554c81b, 0x08ee8bed1a5338113308a32cf4b8f0a4],
+    [0xcb0acfc237cf7f31e063553bab156dd5, 0x068471f745758670cada7f230140e09a],
+    [0x74a1113f3076ed7ddee67845662d5594, 0x17dceed9a861ada2970318ff8b9dc130],
+    [0x407c5ca0bdf9cf6e2ee88a8e6cf36e1c, 0x21c951b2849f51336da12e1930cbb2cd],
+    [0xa18a5c6a1f250cee87cf506d46c36e36, 0x0aeb15bdd33d09c780eb1ab6848dcbc1],
+    [0x2220044c94bf788c40b4806fe86a08eb, 0x037e66e0bf7b7c7f7b00bb5c06721556],
+    [0xc3a6e10d57123701a52769095647bb00, 0x2705d297db4ab693a65d4cc73ed80cea],
+    [0xd0c2dff811425a940a60e0285a71b3c3, 0x0df821b1a387e78f8e2388cae0294e88],
+    [0xada915d23540688ad1f25e34a9ae3afe, 0x2959ffca94642766a46cd8340db08ec9],
+    [0x36410f758e1bc02473e4255d88120a43, 0x0e86b8655306bbde01748175b56e66ca],
+    [0x149ed38f87baffda0a6f4b14264b5fcf, 0x08f94dc12076e1bd3900705e880245a6],
+    [0x5f300fd80d86d22cc095de9514590506, 0x2968e4762ea9e6ee5013d96babb250d9],
+    [0xcb57a23a879dc1cc95b98d9bc497018e, 0x1556c2665a0e74cc056fd454a85a3fbe],
+    [0x29c6ec865bed10a07835c6fe6bee34e1, 0x0ce86a48c05d429be480b9cbcc220f3a],
+    [0x117599c247af6feb577bb44e7524534e, 0x21e6b5e9a5a5c57156dfb8cf5a8785d2],
+    [0xc582e487d60de12cdbc357c9623e4ac4, 0x0444c6559f15a5d6f3781bc392e88e8a],
+    [0x17ab80e4aaab817656342e1b0b3dc1f4, 0x17cdb083db2171c7621198f51b7d1891],
+    [0x5969c9142f365351c7d23478fd68b3f4, 0x0c4fcd32fbbbcfb27be41a9492ec5542],
+    [0xd1103ab69cbf9206d1c2ff4ec0389576, 0x037a47a18dea6fc765e6ce6ce7bd59e3],
+    [0xe06ecbdbd2af7424ce1225d4a4414b5a, 0x2c14bab17a78ba001537e37a630357ab],
+    [0x45f63ef37e67dd541ef07e957984e7f9, 0x10bd8cfbacd505ffd57a2bfaa757ad3e],
+    [0x4c1d4e3d975c34d538cbd8440a3ce9c8, 0x0f2a0b6a66486b55fd8273a453ef622f],
+    [0x1480a02109163863805bb70fc7a92614, 0x297b4eb48434dc5342e4c9a262c20033],
+    [0x64e6b6cf79f55f244d384804946a77b4, 0x22cd3cc552b8310954b62b62d4f7a918],
+    [0x47bf7023f2d7fbce512e1c58045fbb2a, 0x05cf496eadc8ea0ccb36502f443c99b3],
+    [0xb6297d27de3f183aa9a9f2cb2abb90d4, 0x1e0ae8b082d3726190a6878f4b8889e0],
+    [0x9f634215c30c1db82a095012793c7c1b, 0x2b282ffef89e1c3a90c359bc7c80133b],
+    [0x8c9921070d9a02a7fe5bfd1da1dadc4c, 0x13bae7e838c638a41a9961ab81520f40],
+    [0x8826f6c44d824f7f122e430aa4e746cd, 0x0cdc320eead70bf0db57965b29f9cca2],
+    [0x5a5a83e52d5db08b704afd8d48136657, 0x1b94ed891e3a73e1bcd6b7a9fa336b48],
+    [0xac0d7b086bf19596c60cd6406b076c67, 0x2e8d765a70fd39533f1c456db12b420c],
+    [0x7088ff9a338ed283de9ec3a8850e96be, 0x2f69220fd9209bb41b0ef942bfeb3113],
+    [0xa652db364b0020f3485da40ef8079ebf, 0x1506e9fdec14a9dc03eb159d011f0bab],
+    [0xb29d39401dbbd4e7fcb0341914492a08, 0x2e43093a060a595dc13213ae7211c041],
+    [0xa05c6a0218671ec4fa3ef2d03980db21, 0x17426eb02415f1d682735382450efc76],
+    [0xd4e9e57121745b86577da8d106171e3d, 0x22a8a8f47529433cb471acbbceda9b6d],
+    [0xcbad751a721c660ed0f807f1693d6826, 0x10d066af04e7708e512a382310bd9a10],
+    [0xa3324bf47b44abeca3d8e6a26bf64c8f, 0x1322cabd21b8f9826da84816f3599f20],
+    [0xa713c122a7907b9d2eec39ec1e5834d3, 0x0e9033298b7fba81069ad708a401ccac],
+    [0xc58b8f0bb45a1d3932051d4541c9be8b, 0x277a069d3fc5701249012a0fc2d96d70],
+    [0xf31b538c8445562a5f787cb7916238de, 0x2b6a4d2b44646516e0511d03595b94f5],
+    [0x61cf02de5eb4c3344f2b3246f17459d9, 0x1e9b7e897f636c286ac7592181135b30],
+    [0xa38edae669b73ee5f704c018310458bd, 0x089d5576bf0677940ec8531e33761650],
+    [0x7b28eb08b850e47260dbb3d205ada63e, 0x11d91cb6a7c91f3a3778a457bfbcba97],
+    [0xd150adeb27ad95031c1e6d6e4585b9a1, 0x079ebc334aa377cdaf2bb057017b776a],
+    [0x35244642c87c54efaed43be19344eefa, 0x0eeceb66fbafe2552212467fbd7ee2c5],
+    [0xf180217439babf0369a7d6afda53af00, 0x1189bb18157591e582637275139eff1f],
+    [0x4bb66fcad5169061e4c74f42f8082ed4, 0x0a104e1bc5aef5ba3362773b15d152a3],
+    [0x95a79e2a7b8765c0e7f93721f5eb8feb, 0x12aeabc55f0a0a7394844072432e9f10],
+    [0x67c11361536d61cfac1e9eaebf9f9960, 0x2d5fa7e448eba0f3f75b82d155d2bfb4],
+    [0x57de5244008964ddc1ce7d887b3ea15d, 0x11bc382ad6f3998ae12761764e273e8e],
+    [0xcfb7b12499ad768a4c91af37f1fe999f, 0x2f9de52e1164d578244301dc0f52c09d],
+    [0xf75fd3fd4d98479d855e88cc40f2a146, 0x24a160dd3b3f19071f231118426515e7],
+    [0xe5a52b39898d7700c9082fe661ab5aa7, 0x2562dab01972dcd2307417f8356cb096],
+    [0xf2800e7728874c0d4aeee09d37fc8018, 0x0c71fc8544d8bfe1eb57eb8b19344825],
+    [0x5d27595ab5f3bd5b1e818a76b17edc8b, 0x1c38cee709ea57a5433ef05d4f3d8fe8],
+    [0xe8952ca6f775f6951aa176a6cc78d10a, 0x1602213816c60cc200e8b2aee509da4d],
+    [0x38311acaa29182226455685b3a6a1a04, 0x09a046c67b9802554d315821da1c3d13],
+    [0xa07d56f02d172fafdc326e99ed94475f, 0x0a2ac73a3b40f2e8d93b888e15ad9695],
+    [0x0a0b056117fe2d4868bfcb691acc9d23, 0x26497f1cb3ed83f61c32795f5460e232],
+    [0xc68e137697ee082e21d6e9f534adda3e, 0x1b1a886b957b5b7af3608333c34da124],
+    [0x13d66f3fda193985ffffa26388a8b536, 0x1a1cec97b3e63763610f3fee9e5f8843],
+    [0xa1112c41137007f71ffc53e90fd2cd5e, 0x24eb5865c2393f7e9d6da7a0460f9fe0],
+    [0x3e808de0108cae743d7995a23239bfed, 0x05ad739f4ae7385d18ae961990fdb8ed],
+    [0x0f2351841827a82103a36fef81c2b5a3, 0x20ea4df643c31b3aec0c8c2cb444ffec],
+    [0x7d0d0be868f7cc80740d14d6b6d985e3, 0x07e426d7cfb8d0ed8f150d76e11c42bd],
+    [0xfc5712b4b134e117c9cbf91c06c7a156, 0x0c284170139a3d32feb893e2e393be22],
+    [0xaf5c68c79f8f881338ddfb381bcab3ec, 0x198e892e0b6e96942779fc23ac86ed3e],
+    [0x06b188d6a4cbcbdcc6ca631c617a4146, 0x04f7274b4c460cc0d5d78d6391700ab7],
+    [0xf5a9a066997fea4d63cbc4479d153cfe, 0x257a207c9af017f50c24c8286e99a006],
+    [0x7c174a84a9cdd07cd69b4443a45f0c81, 0x2f9195e87792cede31f827dd40816069],
+    [0x74ae1fb598642acebb76d0210d0af7df, 0x02c32457f1c8a48108f1c16491e8da1e],
+    [0xfd31c2d2f1320acd915b84df1cd77c3d, 0x0d16062ea9a5b5184ae022c568e887e2],
+    [0x78b428e446adaae789c159866c15a9aa, 0x273821b59fc23f585f423e16c5d9571e],
+    [0x7cabf49d41e59f4b8ae942dc6fe0ac32, 0x28d58138ca8e1e1f410146ec8401796f],
+    [0x39355f83de4f38f01179735633ae3f85, 0x0cbbab4a22963ed50150ae37e8ae9cc1],
+    [0xeb9afab12540693b781c9397c0da489c, 0x2f29b839fb5759272d1519a17df03310],
+    [0xfd52de8ab3cbe3310f5262a9496a575e, 0x212505f1db73adadfdea6353b38b8574],
+    [0x6b645d94f205f7005ebb04161ea0959c, 0x0511717ed104198d0b67387b576b020b],
+    [0xdab7cb2d870c1cdac0e9651c21593d80, 0x2cd17a3fdff1b2ee153b65554fc06a4e],
+    [0x88853ce9d9e90242ef0ecbe4df94f4a9, 0x2421eced65e40f5bd7b7ce147ab08458],
+    [0x576b71fbcfbe0e8a0ce6ed9a60be4b60, 0x2b7bb1c13a605e06226271b1567d8070],
+    [0x4aff7d4e2a309481635693fc1e7b57cb, 0x20de93b09b10f6f40d2960f3ae7f703d],
+    [0x322712f5fc0db38a8098abd4d3fded43, 0x15f39da6947d0700036090111b9463cd],
+    [0x3201c3f69ba770c90736e59910299486, 0x055a10c5b2e1c3d3ac97853d37acc7ac],
+    [0x4bd7f68e4f8e4af22314df5ea170cb05, 0x0f86f85857f2f3bfa5fa407678359c5e],
+    [0x131022a4471f88c54d4db9bf7a18f475, 0x2d57680dee104eb6887c77345eca7a8c],
+    [0x4c20b97a546408d962e48f6adfdeed55, 0x01d25e7170e6b92b2bbcefed98f7ccf9],
+    [0x9d546b4268043f5998befca980271e07, 0x11dde898034df5d0a50a1067fe3a5b6e],
+    [0xa91d297dba610e196992d08045efc565, 0x0d96e451da1d5351d3848643cd82ac82],
+    [0xe046db692a340f0b814b472cebefc7f9, 0x1ec50e2f05af8bd16955612b7dd0a22a],
+    [0x36d990052e72c97540d79391224c82a5, 0x12e7a565e645e185f7c9e00f06ade717],
+    [0x92db7981559aa350e2ba70b3565632ae, 0x277d5ebf33d14750cec8a5df484fcdb3],
+    [0x257317a8c1b94209c3fb86fd94846a89, 0x227a8c5c6b1f1a259d5fceb57d7dc834],
+    [0x7d3aa5f7d61c4ff5d1bd1eedcec09f7f, 0x2bcc414a720c85a42f6ed14bf7d1e2ca],
+    [0xa9410070863d5da37742d665e2de3188, 0x1f7864a729e70f77cc1b5cd86c193cba],
+    [0xc9d9ac8068584c3ae69f56a9e4d72ebe, 0x2add533a1c14b4ad2a4ecd780397391a],
+    [0x638a7df278300e56059508f6ef65460d, 0x16198d9581405334a13e109b04d9d1f5],
+    [0x04b461cc4c436cfe8b1748d65d143379, 0x0a060a2599eb696b43d275c43eab0b3b],
+    [0x57007b28a3748798965cb4b281822b3a, 0x302e7b6c0abbf4f44b1d799791ae0cc8],
+    [0xc091e96ce505b7ba30c148e036b30963, 0x2b8971547ff17281a93943ddecb489f6],
+    [0x2b5624dc3f12b3e4a6a02570b3e84cd8, 0x21f67c406780578d1e13c001eb36a4dc],
+    [0x09ab94da865e673120f48846ac77bfb6, 0x0caeb5041b8532a723d9c68a990c0839],
+    [0x73fb47b533400bc79a35e18f0a51ad86, 0x0d576a5ddac69399f06fd5e247417ddb],
+    [0xcd1536af3616e994887b033125614d53, 0x06dcc4d20da7204e7e8acb7404b6b719],
+    [0xb88adb18cb43cb3a34052c92a8a0d6b5, 0x0d0ae80d2c03d27ef6d6ac6edd57faaf],
+    [0xccfdbe8f69e43eee315664c465fb6b5a, 0x02217b24cd9e573c8fcba46d3046d830],
+    [0xf314dca33ee2b92a806636e8509694cd, 0x1b037d4657b2b3c4136ff83f2de746a1],
+    [0x5fa3bb6212df994ba9fbd4e2c3591f45, 0x2be7d85266892fa4d49762ce64ef8457],
+    [0x96e4e418132bfd82fcbaf437210dd417, 0x2c2ec68b3fdbe4b10ca34ad1f1c9ee52],
+    [0x4fd562dea92cd30b0b75bd9a7880fcf6, 0x0356ae0ee778d427d6a84545bf241a68],
+    [0xfc9822df791ff6f6ad6c402a0beaf184, 0x0ea67bcd02a935ed5631194f06968434],
+    [0xac2f572e984804acd579f3c22e0e4ce8, 0x188bba4674c2e1a2b8d1f1db4c2b3de7],
+    [0x7a66e62974bb7bd3ca79e142f70742ef, 0x0d821e605046e0b7efc627e1f2dd8417],
+    [0xc637a4a34c33d1c9ca0ecc9559808659, 0x19bbb44064dac933d1074ebeab991790],
+    [0x252380a1e3b3ac2867b214d063b02d4d, 0x0b3f351b3bccd96bbca3453bab6d10bf],
+    [0x6560243c0115152587fa3b633bc7b14c, 0x1f657838a4ddf2b0639961c92619eeaf],
+    [0x903f2b68bf83ffd2b00b2974cc4f4c24, 0x173966b03236c921b1b6e4e4e77fba43],
+    [0x92292b150ae2205f9ee3b6837c687c94, 0x255adfccf4dacfd943393fd90822fb3e],
+    [0x2cedf6f69fdef8f68afe434442487a2a, 0x2ad9b647fa2c5d97d6411a5684ef1831],
+    [0xd88a90f47c878e46d55aa41fb23a00f8, 0x26c815af9baeadf098c8be65a9b66936],
+    [0x4133e613ba42fa8b48984667efae1053, 0x215300316d98fb3bc220cd379cbcf4b5],
+    [0xec1ebf64e1a65138c7f2d862aafbbbea, 0x051ac2e037a8cb84449738040cb5d92b],
+    [0xad8ffc6ac364914aabf33627d1de6a32, 0x1b0cfa4c9f7b4aec9c6a709a106d177f],
+    [0x526dec4a77bca0449f1101f00aad4a4d, 0x0084f5b80cd3806bfd40f7d188bde0f5],
+    [0xbed2b6e7e7e643e0d4c67b5e51099d17, 0x2fd6803e516e8737c45bf2f40736831e],
+    [0x272d7d6d623a8e59ec3ea67e2aaa526c, 0x0b17f732c6d938ac7e85bccf9a8dd401],
+    [0xf905da9a079017bc5299080e1d5d4f64, 0x01b3fb88f6cab80f3af974dd496c9fa5],
+    [0xffc4e51a44e1990fb3015ddf9a183715, 0x2319f57279981d0a12b06f2f7541826a],
+    [0xe4cbfc7d1d11d13f289efba3a3c54121, 0x2f4dc289b5e7f23a370b0f61053bd82f],
+    [0x81c89d68c708a81f8fe61438904c2ab6, 0x1b679b734eabbe6f8c42ad18dab54de0],
+    [0xfa236790cfda0023b588eca466f19216, 0x0a3453c08842ef3080b3af74ee8e799b],
+    [0x42dbe55f21226095045168139344f0a2, 0x11c89d2f5498a788416d0042cefa0d54],
+    [0xfdd88ee755643788837c18dbffb68ce1, 0x0b3f2c98ca3061f627213760506fe76f],
+    [0x46f4e21a7b2f1a4062972a08425a03b4, 0x2575c71fb8acabc5cb513bf66dbd4a49],
+    [0x5cb92e09545749de3f1a118d41801560, 0x1dec0944dfddc9cffbb55c1902f84572],
+    [0x0dd8dec6588f52ed5c3e1980ee030642, 0x2532b96a8b50828cc619ddce80a2e993],
+    [0xc4e6bd67091f59ffa1f4602fc399f21b, 0x00eb3e072e9fc2bea9aea03cf45eb481],
+    [0x2ef920770accabec486b96422698612c, 0x28a6e7466dab1f493bfca1991bfb886c],
+    [0xc04814e3713baa81323f2a1956957b8f, 0x18b72ad6c372bb8b3b52ceb5c65e9d78],
+    [0x5b2cc9d0db7f65aa1b6c8609918b2c63, 0x2fa98e48794fdd4cdba8e11d0cde18ce],
+    [0x9183aa514f69d1672d49ddbfc9e063f7, 0x022d6d05abbab403b1327edce33024fc],
+    [0x9d6bbee9f1b96d3fb3a151023a67fb73, 0x22ce0a2387a223228a0a86b942f135a5],
+    [0x188d99d935596d3dec2c033de5a22d2f, 0x0a559e751dfe1415e40d0227d7b3dd1f],
+    [0xb196153236f86c5968b31ab50c282056, 0x13202360f125ca22c281a18e21a7ac10],
+    [0x0a39cd28e237233f4c1be35632fc72d4, 0x1e85baa376985015ba63c3a38e7817ab],
+    [0x6d85da5fe59816af30806dd2e036c5cc, 0x2450b198b045e4f0cd540b0b74de5ca5],
+    [0xdd2edcc1091255aacb96efaac6712b6e, 0x1bf04795a7979533886f7fb47d5a0b7e],
+    [0x00c8b07c586ba04cf81ae699607e62ef, 0x0f126ed9490b69935be97110c476118d],
+    [0x17b08a90f431417d9bce87ce4fd2bc0a, 0x087ed4752046c22599473251c53f69b8],
+    [0x89901e25569863a4b5b3fa2682dbf9cc, 0x0015185030c12d8cca42f20872de6999],
+    [0x6e001556b08e19002298e05f1664dc84, 0x1b424b9546d87ee6ca28fb8197a2186b],
+    [0x9fa911e18880bc379dfd20ed1177eb2e, 0x214ba23a67a222ae9e33b4615a31e7b0],
+    [0x6ddb6060c993fa9e83616a7daa6c2243, 0x186e0edc096c6253b179102a7c9ee149],
+    [0xc9bb7b6cfb26859c7bcc0d1f7bc4dd0f, 0x151bc9534ec5f36cbacdd7bdf6176a60],
+    [0xda68adddbac3a4ddc18e69b95239b166, 0x28192f62ad97323e6cadfcbb9a99f5ba],
+    [0x439e12e7baff84db3d18b7bd45172b18, 0x0825b1232e6831678f691e7e1eb27888],
+    [0xcad78732b48596d16b44eae459a59fc7, 0x099e93500b62b10127609071e75f4c54],
+    [0xa2ac15bd7057485b43d47340a202abba, 0x0dd5cd3a64731f803da7873057eb0a49],
+    [0xf03adc3da75822bed2b0d8b690b6a34a, 0x1d4cf162651067f82fc2124f6d674dae],
+    [0x1c33b46924c3f601ed79a87f9c464068, 0x23a86b440d5efbb0a26a76a9993b6ec0],
+    [0x7e2a66053bb081bca55f55635168884e, 0x2c983302ac40a26cbb323eca868e9a75],
+    [0x980bd1c808f1f7de799632a90ff09b36, 0x06e98d3bafbdb42dbd2ea2fcb9698273],
+    [0xe53e14eb884989381d79c06f9fa9084a, 0x0f2ebb91192276ca7521084cff7a025f],
+    [0x9081981eb6177db27e5d6f6d851d5021, 0x0b67885ad9dfdac5f5369f6e3181bcc7],
+    [0x94784b6231ff8f85a9edff92f91be4b8, 0x1c32e21095beb791e1bc785043bb33cd],
+    [0x3ad88ced9df449626506be1eeef83264, 0x121818dfa76d98589c3274fe2d702da8],
+    [0x0622fa6b4ebed066c655be21da02410a, 0x2832ac9bb20b57ecc969ba4ff5c713c4],
+    [0x5bac6b2de19f516b37641a6b1c423434, 0x0917180fbd7a19816e8fc3a45fd43b43],
+    [0x7d1c3ba680fb978a1b2ac325bed411a7, 0x1bd85f4c4b11ae715f45a17ba21ddbdc],
+    [0x0a29d721e4c1776d42d783bbcfd22af1, 0x0d0f2a86fb0d48dea88049d2bcb66703],
+    [0xcb96116844491b4277ac0b5ef19b4432, 0x0460af88f6d11afb5d191e488712fd79],
+    [0xe9025f7784bd3efb8dc72096e2c56032, 0x0bc1783c36accadd4fba3d634e9f72c5],
+    [0x423fa50db34de8d5ebaac1302e6f45d6, 0x0eefb878f6eb1589d7a687e94813d9d8],
+    [0x7f81f429538db9b408a3dc20b92c11d5, 0x01ac403196501f0054fa8d4b566cffdb],
+    [0xd35f3ba8c1e857df1f729c52e6a7e5dc, 0x1d5bdde7d64a63bacc2585b8a14082d8],
+    [0x3e1bc3ceaf3ccce077c8733869e52b75, 0x0799dfaff2e7d630414a7ddb0fdeea10],
+    [0x89962492f14353e1afc78efac50ed96e, 0x0fe0f816efd74a7eba5c8dd5e7a89438],
+    [0x8ac33af26da6d2ce77cebf80292bd27e, 0x072561c67ad2fc0793821b50518d4b5d],
+    [0x9986f9463f5a81c615a7cb9f4be0724b, 0x2891352ac60ffb09e9c88660b8449def],
+    [0x2c29f235f044d56c8244a92e882f464b, 0x206e9efcc46cae2cfc7e866cada0a4c7],
+    [0xd04f22a061ac8fcae04d2d93d7c8dfd2, 0x2e7c4493e77005f49407f2d5cb46cdd6],
+    [0xb7f23a1ce7a1e55a6c4b9c382a511524, 0x0b9e944d5bacd889beef5909a139f5e7],
+    [0xf5bc988a0a6fe53dca721304f8f622cb, 0x2635b8471126523448d0b1bce5d8f54f],
+    [0x530c13da58d6381ae0c517f2ddc88e02, 0x10df73bfddab994cdc079216cb585ea8],
+    [0x644b0a9539b27765df23eba241fffd7a, 0x2da54f7a7bfd78f40c98e6ecadb665f2],
+    [0x444d7709bda7c5fc59a527cb2c166509, 0x19ce35c6a104ee3975d4a94908f5b1e0],
+    [0x8a1c86f8b4666d4d118ee99abc115a3d, 0x1a47dbbde2d5004a1be0ed7c21f57a38],
+    [0x7be8faca34c58bc50f021e8734c5399f, 0x2f037e73c7888cef9d7046e58d998883],
+    [0x102464c3f5fa8deff2762af8a8699e7c, 0x27ab727198d9b7a62e0d99b2d1112710],
+    [0x730c4b4936a1bd83cc5574c694080668, 0x301176359be9b665abc8dda6783d3bd7],
+    [0xae309599dce18929a9430100fc5c0869, 0x030e87204cce403ee4e92e0507e8e99c],
+    [0x66a6e9963c3ee6eea46c2b193688d3b1, 0x218e8653761694cf49a1f3e275ece288],
+    [0x11ddb6fb271b5a547946f0de32698a36, 0x2b219687a4549eb17e29db9fccb5796a],
+    [0x9e555a398bae96d6a2d6d9a616ece0ab, 0x221e784939d29eb5442fd1612b30a58b],
+    [0x6e330678c057f7c34993f27eed2e14c4, 0x080fbdb9bc4b4e8a83b2ad89383b2552],
+    [0xb9d8c3edfcb7bd0372d0df43e93e9123, 0x1601e657a22b8a280d4452d527beccfd],
+    [0xd4773e8a5c61301f581651b50e359751, 0x0652d1d878dad9d7f854e48066531fff],
+    [0x531ad3ab580e65d537f4f37e4da26027, 0x10db881688e3db58bb608ec993d36ec8],
+    [0xaa9ccfaf764dd70acccec7177a4c7037, 0x1d8a06b51927f0379250e7359f9dab93],
+    [0x2a2c0f68a5cbad1f5e1c99ea2ca2623f, 0x19cbc2ea50b7bfbf009f709d159aa9ef],
+    [0xbb0cd658be590aa3ac3b413415fe3e8e, 0x1a5279e38883b53f313f86ae7ebb07d7],
+    [0x645234f4397140fd85a2d88a484eab0b, 0x1af136d53cdf676c284100705d04a976],
+    [0x088f3e9c2c555278a3c9a7376a893232, 0x0db8af764eef50bb1ddccfe0583ff730],
+    [0xa207bdbaa33f912808ac693f137d5d51, 0x1613c14bc86f95e25e05f8ec72c8ff6a],
+    [0xc74d2d3e2b9f97bcc43e4783d1d98cf9, 0x0fc47ef92f10f79d336550ab3e0a7545],
+    [0xe8b011797c66fe6fb0474d2b711241db, 0x0f2cbf40b344707cce101e7797d137a9],
+    [0x3094a9d2be4413c817c751dcf56d8e1c, 0x2efd5777f8ca22678839ac60c1a30870],
+    [0x0ad0bed91bfc33739265bbdc2805b180, 0x2ab4e3413795ad9a695b04695db31b6b],
+    [0x7fa7bfacec12bad7afa242744e00c039, 0x2e3b6d8584e42666829c0e195571c50d],
+    [0xb8128a96e3813ed2cd00e322dc91c8d6, 0x1833f151de5bd86ca68d264d5de5d353],
+    [0xe8e423257732f9f686f6b106d7daf0ea, 0x0ea82c252d8d55499ad63c61c47419c8],
+    [0x777f4571e92174d5f82c07b91f2c85be, 0x09857874422693705a7c9806340c6da0],
+    [0x1facccf610cb947724e7a2110d501449, 0x2772ac7928af0a79931f88a87e634dd6],
+    [0xe2631ef4e2cc67e8046fe2e886f6769a, 0x2e62239b790530756ca329c63e6f4338],
+    [0x2dd587f88139bc60d74bf363c6712628, 0x0265bef6b92cd962298621e2609c9016],
+    [0x70b05b155e8bcf330a56b5027cfdbc30, 0x24484c0c291379917d2fd8b48439fb3d],
+    [0xbc91d0d50936538cfb0173360a2bd8cf, 0x2b2d449a3ab55ab7ee77d37759db58ad],
+    [0x2d3ebbfd0134f861ab5529e2a4c7de57, 0x23663d1956fcf1a723085825cc67cac8],
+    [0xbb4b9975643fade00532c1bd1ea0e411, 0x28fcaec0fb6cf43a573261d10ed32c1d],
+    [0x537851eb5a42eddc04caa52cb3ae8abd, 0x0145c6e2418185a9ebc72cd8dd0f53dc],
+    [0xcfda378faa64e85626bfe4636f3c580a, 0x167376a042099efae1cd8988e02c66e8],
+    [0x4107c4a8df58ea674ee7fb719d0dab99, 0x0cb735de0e612a615759528eb278b1b3],
+    [0xced070b8811be72905bf7758f3ebe884, 0x2d67bac6616cc0464d4fe907e5f68f0e],
+    [0xb7f010a0adf6ca584d7ae03f947d216b, 0x00dbd2a2f8d7fbf3ac403b1115523112],
+    [0xb010fddc7bd5abaa82dfddcab3f85c7a, 0x11a992d7e8205757ae36bbabb544e843],
+    [0x307a4c46e2f4470d2c319340fdbe0025, 0x230d34341246fb715b784797e9dd8bb7],
+    [0xd25150ec1d3507b3a7170b9266ff6941, 0x09feeffc218aafa614e8fb7dc4ec786e],
+    [0x9731f7c3f998b7fa707dd9fe53833f82, 0x1208ff94f1223bd249daa4e3a0266e07],
+    [0xcf349fabe16e81f2f973663466802da9, 0x260ca3cc820f873963cd26df1b8ffec4],
+    [0xde68f14a7b734b3834725308609bc854, 0x12655e2c98ad35ec444a134e55cf68dc],
+    [0x46145f3a9e9e0ba6d7ccdaf7b7e05ef9, 0x290097f2c047a3dd6600b706377d0e1c],
+    [0x22b0555bf7ef8b2aeae1b984808d4f81, 0x02c62e5bd65381e0972414ce0090a96c],
+    [0xcf088a63e8da765c5182649424926fbb, 0x233c642308dbf783701420788b19153d],
+    [0x57b6c3d9be5d0c2745bcc76476f8ce50, 0x2b7328e368ed4b7a62fedb9cbf501045],
+    [0xd8db78b70a95da0c2394c4ef18992855, 0x1738ea94cd4331c7b609f42f27c4dd57],
+    [0x076d2ddadef80e31b9895eed02ca21b8, 0x2e902d182a1fe1f3d40da28c358c7e89],
+    [0x79e666450c5a65f54354bbe8676f585d, 0x1683ff7e84a5a4f1dacb206fc44fc921],
+    [0xb54b55d3dd3ed1e1cbcf382790d6254f, 0x132958454e01c1fd2bc43e02424e1296],
+    [0x031807f1ee37f7673738438aea64eb00, 0x0deb48b7df7379266b123e0b6d83a1fe],
+    [0x62d58ccde006ee96bd691f140b15f386, 0x2c683489b3065e4ae5d7106e18f45537],
+    [0xe8152541d878e24876415572734ccf32, 0x1d7c17b521e158ad420c9d69237f4ba6],
+    [0x106329eeeac1b1a59614726561db5bef, 0x064dee7f04cf564333fbdc610ab8bf68],
+    [0xf38b0a29d3849fe384777d0048abd660, 0x21e94e5d9adad9dffcc186e0310ac54f],
+    [0xc39a842db804196bfa07044ae6e2b4dc, 0x16ee0a0b45170d89c5d91557ead0b253],
+    [0x28250e9948807e24a62805388cee836b, 0x0a7009f825b6fa180455fde8a901d42a],
+    [0xa8e4952a148da641d0e15f71efd2cf68, 0x08d5a34853ddfb099fb0fe1df7460ce4],
+    [0xbc5f5ded5b21c1d17237cccf9b1a4812, 0x0fb198531bcad7bc91489abaf1345a3e],
+    [0xe6cb99c285b0705542fdaad40e0b5124, 0x0a91f50c72a422f3f0c06cfb1401dcad],
+    [0x717481b673292246d98e7728a13bc924, 0x2db33b34e5d9a988af35501af4e137c4],
+    [0x5d598549cefbc7669bb63a3d4100ed4f, 0x1b745ff9c6e53c275d378f0a69d6c0d5],
+    [0xf6a24a5c26b875e7344014b2a9858327, 0x114554e3afc96df3b663c62243045e27],
+    [0x165c53ef336b3d96f55fbac58a542bdc, 0x1aef968db4765b450f098d4e1a6210b7],
+    [0xb36038c50bf1f38cf79055279225be52, 0x24bdf5ec4ebb16db47837189172e8a55],
+    [0x10fca5b0f13babddd62dfefa980ff78f, 0x2ae7c4a1655975ac7052d101d1346efa],
+    [0xa0e0cfbe46d63f25521c9af74028a8f0, 0x165cd429a3966d1bceda614b06d459e7],
+    [0xdca2ff1ded3fe2108d125679097b077e, 0x1bb3d4b2f67440a5fbe410756945b7bd],
+    [0x7eda049d50b9a5d7bb284d52f9033d2b, 0x18695f0185440a5f35ad74a5451da633],
+    [0x460306e0e3d4bc618f81e9ed73b43a0d, 0x02eb1bdb4eaa3fddaa625344ea650afc],
+    [0x229fd8fc8a7e112d7d1a24e604d3ef83, 0x1de874e713e5c68d2f56d518f8eaa275],
+    [0xbb1f0735ff2a2d2b68430f4d39b109f8, 0x1e5a6feb4dcefc2b68a5b2c3d0664daa],
+    [0xedd32ebd7af86f44ac50e354f743c782, 0x27a4284ee0c6f73ddad02f9d499be90f],
+    [0x8cf62126788d85b2759774d9de75e777, 0x0099d8e9546fd891bbab547a0ec1dc83],
+    [0xba79831ed8d8649b5bafcf75a00cf759, 0x3043a7ea89d1e4fe14f1f91b584d00c3],
+    [0x8c9096363690f53eb0e99b7d628897ef, 0x0e7aae04dabfa34859024d075a3014e7],
+    [0x06d6cf0d295db09b4199eb3b6a9f06b8, 0x10fe0a11c4030def725ce7684fd8724e],
+    [0x654b42e9ed5d8d92a48f1dae8d2b4c23, 0x168cdf6baf5e970e1e6bd7809931d487],
+    [0x128fe8aa210772bbae5957cb60a239c5, 0x0d241d33c552c85556e62a7afe49e5a7],
+    [0xb776ec23392d8cceb6c392afe5961f3f, 0x1e25c0e00db77c69b87d5bcb95cba934],
+    [0xd8803f920431acf9410f7fd11e9d7d32, 0x22b0e52f766a702afc38c2c60161e352],
+    [0x752ca01ee0a088138c40549a31164fb6, 0x12ec9b91f395f1665567318bd3fced7a],
+    [0x27f1b5d1741abd71a82eac0e1fbf20d0, 0x299c8fdca8918c24daf876812258a755],
+    [0xf4353ce7dca9bc22763b69943344f235, 0x0f428bf91d4d31184f35d770a5475deb],
+    [0xe752e89c203c25b624817f523a79e9e9, 0x1be0549a5470c9429f0e1b6f9482f3c4],
+    [0xe44ca6c96179ead64378cd356dd2acc6, 0x0fdcc3ce028e96b21f91bc87ad052d78],
+    [0x309fd3d169af68af8058dd8b605eaa13, 0x136a6a9843fe104838dc085992c0eb8e],
+    [0x01b93b2ec02fed5b6ced7314a127e66b, 0x0584441581ce63a4ed2174db35365bcb],
+    [0x135a5ae26b6b3511cc906156b621cc13, 0x1bf9ab90b1f5cf6ca9f0195bd4336e68],
+    [0x2d44d26b66e9bd7bdd96abfbb33cc7b8, 0x2b0bf6b3cc9cf023ba13dc6501f00a74],
+    [0x2bb8bbf1aa5c2ee06e1f8e875991397f, 0x19d18619bb73548590c00c7d02ec94b5],
+    [0x71b84d3d9169fb7673317aa6b28433fa, 0x14909f8c8920f79d4686a14a1aa6e03a],
+    [0x6f097f5a350886cdaf0691d0a8abd8f6, 0x2397f311f598d249e6ccdb9af6ae5beb],
+    [0x04608763d69b94ea50288dd7b63b3842, 0x0ac2f7a3c2e4a6fd58f9e502f9dfcfc9],
+    [0x5aa0966a3c58d3086ecafa112f5001d1, 0x0abe36a7048a34d9110a463cff2fa23b],
+    [0xa745f077eb15a960e5d7af379a18dbdc, 0x245ae4790107afc9b9bd1f14c20f10d8],
+    [0x0c56d230b489d08d32bc3503e73bf4e0, 0x14ae110b2829126250e1d694f08436ee],
+    [0xd838770c780a628b1cc720f027f61c6c, 0x20a0576ebdef08dbe219160fdd1a7e75],
+    [0x2f66e9f963c697d7d8e4ecdb55ef833a, 0x23a05ca5b721e48da57d373df376c525],
+    [0x96343f329f65c4e165b5b1387c4c64c7, 0x2813d89a8a9061c300348cefa9447f1a],
+    [0x91bbae48a436273dc04916ae09a64553, 0x2bf7ee542e32e28081fabedd92f4b181],
+    [0xea89cc51079fb5d0cd3654a9188b1385, 0x25c148fb26cfe4c3d977b17da068fd67],
+    [0x756a627897cf47d91f9f2b1ec8ab2bbd, 0x1d3dd23e6048866f0855741903765842],
+    [0x8a5e56a44687a8de351efc172c9bdf50, 0x0af7996cfa4b51163b4ea8ff49ed2ebf],
+    [0x83ac7761d8c0b4b53ec669c693698730, 0x0370dfbd98a73e24952f0208eb7e5bfa],
+    [0x1ebf8d995d5b27bc5c3a92d0438240f8, 0x13aacdbfe05a0647fc158a1306803cbd],
+    [0xb69d4f30579a6b8e9da5d22a4f7bfb26, 0x050fb4ff23f5677d7f42b0c8184c224b],
+    [0xcafff7c34382aa9e19de473a2c67e393, 0x0cb2f5cee85cc6e0f9becb391b7edd42],
+    [0x70996dbcf40a7aa8b6750667b96a2ef2, 0x0a146430a98a85f906d31e8ca7b27575],
+    [0xc8095078e547151662abba0cce1f627d, 0x231efb23ddd7cc3485b8bca812898aef],
+    [0x8b0eef871aefb94a21a3ebac83561350, 0x2ebcc008ed0ecd551829656c73b6ba8d],
+    [0x1dc0a41dffd98a1516761d400f7b379e, 0x1d2902e8b627c0443bea9f8aea5601b2],
+    [0xd9c2c90425bdf1a2a566f9e4ee46819a, 0x277358dbe09c3bb9570889293f795c41],
+    [0x65a43fca504f6f675193f8d63c6039bb, 0x1fbf692e87da200bc4ffd4898893cefb],
+    [0x593af33bb80a15eb7fb88a09d97e0faa, 0x0f3b881fb65b30fb2f849620e74a0dbf],
+    [0x219be6057a4b75bca3a7bd7bef88baea, 0x0a116fa32c97610866b3a93be3919080],
+    [0x8098a754079a69b0e44faa0a3beff23e, 0x30384a31b80c532ecca12d2c61ce4eb9],
+    [0xa6e4f0354a986cd27e9d03aa6a9fd181, 0x1403e729814addbcf809e49a5465ed6b],
+    [0x860aa2d8eac3fa411b68152bc381cd24, 0x11794627e6125ac465ff2ed71d959c31],
+    [0xaaa27314b47f85773b6e066fd4e63dba, 0x070354e3713899b38ad74a7f5e3154ec],
+    [0x2030fd7827d6430c2bcc53d2800631b0, 0x2d1e01fe7b49660ecefd093b5ab269ae],
+    [0xc0c88bfab803e135042ba1b8bceeda3c, 0x11a01533e3713e1f38f94c3906843323],
+    [0x0e0aacfc3a88dc320187d8c5a015d159, 0x058e4bcb4f7463bc6d02d6c40d7e858b],
+    [0xc50fd5f45ed943a8f11b269783335e74, 0x1b2ad38762c6918201e093d2bc393f9f],
+    [0x04a162a0e17577a6e55b8025b1814b0a, 0x05ab9d393a4bb7eee00723e8de668b24],
+    [0x278b09ce1219ba45685bf8f6d69ced77, 0x04058adeb5ade9a9c9e3d869edb70322],
+    [0x1842432f2f1a6c66101b1ac8d11c9a19, 0x21a1c7c62cb8ece8d3bd434f87936c3a],
+    [0xf38184ee0fb9f1c42121210542b00100, 0x2028efb9d44e5af38c0c1ed35f9cb843],
+    [0x1c2d244404c64a41d516076ab0240ca0, 0x24b0792d69eb31364cdc8b74c109223c],
+    [0xe5034d3cad366105341e16ec7483da19, 0x2179ff52ea2032efd95ac4e7a37a9c0c],
+    [0xd598a19fa41f881e12cf4a5edfafa13d, 0x29cbadb37f773c6dc580cc0c2ebf2926],
+    [0x0c3510648bdc400b97dfb7cc4d7fbdf2, 0x2b892f09ea9f60fd7204852f6bb995e2],
+    [0xbf2a03a2df5e6b8faf69d8697990c95b, 0x13badbd0953e09615a4e13d4b340f4e1],
+    [0x69f862124feb62eec83c7150d9936411, 0x2b8fedf8c3662b0a10576cedbd720ced],
+    [0x56791a37b9f21d03f95185d9926c72e0, 0x02b3d62d6efea0a22e2d968f919a8bea],
+    [0x525edc729833c89c660406df21bfb652, 0x07037bac8b6690794dd0907f7e315baa],
+    [0x4f4cb3cd33e310e9fd4bc3c3e6b5242d, 0x2cf9ea47493cb7867b86942e353487cd],
+    [0xfdb4605d8f5a65522426442906edac25, 0x303f5a8faaf2a6aead780f2a13937d75],
+    [0x2eab336a8d91a605b9dee9af0af44f26, 0x091b628bdfd4d49333c2a9ed84aba3ec],
+    [0x13878a653685abfb0d2972fb0f7f9e32, 0x01f468f6ef5c35d3c95592e27b1de7a2],
+    [0x8b076c640cc4cfffce57e9c7477b89a0, 0x1f5923725d991b36de8557d299cfe27d],
+    [0xc4404b207bc5e216ab64b7e10414a6bd, 0x0dd5d7b1d0d52266c387c7e111fab8ea],
+    [0x266afe33f76bbb26da8457e966351444, 0x26ea3dc5cbd01be1c06cec04ef328a7d],
+    [0x958a4bf1cd1eec4c597c25e3a00acb36, 0x067231875a3d536b4511d0a0b21b7d24],
+    [0x4e669458add9a6779d89cf3d3bdd9da8, 0x0724bd4d523a70054c995241b67f4aea],
+    [0x1628b24120990fe6e29026a0b21e9ba9, 0x1cc9dbf8aad45d67178c5b91a0aa4470],
+    [0xc5ba89bda78842208e24a03c46fd32a1, 0x2b5841453a6653fd30c05d2581dbfbfa],
+    [0x227bc2dd4e3d46e133ae268154674f8b, 0x25a1bd92c9ca10226bcf7fbd9b1d8e2b],
+    [0xf3c4b714a907c63f4efcc5bd5cc6e827, 0x01c352fbd922724897ab79a60e47e5bb],
+    [0xae9cb98c6f499bb67681fcf60f7e82b5, 0x05e44830ec170a4a5dfd9de846e7e422],
+    [0x26201d668ee404096b69dc85086ea9c2, 0x077c2736ac2d0a070a1a1c6e45ea9cc5],
+    [0xee71a1137dedb6a4b76443011eec3149, 0x0303f34f6aa229a5228fec959544f831],
+    [0x4558eff7b6637291082c5eaa9ffb153e, 0x273011559d239160d463a2b9890096ba],
+    [0x3f2cb4ecaaa7f0e02919626e58afa7d6, 0x0c4dc5efe016498ea823fad5e12d2e00],
+    [0x692ff7a0d8f0c114f6a080daa5a3ac12, 0x1cb990d809fcea3daa8d03b3bcdf147a],
+    [0x1a59b7453a4a34056e92e771b6dd4f6c, 0x1a0a3d386c26c3d467f50ba733e6d790],
+    [0x041634cb3cf22219f999a54bc5033b7e, 0x20d81618c0e6ae5ec303cfde54346271],
+    [0xff523817cfceccbc2c2de5bbb8dec0cb, 0x0a39ba8f57c793fe65b66907cc656a97],
+    [0x35e6057e64949fb6e7690d3c25923f6e, 0x0de056fb319526dfec15d502173483ea],
+    [0x6e61a262c7b470ac5e97d20a4a4d8aec, 0x03f82ac12d5be639974794fb3c2322bf],
+    [0x7197d12abb0b5ed99392101390de2a46, 0x0835ac61001ea7950d80e32fcc20bc0c],
+    [0x3cb553266fbcb1564452df9016bb4975, 0x120efd9c0663e0ef3b6c596414f018a7],
+    [0x5ec2c1f356a3a5cd2fd2372be6f3f259, 0x05afc3602e02391bd4e2c5ebdda28008],
+    [0xec403922c4479c55ab3b346536ac159f, 0x0f885829358024fc29d041eb29a43130],
+    [0x0c6406abe9e77b4ad1b8f36c9ecc8f1c, 0x09752c21e2a9c7d6d5b89634288e119a],
+    [0x85d88ec70bde4bcd750287016afceeb1, 0x1d6bb32d414b8f98583849694ad7ab57],
+    [0xd5ea3c4c1b3d176bb51859639f924b54, 0x07d455cd69ad00e93b09ac44915f64a7],
+    [0x50c9a6035e66b98bbad1fb625181abab, 0x118886890f7d3dde9af1204a9590e107],
+    [0xaafe307378fd000da08facc33dbb043d, 0x245e7b3643a7766566395efde6e47601],
+    [0x8d119ae64edd6cc84e4b34370f9409da, 0x2ceb6176073a98a6613b40f768654dad],
+    [0x9eca6692fa970287a53317990ce81148, 0x1b4e4d410613ae837b7f6a7f5206a03e],
+    [0x0e3a2226520cf523604973da274d10d8, 0x080057b8e664ad580ea5f385105766fc],
+    [0x083e14f2843012ab36e946d4b901b0ab, 0x1131ec38d8960567a7fa9bd96dfa7fd5],
+    [0x9faff11def7d003066d8e6b0fe7fb1a4, 0x1d278e62c58f3c17e14769fe3dbd6c6b],
+    [0xa7e6361a239abd787b6ce2f54615ccb1, 0x0710431fa88e036f180f0e4d46e8ed78],
+    [0x76f13ef5175535d8f97600b8cfe3b5e6, 0x301e86b0693c07df60f8ac15ac6b1caa],
+    [0x5448458c4d2e7a87a26cda94ca4099ed, 0x25870378c0b7068041b92a7f44b48560],
+    [0x1266005cb0074b9886eb2c4bef5be09b, 0x1176218d48c39ce7b6cf46e760d34887],
+    [0xb0f4e9b6f6e32b3fc10942387a9a8125, 0x1e1efc3b03fefb1c972891409432c02f],
+    [0x29828d4bd840236f14327e186d3946ce, 0x0192ce4fb97eec768607210a2a8fb1cb],
+    [0x38a31d42a47cc6d910ca0b04ee10fd8f, 0x09d944903456cc43a43e9827368755b9],
+    [0x442a89519ca71bf10d8ba64fb2aabf34, 0x18e4179357588f230ab88e6fa610d882],
+    [0xd70f9df71d977a07d934379fee629378, 0x25d0f7bff22a9e99775d8f388a74d4ff],
+    [0xe7068abf79478e2b53f402d584f8014c, 0x056ce0d47418e2c1ed7ea63d7b06dcb7],
+    [0x79c953e8551f878c4b71205387a17b41, 0x20dd7949307bc2ce53323022fcb67653],
+    [0x493ca3a87a19b182eae89812db3aa060, 0x04b97cd5a47874928e8e3ad6de644226],
+    [0x79376f8ad0fd07830045b6f1e9452600, 0x09374a10aa6cb0b2cadaa21e9f10459f],
+    [0x273f7f7a51955bdba59df3ee5ea4c95b, 0x2114b5f038cd400d24959916f83d0cc8],
+    [0xb7cd5933822124189615611ff4ba2d91, 0x2751d812405f13d21c4d5937d20a981d],
+    [0x9d63dd094b965024411281ae2babbf77, 0x0cd90a882276ea2b8a7273eda155a6fd],
+    [0x9951edaf0c9872194456195e9781581e, 0x0283bdacc14757e28398e67d6e792152],
+    [0xf25ecfe4f79fa3e02851fc06108fd498, 0x16f179d4dc4f2101cea8d127a6dc09e4],
+    [0x99cfe5254d9a1b3aa634e5456afb700d, 0x286f91d1b79d9e681d6c540bd14af832],
+    [0x98e2fd75e86e61c61369c6f59ab47bf1, 0x15ccdfad34d292269d6734c173e241df],
+    [0xfe078dc41c9c6edd0a390303a9df210b, 0x0ac6b90c6b7a47a1eda9d4a6d466b2ad],
+    [0x2c8d4c152a2a30f81e195fdbec764982, 0x25497ca515dafc37c125efdce7ee6607],
+    [0x8e6728547e648fe80ba947c475827e2b, 0x280948cfe3eca19e1cd0fbbaa5fd23a3],
+    [0x7fda70232ead9a157f27f6014885473d, 0x0cdb1b6412b6db97dc592bc966c6e1a6],
+    [0xfec5373fba72bec6fd2f52bcb386682d, 0x0c438d5abef5042a0a63ba4318d78911],
+    [0x22a0f7e639858de4ca01199c4452060b, 0x219ec582c9278e78e48798023c5340d5],
+    [0xa4f586eb09a7beeb17c29a625bba8173, 0x03a092730cc3b1eea8a5a761259dd2be],
+    [0xfd932ae0581a856a395a8a52662339a3, 0x10ebbfc35233d7cd631b6204cdc89ba4],
+    [0x3a6ea6c30a24a675d51b9e199b6d7c30, 0x22ce0890d9ef4f2b0ef4e69827dfb68f],
+    [0xce18442a19b8c281d49b2e26534fd8ae, 0x18948f58035bb10956bdf6d4667c2e16],
+    [0x4e281b7ae3678f1d52d352f04a9f0df1, 0x2619ceedcb834ba4008853aeb6a8d614],
+    [0xe986f094a83e09d58f2bc5aed2c8beb9, 0x0244c74d793f1a16f197a2fb001bdd31],
+    [0xdebb15668c180c8ab339ee5b59c87cbe, 0x16ebedd4b23d468b9f36cbdb35e00187],
+    [0xd29becf51d660c86bdaf38809677bad0, 0x2bc41c9eed9f63523245f4b412216715],
+    [0x99dc68012eb7d6c2c1d4c38a0673ea23, 0x009edbf534745f8c735ac87a602d806b],
+    [0x189a25a296efc9977880782b31407989, 0x18dacc8d13202bf232616f3a175bc021],
+    [0x2c2a3cf6bf7be4af77262d3917354ee9, 0x0b2600a07263e83ac076bb1c4b07f48f],
+    [0x4c234e99d180332893f70584a17f116b, 0x27f6d20fce0e747cfe43774331a19cfe],
+    [0x94cb691a9b16868a2deffeed1bb3f62a, 0x1050ba20c8f4d797a1aeec9de46fc7f7],
+    [0x7ff8fe2d418178ec600487ac8e65da20, 0x07ddd2115a1e35311b052494b9c05ed4],
+    [0xb83d6e99668168febed37160adda8a2c, 0x152a65b5478e0f92ec3fc360143e6a1c],
+    [0xaa71ed4437f8cdeb885e3d4a205c370c, 0x21de44ebe484523c17a9ce162f42fcdb],
+    [0x36a1be4d7a64ae23f7cdf07ec93a0111, 0x1be37644a1d3a397b6b549810568ec76],
+    [0xe5ed4a8ab737f3775ef61489298f8759, 0x18e7b112693223260a25dd17c71e431b],
+    [0xb648473f3747f1eb28a78c7e03a3dbd5, 0x0023f7f710bc05777402a21bec4372e3],
+    [0xec79cd12cb1fa49992a3b9bcd0a25de5, 0x2470de23677a016a0004c6ef292064ce],
+    [0xc6f52d8b53b617c4055bd24e7d27bfec, 0x1d1fbf8b2e41000803ef6a50be409987],
+    [0xd656ad2086abc311b67f3ac8b8c9f367, 0x027f5fe1f4bedb064473be4b6eac1ecf],
+    [0x0af9ae651db1014c3f39fa5b9b8ff852, 0x2d53af0119dfa77c28dd102b2828fee8],
+    [0xf4afb74a8c43e0366036e80549a71fd8, 0x12f19947c2645f4481c89113f4514641],
+    [0x445fb590f1088ebe4a4f01fe5f4c2513, 0x2f0073674bf4fd79d249638ec64786a9],
+    [0x00037a95654eed4b6cf4a185d1c9aeba, 0x28ed45b47e0c45c29abf03b265d9fb75],
+    [0xf3c37ecbad9f69b2711211178683be5b, 0x292142dc76706392f3006b8f5b45a451],
+    [0x45ff03b7cc605cb8b05c3edf2b9d67fd, 0x17eaa9eef3dc9a7059bf6ce3a51cea4a],
+    [0x516a977e6f2ed07da85b06910a65be56, 0x1a167d224f703b83f20a9ae1dc90be77],
+    [0xbd6fea80535b2a0e30ae8933d98026ca, 0x084eebe766b141d7a2ecfcff08750b04],
+    [0xe18cabe290bf8b8387e9d1fe8dedca2d, 0x2ec0f402c6cb9709e6e3817e2b364285],
+    [0x7772ffd6c2c0f9b62836a1e8ff6f4780, 0x1657e26c61a0eb6f8bca74c5bbf34a94],
+    [0xe77180f3c3b959979378c47776616ab5, 0x1f8827beb7dac86958edddea38b28f99],
+    [0x7f387006131b83f237fa4c9668ed4fd3, 0x217a07d6592fc0001fc240be44f71c01],
+    [0x4e3904e17f16f139e1fc6f0bf27ada4b, 0x0596d9d6c84f745470fc9997c5a1ce9d],
+    [0xb41738eb06c9e98ff0277fea16c2b0fb, 0x0bd2f1e61a7ed47726482de76ae4b0d7],
+    [0x519c3bd5d139b7344678f74a36f4e4aa, 0x14e4f14b2a7bbf9dd8c3a1b5260c3844],
+    [0xdcaf918a6aa3c967a5bb55d97a8f6dac, 0x0980b0b3d80710a167df147f9d475a28],
+    [0xdf855069fa1b5e7e86f27bafdee60ac1, 0x25a6def1f1a79e8d6356671df1ca3350],
+    [0xd37e9e5d0915582e7a71c53e536b64e6, 0x2cb242a29724740e63d8488ad7dad6c5],
+    [0xb352fb29579b21c6180cd3fb39ab7afa, 0x06b32dc0408b1782c052bba587c379ce],
+    [0xff6e90182ac092f12145ca088fdf3f47, 0x09f20a1375f56e6bee5326ac7563709b],
+    [0xe6764e662cc0ec878b638826dd3db7f8, 0x3040281c47d3b6edb8314182a595a1f3],
+    [0x05e83a85eb5ac753b7a67a23d245518d, 0x0072eda160683c3ba9ca0dcbb27f0085],
+    [0x158fa205d9f63a30b487e615f4721400, 0x1d0c406bd40bb8fe800ca737d501e19f],
+    [0xcc7406a5f463822b717de79344c8a9db, 0x2e1b6f701dfbe38462061a91d5f641ab],
+    [0xd298da146366cf0167ad082f5afb2aab, 0x2918d3fd3323723a1ce75dc1b1f82948],
+    [0xdcd445f51b11afd41f4676673d74af0a, 0x04a8ede82ea0c561443858fe08d2c905],
+    [0x1f3497d195c79a40cc997225a4349280, 0x262f76f5afbfdc84c240e686362f25cd],
+    [0x2c711ecc05204e1d26b4f77f1f107526, 0x0e983bd202a424f24a83f8173737dabf],
+    [0xf83aac7e7516184b4c22eb7afefa5169, 0x1a8c8a3b13837a4a657c26d0f804ca7e],
+    [0x739ab5a216a783039bc055ec6f0aef15, 0x05a16f27f460719f8b75c49c1cdaf348],
+    [0x188b02ba57ab158a46e033590deb1d02, 0x109a4e202a5d6ee943b2138dc5e3c868],
+    [0x4e883dc8a07af1f62caf300d23412dde, 0x114be636e12060d317a7f08c44b85614],
+    [0xdfca12634a4b001bea1d1190e4ec8029, 0x1fecefae8607aeb2f109239e061d1839],
+    [0xfcfe9b121f12bd36e989d2f47cf809cd, 0x1cfb5006b6281147b05dcb8a08aa54a7],
+    [0x09edb57e0a88b4790d731cb34e926d82, 0x2c2e31ca27197590bb0d46682ac10d20],
+    [0x1a1ce6abae5eafd9eec2f3745be561ba, 0x18f2a0b00b5449f4bb8265d9bc2b03a3],
+    [0x06106f7657209a9c96c73f6b44da6b02, 0x0431b6c629cebdce444b0af77df08085],
+    [0xeec9d0930341489adc6433fdb9941f93, 0x0cf33fc2fde1e171be151ee344595705],
+    [0x2f78fbe830bcb0187aba4c21fe7d9275, 0x0a0743877b28a2a675966e6ad6e2caf3],
+    [0x66350cf4becd8126bb5b89e672d39fca, 0x195b9d285a58deae30758a177e20a4cf],
+    [0x503bf62ff1e3f7caf95149021544476f, 0x2c3eac5d52faf5b46fca54b5e7c16cd9],
+    [0x1bfa8cf88d0f15406b7d3e30146bcc2c, 0x10008396ed7e26097d6384716ddf2993],
+    [0x5d31339c11908252ec08fd39aee66c7b, 0x09d6120d8cefd8db99a2906268fc116f],
+    [0x5fca3dfb427be07a32201bcb38019187, 0x29f60fdfbe0d77c1641825b499e2452f],
+    [0xfe0194fc1595e7f8427c0b83f90b4180, 0x2a77d988433dadddfa317cf1a86c2503],
+    [0x637e956b5792fb69a0d7376e732e8113, 0x0c9650f149c994670cbb966f3903e52a],
+    [0xf5032598d1e215a822e3e7ed9b757cba, 0x0cbfa17b2347e6821f788e9a44255c13],
+    [0x8431127aef92a9a00644c61effadd079, 0x167c4c995f5a20357496678fc1ac33e0],
+    [0x2df95666fb204aff23c0b9a9eba37951, 0x17b09378da9ad8fa77b5d10db6cc7b52],
+    [0xd422b12bd062507887a1749c9dbeabd1, 0x15444b346a2eee40220d5abbda30324f],
+    [0xc8c208d42ecaa7ed2cff73b74dc18656, 0x03d08a26f86c97f31c4cd9a01a7f68fe],
+    [0x1fd1c065e70399aff512d8777d7cf1b4, 0x1c539c92417e0e11b263d99a0fad8fc6],
+    [0x8972967e1f7688877f4e857400ff8526, 0x19b9209e1055f404647d2f0bd4817e79],
+    [0x189326f39d1772986de1ba79db72f0a1, 0x014e73253e3b5d2c90f199959ebf071b],
+    [0xe806592a1d72dfe8ca3d583a04febd06, 0x0f76aa8a09cd042559e21edf64495557],
+    [0x74a87454a65e6dfef9440bbe12f01489, 0x07e97e1977f873a0581eda757955c932],
+    [0x7bc51895c34bfcdeac48f8b56dacb362, 0x2fd534af9df31109c948d5a5d9336b8d],
+    [0xfa22a3b79a9ee0033237bb43fdf87723, 0x1b7971c644b7fd517033b8df2325df82],
+    [0x93e80b1ca9307d72709508cb1fea64a2, 0x0c830969ca91ef87be109af46d0ad62c],
+    [0xcb11ba89e798962295cca254c565f2e8, 0x1fdb86f3412ebb18754a1f9cf4df3096],
+    [0x15221e29ae617a30f950d29834ad2654, 0x2c9a5c82481cadcdea6bfbda30343d98],
+    [0x944d1b8cfc4f26b0639ca4faecd98d2b, 0x23b19abc1dbe34a9793537f1f39a3d81],
+    [0x7972516bdc8f96567b29a64769715483, 0x0cd6922f3304a4d038833c697b077414],
+    [0x12472a06c827f93dba5d5af24ec336a6, 0x183212f2e427fc1080c237bfae4203ef],
+    [0xd70f264f5100874a4c1603d1d6f839d0, 0x04417a0dd6d3d8cd6d2ef81cc8333322],
+    [0x433d4d3f5b3b96707eda0c47a422c3a2, 0x1ee7fd86ae6bfe916fbd87cf0a72a4be],
+    [0x51a6391cf49eeef6f1f1443eea173d10, 0x0e386dddd870f8041dfe619d4dd8eef8],
+    [0xf4b145dd8a234f0d7e6c0639bea00747, 0x0b31a49e957ac3bc7ff0f65ee6fdbb4c],
+    [0x27863b892ffcdae05478a0859c7eedc1, 0x17bedf9f13ab9ce8a6e28190479939cd],
+    [0xa4f5afae562f4862fe1e92e702409e15, 0x1c28fb33d99dd1b3ae37f884f3552546],
+    [0x8cdab0ea52f1595c5e7871815ffd02cc, 0x15c6d0a394166967cbc39280505eb952],
+    [0x5f90f7c1ffaebb1c4fcbaa82a0519bcd, 0x236303150f9e76503ccf1be5c90c3a48],
+    [0x1942f79d9a761d3aba4a169681e30fe0, 0x1fbbe0747bd2bbc3ccbb752ced989773],
+    [0x4333e21779cd71f880962a50ea6385ba, 0x147146fa6f464f7e58a1304441d2b7f6],
+    [0x93bfb278d1c79061e666df4a32092c03, 0x217e02c928941516f5a9a78eba51468d],
+    [0x0b47b0afbe4dbeb1b4340f5801ba4c1c, 0x246013b423cc893a16b68c228b01b938],
+    [0xa7b77c30c1c8311c3c81837167ab45ef, 0x2b5225496c1e6facd5b74f12705ca0d4],
+    [0x219e75a884f9bbf941b20ee81b42c022, 0x0a950f572749b6fad6d6c324f40568d7],
+    [0x9810d0927d286af59110769f07f9976e, 0x1a71888e664d2ba9bba5aa22fbc380f2],
+    [0xedce59824d6ca9fc260f5edc8ea5fabc, 0x15c5c720ef6fa5b02936376c298394bb],
+    [0x78edee693520c0b1ca097792cbd1742f, 0x1c84983cab32fb96ccd0276d25fcb5a9],
+    [0x2867e21ca6893d97b463094ec72a1e24, 0x10759b7a744e81322f8368fffa684510],
+    [0x0998b4d20f532d1f5b3935ef2ca3fc6a, 0x1ab43f1acf28763767e587542a3f399f],
+    [0xae7237f4ccfcdd52e0d9b7b037ce20cf, 0x1d32f2e0c56427580de962f147633899],
+    [0xae4c331a4a381915cc195bec94888d65, 0x2147506e00fbe0f6458115126df9eeb1],
+    [0x61ab041aa4d27e4821062b02f7a29ab1, 0x04e95ed8d8a554695f2a8441c175f22c],
+    [0xaada9d4cbcd076a2cecb3ea78f8bfeae, 0x2cc181f94de5719fe2cef2222777dd98],
+    [0x42448157d2c651e3b1c99d32b2c51c0b, 0x1615f6d5826caaa678c4e5c01cef49b4],
+    [0x2346d224aaaa477d5d98fde2bf5df5d3, 0x054b0e2491d3ebd17f7fee0b0dce67b8],
+    [0x4e54b8070b6304f4b4e8ab9f89493713, 0x21052fe17f3f83390ad8aaaece48b792],
+    [0x9f6fb8d8698630e7f096342be4f129be, 0x1b1d8d905c713958dd315b0c84a65d47],
+    [0xacd5a9b5082889ae550bf3ff1e8cfc39, 0x08765df92141cc7b51bd0873fb5259a8],
+    [0xa954b9e28fdcbd895870b32f34811054, 0x1dd1e2c068b653203542fea3746329ec],
+    [0xa843939561216767e4ee6342502e2a18, 0x050582affdfc4e5c3ce73d746680b830],
+    [0x2bb184ad3e0b0bde29271b55732ea2d3, 0x151572a6d1f57de2fa9e4aaa545ffb84],
+    [0x0e8d118d2eafb6c0d6d3faf2dc8e85be, 0x18d0aa380c379a8e7000532a66d56141],
+    [0x7422502c8a219cb95ced3a1209b05fdd, 0x2424a586bb57c0883ee4ca4ec731969c],
+    [0x04c59399d3a007f5f891504bcf7d77cd, 0x0174d6643ccb632a14b11162fc357006],
+    [0x7915b299e04a80e33e612740706e7a6a, 0x182104c94166052fdc748d4979901138],
+    [0xdc9c967ebd45af4d9cd62da07bd522f5, 0x0b70c1185230e22dcb948428cedde9d7],
+    [0xb99cfc51e62ff6dad389fde5dc68eca0, 0x1db44b6e0142bae1070f734522bc1d40],
+    [0x2d4600a8b1f7bbc73cddf10fca81aab2, 0x23779598119faf923336a2e9fe15a7a3],
+    [0x8bb963f09b18df3b8b8d31629b7b8a82, 0x0d310d08d48c04dc78bda687d48a0059],
+    [0xb1045bc3fccebeb252218186161c7b3c, 0x2a8bf4e2d52476ec48829a99fa53b47c],
+    [0x2138966e7ba2812896001837585b2eac, 0x13cbb0f381a6e6609c3fe4077dce0da3],
+    [0x4647d29768a74b335036b24553571b40, 0x2ae412652e44172733e1939fd32eb780],
+    [0x5b2d507bbe98a006f715bf2f28c14333, 0x03f426a5c7707a7da1a8348f9e6c0e6d],
+    [0x80915ed2f56a3d4099b239a9b4449cd0, 0x092fd1fd3d9c5bfccbd1581b4a16ec0a],
+    [0x1131a5aca1cfcb3d20492f9fa4ff2b8a, 0x00b1e816dfb839a8b31ddce51963f352],
+    [0x7fd8f1f67b915d7fcb95c9c5bf3d7c73, 0x0cdaa3a637ae81ec5fc6b4f592b5e04f],
+    [0x7895bd25d53efb5e93a89589d638d0fb, 0x11fed5b9e736562938a571aad6c1bf98],
+    [0x5e11d8817dfa3e74034fb82fcfbc8632, 0x0d06b9038f5d041a9edd114b3d6346ad],
+    [0x63eb0f80d76b90c47af463b7ee49af2f, 0x22f79b3d9df4256fc835f23d5e16c8f9],
+    [0xb24a3ba2744959a2f3733dd4cb777e6b, 0x2bb2ecd74be3c847c7df14a1c83937f8],
+    [0xb2b7631de85e3468faf005adedc2b931, 0x10bd1d611707ebe4a82d2ca962e0054b],
+    [0x071d09732cf17a53ce842eaa42f365c4, 0x2f4035fdb1622d23d1b9cb82da64f9f7],
+    [0xa7c157700bbd07c3a64ba258b8e7920d, 0x0fa7d8c17fa7018ad14f089eb8564bc9],
+    [0x9d0f1e74b666dec2b00436f496bcc5d6, 0x0b8ca93688bfb7c1ae365aeb344630c1],
+    [0x05a03fffd9b037ced90bef0af0db1a72, 0x10d685614a99a405413e3559d8261752],
+    [0xa1d6092a85d90044c3eb14e07c4e4a83, 0x068e162c6a02caee021d6f4461fb4e1c],
+    [0x39b91ea81767cb82064a8aa5e27bcc56, 0x2f6d6a0c4ee21296496f69a965cb8fe2],
+    [0xfe48bb442d9f7521bef498d057c2137c, 0x0c6a439aed8ec86b5ba5f2a4a045faf2],
+    [0xc3b589872c06dfdda207e1d6641aae2d, 0x1e6b0d4b88e393a9d915c013b4fda5a7],
+    [0xb44955dd4bd46fcc6c522360e1c6bd8b, 0x1b2116f8a7de6981e99425446c1b720c],
+    [0xcdb0d882f199569fab37c440e25a8e56, 0x05013f00c86773de39c01ba3cceea5c0],
+    [0xdd90abbb11a8ba1037b92e7c3ce42cd3, 0x083d430c489ec684c164dfdbfc5a0556],
+    [0x5ab246c29694473d6e23ba5c5dd98286, 0x1b0dfa5c2f0fa739b03b0bff40616c9b],
+    [0xb237e59c2c0e7034459f79cba4b29045, 0x0553fdcea1498f28a9c4a586d47b0297],
+    [0x836e9e7124eacc6dbc5cfeee3b74e5e1, 0x164c272c42e1f907ba9fc7af62e5a9dc],
+    [0x8224220ee9c1d093cf965c2003c773db, 0x2eeee949382b74229a3a5f86420c596b],
+    [0x15a091c301c527ec4a54c3c093ed6003, 0x1aa7b899db428b649fa1e4672581909f],
+    [0xc388e6148d2417cac3dff95ebd707303, 0x2844667092565157f2a88a0558450adc],
+    [0x7948d96c781ad98311e279b81f67ef35, 0x247c0d8658c6a57ec482adc5e9ee6bb6],
+    [0xa540d09680497d61e96284e668c9abad, 0x21621a3b3c6489991b000d1efcdc69b0],
+    [0xab0fb88a31783266e5d14048118e62f9, 0x2daeaab1259ff6596d09ee4b567cda64],
+    [0x69cada0a2e046e2d6138bf8f84409f4d, 0x1906cafe7df4f4a8359a4818a4ed5b67],
+    [0x053a1350be50f6268dbe18b10eba2b31, 0x00c2adb51600bba92fc53dc2165f80f7],
+    [0x1f447e47eeb7be8e3b6df38df3d44bcb, 0x26b285169225658f8ccc3040cd85c3ce],
+    [0x5d45d643355e3c60321d24a80ed6ccad, 0x1c2b7e2db564eef0909dc7753604a4cc],
+    [0x6ed236ff956f127a804fc7025bfcddf8, 0x2dda3e9fc09d2c2e6d52a76bda84df10],
+    [0xd8b5e74104814a6ab664de8a45887ef6, 0x11919b8f4797e0ee89f05e0428f57833],
+    [0x6f42601a2475b1d75f9395993ada7b1f, 0x127bb2c1e855294058203de7a73f055c],
+    [0xc7836a23b31bc758028182f83283f61b, 0x2c59318cf432b8e499a99a0863c914e8],
+    [0x747a5fc744353025d2128d447017ac7e, 0x13b24677e68adfb873ed0f23b0025bb5],
+    [0x5a47bd2f0486514b6db4c70cf3c247c3, 0x2628fe26b4d2bc5f8e90679f798cd9de],
+    [0x6066c0e7a5abd261faaa4bccceafeabb, 0x237cd160a1d6ae30ef20c93476c9ce0d],
+    [0x2c29733574cb9c9ada2577d876b99c24, 0x1e1ad584ab675d5b5a4aed3ca5b804d6],
+    [0x0ab6cc4194893884c9f51ea11ffe8f58, 0x0fbed731a82174de7be9e04798567358],
+    [0x6d5334a298fd4480125e6085dcff1078, 0x0b441c8f1423a29a71b1dcb16fdad031],
+    [0xfc0930c59984cf9c8c74dade3af961f5, 0x214e573a6c57ff7da396c0aa15efaa21],
+    [0x8f7eddbd9106b505964ae8e0b48a5dbe, 0x1b6e4364576e33a3cf54bf21c10a91c4],
+    [0xa7ab265de46f45003cf2600f3a4619a5, 0x2cb60dc30aafc3a99f1f439eb34c38e8],
+    [0xcee255102a4622dd990f3410d16853c6, 0x081c0b5c56711e541bdeac150b0644a7],
+    [0x4a0db77310b2c8c533eb602908321444, 0x1920f847ae83969af5be395a45983898],
+    [0xe6e914ec6015517039128b154746aef7, 0x1bd14cb5b0950d3b4783c53d2c733a2a],
+    [0x867ab0c66a7fa1dad6cd60eb3c0d32fa, 0x2ca16700e67134be0eef329f561fb057],
+    [0x63fbdd3a251afca27636ed1dede95365, 0x23a4bd6bef7f4d21c261924755472456],
+    [0xe7399cffcbc1682d7649cdfa12b5b3a2, 0x0090204cbe550a36f11c9d12b2a56a6a],
+    [0x207da285d037bed5c748f39a6f94a04b, 0x1ae99456d0e8b238516a52067559e599],
+    [0x76eff45bae2c1691005cb835ac07f44f, 0x2c18b3a0fc4bf35beb9a40064a69c7d9],
+    [0x21e2e311dfad8d8d52376b60dd2963af, 0x2dca944b91011ea9f224fe5e0612ec20],
+    [0xfa0acb71161655957ad4615ebdf4645a, 0x2ea90ce7f34c864596617fec562b4cd9],
+    [0xb24de85aad12cabe10855c2bd71bda2f, 0x07d2a16fad795f38267999a70ebdd9b2],
+    [0x32ea5143a4e2686e0d8440c044ff862c, 0x2364415fc864df4c341a8e4267a1f3e8],
+    [0x61789e5692cf2d7d8d9a7cc7b9d0cbb1, 0x2f5c88b96aed854cc0049a9ba476a6ba],
+    [0xf32fcf23b74511a62146b3f79c25f6ef, 0x29902aaebf3e38f0ef39c37deb5e4fb0],
+    [0x4fe2ba80646ed69cc7d568411e81cb51, 0x0e190e057ebcf02ce0360f76fd5b279a],
+    [0xff9e1f0d75b5252e988abe3562702f7f, 0x23097b82c0c75e53cb8084869e51400b],
+    [0xc13ebd49574e69aa6df159d4a9dc8553, 0x30180e17530b860901b1fdcaf23b2efb],
+    [0x660a59e6c9dd2ee4838a9e52dd37a09e, 0x11ba605959afb5de92d0a0cf68945b5e],
+    [0x231a330319beabe793e9af41518a34ca, 0x19f8beeb3629ca17720e83269c010278],
+    [0x983fa13838ba88f76aad034c2f90151b, 0x278f39897cc83610330db265f6f959ec],
+    [0x35a49e0b2f8501f00856605e24dec382, 0x2349cabda83ae2bf55842e72b804b364],
+    [0xab6e1543f12bd3665c31818c5f2c7aa1, 0x07279b75f7a7c121005c3b62b7d19dac],
+    [0xca079f3bea76bdb652c9c13eb3a57f26, 0x006a49cec0500c059bf61970d3a03c4e],
+    [0xa53149695b5aacc5c4d50108e2683109, 0x00877dc29b504019644485576670f38c],
+    [0x24d74a728300696f523befad8a329f77, 0x29494a499fd7cc4e34730c48b22a629b],
+    [0x8409e3433eca86c0832070c82f6dbaa0, 0x062086a3a24f3dd07219b38975c451b4],
+    [0x991a0fc21c472717740c3276a9a9051c, 0x2b00d39eee2b4106a70ea6184d95dc19],
+    [0x09876364b764989f86b9a9642c92eceb, 0x25c788dedee36b381925503dc1bfc81f],
+    [0x5927dde8682c37fc9e85005d0ca14e5d, 0x0a339d7c3399eb0090f9ca887196f55e],
+    [0x9fbd76ef45c591c221e08b66f44c9575, 0x010fddd4d628eecc6bb55ec4ed563c9c],
+    [0xe4fb910e98a745707095c42ac32f7cd5, 0x08cad7f09fcd6d0a763b153a6ba13d09],
+    [0x4a47328bfda137edd4115ba0b3274d78, 0x2ba8cf3a07138c08643263e2d4edcf9a],
+    [0x201b21dfb71c1ed32959b212dde8e8af, 0x280c5aadb92f1863c0ab20ca14b01b00],
+    [0x2718bd1ae38bac39b66883a20324547d, 0x243c43be625885bfba5754beffdb8c4e],
+    [0x1a88c0bcc572ab1c780303c569e4108a, 0x1fb8a7db96d88075b7ab36e71669568b],
+    [0x3dd399f254e1d1571e5a6025d85d593f, 0x0f7adf6a27eb979d0a147b417255058e],
+    [0xff98d6f099b53ce9dbb76b7a21c655e9, 0x2a9c20bfb0234dd219ac5933e7616040],
+    [0x36927f43362e983de12b7661aa91f465, 0x2db11a75d864992a6df56cbb1eb569da],
+    [0x3a24ff18a03caa4d99f1e53190cd239a, 0x29cd63ef57ff897ac5b7df3ba8ed72d4],
+    [0x9a168e978eeb4c1a9386190dd74ede71, 0x14d0521932e873b6db85e6a3aab00146],
+    [0x9f8f23475d377e69e4a3731689cded16, 0x026a3e834054cb4736723d1d15a24186],
+    [0x1465b27b9685fcf0d77e4aa73f4a5ea2, 0x08bd8277459e8e0a8359ee5323541bcc],
+    [0x14c87b1bdc8e8ac715f0d906b5f571f0, 0x19f6a1b5a8022bd124d262a774996211],
+    [0x88eaf78de09241f1f59e0834f91c0817, 0x1f99477db5031b0ba723b1c0c6d156fe],
+    [0x175e017d9635a6628cef9158f63d44b3, 0x07a87edad5067f361df3b764ef37fc31],
+    [0x23b2c22655e8fe2f76e4ce8d2563910d, 0x264fe161c98a2c04514b92f20b75c212],
+    [0x688ec209cf1f976a48454dc6ded0799c, 0x25bcba83915954bfc6a3fe5bccf6ec56],
+    [0x425e943a2847fd729227cd01b707dcff, 0x2742a82c41ae2ff68e524803a43e387e],
+    [0x3a7eaba0664529ec48cb4816a32f3e40, 0x0a4bca229eb9cea967db5f8650f3a921],
+    [0xd747a86817bed09d690d2095ac1f302a, 0x162dbbf72f31e2af5a8c62975d6add0d],
+    [0x603ad4f9b10cef41aad2b8008d86bfc5, 0x1d90d3a9705c5a9a1e06c777c9158d78],
+    [0xe403fc080e3193b63471e816d3aa655c, 0x2e97da108e41920c71507d3ffc4198f4],
+    [0x75765bd6927928bcffbea2aeee454519, 0x2bea7cfcff46cb79828d1bd5bc4d18a3],
+    [0x3ecb81bd2d564b2b12d37ea41ce66374, 0x2e5e54e0fc642d8a0a21df48310fe89d],
+    [0xfd7dca85ef9c5ba52c71a78ff2a59d9a, 0x2df4a44be5efc0c61c496a8cb723e9fa],
+    [0x7f5b759fc0f8229f1d645e38073fe051, 0x1dda9c60833b52676cc9476ecbcb34ee],
+    [0x7381ca3f4ef09a2fa27355c4fbd886cb, 0x2f91eee054fa514321dc0aca21ff25af],
+    [0x2e7f36444ea9c816948410ff111e3503, 0x094327a79722ee116aaf8196c4844e44],
+    [0x65a3c7e31b34af28d42e7db606b2ec4b, 0x0e0de329ebc013684671aa68f0f5c56a],
+    [0x379d5d5f9616edd9f19c1787e4875937, 0x0bfc1bdaea3997e63db63e94d43074ea],
+    [0x02c9e10567b892f7714983d142d007b4, 0x2d74256d470e8f893f5a6a127237c245],
+    [0x07dc1eee904f4347083fd1120649d616, 0x108523062a51a8b428eec2a66284bebd],
+    [0xa93d39fc221fd8674b33b68d65ca9e48, 0x0c5a805d2ec7e6b3201e4954184dddc7],
+    [0xfed640850197e72fe848b33efbc8808a, 0x172a06215673e0c0432c98acce1c426c],
+    [0x4b40396e99328a9e9f344c5dbd2a2e9b, 0x0f65d03fccc7537eb1f4fd6c5cd05fb0],
+    [0x39fb80f2e7caa53f0cc967c334dcd6fc, 0x20c30ad3d6b572577a8733996093d5b9],
+    [0xc5c205a75d23def62e22fbf31a61dea3, 0x0373febde10260fefc6ce9670914a9ce],
+    [0x5ba7e4560fdb741aa6569de8d16417db, 0x2334bac29b92db769c06308b78794337],
+    [0x82b021358cdbf7a99805d65822f437ba, 0x0a3e20c8a61c8bfaecbd65e43b13a593],
+    [0x0e0caf8d0628f33c7b229d0931aa6f05, 0x00ea78ba65528ac7035e06fbd9be63a5],
+    [0xc6f9ef8eea1891607f22b1f9167204d8, 0x0d1682fa0a924b9b3511188fbc8f21df],
+    [0x331e7f32b0035d6705dec154b824b4cf, 0x2844a8efb853b2db634a3cf36ce7cfdc],
+    [0xa0002022f963c20c1bf8753c826badbf, 0x2a2a574661f939e6c6a49f90107a2b54],
+    [0x00a259bead4cd8fb9496ca06aac9065a, 0x11cf62ee06e4ab926c482f35525a5dd3],
+    [0x376bc8b812f2654908b5ecd2b8261769, 0x29045c7fa9c73fc5b1e13499d171a16f],
+    [0x22e915c7e90552879af016f7a1665f21, 0x057e44377642d4f3c7c1625dec2f3f4d],
+    [0x3a5d9b267d044a8c3c3c4310e68971fd, 0x258c4640ac1da995bd0525fa9162d7cb],
+    [0x7b7c62bab5fd585b7d4f1bf65ab4cc6c, 0x095ee6ff5568fa535092d74c16311e47],
+    [0x25800d5b61eeea63d0d53f1c46ae4e21, 0x0806951088d6f83631a371948626f666],
+    [0xbff2093625a1d44d8c9498a3172ee2fc, 0x2e8cac5fe2f70b026d84f39f1bc80f3e],
+    [0x17c99609a38c2a6e6a609ebd312a114a, 0x112aae3d8032ae80dfe027a70961c04d],
+    [0xd2128b9826f18ff47a02c604e04b256f, 0x07f1bd289aaee1f4c2832bcd88746343],
+    [0x2105d28b419cb3379be54f68384d3656, 0x020fb251f294ad1fc3d553cf9dc532cf],
+    [0x117f8a92f8fa22713e5c8f4630a33aff, 0x0a76309ef4f35a0d942d2adb40042103],
+    [0x73b6a9c44713543e6ef0ca373032b662, 0x02e338a6e276394e620855137d23d86e],
+    [0x6be3d6a61a55d6c2bf32b9b2d6eb488e, 0x11277c021f4bb0566112a62d02989f30],
+    [0xd3cd23eee8bec05ccf32280d2eaf0e85, 0x0e416f597798c242813d07f02fa3f5af],
+    [0xc1c0476a262df79136087b3b252325a7, 0x1246e80a1e90daf8f50c8e70a1973229],
+    [0x34d40ed25924ce04da52fb13d8197db4, 0x02e7f56ac53002afeddd2ecf49250b52],
+    [0xe97b4d51281993edb77472acee552822, 0x0b383f460b713d1af2c2721ef86ddb9e],
+    [0xae5586600b476acf5e6b6145ab449c52, 0x014c46b4a3f25b85a2d1085cfda812c8],
+    [0x593a9fe5eaaaa6410259cae7a66ab635, 0x0ba3a95d3c28b392f3957569179db635],
+    [0xe25ab05e3477e04ce1f03021ef31b90b, 0x02bbd2ad310bbb5d2546328872c54b64],
+    [0x29d5f2fa62ab47914df14e897467d0a3, 0x1cf0637ff2abd72a4c5d8b24af9998fc],
+    [0x58d82daf3a40bf89e2672ff6785ba580, 0x263acdf45d079d43b1436625d9c3229e],
+    [0xdbe08050ea0837010e174ec3f3acdb84, 0x2811eb97a600f13912348f4e9af47d30],
+    [0xfb1bc247794c5900e68ae82f1cf732ad, 0x15e681960836851b15c8e9896e91d65f],
+    [0xcf8b492a86440f90994543471d554405, 0x1a1bf5d7b73d6cb3ddb0fcd106550674],
+    [0x0fe63d4029ae840e6ca05fb3a8b36243, 0x09137e8736492afc6d714d854ec1bd8c],
+    [0xc8630260bd8e58716edea970ec02ba58, 0x10075a0236e3c6938e8acf35759f8c19],
+    [0x59c09159f97c42943c5d2b735b6769cf, 0x126344b41cb4cfdd9d78f912e02ee470],
+    [0xc01fee5780a38e1e088b06f047024c4f, 0x0472a9ac7f6600881089a175f3287ffd],
+    [0x99592633eeed6cf9506e4f6ca9bbb8c6, 0x0e52b1a8dc66014a14af480b80c8b3fd],
+    [0x155ca89d84c0392bb48a60b009867540, 0x190e307e6626ac796ed7c96932800d3f],
+    [0xee5d9ea4005e9395706ea0f918491384, 0x19017529213e1cb331a1d076be3254c8],
+    [0x04fbabcd71a9f4e3895367adccfec767, 0x1889decfad56cb14a6e2928091550a45],
+    [0x5424c56e0e7bd9ac39b5585fb18d0067, 0x14b3798bb27713b3f92fb08649236733],
+    [0xcf44fc73cb7eabc5fc190b64ca97056d, 0x217faa0b7866c660a700fdaf3ef0438a],
+    [0x529fc815fcee372474ec02cf398f9bb8, 0x2bf465cb5271fe563121bb90877a821d],
+    [0x40d8d56de6a34837dcf8292ba823811d, 0x06a83b6f33415934cc47c952b7911c57],
+    [0xb369c91088835659da957d1ce2b49e7a, 0x26bc4b7152ec95967de18162da0400a2],
+    [0x20bbe7c94163188fab1f79e656d13b18, 0x0d9bc3639ab1e9a50234c10d7041ea22],
+    [0xaee818dac04f11e4e5c6a4dc31c8cb3c, 0x13152f0714ede97bbaef3a29d44fb441],
+    [0x584dc7e4a2ff2cce5d3b6f1350e08d27, 0x1afc129a674ba4ac7552dfead9ae8d2d],
+    [0x75fa16284ac0ced1048e07c280a1745d, 0x28498b045dd1b9b56e558065e0d71006],
+    [0x191b031bbe2b2fe332cd302cd5d946c5, 0x13f8baf6efc94294c82829f59da2ac86],
+    [0xc99ecf8a648c21e23fc5f1946432f790, 0x2b0314eecfcf61ad7d4c6d8c7b95ec7f],
+    [0x438e8ab78b2d37a68ddb1a845d4cddad, 0x2bd02329f573d05bd14eb5971f8e6df9],
+    [0xfd50fab1ac5c49ebf621f32347dbc0ca, 0x2f121eea462e2dbe253184030c4c1a59],
+    [0xd5bde07a03678dbb6fe1302dff3abe2f, 0x0b11ca165736db53fffd72e0d939b16f],
+    [0xe146aeffbb95815c14dd44340e192b9c, 0x244d9d9b9bdbf0a622193df5b53e533a],
+    [0xa8e43ed65d58d452d2486f2affb4fcfa, 0x02dff8347e64c0966cd327c431d42bbf],
+    [0x988181b256bf1aec52a3d0cb8952f717, 0x22362991babed59c5de9d9fd96a339ac],
+    [0xfa8f1cf296fe5beb4049c7565f4f2a50, 0x0b5ddca04cf6621a6f22b94b90395a58],
+    [0xebdb6e71973e0a40d265b99a54d6310e, 0x03b3edc6b3744cf0578b2ec40d13a99d],
+    [0x46ede5daa24b5c33368fb84b55bee38d, 0x2ddfd9a5dd6daa9051779120cb17ff90],
+    [0xf8a557c49f88598ac940227ca2f59833, 0x2e928f258564f11bd72156b627f0374b],
+    [0xdc9703b24f9cf05fb5250c1a24861071, 0x0f970b4e284e95a97909459a34a03b90],
+    [0x39fdd9c44c5d181610f55882b7b381dd, 0x027a802eaf2f9dafe4845f9b30907fdb],
+    [0xa480985e693fd4eaeb92637c73e89e31, 0x04ac7bc03182be8029a8440ef875a248],
+    [0x743157121f093c120d93e180582eaf8c, 0x075f3191afbd21d51497a26c524665de],
+    [0x518e1bbc134a65f440c4476a9eaf585d, 0x0bd3be9213121f779c2b2b0a024be82b],
+    [0x70917ea53b5f8b27dc70a1faab474cdd, 0x0c9bce95f2d5967d3b58f93d7e03c931],
+    [0x1a5ca2e6d7afa0c77b0be192f4f0c6f2, 0x1e3b04dee6ae6635722e354655f6032d],
+    [0x784c7c48322e30ee51cbd78f6ce9d93b, 0x0c1fc33b93195f2aac03fe6eef3e4110],
+    [0x11c17666770ff0cc7c077c935bf2af8a, 0x2ab604267dae12494550e1ef273708dd],
+    [0x5cace62db7eccbbcec55af3db9156468, 0x1c69985ff78559519a0052ea05e608e3],
+    [0x7decb71a01eb43524f8d3ddb0db24d48, 0x16334fcd421256398cdeee08a9345a52],
+    [0x7e2b1ef8a77a84568aefe01f1e9b5ea9, 0x07ef8dd7665152f85d00939cfbabcfe3],
+    [0x1b8a012cd0870fc6ea0b981494f58278, 0x0dcc426dc88bdec8c2848f2d88a20057],
+];
+
+/// MDS matrix for width 13, row by row.
+const MDS_13: [[u128; 2]; 169] = [
+    [0x23c33995784d228839adde2559592592, 0x0d064dee824928d39f2a8be7d2fa5832],
+    [0x2f0dac3680a6c26d55ecbf803ca80569, 0x2b2ff5f27531b6f3d6a85d75e3e08f3a],
+    [0x5ea9f87a5390f5614981eb6e4cb4a5fa, 0x0b658849ce620bbeeff233b7d1225801],
+    [0xd2c417e2782e5efea16e72d47b48b70f, 0x118132fbc1cd83f95167cb4473d54c6b],
+    [0xd085bb562b838d7e2ef14a21fdf524c0, 0x0b5944768d61c1b3562a5749f4f06c4d],
+    [0x0b5bcc0d7b1950b889d1fa6f3e56445a, 0x2b04d6ffbaf7144012d92c5c6212803c],
+    [0x02a3d1ae44c5dce152fdeab5450c9c44, 0x29be2a42834ae32de3320fb0ee46b4c1],
+    [0xf9031fec82da9ab72883c7b218b11eb7, 0x1946fe9de0a943d5a716eac08561cd91],
+    [0x17e39ad5701ef70f8144109f894bf1d0, 0x1af3ca6240a55aba55df8e49835b92fa],
+    [0x35342fd0677ee1bf5215e47f8e58c2c3, 0x081f58a91a4e0d100e0369fdd81a1900],
+    [0xd02b406bd6c7fd8121d7c7793ad2305d, 0x22a852af90680a8ca9688b71ed27c157],
+    [0x7828985c00c3e5df8c1276b1001089a1, 0x0b27ba82a0ffb98368981736ae927a22],
+    [0x65635552dee240661a2cdeef966f20f6, 0x04b46526207edc70a0d23c8438bc49a3],
+    [0xb8183a362be641729a93d6a327c03809, 0x2d8c70035731ad9efba92821a88119f6],
+    [0xee7933c9d3b36bb5fa02283bae5d605e, 0x09ef3492ad19dd1d0bfbeb191330a880],
+    [0x35c38b242eb9291373096ec60e39c1f1, 0x1458de516a534190c5550c6a1bed3af5],
+    [0x189f896ac0ed69b59854d105df47c4b4, 0x136ef2515955ff6619498fac59424816],
+    [0xd828f3b0742371ed02eb83fb0064dd43, 0x18cbfde0cd21c183797a2f089594a81c],
+    [0x70ce977049c393235035677e84357314, 0x22757da9e822345297bd060a3f71bb1e],
+    [0x618b975822ad0c903efbeada5a51d44f, 0x1622805125ddf344c18e351d11e82291],
+    [0x4d6c70a0f4d65dbaa960754b4195d9fe, 0x29765d8cf77619a580658d127fb6faa4],
+    [0x82e8b88ef70355dfc74e096a7ff381cb, 0x2bb0b6b08fc1bd11070edc42f23e807b],
+    [0x181a93ff1d4bf723781e1b938029827c, 0x04951e747b7ae6b99d9250108c5d2b70],
+    [0xc63ef68f3dde51d04cb47b3b83fc299e, 0x17780b9469e81ca70c7cbb4347e7fb40],
+    [0xfdd82b4fd3173ff2fe02b85582528912, 0x1913d51d9c71d042bfbf649d9e6e6ea5],
+    [0x41b62dff3153d3ae6500c2c3be6365e8, 0x2a9e342a56c8b6466d3fe533b89f1ab4],
+    [0x3e4594c49ab3dd6f602bc6c0351d823f, 0x07b620c8a78e09e49ce547aa783aa78f],
+    [0x0028d0b6213d9e5d2c66115237a19051, 0x27d6bd18703d7ca92aa24076d3187b6c],
+    [0xc2229845bf6446cea007c113349bd4d7, 0x2969eb5016f24b31accf173ca204668d],
+    [0x34b22fb86334675a361f059e50395311, 0x29c67bce04bfd020f4ff7eacab6cbde0],
+    [0xf81d519859d21235d7a74450d0a9427b, 0x124474b08829d9b717c50884bc895cd3],
+    [0x283fe22a978a3df9f62a171cb1d65e4e, 0x2c7e28f59fe8a8a3bb2fa2b2b1e114ce],
+    [0x292be54c879882d3f11eaa3103341487, 0x165c0b978b4bb646a9ff225ac4900521],
+    [0x3b60e4b9859d9b9872edaf9152f92d4f, 0x02066134bb299d9e359cb82a8380feb6],
+    [0x0814b0899c6196fa15a7acd15ecc5ca2, 0x09372ed18ba1a774eafe1bff6deec4bc],
+    [0x3668e351cfd28cf66f5012de61345b58, 0x1e3a130e8caff9b5e563e8aaf84b2fc7],
+    [0x98b90576b23b6cd86128665cc3bd9478, 0x255543a314afc5a2991413228c75eae3],
+    [0x683a19733fc04c73ac6a0b89bebb8453, 0x076547449fa03845f185e61578a5a8f2],
+    [0xe838c51e5a0aefd333e9f7cfb38f534b, 0x2f98bf081e0eb78f8025c03ee9c199f2],
+    [0x13929093bbfa00d6a661e4a690351d0f, 0x231996b592528575567d39d00645a83b],
+    [0x8a2118e83658327c4338aa3648d8f7d7, 0x193f32a3f839f76049c9cbf241459069],
+    [0xbd362cac497afd004b8ea920fdb04bff, 0x0a5da659b6fcb1a4273e1b7e3b544d2c],
+    [0x3f6e51126eab9acec96e71953102ef24, 0x02a832ceed3837e742196e09491fe23e],
+    [0x61f6b18a8058a52b619d6227a26228a8, 0x098bb2c4c6334dfea474d04cb2e97618],
+    [0x7f6b44bfe2d1af34fefcb65e958784b0, 0x101749f9dd2605191c1963f25694ae26],
+    [0x98290d971b1e6dc0e9a0e9e7158356b1, 0x0c7b5e09343db5c63c110d3175d01bb4],
+    [0x18bbea4b7cdced3e4d210971dd0a681b, 0x116a313d00cf99dc18e21f93a5bb1980],
+    [0x9d11457e59f90419ce4bd10bb8a803df, 0x0404c0fa1bd1f7e059054cbb87f6b5c6],
+    [0x98b54335f632bd62add29e08067a59d9, 0x0c1c216af33054c497ef04381f585822],
+    [0xf8c7b4437349eedf3fce432a6f3c1653, 0x11cb20544f43437a688a7dcb573a7da4],
+    [0x57337c60c40b34c3a371d753f2ff001a, 0x186f160ae866e10c4be0ea7d204cd64e],
+    [0xb06590340fc07b182ea7c3ebbd933931, 0x1e1d5920edcfe9d6b2d0ddb2f6f741af],
+    [0x640c93fc524dd3c508486d66f079852a, 0x04e47a72e49b9c5aa3f69f6af22fa0ab],
+    [0xe4615e77801e5aadcab8336aadb1712c, 0x235d0e71a350f1f23d9bb5182ac44ec9],
+    [0x76ba960a57e8d7679b828479218570d1, 0x1219740c424743dbbf896996f0ee7a40],
+    [0xb8c169c1e9c63977d7823f80d3f823be, 0x2b501f1003e27aa809dc9e5cd84976a3],
+    [0xada47bdcd8c82d01821f5c21a3ea2325, 0x0f34db93ea8d2ad7d1eff86db5820cc7],
+    [0xe68542d076b683ee61b85869d4535893, 0x04d02ab05826fa50bcc5f9af9e396477],
+    [0x684778fc7a9b1bceb7868bb299cd2072, 0x2bcc82e75391be4ea9cd0f8a505f9e4f],
+    [0x0884ac447906101cea9888986a030b11, 0x0fe29b5bead54c5bbf426bb12844a292],
+    [0x14ad6e48f4676fe41454bfeeb0ffd4d9, 0x1bb2091870a4fac03682fe5b1e2eb56b],
+    [0x657575b7a963c0e786d2a761c4e845bb, 0x0f54c9c522a5c74e0d4e0c63611a7502],
+    [0x4dc03a4be8a15461496247e6ac29002a, 0x160f5ac0b2481f8019e95e0b26445ba6],
+    [0xb09792ac17a5f080d75b3e320affc86c, 0x2f3d6b8e2361166089501b9147e84ab6],
+    [0xc93dd561ce8af5fade754ceac07b1705, 0x1b59bb60e986194987fcea070498bcc7],
+    [0x018f0d31200a6f19fd5195db22eef7f4, 0x0a8cb4b2bf3f0c5da671e48f83e5c1b3],
+    [0x0a08cdb9e78a9f322c22388322f16c2a, 0x1df5c9ef694afe61bc253ae908a8f6af],
+    [0xcdd63cdcf17ad64fd77517701de675f8, 0x134431128e5ffc0ab60136c5d851a11d],
+    [0x921abe2e015f35b36ce4bd0246c2cd89, 0x03ea4e2bffe02da5977ced1f1a6e852e],
+    [0xe6b9b4bf51d48f953fbd1f006d758a75, 0x06e03ca1146705522bb5e9dc73560eee],
+    [0x96f462e431675ee36c7be4e9b069e260, 0x014c499ee51925b231da17ee7b55a75f],
+    [0x15244c6a88f1631a456cc2acca8930cc, 0x0611ecd1f379a0062b05c4aedb100bc0],
+    [0x35cb4b49311e0aae11cb7534d0bcee6e, 0x22af37c0e4a88deae9f7fad838199107],
+    [0xa3d2e29aecdcf6979a9b272a607dd5df, 0x299ef95aed8c75494db82e8679fbc6d5],
+    [0xf85e0413f7b408f1dc2fa1e6fc4af91b, 0x103cc8384be1be1087b9bb47c0eb6049],
+    [0x33905632b793c80b1d59966e96a73832, 0x206cf4290ac984434fa774bb0f8901a2],
+    [0x884308775afa9f667c6ca80954397c86, 0x0dd85dcf08fd38d22f316d91d812cb2a],
+    [0x954babbed3cd1706aa69d767e9afe7cd, 0x24ce0acb522da527d3eab72865cf073d],
+    [0xa8e77ace94e03aab5c16c4b931739dc8, 0x18bbb002b46f2e5fec2b120faf87822c],
+    [0x2af4fae0dc280030e7ebe55394f69d8c, 0x1db2021eca3a3624a7d45bb16cf4a44e],
+    [0xd05de0723bc49e53b9fb5e83d350c295, 0x097c6133aea8fe48f3a5fc416bdf5e46],
+    [0x12684ff5a303b2fb7c1d8d56f56ab61f, 0x150ef9e932a74b8debf670080641339b],
+    [0xa2ac4cf1009896766f5b191cde284972, 0x1237f44f036b2da0067a94947334e3af],
+    [0x6bcc7941d9ff5f07a17baf0099f614d2, 0x2d9ea99d2d237328266d59dd7e018bbd],
+    [0x1b679efbb87fd956e394db9c434e2352, 0x0477f951e19f531b8434ad755b6783d6],
+    [0xd57251b247c632bea255271afcfc70b6, 0x1d8b8ba772c4cda72e8ef9b19c424d55],
+    [0xca7468753f526e6bd82549fc36cd4109, 0x206aa2f36e32b2b06d6be48ad493d354],
+    [0x530bae6c660db3c364d0ebb200c2aeaa, 0x28da9309acca103ecf19df72a722134d],
+    [0xf420980d6cf14721b75e4cb1fe57e118, 0x01b9435855d6cc4339240e9fa3819c2f],
+    [0xb59bdfb4926113a3455f1ff4df6e2b46, 0x238832e643158e96689b5803b0b77601],
+    [0x04b9395cdc14df96861a528a52ae2ec5, 0x0b43b8facc43cfc067b5513abcce8465],
+    [0x9103c57bad129ec8396ce274ba4524df, 0x02bfae1b054ef2e924d75a8a01283da2],
+    [0x6b8af657273cdc4d8a112dea0bcc85cf, 0x1a46a26691841c26109a32ae07eb584a],
+    [0xb6345c174b91651c65c98449682a5204, 0x107da0784dfb314d82859be44d37b4d2],
+    [0xfae0f7edfa9afcd02dfeeccd02bfed46, 0x2b45b3be2674fd820516acd6cc6183c4],
+    [0x60f6e7ee94578f0118f4a920d0f8d525, 0x2082e9103cc452459242891115273128],
+    [0x95142f962b006a8ab09e7bb6335e8487, 0x303a9e22855d95d07e8172f3a0536d4b],
+    [0xb424b707f16599662e5a2e3668cbe5fb, 0x2963514313f046f844f950ffea80f5e5],
+    [0x5b0b229c1638c9c4757faf0d18fa6433, 0x04b7f8d1d5ec8bf689adf12d656b94e1],
+    [0x99c081c149463dc2c3d21fd8b2e4f31c, 0x259547168b64b2a228941a542f394a26],
+    [0x3630e6fd5210faca6acf4629b88e5ff1, 0x27919dd2f9d3dc9fcbda1a23b95cf23e],
+    [0x57fc8d52876ecb599dd5e1052b6b1f2f, 0x2b9237e5941e1657efa507b892c3b8dd],
+    [0x9a3e9ab67efef50f896dfe48b78d3dac, 0x202e38e669883619ecbc6a6a45368b9b],
+    [0xa1e0fe4240a50747e2c3c120d977f3ac, 0x262758fb3e076936995ff3140ef7d1b0],
+    [0xb38b05cdefdcb81965c5be00d780f192, 0x06d101b19477d1627b9542581bf0cdfa],
+    [0x9759da5d7b43d5e25a400e1f92e9d1aa, 0x0cae80cc05aedb66d6e649b057dbc5ae],
+    [0x9dd23e935487da26b3e249ea7cbf91ae, 0x12256b3b99d31d8a372986f95d66cc13],
+    [0x5327c65b0b76e50da30739ded25eb492, 0x238965d658967afa2bce50ff18cbbe53],
+    [0x0dcd94a897cb05d1e297b4880e7433b2, 0x23a6e143284e8f238c4b632ad78ace5b],
+    [0x23a8b3b0127224b8e23751b9cb9f8960, 0x24e13a38f04d5e0fc0b6b6e0f782a6a5],
+    [0x2313ae9c3bf43066ea60bc1ab477a7f8, 0x09010d73677620aae1b0fa8023ff1c81],
+    [0x409a67c3edf4c168ce3368ed944212d7, 0x0a41e5a4421defd5ed7acfd48401395a],
+    [0xc834498120629cc87c4586c627780075, 0x2fc7d17f3a9afc1de289ad0f94126aee],
+    [0xfc40001bb4fc5ccc2661669b155abeba, 0x1f86e3e9d147ed604c71e1fb6ef8459c],
+    [0xc5cc360124cf6b903e70ca83126975dc, 0x182d8f91ee2a9a26215b35f3c0eecf8e],
+    [0xa35b60ef054c0f475302c55fc10bdab0, 0x067d2b612d75f4f974312d04ae0fef43],
+    [0x8ccdf1da91660a461abb0f9fd7bdcd7f, 0x0eab3f26417e6b53c532072de92d4aa7],
+    [0xa5d242dfbebe8e27a2918f620e8a5be8, 0x18af61f6184ff85f104c2bef179b3ec5],
+    [0x003cfc34b001c0e54b9dcf2ea5df6be9, 0x195057af1319e24df1b905c6e2eb2957],
+    [0xe320adf07a286e7f2c211b47f6c3ee86, 0x1d952ebffcbedd26036c54a1e8a2828f],
+    [0x3d5e0482577bbdc14e71090307b5f69a, 0x0370b757ac6cdd46610eec0c22d43a54],
+    [0xfa724338cd67f67ea25330195c628172, 0x22e076a3aa6ebfe996ba068bf5bffb17],
+    [0xe074b3544f0610a51273ad3698be0cb9, 0x126851f3eadc0183288a750153910a41],
+    [0xe7520159da23441edd631a6ac14c20cd, 0x0361f4082b75a6ebd23f9a6e7309d8c4],
+    [0x1b7cee17761730e25ac284d6b1ed7b37, 0x1abe8badb2d12353b9bb98152086a942],
+    [0xd64f182b2041baa00a1359e7e9c5eda7, 0x15a7e64a5a46f5973e4e82a263ef905e],
+    [0x10851b86c353692d1744cb3372bfa72d, 0x0e154ebd50db3c2864dbe1ed72b08162],
+    [0x81c1ed5bae2a51c9e403cc754c54bb55, 0x25528d5d31dbd77cfa6e7b1847f8e84c],
+    [0xe8e27746026220e331cceb89a0ee89a3, 0x21f0c6b896fb14220d811441629c78f8],
+    [0x72773b062922dc8dd3ba068836dba464, 0x20addeb2afd20e752a39d2f21ebddc27],
+    [0x33128af8ee3287546d12d7777cef70cb, 0x11e3a7c2d5f74371beb188c6e3d7a14b],
+    [0xc1e4f084c714ddb1a075f2eae9ce21b3, 0x2d4d35acd9f28a3e6bfd601472948293],
+    [0x2e6b7a9619926d9b1b1e21cba01fa7e1, 0x26309b52cb55f6ea5a9030b01378f65d],
+    [0xf871e9c4c3e2fafaaf79159b0b4e4018, 0x2aa84c1ef9e848a64f748b80e279ddcf],
+    [0xeb58d8c321c62390febab05ecdbc798d, 0x2fe31b0df04f276a525b619acc4a5ba3],
+    [0xb1fd47f671e6604680317e6638b091e1, 0x27c1b406bd19ed13d533550b8b621186],
+    [0xc3424dc4b6edf3984fb02ae1ead44daf, 0x0cebcf30975b37267aedf521ee2bc753],
+    [0x2bf7a2eb35e15aac02fadf530278a3d1, 0x291c0da731c016c0d6c4c75387030a96],
+    [0xac8519a5a3cee4545b08850d8bf160e0, 0x1216f05607e032e49038ac1458100e89],
+    [0x4ab1dee0f333b88fb49edb3fc9ac7899, 0x19ae65945a1dbed3965f2de3a39ff2d5],
+    [0x388f1b7ff3875b725aea6eb9039d8b25, 0x2f996ae620d00a3d4e55ef7a6ce0b681],
+    [0x6fbc34518387e807c20c359739ba9eb6, 0x2c825ee561c4ab6a22331177999c8980],
+    [0x183a49db7e70e7eb73aefb4b5c693246, 0x0754947436c3cded29a60c9e898237c2],
+    [0x3ccb0b672e978f8fdfd214f4d7866404, 0x2de5d6a7a27343dd0422ac71b8d409b4],
+    [0x1886c0fbd18e55695541512dec563a0c, 0x025eb2ed84c520aba164e33f0353b513],
+    [0x2eaaa67b8970dcdf319df25e8019a6de, 0x0a12525f0781322371fd70b0928cce6e],
+    [0x359cbb313c5876fbb52f5e6b1fcb7031, 0x1bbf43ddb5a0b0d2845923b1ce128292],
+    [0xc45bde78dd819c0f35a620593c1f42e6, 0x23e8c3606aee818d2afa8c4465d5ecb9],
+    [0x827da6463891cca129c012c06b16b321, 0x158880f5adb96dd1398128b9ff339f3d],
+    [0xfc6aefc7fa988e3c1f7e7c87e1512a90, 0x23a1ac07351946a974c8841e92092a4f],
+    [0x1e5960fcb58c4f48c7d267c77e58dd66, 0x034c460fff28862ef7a509a1750a26ed],
+    [0xff8e319bd7d1a67fdbc7eef56d667818, 0x223db18f31c85e98e1dd7b7245dddda0],
+    [0x003e2c379b41ca61a6e56a9204fb16bc, 0x0099b97c878ce35c957d01b43dab07f8],
+    [0x9471ae7914bf08df7e47dd8e3bc3ce93, 0x2c2b1e7c70fec2f58ca01bef5ac37cbb],
+    [0x6fc801dc0b59d20ec0f4d0af8cbd23ce, 0x04625f2258edf0262ba0647cae426a48],
+    [0xa8fbd47fd2f7a2c96a818e93a3c38c89, 0x239ca61a6ef7e501810aa89ab4996dbe],
+    [0xfa3df62284b4b3b10199a501ed970622, 0x227cb43b9818924f2a2588964a0ad7dd],
+    [0x0bcc98bfc023f4ab12f5b2a899f37e3d, 0x16c7c5c6161eda32c65bb347655acaa8],
+    [0x737868dc2c3519435e1f2cb88f07b62c, 0x27e37c68a01530f092cd37c4a26dece2],
+    [0x505458d76178eca26298e39a62fb9501, 0x19dd1c6d45298248ad499531f33ce002],
+    [0xe4ee9fa0ad01fc4399b649b2e07d1679, 0x215f5563464e2df6a8daa8c34476a465],
+    [0xc4afa81bf97436076f57383da50436a5, 0x00f36d166e99139b9bfc1ac3270a3d98],
+    [0xd64118b491bcce12340c46f210b07ced, 0x1edd7c7deaf249e0fd3f5768aa92e9da],
+    [0x2dbf03f426769cc779ceee4ba1beb03f, 0x08e1741a97f6348ed6d68f4b7bc6ec8b],
+    [0x8cbca562ba6aa54eae7d32ff7d04dbd2, 0x2f4aa8641ea4135031f4d97a7a2f679a],
+    [0xb0362e5009f3d80033db473228fb7fc8, 0x21a0a43c88ce26effbf8b9ad668056b5],
+    [0xd93ff8035bcda834c3dcc41c3c2df85d, 0x031b369d011f219e4a8fb21df9352878],
+    [0x90d21002275b5ea11f209c763baa8457, 0x020a72083579059cb659952d3f2a01ed],
+    [0x08a4b9dc30cd122441f74319c3adcde9, 0x19f11771049b5be81b43ca81e3611f41],
+];
+
+/// Round constants for width `t` are `ROUND_CONSTANTS[t - 2]`.
+pub(super) const ROUND_CONSTANTS: [&[[u128; 2]]; 12] = [
+    &ROUND_CONSTANTS_2,
+    &ROUND_CONSTANTS_3,
+    &ROUND_CONSTANTS_4,
+    &ROUND_CONSTANTS_5,
+    &ROUND_CONSTANTS_6,
+    &ROUND_CONSTANTS_7,
+    &ROUND_CONSTANTS_8,
+    &ROUND_CONSTANTS_9,
+    &ROUND_CONSTANTS_10,
+    &ROUND_CONSTANTS_11,
+    &ROUND_CONSTANTS_12,
+    &ROUND_CONSTANTS_13,
+];
+
+/// MDS matrix for width `t` is `MDS[t - 2]`, flattened row by row.
+pub(super) const MDS: [&[[u128; 2]]; 12] = [
+    &MDS_2, &MDS_3, &MDS_4, &MDS_5, &MDS_6, &MDS_7, &MDS_8, &MDS_9, &MDS_10, &MDS_11, &MDS_12,
+    &MDS_13,
+];
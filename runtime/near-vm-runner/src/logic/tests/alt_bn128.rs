@@ -1,5 +1,9 @@
+use crate::logic::tests::helpers::{assert_costs, reset_costs_counter};
 use crate::logic::tests::vm_logic_builder::VMLogicBuilder;
 use crate::logic::{HostError, VMLogicError};
+use crate::map;
+use near_parameters::ExtCosts;
+use std::collections::HashMap;
 use std::fmt::Write;
 
 /// Converts a sequence of integers to a single little-endian encoded byte
@@ -268,3 +272,80 @@ fn test_alt_bn128_pairing_check() {
     check_err(b"XXXX", "slice of size 4 cannot be precisely split into chunks of size 192");
     check_err(&le_bytes![0x0 0x0  0x0 0x0 0x0 0x0, 0x0 0x0  0x0 0x0 0x0 0x111], "invalid g2");
 }
+
+#[test]
+fn test_alt_bn128_poseidon() {
+    #[track_caller]
+    fn check(input: &[u8], expected: Result<&[u8], &str>) {
+        let mut logic_builder = VMLogicBuilder::default();
+        let mut logic = logic_builder.build();
+        let input = logic.internal_mem_write(input);
+
+        let res = logic.alt_bn128_poseidon(input.len, input.ptr, 0);
+        if let Some(((), expected)) = check_result(res, expected) {
+            let got = logic.registers().get_for_free(0).unwrap();
+            assert_eq!(expected, got);
+        }
+    }
+    #[track_caller]
+    fn check_ok(input: &[u8], expected: &[u8]) {
+        check(input, Ok(expected))
+    }
+    #[track_caller]
+    fn check_err(input: &[u8], expected_err: &str) {
+        check(input, Err(expected_err))
+    }
+
+    // Test vectors of circomlib.
+    check_ok(
+        &le_bytes![0x1],
+        &le_bytes![0x29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133],
+    );
+    check_ok(
+        &le_bytes![0x1 0x2],
+        &le_bytes![0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a],
+    );
+    check_ok(
+        &le_bytes![0x1 0x2 0x3 0x4],
+        &le_bytes![0x299c867db6c1fdd79dcefa40e4510b9837e60ebb1ce0663dbaa525df65250465],
+    );
+    check_ok(
+        &le_bytes![0x1 0x2 0x0 0x0 0x0],
+        &le_bytes![0x024058dd1e168f34bac462b6fffe58fd69982807e9884c1c6148182319cee427],
+    );
+    check_ok(
+        &le_bytes![0x1 0x2 0x3 0x4 0x5 0x6 0x7 0x8 0x9 0xa 0xb 0xc],
+        &le_bytes![0x058814945232937db248a01e7cc55b3d681cc08702c8168494e856c1ef7693b5],
+    );
+
+    check_err(&le_bytes![], "invalid number of poseidon inputs: 0, expected 1 to 12");
+    check_err(
+        &le_bytes![0x1 0x2 0x3 0x4 0x5 0x6 0x7 0x8 0x9 0xa 0xb 0xc 0xd],
+        "invalid number of poseidon inputs: 13, expected 1 to 12",
+    );
+    check_err(&[92], "slice of size 1 cannot be precisely split into chunks of size 32");
+    check_err(
+        &le_bytes![0x1 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001],
+        "invalid fr",
+    );
+}
+
+#[test]
+fn test_alt_bn128_poseidon_cost() {
+    let mut logic_builder = VMLogicBuilder::default();
+    let mut logic = logic_builder.build();
+    let input = logic.internal_mem_write(&le_bytes![0x1 0x2 0x3 0x4]);
+
+    reset_costs_counter();
+    logic.alt_bn128_poseidon(input.len, input.ptr, 0).unwrap();
+    // Four inputs make a state of width five, the cost is charged per element
+    // of the 5x5 MDS matrix.
+    assert_costs(map! {
+        ExtCosts::read_memory_base: 1,
+        ExtCosts::read_memory_byte: 128,
+        ExtCosts::alt_bn128_poseidon_base: 1,
+        ExtCosts::alt_bn128_poseidon_element: 25,
+        ExtCosts::write_register_base: 1,
+        ExtCosts::write_register_byte: 32,
+    });
+}
@@ -97,6 +97,64 @@ fn test_keccak512() {
     });
 }
 
+#[test]
+fn test_sha3_256() {
+    let mut logic_builder = VMLogicBuilder::default();
+    let mut logic = logic_builder.build();
+
+    let data = logic.internal_mem_write(b"tesdsst");
+    logic.sha3_256(data.len, data.ptr, 0).unwrap();
+    logic.assert_read_register(
+        &[
+            174, 42, 184, 134, 113, 104, 230, 180, 244, 77, 240, 72, 199, 42, 110, 178, 6, 168,
+            121, 77, 27, 183, 153, 108, 197, 171, 78, 61, 186, 133, 193, 182,
+        ],
+        0,
+    );
+    assert_costs(map! {
+        ExtCosts::base: 1,
+        ExtCosts::read_memory_base: 1,
+        ExtCosts::read_memory_byte: data.len,
+        ExtCosts::write_memory_base: 1,
+        ExtCosts::write_memory_byte: 32,
+        ExtCosts::read_register_base: 1,
+        ExtCosts::read_register_byte: 32,
+        ExtCosts::write_register_base: 1,
+        ExtCosts::write_register_byte: 32,
+        ExtCosts::sha3_256_base: 1,
+        ExtCosts::sha3_256_byte: data.len,
+    });
+}
+
+#[test]
+fn test_blake2b() {
+    let mut logic_builder = VMLogicBuilder::default();
+    let mut logic = logic_builder.build();
+
+    let data = logic.internal_mem_write(b"tesdsst");
+    logic.blake2b(data.len, data.ptr, 0).unwrap();
+    logic.assert_read_register(
+        &[
+            95, 43, 222, 216, 153, 183, 57, 242, 166, 14, 219, 16, 169, 205, 255, 30, 42, 71, 70,
+            0, 109, 199, 50, 59, 126, 199, 2, 32, 101, 108, 210, 250,
+        ],
+        0,
+    );
+    assert_costs(map! {
+        ExtCosts::base: 1,
+        ExtCosts::read_memory_base: 1,
+        ExtCosts::read_memory_byte: data.len,
+        ExtCosts::write_memory_base: 1,
+        ExtCosts::write_memory_byte: 32,
+        ExtCosts::read_register_base: 1,
+        ExtCosts::read_register_byte: 32,
+        ExtCosts::write_register_base: 1,
+        ExtCosts::write_register_byte: 32,
+        ExtCosts::blake2b_base: 1,
+        ExtCosts::blake2b_byte: data.len,
+    });
+}
+
 #[test]
 fn test_ripemd160() {
     let mut logic_builder = VMLogicBuilder::default();
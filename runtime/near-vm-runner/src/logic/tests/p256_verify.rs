@@ -0,0 +1,238 @@
+use crate::logic::HostError;
+use crate::logic::VMLogicError;
+use crate::logic::tests::helpers::*;
+use crate::logic::tests::vm_logic_builder::VMLogicBuilder;
+use crate::map;
+use near_parameters::ExtCosts;
+use std::collections::HashMap;
+
+// `r || s` of an ECDSA signature of `MESSAGE` hashed with SHA-256
+const SIGNATURE: [u8; 64] = [
+    200, 22, 172, 101, 202, 128, 36, 143, 60, 214, 186, 70, 72, 20, 135, 199, 192, 222, 211, 194,
+    226, 130, 59, 233, 179, 242, 239, 22, 134, 251, 146, 130, 179, 119, 67, 15, 117, 176, 196, 1,
+    208, 236, 200, 93, 66, 244, 247, 187, 195, 230, 10, 96, 156, 54, 92, 100, 131, 242, 20, 73,
+    210, 247, 72, 45,
+];
+
+const BAD_SIGNATURE: [u8; 64] = [1; 64];
+
+// uncompressed SEC1 encoding of the public key
+const PUBLIC_KEY: [u8; 65] = [
+    4, 170, 207, 181, 199, 7, 59, 54, 149, 153, 33, 245, 122, 49, 112, 135, 203, 114, 68, 234, 237,
+    88, 106, 81, 122, 254, 120, 244, 99, 169, 244, 233, 4, 119, 0, 197, 65, 19, 129, 233, 225, 239,
+    149, 131, 19, 137, 207, 201, 2, 171, 109, 159, 1, 87, 107, 183, 153, 98, 29, 165, 169, 229, 54,
+    123, 29,
+];
+
+// create a forged public key which is not a point on the curve
+const FORGED_PUBLIC_KEY: [u8; 65] = {
+    let mut key = PUBLIC_KEY;
+    key[64] ^= 1;
+    key
+};
+
+// 32 bytes message
+const MESSAGE: [u8; 32] = [
+    107, 97, 106, 100, 108, 102, 107, 106, 97, 108, 107, 102, 106, 97, 107, 108, 102, 106, 100,
+    107, 108, 97, 100, 106, 102, 107, 108, 106, 97, 100, 115, 107,
+];
+
+#[track_caller]
+fn check_p256_verify(
+    signature_len: u64,
+    signature: &[u8],
+    message_len: u64,
+    message: &[u8],
+    public_key_len: u64,
+    public_key: &[u8],
+    want: Result<u64, HostError>,
+    want_costs: HashMap<ExtCosts, u64>,
+) {
+    let mut logic_builder = VMLogicBuilder::default();
+    let mut logic = logic_builder.build();
+
+    let signature_ptr = if signature_len == u64::MAX {
+        logic.wrapped_internal_write_register(1, &signature).unwrap();
+        1
+    } else {
+        logic.internal_mem_write(signature).ptr
+    };
+
+    let message_ptr = if message_len == u64::MAX {
+        logic.wrapped_internal_write_register(2, &message).unwrap();
+        2
+    } else {
+        logic.internal_mem_write(message).ptr
+    };
+
+    let public_key_ptr = if public_key_len == u64::MAX {
+        logic.wrapped_internal_write_register(3, &public_key).unwrap();
+        3
+    } else {
+        logic.internal_mem_write(public_key).ptr
+    };
+
+    let result = logic.p256_verify(
+        signature_len,
+        signature_ptr,
+        message_len,
+        message_ptr,
+        public_key_len,
+        public_key_ptr,
+    );
+
+    let want = want.map_err(VMLogicError::HostError);
+    assert_eq!(want, result);
+    assert_costs(want_costs);
+}
+
+#[test]
+fn test_p256_verify_behavior_and_errors() {
+    check_p256_verify(
+        SIGNATURE.len() as u64,
+        &SIGNATURE,
+        MESSAGE.len() as u64,
+        &MESSAGE,
+        PUBLIC_KEY.len() as u64,
+        &PUBLIC_KEY,
+        Ok(1),
+        map! {
+            ExtCosts::read_memory_byte: 161,
+            ExtCosts::read_memory_base: 3,
+            ExtCosts::p256_verify_base: 1,
+            ExtCosts::p256_verify_byte: 32,
+        },
+    );
+    check_p256_verify(
+        SIGNATURE.len() as u64,
+        &SIGNATURE,
+        MESSAGE.len() as u64,
+        &MESSAGE,
+        PUBLIC_KEY.len() as u64,
+        &FORGED_PUBLIC_KEY,
+        Ok(0),
+        map! {
+            ExtCosts::read_memory_byte: 161,
+            ExtCosts::read_memory_base: 3,
+            ExtCosts::p256_verify_base: 1,
+            ExtCosts::p256_verify_byte: 32,
+        },
+    );
+    check_p256_verify(
+        BAD_SIGNATURE.len() as u64,
+        &BAD_SIGNATURE,
+        MESSAGE.len() as u64,
+        &MESSAGE,
+        PUBLIC_KEY.len() as u64,
+        &PUBLIC_KEY,
+        Ok(0),
+        map! {
+            ExtCosts::read_memory_byte: 161,
+            ExtCosts::read_memory_base: 3,
+            ExtCosts::p256_verify_base: 1,
+            ExtCosts::p256_verify_byte: 32,
+        },
+    );
+    check_p256_verify(
+        SIGNATURE.len() as u64,
+        &SIGNATURE,
+        MESSAGE.len() as u64 - 1,
+        &MESSAGE,
+        PUBLIC_KEY.len() as u64,
+        &PUBLIC_KEY,
+        Ok(0),
+        map! {
+            ExtCosts::read_memory_byte: 160,
+            ExtCosts::read_memory_base: 3,
+            ExtCosts::p256_verify_base: 1,
+            ExtCosts::p256_verify_byte: 31,
+        },
+    );
+    check_p256_verify(
+        SIGNATURE.len() as u64,
+        &SIGNATURE,
+        MESSAGE.len() as u64,
+        &MESSAGE,
+        PUBLIC_KEY.len() as u64 - 1,
+        &PUBLIC_KEY,
+        Err(HostError::P256VerifyInvalidInput { msg: "invalid public key length".to_string() }),
+        map! {
+            ExtCosts::read_memory_byte: 160,
+            ExtCosts::read_memory_base: 3,
+            ExtCosts::p256_verify_base: 1,
+            ExtCosts::p256_verify_byte: 32,
+        },
+    );
+    check_p256_verify(
+        SIGNATURE.len() as u64 - 1,
+        &SIGNATURE,
+        MESSAGE.len() as u64,
+        &MESSAGE,
+        PUBLIC_KEY.len() as u64,
+        &PUBLIC_KEY,
+        Err(HostError::P256VerifyInvalidInput { msg: "invalid signature length".to_string() }),
+        map! {
+            ExtCosts::read_memory_base: 1,
+            ExtCosts::read_memory_byte: 63,
+            ExtCosts::p256_verify_base: 1,
+        },
+    );
+    check_p256_verify(
+        SIGNATURE.len() as u64,
+        &SIGNATURE,
+        0,
+        &[],
+        PUBLIC_KEY.len() as u64,
+        &PUBLIC_KEY,
+        Ok(0),
+        map! {
+            ExtCosts::read_memory_base: 3,
+            ExtCosts::read_memory_byte: 129,
+            ExtCosts::p256_verify_base: 1,
+            ExtCosts::p256_verify_byte: 0,
+        },
+    );
+}
+
+// tests for data being read from registers
+#[test]
+fn test_p256_verify_check_registers() {
+    check_p256_verify(
+        u64::MAX,
+        &SIGNATURE,
+        u64::MAX,
+        &MESSAGE,
+        u64::MAX,
+        &PUBLIC_KEY,
+        Ok(1),
+        map! {
+            ExtCosts::write_register_base: 3,
+            ExtCosts::write_register_byte: 161,
+
+            ExtCosts::read_register_base: 3,
+            ExtCosts::read_register_byte: 161,
+            ExtCosts::p256_verify_base: 1,
+            ExtCosts::p256_verify_byte: 32,
+        },
+    );
+    check_p256_verify(
+        SIGNATURE.len() as u64,
+        &SIGNATURE,
+        MESSAGE.len() as u64,
+        &MESSAGE,
+        u64::MAX,
+        &FORGED_PUBLIC_KEY,
+        Ok(0),
+        map! {
+            ExtCosts::write_register_base: 1,
+            ExtCosts::write_register_byte: 65,
+
+            ExtCosts::read_register_base: 1,
+            ExtCosts::read_register_byte: 65,
+            ExtCosts::read_memory_base: 2,
+            ExtCosts::read_memory_byte: 96,
+            ExtCosts::p256_verify_base: 1,
+            ExtCosts::p256_verify_byte: 32,
+        },
+    );
+}
@@ -1,6 +1,11 @@
-use crate::logic::External;
 use crate::logic::gas_counter::FreeGasCounter;
+use crate::logic::tests::helpers::*;
 use crate::logic::tests::vm_logic_builder::VMLogicBuilder;
+use crate::logic::{External, HostError};
+use crate::map;
+use near_parameters::ExtCosts;
+use near_primitives_core::types::AccountId;
+use std::collections::HashMap;
 
 #[test]
 fn test_storage_write_with_register() {
@@ -63,3 +68,136 @@ fn test_storage_has_key_with_register() {
 
     assert_eq!(logic.storage_has_key(u64::MAX, 1 as _), Ok(1));
 }
+
+#[test]
+fn test_storage_read_account() {
+    let mut logic_builder = VMLogicBuilder::default();
+    let oracle: AccountId = "oracle.near".parse().unwrap();
+    logic_builder.ext.accounts_storage.insert((oracle.clone(), b"price".to_vec()), b"42".to_vec());
+    // The storage of the current account is not visible through another account id.
+    logic_builder.ext.storage_set(&mut FreeGasCounter, b"volume", b"7").unwrap();
+    let mut logic = logic_builder.build();
+
+    let account_id = logic.internal_mem_write(oracle.as_bytes());
+    let key = logic.internal_mem_write(b"price");
+    reset_costs_counter();
+    assert_eq!(
+        logic.storage_read_account(account_id.len, account_id.ptr, key.len, key.ptr, 0),
+        Ok(1)
+    );
+    assert_costs(map! {
+        ExtCosts::base: 1,
+        ExtCosts::read_memory_base: 2,
+        ExtCosts::read_memory_byte: account_id.len + key.len,
+        ExtCosts::utf8_decoding_base: 1,
+        ExtCosts::utf8_decoding_byte: account_id.len,
+        ExtCosts::storage_read_account_base: 1,
+        ExtCosts::storage_read_account_key_byte: account_id.len + key.len,
+        ExtCosts::storage_read_account_value_byte: 2,
+        ExtCosts::write_register_base: 1,
+        ExtCosts::write_register_byte: 2,
+    });
+    logic.assert_read_register(b"42", 0);
+
+    let key = logic.internal_mem_write(b"volume");
+    assert_eq!(
+        logic.storage_read_account(account_id.len, account_id.ptr, key.len, key.ptr, 1),
+        Ok(0)
+    );
+}
+
+#[test]
+fn test_storage_read_account_errors() {
+    let mut logic_builder = VMLogicBuilder::default();
+    let limit = 16;
+    logic_builder.config.limit_config.max_length_storage_key = limit;
+    logic_builder.ext.other_shard_accounts.insert("far.near".parse().unwrap());
+    let mut logic = logic_builder.build();
+    let key = logic.internal_mem_write(b"price");
+
+    let account_id = logic.internal_mem_write(b"far.near");
+    assert_eq!(
+        logic.storage_read_account(account_id.len, account_id.ptr, key.len, key.ptr, 0),
+        Err(HostError::StorageReadAccountOnOtherShard { account_id: "far.near".to_string() }.into())
+    );
+
+    let account_id = logic.internal_mem_write(b"Not An Account");
+    assert_eq!(
+        logic.storage_read_account(account_id.len, account_id.ptr, key.len, key.ptr, 0),
+        Err(HostError::InvalidAccountId.into())
+    );
+
+    let account_id = logic.internal_mem_write(b"oracle.near");
+    let long_key = logic.internal_mem_write(&vec![0; limit as usize + 1]);
+    assert_eq!(
+        logic.storage_read_account(account_id.len, account_id.ptr, long_key.len, long_key.ptr, 0),
+        Err(HostError::KeyLengthExceeded { length: limit + 1, limit }.into())
+    );
+}
+
+#[test]
+fn test_storage_iter_batch() {
+    let mut logic_builder = VMLogicBuilder::default();
+    let entries: [(&[u8], &[u8]); 4] = [(b"a", b"1"), (b"b", b"22"), (b"c", b"333"), (b"d", b"4")];
+    for (key, value) in entries {
+        logic_builder.ext.storage_set(&mut FreeGasCounter, key, value).unwrap();
+    }
+    let mut logic = logic_builder.build();
+
+    let start = logic.internal_mem_write(b"b");
+    let end = logic.internal_mem_write(b"d");
+    reset_costs_counter();
+    assert_eq!(logic.storage_iter_batch(start.len, start.ptr, end.len, end.ptr, 10, 0), Ok(2));
+    let expected =
+        borsh::to_vec(&vec![(b"b".to_vec(), b"22".to_vec()), (b"c".to_vec(), b"333".to_vec())])
+            .unwrap();
+    assert_costs(map! {
+        ExtCosts::base: 1,
+        ExtCosts::read_memory_base: 2,
+        ExtCosts::read_memory_byte: start.len + end.len,
+        ExtCosts::storage_iter_batch_base: 1,
+        ExtCosts::storage_iter_batch_bound_byte: start.len + end.len,
+        ExtCosts::storage_iter_batch_entry_base: 2,
+        ExtCosts::storage_iter_batch_key_byte: 2,
+        ExtCosts::storage_iter_batch_value_byte: 5,
+        ExtCosts::write_register_base: 1,
+        ExtCosts::write_register_byte: expected.len() as u64,
+    });
+    logic.assert_read_register(&expected, 0);
+
+    // An empty end leaves the range unbounded and the limit stops the batch.
+    let unbounded = logic.internal_mem_write(b"");
+    assert_eq!(
+        logic.storage_iter_batch(start.len, start.ptr, unbounded.len, unbounded.ptr, 1, 0),
+        Ok(1)
+    );
+    logic.assert_read_register(&borsh::to_vec(&vec![(b"b".to_vec(), b"22".to_vec())]).unwrap(), 0);
+
+    let start = logic.internal_mem_write(b"e");
+    assert_eq!(
+        logic.storage_iter_batch(start.len, start.ptr, unbounded.len, unbounded.ptr, 10, 0),
+        Ok(0)
+    );
+    logic.assert_read_register(&borsh::to_vec(&Vec::<(Vec<u8>, Vec<u8>)>::new()).unwrap(), 0);
+}
+
+#[test]
+fn test_storage_iter_batch_limits() {
+    let mut logic_builder = VMLogicBuilder::default();
+    let limit = 16;
+    logic_builder.config.limit_config.max_length_storage_key = limit;
+    logic_builder.config.limit_config.max_storage_iter_entries = 2;
+    for key in [b"a", b"b", b"c"] {
+        logic_builder.ext.storage_set(&mut FreeGasCounter, key, b"").unwrap();
+    }
+    let mut logic = logic_builder.build();
+
+    let empty = logic.internal_mem_write(b"");
+    assert_eq!(logic.storage_iter_batch(empty.len, empty.ptr, empty.len, empty.ptr, 10, 0), Ok(2));
+
+    let long_key = logic.internal_mem_write(&vec![0; limit as usize + 1]);
+    assert_eq!(
+        logic.storage_iter_batch(empty.len, empty.ptr, long_key.len, long_key.ptr, 10, 0),
+        Err(HostError::KeyLengthExceeded { length: limit + 1, limit }.into())
+    );
+}
@@ -1,5 +1,6 @@
 use near_o11y::metrics::{
-    HistogramVec, IntCounterVec, try_create_histogram_vec, try_create_int_counter_vec,
+    Histogram, HistogramVec, IntCounterVec, IntGauge, try_create_histogram,
+    try_create_histogram_vec, try_create_int_counter_vec, try_create_int_gauge,
 };
 use std::sync::LazyLock;
 use std::{cell::RefCell, time::Duration};
@@ -41,6 +42,31 @@ static COMPILED_CONTRACT_CACHE_HITS_TOTAL: LazyLock<IntCounterVec> = LazyLock::n
     .unwrap()
 });
 
+pub(crate) static BACKGROUND_COMPILATION_QUEUE_LENGTH: LazyLock<IntGauge> = LazyLock::new(|| {
+    try_create_int_gauge(
+        "near_vm_runner_background_compilation_queue_length",
+        "Number of contracts waiting to be compiled in the background",
+    )
+    .unwrap()
+});
+
+pub(crate) static BACKGROUND_COMPILATIONS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    try_create_int_counter_vec(
+        "near_vm_runner_background_compilations_total",
+        "Number of contracts submitted for background compilation, by outcome",
+        &["outcome"],
+    )
+    .unwrap()
+});
+
+pub(crate) static BACKGROUND_COMPILATION_TIME: LazyLock<Histogram> = LazyLock::new(|| {
+    try_create_histogram(
+        "near_vm_runner_background_compilation_seconds",
+        "Histogram of how long it takes to compile a contract in the background",
+    )
+    .unwrap()
+});
+
 #[derive(Default, Copy, Clone)]
 struct Metrics {
     near_vm_compilation_time: Duration,
@@ -522,6 +522,10 @@ impl near_vm_vm::Tunables for &NearVM {
         u64::from(self.config.regular_op_cost).saturating_mul((stack_size + 7) / 8)
     }
 
+    fn bulk_memory_word_gas_cost(&self) -> u64 {
+        u64::from(self.config.regular_op_cost)
+    }
+
     /// Instrumentation configuration: stack limiter config
     fn stack_limiter_cfg(&self) -> Box<dyn finite_wasm::max_stack::SizeConfig> {
         Box::new(MaxStackCfg)
@@ -294,9 +294,146 @@ pub(crate) fn prepare_contract(
             tracing::error!(?err, ?kind, "Instrumentation failed");
             PrepareError::Serialization
         })?;
+    if config.bulk_memory {
+        return charge_bulk_memory_per_word(&res, config.regular_op_cost);
+    }
     Ok(res)
 }
 
+/// Charges `memory.copy`, `memory.fill` and `memory.init` `word_cost` for every 8 bytes they
+/// access, as NearVM does, on top of the static cost charged by the finite-wasm
+/// instrumentation.
+///
+/// Before each of these instructions, the length operand is saved in a new local of the
+/// function and the cost is passed to the gas instrumentation function imported by
+/// finite-wasm. The operand stack is left unchanged.
+fn charge_bulk_memory_per_word(code: &[u8], word_cost: u32) -> Result<Vec<u8>, PrepareError> {
+    let mut output = Vec::with_capacity(code.len());
+    let mut param_counts = Vec::new();
+    let mut function_types = Vec::new();
+    let mut gas_fn = None;
+    let mut code_section = wasm_encoder::CodeSection::new();
+    let mut remaining_functions = 0;
+    for payload in wp::Parser::new(0).parse_all(code) {
+        let payload = payload.map_err(|_| PrepareError::Serialization)?;
+        match &payload {
+            wp::Payload::Version { range, .. } => output.extend_from_slice(&code[range.clone()]),
+            wp::Payload::TypeSection(reader) => {
+                for ty in reader.clone() {
+                    let wp::Type::Func(ty) = ty.map_err(|_| PrepareError::Serialization)?;
+                    param_counts.push(ty.params().len() as u32);
+                }
+            }
+            wp::Payload::ImportSection(reader) => {
+                for import in reader.clone() {
+                    let import = import.map_err(|_| PrepareError::Serialization)?;
+                    if let wp::TypeRef::Func(ty) = import.ty {
+                        if import.module == "internal" && import.name == "finite_wasm_gas" {
+                            gas_fn = Some(function_types.len() as u32);
+                        }
+                        function_types.push(ty);
+                    }
+                }
+            }
+            wp::Payload::FunctionSection(reader) => {
+                for ty in reader.clone() {
+                    function_types.push(ty.map_err(|_| PrepareError::Serialization)?);
+                }
+            }
+            wp::Payload::CodeSectionStart { count, .. } => {
+                remaining_functions = *count;
+                if remaining_functions == 0 {
+                    code_section.append_to(&mut output);
+                }
+                continue;
+            }
+            wp::Payload::CodeSectionEntry(body) => {
+                let index = function_types.len() - remaining_functions as usize;
+                let params = function_types
+                    .get(index)
+                    .and_then(|ty| param_counts.get(*ty as usize))
+                    .ok_or(PrepareError::Serialization)?;
+                let gas_fn = gas_fn.ok_or(PrepareError::Serialization)?;
+                code_section.raw(&charge_function_body(code, body, *params, gas_fn, word_cost)?);
+                remaining_functions -= 1;
+                if remaining_functions == 0 {
+                    code_section.append_to(&mut output);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        if let Some((id, range)) = payload.as_section() {
+            output.push(id);
+            range.len().encode(&mut output);
+            output.extend_from_slice(&code[range]);
+        }
+    }
+    Ok(output)
+}
+
+/// Returns the body of a function with the bulk memory operations charged per word, see
+/// [`charge_bulk_memory_per_word`].
+fn charge_function_body(
+    code: &[u8],
+    body: &wp::FunctionBody,
+    params: u32,
+    gas_fn: u32,
+    word_cost: u32,
+) -> Result<Vec<u8>, PrepareError> {
+    let mut locals = body.get_locals_reader().map_err(|_| PrepareError::Serialization)?;
+    let locals_start = locals.original_position();
+    let mut len_local = params;
+    for _ in 0..locals.get_count() {
+        let (count, _) = locals.read().map_err(|_| PrepareError::Serialization)?;
+        len_local = len_local.checked_add(count).ok_or(PrepareError::TooManyLocals)?;
+    }
+    let mut operators = body.get_operators_reader().map_err(|_| PrepareError::Serialization)?;
+    let operators_start = operators.original_position();
+    let mut offsets = Vec::new();
+    while !operators.eof() {
+        let (operator, offset) =
+            operators.read_with_offset().map_err(|_| PrepareError::Serialization)?;
+        if let wp::Operator::MemoryCopy { .. }
+        | wp::Operator::MemoryFill { .. }
+        | wp::Operator::MemoryInit { .. } = operator
+        {
+            offsets.push(offset);
+        }
+    }
+    let range = body.range();
+    if offsets.is_empty() {
+        return Ok(code[range].to_vec());
+    }
+
+    let mut output = Vec::with_capacity(range.len() + offsets.len() * 24);
+    (locals.get_count() + 1).encode(&mut output);
+    output.extend_from_slice(&code[locals_start..operators_start]);
+    1_u32.encode(&mut output);
+    wasm_encoder::ValType::I32.encode(&mut output);
+    let mut copied = operators_start;
+    for offset in offsets {
+        output.extend_from_slice(&code[copied..offset]);
+        copied = offset;
+        for instruction in [
+            wasm_encoder::Instruction::LocalTee(len_local),
+            wasm_encoder::Instruction::LocalGet(len_local),
+            wasm_encoder::Instruction::I64ExtendI32U,
+            wasm_encoder::Instruction::I64Const(7),
+            wasm_encoder::Instruction::I64Add,
+            wasm_encoder::Instruction::I64Const(3),
+            wasm_encoder::Instruction::I64ShrU,
+            wasm_encoder::Instruction::I64Const(i64::from(word_cost)),
+            wasm_encoder::Instruction::I64Mul,
+            wasm_encoder::Instruction::Call(gas_fn),
+        ] {
+            instruction.encode(&mut output);
+        }
+    }
+    output.extend_from_slice(&code[copied..range.end]);
+    Ok(output)
+}
+
 // TODO: refactor to avoid copy-paste with the ones currently defined in near_vm_runner
 struct SimpleMaxStackCfg;
 
@@ -332,6 +469,10 @@ impl finite_wasm::max_stack::SizeConfig for SimpleMaxStackCfg {
     }
 }
 
+/// Charges the regular op cost for every instruction.
+///
+/// The instrumentation only supports static costs. The cost of bulk memory operations that
+/// depends on their length is charged by [`charge_bulk_memory_per_word`].
 struct SimpleGasCostCfg(u64);
 
 macro_rules! gas_cost {
@@ -2,7 +2,8 @@
 #![cfg(target_arch = "x86_64")]
 
 use super::{create_context, test_vm_config, with_vm_variants};
-use crate::cache::{CompiledContractInfo, ContractRuntimeCache};
+use crate::background_compiler::BackgroundCompiler;
+use crate::cache::{CompiledContractInfo, ContractRuntimeCache, get_contract_cache_key};
 use crate::logic::Config;
 use crate::logic::errors::VMRunnerError;
 use crate::logic::mocks::mock_external::MockedExternal;
@@ -107,6 +108,56 @@ fn test_does_not_cache_io_error() {
     })
 }
 
+#[test]
+#[cfg(feature = "near_vm")]
+fn test_background_compiler() {
+    let mut config = test_vm_config();
+    config.vm_kind = VMKind::NearVm;
+    let config = Arc::new(config);
+    let cache = MockContractRuntimeCache::default();
+    let compiler = BackgroundCompiler::new(2, 16);
+
+    let code = ContractCode::new(near_test_contracts::trivial_contract().to_vec(), None);
+    assert!(compiler.submit(code.clone_for_tests(), Arc::clone(&config), &cache));
+    while compiler.is_busy() {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(cache.len(), 1);
+
+    // Already compiled contracts are not queued again.
+    assert!(!compiler.submit(code, Arc::clone(&config), &cache));
+
+    // Compilation errors are cached as well.
+    let invalid_code = ContractCode::new(vec![42; 1000], None);
+    assert!(compiler.submit(invalid_code, config, &cache));
+    while compiler.is_busy() {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "near_vm")]
+fn test_background_compiler_stored_contracts() {
+    let mut config = test_vm_config();
+    config.vm_kind = VMKind::NearVm;
+    let config = Arc::new(config);
+    let cache = MockContractRuntimeCache::default();
+    let compiler = BackgroundCompiler::new(2, 16);
+
+    let code = ContractCode::new(near_test_contracts::trivial_contract().to_vec(), None);
+    let code_hash = *code.hash();
+    let missing_hash = CryptoHash::hash_bytes(b"missing");
+    // The code is read on the compilation threads, and missing contracts are skipped.
+    let load = move |hash: CryptoHash| (hash == code_hash).then(|| code.clone_for_tests());
+    assert!(compiler.submit_stored([code_hash, missing_hash], load, Arc::clone(&config), &cache));
+    while compiler.is_busy() {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(cache.len(), 1);
+    assert!(cache.has(&get_contract_cache_key(code_hash, &config)).unwrap());
+}
+
 fn make_cached_contract_call_vm(
     config: Arc<Config>,
     cache: &dyn ContractRuntimeCache,
@@ -70,3 +70,43 @@ pub fn test_ts_contract() {
         }
     });
 }
+
+#[test]
+#[cfg(feature = "wasmtime_vm")]
+pub fn test_ts_contract_wasm_profile() {
+    let mut config = test_vm_config();
+    crate::configure_for_wasm_profiling(&mut config);
+    let config = Arc::new(config);
+    let code = ContractCode::new(near_test_contracts::ts_contract().to_vec(), None);
+    let mut fake_external = MockedExternal::with_code(code);
+    let context = create_context(b"foo bar".to_vec());
+    let fees = Arc::new(RuntimeFeesConfig::test());
+
+    let runtime = config.vm_kind.runtime(config.clone()).expect("runtime has not been compiled");
+    let gas_counter = context.make_gas_counter(&config);
+    let (outcome, profile) = crate::profile_wasm_gas(|| {
+        runtime
+            .prepare(&fake_external, None, gas_counter, "try_storage_write")
+            .run(&mut fake_external, &context, fees)
+            .expect("execution failed")
+    });
+    assert_eq!(outcome.aborted, None);
+    assert_eq!(profile.total_gas(), outcome.burnt_gas);
+
+    let stacks: Vec<_> = profile.stacks().map(|(stack, _)| stack.join(";")).collect();
+    for expected in [
+        crate::CONTRACT_LOADING,
+        "assembly/index/try_storage_write",
+        "assembly/index/try_storage_write;host::input",
+        "assembly/index/try_storage_write;host::storage_write",
+    ] {
+        assert!(stacks.iter().any(|stack| stack == expected), "{expected} not in {stacks:?}");
+    }
+    for stack in &stacks {
+        assert!(
+            stack == crate::CONTRACT_LOADING
+                || stack.starts_with("assembly/index/try_storage_write"),
+            "unexpected stack {stack}"
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use super::test_builder::test_builder;
-#[cfg(feature = "prepare")]
+#[cfg(any(feature = "prepare", all(feature = "near_vm", target_arch = "x86_64")))]
 use super::test_vm_config;
 #[cfg(feature = "prepare")]
 use crate::tests::with_vm_variants;
@@ -95,7 +95,6 @@ static EXPECTED_UNSUPPORTED: &[(&str, &str)] = &[
     // ("module_linking", MODULE_LINKING),
     ("tail_call", TAIL_CALL),
     ("multi_value", MULTI_VALUE),
-    ("bulk_memory", BULK_MEMORY),
     ("reference_types", REFERENCE_TYPES),
     ("threads", THREADS),
     ("simd", SIMD),
@@ -154,3 +153,89 @@ fn extension_saturating_float_to_int() {
             "#]],
         ]);
 }
+
+#[test]
+#[cfg(feature = "prepare")]
+fn extension_bulk_memory() {
+    let wasm = wat::parse_str(BULK_MEMORY).expect("parsing test wat should succeed");
+    for bulk_memory in [false, true] {
+        let config = near_parameters::vm::Config { bulk_memory, ..test_vm_config() };
+        with_vm_variants(&config, |kind| {
+            let result = crate::prepare::prepare_contract(&wasm, &config, kind);
+            assert_eq!(result.is_ok(), bulk_memory, "unexpected preparation result: {result:?}");
+        });
+    }
+}
+
+#[cfg(all(feature = "near_vm", target_arch = "x86_64"))]
+fn bulk_memory_burnt_gas(
+    kind: near_parameters::vm::VMKind,
+    config: &std::sync::Arc<near_parameters::vm::Config>,
+    method: &str,
+) -> u64 {
+    use crate::ContractCode;
+    use crate::logic::mocks::mock_external::MockedExternal;
+    use crate::runner::VMKindExt;
+    use near_parameters::RuntimeFeesConfig;
+    use std::sync::Arc;
+
+    let wasm = wat::parse_str(
+        r#"
+        (module
+          (memory 1)
+          (data "0123456789abcdefghijklmnopqrstuvwxyz")
+          (func (export "fill_small") (memory.fill (i32.const 0) (i32.const 1) (i32.const 8)))
+          (func (export "fill_large") (memory.fill (i32.const 0) (i32.const 1) (i32.const 8008)))
+          (func (export "copy") (local i64)
+            (memory.copy (i32.const 0) (i32.const 100) (i32.const 17))
+            (memory.copy (i32.const 0) (i32.const 100) (i32.const 0)))
+          (func (export "init")
+            (memory.init 0 (i32.const 0) (i32.const 3) (i32.const 9)))
+        )
+        "#,
+    )
+    .unwrap();
+    let mut external = MockedExternal::with_code(ContractCode::new(wasm, None));
+    let context = super::create_context(vec![]);
+    let gas_counter = context.make_gas_counter(config);
+    let runtime = kind.runtime(Arc::clone(config)).unwrap();
+    let outcome = runtime
+        .prepare(&external, None, gas_counter, method)
+        .run(&mut external, &context, Arc::new(RuntimeFeesConfig::test()))
+        .unwrap();
+    assert_eq!(outcome.aborted, None);
+    outcome.burnt_gas
+}
+
+#[test]
+#[cfg(all(feature = "near_vm", target_arch = "x86_64"))]
+fn bulk_memory_charges_per_word() {
+    use near_parameters::vm::VMKind;
+    use std::sync::Arc;
+
+    let config = Arc::new(near_parameters::vm::Config { bulk_memory: true, ..test_vm_config() });
+    let burnt_gas = |method| bulk_memory_burnt_gas(VMKind::NearVm, &config, method);
+    // 1 word against 1001 words, the rest of the two functions costs the same.
+    assert_eq!(
+        burnt_gas("fill_large") - burnt_gas("fill_small"),
+        1000 * u64::from(config.regular_op_cost)
+    );
+}
+
+/// Wasmtime runs the contracts on the nodes that are not on x86_64, so it must burn exactly
+/// the same gas as NearVM.
+#[test]
+#[cfg(all(feature = "near_vm", feature = "wasmtime_vm", target_arch = "x86_64"))]
+fn bulk_memory_burns_the_same_gas_in_all_vms() {
+    use near_parameters::vm::VMKind;
+    use std::sync::Arc;
+
+    let config = Arc::new(near_parameters::vm::Config { bulk_memory: true, ..test_vm_config() });
+    for method in ["fill_small", "fill_large", "copy", "init"] {
+        assert_eq!(
+            bulk_memory_burnt_gas(VMKind::NearVm, &config, method),
+            bulk_memory_burnt_gas(VMKind::Wasmtime, &config, method),
+            "{method}"
+        );
+    }
+}
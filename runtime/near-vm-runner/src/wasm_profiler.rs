@@ -0,0 +1,258 @@
+//! Attribution of the gas burnt by contracts to their wasm functions.
+//!
+//! [`ProfileDataV3`](crate::ProfileDataV3) breaks the gas down by host function and action, but
+//! does not tell which functions of the contract burnt it. While [`profile_wasm_gas`] runs, every
+//! gas charge made by the gas metering instrumentation or by a host function is recorded along
+//! with the wasm call stack that made it. The result can be written in the collapsed stack format
+//! understood by flamegraph tools, or in the pprof format.
+//!
+//! Only Wasmtime can capture the call stack, so the contracts must be run with a config changed by
+//! [`configure_for_wasm_profiling`]. This is meant for offline tools such as the state viewer.
+
+use near_parameters::vm::{Config, VMKind};
+use near_primitives_core::types::Gas;
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static PROFILE: Mutex<WasmGasProfile> = Mutex::new(WasmGasProfile::new());
+
+/// Gas burnt by contracts, by call stack.
+///
+/// Stacks are listed from the outermost frame. Frames of host functions are prefixed with
+/// `host::`, the other frames are the names of the wasm functions in the `name` section of the
+/// contract, or `wasm-function[<index>]` for the functions without a name. The gas burnt for
+/// loading the contract, before any of its code runs, is under the [`CONTRACT_LOADING`] stack.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WasmGasProfile {
+    stacks: BTreeMap<Vec<String>, Gas>,
+}
+
+impl WasmGasProfile {
+    pub const fn new() -> Self {
+        Self { stacks: BTreeMap::new() }
+    }
+
+    pub fn record(&mut self, stack: Vec<String>, gas: Gas) {
+        if gas == 0 {
+            return;
+        }
+        let total = self.stacks.entry(stack).or_default();
+        *total = total.saturating_add(gas);
+    }
+
+    pub fn stacks(&self) -> impl Iterator<Item = (&[String], Gas)> {
+        self.stacks.iter().map(|(stack, gas)| (stack.as_slice(), *gas))
+    }
+
+    pub fn total_gas(&self) -> Gas {
+        self.stacks.values().fold(0, |total, gas| total.saturating_add(*gas))
+    }
+
+    /// Writes the profile in the collapsed stack format, one `frame;frame;frame gas` line per
+    /// stack.
+    pub fn write_collapsed(&self, out: &mut dyn std::io::Write) -> std::io::Result<()> {
+        for (stack, gas) in &self.stacks {
+            writeln!(out, "{} {}", stack.join(";"), gas)?;
+        }
+        Ok(())
+    }
+
+    /// Encodes the profile as an uncompressed pprof `Profile` protobuf message, with a single
+    /// `gas` sample type.
+    pub fn to_pprof(&self) -> Vec<u8> {
+        let mut strings = StringTable::default();
+        // Every function gets a location of the same id.
+        let mut functions: HashMap<&str, u64> = HashMap::new();
+        let mut profile = Vec::new();
+
+        let gas = strings.index("gas");
+        let mut sample_type = Vec::new();
+        put_uint(&mut sample_type, 1, gas);
+        put_uint(&mut sample_type, 2, gas);
+        put_bytes(&mut profile, 1, &sample_type);
+
+        for (stack, value) in &self.stacks {
+            let mut location_ids = Vec::with_capacity(stack.len());
+            // pprof lists the locations of a sample from the innermost frame.
+            for frame in stack.iter().rev() {
+                let next_id = functions.len() as u64 + 1;
+                location_ids.push(*functions.entry(frame.as_str()).or_insert(next_id));
+            }
+            let mut sample = Vec::new();
+            put_packed(&mut sample, 1, &location_ids);
+            put_packed(&mut sample, 2, &[*value]);
+            put_bytes(&mut profile, 2, &sample);
+        }
+
+        let mut functions: Vec<_> = functions.into_iter().collect();
+        functions.sort_by_key(|(_, id)| *id);
+        for (_, id) in &functions {
+            let mut line = Vec::new();
+            put_uint(&mut line, 1, *id);
+            let mut location = Vec::new();
+            put_uint(&mut location, 1, *id);
+            put_bytes(&mut location, 4, &line);
+            put_bytes(&mut profile, 4, &location);
+        }
+        for (name, id) in &functions {
+            let name = strings.index(name);
+            let mut function = Vec::new();
+            put_uint(&mut function, 1, *id);
+            put_uint(&mut function, 2, name);
+            put_uint(&mut function, 3, name);
+            put_bytes(&mut profile, 5, &function);
+        }
+        for string in &strings.strings {
+            put_bytes(&mut profile, 6, string.as_bytes());
+        }
+        profile
+    }
+}
+
+/// Frame of the gas burnt for loading the contracts.
+pub const CONTRACT_LOADING: &str = "contract_loading";
+
+/// Changes `config` so that the contracts run with it can be profiled by [`profile_wasm_gas`].
+///
+/// The contracts are run with Wasmtime, the only VM that can capture the call stack, and keep
+/// their custom sections for the function names. The gas costs stay the same.
+pub fn configure_for_wasm_profiling(config: &mut Config) {
+    config.vm_kind = VMKind::Wasmtime;
+    config.discard_custom_sections = false;
+}
+
+/// Runs `f` while recording the gas burnt by the contracts it executes.
+///
+/// Only the contracts run with Wasmtime are recorded, see [`configure_for_wasm_profiling`].
+///
+/// # Panics
+///
+/// Panics if a profile is already being recorded.
+pub fn profile_wasm_gas<T>(f: impl FnOnce() -> T) -> (T, WasmGasProfile) {
+    struct Guard;
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            ACTIVE.store(false, Ordering::SeqCst);
+        }
+    }
+
+    assert!(!ACTIVE.swap(true, Ordering::SeqCst), "wasm gas profiling is already in progress");
+    let guard = Guard;
+    *PROFILE.lock() = WasmGasProfile::new();
+    let result = f();
+    drop(guard);
+    (result, std::mem::take(&mut *PROFILE.lock()))
+}
+
+pub(crate) fn is_active() -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+}
+
+pub(crate) fn record(stack: Vec<String>, gas: Gas) {
+    PROFILE.lock().record(stack, gas);
+}
+
+#[derive(Default)]
+struct StringTable {
+    strings: Vec<String>,
+    indices: HashMap<String, u64>,
+}
+
+impl StringTable {
+    fn index(&mut self, string: &str) -> u64 {
+        if self.strings.is_empty() {
+            // The first string of a pprof string table must be empty.
+            self.strings.push(String::new());
+            self.indices.insert(String::new(), 0);
+        }
+        if let Some(index) = self.indices.get(string) {
+            return *index;
+        }
+        let index = self.strings.len() as u64;
+        self.strings.push(string.to_string());
+        self.indices.insert(string.to_string(), index);
+        index
+    }
+}
+
+fn put_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn put_uint(out: &mut Vec<u8>, field: u64, value: u64) {
+    put_varint(out, field << 3);
+    put_varint(out, value);
+}
+
+fn put_bytes(out: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    put_varint(out, (field << 3) | 2);
+    put_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn put_packed(out: &mut Vec<u8>, field: u64, values: &[u64]) {
+    let mut packed = Vec::new();
+    for value in values {
+        put_varint(&mut packed, *value);
+    }
+    put_bytes(out, field, &packed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WasmGasProfile;
+
+    fn stack(frames: &[&str]) -> Vec<String> {
+        frames.iter().map(|frame| frame.to_string()).collect()
+    }
+
+    #[test]
+    fn test_collapsed_output() {
+        let mut profile = WasmGasProfile::new();
+        profile.record(stack(&["main", "inner"]), 10);
+        profile.record(stack(&["main"]), 5);
+        profile.record(stack(&["main", "inner"]), 7);
+        profile.record(stack(&["main", "host::log_utf8"]), 3);
+        profile.record(stack(&["main", "unused"]), 0);
+        assert_eq!(profile.total_gas(), 25);
+
+        let mut out = Vec::new();
+        profile.write_collapsed(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "main 5\nmain;host::log_utf8 3\nmain;inner 17\n"
+        );
+    }
+
+    #[test]
+    fn test_pprof_output() {
+        let mut profile = WasmGasProfile::new();
+        profile.record(stack(&["main", "inner"]), 300);
+        assert_eq!(
+            profile.to_pprof(),
+            [
+                // sample_type { type: "gas", unit: "gas" }
+                0x0a, 0x04, 0x08, 0x01, 0x10, 0x01,
+                // sample { location_id: [1, 2], value: [300] }
+                0x12, 0x08, 0x0a, 0x02, 0x01, 0x02, 0x12, 0x02, 0xac, 0x02,
+                // location { id: 1, line { function_id: 1 } }
+                0x22, 0x06, 0x08, 0x01, 0x22, 0x02, 0x08, 0x01,
+                // location { id: 2, line { function_id: 2 } }
+                0x22, 0x06, 0x08, 0x02, 0x22, 0x02, 0x08, 0x02,
+                // function { id: 1, name: "inner", system_name: "inner" }
+                0x2a, 0x06, 0x08, 0x01, 0x10, 0x02, 0x18, 0x02,
+                // function { id: 2, name: "main", system_name: "main" }
+                0x2a, 0x06, 0x08, 0x02, 0x10, 0x03, 0x18, 0x03,
+                // string_table: ["", "gas", "inner", "main"]
+                0x32, 0x00, 0x32, 0x03, b'g', b'a', b's', 0x32, 0x05, b'i', b'n', b'n', b'e', b'r',
+                0x32, 0x04, b'm', b'a', b'i', b'n',
+            ]
+        );
+    }
+}
@@ -373,6 +373,10 @@ impl crate::PreparedContract for VMResult<PreparedContract> {
     ) -> VMResult {
         let PreparedContract { config, gas_counter, result } = (*self)?;
         let result_state = ExecutionResultState::new(&context, gas_counter, config);
+        if crate::wasm_profiler::is_active() {
+            let stack = vec![crate::wasm_profiler::CONTRACT_LOADING.to_string()];
+            crate::wasm_profiler::record(stack, result_state.burnt_gas());
+        }
         let ReadyContract { mut store, mut memory, module, method } = match result {
             PreparationResult::Ready(r) => r,
             PreparationResult::OutcomeAbortButNopInOldProtocol(e) => {
@@ -429,6 +433,17 @@ impl std::fmt::Display for ErrorContainer {
     }
 }
 
+/// Returns the names of the wasm functions on the stack of `caller`, from the outermost one,
+/// followed by `host_function` if any.
+fn wasm_call_stack(caller: &wasmtime::Caller<'_, ()>, host_function: Option<&str>) -> Vec<String> {
+    let backtrace = wasmtime::WasmBacktrace::capture(caller);
+    let frames = backtrace.frames().iter().rev().map(|frame| match frame.func_name() {
+        Some(name) => name.to_string(),
+        None => format!("wasm-function[{}]", frame.func_index()),
+    });
+    frames.chain(host_function.map(|name| format!("host::{name}"))).collect()
+}
+
 thread_local! {
     static CALLER_CONTEXT: UnsafeCell<*mut c_void> = const { UnsafeCell::new(core::ptr::null_mut()) };
 }
@@ -458,6 +473,12 @@ fn link<'a, 'b>(
                 let _span = TRACE.then(|| {
                     tracing::trace_span!(target: "vm::host_function", stringify!($name)).entered()
                 });
+                // The gas charged by the instrumentation is attributed to the calling wasm
+                // function, the gas charged by other host functions to the host function itself.
+                let profiled_stack = crate::wasm_profiler::is_active().then(|| {
+                    let host_function = (stringify!($mod) == "env").then_some(stringify!($name));
+                    wasm_call_stack(&caller, host_function)
+                });
                 // the below is bad. don't do this at home. it probably works thanks to the exact way the system is setup.
                 // Thankfully, this doesn't run in production, and hopefully should be possible to remove before we even
                 // consider doing so.
@@ -472,7 +493,13 @@ fn link<'a, 'b>(
                     crate::wasmtime_runner::CALLER.with(|runner_caller| *runner_caller.borrow_mut() = std::mem::transmute(caller));
                 }
                 let logic: &mut VMLogic<'_> = unsafe { &mut *(data as *mut VMLogic<'_>) };
-                match logic.$func( $( $arg_name as $arg_type, )* ) {
+                let burnt_gas_before = logic.result_state.burnt_gas();
+                let result = logic.$func( $( $arg_name as $arg_type, )* );
+                if let Some(stack) = profiled_stack {
+                    let burnt_gas = logic.result_state.burnt_gas().saturating_sub(burnt_gas_before);
+                    crate::wasm_profiler::record(stack, burnt_gas);
+                }
+                match result {
                     Ok(result) => Ok(result as ($( $returns ),* ) ),
                     Err(err) => {
                         Err(ErrorContainer(parking_lot::Mutex::new(Some(err))).into())
@@ -95,6 +95,9 @@ pub(crate) struct FuncGen<'a> {
     /// Cost for initializing the stack of the function
     stack_init_gas_cost: u64,
 
+    /// Cost charged by bulk memory operations per 8 bytes of memory they access
+    bulk_memory_word_gas_cost: u64,
+
     /// Iterator over the gas instrumentation points
     gas_iter: iter::Peekable<iter::Zip<slice::Iter<'a, usize>, slice::Iter<'a, u64>>>,
 
@@ -420,6 +423,31 @@ impl<'a> FuncGen<'a> {
         self.machine.release_temp_gpr(current_burnt_reg);
     }
 
+    /// Charges the gas for the `len` bytes accessed by a bulk memory operation, on top of the
+    /// static cost of the instruction accounted for by the gas instrumentation.
+    fn emit_bulk_memory_gas(&mut self, len: Location) {
+        if self.bulk_memory_word_gas_cost == 0 {
+            return;
+        }
+        let words = self.machine.acquire_temp_gpr().unwrap();
+        // `len` is an i32, so the 64-bit computation of the rounded up word count cannot
+        // overflow.
+        self.assembler.emit_mov(Size::S32, len, Location::GPR(words));
+        self.assembler.emit_add(Size::S64, Location::Imm32(7), Location::GPR(words));
+        self.assembler.emit_shr(Size::S64, Location::Imm8(3), Location::GPR(words));
+        let word_cost = self.machine.acquire_temp_gpr().unwrap();
+        self.assembler.emit_mov(
+            Size::S64,
+            Location::Imm64(self.bulk_memory_word_gas_cost),
+            Location::GPR(word_cost),
+        );
+        self.assembler.emit_imul(Size::S64, Location::GPR(word_cost), Location::GPR(words));
+        self.assembler.emit_jmp(Condition::Overflow, self.special_labels.integer_overflow);
+        self.machine.release_temp_gpr(word_cost);
+        self.emit_gas(Location::GPR(words));
+        self.machine.release_temp_gpr(words);
+    }
+
     fn emit_trap(&mut self, code: TrapCode) {
         let label = self.assembler.get_label();
         self.assembler.emit_label(label);
@@ -1681,6 +1709,7 @@ impl<'a> FuncGen<'a> {
         local_func_index: LocalFunctionIndex,
         calling_convention: CallingConvention,
         stack_init_gas_cost: u64,
+        bulk_memory_word_gas_cost: u64,
         gas_offsets: &'a [usize],
         gas_costs: &'a [u64],
         _gas_kinds: &'a [InstrumentationKind],
@@ -1721,6 +1750,7 @@ impl<'a> FuncGen<'a> {
             calling_convention,
             signature,
             stack_init_gas_cost,
+            bulk_memory_word_gas_cost,
             gas_iter: gas_offsets.iter().zip(gas_costs.iter()).peekable(),
             stack_size: u32::try_from(stack_size).map_err(|_| CodegenError {
                 message: "one function has a stack more than u32::MAX deep".to_string(),
@@ -5080,6 +5110,7 @@ impl<'a> FuncGen<'a> {
                 let len = self.value_stack.pop().unwrap();
                 let src = self.value_stack.pop().unwrap();
                 let dst = self.value_stack.pop().unwrap();
+                self.emit_bulk_memory_gas(len);
                 self.machine.release_locations_only_regs(&[len, src, dst]);
 
                 self.assembler.emit_mov(
@@ -5130,6 +5161,7 @@ impl<'a> FuncGen<'a> {
                 let len = self.value_stack.pop().unwrap();
                 let src_pos = self.value_stack.pop().unwrap();
                 let dst_pos = self.value_stack.pop().unwrap();
+                self.emit_bulk_memory_gas(len);
                 self.machine.release_locations_only_regs(&[len, src_pos, dst_pos]);
 
                 let memory_index = MemoryIndex::new(src_mem as usize);
@@ -5164,6 +5196,7 @@ impl<'a> FuncGen<'a> {
                 let len = self.value_stack.pop().unwrap();
                 let val = self.value_stack.pop().unwrap();
                 let dst = self.value_stack.pop().unwrap();
+                self.emit_bulk_memory_gas(len);
                 self.machine.release_locations_only_regs(&[len, val, dst]);
 
                 let memory_index = MemoryIndex::new(mem as usize);
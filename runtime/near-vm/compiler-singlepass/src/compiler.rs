@@ -116,6 +116,7 @@ impl Compiler for SinglepassCompiler {
                         near_vm_compiler::FunctionReader::new(input.module_offset, input.data);
                     let stack_init_gas_cost = tunables
                         .stack_init_gas_cost(instrumentation.function_frame_sizes[i.index()]);
+                    let bulk_memory_word_gas_cost = tunables.bulk_memory_word_gas_cost();
                     let stack_size = instrumentation.function_frame_sizes[i.index()]
                         .checked_add(instrumentation.function_operand_stack_sizes[i.index()])
                         .ok_or_else(|| {
@@ -133,6 +134,7 @@ impl Compiler for SinglepassCompiler {
                         i,
                         calling_convention,
                         stack_init_gas_cost,
+                        bulk_memory_word_gas_cost,
                         &instrumentation.gas_offsets[i.index()],
                         &instrumentation.gas_costs[i.index()],
                         &instrumentation.gas_kinds[i.index()],
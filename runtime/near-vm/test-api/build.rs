@@ -32,7 +32,16 @@ fn main() -> anyhow::Result<()> {
                 wast_processor,
             )?;
             test_directory_module(spectests, "../tests/wast/spec/proposals/simd", wast_processor)?;
-            // test_directory_module(spectests, "tests/wast/spec/proposals/bulk-memory-operations", wast_processor)?;
+            test_directory_module(
+                spectests,
+                "../tests/wast/spec/proposals/bulk-memory-operations",
+                wast_processor,
+            )?;
+            test_directory_module(
+                spectests,
+                "../tests/wast/spec/proposals/sign-extension-ops",
+                wast_processor,
+            )?;
             Ok(())
         })?;
         with_test_module(&mut spectests, "wasmer", |spectests| {
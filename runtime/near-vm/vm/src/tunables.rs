@@ -59,6 +59,9 @@ pub trait Tunables: Sync {
 
     /// Cost for initializing a stack frame
     fn stack_init_gas_cost(&self, frame_size: u64) -> u64;
+
+    /// Cost charged by bulk memory operations per 8 bytes of memory accessed
+    fn bulk_memory_word_gas_cost(&self) -> u64;
 }
 
 #[doc(hidden)]
@@ -118,4 +121,8 @@ impl Tunables for TestTunables {
     fn stack_init_gas_cost(&self, _frame_size: u64) -> u64 {
         unimplemented!()
     }
+
+    fn bulk_memory_word_gas_cost(&self) -> u64 {
+        unimplemented!()
+    }
 }
@@ -412,6 +412,18 @@ pub enum Cost {
     /// Estimates `keccak512_byte`, the cost charged per input byte in calls to the
     /// keccak512-hash host function.
     Keccak512Byte,
+    /// Estimates `sha3_256_base`, the cost charged once per call to the
+    /// sha3_256-hash host function.
+    Sha3256Base,
+    /// Estimates `sha3_256_byte`, the cost charged per input byte in calls to the
+    /// sha3_256-hash host function.
+    Sha3256Byte,
+    /// Estimates `blake2b_base`, the cost charged once per call to the
+    /// blake2b-hash host function.
+    Blake2bBase,
+    /// Estimates `blake2b_byte`, the cost charged per input byte in calls to the
+    /// blake2b-hash host function.
+    Blake2bByte,
     /// Estimates `ripemd160_base`, the cost charged once per call to the
     /// ripemd160-hash host function.
     Ripemd160Base,
@@ -457,6 +469,18 @@ pub enum Cost {
     /// In the end, the cost should be low enough, compared to the base cost,
     /// that it does not matter all that much if we overestimate it a bit.
     Ed25519VerifyByte,
+    /// Estimates `p256_verify_base`, which covers the base cost of the host
+    /// function `p256_verify` to verify a secp256r1 ECDSA signature.
+    ///
+    /// Estimation: Same as for `Ed25519VerifyBase`, verify a fixed signature
+    /// embedded in the test contract `N` times in a loop and divide by `N`.
+    P256VerifyBase,
+    /// Estimates `p256_verify_byte`, the cost charged per message byte in calls
+    /// to the p256_verify host function, which is the cost of SHA-256 hashing
+    /// the message.
+    ///
+    /// Estimation: Same as for `Ed25519VerifyByte`.
+    P256VerifyByte,
     // `storage_write` records a single key-value pair, initially in the
     // prospective changes in-memory hash map, and then once a full block has
     // been processed, in the on-disk trie. If there was already a value
@@ -506,6 +530,25 @@ pub enum Cost {
     /// Estimation: Contract call that reads N big values (10kiB) and divide
     /// the cost by total number of value bytes.
     StorageReadValueByte,
+    /// Estimates `ExtCost::storage_read_account_base` which is charged once
+    /// per call to `storage_read_account`.
+    ///
+    /// Estimation: Contract call that reads N small values of its own account
+    /// through `storage_read_account` and divide the cost by N.
+    StorageReadAccountBase,
+    /// Estimates `ExtCost::storage_read_account_key_byte` which is charged for
+    /// each byte in account ids and keys of `storage_read_account` calls.
+    ///
+    /// Estimation: Contract call that reads N small values with a big key
+    /// (10kiB) and divide the cost by total number of account id and key
+    /// bytes.
+    StorageReadAccountKeyByte,
+    /// Estimates `ExtCost::storage_read_account_value_byte` which is charged
+    /// for each byte in values of `storage_read_account` calls.
+    ///
+    /// Estimation: Contract call that reads N big values (100kiB) and divide
+    /// the cost by total number of value bytes.
+    StorageReadAccountValueByte,
 
     // `storage_remove` adds a deletion transaction to the prospective changes,
     // which is applied at the end of the block.
@@ -561,6 +604,39 @@ pub enum Cost {
     /// DEPRECATED: Was charged in `storage_iter_next`
     StorageIterNextValueByte,
 
+    // `storage_iter_batch` walks the trie to find the keys in a range, then
+    // reads their values like `storage_read`.
+    /// Estimates `ExtCost::storage_iter_batch_base` which is charged once per
+    /// call to `storage_iter_batch`.
+    ///
+    /// Estimation: Contract call that looks up N empty ranges starting at
+    /// existing keys and divide the cost by N.
+    StorageIterBatchBase,
+    /// Estimates `ExtCost::storage_iter_batch_bound_byte` which is charged for
+    /// each byte in the range bounds of `storage_iter_batch` calls.
+    ///
+    /// Estimation: Contract call that looks up N empty ranges with big bounds
+    /// (10kiB) and divide the cost by total number of bound bytes.
+    StorageIterBatchBoundByte,
+    /// Estimates `ExtCost::storage_iter_batch_entry_base` which is charged for
+    /// each entry returned by `storage_iter_batch`.
+    ///
+    /// Estimation: Contract call that reads N entries with small keys and
+    /// values in one batch and divide the cost by N.
+    StorageIterBatchEntryBase,
+    /// Estimates `ExtCost::storage_iter_batch_key_byte` which is charged for
+    /// each byte in keys returned by `storage_iter_batch`.
+    ///
+    /// Estimation: Contract call that reads N entries with big keys (10kiB) in
+    /// one batch and divide the cost by total number of key bytes.
+    StorageIterBatchKeyByte,
+    /// Estimates `ExtCost::storage_iter_batch_value_byte` which is charged for
+    /// each byte in values returned by `storage_iter_batch`.
+    ///
+    /// Estimation: Contract call that reads N entries with big values (10kiB)
+    /// in one batch and divide the cost by total number of value bytes.
+    StorageIterBatchValueByte,
+
     /// Estimates `touching_trie_node` which is charged when smart contracts
     /// access storage either through `storage_has_key`, `storage_read`,
     /// `storage_write` or `storage_remove`. The fee is paid once for each
@@ -624,6 +700,21 @@ pub enum Cost {
     AltBn128PairingCheckElement,
     AltBn128G1SumBase,
     AltBn128G1SumElement,
+    /// Estimates `alt_bn128_poseidon_base`, the cost charged once per call to
+    /// the `alt_bn128_poseidon` host function.
+    ///
+    /// Estimation: Hash a single field element `N` times in a loop and divide
+    /// by `N`.
+    AltBn128PoseidonBase,
+    /// Estimates `alt_bn128_poseidon_element`, the cost charged per element of
+    /// the MDS matrix in calls to the `alt_bn128_poseidon` host function. The
+    /// matrix is multiplied with the state in every round, so the cost grows
+    /// with the square of the state width, which is the number of inputs plus
+    /// one.
+    ///
+    /// Estimation: Hash 12 field elements, the maximum, `N` times in a loop and
+    /// divide by `13 * 13 * N`.
+    AltBn128PoseidonElement,
     Bls12381P1SumBase,
     Bls12381P1SumElement,
     Bls12381P2SumBase,
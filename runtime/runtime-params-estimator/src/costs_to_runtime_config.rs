@@ -128,11 +128,17 @@ fn estimation(cost: ExtCosts) -> Option<Cost> {
         ExtCosts::keccak256_byte => Cost::Keccak256Byte,
         ExtCosts::keccak512_base => Cost::Keccak512Base,
         ExtCosts::keccak512_byte => Cost::Keccak512Byte,
+        ExtCosts::sha3_256_base => Cost::Sha3256Base,
+        ExtCosts::sha3_256_byte => Cost::Sha3256Byte,
+        ExtCosts::blake2b_base => Cost::Blake2bBase,
+        ExtCosts::blake2b_byte => Cost::Blake2bByte,
         ExtCosts::ripemd160_base => Cost::Ripemd160Base,
         ExtCosts::ripemd160_block => Cost::Ripemd160Block,
         ExtCosts::ecrecover_base => Cost::EcrecoverBase,
         ExtCosts::ed25519_verify_base => Cost::Ed25519VerifyBase,
         ExtCosts::ed25519_verify_byte => Cost::Ed25519VerifyByte,
+        ExtCosts::p256_verify_base => Cost::P256VerifyBase,
+        ExtCosts::p256_verify_byte => Cost::P256VerifyByte,
         ExtCosts::log_base => Cost::LogBase,
         ExtCosts::log_byte => Cost::LogByte,
         ExtCosts::storage_write_base => Cost::StorageWriteBase,
@@ -142,6 +148,14 @@ fn estimation(cost: ExtCosts) -> Option<Cost> {
         ExtCosts::storage_read_base => Cost::StorageReadBase,
         ExtCosts::storage_read_key_byte => Cost::StorageReadKeyByte,
         ExtCosts::storage_read_value_byte => Cost::StorageReadValueByte,
+        ExtCosts::storage_read_account_base => Cost::StorageReadAccountBase,
+        ExtCosts::storage_read_account_key_byte => Cost::StorageReadAccountKeyByte,
+        ExtCosts::storage_read_account_value_byte => Cost::StorageReadAccountValueByte,
+        ExtCosts::storage_iter_batch_base => Cost::StorageIterBatchBase,
+        ExtCosts::storage_iter_batch_bound_byte => Cost::StorageIterBatchBoundByte,
+        ExtCosts::storage_iter_batch_entry_base => Cost::StorageIterBatchEntryBase,
+        ExtCosts::storage_iter_batch_key_byte => Cost::StorageIterBatchKeyByte,
+        ExtCosts::storage_iter_batch_value_byte => Cost::StorageIterBatchValueByte,
         ExtCosts::storage_remove_base => Cost::StorageRemoveBase,
         ExtCosts::storage_remove_key_byte => Cost::StorageRemoveKeyByte,
         ExtCosts::storage_remove_ret_value_byte => Cost::StorageRemoveRetValueByte,
@@ -154,6 +168,8 @@ fn estimation(cost: ExtCosts) -> Option<Cost> {
         ExtCosts::promise_return => Cost::PromiseReturn,
         ExtCosts::alt_bn128_g1_sum_base => Cost::AltBn128G1SumBase,
         ExtCosts::alt_bn128_g1_sum_element => Cost::AltBn128G1SumElement,
+        ExtCosts::alt_bn128_poseidon_base => Cost::AltBn128PoseidonBase,
+        ExtCosts::alt_bn128_poseidon_element => Cost::AltBn128PoseidonElement,
         ExtCosts::alt_bn128_g1_multiexp_base => Cost::AltBn128G1MultiexpBase,
         ExtCosts::alt_bn128_g1_multiexp_element => Cost::AltBn128G1MultiexpElement,
         ExtCosts::alt_bn128_pairing_check_base => Cost::AltBn128PairingCheckBase,
@@ -56,6 +56,8 @@ pub(crate) struct CachedCosts {
     pub(crate) apply_block: Option<GasCost>,
     pub(crate) touching_trie_node_write: Option<GasCost>,
     pub(crate) ed25519_verify_base: Option<GasCost>,
+    #[cfg(feature = "nightly")]
+    pub(crate) p256_verify_base: Option<GasCost>,
     pub(crate) function_call_base: Option<GasCost>,
     #[cfg(feature = "nightly")]
     pub(crate) yield_create_base: Option<GasCost>,
@@ -187,6 +189,7 @@ impl<'c> EstimatorContext<'c> {
             current_protocol_version: PROTOCOL_VERSION,
             config: Arc::new(runtime_config),
             cache: Some(Box::new(cache)),
+            defer_deploy_compilation: false,
             is_new_chunk: true,
             congestion_info,
             bandwidth_requests: BlockBandwidthRequests::empty(),
@@ -235,15 +235,31 @@ static ALL_COSTS: &[(Cost, fn(&mut EstimatorContext) -> GasCost)] = &[
     (Cost::Keccak256Byte, keccak256_byte),
     (Cost::Keccak512Base, keccak512_base),
     (Cost::Keccak512Byte, keccak512_byte),
+    #[cfg(feature = "nightly")]
+    (Cost::Sha3256Base, sha3_256_base),
+    #[cfg(feature = "nightly")]
+    (Cost::Sha3256Byte, sha3_256_byte),
+    #[cfg(feature = "nightly")]
+    (Cost::Blake2bBase, blake2b_base),
+    #[cfg(feature = "nightly")]
+    (Cost::Blake2bByte, blake2b_byte),
     (Cost::Ripemd160Base, ripemd160_base),
     (Cost::Ripemd160Block, ripemd160_block),
     (Cost::EcrecoverBase, ecrecover_base),
     (Cost::Ed25519VerifyBase, ed25519_verify_base),
     (Cost::Ed25519VerifyByte, ed25519_verify_byte),
+    #[cfg(feature = "nightly")]
+    (Cost::P256VerifyBase, p256_verify_base),
+    #[cfg(feature = "nightly")]
+    (Cost::P256VerifyByte, p256_verify_byte),
     (Cost::AltBn128G1MultiexpBase, alt_bn128g1_multiexp_base),
     (Cost::AltBn128G1MultiexpElement, alt_bn128g1_multiexp_element),
     (Cost::AltBn128G1SumBase, alt_bn128g1_sum_base),
     (Cost::AltBn128G1SumElement, alt_bn128g1_sum_element),
+    #[cfg(feature = "nightly")]
+    (Cost::AltBn128PoseidonBase, alt_bn128_poseidon_base),
+    #[cfg(feature = "nightly")]
+    (Cost::AltBn128PoseidonElement, alt_bn128_poseidon_element),
     (Cost::AltBn128PairingCheckBase, alt_bn128_pairing_check_base),
     (Cost::AltBn128PairingCheckElement, alt_bn128_pairing_check_element),
     (Cost::StorageHasKeyBase, storage_has_key_base),
@@ -251,6 +267,22 @@ static ALL_COSTS: &[(Cost, fn(&mut EstimatorContext) -> GasCost)] = &[
     (Cost::StorageReadBase, storage_read_base),
     (Cost::StorageReadKeyByte, storage_read_key_byte),
     (Cost::StorageReadValueByte, storage_read_value_byte),
+    #[cfg(feature = "nightly")]
+    (Cost::StorageReadAccountBase, storage_read_account_base),
+    #[cfg(feature = "nightly")]
+    (Cost::StorageReadAccountKeyByte, storage_read_account_key_byte),
+    #[cfg(feature = "nightly")]
+    (Cost::StorageReadAccountValueByte, storage_read_account_value_byte),
+    #[cfg(feature = "nightly")]
+    (Cost::StorageIterBatchBase, storage_iter_batch_base),
+    #[cfg(feature = "nightly")]
+    (Cost::StorageIterBatchBoundByte, storage_iter_batch_bound_byte),
+    #[cfg(feature = "nightly")]
+    (Cost::StorageIterBatchEntryBase, storage_iter_batch_entry_base),
+    #[cfg(feature = "nightly")]
+    (Cost::StorageIterBatchKeyByte, storage_iter_batch_key_byte),
+    #[cfg(feature = "nightly")]
+    (Cost::StorageIterBatchValueByte, storage_iter_batch_value_byte),
     (Cost::StorageWriteBase, storage_write_base),
     (Cost::StorageWriteKeyByte, storage_write_key_byte),
     (Cost::StorageWriteValueByte, storage_write_value_byte),
@@ -1028,6 +1060,24 @@ fn keccak512_byte(ctx: &mut EstimatorContext) -> GasCost {
     fn_cost(ctx, "keccak512_10kib_10k", ExtCosts::keccak512_byte, 10 * 1024 * 10_000)
 }
 
+#[cfg(feature = "nightly")]
+fn sha3_256_base(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost(ctx, "sha3_256_10b_10k", ExtCosts::sha3_256_base, 10_000)
+}
+#[cfg(feature = "nightly")]
+fn sha3_256_byte(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost(ctx, "sha3_256_10kib_10k", ExtCosts::sha3_256_byte, 10 * 1024 * 10_000)
+}
+
+#[cfg(feature = "nightly")]
+fn blake2b_base(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost(ctx, "blake2b_10b_10k", ExtCosts::blake2b_base, 10_000)
+}
+#[cfg(feature = "nightly")]
+fn blake2b_byte(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost(ctx, "blake2b_10kib_10k", ExtCosts::blake2b_byte, 10 * 1024 * 10_000)
+}
+
 fn ripemd160_base(ctx: &mut EstimatorContext) -> GasCost {
     fn_cost(ctx, "ripemd160_10b_10k", ExtCosts::ripemd160_base, 10_000)
 }
@@ -1059,6 +1109,28 @@ fn ed25519_verify_byte(ctx: &mut EstimatorContext) -> GasCost {
     byte - base / iteration_bytes
 }
 
+#[cfg(feature = "nightly")]
+fn p256_verify_base(ctx: &mut EstimatorContext) -> GasCost {
+    if let Some(cost) = &ctx.cached.p256_verify_base {
+        return cost.clone();
+    }
+    let cost = fn_cost(ctx, "p256_verify_32b_500", ExtCosts::p256_verify_base, 500);
+    ctx.cached.p256_verify_base.insert(cost).clone()
+}
+
+#[cfg(feature = "nightly")]
+fn p256_verify_byte(ctx: &mut EstimatorContext) -> GasCost {
+    let base = p256_verify_base(ctx);
+    // inside the WASM function, there are 64 calls to `p256_verify`.
+    let base_call_num = 64;
+    // each call checks a message of size 16kiB
+    let iteration_bytes = 16384;
+    let total_bytes = base_call_num * iteration_bytes;
+    let byte = fn_cost(ctx, "p256_verify_16kib_64", ExtCosts::p256_verify_byte, total_bytes);
+    // need to subtract the base cost, which has already been divided by the number of bytes per iteration
+    byte - base / iteration_bytes
+}
+
 fn alt_bn128g1_multiexp_base(ctx: &mut EstimatorContext) -> GasCost {
     fn_cost(ctx, "alt_bn128_g1_multiexp_1_10", ExtCosts::alt_bn128_g1_multiexp_base, 10)
 }
@@ -1073,6 +1145,17 @@ fn alt_bn128g1_sum_element(ctx: &mut EstimatorContext) -> GasCost {
     fn_cost(ctx, "alt_bn128_g1_sum_10_1k", ExtCosts::alt_bn128_g1_sum_element, 10 * 1000)
 }
 
+#[cfg(feature = "nightly")]
+fn alt_bn128_poseidon_base(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost(ctx, "alt_bn128_poseidon_1_100", ExtCosts::alt_bn128_poseidon_base, 100)
+}
+#[cfg(feature = "nightly")]
+fn alt_bn128_poseidon_element(ctx: &mut EstimatorContext) -> GasCost {
+    // 12 inputs give a state of width 13 and the cost is charged per element
+    // of the 13x13 MDS matrix.
+    fn_cost(ctx, "alt_bn128_poseidon_12_100", ExtCosts::alt_bn128_poseidon_element, 13 * 13 * 100)
+}
+
 fn alt_bn128_pairing_check_base(ctx: &mut EstimatorContext) -> GasCost {
     fn_cost(ctx, "alt_bn128_pairing_check_1_10", ExtCosts::alt_bn128_pairing_check_base, 10)
 }
@@ -1218,6 +1301,99 @@ fn storage_read_value_byte(ctx: &mut EstimatorContext) -> GasCost {
     )
 }
 
+#[cfg(feature = "nightly")]
+fn storage_read_account_base(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost_with_setup(
+        ctx,
+        "storage_write_10b_key_10kib_value_1k",
+        "storage_read_account_10b_key_1k",
+        ExtCosts::storage_read_account_base,
+        1000,
+        0,
+    )
+}
+#[cfg(feature = "nightly")]
+fn storage_read_account_key_byte(ctx: &mut EstimatorContext) -> GasCost {
+    // The contracts read from their own account, all accounts have ids of the same length.
+    let account_id_len = genesis_populate::get_account_id(0).len() as u64;
+    fn_cost_with_setup(
+        ctx,
+        "storage_write_10kib_key_10kib_value_1k",
+        "storage_read_account_10kib_key_1k",
+        ExtCosts::storage_read_account_key_byte,
+        (account_id_len + 10 * 1024) * 1000,
+        0,
+    )
+}
+#[cfg(feature = "nightly")]
+fn storage_read_account_value_byte(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost_with_setup(
+        ctx,
+        "storage_write_10b_key_100kib_value_1k",
+        "storage_read_account_10b_key_1k",
+        ExtCosts::storage_read_account_value_byte,
+        100 * 1024 * 1000,
+        0,
+    )
+}
+
+#[cfg(feature = "nightly")]
+fn storage_iter_batch_base(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost_with_setup(
+        ctx,
+        "storage_write_10b_key_10b_value_1k",
+        "storage_iter_batch_10b_bound_1k",
+        ExtCosts::storage_iter_batch_base,
+        1000,
+        0,
+    )
+}
+#[cfg(feature = "nightly")]
+fn storage_iter_batch_bound_byte(ctx: &mut EstimatorContext) -> GasCost {
+    // Both bounds of every range are 10kib long.
+    fn_cost_with_setup(
+        ctx,
+        "storage_write_10kib_key_10b_value_1k",
+        "storage_iter_batch_10kib_bound_1k",
+        ExtCosts::storage_iter_batch_bound_byte,
+        2 * 10 * 1024 * 1000,
+        0,
+    )
+}
+#[cfg(feature = "nightly")]
+fn storage_iter_batch_entry_base(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost_with_setup(
+        ctx,
+        "storage_write_10b_key_10b_value_1k",
+        "storage_iter_batch_1k_entries",
+        ExtCosts::storage_iter_batch_entry_base,
+        1000,
+        0,
+    )
+}
+#[cfg(feature = "nightly")]
+fn storage_iter_batch_key_byte(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost_with_setup(
+        ctx,
+        "storage_write_10kib_key_10b_value_1k",
+        "storage_iter_batch_1k_entries",
+        ExtCosts::storage_iter_batch_key_byte,
+        10 * 1024 * 1000,
+        0,
+    )
+}
+#[cfg(feature = "nightly")]
+fn storage_iter_batch_value_byte(ctx: &mut EstimatorContext) -> GasCost {
+    fn_cost_with_setup(
+        ctx,
+        "storage_write_10b_key_10kib_value_1k",
+        "storage_iter_batch_1k_entries",
+        ExtCosts::storage_iter_batch_value_byte,
+        10 * 1024 * 1000,
+        0,
+    )
+}
+
 fn storage_write_base(ctx: &mut EstimatorContext) -> GasCost {
     fn_cost(ctx, "storage_write_10b_key_10b_value_1k", ExtCosts::storage_write_base, 1000)
 }
@@ -211,10 +211,15 @@ pub(crate) fn fn_cost_with_setup(
         let (gas_cost, ext_costs) =
             aggregate_per_block_measurements(block_size, measurements, Some(overhead));
 
-        let is_write = [ExtCosts::storage_write_base, ExtCosts::storage_remove_base]
-            .iter()
-            .any(|cost| *ext_costs.get(cost).unwrap_or(&0) > 0);
-        if !is_write {
+        // Writes and the storage iteration go through the trie, unlike reads.
+        let uses_trie = [
+            ExtCosts::storage_write_base,
+            ExtCosts::storage_remove_base,
+            ExtCosts::storage_iter_batch_base,
+        ]
+        .iter()
+        .any(|cost| *ext_costs.get(cost).unwrap_or(&0) > 0);
+        if !uses_trie {
             assert_eq!(
                 0,
                 *ext_costs.get(&ExtCosts::touching_trie_node).unwrap_or(&0),
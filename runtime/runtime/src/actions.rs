@@ -1384,6 +1384,7 @@ mod tests {
             current_protocol_version: 1,
             config: Arc::new(RuntimeConfig::test()),
             cache: None,
+            defer_deploy_compilation: false,
             is_new_chunk: false,
             congestion_info: BlockCongestionInfo::default(),
             bandwidth_requests: BlockBandwidthRequests::empty(),
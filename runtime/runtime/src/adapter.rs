@@ -62,6 +62,8 @@ pub trait ViewRuntimeAdapter {
         &self,
         shard_uid: &ShardUId,
         state_root: MerkleHash,
+        height: BlockHeight,
+        block_hash: &CryptoHash,
         account_id: &AccountId,
         prefix: &[u8],
         include_proof: bool,
@@ -4,7 +4,7 @@ use near_primitives::account::Account;
 use near_primitives::account::id::AccountType;
 use near_primitives::errors::{EpochError, StorageError};
 use near_primitives::hash::CryptoHash;
-use near_primitives::trie_key::TrieKey;
+use near_primitives::trie_key::{TrieKey, trie_key_parsers};
 use near_primitives::types::{AccountId, Balance, BlockHeight, EpochId, EpochInfoProvider, Gas};
 use near_primitives::utils::create_receipt_id_from_action_hash;
 use near_primitives::version::ProtocolVersion;
@@ -13,7 +13,7 @@ use near_store::trie::{AccessOptions, AccessTracker};
 use near_store::{KeyLookupMode, TrieUpdate, TrieUpdateValuePtr, has_promise_yield_receipt};
 use near_vm_runner::logic::errors::{AnyError, InconsistentStateError, VMLogicError};
 use near_vm_runner::logic::types::ReceiptIndex;
-use near_vm_runner::logic::{External, StorageAccessTracker, ValuePtr};
+use near_vm_runner::logic::{External, HostError, StorageAccessTracker, ValuePtr};
 use near_vm_runner::{Contract, ContractCode};
 use near_wallet_contract::wallet_contract;
 use parking_lot::Mutex;
@@ -135,6 +135,41 @@ impl<'a> RuntimeExt<'a> {
     pub fn chain_id(&self) -> String {
         self.epoch_info_provider.chain_id()
     }
+
+    /// Collects the keys of the contract data for `storage_iter_keys`.
+    ///
+    /// The iteration goes through the trie rather than flat storage, so that the visited nodes
+    /// are recorded in the state witness and the chunk validators find the same keys. The values
+    /// are not read, only the nodes visited to find the keys are accounted for.
+    fn contract_data_keys(
+        &self,
+        start: &[u8],
+        end: Option<&[u8]>,
+        limit: u64,
+    ) -> Result<Vec<Vec<u8>>, StorageError> {
+        let prefix = trie_key_parsers::get_raw_prefix_for_contract_data(&self.account_id, &[]);
+        let raw_start = trie_key_parsers::get_raw_prefix_for_contract_data(&self.account_id, start);
+        let lock = self.trie_update.trie().lock_for_iter();
+        let mut iter = self.trie_update.locked_iter_from(
+            &prefix,
+            &raw_start,
+            &lock,
+            Some(&self.trie_access_tracker),
+        )?;
+        let mut keys = Vec::new();
+        while (keys.len() as u64) < limit {
+            let Some(raw_key) = iter.next() else {
+                break;
+            };
+            let raw_key = raw_key?;
+            let key = &raw_key[prefix.len()..];
+            if end.is_some_and(|end| key >= end) {
+                break;
+            }
+            keys.push(key.to_vec());
+        }
+        Ok(keys)
+    }
 }
 
 fn wrap_storage_error(error: StorageError) -> VMLogicError {
@@ -300,6 +335,91 @@ impl<'a> External for RuntimeExt<'a> {
         Ok(result?)
     }
 
+    fn storage_get_account<'b>(
+        &'b self,
+        access_tracker: &mut dyn StorageAccessTracker,
+        account_id: &AccountId,
+        key: &[u8],
+    ) -> ExtResult<Option<Box<dyn ValuePtr + 'b>>> {
+        let shard_layout = self
+            .epoch_info_provider
+            .shard_layout(&self.epoch_id)
+            .map_err(ExternalError::ValidatorError)?;
+        if shard_layout.account_id_to_shard_id(account_id)
+            != shard_layout.account_id_to_shard_id(&self.account_id)
+        {
+            return Err(HostError::StorageReadAccountOnOtherShard {
+                account_id: account_id.to_string(),
+            }
+            .into());
+        }
+        let start_ttn = self.trie_access_tracker.state.get_counts();
+        let storage_key =
+            TrieKey::ContractData { account_id: account_id.clone(), key: key.to_vec() };
+        let mode = match self.storage_access_mode {
+            StorageGetMode::FlatStorage => KeyLookupMode::MemOrFlatOrTrie,
+            StorageGetMode::Trie => KeyLookupMode::MemOrTrie,
+        };
+        let deref_options = AccessOptions::contract_runtime(&self.trie_access_tracker);
+        // The lookup goes to the trie directly rather than through the `TrieUpdate`, so that the
+        // value is the one of the chunk pre-state regardless of the receipts applied before.
+        let trie = self.trie_update.trie();
+        let result = trie
+            .get_optimized_ref(&storage_key.to_vec(), mode, deref_options)
+            .map_err(wrap_storage_error)
+            .map(|option| {
+                option.map(|optimized_value_ref| {
+                    Box::new(RuntimeExtValuePtr {
+                        value_ptr: TrieUpdateValuePtr::Ref(trie, optimized_value_ref),
+                        deref_options,
+                        accounting_state: Arc::clone(&self.trie_access_tracker.state),
+                    }) as Box<dyn ValuePtr>
+                })
+            });
+        let _delta =
+            self.trie_access_tracker.state.commit_counts_since(start_ttn, access_tracker)?;
+        #[cfg(feature = "io_trace")]
+        if let Ok(read) = &result {
+            tracing::trace!(
+                target: "io_tracer",
+                storage_op = "read_account",
+                account_id = %account_id,
+                key = base64(&key),
+                size = read.as_ref().map(|v| v.len()),
+                tn_db_reads = _delta.db_reads,
+                tn_mem_reads = _delta.mem_reads,
+            );
+        }
+
+        Ok(result?)
+    }
+
+    fn storage_iter_keys(
+        &self,
+        access_tracker: &mut dyn StorageAccessTracker,
+        start: &[u8],
+        end: Option<&[u8]>,
+        limit: u64,
+    ) -> ExtResult<Vec<Vec<u8>>> {
+        let start_ttn = self.trie_access_tracker.state.get_counts();
+        let result = self.contract_data_keys(start, end, limit).map_err(wrap_storage_error);
+        let _delta =
+            self.trie_access_tracker.state.commit_counts_since(start_ttn, access_tracker)?;
+        #[cfg(feature = "io_trace")]
+        if let Ok(keys) = &result {
+            tracing::trace!(
+                target: "io_tracer",
+                storage_op = "iter",
+                start = base64(&start),
+                num_keys = keys.len(),
+                tn_db_reads = _delta.db_reads,
+                tn_mem_reads = _delta.mem_reads,
+            );
+        }
+
+        Ok(result?)
+    }
+
     fn generate_data_id(&mut self) -> CryptoHash {
         let data_id = create_receipt_id_from_action_hash(
             self.current_protocol_version,
@@ -133,6 +133,9 @@ pub struct ApplyState {
     pub config: Arc<RuntimeConfig>,
     /// Cache for compiled contracts.
     pub cache: Option<Box<dyn ContractRuntimeCache>>,
+    /// Whether the caller compiles the contracts deployed by the successful receipts once the
+    /// chunk is applied. Otherwise the contracts are compiled by the deploy actions.
+    pub defer_deploy_compilation: bool,
     /// Cache for trie node accesses.
     pub trie_access_tracker_state: Arc<ext::AccountingState>,
     /// Whether the chunk being applied is new.
@@ -495,13 +498,14 @@ impl Runtime {
                 );
             }
             Action::DeployContract(deploy_contract) => {
+                // Without a cache, the deploy action does not compile the contract.
                 action_deploy_contract(
                     state_update,
                     account.as_mut().expect(EXPECT_ACCOUNT_EXISTS),
                     account_id,
                     deploy_contract,
                     Arc::clone(&apply_state.config.wasm_config),
-                    apply_state.cache.as_deref(),
+                    apply_state.cache.as_deref().filter(|_| !apply_state.defer_deploy_compilation),
                     apply_state.current_protocol_version,
                 )?;
             }
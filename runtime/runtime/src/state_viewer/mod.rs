@@ -172,6 +172,19 @@ impl TrieViewer {
         let mut values = vec![];
         let query = trie_key_parsers::get_raw_prefix_for_contract_data(account_id, prefix);
         let acc_sep_len = query.len() - prefix.len();
+        // Proofs need the trie nodes, so the history of flat state is only
+        // used without them.
+        if !include_proof {
+            if let Some(items) = state_update.trie().get_history_values_with_prefix(&query)? {
+                for (key, value) in items {
+                    values.push(StateItem {
+                        key: key[acc_sep_len..].to_vec().into(),
+                        value: value.into(),
+                    });
+                }
+                return Ok(ViewStateResult { values, proof: vec![] });
+            }
+        }
         let mut iter = state_update.trie().disk_iter()?;
         iter.remember_visited_nodes(include_proof);
         iter.seek_prefix(&query)?;
@@ -223,6 +236,7 @@ impl TrieViewer {
             current_protocol_version: view_state.current_protocol_version,
             config: Arc::clone(config),
             cache: view_state.cache,
+            defer_deploy_compilation: false,
             is_new_chunk: false,
             congestion_info: Default::default(),
             bandwidth_requests: BlockBandwidthRequests::empty(),
@@ -169,6 +169,7 @@ fn setup_runtime_for_shard(
         current_protocol_version: PROTOCOL_VERSION,
         config: Arc::new(RuntimeConfig::test()),
         cache: Some(Box::new(contract_cache)),
+        defer_deploy_compilation: false,
         is_new_chunk: true,
         congestion_info,
         bandwidth_requests: BlockBandwidthRequests::empty(),
@@ -2331,6 +2332,103 @@ fn test_contract_accesses_when_validating_chunk() {
     assert_eq!(apply_result.contract_updates.contract_accesses, HashSet::new());
 }
 
+/// Tests that a chunk iterating over the contract storage with `storage_iter_batch` can be
+/// replayed from its state witness with the same outcomes and the same gas.
+#[test]
+fn test_storage_iter_batch_state_witness_replay() {
+    let (runtime, tries, root, mut apply_state, signers, epoch_info_provider) =
+        setup_runtime(vec![alice_account()], to_yocto(1_000_000), to_yocto(500_000), 10u64.pow(15));
+
+    let mut runtime_config = RuntimeConfig::test();
+    Arc::make_mut(&mut runtime_config.wasm_config).storage_iter_batch = true;
+    apply_state.config = Arc::new(runtime_config);
+
+    let code = near_test_contracts::wat_contract(
+        r#"
+        (module
+          (import "env" "storage_write" (func $storage_write (param i64 i64 i64 i64 i64) (result i64)))
+          (import "env" "storage_iter_batch" (func $storage_iter_batch (param i64 i64 i64 i64 i64 i64) (result i64)))
+          (import "env" "value_return" (func $value_return (param i64 i64)))
+          (memory (export "memory") 1)
+          (data (i32.const 0) "abcv")
+          (func (export "write")
+            (drop (call $storage_write (i64.const 1) (i64.const 0) (i64.const 1) (i64.const 3) (i64.const 0)))
+            (drop (call $storage_write (i64.const 1) (i64.const 1) (i64.const 1) (i64.const 3) (i64.const 0)))
+            (drop (call $storage_write (i64.const 1) (i64.const 2) (i64.const 1) (i64.const 3) (i64.const 0))))
+          (func (export "iter")
+            (drop (call $storage_iter_batch (i64.const 0) (i64.const 0) (i64.const 0) (i64.const 0) (i64.const 10) (i64.const 0)))
+            (call $value_return (i64.const -1) (i64.const 0))))
+        "#,
+    );
+    let function_call_fn = |method_name: &str| {
+        Action::FunctionCall(Box::new(FunctionCallAction {
+            method_name: method_name.to_string(),
+            args: Vec::new(),
+            gas: MAX_ATTACHED_GAS,
+            deposit: 0,
+        }))
+    };
+
+    let write_receipt = create_receipt_with_actions(
+        alice_account(),
+        signers[0].clone(),
+        vec![Action::DeployContract(DeployContractAction { code }), function_call_fn("write")],
+    );
+    let apply_result = runtime
+        .apply(
+            tries.get_trie_for_shard(ShardUId::single_shard(), root),
+            &None,
+            &apply_state,
+            &[write_receipt],
+            SignedValidPeriodTransactions::empty(),
+            &epoch_info_provider,
+            Default::default(),
+        )
+        .unwrap();
+    let mut store_update = tries.store_update();
+    let root =
+        tries.apply_all(&apply_result.trie_changes, ShardUId::single_shard(), &mut store_update);
+    store_update.commit().unwrap();
+
+    let iter_receipt = create_receipt_with_actions(
+        alice_account(),
+        signers[0].clone(),
+        vec![function_call_fn("iter")],
+    );
+    let apply_result = runtime
+        .apply(
+            tries.get_trie_for_shard(ShardUId::single_shard(), root).recording_reads_new_recorder(),
+            &None,
+            &apply_state,
+            &[iter_receipt.clone()],
+            SignedValidPeriodTransactions::empty(),
+            &epoch_info_provider,
+            Default::default(),
+        )
+        .unwrap();
+    let [outcome] = apply_result.outcomes.as_slice() else {
+        panic!("expected a single outcome, got {:?}", apply_result.outcomes);
+    };
+    assert_matches!(outcome.outcome.status, ExecutionStatus::SuccessValue(_));
+
+    // Replay the chunk on top of the recorded storage only, as a chunk validator would.
+    apply_state.apply_reason = ApplyChunkReason::ValidateChunkStateWitness;
+    let partial_storage = apply_result.proof.clone().unwrap();
+    let replay_result = runtime
+        .apply(
+            Trie::from_recorded_storage(partial_storage, root, false),
+            &None,
+            &apply_state,
+            &[iter_receipt],
+            SignedValidPeriodTransactions::empty(),
+            &epoch_info_provider,
+            Default::default(),
+        )
+        .unwrap();
+    assert_eq!(replay_result.outcomes, apply_result.outcomes);
+    assert_eq!(replay_result.state_root, apply_result.state_root);
+}
+
 /// Tests that the existing contract is not recorded in the state witness for a deploy-contract action.
 /// For this, it deploys two contracts to the same account and checks the storage proof size after the second deploy action.
 #[test]
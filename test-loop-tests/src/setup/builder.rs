@@ -308,6 +308,8 @@ impl<'a> NodeStateBuilder<'a> {
                 location: external_storage_location.clone(),
                 credentials_file: None,
                 restart_dump_for_shards: None,
+                num_concurrent_parts: None,
+                max_parts_in_flight: None,
             }),
             sync: SyncConfig::ExternalStorage(ExternalStorageConfig {
                 location: external_storage_location,
@@ -497,7 +497,7 @@ fn network_message_to_view_client_handler(
     future_spawner: Arc<dyn FutureSpawner>,
 ) -> NetworkRequestHandler {
     Box::new(move |request| match request {
-        NetworkRequests::BlockHeadersRequest { hashes, peer_id } => {
+        NetworkRequests::BlockHeadersRequest { hashes, peer_id, .. } => {
             let my_peer_id = shared_state.account_to_peer_id(&my_account_id);
             let responder =
                 shared_state.senders_for_peer(&peer_id, &my_peer_id).client_sender.clone();
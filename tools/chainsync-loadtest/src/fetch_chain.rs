@@ -6,6 +6,7 @@ use near_async::time;
 use near_network::concurrency::ctx;
 use near_network::concurrency::scope;
 use near_primitives::hash::CryptoHash;
+use near_primitives::types::BlockHeight;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 
@@ -37,7 +38,8 @@ pub async fn run(
         let mut blocks_count = 0;
         while last_height < target_height {
             // Fetch the next batch of headers.
-            let mut headers = network.fetch_block_headers(last_hash).await?;
+            let mut headers =
+                network.fetch_block_headers(last_hash, last_height as BlockHeight).await?;
             headers.sort_by_key(|h| h.height());
             let last_header = headers.last().context("no headers")?;
             last_hash = *last_header.hash();
@@ -21,6 +21,7 @@ use near_primitives::block::{Block, BlockHeader};
 use near_primitives::hash::CryptoHash;
 use near_primitives::sharding::ChunkHash;
 use near_primitives::sharding::ShardChunkHeader;
+use near_primitives::types::BlockHeight;
 use nearcore::config::NearConfig;
 use parking_lot::Mutex;
 use rand::seq::SliceRandom;
@@ -150,12 +151,13 @@ impl Network {
     }
 
     // fetch_block_headers fetches a batch of headers, starting with the header
-    // AFTER the header with the given <hash>. The batch size is bounded by
+    // AFTER the header with the given <hash> at <height>. The batch size is bounded by
     // sync::MAX_BLOCK_HEADERS = (currently) 512
     // https://github.com/near/nearcore/blob/ad896e767b0efbce53060dd145836bbeda3d656b/chain/client/src/sync.rs#L38
     pub async fn fetch_block_headers(
         self: &Arc<Self>,
         hash: CryptoHash,
+        height: BlockHeight,
     ) -> anyhow::Result<Vec<BlockHeader>> {
         scope::run!(|s| async {
             self.stats.header_start.fetch_add(1, Ordering::Relaxed);
@@ -164,6 +166,7 @@ impl Network {
                 self.keep_sending(|peer| NetworkRequests::BlockHeadersRequest {
                     hashes: vec![hash],
                     peer_id: peer.peer_info.id,
+                    tip_height: height,
                 })
                 .await
             });
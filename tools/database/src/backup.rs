@@ -0,0 +1,170 @@
+use anyhow::Context;
+use near_store::backup::{
+    BackupManifest, create_backup, list_backups, prune_backups, restore_backup, verify_backup,
+};
+use near_store::{Mode, NodeStorage, StoreConfig, config::ArchivalConfig};
+use std::path::{Path, PathBuf};
+
+#[derive(clap::Subcommand)]
+enum BackupAction {
+    /// Create a new backup.  The node must be stopped; use the
+    /// `/debug/api/backup` RPC endpoint to back up a running node.
+    Create,
+    /// List existing backups.
+    List,
+    /// Delete old backups.  The node must be stopped or must not be creating
+    /// a backup.
+    Prune {
+        /// Number of most recent backups to keep.  Defaults to
+        /// `store.backup_num_to_keep` from the node config.
+        #[clap(long)]
+        keep: Option<usize>,
+    },
+    /// Check sizes and hashes of all files in a backup.
+    Verify {
+        /// Backup to verify.  Defaults to the latest one.
+        #[clap(long)]
+        id: Option<u64>,
+    },
+    /// Restore a backup.  The database directories must not exist.
+    Restore {
+        /// Backup to restore.  Defaults to the latest one.
+        #[clap(long)]
+        id: Option<u64>,
+        /// Home directory to restore the databases into.  Defaults to the
+        /// database paths from the node config.
+        #[clap(long)]
+        destination: Option<PathBuf>,
+    },
+}
+
+#[derive(clap::Args)]
+pub(crate) struct BackupCommand {
+    /// Backup directory.  Defaults to `store.backup_path` from the node config.
+    #[clap(long)]
+    backup_dir: Option<PathBuf>,
+    #[clap(subcommand)]
+    action: BackupAction,
+}
+
+impl BackupCommand {
+    pub(crate) fn run(
+        &self,
+        home_dir: &Path,
+        store_config: &StoreConfig,
+        archival_config: Option<ArchivalConfig>,
+    ) -> anyhow::Result<()> {
+        let backup_dir =
+            self.backup_dir.clone().unwrap_or_else(|| store_config.backup_path(home_dir));
+        let opener = NodeStorage::opener(home_dir, store_config, archival_config);
+        match &self.action {
+            BackupAction::Create => {
+                let storage = opener.open_in_mode(Mode::ReadWriteExisting)?;
+                print_manifest(&create_backup(
+                    &storage,
+                    opener.path(),
+                    opener.cold_path(),
+                    &backup_dir,
+                )?);
+                if let Some(keep) = store_config.backup_num_to_keep {
+                    prune_backups(&backup_dir, keep.get())?;
+                }
+            }
+            BackupAction::List => {
+                for manifest in list_backups(&backup_dir)? {
+                    print_manifest(&manifest);
+                }
+            }
+            BackupAction::Prune { keep } => {
+                let keep = keep
+                    .or(store_config.backup_num_to_keep.map(|keep| keep.get()))
+                    .context("--keep is required unless store.backup_num_to_keep is set")?;
+                for id in prune_backups(&backup_dir, keep)? {
+                    println!("Deleted backup {id}");
+                }
+            }
+            BackupAction::Verify { id } => {
+                let id = resolve_id(&backup_dir, *id)?;
+                print_manifest(&verify_backup(&backup_dir, id)?);
+                println!("Backup {id} is valid");
+            }
+            BackupAction::Restore { id, destination } => {
+                let id = resolve_id(&backup_dir, *id)?;
+                let manifest = match destination {
+                    Some(destination) => restore_backup(
+                        &backup_dir,
+                        id,
+                        &destination.join("data"),
+                        Some(&destination.join("cold-data")),
+                    )?,
+                    None => restore_backup(&backup_dir, id, opener.path(), opener.cold_path())?,
+                };
+                print_manifest(&manifest);
+                println!("Restored backup {id}");
+            }
+        }
+        Ok(())
+    }
+}
+
+fn resolve_id(backup_dir: &Path, id: Option<u64>) -> anyhow::Result<u64> {
+    match id {
+        Some(id) => Ok(id),
+        None => list_backups(backup_dir)?
+            .last()
+            .map(|manifest| manifest.id)
+            .ok_or_else(|| anyhow::anyhow!("no backups in {}", backup_dir.display())),
+    }
+}
+
+fn print_manifest(manifest: &BackupManifest) {
+    let temperatures: Vec<&str> =
+        manifest.databases.iter().map(|db| db.temperature.as_str()).collect();
+    println!(
+        "id: {} created_at: {} databases: {} total: {} exported: {}",
+        manifest.id,
+        manifest.created_at,
+        temperatures.join(","),
+        bytesize::ByteSize(manifest.total_size()),
+        bytesize::ByteSize(manifest.exported_size),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backup::{BackupAction, BackupCommand};
+    use near_store::{DBCol, Mode, NodeStorage, StoreConfig};
+
+    /// Populates a DB, backs it up and restores the backup into another directory.
+    /// Checks that the restored DB contains the data.
+    #[test]
+    fn test() {
+        let home_dir = tempfile::tempdir().unwrap();
+        let store_config = StoreConfig::test_config();
+        let opener = NodeStorage::opener(home_dir.path(), &store_config, None);
+
+        {
+            let node_storage = opener.open().unwrap();
+            let mut store_update = node_storage.get_hot_store().store_update();
+            store_update.insert(DBCol::Block, vec![0], vec![42]);
+            store_update.commit().unwrap();
+        }
+
+        let run = |action| {
+            let cmd = BackupCommand { backup_dir: None, action };
+            cmd.run(home_dir.path(), &store_config, None).unwrap();
+        };
+        run(BackupAction::Create);
+        run(BackupAction::Create);
+        run(BackupAction::Prune { keep: Some(1) });
+        run(BackupAction::List);
+        run(BackupAction::Verify { id: None });
+        let destination = home_dir.path().join("restored");
+        run(BackupAction::Restore { id: Some(1), destination: Some(destination.clone()) });
+
+        let restored = NodeStorage::opener(&destination, &store_config, None)
+            .open_in_mode(Mode::ReadOnly)
+            .unwrap();
+        assert!(restored.get_hot_store().exists(DBCol::Block, &[0]).unwrap());
+    }
+}
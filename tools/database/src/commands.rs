@@ -4,6 +4,7 @@ use crate::analyze_data_size_distribution::AnalyzeDataSizeDistributionCommand;
 use crate::analyze_delayed_receipt::AnalyzeDelayedReceiptCommand;
 use crate::analyze_gas_usage::AnalyzeGasUsageCommand;
 use crate::analyze_high_load::HighLoadStatsCommand;
+use crate::backup::BackupCommand;
 use crate::compact::RunCompactionCommand;
 use crate::corrupt::CorruptStateSnapshotCommand;
 use crate::drop_column::DropColumnCommand;
@@ -12,6 +13,7 @@ use crate::memtrie::LoadMemTrieCommand;
 use crate::run_migrations::RunMigrationsCommand;
 use crate::set_version::SetVersionCommand;
 use crate::state_perf::StatePerfCommand;
+use crate::validate::ValidateCommand;
 use crate::write_to_db::WriteCryptoHashCommand;
 use clap::Parser;
 use near_chain_configs::GenesisValidationMode;
@@ -32,6 +34,9 @@ enum SubCommand {
     /// Analyze gas usage in a chosen sequence of blocks
     AnalyzeGasUsage(AnalyzeGasUsageCommand),
 
+    /// Create, list, verify and restore incremental backups of the database
+    Backup(BackupCommand),
+
     /// Change DbKind of hot or cold db.
     ChangeDbKind(ChangeDbKindCommand),
 
@@ -68,6 +73,9 @@ enum SubCommand {
 
     /// Manually set database version
     SetVersion(SetVersionCommand),
+
+    /// Validate the consistency of the database of a stopped node
+    Validate(ValidateCommand),
 }
 
 impl DatabaseCommand {
@@ -79,6 +87,10 @@ impl DatabaseCommand {
         match &self.subcmd {
             SubCommand::AnalyzeDataSizeDistribution(cmd) => cmd.run(home),
             SubCommand::AnalyzeGasUsage(cmd) => cmd.run(home, genesis_validation),
+            SubCommand::Backup(cmd) => {
+                let near_config = load_config(home, genesis_validation);
+                cmd.run(home, &near_config.config.store, near_config.config.archival_config())
+            }
             SubCommand::ChangeDbKind(cmd) => cmd.run(home, genesis_validation),
             SubCommand::CompactDatabase(cmd) => cmd.run(home),
             SubCommand::CorruptStateSnapshot(cmd) => cmd.run(home),
@@ -95,6 +107,7 @@ impl DatabaseCommand {
             SubCommand::AnalyzeDelayedReceipt(cmd) => cmd.run(home, genesis_validation),
             SubCommand::AnalyzeContractSizes(cmd) => cmd.run(home, genesis_validation),
             SubCommand::SetVersion(cmd) => cmd.run(home, genesis_validation),
+            SubCommand::Validate(cmd) => cmd.run(home, genesis_validation),
         }
     }
 }
@@ -4,6 +4,7 @@ mod analyze_data_size_distribution;
 mod analyze_delayed_receipt;
 mod analyze_gas_usage;
 mod analyze_high_load;
+mod backup;
 mod block_iterators;
 pub mod commands;
 mod compact;
@@ -15,4 +16,5 @@ mod run_migrations;
 mod set_version;
 mod state_perf;
 mod utils;
+mod validate;
 mod write_to_db;
@@ -0,0 +1,664 @@
+use anyhow::Context;
+use borsh::BorshDeserialize;
+use clap::Parser;
+use indicatif::ProgressBar;
+use near_chain::store_validator::StoreValidator;
+use near_chain::types::RuntimeAdapter;
+use near_chain_configs::GenesisValidationMode;
+use near_epoch_manager::shard_tracker::ShardTracker;
+use near_epoch_manager::{EpochManager, EpochManagerAdapter};
+use near_primitives::block::{Block, Tip};
+use near_primitives::hash::{CryptoHash, hash};
+use near_primitives::receipt::Receipt;
+use near_primitives::shard_layout::ShardUId;
+use near_primitives::transaction::SignedTransaction;
+use near_primitives::types::BlockHeight;
+use near_store::adapter::StoreAdapter;
+use near_store::db::refcount::decode_value_with_rc;
+use near_store::flat::FlatStorageStatus;
+use near_store::{COLD_HEAD_KEY, DBCol, Mode, NodeStorage, Store, Temperature};
+use nearcore::{NightshadeRuntime, NightshadeRuntimeExt};
+use std::cmp::Ordering;
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use strum::IntoEnumIterator;
+
+/// Maximum number of trie and flat storage mismatches listed per shard in the report.
+/// All of them are counted.
+const MAX_REPORTED_MISMATCHES: usize = 100;
+
+/// Validates the consistency of the database of a stopped node.
+///
+/// Runs all the store validator checks, compares the trie with flat storage for
+/// each shard whose flat storage is ready, and checks the values of reference
+/// counted columns. On archival nodes with split storage the cold database is
+/// validated as well.
+/// The database is opened read-only unless `--fix` is given, and it's never
+/// migrated, so it must already be at the version this binary expects.
+/// Example usage: neard database validate --report validation.json
+#[derive(Parser)]
+pub(crate) struct ValidateCommand {
+    /// Skip comparing the trie with flat storage, which reads the whole state
+    /// of every shard.
+    #[clap(long)]
+    skip_flat_storage: bool,
+
+    /// Repair the problems that can be repaired safely. Currently this only
+    /// resets negative reference counts in the hot database to zero, everything
+    /// else is reported. The cold database is never modified.
+    #[clap(long)]
+    fix: bool,
+
+    /// Write a JSON report of all the problems found to this file.
+    #[clap(long)]
+    report: Option<PathBuf>,
+}
+
+#[derive(serde::Serialize, Default)]
+struct ValidationReport {
+    num_errors: u64,
+    num_fixed: u64,
+    store_validator: StoreValidatorReport,
+    flat_storage: Vec<FlatStorageReport>,
+    refcounts: Vec<RefcountReport>,
+    cold_storage: Option<ColdStorageReport>,
+}
+
+#[derive(serde::Serialize, Default)]
+struct StoreValidatorReport {
+    tests_done: u64,
+    errors: Vec<StoreValidatorErrorReport>,
+}
+
+#[derive(serde::Serialize)]
+struct StoreValidatorErrorReport {
+    col: String,
+    key: String,
+    error: String,
+}
+
+#[derive(serde::Serialize)]
+struct FlatStorageReport {
+    shard_uid: String,
+    /// Set if the shard wasn't compared, e.g. because its flat storage isn't ready.
+    skipped: Option<String>,
+    flat_head_height: Option<BlockHeight>,
+    flat_head_hash: Option<CryptoHash>,
+    num_entries: u64,
+    num_mismatches: u64,
+    mismatches: Vec<FlatStorageMismatch>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(tag = "kind")]
+enum FlatStorageMismatch {
+    MissingInFlatStorage { key: String },
+    MissingInTrie { key: String },
+    DifferentValue { key: String },
+}
+
+#[derive(serde::Serialize)]
+struct RefcountReport {
+    col: String,
+    key: String,
+    refcount: i64,
+    problem: RefcountProblem,
+    fixed: bool,
+}
+
+#[derive(serde::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+enum RefcountProblem {
+    /// The value was decremented more times than it was incremented.
+    Negative,
+    /// The cold database stores every value with a reference count of one.
+    NotOneInColdStorage,
+    /// The value is too short to hold a reference count, or can't be decoded.
+    Malformed,
+    /// The value doesn't hash to its key.
+    KeyMismatch,
+}
+
+#[derive(serde::Serialize, Default)]
+struct ColdStorageReport {
+    cold_head_height: Option<BlockHeight>,
+    num_blocks: u64,
+    errors: Vec<ColdStorageErrorReport>,
+    refcounts: Vec<RefcountReport>,
+}
+
+#[derive(serde::Serialize)]
+struct ColdStorageErrorReport {
+    col: String,
+    key: String,
+    error: String,
+}
+
+impl ValidateCommand {
+    pub(crate) fn run(
+        &self,
+        home: &PathBuf,
+        genesis_validation: GenesisValidationMode,
+    ) -> anyhow::Result<()> {
+        let near_config = nearcore::config::load_config(home, genesis_validation)
+            .unwrap_or_else(|e| panic!("Error loading config: {:#}", e));
+        // No migrator is set, so opening fails if the database isn't at the
+        // current version rather than migrating a possibly broken database.
+        let mode = if self.fix { Mode::ReadWriteExisting } else { Mode::ReadOnly };
+        let node_storage = NodeStorage::opener(
+            home,
+            &near_config.config.store,
+            near_config.config.archival_config(),
+        )
+        .open_in_mode(mode)
+        .context("failed to open the database")?;
+        let store = node_storage.get_hot_store();
+        let epoch_manager =
+            EpochManager::new_arc_handle(store.clone(), &near_config.genesis.config, Some(home));
+        let shard_tracker = ShardTracker::new(
+            near_config.client_config.tracked_shards_config.clone(),
+            epoch_manager.clone(),
+        );
+        let runtime = NightshadeRuntime::from_config(
+            home,
+            store.clone(),
+            &near_config,
+            epoch_manager.clone(),
+        )
+        .context("could not create the transaction runtime")?;
+
+        let mut report = ValidationReport::default();
+
+        println!("Running store validator checks...");
+        let mut store_validator = StoreValidator::new(
+            near_config.validator_signer.get().map(|signer| signer.validator_id().clone()),
+            near_config.genesis.config.clone(),
+            epoch_manager.clone(),
+            shard_tracker,
+            runtime.clone(),
+            store.clone(),
+            near_config.config.archive,
+        );
+        let progress = ProgressBar::new(DBCol::iter().count() as u64);
+        store_validator.validate_with_progress(&mut |col| {
+            progress.set_message(col.to_string());
+            progress.inc(1);
+        });
+        progress.finish_and_clear();
+        report.store_validator.tests_done = store_validator.tests_done();
+        report.store_validator.errors = store_validator
+            .errors
+            .iter()
+            .map(|error| StoreValidatorErrorReport {
+                col: error.col.clone(),
+                key: error.key.clone(),
+                error: error.err.to_string(),
+            })
+            .collect();
+        report.num_errors += store_validator.num_failed();
+
+        if !self.skip_flat_storage {
+            let head = store.chain_store().head()?;
+            let shard_layout = epoch_manager.get_shard_layout(&head.epoch_id)?;
+            for shard_uid in shard_layout.shard_uids() {
+                println!("Comparing trie and flat storage for shard {shard_uid}...");
+                let shard_report = validate_flat_storage(&store, runtime.as_ref(), shard_uid)?;
+                report.num_errors += shard_report.num_mismatches;
+                report.flat_storage.push(shard_report);
+            }
+        }
+
+        println!("Checking reference counted columns...");
+        report.refcounts = validate_rc_columns(&store, Temperature::Hot, self.fix)?;
+        report.num_errors += report.refcounts.len() as u64;
+        report.num_fixed +=
+            report.refcounts.iter().filter(|refcount| refcount.fixed).count() as u64;
+
+        if let Some(cold_store) = node_storage.get_cold_store() {
+            println!("Validating cold storage...");
+            let cold_report = validate_cold_storage(&store, &cold_store)?;
+            report.num_errors +=
+                cold_report.errors.len() as u64 + cold_report.refcounts.len() as u64;
+            report.cold_storage = Some(cold_report);
+        }
+
+        print_summary(&report);
+        if let Some(path) = &self.report {
+            write_report(path, &report)?;
+            println!("Report written to {}", path.display());
+        }
+        if report.store_validator.tests_done == 0 {
+            anyhow::bail!("no conditions have been validated");
+        }
+        if report.num_errors > report.num_fixed {
+            anyhow::bail!("found {} errors", report.num_errors - report.num_fixed);
+        }
+        Ok(())
+    }
+}
+
+/// Compares the trie at the flat head of the shard with its flat storage entries.
+/// Both are iterated in key order, so they're merged to find the differing keys.
+fn validate_flat_storage(
+    store: &Store,
+    runtime: &dyn RuntimeAdapter,
+    shard_uid: ShardUId,
+) -> anyhow::Result<FlatStorageReport> {
+    let mut report = FlatStorageReport {
+        shard_uid: shard_uid.to_string(),
+        skipped: None,
+        flat_head_height: None,
+        flat_head_hash: None,
+        num_entries: 0,
+        num_mismatches: 0,
+        mismatches: vec![],
+    };
+    let flat_head = match store.flat_store().get_flat_storage_status(shard_uid)? {
+        FlatStorageStatus::Ready(ready_status) => ready_status.flat_head,
+        status => {
+            report.skipped = Some(format!("flat storage is not ready: {status:?}"));
+            return Ok(report);
+        }
+    };
+    report.flat_head_height = Some(flat_head.height);
+    report.flat_head_hash = Some(flat_head.hash);
+
+    // The state root must be from after applying the flat head block, which is
+    // why it's taken from the chunk extra.
+    let chunk_extra = store.chain_store().get_chunk_extra(&flat_head.hash, &shard_uid)?;
+    let trie = runtime.get_tries().get_view_trie_for_shard(shard_uid, *chunk_extra.state_root());
+    let flat_store = store.flat_store();
+    let mut trie_iter = trie.disk_iter()?.peekable();
+    let mut flat_iter = flat_store.iter(shard_uid).peekable();
+
+    let progress = ProgressBar::new_spinner();
+    loop {
+        let ordering = match (trie_iter.peek(), flat_iter.peek()) {
+            (None, None) => break,
+            (Some(Err(_)), _) => return Err(trie_iter.next().unwrap().unwrap_err().into()),
+            (_, Some(Err(_))) => return Err(flat_iter.next().unwrap().unwrap_err().into()),
+            (Some(Ok(_)), None) => Ordering::Less,
+            (None, Some(Ok(_))) => Ordering::Greater,
+            (Some(Ok((trie_key, _))), Some(Ok((flat_key, _)))) => trie_key.cmp(flat_key),
+        };
+        let mismatch = match ordering {
+            Ordering::Less => {
+                let (key, _) = trie_iter.next().unwrap()?;
+                Some(FlatStorageMismatch::MissingInFlatStorage { key: hex::encode(key) })
+            }
+            Ordering::Greater => {
+                let (key, _) = flat_iter.next().unwrap()?;
+                report.num_entries += 1;
+                Some(FlatStorageMismatch::MissingInTrie { key: hex::encode(key) })
+            }
+            Ordering::Equal => {
+                let (key, trie_value) = trie_iter.next().unwrap()?;
+                let (_, flat_value) = flat_iter.next().unwrap()?;
+                report.num_entries += 1;
+                let value_ref = flat_value.to_value_ref();
+                (trie_value.len() != value_ref.length as usize
+                    || hash(&trie_value) != value_ref.hash)
+                    .then(|| FlatStorageMismatch::DifferentValue { key: hex::encode(key) })
+            }
+        };
+        if let Some(mismatch) = mismatch {
+            report.num_mismatches += 1;
+            if report.mismatches.len() < MAX_REPORTED_MISMATCHES {
+                report.mismatches.push(mismatch);
+            }
+        }
+        progress.inc(1);
+    }
+    progress.finish_and_clear();
+    Ok(report)
+}
+
+/// Checks every value of the reference counted columns of the hot or cold database.
+///
+/// In the hot database the reference counts must not be negative. Negative
+/// counts are left behind when a value is decremented more times than it was
+/// incremented; such values read as missing, but would swallow the next
+/// increment, so with `fix` their count is reset to zero and RocksDB removes
+/// them on compaction. The cold database never decrements, so all its counts
+/// must be exactly one. In both, values must decode and match their keys.
+fn validate_rc_columns(
+    store: &Store,
+    temperature: Temperature,
+    fix: bool,
+) -> anyhow::Result<Vec<RefcountReport>> {
+    let mut reports = vec![];
+    let mut store_update = store.store_update();
+    for col in DBCol::iter().filter(|col| col.is_rc()) {
+        if temperature == Temperature::Cold && !col.is_cold() {
+            continue;
+        }
+        let progress = ProgressBar::new_spinner();
+        progress.set_message(col.to_string());
+        for item in store.iter_raw_bytes(col) {
+            let (key, value) = item?;
+            progress.inc(1);
+            let Some((refcount, problem)) = check_rc_value(col, &key, &value, temperature) else {
+                continue;
+            };
+            let increase = u32::try_from(-refcount).ok().and_then(NonZeroU32::new);
+            let fixed = match increase {
+                Some(increase) if fix && problem == RefcountProblem::Negative => {
+                    store_update.increment_refcount_by(col, &key, &[], increase);
+                    true
+                }
+                _ => false,
+            };
+            reports.push(RefcountReport {
+                col: col.to_string(),
+                key: hex::encode(&key),
+                refcount,
+                problem,
+                fixed,
+            });
+        }
+        progress.finish_and_clear();
+    }
+    // Without `fix` the database may be open read-only, so nothing is written.
+    if fix {
+        store_update.commit()?;
+    }
+    Ok(reports)
+}
+
+/// Returns the reference count and the problem with the raw value of a
+/// reference counted column, if there is one.
+fn check_rc_value(
+    col: DBCol,
+    key: &[u8],
+    value: &[u8],
+    temperature: Temperature,
+) -> Option<(i64, RefcountProblem)> {
+    // Empty values are left behind when the count drops to zero, until compaction.
+    if !value.is_empty() && value.len() < 8 {
+        return Some((0, RefcountProblem::Malformed));
+    }
+    let (data, refcount) = decode_value_with_rc(value);
+    match temperature {
+        Temperature::Hot if refcount < 0 => return Some((refcount, RefcountProblem::Negative)),
+        Temperature::Cold if refcount != 1 => {
+            return Some((refcount, RefcountProblem::NotOneInColdStorage));
+        }
+        _ => {}
+    }
+    let data = data?;
+    let problem = match col {
+        DBCol::State => (key.len() != 40 || hash(data).as_ref() != &key[8..])
+            .then_some(RefcountProblem::KeyMismatch),
+        DBCol::Transactions => match SignedTransaction::try_from_slice(data) {
+            Ok(tx) => (tx.get_hash().as_ref() != key).then_some(RefcountProblem::KeyMismatch),
+            Err(_) => Some(RefcountProblem::Malformed),
+        },
+        DBCol::Receipts => match Receipt::try_from_slice(data) {
+            Ok(receipt) => {
+                (receipt.get_hash().as_ref() != key).then_some(RefcountProblem::KeyMismatch)
+            }
+            Err(_) => Some(RefcountProblem::Malformed),
+        },
+        _ => None,
+    };
+    problem.map(|problem| (refcount, problem))
+}
+
+/// Validates the cold database of an archival node with split storage.
+///
+/// The cold head must match the one recorded in the hot database and must not
+/// be ahead of the final head. Every block in the cold database must be at or
+/// below the cold head and all its chunks must have been copied along with it.
+/// The reference counted columns are checked by `validate_rc_columns`.
+fn validate_cold_storage(
+    hot_store: &Store,
+    cold_store: &Store,
+) -> anyhow::Result<ColdStorageReport> {
+    let mut report = ColdStorageReport::default();
+    let mut errors = vec![];
+    let mut error = |col: DBCol, key: &[u8], error: String| {
+        errors.push(ColdStorageErrorReport { col: col.to_string(), key: hex::encode(key), error });
+    };
+
+    let cold_head = cold_store.get_ser::<Tip>(DBCol::BlockMisc, COLD_HEAD_KEY)?;
+    let hot_cold_head = hot_store.get_ser::<Tip>(DBCol::BlockMisc, COLD_HEAD_KEY)?;
+    let Some(cold_head) = cold_head else {
+        error(DBCol::BlockMisc, COLD_HEAD_KEY, "cold head is missing".to_string());
+        report.errors = errors;
+        report.refcounts = validate_rc_columns(cold_store, Temperature::Cold, false)?;
+        return Ok(report);
+    };
+    if hot_cold_head.as_ref() != Some(&cold_head) {
+        error(
+            DBCol::BlockMisc,
+            COLD_HEAD_KEY,
+            format!(
+                "cold head at height {} doesn't match the one recorded in the hot database: {:?}",
+                cold_head.height,
+                hot_cold_head.map(|tip| tip.height)
+            ),
+        );
+    }
+    match hot_store.chain_store().final_head() {
+        Ok(final_head) if final_head.height < cold_head.height => error(
+            DBCol::BlockMisc,
+            COLD_HEAD_KEY,
+            format!(
+                "cold head at height {} is ahead of the final head at height {}",
+                cold_head.height, final_head.height
+            ),
+        ),
+        Ok(_) => {}
+        Err(err) => error(DBCol::BlockMisc, COLD_HEAD_KEY, format!("no final head: {err}")),
+    }
+    if !cold_store.exists(DBCol::Block, cold_head.last_block_hash.as_ref())? {
+        error(
+            DBCol::Block,
+            cold_head.last_block_hash.as_ref(),
+            "cold head block is missing".to_string(),
+        );
+    }
+
+    let progress = ProgressBar::new_spinner();
+    progress.set_message(DBCol::Block.to_string());
+    for item in cold_store.iter(DBCol::Block) {
+        let (key, value) = item?;
+        progress.inc(1);
+        report.num_blocks += 1;
+        let block = match Block::try_from_slice(&value) {
+            Ok(block) => block,
+            Err(err) => {
+                error(DBCol::Block, &key, format!("can't decode block: {err}"));
+                continue;
+            }
+        };
+        if block.header().height() > cold_head.height {
+            error(
+                DBCol::Block,
+                &key,
+                format!(
+                    "block at height {} is above the cold head at height {}",
+                    block.header().height(),
+                    cold_head.height
+                ),
+            );
+        }
+        for chunk_header in block.chunks().iter_deprecated() {
+            let chunk_hash = chunk_header.chunk_hash();
+            if !cold_store.exists(DBCol::Chunks, chunk_hash.as_bytes())? {
+                error(
+                    DBCol::Chunks,
+                    chunk_hash.as_bytes(),
+                    format!("chunk of block at height {} is missing", block.header().height()),
+                );
+            }
+        }
+    }
+    progress.finish_and_clear();
+
+    report.cold_head_height = Some(cold_head.height);
+    report.errors = errors;
+    report.refcounts = validate_rc_columns(cold_store, Temperature::Cold, false)?;
+    Ok(report)
+}
+
+fn print_summary(report: &ValidationReport) {
+    println!("Conditions validated: {}", report.store_validator.tests_done);
+    for error in &report.store_validator.errors {
+        println!("{}  {}  {}", error.col, error.key, error.error);
+    }
+    for shard in &report.flat_storage {
+        match &shard.skipped {
+            Some(reason) => println!("Shard {}: skipped, {}", shard.shard_uid, reason),
+            None => println!(
+                "Shard {}: {} flat storage entries, {} mismatches with the trie",
+                shard.shard_uid, shard.num_entries, shard.num_mismatches
+            ),
+        }
+    }
+    for refcount in &report.refcounts {
+        print_refcount(refcount);
+    }
+    if let Some(cold) = &report.cold_storage {
+        println!(
+            "Cold storage: head at height {:?}, {} blocks",
+            cold.cold_head_height, cold.num_blocks
+        );
+        for error in &cold.errors {
+            println!("{}  {}  {}", error.col, error.key, error.error);
+        }
+        for refcount in &cold.refcounts {
+            print_refcount(refcount);
+        }
+    }
+    if report.num_errors == 0 {
+        println!("No errors found");
+    } else {
+        println!("Errors found: {}, fixed: {}", report.num_errors, report.num_fixed);
+    }
+}
+
+fn print_refcount(refcount: &RefcountReport) {
+    println!(
+        "{}  {}  {:?} with refcount {}{}",
+        refcount.col,
+        refcount.key,
+        refcount.problem,
+        refcount.refcount,
+        if refcount.fixed { " (fixed)" } else { "" }
+    );
+}
+
+fn write_report(path: &Path, report: &ValidationReport) -> anyhow::Result<()> {
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    serde_json::to_writer_pretty(std::io::BufWriter::new(file), report)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RefcountProblem, validate_cold_storage, validate_rc_columns};
+    use near_primitives::block::Tip;
+    use near_primitives::hash::{CryptoHash, hash};
+    use near_primitives::types::EpochId;
+    use near_store::db::metadata::{DB_VERSION, DbKind};
+    use near_store::test_utils::{create_test_node_storage_with_cold, create_test_store};
+    use near_store::{COLD_HEAD_KEY, DBCol, FINAL_HEAD_KEY, ShardUId, Temperature};
+
+    fn state_key(node: &[u8]) -> Vec<u8> {
+        [ShardUId::single_shard().to_bytes().as_slice(), hash(node).as_ref()].concat()
+    }
+
+    fn tip(height: u64) -> Tip {
+        Tip {
+            height,
+            last_block_hash: hash(&height.to_le_bytes()),
+            prev_block_hash: CryptoHash::default(),
+            epoch_id: EpochId::default(),
+            next_epoch_id: EpochId::default(),
+        }
+    }
+
+    /// Checks that all kinds of broken values of reference counted columns are
+    /// reported and that only negative reference counts are fixed.
+    #[test]
+    fn test_validate_rc_columns() {
+        let store = create_test_store();
+        let mut store_update = store.store_update();
+        store_update.increment_refcount(DBCol::State, &state_key(b"node"), b"node");
+        store_update.increment_refcount(DBCol::State, &state_key(b"other"), b"node");
+        store_update.set_raw_bytes(DBCol::State, &state_key(b"negative"), &(-2i64).to_le_bytes());
+        store_update.set_raw_bytes(DBCol::Receipts, &[1; 32], &[1, 2, 3]);
+        store_update.commit().unwrap();
+
+        let problems = |fix| {
+            let mut problems = validate_rc_columns(&store, Temperature::Hot, fix)
+                .unwrap()
+                .into_iter()
+                .map(|report| (report.key, report.problem, report.fixed))
+                .collect::<Vec<_>>();
+            problems.sort_by(|a, b| a.0.cmp(&b.0));
+            problems
+        };
+        let mut expected = vec![
+            (hex::encode(state_key(b"other")), RefcountProblem::KeyMismatch, false),
+            (hex::encode(state_key(b"negative")), RefcountProblem::Negative, false),
+            (hex::encode([1; 32]), RefcountProblem::Malformed, false),
+        ];
+        expected.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(problems(false), expected);
+
+        for problem in &mut expected {
+            problem.2 = problem.1 == RefcountProblem::Negative;
+        }
+        assert_eq!(problems(true), expected);
+
+        expected.retain(|problem| problem.1 != RefcountProblem::Negative);
+        assert_eq!(problems(false), expected);
+    }
+
+    #[test]
+    fn test_validate_cold_storage() {
+        let (storage, ..) = create_test_node_storage_with_cold(DB_VERSION, DbKind::Hot);
+        let hot_store = storage.get_hot_store();
+        let cold_store = storage.get_cold_store().unwrap();
+
+        // The cold database sets the reference count of every value to one.
+        let mut cold_update = cold_store.store_update();
+        cold_update.increment_refcount(DBCol::State, &state_key(b"node"), b"node");
+        cold_update.increment_refcount(DBCol::State, &state_key(b"node"), b"node");
+        cold_update.commit().unwrap();
+        let report = validate_cold_storage(&hot_store, &cold_store).unwrap();
+        assert_eq!(report.errors.len(), 1, "cold head is missing");
+        assert_eq!(report.refcounts.len(), 0);
+
+        let mut cold_update = cold_store.store_update();
+        cold_update.set_ser(DBCol::BlockMisc, COLD_HEAD_KEY, &tip(5)).unwrap();
+        cold_update.set_raw_bytes(
+            DBCol::State,
+            &state_key(b"rc"),
+            &[b"rc".as_slice(), &2i64.to_le_bytes()].concat(),
+        );
+        cold_update.commit().unwrap();
+        let mut hot_update = hot_store.store_update();
+        hot_update.set_ser(DBCol::BlockMisc, COLD_HEAD_KEY, &tip(5)).unwrap();
+        hot_update.set_ser(DBCol::BlockMisc, FINAL_HEAD_KEY, &tip(4)).unwrap();
+        hot_update.commit().unwrap();
+
+        let report = validate_cold_storage(&hot_store, &cold_store).unwrap();
+        assert_eq!(report.cold_head_height, Some(5));
+        assert_eq!(report.num_blocks, 0);
+        let errors = report.errors.iter().map(|error| error.error.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                "cold head at height 5 is ahead of the final head at height 4",
+                "cold head block is missing",
+            ]
+        );
+        assert_eq!(report.refcounts.len(), 1);
+        assert_eq!(report.refcounts[0].key, hex::encode(state_key(b"rc")));
+        assert_eq!(report.refcounts[0].problem, RefcountProblem::NotOneInColdStorage);
+        assert_eq!(report.refcounts[0].refcount, 2);
+    }
+}
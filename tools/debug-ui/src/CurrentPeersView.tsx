@@ -246,6 +246,7 @@ export const CurrentPeersView = ({ addr }: NetworkInfoViewProps) => {
                         <th>Nonce</th>
                         <th>First connection</th>
                         <th>Traffic (last minute)</th>
+                        <th>Reputation</th>
                         <th>Route to validators</th>
                     </tr>
                 </thead>
@@ -286,6 +287,7 @@ export const CurrentPeersView = ({ addr }: NetworkInfoViewProps) => {
                                         peer.sent_bytes_per_sec
                                     )}
                                 </td>
+                                <td>{peer.reputation_score}</td>
                                 <td>
                                     <CollapsableValidatorList validators={routedValidator} />
                                 </td>
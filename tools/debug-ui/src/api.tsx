@@ -87,6 +87,7 @@ export interface PeerInfoView {
     connection_established_time_millis: number;
     is_outbound_peer: boolean;
     nonce: number;
+    reputation_score: number;
 }
 
 export interface KnownProducerView {
@@ -72,6 +72,7 @@ fn create_chain_and_executor(
         save_trie_changes: config.client_config.save_trie_changes,
         background_migration_threads: config.client_config.client_background_migration_threads,
         resharding_config: config.client_config.resharding_config.clone(),
+        index_contract_events: config.client_config.index_contract_events,
     };
     let executor = Arc::new(SerialExecutor::new(ChainStore::new(
         node_storage.get_hot_store(),
@@ -50,6 +50,18 @@ pub struct PingCommand {
     /// Listen address for prometheus metrics.
     #[clap(long, default_value = "0.0.0.0:9000")]
     prometheus_addr: String,
+    /// keep pinging all the TIER1 validators and all the peers in the routing table,
+    /// not only the peers which announced an account
+    #[clap(long)]
+    continuous: bool,
+    /// directory to periodically write the network topology to, as topology.dot and
+    /// topology.json. The topology is also served at /topology.dot and /topology.json
+    /// on --prometheus-addr.
+    #[clap(long)]
+    topology_dir: Option<PathBuf>,
+    /// seconds between updates of the network topology
+    #[clap(long, default_value = "60")]
+    topology_interval_seconds: u64,
 }
 
 fn display_stats(stats: &mut [(crate::PeerIdentifier, crate::PingStats)], peer_id: &PeerId) {
@@ -112,6 +124,38 @@ pub static CHAIN_INFO: &[ChainInfo] = &[
     },
 ];
 
+/// Returns the genesis hash given with --genesis-hash, or the known genesis hash of the chain.
+pub(crate) fn resolve_genesis_hash(
+    chain_id: &str,
+    genesis_hash: Option<&str>,
+) -> anyhow::Result<CryptoHash> {
+    if let Some(h) = genesis_hash {
+        return match CryptoHash::from_str(h) {
+            Ok(h) => Ok(h),
+            Err(e) => anyhow::bail!("Could not parse --genesis-hash {}: {:?}", h, e),
+        };
+    }
+    match CHAIN_INFO.iter().find(|info| info.chain_id == chain_id) {
+        Some(chain_info) => Ok(chain_info.genesis_hash),
+        None => anyhow::bail!(
+            "--genesis-hash not given, and genesis hash for --chain-id {} not known",
+            chain_id
+        ),
+    }
+}
+
+/// Parses --peer, which has to contain the socket address of the peer.
+pub(crate) fn parse_peer(peer: &str) -> anyhow::Result<PeerInfo> {
+    let peer = match PeerInfo::from_str(peer) {
+        Ok(p) => p,
+        Err(e) => anyhow::bail!("Could not parse --peer {}: {:?}", peer, e),
+    };
+    if peer.addr.is_none() {
+        anyhow::bail!("--peer should be in the form [public key]@[socket addr]");
+    }
+    Ok(peer)
+}
+
 fn parse_account_filter<P: AsRef<Path>>(filename: P) -> std::io::Result<HashSet<AccountId>> {
     let f = File::open(filename.as_ref())?;
     let mut reader = BufReader::new(f);
@@ -147,38 +191,8 @@ impl PingCommand {
     pub fn run(&self) -> anyhow::Result<()> {
         tracing::warn!(target: "ping", "the ping command is not stable, and may be removed or changed arbitrarily at any time");
 
-        let mut chain_info = None;
-        for info in CHAIN_INFO {
-            if &info.chain_id == &self.chain_id {
-                chain_info = Some(info);
-                break;
-            }
-        }
-
-        let genesis_hash = if let Some(h) = &self.genesis_hash {
-            match CryptoHash::from_str(&h) {
-                Ok(h) => h,
-                Err(e) => {
-                    anyhow::bail!("Could not parse --genesis-hash {}: {:?}", &h, e)
-                }
-            }
-        } else {
-            match chain_info {
-                Some(chain_info) => chain_info.genesis_hash,
-                None => anyhow::bail!(
-                    "--genesis-hash not given, and genesis hash for --chain-id {} not known",
-                    &self.chain_id
-                ),
-            }
-        };
-
-        let peer = match PeerInfo::from_str(&self.peer) {
-            Ok(p) => p,
-            Err(e) => anyhow::bail!("Could not parse --peer {}: {:?}", &self.peer, e),
-        };
-        if peer.addr.is_none() {
-            anyhow::bail!("--peer should be in the form [public key]@[socket addr]");
-        }
+        let genesis_hash = resolve_genesis_hash(&self.chain_id, self.genesis_hash.as_deref())?;
+        let peer = parse_peer(&self.peer)?;
         let filter = if let Some(filename) = &self.account_filter_file {
             Some(parse_account_filter(filename)?)
         } else {
@@ -192,6 +206,10 @@ impl PingCommand {
             } else {
                 None
             };
+        if let Some(dir) = &self.topology_dir {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Couldn't create topology directory at {:?}", dir))?;
+        }
         let runtime = tokio::runtime::Runtime::new().unwrap();
         runtime.block_on(async move {
             let mut stats = Vec::new();
@@ -209,6 +227,9 @@ impl PingCommand {
                 csv,
                 &mut stats,
                 &self.prometheus_addr,
+                self.continuous,
+                self.topology_dir.clone(),
+                Duration::from_secs(self.topology_interval_seconds),
             )
             .await?;
             display_stats(&mut stats, &peer.id);
@@ -1,28 +1,34 @@
 use actix_web::cookie::time::ext::InstantExt as _;
-use actix_web::{App, HttpServer, web};
+use actix_web::{App, HttpResponse, HttpServer, web};
 use anyhow::Context;
 pub use cli::PingCommand;
 use near_network::raw::{ConnectError, Connection, DirectMessage, Message, RoutedMessage};
-use near_network::types::HandshakeFailureReason;
+use near_network::types::{Edge, HandshakeFailureReason, SignedAccountData};
 use near_primitives::hash::CryptoHash;
 use near_primitives::network::{AnnounceAccount, PeerId};
 use near_primitives::types::{AccountId, BlockHeight, ShardId};
 use near_primitives::version::ProtocolVersion;
+pub use replay::ReplayCaptureCommand;
 use std::cmp;
 use std::collections::hash_map::Entry;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 
 pub mod cli;
 mod csv;
 mod metrics;
+pub mod replay;
+mod topology;
 
 // TODO: also log number of bytes/other messages (like Blocks) received?
 #[derive(Debug, Default)]
 struct PingStats {
     pings_sent: usize,
     pongs_received: usize,
+    timeouts: usize,
     // TODO: these latency stats could be separated into time to first byte
     // + time to last byte, etc.
     min_latency: near_time::Duration,
@@ -124,16 +130,24 @@ struct AppInfo {
     timeouts: BTreeSet<PingTimeout>,
     account_filter: Option<HashSet<AccountId>>,
     chain_id: String,
+    // if true, we ping all the TIER1 validators and all the peers in the routing table,
+    // not only the peers which announced an account.
+    continuous: bool,
+    topology: topology::Topology,
+    tier1_peers: HashSet<PeerId>,
 }
 
 impl AppInfo {
-    fn new(account_filter: Option<HashSet<AccountId>>, chain_id: &str) -> Self {
+    fn new(account_filter: Option<HashSet<AccountId>>, chain_id: &str, continuous: bool) -> Self {
         Self {
             stats: HashMap::new(),
             requests: BTreeMap::new(),
             timeouts: BTreeSet::new(),
             account_filter,
             chain_id: chain_id.to_owned(),
+            continuous,
+            topology: topology::Topology::default(),
+            tier1_peers: HashSet::new(),
         }
     }
 
@@ -250,7 +264,8 @@ impl AppInfo {
 
     fn pop_timeout(&mut self, t: &PingTimeout) {
         assert!(self.timeouts.remove(&t));
-        let state = self.stats.get(&t.peer_id).unwrap();
+        let state = self.stats.get_mut(&t.peer_id).unwrap();
+        state.stats.timeouts += 1;
 
         let pending_pings = self
             .requests
@@ -307,9 +322,90 @@ impl AppInfo {
         }
     }
 
+    fn add_edges(&mut self, edges: Vec<Edge>) {
+        for peer_id in self.topology.add_edges(edges) {
+            if self.continuous {
+                self.add_peer(peer_id, None);
+            }
+        }
+    }
+
+    fn add_accounts_data(&mut self, accounts_data: Vec<Arc<SignedAccountData>>) {
+        for data in accounts_data {
+            if self.tier1_peers.insert(data.peer_id.clone()) && self.continuous {
+                self.add_peer(data.peer_id.clone(), None);
+            }
+        }
+    }
+
     fn peer_id_to_account_id(&self, peer_id: &PeerId) -> Option<&AccountId> {
         self.stats.get(peer_id).and_then(|s| s.account_id.as_ref())
     }
+
+    fn node_infos(&self) -> HashMap<PeerId, topology::NodeInfo> {
+        let mut nodes: HashMap<_, _> = self
+            .stats
+            .iter()
+            .map(|(peer_id, state)| {
+                let info = topology::NodeInfo {
+                    account_id: state.account_id.clone(),
+                    tier1: self.tier1_peers.contains(peer_id),
+                    pings_sent: state.stats.pings_sent,
+                    pongs_received: state.stats.pongs_received,
+                    timeouts: state.stats.timeouts,
+                    average_latency: (state.stats.pongs_received > 0)
+                        .then_some(state.stats.average_latency),
+                };
+                (peer_id.clone(), info)
+            })
+            .collect();
+        for peer_id in &self.tier1_peers {
+            nodes
+                .entry(peer_id.clone())
+                .or_insert_with(|| topology::NodeInfo { tier1: true, ..Default::default() });
+        }
+        nodes
+    }
+}
+
+/// The latest rendering of the network topology, served over HTTP.
+#[derive(Default)]
+struct RenderedTopology {
+    dot: String,
+    json: String,
+}
+
+async fn topology_dot(topology: web::Data<Mutex<RenderedTopology>>) -> HttpResponse {
+    let dot = topology.lock().unwrap().dot.clone();
+    HttpResponse::Ok().content_type("text/vnd.graphviz").body(dot)
+}
+
+async fn topology_json(topology: web::Data<Mutex<RenderedTopology>>) -> HttpResponse {
+    let json = topology.lock().unwrap().json.clone();
+    HttpResponse::Ok().content_type("application/json").body(json)
+}
+
+fn update_topology(
+    app_info: &AppInfo,
+    rendered: &Mutex<RenderedTopology>,
+    topology_dir: Option<&Path>,
+) -> anyhow::Result<()> {
+    let nodes = app_info.node_infos();
+    let reachable = nodes.values().filter(|n| n.pongs_received > 0).count();
+    crate::metrics::PING_TARGETS.with_label_values(&[&app_info.chain_id]).set(nodes.len() as i64);
+    crate::metrics::REACHABLE_PING_TARGETS
+        .with_label_values(&[&app_info.chain_id])
+        .set(reachable as i64);
+    let dot = app_info.topology.to_dot(&nodes);
+    let json = app_info.topology.to_json(&nodes);
+    if let Some(dir) = topology_dir {
+        std::fs::write(dir.join("topology.dot"), &dot)
+            .with_context(|| format!("Failed writing topology to {:?}", dir))?;
+        std::fs::write(dir.join("topology.json"), &json)
+            .with_context(|| format!("Failed writing topology to {:?}", dir))?;
+    }
+    *rendered.lock().unwrap() = RenderedTopology { dot, json };
+    Ok(())
 }
 
 fn handle_message(
@@ -335,6 +431,12 @@ fn handle_message(
         Message::Direct(DirectMessage::AnnounceAccounts(a)) => {
             app_info.add_announce_accounts(a);
         }
+        Message::Direct(DirectMessage::Edges(edges)) => {
+            app_info.add_edges(edges);
+        }
+        Message::Direct(DirectMessage::AccountsData(accounts_data)) => {
+            app_info.add_accounts_data(accounts_data);
+        }
         _ => {}
     };
     Ok(())
@@ -385,8 +487,11 @@ async fn ping_via_node(
     mut latencies_csv: Option<crate::csv::LatenciesCsv>,
     ping_stats: &mut Vec<(PeerIdentifier, PingStats)>,
     prometheus_addr: &str,
+    continuous: bool,
+    topology_dir: Option<PathBuf>,
+    topology_interval: std::time::Duration,
 ) -> anyhow::Result<()> {
-    let mut app_info = AppInfo::new(account_filter, chain_id);
+    let mut app_info = AppInfo::new(account_filter, chain_id, continuous);
 
     app_info.add_peer(peer_id.clone(), None);
 
@@ -437,10 +542,21 @@ async fn ping_via_node(
     let next_timeout = tokio::time::sleep(std::time::Duration::ZERO);
     tokio::pin!(next_timeout);
 
-    let server = HttpServer::new(move || {
-        App::new().service(
-            web::resource("/metrics").route(web::get().to(near_jsonrpc::prometheus_handler)),
-        )
+    let mut next_topology_update = tokio::time::interval(topology_interval);
+    let rendered_topology = web::Data::new(Mutex::new(RenderedTopology::default()));
+
+    let server = HttpServer::new({
+        let rendered_topology = rendered_topology.clone();
+        move || {
+            App::new()
+                .app_data(rendered_topology.clone())
+                .service(
+                    web::resource("/metrics")
+                        .route(web::get().to(near_jsonrpc::prometheus_handler)),
+                )
+                .service(web::resource("/topology.dot").route(web::get().to(topology_dot)))
+                .service(web::resource("/topology.json").route(web::get().to(topology_json)))
+        }
     })
     .bind(prometheus_addr)
     .unwrap()
@@ -500,11 +616,20 @@ async fn ping_via_node(
                     }
                 }
             }
+            _ = next_topology_update.tick() => {
+                result = update_topology(&app_info, &rendered_topology, topology_dir.as_deref());
+                if result.is_err() {
+                    break;
+                }
+            }
             _ = tokio::signal::ctrl_c() => {
                 break;
             }
         }
     }
+    if result.is_ok() {
+        result = update_topology(&app_info, &rendered_topology, topology_dir.as_deref());
+    }
     collect_stats(app_info, ping_stats);
     result
 }
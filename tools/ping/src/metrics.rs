@@ -1,6 +1,6 @@
 use near_o11y::metrics::{
-    HistogramVec, IntCounterVec, exponential_buckets, try_create_histogram_vec,
-    try_create_int_counter_vec,
+    HistogramVec, IntCounterVec, IntGaugeVec, exponential_buckets, try_create_histogram_vec,
+    try_create_int_counter_vec, try_create_int_gauge_vec,
 };
 use std::sync::LazyLock;
 
@@ -31,3 +31,17 @@ pub(crate) static PING_SENT: LazyLock<IntCounterVec> = LazyLock::new(|| {
     )
     .unwrap()
 });
+
+pub(crate) static PING_TARGETS: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    try_create_int_gauge_vec("near_ping_targets", "Number of peers being pinged", &["chain_id"])
+        .unwrap()
+});
+
+pub(crate) static REACHABLE_PING_TARGETS: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    try_create_int_gauge_vec(
+        "near_ping_reachable_targets",
+        "Number of pinged peers which responded at least once",
+        &["chain_id"],
+    )
+    .unwrap()
+});
@@ -0,0 +1,234 @@
+use crate::cli::{parse_peer, resolve_genesis_hash};
+use anyhow::Context;
+use near_network::capture;
+use near_network::raw::Connection;
+use near_network::types::PeerInfo;
+use near_primitives::network::PeerId;
+use near_primitives::types::ShardId;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(clap::Parser)]
+pub struct ReplayCaptureCommand {
+    #[clap(long)]
+    chain_id: String,
+    /// genesis hash to use in the Handshake we send. This must be provided if --chain-id
+    /// is not "mainnet" or "testnet"
+    #[clap(long)]
+    genesis_hash: Option<String>,
+    /// head height to use in the Handshake we send
+    #[clap(long)]
+    head_height: Option<u64>,
+    /// Protocol version to advertise in our handshake
+    #[clap(long)]
+    protocol_version: Option<u32>,
+    /// public key and socket address of the node to replay the capture to, in the format
+    /// {pub key}@{socket addr}. The node should be running in test mode, as it will
+    /// receive messages from another network.
+    #[clap(long)]
+    peer: String,
+    /// capture file, or a directory with capture files, written by a node with
+    /// `network.experimental.capture` set
+    #[clap(long)]
+    capture: PathBuf,
+    /// replay the messages sent by the captured node, instead of the ones it received
+    #[clap(long)]
+    sent: bool,
+    /// only replay the messages exchanged with this peer
+    #[clap(long)]
+    capture_peer: Option<String>,
+    /// only replay the messages of these types, e.g. "Block,BlockHeaders"
+    #[clap(long, value_delimiter = ',')]
+    message_types: Vec<String>,
+    /// replay speed relative to the capture. 0 sends the messages as fast as possible
+    #[clap(long, default_value = "1")]
+    speed: f64,
+}
+
+#[derive(Default)]
+struct ReplayStats {
+    sent: BTreeMap<&'static str, usize>,
+    skipped: usize,
+    received: usize,
+}
+
+impl ReplayCaptureCommand {
+    pub fn run(&self) -> anyhow::Result<()> {
+        tracing::warn!(target: "ping", "the replay-capture command is not stable, and may be removed or changed arbitrarily at any time");
+
+        let genesis_hash = resolve_genesis_hash(&self.chain_id, self.genesis_hash.as_deref())?;
+        let peer = parse_peer(&self.peer)?;
+        let capture_peer = match &self.capture_peer {
+            Some(p) => match PeerInfo::from_str(p) {
+                Ok(p) => Some(p.id),
+                Err(e) => anyhow::bail!("Could not parse --capture-peer {}: {:?}", p, e),
+            },
+            None => None,
+        };
+        if self.speed.is_nan() || self.speed < 0. {
+            anyhow::bail!("--speed should be non-negative");
+        }
+        let direction =
+            if self.sent { capture::Direction::Sent } else { capture::Direction::Received };
+        let reader = capture::Reader::open(&self.capture)
+            .with_context(|| format!("Couldn't open capture at {:?}", self.capture))?;
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async move {
+            let clock = near_time::Clock::real();
+            let mut conn = Connection::connect(
+                &clock,
+                peer.addr.unwrap(),
+                peer.id.clone(),
+                self.protocol_version,
+                &self.chain_id,
+                genesis_hash,
+                self.head_height.unwrap_or(0),
+                vec![ShardId::new(0)],
+                None,
+            )
+            .await
+            .with_context(|| format!("Couldn't connect to {}", peer))?;
+
+            let mut stats = ReplayStats::default();
+            let mut start = None;
+            for record in reader {
+                let record = record.context("Couldn't read the capture")?;
+                if !self.should_replay(&record, direction, capture_peer.as_ref()) {
+                    continue;
+                }
+                // Keep the relative timing of the captured messages.
+                let (start_instant, start_time) =
+                    *start.get_or_insert((tokio::time::Instant::now(), record.time()));
+                if self.speed > 0. {
+                    let offset: std::time::Duration =
+                        (record.time() - start_time).try_into().unwrap_or_default();
+                    let deadline = start_instant + offset.div_f64(self.speed);
+                    // Read what the node sends us in the meantime, so that its send queue
+                    // doesn't fill up.
+                    loop {
+                        tokio::select! {
+                            _ = tokio::time::sleep_until(deadline) => break,
+                            msg = conn.recv() => {
+                                let (msg, _) = msg.context("Connection broken")?;
+                                tracing::debug!(target: "ping", %msg, "received");
+                                stats.received += 1;
+                            }
+                        }
+                    }
+                }
+                if conn.send_captured(&record).await.context("Connection broken")? {
+                    let msg_type = record.message_type().unwrap_or("unknown");
+                    *stats.sent.entry(msg_type).or_default() += 1;
+                } else {
+                    stats.skipped += 1;
+                }
+            }
+            display_stats(&stats);
+            Ok(())
+        })
+    }
+
+    fn should_replay(
+        &self,
+        record: &capture::Record,
+        direction: capture::Direction,
+        capture_peer: Option<&PeerId>,
+    ) -> bool {
+        if record.direction != direction {
+            return false;
+        }
+        if capture_peer.is_some() && record.peer_id.as_ref() != capture_peer {
+            return false;
+        }
+        if !self.message_types.is_empty() {
+            let Some(msg_type) = record.message_type() else {
+                return false;
+            };
+            if !self.message_types.iter().any(|t| t == msg_type) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn display_stats(stats: &ReplayStats) {
+    let width = stats.sent.keys().map(|t| t.len()).max().unwrap_or(0).max("message".len());
+    println!("{:<width$} | {:<10}", "message", "num sent");
+    for (msg_type, count) in &stats.sent {
+        println!("{:<width$} | {:<10}", msg_type, count);
+    }
+    println!(
+        "skipped {} connection setup messages, received {} messages",
+        stats.skipped, stats.received
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReplayCaptureCommand;
+    use clap::Parser as _;
+    use near_network::capture::{Direction, Format, Record};
+    use near_network::types::PeerMessage;
+    use near_primitives::hash::CryptoHash;
+    use near_primitives::network::PeerId;
+
+    fn parse(args: &[&str]) -> ReplayCaptureCommand {
+        let base =
+            ["replay-capture", "--chain-id", "testnet", "--peer", "peer", "--capture", "capture"];
+        ReplayCaptureCommand::try_parse_from(base.iter().chain(args)).unwrap()
+    }
+
+    fn record(direction: Direction, peer_id: Option<PeerId>, msg: PeerMessage) -> Record {
+        Record::from_message(near_time::Utc::UNIX_EPOCH, direction, peer_id, Format::Proto, &msg)
+    }
+
+    #[test]
+    fn args() {
+        let cmd = parse(&[]);
+        assert!(!cmd.sent);
+        assert_eq!(cmd.speed, 1.);
+        assert!(cmd.message_types.is_empty());
+        let cmd = parse(&["--sent", "--speed", "0", "--message-types", "Block,BlockHeaders"]);
+        assert!(cmd.sent);
+        assert_eq!(cmd.speed, 0.);
+        assert_eq!(cmd.message_types, vec!["Block", "BlockHeaders"]);
+    }
+
+    #[test]
+    fn should_replay() {
+        let peer = PeerId::random();
+        let other_peer = PeerId::random();
+        let block_request = PeerMessage::BlockRequest(CryptoHash::default());
+        let headers_request = PeerMessage::BlockHeadersRequest(vec![]);
+        let invalid = Record::new(
+            near_time::Utc::UNIX_EPOCH,
+            Direction::Received,
+            Some(peer.clone()),
+            Format::Proto,
+            vec![0xff, 0xff],
+        );
+
+        // Only the direction is filtered by default.
+        let cmd = parse(&[]);
+        let received = record(Direction::Received, Some(peer.clone()), block_request.clone());
+        assert!(cmd.should_replay(&received, Direction::Received, None));
+        assert!(!cmd.should_replay(&received, Direction::Sent, None));
+        assert!(cmd.should_replay(&invalid, Direction::Received, None));
+
+        // Messages exchanged with other peers, or before the handshake, are skipped.
+        assert!(cmd.should_replay(&received, Direction::Received, Some(&peer)));
+        assert!(!cmd.should_replay(&received, Direction::Received, Some(&other_peer)));
+        let before_handshake = record(Direction::Received, None, block_request.clone());
+        assert!(!cmd.should_replay(&before_handshake, Direction::Received, Some(&peer)));
+
+        // Messages of other types, and messages which can't be parsed, are skipped.
+        let cmd = parse(&["--message-types", "BlockRequest,Block"]);
+        assert!(cmd.should_replay(&received, Direction::Received, None));
+        let headers = record(Direction::Received, Some(peer), headers_request);
+        assert!(!cmd.should_replay(&headers, Direction::Received, None));
+        assert!(!cmd.should_replay(&invalid, Direction::Received, None));
+    }
+}
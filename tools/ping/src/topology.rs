@@ -0,0 +1,261 @@
+use near_network::types::{Edge, EdgeState};
+use near_primitives::network::PeerId;
+use near_primitives::types::AccountId;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+
+/// What we know about a node of the network.
+#[derive(Debug, Default)]
+pub(crate) struct NodeInfo {
+    pub account_id: Option<AccountId>,
+    /// Whether the node is a TIER1 validator.
+    pub tier1: bool,
+    pub pings_sent: usize,
+    pub pongs_received: usize,
+    pub timeouts: usize,
+    pub average_latency: Option<near_time::Duration>,
+}
+
+impl NodeInfo {
+    fn label(&self, peer_id: &PeerId) -> String {
+        let mut label = match &self.account_id {
+            Some(account_id) => account_id.to_string(),
+            None => peer_id.to_string(),
+        };
+        if let Some(latency) = self.average_latency {
+            let latency: std::time::Duration = latency.try_into().unwrap_or_default();
+            write!(label, "\\n{:?}", latency).unwrap();
+        }
+        label
+    }
+
+    fn color(&self) -> &'static str {
+        if self.pongs_received > 0 {
+            "green"
+        } else if self.pings_sent > 0 {
+            "red"
+        } else {
+            "gray"
+        }
+    }
+}
+
+/// The graph of the network, built from the routing table updates sent to us by the node.
+#[derive(Default)]
+pub(crate) struct Topology {
+    edges: HashMap<(PeerId, PeerId), Edge>,
+    /// Number of active edges of every peer which has at least one.
+    active_degrees: BTreeMap<PeerId, usize>,
+}
+
+impl Topology {
+    /// Adds the edges, replacing older versions of them.
+    /// Returns the peers which were not a part of the topology before.
+    pub fn add_edges(&mut self, edges: Vec<Edge>) -> Vec<PeerId> {
+        let mut new_peers = vec![];
+        for edge in edges {
+            if !edge.verify() {
+                tracing::debug!(target: "ping", key = ?edge.key(), "received edge with invalid signature");
+                continue;
+            }
+            let was_active = match self.edges.entry(edge.key().clone()) {
+                Entry::Occupied(mut e) => {
+                    if e.get().nonce() >= edge.nonce() {
+                        continue;
+                    }
+                    e.insert(edge.clone()).edge_type() == EdgeState::Active
+                }
+                Entry::Vacant(e) => {
+                    e.insert(edge.clone());
+                    false
+                }
+            };
+            let is_active = edge.edge_type() == EdgeState::Active;
+            for peer_id in [&edge.key().0, &edge.key().1] {
+                match (was_active, is_active) {
+                    (false, true) => {
+                        let degree = self.active_degrees.entry(peer_id.clone()).or_default();
+                        *degree += 1;
+                        if *degree == 1 {
+                            new_peers.push(peer_id.clone());
+                        }
+                    }
+                    (true, false) => {
+                        let degree = self.active_degrees.get_mut(peer_id).unwrap();
+                        *degree -= 1;
+                        if *degree == 0 {
+                            self.active_degrees.remove(peer_id);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        new_peers
+    }
+
+    fn active_edges(&self) -> impl Iterator<Item = &(PeerId, PeerId)> {
+        self.edges.values().filter(|e| e.edge_type() == EdgeState::Active).map(|e| e.key())
+    }
+
+    /// Peers which have at least one active edge.
+    fn peers(&self) -> impl Iterator<Item = &PeerId> {
+        self.active_degrees.keys()
+    }
+
+    /// All the nodes to display: the peers from the routing table and the ping targets.
+    fn nodes<'a>(
+        &'a self,
+        nodes: &'a HashMap<PeerId, NodeInfo>,
+    ) -> BTreeMap<&'a PeerId, Option<&'a NodeInfo>> {
+        let mut all: BTreeMap<_, _> = self.peers().map(|p| (p, None)).collect();
+        for (peer_id, info) in nodes {
+            all.insert(peer_id, Some(info));
+        }
+        all
+    }
+
+    fn sorted_edges(&self) -> Vec<&(PeerId, PeerId)> {
+        let mut edges: Vec<_> = self.active_edges().collect();
+        edges.sort();
+        edges
+    }
+
+    /// Renders the topology in the Graphviz DOT format. Nodes which responded to our pings
+    /// are green, the ones which didn't are red, and the ones not pinged yet are gray.
+    /// TIER1 validators are drawn as boxes.
+    pub fn to_dot(&self, nodes: &HashMap<PeerId, NodeInfo>) -> String {
+        let default = NodeInfo::default();
+        let mut dot = String::from("graph network {\n");
+        for (peer_id, info) in self.nodes(nodes) {
+            let info = info.unwrap_or(&default);
+            writeln!(
+                dot,
+                "  \"{}\" [label=\"{}\", color={}, shape={}];",
+                peer_id,
+                info.label(peer_id),
+                info.color(),
+                if info.tier1 { "box" } else { "ellipse" }
+            )
+            .unwrap();
+        }
+        for (a, b) in self.sorted_edges() {
+            writeln!(dot, "  \"{}\" -- \"{}\";", a, b).unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the topology as JSON, with a list of nodes and a list of edges.
+    pub fn to_json(&self, nodes: &HashMap<PeerId, NodeInfo>) -> String {
+        let default = NodeInfo::default();
+        let nodes: Vec<_> = self
+            .nodes(nodes)
+            .into_iter()
+            .map(|(peer_id, info)| {
+                let info = info.unwrap_or(&default);
+                serde_json::json!({
+                    "peer_id": peer_id.to_string(),
+                    "account_id": info.account_id,
+                    "tier1": info.tier1,
+                    "pings_sent": info.pings_sent,
+                    "pongs_received": info.pongs_received,
+                    "timeouts": info.timeouts,
+                    "average_latency_ms": info.average_latency.map(|l| l.as_seconds_f64() * 1000.),
+                })
+            })
+            .collect();
+        let edges: Vec<_> =
+            self.sorted_edges().into_iter().map(|(a, b)| [a.to_string(), b.to_string()]).collect();
+        serde_json::to_string_pretty(&serde_json::json!({ "nodes": nodes, "edges": edges }))
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NodeInfo, Topology};
+    use near_crypto::{KeyType, SecretKey};
+    use near_network::types::Edge;
+    use near_primitives::network::PeerId;
+    use std::collections::HashMap;
+
+    fn peer(key: &SecretKey) -> PeerId {
+        PeerId::new(key.public_key())
+    }
+
+    fn make_edge(a: &SecretKey, b: &SecretKey, nonce: u64) -> Edge {
+        let (a, b) = if peer(a) < peer(b) { (a, b) } else { (b, a) };
+        let hash = Edge::build_hash(&peer(a), &peer(b), nonce);
+        Edge::new(peer(a), peer(b), nonce, a.sign(hash.as_ref()), b.sign(hash.as_ref()))
+    }
+
+    fn sorted(mut peers: Vec<PeerId>) -> Vec<PeerId> {
+        peers.sort();
+        peers
+    }
+
+    #[test]
+    fn add_edges() {
+        let [a, b, c] = ["a", "b", "c"].map(|seed| SecretKey::from_seed(KeyType::ED25519, seed));
+        let mut topology = Topology::default();
+
+        let ab = make_edge(&a, &b, 1);
+        assert_eq!(sorted(topology.add_edges(vec![ab.clone()])), sorted(vec![peer(&a), peer(&b)]));
+        assert_eq!(topology.add_edges(vec![make_edge(&b, &c, 1)]), vec![peer(&c)]);
+        // Known and outdated edges don't change anything.
+        assert!(topology.add_edges(vec![ab.clone()]).is_empty());
+        assert_eq!(topology.peers().count(), 3);
+
+        // Edges with invalid signatures are ignored.
+        let d = SecretKey::from_seed(KeyType::ED25519, "d");
+        let hash = Edge::build_hash(&peer(&a), &peer(&d), 1);
+        let (p0, p1) = Edge::make_key(peer(&a), peer(&d));
+        let forged = Edge::new(p0, p1, 1, a.sign(hash.as_ref()), a.sign(hash.as_ref()));
+        assert!(topology.add_edges(vec![forged]).is_empty());
+        assert_eq!(topology.peers().count(), 3);
+
+        // Peers without active edges are removed, and reported again once they are back.
+        let removal = ab.remove_edge(peer(&a), &a);
+        assert!(topology.add_edges(vec![removal.clone(), ab]).is_empty());
+        assert_eq!(topology.peers().cloned().collect::<Vec<_>>(), sorted(vec![peer(&b), peer(&c)]));
+        assert_eq!(topology.add_edges(vec![make_edge(&a, &b, removal.next())]), vec![peer(&a)]);
+        assert_eq!(topology.peers().count(), 3);
+        assert_eq!(topology.sorted_edges().len(), 2);
+    }
+
+    #[test]
+    fn render() {
+        let [a, b, c] = ["a", "b", "c"].map(|seed| SecretKey::from_seed(KeyType::ED25519, seed));
+        let mut topology = Topology::default();
+        topology.add_edges(vec![make_edge(&a, &b, 1)]);
+        let nodes = HashMap::from([
+            (peer(&a), NodeInfo { pings_sent: 2, pongs_received: 1, ..Default::default() }),
+            (peer(&c), NodeInfo { tier1: true, pings_sent: 1, timeouts: 1, ..Default::default() }),
+        ]);
+
+        let dot = topology.to_dot(&nodes);
+        assert!(dot.contains(&format!(
+            "\"{}\" [label=\"{}\", color=green, shape=ellipse];",
+            peer(&a),
+            peer(&a)
+        )));
+        assert!(dot.contains(&format!(
+            "\"{}\" [label=\"{}\", color=gray, shape=ellipse];",
+            peer(&b),
+            peer(&b)
+        )));
+        assert!(dot.contains(&format!(
+            "\"{}\" [label=\"{}\", color=red, shape=box];",
+            peer(&c),
+            peer(&c)
+        )));
+        let (p0, p1) = Edge::make_key(peer(&a), peer(&b));
+        assert!(dot.contains(&format!("\"{}\" -- \"{}\";", p0, p1)));
+
+        let json: serde_json::Value = serde_json::from_str(&topology.to_json(&nodes)).unwrap();
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 3);
+        assert_eq!(json["edges"], serde_json::json!([[p0.to_string(), p1.to_string()]]));
+    }
+}
@@ -261,6 +261,7 @@ fn load_snapshot(load_cmd: LoadCmd) {
                 ReshardingConfig::default(),
                 "resharding_config",
             ),
+            index_contract_events: false,
         },
         None,
         Arc::new(RayonAsyncComputationSpawner),
@@ -1,4 +1,5 @@
 use crate::commands::*;
+use crate::compiled_contract_cache::CompiledContractCacheCmd;
 use crate::congestion_control::CongestionControlCmd;
 use crate::contract_accounts::ContractAccountFilter;
 use crate::replay_headers::replay_headers;
@@ -11,12 +12,14 @@ use near_chain_configs::{GenesisChangeConfig, GenesisValidationMode};
 use near_epoch_manager::EpochManager;
 use near_jsonrpc::start_http_for_readonly_debug_querying;
 use near_network::tcp::ListenerAddr;
+use near_parameters::RuntimeConfigStore;
 use near_primitives::account::id::AccountId;
 use near_primitives::hash::CryptoHash;
 use near_primitives::shard_layout::ShardUId;
 use near_primitives::sharding::ChunkHash;
 use near_primitives::trie_key::col;
 use near_primitives::types::{BlockHeight, ShardId, StateRoot};
+use near_primitives::version::Version;
 use near_primitives_core::types::EpochHeight;
 use near_store::adapter::StoreAdapter;
 use near_store::{Mode, NodeStorage, Store, Temperature};
@@ -58,6 +61,10 @@ pub enum StateViewerSubCommand {
     /// Clear recoverable data in CachedContractCode column.
     #[clap(alias = "clear_cache")]
     ClearCache,
+    /// Export or import the compiled contracts cache, to share it between
+    /// nodes of the same build.
+    #[clap(subcommand)]
+    CompiledContractCache(CompiledContractCacheCmd),
     /// List account names with contracts deployed.
     #[clap(alias = "contract_accounts")]
     ContractAccounts(ContractAccountsCmd),
@@ -109,6 +116,9 @@ pub enum StateViewerSubCommand {
     StateStats(StateStatsCmd),
     /// Benchmark how long does it take to iterate the trie.
     TrieIterationBenchmark(TrieIterationBenchmarkCmd),
+    /// Call a view method of a contract at the head of the storage.
+    #[clap(alias = "view_call")]
+    ViewCall(ViewCallCmd),
     /// View head of the storage.
     #[clap(alias = "view_chain")]
     ViewChain(ViewChainCmd),
@@ -137,6 +147,7 @@ pub enum StateViewerSubCommand {
 }
 
 impl StateViewerSubCommand {
+    /// `version` is the version of the running neard binary.
     #[allow(clippy::large_stack_frames)]
     pub fn run(
         self,
@@ -144,9 +155,11 @@ impl StateViewerSubCommand {
         genesis_validation: GenesisValidationMode,
         mode: Mode,
         temperature: Temperature,
+        version: Version,
     ) {
-        let near_config = load_config(home_dir, genesis_validation)
+        let mut near_config = load_config(home_dir, genesis_validation)
             .unwrap_or_else(|e| panic!("Error loading config: {:#}", e));
+        near_config.client_config.version = version;
 
         let store_opener = NodeStorage::opener(
             home_dir,
@@ -174,6 +187,9 @@ impl StateViewerSubCommand {
             StateViewerSubCommand::Chunks(cmd) => cmd.run(near_config, store),
             StateViewerSubCommand::ChunkApplyStats(cmd) => cmd.run(near_config, store),
             StateViewerSubCommand::ClearCache => clear_cache(store),
+            StateViewerSubCommand::CompiledContractCache(cmd) => {
+                cmd.run(home_dir, near_config, store)
+            }
             StateViewerSubCommand::ContractAccounts(cmd) => cmd.run(home_dir, near_config, store),
             StateViewerSubCommand::DebugUI(cmd) => {
                 cmd.run(home_dir, near_config, storage.get_hot_store(), storage.get_cold_store())
@@ -194,6 +210,7 @@ impl StateViewerSubCommand {
             StateViewerSubCommand::StateChanges(cmd) => cmd.run(home_dir, near_config, store),
             StateViewerSubCommand::StateParts(cmd) => cmd.run(home_dir, near_config, store),
             StateViewerSubCommand::StateStats(cmd) => cmd.run(home_dir, near_config, store),
+            StateViewerSubCommand::ViewCall(cmd) => cmd.run(home_dir, near_config, store),
             StateViewerSubCommand::ViewChain(cmd) => cmd.run(home_dir, near_config, store),
             StateViewerSubCommand::ViewGenesis(cmd) => cmd.run(home_dir, near_config, store),
             StateViewerSubCommand::ViewTrie(cmd) => cmd.run(store),
@@ -230,6 +247,51 @@ impl StorageSource {
     }
 }
 
+/// Where and how to write the gas burnt by each wasm function of the
+/// executed contracts.
+#[derive(clap::Args)]
+pub struct WasmProfileArgs {
+    /// Write the wasm gas profile to this file. Contracts are run with
+    /// Wasmtime while profiling, regardless of the configured VM.
+    #[clap(long)]
+    wasm_profile: Option<PathBuf>,
+    #[clap(long, default_value = "collapsed")]
+    wasm_profile_format: WasmProfileFormat,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy)]
+pub enum WasmProfileFormat {
+    /// One `frame;frame;frame gas` line per call stack, for flamegraph tools.
+    Collapsed,
+    /// Uncompressed pprof protobuf.
+    Pprof,
+}
+
+impl WasmProfileArgs {
+    /// Runs `f`, profiling the contracts it executes if a profile file has
+    /// been given. In that case `f` gets the runtime config store to run the
+    /// contracts with, see `near_vm_runner::configure_for_wasm_profiling`.
+    fn run<T>(&self, chain_id: &str, f: impl FnOnce(Option<RuntimeConfigStore>) -> T) -> T {
+        let Some(path) = &self.wasm_profile else {
+            return f(None);
+        };
+        let mut runtime_config_store = RuntimeConfigStore::for_chain_id(chain_id);
+        runtime_config_store.map_wasm_config(near_vm_runner::configure_for_wasm_profiling);
+        let (result, profile) = near_vm_runner::profile_wasm_gas(|| f(Some(runtime_config_store)));
+        let contents = match self.wasm_profile_format {
+            WasmProfileFormat::Collapsed => {
+                let mut contents = Vec::new();
+                profile.write_collapsed(&mut contents).unwrap();
+                contents
+            }
+            WasmProfileFormat::Pprof => profile.to_pprof(),
+        };
+        std::fs::write(path, contents).unwrap();
+        println!("Wrote the profile of {} gas to {}", profile.total_gas(), path.display());
+        result
+    }
+}
+
 #[derive(clap::ValueEnum, Debug, Clone, Copy)]
 pub enum SaveTrieTemperature {
     // The logic in `crate::commands::maybe_save_trie_changes` is not guaranteed to work correctly when writing
@@ -361,12 +423,26 @@ pub struct ApplyReceiptCmd {
     hash: String,
     #[clap(long, default_value = "trie")]
     storage: StorageSource,
+    #[clap(flatten)]
+    wasm_profile: WasmProfileArgs,
 }
 
 impl ApplyReceiptCmd {
     pub fn run(self, home_dir: &Path, near_config: NearConfig, store: Store) {
         let hash = CryptoHash::from_str(&self.hash).unwrap();
-        apply_receipt(home_dir, near_config, store, hash, self.storage).unwrap();
+        let chain_id = near_config.genesis.config.chain_id.clone();
+        self.wasm_profile
+            .run(&chain_id, |runtime_config_store| {
+                apply_receipt(
+                    home_dir,
+                    near_config,
+                    store,
+                    hash,
+                    self.storage,
+                    runtime_config_store,
+                )
+            })
+            .unwrap();
     }
 }
 
@@ -846,6 +922,38 @@ impl StateStatsCmd {
     }
 }
 
+#[derive(clap::Parser)]
+pub struct ViewCallCmd {
+    #[clap(long)]
+    account_id: AccountId,
+    #[clap(long)]
+    method_name: String,
+    /// Arguments passed to the method as is, usually JSON.
+    #[clap(long, default_value = "")]
+    args: String,
+    #[clap(flatten)]
+    wasm_profile: WasmProfileArgs,
+}
+
+impl ViewCallCmd {
+    pub fn run(self, home_dir: &Path, near_config: NearConfig, store: Store) {
+        let chain_id = near_config.genesis.config.chain_id.clone();
+        self.wasm_profile
+            .run(&chain_id, |runtime_config_store| {
+                view_call(
+                    &self.account_id,
+                    &self.method_name,
+                    self.args.as_bytes(),
+                    home_dir,
+                    near_config,
+                    store,
+                    runtime_config_store,
+                )
+            })
+            .unwrap();
+    }
+}
+
 #[derive(clap::Parser)]
 pub struct ViewChainCmd {
     #[clap(long)]
@@ -9,7 +9,7 @@ use crate::state_dump::state_dump_redis;
 use crate::tx_dump::dump_tx_from_block;
 use crate::util::{
     LoadTrieMode, check_apply_block_result, load_trie, load_trie_stop_at_height,
-    resulting_chunk_extra,
+    load_trie_with_runtime_config_store, resulting_chunk_extra,
 };
 use crate::{apply_chunk, epoch_info};
 use anyhow::Context;
@@ -29,6 +29,7 @@ use near_epoch_manager::shard_assignment::{
     build_assignment_restrictions_v77_to_v78, shard_id_to_index, shard_id_to_uid,
 };
 use near_epoch_manager::{EpochManager, EpochManagerAdapter, proposals_to_epoch_info};
+use near_parameters::RuntimeConfigStore;
 use near_primitives::account::id::AccountId;
 use near_primitives::apply::ApplyChunkReason;
 use near_primitives::block::Block;
@@ -45,6 +46,7 @@ use near_primitives::trie_key::TrieKey;
 use near_primitives::trie_key::col::COLUMNS_WITH_ACCOUNT_ID_IN_KEY;
 use near_primitives::types::{BlockHeight, EpochId, ShardId};
 use near_primitives::version::{PROTOCOL_VERSION, ProtocolFeature};
+use near_primitives::views::{QueryRequest, QueryResponseKind};
 use near_primitives_core::types::{Balance, EpochHeight};
 use near_store::TrieStorage;
 use near_store::adapter::StoreAdapter;
@@ -289,14 +291,16 @@ pub(crate) fn apply_receipt(
     store: Store,
     hash: CryptoHash,
     storage: StorageSource,
+    runtime_config_store: Option<RuntimeConfigStore>,
 ) -> anyhow::Result<()> {
     let epoch_manager =
         EpochManager::new_arc_handle(store.clone(), &near_config.genesis.config, Some(home_dir));
-    let runtime = NightshadeRuntime::from_config(
+    let runtime = NightshadeRuntime::from_config_with_runtime_config_store(
         home_dir,
         store.clone(),
         &near_config,
         epoch_manager.clone(),
+        runtime_config_store,
     )
     .context("could not create the transaction runtime")?;
     apply_chunk::apply_receipt(
@@ -721,6 +725,46 @@ pub(crate) fn state(home_dir: &Path, near_config: NearConfig, store: Store) {
     }
 }
 
+pub(crate) fn view_call(
+    account_id: &AccountId,
+    method_name: &str,
+    args: &[u8],
+    home_dir: &Path,
+    near_config: NearConfig,
+    store: Store,
+    runtime_config_store: Option<RuntimeConfigStore>,
+) -> anyhow::Result<()> {
+    let (epoch_manager, runtime, state_roots, header) =
+        load_trie_with_runtime_config_store(store, home_dir, &near_config, runtime_config_store);
+    let epoch_id = header.epoch_id();
+    let shard_layout = epoch_manager.get_shard_layout(epoch_id)?;
+    let shard_uid = shard_layout.account_id_to_shard_uid(account_id);
+    let shard_index = shard_layout.get_shard_index(shard_uid.shard_id()).unwrap();
+    let request = QueryRequest::CallFunction {
+        account_id: account_id.clone(),
+        method_name: method_name.to_string(),
+        args: args.to_vec().into(),
+    };
+    let response = runtime.query(
+        shard_uid,
+        &state_roots[shard_index],
+        header.height(),
+        header.raw_timestamp(),
+        header.prev_hash(),
+        header.hash(),
+        epoch_id,
+        &request,
+    )?;
+    let QueryResponseKind::CallResult(result) = response.kind else {
+        anyhow::bail!("unexpected query response: {:?}", response.kind);
+    };
+    for log in &result.logs {
+        println!("Log: {}", log);
+    }
+    println!("Result: {}", String::from_utf8_lossy(&result.result));
+    Ok(())
+}
+
 pub(crate) fn view_chain(
     height: Option<BlockHeight>,
     view_block: bool,
@@ -0,0 +1,96 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use near_chain::types::RuntimeAdapter;
+use near_epoch_manager::{EpochManager, EpochManagerAdapter};
+use near_store::Store;
+use near_store::adapter::StoreAdapter;
+use near_vm_runner::FilesystemContractRuntimeCache;
+use nearcore::{NearConfig, NightshadeRuntime, NightshadeRuntimeExt};
+
+/// Commands for sharing the compiled contracts cache between nodes, so that a
+/// new node doesn't have to compile all the contracts it executes. The cache
+/// keys depend on the VM and its config, so the contracts can only be
+/// imported by a node of the same build, with the same protocol version at
+/// the head.
+///
+/// The imported contracts are machine code executed by the node as is, so
+/// only files exported by trusted nodes must be imported.
+#[derive(clap::Subcommand)]
+pub enum CompiledContractCacheCmd {
+    /// Write all the compiled contracts of the cache to a file.
+    Export(ExportCmd),
+    /// Add the compiled contracts of a file written by `export` to the cache.
+    Import(ImportCmd),
+}
+
+impl CompiledContractCacheCmd {
+    pub(crate) fn run(&self, home_dir: &Path, near_config: NearConfig, store: Store) {
+        match self {
+            CompiledContractCacheCmd::Export(cmd) => cmd.run(home_dir, near_config, store),
+            CompiledContractCacheCmd::Import(cmd) => cmd.run(home_dir, near_config, store),
+        }
+    }
+}
+
+#[derive(clap::Parser)]
+pub struct ExportCmd {
+    /// Path of the file to write.
+    #[clap(long)]
+    file: PathBuf,
+}
+
+impl ExportCmd {
+    pub(crate) fn run(&self, home_dir: &Path, near_config: NearConfig, store: Store) {
+        let (cache, vm_config) = open_cache(home_dir, &near_config, store);
+        let file = BufWriter::new(File::create(&self.file).unwrap());
+        let count = cache.export(&vm_config, &build_id(&near_config), file).unwrap();
+        println!("Exported {} compiled contracts to {}", count, self.file.display());
+    }
+}
+
+#[derive(clap::Parser)]
+pub struct ImportCmd {
+    /// Path of the file written by `export`, by a trusted node.
+    #[clap(long)]
+    file: PathBuf,
+}
+
+impl ImportCmd {
+    pub(crate) fn run(&self, home_dir: &Path, near_config: NearConfig, store: Store) {
+        let (cache, vm_config) = open_cache(home_dir, &near_config, store);
+        let file = BufReader::new(File::open(&self.file).unwrap());
+        let count = cache.import(&vm_config, &build_id(&near_config), file).unwrap();
+        println!("Imported {} compiled contracts from {}", count, self.file.display());
+    }
+}
+
+/// Opens the compiled contracts cache of the node, and returns it with the VM
+/// config of the protocol version at the head.
+fn open_cache(
+    home_dir: &Path,
+    near_config: &NearConfig,
+    store: Store,
+) -> (FilesystemContractRuntimeCache, Arc<near_vm_runner::logic::Config>) {
+    let epoch_manager =
+        EpochManager::new_arc_handle(store.clone(), &near_config.genesis.config, Some(home_dir));
+    let runtime =
+        NightshadeRuntime::from_config(home_dir, store.clone(), near_config, epoch_manager.clone())
+            .unwrap();
+    let head = store.chain_store().head().unwrap();
+    let protocol_version = epoch_manager.get_epoch_protocol_version(&head.epoch_id).unwrap();
+    let vm_config = runtime.get_runtime_config(protocol_version).wasm_config.clone();
+    let cache =
+        FilesystemContractRuntimeCache::new(home_dir, near_config.config.store.path.as_ref())
+            .unwrap();
+    (cache, vm_config)
+}
+
+/// Identifies the build of neard, the compiled contracts may differ between
+/// builds even if the VM and its config are the same.
+fn build_id(near_config: &NearConfig) -> String {
+    let version = &near_config.client_config.version;
+    format!("{} {} {}", version.build, version.commit, version.rustc_version)
+}
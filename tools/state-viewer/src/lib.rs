@@ -4,6 +4,7 @@ mod apply_chain_range;
 mod apply_chunk;
 pub mod cli;
 mod commands;
+mod compiled_contract_cache;
 mod congestion_control;
 mod contract_accounts;
 mod epoch_info;
@@ -11,6 +11,7 @@ use near_primitives::sharding::{ChunkHash, ReceiptProof, ShardChunk, StateSyncIn
 use near_primitives::state::FlatStateValue;
 use near_primitives::state_sync::{
     ShardStateSyncResponseHeader, StateHeaderKey, StatePartKey, StateSyncDumpProgress,
+    StateSyncDumpedParts,
 };
 use near_primitives::transaction::{ExecutionOutcomeWithProof, SignedTransaction};
 use near_primitives::types::chunk_extra::ChunkExtra;
@@ -244,6 +245,8 @@ fn format_block_misc_value<'a>(key: &'a [u8], value: &'a [u8]) -> Box<dyn Debug
         Box::new(Vec::<StateRoot>::try_from_slice(value).unwrap())
     } else if key.starts_with(near_store::STATE_SYNC_DUMP_KEY) {
         Box::new(StateSyncDumpProgress::try_from_slice(value).unwrap())
+    } else if key.starts_with(near_store::STATE_SYNC_DUMPED_PARTS_KEY) {
+        Box::new(StateSyncDumpedParts::try_from_slice(value).unwrap())
     } else {
         Box::new(value)
     }
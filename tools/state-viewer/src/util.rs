@@ -7,6 +7,7 @@ use near_chain::{
 use near_epoch_manager::{
     EpochManager, EpochManagerAdapter, EpochManagerHandle, shard_assignment::shard_id_to_uid,
 };
+use near_parameters::RuntimeConfigStore;
 use near_primitives::{
     errors::EpochError,
     types::{BlockHeight, Gas, ShardId, StateRoot, chunk_extra::ChunkExtra},
@@ -31,11 +32,32 @@ pub fn load_trie(
     load_trie_stop_at_height(store, home_dir, near_config, LoadTrieMode::Latest)
 }
 
+/// Same as `load_trie`, with the runtime config store of the chain replaced by
+/// `runtime_config_store` if it is given.
+pub fn load_trie_with_runtime_config_store(
+    store: Store,
+    home_dir: &Path,
+    near_config: &NearConfig,
+    runtime_config_store: Option<RuntimeConfigStore>,
+) -> (Arc<EpochManagerHandle>, Arc<NightshadeRuntime>, Vec<StateRoot>, BlockHeader) {
+    load_trie_impl(store, home_dir, near_config, LoadTrieMode::Latest, runtime_config_store)
+}
+
 pub fn load_trie_stop_at_height(
     store: Store,
     home_dir: &Path,
     near_config: &NearConfig,
     mode: LoadTrieMode,
+) -> (Arc<EpochManagerHandle>, Arc<NightshadeRuntime>, Vec<StateRoot>, BlockHeader) {
+    load_trie_impl(store, home_dir, near_config, mode, None)
+}
+
+fn load_trie_impl(
+    store: Store,
+    home_dir: &Path,
+    near_config: &NearConfig,
+    mode: LoadTrieMode,
+    runtime_config_store: Option<RuntimeConfigStore>,
 ) -> (Arc<EpochManagerHandle>, Arc<NightshadeRuntime>, Vec<StateRoot>, BlockHeader) {
     let chain_store = ChainStore::new(
         store.clone(),
@@ -45,8 +67,13 @@ pub fn load_trie_stop_at_height(
 
     let epoch_manager =
         EpochManager::new_arc_handle(store.clone(), &near_config.genesis.config, Some(home_dir));
-    let runtime =
-        NightshadeRuntime::from_config(home_dir, store, near_config, epoch_manager.clone());
+    let runtime = NightshadeRuntime::from_config_with_runtime_config_store(
+        home_dir,
+        store,
+        near_config,
+        epoch_manager.clone(),
+        runtime_config_store,
+    );
     let runtime = runtime.expect("could not create the transaction runtime");
 
     let head = chain_store.head().unwrap();